@@ -0,0 +1,314 @@
+//! Benchmarks of the generated components, measuring instantiation time, the latency of a simple
+//! exported function, the throughput of converting records and variants, and how the latency of
+//! calling an export depends on the number of exports in the world.
+//!
+//! The examples are generated and built in release mode into `tmp/bench` before measuring.
+
+use anyhow::{Context, anyhow, bail};
+use camino::{Utf8Path, Utf8PathBuf};
+use criterion::{Criterion, criterion_group, criterion_main};
+use std::process::Command;
+use toml_edit::DocumentMut;
+use wasm_rquickjs::{EmbeddingMode, GeneratorOptions, JsModuleSpec, generate_wrapper_crate};
+use wasmtime::component::{Component, Func, Linker, ResourceTable, Val};
+use wasmtime::{Engine, Store};
+use wasmtime_wasi::p2::{IoView, WasiCtx, WasiView};
+use wasmtime_wasi_http::{WasiHttpCtx, WasiHttpView};
+
+struct Host {
+    table: ResourceTable,
+    wasi: WasiCtx,
+    wasi_http: WasiHttpCtx,
+}
+
+impl IoView for Host {
+    fn table(&mut self) -> &mut ResourceTable {
+        &mut self.table
+    }
+}
+
+impl WasiView for Host {
+    fn ctx(&mut self) -> &mut WasiCtx {
+        &mut self.wasi
+    }
+}
+
+impl WasiHttpView for Host {
+    fn ctx(&mut self) -> &mut WasiHttpCtx {
+        &mut self.wasi_http
+    }
+}
+
+/// A compiled example with everything needed to instantiate it
+struct Bench {
+    engine: Engine,
+    linker: Linker<Host>,
+    component: Component,
+}
+
+impl Bench {
+    fn new(example: &Utf8Path, label: &str, options: &GeneratorOptions) -> anyhow::Result<Self> {
+        let wasm_path = build_example(example, label, options)?;
+
+        let mut config = wasmtime::Config::default();
+        config.async_support(true);
+        config.wasm_component_model(true);
+        let engine = Engine::new(&config)?;
+
+        let mut linker: Linker<Host> = Linker::new(&engine);
+        wasmtime_wasi::p2::add_to_linker_async(&mut linker)?;
+        wasmtime_wasi_http::add_only_http_to_linker_async(&mut linker)?;
+
+        let component = Component::from_file(&engine, &wasm_path)
+            .with_context(|| format!("Failed to load component {wasm_path}"))?;
+
+        Ok(Self {
+            engine,
+            linker,
+            component,
+        })
+    }
+
+    fn new_store(&self) -> Store<Host> {
+        let host = Host {
+            table: ResourceTable::new(),
+            wasi: WasiCtx::builder().build(),
+            wasi_http: WasiHttpCtx::new(),
+        };
+        Store::new(&self.engine, host)
+    }
+
+    /// Instantiates the component and looks up one of its exported functions
+    async fn instantiate(
+        &self,
+        interface: Option<&str>,
+        function: &str,
+    ) -> anyhow::Result<(Store<Host>, Func)> {
+        let mut store = self.new_store();
+        let instance = self
+            .linker
+            .instantiate_async(&mut store, &self.component)
+            .await?;
+        let func = match interface {
+            Some(interface) => {
+                let (_, interface_id) = instance
+                    .get_export(&mut store, None, interface)
+                    .ok_or_else(|| anyhow!("Interface {interface} not found"))?;
+                let (_, func_id) = instance
+                    .get_export(&mut store, Some(&interface_id), function)
+                    .ok_or_else(|| anyhow!("Function {function} not found in {interface}"))?;
+                instance.get_func(&mut store, func_id)
+            }
+            None => instance.get_func(&mut store, function),
+        }
+        .ok_or_else(|| anyhow!("Function {function} not found"))?;
+
+        Ok((store, func))
+    }
+}
+
+async fn invoke(store: &mut Store<Host>, func: Func, params: &[Val]) -> Vec<Val> {
+    let mut results = vec![Val::Bool(false); func.results(&*store).len()];
+    func.call_async(&mut *store, params, &mut results)
+        .await
+        .expect("Failed to call function");
+    func.post_return_async(&mut *store)
+        .await
+        .expect("Failed to finish call");
+    results
+}
+
+/// Generates and builds the wrapper crate of an example in release mode, returning the path of
+/// the built component
+fn build_example(
+    path: &Utf8Path,
+    label: &str,
+    options: &GeneratorOptions,
+) -> anyhow::Result<Utf8PathBuf> {
+    let example = path
+        .file_name()
+        .ok_or_else(|| anyhow!("Invalid example path {path}"))?;
+    let wrapper_crate_root = Utf8Path::new("tmp").join("bench").join(label);
+    let target_dir = Utf8PathBuf::try_from(std::env::current_dir()?)?
+        .join("tmp")
+        .join("bench-target");
+
+    generate_wrapper_crate(
+        path.join("wit"),
+        &[JsModuleSpec {
+            name: example.to_string(),
+            mode: EmbeddingMode::EmbedFile(path.join("src").join(format!("{example}.js")).into()),
+            entry: true,
+        }],
+        &wrapper_crate_root,
+        None,
+        options,
+    )?;
+
+    let status = Command::new("cargo-component")
+        .args([
+            "build",
+            "--release",
+            "--no-default-features",
+            "--features",
+            "http",
+        ])
+        .arg("--target-dir")
+        .arg(&target_dir)
+        .current_dir(&wrapper_crate_root)
+        .status()
+        .context("Failed to run cargo-component")?;
+    if !status.success() {
+        bail!("Failed to compile the wrapper crate in {wrapper_crate_root}");
+    }
+
+    let cargo_toml = std::fs::read_to_string(wrapper_crate_root.join("Cargo.toml"))?;
+    let doc = cargo_toml.parse::<DocumentMut>()?;
+    let package_name = doc["package"]["name"]
+        .as_str()
+        .ok_or_else(|| anyhow!("The wrapper crate's Cargo.toml has no package name"))?;
+
+    Ok(target_dir
+        .join("wasm32-wasip1")
+        .join("release")
+        .join(format!("{}.wasm", package_name.replace('-', "_"))))
+}
+
+fn point(x: i32, y: i32, label: Option<&str>) -> Val {
+    Val::Record(vec![
+        ("x".to_string(), Val::S32(x)),
+        ("y".to_string(), Val::S32(y)),
+        (
+            "label".to_string(),
+            Val::Option(label.map(|label| Box::new(Val::String(label.to_string())))),
+        ),
+    ])
+}
+
+fn benchmarks(c: &mut Criterion) {
+    let runtime = tokio::runtime::Runtime::new().expect("Failed to create tokio runtime");
+
+    let example1 = Bench::new(
+        &example_path("example1"),
+        "example1",
+        &GeneratorOptions::default(),
+    )
+    .expect("Failed to build example1");
+
+    c.bench_function("instantiate example1", |b| {
+        b.to_async(&runtime).iter(|| async {
+            let mut store = example1.new_store();
+            example1
+                .linker
+                .instantiate_async(&mut store, &example1.component)
+                .await
+                .expect("Failed to instantiate example1")
+        })
+    });
+
+    let (mut store, hello) = runtime
+        .block_on(example1.instantiate(None, "hello"))
+        .expect("Failed to instantiate example1");
+    let params = [Val::String("world".to_string())];
+    c.bench_function("call example1 hello", |b| {
+        b.iter(|| runtime.block_on(invoke(&mut store, hello, &params)))
+    });
+
+    let params = [
+        Val::Variant(
+            "line".to_string(),
+            Some(Box::new(Val::Tuple(vec![
+                point(0, 0, Some("start")),
+                point(10, 20, Some("end")),
+            ]))),
+        ),
+        Val::S32(1),
+        Val::S32(-1),
+    ];
+    let mut group = c.benchmark_group("convert records and variants");
+    for (label, intern_field_names) in [("default", false), ("interned", true)] {
+        let options = GeneratorOptions {
+            intern_field_names,
+            ..Default::default()
+        };
+        let bench = Bench::new(
+            &example_path("interned-field-names"),
+            &format!("interned-field-names-{label}"),
+            &options,
+        )
+        .expect("Failed to build interned-field-names");
+        let (mut store, move_shape) = runtime
+            .block_on(bench.instantiate(Some("quickjs:interned-field-names/api"), "move-shape"))
+            .expect("Failed to instantiate interned-field-names");
+
+        group.bench_function(label, |b| {
+            b.iter(|| runtime.block_on(invoke(&mut store, move_shape, &params)))
+        });
+    }
+    group.finish();
+
+    // The generated dispatch resolves exports by index, so calling the last export of a large
+    // world should cost the same as calling the first one of a small world
+    let mut group = c.benchmark_group("call in a large world");
+    for count in [10, 1000] {
+        let path = generate_large_world(count).expect("Failed to generate large world");
+        let options = GeneratorOptions {
+            invoke_export: true,
+            ..Default::default()
+        };
+        let bench = Bench::new(&path, &format!("large-world-{count}"), &options)
+            .expect("Failed to build large world");
+
+        let last = format!("f{}", count - 1);
+        let (mut store, func) = runtime
+            .block_on(bench.instantiate(Some("quickjs:large-world/api"), &last))
+            .expect("Failed to instantiate large world");
+        let params = [Val::U32(1)];
+        group.bench_function(format!("{count} exports, direct"), |b| {
+            b.iter(|| runtime.block_on(invoke(&mut store, func, &params)))
+        });
+
+        let (mut store, rquickjs_invoke) = runtime
+            .block_on(bench.instantiate(None, "rquickjs-invoke"))
+            .expect("Failed to instantiate large world");
+        let params = [
+            Val::String(format!("quickjs:large-world/api#{last}")),
+            Val::String("[1]".to_string()),
+        ];
+        group.bench_function(format!("{count} exports, rquickjs-invoke"), |b| {
+            b.iter(|| runtime.block_on(invoke(&mut store, rquickjs_invoke, &params)))
+        });
+    }
+    group.finish();
+}
+
+fn example_path(example: &str) -> Utf8PathBuf {
+    Utf8Path::new("examples").join(example)
+}
+
+/// Writes the WIT package and JS module of a world exporting `count` functions into
+/// `tmp/bench/sources`, returning the path of the generated example
+fn generate_large_world(count: usize) -> anyhow::Result<Utf8PathBuf> {
+    let path = Utf8Path::new("tmp")
+        .join("bench")
+        .join("sources")
+        .join("large-world");
+    std::fs::create_dir_all(path.join("wit"))?;
+    std::fs::create_dir_all(path.join("src"))?;
+
+    let mut wit = String::from("package quickjs:large-world;\n\ninterface api {\n");
+    let mut js = String::from("export const api = {\n");
+    for index in 0..count {
+        wit.push_str(&format!("  f{index}: func(x: u32) -> u32;\n"));
+        js.push_str(&format!("    f{index}: (x) => x + {index},\n"));
+    }
+    wit.push_str("}\n\nworld large-world {\n  export api;\n}\n");
+    js.push_str("};\n");
+
+    std::fs::write(path.join("wit").join("large-world.wit"), wit)?;
+    std::fs::write(path.join("src").join("large-world.js"), js)?;
+    Ok(path)
+}
+
+criterion_group!(benches, benchmarks);
+criterion_main!(benches);
@@ -0,0 +1,127 @@
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use std::path::PathBuf;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{Ident, LitStr, Token, parse_macro_input};
+use wasm_rquickjs::{EmbeddingMode, GeneratorOptions, JsModuleSpec, generate_inline_module};
+
+/// Generates the wrapper of a JavaScript module implementing a WIT world at compile time, and
+/// includes it into the crate root module the macro is invoked in, without generated sources in
+/// the crate's own directory.
+///
+/// The paths are relative to the crate's manifest directory. The wrapper is generated into the
+/// crate's `OUT_DIR` if it has a build script, otherwise into `target/wasm-rquickjs`.
+///
+/// ```ignore
+/// wasm_rquickjs_macro::embed_js_component! {
+///     wit: "wit",
+///     js: "src/component.js",
+///     world: "my-world", // optional
+/// }
+/// ```
+///
+/// The crate must have the dependencies and features of a generated wrapper crate's
+/// `Cargo.toml`, and be built with `cargo-component` targeting the WIT world.
+#[proc_macro]
+pub fn embed_js_component(input: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(input as Args);
+    expand(&args)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+fn expand(args: &Args) -> syn::Result<proc_macro2::TokenStream> {
+    let manifest_dir = env_path("CARGO_MANIFEST_DIR")?;
+    let package = std::env::var("CARGO_PKG_NAME").unwrap_or_else(|_| "component".to_string());
+    let output = match std::env::var_os("OUT_DIR") {
+        Some(out_dir) => PathBuf::from(out_dir).join("wasm-rquickjs"),
+        None => manifest_dir.join("target").join("wasm-rquickjs"),
+    }
+    .join(package);
+
+    let js = JsModuleSpec {
+        name: "bundle/script_module".to_string(),
+        mode: EmbeddingMode::EmbedFile(manifest_dir.join(args.js.value())),
+        entry: true,
+    };
+    let inline = generate_inline_module(
+        manifest_dir.join(args.wit.value()),
+        &[js],
+        &output,
+        args.world.as_ref().map(|world| world.value()).as_deref(),
+        &GeneratorOptions::default(),
+    )
+    .map_err(|err| syn::Error::new(Span::call_site(), format!("{err:#}")))?;
+
+    // Including the inputs makes cargo rebuild the crate when they change
+    let inputs = inline.inputs.iter().map(|input| input.as_str());
+    let root = inline.root.as_str();
+    Ok(quote! {
+        #[allow(static_mut_refs)]
+        #[allow(unsafe_op_in_unsafe_fn)]
+        mod bindings;
+
+        #(const _: &[u8] = include_bytes!(#inputs);)*
+        include!(#root);
+    })
+}
+
+fn env_path(name: &str) -> syn::Result<PathBuf> {
+    std::env::var_os(name)
+        .map(PathBuf::from)
+        .ok_or_else(|| syn::Error::new(Span::call_site(), format!("{name} is not set")))
+}
+
+/// The `key: "value"` arguments of the macro
+struct Args {
+    wit: LitStr,
+    js: LitStr,
+    world: Option<LitStr>,
+}
+
+impl Parse for Args {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut wit = None;
+        let mut js = None;
+        let mut world = None;
+        for arg in Punctuated::<Arg, Token![,]>::parse_terminated(input)? {
+            let slot = match arg.name.to_string().as_str() {
+                "wit" => &mut wit,
+                "js" => &mut js,
+                "world" => &mut world,
+                _ => {
+                    return Err(syn::Error::new(
+                        arg.name.span(),
+                        "Unknown argument, expected `wit`, `js` or `world`",
+                    ));
+                }
+            };
+            if slot.replace(arg.value).is_some() {
+                return Err(syn::Error::new(arg.name.span(), "Duplicate argument"));
+            }
+        }
+        Ok(Self {
+            wit: wit.ok_or_else(|| input.error("Missing the `wit` argument"))?,
+            js: js.ok_or_else(|| input.error("Missing the `js` argument"))?,
+            world,
+        })
+    }
+}
+
+struct Arg {
+    name: Ident,
+    value: LitStr,
+}
+
+impl Parse for Arg {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = input.parse()?;
+        input.parse::<Token![:]>()?;
+        Ok(Self {
+            name,
+            value: input.parse()?,
+        })
+    }
+}
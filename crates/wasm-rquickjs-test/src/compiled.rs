@@ -0,0 +1,351 @@
+use anyhow::{Context, anyhow, bail};
+use camino::{Utf8Path, Utf8PathBuf};
+use camino_tempfile::NamedUtf8TempFile;
+use sha2::{Digest, Sha256};
+use std::io::Write;
+use std::process::Command;
+use toml_edit::DocumentMut;
+use wasm_rquickjs::compose;
+
+/// The cargo features of a generated wrapper crate to compile it with
+#[derive(Copy, Clone)]
+pub enum FeatureCombination {
+    None,
+    LogOnly,
+    HttpOnly,
+    Default,
+    /// The `http` and the optional `intl` features
+    Intl,
+    /// The `http` and the optional `codecs` features
+    Codecs,
+    /// The `http` and the optional `compression` features
+    Compression,
+    /// The `http` and the optional `passwords` features
+    Passwords,
+    /// The `http` and the optional `jwt` features
+    Jwt,
+    /// The `http` and the optional `regex` features
+    Regex,
+    /// The `http` and the optional `temporal` features
+    Temporal,
+    /// The `http` and the optional `messageformat` features
+    MessageFormat,
+    /// The `http` and the optional `markup` features
+    Markup,
+    /// The `http` and the optional `config` features
+    Config,
+    /// The `http` and the optional `templates` features
+    Templates,
+    /// The `http` and the optional `csv` features
+    Csv,
+    /// The `http` and the optional `hashing` features
+    Hashing,
+}
+
+impl FeatureCombination {
+    pub fn all() -> Vec<FeatureCombination> {
+        vec![Self::None, Self::LogOnly, Self::HttpOnly, Self::Default]
+    }
+
+    pub fn label(&self) -> &str {
+        match self {
+            Self::None => "none",
+            Self::LogOnly => "log",
+            Self::HttpOnly => "http",
+            Self::Default => "default",
+            Self::Intl => "intl",
+            Self::Codecs => "codecs",
+            Self::Compression => "compression",
+            Self::Passwords => "passwords",
+            Self::Jwt => "jwt",
+            Self::Regex => "regex",
+            Self::Temporal => "temporal",
+            Self::MessageFormat => "messageformat",
+            Self::Markup => "markup",
+            Self::Config => "config",
+            Self::Templates => "templates",
+            Self::Csv => "csv",
+            Self::Hashing => "hashing",
+        }
+    }
+
+    pub fn cargo_args(&self) -> Vec<&'static str> {
+        match self {
+            FeatureCombination::None => vec!["--no-default-features"],
+            FeatureCombination::LogOnly => {
+                vec!["--no-default-features", "--features", "logging"]
+            }
+            FeatureCombination::HttpOnly => vec!["--no-default-features", "--features", "http"],
+            FeatureCombination::Default => vec![],
+            FeatureCombination::Intl => vec!["--no-default-features", "--features", "http,intl"],
+            FeatureCombination::Codecs => {
+                vec!["--no-default-features", "--features", "http,codecs"]
+            }
+            FeatureCombination::Compression => {
+                vec!["--no-default-features", "--features", "http,compression"]
+            }
+            FeatureCombination::Passwords => {
+                vec!["--no-default-features", "--features", "http,passwords"]
+            }
+            FeatureCombination::Jwt => vec!["--no-default-features", "--features", "http,jwt"],
+            FeatureCombination::Regex => {
+                vec!["--no-default-features", "--features", "http,regex"]
+            }
+            FeatureCombination::Temporal => {
+                vec!["--no-default-features", "--features", "http,temporal"]
+            }
+            FeatureCombination::MessageFormat => {
+                vec!["--no-default-features", "--features", "http,messageformat"]
+            }
+            FeatureCombination::Markup => {
+                vec!["--no-default-features", "--features", "http,markup"]
+            }
+            FeatureCombination::Config => {
+                vec!["--no-default-features", "--features", "http,config"]
+            }
+            FeatureCombination::Templates => {
+                vec!["--no-default-features", "--features", "http,templates"]
+            }
+            FeatureCombination::Csv => vec!["--no-default-features", "--features", "http,csv"],
+            FeatureCombination::Hashing => {
+                vec!["--no-default-features", "--features", "http,hashing"]
+            }
+        }
+    }
+}
+
+/// The WASI target to compile a generated wrapper crate for
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum CompileTarget {
+    /// `wasm32-wasip1`, adapted to a WASI 0.2 component by `cargo-component`
+    #[default]
+    Wasip1,
+    /// `wasm32-wasip2`, compiled directly to a WASI 0.2 component
+    Wasip2,
+}
+
+impl CompileTarget {
+    /// The environment variable selecting the target of the runtime tests, `wasip1` or `wasip2`
+    pub const ENV_VAR: &'static str = "WASM_RQUICKJS_TEST_TARGET";
+
+    pub fn all() -> Vec<CompileTarget> {
+        vec![Self::Wasip1, Self::Wasip2]
+    }
+
+    pub fn label(&self) -> &str {
+        match self {
+            Self::Wasip1 => "wasip1",
+            Self::Wasip2 => "wasip2",
+        }
+    }
+
+    /// The Rust target triple
+    pub fn triple(&self) -> &str {
+        match self {
+            Self::Wasip1 => "wasm32-wasip1",
+            Self::Wasip2 => "wasm32-wasip2",
+        }
+    }
+
+    /// Gets the target selected by the [`Self::ENV_VAR`] environment variable, defaulting to
+    /// [`CompileTarget::Wasip1`]
+    pub fn from_env() -> anyhow::Result<CompileTarget> {
+        match std::env::var(Self::ENV_VAR) {
+            Ok(value) => Self::all()
+                .into_iter()
+                .find(|target| target.label() == value)
+                .ok_or_else(|| {
+                    anyhow!(
+                        "Unknown target `{value}` in {}, expected wasip1 or wasip2",
+                        Self::ENV_VAR
+                    )
+                }),
+            Err(_) => Ok(Self::default()),
+        }
+    }
+}
+
+enum WasmSource {
+    Precompiled(Utf8PathBuf),
+    OwnedTemporary(NamedUtf8TempFile),
+}
+
+/// A compiled WASM component to be tested
+pub struct CompiledTest {
+    wasm: WasmSource,
+}
+
+impl CompiledTest {
+    /// The environment variable disabling the cache of compiled components when set
+    pub const NO_CACHE_ENV_VAR: &'static str = "WASM_RQUICKJS_TEST_NO_CACHE";
+
+    /// Uses an already compiled WASM component
+    pub fn from_wasm(wasm_path: impl Into<Utf8PathBuf>) -> CompiledTest {
+        CompiledTest {
+            wasm: WasmSource::Precompiled(wasm_path.into()),
+        }
+    }
+
+    /// Compiles a generated wrapper crate with `cargo-component` for `wasm32-wasip1`, using the
+    /// given feature combination.
+    ///
+    /// If `target_dir` is set, it is used as the cargo target directory instead of the crate's
+    /// own `target` directory, to share build artifacts between multiple wrapper crates.
+    ///
+    /// The compiled components are cached in the `wasm-rquickjs-cache` directory of the target
+    /// directory, by the hash of the wrapper crate's sources, the features and the target. A
+    /// wrapper crate generated again from the same example by the same generator is not compiled
+    /// again, unless the [`Self::NO_CACHE_ENV_VAR`] environment variable is set.
+    pub fn compile(
+        wrapper_crate_root: &Utf8Path,
+        feature_combination: FeatureCombination,
+        target_dir: Option<&Utf8Path>,
+    ) -> anyhow::Result<CompiledTest> {
+        Self::compile_for_target(
+            wrapper_crate_root,
+            feature_combination,
+            CompileTarget::Wasip1,
+            target_dir,
+        )
+    }
+
+    /// Compiles a generated wrapper crate like [`CompiledTest::compile`], for the given target
+    pub fn compile_for_target(
+        wrapper_crate_root: &Utf8Path,
+        feature_combination: FeatureCombination,
+        target: CompileTarget,
+        target_dir: Option<&Utf8Path>,
+    ) -> anyhow::Result<CompiledTest> {
+        let mut command = Command::new("cargo-component");
+        command.arg("build").arg("--target").arg(target.triple());
+        let target_dir = match target_dir {
+            Some(target_dir) => {
+                // cargo resolves relative target directories from the wrapper crate's root
+                let target_dir = if target_dir.is_relative() {
+                    Utf8PathBuf::try_from(std::env::current_dir()?)?.join(target_dir)
+                } else {
+                    target_dir.to_path_buf()
+                };
+                command.arg("--target-dir").arg(&target_dir);
+                target_dir
+            }
+            None => wrapper_crate_root.join("target"),
+        };
+
+        let use_cache = std::env::var_os(Self::NO_CACHE_ENV_VAR).is_none();
+        let cache_key = cache_key(wrapper_crate_root, feature_combination, target)?;
+        let cached_wasm = target_dir
+            .join("wasm-rquickjs-cache")
+            .join(format!("{cache_key}.wasm"));
+        if use_cache && cached_wasm.exists() {
+            println!("Using the cached component of the wrapper crate in {wrapper_crate_root}");
+            return Ok(CompiledTest::from_wasm(cached_wasm));
+        }
+
+        println!(
+            "Compiling wrapper crate in {wrapper_crate_root} for {}",
+            target.triple()
+        );
+        let status = command
+            .args(feature_combination.cargo_args())
+            .current_dir(wrapper_crate_root)
+            .status()
+            .context("Failed to run cargo-component")?;
+        if !status.success() {
+            bail!("Failed to compile the wrapper crate in {wrapper_crate_root}");
+        }
+
+        let cargo_toml = std::fs::read_to_string(wrapper_crate_root.join("Cargo.toml"))
+            .context("Failed to read the wrapper crate's Cargo.toml")?;
+        let doc = cargo_toml.parse::<DocumentMut>()?;
+        let package_name = doc["package"]["name"]
+            .as_str()
+            .ok_or_else(|| anyhow!("The wrapper crate's Cargo.toml has no package name"))?;
+
+        let wasm = target_dir
+            .join(target.triple())
+            .join("debug")
+            .join(format!("{}.wasm", package_name.replace('-', "_")));
+        if use_cache {
+            // Other wrapper crates sharing the target directory overwrite the built component
+            if let Some(cache_dir) = cached_wasm.parent() {
+                std::fs::create_dir_all(cache_dir)
+                    .context("Failed to create the compiled component cache")?;
+            }
+            std::fs::copy(&wasm, &cached_wasm).context("Failed to cache the compiled component")?;
+            return Ok(CompiledTest::from_wasm(cached_wasm));
+        }
+        Ok(CompiledTest::from_wasm(wasm))
+    }
+
+    pub fn wasm_path(&self) -> &Utf8Path {
+        match &self.wasm {
+            WasmSource::Precompiled(path) => path,
+            WasmSource::OwnedTemporary(temp_file) => temp_file.path(),
+        }
+    }
+
+    /// Composes this component with `other`, satisfying the imports of `other` with the
+    /// matching exports of this one.
+    pub fn plug_into(&self, other: &CompiledTest) -> anyhow::Result<CompiledTest> {
+        let bytes = compose(other.wasm_path(), &[self.wasm_path().to_path_buf()])?;
+        let mut wasm_path = NamedUtf8TempFile::new()?;
+        wasm_path.write_all(bytes.as_slice())?;
+        wasm_path.flush()?;
+        Ok(CompiledTest {
+            wasm: WasmSource::OwnedTemporary(wasm_path),
+        })
+    }
+}
+
+/// Hashes the sources of a wrapper crate, except its `target` directory and `Cargo.lock`, which
+/// are written by the build, together with the features and the target it is compiled with
+fn cache_key(
+    wrapper_crate_root: &Utf8Path,
+    feature_combination: FeatureCombination,
+    target: CompileTarget,
+) -> anyhow::Result<String> {
+    let mut files = Vec::new();
+    collect_source_files(wrapper_crate_root, wrapper_crate_root, &mut files)?;
+    files.sort();
+
+    let mut hasher = Sha256::new();
+    hasher.update(target.triple());
+    for arg in feature_combination.cargo_args() {
+        hasher.update([0]);
+        hasher.update(arg);
+    }
+    for file in files {
+        hasher.update([0]);
+        hasher.update(file.as_str().replace('\\', "/"));
+        hasher.update([0]);
+        hasher.update(
+            std::fs::read(wrapper_crate_root.join(&file))
+                .with_context(|| format!("Failed to read {file}"))?,
+        );
+    }
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+fn collect_source_files(
+    root: &Utf8Path,
+    dir: &Utf8Path,
+    files: &mut Vec<Utf8PathBuf>,
+) -> anyhow::Result<()> {
+    for entry in dir
+        .read_dir_utf8()
+        .with_context(|| format!("Failed to list {dir}"))?
+    {
+        let entry = entry?;
+        let relative = entry.path().strip_prefix(root)?.to_path_buf();
+        if relative == "target" || relative == "Cargo.lock" {
+            continue;
+        }
+        if entry.file_type()?.is_dir() {
+            collect_source_files(root, entry.path(), files)?;
+        } else {
+            files.push(relative);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,323 @@
+use anyhow::anyhow;
+use camino::{Utf8Path, Utf8PathBuf};
+use camino_tempfile::{NamedUtf8TempFile, Utf8TempDir};
+use std::fs;
+use std::sync::{Arc, Mutex};
+use wasmtime::component::{Component, Func, Instance, Linker, ResourceAny, ResourceTable, Val};
+use wasmtime::{AsContextMut, Engine, Store};
+use wasmtime_wasi::p2::{IoView, OutputFile, WasiCtx, WasiView, bindings};
+use wasmtime_wasi::{DirPerms, FilePerms};
+use wasmtime_wasi_http::{WasiHttpCtx, WasiHttpView};
+
+/// The WASI environment the component under test is instantiated in
+#[derive(Debug, Clone, Default)]
+pub struct TestInstanceOptions {
+    /// Command line arguments
+    pub args: Vec<String>,
+    /// Environment variables
+    pub env: Vec<(String, String)>,
+    /// Files to create in the temporary directory preopened as `/`, with their contents
+    pub files: Vec<(Utf8PathBuf, Vec<u8>)>,
+    /// Directories to create in the temporary directory preopened as `/`
+    pub directories: Vec<Utf8PathBuf>,
+    /// Imported functions implemented by calling back into an export of the instance itself
+    pub reentrant_imports: Vec<ReentrantImport>,
+}
+
+/// An imported function without parameters and results, implemented by the host by calling an
+/// exported function without parameters of the same instance, failing with the error of this call
+#[derive(Debug, Clone)]
+pub struct ReentrantImport {
+    /// The imported interface, such as `quickjs:reentrancy/host`
+    pub interface: String,
+    /// The name of the imported function
+    pub function: String,
+    /// The interface of the called back export, or `None` for a function exported by the world
+    pub export_interface: Option<String>,
+    /// The name of the called back export
+    pub export_function: String,
+}
+
+/// An instance of a component, with its standard output and error captured to temporary files
+#[allow(dead_code)]
+pub struct TestInstance {
+    engine: Engine,
+    linker: Linker<Host>,
+    component: Component,
+    store: Store<Host>,
+    instance: Instance,
+    stdout_file: NamedUtf8TempFile,
+    stderr_file: NamedUtf8TempFile,
+    temp_dir: Utf8TempDir,
+}
+
+impl TestInstance {
+    pub async fn new(wasm_path: &Utf8Path) -> anyhow::Result<Self> {
+        Self::with_options(wasm_path, &TestInstanceOptions::default()).await
+    }
+
+    pub async fn with_options(
+        wasm_path: &Utf8Path,
+        options: &TestInstanceOptions,
+    ) -> anyhow::Result<Self> {
+        let mut config = wasmtime::Config::default();
+        config.async_support(true);
+        config.wasm_component_model(true);
+        let engine = Engine::new(&config)?;
+        let mut linker: Linker<Host> = Linker::new(&engine);
+
+        wasmtime_wasi::p2::add_to_linker_with_options_async(
+            &mut linker,
+            &bindings::LinkOptions::default(),
+        )?;
+        wasmtime_wasi_http::add_only_http_to_linker_async(&mut linker)?;
+
+        let stdout_file = NamedUtf8TempFile::new()?;
+        let stderr_file = NamedUtf8TempFile::new()?;
+
+        let temp_dir = Utf8TempDir::new()?;
+        for directory in &options.directories {
+            fs::create_dir_all(temp_dir.path().join(directory))?;
+        }
+        for (path, contents) in &options.files {
+            fs::write(temp_dir.path().join(path), contents)?;
+        }
+
+        let mut builder = WasiCtx::builder();
+        builder
+            .stdout(OutputFile::new(stdout_file.reopen()?))
+            .stderr(OutputFile::new(stderr_file.reopen()?))
+            .args(&options.args)
+            .envs(&options.env)
+            .preopened_dir(&temp_dir, "/", DirPerms::all(), FilePerms::all())?;
+        let ctx = builder.build();
+        let http_ctx = WasiHttpCtx::new();
+        let host = Host {
+            table: Arc::new(Mutex::new(ResourceTable::new())),
+            wasi: Arc::new(Mutex::new(ctx)),
+            wasi_http: Arc::new(http_ctx),
+        };
+
+        // The reentrant imports need the instance, which only exists after the linking
+        let reentered_instance = Arc::new(Mutex::new(None::<Instance>));
+        for import in &options.reentrant_imports {
+            let reentered_instance = reentered_instance.clone();
+            let import = import.clone();
+            linker.instance(&import.interface)?.func_new_async(
+                &import.function.clone(),
+                move |mut store, _params, _results| {
+                    let instance = reentered_instance
+                        .lock()
+                        .unwrap()
+                        .expect("The reentrant import was called before the instantiation");
+                    let import = import.clone();
+                    Box::new(async move {
+                        let func = find_func(
+                            &instance,
+                            &mut store,
+                            import.export_interface.as_deref(),
+                            &import.export_function,
+                        )?;
+                        let mut results = (0..func.results(&mut store).len())
+                            .map(|_| Val::Bool(false))
+                            .collect::<Vec<_>>();
+                        func.call_async(&mut store, &[], &mut results).await?;
+                        func.post_return_async(&mut store).await
+                    })
+                },
+            )?;
+        }
+
+        let component = Component::from_file(&engine, wasm_path)?;
+        let mut store = Store::new(&engine, host);
+
+        let instance = linker.instantiate_async(&mut store, &component).await?;
+        reentered_instance.lock().unwrap().replace(instance);
+
+        Ok(Self {
+            engine,
+            linker,
+            component,
+            store,
+            instance,
+            stdout_file,
+            stderr_file,
+            temp_dir,
+        })
+    }
+
+    pub async fn invoke_and_capture_output(
+        &mut self,
+        interface_name: Option<&str>,
+        function_name: &str,
+        args: &[Val],
+    ) -> (anyhow::Result<Option<Val>>, String) {
+        let (results, stdout, _stderr) = self
+            .invoke_and_capture_output_with_stderr(interface_name, function_name, args)
+            .await;
+        (results, stdout)
+    }
+
+    pub async fn invoke_and_capture_output_with_stderr(
+        &mut self,
+        interface_name: Option<&str>,
+        function_name: &str,
+        args: &[Val],
+    ) -> (anyhow::Result<Option<Val>>, String, String) {
+        let results = self
+            .invoke_and_capture_output_inner(interface_name, function_name, args)
+            .await;
+
+        let stdout = fs::read_to_string(&self.stdout_file).expect("failed to read stdout");
+        let stderr = fs::read_to_string(&self.stderr_file).expect("failed to read stderr");
+
+        if results.is_err() {
+            for line in stdout.lines() {
+                println!("[stdout] {line}");
+            }
+        }
+
+        for line in stderr.lines() {
+            println!("[stderr] {line}");
+        }
+
+        (
+            results.map(|results| results.first().cloned()),
+            stdout,
+            stderr,
+        )
+    }
+
+    /// The temporary directory preopened as `/` for the component
+    pub fn temp_dir_path(&self) -> &Utf8Path {
+        self.temp_dir.path()
+    }
+
+    async fn invoke_and_capture_output_inner(
+        &mut self,
+        interface_name: Option<&str>,
+        function_name: &str,
+        args: &[Val],
+    ) -> anyhow::Result<Vec<Val>> {
+        let func = find_func(
+            &self.instance,
+            &mut self.store,
+            interface_name,
+            function_name,
+        )?;
+
+        self.perform_invoke(func, args).await
+    }
+
+    async fn perform_invoke(&mut self, func: Func, args: &[Val]) -> anyhow::Result<Vec<Val>> {
+        let mut results = (0..func.results(&mut self.store).len())
+            .map(|_| Val::Bool(false))
+            .collect::<Vec<_>>();
+        func.call_async(&mut self.store, args, &mut results).await?;
+        func.post_return_async(&mut self.store).await?;
+        Ok(results)
+    }
+
+    pub async fn drop_resource(&mut self, resource: ResourceAny) -> anyhow::Result<()> {
+        resource.resource_drop_async::<Host>(&mut self.store).await
+    }
+}
+
+/// Finds an exported function of an instance, in the given exported interface or exported by the
+/// world itself
+fn find_func(
+    instance: &Instance,
+    mut store: impl AsContextMut,
+    interface_name: Option<&str>,
+    function_name: &str,
+) -> anyhow::Result<Func> {
+    match interface_name {
+        Some(interface_name) => {
+            let (_, exported_instance_id) =
+                instance
+                    .get_export(&mut store, None, interface_name)
+                    .ok_or_else(|| anyhow!("Interface {interface_name} not found"))?;
+            let (_, func_id) = instance
+                .get_export(&mut store, Some(&exported_instance_id), function_name)
+                .ok_or_else(|| {
+                    anyhow!("Function {function_name} not found in interface {interface_name}")
+                })?;
+            instance
+                .get_func(&mut store, func_id)
+                .ok_or_else(|| anyhow!("Function {function_name} not found"))
+        }
+        None => instance
+            .get_func(&mut store, function_name)
+            .ok_or_else(|| anyhow!("Function {function_name} not found")),
+    }
+}
+
+/// Instantiates a component with the given options, invokes a single function and returns its
+/// result with the captured standard output
+pub async fn invoke_and_capture_output(
+    wasm_path: &Utf8Path,
+    options: &TestInstanceOptions,
+    interface_name: Option<&str>,
+    function_name: &str,
+    args: &[Val],
+) -> (anyhow::Result<Option<Val>>, String) {
+    match TestInstance::with_options(wasm_path, options).await {
+        Ok(mut test_instance) => {
+            test_instance
+                .invoke_and_capture_output(interface_name, function_name, args)
+                .await
+        }
+        Err(e) => (Err(e), String::new()),
+    }
+}
+
+/// Instantiates a component with the given options, invokes a single function and returns its
+/// result with the captured standard output and error
+pub async fn invoke_and_capture_output_with_stderr(
+    wasm_path: &Utf8Path,
+    options: &TestInstanceOptions,
+    interface_name: Option<&str>,
+    function_name: &str,
+    args: &[Val],
+) -> (anyhow::Result<Option<Val>>, String, String) {
+    match TestInstance::with_options(wasm_path, options).await {
+        Ok(mut test_instance) => {
+            test_instance
+                .invoke_and_capture_output_with_stderr(interface_name, function_name, args)
+                .await
+        }
+        Err(e) => (Err(e), String::new(), String::new()),
+    }
+}
+
+#[derive(Clone)]
+struct Host {
+    pub table: Arc<Mutex<ResourceTable>>,
+    pub wasi: Arc<Mutex<WasiCtx>>,
+    pub wasi_http: Arc<WasiHttpCtx>,
+}
+
+impl IoView for Host {
+    fn table(&mut self) -> &mut ResourceTable {
+        Arc::get_mut(&mut self.table)
+            .expect("ResourceTable is shared and cannot be borrowed mutably")
+            .get_mut()
+            .expect("ResourceTable mutex must never fail")
+    }
+}
+
+impl WasiView for Host {
+    fn ctx(&mut self) -> &mut WasiCtx {
+        Arc::get_mut(&mut self.wasi)
+            .expect("WasiCtx is shared and cannot be borrowed mutably")
+            .get_mut()
+            .expect("WasiCtx mutex must never fail")
+    }
+}
+
+impl WasiHttpView for Host {
+    fn ctx(&mut self) -> &mut WasiHttpCtx {
+        Arc::get_mut(&mut self.wasi_http)
+            .expect("WasiHttpCtx is shared and cannot be borrowed mutably")
+    }
+}
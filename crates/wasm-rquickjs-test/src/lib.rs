@@ -0,0 +1,12 @@
+//! Helpers for writing integration tests for WASM components generated by `wasm-rquickjs`,
+//! running them in wasmtime with WASI and WASI HTTP support.
+
+mod compiled;
+mod instance;
+
+pub use compiled::{CompileTarget, CompiledTest, FeatureCombination};
+pub use instance::{
+    ReentrantImport, TestInstance, TestInstanceOptions, invoke_and_capture_output,
+    invoke_and_capture_output_with_stderr,
+};
+pub use wasm_rquickjs::plug;
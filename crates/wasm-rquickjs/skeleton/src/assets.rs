@@ -0,0 +1,3 @@
+// Empty file, to be generated
+
+pub static ASSETS: &[(&str, &[u8])] = &[];
@@ -0,0 +1,107 @@
+// Implementation of AbortController and AbortSignal
+// See https://developer.mozilla.org/en-US/docs/Web/API/AbortController
+
+// Aborts a signal, only called by its controller and the combinators
+const abort = Symbol('abort');
+
+export class DOMException extends Error {
+    constructor(message = '', name = 'Error') {
+        super(message);
+        this.name = name;
+    }
+}
+
+export class AbortSignal {
+    #aborted = false;
+    #reason = undefined;
+    #listeners = [];
+
+    constructor() {
+        this.onabort = null;
+    }
+
+    get aborted() {
+        return this.#aborted;
+    }
+
+    get reason() {
+        return this.#reason;
+    }
+
+    throwIfAborted() {
+        if (this.#aborted) {
+            throw this.#reason;
+        }
+    }
+
+    // A signal is aborted at most once, so all listeners are only called once
+    addEventListener(type, listener) {
+        if (type === 'abort' && listener !== null && listener !== undefined && !this.#listeners.includes(listener)) {
+            this.#listeners.push(listener);
+        }
+    }
+
+    removeEventListener(type, listener) {
+        if (type === 'abort') {
+            this.#listeners = this.#listeners.filter((entry) => entry !== listener);
+        }
+    }
+
+    [abort](reason) {
+        if (this.#aborted) {
+            return;
+        }
+        this.#aborted = true;
+        this.#reason = reason === undefined ? new DOMException('This operation was aborted', 'AbortError') : reason;
+
+        const event = { type: 'abort', target: this };
+        const listeners = this.#listeners;
+        this.#listeners = [];
+        if (typeof this.onabort === 'function') {
+            this.onabort(event);
+        }
+        for (const listener of listeners) {
+            if (typeof listener === 'function') {
+                listener.call(this, event);
+            } else {
+                listener.handleEvent(event);
+            }
+        }
+    }
+
+    static abort(reason) {
+        const signal = new AbortSignal();
+        signal[abort](reason);
+        return signal;
+    }
+
+    static timeout(milliseconds) {
+        const signal = new AbortSignal();
+        setTimeout(() => signal[abort](new DOMException('The operation timed out', 'TimeoutError')), milliseconds);
+        return signal;
+    }
+
+    static any(signals) {
+        const signal = new AbortSignal();
+        for (const source of signals) {
+            if (source.aborted) {
+                signal[abort](source.reason);
+                break;
+            }
+            source.addEventListener('abort', () => signal[abort](source.reason), { once: true });
+        }
+        return signal;
+    }
+}
+
+export class AbortController {
+    #signal = new AbortSignal();
+
+    get signal() {
+        return this.#signal;
+    }
+
+    abort(reason) {
+        this.#signal[abort](reason);
+    }
+}
@@ -0,0 +1,12 @@
+// JS functions for the AbortController implementation
+pub const ABORT_JS: &str = include_str!("abort.js");
+
+// JS code wiring the abort module into the global context
+pub const WIRE_JS: &str = r#"
+        import * as __wasm_rquickjs_abort from '__wasm_rquickjs_builtin/abort';
+        globalThis.AbortController = __wasm_rquickjs_abort.AbortController;
+        globalThis.AbortSignal = __wasm_rquickjs_abort.AbortSignal;
+        if (globalThis.DOMException === undefined) {
+            globalThis.DOMException = __wasm_rquickjs_abort.DOMException;
+        }
+    "#;
@@ -0,0 +1,36 @@
+import {
+    read_bytes,
+    read_text,
+    asset_names
+} from '__wasm_rquickjs_builtin/assets_native';
+
+export function readText(name) {
+    const [contents, error] = read_text(name);
+    if (error !== undefined) {
+        throw new Error(error);
+    }
+    return contents;
+}
+
+export function readBytes(name) {
+    const [contents, error] = read_bytes(name);
+    if (error !== undefined) {
+        throw new Error(error);
+    }
+    return contents;
+}
+
+export function has(name) {
+    return asset_names().includes(name);
+}
+
+export function list() {
+    return asset_names();
+}
+
+export default {
+    readText,
+    readBytes,
+    has,
+    list
+};
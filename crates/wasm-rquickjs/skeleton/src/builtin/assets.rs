@@ -0,0 +1,54 @@
+// Native functions for the assets implementation
+#[rquickjs::module(rename = "camelCase")]
+pub mod native_module {
+    use rquickjs::prelude::List;
+    use rquickjs::{Ctx, TypedArray};
+
+    #[rquickjs::function]
+    pub fn read_bytes(
+        name: String,
+        ctx: Ctx<'_>,
+    ) -> List<(Option<TypedArray<'_, u8>>, Option<String>)> {
+        match super::find_asset(&name) {
+            Some(bytes) => {
+                let typed_array =
+                    TypedArray::new(ctx.clone(), bytes).expect("Failed to create TypedArray");
+                List((Some(typed_array), None))
+            }
+            None => List((None, Some(format!("Unknown asset: {name}")))),
+        }
+    }
+
+    #[rquickjs::function]
+    pub fn read_text(name: String) -> List<(Option<String>, Option<String>)> {
+        match super::find_asset(&name) {
+            Some(bytes) => match std::str::from_utf8(bytes) {
+                Ok(text) => List((Some(text.to_string()), None)),
+                Err(err) => List((
+                    None,
+                    Some(format!("Asset {name} is not valid UTF-8: {err}")),
+                )),
+            },
+            None => List((None, Some(format!("Unknown asset: {name}")))),
+        }
+    }
+
+    #[rquickjs::function]
+    pub fn asset_names() -> Vec<String> {
+        crate::assets::ASSETS
+            .iter()
+            .map(|(name, _)| name.to_string())
+            .collect()
+    }
+}
+
+/// Looks up an embedded asset by name, the generated list being sorted by name
+pub(super) fn find_asset(name: &str) -> Option<&'static [u8]> {
+    crate::assets::ASSETS
+        .binary_search_by(|(asset_name, _)| (*asset_name).cmp(name))
+        .ok()
+        .map(|index| crate::assets::ASSETS[index].1)
+}
+
+// JS functions for the assets implementation
+pub const ASSETS_JS: &str = include_str!("assets.js");
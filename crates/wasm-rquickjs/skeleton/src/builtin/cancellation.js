@@ -0,0 +1,38 @@
+import * as cancellationNative from '__wasm_rquickjs_builtin/cancellation_native';
+import { AbortController } from '__wasm_rquickjs_builtin/abort';
+
+export class CancellationError extends Error {
+    constructor(message = 'The call has been cancelled') {
+        super(message);
+        this.name = 'CancellationError';
+    }
+}
+
+// The controller of the running export call's signal, created on its first use
+let current = undefined;
+
+function currentController() {
+    const call = cancellationNative.call_id();
+    if (current === undefined || current.call !== call) {
+        current = { call, controller: new AbortController() };
+        if (cancellationNative.is_cancelled()) {
+            current.controller.abort(new CancellationError());
+        }
+    }
+    return current.controller;
+}
+
+export function callSignal() {
+    return currentController().signal;
+}
+
+export function isCallCancelled() {
+    return cancellationNative.is_cancelled();
+}
+
+export function cancelCall(reason = new CancellationError()) {
+    // Rejecting the operations listening to the signal first, so they fail with the reason
+    // instead of the errors of the given up host calls
+    currentController().abort(reason);
+    cancellationNative.cancel();
+}
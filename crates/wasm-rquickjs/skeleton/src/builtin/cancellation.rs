@@ -0,0 +1,152 @@
+use crate::internal::{current_borrow_epoch, format_caught_error, get_js_state};
+use futures::future::{Either, select};
+use rquickjs::{CatchResultExt, Function, Object, async_with};
+use std::cell::RefCell;
+use std::future::Future;
+use std::pin::{Pin, pin};
+use std::task::{Context, Poll, Waker};
+
+pub const CANCELLATION_GLOBAL: &str = "__wasm_rquickjs_cancellation";
+
+// Native functions for the cancellation of export calls
+#[rquickjs::module(rename = "camelCase")]
+pub mod native_module {
+    #[rquickjs::function]
+    pub fn call_id() -> usize {
+        crate::internal::current_borrow_epoch()
+    }
+
+    #[rquickjs::function]
+    pub fn is_cancelled() -> bool {
+        super::is_call_cancelled()
+    }
+
+    #[rquickjs::function]
+    pub fn cancel() {
+        super::cancel_call();
+    }
+}
+
+// JS functions for the cancellation implementation
+pub const CANCELLATION_JS: &str = include_str!("cancellation.js");
+
+// JS code exposing the cancellation module to the Rust side of the export calls
+pub const WIRE_JS: &str = r#"
+        import * as __wasm_rquickjs_cancellation from 'cancellation';
+        globalThis.__wasm_rquickjs_cancellation = __wasm_rquickjs_cancellation;
+    "#;
+
+/// Whether the background work still pending when an export call has its result is cancelled
+/// instead of being waited for
+pub fn is_enabled() -> bool {
+    crate::CANCEL_BACKGROUND_WORK
+}
+
+/// The cancellation state of the running export call, identified by its borrow epoch
+#[derive(Default)]
+struct CallCancellation {
+    call: usize,
+    cancelled: bool,
+    wakers: Vec<Waker>,
+}
+
+thread_local! {
+    static CANCELLATION: RefCell<CallCancellation> = RefCell::new(CallCancellation::default());
+}
+
+/// Runs `f` on the cancellation state of the running export call, resetting the state left by a
+/// previous call
+fn with_cancellation<R>(f: impl FnOnce(&mut CallCancellation) -> R) -> R {
+    CANCELLATION.with_borrow_mut(|cancellation| {
+        let call = current_borrow_epoch();
+        if cancellation.call != call {
+            *cancellation = CallCancellation {
+                call,
+                ..Default::default()
+            };
+        }
+        f(cancellation)
+    })
+}
+
+/// Whether the running export call has been cancelled
+pub fn is_call_cancelled() -> bool {
+    with_cancellation(|cancellation| cancellation.cancelled)
+}
+
+/// Cancels the running export call: the pending timers are cleared and the waits for host
+/// pollables wrapped by [`unless_call_cancelled`] are woken up to give up
+pub fn cancel_call() {
+    let wakers = with_cancellation(|cancellation| {
+        cancellation.cancelled = true;
+        std::mem::take(&mut cancellation.wakers)
+    });
+    for waker in wakers {
+        waker.wake();
+    }
+
+    let state = get_js_state();
+    let abort_handles = std::mem::take(&mut *state.abort_handles.borrow_mut());
+    for handle in abort_handles.into_values() {
+        handle.abort();
+    }
+}
+
+/// Runs a future until it completes, or until the running export call is cancelled, in which case
+/// the future is dropped and `None` is returned
+#[cfg_attr(not(feature = "http"), allow(dead_code))]
+pub async fn unless_call_cancelled<F: Future>(future: F) -> Option<F::Output> {
+    match select(pin!(future), CallCancelled).await {
+        Either::Left((output, _)) => Some(output),
+        Either::Right(_) => None,
+    }
+}
+
+/// Cancels the background work of the finished export call through the JS `cancelCall`, rejecting
+/// the fetches listening to the call's signal before giving up the pending host pollables
+pub async fn cancel_background_work() {
+    if !is_enabled() {
+        return;
+    }
+
+    let js_state = get_js_state();
+    async_with!(js_state.ctx => |ctx| {
+        let cancellation: Object = ctx
+            .globals()
+            .get(CANCELLATION_GLOBAL)
+            .expect("Failed to get the cancellation module");
+        let cancel_call: Function = cancellation
+            .get("cancelCall")
+            .expect("Failed to get cancelCall");
+        cancel_call
+            .call::<_, ()>(())
+            .catch(&ctx)
+            .unwrap_or_else(|e| panic!("Failed to cancel the background work:\n{}", format_caught_error(e)));
+    })
+    .await;
+}
+
+/// Completes when the running export call is cancelled
+#[cfg_attr(not(feature = "http"), allow(dead_code))]
+struct CallCancelled;
+
+impl Future for CallCancelled {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        with_cancellation(|cancellation| {
+            if cancellation.cancelled {
+                Poll::Ready(())
+            } else {
+                if !cancellation
+                    .wakers
+                    .iter()
+                    .any(|waker| waker.will_wake(cx.waker()))
+                {
+                    cancellation.wakers.push(cx.waker().clone());
+                }
+                Poll::Pending
+            }
+        })
+    }
+}
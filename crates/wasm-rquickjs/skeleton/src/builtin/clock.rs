@@ -0,0 +1,29 @@
+// Native functions for the Date implementation using the configured clock
+#[rquickjs::module]
+pub mod native_module {
+    #[rquickjs::function]
+    pub fn now() -> f64 {
+        crate::DATE_CLOCK.map_or(0.0, |now| now())
+    }
+}
+
+/// Whether `Date` uses the configured clock instead of the wall clock
+pub fn is_enabled() -> bool {
+    crate::DATE_CLOCK.is_some()
+}
+
+// JS code making `Date.now()`, `new Date()` and `Date()` use the configured clock
+pub const WIRE_JS: &str = r#"
+        import * as __wasm_rquickjs_clock_native from '__wasm_rquickjs_builtin/clock_native';
+        const __wasm_rquickjs_date = Date;
+        __wasm_rquickjs_date.now = () => __wasm_rquickjs_clock_native.now();
+        globalThis.Date = new Proxy(__wasm_rquickjs_date, {
+            construct(target, args, newTarget) {
+                const dateArgs = args.length === 0 ? [__wasm_rquickjs_clock_native.now()] : args;
+                return Reflect.construct(target, dateArgs, newTarget);
+            },
+            apply() {
+                return new __wasm_rquickjs_date(__wasm_rquickjs_clock_native.now()).toString();
+            },
+        });
+    "#;
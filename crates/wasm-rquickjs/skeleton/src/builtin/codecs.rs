@@ -0,0 +1,762 @@
+use rquickjs::convert::Coerced;
+use rquickjs::function::{Constructor, This};
+use rquickjs::{Array, ArrayBuffer, Ctx, Exception, Function, Object, Type, TypedArray, Value};
+
+// Native MessagePack codec
+#[rquickjs::module]
+pub mod msgpack_module {
+    use rquickjs::{Ctx, TypedArray, Value};
+
+    #[rquickjs::function]
+    pub fn encode<'js>(value: Value<'js>, ctx: Ctx<'js>) -> rquickjs::Result<TypedArray<'js, u8>> {
+        super::encode(&ctx, value, super::Format::MessagePack)
+    }
+
+    #[rquickjs::function]
+    pub fn decode<'js>(bytes: Value<'js>, ctx: Ctx<'js>) -> rquickjs::Result<Value<'js>> {
+        super::decode(&ctx, bytes, super::Format::MessagePack)
+    }
+}
+
+// Native CBOR codec
+#[rquickjs::module]
+pub mod cbor_module {
+    use rquickjs::{Ctx, TypedArray, Value};
+
+    #[rquickjs::function]
+    pub fn encode<'js>(value: Value<'js>, ctx: Ctx<'js>) -> rquickjs::Result<TypedArray<'js, u8>> {
+        super::encode(&ctx, value, super::Format::Cbor)
+    }
+
+    #[rquickjs::function]
+    pub fn decode<'js>(bytes: Value<'js>, ctx: Ctx<'js>) -> rquickjs::Result<Value<'js>> {
+        super::decode(&ctx, bytes, super::Format::Cbor)
+    }
+}
+
+/// Containers nested deeper than this are rejected
+const MAX_DEPTH: usize = 256;
+
+/// Integers of this magnitude or less are decoded as numbers, larger ones as `BigInt`s
+const MAX_SAFE_INTEGER: i128 = (1 << 53) - 1;
+
+#[derive(Clone, Copy)]
+enum Format {
+    MessagePack,
+    Cbor,
+}
+
+impl Format {
+    fn name(self) -> &'static str {
+        match self {
+            Format::MessagePack => "MessagePack",
+            Format::Cbor => "CBOR",
+        }
+    }
+}
+
+/// A value of the data model shared by the codecs, between JS and the encoded bytes
+enum Item {
+    Null,
+    Undefined,
+    Bool(bool),
+    Int(i128),
+    Float(f64),
+    String(String),
+    Bytes(Vec<u8>),
+    Array(Vec<Item>),
+    Map(Vec<(Item, Item)>),
+    /// Milliseconds since the Unix epoch
+    Date(f64),
+}
+
+fn encode<'js>(
+    ctx: &Ctx<'js>,
+    value: Value<'js>,
+    format: Format,
+) -> rquickjs::Result<TypedArray<'js, u8>> {
+    let item = to_item(ctx, value, &mut Vec::new())?;
+    let mut output = Vec::new();
+    let result = match format {
+        Format::MessagePack => write_msgpack(&item, &mut output),
+        Format::Cbor => write_cbor(&item, &mut output),
+    };
+    result.map_err(|error| {
+        Exception::throw_type(ctx, &format!("Cannot encode as {}: {error}", format.name()))
+    })?;
+    TypedArray::new(ctx.clone(), output)
+}
+
+fn decode<'js>(ctx: &Ctx<'js>, bytes: Value<'js>, format: Format) -> rquickjs::Result<Value<'js>> {
+    let bytes = bytes_of(ctx, &bytes)?.ok_or_else(|| {
+        Exception::throw_type(
+            ctx,
+            "Expected an Uint8Array, an ArrayBuffer or a typed array",
+        )
+    })?;
+    let mut reader = Reader {
+        bytes: &bytes,
+        position: 0,
+    };
+    let item = match format {
+        Format::MessagePack => reader.msgpack(0),
+        Format::Cbor => reader.cbor(0),
+    }
+    .and_then(|item| {
+        if reader.position < bytes.len() {
+            Err(format!("Unexpected data at offset {}", reader.position))
+        } else {
+            Ok(item)
+        }
+    })
+    .map_err(|error| {
+        Exception::throw_syntax(ctx, &format!("Invalid {} data: {error}", format.name()))
+    })?;
+    from_item(ctx, item)
+}
+
+/// Gets the bytes of an `ArrayBuffer` or of the view of one, or nothing for other values
+fn bytes_of<'js>(ctx: &Ctx<'js>, value: &Value<'js>) -> rquickjs::Result<Option<Vec<u8>>> {
+    if let Ok(array) = TypedArray::<u8>::from_value(value.clone()) {
+        return Ok(array.as_bytes().map(<[u8]>::to_vec));
+    }
+    if let Some(buffer) = ArrayBuffer::from_value(value.clone()) {
+        return Ok(buffer.as_bytes().map(<[u8]>::to_vec));
+    }
+    let array_buffer: Object = ctx.globals().get("ArrayBuffer")?;
+    let is_view: Function = array_buffer.get("isView")?;
+    if !is_view.call::<_, bool>((value.clone(),))? {
+        return Ok(None);
+    }
+    let view = value.as_object().expect("views are objects");
+    let buffer: ArrayBuffer = view.get("buffer")?;
+    let offset: usize = view.get("byteOffset")?;
+    let length: usize = view.get("byteLength")?;
+    Ok(buffer
+        .as_bytes()
+        .map(|bytes| bytes[offset..offset + length].to_vec()))
+}
+
+fn is_instance_of<'js>(
+    ctx: &Ctx<'js>,
+    object: &Object<'js>,
+    class: &str,
+) -> rquickjs::Result<bool> {
+    let constructor: Value = ctx.globals().get(class)?;
+    Ok(object.is_instance_of(&constructor))
+}
+
+/// Converts a JS value, with `ancestors` holding the arrays and objects it is nested in
+fn to_item<'js>(
+    ctx: &Ctx<'js>,
+    value: Value<'js>,
+    ancestors: &mut Vec<Value<'js>>,
+) -> rquickjs::Result<Item> {
+    if !matches!(value.type_of(), Type::Array | Type::Object) {
+        return to_scalar_item(ctx, value);
+    }
+    if ancestors.contains(&value) {
+        return Err(Exception::throw_type(ctx, "Cannot encode a cyclic value"));
+    }
+    if ancestors.len() >= MAX_DEPTH {
+        return Err(Exception::throw_range(
+            ctx,
+            "Cannot encode a value nested this deeply",
+        ));
+    }
+    ancestors.push(value.clone());
+    let item = to_container_item(ctx, value, ancestors);
+    ancestors.pop();
+    item
+}
+
+fn to_scalar_item<'js>(ctx: &Ctx<'js>, value: Value<'js>) -> rquickjs::Result<Item> {
+    match value.type_of() {
+        Type::Undefined => Ok(Item::Undefined),
+        Type::Null => Ok(Item::Null),
+        Type::Bool => Ok(Item::Bool(value.as_bool().unwrap_or_default())),
+        Type::Int => Ok(Item::Int(value.as_int().unwrap_or_default() as i128)),
+        Type::Float => {
+            let float = value.as_float().unwrap_or_default();
+            if float.fract() == 0.0
+                && float.abs() <= MAX_SAFE_INTEGER as f64
+                && !(float == 0.0 && float.is_sign_negative())
+            {
+                Ok(Item::Int(float as i128))
+            } else {
+                Ok(Item::Float(float))
+            }
+        }
+        Type::BigInt => {
+            let Coerced(digits) = value.get::<Coerced<String>>()?;
+            digits
+                .parse::<i128>()
+                .map(Item::Int)
+                .map_err(|_| Exception::throw_range(ctx, "Cannot encode a BigInt of over 128 bits"))
+        }
+        Type::String => Ok(Item::String(
+            value.as_string().expect("checked type").to_string()?,
+        )),
+        other => Err(Exception::throw_type(
+            ctx,
+            &format!("Cannot encode a value of type {other}"),
+        )),
+    }
+}
+
+fn to_container_item<'js>(
+    ctx: &Ctx<'js>,
+    value: Value<'js>,
+    ancestors: &mut Vec<Value<'js>>,
+) -> rquickjs::Result<Item> {
+    match value.type_of() {
+        Type::Array => {
+            let array = value.as_array().expect("checked type");
+            let mut items = Vec::with_capacity(array.len());
+            for item in array.iter::<Value>() {
+                items.push(to_item(ctx, item?, ancestors)?);
+            }
+            Ok(Item::Array(items))
+        }
+        Type::Object => {
+            if let Some(bytes) = bytes_of(ctx, &value)? {
+                return Ok(Item::Bytes(bytes));
+            }
+            let object = value.as_object().expect("checked type");
+            if is_instance_of(ctx, object, "Date")? {
+                let get_time: Function = object.get("getTime")?;
+                return Ok(Item::Date(get_time.call((This(object.clone()),))?));
+            }
+            if is_instance_of(ctx, object, "Map")? {
+                let array: Object = ctx.globals().get("Array")?;
+                let from: Function = array.get("from")?;
+                let entries: Array = from.call((value.clone(),))?;
+                let mut items = Vec::with_capacity(entries.len());
+                for entry in entries.iter::<Array>() {
+                    let entry = entry?;
+                    items.push((
+                        to_item(ctx, entry.get(0)?, ancestors)?,
+                        to_item(ctx, entry.get(1)?, ancestors)?,
+                    ));
+                }
+                return Ok(Item::Map(items));
+            }
+            let mut items = Vec::new();
+            for key in object.keys::<String>() {
+                let key = key?;
+                let value = object.get(&key)?;
+                items.push((Item::String(key), to_item(ctx, value, ancestors)?));
+            }
+            Ok(Item::Map(items))
+        }
+        _ => unreachable!("only called with arrays and objects"),
+    }
+}
+
+fn from_item<'js>(ctx: &Ctx<'js>, item: Item) -> rquickjs::Result<Value<'js>> {
+    match item {
+        Item::Null => Ok(Value::new_null(ctx.clone())),
+        Item::Undefined => Ok(Value::new_undefined(ctx.clone())),
+        Item::Bool(value) => Ok(Value::new_bool(ctx.clone(), value)),
+        Item::Int(value) if value.abs() <= MAX_SAFE_INTEGER => match i32::try_from(value) {
+            Ok(value) => Ok(Value::new_int(ctx.clone(), value)),
+            Err(_) => Ok(Value::new_float(ctx.clone(), value as f64)),
+        },
+        Item::Int(value) => {
+            let big_int: Function = ctx.globals().get("BigInt")?;
+            big_int.call((value.to_string(),))
+        }
+        Item::Float(value) => Ok(Value::new_float(ctx.clone(), value)),
+        Item::String(value) => rquickjs::String::from_str(ctx.clone(), &value).map(Into::into),
+        Item::Bytes(value) => {
+            TypedArray::<u8>::new(ctx.clone(), value).map(|array| array.into_value())
+        }
+        Item::Array(items) => {
+            let array = Array::new(ctx.clone())?;
+            for (index, item) in items.into_iter().enumerate() {
+                array.set(index, from_item(ctx, item)?)?;
+            }
+            Ok(array.into_value())
+        }
+        // Maps with only string keys become plain objects, others `Map`s
+        Item::Map(entries)
+            if entries
+                .iter()
+                .all(|(key, _)| matches!(key, Item::String(_))) =>
+        {
+            let object = Object::new(ctx.clone())?;
+            for (key, value) in entries {
+                let Item::String(key) = key else {
+                    unreachable!()
+                };
+                object.set(key, from_item(ctx, value)?)?;
+            }
+            Ok(object.into_value())
+        }
+        Item::Map(entries) => {
+            let constructor: Constructor = ctx.globals().get("Map")?;
+            let map: Object = constructor.construct(())?;
+            let set: Function = map.get("set")?;
+            for (key, value) in entries {
+                set.call::<_, ()>((
+                    This(map.clone()),
+                    from_item(ctx, key)?,
+                    from_item(ctx, value)?,
+                ))?;
+            }
+            Ok(map.into_value())
+        }
+        Item::Date(milliseconds) => {
+            let constructor: Constructor = ctx.globals().get("Date")?;
+            constructor.construct((milliseconds,))
+        }
+    }
+}
+
+/// The extension type of MessagePack timestamps
+const MSGPACK_TIMESTAMP: i8 = -1;
+
+fn write_msgpack(item: &Item, output: &mut Vec<u8>) -> Result<(), String> {
+    match item {
+        Item::Null | Item::Undefined => output.push(0xc0),
+        Item::Bool(value) => output.push(if *value { 0xc3 } else { 0xc2 }),
+        Item::Int(value) => match *value {
+            0..=0x7f => output.push(*value as u8),
+            -32..=-1 => output.push(*value as i8 as u8),
+            0x80..=0xff => write_prefixed(output, 0xcc, &[*value as u8]),
+            0x100..=0xffff => write_prefixed(output, 0xcd, &(*value as u16).to_be_bytes()),
+            0x10000..=0xffff_ffff => write_prefixed(output, 0xce, &(*value as u32).to_be_bytes()),
+            value if value > 0 && value <= u64::MAX as i128 => {
+                write_prefixed(output, 0xcf, &(value as u64).to_be_bytes())
+            }
+            -0x80..=-33 => write_prefixed(output, 0xd0, &[*value as i8 as u8]),
+            -0x8000..=-0x81 => write_prefixed(output, 0xd1, &(*value as i16).to_be_bytes()),
+            -0x8000_0000..=-0x8001 => write_prefixed(output, 0xd2, &(*value as i32).to_be_bytes()),
+            value if value < 0 && value >= i64::MIN as i128 => {
+                write_prefixed(output, 0xd3, &(value as i64).to_be_bytes())
+            }
+            value => return Err(format!("the integer {value} does not fit in 64 bits")),
+        },
+        Item::Float(value) => write_prefixed(output, 0xcb, &value.to_be_bytes()),
+        Item::String(value) => {
+            let length = value.len();
+            if length < 32 {
+                output.push(0xa0 | length as u8);
+            } else {
+                write_msgpack_length(output, [0xd9, 0xda, 0xdb], length)?;
+            }
+            output.extend_from_slice(value.as_bytes());
+        }
+        Item::Bytes(value) => {
+            write_msgpack_length(output, [0xc4, 0xc5, 0xc6], value.len())?;
+            output.extend_from_slice(value);
+        }
+        Item::Array(items) => {
+            if items.len() < 16 {
+                output.push(0x90 | items.len() as u8);
+            } else {
+                write_msgpack_length(output, [0, 0xdc, 0xdd], items.len())?;
+            }
+            for item in items {
+                write_msgpack(item, output)?;
+            }
+        }
+        Item::Map(entries) => {
+            if entries.len() < 16 {
+                output.push(0x80 | entries.len() as u8);
+            } else {
+                write_msgpack_length(output, [0, 0xde, 0xdf], entries.len())?;
+            }
+            for (key, value) in entries {
+                write_msgpack(key, output)?;
+                write_msgpack(value, output)?;
+            }
+        }
+        Item::Date(milliseconds) => {
+            if !milliseconds.is_finite() {
+                return Err("invalid dates cannot be encoded".to_string());
+            }
+            let seconds = (milliseconds / 1000.0).floor() as i64;
+            let nanoseconds = ((milliseconds - seconds as f64 * 1000.0) * 1_000_000.0) as u32;
+            if nanoseconds == 0 && (0..=u32::MAX as i64).contains(&seconds) {
+                output.extend_from_slice(&[0xd6, MSGPACK_TIMESTAMP as u8]);
+                output.extend_from_slice(&(seconds as u32).to_be_bytes());
+            } else if (0..1 << 34).contains(&seconds) {
+                output.extend_from_slice(&[0xd7, MSGPACK_TIMESTAMP as u8]);
+                let packed = ((nanoseconds as u64) << 34) | seconds as u64;
+                output.extend_from_slice(&packed.to_be_bytes());
+            } else {
+                output.extend_from_slice(&[0xc7, 12, MSGPACK_TIMESTAMP as u8]);
+                output.extend_from_slice(&nanoseconds.to_be_bytes());
+                output.extend_from_slice(&seconds.to_be_bytes());
+            }
+        }
+    }
+    Ok(())
+}
+
+fn write_prefixed(output: &mut Vec<u8>, prefix: u8, bytes: &[u8]) {
+    output.push(prefix);
+    output.extend_from_slice(bytes);
+}
+
+/// Writes the length of a string, binary, array or map with the prefix of its 8, 16 or 32-bit
+/// variant (arrays and maps have no 8-bit variant)
+fn write_msgpack_length(
+    output: &mut Vec<u8>,
+    [prefix8, prefix16, prefix32]: [u8; 3],
+    length: usize,
+) -> Result<(), String> {
+    if length <= 0xff && prefix8 != 0 {
+        write_prefixed(output, prefix8, &[length as u8]);
+    } else if length <= 0xffff {
+        write_prefixed(output, prefix16, &(length as u16).to_be_bytes());
+    } else if length <= u32::MAX as usize {
+        write_prefixed(output, prefix32, &(length as u32).to_be_bytes());
+    } else {
+        return Err(format!("the length {length} does not fit in 32 bits"));
+    }
+    Ok(())
+}
+
+fn write_cbor(item: &Item, output: &mut Vec<u8>) -> Result<(), String> {
+    match item {
+        Item::Null => output.push(0xf6),
+        Item::Undefined => output.push(0xf7),
+        Item::Bool(value) => output.push(if *value { 0xf5 } else { 0xf4 }),
+        Item::Int(value) if *value >= 0 && *value <= u64::MAX as i128 => {
+            write_cbor_head(output, 0, *value as u64)
+        }
+        Item::Int(value) if *value < 0 && -1 - *value <= u64::MAX as i128 => {
+            write_cbor_head(output, 1, (-1 - *value) as u64)
+        }
+        // Tags 2 and 3: bignums of larger magnitudes
+        Item::Int(value) => {
+            let (tag, magnitude) = if *value >= 0 {
+                (2, *value as u128)
+            } else {
+                (3, (-1 - *value) as u128)
+            };
+            let bytes = magnitude.to_be_bytes();
+            let digits = &bytes[magnitude.leading_zeros() as usize / 8..];
+            write_cbor_head(output, 6, tag);
+            write_cbor_head(output, 2, digits.len() as u64);
+            output.extend_from_slice(digits);
+        }
+        Item::Float(value) => write_prefixed(output, 0xfb, &value.to_be_bytes()),
+        Item::String(value) => {
+            write_cbor_head(output, 3, value.len() as u64);
+            output.extend_from_slice(value.as_bytes());
+        }
+        Item::Bytes(value) => {
+            write_cbor_head(output, 2, value.len() as u64);
+            output.extend_from_slice(value);
+        }
+        Item::Array(items) => {
+            write_cbor_head(output, 4, items.len() as u64);
+            for item in items {
+                write_cbor(item, output)?;
+            }
+        }
+        Item::Map(entries) => {
+            write_cbor_head(output, 5, entries.len() as u64);
+            for (key, value) in entries {
+                write_cbor(key, output)?;
+                write_cbor(value, output)?;
+            }
+        }
+        // Tag 1: seconds since the epoch, as an integer when possible
+        Item::Date(milliseconds) => {
+            if !milliseconds.is_finite() {
+                return Err("invalid dates cannot be encoded".to_string());
+            }
+            write_cbor_head(output, 6, 1);
+            if milliseconds % 1000.0 == 0.0 {
+                write_cbor(&Item::Int((milliseconds / 1000.0) as i128), output)?;
+            } else {
+                write_cbor(&Item::Float(milliseconds / 1000.0), output)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn write_cbor_head(output: &mut Vec<u8>, major: u8, argument: u64) {
+    let major = major << 5;
+    match argument {
+        0..=23 => output.push(major | argument as u8),
+        24..=0xff => write_prefixed(output, major | 24, &[argument as u8]),
+        0x100..=0xffff => write_prefixed(output, major | 25, &(argument as u16).to_be_bytes()),
+        0x10000..=0xffff_ffff => {
+            write_prefixed(output, major | 26, &(argument as u32).to_be_bytes())
+        }
+        _ => write_prefixed(output, major | 27, &argument.to_be_bytes()),
+    }
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl Reader<'_> {
+    fn take(&mut self, count: usize) -> Result<&[u8], String> {
+        let start = self.position;
+        let end = start
+            .checked_add(count)
+            .filter(|end| *end <= self.bytes.len())
+            .ok_or_else(|| format!("Unexpected end of input at offset {start}"))?;
+        self.position = end;
+        Ok(&self.bytes[start..end])
+    }
+
+    fn byte(&mut self) -> Result<u8, String> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn uint(&mut self, size: usize) -> Result<u64, String> {
+        Ok(self
+            .take(size)?
+            .iter()
+            .fold(0, |value, byte| (value << 8) | *byte as u64))
+    }
+
+    fn string(&mut self, length: usize) -> Result<String, String> {
+        let start = self.position;
+        String::from_utf8(self.take(length)?.to_vec())
+            .map_err(|_| format!("Invalid UTF-8 string at offset {start}"))
+    }
+
+    fn check_depth(&self, depth: usize) -> Result<(), String> {
+        if depth > MAX_DEPTH {
+            Err(format!("Nested too deeply at offset {}", self.position))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn msgpack(&mut self, depth: usize) -> Result<Item, String> {
+        self.check_depth(depth)?;
+        let offset = self.position;
+        let byte = self.byte()?;
+        let item = match byte {
+            0x00..=0x7f => Item::Int(byte as i128),
+            0x80..=0x8f => self.msgpack_map((byte & 0x0f) as usize, depth)?,
+            0x90..=0x9f => self.msgpack_array((byte & 0x0f) as usize, depth)?,
+            0xa0..=0xbf => Item::String(self.string((byte & 0x1f) as usize)?),
+            0xc0 => Item::Null,
+            0xc2 => Item::Bool(false),
+            0xc3 => Item::Bool(true),
+            0xc4..=0xc6 => {
+                let length = self.uint(1 << (byte - 0xc4))? as usize;
+                Item::Bytes(self.take(length)?.to_vec())
+            }
+            0xc7..=0xc9 => {
+                let length = self.uint(1 << (byte - 0xc7))? as usize;
+                self.msgpack_extension(length)?
+            }
+            0xca => Item::Float(f32::from_bits(self.uint(4)? as u32) as f64),
+            0xcb => Item::Float(f64::from_bits(self.uint(8)?)),
+            0xcc..=0xcf => Item::Int(self.uint(1 << (byte - 0xcc))? as i128),
+            0xd0 => Item::Int(self.uint(1)? as u8 as i8 as i128),
+            0xd1 => Item::Int(self.uint(2)? as u16 as i16 as i128),
+            0xd2 => Item::Int(self.uint(4)? as u32 as i32 as i128),
+            0xd3 => Item::Int(self.uint(8)? as i64 as i128),
+            0xd4..=0xd8 => self.msgpack_extension(1 << (byte - 0xd4))?,
+            0xd9..=0xdb => {
+                let length = self.uint(1 << (byte - 0xd9))? as usize;
+                Item::String(self.string(length)?)
+            }
+            0xdc | 0xdd => {
+                let length = self.uint(if byte == 0xdc { 2 } else { 4 })? as usize;
+                self.msgpack_array(length, depth)?
+            }
+            0xde | 0xdf => {
+                let length = self.uint(if byte == 0xde { 2 } else { 4 })? as usize;
+                self.msgpack_map(length, depth)?
+            }
+            0xe0..=0xff => Item::Int(byte as i8 as i128),
+            0xc1 => return Err(format!("Invalid byte 0xc1 at offset {offset}")),
+        };
+        Ok(item)
+    }
+
+    fn msgpack_array(&mut self, length: usize, depth: usize) -> Result<Item, String> {
+        // Not preallocating the untrusted length
+        let mut items = Vec::new();
+        for _ in 0..length {
+            items.push(self.msgpack(depth + 1)?);
+        }
+        Ok(Item::Array(items))
+    }
+
+    fn msgpack_map(&mut self, length: usize, depth: usize) -> Result<Item, String> {
+        let mut entries = Vec::new();
+        for _ in 0..length {
+            entries.push((self.msgpack(depth + 1)?, self.msgpack(depth + 1)?));
+        }
+        Ok(Item::Map(entries))
+    }
+
+    /// Reads an extension, decoding timestamps to dates and other types to `{ type, data }`
+    fn msgpack_extension(&mut self, length: usize) -> Result<Item, String> {
+        let offset = self.position;
+        let extension_type = self.byte()? as i8;
+        let data = self.take(length)?;
+        if extension_type != MSGPACK_TIMESTAMP {
+            return Ok(Item::Map(vec![
+                (
+                    Item::String("type".to_string()),
+                    Item::Int(extension_type as i128),
+                ),
+                (Item::String("data".to_string()), Item::Bytes(data.to_vec())),
+            ]));
+        }
+        let be = |bytes: &[u8]| {
+            bytes
+                .iter()
+                .fold(0u64, |value, byte| (value << 8) | *byte as u64)
+        };
+        let (seconds, nanoseconds) = match data.len() {
+            4 => (be(data) as i64, 0),
+            8 => {
+                let packed = be(data);
+                ((packed & ((1 << 34) - 1)) as i64, packed >> 34)
+            }
+            12 => (be(&data[4..]) as i64, be(&data[..4])),
+            length => {
+                return Err(format!(
+                    "Invalid timestamp of {length} bytes at offset {offset}"
+                ));
+            }
+        };
+        Ok(Item::Date(
+            seconds as f64 * 1000.0 + (nanoseconds / 1_000_000) as f64,
+        ))
+    }
+
+    fn cbor(&mut self, depth: usize) -> Result<Item, String> {
+        self.check_depth(depth)?;
+        let offset = self.position;
+        let initial = self.byte()?;
+        let major = initial >> 5;
+        let info = initial & 0x1f;
+
+        if major == 7 {
+            return match info {
+                20 => Ok(Item::Bool(false)),
+                21 => Ok(Item::Bool(true)),
+                22 => Ok(Item::Null),
+                23 => Ok(Item::Undefined),
+                25 => Ok(Item::Float(half_to_f64(self.uint(2)? as u16))),
+                26 => Ok(Item::Float(f32::from_bits(self.uint(4)? as u32) as f64)),
+                27 => Ok(Item::Float(f64::from_bits(self.uint(8)?))),
+                31 => Err(format!("Unexpected break at offset {offset}")),
+                _ => Err(format!("Unsupported simple value at offset {offset}")),
+            };
+        }
+
+        let argument = match info {
+            0..=23 => Some(info as u64),
+            24..=27 => Some(self.uint(1 << (info - 24))?),
+            31 if matches!(major, 2..=5) => None,
+            _ => {
+                return Err(format!(
+                    "Invalid initial byte 0x{initial:02x} at offset {offset}"
+                ));
+            }
+        };
+
+        match (major, argument) {
+            (0, Some(value)) => Ok(Item::Int(value as i128)),
+            (1, Some(value)) => Ok(Item::Int(-1 - value as i128)),
+            (2, Some(length)) => Ok(Item::Bytes(self.take(length as usize)?.to_vec())),
+            (3, Some(length)) => Ok(Item::String(self.string(length as usize)?)),
+            (2 | 3, None) => {
+                // Indefinite-length strings are made of definite-length chunks of the same type
+                let mut bytes = Vec::new();
+                while !self.cbor_break()? {
+                    let chunk_offset = self.position;
+                    match self.cbor(depth + 1)? {
+                        Item::Bytes(chunk) if major == 2 => bytes.extend(chunk),
+                        Item::String(chunk) if major == 3 => bytes.extend(chunk.into_bytes()),
+                        _ => return Err(format!("Invalid string chunk at offset {chunk_offset}")),
+                    }
+                }
+                if major == 2 {
+                    Ok(Item::Bytes(bytes))
+                } else {
+                    String::from_utf8(bytes)
+                        .map(Item::String)
+                        .map_err(|_| format!("Invalid UTF-8 string at offset {offset}"))
+                }
+            }
+            (4, length) => {
+                let mut items = Vec::new();
+                while self.cbor_continues(length, items.len())? {
+                    items.push(self.cbor(depth + 1)?);
+                }
+                Ok(Item::Array(items))
+            }
+            (5, length) => {
+                let mut entries = Vec::new();
+                while self.cbor_continues(length, entries.len())? {
+                    entries.push((self.cbor(depth + 1)?, self.cbor(depth + 1)?));
+                }
+                Ok(Item::Map(entries))
+            }
+            (6, Some(tag)) => {
+                let item = self.cbor(depth + 1)?;
+                match (tag, item) {
+                    (1, Item::Int(seconds)) => Ok(Item::Date(seconds as f64 * 1000.0)),
+                    (1, Item::Float(seconds)) => Ok(Item::Date(seconds * 1000.0)),
+                    (2 | 3, Item::Bytes(bytes)) => {
+                        let digits = bytes.iter().skip_while(|byte| **byte == 0);
+                        let value = (digits.clone().count() < 16)
+                            .then(|| digits.fold(0i128, |value, byte| (value << 8) | *byte as i128))
+                            .ok_or_else(|| format!("Bignum over 120 bits at offset {offset}"))?;
+                        Ok(Item::Int(if tag == 2 { value } else { -1 - value }))
+                    }
+                    // Other tags are ignored, keeping their content
+                    (_, item) => Ok(item),
+                }
+            }
+            _ => unreachable!("all major types are handled"),
+        }
+    }
+
+    /// Whether an array or map has more items, consuming the break ending indefinite lengths
+    fn cbor_continues(&mut self, length: Option<u64>, count: usize) -> Result<bool, String> {
+        match length {
+            Some(length) => Ok((count as u64) < length),
+            None => Ok(!self.cbor_break()?),
+        }
+    }
+
+    fn cbor_break(&mut self) -> Result<bool, String> {
+        if self.bytes.get(self.position) == Some(&0xff) {
+            self.position += 1;
+            Ok(true)
+        } else if self.position >= self.bytes.len() {
+            Err(format!(
+                "Unexpected end of input at offset {}",
+                self.position
+            ))
+        } else {
+            Ok(false)
+        }
+    }
+}
+
+/// Converts an IEEE 754 half-precision float
+fn half_to_f64(half: u16) -> f64 {
+    let sign = if half & 0x8000 != 0 { -1.0 } else { 1.0 };
+    let exponent = (half >> 10) & 0x1f;
+    let mantissa = (half & 0x3ff) as f64;
+    sign * match exponent {
+        0 => mantissa * 2f64.powi(-24),
+        0x1f if mantissa == 0.0 => f64::INFINITY,
+        0x1f => f64::NAN,
+        _ => (1.0 + mantissa / 1024.0) * 2f64.powi(exponent as i32 - 15),
+    }
+}
@@ -0,0 +1,39 @@
+use rquickjs::{Ctx, Exception, Value};
+
+// Stubs of the codecs, failing with an explanation when used
+#[rquickjs::module]
+pub mod msgpack_module {
+    use rquickjs::{Ctx, Value};
+
+    #[rquickjs::function]
+    pub fn encode<'js>(_value: Value<'js>, ctx: Ctx<'js>) -> rquickjs::Result<Value<'js>> {
+        super::unavailable(&ctx, "msgpack")
+    }
+
+    #[rquickjs::function]
+    pub fn decode<'js>(_bytes: Value<'js>, ctx: Ctx<'js>) -> rquickjs::Result<Value<'js>> {
+        super::unavailable(&ctx, "msgpack")
+    }
+}
+
+#[rquickjs::module]
+pub mod cbor_module {
+    use rquickjs::{Ctx, Value};
+
+    #[rquickjs::function]
+    pub fn encode<'js>(_value: Value<'js>, ctx: Ctx<'js>) -> rquickjs::Result<Value<'js>> {
+        super::unavailable(&ctx, "cbor")
+    }
+
+    #[rquickjs::function]
+    pub fn decode<'js>(_bytes: Value<'js>, ctx: Ctx<'js>) -> rquickjs::Result<Value<'js>> {
+        super::unavailable(&ctx, "cbor")
+    }
+}
+
+fn unavailable<'js>(ctx: &Ctx<'js>, module: &str) -> rquickjs::Result<Value<'js>> {
+    Err(Exception::throw_message(
+        ctx,
+        &format!("The `{module}` module requires the `codecs` feature of the wrapper crate"),
+    ))
+}
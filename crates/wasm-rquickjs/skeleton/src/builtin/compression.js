@@ -0,0 +1,45 @@
+import * as native from '__wasm_rquickjs_builtin/compression_native';
+
+const encoder = new TextEncoder();
+
+function toBytes(data) {
+    if (typeof data === 'string') {
+        return encoder.encode(data);
+    }
+    if (data instanceof Uint8Array) {
+        return data;
+    }
+    if (data instanceof ArrayBuffer) {
+        return new Uint8Array(data);
+    }
+    if (ArrayBuffer.isView(data)) {
+        return new Uint8Array(data.buffer, data.byteOffset, data.byteLength);
+    }
+    throw new TypeError('Expected a string, an ArrayBuffer or a typed array to compress or decompress');
+}
+
+/**
+ * Compresses the data (a string, encoded as UTF-8, an `ArrayBuffer` or a typed array) to a
+ * `Uint8Array` in the `gzip`, `deflate` (zlib), `deflate-raw` or `zstd` format. The optional
+ * `level` goes from 0 to 9 for the deflate based formats, and up to 22 for zstd, where negative
+ * levels trade compression for speed.
+ */
+export function compress(data, format, options = {}) {
+    const level = options.level === undefined ? undefined : Number(options.level);
+    if (level !== undefined && !Number.isInteger(level)) {
+        throw new RangeError(`Invalid compression level ${options.level}`);
+    }
+    return native.compress(toBytes(data), String(format), level);
+}
+
+/**
+ * Decompresses the data to a `Uint8Array`. Decompressing more than the optional `maxLength` bytes
+ * fails with a `RangeError`, guarding against compression bombs.
+ */
+export function decompress(data, format, options = {}) {
+    const maxLength = options.maxLength === undefined ? undefined : Number(options.maxLength);
+    if (maxLength !== undefined && !(maxLength >= 0)) {
+        throw new RangeError(`Invalid maximum length ${options.maxLength}`);
+    }
+    return native.decompress(toBytes(data), String(format), maxLength);
+}
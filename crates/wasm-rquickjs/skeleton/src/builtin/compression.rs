@@ -0,0 +1,158 @@
+use flate2::Compression;
+use flate2::read::{DeflateDecoder, MultiGzDecoder, ZlibDecoder};
+use flate2::write::{DeflateEncoder, GzEncoder, ZlibEncoder};
+use rquickjs::{Ctx, Exception};
+use std::io::{Read, Write};
+
+// Native implementation of the compression formats
+#[rquickjs::module(rename = "camelCase")]
+pub mod native_module {
+    use rquickjs::{Ctx, TypedArray};
+
+    #[rquickjs::function]
+    pub fn compress<'js>(
+        data: TypedArray<'js, u8>,
+        format: String,
+        level: Option<i32>,
+        ctx: Ctx<'js>,
+    ) -> rquickjs::Result<TypedArray<'js, u8>> {
+        let format = super::Format::parse(&ctx, &format)?;
+        let data = data.as_bytes().unwrap_or_default();
+        let compressed = super::compress(&ctx, format, data, level)?;
+        TypedArray::new(ctx, compressed)
+    }
+
+    #[rquickjs::function]
+    pub fn decompress<'js>(
+        data: TypedArray<'js, u8>,
+        format: String,
+        max_length: Option<f64>,
+        ctx: Ctx<'js>,
+    ) -> rquickjs::Result<TypedArray<'js, u8>> {
+        let format = super::Format::parse(&ctx, &format)?;
+        let data = data.as_bytes().unwrap_or_default();
+        let max_length = max_length.map_or(u64::MAX, |max_length| max_length as u64);
+        let decompressed = super::decompress(&ctx, format, data, max_length)?;
+        TypedArray::new(ctx, decompressed)
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Format {
+    Gzip,
+    /// The zlib format, named like the format of the `CompressionStream` API
+    Deflate,
+    DeflateRaw,
+    Zstd,
+}
+
+impl Format {
+    fn parse(ctx: &Ctx<'_>, name: &str) -> rquickjs::Result<Self> {
+        match name {
+            "gzip" => Ok(Format::Gzip),
+            "deflate" => Ok(Format::Deflate),
+            "deflate-raw" => Ok(Format::DeflateRaw),
+            "zstd" => Ok(Format::Zstd),
+            _ => Err(Exception::throw_type(
+                ctx,
+                &format!(
+                    "Unsupported compression format `{name}`, expected `gzip`, `deflate`, `deflate-raw` or `zstd`"
+                ),
+            )),
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Format::Gzip => "gzip",
+            Format::Deflate => "deflate",
+            Format::DeflateRaw => "deflate-raw",
+            Format::Zstd => "zstd",
+        }
+    }
+}
+
+fn compress(
+    ctx: &Ctx<'_>,
+    format: Format,
+    data: &[u8],
+    level: Option<i32>,
+) -> rquickjs::Result<Vec<u8>> {
+    let levels = match format {
+        Format::Zstd => zstd::compression_level_range(),
+        _ => 0..=9,
+    };
+    if let Some(level) = level.filter(|level| !levels.contains(level)) {
+        return Err(Exception::throw_range(
+            ctx,
+            &format!(
+                "Invalid {} compression level {level}, expected {} to {}",
+                format.name(),
+                levels.start(),
+                levels.end()
+            ),
+        ));
+    }
+
+    let flate_level = level.map_or(Compression::default(), |level| {
+        Compression::new(level as u32)
+    });
+    let result = match format {
+        Format::Gzip => {
+            let mut encoder = GzEncoder::new(Vec::new(), flate_level);
+            encoder.write_all(data).and_then(|_| encoder.finish())
+        }
+        Format::Deflate => {
+            let mut encoder = ZlibEncoder::new(Vec::new(), flate_level);
+            encoder.write_all(data).and_then(|_| encoder.finish())
+        }
+        Format::DeflateRaw => {
+            let mut encoder = DeflateEncoder::new(Vec::new(), flate_level);
+            encoder.write_all(data).and_then(|_| encoder.finish())
+        }
+        Format::Zstd => zstd::encode_all(data, level.unwrap_or(zstd::DEFAULT_COMPRESSION_LEVEL)),
+    };
+    result.map_err(|error| {
+        Exception::throw_message(
+            ctx,
+            &format!("Failed to compress with {}: {error}", format.name()),
+        )
+    })
+}
+
+/// Decompresses the data, failing if it is longer than `max_length` bytes
+fn decompress(
+    ctx: &Ctx<'_>,
+    format: Format,
+    data: &[u8],
+    max_length: u64,
+) -> rquickjs::Result<Vec<u8>> {
+    let decoder: Box<dyn Read + '_> = match format {
+        Format::Gzip => Box::new(MultiGzDecoder::new(data)),
+        Format::Deflate => Box::new(ZlibDecoder::new(data)),
+        Format::DeflateRaw => Box::new(DeflateDecoder::new(data)),
+        Format::Zstd => Box::new(zstd::Decoder::new(data).map_err(|error| {
+            Exception::throw_message(ctx, &format!("Invalid zstd data: {error}"))
+        })?),
+    };
+
+    let mut output = Vec::new();
+    decoder
+        .take(max_length.saturating_add(1))
+        .read_to_end(&mut output)
+        .map_err(|error| {
+            Exception::throw_message(ctx, &format!("Invalid {} data: {error}", format.name()))
+        })?;
+    if output.len() as u64 > max_length {
+        return Err(Exception::throw_range(
+            ctx,
+            &format!(
+                "The decompressed data is longer than the maximum length of {max_length} bytes"
+            ),
+        ));
+    }
+    Ok(output)
+}
+
+// JS functions for the compression API
+pub const COMPRESSION_JS: &str = include_str!("compression.js");
@@ -0,0 +1,23 @@
+// Stubs of the native compression functions, failing with an explanation when used
+#[rquickjs::module(rename = "camelCase")]
+pub mod native_module {
+    use rquickjs::{Ctx, Exception, Value};
+
+    #[rquickjs::function]
+    pub fn compress<'js>(ctx: Ctx<'js>) -> rquickjs::Result<Value<'js>> {
+        Err(Exception::throw_message(
+            &ctx,
+            "The `compression` module requires the `compression` feature of the wrapper crate",
+        ))
+    }
+
+    #[rquickjs::function]
+    pub fn decompress<'js>(ctx: Ctx<'js>) -> rquickjs::Result<Value<'js>> {
+        Err(Exception::throw_message(
+            &ctx,
+            "The `compression` module requires the `compression` feature of the wrapper crate",
+        ))
+    }
+}
+
+pub const COMPRESSION_JS: &str = include_str!("compression.js");
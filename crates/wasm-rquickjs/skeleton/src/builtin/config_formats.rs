@@ -0,0 +1,213 @@
+use super::data_model::{Node, from_node, to_node};
+use rquickjs::{Ctx, Exception, Value};
+use serde::Deserialize;
+
+// Native TOML and YAML parsers and serializers
+#[rquickjs::module(rename = "camelCase")]
+pub mod native_module {
+    use rquickjs::{Ctx, Value};
+
+    #[rquickjs::function]
+    pub fn parse_toml<'js>(text: String, ctx: Ctx<'js>) -> rquickjs::Result<Value<'js>> {
+        let table = text
+            .parse::<toml::Table>()
+            .map_err(|error| super::invalid_toml(&ctx, &text, error))?;
+        super::from_node(&ctx, super::toml_to_node(toml::Value::Table(table)))
+    }
+
+    #[rquickjs::function]
+    pub fn stringify_toml<'js>(value: Value<'js>, ctx: Ctx<'js>) -> rquickjs::Result<String> {
+        super::stringify_toml(&ctx, value)
+    }
+
+    #[rquickjs::function]
+    pub fn parse_yaml<'js>(text: String, ctx: Ctx<'js>) -> rquickjs::Result<Value<'js>> {
+        let value = super::parse_yaml(&ctx, serde_norway::Deserializer::from_str(&text))?;
+        super::from_node(&ctx, super::yaml_to_node(value))
+    }
+
+    #[rquickjs::function]
+    pub fn parse_yaml_documents<'js>(text: String, ctx: Ctx<'js>) -> rquickjs::Result<Value<'js>> {
+        let array = rquickjs::Array::new(ctx.clone())?;
+        for (index, document) in serde_norway::Deserializer::from_str(&text).enumerate() {
+            let value = super::parse_yaml(&ctx, document)?;
+            array.set(index, super::from_node(&ctx, super::yaml_to_node(value))?)?;
+        }
+        Ok(array.into_value())
+    }
+
+    #[rquickjs::function]
+    pub fn stringify_yaml<'js>(value: Value<'js>, ctx: Ctx<'js>) -> rquickjs::Result<String> {
+        super::stringify_yaml(&ctx, value)
+    }
+}
+
+fn invalid_toml(ctx: &Ctx<'_>, text: &str, error: toml::de::Error) -> rquickjs::Error {
+    let message = error.message().lines().collect::<Vec<_>>().join(", ");
+    let message = if message.is_empty() {
+        "invalid value"
+    } else {
+        &message
+    };
+    match error.span() {
+        Some(span) => {
+            let before = &text[..span.start];
+            let line = before.matches('\n').count() + 1;
+            let column = before.chars().rev().take_while(|c| *c != '\n').count() + 1;
+            Exception::throw_syntax(
+                ctx,
+                &format!("Invalid TOML at line {line}, column {column}: {message}"),
+            )
+        }
+        None => Exception::throw_syntax(ctx, &format!("Invalid TOML: {message}")),
+    }
+}
+
+fn parse_yaml<'de>(
+    ctx: &Ctx<'_>,
+    document: serde_norway::Deserializer<'de>,
+) -> rquickjs::Result<serde_norway::Value> {
+    let mut value = serde_norway::Value::deserialize(document)
+        .map_err(|error| Exception::throw_syntax(ctx, &format!("Invalid YAML: {error}")))?;
+    value
+        .apply_merge()
+        .map_err(|error| Exception::throw_syntax(ctx, &format!("Invalid YAML: {error}")))?;
+    Ok(value)
+}
+
+fn toml_to_node(value: toml::Value) -> Node {
+    match value {
+        toml::Value::String(value) => Node::String(value),
+        toml::Value::Integer(value) => Node::Int(value as i128),
+        toml::Value::Float(value) => Node::Float(value),
+        toml::Value::Boolean(value) => Node::Bool(value),
+        // Local dates and times do not identify an instant, so only offset date-times become `Date`s
+        toml::Value::Datetime(value) if value.date.is_some() && value.offset.is_some() => {
+            Node::Date(value.to_string())
+        }
+        toml::Value::Datetime(value) => Node::String(value.to_string()),
+        toml::Value::Array(values) => Node::Array(values.into_iter().map(toml_to_node).collect()),
+        toml::Value::Table(table) => Node::Map(
+            table
+                .into_iter()
+                .map(|(key, value)| (Node::String(key), toml_to_node(value)))
+                .collect(),
+        ),
+    }
+}
+
+fn yaml_to_node(value: serde_norway::Value) -> Node {
+    match value {
+        serde_norway::Value::Null => Node::Null,
+        serde_norway::Value::Bool(value) => Node::Bool(value),
+        serde_norway::Value::Number(number) => {
+            if let Some(value) = number.as_i64() {
+                Node::Int(value as i128)
+            } else if let Some(value) = number.as_u64() {
+                Node::Int(value as i128)
+            } else {
+                Node::Float(number.as_f64().unwrap_or(f64::NAN))
+            }
+        }
+        serde_norway::Value::String(value) => Node::String(value),
+        serde_norway::Value::Sequence(values) => {
+            Node::Array(values.into_iter().map(yaml_to_node).collect())
+        }
+        serde_norway::Value::Mapping(mapping) => Node::Map(
+            mapping
+                .into_iter()
+                .map(|(key, value)| (yaml_to_node(key), yaml_to_node(value)))
+                .collect(),
+        ),
+        // Tags are application specific, so only their values are kept
+        serde_norway::Value::Tagged(tagged) => yaml_to_node(tagged.value),
+    }
+}
+
+fn stringify_toml<'js>(ctx: &Ctx<'js>, value: Value<'js>) -> rquickjs::Result<String> {
+    let node = to_node(ctx, value, &mut Vec::new())?;
+    if !matches!(node, Node::Map(_)) {
+        return Err(Exception::throw_type(
+            ctx,
+            "Cannot stringify as TOML: the document must be an object",
+        ));
+    }
+    node_to_toml(node)
+        .and_then(|value| toml::to_string(&value).map_err(|error| error.to_string()))
+        .map_err(|error| Exception::throw_type(ctx, &format!("Cannot stringify as TOML: {error}")))
+}
+
+fn node_to_toml(node: Node) -> Result<toml::Value, String> {
+    match node {
+        Node::Undefined | Node::Null => Err("TOML has no null value".to_string()),
+        Node::Bool(value) => Ok(toml::Value::Boolean(value)),
+        Node::Int(value) => i64::try_from(value)
+            .map(toml::Value::Integer)
+            .map_err(|_| format!("the integer {value} does not fit in 64 bits")),
+        Node::Float(value) => Ok(toml::Value::Float(value)),
+        Node::String(value) => Ok(toml::Value::String(value)),
+        Node::Date(value) => value
+            .parse()
+            .map(toml::Value::Datetime)
+            .map_err(|error| format!("invalid date {value}: {error}")),
+        Node::Array(nodes) => nodes
+            .into_iter()
+            .map(node_to_toml)
+            .collect::<Result<_, _>>()
+            .map(toml::Value::Array),
+        Node::Map(entries) => {
+            let mut table = toml::Table::new();
+            for (key, value) in entries {
+                let Node::String(key) = key else {
+                    return Err("TOML keys must be strings".to_string());
+                };
+                if !matches!(value, Node::Undefined) {
+                    table.insert(key, node_to_toml(value)?);
+                }
+            }
+            Ok(toml::Value::Table(table))
+        }
+    }
+}
+
+fn stringify_yaml<'js>(ctx: &Ctx<'js>, value: Value<'js>) -> rquickjs::Result<String> {
+    node_to_yaml(to_node(ctx, value, &mut Vec::new())?)
+        .and_then(|value| serde_norway::to_string(&value).map_err(|error| error.to_string()))
+        .map_err(|error| Exception::throw_type(ctx, &format!("Cannot stringify as YAML: {error}")))
+}
+
+fn node_to_yaml(node: Node) -> Result<serde_norway::Value, String> {
+    match node {
+        Node::Undefined | Node::Null => Ok(serde_norway::Value::Null),
+        Node::Bool(value) => Ok(serde_norway::Value::Bool(value)),
+        Node::Int(value) => {
+            if let Ok(value) = i64::try_from(value) {
+                Ok(serde_norway::Value::Number(value.into()))
+            } else {
+                u64::try_from(value)
+                    .map(|value| serde_norway::Value::Number(value.into()))
+                    .map_err(|_| format!("the integer {value} does not fit in 64 bits"))
+            }
+        }
+        Node::Float(value) => Ok(serde_norway::Value::Number(value.into())),
+        Node::String(value) | Node::Date(value) => Ok(serde_norway::Value::String(value)),
+        Node::Array(nodes) => nodes
+            .into_iter()
+            .map(node_to_yaml)
+            .collect::<Result<_, _>>()
+            .map(serde_norway::Value::Sequence),
+        Node::Map(entries) => {
+            let mut mapping = serde_norway::Mapping::new();
+            for (key, value) in entries {
+                if !matches!(value, Node::Undefined) {
+                    mapping.insert(node_to_yaml(key)?, node_to_yaml(value)?);
+                }
+            }
+            Ok(serde_norway::Value::Mapping(mapping))
+        }
+    }
+}
+
+// JS modules exposing the TOML and YAML functions
+pub const TOML_JS: &str = include_str!("toml.js");
+pub const YAML_JS: &str = include_str!("yaml.js");
@@ -0,0 +1,40 @@
+// Stubs of the native TOML and YAML parsers and serializers, failing with an explanation when used
+#[rquickjs::module(rename = "camelCase")]
+pub mod native_module {
+    use rquickjs::{Ctx, Value};
+
+    #[rquickjs::function]
+    pub fn parse_toml<'js>(ctx: Ctx<'js>) -> rquickjs::Result<Value<'js>> {
+        Err(super::disabled(&ctx, "toml"))
+    }
+
+    #[rquickjs::function]
+    pub fn stringify_toml(ctx: Ctx<'_>) -> rquickjs::Result<String> {
+        Err(super::disabled(&ctx, "toml"))
+    }
+
+    #[rquickjs::function]
+    pub fn parse_yaml<'js>(ctx: Ctx<'js>) -> rquickjs::Result<Value<'js>> {
+        Err(super::disabled(&ctx, "yaml"))
+    }
+
+    #[rquickjs::function]
+    pub fn parse_yaml_documents<'js>(ctx: Ctx<'js>) -> rquickjs::Result<Value<'js>> {
+        Err(super::disabled(&ctx, "yaml"))
+    }
+
+    #[rquickjs::function]
+    pub fn stringify_yaml(ctx: Ctx<'_>) -> rquickjs::Result<String> {
+        Err(super::disabled(&ctx, "yaml"))
+    }
+}
+
+fn disabled(ctx: &rquickjs::Ctx<'_>, module: &str) -> rquickjs::Error {
+    rquickjs::Exception::throw_message(
+        ctx,
+        &format!("The `{module}` module requires the `config` feature of the wrapper crate"),
+    )
+}
+
+pub const TOML_JS: &str = include_str!("toml.js");
+pub const YAML_JS: &str = include_str!("yaml.js");
@@ -0,0 +1,227 @@
+import { CsvReader, CsvWriter } from '__wasm_rquickjs_builtin/csv_native';
+import * as streams from '__wasm_rquickjs_builtin/streams';
+
+const DEFAULT_CHUNK_SIZE = 65536;
+
+function toChunk(chunk) {
+    if (typeof chunk === 'string' || chunk instanceof Uint8Array) {
+        return chunk;
+    }
+    if (chunk instanceof ArrayBuffer) {
+        return new Uint8Array(chunk);
+    }
+    if (ArrayBuffer.isView(chunk)) {
+        return new Uint8Array(chunk.buffer, chunk.byteOffset, chunk.byteLength);
+    }
+    throw new TypeError('Expected a string, an ArrayBuffer or a typed array chunk');
+}
+
+// A single string or byte array is one chunk, anything else is iterated asynchronously
+function chunksOf(source) {
+    if (typeof source === 'string' || source instanceof ArrayBuffer || ArrayBuffer.isView(source)) {
+        return [source];
+    }
+    return source;
+}
+
+/**
+ * Incremental CSV parser, returning the rows completed by each chunk. Rows are arrays of strings,
+ * or objects keyed by the column names with the `headers` option: `true` to take them from the
+ * first row, or an array of names.
+ */
+export class CsvParser {
+    constructor(options = {}) {
+        this._reader = new CsvReader(
+            options.delimiter ?? ',',
+            options.quote ?? '"',
+            options.comment ?? undefined,
+            !!options.flexible,
+        );
+        if (Array.isArray(options.headers)) {
+            this._headers = options.headers.map(String);
+        } else {
+            this._headers = options.headers ? null : undefined;
+        }
+    }
+
+    /** Parses a chunk of the text, given as a string or as UTF-8 bytes */
+    write(chunk) {
+        return this._rows(this._reader.write(toChunk(chunk)));
+    }
+
+    /** Signals the end of the text, returning its last row if it has no final line break */
+    end() {
+        return this._rows(this._reader.end());
+    }
+
+    _rows(records) {
+        if (this._headers === undefined) {
+            return records;
+        }
+        if (this._headers === null && records.length > 0) {
+            this._headers = records.shift();
+        }
+        return records.map((record) => {
+            const row = {};
+            this._headers.forEach((header, index) => {
+                if (index < record.length) {
+                    Object.defineProperty(row, header, {
+                        value: record[index],
+                        writable: true,
+                        enumerable: true,
+                        configurable: true,
+                    });
+                }
+            });
+            return row;
+        });
+    }
+}
+
+/** Parses a whole CSV text, given as a string or as UTF-8 bytes */
+export function parse(text, options = {}) {
+    const parser = new CsvParser(options);
+    const rows = parser.write(text);
+    return rows.concat(parser.end());
+}
+
+/**
+ * Parses a CSV text given as a string, bytes, a ReadableStream or an (async) iterable of chunks,
+ * yielding its rows one by one as soon as they are complete
+ */
+export async function* parseRows(source, options = {}) {
+    const parser = new CsvParser(options);
+    for await (const chunk of chunksOf(source)) {
+        yield* parser.write(chunk);
+    }
+    yield* parser.end();
+}
+
+/** Parses a CSV text like parseRows, as a ReadableStream of rows */
+export function parseStream(source, options = {}) {
+    const rows = parseRows(source, options);
+    return new streams.ReadableStream({
+        async pull(controller) {
+            const { value, done } = await rows.next();
+            if (done) {
+                controller.close();
+            } else {
+                controller.enqueue(value);
+            }
+        },
+        async cancel() {
+            await rows.return();
+        },
+    });
+}
+
+function field(value) {
+    if (value === undefined || value === null) {
+        return '';
+    }
+    if (value instanceof Date) {
+        return value.toISOString();
+    }
+    return String(value);
+}
+
+// Converts the rows to arrays of strings, objects by the columns given or the keys of the first
+// one. When the first row is an object, the columns are written first as the header row.
+class RowWriter {
+    constructor(options) {
+        this._writer = new CsvWriter(
+            options.delimiter ?? ',',
+            options.quote ?? '"',
+            !!options.quoteAll,
+            options.lineTerminator === '\r\n',
+        );
+        this._columns = options.columns?.map(String);
+        this._headers = options.headers ?? true;
+        this._first = true;
+    }
+
+    write(rows) {
+        const records = [];
+        for (const row of rows) {
+            if (Array.isArray(row)) {
+                records.push(row.map(field));
+            } else {
+                this._columns ??= Object.keys(row);
+                if (this._first && this._headers) {
+                    records.push(this._columns);
+                }
+                records.push(this._columns.map((column) => field(row[column])));
+            }
+            this._first = false;
+        }
+        return this._writer.write(records);
+    }
+}
+
+/**
+ * Serializes rows, given as arrays of values or as objects, to CSV text. The values are converted
+ * to strings, with `null` and `undefined` as empty fields and dates in the ISO 8601 format.
+ */
+export function stringify(rows, options = {}) {
+    return new RowWriter(options).write(rows);
+}
+
+/**
+ * Serializes rows like stringify, yielding the text in chunks of about `options.chunkSize`
+ * characters (64 KiB by default) instead of building a single string
+ */
+export function* stringifyChunks(rows, options = {}) {
+    const chunkSize = options.chunkSize ?? DEFAULT_CHUNK_SIZE;
+    const writer = new RowWriter(options);
+    let chunk = '';
+    for (const row of rows) {
+        chunk += writer.write([row]);
+        if (chunk.length >= chunkSize) {
+            yield chunk;
+            chunk = '';
+        }
+    }
+    if (chunk.length > 0) {
+        yield chunk;
+    }
+}
+
+/**
+ * Serializes rows given as an (async) iterable or a ReadableStream, as a ReadableStream of chunks
+ * of about `options.chunkSize` characters
+ */
+export function stringifyStream(rows, options = {}) {
+    const chunkSize = options.chunkSize ?? DEFAULT_CHUNK_SIZE;
+    const writer = new RowWriter(options);
+    const iterator = (rows[Symbol.asyncIterator] ?? rows[Symbol.iterator]).call(rows);
+    return new streams.ReadableStream({
+        async pull(controller) {
+            let chunk = '';
+            while (chunk.length < chunkSize) {
+                const { value, done } = await iterator.next();
+                if (done) {
+                    if (chunk.length > 0) {
+                        controller.enqueue(chunk);
+                    }
+                    controller.close();
+                    return;
+                }
+                chunk += writer.write([value]);
+            }
+            controller.enqueue(chunk);
+        },
+        async cancel() {
+            await iterator.return?.();
+        },
+    });
+}
+
+export default {
+    CsvParser,
+    parse,
+    parseRows,
+    parseStream,
+    stringify,
+    stringifyChunks,
+    stringifyStream,
+};
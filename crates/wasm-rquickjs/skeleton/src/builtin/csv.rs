@@ -0,0 +1,279 @@
+use csv_core::ReadRecordResult;
+use rquickjs::{Ctx, Exception};
+
+// Native implementation of the incremental CSV reader and writer
+#[rquickjs::module(rename = "camelCase")]
+pub mod native_module {
+    use rquickjs::class::Trace;
+    use rquickjs::{Ctx, Exception, JsLifetime, TypedArray, Value};
+
+    #[derive(JsLifetime, Trace)]
+    #[rquickjs::class(rename = "CsvReader")]
+    pub struct JsCsvReader {
+        #[qjs(skip_trace)]
+        reader: super::Reader,
+    }
+
+    #[rquickjs::methods(rename_all = "camelCase")]
+    impl JsCsvReader {
+        #[qjs(constructor)]
+        pub fn new(
+            delimiter: String,
+            quote: String,
+            comment: Option<String>,
+            flexible: bool,
+            ctx: Ctx<'_>,
+        ) -> rquickjs::Result<Self> {
+            let comment = match comment {
+                Some(comment) => Some(super::single_byte(&ctx, "comment", &comment)?),
+                None => None,
+            };
+            let core = csv_core::ReaderBuilder::new()
+                .delimiter(super::single_byte(&ctx, "delimiter", &delimiter)?)
+                .quote(super::single_byte(&ctx, "quote", &quote)?)
+                .comment(comment)
+                .build();
+            Ok(Self {
+                reader: super::Reader::new(core, flexible),
+            })
+        }
+
+        pub fn write(
+            &mut self,
+            chunk: Value<'_>,
+            ctx: Ctx<'_>,
+        ) -> rquickjs::Result<Vec<Vec<String>>> {
+            let mut rows = Vec::new();
+            let result = if let Some(string) = chunk.as_string() {
+                let string = string.to_string()?;
+                self.reader.feed(string.as_bytes(), &mut rows)
+            } else if let Ok(bytes) = TypedArray::<u8>::from_value(chunk) {
+                let bytes = bytes
+                    .as_bytes()
+                    .ok_or_else(|| Exception::throw_type(&ctx, "The written chunk is detached"))?;
+                self.reader.feed(bytes, &mut rows)
+            } else {
+                return Err(Exception::throw_type(
+                    &ctx,
+                    "Expected a string or an Uint8Array chunk",
+                ));
+            };
+            result.map_err(|error| Exception::throw_syntax(&ctx, &error))?;
+            Ok(rows)
+        }
+
+        pub fn end(&mut self, ctx: Ctx<'_>) -> rquickjs::Result<Vec<Vec<String>>> {
+            let mut rows = Vec::new();
+            self.reader
+                .finish(&mut rows)
+                .map_err(|error| Exception::throw_syntax(&ctx, &error))?;
+            Ok(rows)
+        }
+    }
+
+    #[derive(JsLifetime, Trace)]
+    #[rquickjs::class(rename = "CsvWriter")]
+    pub struct JsCsvWriter {
+        #[qjs(skip_trace)]
+        delimiter: u8,
+        #[qjs(skip_trace)]
+        quote: u8,
+        #[qjs(skip_trace)]
+        quote_all: bool,
+        #[qjs(skip_trace)]
+        crlf: bool,
+    }
+
+    #[rquickjs::methods(rename_all = "camelCase")]
+    impl JsCsvWriter {
+        #[qjs(constructor)]
+        pub fn new(
+            delimiter: String,
+            quote: String,
+            quote_all: bool,
+            crlf: bool,
+            ctx: Ctx<'_>,
+        ) -> rquickjs::Result<Self> {
+            Ok(Self {
+                delimiter: super::single_byte(&ctx, "delimiter", &delimiter)?,
+                quote: super::single_byte(&ctx, "quote", &quote)?,
+                quote_all,
+                crlf,
+            })
+        }
+
+        /// Serializes the given rows, each terminated by a line break
+        pub fn write(&self, rows: Vec<Vec<String>>, ctx: Ctx<'_>) -> rquickjs::Result<String> {
+            let mut writer = csv::WriterBuilder::new()
+                .delimiter(self.delimiter)
+                .quote(self.quote)
+                .quote_style(if self.quote_all {
+                    csv::QuoteStyle::Always
+                } else {
+                    csv::QuoteStyle::Necessary
+                })
+                .terminator(if self.crlf {
+                    csv::Terminator::CRLF
+                } else {
+                    csv::Terminator::Any(b'\n')
+                })
+                .flexible(true)
+                .from_writer(Vec::new());
+            for row in rows {
+                writer
+                    .write_record(&row)
+                    .map_err(|error| Exception::throw_message(&ctx, &error.to_string()))?;
+            }
+            let output = writer
+                .into_inner()
+                .map_err(|error| Exception::throw_message(&ctx, &error.to_string()))?;
+            String::from_utf8(output)
+                .map_err(|error| Exception::throw_message(&ctx, &error.to_string()))
+        }
+    }
+}
+
+/// Gets the byte of a delimiter, quote or comment option, which must be a single ASCII character
+fn single_byte(ctx: &Ctx<'_>, option: &str, value: &str) -> rquickjs::Result<u8> {
+    match value.as_bytes() {
+        [byte] if byte.is_ascii() => Ok(*byte),
+        _ => Err(Exception::throw_type(
+            ctx,
+            &format!("The `{option}` option must be a single ASCII character"),
+        )),
+    }
+}
+
+/// A push-based CSV reader, accepting the input in chunks of any size. The fields of a row split
+/// between chunks are buffered until the row is complete.
+struct Reader {
+    core: csv_core::Reader,
+    fields: Vec<u8>,
+    ends: Vec<usize>,
+    fields_len: usize,
+    ends_len: usize,
+    /// The number of rows read so far, for the errors
+    rows: usize,
+    /// The number of fields of the first row, which all the rows must have unless flexible
+    columns: Option<usize>,
+    flexible: bool,
+    error: Option<String>,
+}
+
+impl Reader {
+    fn new(core: csv_core::Reader, flexible: bool) -> Self {
+        Self {
+            core,
+            fields: vec![0; 1024],
+            ends: vec![0; 16],
+            fields_len: 0,
+            ends_len: 0,
+            rows: 0,
+            columns: None,
+            flexible,
+            error: None,
+        }
+    }
+
+    fn feed(&mut self, mut input: &[u8], rows: &mut Vec<Vec<String>>) -> Result<(), String> {
+        self.check_failed()?;
+        // An empty input marks the end of the CSV text for the core reader
+        while !input.is_empty() {
+            let (result, read) = self.read(input);
+            input = &input[read..];
+            if result == ReadRecordResult::Record {
+                let row = self.row()?;
+                rows.push(row);
+            }
+        }
+        Ok(())
+    }
+
+    fn finish(&mut self, rows: &mut Vec<Vec<String>>) -> Result<(), String> {
+        self.check_failed()?;
+        loop {
+            match self.read(&[]).0 {
+                ReadRecordResult::Record => {
+                    let row = self.row()?;
+                    rows.push(row);
+                }
+                ReadRecordResult::End => return Ok(()),
+                _ => {}
+            }
+        }
+    }
+
+    fn check_failed(&self) -> Result<(), String> {
+        match &self.error {
+            Some(error) => Err(error.clone()),
+            None => Ok(()),
+        }
+    }
+
+    /// Reads the input until the end of a row or of the input, growing the buffers as needed.
+    /// Returns the result of the core reader and the number of bytes read.
+    fn read(&mut self, input: &[u8]) -> (ReadRecordResult, usize) {
+        let mut read = 0;
+        loop {
+            let (result, input_read, fields_written, ends_written) = self.core.read_record(
+                &input[read..],
+                &mut self.fields[self.fields_len..],
+                &mut self.ends[self.ends_len..],
+            );
+            read += input_read;
+            self.fields_len += fields_written;
+            self.ends_len += ends_written;
+            match result {
+                ReadRecordResult::OutputFull => {
+                    self.fields.resize(self.fields.len() * 2, 0);
+                }
+                ReadRecordResult::OutputEndsFull => {
+                    self.ends.resize(self.ends.len() * 2, 0);
+                }
+                result => return (result, read),
+            }
+            // Continuing with an empty input would end the CSV text
+            if read == input.len() && !input.is_empty() {
+                return (ReadRecordResult::InputEmpty, read);
+            }
+        }
+    }
+
+    /// Takes the fields of the row just read. Invalid UTF-8 sequences are replaced by U+FFFD.
+    fn row(&mut self) -> Result<Vec<String>, String> {
+        let mut row = Vec::with_capacity(self.ends_len);
+        let mut start = 0;
+        for &end in &self.ends[..self.ends_len] {
+            row.push(String::from_utf8_lossy(&self.fields[start..end]).into_owned());
+            start = end;
+        }
+        self.fields_len = 0;
+        self.ends_len = 0;
+        self.rows += 1;
+
+        match self.columns {
+            Some(columns) if !self.flexible && columns != row.len() => {
+                let fields = |count: usize| match count {
+                    1 => "1 field".to_string(),
+                    count => format!("{count} fields"),
+                };
+                let error = format!(
+                    "Invalid CSV in row {}: found {}, but the previous rows have {}",
+                    self.rows,
+                    fields(row.len()),
+                    fields(columns)
+                );
+                self.error = Some(error.clone());
+                Err(error)
+            }
+            Some(_) => Ok(row),
+            None => {
+                self.columns = Some(row.len());
+                Ok(row)
+            }
+        }
+    }
+}
+
+// JS functions for the CSV implementation
+pub const CSV_JS: &str = include_str!("csv.js");
@@ -0,0 +1,207 @@
+use rquickjs::convert::Coerced;
+use rquickjs::function::{Constructor, This};
+use rquickjs::{Array, Ctx, Exception, Function, Object, Type, Value};
+
+/// Containers nested deeper than this are rejected
+const MAX_DEPTH: usize = 256;
+
+/// Integers of this magnitude or less are parsed as numbers, larger ones as `BigInt`s
+const MAX_SAFE_INTEGER: i128 = (1 << 53) - 1;
+
+/// A value of the data model shared by the TOML and YAML formats and the templates, between JS
+/// and the parsed documents
+pub(super) enum Node {
+    /// A JS `undefined`, left out of objects
+    Undefined,
+    Null,
+    Bool(bool),
+    Int(i128),
+    Float(f64),
+    String(String),
+    /// An RFC 3339 date and time with an offset
+    Date(String),
+    Array(Vec<Node>),
+    Map(Vec<(Node, Node)>),
+}
+
+/// Converts a parsed value to JS
+#[cfg_attr(not(feature = "config"), allow(dead_code))]
+pub(super) fn from_node<'js>(ctx: &Ctx<'js>, node: Node) -> rquickjs::Result<Value<'js>> {
+    match node {
+        Node::Undefined => Ok(Value::new_undefined(ctx.clone())),
+        Node::Null => Ok(Value::new_null(ctx.clone())),
+        Node::Bool(value) => Ok(Value::new_bool(ctx.clone(), value)),
+        Node::Int(value) if value.abs() <= MAX_SAFE_INTEGER => match i32::try_from(value) {
+            Ok(value) => Ok(Value::new_int(ctx.clone(), value)),
+            Err(_) => Ok(Value::new_float(ctx.clone(), value as f64)),
+        },
+        Node::Int(value) => {
+            let big_int: Function = ctx.globals().get("BigInt")?;
+            big_int.call((value.to_string(),))
+        }
+        Node::Float(value) => Ok(Value::new_float(ctx.clone(), value)),
+        Node::String(value) => rquickjs::String::from_str(ctx.clone(), &value).map(Into::into),
+        Node::Date(value) => {
+            let constructor: Constructor = ctx.globals().get("Date")?;
+            constructor.construct((value,))
+        }
+        Node::Array(nodes) => {
+            let array = Array::new(ctx.clone())?;
+            for (index, node) in nodes.into_iter().enumerate() {
+                array.set(index, from_node(ctx, node)?)?;
+            }
+            Ok(array.into_value())
+        }
+        // Mappings with only string keys become plain objects, others `Map`s
+        Node::Map(entries)
+            if entries
+                .iter()
+                .all(|(key, _)| matches!(key, Node::String(_))) =>
+        {
+            let object = Object::new(ctx.clone())?;
+            for (key, value) in entries {
+                let Node::String(key) = key else {
+                    unreachable!()
+                };
+                object.set(key, from_node(ctx, value)?)?;
+            }
+            Ok(object.into_value())
+        }
+        Node::Map(entries) => {
+            let constructor: Constructor = ctx.globals().get("Map")?;
+            let map: Object = constructor.construct(())?;
+            let set: Function = map.get("set")?;
+            for (key, value) in entries {
+                set.call::<_, ()>((
+                    This(map.clone()),
+                    from_node(ctx, key)?,
+                    from_node(ctx, value)?,
+                ))?;
+            }
+            Ok(map.into_value())
+        }
+    }
+}
+
+fn is_instance_of<'js>(
+    ctx: &Ctx<'js>,
+    object: &Object<'js>,
+    class: &str,
+) -> rquickjs::Result<bool> {
+    let constructor: Value = ctx.globals().get(class)?;
+    Ok(object.is_instance_of(&constructor))
+}
+
+/// Converts a JS value, with `ancestors` holding the arrays and objects it is nested in
+pub(super) fn to_node<'js>(
+    ctx: &Ctx<'js>,
+    value: Value<'js>,
+    ancestors: &mut Vec<Value<'js>>,
+) -> rquickjs::Result<Node> {
+    if !matches!(value.type_of(), Type::Array | Type::Object) {
+        return to_scalar_node(ctx, value);
+    }
+    if ancestors.contains(&value) {
+        return Err(Exception::throw_type(
+            ctx,
+            "Cannot stringify a cyclic value",
+        ));
+    }
+    if ancestors.len() >= MAX_DEPTH {
+        return Err(Exception::throw_range(
+            ctx,
+            "Cannot stringify a value nested this deeply",
+        ));
+    }
+    ancestors.push(value.clone());
+    let node = to_container_node(ctx, value, ancestors);
+    ancestors.pop();
+    node
+}
+
+fn to_scalar_node<'js>(ctx: &Ctx<'js>, value: Value<'js>) -> rquickjs::Result<Node> {
+    match value.type_of() {
+        // Like in JSON, functions and symbols are treated as `undefined`
+        Type::Undefined | Type::Function | Type::Constructor | Type::Symbol => Ok(Node::Undefined),
+        Type::Null => Ok(Node::Null),
+        Type::Bool => Ok(Node::Bool(value.as_bool().unwrap_or_default())),
+        Type::Int => Ok(Node::Int(value.as_int().unwrap_or_default() as i128)),
+        Type::Float => {
+            let float = value.as_float().unwrap_or_default();
+            if float.fract() == 0.0
+                && float.abs() <= MAX_SAFE_INTEGER as f64
+                && !(float == 0.0 && float.is_sign_negative())
+            {
+                Ok(Node::Int(float as i128))
+            } else {
+                Ok(Node::Float(float))
+            }
+        }
+        Type::BigInt => {
+            let Coerced(digits) = value.get::<Coerced<String>>()?;
+            digits.parse::<i128>().map(Node::Int).map_err(|_| {
+                Exception::throw_range(ctx, "Cannot stringify a BigInt of over 128 bits")
+            })
+        }
+        Type::String => Ok(Node::String(
+            value.as_string().expect("checked type").to_string()?,
+        )),
+        other => Err(Exception::throw_type(
+            ctx,
+            &format!("Cannot stringify a value of type {other}"),
+        )),
+    }
+}
+
+fn to_container_node<'js>(
+    ctx: &Ctx<'js>,
+    value: Value<'js>,
+    ancestors: &mut Vec<Value<'js>>,
+) -> rquickjs::Result<Node> {
+    match value.type_of() {
+        Type::Array => {
+            let array = value.as_array().expect("checked type");
+            let mut nodes = Vec::with_capacity(array.len());
+            for node in array.iter::<Value>() {
+                nodes.push(to_node(ctx, node?, ancestors)?);
+            }
+            Ok(Node::Array(nodes))
+        }
+        Type::Object => {
+            let object = value.as_object().expect("checked type");
+            if is_instance_of(ctx, object, "Date")? {
+                let get_time: Function = object.get("getTime")?;
+                if get_time.call::<_, f64>((This(object.clone()),))?.is_nan() {
+                    return Err(Exception::throw_range(
+                        ctx,
+                        "Cannot stringify an invalid date",
+                    ));
+                }
+                let to_iso_string: Function = object.get("toISOString")?;
+                return Ok(Node::Date(to_iso_string.call((This(object.clone()),))?));
+            }
+            if is_instance_of(ctx, object, "Map")? {
+                let array: Object = ctx.globals().get("Array")?;
+                let from: Function = array.get("from")?;
+                let entries: Array = from.call((value.clone(),))?;
+                let mut nodes = Vec::with_capacity(entries.len());
+                for entry in entries.iter::<Array>() {
+                    let entry = entry?;
+                    nodes.push((
+                        to_node(ctx, entry.get(0)?, ancestors)?,
+                        to_node(ctx, entry.get(1)?, ancestors)?,
+                    ));
+                }
+                return Ok(Node::Map(nodes));
+            }
+            let mut nodes = Vec::new();
+            for key in object.keys::<String>() {
+                let key = key?;
+                let value = object.get(&key)?;
+                nodes.push((Node::String(key), to_node(ctx, value, ancestors)?));
+            }
+            Ok(Node::Map(nodes))
+        }
+        _ => unreachable!("only called with arrays and objects"),
+    }
+}
@@ -0,0 +1,195 @@
+use rquickjs::convert::Coerced;
+use rquickjs::{Class, Ctx, Exception, FromJs, Value};
+use rust_decimal::{Decimal, RoundingStrategy};
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+// Native implementation of the Decimal class
+#[rquickjs::module(rename = "camelCase")]
+pub mod native_module {
+    use rquickjs::class::Trace;
+    use rquickjs::prelude::*;
+    use rquickjs::{Ctx, Exception, JsLifetime, Value};
+    use rust_decimal::Decimal;
+
+    #[derive(Clone, JsLifetime, Trace)]
+    #[rquickjs::class(rename = "Decimal")]
+    pub struct JsDecimal {
+        #[qjs(skip_trace)]
+        pub(super) value: Decimal,
+    }
+
+    #[rquickjs::methods(rename_all = "camelCase")]
+    impl JsDecimal {
+        #[qjs(constructor)]
+        pub fn new<'js>(value: Value<'js>, ctx: Ctx<'js>) -> rquickjs::Result<Self> {
+            Ok(Self {
+                value: super::to_decimal(&ctx, value)?,
+            })
+        }
+
+        #[qjs(get)]
+        pub fn scale(&self) -> u32 {
+            self.value.scale()
+        }
+
+        pub fn add<'js>(&self, other: Value<'js>, ctx: Ctx<'js>) -> rquickjs::Result<Self> {
+            let other = super::to_decimal(&ctx, other)?;
+            super::checked(&ctx, self.value.checked_add(other), "Decimal overflow")
+        }
+
+        pub fn sub<'js>(&self, other: Value<'js>, ctx: Ctx<'js>) -> rquickjs::Result<Self> {
+            let other = super::to_decimal(&ctx, other)?;
+            super::checked(&ctx, self.value.checked_sub(other), "Decimal overflow")
+        }
+
+        pub fn mul<'js>(&self, other: Value<'js>, ctx: Ctx<'js>) -> rquickjs::Result<Self> {
+            let other = super::to_decimal(&ctx, other)?;
+            super::checked(&ctx, self.value.checked_mul(other), "Decimal overflow")
+        }
+
+        pub fn div<'js>(&self, other: Value<'js>, ctx: Ctx<'js>) -> rquickjs::Result<Self> {
+            let other = super::to_decimal(&ctx, other)?;
+            if other.is_zero() {
+                return Err(Exception::throw_range(&ctx, "Division by zero"));
+            }
+            super::checked(&ctx, self.value.checked_div(other), "Decimal overflow")
+        }
+
+        pub fn rem<'js>(&self, other: Value<'js>, ctx: Ctx<'js>) -> rquickjs::Result<Self> {
+            let other = super::to_decimal(&ctx, other)?;
+            if other.is_zero() {
+                return Err(Exception::throw_range(&ctx, "Division by zero"));
+            }
+            super::checked(&ctx, self.value.checked_rem(other), "Decimal overflow")
+        }
+
+        pub fn neg(&self) -> Self {
+            Self { value: -self.value }
+        }
+
+        pub fn abs(&self) -> Self {
+            Self {
+                value: self.value.abs(),
+            }
+        }
+
+        pub fn round(&self, digits: Opt<u32>) -> Self {
+            Self {
+                value: super::round(self.value, digits.0.unwrap_or(0)),
+            }
+        }
+
+        pub fn cmp<'js>(&self, other: Value<'js>, ctx: Ctx<'js>) -> rquickjs::Result<i32> {
+            Ok(super::compare(self.value, super::to_decimal(&ctx, other)?))
+        }
+
+        pub fn eq<'js>(&self, other: Value<'js>, ctx: Ctx<'js>) -> rquickjs::Result<bool> {
+            Ok(self.cmp(other, ctx)? == 0)
+        }
+
+        pub fn lt<'js>(&self, other: Value<'js>, ctx: Ctx<'js>) -> rquickjs::Result<bool> {
+            Ok(self.cmp(other, ctx)? < 0)
+        }
+
+        pub fn lte<'js>(&self, other: Value<'js>, ctx: Ctx<'js>) -> rquickjs::Result<bool> {
+            Ok(self.cmp(other, ctx)? <= 0)
+        }
+
+        pub fn gt<'js>(&self, other: Value<'js>, ctx: Ctx<'js>) -> rquickjs::Result<bool> {
+            Ok(self.cmp(other, ctx)? > 0)
+        }
+
+        pub fn gte<'js>(&self, other: Value<'js>, ctx: Ctx<'js>) -> rquickjs::Result<bool> {
+            Ok(self.cmp(other, ctx)? >= 0)
+        }
+
+        pub fn is_zero(&self) -> bool {
+            self.value.is_zero()
+        }
+
+        pub fn is_negative(&self) -> bool {
+            self.value.is_sign_negative() && !self.value.is_zero()
+        }
+
+        pub fn to_fixed(&self, digits: Opt<u32>) -> String {
+            let digits = digits.0.unwrap_or(0);
+            let mut value = super::round(self.value, digits);
+            value.rescale(digits);
+            value.to_string()
+        }
+
+        pub fn to_number(&self) -> f64 {
+            self.value.to_string().parse().unwrap_or(f64::NAN)
+        }
+
+        #[qjs(rename = "toString")]
+        pub fn to_js_string(&self) -> String {
+            self.value.to_string()
+        }
+
+        #[qjs(rename = "toJSON")]
+        pub fn to_json(&self) -> String {
+            self.value.to_string()
+        }
+    }
+}
+
+/// Converts a JS value to a decimal. Accepts `Decimal` instances, and strings, numbers and bigints
+/// holding a decimal number.
+pub fn to_decimal<'js>(ctx: &Ctx<'js>, value: Value<'js>) -> rquickjs::Result<Decimal> {
+    if let Ok(decimal) = Class::<native_module::JsDecimal>::from_value(&value) {
+        return Ok(decimal.borrow().value);
+    }
+    if !(value.is_string() || value.is_number() || value.type_of() == rquickjs::Type::BigInt) {
+        return Err(Exception::throw_type(
+            ctx,
+            &format!(
+                "Expected a Decimal, string or number, got {}",
+                value.type_name()
+            ),
+        ));
+    }
+    let string = Coerced::<String>::from_js(ctx, value)?.0;
+    parse_decimal(&string)
+        .ok_or_else(|| Exception::throw_type(ctx, &format!("Invalid decimal number: {string}")))
+}
+
+fn parse_decimal(string: &str) -> Option<Decimal> {
+    let string = string.trim();
+    if string.contains(['e', 'E']) {
+        Decimal::from_scientific(string).ok()
+    } else {
+        Decimal::from_str_exact(string)
+            .or_else(|_| Decimal::from_str(string))
+            .ok()
+    }
+}
+
+fn checked(
+    ctx: &Ctx<'_>,
+    value: Option<Decimal>,
+    message: &str,
+) -> rquickjs::Result<native_module::JsDecimal> {
+    value
+        .map(|value| native_module::JsDecimal { value })
+        .ok_or_else(|| Exception::throw_range(ctx, message))
+}
+
+fn round(value: Decimal, digits: u32) -> Decimal {
+    value.round_dp_with_strategy(digits, RoundingStrategy::MidpointAwayFromZero)
+}
+
+fn compare(left: Decimal, right: Decimal) -> i32 {
+    match left.cmp(&right) {
+        Ordering::Less => -1,
+        Ordering::Equal => 0,
+        Ordering::Greater => 1,
+    }
+}
+
+// JS code wiring the Decimal class into the global context
+pub const WIRE_JS: &str = r#"
+        import * as __wasm_rquickjs_decimal_native from '__wasm_rquickjs_builtin/decimal_native';
+        globalThis.Decimal = __wasm_rquickjs_decimal_native.Decimal;
+    "#;
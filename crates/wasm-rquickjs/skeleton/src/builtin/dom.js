@@ -0,0 +1,684 @@
+// A lightweight DOM shared by the `xml` and `html` modules: trees of elements, texts, comments and
+// doctypes with a subset of the CSS selectors for querying them
+
+const PARENT = Symbol('parent');
+const CHILDREN = Symbol('children');
+const ATTRIBUTES = Symbol('attributes');
+
+const VOID_ELEMENTS = new Set([
+    'area', 'base', 'br', 'col', 'embed', 'hr', 'img', 'input', 'link', 'meta', 'param', 'source', 'track', 'wbr'
+]);
+const RAW_TEXT_ELEMENTS = new Set(['script', 'style']);
+
+export class Node {
+    constructor() {
+        this[PARENT] = null;
+    }
+
+    get parentNode() {
+        return this[PARENT];
+    }
+
+    get parentElement() {
+        return this[PARENT] instanceof Element ? this[PARENT] : null;
+    }
+
+    get ownerDocument() {
+        let node = this[PARENT];
+        while (node !== null && !(node instanceof Document)) {
+            node = node[PARENT];
+        }
+        return node;
+    }
+
+    get childNodes() {
+        return [];
+    }
+
+    get previousSibling() {
+        return sibling(this, -1);
+    }
+
+    get nextSibling() {
+        return sibling(this, 1);
+    }
+
+    get textContent() {
+        return '';
+    }
+
+    remove() {
+        const parent = this[PARENT];
+        if (parent !== null) {
+            parent[CHILDREN].splice(parent[CHILDREN].indexOf(this), 1);
+            this[PARENT] = null;
+        }
+    }
+
+    toString() {
+        return serialize(this, isHtml(this));
+    }
+}
+
+export class Text extends Node {
+    constructor(data) {
+        super();
+        this.data = String(data);
+    }
+
+    get nodeType() {
+        return 3;
+    }
+
+    get nodeName() {
+        return '#text';
+    }
+
+    get textContent() {
+        return this.data;
+    }
+
+    set textContent(value) {
+        this.data = String(value);
+    }
+}
+
+export class Comment extends Node {
+    constructor(data) {
+        super();
+        this.data = String(data);
+    }
+
+    get nodeType() {
+        return 8;
+    }
+
+    get nodeName() {
+        return '#comment';
+    }
+
+    get textContent() {
+        return this.data;
+    }
+}
+
+export class DocumentType extends Node {
+    constructor(name) {
+        super();
+        this.name = String(name);
+    }
+
+    get nodeType() {
+        return 10;
+    }
+
+    get nodeName() {
+        return this.name;
+    }
+}
+
+// The methods of the nodes having children, the elements and the documents
+class ParentNode extends Node {
+    constructor() {
+        super();
+        this[CHILDREN] = [];
+    }
+
+    get childNodes() {
+        return this[CHILDREN].slice();
+    }
+
+    get children() {
+        return this[CHILDREN].filter((node) => node instanceof Element);
+    }
+
+    get firstChild() {
+        return this[CHILDREN][0] ?? null;
+    }
+
+    get lastChild() {
+        return this[CHILDREN].at(-1) ?? null;
+    }
+
+    get textContent() {
+        return this[CHILDREN]
+            .filter((node) => !(node instanceof Comment))
+            .map((node) => node.textContent)
+            .join('');
+    }
+
+    set textContent(value) {
+        this.replaceChildren(new Text(value));
+    }
+
+    appendChild(node) {
+        return this.insertBefore(node, null);
+    }
+
+    insertBefore(node, reference) {
+        if (!(node instanceof Node) || node instanceof Document) {
+            throw new TypeError('Only elements, texts, comments and doctypes can be inserted');
+        }
+        for (let ancestor = this; ancestor !== null; ancestor = ancestor[PARENT]) {
+            if (ancestor === node) {
+                throw new Error('A node cannot be inserted into itself');
+            }
+        }
+        if (reference !== null && reference !== undefined && reference[PARENT] !== this) {
+            throw new Error('The reference node is not a child of this node');
+        }
+        node.remove();
+        const index = reference ? this[CHILDREN].indexOf(reference) : this[CHILDREN].length;
+        this[CHILDREN].splice(index, 0, node);
+        node[PARENT] = this;
+        return node;
+    }
+
+    removeChild(node) {
+        if (node[PARENT] !== this) {
+            throw new Error('The node is not a child of this node');
+        }
+        node.remove();
+        return node;
+    }
+
+    replaceChildren(...nodes) {
+        for (const child of this[CHILDREN]) {
+            child[PARENT] = null;
+        }
+        this[CHILDREN] = [];
+        for (const node of nodes) {
+            this.appendChild(typeof node === 'string' ? new Text(node) : node);
+        }
+    }
+
+    querySelector(selectors) {
+        const parsed = parseSelectors(selectors, isHtml(this));
+        return descendants(this).find((element) => matchesAny(element, parsed)) ?? null;
+    }
+
+    querySelectorAll(selectors) {
+        const parsed = parseSelectors(selectors, isHtml(this));
+        return descendants(this).filter((element) => matchesAny(element, parsed));
+    }
+
+    getElementsByTagName(name) {
+        const html = isHtml(this);
+        const wanted = html ? String(name).toLowerCase() : String(name);
+        return descendants(this).filter((element) =>
+            wanted === '*' || (html ? element.tagName.toLowerCase() : element.tagName) === wanted);
+    }
+
+    get innerHTML() {
+        const html = isHtml(this);
+        return this[CHILDREN].map((node) => serialize(node, html)).join('');
+    }
+}
+
+export class Element extends ParentNode {
+    // The attributes are a list of name and value pairs or an object
+    constructor(name, attributes = []) {
+        super();
+        this.tagName = String(name);
+        this[ATTRIBUTES] = new Map(
+            Array.isArray(attributes) ? attributes : Object.entries(attributes ?? {}),
+        );
+    }
+
+    get nodeType() {
+        return 1;
+    }
+
+    get nodeName() {
+        return this.tagName;
+    }
+
+    // The name without its namespace prefix
+    get localName() {
+        return this.tagName.slice(this.tagName.indexOf(':') + 1);
+    }
+
+    get id() {
+        return this.getAttribute('id') ?? '';
+    }
+
+    get className() {
+        return this.getAttribute('class') ?? '';
+    }
+
+    get attributes() {
+        return Object.fromEntries(this[ATTRIBUTES]);
+    }
+
+    getAttributeNames() {
+        return [...this[ATTRIBUTES].keys()];
+    }
+
+    getAttribute(name) {
+        return this[ATTRIBUTES].get(String(name)) ?? null;
+    }
+
+    hasAttribute(name) {
+        return this[ATTRIBUTES].has(String(name));
+    }
+
+    setAttribute(name, value) {
+        this[ATTRIBUTES].set(String(name), String(value));
+    }
+
+    removeAttribute(name) {
+        this[ATTRIBUTES].delete(String(name));
+    }
+
+    get previousElementSibling() {
+        return elementSibling(this, -1);
+    }
+
+    get nextElementSibling() {
+        return elementSibling(this, 1);
+    }
+
+    matches(selectors) {
+        return matchesAny(this, parseSelectors(selectors, isHtml(this)));
+    }
+
+    closest(selectors) {
+        const parsed = parseSelectors(selectors, isHtml(this));
+        for (let element = this; element !== null; element = element.parentElement) {
+            if (matchesAny(element, parsed)) {
+                return element;
+            }
+        }
+        return null;
+    }
+
+    get outerHTML() {
+        return serialize(this, isHtml(this));
+    }
+}
+
+export class Document extends ParentNode {
+    // `text/html` for HTML documents, `application/xml` for XML ones
+    constructor(contentType = 'application/xml') {
+        super();
+        this.contentType = contentType;
+    }
+
+    get nodeType() {
+        return 9;
+    }
+
+    get nodeName() {
+        return '#document';
+    }
+
+    get textContent() {
+        return null;
+    }
+
+    get documentElement() {
+        return this.children[0] ?? null;
+    }
+
+    get doctype() {
+        return this[CHILDREN].find((node) => node instanceof DocumentType) ?? null;
+    }
+
+    getElementById(id) {
+        return descendants(this).find((element) => element.getAttribute('id') === String(id)) ?? null;
+    }
+
+    createElement(name) {
+        return new Element(this.contentType === 'text/html' ? String(name).toLowerCase() : name);
+    }
+
+    createTextNode(data) {
+        return new Text(data);
+    }
+
+    createComment(data) {
+        return new Comment(data);
+    }
+}
+
+function isHtml(node) {
+    let root = node;
+    while (root[PARENT] !== null) {
+        root = root[PARENT];
+    }
+    return root instanceof Document && root.contentType === 'text/html';
+}
+
+function sibling(node, offset) {
+    const siblings = node[PARENT]?.[CHILDREN] ?? [];
+    const index = siblings.indexOf(node);
+    return index === -1 ? null : (siblings[index + offset] ?? null);
+}
+
+function elementSibling(node, offset) {
+    let current = sibling(node, offset);
+    while (current !== null && !(current instanceof Element)) {
+        current = sibling(current, offset);
+    }
+    return current;
+}
+
+// The elements inside a node, in document order
+function descendants(node) {
+    const elements = [];
+    const stack = [...node[CHILDREN]].reverse();
+    while (stack.length > 0) {
+        const current = stack.pop();
+        if (current instanceof Element) {
+            elements.push(current);
+            for (let index = current[CHILDREN].length - 1; index >= 0; index--) {
+                stack.push(current[CHILDREN][index]);
+            }
+        }
+    }
+    return elements;
+}
+
+// Builds a document from the plain nodes of the native parsers
+export function fromNodes(nodes, html) {
+    const document = new Document(html ? 'text/html' : 'application/xml');
+    const stack = [[document, nodes]];
+    while (stack.length > 0) {
+        const [parent, children] = stack.pop();
+        for (const node of children) {
+            let child;
+            switch (node.type) {
+                case 'element':
+                    child = new Element(node.name, node.attributes);
+                    stack.push([child, node.children]);
+                    break;
+                case 'text':
+                    child = new Text(node.value);
+                    break;
+                case 'comment':
+                    child = new Comment(node.value);
+                    break;
+                default:
+                    child = new DocumentType(node.value);
+            }
+            child[PARENT] = parent;
+            parent[CHILDREN].push(child);
+        }
+    }
+    return document;
+}
+
+// Serializes a node as XML, or as HTML with void and raw text elements
+export function serialize(node, html) {
+    if (!(node instanceof Node)) {
+        throw new TypeError('Only nodes can be serialized');
+    }
+    const parts = [];
+    write(node, html, parts);
+    return parts.join('');
+}
+
+function write(node, html, parts) {
+    if (node instanceof Text) {
+        const parent = node.parentElement;
+        const raw = html && parent !== null && RAW_TEXT_ELEMENTS.has(parent.tagName.toLowerCase());
+        parts.push(raw ? node.data : escape(node.data, html, false));
+    } else if (node instanceof Comment) {
+        parts.push(`<!--${node.data}-->`);
+    } else if (node instanceof DocumentType) {
+        parts.push(`<!DOCTYPE ${node.name}>`);
+    } else if (node instanceof Document) {
+        for (const child of node[CHILDREN]) {
+            write(child, html, parts);
+        }
+    } else if (node instanceof Element) {
+        parts.push(`<${node.tagName}`);
+        for (const [name, value] of node[ATTRIBUTES]) {
+            parts.push(` ${name}="${escape(value, html, true)}"`);
+        }
+        if (html && VOID_ELEMENTS.has(node.tagName.toLowerCase())) {
+            parts.push('>');
+            return;
+        }
+        if (!html && node[CHILDREN].length === 0) {
+            parts.push('/>');
+            return;
+        }
+        parts.push('>');
+        for (const child of node[CHILDREN]) {
+            write(child, html, parts);
+        }
+        parts.push(`</${node.tagName}>`);
+    }
+}
+
+const ESCAPED = {
+    '&': '&amp;',
+    '"': '&quot;',
+    '<': '&lt;',
+    '>': '&gt;',
+    '\u00a0': '&nbsp;',
+};
+
+// Like in browsers, HTML attributes keep their `<` and `>`, while non-breaking spaces are escaped
+function escape(text, html, attribute) {
+    const pattern = html
+        ? (attribute ? /[&"\u00a0]/g : /[&<>\u00a0]/g)
+        : (attribute ? /[&"<>]/g : /[&<>]/g);
+    return text.replace(pattern, (char) => ESCAPED[char]);
+}
+
+// Selectors: type, `*`, `#id`, `.class` and `[attribute]` selectors (with the `=`, `~=`, `|=`, `^=`,
+// `$=` and `*=` operators and the `i` flag), the `:first-child`, `:last-child`, `:only-child`,
+// `:empty` and `:not(...)` pseudo-classes, and the descendant, `>`, `+` and `~` combinators
+const IDENTIFIER = /^(?:\\.|[-\w\u00a0-\uffff])+/;
+
+function parseSelectors(text, html) {
+    const source = String(text);
+    let rest = source.trim();
+    const fail = () => {
+        throw new SyntaxError(`Unsupported selector: ${source}`);
+    };
+    const identifier = () => {
+        const match = IDENTIFIER.exec(rest);
+        if (match === null) {
+            fail();
+        }
+        rest = rest.slice(match[0].length);
+        return match[0].replace(/\\(.)/g, '$1');
+    };
+    const skipWhitespace = () => {
+        const length = rest.length;
+        rest = rest.trimStart();
+        return rest.length !== length;
+    };
+
+    const compound = () => {
+        const tests = [];
+        const universal = rest.startsWith('*');
+        if (universal) {
+            rest = rest.slice(1);
+        } else if (IDENTIFIER.test(rest)) {
+            const name = identifier();
+            tests.push(html
+                ? (element) => element.tagName.toLowerCase() === name.toLowerCase()
+                : (element) => element.tagName === name);
+        }
+        for (;;) {
+            if (rest.startsWith('#')) {
+                rest = rest.slice(1);
+                const id = identifier();
+                tests.push((element) => element.getAttribute('id') === id);
+            } else if (rest.startsWith('.')) {
+                rest = rest.slice(1);
+                const name = identifier();
+                tests.push((element) => classes(element).includes(name));
+            } else if (rest.startsWith('[')) {
+                rest = rest.slice(1);
+                skipWhitespace();
+                tests.push(attribute(identifier()));
+            } else if (rest.startsWith(':')) {
+                rest = rest.slice(1);
+                tests.push(pseudoClass(identifier()));
+            } else {
+                break;
+            }
+        }
+        if (tests.length === 0 && !universal) {
+            fail();
+        }
+        return (element) => tests.every((test) => test(element));
+    };
+
+    const attribute = (name) => {
+        const key = html ? name.toLowerCase() : name;
+        skipWhitespace();
+        const operator = /^[~|^$*]?=/.exec(rest)?.[0];
+        if (operator === undefined) {
+            if (!rest.startsWith(']')) {
+                fail();
+            }
+            rest = rest.slice(1);
+            return (element) => element.hasAttribute(key);
+        }
+        rest = rest.slice(operator.length);
+        skipWhitespace();
+        let expected;
+        const quoted = /^(?:"((?:\\.|[^"\\])*)"|'((?:\\.|[^'\\])*)')/.exec(rest);
+        if (quoted !== null) {
+            rest = rest.slice(quoted[0].length);
+            expected = (quoted[1] ?? quoted[2]).replace(/\\(.)/g, '$1');
+        } else {
+            expected = identifier();
+        }
+        skipWhitespace();
+        const insensitive = /^[iI]\b/.test(rest);
+        if (insensitive) {
+            rest = rest.slice(1);
+            skipWhitespace();
+        }
+        if (!rest.startsWith(']')) {
+            fail();
+        }
+        rest = rest.slice(1);
+        const normalize = (value) => (insensitive ? value.toLowerCase() : value);
+        const wanted = normalize(expected);
+        const compare = {
+            '=': (value) => value === wanted,
+            '~=': (value) => value.split(/\s+/).includes(wanted),
+            '|=': (value) => value === wanted || value.startsWith(`${wanted}-`),
+            '^=': (value) => wanted !== '' && value.startsWith(wanted),
+            '$=': (value) => wanted !== '' && value.endsWith(wanted),
+            '*=': (value) => wanted !== '' && value.includes(wanted),
+        }[operator];
+        return (element) => {
+            const value = element.getAttribute(key);
+            return value !== null && compare(normalize(value));
+        };
+    };
+
+    const pseudoClass = (name) => {
+        switch (name) {
+            case 'first-child':
+                return (element) => element.previousElementSibling === null;
+            case 'last-child':
+                return (element) => element.nextElementSibling === null;
+            case 'only-child':
+                return (element) => element.previousElementSibling === null && element.nextElementSibling === null;
+            case 'empty':
+                return (element) => element[CHILDREN].every((node) => node instanceof Comment);
+            case 'not': {
+                if (!rest.startsWith('(')) {
+                    fail();
+                }
+                rest = rest.slice(1);
+                skipWhitespace();
+                const tests = [compound()];
+                skipWhitespace();
+                while (rest.startsWith(',')) {
+                    rest = rest.slice(1);
+                    skipWhitespace();
+                    tests.push(compound());
+                    skipWhitespace();
+                }
+                if (!rest.startsWith(')')) {
+                    fail();
+                }
+                rest = rest.slice(1);
+                return (element) => !tests.some((test) => test(element));
+            }
+            default:
+                return fail();
+        }
+    };
+
+    // Each selector is a list of compound selectors and the combinators before them
+    const selectors = [];
+    let steps = [{ combinator: null, test: compound() }];
+    for (;;) {
+        const whitespace = skipWhitespace();
+        if (rest === '') {
+            break;
+        }
+        if (rest.startsWith(',')) {
+            rest = rest.slice(1);
+            skipWhitespace();
+            selectors.push(steps);
+            steps = [{ combinator: null, test: compound() }];
+            continue;
+        }
+        let combinator = ' ';
+        if (/^[>+~]/.test(rest)) {
+            combinator = rest[0];
+            rest = rest.slice(1);
+            skipWhitespace();
+        } else if (!whitespace) {
+            fail();
+        }
+        steps.push({ combinator, test: compound() });
+    }
+    selectors.push(steps);
+    return selectors;
+}
+
+function classes(element) {
+    return element.className.split(/\s+/).filter((name) => name !== '');
+}
+
+function matchesAny(element, selectors) {
+    return selectors.some((steps) => matchesSteps(element, steps, steps.length - 1));
+}
+
+// Matches the selector from its last compound selector, going up the tree for the combinators
+function matchesSteps(element, steps, index) {
+    const { combinator, test } = steps[index];
+    if (!test(element)) {
+        return false;
+    }
+    switch (combinator) {
+        case null:
+            return true;
+        case '>': {
+            const parent = element.parentElement;
+            return parent !== null && matchesSteps(parent, steps, index - 1);
+        }
+        case '+': {
+            const previous = element.previousElementSibling;
+            return previous !== null && matchesSteps(previous, steps, index - 1);
+        }
+        case '~':
+            for (let previous = element.previousElementSibling; previous !== null; previous = previous.previousElementSibling) {
+                if (matchesSteps(previous, steps, index - 1)) {
+                    return true;
+                }
+            }
+            return false;
+        default:
+            for (let parent = element.parentElement; parent !== null; parent = parent.parentElement) {
+                if (matchesSteps(parent, steps, index - 1)) {
+                    return true;
+                }
+            }
+            return false;
+    }
+}
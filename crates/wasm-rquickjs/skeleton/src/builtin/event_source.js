@@ -0,0 +1,269 @@
+// Implementation of EventSource on top of streaming fetch
+// See https://html.spec.whatwg.org/multipage/server-sent-events.html
+
+const CONNECTING = 0;
+const OPEN = 1;
+const CLOSED = 2;
+
+// The reconnection delay until the server sets one with a `retry` field
+const DEFAULT_RETRY = 3000;
+
+export class MessageEvent {
+    constructor(type, options = {}) {
+        this.type = type;
+        this.data = options.data ?? null;
+        this.lastEventId = options.lastEventId ?? '';
+        this.origin = options.origin ?? '';
+    }
+}
+
+export class EventSource {
+    static CONNECTING = CONNECTING;
+    static OPEN = OPEN;
+    static CLOSED = CLOSED;
+
+    #url;
+    #withCredentials;
+    #readyState = CONNECTING;
+    #listeners = new Map();
+    #controller = undefined;
+    #lastEventId = '';
+    #retry = DEFAULT_RETRY;
+    #reconnectTimer = undefined;
+
+    constructor(url, options = {}) {
+        this.#url = new URL(String(url)).toString();
+        this.#withCredentials = Boolean(options.withCredentials);
+        this.onopen = null;
+        this.onmessage = null;
+        this.onerror = null;
+        this.#connect();
+    }
+
+    get CONNECTING() {
+        return CONNECTING;
+    }
+
+    get OPEN() {
+        return OPEN;
+    }
+
+    get CLOSED() {
+        return CLOSED;
+    }
+
+    get url() {
+        return this.#url;
+    }
+
+    get withCredentials() {
+        return this.#withCredentials;
+    }
+
+    get readyState() {
+        return this.#readyState;
+    }
+
+    addEventListener(type, listener) {
+        if (listener === null || listener === undefined) {
+            return;
+        }
+        const listeners = this.#listeners.get(type) ?? [];
+        if (!listeners.includes(listener)) {
+            listeners.push(listener);
+        }
+        this.#listeners.set(type, listeners);
+    }
+
+    removeEventListener(type, listener) {
+        const listeners = this.#listeners.get(type);
+        if (listeners !== undefined) {
+            this.#listeners.set(type, listeners.filter((entry) => entry !== listener));
+        }
+    }
+
+    close() {
+        this.#readyState = CLOSED;
+        clearTimeout(this.#reconnectTimer);
+        this.#controller?.abort();
+    }
+
+    #dispatch(event) {
+        event.target = this;
+        const handler = this[`on${event.type}`];
+        if (typeof handler === 'function') {
+            handler.call(this, event);
+        }
+        for (const listener of this.#listeners.get(event.type) ?? []) {
+            if (typeof listener === 'function') {
+                listener.call(this, event);
+            } else {
+                listener.handleEvent(event);
+            }
+        }
+    }
+
+    async #connect() {
+        const controller = new AbortController();
+        this.#controller = controller;
+        const headers = { 'Accept': 'text/event-stream', 'Cache-Control': 'no-cache' };
+        if (this.#lastEventId !== '') {
+            headers['Last-Event-ID'] = this.#lastEventId;
+        }
+
+        let response;
+        try {
+            response = await fetch(this.#url, {
+                headers,
+                cache: 'no-store',
+                credentials: this.#withCredentials ? 'include' : 'same-origin',
+                signal: controller.signal,
+            });
+        } catch (error) {
+            this.#reconnect();
+            return;
+        }
+
+        // Responses other than event streams fail the connection for good
+        const contentType = response.headers.get('Content-Type') ?? '';
+        if (response.status !== 200 || contentType.split(';')[0].trim().toLowerCase() !== 'text/event-stream') {
+            controller.abort();
+            this.#fail();
+            return;
+        }
+
+        if (this.#readyState === CLOSED) {
+            return;
+        }
+        this.#readyState = OPEN;
+        this.#dispatch({ type: 'open' });
+
+        try {
+            await this.#read(response.body.getReader());
+        } catch (error) {
+            // Interrupted streams are reconnected the same way as ended ones
+        }
+        this.#reconnect();
+    }
+
+    async #read(reader) {
+        const decoder = new TextDecoder();
+        const parser = new EventStreamParser((field, value) => this.#field(field, value), () => this.#dispatchMessage());
+        while (this.#readyState !== CLOSED) {
+            const { done, value } = await reader.read();
+            if (done) {
+                parser.push(decoder.decode());
+                return;
+            }
+            parser.push(decoder.decode(value, { stream: true }));
+        }
+    }
+
+    // Event being built from the fields of the stream
+    #data = [];
+    #eventType = '';
+
+    #field(field, value) {
+        if (field === 'event') {
+            this.#eventType = value;
+        } else if (field === 'data') {
+            this.#data.push(value);
+        } else if (field === 'id' && !value.includes('\0')) {
+            this.#lastEventId = value;
+        } else if (field === 'retry' && /^\d+$/.test(value)) {
+            this.#retry = parseInt(value, 10);
+        }
+    }
+
+    #dispatchMessage() {
+        const data = this.#data;
+        const type = this.#eventType || 'message';
+        this.#data = [];
+        this.#eventType = '';
+        if (data.length === 0 || this.#readyState === CLOSED) {
+            return;
+        }
+        this.#dispatch(new MessageEvent(type, {
+            data: data.join('\n'),
+            lastEventId: this.#lastEventId,
+            origin: new URL(this.#url).origin,
+        }));
+    }
+
+    #reconnect() {
+        if (this.#readyState === CLOSED) {
+            return;
+        }
+        this.#readyState = CONNECTING;
+        this.#data = [];
+        this.#eventType = '';
+        this.#dispatch({ type: 'error' });
+        if (this.#readyState !== CLOSED) {
+            this.#reconnectTimer = setTimeout(() => this.#connect(), this.#retry);
+        }
+    }
+
+    #fail() {
+        if (this.#readyState === CLOSED) {
+            return;
+        }
+        this.#readyState = CLOSED;
+        this.#dispatch({ type: 'error' });
+    }
+}
+
+// Splits the decoded text of an event stream into lines, reporting their fields and the blank lines
+// ending the events
+class EventStreamParser {
+    #buffer = '';
+    #first = true;
+    #pendingCarriageReturn = false;
+
+    constructor(onField, onBlankLine) {
+        this.onField = onField;
+        this.onBlankLine = onBlankLine;
+    }
+
+    push(text) {
+        if (this.#first && text.length > 0) {
+            this.#first = false;
+            text = text.replace(/^\uFEFF/, '');
+        }
+        // A CR ending the previous chunk may be the first half of a CRLF
+        if (this.#pendingCarriageReturn && text.startsWith('\n')) {
+            text = text.slice(1);
+        }
+        this.#pendingCarriageReturn = false;
+
+        this.#buffer += text;
+        const pattern = /\r\n|\r|\n/g;
+        let start = 0;
+        let match;
+        while ((match = pattern.exec(this.#buffer)) !== null) {
+            // A trailing CR ends its line, remembering to skip a LF starting the next chunk
+            if (match[0] === '\r' && match.index === this.#buffer.length - 1) {
+                this.#line(this.#buffer.slice(start, match.index));
+                start = this.#buffer.length;
+                this.#pendingCarriageReturn = true;
+                break;
+            }
+            this.#line(this.#buffer.slice(start, match.index));
+            start = match.index + match[0].length;
+        }
+        this.#buffer = this.#buffer.slice(start);
+    }
+
+    #line(line) {
+        if (line === '') {
+            this.onBlankLine();
+        } else if (!line.startsWith(':')) {
+            const colon = line.indexOf(':');
+            const field = colon < 0 ? line : line.slice(0, colon);
+            let value = colon < 0 ? '' : line.slice(colon + 1);
+            if (value.startsWith(' ')) {
+                value = value.slice(1);
+            }
+            this.onField(field, value);
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use golem_wasi_http::Url;
+
+/// Gets the base URL relative fetch URLs are resolved against: the `WASM_RQUICKJS_FETCH_BASE_URL`
+/// environment variable of the component, or the one the crate was generated with
+pub fn base_url() -> Option<String> {
+    env_var("WASM_RQUICKJS_FETCH_BASE_URL").or_else(|| crate::FETCH_BASE_URL.map(String::from))
+}
+
+/// Gets the URL a request to `url` is sent to when it goes through the fetch gateway, which is the
+/// gateway's URL followed by the path and query of the original URL. Requests to hosts listed in
+/// `WASM_RQUICKJS_FETCH_GATEWAY_BYPASS` are sent directly.
+///
+/// Fails if the gateway URL is invalid, so misconfigured requests are not sent to their origin
+/// directly, and if an `https` request would be sent to an `http` gateway in plaintext.
+pub fn gateway_url(url: &Url) -> Result<Option<Url>, String> {
+    let Some(gateway) =
+        env_var("WASM_RQUICKJS_FETCH_GATEWAY").or_else(|| crate::FETCH_GATEWAY.map(String::from))
+    else {
+        return Ok(None);
+    };
+
+    let host = url.host_str().unwrap_or_default();
+    let bypass = env_var("WASM_RQUICKJS_FETCH_GATEWAY_BYPASS").unwrap_or_default();
+    if bypass
+        .split(',')
+        .any(|entry| bypasses_gateway(entry.trim(), host))
+    {
+        return Ok(None);
+    }
+
+    let mut target: Url = gateway
+        .parse()
+        .map_err(|err| format!("Invalid fetch gateway `{gateway}`: {err}"))?;
+    if !matches!(target.scheme(), "http" | "https") {
+        return Err(format!(
+            "Invalid fetch gateway `{gateway}`, it must be an `http://` or `https://` URL"
+        ));
+    }
+    if url.scheme() == "https" && target.scheme() != "https" {
+        return Err(format!(
+            "The https request to {host} cannot be sent through the http fetch gateway `{gateway}`"
+        ));
+    }
+    let path = format!("{}{}", target.path().trim_end_matches('/'), url.path());
+    target.set_path(&path);
+    target.set_query(url.query());
+    Ok(Some(target))
+}
+
+/// Whether a `WASM_RQUICKJS_FETCH_GATEWAY_BYPASS` entry (`*`, a host name or a domain suffix like
+/// `.example.com`) matches the host
+fn bypasses_gateway(entry: &str, host: &str) -> bool {
+    if entry == "*" {
+        return true;
+    }
+    let domain = entry.trim_start_matches('.').to_ascii_lowercase();
+    let host = host.to_ascii_lowercase();
+    !domain.is_empty() && (host == domain || host.ends_with(&format!(".{domain}")))
+}
+
+fn env_var(name: &str) -> Option<String> {
+    std::env::var(name)
+        .ok()
+        .filter(|value| !value.trim().is_empty())
+}
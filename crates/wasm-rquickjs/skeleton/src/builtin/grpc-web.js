@@ -0,0 +1,191 @@
+// Minimal gRPC-web client over fetch, exchanging binary protobuf messages
+// See https://github.com/grpc/grpc/blob/master/doc/PROTOCOL-WEB.md
+
+const DATA_FRAME = 0x00;
+const TRAILERS_FRAME = 0x80;
+const FRAME_HEADER_SIZE = 5;
+
+export const GrpcStatus = Object.freeze({
+    OK: 0,
+    CANCELLED: 1,
+    UNKNOWN: 2,
+    INVALID_ARGUMENT: 3,
+    DEADLINE_EXCEEDED: 4,
+    NOT_FOUND: 5,
+    ALREADY_EXISTS: 6,
+    PERMISSION_DENIED: 7,
+    RESOURCE_EXHAUSTED: 8,
+    FAILED_PRECONDITION: 9,
+    ABORTED: 10,
+    OUT_OF_RANGE: 11,
+    UNIMPLEMENTED: 12,
+    INTERNAL: 13,
+    UNAVAILABLE: 14,
+    DATA_LOSS: 15,
+    UNAUTHENTICATED: 16,
+});
+
+export class GrpcError extends Error {
+    constructor(code, message, metadata = new Headers()) {
+        super(message);
+        this.name = 'GrpcError';
+        this.code = code;
+        this.metadata = metadata;
+    }
+}
+
+// Wraps a message in a data frame: a flags byte, the big-endian length, then the message
+export function encodeFrame(message) {
+    const frame = new Uint8Array(FRAME_HEADER_SIZE + message.length);
+    new DataView(frame.buffer).setUint32(1, message.length);
+    frame.set(message, FRAME_HEADER_SIZE);
+    return frame;
+}
+
+/**
+ * Client of the services at a base URL. The methods are given by their full names, such as
+ * `helloworld.Greeter/SayHello`, and take and return encoded protobuf messages (see the `protobuf`
+ * module). Failed calls reject with a `GrpcError`.
+ */
+export class GrpcWebClient {
+    constructor(baseUrl, options = {}) {
+        this.baseUrl = String(baseUrl).replace(/\/+$/, '');
+        this.headers = options.headers ?? {};
+    }
+
+    // Calls a unary method, resolving to the response message
+    async unary(method, request, options = {}) {
+        let response = undefined;
+        for await (const message of this.serverStreaming(method, request, options)) {
+            if (response !== undefined) {
+                throw new GrpcError(GrpcStatus.INTERNAL, `The unary method ${method} responded with several messages`);
+            }
+            response = message;
+        }
+        if (response === undefined) {
+            throw new GrpcError(GrpcStatus.INTERNAL, `The unary method ${method} responded without a message`);
+        }
+        return response;
+    }
+
+    // Calls a server streaming method, yielding the response messages as they arrive
+    async *serverStreaming(method, request, options = {}) {
+        const headers = new Headers(this.headers);
+        for (const [name, value] of new Headers(options.headers ?? {}).entries()) {
+            headers.set(name, value);
+        }
+        headers.set('Content-Type', 'application/grpc-web+proto');
+        headers.set('Accept', 'application/grpc-web+proto');
+        headers.set('X-Grpc-Web', '1');
+
+        const response = await fetch(`${this.baseUrl}/${method.replace(/^\/+/, '')}`, {
+            method: 'POST',
+            headers,
+            body: encodeFrame(request),
+            signal: options.signal,
+        });
+        if (!response.ok) {
+            throw new GrpcError(httpStatusCode(response.status), `The gRPC-web request failed with HTTP status ${response.status}`);
+        }
+
+        // Trailers-only responses have the status in their headers
+        const responseHeaders = response.headers;
+        if (responseHeaders.has('grpc-status')) {
+            checkStatus(responseHeaders);
+        }
+
+        let buffer = new Uint8Array(0);
+        let trailers = undefined;
+        const reader = response.body.getReader();
+        while (true) {
+            const { done, value } = await reader.read();
+            if (done) {
+                break;
+            }
+            buffer = concat(buffer, value);
+
+            while (buffer.length >= FRAME_HEADER_SIZE) {
+                const length = new DataView(buffer.buffer, buffer.byteOffset).getUint32(1);
+                if (buffer.length < FRAME_HEADER_SIZE + length) {
+                    break;
+                }
+                const flags = buffer[0];
+                const payload = buffer.slice(FRAME_HEADER_SIZE, FRAME_HEADER_SIZE + length);
+                buffer = buffer.subarray(FRAME_HEADER_SIZE + length);
+                if (flags & TRAILERS_FRAME) {
+                    trailers = parseTrailers(payload);
+                } else if (flags === DATA_FRAME) {
+                    yield payload;
+                } else {
+                    throw new GrpcError(GrpcStatus.UNIMPLEMENTED, 'Compressed gRPC-web frames are not supported');
+                }
+            }
+        }
+
+        if (buffer.length > 0) {
+            throw new GrpcError(GrpcStatus.INTERNAL, 'The gRPC-web response ended with an incomplete frame');
+        }
+        if (trailers !== undefined) {
+            checkStatus(trailers);
+        } else if (!responseHeaders.has('grpc-status')) {
+            throw new GrpcError(GrpcStatus.INTERNAL, 'The gRPC-web response ended without a status');
+        }
+    }
+}
+
+function concat(first, second) {
+    if (first.length === 0) {
+        return second;
+    }
+    const result = new Uint8Array(first.length + second.length);
+    result.set(first);
+    result.set(second, first.length);
+    return result;
+}
+
+// The trailers frame holds HTTP/1 style header lines
+function parseTrailers(payload) {
+    const trailers = new Headers();
+    for (const line of new TextDecoder().decode(payload).split('\r\n')) {
+        const colon = line.indexOf(':');
+        if (colon > 0) {
+            trailers.append(line.slice(0, colon).trim(), line.slice(colon + 1).trim());
+        }
+    }
+    return trailers;
+}
+
+function checkStatus(metadata) {
+    const code = parseInt(metadata.get('grpc-status') ?? '', 10);
+    if (code === GrpcStatus.OK) {
+        return;
+    }
+    let message = metadata.get('grpc-message') ?? '';
+    try {
+        message = decodeURIComponent(message);
+    } catch (error) {
+        // Keeping messages that are not valid percent-encoding as they are
+    }
+    throw new GrpcError(isNaN(code) ? GrpcStatus.UNKNOWN : code, message, metadata);
+}
+
+// Maps the HTTP status of a failed response to a gRPC status
+function httpStatusCode(status) {
+    switch (status) {
+        case 400:
+            return GrpcStatus.INTERNAL;
+        case 401:
+            return GrpcStatus.UNAUTHENTICATED;
+        case 403:
+            return GrpcStatus.PERMISSION_DENIED;
+        case 404:
+            return GrpcStatus.UNIMPLEMENTED;
+        case 429:
+        case 502:
+        case 503:
+        case 504:
+            return GrpcStatus.UNAVAILABLE;
+        default:
+            return GrpcStatus.UNKNOWN;
+    }
+}
@@ -0,0 +1,82 @@
+import { Hasher, crc32 as nativeCrc32, timing_safe_equal } from '__wasm_rquickjs_builtin/hashing_native';
+import { fromByteArray } from 'base64-js';
+
+const encoder = new TextEncoder();
+
+function toBytes(data) {
+    if (typeof data === 'string') {
+        return encoder.encode(data);
+    }
+    if (data instanceof Uint8Array) {
+        return data;
+    }
+    if (data instanceof ArrayBuffer) {
+        return new Uint8Array(data);
+    }
+    if (ArrayBuffer.isView(data)) {
+        return new Uint8Array(data.buffer, data.byteOffset, data.byteLength);
+    }
+    throw new TypeError('Expected a string, an ArrayBuffer or a typed array to hash');
+}
+
+function encode(bytes, encoding) {
+    switch (encoding) {
+        case undefined:
+            return bytes;
+        case 'hex':
+            return Array.from(bytes, (byte) => byte.toString(16).padStart(2, '0')).join('');
+        case 'base64':
+            return fromByteArray(bytes);
+        case 'base64url':
+            return fromByteArray(bytes).replace(/\+/g, '-').replace(/\//g, '_').replace(/=+$/, '');
+        default:
+            throw new TypeError(`Unsupported digest encoding \`${encoding}\`, expected \`hex\`, \`base64\` or \`base64url\``);
+    }
+}
+
+/**
+ * An incremental hash: data is added with `update(data)`, which can be chained, and `digest(encoding)`
+ * returns the result as a `Uint8Array`, or as a `hex`, `base64` or `base64url` string.
+ */
+export class Hash {
+    #hasher;
+
+    constructor(algorithm, key) {
+        this.#hasher = new Hasher(String(algorithm), key === undefined ? undefined : toBytes(key));
+    }
+
+    update(data) {
+        this.#hasher.update(toBytes(data));
+        return this;
+    }
+
+    digest(encoding) {
+        return encode(this.#hasher.digest(), encoding);
+    }
+}
+
+export function createHash(algorithm) {
+    return new Hash(algorithm);
+}
+
+export function createHmac(algorithm, key) {
+    return new Hash(algorithm, key);
+}
+
+// Hashes the data with `sha1`, `sha224`, `sha256`, `sha384` or `sha512` (also accepted as `SHA-256`)
+export function hash(algorithm, data, encoding) {
+    return new Hash(algorithm).update(data).digest(encoding);
+}
+
+export function hmac(algorithm, key, data, encoding) {
+    return new Hash(algorithm, key).update(data).digest(encoding);
+}
+
+// The CRC-32 checksum of the data as an unsigned integer, continuing from `initial` if given
+export function crc32(data, initial = 0) {
+    return nativeCrc32(toBytes(data), initial >>> 0);
+}
+
+export function timingSafeEqual(first, second) {
+    return timing_safe_equal(toBytes(first), toBytes(second));
+}
@@ -0,0 +1,137 @@
+use hmac::{Hmac, Mac};
+use sha1::Sha1;
+use sha2::{Digest, Sha224, Sha256, Sha384, Sha512};
+
+// Native implementation of the hash functions
+#[rquickjs::module(rename = "camelCase")]
+pub mod native_module {
+    use rquickjs::class::Trace;
+    use rquickjs::{Ctx, Exception, JsLifetime, TypedArray};
+
+    #[derive(JsLifetime, Trace)]
+    #[rquickjs::class(rename = "Hasher")]
+    pub struct JsHasher {
+        #[qjs(skip_trace)]
+        hasher: Option<Box<dyn super::Hasher>>,
+    }
+
+    #[rquickjs::methods(rename_all = "camelCase")]
+    impl JsHasher {
+        /// Creates a hasher of the algorithm, computing an HMAC if a key is given
+        #[qjs(constructor)]
+        pub fn new<'js>(
+            algorithm: String,
+            key: Option<TypedArray<'js, u8>>,
+            ctx: Ctx<'js>,
+        ) -> rquickjs::Result<Self> {
+            let key = key.as_ref().map(|key| key.as_bytes().unwrap_or_default());
+            let hasher = super::hasher(&algorithm, key).ok_or_else(|| {
+                Exception::throw_type(
+                    &ctx,
+                    &format!(
+                        "Unsupported hash algorithm `{algorithm}`, expected `sha1`, `sha224`, `sha256`, `sha384` or `sha512`"
+                    ),
+                )
+            })?;
+            Ok(Self {
+                hasher: Some(hasher),
+            })
+        }
+
+        pub fn update<'js>(
+            &mut self,
+            data: TypedArray<'js, u8>,
+            ctx: Ctx<'js>,
+        ) -> rquickjs::Result<()> {
+            let hasher = self.hasher.as_mut().ok_or_else(|| finished(&ctx))?;
+            hasher.update(data.as_bytes().unwrap_or_default());
+            Ok(())
+        }
+
+        pub fn digest<'js>(&mut self, ctx: Ctx<'js>) -> rquickjs::Result<TypedArray<'js, u8>> {
+            let hasher = self.hasher.take().ok_or_else(|| finished(&ctx))?;
+            TypedArray::new(ctx, hasher.finish())
+        }
+    }
+
+    fn finished(ctx: &Ctx<'_>) -> rquickjs::Error {
+        Exception::throw_message(ctx, "The digest of this hash has already been computed")
+    }
+
+    #[rquickjs::function]
+    pub fn crc32<'js>(data: TypedArray<'js, u8>, initial: Option<u32>) -> u32 {
+        let mut hasher = crc32fast::Hasher::new_with_initial(initial.unwrap_or_default());
+        hasher.update(data.as_bytes().unwrap_or_default());
+        hasher.finalize()
+    }
+
+    /// Compares the bytes in a time independent of their contents
+    #[rquickjs::function]
+    pub fn timing_safe_equal<'js>(first: TypedArray<'js, u8>, second: TypedArray<'js, u8>) -> bool {
+        let first = first.as_bytes().unwrap_or_default();
+        let second = second.as_bytes().unwrap_or_default();
+        first.len() == second.len()
+            && first
+                .iter()
+                .zip(second)
+                .fold(0, |difference, (a, b)| difference | (a ^ b))
+                == 0
+    }
+}
+
+/// A hash or HMAC being computed
+pub trait Hasher {
+    fn update(&mut self, data: &[u8]);
+
+    fn finish(self: Box<Self>) -> Vec<u8>;
+}
+
+struct Plain<D>(D);
+
+impl<D: Digest> Hasher for Plain<D> {
+    fn update(&mut self, data: &[u8]) {
+        Digest::update(&mut self.0, data);
+    }
+
+    fn finish(self: Box<Self>) -> Vec<u8> {
+        self.0.finalize().to_vec()
+    }
+}
+
+struct Keyed<M>(M);
+
+impl<M: Mac> Hasher for Keyed<M> {
+    fn update(&mut self, data: &[u8]) {
+        Mac::update(&mut self.0, data);
+    }
+
+    fn finish(self: Box<Self>) -> Vec<u8> {
+        self.0.finalize().into_bytes().to_vec()
+    }
+}
+
+/// Creates the hasher of an algorithm, named like `sha256` or `SHA-256`
+fn hasher(algorithm: &str, key: Option<&[u8]>) -> Option<Box<dyn Hasher>> {
+    macro_rules! create {
+        ($digest:ty) => {
+            match key {
+                Some(key) => Box::new(Keyed(
+                    Hmac::<$digest>::new_from_slice(key).expect("HMAC accepts keys of any length"),
+                )) as Box<dyn Hasher>,
+                None => Box::new(Plain(<$digest>::new())),
+            }
+        };
+    }
+
+    match algorithm.to_ascii_lowercase().replace('-', "").as_str() {
+        "sha1" => Some(create!(Sha1)),
+        "sha224" => Some(create!(Sha224)),
+        "sha256" => Some(create!(Sha256)),
+        "sha384" => Some(create!(Sha384)),
+        "sha512" => Some(create!(Sha512)),
+        _ => None,
+    }
+}
+
+// JS functions for the hashing API
+pub const HASHING_JS: &str = include_str!("hashing.js");
@@ -0,0 +1,39 @@
+use rquickjs::{Ctx, Exception};
+
+// Stubs of the native hash functions, failing with an explanation when used
+#[rquickjs::module(rename = "camelCase")]
+pub mod native_module {
+    use rquickjs::class::Trace;
+    use rquickjs::{Ctx, JsLifetime};
+
+    #[derive(JsLifetime, Trace)]
+    #[rquickjs::class(rename = "Hasher")]
+    pub struct JsHasher {}
+
+    #[rquickjs::methods(rename_all = "camelCase")]
+    impl JsHasher {
+        #[qjs(constructor)]
+        pub fn new(ctx: Ctx<'_>) -> rquickjs::Result<Self> {
+            Err(super::unavailable(&ctx))
+        }
+    }
+
+    #[rquickjs::function]
+    pub fn crc32(ctx: Ctx<'_>) -> rquickjs::Result<u32> {
+        Err(super::unavailable(&ctx))
+    }
+
+    #[rquickjs::function]
+    pub fn timing_safe_equal(ctx: Ctx<'_>) -> rquickjs::Result<bool> {
+        Err(super::unavailable(&ctx))
+    }
+}
+
+fn unavailable(ctx: &Ctx<'_>) -> rquickjs::Error {
+    Exception::throw_message(
+        ctx,
+        "The `hashing` module requires the `hashing` feature of the wrapper crate",
+    )
+}
+
+pub const HASHING_JS: &str = include_str!("hashing.js");
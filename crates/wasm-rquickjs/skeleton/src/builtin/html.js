@@ -0,0 +1,12 @@
+import { parse_html } from '__wasm_rquickjs_builtin/markup_native';
+import { Comment, Document, DocumentType, Element, Node, Text, fromNodes, serialize } from '__wasm_rquickjs_builtin/dom';
+
+export { Comment, Document, DocumentType, Element, Node, Text };
+
+export function parse(text) {
+    return fromNodes(parse_html(String(text)), true);
+}
+
+export function stringify(node) {
+    return serialize(node, true);
+}
@@ -1,14 +1,51 @@
 import * as httpNative from '__wasm_rquickjs_builtin/http_native'
 import {formDataToBlob} from '__wasm_rquickjs_builtin/http_form_data';
+import {callSignal} from 'cancellation';
+import {cookieHeader, storeCookies, usesCookies} from '__wasm_rquickjs_builtin/http_cookies';
+import {cachedFetch, usesCache} from '__wasm_rquickjs_builtin/http_cache';
 
 // Partially based on the implementation in wasmedge-quickjs
 // Partially based on https://github.com/JakeChampion/fetch/blob/main/fetch.js
 // Depends on https://github.com/jimmywarting/FormData and https://github.com/node-fetch/fetch-blob
 
 export async function fetch(resource, options = {}) {
+    const isRequest = resource instanceof Request;
+    const method = (isRequest ? resource.method : options.method || 'GET').toUpperCase();
+    const mode = options.cache ?? (isRequest ? resource.cache : 'default');
+    if (!usesCache(method, mode)) {
+        return await send(resource, options);
+    }
+
+    // Cached requests are sent with their URL, so the cache can add its conditional headers
+    const url = resolveUrl(isRequest ? resource.url : resource);
+    // The headers of the options take precedence over the ones of a request
+    const headers = new Headers(isRequest ? resource.headers : {});
+    new Headers(options.headers || {}).forEach((value, name) => headers.set(name, value));
+    const sendOptions = {
+        ...options,
+        method,
+        signal: options.signal ?? (isRequest ? resource.signal : undefined),
+        credentials: options.credentials ?? (isRequest ? resource.credentials : undefined),
+    };
+    return await cachedFetch(
+        url,
+        headers,
+        mode,
+        (headers) => send(url, {...sendOptions, headers}),
+        (cached) => new Response(cached, url),
+    );
+}
+
+async function send(resource, options) {
     let request;
     let body;
     let url;
+    let credentials;
+
+    // The fetch is aborted by its own signal, or when the export call is cancelled
+    const userSignal = options.signal ?? (resource instanceof Request ? resource.signal : undefined);
+    const signal = userSignal ? AbortSignal.any([userSignal, callSignal()]) : callSignal();
+    signal.throwIfAborted();
 
     if (typeof resource === 'object' && resource instanceof Request) {
         const method = resource.method.toUpperCase();
@@ -16,20 +53,24 @@ export async function fetch(resource, options = {}) {
         if (!headers.has('Accept')) {
             headers.set('Accept', '*/*');
         }
+        url = resolveUrl(resource.url);
+        credentials = options.credentials ?? resource.credentials;
+        addCookieHeader(headers, url, credentials);
+        const target = gatewayUrl(url, headers);
         let rawHeaders = {};
         for (const [name, value] of headers.entries()) {
             rawHeaders[name] = value;
         }
+        checkHeaderCount(rawHeaders);
         let version = options.version || 'HTTP/1.1';
         request = new httpNative.HttpRequest(
-            resource.url,
+            target,
             method,
             rawHeaders,
             version
         )
         resource._bodyUsed = true;
         body = resource._body;
-        url = resource.url;
     } else {
         let method = options.method || 'GET'
         method = method.toUpperCase();
@@ -39,89 +80,219 @@ export async function fetch(resource, options = {}) {
         if (!headers.has('Accept')) {
             headers.set('Accept', '*/*');
         }
+        body = urlEncodedBody(options.body || '', headers);
+        url = resolveUrl(resource);
+        credentials = options.credentials ?? 'same-origin';
+        addCookieHeader(headers, url, credentials);
+        const target = gatewayUrl(url, headers);
 
         let rawHeaders = {};
         for (const [name, value] of headers.entries()) {
             rawHeaders[name] = value;
         }
+        checkHeaderCount(rawHeaders);
 
         let version = options.version || 'HTTP/1.1';
 
         // TODO: options.mode
         // TODO: options.referer
-        // TODO: options.credentials
-        // TODO: options.cache
 
         request = new httpNative.HttpRequest(
-            resource,
+            target,
             method,
             rawHeaders,
             version
         )
 
         // TODO: DataView support
-        // TODO: URLSearchParams support
-
-        body = options.body || '';
-        url = resource;
     }
 
+    let response;
     if (body instanceof ReadableStream) {
-        return await streamingRequest(request, url, body);
+        response = await streamingRequest(request, url, body, signal);
     } else if (body instanceof FormData) {
         const blob = formDataToBlob(body);
-        return await blobRequestBody(request, url, blob);
+        response = await blobRequestBody(request, url, blob, signal);
     } else if (body instanceof Blob) {
-        return await blobRequestBody(request, url, body);
+        response = await blobRequestBody(request, url, body, signal);
     } else {
         if (body instanceof ArrayBuffer) {
+            checkRequestBodySize(body.byteLength);
             request.arrayBufferBody(body);
         } else if (body instanceof Uint8Array) {
+            checkRequestBodySize(body.byteLength);
             request.uint8ArrayBody(body);
         } else if (typeof body === 'string' || body instanceof String) {
+            checkRequestBodySize(utf8Length(body));
             request.stringBody(body);
         } else {
             console.warn('Unsupported body type');
         }
 
-        const nativeResponse = await request.simpleSend();
-        return new Response(nativeResponse, url);
+        const nativeResponse = await untilAborted(request, signal, request.simpleSend());
+        response = new Response(nativeResponse, url, signal);
+    }
+
+    if (usesCookies(credentials)) {
+        storeCookies(url, response.nativeResponse.headers);
+    }
+    return response;
+}
+
+// Adds the stored cookies matching the URL, unless the request has its own `Cookie` header
+function addCookieHeader(headers, url, credentials) {
+    if (usesCookies(credentials) && !headers.has('Cookie')) {
+        const cookie = cookieHeader(url);
+        if (cookie !== undefined) {
+            headers.set('Cookie', cookie);
+        }
+    }
+}
+
+// Resolves relative URLs against the configured base URL
+function resolveUrl(url) {
+    const baseUrl = httpNative.base_url();
+    return baseUrl === undefined ? String(url) : new URL(String(url), baseUrl).toString();
+}
+
+// Gets the URL the request is sent to, which is the fetch gateway's if the request goes through
+// one. The gateway gets the original host and scheme in the `X-Forwarded-Host` and
+// `X-Forwarded-Proto` headers.
+function gatewayUrl(url, headers) {
+    const gateway = httpNative.gateway_url(url);
+    if (gateway === undefined) {
+        return url;
+    }
+    const original = new URL(url);
+    headers.set('X-Forwarded-Host', original.host);
+    headers.set('X-Forwarded-Proto', original.protocol.replace(/:$/, ''));
+    return gateway;
+}
+
+// Converts URLSearchParams bodies to strings, setting their Content-Type unless it is given
+function urlEncodedBody(body, headers) {
+    if (body instanceof URLSearchParams) {
+        if (!headers.has('Content-Type')) {
+            headers.set('Content-Type', 'application/x-www-form-urlencoded;charset=UTF-8');
+        }
+        return body.toString();
     }
+    return body;
 }
 
-async function sendBody(bodyWriter, body) {
+// The limits are read for every request, as they can be overridden by environment variables
+function checkHeaderCount(rawHeaders) {
+    const maxHeaderCount = httpNative.max_header_count();
+    const headerCount = Object.keys(rawHeaders).length;
+    if (maxHeaderCount !== undefined && headerCount > maxHeaderCount) {
+        throw new TypeError(`The HTTP request has ${headerCount} headers, exceeding the limit of ${maxHeaderCount} headers`);
+    }
+}
+
+function checkRequestBodySize(size) {
+    const maxBodySize = httpNative.max_request_body_size();
+    if (maxBodySize !== undefined && size > maxBodySize) {
+        throw new TypeError(`The HTTP request body exceeds the limit of ${maxBodySize} bytes`);
+    }
+}
+
+// The size of a string encoded as UTF-8, without encoding it. Lone surrogates are counted as the
+// three bytes of their replacement character.
+function utf8Length(string) {
+    let length = 0;
+    for (let i = 0; i < string.length; i++) {
+        const code = string.charCodeAt(i);
+        if (code < 0x80) {
+            length += 1;
+        } else if (code < 0x800) {
+            length += 2;
+        } else if (code >= 0xd800 && code < 0xdc00 && (string.charCodeAt(i + 1) & 0xfc00) === 0xdc00) {
+            length += 4;
+            i++;
+        } else {
+            length += 3;
+        }
+    }
+    return length;
+}
+
+// Converts the error of a native call to the reason of the signal if it has been aborted
+function abortedError(signal, error) {
+    return signal?.aborted ? signal.reason : new TypeError(error);
+}
+
+// Waits for the `[result, error]` of a native call, rejecting with the signal's reason as soon as
+// the signal is aborted, in which case the native request also gives up waiting for the response
+function untilAborted(request, signal, nativeCall) {
+    return new Promise((resolve, reject) => {
+        const abortHandle = request.abortHandle();
+        const onAbort = () => {
+            abortHandle.abort();
+            reject(signal.reason);
+        };
+        signal.addEventListener('abort', onAbort);
+        nativeCall.then(([result, error]) => {
+            signal.removeEventListener('abort', onAbort);
+            if (error !== undefined) {
+                reject(abortedError(signal, error));
+            } else {
+                resolve(result);
+            }
+        }, (error) => {
+            signal.removeEventListener('abort', onAbort);
+            reject(error);
+        });
+    });
+}
+
+async function sendBody(bodyWriter, body, signal) {
     const reader = body.getReader();
+    let size = 0;
     while (true) {
         const {done, value} = await reader.read();
         if (done) break;
-        await bodyWriter.writeRequestBodyChunk(value);
+        size += value.byteLength;
+        checkRequestBodySize(size);
+        const error = await bodyWriter.writeRequestBodyChunk(value);
+        if (error !== undefined) {
+            throw abortedError(signal, error);
+        }
     }
     bodyWriter.finishBody();
 }
 
-async function streamingRequest(request, resource, body) {
+async function streamingRequest(request, resource, body, signal) {
     request.initSend();
     const bodyWriter = request.initRequestBody();
     request.sendRequest();
 
-    const [nativeResponse, _] = await Promise.all([request.receiveResponse(), sendBody(bodyWriter, body)]);
-
-    return new Response(nativeResponse, resource);
+    // A body failing to be sent (such as by exceeding the size limit) gives up the response too
+    const bodySent = sendBody(bodyWriter, body, signal).catch((error) => {
+        request.abortHandle().abort();
+        throw error;
+    });
+    const [nativeResponse, _] = await Promise.all([
+        untilAborted(request, signal, request.receiveResponse()),
+        bodySent,
+    ]);
+
+    return new Response(nativeResponse, resource, signal);
 }
 
-async function blobRequestBody(request, resource, blob) {
+async function blobRequestBody(request, resource, blob, signal) {
     const stream = blob.stream();
     if (blob.type && blob.type !== '') {
         request.addHeader('Content-Type', blob.type);
     }
-    return await streamingRequest(request, resource, stream);
+    return await streamingRequest(request, resource, stream, signal);
 }
 
 export class Response {
-    constructor(nativeResponse, url) {
+    constructor(nativeResponse, url, signal) {
         this.nativeResponse = nativeResponse;
         this.url = url;
         this.bodyUsed = false;
+        this._signal = signal;
     }
 
     get status() {
@@ -134,6 +305,7 @@ export class Response {
 
     get body() {
         let nativeStreamSource = this.nativeResponse.stream();
+        const signal = this._signal;
         this.bodyUsed = true;
         return new ReadableStream({
             start() {
@@ -143,8 +315,14 @@ export class Response {
             },
             async pull(controller) {
                 // controller is https://developer.mozilla.org/en-US/docs/Web/API/ReadableByteStreamController
+                if (signal?.aborted) {
+                    controller.error(signal.reason);
+                    return;
+                }
                 const [next, err] = await nativeStreamSource.pull();
-                if (err !== undefined) {
+                if (signal?.aborted) {
+                    controller.error(signal.reason);
+                } else if (err !== undefined) {
                     console.error("Error reading response body stream:", err);
                     controller.error(err);
                 } else if (next === undefined) {
@@ -180,11 +358,17 @@ export class Response {
     // TODO: static json()
 
     // TODO: clone()
-    // TODO: formData()
+
+    async formData() {
+        return parseFormData(await this.arrayBuffer(), this.headers.get('Content-Type'));
+    }
 
     async arrayBuffer() {
-        let result = await this.nativeResponse.arrayBuffer();
+        const [result, err] = await this.nativeResponse.arrayBuffer();
         this.bodyUsed = true;
+        if (err !== undefined) {
+            throw abortedError(this._signal, err);
+        }
         return result;
     }
 
@@ -203,8 +387,11 @@ export class Response {
     }
 
     async text() {
-        let result = await this.nativeResponse.text();
+        const [result, err] = await this.nativeResponse.text();
         this.bodyUsed = true;
+        if (err !== undefined) {
+            throw abortedError(this._signal, err);
+        }
         return result;
     }
 }
@@ -340,7 +527,7 @@ export class Request {
             this._headers = new Headers(options.headers || {});
             this._bodyUsed = false;
             this._options = options;
-            this._body = options.body;
+            this._body = urlEncodedBody(options.body, this._headers);
         }
     }
 
@@ -449,7 +636,7 @@ export class Request {
             } else if (this._body instanceof Uint8Array) {
                 return this._body.buffer;
             } else if (typeof this._body === 'string' || this._body instanceof String) {
-                new TextEncoder().encode(this._body).buffer;
+                return new TextEncoder().encode(this._body).buffer;
             } else {
                 console.warn('Unsupported body type');
                 return new ArrayBuffer(0);
@@ -495,7 +682,7 @@ export class Request {
             } else if (this._body instanceof Uint8Array) {
                 return this._body;
             } else if (typeof this._body === 'string' || this._body instanceof String) {
-                new TextEncoder().encode(this._body);
+                return new TextEncoder().encode(this._body);
             } else {
                 console.warn('Unsupported body type');
                 return new Uint8Array(0);
@@ -508,11 +695,11 @@ export class Request {
     }
 
     async formData() {
-        this._bodyUsed = true;
         if (this._body instanceof FormData) {
+            this._bodyUsed = true;
             return this._body;
         } else {
-            throw new Error('Body is not FormData');
+            return parseFormData(await this.arrayBuffer(), this._headers.get('Content-Type'));
         }
     }
 
@@ -564,4 +751,107 @@ async function streamToBlob(stream) {
     }
 
     return new Blob(chunks);
-}
\ No newline at end of file
+}
+
+// Parses a multipart/form-data or application/x-www-form-urlencoded body into a FormData
+function parseFormData(buffer, contentType) {
+    const mimeType = (contentType ?? '').split(';')[0].trim().toLowerCase();
+    if (mimeType === 'application/x-www-form-urlencoded') {
+        const formData = new FormData();
+        for (const [name, value] of new URLSearchParams(new TextDecoder().decode(buffer))) {
+            formData.append(name, value);
+        }
+        return formData;
+    } else if (mimeType === 'multipart/form-data') {
+        const boundary = headerParameter(contentType, 'boundary');
+        if (boundary === undefined) {
+            throw new TypeError('Could not parse the body as FormData: the multipart/form-data Content-Type has no boundary');
+        }
+        return parseMultipart(new Uint8Array(buffer), boundary);
+    } else {
+        throw new TypeError(`Could not parse the body as FormData: unsupported Content-Type ${contentType}`);
+    }
+}
+
+// Gets a parameter of a header value such as `multipart/form-data; boundary=...`
+function headerParameter(value, name) {
+    for (const part of value.split(';').slice(1)) {
+        const separator = part.indexOf('=');
+        if (separator !== -1 && part.slice(0, separator).trim().toLowerCase() === name) {
+            const parameter = part.slice(separator + 1).trim();
+            return parameter.startsWith('"') && parameter.endsWith('"') ? parameter.slice(1, -1) : parameter;
+        }
+    }
+    return undefined;
+}
+
+function indexOfBytes(bytes, pattern, from) {
+    search: for (let i = from; i <= bytes.length - pattern.length; i++) {
+        for (let j = 0; j < pattern.length; j++) {
+            if (bytes[i + j] !== pattern[j]) {
+                continue search;
+            }
+        }
+        return i;
+    }
+    return -1;
+}
+
+// Reverts the escaping of quotes and line breaks in the names of the parts, see `formDataToBlob`
+function unescapePartName(name) {
+    return name.replace(/%22/g, '"').replace(/%0D/g, '\r').replace(/%0A/g, '\n');
+}
+
+function parseMultipart(bytes, boundary) {
+    const encoder = new TextEncoder();
+    const decoder = new TextDecoder();
+    const delimiter = encoder.encode(`--${boundary}`);
+    const headersEnd = encoder.encode('\r\n\r\n');
+    const invalid = (reason) => new TypeError(`Could not parse the body as FormData: ${reason}`);
+
+    const formData = new FormData();
+    let start = indexOfBytes(bytes, delimiter, 0);
+    if (start === -1) {
+        throw invalid('the boundary is missing');
+    }
+    while (true) {
+        start += delimiter.length;
+        // The last delimiter is followed by `--`, the others by a line break
+        if (bytes[start] === 0x2d && bytes[start + 1] === 0x2d) {
+            return formData;
+        }
+        start += 2;
+        const end = indexOfBytes(bytes, delimiter, start);
+        if (end === -1) {
+            throw invalid('the closing boundary is missing');
+        }
+        // The line break before the next delimiter belongs to the delimiter
+        const part = bytes.subarray(start, end - 2);
+        const separator = indexOfBytes(part, headersEnd, 0);
+        if (separator === -1) {
+            throw invalid('a part has no headers');
+        }
+
+        const headers = new Headers();
+        for (const line of decoder.decode(part.subarray(0, separator)).split('\r\n')) {
+            const colon = line.indexOf(':');
+            if (colon !== -1) {
+                headers.append(line.slice(0, colon).trim(), line.slice(colon + 1).trim());
+            }
+        }
+        const disposition = headers.get('Content-Disposition') ?? '';
+        const name = headerParameter(disposition, 'name');
+        if (name === undefined) {
+            throw invalid('a part has no name');
+        }
+        const content = part.subarray(separator + headersEnd.length);
+        const filename = headerParameter(disposition, 'filename');
+        if (filename === undefined) {
+            formData.append(unescapePartName(name), decoder.decode(content));
+        } else {
+            const type = headers.get('Content-Type') ?? 'application/octet-stream';
+            formData.append(unescapePartName(name), new File([content.slice()], unescapePartName(filename), {type}));
+        }
+        start = end;
+    }
+}
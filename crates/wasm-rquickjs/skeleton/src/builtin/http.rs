@@ -3,10 +3,62 @@
 pub mod native_module {
     pub use super::HttpRequest;
     pub use super::HttpResponse;
+
+    #[rquickjs::function]
+    pub fn max_request_body_size(ctx: rquickjs::Ctx<'_>) -> rquickjs::Result<Option<usize>> {
+        super::current_limits(&ctx).map(|limits| limits.max_request_body_size)
+    }
+
+    #[rquickjs::function]
+    pub fn max_header_count(ctx: rquickjs::Ctx<'_>) -> rquickjs::Result<Option<usize>> {
+        super::current_limits(&ctx).map(|limits| limits.max_header_count)
+    }
+
+    #[rquickjs::function]
+    pub fn cookie_jar_enabled() -> bool {
+        crate::COOKIE_JAR
+    }
+
+    #[rquickjs::function]
+    pub fn http_cache_enabled() -> bool {
+        crate::HTTP_CACHE
+    }
+
+    #[rquickjs::function]
+    pub fn http_cache_max_entries() -> Option<usize> {
+        crate::HTTP_CACHE_LIMITS.max_entries
+    }
+
+    #[rquickjs::function]
+    pub fn http_cache_max_size() -> Option<usize> {
+        crate::HTTP_CACHE_LIMITS.max_size
+    }
+
+    #[rquickjs::function]
+    pub fn http_cache_max_body_size() -> Option<usize> {
+        crate::HTTP_CACHE_LIMITS.max_body_size
+    }
+
+    #[rquickjs::function]
+    pub fn base_url() -> Option<String> {
+        crate::builtin::fetch_gateway::base_url()
+    }
+
+    #[rquickjs::function]
+    pub fn gateway_url(url: String, ctx: rquickjs::Ctx<'_>) -> rquickjs::Result<Option<String>> {
+        let Ok(url) = url.parse() else {
+            return Ok(None);
+        };
+        crate::builtin::fetch_gateway::gateway_url(&url)
+            .map(|url| url.map(|url| url.to_string()))
+            .map_err(|error| rquickjs::Exception::throw_type(&ctx, &error))
+    }
 }
 
+use crate::builtin::{HttpLimits, unless_call_cancelled};
 use futures::SinkExt;
 use futures::channel::mpsc::{UnboundedReceiver, UnboundedSender};
+use futures::future::{AbortHandle, AbortRegistration, Abortable};
 use futures_concurrency::stream::IntoStream;
 use golem_wasi_http::header::{HeaderName, HeaderValue};
 use golem_wasi_http::{
@@ -18,6 +70,7 @@ use rquickjs::prelude::List;
 use rquickjs::{ArrayBuffer, Ctx, JsLifetime, TypedArray};
 use std::cell::RefCell;
 use std::collections::HashMap;
+use std::future::Future;
 use wstd::runtime::AsyncPollable;
 
 #[derive(Trace, JsLifetime)]
@@ -35,10 +88,15 @@ pub struct HttpRequest {
     body: Option<Body>,
     #[qjs(skip_trace)]
     execution: Option<CustomRequestExecution>,
+    #[qjs(skip_trace)]
+    abort_handle: AbortHandle,
+    #[qjs(skip_trace)]
+    abort_registration: Option<AbortRegistration>,
 }
 
 impl Default for HttpRequest {
     fn default() -> Self {
+        let (abort_handle, abort_registration) = AbortHandle::new_pair();
         HttpRequest {
             method: Method::GET,
             url: Url::parse("http://localhost").expect("failed to parse default URL"),
@@ -46,6 +104,8 @@ impl Default for HttpRequest {
             version: Version::HTTP_11,
             body: None,
             execution: None,
+            abort_handle,
+            abort_registration: Some(abort_registration),
         }
     }
 }
@@ -78,6 +138,7 @@ impl HttpRequest {
             hdrs.insert(header_name, header_value);
         }
 
+        let (abort_handle, abort_registration) = AbortHandle::new_pair();
         HttpRequest {
             url,
             method,
@@ -85,6 +146,15 @@ impl HttpRequest {
             version,
             body: None,
             execution: None,
+            abort_handle,
+            abort_registration: Some(abort_registration),
+        }
+    }
+
+    /// Gets a handle giving up waiting for the response, for requests aborted by their signal
+    pub fn abort_handle(&self) -> HttpAbortHandle {
+        HttpAbortHandle {
+            handle: self.abort_handle.clone(),
         }
     }
 
@@ -158,20 +228,21 @@ impl HttpRequest {
         }
     }
 
-    pub async fn receive_response(&mut self) -> HttpResponse {
+    pub async fn receive_response(&mut self) -> List<(Option<HttpResponse>, Option<String>)> {
         if let Some(execution) = self.execution.take() {
-            let response = execution
-                .receive_response()
-                .await
-                .expect("Failed to receive HTTP response");
-
-            HttpResponse::from_response(response)
+            match until_aborted(self.abort_registration.take(), execution.receive_response()).await
+            {
+                Ok(response) => {
+                    checked_response(response.expect("Failed to receive HTTP response"))
+                }
+                Err(err) => List((None, Some(err))),
+            }
         } else {
             panic!("HTTP request has not been initialized for sending");
         }
     }
 
-    pub async fn simple_send(&mut self) -> HttpResponse {
+    pub async fn simple_send(&mut self) -> List<(Option<HttpResponse>, Option<String>)> {
         let client = golem_wasi_http::ClientBuilder::new()
             .build()
             .expect("Failed to create HTTP client");
@@ -185,9 +256,115 @@ impl HttpRequest {
 
         *request.body_mut() = self.body.take();
 
-        let response = client.execute(request).await.expect("HTTP request failed");
+        match until_aborted(self.abort_registration.take(), client.execute(request)).await {
+            Ok(response) => checked_response(response.expect("HTTP request failed")),
+            Err(err) => List((None, Some(err))),
+        }
+    }
+}
+
+/// Gets the current HTTP limits, throwing a `TypeError` if they are misconfigured
+fn current_limits(ctx: &rquickjs::Ctx<'_>) -> rquickjs::Result<HttpLimits> {
+    HttpLimits::current().map_err(|error| rquickjs::Exception::throw_type(ctx, &error))
+}
 
-        HttpResponse::from_response(response)
+const CALL_CANCELLED: &str = "The HTTP request has been cancelled with the call";
+
+/// The number of bytes read from the response body stream at once
+const BODY_CHUNK_SIZE: u64 = 4096;
+
+/// Wraps a received response, failing if it exceeds the header count or (by its `Content-Length`)
+/// the body size limits
+fn checked_response(
+    response: golem_wasi_http::Response,
+) -> List<(Option<HttpResponse>, Option<String>)> {
+    let limits = match HttpLimits::current() {
+        Ok(limits) => limits,
+        Err(error) => return List((None, Some(error))),
+    };
+    if let Some(max_header_count) = limits.max_header_count
+        && response.headers().len() > max_header_count
+    {
+        return List((
+            None,
+            Some(format!(
+                "The HTTP response has {} headers, exceeding the limit of {max_header_count} headers",
+                response.headers().len()
+            )),
+        ));
+    }
+    if let (Some(max_body_size), Some(content_length)) =
+        (limits.max_response_body_size, response.content_length())
+        && content_length > max_body_size as u64
+    {
+        return List((None, Some(response_body_too_large(max_body_size))));
+    }
+    List((
+        Some(HttpResponse::from_response(
+            response,
+            limits.max_response_body_size,
+        )),
+        None,
+    ))
+}
+
+fn response_body_too_large(max_body_size: usize) -> String {
+    format!("The HTTP response body exceeds the limit of {max_body_size} bytes")
+}
+
+/// Reads a response body into memory chunk by chunk, failing as soon as it exceeds the limit
+/// instead of buffering all of it first
+async fn read_limited_body(
+    mut response: golem_wasi_http::Response,
+    max_body_size: usize,
+) -> Result<Vec<u8>, String> {
+    // Kept as a tuple, so the stream is dropped before the body it belongs to
+    let raw = response.get_raw_input_stream();
+    let mut bytes = Vec::new();
+    loop {
+        AsyncPollable::new(raw.0.subscribe()).wait_for().await;
+        match raw.0.read(BODY_CHUNK_SIZE) {
+            Ok(chunk) => {
+                if bytes.len() + chunk.len() > max_body_size {
+                    return Err(response_body_too_large(max_body_size));
+                }
+                bytes.extend_from_slice(&chunk);
+            }
+            Err(StreamError::Closed) => return Ok(bytes),
+            Err(StreamError::LastOperationFailed(err)) => {
+                return Err(format!(
+                    "Failed to read response body: {}",
+                    err.to_debug_string()
+                ));
+            }
+        }
+    }
+}
+
+/// Runs a future until it completes, the request is aborted or the export call is cancelled
+async fn until_aborted<F: Future>(
+    registration: Option<AbortRegistration>,
+    future: F,
+) -> Result<F::Output, String> {
+    let registration = registration.expect("HTTP request has already been sent");
+    match unless_call_cancelled(Abortable::new(future, registration)).await {
+        Some(Ok(output)) => Ok(output),
+        Some(Err(_)) => Err("The HTTP request has been aborted".to_string()),
+        None => Err(CALL_CANCELLED.to_string()),
+    }
+}
+
+#[derive(Trace, JsLifetime)]
+#[rquickjs::class(rename_all = "camelCase")]
+pub struct HttpAbortHandle {
+    #[qjs(skip_trace)]
+    handle: AbortHandle,
+}
+
+#[rquickjs::methods(rename_all = "camelCase")]
+impl HttpAbortHandle {
+    pub fn abort(&self) {
+        self.handle.abort();
     }
 }
 
@@ -205,16 +382,23 @@ impl WrappedRequestBodyWriter {
         WrappedRequestBodyWriter { writer: None }
     }
 
-    pub async fn write_request_body_chunk(&mut self, chunk: TypedArray<'_, u8>) {
+    pub async fn write_request_body_chunk(&mut self, chunk: TypedArray<'_, u8>) -> Option<String> {
         if let Some(writer) = self.writer.as_mut() {
-            writer
-                .write_body_chunk(
+            let written = unless_call_cancelled(
+                writer.write_body_chunk(
                     chunk
                         .as_bytes()
                         .expect("the UInt8Array passed to the HTTP request is detached"),
-                )
-                .await
-                .expect("Failed to write HTTP request body chunk");
+                ),
+            )
+            .await;
+            match written {
+                Some(result) => {
+                    result.expect("Failed to write HTTP request body chunk");
+                    None
+                }
+                None => Some(CALL_CANCELLED.to_string()),
+            }
         } else {
             panic!("HTTP request has not been initialized for sending");
         }
@@ -239,6 +423,8 @@ pub struct HttpResponse {
     headers: Vec<Vec<String>>,
     #[qjs(skip_trace)]
     status: golem_wasi_http::StatusCode,
+    #[qjs(skip_trace)]
+    max_body_size: Option<usize>,
 }
 
 impl Default for HttpResponse {
@@ -255,11 +441,15 @@ impl HttpResponse {
             response: None,
             headers: Vec::new(),
             status: golem_wasi_http::StatusCode::OK,
+            max_body_size: None,
         }
     }
 
     #[qjs(skip)]
-    pub fn from_response(response: golem_wasi_http::Response) -> Self {
+    pub fn from_response(
+        response: golem_wasi_http::Response,
+        max_body_size: Option<usize>,
+    ) -> Self {
         let headers = response
             .headers()
             .iter()
@@ -277,6 +467,7 @@ impl HttpResponse {
             response: Some(response),
             headers,
             status,
+            max_body_size,
         }
     }
 
@@ -298,16 +489,33 @@ impl HttpResponse {
             .to_string()
     }
 
-    pub async fn array_buffer<'js>(&mut self, ctx: Ctx<'js>) -> ArrayBuffer<'js> {
-        let bytes = self
+    pub async fn array_buffer<'js>(
+        &mut self,
+        ctx: Ctx<'js>,
+    ) -> List<(Option<ArrayBuffer<'js>>, Option<String>)> {
+        let response = self
             .response
             .take()
-            .expect("The response has already been consumed")
-            .bytes()
-            .await
-            .expect("failed to read response body");
-
-        ArrayBuffer::new(ctx, bytes).expect("failed to create ArrayBuffer from response body")
+            .expect("The response has already been consumed");
+        let bytes = match self.max_body_size {
+            Some(max_body_size) => {
+                unless_call_cancelled(read_limited_body(response, max_body_size)).await
+            }
+            None => unless_call_cancelled(response.bytes())
+                .await
+                .map(|bytes| Ok(bytes.expect("failed to read response body").to_vec())),
+        };
+        match bytes {
+            Some(Ok(bytes)) => List((
+                Some(
+                    ArrayBuffer::new(ctx, bytes)
+                        .expect("failed to create ArrayBuffer from response body"),
+                ),
+                None,
+            )),
+            Some(Err(err)) => List((None, Some(err))),
+            None => List((None, Some(CALL_CANCELLED.to_string()))),
+        }
     }
 
     pub fn stream(&mut self) -> ResponseBodyStream {
@@ -320,16 +528,32 @@ impl HttpResponse {
 
         ResponseBodyStream {
             stream: Some((stream, body, response)),
+            max_body_size: self.max_body_size,
+            read: 0,
         }
     }
 
-    pub async fn text(&mut self) -> String {
-        self.response
+    pub async fn text(&mut self) -> List<(Option<String>, Option<String>)> {
+        let response = self
+            .response
             .take()
-            .expect("The response has already been consumed")
-            .text()
-            .await
-            .expect("failed to read response body")
+            .expect("The response has already been consumed");
+        let text = match self.max_body_size {
+            // Decoded as UTF-8, as the charset of the response is only known by `Response::text`
+            Some(max_body_size) => {
+                unless_call_cancelled(read_limited_body(response, max_body_size))
+                    .await
+                    .map(|bytes| bytes.map(|bytes| String::from_utf8_lossy(&bytes).into_owned()))
+            }
+            None => unless_call_cancelled(response.text())
+                .await
+                .map(|text| Ok(text.expect("failed to read response body"))),
+        };
+        match text {
+            Some(Ok(text)) => List((Some(text), None)),
+            Some(Err(err)) => List((None, Some(err))),
+            None => List((None, Some(CALL_CANCELLED.to_string()))),
+        }
     }
 }
 
@@ -345,13 +569,22 @@ pub struct ResponseBodyStream {
         golem_wasi_http::IncomingBody,
         golem_wasi_http::Response,
     )>,
+    #[qjs(skip_trace)]
+    max_body_size: Option<usize>,
+    /// The number of bytes read so far, checked against the body size limit
+    #[qjs(skip_trace)]
+    read: usize,
 }
 
 #[rquickjs::methods(rename_all = "camelCase")]
 impl ResponseBodyStream {
     #[qjs(constructor)]
     pub fn new() -> Self {
-        ResponseBodyStream { stream: None }
+        ResponseBodyStream {
+            stream: None,
+            max_body_size: None,
+            read: 0,
+        }
     }
 
     #[qjs(get, rename = "type")]
@@ -364,12 +597,24 @@ impl ResponseBodyStream {
         ctx: Ctx<'js>,
     ) -> List<(Option<TypedArray<'js, u8>>, Option<String>)> {
         if let Some((stream, _body, _response)) = &mut self.stream {
-            const CHUNK_SIZE: u64 = 4096;
             let pollable = stream.subscribe();
-            AsyncPollable::new(pollable).wait_for().await;
+            if unless_call_cancelled(AsyncPollable::new(pollable).wait_for())
+                .await
+                .is_none()
+            {
+                self.stream = None;
+                return List((None, Some(CALL_CANCELLED.to_string())));
+            }
 
-            match stream.read(CHUNK_SIZE) {
+            match stream.read(BODY_CHUNK_SIZE) {
                 Ok(chunk) => {
+                    self.read += chunk.len();
+                    if let Some(max_body_size) = self.max_body_size
+                        && self.read > max_body_size
+                    {
+                        self.stream = None;
+                        return List((None, Some(response_body_too_large(max_body_size))));
+                    }
                     let js_array = TypedArray::new_copy(ctx.clone(), chunk)
                         .expect("Failed to create TypedArray from response body chunk");
                     List((Some(js_array), None))
@@ -446,12 +691,16 @@ impl BodySink {
 pub const HTTP_JS: &str = include_str!("http.js");
 pub const FETCH_BLOB_JS: &str = include_str!("fetch-blob-4.0.0.js");
 pub const FORMDATA_JS: &str = include_str!("formdata-polyfill-4.0.10.js");
+pub const COOKIES_JS: &str = include_str!("http_cookies.js");
+pub const CACHE_JS: &str = include_str!("http_cache.js");
+pub const EVENT_SOURCE_JS: &str = include_str!("event_source.js");
 
 // JS code wiring the console module into the global context
 pub const WIRE_JS: &str = r#"
         import * as __wasm_rquickjs_http from '__wasm_rquickjs_builtin/http';
         import * as __wasm_rquickjs_http_blob from '__wasm_rquickjs_builtin/http_blob';
         import * as __wasm_rquickjs_http_form_data from '__wasm_rquickjs_builtin/http_form_data';
+        import * as __wasm_rquickjs_event_source from '__wasm_rquickjs_builtin/event_source';
 
         globalThis.fetch = __wasm_rquickjs_http.fetch;
         globalThis.Headers = __wasm_rquickjs_http.Headers;
@@ -460,4 +709,6 @@ pub const WIRE_JS: &str = r#"
         globalThis.Blob = __wasm_rquickjs_http_blob.Blob;
         globalThis.File = __wasm_rquickjs_http_blob.File;
         globalThis.FormData = __wasm_rquickjs_http_form_data.FormData;
+        globalThis.EventSource = __wasm_rquickjs_event_source.EventSource;
+        globalThis.MessageEvent = __wasm_rquickjs_event_source.MessageEvent;
     "#;
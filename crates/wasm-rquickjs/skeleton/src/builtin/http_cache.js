@@ -0,0 +1,230 @@
+// In-memory HTTP cache of fetch for GET requests, keeping the responses for the lifetime of the
+// component instance. A simplified private cache following https://www.rfc-editor.org/rfc/rfc9111
+import * as httpNative from '__wasm_rquickjs_builtin/http_native'
+
+// Cached responses keyed by URL, in the order of their last use
+const entries = new Map();
+// The total size of the cached response bodies
+let totalSize = 0;
+
+// Whether a fetch of the method with the given `cache` option goes through the cache
+export function usesCache(method, mode) {
+    return httpNative.http_cache_enabled() && method === 'GET' && mode !== 'no-store';
+}
+
+// Fetches a URL through the cache. `send(headers)` sends the request with the given headers and
+// resolves to a `Response`, and `toResponse(source)` creates a `Response` reading a cached entry.
+export async function cachedFetch(url, headers, mode, send, toResponse) {
+    const entry = lookup(url);
+    const usable = entry !== undefined && varyMatches(entry, headers);
+
+    if (usable && (mode === 'force-cache' || mode === 'only-if-cached' || (mode === 'default' && isFresh(entry)))) {
+        return toResponse(new CachedResponse(entry));
+    }
+    if (mode === 'only-if-cached') {
+        return toResponse(new CachedResponse({ status: 504, headers: [], body: new ArrayBuffer(0) }));
+    }
+
+    // Stale entries are revalidated with their validators, unless the request has its own
+    const revalidating = usable && mode !== 'reload' && addValidators(entry, headers);
+    const response = await send(headers);
+    if (revalidating && response.status === 304) {
+        updateHeaders(entry, response.nativeResponse.headers);
+        entry.responseTime = Date.now();
+        return toResponse(new CachedResponse(entry));
+    }
+    // Responses of unknown or too large size are not cached, keeping their bodies streamed
+    if (!isStorable(response) || !fitsCache(response.nativeResponse.headers)) {
+        return response;
+    }
+
+    const stored = {
+        status: response.status,
+        statusText: response.statusText,
+        headers: response.nativeResponse.headers.map(([name, value]) => [name, value]),
+        body: await response.arrayBuffer(),
+        responseTime: Date.now(),
+        vary: {},
+    };
+    for (const name of varyNames(stored)) {
+        stored.vary[name] = headers.get(name);
+    }
+    store(url, stored);
+    return toResponse(new CachedResponse(stored));
+}
+
+// Gets the cached entry of a URL, marking it as the most recently used
+function lookup(url) {
+    const entry = entries.get(url);
+    if (entry !== undefined) {
+        entries.delete(url);
+        entries.set(url, entry);
+    }
+    return entry;
+}
+
+// Caches an entry, unless its body is larger than announced, evicting the least recently used
+// entries exceeding the limits of the cache
+function store(url, entry) {
+    const maxBodySize = httpNative.http_cache_max_body_size();
+    if (maxBodySize !== undefined && entry.body.byteLength > maxBodySize) {
+        return;
+    }
+    const previous = entries.get(url);
+    if (previous !== undefined) {
+        entries.delete(url);
+        totalSize -= previous.body.byteLength;
+    }
+    entries.set(url, entry);
+    totalSize += entry.body.byteLength;
+
+    const maxEntries = httpNative.http_cache_max_entries();
+    const maxSize = httpNative.http_cache_max_size();
+    for (const [key, evicted] of entries) {
+        if ((maxEntries === undefined || entries.size <= maxEntries) && (maxSize === undefined || totalSize <= maxSize)) {
+            break;
+        }
+        entries.delete(key);
+        totalSize -= evicted.body.byteLength;
+    }
+}
+
+// Whether the `Content-Length` of a response is known and within the limits of the cache
+function fitsCache(rawHeaders) {
+    const value = headerValue(rawHeaders, 'content-length')?.trim();
+    if (value === undefined || !/^\d+$/.test(value)) {
+        return false;
+    }
+    const contentLength = Number(value);
+    const maxBodySize = httpNative.http_cache_max_body_size();
+    const maxSize = httpNative.http_cache_max_size();
+    return (maxBodySize === undefined || contentLength <= maxBodySize)
+        && (maxSize === undefined || contentLength <= maxSize);
+}
+
+// Reads a cached entry like the native response of the HTTP module
+class CachedResponse {
+    constructor(entry) {
+        this.entry = entry;
+    }
+
+    get headers() {
+        return this.entry.headers;
+    }
+
+    get status() {
+        return this.entry.status;
+    }
+
+    get statusText() {
+        return this.entry.statusText ?? '';
+    }
+
+    async arrayBuffer() {
+        return [this.entry.body.slice(0), undefined];
+    }
+
+    async text() {
+        return [new TextDecoder().decode(this.entry.body), undefined];
+    }
+
+    stream() {
+        let chunk = new Uint8Array(this.entry.body.slice(0));
+        return {
+            async pull() {
+                const next = chunk;
+                chunk = undefined;
+                return [next, undefined];
+            }
+        };
+    }
+}
+
+function headerValue(rawHeaders, name) {
+    const values = rawHeaders.filter(([key]) => key.toLowerCase() === name).map(([, value]) => value);
+    return values.length === 0 ? undefined : values.join(', ');
+}
+
+// The directives of a `Cache-Control` header, with the values of the ones having any
+function cacheControl(rawHeaders) {
+    const directives = {};
+    for (const directive of (headerValue(rawHeaders, 'cache-control') ?? '').split(',')) {
+        const [name, value] = directive.split('=', 2);
+        if (name.trim() !== '') {
+            directives[name.trim().toLowerCase()] = value?.trim().replace(/^"(.*)"$/, '$1') ?? true;
+        }
+    }
+    return directives;
+}
+
+function varyNames(entry) {
+    return (headerValue(entry.headers, 'vary') ?? '')
+        .split(',')
+        .map((name) => name.trim().toLowerCase())
+        .filter((name) => name !== '');
+}
+
+function varyMatches(entry, headers) {
+    return Object.entries(entry.vary).every(([name, value]) => headers.get(name) === value);
+}
+
+function isStorable(response) {
+    const rawHeaders = response.nativeResponse.headers;
+    const directives = cacheControl(rawHeaders);
+    if (response.status !== 200 || directives['no-store'] || headerValue(rawHeaders, 'vary')?.includes('*')) {
+        return false;
+    }
+    return directives['max-age'] !== undefined
+        || headerValue(rawHeaders, 'expires') !== undefined
+        || headerValue(rawHeaders, 'etag') !== undefined
+        || headerValue(rawHeaders, 'last-modified') !== undefined;
+}
+
+function isFresh(entry) {
+    const directives = cacheControl(entry.headers);
+    if (directives['no-cache']) {
+        return false;
+    }
+
+    let lifetime = 0;
+    const maxAge = parseInt(directives['max-age'], 10);
+    if (!isNaN(maxAge)) {
+        lifetime = maxAge * 1000;
+    } else {
+        const expires = Date.parse(headerValue(entry.headers, 'expires'));
+        const date = Date.parse(headerValue(entry.headers, 'date')) || entry.responseTime;
+        if (!isNaN(expires)) {
+            lifetime = expires - date;
+        }
+    }
+
+    const initialAge = (parseInt(headerValue(entry.headers, 'age'), 10) || 0) * 1000;
+    const age = initialAge + Date.now() - entry.responseTime;
+    return age < lifetime;
+}
+
+// Adds the conditional headers validating the entry, returning whether there were any
+function addValidators(entry, headers) {
+    if (headers.has('If-None-Match') || headers.has('If-Modified-Since')) {
+        return false;
+    }
+    const etag = headerValue(entry.headers, 'etag');
+    const lastModified = headerValue(entry.headers, 'last-modified');
+    if (etag !== undefined) {
+        headers.set('If-None-Match', etag);
+    }
+    if (lastModified !== undefined) {
+        headers.set('If-Modified-Since', lastModified);
+    }
+    return etag !== undefined || lastModified !== undefined;
+}
+
+// Updates the headers of the entry by the ones of a `304 Not Modified` response
+function updateHeaders(entry, rawHeaders) {
+    const updated = new Set(rawHeaders.map(([name]) => name.toLowerCase()));
+    updated.delete('content-length');
+    entry.headers = [
+        ...entry.headers.filter(([name]) => !updated.has(name.toLowerCase())),
+        ...rawHeaders.filter(([name]) => updated.has(name.toLowerCase())),
+    ];
+}
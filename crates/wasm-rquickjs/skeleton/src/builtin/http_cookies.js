@@ -0,0 +1,122 @@
+// Cookie store of fetch, keeping the cookies set by the responses for the lifetime of the component
+// instance. A simplified version of https://datatracker.ietf.org/doc/html/rfc6265#section-5
+import * as httpNative from '__wasm_rquickjs_builtin/http_native'
+
+// Cookies keyed by `name;domain;path`
+const cookies = new Map();
+
+// Whether the cookie store is used by a fetch with the given `credentials` option
+export function usesCookies(credentials) {
+    return httpNative.cookie_jar_enabled() && credentials !== 'omit';
+}
+
+// Gets the value of the `Cookie` header for a request to the URL, or undefined if no cookies match
+export function cookieHeader(url) {
+    const target = new URL(url);
+    const host = target.hostname.toLowerCase();
+    const secure = target.protocol === 'https:';
+    const now = Date.now();
+
+    const matching = [];
+    for (const [key, cookie] of cookies) {
+        if (cookie.expires !== undefined && cookie.expires <= now) {
+            cookies.delete(key);
+            continue;
+        }
+        const domainMatches = cookie.hostOnly ? host === cookie.domain : domainMatch(host, cookie.domain);
+        if (domainMatches && pathMatch(target.pathname, cookie.path) && (secure || !cookie.secure)) {
+            matching.push(cookie);
+        }
+    }
+    if (matching.length === 0) {
+        return undefined;
+    }
+
+    // Cookies with longer paths are listed first, then the older ones
+    matching.sort((a, b) => b.path.length - a.path.length || a.created - b.created);
+    return matching.map((cookie) => `${cookie.name}=${cookie.value}`).join('; ');
+}
+
+// Stores the cookies of the `Set-Cookie` headers of a response to the URL
+export function storeCookies(url, rawHeaders) {
+    const target = new URL(url);
+    for (const [name, value] of rawHeaders) {
+        if (name.toLowerCase() === 'set-cookie') {
+            storeCookie(target, value);
+        }
+    }
+}
+
+function storeCookie(target, setCookie) {
+    const [pair, ...attributes] = setCookie.split(';');
+    const separator = pair.indexOf('=');
+    if (separator <= 0) {
+        return;
+    }
+    const host = target.hostname.toLowerCase();
+    const cookie = {
+        name: pair.slice(0, separator).trim(),
+        value: pair.slice(separator + 1).trim(),
+        domain: host,
+        hostOnly: true,
+        path: defaultPath(target.pathname),
+        secure: false,
+        expires: undefined,
+        created: Date.now(),
+    };
+
+    let maxAge = undefined;
+    for (const attribute of attributes) {
+        const index = attribute.indexOf('=');
+        const key = (index < 0 ? attribute : attribute.slice(0, index)).trim().toLowerCase();
+        const value = index < 0 ? '' : attribute.slice(index + 1).trim();
+        if (key === 'domain' && value !== '') {
+            const domain = value.replace(/^\./, '').toLowerCase();
+            if (!domainMatch(host, domain)) {
+                return;
+            }
+            cookie.domain = domain;
+            cookie.hostOnly = false;
+        } else if (key === 'path' && value.startsWith('/')) {
+            cookie.path = value;
+        } else if (key === 'secure') {
+            cookie.secure = true;
+        } else if (key === 'max-age' && /^-?\d+$/.test(value)) {
+            maxAge = parseInt(value, 10);
+        } else if (key === 'expires') {
+            const expires = Date.parse(value);
+            if (!isNaN(expires)) {
+                cookie.expires = expires;
+            }
+        }
+    }
+    if (maxAge !== undefined) {
+        cookie.expires = cookie.created + maxAge * 1000;
+    }
+
+    const key = `${cookie.name};${cookie.domain};${cookie.path}`;
+    const existing = cookies.get(key);
+    if (existing !== undefined) {
+        cookie.created = existing.created;
+    }
+    if (cookie.expires !== undefined && cookie.expires <= Date.now()) {
+        cookies.delete(key);
+    } else {
+        cookies.set(key, cookie);
+    }
+}
+
+function domainMatch(host, domain) {
+    return host === domain || host.endsWith(`.${domain}`);
+}
+
+function pathMatch(path, cookiePath) {
+    return path === cookiePath
+        || (path.startsWith(cookiePath) && (cookiePath.endsWith('/') || path[cookiePath.length] === '/'));
+}
+
+// The directory of the request path, used for cookies without a `Path` attribute
+function defaultPath(path) {
+    const index = path.lastIndexOf('/');
+    return index <= 0 ? '/' : path.slice(0, index);
+}
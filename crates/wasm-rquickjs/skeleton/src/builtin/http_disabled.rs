@@ -4,5 +4,8 @@ pub mod native_module {}
 pub const HTTP_JS: &str = "";
 pub const FETCH_BLOB_JS: &str = "";
 pub const FORMDATA_JS: &str = "";
+pub const COOKIES_JS: &str = "";
+pub const CACHE_JS: &str = "";
+pub const EVENT_SOURCE_JS: &str = "";
 
 pub const WIRE_JS: &str = "";
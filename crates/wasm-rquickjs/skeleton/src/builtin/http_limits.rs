@@ -0,0 +1,68 @@
+/// Limits on the HTTP messages sent and received by `fetch`, protecting the small linear memory of
+/// the component from oversized payloads. Limits set to `None` are not checked.
+#[cfg_attr(not(feature = "http"), allow(dead_code))]
+pub struct HttpLimits {
+    /// The maximum size of request bodies in bytes
+    pub max_request_body_size: Option<usize>,
+    /// The maximum size of response bodies in bytes
+    pub max_response_body_size: Option<usize>,
+    /// The maximum number of headers of requests and responses
+    pub max_header_count: Option<usize>,
+}
+
+#[cfg_attr(not(feature = "http"), allow(dead_code))]
+impl HttpLimits {
+    // Only used by the generated code when no limit is configured
+    #[allow(dead_code)]
+    pub const UNLIMITED: HttpLimits = HttpLimits {
+        max_request_body_size: None,
+        max_response_body_size: None,
+        max_header_count: None,
+    };
+
+    /// Gets the limits the crate was generated with, overridden by the environment variables of
+    /// the component. Fails on invalid values of the variables, so a misconfigured limit is not
+    /// silently ignored.
+    pub fn current() -> Result<HttpLimits, String> {
+        let generated = &crate::HTTP_LIMITS;
+        Ok(HttpLimits {
+            max_request_body_size: env_override(
+                "WASM_RQUICKJS_MAX_REQUEST_BODY_SIZE",
+                generated.max_request_body_size,
+            )?,
+            max_response_body_size: env_override(
+                "WASM_RQUICKJS_MAX_RESPONSE_BODY_SIZE",
+                generated.max_response_body_size,
+            )?,
+            max_header_count: env_override(
+                "WASM_RQUICKJS_MAX_HEADER_COUNT",
+                generated.max_header_count,
+            )?,
+        })
+    }
+}
+
+/// Limits on the responses kept by the HTTP cache of `fetch`, with the least recently used ones
+/// evicted when exceeded. Limits set to `None` are not checked.
+#[cfg_attr(not(feature = "http"), allow(dead_code))]
+pub struct HttpCacheLimits {
+    /// The maximum number of cached responses
+    pub max_entries: Option<usize>,
+    /// The maximum total size of the cached response bodies in bytes
+    pub max_size: Option<usize>,
+    /// The maximum size of a cached response body in bytes
+    pub max_body_size: Option<usize>,
+}
+
+/// Gets a limit from an environment variable, which is either a number or `none` to remove the
+/// limit. Unset variables keep the generated limit.
+#[cfg_attr(not(feature = "http"), allow(dead_code))]
+fn env_override(name: &str, generated: Option<usize>) -> Result<Option<usize>, String> {
+    match std::env::var(name) {
+        Ok(value) if value.trim().eq_ignore_ascii_case("none") => Ok(None),
+        Ok(value) => value.trim().parse().map(Some).map_err(|_| {
+            format!("Invalid value `{value}` of {name}, it must be a number or `none`")
+        }),
+        Err(_) => Ok(generated),
+    }
+}
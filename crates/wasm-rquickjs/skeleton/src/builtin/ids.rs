@@ -0,0 +1,134 @@
+use std::cell::Cell;
+use std::thread::LocalKey;
+
+thread_local! {
+    static LAST_UUID_V7: Cell<Option<(u64, u128)>> = const { Cell::new(None) };
+    static LAST_ULID: Cell<Option<(u64, u128)>> = const { Cell::new(None) };
+}
+
+// Native functions generating IDs from `wasi:random`
+#[rquickjs::module(rename = "camelCase")]
+pub mod native_module {
+    #[rquickjs::function]
+    pub fn uuid_v4() -> String {
+        uuid::Builder::from_random_bytes(super::random_bytes())
+            .into_uuid()
+            .to_string()
+    }
+
+    /// A UUID version 7, made of the milliseconds since the Unix epoch and 74 random bits
+    #[rquickjs::function]
+    pub fn uuid_v7(now: f64) -> String {
+        let (millis, random) = super::next(&super::LAST_UUID_V7, now, 74);
+        let value = ((millis as u128) << 80)
+            | (0x7 << 76)
+            | ((random >> 62) << 64)
+            | (0b10 << 62)
+            | (random & ((1 << 62) - 1));
+        uuid::Uuid::from_u128(value).to_string()
+    }
+
+    /// A ULID, made of the milliseconds since the Unix epoch and 80 random bits
+    #[rquickjs::function]
+    pub fn ulid(now: f64) -> String {
+        let (millis, random) = super::next(&super::LAST_ULID, now, 80);
+        super::crockford_base32(((millis as u128) << 80) | random)
+    }
+}
+
+fn random_bytes() -> [u8; 16] {
+    let mut bytes = [0; 16];
+    bytes.copy_from_slice(&wasi::random::random::get_random_bytes(16));
+    bytes
+}
+
+/// Gets the timestamp and the random bits of the next ID. Within the millisecond of the previous
+/// one, or if the clock went back, its random bits are incremented instead, so that the IDs sort in
+/// the order they were generated. New random bits have their highest bit cleared to leave room for
+/// these increments.
+fn next(last: &'static LocalKey<Cell<Option<(u64, u128)>>>, now: f64, bits: u32) -> (u64, u128) {
+    let millis = (now.max(0.0) as u64).min((1 << 48) - 1);
+    let random = || u128::from_be_bytes(random_bytes()) & ((1 << (bits - 1)) - 1);
+    last.with(|last| {
+        let next = match last.get() {
+            Some((last_millis, previous)) if millis <= last_millis => {
+                if previous + 1 < 1 << bits {
+                    (last_millis, previous + 1)
+                } else {
+                    (last_millis + 1, random())
+                }
+            }
+            _ => (millis, random()),
+        };
+        last.set(Some(next));
+        next
+    })
+}
+
+const CROCKFORD_ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// Encodes the 128 bits as the 26 characters of a ULID
+fn crockford_base32(value: u128) -> String {
+    (0..26)
+        .rev()
+        .map(|index| CROCKFORD_ALPHABET[((value >> (index * 5)) & 0x1f) as usize] as char)
+        .collect()
+}
+
+// JS module of the UUID functions
+pub const UUID_JS: &str = r#"
+        import { uuid_v4, uuid_v7 } from '__wasm_rquickjs_builtin/ids_native';
+
+        const PATTERN =
+            /^(?:[0-9a-f]{8}-[0-9a-f]{4}-[1-8][0-9a-f]{3}-[89ab][0-9a-f]{3}-[0-9a-f]{12}|0{8}-0{4}-0{4}-0{4}-0{12}|f{8}-f{4}-f{4}-f{4}-f{12})$/i;
+
+        export const NIL = '00000000-0000-0000-0000-000000000000';
+        export const MAX = 'ffffffff-ffff-ffff-ffff-ffffffffffff';
+
+        export function v4() {
+            return uuid_v4();
+        }
+
+        export function v7() {
+            return uuid_v7(Date.now());
+        }
+
+        export function validate(uuid) {
+            return typeof uuid === 'string' && PATTERN.test(uuid);
+        }
+
+        export function version(uuid) {
+            if (!validate(uuid)) {
+                throw new TypeError('Invalid UUID');
+            }
+            return parseInt(uuid.slice(14, 15), 16);
+        }
+    "#;
+
+// JS module of the ULID functions
+pub const ULID_JS: &str = r#"
+        import { ulid as nativeUlid } from '__wasm_rquickjs_builtin/ids_native';
+
+        const ALPHABET = '0123456789ABCDEFGHJKMNPQRSTVWXYZ';
+        const PATTERN = /^[0-7][0-9A-HJKMNP-TV-Z]{25}$/i;
+
+        export function ulid() {
+            return nativeUlid(Date.now());
+        }
+
+        export function isValid(id) {
+            return typeof id === 'string' && PATTERN.test(id);
+        }
+
+        // The milliseconds since the Unix epoch at which the ULID was generated
+        export function decodeTime(id) {
+            if (!isValid(id)) {
+                throw new TypeError('Invalid ULID');
+            }
+            let time = 0;
+            for (const char of id.slice(0, 10).toUpperCase()) {
+                time = time * 32 + ALPHABET.indexOf(char);
+            }
+            return time;
+        }
+    "#;
@@ -0,0 +1,69 @@
+import { recordedCall } from '__wasm_rquickjs_builtin/import_recording';
+
+// The registered hooks, the first one being the outermost
+const hooks = [];
+
+export function registerImportHook(hook) {
+    if (typeof hook !== 'function') {
+        throw new TypeError('The import hook must be a function');
+    }
+    hooks.push(hook);
+    return () => {
+        const index = hooks.indexOf(hook);
+        if (index >= 0) {
+            hooks.splice(index, 1);
+        }
+    };
+}
+
+// Calls `call` with `args` through the hooks. Each hook gets a `next` function calling the
+// remaining hooks, with the original arguments or the ones passed to it. The innermost layer
+// records or replays the call, if enabled.
+function callThroughHooks(iface, name, args, call) {
+    const active = hooks.slice();
+    const next = (index, currentArgs) => (nextArgs = currentArgs) => index < active.length
+        ? active[index](iface, name, nextArgs, next(index + 1, nextArgs))
+        : recordedCall(iface, name, nextArgs, call);
+    return next(0, args)();
+}
+
+function hookMethods(iface, prefix, target) {
+    for (const key of Object.getOwnPropertyNames(target)) {
+        const descriptor = Object.getOwnPropertyDescriptor(target, key);
+        if (key === 'constructor' || key === 'prototype' || typeof descriptor.value !== 'function') {
+            continue;
+        }
+        const method = descriptor.value;
+        const name = `${prefix}.${key}`;
+        Object.defineProperty(target, key, {
+            ...descriptor,
+            value: function (...args) {
+                return callThroughHooks(iface, name, args, (callArgs) => method.apply(this, callArgs));
+            },
+        });
+    }
+}
+
+export function hookFunction(iface, name, f) {
+    return function (...args) {
+        return callThroughHooks(iface, name, args, (callArgs) => f.apply(this, callArgs));
+    };
+}
+
+// Hooks the methods and static functions of a resource class in place, and returns a proxy of the
+// class hooking its constructor. Resources without constructors only have a prototype.
+export function hookClass(iface, name, cls, prototype) {
+    if (prototype !== undefined) {
+        hookMethods(iface, name, prototype);
+    }
+    if (cls === undefined) {
+        return cls;
+    }
+    hookMethods(iface, name, cls);
+    return new Proxy(cls, {
+        construct(target, args, newTarget) {
+            return callThroughHooks(iface, `new ${name}`, args,
+                (callArgs) => Reflect.construct(target, callArgs, newTarget));
+        },
+    });
+}
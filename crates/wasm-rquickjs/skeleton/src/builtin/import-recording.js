@@ -0,0 +1,113 @@
+import * as native from '__wasm_rquickjs_builtin/import_recording_native';
+
+const mode = native.mode();
+
+// The recorded calls not replayed yet, by the module name of the interface and the function name
+const replayed = new Map();
+
+const key = (iface, fn) => `${iface}#${fn}`;
+
+// Encodes the values JSON cannot represent as objects with a single `$`-prefixed property
+function encode(name, value) {
+    if (typeof value === 'bigint') {
+        return { $bigint: value.toString() };
+    }
+    if (value === undefined) {
+        return { $undefined: true };
+    }
+    if (ArrayBuffer.isView(value)) {
+        return {
+            $bytes: Array.from(new Uint8Array(value.buffer, value.byteOffset, value.byteLength)),
+            type: value.constructor.name,
+        };
+    }
+    if (value instanceof Error) {
+        return { $error: value.message };
+    }
+    if (value !== null && typeof value === 'object' && !Array.isArray(value)
+        && Object.getPrototypeOf(value) !== Object.prototype) {
+        // Resources cannot be recorded, only the name of their class is kept
+        return { $resource: value.constructor?.name ?? 'unknown' };
+    }
+    return value;
+}
+
+function decode(name, value) {
+    if (value === null || typeof value !== 'object') {
+        return value;
+    }
+    if (value.$bigint !== undefined) {
+        return BigInt(value.$bigint);
+    }
+    if (value.$undefined !== undefined) {
+        return undefined;
+    }
+    if (value.$bytes !== undefined) {
+        const bytes = new Uint8Array(value.$bytes);
+        const TypedArray = globalThis[value.type] ?? Uint8Array;
+        return new TypedArray(bytes.buffer, 0, bytes.byteLength / (TypedArray.BYTES_PER_ELEMENT ?? 1));
+    }
+    if (value.$error !== undefined) {
+        return new Error(value.$error);
+    }
+    return value;
+}
+
+function record(entry) {
+    const error = native.record(JSON.stringify(entry, encode));
+    if (error !== undefined) {
+        throw new Error(error);
+    }
+}
+
+if (mode === 'replay') {
+    for (const line of native.recording().split('\n')) {
+        // Anything else written to stderr during the recording is skipped
+        if (!line.startsWith('{')) {
+            continue;
+        }
+        let entry;
+        try {
+            entry = JSON.parse(line, decode);
+        } catch {
+            continue;
+        }
+        if (typeof entry?.iface !== 'string' || typeof entry?.fn !== 'string') {
+            continue;
+        }
+        const entryKey = key(entry.iface, entry.fn);
+        if (!replayed.has(entryKey)) {
+            replayed.set(entryKey, []);
+        }
+        replayed.get(entryKey).push(entry);
+    }
+}
+
+// Calls an imported function, recording the call or replaying a recorded one if enabled
+export function recordedCall(iface, fn, args, call) {
+    switch (mode) {
+        case 'record': {
+            let result;
+            try {
+                result = call(args);
+            } catch (error) {
+                record({ iface, fn, args, error });
+                throw error;
+            }
+            record({ iface, fn, args, result });
+            return result;
+        }
+        case 'replay': {
+            const entry = replayed.get(key(iface, fn))?.shift();
+            if (entry === undefined) {
+                throw new Error(`No recorded call of ${fn} of ${iface} is left to replay`);
+            }
+            if ('error' in entry) {
+                throw entry.error;
+            }
+            return entry.result;
+        }
+        default:
+            return call(args);
+    }
+}
@@ -0,0 +1,59 @@
+use rquickjs::{Ctx, Function, IntoJs, Object, Value};
+
+#[allow(dead_code)]
+const IMPORT_HOOKS_GLOBAL: &str = "__wasm_rquickjs_import_hooks";
+
+pub const IMPORT_HOOKS_JS: &str = include_str!("import-hooks.js");
+
+/// Whether the calls of the imported interfaces go through the hooks registered by
+/// `registerImportHook`
+pub fn is_enabled() -> bool {
+    crate::IMPORT_HOOKS
+}
+
+// JS code exposing the hooking functions to the import modules, used by both the hooks and the
+// recording of the imports
+pub const WIRE_JS: &str = r#"
+        import * as __wasm_rquickjs_import_hooks from '__wasm_rquickjs_builtin/import_hooks';
+        globalThis.__wasm_rquickjs_import_hooks = __wasm_rquickjs_import_hooks;
+    "#;
+
+// JS code defining `registerImportHook`
+pub const REGISTER_WIRE_JS: &str = r#"
+        import { registerImportHook } from '__wasm_rquickjs_builtin/import_hooks';
+        globalThis.registerImportHook = registerImportHook;
+    "#;
+
+/// Wraps a function exported by the module of an imported interface, calling it through the
+/// registered hooks
+#[allow(dead_code)]
+pub fn hook_import_function<'js>(
+    ctx: &Ctx<'js>,
+    interface: &str,
+    name: &str,
+    function: impl IntoJs<'js>,
+) -> rquickjs::Result<Value<'js>> {
+    hooks_module(ctx)?
+        .get::<_, Function>("hookFunction")?
+        .call((interface, name, function.into_js(ctx)?))
+}
+
+/// Wraps the class of a resource exported by the module of an imported interface, calling its
+/// constructor, methods and static functions through the registered hooks
+#[allow(dead_code)]
+pub fn hook_import_class<'js>(
+    ctx: &Ctx<'js>,
+    interface: &str,
+    name: &str,
+    constructor: impl IntoJs<'js>,
+    prototype: Option<Object<'js>>,
+) -> rquickjs::Result<Value<'js>> {
+    hooks_module(ctx)?
+        .get::<_, Function>("hookClass")?
+        .call((interface, name, constructor.into_js(ctx)?, prototype))
+}
+
+#[allow(dead_code)]
+fn hooks_module<'js>(ctx: &Ctx<'js>) -> rquickjs::Result<Object<'js>> {
+    ctx.globals().get(IMPORT_HOOKS_GLOBAL)
+}
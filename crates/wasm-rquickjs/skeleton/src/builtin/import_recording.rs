@@ -0,0 +1,63 @@
+use std::io::Write;
+
+/// How the calls of the imported interfaces are recorded or replayed
+#[allow(dead_code)]
+pub enum ImportRecording {
+    /// The imported interfaces are called without recording them
+    None,
+    /// Every call is written as a JSON line to stderr, or appended to the given file
+    Record(Option<&'static str>),
+    /// The imported interfaces are not called, their results are served from the JSON lines of
+    /// the given recording
+    Replay(&'static str),
+}
+
+pub const IMPORT_RECORDING_JS: &str = include_str!("import-recording.js");
+
+// Native functions for recording and replaying the calls of the imported interfaces
+#[rquickjs::module(rename = "camelCase")]
+pub mod native_module {
+    use super::ImportRecording;
+
+    #[rquickjs::function]
+    pub fn mode() -> &'static str {
+        match crate::IMPORT_RECORDING {
+            ImportRecording::None => "none",
+            ImportRecording::Record(_) => "record",
+            ImportRecording::Replay(_) => "replay",
+        }
+    }
+
+    #[rquickjs::function]
+    pub fn recording() -> &'static str {
+        match crate::IMPORT_RECORDING {
+            ImportRecording::Replay(recording) => recording,
+            _ => "",
+        }
+    }
+
+    #[rquickjs::function]
+    pub fn record(line: String) -> Option<String> {
+        super::write_line(&line).err()
+    }
+}
+
+/// Whether the calls of the imported interfaces are recorded or replayed
+pub fn is_enabled() -> bool {
+    !matches!(crate::IMPORT_RECORDING, ImportRecording::None)
+}
+
+fn write_line(line: &str) -> Result<(), String> {
+    match crate::IMPORT_RECORDING {
+        ImportRecording::Record(Some(path)) => std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .and_then(|mut file| writeln!(file, "{line}"))
+            .map_err(|err| format!("Failed to write the import recording to {path}: {err}")),
+        _ => {
+            eprintln!("{line}");
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,274 @@
+import {
+    canonicalize_locale,
+    compare_strings,
+    format_date_time,
+    format_number
+} from '__wasm_rquickjs_builtin/intl_native';
+
+// A small subset of the ECMAScript Internationalization API, backed by ICU4X. Dates are always
+// formatted in UTC, as components have no access to time zone information.
+
+const DEFAULT_LOCALE = 'en-US';
+const STYLES = ['full', 'long', 'medium', 'short'];
+const SENSITIVITIES = ['base', 'accent', 'case', 'variant'];
+
+function unwrap([value, error]) {
+    if (error !== undefined) {
+        throw new RangeError(error);
+    }
+    return value;
+}
+
+function getCanonicalLocales(locales) {
+    if (locales === undefined) {
+        return [];
+    }
+    const list = typeof locales === 'string' ? [locales] : Array.from(locales);
+    const result = [];
+    for (const tag of list) {
+        const locale = unwrap(canonicalize_locale(String(tag)));
+        if (!result.includes(locale)) {
+            result.push(locale);
+        }
+    }
+    return result;
+}
+
+function resolveLocale(locales) {
+    const canonical = getCanonicalLocales(locales);
+    return canonical.length > 0 ? canonical[0] : DEFAULT_LOCALE;
+}
+
+function supportedLocalesOf(locales) {
+    // The compiled locale data falls back to the closest supported locale, so every valid
+    // locale is supported
+    return getCanonicalLocales(locales);
+}
+
+function option(options, name, allowed, fallback) {
+    const value = options[name];
+    if (value === undefined) {
+        return fallback;
+    }
+    const string = String(value);
+    if (!allowed.includes(string)) {
+        throw new RangeError(`Value ${string} out of range for Intl options property ${name}`);
+    }
+    return string;
+}
+
+function digitsOption(options, name, fallback) {
+    const value = options[name];
+    if (value === undefined) {
+        return fallback;
+    }
+    const digits = Number(value);
+    if (!Number.isInteger(digits) || digits < 0 || digits > 100) {
+        throw new RangeError(`${name} value is out of range.`);
+    }
+    return digits;
+}
+
+function toEpochMillis(date) {
+    const millis = date === undefined ? Date.now() : Number(date instanceof Date ? date.getTime() : date);
+    if (!Number.isFinite(millis)) {
+        throw new RangeError('Invalid time value');
+    }
+    return millis;
+}
+
+class NumberFormat {
+    #locale;
+    #style;
+    #minimumFractionDigits;
+    #maximumFractionDigits;
+    #useGrouping;
+
+    constructor(locales, options = {}) {
+        this.#locale = resolveLocale(locales);
+        this.#style = option(options, 'style', ['decimal', 'percent'], 'decimal');
+        this.#minimumFractionDigits = digitsOption(options, 'minimumFractionDigits', 0);
+        const defaultMaximum = this.#style === 'percent' ? 0 : 3;
+        this.#maximumFractionDigits = digitsOption(
+            options,
+            'maximumFractionDigits',
+            Math.max(this.#minimumFractionDigits, defaultMaximum)
+        );
+        if (this.#minimumFractionDigits > this.#maximumFractionDigits) {
+            throw new RangeError('maximumFractionDigits value is out of range.');
+        }
+        this.#useGrouping = options.useGrouping === undefined ? true : Boolean(options.useGrouping);
+    }
+
+    get format() {
+        return (value) => {
+            const number = Number(value);
+            if (Number.isNaN(number)) {
+                return 'NaN';
+            }
+            if (!Number.isFinite(number)) {
+                return number < 0 ? '-∞' : '∞';
+            }
+            const scaled = this.#style === 'percent' ? number * 100 : number;
+            const formatted = unwrap(format_number(
+                this.#locale,
+                scaled,
+                this.#minimumFractionDigits,
+                this.#maximumFractionDigits,
+                this.#useGrouping
+            ));
+            return this.#style === 'percent' ? `${formatted}%` : formatted;
+        };
+    }
+
+    resolvedOptions() {
+        return {
+            locale: this.#locale,
+            numberingSystem: 'latn',
+            style: this.#style,
+            minimumFractionDigits: this.#minimumFractionDigits,
+            maximumFractionDigits: this.#maximumFractionDigits,
+            useGrouping: this.#useGrouping ? 'auto' : false,
+        };
+    }
+
+    static supportedLocalesOf(locales) {
+        return supportedLocalesOf(locales);
+    }
+}
+
+class DateTimeFormat {
+    #locale;
+    #dateStyle;
+    #timeStyle;
+
+    constructor(locales, options = {}, defaults = 'date') {
+        this.#locale = resolveLocale(locales);
+        const timeZone = options.timeZone === undefined ? 'UTC' : String(options.timeZone);
+        if (timeZone.toUpperCase() !== 'UTC') {
+            throw new RangeError(`Unsupported time zone specified ${timeZone}, only UTC is supported`);
+        }
+        this.#dateStyle = option(options, 'dateStyle', STYLES, undefined);
+        this.#timeStyle = option(options, 'timeStyle', STYLES, undefined);
+
+        // Individual date and time fields are approximated by the closest style
+        if (this.#dateStyle === undefined && this.#timeStyle === undefined) {
+            if (options.weekday !== undefined) {
+                this.#dateStyle = 'full';
+            } else if (options.month === 'long') {
+                this.#dateStyle = 'long';
+            } else if (options.month === 'short') {
+                this.#dateStyle = 'medium';
+            } else if (options.year !== undefined || options.month !== undefined || options.day !== undefined) {
+                this.#dateStyle = 'short';
+            }
+            if (options.second !== undefined) {
+                this.#timeStyle = 'medium';
+            } else if (options.hour !== undefined || options.minute !== undefined) {
+                this.#timeStyle = 'short';
+            }
+        }
+        if (this.#dateStyle === undefined && this.#timeStyle === undefined) {
+            if (defaults !== 'time') {
+                this.#dateStyle = 'short';
+            }
+            if (defaults !== 'date') {
+                this.#timeStyle = 'medium';
+            }
+        }
+    }
+
+    get format() {
+        return (date) => unwrap(format_date_time(
+            this.#locale,
+            toEpochMillis(date),
+            this.#dateStyle,
+            this.#timeStyle
+        ));
+    }
+
+    resolvedOptions() {
+        const result = {
+            locale: this.#locale,
+            calendar: 'gregory',
+            numberingSystem: 'latn',
+            timeZone: 'UTC',
+        };
+        if (this.#dateStyle !== undefined) {
+            result.dateStyle = this.#dateStyle;
+        }
+        if (this.#timeStyle !== undefined) {
+            result.timeStyle = this.#timeStyle;
+        }
+        return result;
+    }
+
+    static supportedLocalesOf(locales) {
+        return supportedLocalesOf(locales);
+    }
+}
+
+class Collator {
+    #locale;
+    #sensitivity;
+    #numeric;
+
+    constructor(locales, options = {}) {
+        this.#locale = resolveLocale(locales);
+        this.#sensitivity = option(options, 'sensitivity', SENSITIVITIES, 'variant');
+        this.#numeric = Boolean(options.numeric);
+    }
+
+    get compare() {
+        return (left, right) => unwrap(compare_strings(
+            this.#locale,
+            String(left),
+            String(right),
+            this.#sensitivity,
+            this.#numeric
+        ));
+    }
+
+    resolvedOptions() {
+        return {
+            locale: this.#locale,
+            usage: 'sort',
+            sensitivity: this.#sensitivity,
+            ignorePunctuation: false,
+            collation: 'default',
+            numeric: this.#numeric,
+            caseFirst: 'false',
+        };
+    }
+
+    static supportedLocalesOf(locales) {
+        return supportedLocalesOf(locales);
+    }
+}
+
+export const Intl = {
+    NumberFormat,
+    DateTimeFormat,
+    Collator,
+    getCanonicalLocales,
+};
+
+// Replaces the locale-dependent methods of the builtin prototypes, which ignore the locale in
+// QuickJS, with ones using the Intl implementation
+export function installLocaleMethods() {
+    Number.prototype.toLocaleString = function (locales, options) {
+        return new NumberFormat(locales, options).format(this);
+    };
+    Date.prototype.toLocaleString = function (locales, options) {
+        return new DateTimeFormat(locales, options, 'all').format(this);
+    };
+    Date.prototype.toLocaleDateString = function (locales, options) {
+        return new DateTimeFormat(locales, options, 'date').format(this);
+    };
+    Date.prototype.toLocaleTimeString = function (locales, options) {
+        return new DateTimeFormat(locales, options, 'time').format(this);
+    };
+    String.prototype.localeCompare = function (that, locales, options) {
+        return new Collator(locales, options).compare(this, that);
+    };
+}
@@ -0,0 +1,275 @@
+use fixed_decimal::{Decimal, FloatPrecision};
+use icu_collator::options::{CaseLevel, CollatorOptions, Strength};
+use icu_collator::preferences::CollationNumericOrdering;
+use icu_collator::{Collator, CollatorBorrowed, CollatorPreferences};
+use icu_datetime::DateTimeFormatter;
+use icu_datetime::fieldsets::builder::{DateFields, FieldSetBuilder};
+use icu_datetime::fieldsets::enums::CompositeDateTimeFieldSet;
+use icu_datetime::input::{Date, DateTime, Time};
+use icu_datetime::options::{Length, TimePrecision};
+use icu_decimal::DecimalFormatter;
+use icu_decimal::options::{DecimalFormatterOptions, GroupingStrategy};
+use icu_locale_core::Locale;
+use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+// Native functions for the Intl implementation
+#[rquickjs::module(rename = "camelCase")]
+pub mod native_module {
+    use rquickjs::prelude::List;
+
+    #[rquickjs::function]
+    pub fn canonicalize_locale(tag: String) -> List<(Option<String>, Option<String>)> {
+        to_list(super::parse_locale(&tag).map(|locale| locale.to_string()))
+    }
+
+    #[rquickjs::function]
+    pub fn format_number(
+        locale: String,
+        value: f64,
+        minimum_fraction_digits: u8,
+        maximum_fraction_digits: u8,
+        use_grouping: bool,
+    ) -> List<(Option<String>, Option<String>)> {
+        to_list(super::format_number(
+            &locale,
+            value,
+            minimum_fraction_digits,
+            maximum_fraction_digits,
+            use_grouping,
+        ))
+    }
+
+    #[rquickjs::function]
+    pub fn format_date_time(
+        locale: String,
+        epoch_millis: f64,
+        date_style: Option<String>,
+        time_style: Option<String>,
+    ) -> List<(Option<String>, Option<String>)> {
+        to_list(super::format_date_time(
+            &locale,
+            epoch_millis,
+            date_style.as_deref(),
+            time_style.as_deref(),
+        ))
+    }
+
+    #[rquickjs::function]
+    pub fn compare_strings(
+        locale: String,
+        left: String,
+        right: String,
+        sensitivity: String,
+        numeric: bool,
+    ) -> List<(Option<i32>, Option<String>)> {
+        to_list(super::compare_strings(
+            &locale,
+            &left,
+            &right,
+            &sensitivity,
+            numeric,
+        ))
+    }
+
+    fn to_list<T>(result: Result<T, String>) -> List<(Option<T>, Option<String>)> {
+        match result {
+            Ok(value) => List((Some(value), None)),
+            Err(error) => List((None, Some(error))),
+        }
+    }
+}
+
+// The formatters and collators are created with the data compiled into the component. Creating
+// them is relatively expensive, so they are reused for the same locale and options.
+thread_local! {
+    static DECIMAL_FORMATTERS: RefCell<HashMap<(String, bool), Rc<DecimalFormatter>>> =
+        RefCell::new(HashMap::new());
+    static DATE_TIME_FORMATTERS: RefCell<HashMap<(String, Option<String>, Option<String>), Rc<DateTimeFormatter<CompositeDateTimeFieldSet>>>> =
+        RefCell::new(HashMap::new());
+    static COLLATORS: RefCell<HashMap<(String, String, bool), Rc<CollatorBorrowed<'static>>>> =
+        RefCell::new(HashMap::new());
+}
+
+pub(super) fn parse_locale(tag: &str) -> Result<Locale, String> {
+    Locale::try_from_str(tag).map_err(|_| format!("Incorrect locale information provided: {tag}"))
+}
+
+pub(super) fn cached<K: Eq + std::hash::Hash, V>(
+    cache: &'static std::thread::LocalKey<RefCell<HashMap<K, Rc<V>>>>,
+    key: K,
+    create: impl FnOnce() -> Result<V, String>,
+) -> Result<Rc<V>, String> {
+    if let Some(value) = cache.with_borrow(|cache| cache.get(&key).cloned()) {
+        return Ok(value);
+    }
+    let value = Rc::new(create()?);
+    cache.with_borrow_mut(|cache| cache.insert(key, value.clone()));
+    Ok(value)
+}
+
+pub(super) fn format_number(
+    locale: &str,
+    value: f64,
+    minimum_fraction_digits: u8,
+    maximum_fraction_digits: u8,
+    use_grouping: bool,
+) -> Result<String, String> {
+    let formatter = cached(
+        &DECIMAL_FORMATTERS,
+        (locale.to_string(), use_grouping),
+        || {
+            let locale = parse_locale(locale)?;
+            let mut options = DecimalFormatterOptions::default();
+            options.grouping_strategy = Some(if use_grouping {
+                GroupingStrategy::Auto
+            } else {
+                GroupingStrategy::Never
+            });
+            DecimalFormatter::try_new((&locale).into(), options)
+                .map_err(|err| format!("Failed to create number formatter: {err}"))
+        },
+    )?;
+
+    let mut decimal = Decimal::try_from_f64(value, FloatPrecision::RoundTrip)
+        .map_err(|err| format!("Cannot format number {value}: {err}"))?;
+    decimal.round(-i16::from(maximum_fraction_digits));
+    decimal.absolute.trim_end();
+    decimal
+        .absolute
+        .pad_end(-i16::from(minimum_fraction_digits));
+    Ok(formatter.format(&decimal).to_string())
+}
+
+pub(super) fn format_date_time(
+    locale: &str,
+    epoch_millis: f64,
+    date_style: Option<&str>,
+    time_style: Option<&str>,
+) -> Result<String, String> {
+    let formatter = cached(
+        &DATE_TIME_FORMATTERS,
+        (
+            locale.to_string(),
+            date_style.map(str::to_string),
+            time_style.map(str::to_string),
+        ),
+        || {
+            let locale = parse_locale(locale)?;
+            let length = |style: &str| match style {
+                "full" | "long" => Length::Long,
+                "medium" => Length::Medium,
+                _ => Length::Short,
+            };
+            let mut builder = FieldSetBuilder::new();
+            if let Some(style) = date_style {
+                builder.length = Some(length(style));
+                builder.date_fields = Some(if style == "full" {
+                    DateFields::YMDE
+                } else {
+                    DateFields::YMD
+                });
+            }
+            if let Some(style) = time_style {
+                builder.length.get_or_insert(length(style));
+                builder.time_precision = Some(if style == "short" {
+                    TimePrecision::Minute
+                } else {
+                    TimePrecision::Second
+                });
+            }
+            let field_set = builder
+                .build_composite_datetime()
+                .map_err(|err| format!("Unsupported date and time styles: {err}"))?;
+            DateTimeFormatter::try_new((&locale).into(), field_set)
+                .map_err(|err| format!("Failed to create date and time formatter: {err}"))
+        },
+    )?;
+
+    // Dates are always formatted in UTC, as there is no time zone information available
+    let millis = epoch_millis as i64;
+    let days = millis.div_euclid(86_400_000);
+    let millis_of_day = millis.rem_euclid(86_400_000);
+    let (year, month, day) = civil_from_days(days);
+    let datetime = DateTime {
+        date: Date::try_new_iso(year, month, day).map_err(|err| err.to_string())?,
+        time: Time::try_new(
+            (millis_of_day / 3_600_000) as u8,
+            (millis_of_day / 60_000 % 60) as u8,
+            (millis_of_day / 1000 % 60) as u8,
+            (millis_of_day % 1000) as u32 * 1_000_000,
+        )
+        .map_err(|err| err.to_string())?,
+    };
+    Ok(formatter.format(&datetime).to_string())
+}
+
+/// Converts days since the Unix epoch to a proleptic Gregorian (year, month, day), using
+/// the algorithm from http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+fn civil_from_days(days: i64) -> (i32, u8, u8) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year as i32, month as u8, day as u8)
+}
+
+fn compare_strings(
+    locale: &str,
+    left: &str,
+    right: &str,
+    sensitivity: &str,
+    numeric: bool,
+) -> Result<i32, String> {
+    let collator = cached(
+        &COLLATORS,
+        (locale.to_string(), sensitivity.to_string(), numeric),
+        || {
+            let locale = parse_locale(locale)?;
+            let mut preferences: CollatorPreferences = (&locale).into();
+            if numeric {
+                preferences.numeric_ordering = Some(CollationNumericOrdering::True);
+            }
+            let mut options = CollatorOptions::default();
+            match sensitivity {
+                "base" => options.strength = Some(Strength::Primary),
+                "accent" => options.strength = Some(Strength::Secondary),
+                "case" => {
+                    options.strength = Some(Strength::Primary);
+                    options.case_level = Some(CaseLevel::On);
+                }
+                _ => options.strength = Some(Strength::Tertiary),
+            }
+            Collator::try_new(preferences, options)
+                .map_err(|err| format!("Failed to create collator: {err}"))
+        },
+    )?;
+
+    Ok(match collator.compare(left, right) {
+        Ordering::Less => -1,
+        Ordering::Equal => 0,
+        Ordering::Greater => 1,
+    })
+}
+
+// JS functions for the Intl implementation
+pub const INTL_JS: &str = include_str!("intl.js");
+
+// JS code wiring the Intl module into the global context
+pub const WIRE_JS: &str = r#"
+        import * as __wasm_rquickjs_intl from '__wasm_rquickjs_builtin/intl';
+        globalThis.Intl = __wasm_rquickjs_intl.Intl;
+        __wasm_rquickjs_intl.installLocaleMethods();
+    "#;
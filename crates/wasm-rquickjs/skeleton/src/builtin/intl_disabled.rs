@@ -0,0 +1,6 @@
+#[rquickjs::module]
+pub mod native_module {}
+
+pub const INTL_JS: &str = "";
+
+pub const WIRE_JS: &str = "";
@@ -0,0 +1,342 @@
+import { JsonTokenizer, JsonWriter } from '__wasm_rquickjs_builtin/json_stream_native';
+import * as streams from '__wasm_rquickjs_builtin/streams';
+
+const START_OBJECT = 0;
+const END_OBJECT = 1;
+const START_ARRAY = 2;
+const END_ARRAY = 3;
+const KEY = 4;
+const VALUE = 5;
+
+const DEFAULT_CHUNK_SIZE = 65536;
+
+function toChunk(chunk) {
+    if (typeof chunk === 'string' || chunk instanceof Uint8Array) {
+        return chunk;
+    }
+    if (chunk instanceof ArrayBuffer) {
+        return new Uint8Array(chunk);
+    }
+    if (ArrayBuffer.isView(chunk)) {
+        return new Uint8Array(chunk.buffer, chunk.byteOffset, chunk.byteLength);
+    }
+    throw new TypeError('Expected a string, an ArrayBuffer or a typed array chunk');
+}
+
+// A single string or byte array is one chunk, anything else is iterated asynchronously
+function chunksOf(source) {
+    if (typeof source === 'string' || source instanceof ArrayBuffer || ArrayBuffer.isView(source)) {
+        return [source];
+    }
+    return source;
+}
+
+/**
+ * Incremental JSON parser calling the given handlers for each token, without holding the whole
+ * text in memory. The handlers are all optional: onStartObject(), onEndObject(), onStartArray(),
+ * onEndArray(), onKey(key) and onValue(value), the latter for strings, numbers, booleans and null.
+ */
+export class JsonParser {
+    constructor(handlers = {}, options = {}) {
+        this._handlers = handlers;
+        this._tokenizer = new JsonTokenizer(!!options.multipleValues);
+    }
+
+    /** Number of objects and arrays currently open */
+    get depth() {
+        return this._tokenizer.depth;
+    }
+
+    /** Parses a chunk of the text, given as a string or as UTF-8 bytes */
+    write(chunk) {
+        this._dispatch(this._tokenizer.write(toChunk(chunk)));
+    }
+
+    /** Signals the end of the text, throwing a SyntaxError if it is incomplete */
+    end() {
+        this._dispatch(this._tokenizer.end());
+    }
+
+    /** A WritableStream feeding the parser, so a ReadableStream can be piped into it */
+    get writable() {
+        return new streams.WritableStream({
+            write: (chunk) => this.write(chunk),
+            close: () => this.end(),
+        });
+    }
+
+    _dispatch(events) {
+        const handlers = this._handlers;
+        for (let i = 0; i < events.length; i += 2) {
+            switch (events[i]) {
+                case START_OBJECT:
+                    handlers.onStartObject?.();
+                    break;
+                case END_OBJECT:
+                    handlers.onEndObject?.();
+                    break;
+                case START_ARRAY:
+                    handlers.onStartArray?.();
+                    break;
+                case END_ARRAY:
+                    handlers.onEndArray?.();
+                    break;
+                case KEY:
+                    handlers.onKey?.(events[i + 1]);
+                    break;
+                case VALUE:
+                    handlers.onValue?.(events[i + 1]);
+                    break;
+            }
+        }
+    }
+}
+
+// Parser handlers assembling values, emitting the ones completed at `emitDepth` instead of
+// adding them to their parent. With `itemsOnly`, the top-level value must be an array.
+class ValueBuilder {
+    constructor(emitDepth, emit, itemsOnly) {
+        this._stack = [];
+        this._keys = [];
+        this._emitDepth = emitDepth;
+        this._emit = emit;
+        this._itemsOnly = itemsOnly;
+    }
+
+    onStartObject() {
+        this._open({});
+    }
+
+    onStartArray() {
+        this._open([]);
+    }
+
+    onEndObject() {
+        this._close();
+    }
+
+    onEndArray() {
+        this._close();
+    }
+
+    onKey(key) {
+        this._keys[this._keys.length - 1] = key;
+    }
+
+    onValue(value) {
+        this._checkItemsOnly();
+        this._add(value);
+    }
+
+    _open(container) {
+        if (!Array.isArray(container)) {
+            this._checkItemsOnly();
+        }
+        this._stack.push(container);
+        this._keys.push(undefined);
+    }
+
+    _close() {
+        const value = this._stack.pop();
+        this._keys.pop();
+        this._add(value);
+    }
+
+    _checkItemsOnly() {
+        if (this._itemsOnly && this._stack.length === 0) {
+            throw new TypeError('Expected the JSON text to be an array');
+        }
+    }
+
+    _add(value) {
+        const depth = this._stack.length;
+        if (depth === this._emitDepth) {
+            this._emit(value);
+        } else if (depth > this._emitDepth) {
+            const parent = this._stack[depth - 1];
+            if (Array.isArray(parent)) {
+                parent.push(value);
+            } else {
+                const key = this._keys[depth - 1];
+                if (key === '__proto__') {
+                    Object.defineProperty(parent, key, {
+                        value,
+                        writable: true,
+                        enumerable: true,
+                        configurable: true,
+                    });
+                } else {
+                    parent[key] = value;
+                }
+            }
+        }
+    }
+}
+
+/**
+ * Parses a JSON text given as a string, bytes, a ReadableStream or an (async) iterable of chunks,
+ * without first concatenating it into a single string
+ */
+export async function parse(source) {
+    let result;
+    const parser = new JsonParser(new ValueBuilder(0, (value) => {
+        result = value;
+    }));
+    for await (const chunk of chunksOf(source)) {
+        parser.write(chunk);
+    }
+    parser.end();
+    return result;
+}
+
+/**
+ * Parses a JSON text whose top-level value is an array, yielding its items one by one as soon as
+ * they are complete, so the whole array is never held in memory
+ */
+export async function* parseItems(source) {
+    const items = [];
+    const parser = new JsonParser(new ValueBuilder(1, (item) => items.push(item), true));
+    for await (const chunk of chunksOf(source)) {
+        parser.write(chunk);
+        yield* items.splice(0);
+    }
+    parser.end();
+    yield* items.splice(0);
+}
+
+/**
+ * Parses a sequence of whitespace-separated JSON values, such as newline-delimited JSON, yielding
+ * them one by one
+ */
+export async function* parseValues(source) {
+    const values = [];
+    const parser = new JsonParser(
+        new ValueBuilder(0, (value) => values.push(value)),
+        { multipleValues: true },
+    );
+    for await (const chunk of chunksOf(source)) {
+        parser.write(chunk);
+        yield* values.splice(0);
+    }
+    parser.end();
+    yield* values.splice(0);
+}
+
+// Applies toJSON and unwraps boxed primitives, like JSON.stringify
+function prepare(value, key) {
+    if (value !== null && (typeof value === 'object' || typeof value === 'bigint')
+        && typeof value.toJSON === 'function') {
+        value = value.toJSON(key);
+    }
+    if (value instanceof Number) {
+        return Number(value);
+    }
+    if (value instanceof String) {
+        return String(value);
+    }
+    if (value instanceof Boolean) {
+        return value.valueOf();
+    }
+    return value;
+}
+
+function isSerializable(value) {
+    return value !== undefined && typeof value !== 'function' && typeof value !== 'symbol';
+}
+
+/**
+ * Serializes a value like JSON.stringify (without the replacer and space arguments), yielding the
+ * text in chunks of about `options.chunkSize` characters (64 KiB by default) instead of building
+ * a single string. Yields nothing for values JSON.stringify returns undefined for.
+ */
+export function* stringifyChunks(value, options = {}) {
+    const chunkSize = options.chunkSize ?? DEFAULT_CHUNK_SIZE;
+    const writer = new JsonWriter();
+    const ancestors = new Set();
+
+    function* visit(value) {
+        switch (typeof value) {
+            case 'string':
+                writer.string(value);
+                return;
+            case 'number':
+                writer.raw(Number.isFinite(value) ? String(value) : 'null');
+                return;
+            case 'boolean':
+                writer.raw(value ? 'true' : 'false');
+                return;
+            case 'bigint':
+                throw new TypeError('Do not know how to serialize a BigInt');
+        }
+        if (value === null) {
+            writer.raw('null');
+            return;
+        }
+        if (ancestors.has(value)) {
+            throw new TypeError('Converting circular structure to JSON');
+        }
+        ancestors.add(value);
+        if (Array.isArray(value)) {
+            writer.beginArray();
+            for (let i = 0; i < value.length; i++) {
+                const item = prepare(value[i], String(i));
+                if (isSerializable(item)) {
+                    yield* visit(item);
+                } else {
+                    writer.raw('null');
+                }
+                if (writer.length >= chunkSize) {
+                    yield writer.take();
+                }
+            }
+            writer.endArray();
+        } else {
+            writer.beginObject();
+            for (const key of Object.keys(value)) {
+                const item = prepare(value[key], key);
+                if (isSerializable(item)) {
+                    writer.key(key);
+                    yield* visit(item);
+                    if (writer.length >= chunkSize) {
+                        yield writer.take();
+                    }
+                }
+            }
+            writer.endObject();
+        }
+        ancestors.delete(value);
+    }
+
+    const root = prepare(value, '');
+    if (isSerializable(root)) {
+        yield* visit(root);
+        const rest = writer.take();
+        if (rest.length > 0) {
+            yield rest;
+        }
+    }
+}
+
+/** Serializes a value like stringifyChunks, as a ReadableStream of strings */
+export function stringifyStream(value, options = {}) {
+    const chunks = stringifyChunks(value, options);
+    return new streams.ReadableStream({
+        pull(controller) {
+            const { value, done } = chunks.next();
+            if (done) {
+                controller.close();
+            } else {
+                controller.enqueue(value);
+            }
+        },
+    });
+}
+
+export default {
+    JsonParser,
+    parse,
+    parseItems,
+    parseValues,
+    stringifyChunks,
+    stringifyStream,
+};
@@ -0,0 +1,621 @@
+use rquickjs::{Array, Ctx, IntoJs, Value};
+
+// Native implementation of the incremental JSON tokenizer and writer
+#[rquickjs::module(rename = "camelCase")]
+pub mod native_module {
+    use rquickjs::class::Trace;
+    use rquickjs::{Array, Ctx, Exception, JsLifetime, TypedArray, Value};
+
+    #[derive(JsLifetime, Trace)]
+    #[rquickjs::class(rename = "JsonTokenizer")]
+    pub struct JsJsonTokenizer {
+        #[qjs(skip_trace)]
+        tokenizer: super::Tokenizer,
+    }
+
+    #[rquickjs::methods(rename_all = "camelCase")]
+    impl JsJsonTokenizer {
+        #[qjs(constructor)]
+        pub fn new(multiple_values: bool) -> Self {
+            Self {
+                tokenizer: super::Tokenizer::new(multiple_values),
+            }
+        }
+
+        #[qjs(get)]
+        pub fn depth(&self) -> usize {
+            self.tokenizer.stack.len()
+        }
+
+        pub fn write<'js>(
+            &mut self,
+            chunk: Value<'js>,
+            ctx: Ctx<'js>,
+        ) -> rquickjs::Result<Array<'js>> {
+            let mut events = Vec::new();
+            let result = if let Some(string) = chunk.as_string() {
+                let string = string.to_string()?;
+                self.tokenizer.feed(string.as_bytes(), &mut events)
+            } else if let Ok(bytes) = TypedArray::<u8>::from_value(chunk) {
+                let bytes = bytes
+                    .as_bytes()
+                    .ok_or_else(|| Exception::throw_type(&ctx, "The written chunk is detached"))?;
+                self.tokenizer.feed(bytes, &mut events)
+            } else {
+                return Err(Exception::throw_type(
+                    &ctx,
+                    "Expected a string or an Uint8Array chunk",
+                ));
+            };
+            super::into_js_events(&ctx, result, events)
+        }
+
+        pub fn end<'js>(&mut self, ctx: Ctx<'js>) -> rquickjs::Result<Array<'js>> {
+            let mut events = Vec::new();
+            let result = self.tokenizer.finish(&mut events);
+            super::into_js_events(&ctx, result, events)
+        }
+    }
+
+    #[derive(JsLifetime, Trace)]
+    #[rquickjs::class(rename = "JsonWriter")]
+    pub struct JsJsonWriter {
+        #[qjs(skip_trace)]
+        writer: super::Writer,
+    }
+
+    #[rquickjs::methods(rename_all = "camelCase")]
+    impl JsJsonWriter {
+        #[qjs(constructor)]
+        pub fn new() -> Self {
+            Self {
+                writer: super::Writer::default(),
+            }
+        }
+
+        #[qjs(get)]
+        pub fn length(&self) -> usize {
+            self.writer.output.len()
+        }
+
+        pub fn begin_object(&mut self) {
+            self.writer.begin('{');
+        }
+
+        pub fn end_object(&mut self) {
+            self.writer.end('}');
+        }
+
+        pub fn begin_array(&mut self) {
+            self.writer.begin('[');
+        }
+
+        pub fn end_array(&mut self) {
+            self.writer.end(']');
+        }
+
+        pub fn key(&mut self, key: String) {
+            self.writer.key(&key);
+        }
+
+        pub fn string(&mut self, value: String) {
+            self.writer.separator();
+            self.writer.string(&value);
+        }
+
+        pub fn raw(&mut self, value: String) {
+            self.writer.separator();
+            self.writer.output.push_str(&value);
+        }
+
+        pub fn take(&mut self) -> String {
+            std::mem::take(&mut self.writer.output)
+        }
+    }
+}
+
+const START_OBJECT: i32 = 0;
+const END_OBJECT: i32 = 1;
+const START_ARRAY: i32 = 2;
+const END_ARRAY: i32 = 3;
+const KEY: i32 = 4;
+const VALUE: i32 = 5;
+
+enum Event {
+    StartObject,
+    EndObject,
+    StartArray,
+    EndArray,
+    Key(String),
+    Value(Scalar),
+}
+
+enum Scalar {
+    String(String),
+    Number(f64),
+    Bool(bool),
+    Null,
+}
+
+/// Converts the events to a flat JS array of `kind, payload` pairs, or throws a `SyntaxError`.
+/// The events preceding the error are not reported.
+fn into_js_events<'js>(
+    ctx: &Ctx<'js>,
+    result: Result<(), String>,
+    events: Vec<Event>,
+) -> rquickjs::Result<Array<'js>> {
+    if let Err(error) = result {
+        return Err(rquickjs::Exception::throw_syntax(ctx, &error));
+    }
+
+    let array = Array::new(ctx.clone())?;
+    let mut index = 0;
+    for event in events {
+        let (kind, payload) = match event {
+            Event::StartObject => (START_OBJECT, Value::new_undefined(ctx.clone())),
+            Event::EndObject => (END_OBJECT, Value::new_undefined(ctx.clone())),
+            Event::StartArray => (START_ARRAY, Value::new_undefined(ctx.clone())),
+            Event::EndArray => (END_ARRAY, Value::new_undefined(ctx.clone())),
+            Event::Key(key) => (KEY, key.into_js(ctx)?),
+            Event::Value(Scalar::String(value)) => (VALUE, value.into_js(ctx)?),
+            Event::Value(Scalar::Number(value)) => (VALUE, value.into_js(ctx)?),
+            Event::Value(Scalar::Bool(value)) => (VALUE, value.into_js(ctx)?),
+            Event::Value(Scalar::Null) => (VALUE, Value::new_null(ctx.clone())),
+        };
+        array.set(index, kind)?;
+        array.set(index + 1, payload)?;
+        index += 2;
+    }
+    Ok(array)
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Container {
+    Object,
+    Array,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Expect {
+    Value,
+    FirstValueOrEnd,
+    FirstKeyOrEnd,
+    Key,
+    Colon,
+    CommaOrEnd,
+    /// Only whitespace, or the next value if multiple top-level values are allowed
+    End,
+}
+
+enum Token {
+    None,
+    String(StringToken),
+    Number(Vec<u8>),
+    Literal { text: &'static [u8], matched: usize },
+}
+
+/// A push-based JSON tokenizer, accepting the input in chunks of any size. Strings and numbers
+/// split between chunks are buffered until they are complete.
+struct Tokenizer {
+    stack: Vec<Container>,
+    expect: Expect,
+    token: Token,
+    position: usize,
+    multiple_values: bool,
+    error: Option<String>,
+}
+
+impl Tokenizer {
+    fn new(multiple_values: bool) -> Self {
+        Self {
+            stack: Vec::new(),
+            expect: if multiple_values {
+                Expect::End
+            } else {
+                Expect::Value
+            },
+            token: Token::None,
+            position: 0,
+            multiple_values,
+            error: None,
+        }
+    }
+
+    fn feed(&mut self, input: &[u8], events: &mut Vec<Event>) -> Result<(), String> {
+        self.check_failed()?;
+        for &byte in input {
+            if let Err(error) = self.byte(byte, events) {
+                return Err(self.fail(format!("{error} in JSON at position {}", self.position)));
+            }
+            self.position += 1;
+        }
+        Ok(())
+    }
+
+    fn finish(&mut self, events: &mut Vec<Event>) -> Result<(), String> {
+        self.check_failed()?;
+        if matches!(self.token, Token::Number(_))
+            && let Err(error) = self.finish_number(events)
+        {
+            return Err(self.fail(format!("{error} in JSON at position {}", self.position)));
+        }
+        if !matches!(self.token, Token::None) || self.expect != Expect::End {
+            return Err(self.fail("Unexpected end of JSON input".to_string()));
+        }
+        Ok(())
+    }
+
+    fn check_failed(&self) -> Result<(), String> {
+        match &self.error {
+            Some(error) => Err(error.clone()),
+            None => Ok(()),
+        }
+    }
+
+    fn fail(&mut self, error: String) -> String {
+        self.error = Some(error.clone());
+        error
+    }
+
+    fn byte(&mut self, byte: u8, events: &mut Vec<Event>) -> Result<(), String> {
+        match &mut self.token {
+            Token::String(string) => {
+                if string.push(byte)? {
+                    let Token::String(string) = std::mem::replace(&mut self.token, Token::None)
+                    else {
+                        unreachable!()
+                    };
+                    if string.is_key {
+                        events.push(Event::Key(string.finish()));
+                        self.expect = Expect::Colon;
+                    } else {
+                        self.value_done(Event::Value(Scalar::String(string.finish())), events);
+                    }
+                }
+                return Ok(());
+            }
+            Token::Number(digits) => {
+                if matches!(byte, b'0'..=b'9' | b'-' | b'+' | b'.' | b'e' | b'E') {
+                    digits.push(byte);
+                    return Ok(());
+                }
+                self.finish_number(events)?;
+            }
+            Token::Literal { text, matched } => {
+                if byte != text[*matched] {
+                    return Err(unexpected_character(byte));
+                }
+                *matched += 1;
+                if *matched == text.len() {
+                    let value = match *text {
+                        b"true" => Scalar::Bool(true),
+                        b"false" => Scalar::Bool(false),
+                        _ => Scalar::Null,
+                    };
+                    self.token = Token::None;
+                    self.value_done(Event::Value(value), events);
+                }
+                return Ok(());
+            }
+            Token::None => {}
+        }
+        self.structural(byte, events)
+    }
+
+    fn structural(&mut self, byte: u8, events: &mut Vec<Event>) -> Result<(), String> {
+        if matches!(byte, b' ' | b'\t' | b'\n' | b'\r') {
+            return Ok(());
+        }
+        match self.expect {
+            Expect::Colon if byte == b':' => self.expect = Expect::Value,
+            Expect::CommaOrEnd => match (byte, self.stack.last()) {
+                (b',', Some(Container::Object)) => self.expect = Expect::Key,
+                (b',', Some(Container::Array)) => self.expect = Expect::Value,
+                (b'}', Some(Container::Object)) | (b']', Some(Container::Array)) => {
+                    self.close(events)
+                }
+                _ => return Err(unexpected_character(byte)),
+            },
+            Expect::FirstKeyOrEnd if byte == b'}' => self.close(events),
+            Expect::FirstKeyOrEnd | Expect::Key if byte == b'"' => {
+                self.token = Token::String(StringToken::new(true));
+            }
+            Expect::FirstValueOrEnd if byte == b']' => self.close(events),
+            Expect::FirstValueOrEnd | Expect::Value => self.start_value(byte, events)?,
+            Expect::End if self.multiple_values => self.start_value(byte, events)?,
+            _ => return Err(unexpected_character(byte)),
+        }
+        Ok(())
+    }
+
+    fn start_value(&mut self, byte: u8, events: &mut Vec<Event>) -> Result<(), String> {
+        match byte {
+            b'{' => {
+                events.push(Event::StartObject);
+                self.stack.push(Container::Object);
+                self.expect = Expect::FirstKeyOrEnd;
+            }
+            b'[' => {
+                events.push(Event::StartArray);
+                self.stack.push(Container::Array);
+                self.expect = Expect::FirstValueOrEnd;
+            }
+            b'"' => self.token = Token::String(StringToken::new(false)),
+            b'-' | b'0'..=b'9' => self.token = Token::Number(vec![byte]),
+            b't' => {
+                self.token = Token::Literal {
+                    text: b"true",
+                    matched: 1,
+                }
+            }
+            b'f' => {
+                self.token = Token::Literal {
+                    text: b"false",
+                    matched: 1,
+                }
+            }
+            b'n' => {
+                self.token = Token::Literal {
+                    text: b"null",
+                    matched: 1,
+                }
+            }
+            _ => return Err(unexpected_character(byte)),
+        }
+        Ok(())
+    }
+
+    fn finish_number(&mut self, events: &mut Vec<Event>) -> Result<(), String> {
+        let Token::Number(digits) = std::mem::replace(&mut self.token, Token::None) else {
+            unreachable!()
+        };
+        let number = std::str::from_utf8(&digits)
+            .ok()
+            .filter(|number| is_json_number(number.as_bytes()))
+            .and_then(|number| number.parse::<f64>().ok())
+            .ok_or_else(|| format!("Invalid number {}", String::from_utf8_lossy(&digits)))?;
+        self.value_done(Event::Value(Scalar::Number(number)), events);
+        Ok(())
+    }
+
+    fn close(&mut self, events: &mut Vec<Event>) {
+        match self.stack.pop() {
+            Some(Container::Object) => events.push(Event::EndObject),
+            _ => events.push(Event::EndArray),
+        }
+        self.after_value();
+    }
+
+    fn value_done(&mut self, event: Event, events: &mut Vec<Event>) {
+        events.push(event);
+        self.after_value();
+    }
+
+    fn after_value(&mut self) {
+        self.expect = if self.stack.is_empty() {
+            Expect::End
+        } else {
+            Expect::CommaOrEnd
+        };
+    }
+}
+
+fn unexpected_character(byte: u8) -> String {
+    if byte.is_ascii_graphic() {
+        format!("Unexpected character '{}'", byte as char)
+    } else {
+        format!("Unexpected byte 0x{byte:02x}")
+    }
+}
+
+/// Checks the grammar of JSON numbers, which is stricter than Rust's float parsing
+fn is_json_number(number: &[u8]) -> bool {
+    let digits = |rest: &[u8]| rest.iter().take_while(|byte| byte.is_ascii_digit()).count();
+
+    let mut rest = number.strip_prefix(b"-").unwrap_or(number);
+    match digits(rest) {
+        0 => return false,
+        count if count > 1 && rest[0] == b'0' => return false,
+        count => rest = &rest[count..],
+    }
+    if let Some(fraction) = rest.strip_prefix(b".") {
+        match digits(fraction) {
+            0 => return false,
+            count => rest = &fraction[count..],
+        }
+    }
+    if let Some(exponent) = rest.strip_prefix(b"e").or_else(|| rest.strip_prefix(b"E")) {
+        let exponent = exponent
+            .strip_prefix(b"+")
+            .or_else(|| exponent.strip_prefix(b"-"))
+            .unwrap_or(exponent);
+        match digits(exponent) {
+            0 => return false,
+            count => rest = &exponent[count..],
+        }
+    }
+    rest.is_empty()
+}
+
+enum Escape {
+    None,
+    Backslash,
+    Unicode { code: u32, digits: u8 },
+}
+
+/// A string being tokenized, kept as UTF-8 bytes so multi-byte characters can be split between
+/// chunks
+struct StringToken {
+    bytes: Vec<u8>,
+    escape: Escape,
+    high_surrogate: Option<u32>,
+    is_key: bool,
+}
+
+impl StringToken {
+    fn new(is_key: bool) -> Self {
+        Self {
+            bytes: Vec::new(),
+            escape: Escape::None,
+            high_surrogate: None,
+            is_key,
+        }
+    }
+
+    /// Adds a byte of the input, returning whether it closed the string
+    fn push(&mut self, byte: u8) -> Result<bool, String> {
+        match self.escape {
+            Escape::None => match byte {
+                b'"' => {
+                    self.flush_surrogate();
+                    return Ok(true);
+                }
+                b'\\' => self.escape = Escape::Backslash,
+                0..=0x1f => return Err(unexpected_character(byte)),
+                _ => {
+                    self.flush_surrogate();
+                    self.bytes.push(byte);
+                }
+            },
+            Escape::Backslash => {
+                let char = match byte {
+                    b'"' => '"',
+                    b'\\' => '\\',
+                    b'/' => '/',
+                    b'b' => '\u{8}',
+                    b'f' => '\u{c}',
+                    b'n' => '\n',
+                    b'r' => '\r',
+                    b't' => '\t',
+                    b'u' => {
+                        self.escape = Escape::Unicode { code: 0, digits: 0 };
+                        return Ok(false);
+                    }
+                    _ => return Err(unexpected_character(byte)),
+                };
+                self.escape = Escape::None;
+                self.flush_surrogate();
+                self.push_char(char);
+            }
+            Escape::Unicode { code, digits } => {
+                let digit = (byte as char)
+                    .to_digit(16)
+                    .ok_or_else(|| unexpected_character(byte))?;
+                let code = code * 16 + digit;
+                if digits == 3 {
+                    self.escape = Escape::None;
+                    self.push_code_unit(code);
+                } else {
+                    self.escape = Escape::Unicode {
+                        code,
+                        digits: digits + 1,
+                    };
+                }
+            }
+        }
+        Ok(false)
+    }
+
+    /// Adds an UTF-16 code unit of an `\uXXXX` escape. Unpaired surrogates, which Rust strings
+    /// cannot hold, are replaced by U+FFFD.
+    fn push_code_unit(&mut self, unit: u32) {
+        match unit {
+            0xD800..=0xDBFF => {
+                self.flush_surrogate();
+                self.high_surrogate = Some(unit);
+            }
+            0xDC00..=0xDFFF => match self.high_surrogate.take() {
+                Some(high) => {
+                    let code = 0x10000 + ((high - 0xD800) << 10) + (unit - 0xDC00);
+                    self.push_char(char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER));
+                }
+                None => self.push_char(char::REPLACEMENT_CHARACTER),
+            },
+            _ => {
+                self.flush_surrogate();
+                self.push_char(char::from_u32(unit).unwrap_or(char::REPLACEMENT_CHARACTER));
+            }
+        }
+    }
+
+    fn flush_surrogate(&mut self) {
+        if self.high_surrogate.take().is_some() {
+            self.push_char(char::REPLACEMENT_CHARACTER);
+        }
+    }
+
+    fn push_char(&mut self, char: char) {
+        let mut buffer = [0; 4];
+        self.bytes
+            .extend_from_slice(char.encode_utf8(&mut buffer).as_bytes());
+    }
+
+    fn finish(self) -> String {
+        match String::from_utf8(self.bytes) {
+            Ok(string) => string,
+            Err(error) => String::from_utf8_lossy(error.as_bytes()).into_owned(),
+        }
+    }
+}
+
+/// Builds JSON text incrementally, inserting the separators. The output is taken in chunks
+/// by the JS serializer walking the value.
+#[derive(Default)]
+struct Writer {
+    output: String,
+    /// Whether each open container already has items
+    stack: Vec<bool>,
+    after_key: bool,
+}
+
+impl Writer {
+    fn separator(&mut self) {
+        if self.after_key {
+            self.after_key = false;
+        } else if let Some(has_items) = self.stack.last_mut() {
+            if *has_items {
+                self.output.push(',');
+            }
+            *has_items = true;
+        }
+    }
+
+    fn begin(&mut self, bracket: char) {
+        self.separator();
+        self.output.push(bracket);
+        self.stack.push(false);
+    }
+
+    fn end(&mut self, bracket: char) {
+        self.stack.pop();
+        self.output.push(bracket);
+    }
+
+    fn key(&mut self, key: &str) {
+        self.separator();
+        self.string(key);
+        self.output.push(':');
+        self.after_key = true;
+    }
+
+    fn string(&mut self, value: &str) {
+        self.output.push('"');
+        for char in value.chars() {
+            match char {
+                '"' => self.output.push_str("\\\""),
+                '\\' => self.output.push_str("\\\\"),
+                '\u{8}' => self.output.push_str("\\b"),
+                '\u{c}' => self.output.push_str("\\f"),
+                '\n' => self.output.push_str("\\n"),
+                '\r' => self.output.push_str("\\r"),
+                '\t' => self.output.push_str("\\t"),
+                '\u{0}'..='\u{1f}' => self.output.push_str(&format!("\\u{:04x}", char as u32)),
+                _ => self.output.push(char),
+            }
+        }
+        self.output.push('"');
+    }
+}
+
+// JS functions for the streaming JSON implementation
+pub const JSON_STREAM_JS: &str = include_str!("json-stream.js");
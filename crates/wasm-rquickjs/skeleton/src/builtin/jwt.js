@@ -0,0 +1,198 @@
+import * as native from '__wasm_rquickjs_builtin/jwt_native';
+import { fromByteArray, toByteArray } from 'base64-js';
+
+const encoder = new TextEncoder();
+const decoder = new TextDecoder('utf-8', { fatal: true });
+
+const HMAC_ALGORITHMS = ['HS256', 'HS384', 'HS512'];
+const PEM_ALGORITHMS = ['RS256', 'ES256'];
+const ALGORITHMS = [...HMAC_ALGORITHMS, ...PEM_ALGORITHMS];
+
+export class JwtError extends Error {
+    constructor(code, message) {
+        super(message);
+        this.name = 'JwtError';
+        this.code = code;
+    }
+}
+
+function toBytes(data) {
+    if (typeof data === 'string') {
+        return encoder.encode(data);
+    }
+    if (data instanceof Uint8Array) {
+        return data;
+    }
+    if (data instanceof ArrayBuffer) {
+        return new Uint8Array(data);
+    }
+    if (ArrayBuffer.isView(data)) {
+        return new Uint8Array(data.buffer, data.byteOffset, data.byteLength);
+    }
+    throw new TypeError('Expected the key to be a string, an ArrayBuffer or a typed array');
+}
+
+// Whether the key is PEM encoded, also when given as bytes
+function isPem(key) {
+    const text = typeof key === 'string' ? key : new TextDecoder().decode(toBytes(key).subarray(0, 64));
+    return text.trimStart().startsWith('-----BEGIN ');
+}
+
+function base64url(bytes) {
+    return fromByteArray(bytes).replace(/\+/g, '-').replace(/\//g, '_').replace(/=+$/, '');
+}
+
+function fromBase64url(text) {
+    if (!/^[A-Za-z0-9_-]*$/.test(text) || text.length % 4 === 1) {
+        throw new JwtError('ERR_JWT_MALFORMED', 'The token is not valid base64url');
+    }
+    const base64 = text.replace(/-/g, '+').replace(/_/g, '/');
+    return toByteArray(base64.padEnd(base64.length + ((4 - (base64.length % 4)) % 4), '='));
+}
+
+function parsePart(text, what) {
+    const bytes = fromBase64url(text);
+    let value;
+    try {
+        value = JSON.parse(decoder.decode(bytes));
+    } catch (error) {
+        throw new JwtError('ERR_JWT_MALFORMED', `The token ${what} is not valid JSON: ${error.message}`);
+    }
+    if (value === null || typeof value !== 'object' || Array.isArray(value)) {
+        throw new JwtError('ERR_JWT_MALFORMED', `The token ${what} is not a JSON object`);
+    }
+    return value;
+}
+
+function checkAlgorithm(algorithm) {
+    if (!ALGORITHMS.includes(algorithm)) {
+        throw new TypeError(`Unsupported JWT algorithm \`${algorithm}\`, expected HS256, HS384, HS512, RS256 or ES256`);
+    }
+}
+
+function seconds(date) {
+    return Math.floor((date ?? new Date()).getTime() / 1000);
+}
+
+function numericClaim(payload, claim) {
+    const value = payload[claim];
+    if (value !== undefined && typeof value !== 'number') {
+        throw new JwtError('ERR_JWT_CLAIM', `The \`${claim}\` claim must be a number of seconds`);
+    }
+    return value;
+}
+
+function optionClaim(payload, claim, value) {
+    if (value === undefined) {
+        return;
+    }
+    if (payload[claim] !== undefined) {
+        throw new TypeError(`The \`${claim}\` claim is already set in the payload`);
+    }
+    payload[claim] = value;
+}
+
+/**
+ * Splits a token into its decoded header and payload, without verifying the signature or the claims
+ */
+export function decode(token) {
+    const parts = String(token).split('.');
+    if (parts.length !== 3) {
+        throw new JwtError('ERR_JWT_MALFORMED', 'A token must consist of three dot-separated parts');
+    }
+    return { header: parsePart(parts[0], 'header'), payload: parsePart(parts[1], 'payload') };
+}
+
+/**
+ * Creates a signed token from a payload object. The `algorithm` is `HS256` (the default), `HS384` or
+ * `HS512` with a secret string or bytes as the key, or `RS256` or `ES256` with a PEM encoded private key.
+ * The `iat` claim is set to the current time unless `noTimestamp` is set, and `expiresIn` and `notBefore`
+ * (in seconds from now), `issuer`, `subject`, `audience` and `jwtId` set the matching claims. The header
+ * gets a `kid` from `keyId`, and any other fields of `header`.
+ */
+export function sign(payload, key, options = {}) {
+    if (payload === null || typeof payload !== 'object' || Array.isArray(payload)) {
+        throw new TypeError('Expected the JWT payload to be an object');
+    }
+    const algorithm = options.algorithm ?? 'HS256';
+    checkAlgorithm(algorithm);
+
+    const now = seconds(options.currentDate);
+    const claims = { ...payload };
+    if (!options.noTimestamp && claims.iat === undefined) {
+        claims.iat = now;
+    }
+    optionClaim(claims, 'exp', options.expiresIn === undefined ? undefined : now + Number(options.expiresIn));
+    optionClaim(claims, 'nbf', options.notBefore === undefined ? undefined : now + Number(options.notBefore));
+    optionClaim(claims, 'iss', options.issuer);
+    optionClaim(claims, 'sub', options.subject);
+    optionClaim(claims, 'aud', options.audience);
+    optionClaim(claims, 'jti', options.jwtId);
+
+    const header = { alg: algorithm, typ: 'JWT' };
+    if (options.keyId !== undefined) {
+        header.kid = options.keyId;
+    }
+    Object.assign(header, options.header, { alg: algorithm });
+
+    const input = `${base64url(encoder.encode(JSON.stringify(header)))}.${base64url(encoder.encode(JSON.stringify(claims)))}`;
+    return `${input}.${base64url(native.sign(algorithm, input, toBytes(key)))}`;
+}
+
+/**
+ * Verifies a token's signature and claims, returning its payload or throwing a `JwtError` with a `code`.
+ * Only the `algorithms` given are accepted, and only the ones matching the key: the HMAC ones for a secret
+ * and `RS256` and `ES256` for a PEM encoded (public or private) key. The `exp` and `nbf` claims are
+ * checked against the current time (or `currentDate`) with `clockTolerance` seconds of leeway, `maxAge`
+ * limits the age in seconds given by `iat`, and `issuer`, `subject` and `audience` (a value or an array of
+ * accepted values) must match the claims.
+ */
+export function verify(token, key, options = {}) {
+    const parts = String(token).split('.');
+    const { header, payload } = decode(token);
+
+    // The algorithm must also match the kind of the key, so a PEM encoded public key can never be used
+    // as an HMAC secret
+    const keyAlgorithms = isPem(key) ? PEM_ALGORITHMS : HMAC_ALGORITHMS;
+    const algorithms = options.algorithms ?? keyAlgorithms;
+    if (!algorithms.includes(header.alg) || !keyAlgorithms.includes(header.alg)) {
+        throw new JwtError('ERR_JWT_ALGORITHM', `The token algorithm \`${header.alg}\` is not allowed`);
+    }
+    const input = `${parts[0]}.${parts[1]}`;
+    if (!native.verify(header.alg, input, fromBase64url(parts[2]), toBytes(key))) {
+        throw new JwtError('ERR_JWT_SIGNATURE', 'The token signature is invalid');
+    }
+
+    const now = seconds(options.currentDate);
+    const tolerance = Number(options.clockTolerance ?? 0);
+    const exp = numericClaim(payload, 'exp');
+    if (exp !== undefined && now - tolerance >= exp) {
+        throw new JwtError('ERR_JWT_EXPIRED', 'The token has expired');
+    }
+    const nbf = numericClaim(payload, 'nbf');
+    if (nbf !== undefined && now + tolerance < nbf) {
+        throw new JwtError('ERR_JWT_NOT_YET_VALID', 'The token is not valid yet');
+    }
+    const iat = numericClaim(payload, 'iat');
+    if (options.maxAge !== undefined) {
+        if (iat === undefined) {
+            throw new JwtError('ERR_JWT_CLAIM', 'The token has no `iat` claim to check its age');
+        }
+        if (now - tolerance > iat + Number(options.maxAge)) {
+            throw new JwtError('ERR_JWT_EXPIRED', 'The token is older than the maximum age');
+        }
+    }
+
+    for (const [claim, option] of [['iss', 'issuer'], ['sub', 'subject'], ['aud', 'audience']]) {
+        const expected = options[option];
+        if (expected === undefined) {
+            continue;
+        }
+        const accepted = Array.isArray(expected) ? expected : [expected];
+        const actual = Array.isArray(payload[claim]) ? payload[claim] : [payload[claim]];
+        if (!actual.some((value) => value !== undefined && accepted.includes(value))) {
+            throw new JwtError('ERR_JWT_CLAIM', `The \`${claim}\` claim does not match the expected ${option}`);
+        }
+    }
+    return payload;
+}
@@ -0,0 +1,26 @@
+use rquickjs::{Ctx, Exception, Value};
+
+// Stubs of the native JWT functions, failing with an explanation when used
+#[rquickjs::module(rename = "camelCase")]
+pub mod native_module {
+    use rquickjs::{Ctx, Value};
+
+    #[rquickjs::function]
+    pub fn sign<'js>(ctx: Ctx<'js>) -> rquickjs::Result<Value<'js>> {
+        super::unavailable(&ctx)
+    }
+
+    #[rquickjs::function]
+    pub fn verify<'js>(ctx: Ctx<'js>) -> rquickjs::Result<Value<'js>> {
+        super::unavailable(&ctx)
+    }
+}
+
+fn unavailable<'js>(ctx: &Ctx<'js>) -> rquickjs::Result<Value<'js>> {
+    Err(Exception::throw_message(
+        ctx,
+        "The `jwt` module requires the `jwt` feature of the wrapper crate",
+    ))
+}
+
+pub const JWT_JS: &str = include_str!("jwt.js");
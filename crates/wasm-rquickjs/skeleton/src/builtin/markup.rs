@@ -0,0 +1,420 @@
+use html5gum::emitters::callback::{CallbackEmitter, CallbackEvent};
+use html5gum::{Span, Tokenizer};
+use quick_xml::Reader;
+use quick_xml::events::Event;
+use rquickjs::{Array, Ctx, Object, Value};
+
+// Native XML and HTML parsers, building the node trees wrapped by the JS DOM classes
+#[rquickjs::module(rename = "camelCase")]
+pub mod native_module {
+    use rquickjs::{Ctx, Exception, Value};
+
+    #[rquickjs::function]
+    pub fn parse_xml<'js>(text: String, ctx: Ctx<'js>) -> rquickjs::Result<Value<'js>> {
+        let nodes = super::parse_xml(&text).map_err(|(position, message)| {
+            let before = &text[..position.min(text.len())];
+            let line = before.matches('\n').count() + 1;
+            let column = before.chars().rev().take_while(|c| *c != '\n').count() + 1;
+            Exception::throw_syntax(
+                &ctx,
+                &format!("Invalid XML at line {line}, column {column}: {message}"),
+            )
+        })?;
+        super::to_js(&ctx, nodes)
+    }
+
+    #[rquickjs::function]
+    pub fn parse_html<'js>(text: String, ctx: Ctx<'js>) -> rquickjs::Result<Value<'js>> {
+        super::to_js(&ctx, super::parse_html(&text))
+    }
+}
+
+/// A parsed node, before it is converted to the plain objects the JS DOM classes are built from
+enum Markup {
+    Element {
+        name: String,
+        attributes: Vec<(String, String)>,
+        children: Vec<Markup>,
+    },
+    Text(String),
+    Comment(String),
+    Doctype(String),
+}
+
+/// Elements cannot nest deeper than this, keeping the conversions of the trees from overflowing
+/// the stack
+const MAX_DEPTH: usize = 512;
+
+/// An open element of the tree being built
+struct Open {
+    name: String,
+    attributes: Vec<(String, String)>,
+    children: Vec<Markup>,
+}
+
+/// Builds a tree from the start and end tags, keeping the elements still open on a stack
+struct TreeBuilder {
+    root: Vec<Markup>,
+    open: Vec<Open>,
+}
+
+impl TreeBuilder {
+    fn new() -> Self {
+        Self {
+            root: Vec::new(),
+            open: Vec::new(),
+        }
+    }
+
+    fn children(&mut self) -> &mut Vec<Markup> {
+        match self.open.last_mut() {
+            Some(open) => &mut open.children,
+            None => &mut self.root,
+        }
+    }
+
+    fn push(&mut self, node: Markup) {
+        let children = self.children();
+        match (children.last_mut(), node) {
+            (Some(Markup::Text(text)), Markup::Text(more)) => text.push_str(&more),
+            (_, node) => children.push(node),
+        }
+    }
+
+    fn open(&mut self, name: String, attributes: Vec<(String, String)>) {
+        self.open.push(Open {
+            name,
+            attributes,
+            children: Vec::new(),
+        });
+    }
+
+    fn close(&mut self) {
+        if let Some(open) = self.open.pop() {
+            self.push(Markup::Element {
+                name: open.name,
+                attributes: open.attributes,
+                children: open.children,
+            });
+        }
+    }
+
+    fn finish(mut self) -> Vec<Markup> {
+        while !self.open.is_empty() {
+            self.close();
+        }
+        self.root
+    }
+}
+
+fn parse_xml(text: &str) -> Result<Vec<Markup>, (usize, String)> {
+    let mut reader = Reader::from_str(text);
+    reader.config_mut().check_comments = true;
+    let mut builder = TreeBuilder::new();
+    let mut has_root = false;
+    loop {
+        let position = reader.buffer_position() as usize;
+        let error = |message: String| (position, message);
+        let event = reader
+            .read_event()
+            .map_err(|err| (reader.error_position() as usize, err.to_string()))?;
+        let outside_root = builder.open.is_empty();
+        match event {
+            Event::Start(start) | Event::Empty(start) if outside_root && has_root => {
+                let name = String::from_utf8_lossy(start.name().as_ref()).to_string();
+                return Err(error(format!(
+                    "the element <{name}> follows the root element"
+                )));
+            }
+            Event::Start(start) => {
+                if builder.open.len() == MAX_DEPTH {
+                    return Err(error("the elements are nested too deeply".to_string()));
+                }
+                let (name, attributes) = xml_tag(&start).map_err(error)?;
+                builder.open(name, attributes);
+            }
+            Event::Empty(start) => {
+                let (name, attributes) = xml_tag(&start).map_err(error)?;
+                builder.push(Markup::Element {
+                    name,
+                    attributes,
+                    children: Vec::new(),
+                });
+                has_root |= outside_root;
+            }
+            Event::End(_) => {
+                builder.close();
+                has_root |= builder.open.is_empty();
+            }
+            Event::Text(text) => {
+                let text = text.unescape().map_err(|err| error(err.to_string()))?;
+                if !outside_root {
+                    builder.push(Markup::Text(text.into_owned()));
+                } else if !text.trim().is_empty() {
+                    return Err(error("text outside of the root element".to_string()));
+                }
+            }
+            Event::CData(data) if !outside_root => {
+                let data = String::from_utf8(data.into_inner().into_owned())
+                    .map_err(|err| error(err.to_string()))?;
+                builder.push(Markup::Text(data));
+            }
+            Event::CData(_) => return Err(error("text outside of the root element".to_string())),
+            Event::Comment(comment) => {
+                let comment = String::from_utf8_lossy(&comment).into_owned();
+                builder.push(Markup::Comment(comment));
+            }
+            Event::DocType(doctype) => {
+                let doctype = String::from_utf8_lossy(&doctype).trim().to_string();
+                builder.push(Markup::Doctype(doctype));
+            }
+            // The XML declaration and the processing instructions are not kept
+            Event::Decl(_) | Event::PI(_) => {}
+            Event::Eof => break,
+        }
+    }
+    if let Some(open) = builder.open.last() {
+        return Err((
+            text.len(),
+            format!("the element <{}> is not closed", open.name),
+        ));
+    }
+    if !has_root {
+        return Err((text.len(), "the document has no root element".to_string()));
+    }
+    Ok(builder.finish())
+}
+
+fn xml_tag(
+    start: &quick_xml::events::BytesStart<'_>,
+) -> Result<(String, Vec<(String, String)>), String> {
+    let name = String::from_utf8_lossy(start.name().as_ref()).into_owned();
+    let mut attributes = Vec::new();
+    for attribute in start.attributes() {
+        let attribute = attribute.map_err(|err| err.to_string())?;
+        let key = String::from_utf8_lossy(attribute.key.as_ref()).into_owned();
+        let value = attribute.unescape_value().map_err(|err| err.to_string())?;
+        attributes.push((key, value.into_owned()));
+    }
+    Ok((name, attributes))
+}
+
+/// The elements that have no contents nor end tag
+const VOID_ELEMENTS: [&str; 14] = [
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source",
+    "track", "wbr",
+];
+
+/// The elements that end an open paragraph when they start
+const CLOSING_PARAGRAPH: [&str; 26] = [
+    "address",
+    "article",
+    "aside",
+    "blockquote",
+    "details",
+    "div",
+    "dl",
+    "fieldset",
+    "figure",
+    "footer",
+    "form",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "header",
+    "hr",
+    "main",
+    "nav",
+    "ol",
+    "p",
+    "pre",
+    "section",
+    "table",
+];
+
+/// The elements whose start tag ends open elements of the same kind, up to the element containing
+/// them, such as a `li` ending the previous `li` of its list
+fn implied_end(name: &str) -> Option<(&'static [&'static str], &'static [&'static str])> {
+    match name {
+        "li" => Some((&["li"], &["ul", "ol"])),
+        "dt" | "dd" => Some((&["dt", "dd"], &["dl"])),
+        "option" => Some((&["option"], &["select", "datalist", "optgroup"])),
+        "tr" => Some((&["tr", "td", "th"], &["table", "thead", "tbody", "tfoot"])),
+        "td" | "th" => Some((&["td", "th"], &["tr", "table"])),
+        "thead" | "tbody" | "tfoot" => {
+            Some((&["thead", "tbody", "tfoot", "tr", "td", "th"], &["table"]))
+        }
+        _ => None,
+    }
+}
+
+enum HtmlToken {
+    Start {
+        name: String,
+        attributes: Vec<(String, String)>,
+        self_closing: bool,
+    },
+    End(String),
+    Text(String),
+    Comment(String),
+    Doctype(String),
+}
+
+/// Parses HTML leniently like browsers do, though with a simplified tree construction: unknown end
+/// tags are ignored, elements left open are closed at the end, and the implied `html`, `head` and
+/// `body` elements are not added
+fn parse_html(text: &str) -> Vec<Markup> {
+    let mut builder = TreeBuilder::new();
+    for token in html_tokens(text) {
+        match token {
+            HtmlToken::Start {
+                name,
+                attributes,
+                self_closing,
+            } => {
+                if CLOSING_PARAGRAPH.contains(&name.as_str()) {
+                    close_within(&mut builder, &["p"], &["button", "table", "td", "th"]);
+                }
+                if let Some((ends, boundaries)) = implied_end(&name) {
+                    close_within(&mut builder, ends, boundaries);
+                }
+                if self_closing || VOID_ELEMENTS.contains(&name.as_str()) {
+                    builder.push(Markup::Element {
+                        name,
+                        attributes,
+                        children: Vec::new(),
+                    });
+                } else if builder.open.len() < MAX_DEPTH {
+                    builder.open(name, attributes);
+                }
+            }
+            HtmlToken::End(name) => {
+                if let Some(index) = builder.open.iter().rposition(|open| open.name == name) {
+                    while builder.open.len() > index {
+                        builder.close();
+                    }
+                }
+            }
+            HtmlToken::Text(text) => builder.push(Markup::Text(text)),
+            HtmlToken::Comment(comment) => builder.push(Markup::Comment(comment)),
+            HtmlToken::Doctype(doctype) => builder.push(Markup::Doctype(doctype)),
+        }
+    }
+    builder.finish()
+}
+
+/// Closes the innermost open element named in `ends` and the elements inside it, unless one of the
+/// `boundaries` is open inside it
+fn close_within(builder: &mut TreeBuilder, ends: &[&str], boundaries: &[&str]) {
+    for index in (0..builder.open.len()).rev() {
+        let name = builder.open[index].name.as_str();
+        if ends.contains(&name) {
+            while builder.open.len() > index {
+                builder.close();
+            }
+            return;
+        }
+        if boundaries.contains(&name) {
+            return;
+        }
+    }
+}
+
+/// Tokenizes HTML with `html5gum`, which decodes the character references and switches to raw text
+/// inside `script`, `style` and the like, collecting the attributes in their order
+fn html_tokens(text: &str) -> Vec<HtmlToken> {
+    let mut name = String::new();
+    let mut attributes: Vec<(String, String)> = Vec::new();
+    let mut emitter = CallbackEmitter::new(
+        move |event: CallbackEvent<'_>, _span: Span<()>| -> Option<HtmlToken> {
+            let string = |bytes: &[u8]| String::from_utf8_lossy(bytes).into_owned();
+            match event {
+                CallbackEvent::OpenStartTag { name: tag } => {
+                    name = string(tag);
+                    attributes.clear();
+                }
+                // Like in browsers, only the first of the attributes with the same name is kept
+                CallbackEvent::AttributeName { name: attribute } => {
+                    attributes.push((string(attribute), String::new()))
+                }
+                CallbackEvent::AttributeValue { value } => {
+                    if let Some((_, current)) = attributes.last_mut() {
+                        current.push_str(&String::from_utf8_lossy(value));
+                    }
+                }
+                CallbackEvent::CloseStartTag { self_closing } => {
+                    let mut unique: Vec<(String, String)> = Vec::new();
+                    for (key, value) in attributes.drain(..) {
+                        if !unique.iter().any(|(existing, _)| *existing == key) {
+                            unique.push((key, value));
+                        }
+                    }
+                    return Some(HtmlToken::Start {
+                        name: std::mem::take(&mut name),
+                        attributes: unique,
+                        self_closing,
+                    });
+                }
+                CallbackEvent::EndTag { name } => return Some(HtmlToken::End(string(name))),
+                CallbackEvent::String { value } => return Some(HtmlToken::Text(string(value))),
+                CallbackEvent::Comment { value } => {
+                    return Some(HtmlToken::Comment(string(value)));
+                }
+                CallbackEvent::Doctype { name, .. } => {
+                    return Some(HtmlToken::Doctype(string(name)));
+                }
+                CallbackEvent::Error(_) => {}
+            }
+            None
+        },
+    );
+    emitter.naively_switch_states(true);
+    Tokenizer::new_with_emitter(text, emitter)
+        .filter_map(Result::ok)
+        .collect()
+}
+
+/// Converts the nodes to plain objects with a `type` of `element`, `text`, `comment` or `doctype`
+fn to_js<'js>(ctx: &Ctx<'js>, nodes: Vec<Markup>) -> rquickjs::Result<Value<'js>> {
+    let array = Array::new(ctx.clone())?;
+    for (index, node) in nodes.into_iter().enumerate() {
+        let object = Object::new(ctx.clone())?;
+        match node {
+            Markup::Element {
+                name,
+                attributes,
+                children,
+            } => {
+                object.set("type", "element")?;
+                object.set("name", name)?;
+                let pairs = Array::new(ctx.clone())?;
+                for (index, (key, value)) in attributes.into_iter().enumerate() {
+                    pairs.set(index, vec![key, value])?;
+                }
+                object.set("attributes", pairs)?;
+                object.set("children", to_js(ctx, children)?)?;
+            }
+            Markup::Text(value) => {
+                object.set("type", "text")?;
+                object.set("value", value)?;
+            }
+            Markup::Comment(value) => {
+                object.set("type", "comment")?;
+                object.set("value", value)?;
+            }
+            Markup::Doctype(value) => {
+                object.set("type", "doctype")?;
+                object.set("value", value)?;
+            }
+        }
+        array.set(index, object)?;
+    }
+    Ok(array.into_value())
+}
+
+pub const DOM_JS: &str = include_str!("dom.js");
+pub const XML_JS: &str = include_str!("xml.js");
+pub const HTML_JS: &str = include_str!("html.js");
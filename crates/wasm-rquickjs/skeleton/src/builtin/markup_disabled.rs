@@ -0,0 +1,25 @@
+// Stubs of the native XML and HTML parsers, failing with an explanation when used
+#[rquickjs::module(rename = "camelCase")]
+pub mod native_module {
+    use rquickjs::{Ctx, Exception, Value};
+
+    #[rquickjs::function]
+    pub fn parse_xml<'js>(ctx: Ctx<'js>) -> rquickjs::Result<Value<'js>> {
+        Err(Exception::throw_message(
+            &ctx,
+            "The `xml` module requires the `markup` feature of the wrapper crate",
+        ))
+    }
+
+    #[rquickjs::function]
+    pub fn parse_html<'js>(ctx: Ctx<'js>) -> rquickjs::Result<Value<'js>> {
+        Err(Exception::throw_message(
+            &ctx,
+            "The `html` module requires the `markup` feature of the wrapper crate",
+        ))
+    }
+}
+
+pub const DOM_JS: &str = include_str!("dom.js");
+pub const XML_JS: &str = include_str!("xml.js");
+pub const HTML_JS: &str = include_str!("html.js");
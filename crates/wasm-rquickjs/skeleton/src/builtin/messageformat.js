@@ -0,0 +1,7 @@
+import { MessageFormat } from '__wasm_rquickjs_builtin/messageformat_native';
+
+export { MessageFormat };
+
+export function formatMessage(message, values, locales) {
+    return new MessageFormat(String(message), locales).format(values);
+}
@@ -0,0 +1,535 @@
+use super::intl::{cached, format_date_time, format_number, parse_locale};
+use fixed_decimal::{Decimal, FloatPrecision};
+use icu_plurals::{PluralCategory, PluralRuleType, PluralRules, PluralRulesOptions};
+use rquickjs::convert::Coerced;
+use rquickjs::{Ctx, Exception, FromJs, Object, Value};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+// Native message formatting, parsing the messages once and formatting them with the Intl data
+#[rquickjs::module(rename = "camelCase")]
+pub mod native_module {
+    use rquickjs::class::Trace;
+    use rquickjs::function::Opt;
+    use rquickjs::{Array, Ctx, Exception, FromJs, JsLifetime, Object, Value};
+
+    #[derive(JsLifetime, Trace)]
+    #[rquickjs::class(rename = "MessageFormat")]
+    pub struct JsMessageFormat {
+        #[qjs(skip_trace)]
+        parts: Vec<super::Part>,
+        #[qjs(skip_trace)]
+        locale: String,
+    }
+
+    #[rquickjs::methods(rename_all = "camelCase")]
+    impl JsMessageFormat {
+        /// Parses a message for a locale, or the first of a list of locales, `en-US` by default
+        #[qjs(constructor)]
+        pub fn new<'js>(
+            message: String,
+            locales: Opt<Value<'js>>,
+            ctx: Ctx<'js>,
+        ) -> rquickjs::Result<Self> {
+            let tag = match locales.0 {
+                Some(locales) if locales.is_array() => {
+                    Array::from_value(locales)?.get::<Option<String>>(0)?
+                }
+                Some(locales) if !locales.is_undefined() => Some(String::from_js(&ctx, locales)?),
+                _ => None,
+            };
+            let locale = match tag {
+                Some(tag) => super::parse_locale(&tag)
+                    .map_err(|error| Exception::throw_range(&ctx, &error))?
+                    .to_string(),
+                None => super::DEFAULT_LOCALE.to_string(),
+            };
+            let parts = super::Parser::new(&message)
+                .parse()
+                .map_err(|error| Exception::throw_syntax(&ctx, &error))?;
+            Ok(Self { parts, locale })
+        }
+
+        #[qjs(get)]
+        pub fn locale(&self) -> String {
+            self.locale.clone()
+        }
+
+        /// Formats the message with the values of its arguments
+        pub fn format<'js>(
+            &self,
+            values: Opt<Value<'js>>,
+            ctx: Ctx<'js>,
+        ) -> rquickjs::Result<String> {
+            let values = match values.0 {
+                Some(values) if !values.is_undefined() && !values.is_null() => {
+                    Some(Object::from_js(&ctx, values)?)
+                }
+                _ => None,
+            };
+            let formatter = super::Formatter {
+                ctx: &ctx,
+                locale: &self.locale,
+                values,
+            };
+            let mut output = String::new();
+            formatter.write(&self.parts, None, &mut output)?;
+            Ok(output)
+        }
+    }
+}
+
+const DEFAULT_LOCALE: &str = "en-US";
+
+const PLURAL_CATEGORIES: [&str; 6] = ["zero", "one", "two", "few", "many", "other"];
+
+/// Messages cannot nest arguments deeper than this
+const MAX_DEPTH: usize = 32;
+
+thread_local! {
+    static PLURAL_RULES: RefCell<HashMap<(String, bool), Rc<PluralRules>>> =
+        RefCell::new(HashMap::new());
+}
+
+/// A part of a parsed message
+pub enum Part {
+    Text(String),
+    /// The `#` of a plural branch, standing for the number
+    Pound,
+    Argument(String),
+    Number {
+        name: String,
+        integer: bool,
+    },
+    DateTime {
+        name: String,
+        date_style: Option<String>,
+        time_style: Option<String>,
+    },
+    /// The branches are keyed by `=` and an exact value, or by a plural category
+    Plural {
+        name: String,
+        ordinal: bool,
+        offset: f64,
+        branches: Vec<(String, Vec<Part>)>,
+    },
+    Select {
+        name: String,
+        branches: Vec<(String, Vec<Part>)>,
+    },
+}
+
+/// Parses the ICU MessageFormat syntax, without the `choice` arguments and the number skeletons
+struct Parser<'a> {
+    text: &'a str,
+    position: usize,
+    depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(text: &'a str) -> Self {
+        Self {
+            text,
+            position: 0,
+            depth: 0,
+        }
+    }
+
+    fn parse(mut self) -> Result<Vec<Part>, String> {
+        self.message(false, false)
+    }
+
+    fn error(&self, message: &str) -> String {
+        let offset = self.text[..self.position].chars().count();
+        format!("Invalid message at offset {offset}: {message}")
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.text[self.position..].chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let char = self.peek()?;
+        self.position += char.len_utf8();
+        Some(char)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.bump();
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), String> {
+        self.skip_whitespace();
+        if self.peek() == Some(expected) {
+            self.bump();
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected `{expected}`")))
+        }
+    }
+
+    /// Reads a name, type, style or selector
+    fn word(&mut self) -> String {
+        self.skip_whitespace();
+        let start = self.position;
+        while self
+            .peek()
+            .is_some_and(|char| !char.is_whitespace() && !matches!(char, '{' | '}' | ',' | '\''))
+        {
+            self.bump();
+        }
+        self.text[start..self.position].to_string()
+    }
+
+    /// Parses text and arguments, up to the end of the text or to the `}` closing a branch
+    fn message(&mut self, in_plural: bool, nested: bool) -> Result<Vec<Part>, String> {
+        let mut parts = Vec::new();
+        let mut text = String::new();
+        loop {
+            match self.peek() {
+                None if nested => return Err(self.error("expected `}`")),
+                None => break,
+                Some('}') if nested => break,
+                Some('}') => return Err(self.error("unexpected `}`")),
+                Some('{') => {
+                    self.bump();
+                    if !text.is_empty() {
+                        parts.push(Part::Text(std::mem::take(&mut text)));
+                    }
+                    parts.push(self.argument(in_plural)?);
+                }
+                Some('#') if in_plural => {
+                    self.bump();
+                    if !text.is_empty() {
+                        parts.push(Part::Text(std::mem::take(&mut text)));
+                    }
+                    parts.push(Part::Pound);
+                }
+                Some('\'') => {
+                    self.bump();
+                    self.quoted(in_plural, &mut text)?;
+                }
+                Some(char) => {
+                    self.bump();
+                    text.push(char);
+                }
+            }
+        }
+        if !text.is_empty() {
+            parts.push(Part::Text(text));
+        }
+        Ok(parts)
+    }
+
+    /// Handles the text after an apostrophe: `''` is an apostrophe, and an apostrophe before a
+    /// special character quotes the text up to the next single apostrophe
+    fn quoted(&mut self, in_plural: bool, text: &mut String) -> Result<(), String> {
+        match self.peek() {
+            Some('\'') => {
+                self.bump();
+                text.push('\'');
+            }
+            Some('{' | '}') => self.quote(text)?,
+            Some('#') if in_plural => self.quote(text)?,
+            _ => text.push('\''),
+        }
+        Ok(())
+    }
+
+    fn quote(&mut self, text: &mut String) -> Result<(), String> {
+        loop {
+            match self.bump() {
+                None => return Err(self.error("unterminated quote")),
+                Some('\'') if self.peek() == Some('\'') => {
+                    self.bump();
+                    text.push('\'');
+                }
+                Some('\'') => return Ok(()),
+                Some(char) => text.push(char),
+            }
+        }
+    }
+
+    /// Parses an argument, after its `{`
+    fn argument(&mut self, in_plural: bool) -> Result<Part, String> {
+        if self.depth == MAX_DEPTH {
+            return Err(self.error("the arguments are nested too deeply"));
+        }
+        let name = self.word();
+        if name.is_empty() {
+            return Err(self.error("expected an argument name"));
+        }
+        self.skip_whitespace();
+        match self.bump() {
+            Some('}') => return Ok(Part::Argument(name)),
+            Some(',') => {}
+            _ => return Err(self.error("expected `,` or `}`")),
+        }
+        let kind = self.word();
+        self.depth += 1;
+        let part = match kind.as_str() {
+            "number" | "date" | "time" => {
+                self.skip_whitespace();
+                let style = match self.bump() {
+                    Some('}') => None,
+                    Some(',') => {
+                        let style = self.word();
+                        self.expect('}')?;
+                        Some(style)
+                    }
+                    _ => return Err(self.error("expected `,` or `}`")),
+                };
+                match (kind.as_str(), style.as_deref()) {
+                    ("number", None) => Part::Number {
+                        name,
+                        integer: false,
+                    },
+                    ("number", Some("integer")) => Part::Number {
+                        name,
+                        integer: true,
+                    },
+                    ("number", Some(style)) => {
+                        return Err(self.error(&format!("unsupported number style `{style}`")));
+                    }
+                    (_, style) => {
+                        let style = style.unwrap_or("medium");
+                        if !matches!(style, "short" | "medium" | "long" | "full") {
+                            return Err(self.error(&format!("unsupported {kind} style `{style}`")));
+                        }
+                        let style = Some(style.to_string());
+                        if kind == "date" {
+                            Part::DateTime {
+                                name,
+                                date_style: style,
+                                time_style: None,
+                            }
+                        } else {
+                            Part::DateTime {
+                                name,
+                                date_style: None,
+                                time_style: style,
+                            }
+                        }
+                    }
+                }
+            }
+            "plural" | "selectordinal" => {
+                self.expect(',')?;
+                self.skip_whitespace();
+                let mut offset = 0.0;
+                if self.text[self.position..].starts_with("offset:") {
+                    self.position += "offset:".len();
+                    let value = self.word();
+                    offset = value
+                        .parse()
+                        .map_err(|_| self.error(&format!("invalid offset `{value}`")))?;
+                }
+                Part::Plural {
+                    name,
+                    ordinal: kind == "selectordinal",
+                    offset,
+                    branches: self.branches(true, in_plural)?,
+                }
+            }
+            "select" => {
+                self.expect(',')?;
+                Part::Select {
+                    name,
+                    branches: self.branches(false, in_plural)?,
+                }
+            }
+            "" => return Err(self.error("expected an argument type")),
+            _ => return Err(self.error(&format!("unsupported argument type `{kind}`"))),
+        };
+        self.depth -= 1;
+        Ok(part)
+    }
+
+    /// Parses the `selector {message}` branches of a plural or select argument, and its `}`
+    fn branches(
+        &mut self,
+        plural: bool,
+        in_plural: bool,
+    ) -> Result<Vec<(String, Vec<Part>)>, String> {
+        let mut branches: Vec<(String, Vec<Part>)> = Vec::new();
+        loop {
+            self.skip_whitespace();
+            if self.peek() == Some('}') {
+                self.bump();
+                break;
+            }
+            let key = self.word();
+            if key.is_empty() {
+                return Err(self.error("expected a selector"));
+            }
+            let valid = !plural
+                || PLURAL_CATEGORIES.contains(&key.as_str())
+                || key
+                    .strip_prefix('=')
+                    .is_some_and(|value| value.parse::<f64>().is_ok());
+            if !valid {
+                return Err(self.error(&format!("invalid plural selector `{key}`")));
+            }
+            if branches.iter().any(|(existing, _)| *existing == key) {
+                return Err(self.error(&format!("duplicate selector `{key}`")));
+            }
+            self.expect('{')?;
+            let message = self.message(plural || in_plural, true)?;
+            self.expect('}')?;
+            branches.push((key, message));
+        }
+        if !branches.iter().any(|(key, _)| key == "other") {
+            return Err(self.error("expected an `other` branch"));
+        }
+        Ok(branches)
+    }
+}
+
+struct Formatter<'a, 'js> {
+    ctx: &'a Ctx<'js>,
+    locale: &'a str,
+    values: Option<Object<'js>>,
+}
+
+impl<'js> Formatter<'_, 'js> {
+    fn value(&self, name: &str) -> rquickjs::Result<Value<'js>> {
+        let value = match &self.values {
+            Some(values) => values.get::<_, Value<'js>>(name)?,
+            None => Value::new_undefined(self.ctx.clone()),
+        };
+        if value.is_undefined() {
+            return Err(Exception::throw_type(
+                self.ctx,
+                &format!("No value was given for the argument `{name}`"),
+            ));
+        }
+        Ok(value)
+    }
+
+    fn number(&self, name: &str) -> rquickjs::Result<f64> {
+        Ok(Coerced::<f64>::from_js(self.ctx, self.value(name)?)?.0)
+    }
+
+    fn format_number(&self, value: f64, maximum_fraction_digits: u8) -> rquickjs::Result<String> {
+        format_number(self.locale, value, 0, maximum_fraction_digits, true)
+            .map_err(|error| Exception::throw_range(self.ctx, &error))
+    }
+
+    /// Writes the formatted parts, with the number of the innermost plural argument for `#`
+    fn write(
+        &self,
+        parts: &[Part],
+        pound: Option<f64>,
+        output: &mut String,
+    ) -> rquickjs::Result<()> {
+        for part in parts {
+            match part {
+                Part::Text(text) => output.push_str(text),
+                Part::Pound => match pound {
+                    Some(value) => output.push_str(&self.format_number(value, 3)?),
+                    None => output.push('#'),
+                },
+                Part::Argument(name) => {
+                    let value = self.value(name)?;
+                    output.push_str(&Coerced::<String>::from_js(self.ctx, value)?.0);
+                }
+                Part::Number { name, integer } => {
+                    let value = self.number(name)?;
+                    output.push_str(&self.format_number(value, if *integer { 0 } else { 3 })?);
+                }
+                Part::DateTime {
+                    name,
+                    date_style,
+                    time_style,
+                } => {
+                    let epoch_millis = self.number(name)?;
+                    if !epoch_millis.is_finite() {
+                        return Err(Exception::throw_range(
+                            self.ctx,
+                            &format!("Invalid date for the argument `{name}`"),
+                        ));
+                    }
+                    let formatted = format_date_time(
+                        self.locale,
+                        epoch_millis,
+                        date_style.as_deref(),
+                        time_style.as_deref(),
+                    )
+                    .map_err(|error| Exception::throw_range(self.ctx, &error))?;
+                    output.push_str(&formatted);
+                }
+                Part::Plural {
+                    name,
+                    ordinal,
+                    offset,
+                    branches,
+                } => {
+                    let value = self.number(name)?;
+                    let exact = branches.iter().find(|(key, _)| {
+                        key.strip_prefix('=')
+                            .and_then(|exact| exact.parse::<f64>().ok())
+                            == Some(value)
+                    });
+                    let branch = match exact {
+                        Some((_, branch)) => branch,
+                        None => {
+                            let category = self.plural_category(value - offset, *ordinal)?;
+                            select(branches, category)
+                        }
+                    };
+                    self.write(branch, Some(value - offset), output)?;
+                }
+                Part::Select { name, branches } => {
+                    let value = Coerced::<String>::from_js(self.ctx, self.value(name)?)?.0;
+                    self.write(select(branches, &value), pound, output)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn plural_category(&self, value: f64, ordinal: bool) -> rquickjs::Result<&'static str> {
+        let rules = cached(&PLURAL_RULES, (self.locale.to_string(), ordinal), || {
+            let locale = parse_locale(self.locale)?;
+            let mut options = PluralRulesOptions::default();
+            options.rule_type = Some(if ordinal {
+                PluralRuleType::Ordinal
+            } else {
+                PluralRuleType::Cardinal
+            });
+            PluralRules::try_new((&locale).into(), options)
+                .map_err(|err| format!("Failed to create plural rules: {err}"))
+        })
+        .map_err(|error| Exception::throw_range(self.ctx, &error))?;
+        let decimal =
+            Decimal::try_from_f64(value.abs(), FloatPrecision::RoundTrip).map_err(|err| {
+                Exception::throw_range(
+                    self.ctx,
+                    &format!("Cannot select a plural form for {value}: {err}"),
+                )
+            })?;
+        Ok(match rules.category_for(&decimal) {
+            PluralCategory::Zero => "zero",
+            PluralCategory::One => "one",
+            PluralCategory::Two => "two",
+            PluralCategory::Few => "few",
+            PluralCategory::Many => "many",
+            PluralCategory::Other => "other",
+        })
+    }
+}
+
+/// The branch for the key, or the `other` branch that every plural and select argument has
+fn select<'a>(branches: &'a [(String, Vec<Part>)], key: &str) -> &'a [Part] {
+    branches
+        .iter()
+        .find(|(branch, _)| branch == key)
+        .or_else(|| branches.iter().find(|(branch, _)| branch == "other"))
+        .map(|(_, parts)| parts.as_slice())
+        .unwrap_or_default()
+}
+
+pub const MESSAGEFORMAT_JS: &str = include_str!("messageformat.js");
@@ -0,0 +1,29 @@
+use rquickjs::{Ctx, Exception};
+
+// Stub of the native message formatting, failing with an explanation when used
+#[rquickjs::module(rename = "camelCase")]
+pub mod native_module {
+    use rquickjs::class::Trace;
+    use rquickjs::{Ctx, JsLifetime};
+
+    #[derive(JsLifetime, Trace)]
+    #[rquickjs::class(rename = "MessageFormat")]
+    pub struct JsMessageFormat {}
+
+    #[rquickjs::methods]
+    impl JsMessageFormat {
+        #[qjs(constructor)]
+        pub fn new(ctx: Ctx<'_>) -> rquickjs::Result<Self> {
+            Err(super::unavailable(&ctx))
+        }
+    }
+}
+
+fn unavailable(ctx: &Ctx<'_>) -> rquickjs::Error {
+    Exception::throw_message(
+        ctx,
+        "The `messageformat` module requires the `messageformat` feature of the wrapper crate",
+    )
+}
+
+pub const MESSAGEFORMAT_JS: &str = include_str!("messageformat.js");
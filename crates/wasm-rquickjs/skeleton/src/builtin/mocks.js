@@ -0,0 +1,71 @@
+// The mock implementations and the recorded calls of the imported functions, by the module name
+// of the interface and the JS name of the function
+const mocks = new Map();
+const recordedCalls = new Map();
+
+const key = (iface, fn) => `${iface}#${fn}`;
+
+export function mock(iface, fn, implementation) {
+    if (typeof implementation !== 'function') {
+        throw new TypeError(`The mock of ${fn} of ${iface} must be a function`);
+    }
+    mocks.set(key(iface, fn), implementation);
+}
+
+export function mockModule(iface, implementations) {
+    for (const [fn, implementation] of Object.entries(implementations)) {
+        mock(iface, fn, implementation);
+    }
+}
+
+export function calls(iface, fn) {
+    return recordedCalls.get(key(iface, fn)) ?? [];
+}
+
+export function resetMocks(iface) {
+    for (const map of [mocks, recordedCalls]) {
+        for (const mapKey of [...map.keys()]) {
+            if (iface === undefined || mapKey.startsWith(`${iface}#`)) {
+                map.delete(mapKey);
+            }
+        }
+    }
+}
+
+function callMock(iface, fn, self, args) {
+    const mapKey = key(iface, fn);
+    const implementation = mocks.get(mapKey);
+    if (implementation === undefined) {
+        throw new Error(`No mock is set for ${fn} of ${iface}`);
+    }
+    if (!recordedCalls.has(mapKey)) {
+        recordedCalls.set(mapKey, []);
+    }
+    recordedCalls.get(mapKey).push(args);
+    return implementation.apply(self, args);
+}
+
+export function mockFunction(iface, name) {
+    return function (...args) {
+        return callMock(iface, name, this, args);
+    };
+}
+
+// Replaces the static functions of a resource class by mocks in place, and returns a proxy of the
+// class constructing the objects returned by the mock of its constructor
+export function mockClass(iface, name, cls) {
+    if (cls === undefined) {
+        return cls;
+    }
+    for (const fnName of Object.getOwnPropertyNames(cls)) {
+        const descriptor = Object.getOwnPropertyDescriptor(cls, fnName);
+        if (fnName !== 'prototype' && typeof descriptor.value === 'function') {
+            Object.defineProperty(cls, fnName, { ...descriptor, value: mockFunction(iface, `${name}.${fnName}`) });
+        }
+    }
+    return new Proxy(cls, {
+        construct(target, args) {
+            return callMock(iface, `new ${name}`, undefined, args);
+        },
+    });
+}
@@ -0,0 +1,65 @@
+use rquickjs::function::Constructor;
+use rquickjs::{Ctx, Function, IntoJs, Object, Value};
+
+#[allow(dead_code)]
+const MOCKS_GLOBAL: &str = "__wasm_rquickjs_mocks";
+
+pub const MOCKS_JS: &str = include_str!("mocks.js");
+
+// The public part of the mocks module, imported by the tests of the JS code
+pub const PUBLIC_MOCKS_JS: &str = r#"
+        export { mock, mockModule, calls, resetMocks } from '__wasm_rquickjs_builtin/mocks';
+    "#;
+
+/// Whether the wrapper crate is built with the `mock-imports` feature, calling the mocks set
+/// through the `__mocks` module instead of the imported interfaces
+pub fn is_enabled() -> bool {
+    crate::MOCK_IMPORTS
+}
+
+// JS code exposing the mocking functions to the import modules
+pub const WIRE_JS: &str = r#"
+        import * as __wasm_rquickjs_mocks from '__wasm_rquickjs_builtin/mocks';
+        globalThis.__wasm_rquickjs_mocks = __wasm_rquickjs_mocks;
+    "#;
+
+/// Replaces a function exported by the module of an imported interface by its mock when mocking
+/// is enabled
+#[allow(dead_code)]
+pub fn mock_import_function<'js>(
+    ctx: &Ctx<'js>,
+    interface: &str,
+    name: &str,
+    function: impl IntoJs<'js>,
+) -> rquickjs::Result<Value<'js>> {
+    if is_enabled() {
+        mocks_module(ctx)?
+            .get::<_, Function>("mockFunction")?
+            .call((interface, name))
+    } else {
+        function.into_js(ctx)
+    }
+}
+
+/// Replaces the constructor and static functions of a resource class exported by the module of
+/// an imported interface by their mocks when mocking is enabled
+#[allow(dead_code)]
+pub fn mock_import_class<'js>(
+    ctx: &Ctx<'js>,
+    interface: &str,
+    name: &str,
+    constructor: Option<Constructor<'js>>,
+) -> rquickjs::Result<Value<'js>> {
+    if is_enabled() {
+        mocks_module(ctx)?
+            .get::<_, Function>("mockClass")?
+            .call((interface, name, constructor))
+    } else {
+        constructor.into_js(ctx)
+    }
+}
+
+#[allow(dead_code)]
+fn mocks_module<'js>(ctx: &Ctx<'js>) -> rquickjs::Result<Object<'js>> {
+    ctx.globals().get(MOCKS_GLOBAL)
+}
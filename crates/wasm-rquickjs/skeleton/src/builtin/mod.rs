@@ -1,10 +1,67 @@
 use std::fmt::Write;
 
+pub use cancellation::cancel_background_work;
+#[cfg_attr(not(feature = "http"), allow(unused_imports))]
+pub use cancellation::unless_call_cancelled;
+pub use http_limits::{HttpCacheLimits, HttpLimits};
+// Only used by the generated code of the imports when they are hooked or recorded
+#[allow(unused_imports)]
+pub use import_hooks::{hook_import_class, hook_import_function};
+pub use import_recording::ImportRecording;
+// Only used by the generated code of the imports when they can be mocked
+#[allow(unused_imports)]
+pub use mocks::{mock_import_class, mock_import_function};
+pub use random::MathRandomSeed;
+
+mod abort;
+mod assets;
 mod base64;
 mod buffer;
+mod cancellation;
+mod clock;
+#[cfg(feature = "codecs")]
+mod codecs;
+#[cfg(not(feature = "codecs"))]
+mod codecs_disabled;
+#[cfg(not(feature = "codecs"))]
+mod codecs {
+    pub use super::codecs_disabled::*;
+}
+#[cfg(feature = "compression")]
+mod compression;
+#[cfg(not(feature = "compression"))]
+mod compression_disabled;
+#[cfg(not(feature = "compression"))]
+mod compression {
+    pub use super::compression_disabled::*;
+}
+#[cfg(feature = "config")]
+mod config_formats;
+#[cfg(not(feature = "config"))]
+mod config_formats_disabled;
+#[cfg(not(feature = "config"))]
+mod config_formats {
+    pub use super::config_formats_disabled::*;
+}
 mod console;
+#[cfg(feature = "csv")]
+mod csv;
+#[cfg(any(feature = "config", feature = "templates"))]
+mod data_model;
+#[cfg(feature = "decimal")]
+mod decimal;
 mod encoding;
+#[cfg(feature = "http")]
+mod fetch_gateway;
 mod fs;
+#[cfg(feature = "hashing")]
+mod hashing;
+#[cfg(not(feature = "hashing"))]
+mod hashing_disabled;
+#[cfg(not(feature = "hashing"))]
+mod hashing {
+    pub use super::hashing_disabled::*;
+}
 
 #[cfg(feature = "http")]
 mod http;
@@ -16,9 +73,84 @@ mod http {
     pub use super::http_disabled::*;
 }
 
+mod http_limits;
+mod ids;
 mod ieee754;
+mod import_hooks;
+mod import_recording;
+
+#[cfg(feature = "intl")]
+mod intl;
+
+#[cfg(not(feature = "intl"))]
+mod intl_disabled;
+#[cfg(not(feature = "intl"))]
+mod intl {
+    pub use super::intl_disabled::*;
+}
+
+mod json_stream;
+#[cfg(feature = "jwt")]
+mod jwt;
+#[cfg(not(feature = "jwt"))]
+mod jwt_disabled;
+#[cfg(not(feature = "jwt"))]
+mod jwt {
+    pub use super::jwt_disabled::*;
+}
+#[cfg(feature = "messageformat")]
+mod messageformat;
+#[cfg(feature = "markup")]
+mod markup;
+#[cfg(not(feature = "markup"))]
+mod markup_disabled;
+#[cfg(not(feature = "markup"))]
+mod markup {
+    pub use super::markup_disabled::*;
+}
+#[cfg(not(feature = "messageformat"))]
+mod messageformat_disabled;
+#[cfg(not(feature = "messageformat"))]
+mod messageformat {
+    pub use super::messageformat_disabled::*;
+}
+mod mocks;
+#[cfg(feature = "passwords")]
+mod passwords;
+#[cfg(not(feature = "passwords"))]
+mod passwords_disabled;
+#[cfg(not(feature = "passwords"))]
+mod passwords {
+    pub use super::passwords_disabled::*;
+}
 mod process;
+mod protobuf;
+mod random;
+#[cfg(feature = "regex")]
+mod re;
+#[cfg(not(feature = "regex"))]
+mod re_disabled;
+#[cfg(not(feature = "regex"))]
+mod re {
+    pub use super::re_disabled::*;
+}
 mod streams;
+#[cfg(feature = "templates")]
+mod templates;
+#[cfg(not(feature = "templates"))]
+mod templates_disabled;
+#[cfg(not(feature = "templates"))]
+mod templates {
+    pub use super::templates_disabled::*;
+}
+#[cfg(feature = "temporal")]
+mod temporal;
+#[cfg(not(feature = "temporal"))]
+mod temporal_disabled;
+#[cfg(not(feature = "temporal"))]
+mod temporal {
+    pub use super::temporal_disabled::*;
+}
 mod timeout;
 mod url;
 mod util;
@@ -27,6 +159,20 @@ mod web_crypto;
 pub fn add_module_resolvers(
     resolver: rquickjs::loader::BuiltinResolver,
 ) -> rquickjs::loader::BuiltinResolver {
+    // The mocks can only be imported by the JS code when mocking is enabled
+    let resolver = if mocks::is_enabled() {
+        resolver.with_module("__mocks")
+    } else {
+        resolver
+    };
+    // The `Decimal` class is only defined with the `decimal` feature
+    #[cfg(feature = "decimal")]
+    let resolver = resolver.with_module("__wasm_rquickjs_builtin/decimal_native");
+    // The CSV module can only be imported with the `csv` feature
+    #[cfg(feature = "csv")]
+    let resolver = resolver
+        .with_module("__wasm_rquickjs_builtin/csv_native")
+        .with_module("csv");
     resolver
         .with_module("__wasm_rquickjs_builtin/console_native")
         .with_module("__wasm_rquickjs_builtin/console")
@@ -36,6 +182,9 @@ pub fn add_module_resolvers(
         .with_module("__wasm_rquickjs_builtin/http")
         .with_module("__wasm_rquickjs_builtin/http_blob")
         .with_module("__wasm_rquickjs_builtin/http_form_data")
+        .with_module("__wasm_rquickjs_builtin/http_cookies")
+        .with_module("__wasm_rquickjs_builtin/http_cache")
+        .with_module("__wasm_rquickjs_builtin/event_source")
         .with_module("__wasm_rquickjs_builtin/streams")
         .with_module("__wasm_rquickjs_builtin/encoding_native")
         .with_module("__wasm_rquickjs_builtin/encoding")
@@ -55,13 +204,58 @@ pub fn add_module_resolvers(
         .with_module("__wasm_rquickjs_builtin/url")
         .with_module("__wasm_rquickjs_builtin/web_crypto_native")
         .with_module("__wasm_rquickjs_builtin/web_crypto")
+        .with_module("__wasm_rquickjs_builtin/intl_native")
+        .with_module("__wasm_rquickjs_builtin/intl")
+        .with_module("__wasm_rquickjs_builtin/assets_native")
+        .with_module("assets")
+        .with_module("__wasm_rquickjs_builtin/json_stream_native")
+        .with_module("json-stream")
+        .with_module("protobuf")
+        .with_module("grpc-web")
+        .with_module("msgpack")
+        .with_module("cbor")
+        .with_module("__wasm_rquickjs_builtin/compression_native")
+        .with_module("compression")
+        .with_module("__wasm_rquickjs_builtin/hashing_native")
+        .with_module("hashing")
+        .with_module("__wasm_rquickjs_builtin/passwords_native")
+        .with_module("passwords")
+        .with_module("__wasm_rquickjs_builtin/config_formats_native")
+        .with_module("toml")
+        .with_module("yaml")
+        .with_module("__wasm_rquickjs_builtin/re_native")
+        .with_module("re")
+        .with_module("__wasm_rquickjs_builtin/ids_native")
+        .with_module("uuid")
+        .with_module("ulid")
+        .with_module("__wasm_rquickjs_builtin/temporal_native")
+        .with_module("temporal")
+        .with_module("__wasm_rquickjs_builtin/messageformat_native")
+        .with_module("messageformat")
+        .with_module("__wasm_rquickjs_builtin/templates_native")
+        .with_module("templates")
+        .with_module("__wasm_rquickjs_builtin/markup_native")
+        .with_module("__wasm_rquickjs_builtin/dom")
+        .with_module("xml")
+        .with_module("html")
+        .with_module("__wasm_rquickjs_builtin/jwt_native")
+        .with_module("jwt")
+        .with_module("__wasm_rquickjs_builtin/random_native")
+        .with_module("__wasm_rquickjs_builtin/clock_native")
+        .with_module("__wasm_rquickjs_builtin/import_hooks")
+        .with_module("__wasm_rquickjs_builtin/import_recording_native")
+        .with_module("__wasm_rquickjs_builtin/import_recording")
+        .with_module("__wasm_rquickjs_builtin/mocks")
+        .with_module("__wasm_rquickjs_builtin/abort")
+        .with_module("__wasm_rquickjs_builtin/cancellation_native")
+        .with_module("cancellation")
 }
 
 pub fn module_loader() -> (
     rquickjs::loader::ModuleLoader,
     rquickjs::loader::BuiltinLoader,
 ) {
-    (
+    let (module_loader, builtin_loader) = (
         rquickjs::loader::ModuleLoader::default()
             .with_module(
                 "__wasm_rquickjs_builtin/console_native",
@@ -88,12 +282,80 @@ pub fn module_loader() -> (
             .with_module(
                 "__wasm_rquickjs_builtin/web_crypto_native",
                 web_crypto::js_native_module,
+            )
+            .with_module(
+                "__wasm_rquickjs_builtin/intl_native",
+                intl::js_native_module,
+            )
+            .with_module(
+                "__wasm_rquickjs_builtin/assets_native",
+                assets::js_native_module,
+            )
+            .with_module(
+                "__wasm_rquickjs_builtin/json_stream_native",
+                json_stream::js_native_module,
+            )
+            .with_module("msgpack", codecs::js_msgpack_module)
+            .with_module("cbor", codecs::js_cbor_module)
+            .with_module(
+                "__wasm_rquickjs_builtin/compression_native",
+                compression::js_native_module,
+            )
+            .with_module(
+                "__wasm_rquickjs_builtin/hashing_native",
+                hashing::js_native_module,
+            )
+            .with_module(
+                "__wasm_rquickjs_builtin/passwords_native",
+                passwords::js_native_module,
+            )
+            .with_module("__wasm_rquickjs_builtin/jwt_native", jwt::js_native_module)
+            .with_module(
+                "__wasm_rquickjs_builtin/config_formats_native",
+                config_formats::js_native_module,
+            )
+            .with_module("__wasm_rquickjs_builtin/re_native", re::js_native_module)
+            .with_module("__wasm_rquickjs_builtin/ids_native", ids::js_native_module)
+            .with_module(
+                "__wasm_rquickjs_builtin/temporal_native",
+                temporal::js_native_module,
+            )
+            .with_module(
+                "__wasm_rquickjs_builtin/messageformat_native",
+                messageformat::js_native_module,
+            )
+            .with_module(
+                "__wasm_rquickjs_builtin/templates_native",
+                templates::js_native_module,
+            )
+            .with_module(
+                "__wasm_rquickjs_builtin/markup_native",
+                markup::js_native_module,
+            )
+            .with_module(
+                "__wasm_rquickjs_builtin/random_native",
+                random::js_native_module,
+            )
+            .with_module(
+                "__wasm_rquickjs_builtin/clock_native",
+                clock::js_native_module,
+            )
+            .with_module(
+                "__wasm_rquickjs_builtin/import_recording_native",
+                import_recording::js_native_module,
+            )
+            .with_module(
+                "__wasm_rquickjs_builtin/cancellation_native",
+                cancellation::js_native_module,
             ),
         rquickjs::loader::BuiltinLoader::default()
             .with_module("__wasm_rquickjs_builtin/console", console::CONSOLE_JS)
             .with_module("__wasm_rquickjs_builtin/timeout", timeout::TIMEOUT_JS)
             .with_module("__wasm_rquickjs_builtin/http_blob", http::FETCH_BLOB_JS)
             .with_module("__wasm_rquickjs_builtin/http_form_data", http::FORMDATA_JS)
+            .with_module("__wasm_rquickjs_builtin/http_cookies", http::COOKIES_JS)
+            .with_module("__wasm_rquickjs_builtin/http_cache", http::CACHE_JS)
+            .with_module("__wasm_rquickjs_builtin/event_source", http::EVENT_SOURCE_JS)
             .with_module("__wasm_rquickjs_builtin/http", http::HTTP_JS)
             .with_module("__wasm_rquickjs_builtin/streams", streams::STREAMS_JS)
             .with_module("__wasm_rquickjs_builtin/encoding", encoding::ENCODING_JS)
@@ -111,19 +373,82 @@ pub fn module_loader() -> (
             .with_module(
                 "__wasm_rquickjs_builtin/web_crypto",
                 web_crypto::WEB_CRYPTO_JS,
-            ),
-    )
+            )
+            .with_module("__wasm_rquickjs_builtin/intl", intl::INTL_JS)
+            .with_module("assets", assets::ASSETS_JS)
+            .with_module("json-stream", json_stream::JSON_STREAM_JS)
+            .with_module("compression", compression::COMPRESSION_JS)
+            .with_module("hashing", hashing::HASHING_JS)
+            .with_module("passwords", passwords::PASSWORDS_JS)
+            .with_module("jwt", jwt::JWT_JS)
+            .with_module("toml", config_formats::TOML_JS)
+            .with_module("yaml", config_formats::YAML_JS)
+            .with_module("re", re::RE_JS)
+            .with_module("uuid", ids::UUID_JS)
+            .with_module("ulid", ids::ULID_JS)
+            .with_module("temporal", temporal::TEMPORAL_JS)
+            .with_module("messageformat", messageformat::MESSAGEFORMAT_JS)
+            .with_module("templates", templates::TEMPLATES_JS)
+            .with_module("__wasm_rquickjs_builtin/dom", markup::DOM_JS)
+            .with_module("xml", markup::XML_JS)
+            .with_module("html", markup::HTML_JS)
+            .with_module("protobuf", protobuf::PROTOBUF_JS)
+            .with_module("grpc-web", protobuf::GRPC_WEB_JS)
+            .with_module(
+                "__wasm_rquickjs_builtin/import_hooks",
+                import_hooks::IMPORT_HOOKS_JS,
+            )
+            .with_module(
+                "__wasm_rquickjs_builtin/import_recording",
+                import_recording::IMPORT_RECORDING_JS,
+            )
+            .with_module("__wasm_rquickjs_builtin/mocks", mocks::MOCKS_JS)
+            .with_module("__mocks", mocks::PUBLIC_MOCKS_JS)
+            .with_module("__wasm_rquickjs_builtin/abort", abort::ABORT_JS)
+            .with_module("cancellation", cancellation::CANCELLATION_JS),
+    );
+    #[cfg(feature = "decimal")]
+    let module_loader = module_loader.with_module(
+        "__wasm_rquickjs_builtin/decimal_native",
+        decimal::js_native_module,
+    );
+    #[cfg(feature = "csv")]
+    let (module_loader, builtin_loader) = (
+        module_loader.with_module("__wasm_rquickjs_builtin/csv_native", csv::js_native_module),
+        builtin_loader.with_module("csv", csv::CSV_JS),
+    );
+    (module_loader, builtin_loader)
 }
 
 pub fn wire_builtins() -> String {
     let mut result = String::new();
     writeln!(result, "{}", console::WIRE_JS).unwrap();
     writeln!(result, "{}", timeout::WIRE_JS).unwrap();
+    writeln!(result, "{}", abort::WIRE_JS).unwrap();
+    writeln!(result, "{}", cancellation::WIRE_JS).unwrap();
     writeln!(result, "{}", http::WIRE_JS).unwrap();
     writeln!(result, "{}", streams::WIRE_JS).unwrap();
     writeln!(result, "{}", encoding::WIRE_JS).unwrap();
     writeln!(result, "{}", url::WIRE_JS).unwrap();
     writeln!(result, "{}", web_crypto::WIRE_JS).unwrap();
+    #[cfg(feature = "decimal")]
+    writeln!(result, "{}", decimal::WIRE_JS).unwrap();
+    writeln!(result, "{}", intl::WIRE_JS).unwrap();
+    if random::is_enabled() {
+        writeln!(result, "{}", random::WIRE_JS).unwrap();
+    }
+    if clock::is_enabled() {
+        writeln!(result, "{}", clock::WIRE_JS).unwrap();
+    }
+    if mocks::is_enabled() {
+        writeln!(result, "{}", mocks::WIRE_JS).unwrap();
+    }
+    if import_hooks::is_enabled() || import_recording::is_enabled() {
+        writeln!(result, "{}", import_hooks::WIRE_JS).unwrap();
+    }
+    if import_hooks::is_enabled() {
+        writeln!(result, "{}", import_hooks::REGISTER_WIRE_JS).unwrap();
+    }
 
     result
 }
@@ -0,0 +1,72 @@
+import * as native from '__wasm_rquickjs_builtin/passwords_native';
+
+const encoder = new TextEncoder();
+
+// The defaults recommended by OWASP
+const ARGON2ID_DEFAULTS = { memoryCost: 19456, timeCost: 2, parallelism: 1 };
+const PBKDF2_ITERATIONS = { sha256: 600000, sha512: 210000 };
+
+function toBytes(data, what) {
+    if (typeof data === 'string') {
+        return encoder.encode(data);
+    }
+    if (data instanceof Uint8Array) {
+        return data;
+    }
+    if (data instanceof ArrayBuffer) {
+        return new Uint8Array(data);
+    }
+    if (ArrayBuffer.isView(data)) {
+        return new Uint8Array(data.buffer, data.byteOffset, data.byteLength);
+    }
+    throw new TypeError(`Expected the ${what} to be a string, an ArrayBuffer or a typed array`);
+}
+
+function positiveInteger(value, name) {
+    const number = Number(value);
+    if (!Number.isInteger(number) || number < 1 || number > 0xffffffff) {
+        throw new RangeError(`Invalid ${name} ${value}, expected a positive integer`);
+    }
+    return number;
+}
+
+/**
+ * Hashes a password with a random salt, returning the hash in the PHC string format (such as
+ * `$argon2id$v=19$m=19456,t=2,p=1$...`), which holds the algorithm and parameters needed to verify
+ * it. The algorithm is `argon2id` (by default, tuned with `memoryCost` in KiB, `timeCost` and
+ * `parallelism`), `pbkdf2-sha256` or `pbkdf2-sha512` (tuned with `iterations`).
+ */
+export function hashPassword(password, options = {}) {
+    const bytes = toBytes(password, 'password');
+    const algorithm = options.algorithm ?? 'argon2id';
+    switch (algorithm) {
+        case 'argon2id':
+            return native.hash_argon2id(
+                bytes,
+                positiveInteger(options.memoryCost ?? ARGON2ID_DEFAULTS.memoryCost, 'memory cost'),
+                positiveInteger(options.timeCost ?? ARGON2ID_DEFAULTS.timeCost, 'time cost'),
+                positiveInteger(options.parallelism ?? ARGON2ID_DEFAULTS.parallelism, 'parallelism'),
+            );
+        case 'pbkdf2-sha256':
+        case 'pbkdf2-sha512': {
+            const hash = algorithm.slice('pbkdf2-'.length);
+            const iterations = positiveInteger(options.iterations ?? PBKDF2_ITERATIONS[hash], 'iteration count');
+            return native.hash_pbkdf2(bytes, hash, iterations);
+        }
+        default:
+            throw new TypeError(`Unsupported password hashing algorithm \`${algorithm}\`, expected \`argon2id\`, \`pbkdf2-sha256\` or \`pbkdf2-sha512\``);
+    }
+}
+
+// Whether the password matches a hash returned by `hashPassword` (or another PHC string of these algorithms)
+export function verifyPassword(password, hash) {
+    return native.verify(toBytes(password, 'password'), String(hash));
+}
+
+// Derives a key of `length` bytes from a password and salt with PBKDF2, using HMAC with `sha256` or `sha512`
+export function pbkdf2(password, salt, options = {}) {
+    const hash = options.hash ?? 'sha256';
+    const iterations = positiveInteger(options.iterations ?? PBKDF2_ITERATIONS[hash] ?? 1, 'iteration count');
+    const length = positiveInteger(options.length ?? 32, 'key length');
+    return native.derive_pbkdf2(toBytes(password, 'password'), toBytes(salt, 'salt'), String(hash), iterations, length);
+}
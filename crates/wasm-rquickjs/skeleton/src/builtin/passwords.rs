@@ -0,0 +1,168 @@
+use argon2::password_hash::{self, PasswordHash, PasswordHasher, SaltString};
+use argon2::{Argon2, Params, Version};
+use pbkdf2::Pbkdf2;
+use rand::RngCore;
+use rquickjs::{Ctx, Exception};
+use sha2::{Sha256, Sha512};
+
+// Native implementation of the password hashing functions
+#[rquickjs::module(rename = "camelCase")]
+pub mod native_module {
+    use rquickjs::{Ctx, TypedArray};
+
+    #[rquickjs::function]
+    pub fn hash_argon2id<'js>(
+        password: TypedArray<'js, u8>,
+        memory_cost: u32,
+        time_cost: u32,
+        parallelism: u32,
+        ctx: Ctx<'js>,
+    ) -> rquickjs::Result<String> {
+        super::hash_argon2id(
+            &ctx,
+            password.as_bytes().unwrap_or_default(),
+            memory_cost,
+            time_cost,
+            parallelism,
+        )
+    }
+
+    #[rquickjs::function]
+    pub fn hash_pbkdf2<'js>(
+        password: TypedArray<'js, u8>,
+        hash: String,
+        iterations: u32,
+        ctx: Ctx<'js>,
+    ) -> rquickjs::Result<String> {
+        super::hash_pbkdf2(
+            &ctx,
+            password.as_bytes().unwrap_or_default(),
+            &hash,
+            iterations,
+        )
+    }
+
+    #[rquickjs::function]
+    pub fn verify<'js>(
+        password: TypedArray<'js, u8>,
+        hash: String,
+        ctx: Ctx<'js>,
+    ) -> rquickjs::Result<bool> {
+        super::verify(&ctx, password.as_bytes().unwrap_or_default(), &hash)
+    }
+
+    #[rquickjs::function]
+    pub fn derive_pbkdf2<'js>(
+        password: TypedArray<'js, u8>,
+        salt: TypedArray<'js, u8>,
+        hash: String,
+        iterations: u32,
+        length: usize,
+        ctx: Ctx<'js>,
+    ) -> rquickjs::Result<TypedArray<'js, u8>> {
+        let key = super::derive_pbkdf2(
+            &ctx,
+            password.as_bytes().unwrap_or_default(),
+            salt.as_bytes().unwrap_or_default(),
+            &hash,
+            iterations,
+            length,
+        )?;
+        TypedArray::new(ctx, key)
+    }
+}
+
+/// Salts are 16 random bytes, as recommended for both algorithms
+fn random_salt(ctx: &Ctx<'_>) -> rquickjs::Result<SaltString> {
+    let mut salt = [0u8; 16];
+    rand::rng().fill_bytes(&mut salt);
+    SaltString::encode_b64(&salt).map_err(|error| password_hash_error(ctx, error))
+}
+
+fn hash_argon2id(
+    ctx: &Ctx<'_>,
+    password: &[u8],
+    memory_cost: u32,
+    time_cost: u32,
+    parallelism: u32,
+) -> rquickjs::Result<String> {
+    let params = Params::new(memory_cost, time_cost, parallelism, None).map_err(|error| {
+        Exception::throw_range(ctx, &format!("Invalid argon2id parameters: {error}"))
+    })?;
+    let salt = random_salt(ctx)?;
+    Argon2::new(argon2::Algorithm::Argon2id, Version::V0x13, params)
+        .hash_password(password, &salt)
+        .map(|hash| hash.to_string())
+        .map_err(|error| password_hash_error(ctx, error))
+}
+
+fn hash_pbkdf2(
+    ctx: &Ctx<'_>,
+    password: &[u8],
+    hash: &str,
+    iterations: u32,
+) -> rquickjs::Result<String> {
+    let algorithm = match hash {
+        "sha256" => pbkdf2::Algorithm::Pbkdf2Sha256,
+        "sha512" => pbkdf2::Algorithm::Pbkdf2Sha512,
+        _ => return Err(unsupported_hash(ctx, hash)),
+    };
+    let params = pbkdf2::Params {
+        rounds: iterations,
+        output_length: 32,
+    };
+    let salt = random_salt(ctx)?;
+    Pbkdf2
+        .hash_password_customized(password, Some(algorithm.ident()), None, params, &salt)
+        .map(|hash| hash.to_string())
+        .map_err(|error| password_hash_error(ctx, error))
+}
+
+/// Verifies a password against a hash in the PHC string format of either algorithm
+fn verify(ctx: &Ctx<'_>, password: &[u8], hash: &str) -> rquickjs::Result<bool> {
+    let hash = PasswordHash::new(hash).map_err(|error| {
+        Exception::throw_syntax(ctx, &format!("Invalid password hash: {error}"))
+    })?;
+    match hash.verify_password(&[&Argon2::default(), &Pbkdf2], password) {
+        Ok(()) => Ok(true),
+        Err(password_hash::Error::Password) => Ok(false),
+        Err(error) => Err(password_hash_error(ctx, error)),
+    }
+}
+
+fn derive_pbkdf2(
+    ctx: &Ctx<'_>,
+    password: &[u8],
+    salt: &[u8],
+    hash: &str,
+    iterations: u32,
+    length: usize,
+) -> rquickjs::Result<Vec<u8>> {
+    if iterations == 0 {
+        return Err(Exception::throw_range(
+            ctx,
+            "The PBKDF2 iteration count must be positive",
+        ));
+    }
+    let mut key = vec![0; length];
+    match hash {
+        "sha256" => pbkdf2::pbkdf2_hmac::<Sha256>(password, salt, iterations, &mut key),
+        "sha512" => pbkdf2::pbkdf2_hmac::<Sha512>(password, salt, iterations, &mut key),
+        _ => return Err(unsupported_hash(ctx, hash)),
+    }
+    Ok(key)
+}
+
+fn unsupported_hash(ctx: &Ctx<'_>, hash: &str) -> rquickjs::Error {
+    Exception::throw_type(
+        ctx,
+        &format!("Unsupported PBKDF2 hash `{hash}`, expected `sha256` or `sha512`"),
+    )
+}
+
+fn password_hash_error(ctx: &Ctx<'_>, error: password_hash::Error) -> rquickjs::Error {
+    Exception::throw_message(ctx, &format!("Password hashing failed: {error}"))
+}
+
+// JS functions for the password hashing API
+pub const PASSWORDS_JS: &str = include_str!("passwords.js");
@@ -0,0 +1,36 @@
+use rquickjs::{Ctx, Exception, Value};
+
+// Stubs of the native password hashing functions, failing with an explanation when used
+#[rquickjs::module(rename = "camelCase")]
+pub mod native_module {
+    use rquickjs::{Ctx, Value};
+
+    #[rquickjs::function]
+    pub fn hash_argon2id<'js>(ctx: Ctx<'js>) -> rquickjs::Result<Value<'js>> {
+        super::unavailable(&ctx)
+    }
+
+    #[rquickjs::function]
+    pub fn hash_pbkdf2<'js>(ctx: Ctx<'js>) -> rquickjs::Result<Value<'js>> {
+        super::unavailable(&ctx)
+    }
+
+    #[rquickjs::function]
+    pub fn verify<'js>(ctx: Ctx<'js>) -> rquickjs::Result<Value<'js>> {
+        super::unavailable(&ctx)
+    }
+
+    #[rquickjs::function]
+    pub fn derive_pbkdf2<'js>(ctx: Ctx<'js>) -> rquickjs::Result<Value<'js>> {
+        super::unavailable(&ctx)
+    }
+}
+
+fn unavailable<'js>(ctx: &Ctx<'js>) -> rquickjs::Result<Value<'js>> {
+    Err(Exception::throw_message(
+        ctx,
+        "The `passwords` module requires the `passwords` feature of the wrapper crate",
+    ))
+}
+
+pub const PASSWORDS_JS: &str = include_str!("passwords.js");
@@ -0,0 +1,313 @@
+// Protocol Buffers wire format primitives
+// See https://protobuf.dev/programming-guides/encoding/
+
+export const WireType = Object.freeze({
+    VARINT: 0,
+    I64: 1,
+    LEN: 2,
+    SGROUP: 3,
+    EGROUP: 4,
+    I32: 5,
+});
+
+const encoder = new TextEncoder();
+const decoder = new TextDecoder();
+
+function toBigInt(value) {
+    return typeof value === 'bigint' ? value : BigInt(Math.trunc(value));
+}
+
+/**
+ * Builds an encoded message from its fields. Each field is written as a `tag(fieldNumber, wireType)`
+ * followed by its value; nested messages are written with `bytes` (or `message`) from the result of
+ * another writer. All methods return the writer, so calls can be chained.
+ */
+export class ProtobufWriter {
+    #bytes = [];
+
+    tag(fieldNumber, wireType) {
+        if (!Number.isInteger(fieldNumber) || fieldNumber < 1 || fieldNumber > 0x1fffffff) {
+            throw new RangeError(`Invalid protobuf field number ${fieldNumber}`);
+        }
+        return this.uint32(fieldNumber * 8 + wireType);
+    }
+
+    uint32(value) {
+        value >>>= 0;
+        while (value > 0x7f) {
+            this.#bytes.push((value & 0x7f) | 0x80);
+            value >>>= 7;
+        }
+        this.#bytes.push(value);
+        return this;
+    }
+
+    // Negative values take 10 bytes, as they are sign-extended to 64 bits
+    int32(value) {
+        value |= 0;
+        return value < 0 ? this.uint64(BigInt(value)) : this.uint32(value);
+    }
+
+    sint32(value) {
+        value |= 0;
+        return this.uint32((value << 1) ^ (value >> 31));
+    }
+
+    uint64(value) {
+        let big = BigInt.asUintN(64, toBigInt(value));
+        while (big > 0x7fn) {
+            this.#bytes.push(Number(big & 0x7fn) | 0x80);
+            big >>= 7n;
+        }
+        this.#bytes.push(Number(big));
+        return this;
+    }
+
+    int64(value) {
+        return this.uint64(value);
+    }
+
+    sint64(value) {
+        const big = BigInt.asIntN(64, toBigInt(value));
+        return this.uint64((big << 1n) ^ (big >> 63n));
+    }
+
+    bool(value) {
+        this.#bytes.push(value ? 1 : 0);
+        return this;
+    }
+
+    fixed32(value) {
+        return this.#fixed(4, (view) => view.setUint32(0, value >>> 0, true));
+    }
+
+    sfixed32(value) {
+        return this.#fixed(4, (view) => view.setInt32(0, value | 0, true));
+    }
+
+    float(value) {
+        return this.#fixed(4, (view) => view.setFloat32(0, value, true));
+    }
+
+    fixed64(value) {
+        return this.#fixed(8, (view) => view.setBigUint64(0, BigInt.asUintN(64, toBigInt(value)), true));
+    }
+
+    sfixed64(value) {
+        return this.#fixed(8, (view) => view.setBigInt64(0, BigInt.asIntN(64, toBigInt(value)), true));
+    }
+
+    double(value) {
+        return this.#fixed(8, (view) => view.setFloat64(0, value, true));
+    }
+
+    // Length-delimited bytes, such as an encoded nested message
+    bytes(value) {
+        const bytes = value instanceof Uint8Array ? value : new Uint8Array(value);
+        this.uint32(bytes.length);
+        for (const byte of bytes) {
+            this.#bytes.push(byte);
+        }
+        return this;
+    }
+
+    string(value) {
+        return this.bytes(encoder.encode(value));
+    }
+
+    // Writes a nested message, built by the given function on a new writer
+    message(build) {
+        const writer = new ProtobufWriter();
+        build(writer);
+        return this.bytes(writer.finish());
+    }
+
+    finish() {
+        return new Uint8Array(this.#bytes);
+    }
+
+    #fixed(size, write) {
+        const view = new DataView(new ArrayBuffer(size));
+        write(view);
+        for (const byte of new Uint8Array(view.buffer)) {
+            this.#bytes.push(byte);
+        }
+        return this;
+    }
+}
+
+/**
+ * Reads the fields of an encoded message. `tag()` reads the next field's number and wire type,
+ * after which its value is read by the method matching its type, or skipped with `skip(wireType)`.
+ * 64-bit integers are read as `BigInt`s.
+ */
+export class ProtobufReader {
+    constructor(bytes) {
+        this.buffer = bytes instanceof Uint8Array ? bytes : new Uint8Array(bytes);
+        this.pos = 0;
+        this.view = new DataView(this.buffer.buffer, this.buffer.byteOffset, this.buffer.byteLength);
+    }
+
+    get length() {
+        return this.buffer.length;
+    }
+
+    eof() {
+        return this.pos >= this.buffer.length;
+    }
+
+    tag() {
+        const tag = this.uint32();
+        const fieldNumber = tag >>> 3;
+        if (fieldNumber === 0) {
+            throw new RangeError(`Invalid protobuf field number 0 at offset ${this.pos}`);
+        }
+        return { fieldNumber, wireType: tag & 7 };
+    }
+
+    uint32() {
+        return Number(BigInt.asUintN(32, this.uint64()));
+    }
+
+    int32() {
+        return Number(BigInt.asIntN(32, this.uint64()));
+    }
+
+    sint32() {
+        const value = this.uint32();
+        return (value >>> 1) ^ -(value & 1);
+    }
+
+    uint64() {
+        let result = 0n;
+        for (let shift = 0n; shift < 70n; shift += 7n) {
+            const byte = this.#byte();
+            result |= BigInt(byte & 0x7f) << shift;
+            if (byte < 0x80) {
+                return BigInt.asUintN(64, result);
+            }
+        }
+        throw new RangeError(`Invalid protobuf varint at offset ${this.pos}`);
+    }
+
+    int64() {
+        return BigInt.asIntN(64, this.uint64());
+    }
+
+    sint64() {
+        const value = this.uint64();
+        return (value >> 1n) ^ -(value & 1n);
+    }
+
+    bool() {
+        return this.uint64() !== 0n;
+    }
+
+    fixed32() {
+        return this.view.getUint32(this.#advance(4), true);
+    }
+
+    sfixed32() {
+        return this.view.getInt32(this.#advance(4), true);
+    }
+
+    float() {
+        return this.view.getFloat32(this.#advance(4), true);
+    }
+
+    fixed64() {
+        return this.view.getBigUint64(this.#advance(8), true);
+    }
+
+    sfixed64() {
+        return this.view.getBigInt64(this.#advance(8), true);
+    }
+
+    double() {
+        return this.view.getFloat64(this.#advance(8), true);
+    }
+
+    bytes() {
+        const length = this.uint32();
+        const start = this.#advance(length);
+        return this.buffer.slice(start, start + length);
+    }
+
+    string() {
+        return decoder.decode(this.bytes());
+    }
+
+    // Reads a nested message with a new reader
+    message() {
+        return new ProtobufReader(this.bytes());
+    }
+
+    skip(wireType) {
+        switch (wireType) {
+            case WireType.VARINT:
+                this.uint64();
+                break;
+            case WireType.I64:
+                this.#advance(8);
+                break;
+            case WireType.LEN:
+                this.#advance(this.uint32());
+                break;
+            case WireType.SGROUP:
+                for (let field = this.tag(); field.wireType !== WireType.EGROUP; field = this.tag()) {
+                    this.skip(field.wireType);
+                }
+                break;
+            case WireType.I32:
+                this.#advance(4);
+                break;
+            default:
+                throw new RangeError(`Invalid protobuf wire type ${wireType} at offset ${this.pos}`);
+        }
+    }
+
+    #byte() {
+        return this.buffer[this.#advance(1)];
+    }
+
+    // Moves past the given number of bytes, returning the offset they start at
+    #advance(count) {
+        const start = this.pos;
+        if (start + count > this.buffer.length) {
+            throw new RangeError(`Truncated protobuf message: expected ${count} more bytes at offset ${start}`);
+        }
+        this.pos += count;
+        return start;
+    }
+}
+
+export function encodeVarint(value) {
+    return new ProtobufWriter().uint64(value).finish();
+}
+
+// Decodes the varint at the offset, returning its value as a `BigInt` and the offset after it
+export function decodeVarint(bytes, offset = 0) {
+    const reader = new ProtobufReader(bytes);
+    reader.pos = offset;
+    const value = reader.uint64();
+    return { value, offset: reader.pos };
+}
+
+// Encodes messages as a stream of length-delimited frames, each prefixed by its varint length
+export function encodeDelimited(messages) {
+    const writer = new ProtobufWriter();
+    for (const message of messages) {
+        writer.bytes(message);
+    }
+    return writer.finish();
+}
+
+// Decodes a stream of length-delimited frames to the messages they contain
+export function decodeDelimited(bytes) {
+    const reader = new ProtobufReader(bytes);
+    const messages = [];
+    while (!reader.eof()) {
+        messages.push(reader.bytes());
+    }
+    return messages;
+}
@@ -0,0 +1,5 @@
+// JS functions for the protobuf wire format implementation
+pub const PROTOBUF_JS: &str = include_str!("protobuf.js");
+
+// JS functions for the gRPC-web client, built on fetch
+pub const GRPC_WEB_JS: &str = include_str!("grpc-web.js");
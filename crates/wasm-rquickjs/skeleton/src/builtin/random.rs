@@ -0,0 +1,58 @@
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use std::cell::RefCell;
+
+/// How `Math.random` generates its numbers
+#[allow(dead_code)]
+pub enum MathRandomSeed {
+    /// The builtin generator of QuickJS
+    Default,
+    /// A deterministic generator seeded by a fixed seed
+    Fixed(u64),
+    /// A deterministic generator seeded by the host through `wasi:random/insecure-seed`
+    Host,
+}
+
+thread_local! {
+    // A named algorithm instead of `StdRng`, which may change between releases of `rand`, so a fixed
+    // seed gives the same numbers across versions of the generated crates
+    static RNG: RefCell<Option<ChaCha8Rng>> = const { RefCell::new(None) };
+}
+
+// Native functions for the deterministic Math.random implementation
+#[rquickjs::module]
+pub mod native_module {
+    #[rquickjs::function]
+    pub fn random() -> f64 {
+        super::next_random()
+    }
+}
+
+/// Generates the next number, seeding the generator on the first call
+fn next_random() -> f64 {
+    RNG.with_borrow_mut(|rng| {
+        rng.get_or_insert_with(|| ChaCha8Rng::seed_from_u64(seed()))
+            .random()
+    })
+}
+
+fn seed() -> u64 {
+    match crate::MATH_RANDOM_SEED {
+        MathRandomSeed::Fixed(seed) => seed,
+        MathRandomSeed::Default | MathRandomSeed::Host => {
+            let (high, low) = wasi::random::insecure_seed::insecure_seed();
+            high ^ low
+        }
+    }
+}
+
+/// Whether `Math.random` is replaced by the deterministic generator
+pub fn is_enabled() -> bool {
+    !matches!(crate::MATH_RANDOM_SEED, MathRandomSeed::Default)
+}
+
+// JS code replacing Math.random by the deterministic generator
+pub const WIRE_JS: &str = r#"
+        import * as __wasm_rquickjs_random_native from '__wasm_rquickjs_builtin/random_native';
+        Math.random = __wasm_rquickjs_random_native.random;
+    "#;
@@ -0,0 +1,123 @@
+import { Regex as NativeRegex, escape as nativeEscape } from '__wasm_rquickjs_builtin/re_native';
+
+function startIndex(start) {
+    const index = Number(start ?? 0);
+    if (!Number.isInteger(index) || index < 0) {
+        throw new RangeError(`Invalid start index ${start}, expected a non-negative integer`);
+    }
+    return index;
+}
+
+/**
+ * A regular expression of the Rust `regex` crate, which runs in time linear to the text but has no
+ * backreferences or lookaround. Its matches are arrays like the ones of `RegExp.prototype.exec`, with
+ * the `index` of the match, the `input` and the named `groups`; indices count UTF-16 code units.
+ */
+export class Regex {
+    #native;
+    #names;
+
+    constructor(pattern, flags = '') {
+        this.source = String(pattern);
+        this.flags = String(flags);
+        this.#native = new NativeRegex(this.source, this.flags);
+        this.#names = this.#native.captureNames;
+    }
+
+    // The names of the named capture groups
+    get groupNames() {
+        return this.#names.filter((name) => name !== undefined);
+    }
+
+    test(text, start) {
+        return this.#native.isMatch(String(text), startIndex(start));
+    }
+
+    // The first match at or after `start`, or `null`
+    exec(text, start) {
+        const input = String(text);
+        const offsets = this.#native.find(input, startIndex(start));
+        return offsets === undefined ? null : this.#match(input, offsets, 0);
+    }
+
+    // All the non-overlapping matches
+    matchAll(text) {
+        const input = String(text);
+        const offsets = this.#native.findAll(input);
+        const stride = this.#names.length * 2;
+        const matches = [];
+        for (let position = 0; position < offsets.length; position += stride) {
+            matches.push(this.#match(input, offsets, position));
+        }
+        return matches;
+    }
+
+    // Replaces the first match, see `replaceAll`
+    replace(text, replacement) {
+        return this.#replace(String(text), replacement, 1);
+    }
+
+    /**
+     * Replaces all the matches, with a string in which `$1`, `$name`, `${name}` and `$$` are expanded
+     * (following the `regex` crate rather than `String.prototype.replace`), or with the result of a
+     * function called with the same arguments as by `String.prototype.replace`.
+     */
+    replaceAll(text, replacement) {
+        return this.#replace(String(text), replacement, 0);
+    }
+
+    // Splits the text at the matches, into at most `limit` parts if given
+    split(text, limit) {
+        return this.#native.split(String(text), limit === undefined ? undefined : startIndex(limit));
+    }
+
+    toString() {
+        return `/${this.source}/${this.flags}`;
+    }
+
+    #match(input, offsets, position) {
+        const match = [];
+        let groups = undefined;
+        for (let group = 0; group < this.#names.length; group++) {
+            const start = offsets[position + group * 2];
+            const value = start < 0 ? undefined : input.slice(start, offsets[position + group * 2 + 1]);
+            match.push(value);
+            const name = this.#names[group];
+            if (name !== undefined) {
+                groups ??= Object.create(null);
+                groups[name] = value;
+            }
+        }
+        match.index = offsets[position];
+        match.input = input;
+        match.groups = groups;
+        return match;
+    }
+
+    #replace(input, replacement, limit) {
+        if (typeof replacement !== 'function') {
+            return this.#native.replace(input, String(replacement), limit);
+        }
+        const matches = limit === 1 ? [this.exec(input)].filter((match) => match !== null) : this.matchAll(input);
+        let result = '';
+        let last = 0;
+        for (const match of matches) {
+            const args = [...match, match.index, input];
+            if (match.groups !== undefined) {
+                args.push(match.groups);
+            }
+            result += input.slice(last, match.index) + String(replacement(...args));
+            last = match.index + match[0].length;
+        }
+        return result + input.slice(last);
+    }
+}
+
+export function compile(pattern, flags) {
+    return new Regex(pattern, flags);
+}
+
+// Escapes the special characters of the `regex` syntax in the text, to match it literally
+export function escape(text) {
+    return nativeEscape(String(text));
+}
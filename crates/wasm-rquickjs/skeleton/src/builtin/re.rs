@@ -0,0 +1,152 @@
+// Native regular expressions of the `regex` crate
+#[rquickjs::module(rename = "camelCase")]
+pub mod native_module {
+    use rquickjs::class::Trace;
+    use rquickjs::{Ctx, Exception, JsLifetime};
+
+    #[derive(JsLifetime, Trace)]
+    #[rquickjs::class(rename = "Regex")]
+    pub struct JsRegex {
+        #[qjs(skip_trace)]
+        regex: regex::Regex,
+    }
+
+    #[rquickjs::methods(rename_all = "camelCase")]
+    impl JsRegex {
+        #[qjs(constructor)]
+        pub fn new(pattern: String, flags: String, ctx: Ctx<'_>) -> rquickjs::Result<Self> {
+            let mut builder = regex::RegexBuilder::new(&pattern);
+            for flag in flags.chars() {
+                match flag {
+                    'i' => builder.case_insensitive(true),
+                    'm' => builder.multi_line(true),
+                    's' => builder.dot_matches_new_line(true),
+                    'x' => builder.ignore_whitespace(true),
+                    _ => {
+                        return Err(Exception::throw_type(
+                            &ctx,
+                            &format!("Unsupported regex flag `{flag}`, expected i, m, s or x"),
+                        ));
+                    }
+                };
+            }
+            let regex = builder.build().map_err(|error| {
+                Exception::throw_syntax(&ctx, &format!("Invalid regular expression: {error}"))
+            })?;
+            Ok(Self { regex })
+        }
+
+        /// The names of the capture groups, with `undefined` for the whole match and unnamed groups
+        #[qjs(get)]
+        pub fn capture_names(&self) -> Vec<Option<String>> {
+            self.regex
+                .capture_names()
+                .map(|name| name.map(str::to_string))
+                .collect()
+        }
+
+        pub fn is_match(&self, text: String, start: usize) -> bool {
+            super::byte_offset(&text, start)
+                .is_some_and(|start| self.regex.is_match_at(&text, start))
+        }
+
+        /// The UTF-16 start and end offsets of the first match and its groups from `start`, with
+        /// -1 for groups that did not participate
+        pub fn find(&self, text: String, start: usize) -> Option<Vec<f64>> {
+            let start = super::byte_offset(&text, start)?;
+            let mut cursor = super::Utf16Cursor::new(&text);
+            self.regex
+                .captures_at(&text, start)
+                .map(|captures| cursor.offsets(&captures))
+        }
+
+        /// The offsets of all the non-overlapping matches, as `find` returns them, one after the other
+        pub fn find_all(&self, text: String) -> Vec<f64> {
+            let mut cursor = super::Utf16Cursor::new(&text);
+            self.regex
+                .captures_iter(&text)
+                .flat_map(|captures| cursor.offsets(&captures))
+                .collect()
+        }
+
+        /// Replaces the first `limit` matches, or all of them if it is 0, expanding `$1`, `$name`
+        /// and `${name}` in the replacement
+        pub fn replace(&self, text: String, replacement: String, limit: usize) -> String {
+            self.regex
+                .replacen(&text, limit, replacement.as_str())
+                .into_owned()
+        }
+
+        pub fn split(&self, text: String, limit: Option<usize>) -> Vec<String> {
+            match limit {
+                Some(limit) => self
+                    .regex
+                    .splitn(&text, limit)
+                    .map(str::to_string)
+                    .collect(),
+                None => self.regex.split(&text).map(str::to_string).collect(),
+            }
+        }
+    }
+
+    #[rquickjs::function]
+    pub fn escape(text: String) -> String {
+        regex::escape(&text)
+    }
+}
+
+/// Converts an offset in UTF-16 code units, as JS strings are indexed, to a byte offset, or nothing
+/// if it is past the end of the text. Offsets inside a character are moved to its end.
+fn byte_offset(text: &str, offset: usize) -> Option<usize> {
+    let mut units = 0;
+    for (index, char) in text.char_indices() {
+        if units >= offset {
+            return Some(index);
+        }
+        units += char.len_utf16();
+    }
+    (units >= offset).then_some(text.len())
+}
+
+/// Converts byte offsets into a text to UTF-16 offsets, counting from the previously converted one
+struct Utf16Cursor<'a> {
+    text: &'a str,
+    byte: usize,
+    unit: usize,
+}
+
+impl<'a> Utf16Cursor<'a> {
+    fn new(text: &'a str) -> Self {
+        Self {
+            text,
+            byte: 0,
+            unit: 0,
+        }
+    }
+
+    fn seek(&mut self, byte: usize) -> usize {
+        if byte >= self.byte {
+            self.unit += self.text[self.byte..byte].encode_utf16().count();
+        } else {
+            self.unit -= self.text[byte..self.byte].encode_utf16().count();
+        }
+        self.byte = byte;
+        self.unit
+    }
+
+    fn offsets(&mut self, captures: &regex::Captures<'_>) -> Vec<f64> {
+        captures
+            .iter()
+            .flat_map(|group| match group {
+                Some(group) => [
+                    self.seek(group.start()) as f64,
+                    self.seek(group.end()) as f64,
+                ],
+                None => [-1.0, -1.0],
+            })
+            .collect()
+    }
+}
+
+// JS functions for the regular expression API
+pub const RE_JS: &str = include_str!("re.js");
@@ -0,0 +1,34 @@
+use rquickjs::{Ctx, Exception};
+
+// Stubs of the native regular expressions, failing with an explanation when used
+#[rquickjs::module(rename = "camelCase")]
+pub mod native_module {
+    use rquickjs::class::Trace;
+    use rquickjs::{Ctx, JsLifetime, Value};
+
+    #[derive(JsLifetime, Trace)]
+    #[rquickjs::class(rename = "Regex")]
+    pub struct JsRegex {}
+
+    #[rquickjs::methods]
+    impl JsRegex {
+        #[qjs(constructor)]
+        pub fn new(ctx: Ctx<'_>) -> rquickjs::Result<Self> {
+            Err(super::unavailable(&ctx))
+        }
+    }
+
+    #[rquickjs::function]
+    pub fn escape<'js>(ctx: Ctx<'js>) -> rquickjs::Result<Value<'js>> {
+        Err(super::unavailable(&ctx))
+    }
+}
+
+fn unavailable(ctx: &Ctx<'_>) -> rquickjs::Error {
+    Exception::throw_message(
+        ctx,
+        "The `re` module requires the `regex` feature of the wrapper crate",
+    )
+}
+
+pub const RE_JS: &str = include_str!("re.js");
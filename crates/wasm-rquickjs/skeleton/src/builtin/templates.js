@@ -0,0 +1,14 @@
+import { render as nativeRender, render_asset } from '__wasm_rquickjs_builtin/templates_native';
+
+export function render(source, data, options) {
+    return nativeRender(String(source), data, options?.autoescape ?? true);
+}
+
+export function renderAsset(name, data) {
+    return render_asset(String(name), data);
+}
+
+export default {
+    render,
+    renderAsset
+};
@@ -0,0 +1,121 @@
+use super::data_model::{Node, to_node};
+use minijinja::{Environment, ErrorKind, Value};
+use rquickjs::{Ctx, Exception, Type};
+use std::cell::RefCell;
+use std::error::Error;
+
+// Native template rendering with minijinja, loading the included templates from the assets
+#[rquickjs::module(rename = "camelCase")]
+pub mod native_module {
+    use rquickjs::{Ctx, Value};
+
+    #[rquickjs::function]
+    pub fn render<'js>(
+        source: String,
+        data: Value<'js>,
+        autoescape: bool,
+        ctx: Ctx<'js>,
+    ) -> rquickjs::Result<String> {
+        let data = super::context(&ctx, data)?;
+        super::ENVIRONMENT
+            .with_borrow_mut(|environment| {
+                environment.set_auto_escape_callback(move |name| {
+                    if name != super::INLINE_NAME {
+                        minijinja::default_auto_escape_callback(name)
+                    } else if autoescape {
+                        minijinja::AutoEscape::Html
+                    } else {
+                        minijinja::AutoEscape::None
+                    }
+                });
+                environment.render_named_str(super::INLINE_NAME, &source, data)
+            })
+            .map_err(|error| super::template_error(&ctx, error))
+    }
+
+    #[rquickjs::function]
+    pub fn render_asset<'js>(
+        name: String,
+        data: Value<'js>,
+        ctx: Ctx<'js>,
+    ) -> rquickjs::Result<String> {
+        let data = super::context(&ctx, data)?;
+        super::ENVIRONMENT
+            .with_borrow(|environment| environment.get_template(&name)?.render(data))
+            .map_err(|error| super::template_error(&ctx, error))
+    }
+}
+
+/// The name of the templates given as strings in the errors
+const INLINE_NAME: &str = "<string>";
+
+thread_local! {
+    static ENVIRONMENT: RefCell<Environment<'static>> = RefCell::new(environment());
+}
+
+/// The environment of the templates, with the embedded assets as the templates that can be
+/// rendered by name, included, imported and extended. These are compiled once and escaped
+/// according to their file extension, while the templates given as strings are escaped as HTML
+/// unless disabled.
+fn environment() -> Environment<'static> {
+    let mut environment = Environment::new();
+    environment.set_loader(|name| match super::assets::find_asset(name) {
+        Some(bytes) => std::str::from_utf8(bytes)
+            .map(|text| Some(text.to_string()))
+            .map_err(|err| {
+                minijinja::Error::new(
+                    ErrorKind::InvalidOperation,
+                    format!("Asset {name} is not valid UTF-8: {err}"),
+                )
+            }),
+        None => Ok(None),
+    });
+    environment
+}
+
+/// Converts the data of a template, an object or `undefined`
+fn context<'js>(ctx: &Ctx<'js>, data: rquickjs::Value<'js>) -> rquickjs::Result<Value> {
+    match data.type_of() {
+        Type::Undefined | Type::Null => Ok(Value::from(())),
+        Type::Object => Ok(from_node(to_node(ctx, data, &mut Vec::new())?)),
+        _ => Err(Exception::throw_type(
+            ctx,
+            "The data of a template must be an object",
+        )),
+    }
+}
+
+fn from_node(node: Node) -> Value {
+    match node {
+        Node::Undefined => Value::UNDEFINED,
+        Node::Null => Value::from(()),
+        Node::Bool(value) => Value::from(value),
+        Node::Int(value) => Value::from(value),
+        Node::Float(value) => Value::from(value),
+        Node::String(value) | Node::Date(value) => Value::from(value),
+        Node::Array(nodes) => nodes.into_iter().map(from_node).collect(),
+        Node::Map(entries) => entries
+            .into_iter()
+            .filter(|(_, value)| !matches!(value, Node::Undefined))
+            .map(|(key, value)| (from_node(key), from_node(value)))
+            .collect(),
+    }
+}
+
+/// Syntax errors of the templates are thrown as `SyntaxError`s, the others as `Error`s, with the
+/// name and line of the template where they happened
+fn template_error(ctx: &Ctx<'_>, error: minijinja::Error) -> rquickjs::Error {
+    let mut message = error.to_string();
+    let mut source = error.source();
+    while let Some(error) = source {
+        message.push_str(&format!(": {error}"));
+        source = error.source();
+    }
+    match error.kind() {
+        ErrorKind::SyntaxError => Exception::throw_syntax(ctx, &message),
+        _ => Exception::throw_message(ctx, &message),
+    }
+}
+
+// JS module of the template engine
+pub const TEMPLATES_JS: &str = include_str!("templates.js");
@@ -0,0 +1,23 @@
+// Stubs of the native template rendering, failing with an explanation when used
+#[rquickjs::module(rename = "camelCase")]
+pub mod native_module {
+    use rquickjs::{Ctx, Exception};
+
+    #[rquickjs::function]
+    pub fn render(ctx: Ctx<'_>) -> rquickjs::Result<String> {
+        Err(Exception::throw_message(
+            &ctx,
+            "The `templates` module requires the `templates` feature of the wrapper crate",
+        ))
+    }
+
+    #[rquickjs::function]
+    pub fn render_asset(ctx: Ctx<'_>) -> rquickjs::Result<String> {
+        Err(Exception::throw_message(
+            &ctx,
+            "The `templates` module requires the `templates` feature of the wrapper crate",
+        ))
+    }
+}
+
+pub const TEMPLATES_JS: &str = include_str!("templates.js");
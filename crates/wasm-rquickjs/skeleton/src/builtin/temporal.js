@@ -0,0 +1,43 @@
+import { Duration, Instant, PlainDate, ZonedDateTime } from '__wasm_rquickjs_builtin/temporal_native';
+
+for (const type of [Duration, Instant, PlainDate, ZonedDateTime]) {
+    Object.defineProperties(type.prototype, {
+        [Symbol.toStringTag]: { value: `Temporal.${type.name}`, configurable: true },
+        toJSON: {
+            value: function toJSON() {
+                return this.toString();
+            },
+            writable: true,
+            configurable: true,
+        },
+        // Like in Temporal, relational operators cannot be used to compare values
+        valueOf: {
+            value: function valueOf() {
+                throw new TypeError(`Use ${type.name}.compare or equals to compare Temporal values`);
+            },
+            writable: true,
+            configurable: true,
+        },
+    });
+}
+
+// The current time, following the clock configured for `Date`. Components have no local time zone, so
+// UTC is used unless a time zone is given.
+export const Now = {
+    timeZoneId() {
+        return 'UTC';
+    },
+    instant() {
+        return Instant.fromEpochMilliseconds(Date.now());
+    },
+    zonedDateTimeISO(timeZone = 'UTC') {
+        return Now.instant().toZonedDateTimeISO(timeZone);
+    },
+    plainDateISO(timeZone = 'UTC') {
+        return Now.zonedDateTimeISO(timeZone).toPlainDate();
+    },
+};
+
+export { Duration, Instant, PlainDate, ZonedDateTime };
+
+export const Temporal = { Duration, Instant, Now, PlainDate, ZonedDateTime };
@@ -0,0 +1,1343 @@
+use jiff::civil::{Date, DateTime};
+use jiff::tz::{Offset, TimeZone};
+use jiff::{RoundMode, Span, SpanRelativeTo, Timestamp, Unit, Zoned};
+use rquickjs::{Class, Ctx, Exception, Function, Object, Value};
+use std::fmt::Display;
+
+// Native date and time types backed by the `jiff` crate, following the Temporal proposal
+#[rquickjs::module(rename = "camelCase")]
+pub mod native_module {
+    use jiff::civil::{Date, DateDifference};
+    use jiff::{Span, SpanArithmetic, SpanRound, Timestamp, TimestampDifference, TimestampRound};
+    use jiff::{Zoned, ZonedDifference, ZonedRound};
+    use rquickjs::class::Trace;
+    use rquickjs::function::Opt;
+    use rquickjs::{Ctx, Exception, JsLifetime, Object, Value};
+
+    use super::{
+        Relative, bigint, bigint_to_i128, configure, date_from, duration_from, field, instant_from,
+        integer, offset_string, range_error, rounding, time_zone, time_zone_id, unit, zoned_from,
+    };
+
+    #[derive(Clone, JsLifetime, Trace)]
+    #[rquickjs::class(rename = "Instant")]
+    pub struct JsInstant {
+        #[qjs(skip_trace)]
+        pub(super) timestamp: Timestamp,
+    }
+
+    #[rquickjs::methods(rename_all = "camelCase")]
+    impl JsInstant {
+        /// Creates an instant from the nanoseconds since the Unix epoch, as a `BigInt`
+        #[qjs(constructor)]
+        pub fn new<'js>(epoch_nanoseconds: Value<'js>, ctx: Ctx<'js>) -> rquickjs::Result<Self> {
+            let nanoseconds = bigint_to_i128(&ctx, epoch_nanoseconds)?;
+            Timestamp::from_nanosecond(nanoseconds)
+                .map(Into::into)
+                .map_err(|error| range_error(&ctx, error))
+        }
+
+        #[qjs(static)]
+        pub fn from<'js>(value: Value<'js>, ctx: Ctx<'js>) -> rquickjs::Result<Self> {
+            instant_from(&ctx, value).map(Into::into)
+        }
+
+        #[qjs(static)]
+        pub fn from_epoch_milliseconds(milliseconds: f64, ctx: Ctx<'_>) -> rquickjs::Result<Self> {
+            let milliseconds = integer(&ctx, "epochMilliseconds", milliseconds)?;
+            Timestamp::from_millisecond(milliseconds)
+                .map(Into::into)
+                .map_err(|error| range_error(&ctx, error))
+        }
+
+        #[qjs(static)]
+        pub fn from_epoch_nanoseconds<'js>(
+            nanoseconds: Value<'js>,
+            ctx: Ctx<'js>,
+        ) -> rquickjs::Result<Self> {
+            Self::new(nanoseconds, ctx)
+        }
+
+        #[qjs(static)]
+        pub fn compare<'js>(
+            first: Value<'js>,
+            second: Value<'js>,
+            ctx: Ctx<'js>,
+        ) -> rquickjs::Result<i32> {
+            let first = instant_from(&ctx, first)?;
+            let second = instant_from(&ctx, second)?;
+            Ok(first.cmp(&second) as i32)
+        }
+
+        #[qjs(get)]
+        pub fn epoch_milliseconds(&self) -> f64 {
+            self.timestamp.as_millisecond() as f64
+        }
+
+        #[qjs(get)]
+        pub fn epoch_nanoseconds<'js>(&self, ctx: Ctx<'js>) -> rquickjs::Result<Value<'js>> {
+            bigint(&ctx, self.timestamp.as_nanosecond())
+        }
+
+        /// Adds a duration of hours or smaller units
+        pub fn add<'js>(&self, duration: Value<'js>, ctx: Ctx<'js>) -> rquickjs::Result<Self> {
+            let span = duration_from(&ctx, duration)?;
+            self.timestamp
+                .checked_add(span)
+                .map(Into::into)
+                .map_err(|error| range_error(&ctx, error))
+        }
+
+        pub fn subtract<'js>(&self, duration: Value<'js>, ctx: Ctx<'js>) -> rquickjs::Result<Self> {
+            let span = duration_from(&ctx, duration)?;
+            self.timestamp
+                .checked_sub(span)
+                .map(Into::into)
+                .map_err(|error| range_error(&ctx, error))
+        }
+
+        pub fn until<'js>(
+            &self,
+            other: Value<'js>,
+            options: Opt<Value<'js>>,
+            ctx: Ctx<'js>,
+        ) -> rquickjs::Result<JsDuration> {
+            let other = instant_from(&ctx, other)?;
+            let rounding = rounding(&ctx, options.0)?;
+            let difference = configure!(
+                TimestampDifference::new(other),
+                rounding,
+                largest,
+                smallest,
+                mode,
+                increment
+            );
+            self.timestamp
+                .until(difference)
+                .map(Into::into)
+                .map_err(|error| range_error(&ctx, error))
+        }
+
+        pub fn since<'js>(
+            &self,
+            other: Value<'js>,
+            options: Opt<Value<'js>>,
+            ctx: Ctx<'js>,
+        ) -> rquickjs::Result<JsDuration> {
+            let other = instant_from(&ctx, other)?;
+            let rounding = rounding(&ctx, options.0)?;
+            let difference = configure!(
+                TimestampDifference::new(other),
+                rounding,
+                largest,
+                smallest,
+                mode,
+                increment
+            );
+            self.timestamp
+                .since(difference)
+                .map(Into::into)
+                .map_err(|error| range_error(&ctx, error))
+        }
+
+        pub fn round<'js>(&self, options: Value<'js>, ctx: Ctx<'js>) -> rquickjs::Result<Self> {
+            let rounding = rounding(&ctx, Some(options))?;
+            let round = configure!(TimestampRound::new(), rounding, smallest, mode, increment);
+            self.timestamp
+                .round(round)
+                .map(Into::into)
+                .map_err(|error| range_error(&ctx, error))
+        }
+
+        pub fn equals<'js>(&self, other: Value<'js>, ctx: Ctx<'js>) -> rquickjs::Result<bool> {
+            Ok(self.timestamp == instant_from(&ctx, other)?)
+        }
+
+        #[qjs(rename = "toZonedDateTimeISO")]
+        pub fn to_zoned_date_time_iso<'js>(
+            &self,
+            time_zone_id: Value<'js>,
+            ctx: Ctx<'js>,
+        ) -> rquickjs::Result<JsZonedDateTime> {
+            let time_zone = time_zone(&ctx, time_zone_id)?;
+            Ok(self.timestamp.to_zoned(time_zone).into())
+        }
+
+        /// The RFC 3339 form, in UTC or with the offset of the `timeZone` option
+        pub fn to_string<'js>(
+            &self,
+            options: Opt<Object<'js>>,
+            ctx: Ctx<'js>,
+        ) -> rquickjs::Result<String> {
+            let time_zone_id = match &options.0 {
+                Some(options) => options.get::<_, Option<Value<'js>>>("timeZone")?,
+                None => None,
+            };
+            match time_zone_id {
+                Some(time_zone_id) => {
+                    let zoned = self.timestamp.to_zoned(time_zone(&ctx, time_zone_id)?);
+                    Ok(self
+                        .timestamp
+                        .display_with_offset(zoned.offset())
+                        .to_string())
+                }
+                None => Ok(self.timestamp.to_string()),
+            }
+        }
+    }
+
+    impl From<Timestamp> for JsInstant {
+        fn from(timestamp: Timestamp) -> Self {
+            Self { timestamp }
+        }
+    }
+
+    #[derive(Clone, JsLifetime, Trace)]
+    #[rquickjs::class(rename = "PlainDate")]
+    pub struct JsPlainDate {
+        #[qjs(skip_trace)]
+        pub(super) date: Date,
+    }
+
+    #[rquickjs::methods(rename_all = "camelCase")]
+    impl JsPlainDate {
+        #[qjs(constructor)]
+        pub fn new(year: f64, month: f64, day: f64, ctx: Ctx<'_>) -> rquickjs::Result<Self> {
+            let date = Date::new(
+                integer(&ctx, "year", year)?,
+                integer(&ctx, "month", month)?,
+                integer(&ctx, "day", day)?,
+            )
+            .map_err(|error| range_error(&ctx, error))?;
+            Ok(date.into())
+        }
+
+        #[qjs(static)]
+        pub fn from<'js>(value: Value<'js>, ctx: Ctx<'js>) -> rquickjs::Result<Self> {
+            date_from(&ctx, value).map(Into::into)
+        }
+
+        #[qjs(static)]
+        pub fn compare<'js>(
+            first: Value<'js>,
+            second: Value<'js>,
+            ctx: Ctx<'js>,
+        ) -> rquickjs::Result<i32> {
+            let first = date_from(&ctx, first)?;
+            let second = date_from(&ctx, second)?;
+            Ok(first.cmp(&second) as i32)
+        }
+
+        #[qjs(get)]
+        pub fn year(&self) -> i16 {
+            self.date.year()
+        }
+
+        #[qjs(get)]
+        pub fn month(&self) -> i8 {
+            self.date.month()
+        }
+
+        #[qjs(get)]
+        pub fn day(&self) -> i8 {
+            self.date.day()
+        }
+
+        /// The day of the week, from 1 for Monday to 7 for Sunday
+        #[qjs(get)]
+        pub fn day_of_week(&self) -> i8 {
+            self.date.weekday().to_monday_one_offset()
+        }
+
+        #[qjs(get)]
+        pub fn day_of_year(&self) -> i16 {
+            self.date.day_of_year()
+        }
+
+        #[qjs(get)]
+        pub fn days_in_month(&self) -> i8 {
+            self.date.days_in_month()
+        }
+
+        #[qjs(get)]
+        pub fn days_in_year(&self) -> i16 {
+            self.date.days_in_year()
+        }
+
+        #[qjs(get)]
+        pub fn in_leap_year(&self) -> bool {
+            self.date.in_leap_year()
+        }
+
+        /// Adds a duration, ending on the last day of the month if the day does not exist in it
+        pub fn add<'js>(&self, duration: Value<'js>, ctx: Ctx<'js>) -> rquickjs::Result<Self> {
+            let span = duration_from(&ctx, duration)?;
+            self.date
+                .checked_add(span)
+                .map(Into::into)
+                .map_err(|error| range_error(&ctx, error))
+        }
+
+        pub fn subtract<'js>(&self, duration: Value<'js>, ctx: Ctx<'js>) -> rquickjs::Result<Self> {
+            let span = duration_from(&ctx, duration)?;
+            self.date
+                .checked_sub(span)
+                .map(Into::into)
+                .map_err(|error| range_error(&ctx, error))
+        }
+
+        pub fn until<'js>(
+            &self,
+            other: Value<'js>,
+            options: Opt<Value<'js>>,
+            ctx: Ctx<'js>,
+        ) -> rquickjs::Result<JsDuration> {
+            let other = date_from(&ctx, other)?;
+            let rounding = rounding(&ctx, options.0)?;
+            let difference = configure!(
+                DateDifference::new(other),
+                rounding,
+                largest,
+                smallest,
+                mode,
+                increment
+            );
+            self.date
+                .until(difference)
+                .map(Into::into)
+                .map_err(|error| range_error(&ctx, error))
+        }
+
+        pub fn since<'js>(
+            &self,
+            other: Value<'js>,
+            options: Opt<Value<'js>>,
+            ctx: Ctx<'js>,
+        ) -> rquickjs::Result<JsDuration> {
+            let other = date_from(&ctx, other)?;
+            let rounding = rounding(&ctx, options.0)?;
+            let difference = configure!(
+                DateDifference::new(other),
+                rounding,
+                largest,
+                smallest,
+                mode,
+                increment
+            );
+            self.date
+                .since(difference)
+                .map(Into::into)
+                .map_err(|error| range_error(&ctx, error))
+        }
+
+        /// A copy with the given `year`, `month` and `day` fields replaced
+        pub fn with(&self, fields: Object<'_>, ctx: Ctx<'_>) -> rquickjs::Result<Self> {
+            let mut with = self.date.with();
+            if let Some(year) = field(&ctx, &fields, "year")? {
+                with = with.year(year);
+            }
+            if let Some(month) = field(&ctx, &fields, "month")? {
+                with = with.month(month);
+            }
+            if let Some(day) = field(&ctx, &fields, "day")? {
+                with = with.day(day);
+            }
+            with.build()
+                .map(Into::into)
+                .map_err(|error| range_error(&ctx, error))
+        }
+
+        pub fn equals<'js>(&self, other: Value<'js>, ctx: Ctx<'js>) -> rquickjs::Result<bool> {
+            Ok(self.date == date_from(&ctx, other)?)
+        }
+
+        /// The start of the day in the time zone
+        pub fn to_zoned_date_time<'js>(
+            &self,
+            time_zone_id: Value<'js>,
+            ctx: Ctx<'js>,
+        ) -> rquickjs::Result<JsZonedDateTime> {
+            let time_zone = time_zone(&ctx, time_zone_id)?;
+            self.date
+                .to_zoned(time_zone)
+                .and_then(|zoned| zoned.start_of_day())
+                .map(Into::into)
+                .map_err(|error| range_error(&ctx, error))
+        }
+
+        pub fn to_string(&self) -> String {
+            self.date.to_string()
+        }
+    }
+
+    impl From<Date> for JsPlainDate {
+        fn from(date: Date) -> Self {
+            Self { date }
+        }
+    }
+
+    #[derive(Clone, JsLifetime, Trace)]
+    #[rquickjs::class(rename = "ZonedDateTime")]
+    pub struct JsZonedDateTime {
+        #[qjs(skip_trace)]
+        pub(super) zoned: Zoned,
+    }
+
+    #[rquickjs::methods(rename_all = "camelCase")]
+    impl JsZonedDateTime {
+        #[qjs(constructor)]
+        pub fn new<'js>(
+            epoch_nanoseconds: Value<'js>,
+            time_zone_id: Value<'js>,
+            ctx: Ctx<'js>,
+        ) -> rquickjs::Result<Self> {
+            let nanoseconds = bigint_to_i128(&ctx, epoch_nanoseconds)?;
+            let timestamp = Timestamp::from_nanosecond(nanoseconds)
+                .map_err(|error| range_error(&ctx, error))?;
+            Ok(timestamp.to_zoned(time_zone(&ctx, time_zone_id)?).into())
+        }
+
+        #[qjs(static)]
+        pub fn from<'js>(value: Value<'js>, ctx: Ctx<'js>) -> rquickjs::Result<Self> {
+            zoned_from(&ctx, value).map(Into::into)
+        }
+
+        /// Compares the instants, regardless of the time zones
+        #[qjs(static)]
+        pub fn compare<'js>(
+            first: Value<'js>,
+            second: Value<'js>,
+            ctx: Ctx<'js>,
+        ) -> rquickjs::Result<i32> {
+            let first = zoned_from(&ctx, first)?;
+            let second = zoned_from(&ctx, second)?;
+            Ok(first.timestamp().cmp(&second.timestamp()) as i32)
+        }
+
+        #[qjs(get)]
+        pub fn time_zone_id(&self) -> String {
+            time_zone_id(&self.zoned)
+        }
+
+        #[qjs(get)]
+        pub fn year(&self) -> i16 {
+            self.zoned.year()
+        }
+
+        #[qjs(get)]
+        pub fn month(&self) -> i8 {
+            self.zoned.month()
+        }
+
+        #[qjs(get)]
+        pub fn day(&self) -> i8 {
+            self.zoned.day()
+        }
+
+        #[qjs(get)]
+        pub fn hour(&self) -> i8 {
+            self.zoned.hour()
+        }
+
+        #[qjs(get)]
+        pub fn minute(&self) -> i8 {
+            self.zoned.minute()
+        }
+
+        #[qjs(get)]
+        pub fn second(&self) -> i8 {
+            self.zoned.second()
+        }
+
+        #[qjs(get)]
+        pub fn millisecond(&self) -> i16 {
+            self.zoned.millisecond()
+        }
+
+        #[qjs(get)]
+        pub fn microsecond(&self) -> i16 {
+            self.zoned.microsecond()
+        }
+
+        #[qjs(get)]
+        pub fn nanosecond(&self) -> i16 {
+            self.zoned.nanosecond()
+        }
+
+        #[qjs(get)]
+        pub fn day_of_week(&self) -> i8 {
+            self.zoned.weekday().to_monday_one_offset()
+        }
+
+        #[qjs(get)]
+        pub fn day_of_year(&self) -> i16 {
+            self.zoned.day_of_year()
+        }
+
+        #[qjs(get)]
+        pub fn days_in_month(&self) -> i8 {
+            self.zoned.days_in_month()
+        }
+
+        /// The length of the day in hours, which differs from 24 on daylight saving time changes
+        #[qjs(get)]
+        pub fn hours_in_day(&self, ctx: Ctx<'_>) -> rquickjs::Result<f64> {
+            let start = self
+                .zoned
+                .start_of_day()
+                .map_err(|error| range_error(&ctx, error))?;
+            let end = self
+                .zoned
+                .tomorrow()
+                .and_then(|tomorrow| tomorrow.start_of_day())
+                .map_err(|error| range_error(&ctx, error))?;
+            Ok(start.duration_until(&end).as_secs_f64() / 3600.0)
+        }
+
+        /// The offset from UTC, such as `+01:00`
+        #[qjs(get)]
+        pub fn offset(&self) -> String {
+            offset_string(self.zoned.offset())
+        }
+
+        #[qjs(get)]
+        pub fn offset_nanoseconds(&self) -> f64 {
+            self.zoned.offset().seconds() as f64 * 1e9
+        }
+
+        #[qjs(get)]
+        pub fn epoch_milliseconds(&self) -> f64 {
+            self.zoned.timestamp().as_millisecond() as f64
+        }
+
+        #[qjs(get)]
+        pub fn epoch_nanoseconds<'js>(&self, ctx: Ctx<'js>) -> rquickjs::Result<Value<'js>> {
+            bigint(&ctx, self.zoned.timestamp().as_nanosecond())
+        }
+
+        /// Adds a duration in the time zone: calendar units keep the wall-clock time across
+        /// daylight saving time changes, while time units are exact
+        pub fn add<'js>(&self, duration: Value<'js>, ctx: Ctx<'js>) -> rquickjs::Result<Self> {
+            let span = duration_from(&ctx, duration)?;
+            self.zoned
+                .checked_add(span)
+                .map(Into::into)
+                .map_err(|error| range_error(&ctx, error))
+        }
+
+        pub fn subtract<'js>(&self, duration: Value<'js>, ctx: Ctx<'js>) -> rquickjs::Result<Self> {
+            let span = duration_from(&ctx, duration)?;
+            self.zoned
+                .checked_sub(span)
+                .map(Into::into)
+                .map_err(|error| range_error(&ctx, error))
+        }
+
+        pub fn until<'js>(
+            &self,
+            other: Value<'js>,
+            options: Opt<Value<'js>>,
+            ctx: Ctx<'js>,
+        ) -> rquickjs::Result<JsDuration> {
+            let other = zoned_from(&ctx, other)?;
+            let rounding = rounding(&ctx, options.0)?;
+            let difference = configure!(
+                ZonedDifference::new(&other),
+                rounding,
+                largest,
+                smallest,
+                mode,
+                increment
+            );
+            self.zoned
+                .until(difference)
+                .map(Into::into)
+                .map_err(|error| range_error(&ctx, error))
+        }
+
+        pub fn since<'js>(
+            &self,
+            other: Value<'js>,
+            options: Opt<Value<'js>>,
+            ctx: Ctx<'js>,
+        ) -> rquickjs::Result<JsDuration> {
+            let other = zoned_from(&ctx, other)?;
+            let rounding = rounding(&ctx, options.0)?;
+            let difference = configure!(
+                ZonedDifference::new(&other),
+                rounding,
+                largest,
+                smallest,
+                mode,
+                increment
+            );
+            self.zoned
+                .since(difference)
+                .map(Into::into)
+                .map_err(|error| range_error(&ctx, error))
+        }
+
+        pub fn round<'js>(&self, options: Value<'js>, ctx: Ctx<'js>) -> rquickjs::Result<Self> {
+            let rounding = rounding(&ctx, Some(options))?;
+            let round = configure!(ZonedRound::new(), rounding, smallest, mode, increment);
+            self.zoned
+                .round(round)
+                .map(Into::into)
+                .map_err(|error| range_error(&ctx, error))
+        }
+
+        /// A copy with the given date and time fields replaced, keeping the time zone
+        pub fn with(&self, fields: Object<'_>, ctx: Ctx<'_>) -> rquickjs::Result<Self> {
+            let mut with = self.zoned.with();
+            if let Some(year) = field(&ctx, &fields, "year")? {
+                with = with.year(year);
+            }
+            if let Some(month) = field(&ctx, &fields, "month")? {
+                with = with.month(month);
+            }
+            if let Some(day) = field(&ctx, &fields, "day")? {
+                with = with.day(day);
+            }
+            if let Some(hour) = field(&ctx, &fields, "hour")? {
+                with = with.hour(hour);
+            }
+            if let Some(minute) = field(&ctx, &fields, "minute")? {
+                with = with.minute(minute);
+            }
+            if let Some(second) = field(&ctx, &fields, "second")? {
+                with = with.second(second);
+            }
+            if let Some(millisecond) = field(&ctx, &fields, "millisecond")? {
+                with = with.millisecond(millisecond);
+            }
+            if let Some(microsecond) = field(&ctx, &fields, "microsecond")? {
+                with = with.microsecond(microsecond);
+            }
+            if let Some(nanosecond) = field(&ctx, &fields, "nanosecond")? {
+                with = with.nanosecond(nanosecond);
+            }
+            with.build()
+                .map(Into::into)
+                .map_err(|error| range_error(&ctx, error))
+        }
+
+        /// The same instant in another time zone
+        pub fn with_time_zone<'js>(
+            &self,
+            time_zone_id: Value<'js>,
+            ctx: Ctx<'js>,
+        ) -> rquickjs::Result<Self> {
+            Ok(self
+                .zoned
+                .with_time_zone(time_zone(&ctx, time_zone_id)?)
+                .into())
+        }
+
+        pub fn start_of_day(&self, ctx: Ctx<'_>) -> rquickjs::Result<Self> {
+            self.zoned
+                .start_of_day()
+                .map(Into::into)
+                .map_err(|error| range_error(&ctx, error))
+        }
+
+        /// Whether the instants and the time zones are the same
+        pub fn equals<'js>(&self, other: Value<'js>, ctx: Ctx<'js>) -> rquickjs::Result<bool> {
+            let other = zoned_from(&ctx, other)?;
+            Ok(self.zoned.timestamp() == other.timestamp()
+                && time_zone_id(&self.zoned) == time_zone_id(&other))
+        }
+
+        pub fn to_instant(&self) -> JsInstant {
+            self.zoned.timestamp().into()
+        }
+
+        pub fn to_plain_date(&self) -> JsPlainDate {
+            self.zoned.date().into()
+        }
+
+        /// The RFC 9557 form, such as `2024-03-10T03:00:00-04:00[America/New_York]`
+        pub fn to_string(&self) -> String {
+            self.zoned.to_string()
+        }
+    }
+
+    impl From<Zoned> for JsZonedDateTime {
+        fn from(zoned: Zoned) -> Self {
+            Self { zoned }
+        }
+    }
+
+    #[derive(Clone, JsLifetime, Trace)]
+    #[rquickjs::class(rename = "Duration")]
+    pub struct JsDuration {
+        #[qjs(skip_trace)]
+        pub(super) span: Span,
+    }
+
+    #[rquickjs::methods(rename_all = "camelCase")]
+    impl JsDuration {
+        #[qjs(constructor)]
+        #[allow(clippy::too_many_arguments)]
+        pub fn new(
+            years: Opt<f64>,
+            months: Opt<f64>,
+            weeks: Opt<f64>,
+            days: Opt<f64>,
+            hours: Opt<f64>,
+            minutes: Opt<f64>,
+            seconds: Opt<f64>,
+            milliseconds: Opt<f64>,
+            microseconds: Opt<f64>,
+            nanoseconds: Opt<f64>,
+            ctx: Ctx<'_>,
+        ) -> rquickjs::Result<Self> {
+            let fields = Object::new(ctx.clone())?;
+            for (name, value) in [
+                ("years", years),
+                ("months", months),
+                ("weeks", weeks),
+                ("days", days),
+                ("hours", hours),
+                ("minutes", minutes),
+                ("seconds", seconds),
+                ("milliseconds", milliseconds),
+                ("microseconds", microseconds),
+                ("nanoseconds", nanoseconds),
+            ] {
+                fields.set(name, value.0.unwrap_or(0.0))?;
+            }
+            duration_from(&ctx, fields.into_value()).map(Into::into)
+        }
+
+        /// Creates a duration from an ISO 8601 string such as `P1DT12H`, or an object of fields
+        #[qjs(static)]
+        pub fn from<'js>(value: Value<'js>, ctx: Ctx<'js>) -> rquickjs::Result<Self> {
+            duration_from(&ctx, value).map(Into::into)
+        }
+
+        /// Compares the lengths of two durations, which requires `relativeTo` for calendar units
+        #[qjs(static)]
+        pub fn compare<'js>(
+            first: Value<'js>,
+            second: Value<'js>,
+            options: Opt<Object<'js>>,
+            ctx: Ctx<'js>,
+        ) -> rquickjs::Result<i32> {
+            let first = duration_from(&ctx, first)?;
+            let second = duration_from(&ctx, second)?;
+            let relative = Relative::from_options(&ctx, options.0.as_ref())?;
+            let ordering = match relative.as_ref().map(Relative::as_span_relative_to) {
+                Some(relative) => first.compare((second, relative)),
+                None => first.compare(jiff::SpanCompare::from(second).days_are_24_hours()),
+            };
+            ordering
+                .map(|ordering| ordering as i32)
+                .map_err(|error| range_error(&ctx, error))
+        }
+
+        #[qjs(get)]
+        pub fn years(&self) -> f64 {
+            self.span.get_years().into()
+        }
+
+        #[qjs(get)]
+        pub fn months(&self) -> f64 {
+            self.span.get_months().into()
+        }
+
+        #[qjs(get)]
+        pub fn weeks(&self) -> f64 {
+            self.span.get_weeks().into()
+        }
+
+        #[qjs(get)]
+        pub fn days(&self) -> f64 {
+            self.span.get_days().into()
+        }
+
+        #[qjs(get)]
+        pub fn hours(&self) -> f64 {
+            self.span.get_hours().into()
+        }
+
+        #[qjs(get)]
+        pub fn minutes(&self) -> f64 {
+            self.span.get_minutes() as f64
+        }
+
+        #[qjs(get)]
+        pub fn seconds(&self) -> f64 {
+            self.span.get_seconds() as f64
+        }
+
+        #[qjs(get)]
+        pub fn milliseconds(&self) -> f64 {
+            self.span.get_milliseconds() as f64
+        }
+
+        #[qjs(get)]
+        pub fn microseconds(&self) -> f64 {
+            self.span.get_microseconds() as f64
+        }
+
+        #[qjs(get)]
+        pub fn nanoseconds(&self) -> f64 {
+            self.span.get_nanoseconds() as f64
+        }
+
+        /// -1, 0 or 1, as all the fields have the same sign
+        #[qjs(get)]
+        pub fn sign(&self) -> i8 {
+            self.span.signum()
+        }
+
+        #[qjs(get)]
+        pub fn blank(&self) -> bool {
+            self.span.is_zero()
+        }
+
+        pub fn negated(&self) -> Self {
+            self.span.negate().into()
+        }
+
+        pub fn abs(&self) -> Self {
+            self.span.abs().into()
+        }
+
+        /// Adds the durations, which requires the `relativeTo` option for calendar units
+        pub fn add<'js>(
+            &self,
+            other: Value<'js>,
+            options: Opt<Object<'js>>,
+            ctx: Ctx<'js>,
+        ) -> rquickjs::Result<Self> {
+            let other = duration_from(&ctx, other)?;
+            self.sum(other, options.0, &ctx)
+        }
+
+        pub fn subtract<'js>(
+            &self,
+            other: Value<'js>,
+            options: Opt<Object<'js>>,
+            ctx: Ctx<'js>,
+        ) -> rquickjs::Result<Self> {
+            let other = duration_from(&ctx, other)?;
+            self.sum(other.negate(), options.0, &ctx)
+        }
+
+        /// Rounds and balances the duration between the `largestUnit` and `smallestUnit` options,
+        /// which requires the `relativeTo` option for calendar units
+        pub fn round<'js>(&self, options: Value<'js>, ctx: Ctx<'js>) -> rquickjs::Result<Self> {
+            let relative = match options.as_object() {
+                Some(options) => Relative::from_options(&ctx, Some(options))?,
+                None => None,
+            };
+            let rounding = rounding(&ctx, Some(options))?;
+            let round = configure!(
+                SpanRound::new(),
+                rounding,
+                largest,
+                smallest,
+                mode,
+                increment
+            );
+            let round = match relative.as_ref().map(Relative::as_span_relative_to) {
+                Some(relative) => round.relative(relative),
+                None => round.days_are_24_hours(),
+            };
+            self.span
+                .round(round)
+                .map(Into::into)
+                .map_err(|error| range_error(&ctx, error))
+        }
+
+        /// The length of the duration in the given `unit`, as a fractional number
+        pub fn total<'js>(&self, options: Value<'js>, ctx: Ctx<'js>) -> rquickjs::Result<f64> {
+            let (unit_name, relative) = match options.as_object() {
+                Some(options) => (
+                    options.get::<_, Option<String>>("unit")?,
+                    Relative::from_options(&ctx, Some(options))?,
+                ),
+                None => (
+                    options
+                        .as_string()
+                        .map(|name| name.to_string())
+                        .transpose()?,
+                    None,
+                ),
+            };
+            let Some(unit_name) = unit_name else {
+                return Err(Exception::throw_range(
+                    &ctx,
+                    "The `unit` option is required",
+                ));
+            };
+            let unit = unit(&ctx, &unit_name)?;
+            let total = match relative.as_ref().map(Relative::as_span_relative_to) {
+                Some(relative) => self.span.total((unit, relative)),
+                None => self
+                    .span
+                    .total(jiff::SpanTotal::from(unit).days_are_24_hours()),
+            };
+            total.map_err(|error| range_error(&ctx, error))
+        }
+
+        /// The ISO 8601 form, such as `P1Y2M3DT4H5M6.789S`
+        pub fn to_string(&self) -> String {
+            self.span.to_string()
+        }
+    }
+
+    impl JsDuration {
+        fn sum<'js>(
+            &self,
+            other: Span,
+            options: Option<Object<'js>>,
+            ctx: &Ctx<'js>,
+        ) -> rquickjs::Result<Self> {
+            let relative = Relative::from_options(ctx, options.as_ref())?;
+            let sum = match relative.as_ref().map(Relative::as_span_relative_to) {
+                Some(relative) => self.span.checked_add((other, relative)),
+                None => self
+                    .span
+                    .checked_add(SpanArithmetic::from(other).days_are_24_hours()),
+            };
+            sum.map(Into::into).map_err(|error| range_error(ctx, error))
+        }
+    }
+
+    impl From<Span> for JsDuration {
+        fn from(span: Span) -> Self {
+            Self { span }
+        }
+    }
+}
+
+/// Sets the rounding options that were given on one of the builders of `jiff`
+macro_rules! configure {
+    ($builder:expr, $rounding:expr, $($setter:ident),+) => {{
+        let mut builder = $builder;
+        $(
+            if let Some(value) = $rounding.$setter {
+                builder = builder.$setter(value);
+            }
+        )+
+        builder
+    }};
+}
+use configure;
+
+/// The `largestUnit`, `smallestUnit`, `roundingMode` and `roundingIncrement` options
+struct Rounding {
+    largest: Option<Unit>,
+    smallest: Option<Unit>,
+    mode: Option<RoundMode>,
+    increment: Option<i64>,
+}
+
+/// Reads the rounding options from an object, or only the smallest unit from a string
+fn rounding<'js>(ctx: &Ctx<'js>, options: Option<Value<'js>>) -> rquickjs::Result<Rounding> {
+    let mut rounding = Rounding {
+        largest: None,
+        smallest: None,
+        mode: None,
+        increment: None,
+    };
+    let Some(options) = options.filter(|options| !options.is_undefined()) else {
+        return Ok(rounding);
+    };
+    if let Some(smallest) = options.as_string() {
+        rounding.smallest = Some(unit(ctx, &smallest.to_string()?)?);
+        return Ok(rounding);
+    }
+    let Some(options) = options.as_object() else {
+        return Err(Exception::throw_type(
+            ctx,
+            "Expected an object of options or a unit",
+        ));
+    };
+    if let Some(largest) = options.get::<_, Option<String>>("largestUnit")? {
+        if largest != "auto" {
+            rounding.largest = Some(unit(ctx, &largest)?);
+        }
+    }
+    if let Some(smallest) = options.get::<_, Option<String>>("smallestUnit")? {
+        rounding.smallest = Some(unit(ctx, &smallest)?);
+    }
+    if let Some(mode) = options.get::<_, Option<String>>("roundingMode")? {
+        rounding.mode = Some(round_mode(ctx, &mode)?);
+    }
+    if let Some(increment) = options.get::<_, Option<f64>>("roundingIncrement")? {
+        rounding.increment = Some(integer(ctx, "roundingIncrement", increment)?);
+    }
+    Ok(rounding)
+}
+
+/// Parses a unit name, in the singular or the plural
+fn unit(ctx: &Ctx<'_>, name: &str) -> rquickjs::Result<Unit> {
+    match name.strip_suffix('s').unwrap_or(name) {
+        "year" => Ok(Unit::Year),
+        "month" => Ok(Unit::Month),
+        "week" => Ok(Unit::Week),
+        "day" => Ok(Unit::Day),
+        "hour" => Ok(Unit::Hour),
+        "minute" => Ok(Unit::Minute),
+        "second" => Ok(Unit::Second),
+        "millisecond" => Ok(Unit::Millisecond),
+        "microsecond" => Ok(Unit::Microsecond),
+        "nanosecond" => Ok(Unit::Nanosecond),
+        _ => Err(Exception::throw_range(
+            ctx,
+            &format!("Invalid unit `{name}`"),
+        )),
+    }
+}
+
+fn round_mode(ctx: &Ctx<'_>, name: &str) -> rquickjs::Result<RoundMode> {
+    match name {
+        "ceil" => Ok(RoundMode::Ceil),
+        "floor" => Ok(RoundMode::Floor),
+        "expand" => Ok(RoundMode::Expand),
+        "trunc" => Ok(RoundMode::Trunc),
+        "halfCeil" => Ok(RoundMode::HalfCeil),
+        "halfFloor" => Ok(RoundMode::HalfFloor),
+        "halfExpand" => Ok(RoundMode::HalfExpand),
+        "halfTrunc" => Ok(RoundMode::HalfTrunc),
+        "halfEven" => Ok(RoundMode::HalfEven),
+        _ => Err(Exception::throw_range(
+            ctx,
+            &format!("Invalid rounding mode `{name}`"),
+        )),
+    }
+}
+
+/// The `relativeTo` option, needed to measure calendar units
+enum Relative {
+    Date(Date),
+    Zoned(Zoned),
+}
+
+impl Relative {
+    fn from_options<'js>(
+        ctx: &Ctx<'js>,
+        options: Option<&Object<'js>>,
+    ) -> rquickjs::Result<Option<Self>> {
+        let Some(value) = options
+            .map(|options| options.get::<_, Value<'js>>("relativeTo"))
+            .transpose()?
+            .filter(|value| !value.is_undefined())
+        else {
+            return Ok(None);
+        };
+        let zoned = match value.as_object() {
+            Some(object) => {
+                Class::<native_module::JsZonedDateTime>::from_object(object).is_some()
+                    || object.contains_key("timeZone")?
+            }
+            None => value
+                .as_string()
+                .map(|text| text.to_string())
+                .transpose()?
+                .is_some_and(|text| text.contains('[')),
+        };
+        if zoned {
+            Ok(Some(Self::Zoned(zoned_from(ctx, value)?)))
+        } else {
+            Ok(Some(Self::Date(date_from(ctx, value)?)))
+        }
+    }
+
+    fn as_span_relative_to(&self) -> SpanRelativeTo<'_> {
+        match self {
+            Self::Date(date) => (*date).into(),
+            Self::Zoned(zoned) => zoned.into(),
+        }
+    }
+}
+
+fn range_error(ctx: &Ctx<'_>, error: impl Display) -> rquickjs::Error {
+    Exception::throw_range(ctx, &error.to_string())
+}
+
+/// Converts a number to an integer type, failing for fractional or out of range numbers
+fn integer<T: TryFrom<i64>>(ctx: &Ctx<'_>, name: &str, value: f64) -> rquickjs::Result<T> {
+    if value.fract() != 0.0 || !value.is_finite() || value.abs() > i64::MAX as f64 {
+        return Err(Exception::throw_range(
+            ctx,
+            &format!("`{name}` must be an integer, got {value}"),
+        ));
+    }
+    T::try_from(value as i64)
+        .map_err(|_| Exception::throw_range(ctx, &format!("`{name}` is out of range, got {value}")))
+}
+
+/// Reads an integer field of an object, if it is given
+fn field<T: TryFrom<i64>>(
+    ctx: &Ctx<'_>,
+    fields: &Object<'_>,
+    name: &str,
+) -> rquickjs::Result<Option<T>> {
+    fields
+        .get::<_, Option<f64>>(name)?
+        .map(|value| integer(ctx, name, value))
+        .transpose()
+}
+
+fn bigint<'js>(ctx: &Ctx<'js>, value: i128) -> rquickjs::Result<Value<'js>> {
+    let constructor: Function = ctx.globals().get("BigInt")?;
+    constructor.call((value.to_string(),))
+}
+
+fn bigint_to_i128<'js>(ctx: &Ctx<'js>, value: Value<'js>) -> rquickjs::Result<i128> {
+    if value.as_big_int().is_none() {
+        return Err(Exception::throw_type(
+            ctx,
+            "Expected the nanoseconds since the epoch as a BigInt",
+        ));
+    }
+    let to_string: Function = ctx.globals().get("String")?;
+    let digits: String = to_string.call((value,))?;
+    digits
+        .parse()
+        .map_err(|_| Exception::throw_range(ctx, &format!("{digits} is out of range")))
+}
+
+/// Gets a time zone by its IANA name, such as `Europe/Amsterdam`, or as a fixed offset such as
+/// `+05:30`
+fn time_zone<'js>(ctx: &Ctx<'js>, value: Value<'js>) -> rquickjs::Result<TimeZone> {
+    if let Some(object) = value.as_object() {
+        if let Some(zoned) = Class::<native_module::JsZonedDateTime>::from_object(object) {
+            return Ok(zoned.borrow().zoned.time_zone().clone());
+        }
+    }
+    let Some(name) = value.as_string() else {
+        return Err(Exception::throw_type(
+            ctx,
+            "Expected a time zone name or offset",
+        ));
+    };
+    let name = name.to_string()?;
+    if let Some(offset) = fixed_offset(&name) {
+        return Offset::from_seconds(offset)
+            .map(TimeZone::fixed)
+            .map_err(|error| range_error(ctx, error));
+    }
+    TimeZone::get(&name)
+        .map_err(|_| Exception::throw_range(ctx, &format!("Unknown time zone `{name}`")))
+}
+
+/// Parses an offset of the form `±HH`, `±HHMM` or `±HH:MM` into seconds
+fn fixed_offset(text: &str) -> Option<i32> {
+    let (sign, digits) = match text.as_bytes().first()? {
+        b'+' => (1, &text[1..]),
+        b'-' => (-1, &text[1..]),
+        _ => return None,
+    };
+    let digits = digits.replacen(':', "", 1);
+    if !matches!(digits.len(), 2 | 4) || !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+    let hours: i32 = digits[..2].parse().ok()?;
+    let minutes: i32 = digits
+        .get(2..)
+        .filter(|m| !m.is_empty())
+        .unwrap_or("0")
+        .parse()
+        .ok()?;
+    Some(sign * (hours * 3600 + minutes * 60))
+}
+
+/// The IANA name of the time zone, or its offset if it is fixed
+fn time_zone_id(zoned: &Zoned) -> String {
+    match zoned.time_zone().iana_name() {
+        Some(name) => name.to_string(),
+        None => offset_string(zoned.offset()),
+    }
+}
+
+/// Formats an offset as `±HH:MM`, with the seconds only if there are any
+fn offset_string(offset: Offset) -> String {
+    let sign = if offset.seconds() < 0 { '-' } else { '+' };
+    let seconds = offset.seconds().unsigned_abs();
+    let (hours, minutes, seconds) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+    if seconds == 0 {
+        format!("{sign}{hours:02}:{minutes:02}")
+    } else {
+        format!("{sign}{hours:02}:{minutes:02}:{seconds:02}")
+    }
+}
+
+fn string_of<'js>(value: &Value<'js>) -> rquickjs::Result<Option<String>> {
+    value.as_string().map(|text| text.to_string()).transpose()
+}
+
+fn instant_from<'js>(ctx: &Ctx<'js>, value: Value<'js>) -> rquickjs::Result<Timestamp> {
+    if let Some(object) = value.as_object() {
+        if let Some(instant) = Class::<native_module::JsInstant>::from_object(object) {
+            return Ok(instant.borrow().timestamp);
+        }
+        if let Some(zoned) = Class::<native_module::JsZonedDateTime>::from_object(object) {
+            return Ok(zoned.borrow().zoned.timestamp());
+        }
+    }
+    match string_of(&value)? {
+        Some(text) => text
+            .parse()
+            .map_err(|error| range_error(ctx, format!("Invalid instant `{text}`: {error}"))),
+        None => Err(Exception::throw_type(
+            ctx,
+            "Expected an Instant or an RFC 3339 string",
+        )),
+    }
+}
+
+fn date_from<'js>(ctx: &Ctx<'js>, value: Value<'js>) -> rquickjs::Result<Date> {
+    if let Some(object) = value.as_object() {
+        if let Some(date) = Class::<native_module::JsPlainDate>::from_object(object) {
+            return Ok(date.borrow().date);
+        }
+        if let Some(zoned) = Class::<native_module::JsZonedDateTime>::from_object(object) {
+            return Ok(zoned.borrow().zoned.date());
+        }
+        let year = required(ctx, object, "year")?;
+        let month = required(ctx, object, "month")?;
+        let day = required(ctx, object, "day")?;
+        return Date::new(year, month, day).map_err(|error| range_error(ctx, error));
+    }
+    match string_of(&value)? {
+        Some(text) => text
+            .parse()
+            .map_err(|error| range_error(ctx, format!("Invalid date `{text}`: {error}"))),
+        None => Err(Exception::throw_type(
+            ctx,
+            "Expected a PlainDate, an ISO 8601 string or an object of fields",
+        )),
+    }
+}
+
+fn zoned_from<'js>(ctx: &Ctx<'js>, value: Value<'js>) -> rquickjs::Result<Zoned> {
+    if let Some(object) = value.as_object() {
+        if let Some(zoned) = Class::<native_module::JsZonedDateTime>::from_object(object) {
+            return Ok(zoned.borrow().zoned.clone());
+        }
+        let time_zone = time_zone(ctx, object.get("timeZone")?)?;
+        let subsecond = field::<i32>(ctx, object, "millisecond")?.unwrap_or(0) * 1_000_000
+            + field::<i32>(ctx, object, "microsecond")?.unwrap_or(0) * 1_000
+            + field::<i32>(ctx, object, "nanosecond")?.unwrap_or(0);
+        let datetime = DateTime::new(
+            required(ctx, object, "year")?,
+            required(ctx, object, "month")?,
+            required(ctx, object, "day")?,
+            field(ctx, object, "hour")?.unwrap_or(0),
+            field(ctx, object, "minute")?.unwrap_or(0),
+            field(ctx, object, "second")?.unwrap_or(0),
+            subsecond,
+        )
+        .map_err(|error| range_error(ctx, error))?;
+        return datetime
+            .to_zoned(time_zone)
+            .map_err(|error| range_error(ctx, error));
+    }
+    match string_of(&value)? {
+        Some(text) => text.parse().map_err(|error| {
+            range_error(ctx, format!("Invalid zoned date-time `{text}`: {error}"))
+        }),
+        None => Err(Exception::throw_type(
+            ctx,
+            "Expected a ZonedDateTime, an RFC 9557 string or an object of fields",
+        )),
+    }
+}
+
+fn duration_from<'js>(ctx: &Ctx<'js>, value: Value<'js>) -> rquickjs::Result<Span> {
+    if let Some(object) = value.as_object() {
+        if let Some(duration) = Class::<native_module::JsDuration>::from_object(object) {
+            return Ok(duration.borrow().span);
+        }
+        return duration_from_fields(ctx, object);
+    }
+    match string_of(&value)? {
+        Some(text) => text
+            .parse()
+            .map_err(|error| range_error(ctx, format!("Invalid duration `{text}`: {error}"))),
+        None => Err(Exception::throw_type(
+            ctx,
+            "Expected a Duration, an ISO 8601 string or an object of fields",
+        )),
+    }
+}
+
+/// Builds a span from the fields of a duration-like object, which must all have the same sign
+fn duration_from_fields(ctx: &Ctx<'_>, object: &Object<'_>) -> rquickjs::Result<Span> {
+    let mut values = [0i64; 10];
+    let mut given = false;
+    for (value, name) in values.iter_mut().zip(DURATION_FIELDS) {
+        if let Some(field) = field(ctx, object, name)? {
+            *value = field;
+            given = true;
+        }
+    }
+    if !given {
+        return Err(Exception::throw_type(
+            ctx,
+            "A duration needs at least one of the fields years, months, weeks, days, hours, minutes, seconds, milliseconds, microseconds or nanoseconds",
+        ));
+    }
+    let negative = values.iter().any(|value| *value < 0);
+    if negative && values.iter().any(|value| *value > 0) {
+        return Err(Exception::throw_range(
+            ctx,
+            "The fields of a duration must not have mixed signs",
+        ));
+    }
+    let [
+        years,
+        months,
+        weeks,
+        days,
+        hours,
+        minutes,
+        seconds,
+        milliseconds,
+        microseconds,
+        nanoseconds,
+    ] = values.map(i64::abs);
+    let span = Span::new()
+        .try_years(years)
+        .and_then(|span| span.try_months(months))
+        .and_then(|span| span.try_weeks(weeks))
+        .and_then(|span| span.try_days(days))
+        .and_then(|span| span.try_hours(hours))
+        .and_then(|span| span.try_minutes(minutes))
+        .and_then(|span| span.try_seconds(seconds))
+        .and_then(|span| span.try_milliseconds(milliseconds))
+        .and_then(|span| span.try_microseconds(microseconds))
+        .and_then(|span| span.try_nanoseconds(nanoseconds))
+        .map_err(|error| range_error(ctx, error))?;
+    Ok(if negative { span.negate() } else { span })
+}
+
+const DURATION_FIELDS: [&str; 10] = [
+    "years",
+    "months",
+    "weeks",
+    "days",
+    "hours",
+    "minutes",
+    "seconds",
+    "milliseconds",
+    "microseconds",
+    "nanoseconds",
+];
+
+fn required<T: TryFrom<i64>>(
+    ctx: &Ctx<'_>,
+    object: &Object<'_>,
+    name: &str,
+) -> rquickjs::Result<T> {
+    field(ctx, object, name)?
+        .ok_or_else(|| Exception::throw_type(ctx, &format!("The `{name}` field is required")))
+}
+
+// JS module of the Temporal types
+pub const TEMPORAL_JS: &str = include_str!("temporal.js");
@@ -0,0 +1,95 @@
+use rquickjs::{Ctx, Exception};
+
+// Stubs of the native Temporal types, failing with an explanation when used
+#[rquickjs::module(rename = "camelCase")]
+pub mod native_module {
+    use rquickjs::class::Trace;
+    use rquickjs::{Ctx, JsLifetime};
+
+    #[derive(JsLifetime, Trace)]
+    #[rquickjs::class(rename = "Instant")]
+    pub struct JsInstant {}
+
+    #[rquickjs::methods(rename_all = "camelCase")]
+    impl JsInstant {
+        #[qjs(constructor)]
+        pub fn new(ctx: Ctx<'_>) -> rquickjs::Result<Self> {
+            Err(super::unavailable(&ctx))
+        }
+
+        #[qjs(static)]
+        pub fn from(ctx: Ctx<'_>) -> rquickjs::Result<Self> {
+            Err(super::unavailable(&ctx))
+        }
+
+        #[qjs(static)]
+        pub fn from_epoch_milliseconds(ctx: Ctx<'_>) -> rquickjs::Result<Self> {
+            Err(super::unavailable(&ctx))
+        }
+
+        #[qjs(static)]
+        pub fn from_epoch_nanoseconds(ctx: Ctx<'_>) -> rquickjs::Result<Self> {
+            Err(super::unavailable(&ctx))
+        }
+    }
+
+    #[derive(JsLifetime, Trace)]
+    #[rquickjs::class(rename = "PlainDate")]
+    pub struct JsPlainDate {}
+
+    #[rquickjs::methods]
+    impl JsPlainDate {
+        #[qjs(constructor)]
+        pub fn new(ctx: Ctx<'_>) -> rquickjs::Result<Self> {
+            Err(super::unavailable(&ctx))
+        }
+
+        #[qjs(static)]
+        pub fn from(ctx: Ctx<'_>) -> rquickjs::Result<Self> {
+            Err(super::unavailable(&ctx))
+        }
+    }
+
+    #[derive(JsLifetime, Trace)]
+    #[rquickjs::class(rename = "ZonedDateTime")]
+    pub struct JsZonedDateTime {}
+
+    #[rquickjs::methods]
+    impl JsZonedDateTime {
+        #[qjs(constructor)]
+        pub fn new(ctx: Ctx<'_>) -> rquickjs::Result<Self> {
+            Err(super::unavailable(&ctx))
+        }
+
+        #[qjs(static)]
+        pub fn from(ctx: Ctx<'_>) -> rquickjs::Result<Self> {
+            Err(super::unavailable(&ctx))
+        }
+    }
+
+    #[derive(JsLifetime, Trace)]
+    #[rquickjs::class(rename = "Duration")]
+    pub struct JsDuration {}
+
+    #[rquickjs::methods]
+    impl JsDuration {
+        #[qjs(constructor)]
+        pub fn new(ctx: Ctx<'_>) -> rquickjs::Result<Self> {
+            Err(super::unavailable(&ctx))
+        }
+
+        #[qjs(static)]
+        pub fn from(ctx: Ctx<'_>) -> rquickjs::Result<Self> {
+            Err(super::unavailable(&ctx))
+        }
+    }
+}
+
+fn unavailable(ctx: &Ctx<'_>) -> rquickjs::Error {
+    Exception::throw_message(
+        ctx,
+        "The `temporal` module requires the `temporal` feature of the wrapper crate",
+    )
+}
+
+pub const TEMPORAL_JS: &str = include_str!("temporal.js");
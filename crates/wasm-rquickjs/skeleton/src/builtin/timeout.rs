@@ -1,4 +1,4 @@
-use crate::internal::{format_caught_error};
+use crate::internal::format_caught_error;
 use rquickjs::function::Args;
 use rquickjs::{CatchResultExt, Ctx, Persistent, Value};
 
@@ -41,10 +41,11 @@ pub mod native_module {
     pub fn clear_schedule(timeout_id: usize) {
         let state = get_js_state();
         let mut abort_handles = state.abort_handles.borrow_mut();
-        let handle = abort_handles
-            .remove(&timeout_id)
-            .expect("No such timeout ID");
-        handle.abort();
+        // Unknown IDs are ignored like in browsers, the timers may have been cleared by the
+        // cancellation of the call
+        if let Some(handle) = abort_handles.remove(&timeout_id) {
+            handle.abort();
+        }
     }
 }
 
@@ -72,10 +73,7 @@ async fn scheduled_task(
         run_scheduled_task(ctx.clone(), code_or_fn.clone(), args.clone())
             .catch(&ctx)
             .unwrap_or_else(|e| {
-                panic!(
-                    "Failed to run scheduled task:\n{}",
-                    format_caught_error(e)
-                )
+                panic!("Failed to run scheduled task:\n{}", format_caught_error(e))
             });
     } else {
         let duration = wstd::time::Duration::from_millis(delay as u64);
@@ -86,10 +84,7 @@ async fn scheduled_task(
             run_scheduled_task(ctx.clone(), code_or_fn.clone(), args.clone())
                 .catch(&ctx)
                 .unwrap_or_else(|e| {
-                    panic!(
-                        "Failed to run scheduled task:\n{}",
-                        format_caught_error(e)
-                    )
+                    panic!("Failed to run scheduled task:\n{}", format_caught_error(e))
                 });
 
             if !periodic {
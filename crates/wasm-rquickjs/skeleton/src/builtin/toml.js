@@ -0,0 +1,9 @@
+import { parse_toml, stringify_toml } from '__wasm_rquickjs_builtin/config_formats_native';
+
+export function parse(text) {
+    return parse_toml(String(text));
+}
+
+export function stringify(value) {
+    return stringify_toml(value);
+}
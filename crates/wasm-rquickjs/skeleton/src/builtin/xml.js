@@ -0,0 +1,12 @@
+import { parse_xml } from '__wasm_rquickjs_builtin/markup_native';
+import { Comment, Document, DocumentType, Element, Node, Text, fromNodes, serialize } from '__wasm_rquickjs_builtin/dom';
+
+export { Comment, Document, DocumentType, Element, Node, Text };
+
+export function parse(text) {
+    return fromNodes(parse_xml(String(text)), false);
+}
+
+export function stringify(node) {
+    return serialize(node, false);
+}
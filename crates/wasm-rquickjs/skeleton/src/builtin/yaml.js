@@ -0,0 +1,17 @@
+import {
+    parse_yaml,
+    parse_yaml_documents,
+    stringify_yaml,
+} from '__wasm_rquickjs_builtin/config_formats_native';
+
+export function parse(text) {
+    return parse_yaml(String(text));
+}
+
+export function parseAll(text) {
+    return parse_yaml_documents(String(text));
+}
+
+export function stringify(value) {
+    return stringify_yaml(value);
+}
@@ -4,13 +4,13 @@ use rquickjs::function::{Args, Constructor};
 use rquickjs::loader::{BuiltinLoader, BuiltinResolver, ScriptLoader};
 use rquickjs::{
     AsyncContext, AsyncRuntime, CatchResultExt, Ctx, Error, Filter, FromJs, Function, Module,
-    Object, Promise, Value, async_with,
+    Object, Persistent, Promise, Value, async_with,
 };
 use rquickjs::{CaughtError, prelude::*};
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::future::Future;
-use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::time::Duration;
 use wstd::runtime::block_on;
 
@@ -26,6 +26,14 @@ pub struct JsState {
     pub resource_drop_queue_rx: RefCell<Option<futures::channel::mpsc::UnboundedReceiver<usize>>>,
     pub abort_handles: RefCell<HashMap<usize, AbortHandle>>,
     pub last_abort_id: AtomicUsize,
+    /// The exported JS functions already looked up, by their export index
+    resolved_exports: RefCell<Vec<Option<ResolvedExport>>>,
+}
+
+/// An exported JS function and the object it is a property of, which is passed as `this`
+struct ResolvedExport {
+    function: Persistent<Object<'static>>,
+    parent: Persistent<Object<'static>>,
 }
 
 impl Default for JsState {
@@ -61,11 +69,20 @@ impl JsState {
             rt.idle().await;
 
             let mut resolver = BuiltinResolver::default().with_module(crate::JS_EXPORT_MODULE_NAME);
+            let mut module_names = vec![crate::JS_EXPORT_MODULE_NAME.to_string()];
             for (name, _) in crate::JS_ADDITIONAL_MODULES.iter() {
                 resolver = resolver.with_module(name.to_string());
+                module_names.push(name.to_string());
             }
-            let resolver = crate::modules::add_native_module_resolvers(resolver);
-            let resolver = crate::builtin::add_module_resolvers(resolver);
+            let builtin_resolver = PermittedBuiltinResolver {
+                resolver: crate::builtin::add_module_resolvers(BuiltinResolver::default()),
+            };
+            let resolver = (
+                EmbeddedModuleResolver { module_names },
+                resolver,
+                builtin_resolver,
+                crate::modules::NativeModuleResolver,
+            );
 
             let mut builtin_loader = BuiltinLoader::default()
                 .with_module(crate::JS_EXPORT_MODULE_NAME, crate::JS_EXPORT_MODULE);
@@ -75,7 +92,7 @@ impl JsState {
 
             let loader = (
                 builtin_loader,
-                crate::modules::module_loader(),
+                crate::modules::NativeModuleLoader,
                 crate::builtin::module_loader(),
                 ScriptLoader::default(),
             );
@@ -89,13 +106,38 @@ impl JsState {
                     .expect("Failed to initialize resource table");
 
                 let wiring = crate::builtin::wire_builtins();
+                Module::evaluate(ctx.clone(), "wiring", wiring)
+                    .catch(&ctx)
+                    .unwrap_or_else(|e| panic!("Failed to evaluate builtin wiring:\n{}", format_caught_error(e)))
+                    .finish::<()>()
+                    .catch(&ctx)
+                    .unwrap_or_else(|e| panic!("Failed to finish builtin wiring:\n{}", format_caught_error(e)));
+
+                // The user modules are evaluated in the given order, each one's optional `__init`
+                // hook being awaited before evaluating the next one
+                for name in crate::JS_MODULE_ORDER.iter() {
+                    let namespace = Module::import(&ctx, name.to_string())
+                        .catch(&ctx)
+                        .unwrap_or_else(|e| panic!("Failed to import user module {name}:\n{}", format_caught_error(e)))
+                        .into_future::<Object>()
+                        .await
+                        .catch(&ctx)
+                        .unwrap_or_else(|e| panic!("Failed to finish importing user module {name}:\n{}", format_caught_error(e)));
+                    run_init_hook(&ctx, name, &namespace).await;
+                }
+
+                // With verbatim export names the implementations are looked up in the default export
+                let exports = if crate::JS_EXPORT_NAMES_VERBATIM {
+                    "userModule.default ?? {}"
+                } else {
+                    "userModule"
+                };
                 Module::evaluate(
                     ctx.clone(),
                     "test",
                     format!(r#"
-                    {wiring}
                     import * as userModule from '{}';
-                    globalThis.userModule = userModule;
+                    globalThis.userModule = {exports};
                     "#, crate::JS_EXPORT_MODULE_NAME),
                 )
                 .catch(&ctx)
@@ -103,15 +145,6 @@ impl JsState {
                 .finish::<()>()
                 .catch(&ctx)
                 .unwrap_or_else(|e| panic!("Failed to finish module initialization:\n{}", format_caught_error(e)));
-
-                for (name, _) in crate::JS_ADDITIONAL_MODULES.iter() {
-                  Module::import(&ctx, name.to_string())
-                     .catch(&ctx)
-                     .unwrap_or_else(|e| panic!("Failed to import user module {name}:\n{}", format_caught_error(e)))
-                     .finish::<()>()
-                     .catch(&ctx)
-                     .unwrap_or_else(|e| panic!("Failed to finish importing user module {name}:\n{}", format_caught_error(e)));
-                }
             })
                 .await;
             rt.idle().await;
@@ -128,6 +161,7 @@ impl JsState {
                 resource_drop_queue_rx: RefCell::new(Some(resource_drop_queue_rx)),
                 abort_handles: RefCell::new(HashMap::new()),
                 last_abort_id: AtomicUsize::new(0),
+                resolved_exports: RefCell::new(Vec::new()),
             }
         })
     }
@@ -135,6 +169,105 @@ impl JsState {
 
 static mut STATE: Option<JsState> = None;
 
+/// Resolves the imports between the embedded JS modules by path, relative (`./utils`,
+/// `../lib/utils.js`) to the name of the importing module, or by the full name with a `.js`
+/// extension. Imports by the exact module name are resolved by the `BuiltinResolver`.
+struct EmbeddedModuleResolver {
+    module_names: Vec<String>,
+}
+
+impl rquickjs::loader::Resolver for EmbeddedModuleResolver {
+    fn resolve<'js>(
+        &mut self,
+        _ctx: &Ctx<'js>,
+        base: &str,
+        name: &str,
+    ) -> rquickjs::Result<String> {
+        let path = if name.starts_with("./") || name.starts_with("../") {
+            let mut segments = base.split('/').collect::<Vec<_>>();
+            segments.pop();
+            for segment in name.split('/') {
+                match segment {
+                    "." | "" => {}
+                    ".." => {
+                        if segments.pop().is_none() {
+                            return Err(Error::new_resolving(base, name));
+                        }
+                    }
+                    _ => segments.push(segment),
+                }
+            }
+            segments.join("/")
+        } else {
+            name.to_string()
+        };
+
+        let candidates = [Some(path.as_str()), path.strip_suffix(".js")];
+        candidates
+            .into_iter()
+            .flatten()
+            .find(|candidate| self.module_names.iter().any(|module| module == candidate))
+            .map(|candidate| candidate.to_string())
+            .ok_or_else(|| Error::new_resolving(base, name))
+    }
+}
+
+/// Resolves the builtin modules, unless they are imported by a user module whose builtin
+/// permissions do not include them
+struct PermittedBuiltinResolver {
+    resolver: BuiltinResolver,
+}
+
+impl rquickjs::loader::Resolver for PermittedBuiltinResolver {
+    fn resolve<'js>(
+        &mut self,
+        ctx: &Ctx<'js>,
+        base: &str,
+        name: &str,
+    ) -> rquickjs::Result<String> {
+        let resolved = rquickjs::loader::Resolver::resolve(&mut self.resolver, ctx, base, name)?;
+        match crate::JS_BUILTIN_PERMISSIONS
+            .iter()
+            .find(|(module, _)| *module == base)
+        {
+            Some((_, permitted)) if !permitted.contains(&resolved.as_str()) => {
+                Err(Error::new_resolving_message(
+                    base,
+                    name,
+                    format!("The module {base} is not permitted to import the builtin module {name}"),
+                ))
+            }
+            _ => Ok(resolved),
+        }
+    }
+}
+
+/// Calls the `__init` function exported by a user module, if any, awaiting it if it returns a promise
+async fn run_init_hook<'js>(ctx: &Ctx<'js>, name: &str, namespace: &Object<'js>) {
+    let Ok(init) = namespace.get::<_, Function>("__init") else {
+        return;
+    };
+    let result: Value = init.call(()).catch(ctx).unwrap_or_else(|e| {
+        panic!(
+            "Exception in the __init hook of user module {name}:\n{}",
+            format_caught_error(e)
+        )
+    });
+    if let Some(promise) = result.as_promise() {
+        promise
+            .clone()
+            .into_future::<Value>()
+            .await
+            .catch(ctx)
+            .unwrap_or_else(|e| {
+                panic!(
+                    "Exception in the __init hook of user module {name}:\n{}",
+                    format_caught_error(e)
+                )
+            });
+    }
+}
+
 #[allow(static_mut_refs)]
 pub fn get_js_state() -> &'static JsState {
     unsafe {
@@ -145,7 +278,75 @@ pub fn get_js_state() -> &'static JsState {
     }
 }
 
+/// Set while an exported function is running on the JS context
+static EXPORT_CALL_IN_PROGRESS: AtomicBool = AtomicBool::new(false);
+
+/// The stable code starting the error of an export call rejected because another one is still in
+/// progress, so hosts can tell it apart from the other failures of the component
+pub const BUSY_ERROR_CODE: &str = "ERR_COMPONENT_BUSY";
+
+/// Marks an exported function call as running until dropped.
+///
+/// There is a single JS context, and an export call drives it to completion with `block_on`, which
+/// cannot be entered again while it is running. A second export call arriving before the first one
+/// finished (for example an imported function calling back into the component's exports) is
+/// rejected with a busy error starting with [`BUSY_ERROR_CODE`] instead of corrupting the state of
+/// the running call.
+struct ExportCallGuard;
+
+impl ExportCallGuard {
+    fn try_enter() -> Result<Self, String> {
+        if EXPORT_CALL_IN_PROGRESS.swap(true, Ordering::Acquire) {
+            return Err(format!(
+                "{BUSY_ERROR_CODE}: The component is busy: an exported function was called while another exported function call is still in progress. Export calls cannot be reentered or run concurrently on the single JS context"
+            ));
+        }
+        Ok(Self)
+    }
+}
+
+impl Drop for ExportCallGuard {
+    fn drop(&mut self) {
+        // Borrowed handles passed to the finished call must not be used anymore
+        BORROW_EPOCH.fetch_add(1, Ordering::Relaxed);
+        EXPORT_CALL_IN_PROGRESS.store(false, Ordering::Release);
+    }
+}
+
+/// Identifies the export call during which borrowed resource handles were passed to JS
+static BORROW_EPOCH: AtomicUsize = AtomicUsize::new(0);
+
+/// Gets the epoch of the running export call, to be stored in the JS proxies of borrowed resource
+/// handles
+pub fn current_borrow_epoch() -> usize {
+    BORROW_EPOCH.load(Ordering::Relaxed)
+}
+
+/// Checks whether a borrowed resource handle passed to JS in the given epoch can still be used,
+/// which is only the case until the export call it was passed to returns
+pub fn is_borrow_active(epoch: usize) -> bool {
+    epoch == BORROW_EPOCH.load(Ordering::Relaxed)
+}
+
+/// Runs an exported function call on the JS context. The exported functions have no error type to
+/// report a busy component with, so a call arriving while another one is in progress traps after
+/// printing the busy error.
 pub fn async_exported_function<F: Future>(future: F) -> F::Output {
+    let guard = ExportCallGuard::try_enter().unwrap_or_else(|error| {
+        eprintln!("{error}");
+        std::process::abort()
+    });
+    run_exported_function(guard, future)
+}
+
+/// Runs an exported function call on the JS context, returning the busy error if another one is
+/// in progress
+pub fn try_async_exported_function<F: Future>(future: F) -> Result<F::Output, String> {
+    let guard = ExportCallGuard::try_enter()?;
+    Ok(run_exported_function(guard, future))
+}
+
+fn run_exported_function<F: Future>(_guard: ExportCallGuard, future: F) -> F::Output {
     let js_state = get_js_state();
 
     block_on(async move {
@@ -175,22 +376,36 @@ pub fn async_exported_function<F: Future>(future: F) -> F::Output {
 
             result
         } else {
-            // This case will never happen because block_on does not allow reentry
+            // This case will never happen because ExportCallGuard rejects reentrant calls
             unreachable!()
         }
     })
 }
 
-pub async fn call_js_export<A, R>(wit_package: &str, function_path: &[&str], args: A) -> R
+pub async fn call_js_export<A, R>(
+    wit_package: &str,
+    export_index: usize,
+    function_path: &[&str],
+    args: A,
+) -> R
 where
     A: for<'js> IntoArgs<'js>,
     R: for<'js> FromJs<'js> + 'static,
 {
-    call_js_export_internal(wit_package, function_path, args, |a| a, |_, _| None).await
+    call_js_export_internal(
+        wit_package,
+        export_index,
+        function_path,
+        args,
+        |a| a,
+        |_, _| None,
+    )
+    .await
 }
 
 pub async fn call_js_export_returning_result<A, R, E>(
     wit_package: &str,
+    export_index: usize,
     function_path: &[&str],
     args: A,
 ) -> crate::wrappers::JsResult<R, E>
@@ -201,6 +416,7 @@ where
 {
     call_js_export_internal(
         wit_package,
+        export_index,
         function_path,
         args,
         |a| crate::wrappers::JsResult(Ok(a)),
@@ -215,6 +431,7 @@ where
 
 async fn call_js_export_internal<A, R, FR, TME>(
     wit_package: &str,
+    export_index: usize,
     function_path: &[&str],
     args: A,
     map_result: impl Fn(R) -> FR,
@@ -229,8 +446,10 @@ where
     let js_state = get_js_state();
 
     let result: FR = async_with!(js_state.ctx => |ctx| {
-        let module: Object = ctx.globals().get("userModule").expect("Failed to get userModule");
-        let (user_function_obj, parent): (Object, Object) = get_path(&module, function_path).unwrap_or_else(|| panic!("{}", dump_cannot_find_export("exported JS function", function_path, &module, wit_package)));
+        let (user_function_obj, parent) = resolve_export(&ctx, export_index, function_path).unwrap_or_else(|| {
+            let module: Object = ctx.globals().get("userModule").expect("Failed to get userModule");
+            panic!("{}", dump_cannot_find_export("exported JS function", function_path, &module, wit_package))
+        });
         let user_function = user_function_obj.as_function().unwrap_or_else(|| panic!("Expected export {} to be a function", function_path.join("."))).clone();
 
         let parameter_count = user_function_obj.get::<&str, usize>("length").unwrap_or_else(|_| panic!("Failed to get parameter count of exported function {}", function_path.join(".")));
@@ -261,10 +480,10 @@ where
             Ok(value) => {
                 if value.is_promise() {
                     let promise: Promise = value.into_promise().unwrap();
-                    let promise_future = promise.into_future::<R> ();
+                    let promise_future = promise.into_future::<Value> ();
                     match promise_future.await {
-                        Ok(result) => {
-                            map_result(result)
+                        Ok(value) => {
+                            map_result(crate::wrappers::export_result_from_js(&ctx, value).expect(&format!("Unexpected result value for exported function {path}", path=function_path.join("."))))
                         }
                         Err(e) => {
                             match e {
@@ -285,16 +504,128 @@ where
                 }
                 else {
                     (map_result)(
-                        R::from_js(&ctx, value).expect(&format!("Unexpected result value for exported function {path}", path=function_path.join(".")))
+                        crate::wrappers::export_result_from_js(&ctx, value).expect(&format!("Unexpected result value for exported function {path}", path=function_path.join(".")))
                     )
                 }
             }
         }
     }).await;
+    crate::builtin::cancel_background_work().await;
     js_state.rt.idle().await;
     result
 }
 
+/// Calls an exported JS function with arguments parsed from a JSON array, returning the
+/// JSON-encoded result. The values are passed to and from JS as they are, without any WIT type
+/// based conversion; `BigInt` values in the result are encoded as strings.
+pub async fn call_js_export_with_json(
+    export_index: usize,
+    function_path: &[&str],
+    args_json: String,
+) -> Result<String, String> {
+    let js_state = get_js_state();
+
+    let result = async_with!(js_state.ctx => |ctx| {
+        let resolved = resolve_export(&ctx, export_index, function_path)
+            .and_then(|(function, parent)| Some((function.as_function()?.clone(), parent)));
+        let Some((user_function, parent)) = resolved else {
+            return Err(format!("Cannot find exported JS function {}", function_path.join(".")));
+        };
+
+        let args: Vec<Value> = match ctx.json_parse(args_json).catch(&ctx) {
+            Ok(args) => Vec::from_js(&ctx, args)
+                .map_err(|_| "The arguments must be encoded as a JSON array".to_string())?,
+            Err(err) => return Err(format!("Invalid JSON arguments: {}", format_caught_error(err))),
+        };
+
+        let mut call_args = Args::new(ctx.clone(), args.len() + 1);
+        call_args.this(parent).map_err(|e| e.to_string())?;
+        call_args.push_args(args).map_err(|e| e.to_string())?;
+        let mut result: Value = match user_function.call_arg(call_args).catch(&ctx) {
+            Ok(result) => result,
+            Err(err) => return Err(format_caught_error(err)),
+        };
+        if let Some(promise) = result.as_promise() {
+            result = match promise.clone().into_future::<Value>().await.catch(&ctx) {
+                Ok(result) => result,
+                Err(err) => return Err(format_caught_error(err)),
+            };
+        }
+
+        let replacer: Function = ctx
+            .eval("(key, value) => typeof value === 'bigint' ? value.toString() : value")
+            .expect("Failed to create the JSON replacer");
+        match ctx.json_stringify_replacer(result, replacer).catch(&ctx) {
+            Ok(json) => Ok(json
+                .map(|json| json.to_string().expect("Failed to convert JSON to string"))
+                .unwrap_or_else(|| "null".to_string())),
+            Err(err) => Err(format!("Failed to encode the result as JSON: {}", format_caught_error(err))),
+        }
+    }).await;
+    crate::builtin::cancel_background_work().await;
+    js_state.rt.idle().await;
+    result
+}
+
+/// Completes the JSON description of the component returned by the `rquickjs-describe` export
+/// with the cargo features the component was built with
+pub fn describe_component(description: &str) -> String {
+    let mut features = Vec::new();
+    if cfg!(feature = "http") {
+        features.push("\"http\"");
+    }
+    if cfg!(feature = "logging") {
+        features.push("\"logging\"");
+    }
+    if cfg!(feature = "intl") {
+        features.push("\"intl\"");
+    }
+    if cfg!(feature = "codecs") {
+        features.push("\"codecs\"");
+    }
+    if cfg!(feature = "compression") {
+        features.push("\"compression\"");
+    }
+    if cfg!(feature = "passwords") {
+        features.push("\"passwords\"");
+    }
+    if cfg!(feature = "jwt") {
+        features.push("\"jwt\"");
+    }
+    if cfg!(feature = "regex") {
+        features.push("\"regex\"");
+    }
+    if cfg!(feature = "temporal") {
+        features.push("\"temporal\"");
+    }
+    if cfg!(feature = "messageformat") {
+        features.push("\"messageformat\"");
+    }
+    if cfg!(feature = "markup") {
+        features.push("\"markup\"");
+    }
+    if cfg!(feature = "config") {
+        features.push("\"config\"");
+    }
+    if cfg!(feature = "templates") {
+        features.push("\"templates\"");
+    }
+    if cfg!(feature = "csv") {
+        features.push("\"csv\"");
+    }
+    if cfg!(feature = "hashing") {
+        features.push("\"hashing\"");
+    }
+    if cfg!(feature = "decimal") {
+        features.push("\"decimal\"");
+    }
+    description.replacen(
+        "\"__RQUICKJS_FEATURES__\"",
+        &format!("[{}]", features.join(",")),
+        1,
+    )
+}
+
 pub async fn call_js_resource_constructor<A>(
     wit_package: &str,
     resource_path: &[&str],
@@ -345,6 +676,7 @@ where
             }
         }
     }).await;
+    crate::builtin::cancel_background_work().await;
     js_state.rt.idle().await;
     result
 }
@@ -352,7 +684,7 @@ where
 pub fn get_free_resource_id() -> usize {
     get_js_state()
         .last_resource_id
-        .fetch_add(1, std::sync::atomic::Ordering::Relaxed)
+        .fetch_add(1, Ordering::Relaxed)
 }
 
 pub async fn call_js_resource_method<A, R>(
@@ -468,10 +800,13 @@ where
             Ok(value) => {
                 if value.is_promise() {
                     let promise: Promise = value.into_promise().unwrap();
-                    let promise_future = promise.into_future::<R> ();
+                    let promise_future = promise.into_future::<Value> ();
                     match promise_future.await {
-                        Ok(result) => {
-                            map_result(result)
+                        Ok(value) => {
+                            map_result(crate::wrappers::export_result_from_js(&ctx, value).expect(
+                                &format!("Unexpected result value for method {name} in exported class {path}",
+                                        path=resource_path.join(".")
+                                )))
                         }
                         Err(e) => {
                             match e {
@@ -491,7 +826,7 @@ where
                     }
                 }
                 else {
-                    map_result(R::from_js(&ctx, value).expect(
+                    map_result(crate::wrappers::export_result_from_js(&ctx, value).expect(
                         &format!("Unexpected result value for method {name} in exported class {path}",
                                 path=resource_path.join(".")
                         )))
@@ -499,6 +834,7 @@ where
             }
         }
     }).await;
+    crate::builtin::cancel_background_work().await;
     js_state.rt.idle().await;
     result
 }
@@ -542,6 +878,37 @@ where
     function.call_arg(accum_args)
 }
 
+/// Gets an exported JS function (or any other value) and the object it is a property of.
+///
+/// It is only looked up by its path in the user module on its first call, later calls get it by the
+/// index the generator assigned to the export, so the cost of dispatching a call does not grow with
+/// the number of exports or the length of their names.
+fn resolve_export<'js>(
+    ctx: &Ctx<'js>,
+    export_index: usize,
+    function_path: &[&str],
+) -> Option<(Object<'js>, Object<'js>)> {
+    let js_state = get_js_state();
+    if let Some(Some(resolved)) = js_state.resolved_exports.borrow().get(export_index) {
+        let function = resolved.function.clone().restore(ctx).ok()?;
+        let parent = resolved.parent.clone().restore(ctx).ok()?;
+        return Some((function, parent));
+    }
+
+    let module: Object = ctx.globals().get("userModule").ok()?;
+    let (function, parent): (Object, Object) = get_path(&module, function_path)?;
+
+    let mut resolved_exports = js_state.resolved_exports.borrow_mut();
+    if resolved_exports.len() <= export_index {
+        resolved_exports.resize_with(export_index + 1, || None);
+    }
+    resolved_exports[export_index] = Some(ResolvedExport {
+        function: Persistent::save(ctx, function.clone()),
+        parent: Persistent::save(ctx, parent.clone()),
+    });
+    Some((function, parent))
+}
+
 fn get_path<'js, V: FromJs<'js>>(root: &Object<'js>, path: &[&str]) -> Option<(V, Object<'js>)> {
     let (head, tail) = path.split_first()?;
     if tail.is_empty() {
@@ -572,10 +939,17 @@ fn dump_cannot_find_export(
     panic_message.push_str(&format!("  {}\n", keys.join(", ")));
 
     if path.len() == 1 {
-        panic_message.push_str(&format!(
-            "\nTry adding an export `export const {} = ...`\n",
-            path[0]
-        ));
+        if crate::JS_EXPORT_NAMES_VERBATIM {
+            panic_message.push_str(&format!(
+                "\nTry adding a field `\"{}\": ...` to the default export\n",
+                path[0]
+            ));
+        } else {
+            panic_message.push_str(&format!(
+                "\nTry adding an export `export const {} = ...`\n",
+                path[0]
+            ));
+        }
     } else if path.len() > 1 {
         let mut current_object = module.clone();
         for i in 0..path.len() {
@@ -584,7 +958,12 @@ fn dump_cannot_find_export(
                     current_object = child;
                 }
                 Err(_) => {
-                    if i == 0 {
+                    if i == 0 && crate::JS_EXPORT_NAMES_VERBATIM {
+                        panic_message.push_str(&format!(
+                            "\nTry adding a field `\"{}\": {{ ... }}` to the default export\n",
+                            path[i]
+                        ));
+                    } else if i == 0 {
                         panic_message.push_str(&format!(
                             "\nTry adding an export `export const {} = {{ ... }}`\n",
                             path[i]
@@ -666,13 +1045,22 @@ pub fn try_format_js_error(err: &Value) -> Option<String> {
         return None;
     }
 
+    // The name (`TypeError`, `RangeError`, a custom error class, ...) is included so the host can
+    // tell the kind of failure from the trap message
+    let name: String = obj
+        .get::<_, Option<String>>("name")
+        .ok()
+        .flatten()
+        .unwrap_or_else(|| "Error".to_string());
     let message: Option<String> = obj.get("message").ok();
     let stack: Option<String> = obj.get("stack").ok();
 
     match (message, stack) {
-        (Some(msg), Some(st)) => Some(format!("JavaScript error: {msg}\nStack:\n{st}")),
-        (Some(msg), None) => Some(format!("JavaScript error: {msg}")),
-        (None, Some(st)) => Some(format!("JavaScript error: <no message>\nStack:\n{st}")),
+        (Some(msg), Some(st)) => Some(format!("JavaScript error: {name}: {msg}\nStack:\n{st}")),
+        (Some(msg), None) => Some(format!("JavaScript error: {name}: {msg}")),
+        (None, Some(st)) => Some(format!(
+            "JavaScript error: {name}: <no message>\nStack:\n{st}"
+        )),
         _ => None,
     }
 }
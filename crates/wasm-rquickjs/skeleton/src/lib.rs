@@ -11,3 +11,40 @@ static JS_EXPORT_MODULE: &str = include_str!("bundle_script_module.js");
 static JS_ADDITIONAL_MODULES: std::sync::LazyLock<
     Vec<(&str, Box<dyn (Fn() -> String) + Send + Sync>)>,
 > = std::sync::LazyLock::new(std::vec::Vec::new);
+
+static JS_MODULE_ORDER: &[&str] = &["bundle/script_module"];
+
+static JS_BUILTIN_PERMISSIONS: &[(&str, &[&str])] = &[];
+
+static MATH_RANDOM_SEED: builtin::MathRandomSeed = builtin::MathRandomSeed::Default;
+
+static DATE_CLOCK: Option<fn() -> f64> = None;
+
+static IMPORT_HOOKS: bool = false;
+
+static MOCK_IMPORTS: bool = false;
+
+static IMPORT_RECORDING: builtin::ImportRecording = builtin::ImportRecording::None;
+
+static CANCEL_BACKGROUND_WORK: bool = false;
+
+static HTTP_LIMITS: builtin::HttpLimits = builtin::HttpLimits::UNLIMITED;
+
+#[allow(dead_code)]
+static COOKIE_JAR: bool = false;
+
+#[allow(dead_code)]
+static HTTP_CACHE: bool = false;
+
+#[allow(dead_code)]
+static HTTP_CACHE_LIMITS: builtin::HttpCacheLimits = builtin::HttpCacheLimits {
+    max_entries: None,
+    max_size: None,
+    max_body_size: None,
+};
+
+#[allow(dead_code)]
+static FETCH_BASE_URL: Option<&str> = None;
+
+#[allow(dead_code)]
+static FETCH_GATEWAY: Option<&str> = None;
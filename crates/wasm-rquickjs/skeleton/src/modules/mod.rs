@@ -1,11 +1,26 @@
 // Empty file, to be generated
 
-pub fn add_native_module_resolvers(
-    resolver: rquickjs::loader::BuiltinResolver,
-) -> rquickjs::loader::BuiltinResolver {
-    resolver
+pub struct NativeModuleResolver;
+
+impl rquickjs::loader::Resolver for NativeModuleResolver {
+    fn resolve<'js>(
+        &mut self,
+        _ctx: &rquickjs::Ctx<'js>,
+        base: &str,
+        name: &str,
+    ) -> rquickjs::Result<String> {
+        Err(rquickjs::Error::new_resolving(base, name))
+    }
 }
 
-pub fn module_loader() -> rquickjs::loader::ModuleLoader {
-    rquickjs::loader::ModuleLoader::default()
+pub struct NativeModuleLoader;
+
+impl rquickjs::loader::Loader for NativeModuleLoader {
+    fn load<'js>(
+        &mut self,
+        _ctx: &rquickjs::Ctx<'js>,
+        name: &str,
+    ) -> rquickjs::Result<rquickjs::Module<'js, rquickjs::module::Declared>> {
+        Err(rquickjs::Error::new_loading(name))
+    }
 }
@@ -1,5 +1,9 @@
 use rquickjs::function::Args;
-use rquickjs::{Ctx, FromJs, IntoJs, Object, Value};
+use rquickjs::{Array, Atom, Ctx, FromJs, IntoJs, Object, Persistent, Value, qjs};
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::ffi::c_void;
+use std::mem::ManuallyDrop;
 
 pub const TAG: &str = "tag";
 pub const VALUE: &str = "val";
@@ -103,11 +107,13 @@ impl<'js, Ok: FromJs<'js>, Err: FromJs<'js>> FromJs<'js> for JsResult<Ok, Err> {
         let tag: String = obj.get(TAG)?;
         match tag.as_str() {
             RESULT_OK => {
-                let val: Ok = obj.get(VALUE)?;
+                let val: Ok =
+                    with_path_segment(PathSegment::Field(VALUE), None, || obj.get(VALUE))?;
                 Ok(JsResult(Ok(val)))
             }
             RESULT_ERR => {
-                let val: Err = obj.get(VALUE)?;
+                let val: Err =
+                    with_path_segment(PathSegment::Field(VALUE), None, || obj.get(VALUE))?;
                 Ok(JsResult(Err(val)))
             }
             _ => Err(rquickjs::Error::new_from_js_message(
@@ -119,6 +125,315 @@ impl<'js, Ok: FromJs<'js>, Err: FromJs<'js>> FromJs<'js> for JsResult<Ok, Err> {
     }
 }
 
+/// Wrapper for `Vec` for implementing `FromJs` in a way that failed conversions report the index of
+/// the failing element.
+#[allow(dead_code)]
+pub struct JsList<T>(pub Vec<T>);
+
+impl<'js, T: IntoJs<'js>> IntoJs<'js> for JsList<T> {
+    fn into_js(self, ctx: &Ctx<'js>) -> rquickjs::Result<Value<'js>> {
+        self.0.into_js(ctx)
+    }
+}
+
+impl<'js, T: FromJs<'js>> FromJs<'js> for JsList<T> {
+    fn from_js(ctx: &Ctx<'js>, value: Value<'js>) -> rquickjs::Result<Self> {
+        let array = Array::from_js(ctx, value)?;
+        let mut result = Vec::with_capacity(array.len());
+        for (idx, item) in array.iter::<Value>().enumerate() {
+            let item = item?;
+            result.push(with_path_segment(PathSegment::Index(idx), None, || {
+                T::from_js(ctx, item)
+            })?);
+        }
+        Ok(JsList(result))
+    }
+}
+
+/// A step in the path from the root of a converted JS value to one of its nested values
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy)]
+pub enum PathSegment {
+    Field(&'static str),
+    Index(usize),
+}
+
+struct ConversionPath {
+    segments: Vec<(PathSegment, Option<&'static str>)>,
+    reported: bool,
+}
+
+thread_local! {
+    static CONVERSION_PATH: RefCell<ConversionPath> = const {
+        RefCell::new(ConversionPath {
+            segments: Vec::new(),
+            reported: false,
+        })
+    };
+}
+
+/// Runs a nested FromJs conversion, tracking where it is in the converted value.
+///
+/// If the conversion fails, the innermost failing step rewrites the error to include the full path
+/// of the failing value and its WIT type (if known), for example
+/// `orders[3].customer.address.zip: expected string, got number`.
+#[allow(dead_code)]
+pub fn with_path_segment<T>(
+    segment: PathSegment,
+    wit_type: Option<&'static str>,
+    f: impl FnOnce() -> rquickjs::Result<T>,
+) -> rquickjs::Result<T> {
+    CONVERSION_PATH.with_borrow_mut(|path| path.segments.push((segment, wit_type)));
+    let result = f().map_err(|err| {
+        CONVERSION_PATH.with_borrow_mut(|path| {
+            if path.reported {
+                err
+            } else {
+                path.reported = true;
+                annotate_conversion_error(err, &path.segments)
+            }
+        })
+    });
+    CONVERSION_PATH.with_borrow_mut(|path| {
+        path.segments.pop();
+        if path.segments.is_empty() {
+            path.reported = false;
+        }
+    });
+    result
+}
+
+fn annotate_conversion_error(
+    err: rquickjs::Error,
+    segments: &[(PathSegment, Option<&'static str>)],
+) -> rquickjs::Error {
+    match err {
+        rquickjs::Error::FromJs { from, to, message } => {
+            let mut path = String::new();
+            for (segment, _) in segments {
+                match segment {
+                    PathSegment::Field(name) if path.is_empty() => path.push_str(name),
+                    PathSegment::Field(name) => {
+                        path.push('.');
+                        path.push_str(name);
+                    }
+                    PathSegment::Index(idx) => path.push_str(&format!("[{idx}]")),
+                }
+            }
+            let expected = segments
+                .last()
+                .and_then(|(_, wit_type)| *wit_type)
+                .unwrap_or(to);
+            // rquickjs reports the internal QuickJS value tags, showing the JS type names instead
+            let got = match from {
+                "int" | "float" => "number",
+                "big_int" => "bigint",
+                other => other,
+            };
+            let mut annotated = format!("{path}: expected {expected}, got {got}");
+            if let Some(message) = message {
+                annotated.push_str(&format!(" ({message})"));
+            }
+            rquickjs::Error::new_from_js_message(from, to, annotated)
+        }
+        other => other,
+    }
+}
+
+/// Checks a JS object before converting it to a WIT record.
+///
+/// `required_fields` are the JS names and WIT types of the non-optional fields of the record, all of
+/// which must be present. Missing optional fields are converted to `None`. Properties not in `fields`
+/// are ignored, unless `strict` is set.
+#[allow(dead_code)]
+pub fn check_record_fields(
+    obj: &Object<'_>,
+    record_type: &'static str,
+    fields: &[&str],
+    required_fields: &[(&str, &str)],
+    strict: bool,
+) -> rquickjs::Result<()> {
+    let mut missing = Vec::new();
+    for (name, wit_type) in required_fields {
+        let value: Value = obj.get(*name)?;
+        if value.is_undefined() {
+            missing.push(format!("`{name}` ({wit_type})"));
+        }
+    }
+    if !missing.is_empty() {
+        return Err(rquickjs::Error::new_from_js_message(
+            "JS object",
+            record_type,
+            format!("Missing required fields: {}", missing.join(", ")),
+        ));
+    }
+
+    if strict {
+        let mut unexpected = Vec::new();
+        for key in obj.keys::<String>() {
+            let key = key?;
+            if !fields.contains(&key.as_str()) {
+                unexpected.push(format!("`{key}`"));
+            }
+        }
+        if !unexpected.is_empty() {
+            return Err(rquickjs::Error::new_from_js_message(
+                "JS object",
+                record_type,
+                format!("Unexpected properties: {}", unexpected.join(", ")),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Gets a field of a JS object being converted to a WIT record or variant, tracking it in the
+/// conversion path
+#[allow(dead_code)]
+pub fn get_field<'js, T: FromJs<'js>>(
+    obj: &Object<'js>,
+    name: &'static str,
+    wit_type: &'static str,
+) -> rquickjs::Result<T> {
+    with_path_segment(PathSegment::Field(name), Some(wit_type), || obj.get(name))
+}
+
+/// Gets a field of a JS object by its interned name, tracking it in the conversion path
+#[allow(dead_code)]
+pub fn get_interned_field<'js, T: FromJs<'js>>(
+    obj: &Object<'js>,
+    key: Atom<'js>,
+    name: &'static str,
+    wit_type: &'static str,
+) -> rquickjs::Result<T> {
+    with_path_segment(PathSegment::Field(name), Some(wit_type), || obj.get(key))
+}
+
+/// Atoms of a fixed set of property names, created on first use and reused by later conversions
+/// instead of interning the names again on every call.
+///
+/// Atoms belong to a QuickJS runtime, so the cache is rebuilt if it is used from another one.
+#[allow(dead_code)]
+pub struct AtomCache {
+    names: &'static [&'static str],
+    atoms: RefCell<Vec<Persistent<Atom<'static>>>>,
+}
+
+#[allow(dead_code)]
+impl AtomCache {
+    pub const fn new(names: &'static [&'static str]) -> Self {
+        Self {
+            names,
+            atoms: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Gets the atom of the name at `idx`
+    pub fn get<'js>(&self, ctx: &Ctx<'js>, idx: usize) -> rquickjs::Result<Atom<'js>> {
+        let mut atoms = self.atoms.borrow_mut();
+        if let Some(atom) = atoms.get(idx) {
+            match atom.clone().restore(ctx) {
+                Err(rquickjs::Error::UnrelatedRuntime) => atoms.clear(),
+                result => return result,
+            }
+        }
+
+        for name in self.names {
+            atoms.push(Persistent::save(ctx, Atom::from_str(ctx.clone(), name)?));
+        }
+        atoms[idx].clone().restore(ctx)
+    }
+}
+
+thread_local! {
+    static VARIANT_ATOMS: AtomCache = const { AtomCache::new(&[TAG, VALUE]) };
+}
+
+/// The key of a variant object's `tag` (0) or `val` (1) property, interned if the wrapper was
+/// generated with interned field names
+fn variant_key<'js>(ctx: &Ctx<'js>, idx: usize) -> rquickjs::Result<Atom<'js>> {
+    if crate::INTERN_FIELD_NAMES {
+        VARIANT_ATOMS.with(|atoms| atoms.get(ctx, idx))
+    } else {
+        Atom::from_str(ctx.clone(), [TAG, VALUE][idx])
+    }
+}
+
+/// Creates the JS object representing a variant case without a value
+#[allow(dead_code)]
+pub fn variant_case<'js>(ctx: &Ctx<'js>, tag: &'static str) -> rquickjs::Result<Value<'js>> {
+    let obj = Object::new(ctx.clone())?;
+    obj.set(variant_key(ctx, 0)?, tag)?;
+    Ok(obj.into_value())
+}
+
+/// Creates the JS object representing a variant case with a value
+#[allow(dead_code)]
+pub fn variant_case_with_value<'js, T: IntoJs<'js>>(
+    ctx: &Ctx<'js>,
+    tag: &'static str,
+    value: T,
+) -> rquickjs::Result<Value<'js>> {
+    let obj = Object::new(ctx.clone())?;
+    obj.set(variant_key(ctx, 0)?, tag)?;
+    obj.set(variant_key(ctx, 1)?, value)?;
+    Ok(obj.into_value())
+}
+
+/// Gets the JS object being converted to a WIT variant, and the name of its case
+#[allow(dead_code)]
+pub fn variant_tag<'js>(value: Value<'js>) -> rquickjs::Result<(Object<'js>, String)> {
+    let obj = Object::from_value(value)?;
+    let tag: String = obj.get(variant_key(obj.ctx(), 0)?)?;
+    Ok((obj, tag))
+}
+
+/// Gets the value of a JS object being converted to a WIT variant case
+#[allow(dead_code)]
+pub fn variant_value<'js, T: FromJs<'js>>(
+    obj: &Object<'js>,
+    wit_type: &'static str,
+) -> rquickjs::Result<T> {
+    let key = variant_key(obj.ctx(), 1)?;
+    get_interned_field(obj, key, VALUE, wit_type)
+}
+
+/// Gets the case name of a JS value being converted to a WIT enum
+#[allow(dead_code)]
+pub fn enum_case(
+    value: Value<'_>,
+    js_type: &'static str,
+    wit_type: &'static str,
+) -> rquickjs::Result<String> {
+    value
+        .as_string()
+        .ok_or_else(|| {
+            rquickjs::Error::new_from_js_message(js_type, wit_type, "Expected a string")
+        })?
+        .to_string()
+}
+
+/// The error of converting a JS object with an unknown case name to a WIT variant
+#[allow(dead_code)]
+pub fn unknown_variant_case(
+    js_type: &'static str,
+    wit_type: &'static str,
+    tag: &str,
+) -> rquickjs::Error {
+    rquickjs::Error::new_from_js_message(js_type, wit_type, format!("Unknown variant case: {tag}"))
+}
+
+/// The error of converting a JS string with an unknown case name to a WIT enum
+#[allow(dead_code)]
+pub fn unknown_enum_case(
+    js_type: &'static str,
+    wit_type: &'static str,
+    value: &str,
+) -> rquickjs::Error {
+    rquickjs::Error::new_from_js_message(js_type, wit_type, format!("Unknown case value: {value}"))
+}
+
 // Wrapper type that forces the js type to be a bigint instead of the default number which can loose some bits due to
 #[allow(dead_code)]
 pub struct BigIntWrapper<T>(pub T);
@@ -153,19 +468,157 @@ impl<'js> FromJs<'js> for BigIntWrapper<i64> {
     }
 }
 
+/// Wraps WIT `string` values, converting JS strings containing lone surrogates according to the
+/// lone surrogate policy the wrapper crate was generated with
+#[allow(dead_code)]
+pub struct JsString(pub String);
+
+impl<'js> IntoJs<'js> for JsString {
+    fn into_js(self, ctx: &Ctx<'js>) -> rquickjs::Result<Value<'js>> {
+        self.0.into_js(ctx)
+    }
+}
+
+impl<'js> FromJs<'js> for JsString {
+    fn from_js(ctx: &Ctx<'js>, value: Value<'js>) -> rquickjs::Result<Self> {
+        let string = rquickjs::String::from_value(value)?;
+        match string.to_string() {
+            Ok(string) => Ok(JsString(string)),
+            // QuickJS encodes lone surrogates as invalid UTF-8 sequences
+            Err(rquickjs::Error::Utf8(_)) => {
+                if crate::REPLACE_LONE_SURROGATES {
+                    let string_prototype: Object =
+                        ctx.globals().get::<_, Object>("String")?.get("prototype")?;
+                    let to_well_formed: rquickjs::Function =
+                        string_prototype.get("toWellFormed")?;
+                    let well_formed: rquickjs::String =
+                        to_well_formed.call((rquickjs::function::This(string),))?;
+                    Ok(JsString(well_formed.to_string()?))
+                } else {
+                    Err(rquickjs::Error::new_from_js_message(
+                        "string",
+                        "WIT string",
+                        "The string contains lone surrogates, which are not valid Unicode",
+                    ))
+                }
+            }
+            Err(err) => Err(err),
+        }
+    }
+}
+
+/// Wraps WIT `string` values of the types configured as decimal types, represented by the builtin
+/// `Decimal` class in JS. Decimal strings and numbers are also accepted when converting from JS.
+#[allow(dead_code)]
+pub struct DecimalWrapper(pub String);
+
+impl<'js> IntoJs<'js> for DecimalWrapper {
+    fn into_js(self, ctx: &Ctx<'js>) -> rquickjs::Result<Value<'js>> {
+        let constructor: rquickjs::function::Constructor = ctx.globals().get("Decimal")?;
+        constructor.construct((self.0,))
+    }
+}
+
+impl<'js> FromJs<'js> for DecimalWrapper {
+    fn from_js(ctx: &Ctx<'js>, value: Value<'js>) -> rquickjs::Result<Self> {
+        let constructor: rquickjs::function::Constructor = ctx.globals().get("Decimal")?;
+        let decimal: Object = constructor.construct((value,))?;
+        let to_string: rquickjs::Function = decimal.get("toString")?;
+        Ok(DecimalWrapper(
+            to_string.call((rquickjs::function::This(decimal),))?,
+        ))
+    }
+}
+
+/// Wraps `list<u8>` values to be represented by `Uint8Array` in JS.
+///
+/// The vector's buffer is moved into the `ArrayBuffer` without copying. When such a buffer is
+/// returned from an exported function in a `Uint8Array` covering all of it, the buffer is moved
+/// back into a vector, detaching the `ArrayBuffer`. Buffers allocated by JS are copied, like the
+/// strings and the other lists, which QuickJS keeps in its own representation.
 #[allow(dead_code)]
 pub struct UInt8Array(pub Vec<u8>);
 
+thread_local! {
+    /// Addresses and capacities of the vectors backing `ArrayBuffer`s created by `UInt8Array`
+    static OWNED_BUFFERS: RefCell<HashMap<usize, usize>> = RefCell::new(HashMap::new());
+    /// Whether converting a `Uint8Array` to `list<u8>` can take over its buffer
+    static TAKE_OWNED_BUFFERS: Cell<bool> = const { Cell::new(false) };
+}
+
+#[allow(dead_code)]
+unsafe extern "C" fn free_owned_buffer(
+    _rt: *mut qjs::JSRuntime,
+    _opaque: *mut c_void,
+    ptr: *mut c_void,
+) {
+    // Buffers taken over by a vector are no longer registered, and must not be freed
+    let capacity = OWNED_BUFFERS
+        .try_with(|buffers| buffers.borrow_mut().remove(&(ptr as usize)))
+        .ok()
+        .flatten();
+    if let Some(capacity) = capacity {
+        drop(unsafe { Vec::from_raw_parts(ptr as *mut u8, capacity, capacity) });
+    }
+}
+
+/// Converts the result of an exported JS function, taking over the buffers of the `Uint8Array`s
+/// created by `UInt8Array` instead of copying them
+#[allow(dead_code)]
+pub fn export_result_from_js<'js, R: FromJs<'js>>(
+    ctx: &Ctx<'js>,
+    value: Value<'js>,
+) -> rquickjs::Result<R> {
+    TAKE_OWNED_BUFFERS.set(true);
+    let result = R::from_js(ctx, value);
+    TAKE_OWNED_BUFFERS.set(false);
+    result
+}
+
 impl<'js> IntoJs<'js> for UInt8Array {
     fn into_js(self, ctx: &Ctx<'js>) -> rquickjs::Result<Value<'js>> {
-        let array = rquickjs::TypedArray::new_copy(ctx.clone(), self.0)?;
-        Ok(array.into_value())
+        if self.0.capacity() == 0 {
+            return Ok(rquickjs::TypedArray::<u8>::new(ctx.clone(), self.0)?.into_value());
+        }
+
+        let mut bytes = ManuallyDrop::new(self.0);
+        let ptr = bytes.as_mut_ptr();
+        let len = bytes.len();
+        let capacity = bytes.capacity();
+        OWNED_BUFFERS.with_borrow_mut(|buffers| buffers.insert(ptr as usize, capacity));
+
+        let value = unsafe {
+            qjs::JS_NewArrayBuffer(
+                ctx.as_raw().as_ptr(),
+                ptr,
+                len as _,
+                Some(free_owned_buffer),
+                std::ptr::null_mut(),
+                false,
+            )
+        };
+        if unsafe { qjs::JS_IsException(value) } {
+            OWNED_BUFFERS.with_borrow_mut(|buffers| buffers.remove(&(ptr as usize)));
+            drop(unsafe { Vec::from_raw_parts(ptr, capacity, capacity) });
+            return Err(rquickjs::Error::Exception);
+        }
+
+        let buffer =
+            rquickjs::ArrayBuffer::from_value(unsafe { Value::from_raw(ctx.clone(), value) })
+                .ok_or_else(|| rquickjs::Error::new_into_js("ArrayBuffer", "ArrayBuffer"))?;
+        Ok(rquickjs::TypedArray::<u8>::from_arraybuffer(buffer)?.into_value())
     }
 }
 
 impl<'js> FromJs<'js> for UInt8Array {
     fn from_js(_ctx: &Ctx<'js>, value: Value<'js>) -> rquickjs::Result<Self> {
         let array = rquickjs::TypedArray::<'js, u8>::from_value(value)?;
+        if TAKE_OWNED_BUFFERS.get() {
+            if let Some(bytes) = take_owned_buffer(&array)? {
+                return Ok(UInt8Array(bytes));
+            }
+        }
+
         Ok(UInt8Array(
             array
                 .as_bytes()
@@ -174,3 +627,31 @@ impl<'js> FromJs<'js> for UInt8Array {
         ))
     }
 }
+
+/// Takes over the buffer of a `Uint8Array` if it was created by `UInt8Array` and the array covers
+/// all of it, detaching the `ArrayBuffer`
+#[allow(dead_code)]
+fn take_owned_buffer(array: &rquickjs::TypedArray<'_, u8>) -> rquickjs::Result<Option<Vec<u8>>> {
+    let Some(raw_array) = array.as_raw() else {
+        return Ok(None);
+    };
+    let mut buffer = array.arraybuffer()?;
+    let Some(raw_buffer) = buffer.as_raw() else {
+        return Ok(None);
+    };
+    if raw_array.ptr != raw_buffer.ptr || raw_array.len != raw_buffer.len {
+        return Ok(None);
+    }
+
+    let ptr = raw_buffer.ptr.as_ptr();
+    let Some(capacity) = OWNED_BUFFERS.with_borrow_mut(|buffers| buffers.remove(&(ptr as usize)))
+    else {
+        return Ok(None);
+    };
+
+    // Not registered anymore, so detaching does not free the buffer
+    buffer.detach();
+    Ok(Some(unsafe {
+        Vec::from_raw_parts(ptr, raw_buffer.len, capacity)
+    }))
+}
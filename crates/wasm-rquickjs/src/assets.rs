@@ -0,0 +1,90 @@
+use crate::{AssetSpec, GeneratorContext, ImportRecording};
+use anyhow::{Context, anyhow};
+use camino::{Utf8Component, Utf8Path, Utf8PathBuf};
+use quote::quote;
+
+/// Copies the assets into `<output>/assets` and generates `<output>/src/assets.rs`, listing them
+/// sorted by name for the `assets` builtin module.
+pub fn generate_assets(context: &GeneratorContext<'_>) -> anyhow::Result<()> {
+    check_asset_names(&context.options.assets)?;
+
+    let mut assets = context.options.assets.iter().collect::<Vec<_>>();
+    assets.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let mut entries = Vec::new();
+    for asset in assets {
+        let target = context.output.join(asset_file_path(&asset.name));
+        if let Some(parent) = target.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create directory {}", parent.display()))?;
+        }
+        std::fs::copy(&asset.path, &target).with_context(|| {
+            format!(
+                "Failed to copy asset {} from {}",
+                asset.name,
+                asset.path.display()
+            )
+        })?;
+
+        let name = &asset.name;
+        let include_path = format!("../assets/{name}");
+        entries.push(quote! {
+            (#name, include_bytes!(#include_path))
+        });
+    }
+
+    let assets_tokens = quote! {
+        pub static ASSETS: &[(&str, &[u8])] = &[#(#entries),*];
+    };
+    let assets_ast: syn::File =
+        syn::parse2(assets_tokens).context("failed to parse generated assets.rs tokens")?;
+    let assets_src = prettier_please::unparse(&assets_ast);
+
+    context
+        .write_generated(&context.output.join("src").join("assets.rs"), assets_src)
+        .context("Failed to write assets.rs")
+}
+
+/// Name of the copy of the replayed import recording in the wrapper crate's `src` directory
+pub const IMPORT_RECORDING_FILE_NAME: &str = "import-recording.jsonl";
+
+/// Copies the recording replayed instead of calling the imported interfaces into
+/// `<output>/src`, if any
+pub fn copy_import_recording(context: &GeneratorContext<'_>) -> anyhow::Result<()> {
+    if let Some(ImportRecording::Replay(path)) = &context.options.import_recording {
+        std::fs::copy(
+            path,
+            context.output.join("src").join(IMPORT_RECORDING_FILE_NAME),
+        )
+        .with_context(|| format!("Failed to copy the import recording {}", path.display()))?;
+    }
+    Ok(())
+}
+
+/// Path of an asset's copy, relative to the root of the wrapper crate
+pub fn asset_file_path(name: &str) -> Utf8PathBuf {
+    Utf8Path::new("assets").join(name)
+}
+
+/// Checks that the asset names are unique relative paths separated by `/`, not leaving the
+/// `assets` directory of the wrapper crate
+pub fn check_asset_names(assets: &[AssetSpec]) -> anyhow::Result<()> {
+    for (index, asset) in assets.iter().enumerate() {
+        let name = Utf8Path::new(&asset.name);
+        let is_valid = !asset.name.is_empty()
+            && !asset.name.contains('\\')
+            && name
+                .components()
+                .all(|component| matches!(component, Utf8Component::Normal(_)));
+        if !is_valid {
+            return Err(anyhow!(
+                "Invalid asset name `{}`, it must be a relative path such as `templates/index.html`",
+                asset.name
+            ));
+        }
+        if assets[..index].iter().any(|other| other.name == asset.name) {
+            return Err(anyhow!("Duplicate asset name `{}`", asset.name));
+        }
+    }
+    Ok(())
+}
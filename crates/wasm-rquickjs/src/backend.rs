@@ -0,0 +1,17 @@
+use crate::WorldModel;
+use std::fmt::Debug;
+use std::path::Path;
+
+/// A custom code generator that is run by [`generate_wrapper_crate`](crate::generate_wrapper_crate)
+/// after the builtin ones, writing extra artifacts derived from the analyzed world, such as an
+/// OpenAPI document for a world exporting an HTTP handler, or JSON schemas of the WIT types.
+///
+/// Backends are registered in [`GeneratorOptions::backends`](crate::GeneratorOptions::backends).
+pub trait CodegenBackend: Debug + Send + Sync {
+    /// Name of the backend, used in error messages
+    fn name(&self) -> &str;
+
+    /// Generates the backend's artifacts. The `output` parameter is the root directory of the
+    /// generated wrapper crate.
+    fn generate(&self, model: &WorldModel, output: &Path) -> anyhow::Result<()>;
+}
@@ -0,0 +1,65 @@
+//! Helpers for generating a wrapper crate from a cargo build script (`build.rs`) instead of
+//! invoking the CLI.
+
+use crate::paths::to_utf8;
+use crate::{EmbeddingMode, GeneratorOptions, JsModuleSpec, generate_wrapper_crate};
+use anyhow::Context;
+use std::path::{Path, PathBuf};
+
+/// Generates a wrapper crate into the `$OUT_DIR/<name>` directory of the running build script,
+/// returning its path.
+///
+/// Prints a `cargo:rerun-if-changed` line for each of the inputs (see [`input_paths`]), so
+/// cargo only runs the build script again if one of them changes, and a `cargo:warning` line
+/// for each of the generator's diagnostics.
+pub fn generate(
+    name: &str,
+    wit: impl AsRef<Path>,
+    js_modules: &[JsModuleSpec],
+    world: Option<&str>,
+    options: &GeneratorOptions,
+) -> anyhow::Result<PathBuf> {
+    let wit = wit.as_ref();
+    let out_dir = std::env::var_os("OUT_DIR")
+        .context("OUT_DIR is not set, the wrapper crate must be generated from a build script")?;
+    let output = PathBuf::from(out_dir).join(name);
+
+    // The instructions of build scripts cannot contain non-UTF-8 paths
+    for path in input_paths(wit, js_modules, options) {
+        println!(
+            "cargo:rerun-if-changed={}",
+            to_utf8(&path, "an input of the build script")?
+        );
+    }
+
+    let diagnostics = generate_wrapper_crate(wit, js_modules, &output, world, options)
+        .with_context(|| format!("Failed to generate the wrapper crate {name}"))?;
+    for diagnostic in diagnostics {
+        // Warnings are single lines
+        println!(
+            "cargo:warning={}",
+            diagnostic.to_string().replace('\n', " ")
+        );
+    }
+    Ok(output)
+}
+
+/// Lists the files and directories the generated wrapper crate depends on: the WIT directory,
+/// the embedded JS modules and the assets
+pub fn input_paths(
+    wit: impl AsRef<Path>,
+    js_modules: &[JsModuleSpec],
+    options: &GeneratorOptions,
+) -> Vec<PathBuf> {
+    // Cargo checks all the files in a directory for changes
+    let mut paths = vec![wit.as_ref().to_path_buf()];
+    for module in js_modules {
+        if let EmbeddingMode::EmbedFile(path) = &module.mode {
+            paths.push(path.clone());
+        }
+    }
+    for asset in &options.assets {
+        paths.push(asset.path.clone());
+    }
+    paths
+}
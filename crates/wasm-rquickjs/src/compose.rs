@@ -0,0 +1,97 @@
+use anyhow::Context;
+use camino::{Utf8Path, Utf8PathBuf};
+use std::collections::{BTreeMap, BTreeSet};
+use wac_graph::types::{Package, SubtypeChecker};
+use wac_graph::{CompositionGraph, EncodeOptions, PackageId, PlugError};
+
+/// Composes the `socket` component with the `plugs` components, satisfying the imports of the
+/// socket with the matching exports of the plugs, and returns the encoded composed component.
+///
+/// This is the step needed to combine a component generated in composition mode with the
+/// component providing its JavaScript module.
+pub fn compose(socket: &Utf8Path, plugs: &[Utf8PathBuf]) -> anyhow::Result<Vec<u8>> {
+    let mut graph = CompositionGraph::new();
+    let socket_package = Package::from_file("socket", None, socket, graph.types_mut())
+        .with_context(|| format!("Failed to load socket component {socket}"))?;
+    let socket_id = graph.register_package(socket_package)?;
+
+    let mut plug_ids = Vec::new();
+    for (idx, plug_path) in plugs.iter().enumerate() {
+        let plug_package =
+            Package::from_file(&format!("plug{idx}"), None, plug_path, graph.types_mut())
+                .with_context(|| format!("Failed to load plug component {plug_path}"))?;
+        plug_ids.push((plug_path.to_string(), graph.register_package(plug_package)?));
+    }
+
+    plug(&mut graph, plug_ids, socket_id)?;
+
+    graph
+        .encode(EncodeOptions::default())
+        .context("Failed to encode the composed component")
+}
+
+/// Plugs the exports of the `plugs` packages into the matching imports of the `socket` package,
+/// and exports all the exports of the socket from the composition.
+///
+/// Based on https://github.com/bytecodealliance/wac/blob/release-0.6.0/crates/wac-graph/src/plug.rs#L23
+/// but instead of returning NoPlugError, it logs skipped instantiations
+pub fn plug(
+    graph: &mut CompositionGraph,
+    plugs: Vec<(String, PackageId)>,
+    socket: PackageId,
+) -> Result<(), PlugError> {
+    let socket_instantiation = graph.instantiate(socket);
+
+    let mut requested_plugs = BTreeSet::<String>::new();
+    let mut plug_exports_to_plug = BTreeMap::<String, String>::new();
+
+    for (plug_name, plug) in plugs {
+        requested_plugs.insert(plug_name.clone());
+
+        let mut plug_exports = Vec::new();
+        let mut cache = Default::default();
+        let mut checker = SubtypeChecker::new(&mut cache);
+        for (name, plug_ty) in &graph.types()[graph[plug].ty()].exports {
+            if let Some(socket_ty) = graph.types()[graph[socket].ty()].imports.get(name)
+                && checker
+                    .is_subtype(*plug_ty, graph.types(), *socket_ty, graph.types())
+                    .is_ok()
+            {
+                plug_exports.push(name.clone());
+            }
+        }
+
+        // Instantiate the plug component
+        let mut plug_instantiation = None;
+        for plug_export_name in plug_exports {
+            plug_exports_to_plug.insert(plug_export_name.clone(), plug_name.clone());
+
+            let plug_instantiation =
+                *plug_instantiation.get_or_insert_with(|| graph.instantiate(plug));
+            let export = graph
+                .alias_instance_export(plug_instantiation, &plug_export_name)
+                .map_err(|err| PlugError::GraphError { source: err.into() })?;
+            graph
+                .set_instantiation_argument(socket_instantiation, &plug_export_name, export)
+                .map_err(|err| PlugError::GraphError { source: err.into() })?;
+        }
+    }
+
+    // Export all exports from the socket component.
+    for name in graph.types()[graph[socket].ty()]
+        .exports
+        .keys()
+        .cloned()
+        .collect::<Vec<_>>()
+    {
+        let export = graph
+            .alias_instance_export(socket_instantiation, &name)
+            .map_err(|err| PlugError::GraphError { source: err.into() })?;
+
+        graph
+            .export(export, &name)
+            .map_err(|err| PlugError::GraphError { source: err.into() })?;
+    }
+
+    Ok(())
+}
@@ -1,14 +1,14 @@
-use crate::GeneratorContext;
 use crate::javascript::escape_js_ident;
 use crate::rust_bindgen::{RustType, TypeOwnershipStyle, escape_rust_ident, type_mode_for};
 use crate::types::{get_wrapped_type, type_id_to_type_ref};
+use crate::{GenerationStage, GeneratorContext, report_progress};
 use anyhow::Context;
 use heck::{ToLowerCamelCase, ToShoutySnakeCase, ToSnakeCase, ToUpperCamelCase};
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::quote;
 use std::collections::BTreeSet;
 use syn::{Lit, LitStr};
-use wit_parser::{Type, TypeDefKind, TypeId};
+use wit_parser::{Handle, Type, TypeDefKind, TypeId};
 
 /// Generates the `<output>/src/conversions.rs` file for the wrapper crate, implementing the IntoJs
 /// and FromJs typeclass instances for the types generated in the Rust bindings..
@@ -25,7 +25,7 @@ pub fn generate_conversions(context: &GeneratorContext<'_>) -> anyhow::Result<()
     let conversions_path = context.output.join("src").join("conversions.rs");
     let conversions_src = prettier_please::unparse(&conversions_ast);
 
-    std::fs::write(&conversions_path, conversions_src)?;
+    context.write_generated(&conversions_path, conversions_src)?;
 
     Ok(())
 }
@@ -36,13 +36,21 @@ fn generate_conversion_instances(
     let mut result = Vec::new();
 
     let types_to_process = context.visited_types.borrow().clone();
+    let total = types_to_process.len();
     let mut visited_types = BTreeSet::new();
-    for type_id in &types_to_process {
+    report_progress(context.options, GenerationStage::Conversions, 0, total);
+    for (index, type_id) in types_to_process.iter().enumerate() {
         if let Some(snippet) =
             generate_conversion_instances_for_type(context, *type_id, &mut visited_types)?
         {
             result.push(snippet);
         }
+        report_progress(
+            context.options,
+            GenerationStage::Conversions,
+            index + 1,
+            total,
+        );
     }
 
     Ok(result)
@@ -67,9 +75,17 @@ fn generate_conversion_instances_for_type(
             let mut set_fields = Vec::new();
             let mut get_fields = Vec::new();
             let mut rust_field_list = Vec::new();
+            let mut js_field_names = Vec::new();
+            let mut required_fields = Vec::new();
 
             for field in &record.fields {
                 let js_field_name = escape_js_ident(field.name.to_lower_camel_case());
+                let wit_type_lit =
+                    LitStr::new(&wit_type_name(context, &field.ty)?, Span::call_site());
+                if !is_optional(context, &field.ty)? {
+                    required_fields.push(quote! { (#js_field_name, #wit_type_lit) });
+                }
+                js_field_names.push(js_field_name.clone());
                 let rust_field_ident = Ident::new(
                     &escape_rust_ident(&field.name.to_snake_case()),
                     Span::call_site(),
@@ -89,20 +105,41 @@ fn generate_conversion_instances_for_type(
                 let wrapped_field = field_type.wrap.run(quote! { self.#rust_field_ident });
                 let unwrapped_field = field_type.unwrap.run(quote! { #rust_field_ident });
 
-                set_fields.push(quote! {
-                    let #rust_field_ident: #wrapped_field_type = #wrapped_field;
-                    obj.set(#field_name_lit, #rust_field_ident)?;
-                });
+                if context.options.intern_field_names {
+                    let idx = js_field_names.len() - 1;
+                    set_fields.push(quote! {
+                        let __key = FIELD_ATOMS.with(|atoms| atoms.get(ctx, #idx))?;
+                        obj.set::<_, #wrapped_field_type>(__key, #wrapped_field)?;
+                    });
 
-                get_fields.push(quote! {
-                    let #rust_field_ident: #wrapped_field_type = obj.get(#field_name_lit)?;
-                    let #rust_field_ident: #original_field_type = #unwrapped_field;
-                });
+                    get_fields.push(quote! {
+                        let __key = FIELD_ATOMS.with(|atoms| atoms.get(obj.ctx(), #idx))?;
+                        let #rust_field_ident: #wrapped_field_type =
+                            crate::wrappers::get_interned_field(&obj, __key, #field_name_lit, #wit_type_lit)?;
+                        let #rust_field_ident: #original_field_type = #unwrapped_field;
+                    });
+                } else {
+                    set_fields.push(quote! {
+                        obj.set::<_, #wrapped_field_type>(#field_name_lit, #wrapped_field)?;
+                    });
+
+                    get_fields.push(quote! {
+                        let #rust_field_ident: #wrapped_field_type =
+                            crate::wrappers::get_field(&obj, #field_name_lit, #wit_type_lit)?;
+                        let #rust_field_ident: #original_field_type = #unwrapped_field;
+                    });
+                }
 
                 rust_field_list.push(rust_field_ident);
             }
 
-            Ok(Some(quote! {
+            let lit_wit_type = Lit::Str(LitStr::new(
+                &format!("WIT {}", typ.name.clone().unwrap_or_default()),
+                Span::call_site(),
+            ));
+            let strict = context.options.strict_records;
+
+            let impls = quote! {
                 impl<'js> rquickjs::IntoJs<'js> for #type_path {
                     fn into_js(self, ctx: &rquickjs::Ctx<'js>) -> rquickjs::Result<rquickjs::Value<'js>> {
                         // record
@@ -115,13 +152,37 @@ fn generate_conversion_instances_for_type(
                 impl<'js> rquickjs::FromJs<'js> for #type_path {
                     fn from_js(_ctx: &rquickjs::Ctx<'js>, value: rquickjs::Value<'js>) -> rquickjs::Result<Self> {
                         let obj = rquickjs::Object::from_value(value)?;
+                        crate::wrappers::check_record_fields(
+                            &obj,
+                            #lit_wit_type,
+                            &[#(#js_field_names),*],
+                            &[#(#required_fields),*],
+                            #strict,
+                        )?;
                         #(#get_fields);*
                         Ok(Self {
                             #(#rust_field_list),*
                         })
                     }
                 }
-            }))
+            };
+
+            if context.options.intern_field_names {
+                // The field name atoms are cached per record type, in a scope shared by both
+                // conversion directions
+                Ok(Some(quote! {
+                    const _: () = {
+                        thread_local! {
+                            static FIELD_ATOMS: crate::wrappers::AtomCache =
+                                const { crate::wrappers::AtomCache::new(&[#(#js_field_names),*]) };
+                        }
+
+                        #impls
+                    };
+                }))
+            } else {
+                Ok(Some(impls))
+            }
         }
         TypeDefKind::Flags(flags) => {
             let type_path = type_id_to_type_ref(context, type_id)?;
@@ -182,29 +243,24 @@ fn generate_conversion_instances_for_type(
                         type_mode_for(context, ty, TypeOwnershipStyle::Owned, "'_"),
                     );
                     let wrapped_type = get_wrapped_type(context, &rust_type, &rust_type, ty)?;
+                    let wit_type_lit = LitStr::new(&wit_type_name(context, ty)?, Span::call_site());
                     let wrapped_inner = wrapped_type.wrap.run(quote! { inner });
                     let unwrapped_inner = wrapped_type.unwrap.run(quote! { inner });
                     let wrapped_type = &wrapped_type.wrapped_type_ref;
 
                     into_cases.push(quote! {
-                        #type_path::#rust_ident(inner) => {
-                            obj.set(crate::wrappers::TAG, #case_name_lit)?;
-                            let case_value: #wrapped_type = #wrapped_inner;
-                            obj.set(crate::wrappers::VALUE, case_value)?;
-                        }
+                        #type_path::#rust_ident(inner) => crate::wrappers::variant_case_with_value::<#wrapped_type>(ctx, #case_name_lit, #wrapped_inner),
                     });
 
                     from_cases.push(quote! {
                         #case_name_lit => {
-                            let inner: #wrapped_type = obj.get(crate::wrappers::VALUE)?;
+                            let inner: #wrapped_type = crate::wrappers::variant_value(&obj, #wit_type_lit)?;
                             Ok(#type_path::#rust_ident(#unwrapped_inner))
                         }
                     });
                 } else {
                     into_cases.push(quote! {
-                        #type_path::#rust_ident => {
-                            obj.set(crate::wrappers::TAG, #case_name_lit)?;
-                        }
+                        #type_path::#rust_ident => crate::wrappers::variant_case(ctx, #case_name_lit),
                     });
 
                     from_cases.push(quote! {
@@ -221,29 +277,28 @@ fn generate_conversion_instances_for_type(
                 &format!("WIT {}", typ.name.clone().unwrap_or_default()),
                 Span::call_site(),
             ));
+            // The object is only needed for getting the values of the cases
+            let obj_ident = if variant.cases.iter().any(|case| case.ty.is_some()) {
+                quote! { obj }
+            } else {
+                quote! { _ }
+            };
 
             Ok(Some(quote! {
                 impl<'js> rquickjs::IntoJs<'js> for #type_path {
                     fn into_js(self, ctx: &rquickjs::Ctx<'js>) -> rquickjs::Result<rquickjs::Value<'js>> {
-                        let obj = rquickjs::Object::new(ctx.clone())?;
                         match self {
                             #(#into_cases)*
                         }
-                        Ok(obj.into_value())
                     }
                 }
 
                 impl<'js> rquickjs::FromJs<'js> for #type_path {
                     fn from_js(_ctx: &rquickjs::Ctx<'js>, value: rquickjs::Value<'js>) -> rquickjs::Result<Self> {
-                        let obj = rquickjs::Object::from_value(value)?;
-                        let tag: String = obj.get(crate::wrappers::TAG)?;
+                        let (#obj_ident, tag) = crate::wrappers::variant_tag(value)?;
                         match tag.as_str() {
                             #(#from_cases)*
-                            _ => Err(rquickjs::Error::new_from_js_message(
-                                #lit_js_type,
-                                #lit_wit_type,
-                                format!("Unknown variant case: {tag}"),
-                            )),
+                            _ => Err(crate::wrappers::unknown_variant_case(#lit_js_type, #lit_wit_type, &tag)),
                         }
                     }
                 }
@@ -282,19 +337,10 @@ fn generate_conversion_instances_for_type(
 
                 impl<'js> rquickjs::FromJs<'js> for #type_path {
                     fn from_js(_ctx: &rquickjs::Ctx<'js>, value: rquickjs::Value<'js>) -> rquickjs::Result<Self> {
-                        let value = value
-                            .as_string()
-                            .ok_or_else(|| {
-                                rquickjs::Error::new_from_js_message(#lit_js_type, #lit_wit_type, "Expected a string")
-                            })?
-                            .to_string()?;
+                        let value = crate::wrappers::enum_case(value, #lit_js_type, #lit_wit_type)?;
                         match value.as_str() {
                             #(#from_cases)*
-                            _ => Err(rquickjs::Error::new_from_js_message(
-                                #lit_js_type,
-                                #lit_wit_type,
-                                format!("Unknown case value: {value}"),
-                            )),
+                            _ => Err(crate::wrappers::unknown_enum_case(#lit_js_type, #lit_wit_type, &value)),
                         }
                     }
                 }
@@ -306,3 +352,74 @@ fn generate_conversion_instances_for_type(
         _ => Ok(None),
     }
 }
+
+/// Checks whether a type accepts a missing (`undefined`) value when converted from JS
+fn is_optional(context: &GeneratorContext<'_>, typ: &Type) -> anyhow::Result<bool> {
+    match typ {
+        Type::Id(type_id) => match &context.typ(*type_id)?.kind {
+            TypeDefKind::Option(_) => Ok(true),
+            TypeDefKind::Type(inner) => is_optional(context, inner),
+            _ => Ok(false),
+        },
+        _ => Ok(false),
+    }
+}
+
+/// Renders a type in WIT syntax, to be used in the generated error messages
+pub fn wit_type_name(context: &GeneratorContext<'_>, typ: &Type) -> anyhow::Result<String> {
+    match typ {
+        Type::Bool => Ok("bool".to_string()),
+        Type::U8 => Ok("u8".to_string()),
+        Type::U16 => Ok("u16".to_string()),
+        Type::U32 => Ok("u32".to_string()),
+        Type::U64 => Ok("u64".to_string()),
+        Type::S8 => Ok("s8".to_string()),
+        Type::S16 => Ok("s16".to_string()),
+        Type::S32 => Ok("s32".to_string()),
+        Type::S64 => Ok("s64".to_string()),
+        Type::F32 => Ok("f32".to_string()),
+        Type::F64 => Ok("f64".to_string()),
+        Type::Char => Ok("char".to_string()),
+        Type::String => Ok("string".to_string()),
+        Type::ErrorContext => Ok("error-context".to_string()),
+        Type::Id(type_id) => {
+            let typ = context.typ(*type_id)?;
+            if let Some(name) = &typ.name {
+                return Ok(name.clone());
+            }
+            match &typ.kind {
+                TypeDefKind::Option(inner) => {
+                    Ok(format!("option<{}>", wit_type_name(context, inner)?))
+                }
+                TypeDefKind::List(inner) => Ok(format!("list<{}>", wit_type_name(context, inner)?)),
+                TypeDefKind::Tuple(tuple) => {
+                    let types = tuple
+                        .types
+                        .iter()
+                        .map(|t| wit_type_name(context, t))
+                        .collect::<anyhow::Result<Vec<_>>>()?;
+                    Ok(format!("tuple<{}>", types.join(", ")))
+                }
+                TypeDefKind::Result(result) => {
+                    let ok = match &result.ok {
+                        Some(ok) => wit_type_name(context, ok)?,
+                        None => "_".to_string(),
+                    };
+                    match &result.err {
+                        Some(err) => Ok(format!("result<{ok}, {}>", wit_type_name(context, err)?)),
+                        None => Ok(format!("result<{ok}>")),
+                    }
+                }
+                TypeDefKind::Handle(Handle::Own(resource_id)) => {
+                    Ok(context.typ(*resource_id)?.name.clone().unwrap_or_default())
+                }
+                TypeDefKind::Handle(Handle::Borrow(resource_id)) => Ok(format!(
+                    "borrow<{}>",
+                    context.typ(*resource_id)?.name.clone().unwrap_or_default()
+                )),
+                TypeDefKind::Type(inner) => wit_type_name(context, inner),
+                _ => Ok("_".to_string()),
+            }
+        }
+    }
+}
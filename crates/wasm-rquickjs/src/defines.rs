@@ -0,0 +1,62 @@
+use crate::js_scanner::{is_identifier_char, rewrite_identifiers};
+use std::collections::BTreeMap;
+
+/// The operators making the expression before them an assignment target, checked after the ones
+/// starting with `=` but not assigning (`==`, `===` and `=>`)
+const ASSIGNMENT_OPERATORS: &[&str] = &[
+    "=", "+=", "-=", "*=", "/=", "%=", "**=", "<<=", ">>=", ">>>=", "&=", "|=", "^=", "&&=", "||=",
+    "??=", "++", "--",
+];
+
+/// Replaces the `process.env.KEY` and `__DEFINE__.KEY` expressions of a JS source by the string
+/// literal of the value defined for `KEY`. Occurrences in string literals, template literal texts,
+/// comments and regular expression literals, and keys without a definition are left untouched, as
+/// are the occurrences being assigned, incremented, decremented or deleted, which a string literal
+/// cannot replace.
+pub fn apply_defines(source: &str, defines: &BTreeMap<String, String>) -> String {
+    if defines.is_empty() {
+        return source.to_string();
+    }
+    rewrite_identifiers(source, |identifier| {
+        if identifier.after_dot {
+            return None;
+        }
+        let prefix = match identifier.name {
+            "process" => ".env.",
+            "__DEFINE__" => ".",
+            _ => return None,
+        };
+        let key_start = identifier.start + identifier.name.len() + prefix.len();
+        if !source[identifier.start + identifier.name.len()..].starts_with(prefix) {
+            return None;
+        }
+        let key_len = source[key_start..]
+            .bytes()
+            .position(|c| !is_identifier_char(c))
+            .unwrap_or(source.len() - key_start);
+        let value = defines.get(&source[key_start..key_start + key_len])?;
+        if is_assignment_target(source, identifier.start, key_start + key_len)
+            || identifier.previous == Some("delete")
+        {
+            return None;
+        }
+        let literal = serde_json::to_string(value).expect("Failed to encode a string as JSON");
+        Some((key_start + key_len, literal))
+    })
+}
+
+/// Whether the expression of a JS source between `start` and `end` is followed by an assignment
+/// or update operator, or preceded by a prefix update operator
+fn is_assignment_target(source: &str, start: usize, end: usize) -> bool {
+    let before = source[..start].trim_end();
+    if before.ends_with("++") || before.ends_with("--") {
+        return true;
+    }
+    let after = source[end..].trim_start();
+    if after.starts_with("==") || after.starts_with("=>") {
+        return false;
+    }
+    ASSIGNMENT_OPERATORS
+        .iter()
+        .any(|operator| after.starts_with(operator))
+}
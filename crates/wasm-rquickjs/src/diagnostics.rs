@@ -0,0 +1,350 @@
+use crate::helper_modules::{GOLEM_MODULE_NAME, enabled_helper_modules};
+use crate::imports::{WORLD_IMPORTS_MODULE_NAME, collect_imported_interfaces};
+use crate::js_scanner::scan_identifiers;
+use crate::model::{FunctionModel, FunctionModelKind, TypeModelKind};
+use crate::pragmas::read_module_variants;
+use crate::skeleton::{cargo_toml_document, default_features};
+use crate::{EmbeddingMode, GeneratorContext, JsModuleSpec};
+use anyhow::Context;
+use heck::ToLowerCamelCase;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{Display, Formatter};
+
+/// A non-fatal finding of the generator, returned to the caller instead of failing the generation
+/// or being printed, so tools embedding the generator can present it in their own way.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Diagnostic {
+    /// None of the embedded JS modules refers to the JS module of an imported interface, so the
+    /// import may be unnecessary
+    UnusedImport { module: String },
+    /// A WIT name maps to a reserved word in JavaScript, so a `_` suffix was added to its JS name
+    RenamedIdentifier {
+        item: String,
+        wit_name: String,
+        js_name: String,
+    },
+    /// The world imports the Golem host API, so the `golem` JS module with durability helpers
+    /// was added to the component
+    GolemModuleEnabled,
+    /// An embedded JS module uses a global API that is only defined if a cargo feature not
+    /// enabled by default in the wrapper crate is enabled, so it would fail with a `ReferenceError`
+    UnavailableApi {
+        api: String,
+        feature: String,
+        /// The first use of the API, as `path:line:column`
+        location: String,
+    },
+    /// An imported or exported interface uses WIT constructs that are not supported, and was
+    /// skipped in `keep_going` mode: imports are not available to JS, and the functions of
+    /// exports trap when called
+    SkippedInterface {
+        interface: String,
+        exported: bool,
+        /// The unsupported items of the interface and why they are not supported
+        reasons: Vec<String>,
+    },
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Diagnostic::UnusedImport { module } => write!(
+                f,
+                "The imported module `{module}` is not used by any of the JavaScript modules"
+            ),
+            Diagnostic::RenamedIdentifier {
+                item,
+                wit_name,
+                js_name,
+            } => write!(
+                f,
+                "The name of {item} `{wit_name}` is a reserved word in JavaScript, it is available as `{js_name}`"
+            ),
+            Diagnostic::GolemModuleEnabled => write!(
+                f,
+                "The world imports the Golem host API, the `{GOLEM_MODULE_NAME}` module is available to JavaScript"
+            ),
+            Diagnostic::UnavailableApi {
+                api,
+                feature,
+                location,
+            } => write!(
+                f,
+                "`{api}` is used at {location}, but it is only available with the `{feature}` feature, which is not enabled by default"
+            ),
+            Diagnostic::SkippedInterface {
+                interface,
+                exported,
+                reasons,
+            } => {
+                if *exported {
+                    write!(
+                        f,
+                        "The exported interface `{interface}` is not supported, its functions trap when called:"
+                    )?;
+                } else {
+                    write!(
+                        f,
+                        "The imported interface `{interface}` is not supported, it is not available to JavaScript:"
+                    )?;
+                }
+                for reason in reasons {
+                    write!(f, "\n  - {reason}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Collects the diagnostics of the selected world. If the JS modules are given, it also checks
+/// whether they use the imported interfaces.
+pub fn collect_diagnostics(
+    context: &GeneratorContext<'_>,
+    js_modules: Option<&[JsModuleSpec]>,
+) -> anyhow::Result<Vec<Diagnostic>> {
+    let mut collector = DiagnosticCollector {
+        context,
+        diagnostics: Vec::new(),
+    };
+    collector.collect_renamed_identifiers()?;
+    if let Some(js_modules) = js_modules {
+        collector.collect_unused_imports(js_modules)?;
+        collector
+            .diagnostics
+            .extend(unavailable_apis(context, js_modules)?);
+    }
+
+    let (_, interfaces) = collect_imported_interfaces(context)?;
+    if enabled_helper_modules(&interfaces)
+        .iter()
+        .any(|helper| helper.module.name == GOLEM_MODULE_NAME)
+    {
+        collector.diagnostics.push(Diagnostic::GolemModuleEnabled);
+    }
+
+    Ok(collector.diagnostics)
+}
+
+/// The global JS APIs only defined if a cargo feature of the wrapper crate is enabled
+const FEATURE_APIS: &[(&str, &[&str])] = &[
+    (
+        "http",
+        &[
+            "fetch",
+            "Headers",
+            "Request",
+            "Response",
+            "Blob",
+            "File",
+            "FormData",
+            "EventSource",
+            "MessageEvent",
+        ],
+    ),
+    ("intl", &["Intl"]),
+    ("decimal", &["Decimal"]),
+];
+
+/// Keywords after which an identifier is declared instead of referring to a global
+const DECLARATION_KEYWORDS: &[&str] = &["class", "const", "function", "let", "var"];
+
+/// Finds the uses of global APIs in the embedded JS modules that are not available with the
+/// default features of the wrapper crate, reporting the first use of each API per module. For
+/// modules with feature pragmas, the variant matching the default features is checked.
+pub fn unavailable_apis(
+    context: &GeneratorContext<'_>,
+    js_modules: &[JsModuleSpec],
+) -> anyhow::Result<Vec<Diagnostic>> {
+    let enabled = default_features(&cargo_toml_document(context)?);
+    let disabled_apis = FEATURE_APIS
+        .iter()
+        .filter(|(feature, _)| !enabled.iter().any(|enabled| enabled == feature))
+        .flat_map(|(feature, apis)| apis.iter().map(move |api| (*api, *feature)))
+        .collect::<BTreeMap<_, _>>();
+
+    let mut diagnostics = Vec::new();
+    if disabled_apis.is_empty() {
+        return Ok(diagnostics);
+    }
+    for module in js_modules {
+        let EmbeddingMode::EmbedFile(path) = &module.mode else {
+            continue;
+        };
+        let variants = read_module_variants(module, path)?;
+        let Some(variant) = variants.iter().find(|variant| {
+            variant
+                .features
+                .iter()
+                .all(|(feature, is_enabled)| enabled.contains(feature) == *is_enabled)
+        }) else {
+            continue;
+        };
+
+        let mut declared = BTreeSet::new();
+        let mut uses = BTreeMap::new();
+        scan_identifiers(&variant.source, |identifier| {
+            if identifier.after_dot || !disabled_apis.contains_key(identifier.name) {
+                return;
+            }
+            match identifier.previous {
+                Some(previous) if DECLARATION_KEYWORDS.contains(&previous) => {
+                    declared.insert(identifier.name);
+                }
+                // Feature detection such as `typeof fetch === 'function'` is fine
+                Some("typeof") => {}
+                _ => {
+                    uses.entry(identifier.name).or_insert(identifier.start);
+                }
+            }
+        });
+        let mut uses = uses
+            .into_iter()
+            .filter(|(api, _)| !declared.contains(api))
+            .collect::<Vec<_>>();
+        uses.sort_by_key(|(_, start)| *start);
+        for (api, start) in uses {
+            let (line, column) = line_and_column(&variant.source, start);
+            diagnostics.push(Diagnostic::UnavailableApi {
+                api: api.to_string(),
+                feature: disabled_apis[api].to_string(),
+                location: format!("{}:{line}:{column}", path.display()),
+            });
+        }
+    }
+    Ok(diagnostics)
+}
+
+/// The 1-based line and column of a byte offset of a source
+fn line_and_column(source: &str, offset: usize) -> (usize, usize) {
+    let before = &source[..offset];
+    let line_start = before.rfind('\n').map(|index| index + 1).unwrap_or(0);
+    (
+        before.matches('\n').count() + 1,
+        before[line_start..].chars().count() + 1,
+    )
+}
+
+struct DiagnosticCollector<'a> {
+    context: &'a GeneratorContext<'a>,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl DiagnosticCollector<'_> {
+    fn collect_renamed_identifiers(&mut self) -> anyhow::Result<()> {
+        let model = self.context.model()?;
+
+        for function in &model.exported_functions {
+            self.check_function("the exported function", function);
+        }
+        for function in &model.imported_functions {
+            self.check_function("the imported function", function);
+        }
+        for (direction, interfaces) in [("exported", &model.exports), ("imported", &model.imports)]
+        {
+            for interface in interfaces.iter().filter(|interface| !interface.skipped) {
+                if direction == "exported" {
+                    let name = interface.wit_name.rsplit('/').next().unwrap_or_default();
+                    let name = name.split('@').next().unwrap_or_default();
+                    self.check_name("the exported interface", name, &interface.js_name);
+                }
+                for function in &interface.functions {
+                    self.check_function(&format!("the {direction} function"), function);
+                }
+                for typ in &interface.types {
+                    let (kind, fields) = match &typ.kind {
+                        TypeModelKind::Record { fields } => ("a field of record", fields),
+                        TypeModelKind::Flags { flags } => ("a flag of flags", flags),
+                        _ => continue,
+                    };
+                    for field in fields {
+                        self.check_name(
+                            &format!("{kind} `{}`", typ.wit_name),
+                            &field.wit_name,
+                            &field.js_name,
+                        );
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn check_function(&mut self, item: &str, function: &FunctionModel) {
+        if function.kind == FunctionModelKind::Freestanding {
+            self.check_name(item, &function.wit_name, &function.js_name);
+        }
+    }
+
+    /// Reports names that got a `_` suffix because they are reserved words in JS
+    fn check_name(&mut self, item: &str, wit_name: &str, js_name: &str) {
+        if js_name == format!("{}_", wit_name.to_lower_camel_case()) {
+            let diagnostic = Diagnostic::RenamedIdentifier {
+                item: item.to_string(),
+                wit_name: wit_name.to_string(),
+                js_name: js_name.to_string(),
+            };
+            if !self.diagnostics.contains(&diagnostic) {
+                self.diagnostics.push(diagnostic);
+            }
+        }
+    }
+
+    /// Reports the imported modules that none of the embedded JS modules refers to by name. This
+    /// is not possible if any of the modules is provided through composition.
+    fn collect_unused_imports(&mut self, js_modules: &[JsModuleSpec]) -> anyhow::Result<()> {
+        let mut sources = Vec::new();
+        for module in js_modules {
+            match &module.mode {
+                EmbeddingMode::EmbedFile(path) => {
+                    sources.push(std::fs::read_to_string(path).with_context(|| {
+                        format!("Failed to read JavaScript module {}", path.display())
+                    })?)
+                }
+                EmbeddingMode::Composition => return Ok(()),
+            }
+        }
+        let is_used = |module: &str| {
+            sources.iter().any(|source| {
+                source.contains(&format!("'{module}'")) || source.contains(&format!("\"{module}\""))
+            })
+        };
+
+        let model = self.context.model()?;
+        let (_, interfaces) = collect_imported_interfaces(self.context)?;
+        // The interfaces used through the helper modules built on top of them
+        let used_by_helpers = enabled_helper_modules(&interfaces)
+            .iter()
+            .filter(|helper| is_used(helper.module.name))
+            .flat_map(|helper| helper.imported_interfaces())
+            .map(|interface| interface.fully_qualified_interface_name())
+            .collect::<BTreeSet<_>>();
+        for interface in model.imports.iter().filter(|interface| !interface.skipped) {
+            // Interfaces only defining types, or resources obtained through other functions, are
+            // not meant to be imported by the JS code
+            let has_entry_points = interface
+                .functions
+                .iter()
+                .any(|function| !matches!(function.kind, FunctionModelKind::Method { .. }));
+            if !has_entry_points {
+                continue;
+            }
+
+            let module = &interface.js_name;
+            if !is_used(module) && !used_by_helpers.contains(module) {
+                self.diagnostics.push(Diagnostic::UnusedImport {
+                    module: module.clone(),
+                });
+            }
+        }
+        if !model.imported_functions.is_empty() && !is_used(WORLD_IMPORTS_MODULE_NAME) {
+            self.diagnostics.push(Diagnostic::UnusedImport {
+                module: WORLD_IMPORTS_MODULE_NAME.to_string(),
+            });
+        }
+
+        Ok(())
+    }
+}
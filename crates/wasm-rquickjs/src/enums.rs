@@ -0,0 +1,63 @@
+use crate::GeneratorContext;
+use crate::validators::collect_validated_types;
+use anyhow::Context;
+use heck::ToUpperCamelCase;
+use wit_parser::{Enum, TypeDefKind};
+
+/// A WIT enum with a constant object in the `enums` module
+pub struct EnumConstants<'a> {
+    /// The name of the constant object, qualified like the names of the validators
+    pub name: String,
+    pub enum_type: &'a Enum,
+}
+
+impl EnumConstants<'_> {
+    /// The properties of the constant object, with the UpperCamelCase case names mapped to the
+    /// values representing the cases in JS
+    pub fn cases(&self) -> impl Iterator<Item = (String, &str)> {
+        self.enum_type
+            .cases
+            .iter()
+            .map(|case| (case.name.to_upper_camel_case(), case.name.as_str()))
+    }
+}
+
+/// Collects the enums defined in the world and in its imported and exported interfaces, except
+/// the skipped ones
+pub fn collect_enums<'a>(
+    context: &'a GeneratorContext<'_>,
+) -> anyhow::Result<Vec<EnumConstants<'a>>> {
+    let mut enums = Vec::new();
+    for typ in collect_validated_types(context)? {
+        if let TypeDefKind::Enum(enum_type) = &context.typ(typ.type_id)?.kind {
+            enums.push(EnumConstants {
+                name: typ.name,
+                enum_type,
+            });
+        }
+    }
+    Ok(enums)
+}
+
+/// Writes the `enums` JS module to `<output>/src/modules/enums.js`, exporting a frozen object for
+/// each enum, mapping its case names to the strings representing the cases
+pub fn generate_enums_module(context: &GeneratorContext<'_>) -> anyhow::Result<()> {
+    let mut source = String::new();
+    for constants in collect_enums(context)? {
+        let cases = constants
+            .cases()
+            .map(|(name, value)| format!("    {name}: '{value}',\n"))
+            .collect::<String>();
+        source.push_str(&format!(
+            "export const {} = Object.freeze({{\n{cases}}});\n",
+            constants.name
+        ));
+    }
+
+    context
+        .write_generated(
+            &context.output.join("src").join("modules").join("enums.js"),
+            source,
+        )
+        .context("Failed to write the enums module")
+}
@@ -1,17 +1,26 @@
-use crate::javascript::escape_js_ident;
+use crate::assets::IMPORT_RECORDING_FILE_NAME;
+use crate::handlers::handler_function_name;
+use crate::model::{FunctionModel, FunctionModelKind, InterfaceModel};
+use crate::pragmas::read_module_variants;
 use crate::rust_bindgen::RustWitFunction;
 use crate::types::{
     ProcessedParameter, ReturnTypeInformation, WrappedType, get_function_name, get_return_type,
     ident_in_exported_interface, ident_in_exported_interface_or_global, param_refs_as_tuple,
     process_parameter, to_original_func_arg_list, to_wrapped_param_refs, type_borrows_resource,
 };
-use crate::{EmbeddingMode, GeneratorContext, JsModuleSpec};
+use crate::validation::builtin_module_names;
+use crate::{
+    DateClock, EmbeddingMode, GeneratorContext, HttpCacheLimits, HttpLimits, ImportRecording,
+    JsModuleSpec, JsNamingPolicy, LoneSurrogatePolicy, MathRandomSeed, RecordTarget,
+};
 use anyhow::{Context, anyhow};
-use heck::{ToLowerCamelCase, ToUpperCamelCase};
+use heck::ToUpperCamelCase;
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::quote;
-use std::collections::BTreeMap;
+use serde_json::json;
+use std::collections::{BTreeMap, BTreeSet};
 use syn::{Lit, LitStr};
+use tracing::debug;
 use wit_parser::{Function, FunctionKind, Interface, TypeId, WorldItem, WorldKey};
 
 /// Generates the `<output>/src/lib.rs` file for the wrapper crate, implementing the component exports
@@ -21,12 +30,13 @@ pub fn generate_export_impls(
     js_modules: &[JsModuleSpec],
 ) -> anyhow::Result<()> {
     let guest_impls = generate_guest_impls(context)?;
-    let module_defs = generate_module_defs(js_modules)?;
+    let module_defs = generate_module_defs(context, js_modules)?;
 
     let lib_tokens = quote! {
         #[allow(static_mut_refs)]
         #[allow(unsafe_op_in_unsafe_fn)]
         mod bindings;
+        mod assets;
         mod builtin;
         mod conversions;
         #[allow(unused)]
@@ -50,7 +60,7 @@ pub fn generate_export_impls(
     let lib_path = context.output.join("src").join("lib.rs");
     let lib_src = prettier_please::unparse(&lib_ast);
 
-    std::fs::write(&lib_path, lib_src)?;
+    context.write_generated(&lib_path, lib_src)?;
 
     Ok(())
 }
@@ -66,8 +76,8 @@ fn generate_guest_impls(context: &GeneratorContext<'_>) -> anyhow::Result<Vec<To
     let mut interface_exports = Vec::new();
 
     // Enumerating all exports and separating them into global exports and interface exports.
-    for (name, export) in &world.exports {
-        let name = match name {
+    for (key, export) in &world.exports {
+        let name = match key {
             WorldKey::Name(name) => name.clone(),
             WorldKey::Interface(id) => {
                 let interface = &context.resolve.interfaces[*id];
@@ -80,27 +90,40 @@ fn generate_guest_impls(context: &GeneratorContext<'_>) -> anyhow::Result<Vec<To
         match export {
             WorldItem::Interface { id, .. } => {
                 let interface = &context.resolve.interfaces[*id];
-                interface_exports.push((name, interface));
+                let skipped = context.is_skipped_export(key);
+                debug!(interface = name, skipped, "Generating exported interface");
+                interface_exports.push((name, interface, skipped));
             }
             WorldItem::Function(function) => {
+                debug!(function = name, "Generating exported function");
                 global_exports.push((name, function));
             }
             WorldItem::Type(_) => {}
         }
     }
 
+    let mut extra_global_func_impls = Vec::new();
+    if context.options.invoke_export {
+        extra_global_func_impls.push(generate_invoke_export_impl(context)?);
+    }
+    if context.options.describe_export {
+        extra_global_func_impls.push(generate_describe_export_impl(context)?);
+    }
+
     // Implementing a single Guest trait containing all the global exported functions
-    if !global_exports.is_empty() {
+    if !global_exports.is_empty() || !extra_global_func_impls.is_empty() {
         result.extend(generate_guest_impl(
             context,
             quote! { crate::bindings::Guest },
             None,
             &global_exports,
+            extra_global_func_impls,
+            false,
         )?);
     }
 
     // Implementing a Guest trait per exported interface
-    for (name, interface) in interface_exports {
+    for (name, interface, skipped) in interface_exports {
         let interface_exports: Vec<_> = interface
             .functions
             .iter()
@@ -117,12 +140,137 @@ fn generate_guest_impls(context: &GeneratorContext<'_>) -> anyhow::Result<Vec<To
             ),
             Some((&name, interface)),
             &interface_exports,
+            Vec::new(),
+            skipped,
         )?);
     }
 
     Ok(result)
 }
 
+/// Generates the implementation of the `rquickjs-invoke` export, calling any of the exported
+/// freestanding functions by name with JSON-encoded arguments.
+///
+/// World-level functions are identified by their WIT name, interface functions by the exported
+/// interface's name and the function name separated by `#`, for example `quickjs:example2/exp1#hello`.
+fn generate_invoke_export_impl(context: &GeneratorContext<'_>) -> anyhow::Result<TokenStream> {
+    let model = context.model()?;
+
+    let mut targets = BTreeMap::new();
+    for function in &model.exported_functions {
+        if function.kind == FunctionModelKind::Freestanding {
+            targets.insert(function.wit_name.clone(), vec![function.js_name.clone()]);
+        }
+    }
+    for interface in model.exports.iter().filter(|interface| !interface.skipped) {
+        for function in &interface.functions {
+            if function.kind == FunctionModelKind::Freestanding {
+                let js_path = if interface.handler {
+                    vec![function.js_name.clone()]
+                } else {
+                    vec![interface.js_name.clone(), function.js_name.clone()]
+                };
+                targets.insert(
+                    format!("{}#{}", interface.wit_name, function.wit_name),
+                    js_path,
+                );
+            }
+        }
+    }
+
+    // The targets are sorted by name, so they can be found with a binary search instead of
+    // comparing the name to each of them
+    let targets = targets.iter().map(|(wit_name, js_path)| {
+        let export_index = context.export_index(js_path);
+        quote! { (#wit_name, #export_index, &[#(#js_path),*]) }
+    });
+
+    Ok(quote! {
+        fn rquickjs_invoke(name: String, args_json: String) -> Result<String, String> {
+            const TARGETS: &[(&str, usize, &[&str])] = &[#(#targets),*];
+
+            let Ok(target) = TARGETS.binary_search_by(|(target, _, _)| (*target).cmp(name.as_str())) else {
+                return Err(format!("Unknown exported function: {name}"));
+            };
+            let (_, export_index, function_path) = TARGETS[target];
+            crate::internal::try_async_exported_function(
+                crate::internal::call_js_export_with_json(export_index, function_path, args_json)
+            )?
+        }
+    })
+}
+
+/// Placeholder for the list of enabled cargo features in the generated component description,
+/// replaced at runtime by `describe_component` in the skeleton's `internal` module
+const FEATURES_PLACEHOLDER: &str = "__RQUICKJS_FEATURES__";
+
+/// Generates the implementation of the `rquickjs-describe` export, returning a JSON description of
+/// the world's exports and imports, built at generation time, completed with the cargo features
+/// the component was built with.
+fn generate_describe_export_impl(context: &GeneratorContext<'_>) -> anyhow::Result<TokenStream> {
+    let model = context.model()?;
+
+    let describe_function = |function: &FunctionModel| {
+        let (kind, resource) = match &function.kind {
+            FunctionModelKind::Freestanding => ("function", None),
+            FunctionModelKind::Constructor { resource } => ("constructor", Some(resource)),
+            FunctionModelKind::Method { resource } => ("method", Some(resource)),
+            FunctionModelKind::Static { resource } => ("static", Some(resource)),
+        };
+        let params = function
+            .params
+            .iter()
+            .map(|param| json!({ "name": param.wit_name, "type": param.ts_type }))
+            .collect::<Vec<_>>();
+        json!({
+            "name": function.wit_name,
+            "kind": kind,
+            "resource": resource,
+            "jsName": function.js_name,
+            "params": params,
+            "result": function.ts_result,
+        })
+    };
+    let describe_interface = |interface: &InterfaceModel| {
+        json!({
+            "name": interface.wit_name,
+            "jsName": interface.js_name,
+            "handler": interface.handler,
+            "skipped": interface.skipped,
+            "functions": interface.functions.iter().map(describe_function).collect::<Vec<_>>(),
+        })
+    };
+
+    let mut extensions = vec!["rquickjs-describe"];
+    if context.options.invoke_export {
+        extensions.push("rquickjs-invoke");
+    }
+
+    let description = json!({
+        "world": model.name,
+        "package": model.package,
+        "exports": {
+            "functions": model.exported_functions.iter().map(describe_function).collect::<Vec<_>>(),
+            "interfaces": model.exports.iter().map(describe_interface).collect::<Vec<_>>(),
+        },
+        "imports": {
+            "functions": model.imported_functions.iter().map(describe_function).collect::<Vec<_>>(),
+            "interfaces": model.imports.iter().map(describe_interface).collect::<Vec<_>>(),
+        },
+        "extensions": extensions,
+        "generatorVersion": env!("CARGO_PKG_VERSION"),
+        "features": FEATURES_PLACEHOLDER,
+    });
+    let description = serde_json::to_string(&description)
+        .context("Failed to encode the component description")?;
+
+    Ok(quote! {
+        fn rquickjs_describe() -> String {
+            crate::internal::describe_component(#description)
+        }
+    })
+}
+
 /// Generates the implementation of a `Guest` trait for the component, implementing the exported functions.
 ///
 /// The `guest_trait` parameter is a Rust snippet containing the fully-qualified path to the `Guest` trait to
@@ -130,13 +278,19 @@ fn generate_guest_impls(context: &GeneratorContext<'_>) -> anyhow::Result<Vec<To
 ///
 /// If there are resources in the interface, the return contains all the trait implementations, for the interface
 /// and the resources as well.
+///
+/// The `extra_func_impls` are added as-is to the `Guest` trait implementation.
+///
+/// If `skipped` is set, the interface is not implemented in JS, and all its functions trap.
 fn generate_guest_impl(
     context: &GeneratorContext<'_>,
     guest_trait: TokenStream,
     interface: Option<(&str, &Interface)>,
     exports: &[(String, &Function)],
+    extra_func_impls: Vec<TokenStream>,
+    skipped: bool,
 ) -> anyhow::Result<Vec<TokenStream>> {
-    let mut func_impls = Vec::new();
+    let mut func_impls = extra_func_impls;
     let mut resource_impls = Vec::new();
     let mut resource_functions = BTreeMap::new();
 
@@ -144,7 +298,7 @@ fn generate_guest_impl(
         match &function.kind {
             FunctionKind::Freestanding => {
                 let func_impl =
-                    generate_exported_function_impl(context, interface, name, function)?;
+                    generate_exported_function_impl(context, interface, name, function, skipped)?;
                 func_impls.push(func_impl);
             }
             FunctionKind::AsyncFreestanding
@@ -201,6 +355,7 @@ fn generate_guest_impl(
                 resource_type_id,
                 name,
                 resource_function,
+                skipped,
             )?;
             resource_func_impls.push(func_impl);
         }
@@ -292,6 +447,7 @@ fn generate_exported_function_impl(
     interface: Option<(&str, &Interface)>,
     name: &str,
     function: &Function,
+    skipped: bool,
 ) -> anyhow::Result<TokenStream> {
     let rust_fn = RustWitFunction::new(context, name, function);
     let func_name = rust_fn.function_name_ident();
@@ -320,16 +476,30 @@ fn generate_exported_function_impl(
     let param_refs = to_wrapped_param_refs(&param_ident_type);
     let param_refs_tuple = param_refs_as_tuple(&param_refs);
 
-    let js_func_name_str = Lit::Str(LitStr::new(
-        &escape_js_ident(name.to_lower_camel_case()),
-        func_name.span(),
-    ));
+    let naming = context.options.export_naming;
+    let js_func_name_str = Lit::Str(LitStr::new(&naming.function_name(name), func_name.span()));
+    // Functions of handler interfaces are implemented by top-level JS functions
+    let handler =
+        interface.and_then(|(_, iface)| handler_function_name(&context.resolve, iface, name));
+    let js_path = match handler {
+        Some(handler) => vec![naming.function_name(handler)],
+        None => interface
+            .map(|(iface_name, _)| naming.interface_name(iface_name))
+            .into_iter()
+            .chain([naming.function_name(name)])
+            .collect::<Vec<_>>(),
+    };
+    let export_index = context.export_index(&js_path);
     let (js_func_path, wit_package_lit) = match interface {
         Some((iface_name, iface)) => {
-            let if_name_str = LitStr::new(
-                &escape_js_ident(iface_name.to_lower_camel_case()),
-                func_name.span(),
-            );
+            let if_name_str = LitStr::new(&naming.interface_name(iface_name), func_name.span());
+            let js_func_path = match handler {
+                Some(handler) => {
+                    let handler_str = LitStr::new(&naming.function_name(handler), func_name.span());
+                    quote! { &[#handler_str] }
+                }
+                None => quote! { &[#if_name_str, #js_func_name_str] },
+            };
 
             let owner_package_name = match iface.package {
                 Some(package_id) => {
@@ -342,7 +512,7 @@ fn generate_exported_function_impl(
             };
 
             (
-                quote! { &[#if_name_str, #js_func_name_str] },
+                js_func_path,
                 Lit::Str(LitStr::new(&owner_package_name, Span::call_site())),
             )
         }
@@ -353,6 +523,16 @@ fn generate_exported_function_impl(
     };
 
     let original_result = &return_types.wit_level_ret.original_type_ref;
+    if skipped {
+        return Ok(generate_skipped_function_impl(
+            &func_name,
+            &func_arg_list,
+            original_result,
+            interface,
+            name,
+        ));
+    }
+
     let wrapped_result = &return_types.wit_level_ret.wrapped_type_ref;
     let unwrap = &return_types.wit_level_ret.unwrap;
     let unwrap_result = unwrap.run(quote! { result });
@@ -366,6 +546,7 @@ fn generate_exported_function_impl(
            crate::internal::async_exported_function(async move {
                let result: #wrapped_result = crate::internal::#call(
                    #wit_package_lit,
+                   #export_index,
                    #js_func_path,
                    #param_refs_tuple
                ).await;
@@ -383,6 +564,7 @@ fn generate_exported_resource_function_impl(
     resource_type_id: &TypeId,
     name: &str,
     function: &Function,
+    skipped: bool,
 ) -> anyhow::Result<TokenStream> {
     let func_name = get_function_name(name, function)?;
 
@@ -429,6 +611,20 @@ fn generate_exported_resource_function_impl(
         get_return_type(context, function, name, &rust_fn)?
     };
 
+    if skipped {
+        let original_result = match &function.kind {
+            FunctionKind::Method(_) => &return_types.func_ret.original_type_ref,
+            _ => &return_types.wit_level_ret.original_type_ref,
+        };
+        return Ok(generate_skipped_function_impl(
+            &func_name_ident,
+            &func_arg_list,
+            original_result,
+            interface,
+            name,
+        ));
+    }
+
     let param_refs = to_wrapped_param_refs(&param_ident_type);
 
     let resource_name = context
@@ -440,16 +636,14 @@ fn generate_exported_resource_function_impl(
         .as_ref()
         .ok_or_else(|| anyhow::anyhow!("Resource type has no name"))?;
 
+    let naming = context.options.export_naming;
     let js_resource_name_str = Lit::Str(LitStr::new(
-        &resource_name.to_upper_camel_case(),
+        &naming.class_name(resource_name),
         Span::call_site(),
     ));
     let (js_resource_path, wit_package_lit) = match interface {
         Some((iface_name, iface)) => {
-            let if_name_str = LitStr::new(
-                &escape_js_ident(iface_name.to_lower_camel_case()),
-                Span::call_site(),
-            );
+            let if_name_str = LitStr::new(&naming.interface_name(iface_name), Span::call_site());
 
             let owner_package_name = match iface.package {
                 Some(package_id) => {
@@ -473,19 +667,24 @@ fn generate_exported_resource_function_impl(
     };
 
     let js_func_name_str = Lit::Str(LitStr::new(
-        &escape_js_ident(func_name.to_lower_camel_case()),
+        &naming.function_name(&func_name),
         Span::call_site(),
     ));
     let js_static_func_path = match interface {
         Some((iface_name, _)) => {
-            let if_name_str = LitStr::new(
-                &escape_js_ident(iface_name.to_lower_camel_case()),
-                Span::call_site(),
-            );
+            let if_name_str = LitStr::new(&naming.interface_name(iface_name), Span::call_site());
             quote! { &[#if_name_str, #js_resource_name_str, #js_func_name_str] }
         }
         None => quote! { &[#js_func_name_str] },
     };
+    let static_export_index = context.export_index(&match interface {
+        Some((iface_name, _)) => vec![
+            naming.interface_name(iface_name),
+            naming.class_name(resource_name),
+            naming.function_name(&func_name),
+        ],
+        None => vec![naming.function_name(&func_name)],
+    });
 
     let func_impl = match &function.kind {
         FunctionKind::Constructor(_) => {
@@ -549,6 +748,7 @@ fn generate_exported_resource_function_impl(
                    crate::internal::async_exported_function(async move {
                        let result: #wrapped_result = crate::internal::#call(
                            #wit_package_lit,
+                           #static_export_index,
                            #js_static_func_path,
                            #param_refs_tuple,
                        ).await;
@@ -568,19 +768,47 @@ fn generate_exported_resource_function_impl(
     Ok(func_impl)
 }
 
-fn generate_module_defs(js_modules: &[JsModuleSpec]) -> anyhow::Result<TokenStream> {
-    if let Some((export_module, additional_modules)) = js_modules.split_first() {
+/// Generates a trait method implementation for a function of a skipped exported interface,
+/// which traps when called
+fn generate_skipped_function_impl(
+    func_name: &Ident,
+    func_arg_list: &[TokenStream],
+    original_result: &TokenStream,
+    interface: Option<(&str, &Interface)>,
+    name: &str,
+) -> TokenStream {
+    let message = LitStr::new(
+        &format!(
+            "Exported function {name} of interface {} is not implemented by this component",
+            interface.map_or("<world>", |(iface_name, _)| iface_name)
+        ),
+        Span::call_site(),
+    );
+    quote! {
+        #[allow(unused_variables)]
+        fn #func_name(#(#func_arg_list),*) -> #original_result {
+            panic!(#message)
+        }
+    }
+}
+
+fn generate_module_defs(
+    context: &GeneratorContext<'_>,
+    js_modules: &[JsModuleSpec],
+) -> anyhow::Result<TokenStream> {
+    if let Some(export_module) = js_modules.iter().find(|module| module.entry) {
+        let additional_modules = js_modules.iter().filter(|module| !module.entry);
         let export_module_name = LitStr::new(&export_module.name, Span::call_site());
-        let export_module_file_name = LitStr::new(&export_module.file_name(), Span::call_site());
+        let export_module_source = embedded_module_source(export_module)?;
 
         let mut additional_module_pairs = Vec::new();
         for module in additional_modules {
             match module.mode {
                 EmbeddingMode::EmbedFile(_) => {
                     let name = LitStr::new(&module.name, Span::call_site());
-                    let file_name = LitStr::new(&module.file_name(), Span::call_site());
+                    let source = embedded_module_source(module)?;
                     additional_module_pairs
-                        .push(quote! { (#name, Box::new(|| { include_str!(#file_name) })) });
+                        .push(quote! { (#name, Box::new(|| { #source.to_string() })) });
                 }
                 EmbeddingMode::Composition => {
                     let name = LitStr::new(&module.name, Span::call_site());
@@ -590,16 +818,205 @@ fn generate_module_defs(js_modules: &[JsModuleSpec]) -> anyhow::Result<TokenStre
             }
         }
 
+        let module_order = js_modules
+            .iter()
+            .map(|module| LitStr::new(&module.name, Span::call_site()));
+
+        let builtin_permissions =
+            context
+                .options
+                .builtin_permissions
+                .iter()
+                .map(|(module, builtins)| {
+                    let module = LitStr::new(module, Span::call_site());
+                    let builtins = builtins
+                        .iter()
+                        .flat_map(|builtin| builtin_module_names(builtin))
+                        .collect::<BTreeSet<_>>()
+                        .into_iter()
+                        .map(|builtin| LitStr::new(&builtin, Span::call_site()));
+                    quote! { (#module, &[#(#builtins),*]) }
+                });
+
+        let verbatim_export_names = context.options.export_naming == JsNamingPolicy::Verbatim;
+        let intern_field_names = context.options.intern_field_names;
+        let replace_lone_surrogates =
+            context.options.lone_surrogates == LoneSurrogatePolicy::Replace;
+        let date_clock = match context.options.date_clock {
+            None => quote! {
+                static DATE_CLOCK: Option<fn() -> f64> = None;
+            },
+            Some(DateClock::Fixed(millis)) => {
+                let millis = millis as f64;
+                quote! {
+                    static DATE_CLOCK: Option<fn() -> f64> = Some(date_clock_now);
+
+                    fn date_clock_now() -> f64 {
+                        #millis
+                    }
+                }
+            }
+            Some(DateClock::Host) => quote! {
+                static DATE_CLOCK: Option<fn() -> f64> = Some(date_clock_now);
+
+                fn date_clock_now() -> f64 {
+                    crate::bindings::rquickjs_clock_now() as f64
+                }
+            },
+        };
+        let import_hooks = context.options.import_hooks;
+        let cancel_background_work = context.options.cancel_background_work;
+        let http_limits = http_limits_expr(&context.options.http_limits);
+        let cookie_jar = context.options.cookie_jar;
+        let http_cache = context.options.http_cache;
+        let http_cache_limits = http_cache_limits_expr(&context.options.http_cache_limits);
+        let fetch_base_url = optional_str_expr(context.options.fetch_base_url.as_deref());
+        let fetch_gateway = optional_str_expr(context.options.fetch_gateway.as_deref());
+        let mock_imports = if context.options.mock_imports {
+            quote! { cfg!(feature = "mock-imports") }
+        } else {
+            quote! { false }
+        };
+        let import_recording = match &context.options.import_recording {
+            None => quote! { crate::builtin::ImportRecording::None },
+            Some(ImportRecording::Record(RecordTarget::Stderr)) => {
+                quote! { crate::builtin::ImportRecording::Record(None) }
+            }
+            Some(ImportRecording::Record(RecordTarget::File(path))) => {
+                quote! { crate::builtin::ImportRecording::Record(Some(#path)) }
+            }
+            Some(ImportRecording::Replay(_)) => {
+                let file_name = LitStr::new(IMPORT_RECORDING_FILE_NAME, Span::call_site());
+                quote! { crate::builtin::ImportRecording::Replay(include_str!(#file_name)) }
+            }
+        };
+        let math_random_seed = match context.options.math_random_seed {
+            None => quote! { crate::builtin::MathRandomSeed::Default },
+            Some(MathRandomSeed::Fixed(seed)) => {
+                quote! { crate::builtin::MathRandomSeed::Fixed(#seed) }
+            }
+            Some(MathRandomSeed::Host) => quote! { crate::builtin::MathRandomSeed::Host },
+        };
+
         Ok(quote! {
             static JS_EXPORT_MODULE_NAME: &str = #export_module_name;
-            static JS_EXPORT_MODULE: &str = include_str!(#export_module_file_name);
+            static JS_EXPORT_NAMES_VERBATIM: bool = #verbatim_export_names;
+            static INTERN_FIELD_NAMES: bool = #intern_field_names;
+            #[allow(dead_code)]
+            static REPLACE_LONE_SURROGATES: bool = #replace_lone_surrogates;
+            static MATH_RANDOM_SEED: crate::builtin::MathRandomSeed = #math_random_seed;
+            #date_clock
+            static IMPORT_HOOKS: bool = #import_hooks;
+            static MOCK_IMPORTS: bool = #mock_imports;
+            static IMPORT_RECORDING: crate::builtin::ImportRecording = #import_recording;
+            static CANCEL_BACKGROUND_WORK: bool = #cancel_background_work;
+            static HTTP_LIMITS: crate::builtin::HttpLimits = #http_limits;
+            #[allow(dead_code)]
+            static COOKIE_JAR: bool = #cookie_jar;
+            #[allow(dead_code)]
+            static HTTP_CACHE: bool = #http_cache;
+            #[allow(dead_code)]
+            static HTTP_CACHE_LIMITS: crate::builtin::HttpCacheLimits = #http_cache_limits;
+            #[allow(dead_code)]
+            static FETCH_BASE_URL: Option<&str> = #fetch_base_url;
+            #[allow(dead_code)]
+            static FETCH_GATEWAY: Option<&str> = #fetch_gateway;
+            static JS_EXPORT_MODULE: &str = #export_module_source;
 
             static JS_ADDITIONAL_MODULES: std::sync::LazyLock<Vec<(&str, Box<dyn (Fn() -> String) + Send + Sync>)>> =
               std::sync::LazyLock::new(|| { vec![
                  #(#additional_module_pairs),*
               ]});
+
+            static JS_MODULE_ORDER: &[&str] = &[#(#module_order),*];
+
+            static JS_BUILTIN_PERMISSIONS: &[(&str, &[&str])] = &[#(#builtin_permissions),*];
         })
     } else {
-        Err(anyhow!("No JS modules provided."))?
+        Err(anyhow!("No entry JS module provided."))?
     }
 }
+
+/// Generates the expression of the `HttpLimits` of the skeleton with the configured limits
+fn http_limits_expr(limits: &HttpLimits) -> TokenStream {
+    if *limits == HttpLimits::default() {
+        return quote! { crate::builtin::HttpLimits::UNLIMITED };
+    }
+    let limit = |limit: Option<usize>| match limit {
+        Some(limit) => quote! { Some(#limit) },
+        None => quote! { None },
+    };
+    let max_request_body_size = limit(limits.max_request_body_size);
+    let max_response_body_size = limit(limits.max_response_body_size);
+    let max_header_count = limit(limits.max_header_count);
+    quote! {
+        crate::builtin::HttpLimits {
+            max_request_body_size: #max_request_body_size,
+            max_response_body_size: #max_response_body_size,
+            max_header_count: #max_header_count,
+        }
+    }
+}
+
+/// Generates the expression of the `HttpCacheLimits` of the skeleton with the configured limits
+fn http_cache_limits_expr(limits: &HttpCacheLimits) -> TokenStream {
+    let limit = |limit: Option<usize>| match limit {
+        Some(limit) => quote! { Some(#limit) },
+        None => quote! { None },
+    };
+    let max_entries = limit(limits.max_entries);
+    let max_size = limit(limits.max_size);
+    let max_body_size = limit(limits.max_body_size);
+    quote! {
+        crate::builtin::HttpCacheLimits {
+            max_entries: #max_entries,
+            max_size: #max_size,
+            max_body_size: #max_body_size,
+        }
+    }
+}
+
+/// Generates an `Option<&str>` expression
+fn optional_str_expr(value: Option<&str>) -> TokenStream {
+    match value {
+        Some(value) => quote! { Some(#value) },
+        None => quote! { None },
+    }
+}
+
+/// Generates the expression including the source of an embedded JS module. For modules with
+/// feature pragmas, the variant matching the enabled cargo features is selected.
+fn embedded_module_source(module: &JsModuleSpec) -> anyhow::Result<TokenStream> {
+    let EmbeddingMode::EmbedFile(path) = &module.mode else {
+        let file_name = LitStr::new(&module.file_name(), Span::call_site());
+        return Ok(quote! { include_str!(#file_name) });
+    };
+    let variants = read_module_variants(module, path)?;
+    if let [variant] = variants.as_slice()
+        && variant.features.is_empty()
+    {
+        let file_name = LitStr::new(&variant.file_name, Span::call_site());
+        return Ok(quote! { include_str!(#file_name) });
+    }
+
+    let variants = variants.iter().map(|variant| {
+        let file_name = LitStr::new(&variant.file_name, Span::call_site());
+        let conditions = variant.features.iter().map(|(feature, enabled)| {
+            if *enabled {
+                quote! { feature = #feature }
+            } else {
+                quote! { not(feature = #feature) }
+            }
+        });
+        quote! {
+            #[cfg(all(#(#conditions),*))]
+            let source = include_str!(#file_name);
+        }
+    });
+    Ok(quote! {
+        {
+            #(#variants)*
+            source
+        }
+    })
+}
@@ -0,0 +1,31 @@
+/**
+ * Helpers for controlling Golem's durability guarantees, built on top of the Golem host API.
+ */
+declare module 'golem' {
+  import type { PersistenceLevel, RetryPolicy, Uuid } from '__GOLEM_HOST_MODULE__';
+  /**
+   * Runs `f` as an atomic region: if the worker fails while running it, the whole region is retried.
+   * If `f` returns a promise, the region ends when it settles.
+   */
+  export function atomically<T>(f: () => T): T;
+  /**
+   * Runs `f` with the given idempotence mode, restoring the previous one afterwards.
+   */
+  export function withIdempotenceMode<T>(idempotent: boolean, f: () => T): T;
+  /**
+   * Runs `f` with the given retry policy, restoring the previous one afterwards.
+   */
+  export function withRetryPolicy<T>(policy: RetryPolicy, f: () => T): T;
+  /**
+   * Runs `f` with the given persistence level, restoring the previous one afterwards.
+   */
+  export function withPersistenceLevel<T>(level: PersistenceLevel | PersistenceLevel['tag'], f: () => T): T;
+  /**
+   * Generates an idempotency key which is stable across retries of the current invocation.
+   */
+  export function generateIdempotencyKey(): Uuid;
+  /**
+   * Waits until the oplog is committed to at least `replicas` replicas.
+   */
+  export function oplogCommit(replicas: number): void;
+}
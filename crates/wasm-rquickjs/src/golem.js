@@ -0,0 +1,48 @@
+import * as host from '__GOLEM_HOST_MODULE__';
+
+// Runs `f`, and runs `cleanup` when it finished - if `f` returns a promise, after it settled
+function withCleanup(f, cleanup) {
+    let result;
+    try {
+        result = f();
+    } catch (error) {
+        cleanup();
+        throw error;
+    }
+    if (result instanceof Promise) {
+        return result.finally(cleanup);
+    }
+    cleanup();
+    return result;
+}
+
+export function atomically(f) {
+    const begin = host.markBeginOperation();
+    return withCleanup(f, () => host.markEndOperation(begin));
+}
+
+export function withIdempotenceMode(idempotent, f) {
+    const original = host.getIdempotenceMode();
+    host.setIdempotenceMode(idempotent);
+    return withCleanup(f, () => host.setIdempotenceMode(original));
+}
+
+export function withRetryPolicy(policy, f) {
+    const original = host.getRetryPolicy();
+    host.setRetryPolicy(policy);
+    return withCleanup(f, () => host.setRetryPolicy(original));
+}
+
+export function withPersistenceLevel(level, f) {
+    const original = host.getOplogPersistenceLevel();
+    host.setOplogPersistenceLevel(typeof level === 'string' ? { tag: level } : level);
+    return withCleanup(f, () => host.setOplogPersistenceLevel(original));
+}
+
+export function generateIdempotencyKey() {
+    return host.generateIdempotencyKey();
+}
+
+export function oplogCommit(replicas) {
+    host.oplogCommit(replicas);
+}
@@ -0,0 +1,54 @@
+//! Well-known exported interfaces of event-driven worlds, whose functions are implemented by
+//! top-level JS handler functions (such as `onMessage`) instead of the functions of an exported
+//! interface object.
+
+use wit_parser::{Interface, Resolve};
+
+/// A function of a well-known exported interface implemented by a top-level JS function
+struct HandlerFunction {
+    /// Namespace and name of the WIT package of the interface, of any version
+    package: (&'static str, &'static str),
+    interface: &'static str,
+    function: &'static str,
+    /// The WIT-style name of the JS function, converted by the export naming policy like the
+    /// names of exported functions
+    handler: &'static str,
+}
+
+const HANDLER_FUNCTIONS: &[HandlerFunction] = &[HandlerFunction {
+    package: ("wasi", "messaging"),
+    interface: "incoming-handler",
+    function: "handle",
+    handler: "on-message",
+}];
+
+/// Checks whether the functions of an exported interface are implemented by top-level JS
+/// functions
+pub fn is_handler_interface(resolve: &Resolve, interface: &Interface) -> bool {
+    HANDLER_FUNCTIONS
+        .iter()
+        .any(|handler| matches_interface(resolve, interface, handler))
+}
+
+/// Gets the WIT-style name of the top-level JS function implementing a function of an exported
+/// handler interface
+pub fn handler_function_name(
+    resolve: &Resolve,
+    interface: &Interface,
+    function: &str,
+) -> Option<&'static str> {
+    HANDLER_FUNCTIONS
+        .iter()
+        .find(|handler| {
+            handler.function == function && matches_interface(resolve, interface, handler)
+        })
+        .map(|handler| handler.handler)
+}
+
+fn matches_interface(resolve: &Resolve, interface: &Interface, handler: &HandlerFunction) -> bool {
+    interface.name.as_deref() == Some(handler.interface)
+        && interface.package.is_some_and(|package_id| {
+            let name = &resolve.packages[package_id].name;
+            (name.namespace.as_str(), name.name.as_str()) == handler.package
+        })
+}
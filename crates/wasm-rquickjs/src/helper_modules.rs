@@ -0,0 +1,239 @@
+//! JS modules with idiomatic APIs built on top of the generated modules of well-known imported
+//! interfaces, available when the world imports these interfaces.
+
+use crate::ImportedInterface;
+
+/// A JS module built on top of the generated modules of some imported interfaces
+#[derive(Debug)]
+pub struct HelperModule {
+    /// Name of the JS module, also used for the names of its generated files
+    pub name: &'static str,
+    /// Namespace and name of the WIT package of the interfaces, of any version
+    package: (&'static str, &'static str),
+    interfaces: &'static [HelperInterface],
+    source: &'static str,
+    dts: &'static str,
+}
+
+/// An imported interface a helper module is built on
+#[derive(Debug)]
+struct HelperInterface {
+    name: &'static str,
+    /// Placeholder for the versioned name of the interface's module in the helper module's
+    /// sources and type declarations
+    placeholder: &'static str,
+    /// The helper module is only available if the world imports all its required interfaces, and
+    /// at least one of its interfaces. The placeholders of the optional interfaces not imported
+    /// are replaced by an empty string.
+    required: bool,
+}
+
+/// Name of the JS module exposing durability helpers built on top of the Golem host API,
+/// available when the world imports `golem:api/host`.
+pub const GOLEM_MODULE_NAME: &str = "golem";
+
+const HELPER_MODULES: &[HelperModule] = &[
+    HelperModule {
+        name: GOLEM_MODULE_NAME,
+        package: ("golem", "api"),
+        interfaces: &[HelperInterface {
+            name: "host",
+            placeholder: "__GOLEM_HOST_MODULE__",
+            required: true,
+        }],
+        source: include_str!("golem.js"),
+        dts: include_str!("golem.d.ts"),
+    },
+    HelperModule {
+        name: "keyvalue",
+        package: ("wasi", "keyvalue"),
+        interfaces: &[
+            HelperInterface {
+                name: "eventual",
+                placeholder: "__WASI_KEYVALUE_EVENTUAL_MODULE__",
+                required: true,
+            },
+            HelperInterface {
+                name: "types",
+                placeholder: "__WASI_KEYVALUE_TYPES_MODULE__",
+                required: true,
+            },
+            HelperInterface {
+                name: "eventual-batch",
+                placeholder: "__WASI_KEYVALUE_EVENTUAL_BATCH_MODULE__",
+                required: false,
+            },
+        ],
+        source: include_str!("keyvalue.js"),
+        dts: include_str!("keyvalue.d.ts"),
+    },
+    HelperModule {
+        name: "blobstore",
+        package: ("wasi", "blobstore"),
+        interfaces: &[
+            HelperInterface {
+                name: "blobstore",
+                placeholder: "__WASI_BLOBSTORE_BLOBSTORE_MODULE__",
+                required: true,
+            },
+            HelperInterface {
+                name: "types",
+                placeholder: "__WASI_BLOBSTORE_TYPES_MODULE__",
+                required: true,
+            },
+        ],
+        source: include_str!("blobstore.js"),
+        dts: include_str!("blobstore.d.ts"),
+    },
+    HelperModule {
+        name: "messaging",
+        package: ("wasi", "messaging"),
+        interfaces: &[
+            HelperInterface {
+                name: "producer",
+                placeholder: "__WASI_MESSAGING_PRODUCER_MODULE__",
+                required: true,
+            },
+            HelperInterface {
+                name: "types",
+                placeholder: "__WASI_MESSAGING_TYPES_MODULE__",
+                required: true,
+            },
+        ],
+        source: include_str!("messaging.js"),
+        dts: include_str!("messaging.d.ts"),
+    },
+    HelperModule {
+        name: "incoming-http",
+        package: ("wasi", "http"),
+        interfaces: &[HelperInterface {
+            name: "types",
+            placeholder: "__WASI_HTTP_TYPES_MODULE__",
+            required: true,
+        }],
+        source: include_str!("incoming-http.js"),
+        dts: include_str!("incoming-http.d.ts"),
+    },
+    HelperModule {
+        name: "db",
+        package: ("golem", "rdbms"),
+        interfaces: &[
+            HelperInterface {
+                name: "postgres",
+                placeholder: "__GOLEM_RDBMS_POSTGRES_MODULE__",
+                required: false,
+            },
+            HelperInterface {
+                name: "mysql",
+                placeholder: "__GOLEM_RDBMS_MYSQL_MODULE__",
+                required: false,
+            },
+        ],
+        source: include_str!("db.js"),
+        dts: include_str!("db.d.ts"),
+    },
+    HelperModule {
+        name: "llm",
+        package: ("golem", "llm"),
+        interfaces: &[HelperInterface {
+            name: "llm",
+            placeholder: "__GOLEM_LLM_MODULE__",
+            required: true,
+        }],
+        source: include_str!("llm.js"),
+        dts: include_str!("llm.d.ts"),
+    },
+];
+
+/// A helper module available for the world, with the imported interfaces it is built on
+pub struct EnabledHelperModule<'a, 'b> {
+    pub module: &'static HelperModule,
+    interfaces: Vec<(&'static HelperInterface, Option<&'b ImportedInterface<'a>>)>,
+}
+
+impl<'a, 'b> EnabledHelperModule<'a, 'b> {
+    /// The imported interfaces the module is built on
+    pub fn imported_interfaces(&self) -> impl Iterator<Item = &'b ImportedInterface<'a>> + '_ {
+        self.interfaces
+            .iter()
+            .filter_map(|(_, interface)| *interface)
+    }
+
+    /// The JS source of the module, importing the modules of the interfaces by their fully
+    /// qualified names
+    pub fn source(&self) -> anyhow::Result<String> {
+        self.replace_placeholders(self.module.source, |interface| {
+            Ok(interface.fully_qualified_interface_name())
+        })
+    }
+
+    /// The type declarations of the module, referring to the declarations of the interfaces by
+    /// the module specifiers returned by `specifier`
+    pub fn dts(
+        &self,
+        specifier: impl Fn(&ImportedInterface<'a>) -> anyhow::Result<String>,
+    ) -> anyhow::Result<String> {
+        self.replace_placeholders(self.module.dts, specifier)
+    }
+
+    fn replace_placeholders(
+        &self,
+        template: &str,
+        replacement: impl Fn(&ImportedInterface<'a>) -> anyhow::Result<String>,
+    ) -> anyhow::Result<String> {
+        let mut result = template.to_string();
+        for (helper_interface, interface) in &self.interfaces {
+            if result.contains(helper_interface.placeholder) {
+                let value = match interface {
+                    Some(interface) => replacement(interface)?,
+                    None => String::new(),
+                };
+                result = result.replace(helper_interface.placeholder, &value);
+            }
+        }
+        Ok(result)
+    }
+}
+
+/// Selects the helper modules whose required interfaces are all imported by the world
+pub fn enabled_helper_modules<'a, 'b>(
+    interfaces: &'b [ImportedInterface<'a>],
+) -> Vec<EnabledHelperModule<'a, 'b>> {
+    HELPER_MODULES
+        .iter()
+        .filter_map(|module| {
+            let found = module
+                .interfaces
+                .iter()
+                .map(|helper_interface| {
+                    (
+                        helper_interface,
+                        find_interface(interfaces, module.package, helper_interface.name),
+                    )
+                })
+                .collect::<Vec<_>>();
+            let enabled = found.iter().all(|(helper_interface, interface)| {
+                !helper_interface.required || interface.is_some()
+            }) && found.iter().any(|(_, interface)| interface.is_some());
+            enabled.then_some(EnabledHelperModule {
+                module,
+                interfaces: found,
+            })
+        })
+        .collect()
+}
+
+/// Finds an imported interface of a package (of any version)
+fn find_interface<'a, 'b>(
+    interfaces: &'b [ImportedInterface<'a>],
+    (namespace, package): (&str, &str),
+    name: &str,
+) -> Option<&'b ImportedInterface<'a>> {
+    interfaces.iter().find(|interface| {
+        interface.world_name.is_none()
+            && interface.name == name
+            && interface.package_name.is_some_and(|package_name| {
+                package_name.namespace == namespace && package_name.name == package
+            })
+    })
+}
@@ -0,0 +1,12 @@
+/**
+ * Intercepts a call of an imported interface. `iface` is the module name the interface is imported
+ * by, and `fn` the JS name of the called function: `Class.method` for the methods and static
+ * functions of resources, and `new Class` for their constructors. Calling `next` continues with
+ * the next hook and finally the host, with the original arguments or the given ones.
+ */
+type ImportHook = (iface: string, fn: string, args: unknown[], next: (args?: unknown[]) => unknown) => unknown;
+/**
+ * Registers a hook around all the calls of imported interfaces. The hooks registered first are
+ * the outermost ones. Returns a function unregistering the hook.
+ */
+declare function registerImportHook(hook: ImportHook): () => void;
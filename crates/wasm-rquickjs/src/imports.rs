@@ -1,18 +1,34 @@
+use crate::enums::generate_enums_module;
+use crate::helper_modules::enabled_helper_modules;
 use crate::javascript::escape_js_ident;
 use crate::rust_bindgen::RustWitFunction;
 use crate::types::{
     get_function_name, get_return_type, ident_in_imported_interface_or_global, process_parameter,
     to_unwrapped_param_refs, to_wrapped_func_arg_list,
 };
-use crate::{GeneratorContext, ImportedInterface};
+use crate::validators::generate_validators_module;
+use crate::{GenerationStage, GeneratorContext, ImportedInterface, report_progress};
 use anyhow::{Context, anyhow};
 use heck::{ToLowerCamelCase, ToSnakeCase, ToUpperCamelCase};
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::quote;
 use std::collections::BTreeMap;
 use syn::LitStr;
+use tracing::{debug, trace};
 use wit_parser::{FunctionKind, TypeDefKind, WorldItem, WorldKey};
 
+/// Name of the JS module exposing the functions imported directly into the world
+/// (not as part of an interface).
+pub const WORLD_IMPORTS_MODULE_NAME: &str = "world-imports";
+
+/// Name of the JS module with the generated validators of the world's types, available when the
+/// `validators` option is enabled.
+pub const VALIDATORS_MODULE_NAME: &str = "validators";
+
+/// Name of the JS module with the constant objects of the world's enums, available when the
+/// `enum_constants` option is enabled.
+pub const ENUMS_MODULE_NAME: &str = "enums";
+
 /// Generates the `mod.rs` and one file per imported interface in the `<output>/src/modules`
 /// directory.
 /// Each Rust module contains a rquicks `NativeModule` exposing the WIT bindings for the
@@ -20,9 +36,20 @@ use wit_parser::{FunctionKind, TypeDefKind, WorldItem, WorldKey};
 pub fn generate_import_modules(context: &GeneratorContext<'_>) -> anyhow::Result<()> {
     let (global, interfaces) = collect_imported_interfaces(context)?;
 
-    for interface in &interfaces {
+    report_progress(
+        context.options,
+        GenerationStage::Imports,
+        0,
+        interfaces.len(),
+    );
+    for (index, interface) in interfaces.iter().enumerate() {
         let module_name = interface.module_name()?;
         let file_name = format!("{module_name}.rs");
+        debug!(
+            interface = interface.fully_qualified_interface_name(),
+            module = module_name,
+            "Generating import module"
+        );
 
         let module_path = context.output.join("src").join("modules").join(&file_name);
         let module_tokens = generate_import_module(context, interface, &interfaces)?;
@@ -32,7 +59,35 @@ pub fn generate_import_modules(context: &GeneratorContext<'_>) -> anyhow::Result
 
         let module_src = prettier_please::unparse(&module_ast);
 
-        std::fs::write(&module_path, module_src)?;
+        context.write_generated(&module_path, module_src)?;
+        report_progress(
+            context.options,
+            GenerationStage::Imports,
+            index + 1,
+            interfaces.len(),
+        );
+    }
+
+    for helper in enabled_helper_modules(&interfaces) {
+        let name = helper.module.name;
+        context
+            .write_generated(
+                &context
+                    .output
+                    .join("src")
+                    .join("modules")
+                    .join(format!("{name}.js")),
+                helper.source()?,
+            )
+            .with_context(|| format!("Failed to write the {name} module"))?;
+    }
+
+    if context.options.validators {
+        generate_validators_module(context)?;
+    }
+
+    if context.options.enum_constants {
+        generate_enums_module(context)?;
     }
 
     let global_module_path = context.output.join("src").join("modules").join("mod.rs");
@@ -41,11 +96,28 @@ pub fn generate_import_modules(context: &GeneratorContext<'_>) -> anyhow::Result
     let global_module_ast: syn::File =
         syn::parse2(global_module_tokens).context("failed to parse generated mod.rs tokens")?;
     let global_module_src = prettier_please::unparse(&global_module_ast);
-    std::fs::write(&global_module_path, global_module_src)?;
+    context.write_generated(&global_module_path, global_module_src)?;
 
     Ok(())
 }
 
+/// Collects the imported interfaces denied by the `deny_imports` option, which get a module
+/// throwing an error when imported instead of their generated modules
+fn collect_denied_interfaces<'a>(
+    context: &'a GeneratorContext<'a>,
+) -> anyhow::Result<Vec<ImportedInterface<'a>>> {
+    let world = &context.resolve.worlds[context.world];
+    let mut interfaces = Vec::new();
+    for (key, import) in &world.imports {
+        if let WorldItem::Interface { id, .. } = import
+            && context.is_denied_import(key)
+        {
+            interfaces.push(context.get_imported_interface(id)?);
+        }
+    }
+    Ok(interfaces)
+}
+
 pub fn collect_imported_interfaces<'a>(
     context: &'a GeneratorContext<'a>,
 ) -> anyhow::Result<(ImportedInterface<'a>, Vec<ImportedInterface<'a>>)> {
@@ -54,21 +126,18 @@ pub fn collect_imported_interfaces<'a>(
     let mut global_imports = Vec::new();
     let mut interfaces = Vec::new();
 
-    for (name, import) in &world.imports {
-        let name = match name {
+    for (key, import) in &world.imports {
+        let name = match key {
             WorldKey::Name(name) => name.as_str(),
-            WorldKey::Interface(id) => {
-                let interface = &context.resolve.interfaces[*id];
-                interface
-                    .name
-                    .as_ref()
-                    .ok_or_else(|| anyhow!("Interface import does not have a name"))?
-                    .as_str()
-            }
+            WorldKey::Interface(id) => context.interface_name(*id)?,
         };
         match import {
             WorldItem::Interface { id, .. } => {
-                interfaces.push(context.get_imported_interface(id)?);
+                if context.is_skipped_import(key) {
+                    trace!(interface = name, "Skipping imported interface");
+                } else {
+                    interfaces.push(context.get_imported_interface(id)?);
+                }
             }
             WorldItem::Function(function) => {
                 global_imports.push((name, function));
@@ -79,6 +148,8 @@ pub fn collect_imported_interfaces<'a>(
 
     let global = ImportedInterface {
         package_name: None,
+        include_version: false,
+        world_name: None,
         name: context.world_name.to_upper_camel_case(),
         functions: global_imports,
         interface: None,
@@ -96,8 +167,8 @@ fn generate_import_module(
     let mut submodules = Vec::new();
     let mut loader_init = quote! {};
     if import.interface.is_none() {
-        let mut resolver_chain = Vec::new();
-        let mut loader_chain = Vec::new();
+        let mut module_names = Vec::new();
+        let mut load_cases = Vec::new();
 
         // This is the global module
         for interface in all_imported_interfaces {
@@ -110,35 +181,114 @@ fn generate_import_module(
             let fully_qualified_interface_lit =
                 LitStr::new(&fully_qualified_interface, Span::call_site());
 
-            resolver_chain.push(quote! { with_module(#fully_qualified_interface_lit)});
-            loader_chain.push(quote! { with_module(#fully_qualified_interface_lit, crate::modules::#module_ident::#rust_module_struct_ident) });
+            module_names.push(fully_qualified_interface_lit.clone());
+            load_cases.push(quote! {
+                #fully_qualified_interface_lit => rquickjs::Module::declare_def::<crate::modules::#module_ident::#rust_module_struct_ident, _>(ctx.clone(), name)
+            });
         }
 
-        if all_imported_interfaces.is_empty() {
-            loader_init = quote! {
-                pub fn add_native_module_resolvers(resolver: rquickjs::loader::BuiltinResolver) -> rquickjs::loader::BuiltinResolver {
-                    resolver
-                }
+        // Functions imported directly into the world are exposed by the global module itself
+        if !import.functions.is_empty() {
+            let rust_module_struct_ident = import.rust_interface_name();
+            let world_imports_lit = LitStr::new(WORLD_IMPORTS_MODULE_NAME, Span::call_site());
 
-                pub fn module_loader() -> rquickjs::loader::ModuleLoader {
-                  rquickjs::loader::ModuleLoader::default()
-                }
-            };
-        } else {
-            loader_init = quote! {
-                pub fn add_native_module_resolvers(resolver: rquickjs::loader::BuiltinResolver) -> rquickjs::loader::BuiltinResolver {
-                    resolver.#(#resolver_chain).*
+            module_names.push(world_imports_lit.clone());
+            load_cases.push(quote! {
+                #world_imports_lit => rquickjs::Module::declare_def::<crate::modules::#rust_module_struct_ident, _>(ctx.clone(), name)
+            });
+        }
+
+        // The helper modules are JS modules built on top of the native modules of the imports
+        for helper in enabled_helper_modules(all_imported_interfaces) {
+            let name_lit = LitStr::new(helper.module.name, Span::call_site());
+            let file_lit = LitStr::new(&format!("{}.js", helper.module.name), Span::call_site());
+
+            module_names.push(name_lit.clone());
+            load_cases.push(quote! {
+                #name_lit => rquickjs::Module::declare(ctx.clone(), name, include_str!(#file_lit))
+            });
+        }
+
+        if context.options.validators {
+            let validators_lit = LitStr::new(VALIDATORS_MODULE_NAME, Span::call_site());
+
+            module_names.push(validators_lit.clone());
+            load_cases.push(quote! {
+                #validators_lit => rquickjs::Module::declare(ctx.clone(), name, include_str!("validators.js"))
+            });
+        }
+
+        if context.options.enum_constants {
+            let enums_lit = LitStr::new(ENUMS_MODULE_NAME, Span::call_site());
+
+            module_names.push(enums_lit.clone());
+            load_cases.push(quote! {
+                #enums_lit => rquickjs::Module::declare(ctx.clone(), name, include_str!("enums.js"))
+            });
+        }
+
+        // Denied interfaces are still resolved, so importing them fails with an explicit error
+        // instead of a missing module
+        for interface in collect_denied_interfaces(context)? {
+            let fully_qualified_interface = interface.fully_qualified_interface_name();
+            let denied_source = format!(
+                "throw new Error({:?});",
+                format!(
+                    "Importing the {fully_qualified_interface} interface is denied by the capability policy of the component"
+                )
+            );
+            let fully_qualified_interface_lit =
+                LitStr::new(&fully_qualified_interface, Span::call_site());
+
+            module_names.push(fully_qualified_interface_lit.clone());
+            load_cases.push(quote! {
+                #fully_qualified_interface_lit => rquickjs::Module::declare(ctx.clone(), name, #denied_source)
+            });
+        }
+
+        // The native modules are only declared in the QuickJS context when the JS code first
+        // imports them, so unused imported interfaces cost nothing at startup
+        loader_init = quote! {
+            const NATIVE_MODULE_NAMES: &[&str] = &[#(#module_names),*];
+
+            pub struct NativeModuleResolver;
+
+            impl rquickjs::loader::Resolver for NativeModuleResolver {
+                fn resolve<'js>(&mut self, _ctx: &rquickjs::Ctx<'js>, base: &str, name: &str) -> rquickjs::Result<String> {
+                    if NATIVE_MODULE_NAMES.contains(&name) {
+                        Ok(name.to_string())
+                    } else {
+                        Err(rquickjs::Error::new_resolving(base, name))
+                    }
                 }
+            }
+
+            pub struct NativeModuleLoader;
 
-                pub fn module_loader() -> rquickjs::loader::ModuleLoader {
-                  rquickjs::loader::ModuleLoader::default().#(#loader_chain).*
+            impl rquickjs::loader::Loader for NativeModuleLoader {
+                fn load<'js>(&mut self, ctx: &rquickjs::Ctx<'js>, name: &str) -> rquickjs::Result<rquickjs::Module<'js, rquickjs::module::Declared>> {
+                    match name {
+                        #(#load_cases,)*
+                        _ => Err(rquickjs::Error::new_loading(name)),
+                    }
                 }
-            };
-        }
+            }
+        };
     }
 
     let rust_interface_name = import.rust_interface_name();
 
+    // With import hooks, recording or mocks, the exports are wrapped to be called through the
+    // registered hooks or replaced by mocks, identified by the module name the JS code imports
+    // them by
+    // Recording the imports is done by the innermost layer of the hooks
+    let hook_imports = context.options.import_hooks || context.options.import_recording.is_some();
+    let module_name_lit = if import.interface.is_some() {
+        LitStr::new(&import.fully_qualified_interface_name(), Span::call_site())
+    } else {
+        LitStr::new(WORLD_IMPORTS_MODULE_NAME, Span::call_site())
+    };
+
     let mut bridge_functions = Vec::new();
     let mut bridge_classes = Vec::new();
     let mut declarations = Vec::new();
@@ -175,7 +325,18 @@ fn generate_import_module(
 
                 declarations.push(quote! { decl.declare(#js_function_lit)? });
 
-                exports.push(quote! { exports.export(#js_function_lit, #js_bridge_ident)? });
+                let mut js_function = quote! { #js_bridge_ident };
+                if context.options.mock_imports {
+                    js_function = quote! {
+                        crate::builtin::mock_import_function(ctx, #module_name_lit, #js_function_lit, #js_function)?
+                    };
+                }
+                if hook_imports {
+                    js_function = quote! {
+                        crate::builtin::hook_import_function(ctx, #module_name_lit, #js_function_lit, #js_function)?
+                    };
+                }
+                exports.push(quote! { exports.export(#js_function_lit, #js_function)? });
 
                 let bindgen_path = ident_in_imported_interface_or_global(
                     context,
@@ -312,6 +473,7 @@ fn generate_import_module(
                 pub fn new(#(#param_list),*) -> Self {
                   Self {
                     inner: Some(std::rc::Rc::new(#bindgen_path::new(#(#param_refs),*))),
+                    borrow_epoch: None,
                   }
                 }
             }
@@ -321,6 +483,7 @@ fn generate_import_module(
                 pub fn new() -> Self {
                   Self {
                     inner: None,
+                    borrow_epoch: None,
                   }
                 }
             }
@@ -373,10 +536,7 @@ fn generate_import_module(
                         methods.push(quote! {
                             pub fn #rust_method_name_ident(&self, ctx: rquickjs::Ctx<'_>, #(#param_list),*) -> rquickjs::Result<#wrapped_result> {
                                 let result: #original_result = self
-                                      .inner
-                                      .as_ref()
-                                      .expect("Resource has already been disposed")
-                                      .deref()
+                                      .resource(&ctx)?
                                       .#rust_method_name_ident(#(#param_refs),*);
                                 match result {
                                     Ok(result) => Ok(#wrap_result),
@@ -389,14 +549,11 @@ fn generate_import_module(
                         });
                     } else {
                         methods.push(quote! {
-                           pub fn #rust_method_name_ident(&self, #(#param_list),*) -> #wrapped_result {
+                           pub fn #rust_method_name_ident(&self, ctx: rquickjs::Ctx<'_>, #(#param_list),*) -> rquickjs::Result<#wrapped_result> {
                                 let result: #original_result = self
-                                  .inner
-                                  .as_ref()
-                                  .expect("Resource has already been disposed")
-                                  .deref()
+                                  .resource(&ctx)?
                                   .#rust_method_name_ident(#(#param_refs),*);
-                                #wrap_result
+                                Ok(#wrap_result)
                             }
                         });
                     }
@@ -446,6 +603,9 @@ fn generate_import_module(
         {
             special_methods.push(quote! {
                 pub async fn promise(&mut self) -> () {
+                    if self.borrow_epoch.is_some() {
+                        panic!("A borrowed pollable cannot be awaited, as that would take its ownership");
+                    }
                     let pollable = self.inner.take().expect("Resource has already been disposed");
                     let pollable: wasi::io::poll::Pollable = unsafe { wasi::io::poll::Pollable::from_handle(pollable.take_handle()) };
                     wstd::runtime::AsyncPollable::new(pollable).wait_for().await;
@@ -461,10 +621,50 @@ fn generate_import_module(
             pub struct #resource_name_ident {
                 #[qjs(skip_trace = true)]
                 inner: Option<std::rc::Rc<#bindgen_path>>,
+                /// The export call epoch if the handle is only borrowed from the host
+                #[qjs(skip_trace = true)]
+                borrow_epoch: Option<usize>,
             }
 
             #rquickjs_class
 
+            impl #resource_name_ident {
+                /// Gets the wrapped resource, failing if it has been disposed or if it is a
+                /// borrowed handle and the call it was passed to has already returned
+                fn checked_inner(&self) -> Result<&std::rc::Rc<#bindgen_path>, &'static str> {
+                    if self.borrow_epoch.is_some_and(|epoch| !crate::internal::is_borrow_active(epoch)) {
+                        return Err("Borrowed resource handle cannot be used after the call it was passed to has returned");
+                    }
+                    self.inner.as_ref().ok_or("Resource has already been disposed")
+                }
+
+                /// Gets the wrapped resource for calling one of its methods, throwing a
+                /// `ReferenceError` if it cannot be used
+                fn resource(&self, ctx: &rquickjs::Ctx<'_>) -> rquickjs::Result<&#bindgen_path> {
+                    self.checked_inner()
+                        .map(|inner| inner.deref())
+                        .map_err(|message| rquickjs::Exception::throw_reference(ctx, message))
+                }
+
+                /// Releases the wrapped resource. Borrowed handles belong to the host, so they
+                /// are never dropped, only forgotten.
+                fn release(&mut self) {
+                    if let Some(inner) = self.inner.take() {
+                        if self.borrow_epoch.is_some() {
+                            if let Ok(inner) = std::rc::Rc::try_unwrap(inner) {
+                                let _ = inner.take_handle();
+                            }
+                        }
+                    }
+                }
+            }
+
+            impl Drop for #resource_name_ident {
+                fn drop(&mut self) {
+                    self.release();
+                }
+            }
+
             #[rquickjs::methods(rename_all = "camelCase")]
             impl #resource_name_ident {
                 #constructor
@@ -473,7 +673,7 @@ fn generate_import_module(
 
                 #[qjs(rename="__dispose")]
                 pub fn __dispose(&mut self) {
-                    let _ = self.inner.take();
+                    self.release();
                 }
 
                 #(#special_methods)*
@@ -483,6 +683,7 @@ fn generate_import_module(
                 fn into_js(self, ctx: &rquickjs::Ctx<'js>) -> rquickjs::Result<rquickjs::Value<'js>> {
                     #resource_name_ident {
                         inner: Some(std::rc::Rc::new(self)),
+                        borrow_epoch: None,
                     }
                     .into_js(ctx)
                 }
@@ -491,16 +692,13 @@ fn generate_import_module(
             impl<'js> rquickjs::FromJs<'js> for #bindgen_path {
                 fn from_js(ctx: &rquickjs::Ctx<'js>, value: rquickjs::Value<'js>) -> rquickjs::Result<Self> {
                     let wrapper = #resource_name_ident::from_js(ctx, value)?;
-                    unsafe {
-                        Ok(
-                            #bindgen_path::from_handle(
-                                wrapper
-                                  .inner
-                                  .ok_or_else(|| rquickjs::Error::FromJs { from: "JavaScript object", to: #resource_name_lit, message: Some("Resource has already been disposed".to_string()) })?
-                                  .take_handle(),
-                            ),
-                        )
+                    if wrapper.borrow_epoch.is_some() {
+                        return Err(rquickjs::Error::FromJs { from: "JavaScript object", to: #resource_name_lit, message: Some("The ownership of a borrowed resource handle cannot be transferred".to_string()) });
                     }
+                    let inner = wrapper
+                        .checked_inner()
+                        .map_err(|message| rquickjs::Error::FromJs { from: "JavaScript object", to: #resource_name_lit, message: Some(message.to_string()) })?;
+                    unsafe { Ok(#bindgen_path::from_handle(inner.take_handle())) }
                 }
             }
 
@@ -509,16 +707,28 @@ fn generate_import_module(
             impl<'js> rquickjs::FromJs<'js> for #borrow_wrapper_ident {
                 fn from_js(ctx: &rquickjs::Ctx<'js>, value: rquickjs::Value<'js>) -> rquickjs::Result<Self> {
                     let wrapper = #resource_name_ident::from_js(ctx, value)?;
-                    unsafe {
-                        Ok(#borrow_wrapper_ident(
-                            #bindgen_path::from_handle(
-                                wrapper
-                                  .inner
-                                  .ok_or_else(|| rquickjs::Error::FromJs { from: "JavaScript object", to: #resource_name_lit, message: Some("Resource has already been disposed".to_string()) })?
-                                  .handle(),
-                            ),
-                        ))
+                    let inner = wrapper
+                        .checked_inner()
+                        .map_err(|message| rquickjs::Error::FromJs { from: "JavaScript object", to: #resource_name_lit, message: Some(message.to_string()) })?;
+                    unsafe { Ok(#borrow_wrapper_ident(#bindgen_path::from_handle(inner.handle()))) }
+                }
+            }
+
+            impl #borrow_wrapper_ident {
+                /// Borrows a handle passed to an exported function, without taking its ownership
+                pub fn from_ref(resource: &#bindgen_path) -> Self {
+                    unsafe { #borrow_wrapper_ident(#bindgen_path::from_handle(resource.handle())) }
+                }
+            }
+
+            impl<'js> rquickjs::IntoJs<'js> for #borrow_wrapper_ident {
+                fn into_js(self, ctx: &rquickjs::Ctx<'js>) -> rquickjs::Result<rquickjs::Value<'js>> {
+                    let resource = unsafe { #bindgen_path::from_handle(self.0.take_handle()) };
+                    #resource_name_ident {
+                        inner: Some(std::rc::Rc::new(resource)),
+                        borrow_epoch: Some(crate::internal::current_borrow_epoch()),
                     }
+                    .into_js(ctx)
                 }
             }
 
@@ -535,9 +745,18 @@ fn generate_import_module(
             Span::call_site(),
         );
         declarations.push(quote! { decl.declare(#js_class_lit)? });
-        exports.push(
-            quote! { exports.export(#js_class_lit, #resource_name_ident::constructor(ctx)?)? },
-        );
+        let mut js_class = quote! { #resource_name_ident::constructor(ctx)? };
+        if context.options.mock_imports {
+            js_class = quote! {
+                crate::builtin::mock_import_class(ctx, #module_name_lit, #js_class_lit, #js_class)?
+            };
+        }
+        if hook_imports {
+            js_class = quote! {
+                crate::builtin::hook_import_class(ctx, #module_name_lit, #js_class_lit, #js_class, rquickjs::class::Class::<#resource_name_ident>::prototype(ctx)?)?
+            };
+        }
+        exports.push(quote! { exports.export(#js_class_lit, #js_class)? });
     }
 
     let module = quote! {
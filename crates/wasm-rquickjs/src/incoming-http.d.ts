@@ -0,0 +1,33 @@
+/**
+ * Reading the requests received by an exported `wasi:http/incoming-handler`, built on top of
+ * `wasi:http/types`.
+ */
+declare module 'incoming-http' {
+  import type { IncomingRequest } from '__WASI_HTTP_TYPES_MODULE__';
+  /**
+   * A received request, as returned by `readRequest`.
+   */
+  export interface ReceivedRequest {
+    method: string;
+    pathWithQuery: string | undefined;
+    /**
+     * The headers by their names, with the values of repeated headers joined by `, `.
+     */
+    headers: Record<string, string>;
+    body: Uint8Array;
+    /**
+     * The body decoded as UTF-8.
+     */
+    text(): string;
+    /**
+     * The body parsed as JSON.
+     */
+    json<T = unknown>(): T;
+  }
+  /**
+   * Reads the method, headers and body of a request received by the `handle` function of
+   * `wasi:http/incoming-handler`, consuming its body. Throws a `TypeError` if the request exceeds
+   * the header count or request body size limits of the `http` feature.
+   */
+  export function readRequest(request: IncomingRequest): ReceivedRequest;
+}
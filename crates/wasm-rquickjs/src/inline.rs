@@ -0,0 +1,94 @@
+use crate::paths::{list_files, simplified, to_utf8};
+use crate::{Diagnostic, EmbeddingMode, GeneratorOptions, JsModuleSpec, generate_wrapper_crate};
+use anyhow::{Context, anyhow};
+use camino::Utf8PathBuf;
+use std::path::Path;
+use syn::{Item, parse_quote};
+
+/// A wrapper crate generated to be included into another crate's root module by
+/// [`generate_inline_module`]
+#[derive(Debug, Clone)]
+pub struct InlineModule {
+    /// The file to `include!` into the crate root, declaring the wrapper's modules with absolute
+    /// `#[path]` attributes, except for the `bindings` module
+    pub root: Utf8PathBuf,
+    /// The files the generated code depends on: the WIT files and the embedded JS modules
+    pub inputs: Vec<Utf8PathBuf>,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+/// Generates a wrapper crate into `output` like [`generate_wrapper_crate`], and writes a variant
+/// of its `lib.rs` that can be included into the root module of another crate, so the wrapper
+/// does not need to be a crate on its own. This is what the `embed_js_component!` macro of the
+/// `wasm-rquickjs-macro` crate is built on.
+///
+/// The including crate must have the dependencies and features of the generated `Cargo.toml`,
+/// and be built with `cargo-component` targeting the WIT world. It declares the `bindings` module
+/// generated by `cargo-component` itself, as modules declared in an included file are looked up
+/// relative to the including file.
+///
+/// Unlike the other generators, it requires the output directory and the inputs to have UTF-8
+/// paths, as their absolute paths are embedded into the generated code.
+pub fn generate_inline_module(
+    wit: impl AsRef<Path>,
+    js_modules: &[JsModuleSpec],
+    output: impl AsRef<Path>,
+    world: Option<&str>,
+    options: &GeneratorOptions,
+) -> anyhow::Result<InlineModule> {
+    let (wit, output) = (wit.as_ref(), output.as_ref());
+    let diagnostics = generate_wrapper_crate(wit, js_modules, output, world, options)?;
+
+    let src = absolute_path(output)?.join("src");
+    let lib_rs = std::fs::read_to_string(src.join("lib.rs")).context("Failed to read lib.rs")?;
+    let mut lib: syn::File = syn::parse_str(&lib_rs).context("Failed to parse lib.rs")?;
+
+    // Inner attributes cannot be included into another module
+    lib.attrs.clear();
+    // The bindings are generated by cargo-component into the including crate
+    lib.items
+        .retain(|item| !matches!(item, Item::Mod(module) if module.ident == "bindings"));
+    for item in &mut lib.items {
+        if let Item::Mod(module) = item
+            && module.content.is_none()
+        {
+            let name = module.ident.to_string();
+            let path = if src.join(&name).is_dir() {
+                src.join(&name).join("mod.rs")
+            } else {
+                src.join(format!("{name}.rs"))
+            };
+            let path = path.as_str();
+            module.attrs.push(parse_quote! { #[path = #path] });
+        }
+    }
+    let root = src.join("inline.rs");
+    std::fs::write(&root, prettier_please::unparse(&lib)).context("Failed to write inline.rs")?;
+
+    let mut inputs = Vec::new();
+    for file in list_files(wit).context("Failed to list the WIT directory")? {
+        inputs.push(absolute_path(&file)?);
+    }
+    for module in js_modules {
+        if let EmbeddingMode::EmbedFile(path) = &module.mode {
+            inputs.push(absolute_path(path)?);
+        }
+    }
+
+    Ok(InlineModule {
+        root,
+        inputs,
+        diagnostics,
+    })
+}
+
+/// The absolute path of a file, as embedded into the generated code
+fn absolute_path(path: &Path) -> anyhow::Result<Utf8PathBuf> {
+    let absolute = path
+        .canonicalize()
+        .map_err(|err| anyhow!("Failed to resolve {}: {err}", path.display()))?;
+    Ok(simplified(to_utf8(
+        &absolute,
+        "an input of the inline module",
+    )?))
+}
@@ -1,3 +1,6 @@
+use crate::JsNamingPolicy;
+use heck::{ToLowerCamelCase, ToUpperCamelCase};
+
 pub fn escape_js_ident(ident: impl AsRef<str>) -> String {
     if KEYWORDS.contains(&ident.as_ref()) {
         format!("{}_", ident.as_ref())
@@ -6,6 +9,32 @@ pub fn escape_js_ident(ident: impl AsRef<str>) -> String {
     }
 }
 
+impl JsNamingPolicy {
+    /// Gets the JS name of an exported WIT function or resource method
+    pub fn function_name(&self, name: &str) -> String {
+        match self {
+            JsNamingPolicy::CamelCase => escape_js_ident(name.to_lower_camel_case()),
+            JsNamingPolicy::Verbatim => name.to_string(),
+        }
+    }
+
+    /// Gets the JS name of an exported WIT interface
+    pub fn interface_name(&self, name: &str) -> String {
+        match self {
+            JsNamingPolicy::CamelCase => escape_js_ident(name.to_lower_camel_case()),
+            JsNamingPolicy::Verbatim => name.to_string(),
+        }
+    }
+
+    /// Gets the JS name of the class implementing an exported WIT resource
+    pub fn class_name(&self, name: &str) -> String {
+        match self {
+            JsNamingPolicy::CamelCase => name.to_upper_camel_case(),
+            JsNamingPolicy::Verbatim => name.to_string(),
+        }
+    }
+}
+
 const KEYWORDS: &[&str] = &[
     "await",
     "break",
@@ -0,0 +1,286 @@
+/// An identifier of a JS source found by [`rewrite_identifiers`]
+pub struct Identifier<'a> {
+    pub name: &'a str,
+    /// Byte offset of the identifier in the source
+    pub start: usize,
+    /// Whether the identifier follows a member access `.`, being a property name
+    pub after_dot: bool,
+    /// The token directly preceding the identifier, if it is an identifier or keyword too
+    pub previous: Option<&'a str>,
+}
+
+/// Calls `visit` for each identifier of a JS source outside of string literals, template literal
+/// texts, comments and regular expression literals. By returning `Some((end, replacement))`, the
+/// visitor replaces the source from the identifier's start to `end`.
+pub fn rewrite_identifiers<'a>(
+    source: &'a str,
+    visit: impl FnMut(Identifier<'a>) -> Option<(usize, String)>,
+) -> String {
+    let mut scanner = Scanner {
+        source: source.as_bytes(),
+        pos: 0,
+        output: Vec::with_capacity(source.len()),
+        visit,
+        template_depths: Vec::new(),
+        regex_allowed: true,
+        after_dot: false,
+        previous: None,
+    };
+    scanner.run();
+    // Only whole tokens are replaced, so the output is valid UTF-8 like the source
+    String::from_utf8(scanner.output).expect("Invalid UTF-8 after rewriting a JS source")
+}
+
+/// Calls `visit` for each identifier of a JS source, like [`rewrite_identifiers`]
+pub fn scan_identifiers<'a>(source: &'a str, mut visit: impl FnMut(Identifier<'a>)) {
+    rewrite_identifiers(source, |identifier| {
+        visit(identifier);
+        None
+    });
+}
+
+/// A simplified JS tokenizer, only distinguishing the tokens needed to find the identifiers
+struct Scanner<'a, F> {
+    source: &'a [u8],
+    pos: usize,
+    output: Vec<u8>,
+    visit: F,
+    /// Number of open braces in each template literal substitution (`${...}`) being scanned
+    template_depths: Vec<usize>,
+    /// Whether a `/` starts a regular expression literal instead of being a division
+    regex_allowed: bool,
+    /// Whether the previous token is a member access `.`, so an identifier is a property name
+    after_dot: bool,
+    /// The previous token, if it is an identifier
+    previous: Option<&'a str>,
+}
+
+impl<'a, F: FnMut(Identifier<'a>) -> Option<(usize, String)>> Scanner<'a, F> {
+    fn run(&mut self) {
+        while let Some(c) = self.peek(0) {
+            match c {
+                b' ' | b'\t' | b'\r' | b'\n' => self.copy(1),
+                b'/' if self.peek(1) == Some(b'/') => {
+                    let end = self.find_from(self.pos, b'\n').unwrap_or(self.source.len());
+                    self.copy(end - self.pos);
+                }
+                b'/' if self.peek(1) == Some(b'*') => {
+                    let end = find_subslice(&self.source[self.pos + 2..], b"*/")
+                        .map(|index| self.pos + 2 + index + 2)
+                        .unwrap_or(self.source.len());
+                    self.copy(end - self.pos);
+                }
+                b'/' if self.regex_allowed => {
+                    self.regex();
+                    self.token(false);
+                }
+                b'\'' | b'"' => {
+                    self.string(c);
+                    self.token(false);
+                }
+                b'`' => {
+                    self.copy(1);
+                    self.template();
+                }
+                b'{' => {
+                    if let Some(depth) = self.template_depths.last_mut() {
+                        *depth += 1;
+                    }
+                    self.copy(1);
+                    self.token(true);
+                }
+                b'}' => match self.template_depths.last_mut() {
+                    Some(0) => {
+                        self.template_depths.pop();
+                        self.copy(1);
+                        self.template();
+                    }
+                    Some(depth) => {
+                        *depth -= 1;
+                        self.copy(1);
+                        self.token(true);
+                    }
+                    None => {
+                        self.copy(1);
+                        self.token(true);
+                    }
+                },
+                b'.' if self.peek(1).is_some_and(|c| c.is_ascii_digit()) => {
+                    self.number();
+                }
+                b'.' if self.peek(1) == Some(b'.') && self.peek(2) == Some(b'.') => {
+                    self.copy(3);
+                    self.token(true);
+                }
+                b'.' => {
+                    self.copy(1);
+                    self.regex_allowed = false;
+                    self.after_dot = true;
+                }
+                b')' | b']' => {
+                    self.copy(1);
+                    self.token(false);
+                }
+                c if c.is_ascii_digit() => self.number(),
+                c if is_identifier_char(c) => self.identifier(),
+                _ => {
+                    self.copy(1);
+                    self.token(true);
+                }
+            }
+        }
+    }
+
+    fn identifier(&mut self) {
+        let start = self.pos;
+        let name = self.word_at(start);
+        let identifier = Identifier {
+            name,
+            start,
+            after_dot: self.after_dot,
+            previous: self.previous,
+        };
+        if let Some((end, replacement)) = (self.visit)(identifier) {
+            self.output.extend_from_slice(replacement.as_bytes());
+            self.pos = end;
+            self.token(false);
+            return;
+        }
+        self.copy(name.len());
+        self.token(KEYWORDS_BEFORE_EXPRESSION.contains(&name));
+        self.previous = Some(name);
+    }
+
+    fn word_at(&self, start: usize) -> &'a str {
+        let end = self.source[start..]
+            .iter()
+            .position(|c| !is_identifier_char(*c))
+            .map(|index| start + index)
+            .unwrap_or(self.source.len());
+        // Identifiers are only split at ASCII characters, so they are valid UTF-8
+        std::str::from_utf8(&self.source[start..end]).unwrap_or_default()
+    }
+
+    fn number(&mut self) {
+        let end = self.source[self.pos + 1..]
+            .iter()
+            .position(|c| !(c.is_ascii_alphanumeric() || *c == b'.' || *c == b'_'))
+            .map(|index| self.pos + 1 + index)
+            .unwrap_or(self.source.len());
+        self.copy(end - self.pos);
+        self.token(false);
+    }
+
+    fn string(&mut self, quote: u8) {
+        let mut end = self.pos + 1;
+        while let Some(c) = self.source.get(end) {
+            end += 1;
+            match c {
+                b'\\' => end += 1,
+                b'\n' => break,
+                c if *c == quote => break,
+                _ => {}
+            }
+        }
+        self.copy(end.min(self.source.len()) - self.pos);
+    }
+
+    /// Copies the text of a template literal up to its end or to the next substitution
+    fn template(&mut self) {
+        let mut end = self.pos;
+        while let Some(c) = self.source.get(end) {
+            end += 1;
+            match c {
+                b'\\' => end += 1,
+                b'`' => {
+                    self.copy(end.min(self.source.len()) - self.pos);
+                    self.token(false);
+                    return;
+                }
+                b'$' if self.source.get(end) == Some(&b'{') => {
+                    self.copy(end + 1 - self.pos);
+                    self.template_depths.push(0);
+                    self.token(true);
+                    return;
+                }
+                _ => {}
+            }
+        }
+        self.copy(self.source.len() - self.pos);
+    }
+
+    fn regex(&mut self) {
+        let mut end = self.pos + 1;
+        let mut in_class = false;
+        while let Some(c) = self.source.get(end) {
+            match c {
+                b'\\' => end += 1,
+                b'[' => in_class = true,
+                b']' => in_class = false,
+                b'/' if !in_class => {
+                    end += 1;
+                    break;
+                }
+                // Regular expressions cannot span lines, so this was a division after all
+                b'\n' => break,
+                _ => {}
+            }
+            end += 1;
+        }
+        let end = end.min(self.source.len());
+        let flags = self.word_at(end).len();
+        self.copy(end + flags - self.pos);
+    }
+
+    fn token(&mut self, regex_allowed: bool) {
+        self.regex_allowed = regex_allowed;
+        self.after_dot = false;
+        self.previous = None;
+    }
+
+    fn copy(&mut self, len: usize) {
+        let end = (self.pos + len).min(self.source.len());
+        self.output.extend_from_slice(&self.source[self.pos..end]);
+        self.pos = end;
+    }
+
+    fn peek(&self, offset: usize) -> Option<u8> {
+        self.source.get(self.pos + offset).copied()
+    }
+
+    fn find_from(&self, start: usize, c: u8) -> Option<usize> {
+        self.source[start..]
+            .iter()
+            .position(|x| *x == c)
+            .map(|index| start + index)
+    }
+}
+
+fn find_subslice(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+/// Identifier characters, treating all non-ASCII characters as such
+pub fn is_identifier_char(c: u8) -> bool {
+    c.is_ascii_alphanumeric() || c == b'_' || c == b'$' || c >= 0x80
+}
+
+/// Keywords after which a `/` starts a regular expression literal
+const KEYWORDS_BEFORE_EXPRESSION: &[&str] = &[
+    "await",
+    "case",
+    "delete",
+    "do",
+    "else",
+    "in",
+    "instanceof",
+    "new",
+    "of",
+    "return",
+    "throw",
+    "typeof",
+    "void",
+    "yield",
+];
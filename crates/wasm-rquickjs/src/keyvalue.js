@@ -0,0 +1,112 @@
+import * as eventual from '__WASI_KEYVALUE_EVENTUAL_MODULE__';
+import * as types from '__WASI_KEYVALUE_TYPES_MODULE__';
+
+// Empty if the world does not import the batch interface
+const EVENTUAL_BATCH_MODULE = '__WASI_KEYVALUE_EVENTUAL_BATCH_MODULE__';
+
+export class KeyValueError extends Error {
+    constructor(message, options) {
+        super(message, options);
+        this.name = 'KeyValueError';
+    }
+}
+
+// Runs a call of the host interface, converting the thrown `error` resources to `KeyValueError`s
+function call(operation, f) {
+    try {
+        return f();
+    } catch (error) {
+        if (error instanceof Error) {
+            throw error;
+        }
+        const trace = typeof error?.trace === 'function' ? error.trace() : String(error);
+        throw new KeyValueError(`Failed to ${operation}: ${trace}`, { cause: error });
+    }
+}
+
+function toBytes(value) {
+    if (typeof value === 'string') {
+        return new TextEncoder().encode(value);
+    }
+    if (value instanceof Uint8Array) {
+        return value;
+    }
+    if (ArrayBuffer.isView(value)) {
+        return new Uint8Array(value.buffer, value.byteOffset, value.byteLength);
+    }
+    if (value instanceof ArrayBuffer) {
+        return new Uint8Array(value);
+    }
+    throw new TypeError('The value must be a string, an ArrayBuffer or a typed array');
+}
+
+export class Bucket {
+    #bucket;
+
+    constructor(name, bucket) {
+        this.name = name;
+        this.#bucket = bucket;
+    }
+
+    async get(key) {
+        const incoming = call(`get the key ${key}`, () => eventual.get(this.#bucket, key));
+        if (incoming === undefined) {
+            return undefined;
+        }
+        return call(`read the value of the key ${key}`, () => incoming.incomingValueConsumeSync());
+    }
+
+    async getText(key) {
+        const bytes = await this.get(key);
+        return bytes === undefined ? undefined : new TextDecoder().decode(bytes);
+    }
+
+    async getJson(key) {
+        const text = await this.getText(key);
+        return text === undefined ? undefined : JSON.parse(text);
+    }
+
+    async set(key, value) {
+        const outgoing = types.OutgoingValue.newOutgoingValue();
+        call(`write the value of the key ${key}`, () => outgoing.outgoingValueWriteBodySync(toBytes(value)));
+        call(`set the key ${key}`, () => eventual.set(this.#bucket, key, outgoing));
+    }
+
+    async setJson(key, value) {
+        await this.set(key, JSON.stringify(value));
+    }
+
+    async delete(key) {
+        call(`delete the key ${key}`, () => eventual.delete_(this.#bucket, key));
+    }
+
+    async has(key) {
+        return call(`check the key ${key}`, () => eventual.exists(this.#bucket, key));
+    }
+
+    async keys() {
+        if (EVENTUAL_BATCH_MODULE === '') {
+            throw new KeyValueError('Listing the keys requires the world to import wasi:keyvalue/eventual-batch');
+        }
+        const batch = await import(EVENTUAL_BATCH_MODULE);
+        return call(`list the keys of the bucket ${this.name}`, () => batch.keys(this.#bucket));
+    }
+
+    async* entries() {
+        for (const key of await this.keys()) {
+            const value = await this.get(key);
+            if (value !== undefined) {
+                yield [key, value];
+            }
+        }
+    }
+
+    [Symbol.asyncIterator]() {
+        return this.entries();
+    }
+}
+
+export async function openBucket(name) {
+    const bucket = call(`open the bucket ${name}`, () => types.Bucket.openBucket(name));
+    return new Bucket(name, bucket);
+}
@@ -1,37 +1,79 @@
+use crate::assets::{copy_import_recording, generate_assets};
 use crate::conversions::generate_conversions;
 use crate::exports::generate_export_impls;
 use crate::imports::generate_import_modules;
+use crate::provenance::{Provenance, comment_syntax};
 use crate::skeleton::{
     copy_cargo_config, copy_skeleton_sources, generate_app_manifest, generate_cargo_toml,
 };
-use crate::wit::add_get_script_import;
+use crate::wit::{
+    add_clock_import, add_describe_export, add_get_script_import, add_invoke_export,
+    add_repl_export,
+};
 use anyhow::{Context, anyhow};
-use camino::{Utf8Path, Utf8PathBuf};
+use camino::Utf8PathBuf;
 use fs_extra::dir::CopyOptions;
 use heck::{ToSnakeCase, ToUpperCamelCase};
 use proc_macro2::{Ident, Span};
-use std::cell::RefCell;
-use std::collections::{BTreeSet, VecDeque};
+use std::cell::{OnceCell, RefCell};
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tracing::{debug, info_span, trace};
 use wit_parser::{
-    Function, Interface, InterfaceId, PackageId, PackageName, PackageSourceMap, Resolve, TypeDef,
-    TypeId, TypeOwner, WorldId, WorldItem,
+    Function, Interface, InterfaceId, PackageId, PackageName, PackageSourceMap, Resolve, Type,
+    TypeDef, TypeDefKind, TypeId, TypeOwner, WorldId, WorldItem, WorldKey,
 };
 
+mod assets;
+mod backend;
+pub mod build_script;
+mod compose;
 mod conversions;
+mod defines;
+mod diagnostics;
+mod enums;
 mod exports;
+mod handlers;
+mod helper_modules;
 mod imports;
+mod inline;
 mod javascript;
+mod js_scanner;
+mod model;
+pub mod paths;
+mod plan;
+mod pragmas;
+mod progress;
+mod provenance;
 mod rust_bindgen;
+mod script_component;
 mod skeleton;
 mod types;
 mod typescript;
+mod validation;
+mod validators;
 mod wit;
 
+pub use backend::CodegenBackend;
+pub use compose::{compose, plug};
+pub use diagnostics::Diagnostic;
+pub use inline::{InlineModule, generate_inline_module};
+pub use model::{
+    FieldModel, FunctionModel, FunctionModelKind, InterfaceModel, ParameterModel, TypeModel,
+    TypeModelKind, WorldModel, analyze_world,
+};
+pub use plan::{GenerationPlan, PlannedJsModule, plan_wrapper_crate};
+pub use progress::{GenerationStage, ProgressReporter};
+pub use script_component::generate_script_component_crate;
+
+const REPL_MODULE: &str = include_str!("repl.js");
+
 /// Specifies how a given user-defined JS module gets embedded into the generated Rust crate.
 #[derive(Debug, Clone)]
 pub enum EmbeddingMode {
     /// Points to a JS module file that is going to be embedded into the generated Rust crate
-    EmbedFile(Utf8PathBuf),
+    EmbedFile(PathBuf),
     /// The JS module is going to be fetched run-time through an imported WIT interface
     Composition,
 }
@@ -41,34 +83,418 @@ pub enum EmbeddingMode {
 pub struct JsModuleSpec {
     pub name: String,
     pub mode: EmbeddingMode,
+    /// Whether this is the entry module implementing the WIT exports. Exactly one of the modules
+    /// must be the entry, the others are imported during the initialization of the component.
+    ///
+    /// The modules are evaluated in the order they are given, and a module exporting an `__init`
+    /// function has it called and awaited right after its evaluation, before any export is called.
+    pub entry: bool,
 }
 
 impl JsModuleSpec {
+    /// Path of the embedded module's file, relative to the `src` directory of the wrapper crate.
+    /// Names separated by `/` are kept as nested directories.
     pub fn file_name(&self) -> String {
-        self.name.replace('/', "_") + ".js"
+        format!("{}.js", self.name)
+    }
+}
+
+/// Specifies a static file embedded into the generated component, readable from JS through the
+/// `assets` module.
+#[derive(Debug, Clone)]
+pub struct AssetSpec {
+    /// The name the asset is read by in JS, a relative path such as `templates/index.html`
+    pub name: String,
+    /// Path of the file to embed
+    pub path: PathBuf,
+}
+
+/// Options controlling the generated code.
+#[derive(Debug, Clone, Default)]
+pub struct GeneratorOptions {
+    /// Whether to include the `.cargo/config.toml` file of the skeleton in the generated crate
+    pub include_cargo_config: bool,
+    /// How the exported WIT names are mapped to the names of their JavaScript implementations
+    pub export_naming: JsNamingPolicy,
+    /// Whether converting a JS object to a WIT record fails if the object has properties not
+    /// belonging to the record. By default these are ignored.
+    pub strict_records: bool,
+    /// Whether the conversion code caches the interned JS names (atoms) of record fields and
+    /// variant properties instead of interning them on every conversion, speeding up frequently
+    /// called functions with large records
+    pub intern_field_names: bool,
+    /// How JS strings containing lone surrogates are converted to WIT strings
+    pub lone_surrogates: LoneSurrogatePolicy,
+    /// Makes `Math.random` deterministic, generating its numbers from the given seed. If not set,
+    /// the builtin generator of QuickJS is used.
+    pub math_random_seed: Option<MathRandomSeed>,
+    /// Makes `Date.now()` and `new Date()` get the current time from the given clock instead of
+    /// the wall clock of WASI. If not set, the wall clock is used.
+    pub date_clock: Option<DateClock>,
+    /// Whether the calls of the imported interfaces' functions, resource constructors and methods
+    /// go through the hooks registered from JS with the global `registerImportHook` function, to
+    /// log, mock or cache them
+    pub import_hooks: bool,
+    /// Whether the wrapper crate gets a `mock-imports` cargo feature, which replaces the imported
+    /// interfaces by mocks set from JS through the `__mocks` module, to unit test the JS code
+    /// without a host providing the imports
+    pub mock_imports: bool,
+    /// Records the calls of the imported interfaces with their arguments and results, or replays
+    /// the results of a recording instead of calling the imports, to debug nondeterministic
+    /// behavior of the host
+    pub import_recording: Option<ImportRecording>,
+    /// Whether the background work still pending when an exported function has its result (timers,
+    /// fetches and reads of response bodies) is cancelled instead of being waited for before
+    /// returning. Pending fetches are rejected with a `CancellationError`.
+    pub cancel_background_work: bool,
+    /// Limits on the sizes of the HTTP messages of `fetch`, which can be overridden at runtime by
+    /// the `WASM_RQUICKJS_MAX_*` environment variables of the component
+    pub http_limits: HttpLimits,
+    /// Whether `fetch` keeps the cookies set by the responses (`Set-Cookie`) for the lifetime of the
+    /// component instance and sends them with the subsequent requests matching their domain and
+    /// path. Fetches with `credentials: 'omit'` neither send nor store cookies.
+    pub cookie_jar: bool,
+    /// Whether `fetch` caches the responses of GET requests in memory for the lifetime of the
+    /// component instance, honoring their `Cache-Control` and `Expires` headers and revalidating
+    /// stale responses by their `ETag` or `Last-Modified` headers. The `cache` option of a fetch
+    /// selects how it uses the cache.
+    pub http_cache: bool,
+    /// Limits on the responses kept by the HTTP cache of `fetch`, evicting the least recently used
+    /// ones when exceeded
+    pub http_cache_limits: HttpCacheLimits,
+    /// The base URL the relative URLs given to `fetch` are resolved against, which can be
+    /// overridden at runtime by the `WASM_RQUICKJS_FETCH_BASE_URL` environment variable
+    pub fetch_base_url: Option<String>,
+    /// The URL of a gateway the outgoing HTTP requests of `fetch` are sent to, keeping their path
+    /// and query and passing their original host and scheme in the `X-Forwarded-Host` and
+    /// `X-Forwarded-Proto` headers. The `WASM_RQUICKJS_FETCH_GATEWAY` and
+    /// `WASM_RQUICKJS_FETCH_GATEWAY_BYPASS` environment variables of the component take precedence.
+    /// This is not a forward proxy: the standard `HTTP_PROXY` variables are not used.
+    pub fetch_gateway: Option<String>,
+    /// Whether the JS module specifiers and TypeScript module files of imported interfaces omit
+    /// the version of the interface's package (`wasi:random/random` instead of
+    /// `wasi:random/random@0.2.3`). Interfaces imported in several versions always keep it.
+    pub unversioned_imports: bool,
+    /// Imported interfaces not exposed to JS as modules, given by their fully qualified names
+    /// (`wasi:random/random`, with or without the package version), or by their names for
+    /// anonymous interfaces
+    pub skip_imports: Vec<String>,
+    /// Exported interfaces not implemented in JS, given like `skip_imports`. Their functions are
+    /// still exported to satisfy the world, but trap when called.
+    pub skip_exports: Vec<String>,
+    /// Imported interfaces hidden from JS as a capability policy, given like `skip_imports`. Like
+    /// skipped imports, no module is generated for them, but importing them from JS throws an
+    /// error stating that they are denied. The builtin APIs of the runtime (such as `fetch` on
+    /// top of `wasi:http`) can still use them.
+    pub deny_imports: Vec<String>,
+    /// Whether to add an extra `rquickjs-invoke` export to the world, calling any of the exported
+    /// functions by name with JSON-encoded arguments and returning its JSON-encoded result
+    pub invoke_export: bool,
+    /// Whether to add an extra `rquickjs-describe` export to the world, returning a JSON
+    /// description of the component's exports, imports and enabled features
+    pub describe_export: bool,
+    /// Whether to add a `validators` JS module with a `validateX(value)` function for each WIT
+    /// type of the world, checking that a JS value converts to the type and otherwise throwing a
+    /// `TypeError` pointing to the invalid part of the value
+    pub validators: bool,
+    /// Whether to add an `enums` JS module exporting a frozen object for each WIT enum of the
+    /// world, mapping its UpperCamelCase case names to the strings representing the cases
+    pub enum_constants: bool,
+    /// Static files embedded into the component, readable from JS through the `assets` module
+    pub assets: Vec<AssetSpec>,
+    /// Names of WIT type aliases of `string` (such as `type money = string`) whose values are
+    /// represented by instances of the builtin `Decimal` class in JS instead of strings
+    pub decimal_types: Vec<String>,
+    /// Compile-time constants baked into the embedded JS modules: the `process.env.KEY` and
+    /// `__DEFINE__.KEY` expressions are replaced by the string literal of the value defined for
+    /// `KEY`, without a separate bundler pass
+    pub defines: BTreeMap<String, String>,
+    /// The cargo features enabled by default in the wrapper crate. If `None`, the skeleton's
+    /// defaults (`http` and `logging`) are kept.
+    pub default_features: Option<Vec<String>>,
+    /// Whether using JS APIs that are not available with the default features, such as `fetch`
+    /// without the `http` feature, fails the generation instead of being reported as a
+    /// [`Diagnostic::UnavailableApi`]
+    pub deny_unavailable_apis: bool,
+    /// Restricts the builtin modules the embedded JS modules can import, by the name of the JS
+    /// module. A listed JS module can only import the given builtin modules (such as `fs`, also
+    /// allowing `node:fs`), besides the other JS modules and the modules of the imported
+    /// interfaces. The JS modules not listed can import all builtin modules.
+    pub builtin_permissions: BTreeMap<String, Vec<String>>,
+    /// Whether interfaces using unsupported WIT constructs are skipped instead of failing the
+    /// generation, reported as [`Diagnostic::SkippedInterface`]s: imports are not exposed to JS,
+    /// and exports trap when called. Exports whose function signatures use types without a Rust
+    /// representation (such as `stream`) cannot be skipped.
+    pub keep_going: bool,
+    /// Whether to omit the header comments of the generated files, marking them as `@generated`
+    /// and recording the generator version, the WIT world and the hashes of the inputs
+    pub omit_generated_headers: bool,
+    /// The file format of the generated TypeScript declarations
+    pub dts_format: DtsFormat,
+    /// Whether each generated TypeScript declaration file is an ES module on its own, importing
+    /// the others by relative paths, instead of declaring an ambient module named by its module
+    /// specifier (`declare module 'wasi:http/types' { ... }`). Always the case for `.d.mts` files.
+    pub dts_module_files: bool,
+    /// Whether the global declarations of the generated TypeScript declarations (such as the
+    /// `Decimal` class) are wrapped into `declare global { ... }` in a module, instead of being
+    /// declared by a script. Always the case for `.d.mts` files.
+    pub dts_declare_global: bool,
+    /// Whether the generated TypeScript declarations refer to resource handles by branded types
+    /// (`OwnedFoo`, `BorrowedFoo`) declared next to the resource classes, telling apart the
+    /// parameters taking over the ownership of a handle from the ones borrowing it
+    pub dts_branded_handles: bool,
+    /// Where the generated TypeScript declarations of the imported interfaces are placed
+    pub dts_layout: DtsLayout,
+    /// Controls the generated Golem application manifest (`golem.yaml`)
+    pub app_manifest: AppManifestOptions,
+    /// Custom code generators run after the builtin ones, in order
+    pub backends: Vec<Arc<dyn CodegenBackend>>,
+    /// Receives the progress of the generation of wrapper crates
+    pub progress: Option<Arc<dyn ProgressReporter>>,
+}
+
+/// Options controlling the Golem application manifest (`golem.yaml`) generated into the wrapper
+/// crate.
+#[derive(Debug, Clone)]
+pub struct AppManifestOptions {
+    /// Whether to generate the `golem.yaml` file at all
+    pub enabled: bool,
+    /// The type of the Golem component. If not set, Golem's default is used.
+    pub component_type: Option<ComponentType>,
+    /// The build profiles to define. The first one is the default profile.
+    pub profiles: Vec<BuildProfile>,
+    /// YAML fragment added to the component's definition. Its keys are at the level of the
+    /// component's `profiles` key, without indentation.
+    pub component_fragment: Option<String>,
+    /// YAML fragment appended to the manifest, with top-level keys
+    pub fragment: Option<String>,
+}
+
+impl Default for AppManifestOptions {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            component_type: None,
+            profiles: vec![BuildProfile::Debug, BuildProfile::Release],
+            component_fragment: None,
+            fragment: None,
+        }
+    }
+}
+
+/// The type of a Golem component
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComponentType {
+    Durable,
+    Ephemeral,
+}
+
+impl ComponentType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ComponentType::Durable => "durable",
+            ComponentType::Ephemeral => "ephemeral",
+        }
+    }
+}
+
+/// A build profile of the generated Golem application manifest
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BuildProfile {
+    Debug,
+    Release,
+}
+
+impl BuildProfile {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BuildProfile::Debug => "debug",
+            BuildProfile::Release => "release",
+        }
+    }
+}
+
+/// Specifies how the names of exported WIT functions, interfaces and resources are mapped to the
+/// names they are looked up by in the JavaScript module.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum JsNamingPolicy {
+    /// Functions and interfaces are looked up by their camelCase name and resources by their
+    /// UpperCamelCase name, among the named exports of the JS module.
+    #[default]
+    CamelCase,
+    /// All names are kept verbatim as in WIT (kebab-case). As these are not valid JavaScript
+    /// identifiers, they are looked up as properties of the JS module's default export.
+    Verbatim,
+}
+
+/// Specifies how JS strings containing lone surrogates (UTF-16 code units not forming a valid
+/// character) are converted to WIT strings, which must be valid Unicode.
+///
+/// Passing such strings through as WTF-8 is not possible, as the component model rejects strings
+/// that are not valid UTF-8.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LoneSurrogatePolicy {
+    /// The conversion fails with an error
+    #[default]
+    Error,
+    /// Each lone surrogate is replaced by U+FFFD, like `TextEncoder` and
+    /// `String.prototype.toWellFormed` do
+    Replace,
+}
+
+/// Specifies the seed of a deterministic `Math.random`, which generates the same sequence of
+/// numbers for the same seed, for example to replay durable executions or in reproducible tests
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MathRandomSeed {
+    /// A seed fixed at generation time
+    Fixed(u64),
+    /// A seed provided by the host through `wasi:random/insecure-seed` when `Math.random` is first
+    /// called
+    Host,
+}
+
+/// Specifies the clock of `Date.now()` and `new Date()`, for replay-based durable execution
+/// engines and reproducible tests
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateClock {
+    /// A fixed time in milliseconds since the Unix epoch, which never advances
+    Fixed(i64),
+    /// The `rquickjs-clock-now` function added to the world's imports, returning the milliseconds
+    /// since the Unix epoch
+    Host,
+}
+
+/// Specifies whether the calls of the imported interfaces are recorded or replayed. Each call is
+/// recorded as a JSON line with the module name of the interface (`iface`), the JS name of the
+/// function (`fn`), the arguments (`args`) and the result (`result`) or thrown error (`error`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ImportRecording {
+    /// Every call is recorded to the given target while calling the imports as usual
+    Record(RecordTarget),
+    /// The imports are not called, the results of the calls are served in order from the
+    /// recording in the given file, embedded into the component. Lines of the file that are not
+    /// recorded calls (such as other output of the component on stderr) are skipped.
+    Replay(PathBuf),
+}
+
+/// Specifies where the recorded calls of the imported interfaces are written
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RecordTarget {
+    /// The standard error of the component
+    Stderr,
+    /// A file the calls are appended to, which must be in a directory preopened by the host
+    File(String),
+}
+
+/// Limits on the HTTP requests and responses of `fetch`, protecting the linear memory of the
+/// component from oversized payloads. Exceeding a limit makes the fetch, or the reading of the
+/// response body, fail with a `TypeError`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HttpLimits {
+    /// The maximum size of request bodies in bytes
+    pub max_request_body_size: Option<usize>,
+    /// The maximum size of response bodies in bytes, checked against the `Content-Length` of the
+    /// response and while reading the body
+    pub max_response_body_size: Option<usize>,
+    /// The maximum number of headers of requests and responses
+    pub max_header_count: Option<usize>,
+}
+
+/// Limits on the responses kept in memory by the HTTP cache of `fetch`. Responses without a
+/// `Content-Length` or with a larger body than `max_body_size` are not cached, so their bodies are
+/// streamed as usual, and the least recently used responses are evicted once there are more than
+/// `max_entries` of them or their bodies exceed `max_size` bytes. Limits set to `None` are not
+/// checked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HttpCacheLimits {
+    /// The maximum number of cached responses
+    pub max_entries: Option<usize>,
+    /// The maximum total size of the cached response bodies in bytes
+    pub max_size: Option<usize>,
+    /// The maximum size in bytes of a cached response body
+    pub max_body_size: Option<usize>,
+}
+
+impl Default for HttpCacheLimits {
+    fn default() -> Self {
+        Self {
+            max_entries: Some(256),
+            max_size: Some(16 * 1024 * 1024),
+            max_body_size: Some(1024 * 1024),
+        }
+    }
+}
+
+/// Specifies the file format of the generated TypeScript declarations
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DtsFormat {
+    /// `.d.ts` files
+    #[default]
+    Dts,
+    /// `.d.mts` files, declaring ES modules for projects using `"module": "nodenext"` or similar
+    /// settings. These files are always modules.
+    Mts,
+}
+
+impl DtsFormat {
+    /// The extension of the declaration files, including the leading dot
+    pub fn extension(&self) -> &'static str {
+        match self {
+            DtsFormat::Dts => ".d.ts",
+            DtsFormat::Mts => ".d.mts",
+        }
+    }
+
+    /// The extension the declaration files are imported by in the module specifiers of other
+    /// declaration files
+    fn import_extension(&self) -> &'static str {
+        match self {
+            DtsFormat::Dts => ".js",
+            DtsFormat::Mts => ".mjs",
+        }
     }
 }
 
+/// Specifies where the generated TypeScript declarations of the imported interfaces are placed
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DtsLayout {
+    /// Every interface is declared by a file in the output directory, named after its package and
+    /// interface (`wasi_http_0_2_3_types.d.ts`)
+    #[default]
+    Flat,
+    /// Every interface is declared by a file in a directory of its package
+    /// (`types/wasi_http_0_2_3/types.d.ts`), and an `index.d.ts` file includes all the generated
+    /// declarations, for worlds importing many interfaces
+    Nested,
+}
+
 /// Generates a Rust wrapper crate for a combination of a WIT package and a JavaScript module.
 ///
 /// The `wit` parameter should point to a WIT root (holding the WIT package of the component, with
 /// optionally a `deps` subdirectory with an arbitrary number of dependencies).
 ///
 /// The `js_modules` parameter must point to at least one JavaScript module that implements the WIT package,
-/// and optionally additional modules that get imported during the initialization of the component. It is
-/// always the first in the list that is considered the one containing the implementation of the WIT exports.
+/// and optionally additional modules that get imported during the initialization of the component. Exactly
+/// one of them must be marked as the [entry](JsModuleSpec::entry), containing the implementation of the WIT
+/// exports.
 ///
 /// The `output` parameter is the root directory where the generated Rust crate's source code and
 /// Cargo manifest is placed.
 ///
 /// If `world` is `None`, the default world is selected and used, otherwise the specified one.
+///
+/// Returns the non-fatal diagnostics found during generation.
 pub fn generate_wrapper_crate(
-    wit: &Utf8Path,
+    wit: impl AsRef<Path>,
     js_modules: &[JsModuleSpec],
-    output: &Utf8Path,
+    output: impl AsRef<Path>,
     world: Option<&str>,
-    include_cargo_config: bool,
-) -> anyhow::Result<()> {
+    options: &GeneratorOptions,
+) -> anyhow::Result<Vec<Diagnostic>> {
+    let wit = wit.as_ref();
+    let output = output.as_ref();
+    let _span = info_span!("generate_wrapper_crate", output = %output.display()).entered();
+
     // Making sure the target directories exists
     std::fs::create_dir_all(output).context("Failed to create output directory")?;
     std::fs::create_dir_all(output.join("src")).context("Failed to create output/src directory")?;
@@ -76,23 +502,47 @@ pub fn generate_wrapper_crate(
         .context("Failed to create output/src/modules directory")?;
 
     // Resolving the WIT package
-    let context = GeneratorContext::new(output, wit, world)?;
+    report_progress(options, GenerationStage::Resolve, 0, 1);
+    let (options, skipped_interfaces) = keep_going_options(output, wit, world, options)?;
+    let options = &options;
+    let context = GeneratorContext::new(output, wit, world, options)?;
+
+    // Checking that the selected world only uses supported WIT constructs
+    validation::check_skipped_interfaces(&context)?;
+    validation::check_decimal_types(&context)?;
+    validation::check_world_support(&context)?;
+    validation::check_js_modules(js_modules)?;
+    validation::check_defines(&context)?;
+    validation::check_http_urls(&context)?;
+    validation::check_builtin_permissions(&context, js_modules)?;
+    validation::check_js_pragmas(&context, js_modules)?;
+    validation::check_default_features(&context)?;
+    validation::check_api_availability(&context, js_modules)?;
+    context.set_provenance(js_modules)?;
+    report_progress(options, GenerationStage::Resolve, 1, 1);
 
     // Generating the Cargo.toml file
+    debug!("Generating Cargo.toml");
+    report_progress(options, GenerationStage::CargoToml, 0, 1);
     generate_cargo_toml(&context)?;
 
-    // Generating a Golem App Manifest file (for debugging)
+    // Generating a Golem App Manifest file (for debugging), unless disabled
     generate_app_manifest(&context)?;
 
+    report_progress(options, GenerationStage::CargoToml, 1, 1);
+
     // Copying the skeleton files
-    copy_skeleton_sources(context.output).context("Failed to copy skeleton sources")?;
+    debug!("Copying the skeleton sources");
+    report_progress(options, GenerationStage::Skeleton, 0, 1);
+    copy_skeleton_sources(&context).context("Failed to copy skeleton sources")?;
 
-    if include_cargo_config {
+    if options.include_cargo_config {
         // Copying the cargo config file, if it exists in the skeleton
-        copy_cargo_config(context.output).context("Failed to copy cargo config")?;
+        copy_cargo_config(&context).context("Failed to copy cargo config")?;
     }
 
     // Copying the WIT package to the output directory
+    debug!("Copying the WIT package");
     copy_wit_directory(wit, &context.output.join("wit"))
         .context("Failed to copy WIT package to output directory")?;
 
@@ -101,38 +551,184 @@ pub fn generate_wrapper_crate(
             .context("Failed to add get-script import to the WIT world")?;
     }
 
+    if options.date_clock == Some(DateClock::Host) {
+        add_clock_import(&context.output.join("wit"), world)
+            .context("Failed to add rquickjs-clock-now import to the WIT world")?;
+    }
+
+    if options.invoke_export {
+        add_invoke_export(&context.output.join("wit"), world)
+            .context("Failed to add rquickjs-invoke export to the WIT world")?;
+    }
+
+    if options.describe_export {
+        add_describe_export(&context.output.join("wit"), world)
+            .context("Failed to add rquickjs-describe export to the WIT world")?;
+    }
+
     // Copying the JavaScript module to the output directory
-    copy_js_modules(js_modules, context.output)
+    debug!("Copying the JavaScript modules");
+    copy_js_modules(js_modules, &options.defines, context.output)
         .context("Failed to copy JavaScript module to output directory")?;
 
+    // Copying the assets and generating the assets.rs file listing them
+    generate_assets(&context).context("Failed to embed the assets")?;
+    copy_import_recording(&context)?;
+    report_progress(options, GenerationStage::Skeleton, 1, 1);
+
     // Generating the lib.rs file implementing the component exports
+    debug!("Generating the export implementations");
+    let exports = context.exported_items_count();
+    report_progress(options, GenerationStage::Exports, 0, exports);
     generate_export_impls(&context, js_modules)
         .context("Failed to generate the component export implementations")?;
+    report_progress(options, GenerationStage::Exports, exports, exports);
 
     // Generating the native modules implementing the component imports
+    debug!("Generating the import modules");
     generate_import_modules(&context).context("Failed to generate the component import modules")?;
 
     // Generating the conversions.rs file implementing the IntoJs and FromJs typeclass instances
     // This step must be done after `generate_export_impls` to ensure all visited types are registered.
+    debug!(
+        types = context.visited_types.borrow().len(),
+        "Generating the type conversions"
+    );
     generate_conversions(&context)
         .context("Failed to generate the IntoJs and FromJs typeclass instances")?;
 
-    Ok(())
+    // Running the custom code generators
+    for backend in &options.backends {
+        debug!(backend = backend.name(), "Running custom code generator");
+        backend
+            .generate(context.model()?, context.output)
+            .with_context(|| format!("Failed to run the {} code generator", backend.name()))?;
+    }
+
+    let mut diagnostics = skipped_interfaces;
+    diagnostics.extend(diagnostics::collect_diagnostics(
+        &context,
+        Some(js_modules),
+    )?);
+    Ok(diagnostics)
+}
+
+fn report_progress(
+    options: &GeneratorOptions,
+    stage: GenerationStage,
+    completed: usize,
+    total: usize,
+) {
+    if let Some(reporter) = &options.progress {
+        reporter.progress(stage, completed, total);
+    }
+}
+
+/// Gets the options to generate with. In `keep_going` mode, the interfaces using unsupported WIT
+/// constructs are added to the skipped ones, and returned as diagnostics.
+fn keep_going_options(
+    output: &Path,
+    wit: &Path,
+    world: Option<&str>,
+    options: &GeneratorOptions,
+) -> anyhow::Result<(GeneratorOptions, Vec<Diagnostic>)> {
+    let mut options = options.clone();
+    if !options.keep_going {
+        return Ok((options, Vec::new()));
+    }
+
+    let context = GeneratorContext::new(output, wit, world, &options)?;
+    let skipped = validation::unsupported_interfaces(&context);
+    drop(context);
+    for diagnostic in &skipped {
+        debug!("Skipping in keep-going mode: {diagnostic}");
+        if let Diagnostic::SkippedInterface {
+            interface,
+            exported,
+            ..
+        } = diagnostic
+        {
+            let selectors = if *exported {
+                &mut options.skip_exports
+            } else {
+                &mut options.skip_imports
+            };
+            selectors.push(interface.clone());
+        }
+    }
+    Ok((options, skipped))
+}
+
+/// Generates a wrapper crate for a (or the default) world of a WIT package, that instead of
+/// implementing the world's exports, exports a `repl-eval` function evaluating arbitrary
+/// JavaScript code in the component, with the world's imports available as modules.
+///
+/// The world's own exports are still present in the generated crate, but they are not
+/// implemented and fail when called.
+pub fn generate_repl_wrapper_crate(
+    wit: impl AsRef<Path>,
+    output: impl AsRef<Path>,
+    world: Option<&str>,
+    options: &GeneratorOptions,
+) -> anyhow::Result<Vec<Diagnostic>> {
+    let output = output.as_ref();
+    std::fs::create_dir_all(output).context("Failed to create output directory")?;
+
+    let repl_wit = output.join("repl-wit");
+    copy_wit_directory(wit.as_ref(), &repl_wit)
+        .context("Failed to copy WIT package for the REPL")?;
+    add_repl_export(&repl_wit, world).context("Failed to add repl-eval export to the WIT world")?;
+
+    let repl_js = output.join("repl.js");
+    std::fs::write(&repl_js, REPL_MODULE).context("Failed to write the REPL module")?;
+
+    generate_wrapper_crate(
+        &repl_wit,
+        &[JsModuleSpec {
+            name: "repl".to_string(),
+            mode: EmbeddingMode::EmbedFile(repl_js),
+            entry: true,
+        }],
+        output,
+        world,
+        options,
+    )
+}
+
+/// The result of [`generate_dts`]
+#[derive(Debug, Clone)]
+pub struct GeneratedDts {
+    /// The generated `.d.ts` files
+    pub files: Vec<PathBuf>,
+    /// The non-fatal diagnostics found during generation
+    pub diagnostics: Vec<Diagnostic>,
 }
 
 /// Generates TypeScript module definitions for a given (or default) world of a WIT package.
 ///
-/// Returns the list of generated files.
+/// Returns the list of generated files and the non-fatal diagnostics.
 pub fn generate_dts(
-    wit: &Utf8Path,
-    output: &Utf8Path,
+    wit: impl AsRef<Path>,
+    output: impl AsRef<Path>,
     world: Option<&str>,
-) -> anyhow::Result<Vec<Utf8PathBuf>> {
+    options: &GeneratorOptions,
+) -> anyhow::Result<GeneratedDts> {
+    let wit = wit.as_ref();
+    let output = output.as_ref();
+    let _span = info_span!("generate_dts", output = %output.display()).entered();
+
     // Making sure the target directories exist
     std::fs::create_dir_all(output).context("Failed to create output directory")?;
 
     // Resolving the WIT package
-    let context = GeneratorContext::new(output, wit, world)?;
+    let (options, skipped_interfaces) = keep_going_options(output, wit, world, options)?;
+    let context = GeneratorContext::new(output, wit, world, &options)?;
+
+    // Checking that the selected world only uses supported WIT constructs
+    validation::check_skipped_interfaces(&context)?;
+    validation::check_decimal_types(&context)?;
+    validation::check_world_support(&context)?;
+    context.set_provenance(&[])?;
 
     let mut result = Vec::new();
     result.extend(
@@ -145,12 +741,24 @@ pub fn generate_dts(
         "Failed to generate the TypeScript module definitions for the imported modules",
     )?);
 
-    Ok(result)
+    if options.dts_layout == DtsLayout::Nested {
+        result.push(
+            typescript::generate_index(&context, &result)
+                .context("Failed to generate the TypeScript index")?,
+        );
+    }
+
+    let mut diagnostics = skipped_interfaces;
+    diagnostics.extend(diagnostics::collect_diagnostics(&context, None)?);
+    Ok(GeneratedDts {
+        files: result,
+        diagnostics,
+    })
 }
 
 struct GeneratorContext<'a> {
-    output: &'a Utf8Path,
-    wit_source_path: &'a Utf8Path,
+    output: &'a Path,
+    wit_source_path: &'a Path,
     resolve: Resolve,
     root_package: PackageId,
     world: WorldId,
@@ -158,19 +766,35 @@ struct GeneratorContext<'a> {
     visited_types: RefCell<BTreeSet<TypeId>>,
     world_name: String,
     types: wit_bindgen_core::Types,
+    options: &'a GeneratorOptions,
+    model: OnceCell<WorldModel>,
+    /// Paths of the exported JS functions in the JS module, by their export index
+    export_paths: RefCell<Vec<Vec<String>>>,
+    /// The provenance written into the headers of the generated files, once the inputs are known
+    provenance: OnceCell<Provenance>,
 }
 
 impl<'a> GeneratorContext<'a> {
-    fn new(output: &'a Utf8Path, wit: &'a Utf8Path, world: Option<&str>) -> anyhow::Result<Self> {
+    fn new(
+        output: &'a Path,
+        wit: &'a Path,
+        world: Option<&str>,
+        options: &'a GeneratorOptions,
+    ) -> anyhow::Result<Self> {
+        debug!(wit = %wit.display(), "Resolving the WIT package");
         let mut resolve = Resolve::default();
         let (root_package, source_map) = resolve
             .push_path(wit)
             .context("Failed to resolve WIT package")?;
-        let world = resolve
-            .select_world(root_package, world)
-            .context("Failed to select WIT world")?;
+        let world = wit::select_world(&resolve, root_package, world)?;
 
         let world_name = resolve.worlds[world].name.clone();
+        debug!(
+            package = %resolve.packages[root_package].name,
+            packages = resolve.packages.len(),
+            world = world_name,
+            "Resolved the WIT package"
+        );
 
         let mut types = wit_bindgen_core::Types::default();
         types.analyze(&resolve);
@@ -185,9 +809,68 @@ impl<'a> GeneratorContext<'a> {
             visited_types: RefCell::new(BTreeSet::new()),
             world_name,
             types,
+            options,
+            model: OnceCell::new(),
+            export_paths: RefCell::new(Vec::new()),
+            provenance: OnceCell::new(),
         })
     }
 
+    /// Gets the analyzed model of the world, building it on first use
+    fn model(&self) -> anyhow::Result<&WorldModel> {
+        if let Some(model) = self.model.get() {
+            return Ok(model);
+        }
+        let model = model::build_world_model(self)?;
+        Ok(self.model.get_or_init(|| model))
+    }
+
+    /// Records the inputs of the generation for the headers of the generated files
+    fn set_provenance(&self, js_modules: &[JsModuleSpec]) -> anyhow::Result<()> {
+        let provenance = Provenance::new(self, js_modules)?;
+        let _ = self.provenance.set(provenance);
+        Ok(())
+    }
+
+    /// Writes a generated file, starting with a header comment marking it as generated and
+    /// describing its provenance, unless disabled in the options or the file cannot have comments
+    fn write_generated(&self, path: &Path, contents: impl AsRef<[u8]>) -> std::io::Result<()> {
+        let header = match (
+            self.options.omit_generated_headers,
+            self.provenance.get(),
+            comment_syntax(path),
+        ) {
+            (false, Some(provenance), Some(comment)) => provenance.header(comment),
+            _ => String::new(),
+        };
+        let mut bytes = header.into_bytes();
+        bytes.extend_from_slice(contents.as_ref());
+        trace!(path = %path.display(), bytes = bytes.len(), "Writing generated file");
+        std::fs::write(path, bytes)
+    }
+
+    /// Counts the exported interfaces and functions of the selected world
+    fn exported_items_count(&self) -> usize {
+        self.resolve.worlds[self.world]
+            .exports
+            .values()
+            .filter(|item| !matches!(item, WorldItem::Type(_)))
+            .count()
+    }
+
+    /// Gets the index of an exported JS function, given by its path in the JS module, which the
+    /// generated code uses to cache the function after looking it up on the first call
+    fn export_index(&self, js_path: &[String]) -> usize {
+        let mut export_paths = self.export_paths.borrow_mut();
+        match export_paths.iter().position(|path| path == js_path) {
+            Some(index) => index,
+            None => {
+                export_paths.push(js_path.to_vec());
+                export_paths.len() - 1
+            }
+        }
+    }
+
     fn root_package_name(&self) -> String {
         self.resolve.packages[self.root_package].name.to_string()
     }
@@ -230,16 +913,37 @@ impl<'a> GeneratorContext<'a> {
         self.types.get(type_id)
     }
 
+    /// Gets the name of an interface. Anonymous (inline) interfaces are named by their key in
+    /// the world.
+    fn interface_name(&self, interface_id: InterfaceId) -> anyhow::Result<&str> {
+        let interface = &self.resolve.interfaces[interface_id];
+        match &interface.name {
+            Some(name) => Ok(name.as_str()),
+            None => {
+                let world = &self.resolve.worlds[self.world];
+                world
+                    .imports
+                    .iter()
+                    .chain(world.exports.iter())
+                    .find_map(|(key, item)| match (key, item) {
+                        (WorldKey::Name(name), WorldItem::Interface { id, .. })
+                            if id == &interface_id =>
+                        {
+                            Some(name.as_str())
+                        }
+                        _ => None,
+                    })
+                    .ok_or_else(|| anyhow!("Could not find the name of anonymous interface"))
+            }
+        }
+    }
+
     fn get_imported_interface(
         &self,
         interface_id: &InterfaceId,
     ) -> anyhow::Result<ImportedInterface<'_>> {
         let interface = &self.resolve.interfaces[*interface_id];
-        let name = interface
-            .name
-            .as_ref()
-            .ok_or_else(|| anyhow!("Interface import does not have a name"))?
-            .as_str();
+        let name = self.interface_name(*interface_id)?;
 
         let functions = interface
             .functions
@@ -249,7 +953,7 @@ impl<'a> GeneratorContext<'a> {
 
         let package_id = interface
             .package
-            .ok_or_else(|| anyhow!("Anonymous interface imports are not supported yet"))?;
+            .ok_or_else(|| anyhow!("Could not find package of imported interface {name}"))?;
         let package = self
             .resolve
             .packages
@@ -257,8 +961,17 @@ impl<'a> GeneratorContext<'a> {
             .ok_or_else(|| anyhow!("Could not find package of imported interface {name}"))?;
         let package_name = &package.name;
 
+        // Anonymous interfaces are defined inline in the world, so the world's name is
+        // used to qualify their module names
+        let world_name = interface.name.is_none().then_some(self.world_name.as_str());
+
+        let include_version = !self.options.unversioned_imports
+            || self.is_imported_in_multiple_versions(package_name, name);
+
         Ok(ImportedInterface {
             package_name: Some(package_name),
+            include_version,
+            world_name,
             name: name.to_string(),
             functions,
             interface: Some(interface),
@@ -266,6 +979,62 @@ impl<'a> GeneratorContext<'a> {
         })
     }
 
+    /// Checks whether a type is an alias of `string` mapped to the builtin `Decimal` JS class
+    fn is_decimal_type(&self, typ: &TypeDef) -> bool {
+        matches!(typ.kind, TypeDefKind::Type(Type::String))
+            && typ
+                .name
+                .as_ref()
+                .is_some_and(|name| self.options.decimal_types.contains(name))
+    }
+
+    /// Checks whether an imported interface is excluded from the JS modules
+    fn is_skipped_import(&self, key: &WorldKey) -> bool {
+        self.matches_interface_selector(key, &self.options.skip_imports)
+            || self.is_denied_import(key)
+    }
+
+    fn is_denied_import(&self, key: &WorldKey) -> bool {
+        self.matches_interface_selector(key, &self.options.deny_imports)
+    }
+
+    /// Checks whether an exported interface is excluded from the JS implementation
+    fn is_skipped_export(&self, key: &WorldKey) -> bool {
+        self.matches_interface_selector(key, &self.options.skip_exports)
+    }
+
+    /// Checks whether a world key matches one of the interface names given in the options, which
+    /// may omit the package version
+    fn matches_interface_selector(&self, key: &WorldKey, selectors: &[String]) -> bool {
+        let name = self.resolve.name_world_key(key);
+        let unversioned_name = name.split_once('@').map_or(name.as_str(), |(name, _)| name);
+        selectors
+            .iter()
+            .any(|selector| selector == &name || selector == unversioned_name)
+    }
+
+    /// Checks whether the world imports an interface of the given name from more than one version
+    /// of the package
+    fn is_imported_in_multiple_versions(&self, package_name: &PackageName, name: &str) -> bool {
+        let world = &self.resolve.worlds[self.world];
+        let versions: BTreeSet<_> = world
+            .imports
+            .values()
+            .filter_map(|item| match item {
+                WorldItem::Interface { id, .. } => Some(&self.resolve.interfaces[*id]),
+                _ => None,
+            })
+            .filter(|interface| interface.name.as_deref() == Some(name))
+            .filter_map(|interface| interface.package)
+            .map(|package_id| &self.resolve.packages[package_id].name)
+            .filter(|other| {
+                other.namespace == package_name.namespace && other.name == package_name.name
+            })
+            .map(|other| &other.version)
+            .collect();
+        versions.len() > 1
+    }
+
     fn typ(&self, type_id: TypeId) -> anyhow::Result<&TypeDef> {
         self.resolve
             .types
@@ -276,6 +1045,10 @@ impl<'a> GeneratorContext<'a> {
 
 pub struct ImportedInterface<'a> {
     package_name: Option<&'a PackageName>,
+    /// Whether the package version is part of the module's names
+    include_version: bool,
+    /// Name of the world defining the interface, if it is an anonymous (inline) interface import
+    world_name: Option<&'a str>,
     name: String,
     functions: Vec<(&'a str, &'a Function)>,
     interface: Option<&'a Interface>,
@@ -285,15 +1058,37 @@ pub struct ImportedInterface<'a> {
 impl<'a> ImportedInterface<'a> {
     pub fn module_name(&self) -> anyhow::Result<String> {
         let package_name = self
-            .package_name
+            .named_package()
             .ok_or_else(|| anyhow!("imported interface has no package name"))?;
         let interface_name = &self.name;
 
-        Ok(format!(
-            "{}_{}",
-            package_name.to_string().to_snake_case(),
-            interface_name.to_snake_case()
-        ))
+        match self.world_name {
+            Some(world_name) => Ok(format!(
+                "{}_{}_{}",
+                package_name.to_string().to_snake_case(),
+                world_name.to_snake_case(),
+                interface_name.to_snake_case()
+            )),
+            None => Ok(format!(
+                "{}_{}",
+                package_name.to_string().to_snake_case(),
+                interface_name.to_snake_case()
+            )),
+        }
+    }
+
+    /// The path of the module relative to the directory of its package's modules, without
+    /// extension (`wasi_http_0_2_3/types`)
+    pub fn module_path(&self) -> anyhow::Result<Utf8PathBuf> {
+        let package_name = self
+            .named_package()
+            .ok_or_else(|| anyhow!("imported interface has no package name"))?;
+        let mut path = Utf8PathBuf::from(package_name.to_string().to_snake_case());
+        if let Some(world_name) = self.world_name {
+            path.push(world_name.to_snake_case());
+        }
+        path.push(self.name.to_snake_case());
+        Ok(path)
     }
 
     pub fn rust_interface_name(&self) -> Ident {
@@ -307,20 +1102,36 @@ impl<'a> ImportedInterface<'a> {
     }
 
     pub fn fully_qualified_interface_name(&self) -> String {
-        if let Some(package_name) = &self.package_name {
-            package_name.interface_id(&self.name)
+        if let Some(package_name) = self.named_package() {
+            match self.world_name {
+                Some(world_name) => {
+                    package_name.interface_id(&format!("{world_name}/{}", self.name))
+                }
+                None => package_name.interface_id(&self.name),
+            }
         } else {
             self.name.clone()
         }
     }
 
+    /// The package name the module is named after, with or without its version
+    fn named_package(&self) -> Option<PackageName> {
+        self.package_name.map(|package_name| {
+            let mut package_name = package_name.clone();
+            if !self.include_version {
+                package_name.version = None;
+            }
+            package_name
+        })
+    }
+
     pub fn interface_stack(&self) -> VecDeque<InterfaceId> {
         self.interface_id.iter().cloned().collect()
     }
 }
 
 /// Recursively copies a WIT directory to `<output>/wit`.
-fn copy_wit_directory(wit: &Utf8Path, output: &Utf8Path) -> anyhow::Result<()> {
+fn copy_wit_directory(wit: &Path, output: &Path) -> anyhow::Result<()> {
     fs_extra::dir::create(output, true)
         .context("Failed to create and erase output WIT directory")?;
     fs_extra::dir::copy(wit, output, &CopyOptions::new().content_only(true))
@@ -329,14 +1140,29 @@ fn copy_wit_directory(wit: &Utf8Path, output: &Utf8Path) -> anyhow::Result<()> {
     Ok(())
 }
 
-/// Copies the JS module files to `<output>/src/<name>.js`.
-fn copy_js_modules(js_modules: &[JsModuleSpec], output: &Utf8Path) -> anyhow::Result<()> {
+/// Copies the JS module files to `<output>/src/<name>.js`, applying the defines. Modules with
+/// `// #if feature(name)` pragmas are written as one file per combination of the features.
+fn copy_js_modules(
+    js_modules: &[JsModuleSpec],
+    defines: &BTreeMap<String, String>,
+    output: &Path,
+) -> anyhow::Result<()> {
     for module in js_modules {
         if let EmbeddingMode::EmbedFile(source) = &module.mode {
-            let filename = module.file_name();
-            let js_dest = output.join("src").join(filename);
-            std::fs::copy(source, js_dest)
-                .context(format!("Failed to copy JavaScript module {}", module.name))?;
+            let js = std::fs::read_to_string(source)
+                .context(format!("Failed to read JavaScript module {}", module.name))?;
+            let js = defines::apply_defines(&js, defines);
+            for variant in pragmas::module_variants(module, &js)? {
+                let js_dest = output.join("src").join(&variant.file_name);
+                if let Some(parent) = js_dest.parent() {
+                    std::fs::create_dir_all(parent).with_context(|| {
+                        format!("Failed to create directory {}", parent.display())
+                    })?;
+                }
+                trace!(module = module.name, path = %js_dest.display(), "Writing JavaScript module");
+                std::fs::write(js_dest, variant.source)
+                    .context(format!("Failed to write JavaScript module {}", module.name))?;
+            }
         }
     }
     Ok(())
@@ -0,0 +1,96 @@
+/**
+ * Chat completions with streaming, built on top of the Golem LLM interface.
+ */
+declare module 'llm' {
+  import type { ContentPart, FinishReason, ResponseMetadata, Role, Usage } from '__GOLEM_LLM_MODULE__';
+  /**
+   * Thrown when the provider fails, with the provider's error code and message.
+   */
+  export class LlmError extends Error {
+    readonly code: string;
+    readonly providerError: string | undefined;
+  }
+  /**
+   * A part of the content of a message. Strings are text parts.
+   */
+  export type Part =
+    string |
+    { type: 'text', text: string } |
+    { type: 'image', url: string, detail?: 'low' | 'high' | 'auto' } |
+    { type: 'image', data: Uint8Array, mimeType: string, detail?: 'low' | 'high' | 'auto' } |
+    ContentPart;
+  export interface Message {
+    role: Role;
+    name?: string;
+    content: string | Part[];
+  }
+  export interface Tool {
+    name: string;
+    description?: string;
+    /**
+     * The JSON schema of the tool's arguments, as an object or as a JSON string.
+     */
+    parameters?: object | string;
+  }
+  export interface ChatOptions {
+    model: string;
+    temperature?: number;
+    maxTokens?: number;
+    stopSequences?: string[];
+    tools?: Tool[];
+    toolChoice?: string;
+    providerOptions?: Record<string, string | number | boolean>;
+  }
+  export interface ToolCall {
+    id: string;
+    name: string;
+    /**
+     * The arguments parsed from JSON, or the raw string if they are not valid JSON.
+     */
+    arguments: unknown;
+  }
+  export interface ToolResult {
+    toolCall: ToolCall;
+    /**
+     * The result of the tool, sent as JSON.
+     */
+    result?: unknown;
+    /**
+     * The error of the tool, if it failed.
+     */
+    error?: unknown;
+  }
+  export interface ChatResponse {
+    id?: string;
+    /**
+     * The text parts of the content, concatenated.
+     */
+    text: string;
+    content: ContentPart[];
+    toolCalls: ToolCall[];
+    finishReason?: FinishReason;
+    usage?: Usage;
+    metadata?: ResponseMetadata;
+  }
+  export type StreamEvent =
+    { type: 'text', text: string } |
+    { type: 'image', image: unknown } |
+    { type: 'tool-call', toolCall: ToolCall } |
+    { type: 'finish', finishReason?: FinishReason, usage?: Usage, metadata: ResponseMetadata };
+  /**
+   * Sends the messages and waits for the complete response.
+   */
+  export function chat(messages: Message[], options: ChatOptions): Promise<ChatResponse>;
+  /**
+   * Continues a chat after the tools requested by the model were run.
+   */
+  export function continueChat(messages: Message[], toolResults: ToolResult[], options: ChatOptions): Promise<ChatResponse>;
+  /**
+   * Sends the messages and iterates over the events of the streamed response.
+   */
+  export function stream(messages: Message[], options: ChatOptions): AsyncIterableIterator<StreamEvent>;
+  /**
+   * Sends the messages and iterates over the text of the streamed response.
+   */
+  export function streamText(messages: Message[], options: ChatOptions): AsyncIterableIterator<string>;
+}
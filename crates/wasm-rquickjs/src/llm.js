@@ -0,0 +1,160 @@
+import * as llm from '__GOLEM_LLM_MODULE__';
+
+export class LlmError extends Error {
+    constructor(error) {
+        super(error.message);
+        this.name = 'LlmError';
+        this.code = error.code;
+        this.providerError = error.providerErrorJson;
+    }
+}
+
+function parseJson(text) {
+    try {
+        return JSON.parse(text);
+    } catch {
+        return text;
+    }
+}
+
+function toContentPart(part) {
+    if (typeof part === 'string') {
+        return { tag: 'text', val: part };
+    }
+    switch (part.type) {
+        case 'text':
+            return { tag: 'text', val: part.text };
+        case 'image':
+            if (part.url !== undefined) {
+                return { tag: 'image', val: { tag: 'url', val: { url: part.url, detail: part.detail } } };
+            }
+            return {
+                tag: 'image',
+                val: { tag: 'inline', val: { data: part.data, mimeType: part.mimeType, detail: part.detail } },
+            };
+        default:
+            // Already in the form of the host interface
+            return part;
+    }
+}
+
+function toMessage(message) {
+    const content = typeof message.content === 'string' ? [message.content] : message.content;
+    return {
+        role: message.role,
+        name: message.name,
+        content: content.map(toContentPart),
+    };
+}
+
+function toConfig(options) {
+    return {
+        model: options.model,
+        temperature: options.temperature,
+        maxTokens: options.maxTokens,
+        stopSequences: options.stopSequences,
+        tools: (options.tools ?? []).map((tool) => ({
+            name: tool.name,
+            description: tool.description,
+            parametersSchema: typeof tool.parameters === 'string' ? tool.parameters : JSON.stringify(tool.parameters ?? {}),
+        })),
+        toolChoice: options.toolChoice,
+        providerOptions: Object.entries(options.providerOptions ?? {}).map(([key, value]) => ({ key, value: String(value) })),
+    };
+}
+
+function fromToolCall(toolCall) {
+    return { id: toolCall.id, name: toolCall.name, arguments: parseJson(toolCall.argumentsJson) };
+}
+
+function toToolCall(toolCall) {
+    return { id: toolCall.id, name: toolCall.name, argumentsJson: JSON.stringify(toolCall.arguments) };
+}
+
+function toToolResult({ toolCall, result, error }) {
+    if (error !== undefined) {
+        return [toToolCall(toolCall), {
+            tag: 'error',
+            val: { id: toolCall.id, name: toolCall.name, errorMessage: String(error?.message ?? error) },
+        }];
+    }
+    return [toToolCall(toolCall), {
+        tag: 'success',
+        val: { id: toolCall.id, name: toolCall.name, resultJson: JSON.stringify(result) },
+    }];
+}
+
+function textOf(content) {
+    return content.filter((part) => part.tag === 'text').map((part) => part.val).join('');
+}
+
+function fromChatEvent(event) {
+    switch (event.tag) {
+        case 'message':
+            return {
+                id: event.val.id,
+                text: textOf(event.val.content),
+                content: event.val.content,
+                toolCalls: event.val.toolCalls.map(fromToolCall),
+                finishReason: event.val.metadata.finishReason,
+                usage: event.val.metadata.usage,
+                metadata: event.val.metadata,
+            };
+        case 'tool-request':
+            return {
+                text: '',
+                content: [],
+                toolCalls: event.val.map(fromToolCall),
+                finishReason: 'tool-calls',
+            };
+        default:
+            throw new LlmError(event.val);
+    }
+}
+
+export async function chat(messages, options) {
+    return fromChatEvent(llm.send(messages.map(toMessage), toConfig(options)));
+}
+
+export async function continueChat(messages, toolResults, options) {
+    return fromChatEvent(llm.continue_(messages.map(toMessage), toolResults.map(toToolResult), toConfig(options)));
+}
+
+export async function* stream(messages, options) {
+    const chatStream = llm.stream(messages.map(toMessage), toConfig(options));
+    try {
+        for (;;) {
+            for (const event of chatStream.blockingGetNext()) {
+                switch (event.tag) {
+                    case 'delta':
+                        for (const part of event.val.content ?? []) {
+                            if (part.tag === 'text') {
+                                yield { type: 'text', text: part.val };
+                            } else {
+                                yield { type: 'image', image: part.val };
+                            }
+                        }
+                        for (const toolCall of event.val.toolCalls ?? []) {
+                            yield { type: 'tool-call', toolCall: fromToolCall(toolCall) };
+                        }
+                        break;
+                    case 'finish':
+                        yield { type: 'finish', finishReason: event.val.finishReason, usage: event.val.usage, metadata: event.val };
+                        return;
+                    case 'error':
+                        throw new LlmError(event.val);
+                }
+            }
+        }
+    } finally {
+        chatStream[Symbol.dispose]();
+    }
+}
+
+export async function* streamText(messages, options) {
+    for await (const event of stream(messages, options)) {
+        if (event.type === 'text') {
+            yield event.text;
+        }
+    }
+}
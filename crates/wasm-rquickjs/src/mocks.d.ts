@@ -0,0 +1,25 @@
+/**
+ * Mocks of the imported interfaces, used instead of the host when the wrapper crate is built with
+ * the `mock-imports` feature. Interfaces are given by their module names, and functions by their
+ * JS names: `Class.method` for the static functions of resources, and `new Class` for their
+ * constructors.
+ */
+declare module '__mocks' {
+  /**
+   * Sets the implementation of an imported function. Calling a function without a mock throws an
+   * error.
+   */
+  export function mock(iface: string, fn: string, implementation: (...args: any[]) => any): void;
+  /**
+   * Sets the implementations of several functions of an imported interface, by their names.
+   */
+  export function mockModule(iface: string, implementations: Record<string, (...args: any[]) => any>): void;
+  /**
+   * Gets the arguments of the calls of a mocked function, in order.
+   */
+  export function calls(iface: string, fn: string): unknown[][];
+  /**
+   * Removes the mocks and the recorded calls of an interface, or of all interfaces.
+   */
+  export function resetMocks(iface?: string): void;
+}
@@ -0,0 +1,361 @@
+use crate::handlers::{handler_function_name, is_handler_interface};
+use crate::javascript::escape_js_ident;
+use crate::rust_bindgen::escape_rust_ident;
+use crate::types::get_function_name;
+use crate::typescript::ts_type_reference;
+use crate::{GeneratorContext, GeneratorOptions, validation};
+use anyhow::anyhow;
+use heck::{ToLowerCamelCase, ToUpperCamelCase};
+use std::collections::VecDeque;
+use std::path::Path;
+use wit_parser::{Function, FunctionKind, Interface, InterfaceId, TypeDefKind, TypeId, WorldItem};
+
+/// The analyzed model of a WIT world: its imported and exported interfaces, functions and types,
+/// with the names they get in JavaScript, in the generated Rust code and in the TypeScript
+/// declarations.
+///
+/// It is built once from the resolved WIT world and used by the code generators, and can be
+/// obtained with [`analyze_world`] by tools (documentation generators, linters) that want to
+/// reuse the analysis.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorldModel {
+    /// Name of the world
+    pub name: String,
+    /// Name of the package defining the world
+    pub package: String,
+    /// The imported interfaces, including the skipped ones
+    pub imports: Vec<InterfaceModel>,
+    /// The exported interfaces, including the skipped ones
+    pub exports: Vec<InterfaceModel>,
+    /// Functions imported directly into the world, available from the `world-imports` module
+    pub imported_functions: Vec<FunctionModel>,
+    /// Functions exported directly from the world
+    pub exported_functions: Vec<FunctionModel>,
+}
+
+/// An imported or exported interface of a [`WorldModel`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InterfaceModel {
+    /// Fully qualified name of the interface (`wasi:random/random@0.2.3`), or its key in the world
+    /// for exported anonymous interfaces
+    pub wit_name: String,
+    /// For imports the name of the JS module exposing the interface, for exports the name of the
+    /// JS object implementing it
+    pub js_name: String,
+    /// Whether the interface was skipped with the `skip_imports` or `skip_exports` options. The
+    /// functions and types of skipped imports are not listed, as they may use unsupported WIT
+    /// constructs.
+    pub skipped: bool,
+    /// Whether the exported interface is a well-known handler interface (such as
+    /// `wasi:messaging/incoming-handler`), whose functions are implemented by top-level JS
+    /// functions instead of the functions of the `js_name` object
+    pub handler: bool,
+    pub functions: Vec<FunctionModel>,
+    pub types: Vec<TypeModel>,
+}
+
+/// A function (or resource method) of a [`WorldModel`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FunctionModel {
+    /// Name of the function (or method) in WIT
+    pub wit_name: String,
+    pub kind: FunctionModelKind,
+    /// Name of the function in JavaScript. For constructors, it is the name of the class.
+    pub js_name: String,
+    /// Name of the function in the generated Rust bindings
+    pub rust_name: String,
+    pub params: Vec<ParameterModel>,
+    /// TypeScript type of the function's result, if it has one
+    pub ts_result: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FunctionModelKind {
+    Freestanding,
+    Constructor { resource: String },
+    Method { resource: String },
+    Static { resource: String },
+}
+
+/// A parameter of a [`FunctionModel`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParameterModel {
+    pub wit_name: String,
+    pub js_name: String,
+    pub ts_type: String,
+}
+
+/// A type defined in an interface of a [`WorldModel`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeModel {
+    pub wit_name: String,
+    pub kind: TypeModelKind,
+    /// Name of the type in the TypeScript declarations and the generated Rust code
+    pub ts_name: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TypeModelKind {
+    Record {
+        fields: Vec<FieldModel>,
+    },
+    Variant {
+        cases: Vec<String>,
+    },
+    Enum {
+        cases: Vec<String>,
+    },
+    Flags {
+        flags: Vec<FieldModel>,
+    },
+    Resource,
+    /// Any other type, including aliases of types defined elsewhere
+    Other,
+}
+
+/// A record field or flag, which become properties of JS objects
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldModel {
+    pub wit_name: String,
+    pub js_name: String,
+}
+
+/// Resolves a WIT package and analyzes the selected (or default) world, returning the model the
+/// code generators work from.
+pub fn analyze_world(
+    wit: impl AsRef<Path>,
+    world: Option<&str>,
+    options: &GeneratorOptions,
+) -> anyhow::Result<WorldModel> {
+    let context = GeneratorContext::new(Path::new(""), wit.as_ref(), world, options)?;
+
+    validation::check_skipped_interfaces(&context)?;
+    validation::check_decimal_types(&context)?;
+    validation::check_world_support(&context)?;
+
+    build_world_model(&context)
+}
+
+/// Builds the model of the context's world
+pub fn build_world_model(context: &GeneratorContext<'_>) -> anyhow::Result<WorldModel> {
+    let world = &context.resolve.worlds[context.world];
+    let package = world
+        .package
+        .map(|package| context.resolve.packages[package].name.to_string())
+        .unwrap_or_default();
+
+    let naming = context.options.export_naming;
+
+    let mut imports = Vec::new();
+    let mut imported_functions = Vec::new();
+    for (key, item) in &world.imports {
+        match item {
+            WorldItem::Interface { id, .. } => {
+                let interface = &context.resolve.interfaces[*id];
+                let imported = context.get_imported_interface(id)?;
+                let wit_name = imported.fully_qualified_interface_name();
+                let stack = VecDeque::from([*id]);
+                let skipped = context.is_skipped_import(key);
+                if skipped {
+                    imports.push(InterfaceModel {
+                        js_name: wit_name.clone(),
+                        wit_name,
+                        skipped,
+                        handler: false,
+                        functions: Vec::new(),
+                        types: Vec::new(),
+                    });
+                    continue;
+                }
+                imports.push(InterfaceModel {
+                    js_name: wit_name.clone(),
+                    wit_name,
+                    skipped,
+                    handler: false,
+                    functions: interface
+                        .functions
+                        .iter()
+                        .map(|(name, function)| {
+                            let js_name = |name: &str| match &function.kind {
+                                FunctionKind::Freestanding => {
+                                    escape_js_ident(name.to_lower_camel_case())
+                                }
+                                _ => name.to_lower_camel_case(),
+                            };
+                            function_model(context, name, function, &stack, js_name)
+                        })
+                        .collect::<anyhow::Result<_>>()?,
+                    types: interface_types(context, interface)?,
+                });
+            }
+            WorldItem::Function(function) => {
+                imported_functions.push(function_model(
+                    context,
+                    &function.name,
+                    function,
+                    &VecDeque::new(),
+                    |name| escape_js_ident(name.to_lower_camel_case()),
+                )?);
+            }
+            WorldItem::Type(_) => {}
+        }
+    }
+
+    let mut exports = Vec::new();
+    let mut exported_functions = Vec::new();
+    for (key, item) in &world.exports {
+        match item {
+            WorldItem::Interface { id, .. } => {
+                let interface = &context.resolve.interfaces[*id];
+                let name = context.interface_name(*id)?;
+                let stack = VecDeque::from([*id]);
+                exports.push(InterfaceModel {
+                    wit_name: context.resolve.name_world_key(key),
+                    js_name: naming.interface_name(name),
+                    skipped: context.is_skipped_export(key),
+                    handler: is_handler_interface(&context.resolve, interface),
+                    functions: interface
+                        .functions
+                        .iter()
+                        .map(|(name, function)| {
+                            let handler = handler_function_name(&context.resolve, interface, name);
+                            function_model(context, name, function, &stack, |name| {
+                                naming.function_name(handler.unwrap_or(name))
+                            })
+                        })
+                        .collect::<anyhow::Result<_>>()?,
+                    types: interface_types(context, interface)?,
+                });
+            }
+            WorldItem::Function(function) => {
+                exported_functions.push(function_model(
+                    context,
+                    &function.name,
+                    function,
+                    &VecDeque::new(),
+                    |name| naming.function_name(name),
+                )?);
+            }
+            WorldItem::Type(_) => {}
+        }
+    }
+
+    Ok(WorldModel {
+        name: world.name.clone(),
+        package,
+        imports,
+        exports,
+        imported_functions,
+        exported_functions,
+    })
+}
+
+/// Builds the model of a function. The `js_name` function maps the WIT name of freestanding
+/// functions, methods and static functions to their JS names.
+fn function_model(
+    context: &GeneratorContext<'_>,
+    name: &str,
+    function: &Function,
+    interface_stack: &VecDeque<InterfaceId>,
+    js_name: impl Fn(&str) -> String,
+) -> anyhow::Result<FunctionModel> {
+    let resource_name = |resource: &TypeId| {
+        context.resolve.types[*resource]
+            .name
+            .clone()
+            .ok_or_else(|| anyhow!("Resource type has no name"))
+    };
+    let wit_name = get_function_name(name, function)?;
+    let (kind, js_name) = match &function.kind {
+        FunctionKind::Freestanding | FunctionKind::AsyncFreestanding => {
+            (FunctionModelKind::Freestanding, js_name(&wit_name))
+        }
+        FunctionKind::Constructor(resource) => {
+            let resource = resource_name(resource)?;
+            let class_name = resource.to_upper_camel_case();
+            (FunctionModelKind::Constructor { resource }, class_name)
+        }
+        FunctionKind::Method(resource) | FunctionKind::AsyncMethod(resource) => (
+            FunctionModelKind::Method {
+                resource: resource_name(resource)?,
+            },
+            js_name(&wit_name),
+        ),
+        FunctionKind::Static(resource) | FunctionKind::AsyncStatic(resource) => (
+            FunctionModelKind::Static {
+                resource: resource_name(resource)?,
+            },
+            js_name(&wit_name),
+        ),
+    };
+
+    // The `self` parameter of methods is not visible in JS
+    let skip = usize::from(matches!(
+        function.kind,
+        FunctionKind::Method(_) | FunctionKind::AsyncMethod(_)
+    ));
+    let params = function
+        .params
+        .iter()
+        .skip(skip)
+        .map(|(param_name, param_type)| {
+            Ok(ParameterModel {
+                wit_name: param_name.clone(),
+                js_name: escape_js_ident(param_name.to_lower_camel_case()),
+                ts_type: ts_type_reference(context, param_type, false, interface_stack)?,
+            })
+        })
+        .collect::<anyhow::Result<_>>()?;
+    let ts_result = match &function.result {
+        Some(result) if !matches!(function.kind, FunctionKind::Constructor(_)) => {
+            Some(ts_type_reference(context, result, false, interface_stack)?)
+        }
+        _ => None,
+    };
+
+    Ok(FunctionModel {
+        rust_name: escape_rust_ident(&wit_name),
+        wit_name,
+        kind,
+        js_name,
+        params,
+        ts_result,
+    })
+}
+
+fn interface_types(
+    context: &GeneratorContext<'_>,
+    interface: &Interface,
+) -> anyhow::Result<Vec<TypeModel>> {
+    let field = |name: &str| FieldModel {
+        wit_name: name.to_string(),
+        js_name: escape_js_ident(name.to_lower_camel_case()),
+    };
+
+    let mut types = Vec::new();
+    for (name, type_id) in &interface.types {
+        let typ = context.typ(*type_id)?;
+        let kind = match &typ.kind {
+            TypeDefKind::Record(record) => TypeModelKind::Record {
+                fields: record.fields.iter().map(|f| field(&f.name)).collect(),
+            },
+            TypeDefKind::Flags(flags) => TypeModelKind::Flags {
+                flags: flags.flags.iter().map(|f| field(&f.name)).collect(),
+            },
+            TypeDefKind::Variant(variant) => TypeModelKind::Variant {
+                cases: variant.cases.iter().map(|c| c.name.clone()).collect(),
+            },
+            TypeDefKind::Enum(enum_type) => TypeModelKind::Enum {
+                cases: enum_type.cases.iter().map(|c| c.name.clone()).collect(),
+            },
+            TypeDefKind::Resource => TypeModelKind::Resource,
+            _ => TypeModelKind::Other,
+        };
+        types.push(TypeModel {
+            wit_name: name.clone(),
+            kind,
+            ts_name: name.to_upper_camel_case(),
+        });
+    }
+    Ok(types)
+}
@@ -0,0 +1,115 @@
+//! Path helpers behaving the same on every platform, for the paths written into the generated
+//! files, which must not depend on the separators or the form of the paths given as inputs.
+
+use anyhow::{Context, anyhow};
+use camino::{Utf8Path, Utf8PathBuf};
+use std::path::{Path, PathBuf};
+
+/// Renders a relative path with `/` separators, accepting both `/` and `\` as separators in the
+/// input and dropping the empty and `.` segments
+pub fn to_slash(path: impl AsRef<Path>) -> String {
+    segments(&path.as_ref().to_string_lossy())
+        .into_iter()
+        .filter(|segment| !segment.is_empty())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Returns the path of `path` relative to `base` with `/` separators, or `None` if `path` is not
+/// inside `base`.
+///
+/// The paths are compared by their segments, accepting both `/` and `\` as separators, ignoring
+/// the case of Windows drive letters and the `\\?\` prefix of verbatim paths. If they do not match
+/// as given, they are compared in their canonical forms.
+pub fn relative_slash_path(path: impl AsRef<Path>, base: impl AsRef<Path>) -> Option<String> {
+    let (path, base) = (path.as_ref(), base.as_ref());
+    strip_segments(path, base).or_else(|| {
+        let path = path.canonicalize().ok()?;
+        let base = base.canonicalize().ok()?;
+        strip_segments(&path, &base)
+    })
+}
+
+/// Removes the `\\?\` prefix of verbatim Windows paths, as returned by `canonicalize` on
+/// Windows, if the path has a drive letter; `rustc` and most tools do not accept `/` in verbatim
+/// paths
+pub fn simplified(path: &Utf8Path) -> Utf8PathBuf {
+    match strip_verbatim_prefix(path.as_str()) {
+        Some(rest) if has_drive_letter(rest) => Utf8PathBuf::from(rest),
+        _ => path.to_path_buf(),
+    }
+}
+
+/// Converts a path to UTF-8, for the few places where it is embedded into the generated code or
+/// passed on to tools only accepting UTF-8, describing the path by `what` in the error
+pub fn to_utf8<'a>(path: &'a Path, what: &str) -> anyhow::Result<&'a Utf8Path> {
+    Utf8Path::from_path(path)
+        .ok_or_else(|| anyhow!("The path of {what} is not valid UTF-8: {}", path.display()))
+}
+
+/// Lists the files in a directory and its subdirectories, in alphabetical order
+pub fn list_files(dir: &Path) -> anyhow::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    let mut dirs = vec![dir.to_path_buf()];
+    while let Some(dir) = dirs.pop() {
+        let entries = std::fs::read_dir(&dir)
+            .with_context(|| format!("Failed to list the directory {}", dir.display()))?;
+        for entry in entries {
+            let path = entry
+                .with_context(|| format!("Failed to list the directory {}", dir.display()))?
+                .path();
+            if path.is_dir() {
+                dirs.push(path);
+            } else {
+                files.push(path);
+            }
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+fn strip_segments(path: &Path, base: &Path) -> Option<String> {
+    // Non-UTF-8 segments are compared in their lossy form
+    let (path, base) = (path.to_string_lossy(), base.to_string_lossy());
+    let path = segments(&path);
+    let base = segments(&base);
+    if path.len() < base.len()
+        || !path
+            .iter()
+            .zip(&base)
+            .all(|(segment, base)| segments_equal(segment, base))
+    {
+        return None;
+    }
+    Some(path[base.len()..].join("/"))
+}
+
+/// Splits a path on both separators, keeping a leading empty segment for absolute paths, and
+/// dropping the verbatim prefix, the other empty segments and the `.` segments
+fn segments(path: &str) -> Vec<&str> {
+    let path = strip_verbatim_prefix(path).unwrap_or(path);
+    path.split(['/', '\\'])
+        .enumerate()
+        .filter(|(index, segment)| *segment != "." && (*index == 0 || !segment.is_empty()))
+        .map(|(_, segment)| segment)
+        .collect()
+}
+
+fn segments_equal(a: &str, b: &str) -> bool {
+    if has_drive_letter(a) && a.len() == 2 {
+        a.eq_ignore_ascii_case(b)
+    } else {
+        a == b
+    }
+}
+
+fn strip_verbatim_prefix(path: &str) -> Option<&str> {
+    path.strip_prefix(r"\\?\")
+        .or_else(|| path.strip_prefix("//?/"))
+}
+
+fn has_drive_letter(path: &str) -> bool {
+    let bytes = path.as_bytes();
+    bytes.len() >= 2 && bytes[0].is_ascii_alphabetic() && bytes[1] == b':'
+}
@@ -0,0 +1,199 @@
+use crate::assets::{IMPORT_RECORDING_FILE_NAME, asset_file_path, check_asset_names};
+use crate::diagnostics::collect_diagnostics;
+use crate::helper_modules::enabled_helper_modules;
+use crate::imports::collect_imported_interfaces;
+use crate::paths::{list_files, relative_slash_path};
+use crate::pragmas::read_module_variants;
+use crate::skeleton::{cargo_toml_document, default_features, skeleton_file_paths};
+use crate::{
+    DateClock, Diagnostic, EmbeddingMode, GeneratorContext, GeneratorOptions, ImportRecording,
+    JsModuleSpec, keep_going_options, uses_composition, validation,
+};
+use anyhow::{Context, anyhow};
+use camino::{Utf8Path, Utf8PathBuf};
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+
+/// Describes what [`generate_wrapper_crate`](crate::generate_wrapper_crate) would generate
+/// for a given set of inputs, computed without touching the file system.
+#[derive(Debug, Clone)]
+pub struct GenerationPlan {
+    /// Name of the selected WIT world
+    pub world: String,
+    /// The files that would be written, relative to the output directory, in alphabetical order
+    pub files: Vec<Utf8PathBuf>,
+    /// The cargo features enabled by default in the generated crate
+    pub default_features: Vec<String>,
+    /// The JS modules evaluated by the component, in the given order
+    pub js_modules: Vec<PlannedJsModule>,
+    /// Names of the JS modules exposing the imported interfaces
+    pub import_modules: Vec<String>,
+    /// Exports and imports added to the world by the generator
+    pub world_additions: Vec<String>,
+    /// The non-fatal diagnostics the generation would report
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+/// A JS module of a [`GenerationPlan`]
+#[derive(Debug, Clone)]
+pub struct PlannedJsModule {
+    pub name: String,
+    /// Path of the file embedded into the crate, or `None` if the module is provided through
+    /// composition
+    pub source: Option<PathBuf>,
+    /// Size of the embedded file in bytes
+    pub size: Option<u64>,
+    /// Whether this is the entry module implementing the exports
+    pub entry: bool,
+}
+
+/// Resolves the WIT package and computes the plan of generating a wrapper crate, performing the
+/// same checks as [`generate_wrapper_crate`](crate::generate_wrapper_crate) but without writing
+/// anything to `output`.
+pub fn plan_wrapper_crate(
+    wit: impl AsRef<Path>,
+    js_modules: &[JsModuleSpec],
+    output: impl AsRef<Path>,
+    world: Option<&str>,
+    options: &GeneratorOptions,
+) -> anyhow::Result<GenerationPlan> {
+    let wit = wit.as_ref();
+    let output = output.as_ref();
+    let (options, skipped_interfaces) = keep_going_options(output, wit, world, options)?;
+    let options = &options;
+    let context = GeneratorContext::new(output, wit, world, options)?;
+
+    validation::check_skipped_interfaces(&context)?;
+    validation::check_decimal_types(&context)?;
+    validation::check_world_support(&context)?;
+    validation::check_js_modules(js_modules)?;
+    validation::check_defines(&context)?;
+    validation::check_http_urls(&context)?;
+    validation::check_builtin_permissions(&context, js_modules)?;
+    validation::check_js_pragmas(&context, js_modules)?;
+    validation::check_default_features(&context)?;
+    validation::check_api_availability(&context, js_modules)?;
+
+    let cargo_toml = cargo_toml_document(&context)?;
+
+    // Some of the skeleton's files are overwritten by generated ones
+    let mut files = BTreeSet::from([Utf8PathBuf::from("Cargo.toml")]);
+    if options.app_manifest.enabled {
+        files.insert(Utf8PathBuf::from("golem.yaml"));
+    }
+    files.extend(skeleton_file_paths(options.include_cargo_config)?);
+    files.extend(wit_file_paths(wit)?);
+
+    let mut planned_js_modules = Vec::new();
+    for module in js_modules {
+        let (source, size) = match &module.mode {
+            EmbeddingMode::EmbedFile(source) => {
+                let metadata = std::fs::metadata(source).with_context(|| {
+                    format!("Failed to read JavaScript module {}", source.display())
+                })?;
+                for variant in read_module_variants(module, source)? {
+                    files.insert(Utf8Path::new("src").join(variant.file_name));
+                }
+                (Some(source.clone()), Some(metadata.len()))
+            }
+            EmbeddingMode::Composition => (None, None),
+        };
+        planned_js_modules.push(PlannedJsModule {
+            name: module.name.clone(),
+            source,
+            size,
+            entry: module.entry,
+        });
+    }
+
+    check_asset_names(&options.assets)?;
+    for asset in &options.assets {
+        if !asset.path.is_file() {
+            return Err(anyhow!(
+                "Asset file {} does not exist",
+                asset.path.display()
+            ));
+        }
+        files.insert(asset_file_path(&asset.name));
+    }
+
+    if let Some(ImportRecording::Replay(path)) = &options.import_recording {
+        if !path.is_file() {
+            return Err(anyhow!(
+                "Import recording file {} does not exist",
+                path.display()
+            ));
+        }
+        files.insert(Utf8Path::new("src").join(IMPORT_RECORDING_FILE_NAME));
+    }
+
+    files.insert(Utf8PathBuf::from("src/lib.rs"));
+    files.insert(Utf8PathBuf::from("src/conversions.rs"));
+
+    let (_, interfaces) = collect_imported_interfaces(&context)?;
+    let modules_dir = Utf8Path::new("src").join("modules");
+    files.insert(modules_dir.join("mod.rs"));
+    for interface in &interfaces {
+        files.insert(modules_dir.join(format!("{}.rs", interface.module_name()?)));
+    }
+    for helper in enabled_helper_modules(&interfaces) {
+        files.insert(modules_dir.join(format!("{}.js", helper.module.name)));
+    }
+    if options.validators {
+        files.insert(modules_dir.join("validators.js"));
+    }
+    if options.enum_constants {
+        files.insert(modules_dir.join("enums.js"));
+    }
+
+    let mut world_additions = Vec::new();
+    if uses_composition(js_modules) {
+        world_additions.push("import get-script: func() -> string".to_string());
+    }
+    if options.date_clock == Some(DateClock::Host) {
+        world_additions.push("import rquickjs-clock-now: func() -> u64".to_string());
+    }
+    if options.invoke_export {
+        world_additions.push(
+            "export rquickjs-invoke: func(name: string, args-json: string) -> result<string, string>"
+                .to_string(),
+        );
+    }
+    if options.describe_export {
+        world_additions.push("export rquickjs-describe: func() -> string".to_string());
+    }
+
+    Ok(GenerationPlan {
+        world: context.world_name.clone(),
+        files: files.into_iter().collect(),
+        default_features: default_features(&cargo_toml),
+        js_modules: planned_js_modules,
+        import_modules: context
+            .model()?
+            .imports
+            .iter()
+            .filter(|interface| !interface.skipped)
+            .map(|interface| interface.js_name.clone())
+            .collect(),
+        world_additions,
+        diagnostics: skipped_interfaces
+            .into_iter()
+            .chain(collect_diagnostics(&context, Some(js_modules))?)
+            .collect(),
+    })
+}
+
+/// Lists the files of the WIT root as they are copied into the wrapper crate's `wit` directory
+fn wit_file_paths(wit: &Path) -> anyhow::Result<Vec<Utf8PathBuf>> {
+    let mut paths = Vec::new();
+    for file in list_files(wit).context("Failed to list the WIT directory")? {
+        let relative = relative_slash_path(&file, wit).ok_or_else(|| {
+            anyhow!(
+                "Unexpected file {} outside of the WIT directory",
+                file.display()
+            )
+        })?;
+        paths.push(Utf8Path::new("wit").join(relative));
+    }
+    Ok(paths)
+}
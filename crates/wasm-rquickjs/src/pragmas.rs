@@ -0,0 +1,186 @@
+use crate::JsModuleSpec;
+use anyhow::{Context, anyhow};
+use std::collections::BTreeSet;
+
+/// A JS module written to the wrapper crate for a combination of the cargo features its pragmas
+/// refer to
+pub struct ModuleVariant {
+    pub file_name: String,
+    /// The features referred to by the module's pragmas, and whether they are enabled in this
+    /// variant. Empty for modules without pragmas, having a single variant.
+    pub features: Vec<(String, bool)>,
+    pub source: String,
+}
+
+/// Splits a JS module into the variants to embed, one for each combination of the cargo features
+/// its `// #if feature(name)` pragmas refer to, with the regions of the disabled features blanked
+/// out
+pub fn module_variants(module: &JsModuleSpec, source: &str) -> anyhow::Result<Vec<ModuleVariant>> {
+    let parsed = PragmaSource::parse(source)
+        .with_context(|| format!("Invalid pragma in JS module `{}`", module.name))?;
+    let features = parsed.features();
+    if features.is_empty() {
+        return Ok(vec![ModuleVariant {
+            file_name: module.file_name(),
+            features: Vec::new(),
+            source: source.to_string(),
+        }]);
+    }
+
+    let stem = &module.name;
+    let mut variants = Vec::new();
+    for combination in 0..(1usize << features.len()) {
+        let features = features
+            .iter()
+            .enumerate()
+            .map(|(index, feature)| (feature.to_string(), combination & (1 << index) != 0))
+            .collect::<Vec<_>>();
+        let enabled = features
+            .iter()
+            .filter(|(_, enabled)| *enabled)
+            .map(|(feature, _)| feature.as_str())
+            .collect::<Vec<_>>();
+        let suffix = if enabled.is_empty() {
+            "none".to_string()
+        } else {
+            enabled.join("-")
+        };
+        variants.push(ModuleVariant {
+            file_name: format!("{stem}.features-{suffix}.js"),
+            source: parsed.render(&enabled),
+            features,
+        });
+    }
+    Ok(variants)
+}
+
+/// Reads an embedded JS module and splits it into its variants like [`module_variants`]
+pub fn read_module_variants(
+    module: &JsModuleSpec,
+    path: &std::path::Path,
+) -> anyhow::Result<Vec<ModuleVariant>> {
+    let source = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read JavaScript module {}", path.display()))?;
+    module_variants(module, &source)
+}
+
+/// A condition of a pragma region
+struct Condition {
+    feature: String,
+    negated: bool,
+}
+
+impl Condition {
+    fn holds(&self, enabled: &[&str]) -> bool {
+        enabled.contains(&self.feature.as_str()) != self.negated
+    }
+}
+
+/// A JS source with regions between `// #if feature(name)` (or `// #if !feature(name)`),
+/// `// #else` and `// #endif` pragma lines, which can be nested
+struct PragmaSource<'a> {
+    /// The lines of the source with their line endings, and the conditions of the regions they
+    /// are in
+    lines: Vec<(&'a str, Vec<Condition>)>,
+    features: BTreeSet<&'a str>,
+}
+
+impl<'a> PragmaSource<'a> {
+    fn parse(source: &'a str) -> anyhow::Result<Self> {
+        let mut lines = Vec::new();
+        let mut features = BTreeSet::new();
+        // The open regions, with the line number of their `#if` pragma and whether their
+        // `#else` pragma has been seen
+        let mut stack: Vec<(Condition, usize, bool)> = Vec::new();
+        for (index, line) in source.split_inclusive('\n').enumerate() {
+            let line_number = index + 1;
+            let directive = line
+                .trim()
+                .strip_prefix("//")
+                .map(|comment| comment.trim_start())
+                .and_then(|comment| comment.strip_prefix('#'));
+            let conditions = |stack: &[(Condition, usize, bool)]| {
+                stack
+                    .iter()
+                    .map(|(condition, _, _)| Condition {
+                        feature: condition.feature.clone(),
+                        negated: condition.negated,
+                    })
+                    .collect()
+            };
+
+            match directive.map(|directive| directive.split_once(' ').unwrap_or((directive, ""))) {
+                Some(("if", condition)) => {
+                    lines.push((line, conditions(&stack)));
+                    let condition = condition.trim();
+                    let (negated, condition) = match condition.strip_prefix('!') {
+                        Some(condition) => (true, condition.trim_start()),
+                        None => (false, condition),
+                    };
+                    let feature = condition
+                        .strip_prefix("feature(")
+                        .and_then(|rest| rest.strip_suffix(')'))
+                        .map(|feature| feature.trim())
+                        .filter(|feature| !feature.is_empty())
+                        .ok_or_else(|| {
+                            anyhow!(
+                                "Expected `#if feature(name)` or `#if !feature(name)` at line {line_number}"
+                            )
+                        })?;
+                    features.insert(feature);
+                    stack.push((
+                        Condition {
+                            feature: feature.to_string(),
+                            negated,
+                        },
+                        line_number,
+                        false,
+                    ));
+                }
+                Some(("else", _)) => {
+                    let (condition, _, seen_else) = stack.last_mut().ok_or_else(|| {
+                        anyhow!("`#else` without a matching `#if` at line {line_number}")
+                    })?;
+                    if *seen_else {
+                        return Err(anyhow!("Second `#else` of a region at line {line_number}"));
+                    }
+                    condition.negated = !condition.negated;
+                    *seen_else = true;
+                    lines.push((line, conditions(&stack[..stack.len() - 1])));
+                }
+                Some(("endif", _)) => {
+                    stack.pop().ok_or_else(|| {
+                        anyhow!("`#endif` without a matching `#if` at line {line_number}")
+                    })?;
+                    lines.push((line, conditions(&stack)));
+                }
+                _ => lines.push((line, conditions(&stack))),
+            }
+        }
+        if let Some((_, line_number, _)) = stack.last() {
+            return Err(anyhow!(
+                "`#if` at line {line_number} is not closed by an `#endif`"
+            ));
+        }
+        Ok(Self { lines, features })
+    }
+
+    /// The features the pragmas refer to
+    fn features(&self) -> Vec<&'a str> {
+        self.features.iter().copied().collect()
+    }
+
+    /// Renders the source with the given features enabled, replacing the lines of the excluded
+    /// regions by empty lines so the line numbers of the rest stay the same
+    fn render(&self, enabled: &[&str]) -> String {
+        let mut result = String::new();
+        for (line, conditions) in &self.lines {
+            if conditions.iter().all(|condition| condition.holds(enabled)) {
+                result.push_str(line);
+            } else if line.ends_with('\n') {
+                result.push('\n');
+            }
+        }
+        result
+    }
+}
@@ -0,0 +1,50 @@
+use std::fmt::{Debug, Display, Formatter};
+
+/// The stages of [`generate_wrapper_crate`](crate::generate_wrapper_crate), in the order they are
+/// run
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum GenerationStage {
+    /// Resolving the WIT package, selecting the world and validating it
+    Resolve,
+    /// Generating the `Cargo.toml` file
+    CargoToml,
+    /// Copying the skeleton sources, the WIT package and the JS modules
+    Skeleton,
+    /// Generating the implementations of the exports. Counts the exported interfaces and
+    /// functions.
+    Exports,
+    /// Generating the modules of the imports. Counts the imported interfaces.
+    Imports,
+    /// Generating the conversions between the WIT and JS types. Counts the converted types.
+    Conversions,
+}
+
+impl GenerationStage {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GenerationStage::Resolve => "resolve",
+            GenerationStage::CargoToml => "cargo-toml",
+            GenerationStage::Skeleton => "skeleton",
+            GenerationStage::Exports => "exports",
+            GenerationStage::Imports => "imports",
+            GenerationStage::Conversions => "conversions",
+        }
+    }
+}
+
+impl Display for GenerationStage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Receives the progress of [`generate_wrapper_crate`](crate::generate_wrapper_crate), so GUI and
+/// IDE integrations can show progress for large worlds.
+///
+/// Reporters are registered in [`GeneratorOptions::progress`](crate::GeneratorOptions::progress).
+pub trait ProgressReporter: Debug + Send + Sync {
+    /// Called with `completed == 0` when a stage starts, after each completed item of the stage,
+    /// and with `completed == total` when the stage is finished. The stages without countable
+    /// items have a single item.
+    fn progress(&self, stage: GenerationStage, completed: usize, total: usize);
+}
@@ -0,0 +1,97 @@
+use crate::paths::{list_files, relative_slash_path, to_slash};
+use crate::{EmbeddingMode, GeneratorContext, JsModuleSpec};
+use anyhow::Context;
+use sha2::{Digest, Sha256};
+use std::path::Path;
+
+/// Where the contents of the generated files come from, written into their header comments so
+/// they can be traced back to the generator and its inputs
+pub struct Provenance {
+    world: String,
+    /// The hashed inputs, with their hashes as `sha256:<hex>`
+    inputs: Vec<(String, String)>,
+}
+
+impl Provenance {
+    /// Hashes the WIT package and the embedded JS modules of the generation
+    pub fn new(
+        context: &GeneratorContext<'_>,
+        js_modules: &[JsModuleSpec],
+    ) -> anyhow::Result<Self> {
+        let world = &context.resolve.worlds[context.world];
+        let world = match world.package {
+            Some(package_id) => context.resolve.packages[package_id]
+                .name
+                .interface_id(&world.name),
+            None => world.name.clone(),
+        };
+
+        let mut inputs = vec![("wit".to_string(), hash_wit(context.wit_source_path)?)];
+        for module in js_modules {
+            if let EmbeddingMode::EmbedFile(path) = &module.mode {
+                let source = std::fs::read(path).with_context(|| {
+                    format!("Failed to read JavaScript module {}", path.display())
+                })?;
+                inputs.push((
+                    format!("js {}", module.name),
+                    format!("sha256:{:x}", Sha256::digest(source)),
+                ));
+            }
+        }
+        Ok(Self { world, inputs })
+    }
+
+    /// Renders the header as line comments starting with `comment`, followed by an empty line
+    pub fn header(&self, comment: &str) -> String {
+        let mut header = format!(
+            "{comment} @generated by wasm-rquickjs {}, do not edit\n{comment} world: {}\n",
+            env!("CARGO_PKG_VERSION"),
+            self.world
+        );
+        for (input, hash) in &self.inputs {
+            header.push_str(&format!("{comment} {input}: {hash}\n"));
+        }
+        header.push('\n');
+        header
+    }
+}
+
+/// The line comment syntax of a generated file, if its kind supports comments
+pub fn comment_syntax(path: &Path) -> Option<&'static str> {
+    match path.extension().and_then(|extension| extension.to_str()) {
+        Some("rs" | "js" | "ts") => Some("//"),
+        Some("toml" | "yaml") => Some("#"),
+        _ => None,
+    }
+}
+
+/// Hashes the files of a WIT directory with their relative paths, so the hash does not depend on
+/// where the directory is
+fn hash_wit(wit: &Path) -> anyhow::Result<String> {
+    let mut hasher = Sha256::new();
+    if wit.is_dir() {
+        let mut files = list_files(wit)
+            .context("Failed to list the WIT directory")?
+            .into_iter()
+            .map(|path| {
+                let relative = relative_slash_path(&path, wit).unwrap_or_else(|| to_slash(&path));
+                (relative, path)
+            })
+            .collect::<Vec<_>>();
+        files.sort();
+        for (relative, path) in files {
+            hasher.update(relative.as_bytes());
+            hasher.update([0]);
+            hasher.update(
+                std::fs::read(&path)
+                    .with_context(|| format!("Failed to read {}", path.display()))?,
+            );
+            hasher.update([0]);
+        }
+    } else {
+        hasher.update(
+            std::fs::read(wit).with_context(|| format!("Failed to read {}", wit.display()))?,
+        );
+    }
+    Ok(format!("sha256:{:x}", hasher.finalize()))
+}
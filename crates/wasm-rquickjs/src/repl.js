@@ -0,0 +1,27 @@
+import { inspect } from 'node:util';
+
+function evaluate(code) {
+    try {
+        return (0, eval)(code);
+    } catch (error) {
+        // Supporting top-level await in expressions by evaluating them in an async function
+        if (error instanceof SyntaxError && code.includes('await')) {
+            return (0, eval)(`(async () => (${code}))()`);
+        }
+        throw error;
+    }
+}
+
+export async function replEval(code) {
+    try {
+        const result = await evaluate(code);
+        return { tag: 'ok', val: inspect(result) };
+    } catch (error) {
+        if (error instanceof Error && error.stack) {
+            return { tag: 'err', val: `${error}\n${error.stack}` };
+        }
+        return { tag: 'err', val: inspect(error) };
+    }
+}
+
+export default { 'repl-eval': replEval };
@@ -0,0 +1,63 @@
+use anyhow::Context;
+use camino::Utf8Path;
+
+const SCRIPT_CARGO_TOML: &str = r#"[package]
+name = "script-component"
+version = "0.0.1"
+edition = "2024"
+
+[workspace]
+
+[profile.release]
+opt-level = "s"
+lto = true
+
+[lib]
+path = "src/lib.rs"
+crate-type = ["cdylib"]
+
+[dependencies]
+wit-bindgen-rt = { version = "0.42.1", features = ["bitflags"] }
+
+[package.metadata.component]
+package = "wasm-rquickjs:script"
+"#;
+
+const SCRIPT_WIT: &str = r#"package wasm-rquickjs:script;
+
+world script {
+    export get-script: func() -> string;
+}
+"#;
+
+const SCRIPT_LIB_RS: &str = r#"#[allow(warnings)]
+mod bindings;
+
+struct Component;
+
+impl bindings::Guest for Component {
+    fn get_script() -> String {
+        include_str!("script.js").to_string()
+    }
+}
+
+bindings::export!(Component with_types_in bindings);
+"#;
+
+/// Generates a Rust crate for a component exporting `get-script`, returning the contents of the
+/// `js` module.
+///
+/// Composing it with a wrapper component generated with a [`crate::EmbeddingMode::Composition`]
+/// module provides that module's source code.
+pub fn generate_script_component_crate(js: &Utf8Path, output: &Utf8Path) -> anyhow::Result<()> {
+    std::fs::create_dir_all(output.join("src")).context("Failed to create output/src directory")?;
+    std::fs::create_dir_all(output.join("wit")).context("Failed to create output/wit directory")?;
+
+    std::fs::write(output.join("Cargo.toml"), SCRIPT_CARGO_TOML)?;
+    std::fs::write(output.join("wit").join("script.wit"), SCRIPT_WIT)?;
+    std::fs::write(output.join("src").join("lib.rs"), SCRIPT_LIB_RS)?;
+    std::fs::copy(js, output.join("src").join("script.js"))
+        .with_context(|| format!("Failed to copy JavaScript module {js}"))?;
+
+    Ok(())
+}
@@ -1,11 +1,13 @@
-use crate::GeneratorContext;
-use anyhow::anyhow;
-use camino::Utf8Path;
+use crate::paths::relative_slash_path;
+use crate::{BuildProfile, GeneratorContext};
+use anyhow::{anyhow, bail};
+use camino::{Utf8Path, Utf8PathBuf};
 use heck::ToSnakeCase;
 use include_dir::{Dir, include_dir};
 use std::collections::BTreeSet;
-use std::path::Path;
-use toml_edit::{DocumentMut, Item, Table, Value, value};
+use std::fmt::Write;
+use toml_edit::{Array, DocumentMut, Item, Table, Value, value};
+use tracing::trace;
 
 static SKELETON: Dir<'_> = include_dir!("$CARGO_MANIFEST_DIR/skeleton");
 
@@ -16,7 +18,20 @@ static SKELETON: Dir<'_> = include_dir!("$CARGO_MANIFEST_DIR/skeleton");
 /// - Changing the package name to `crate_name` (which is the name of the chosen WIT world).
 /// - Adding a `[package.metadata.component.target.dependencies]` section with all the WIT
 ///   dependencies of the WIT package.
+/// - Adding an empty `mock-imports` feature when the imports can be mocked.
+/// - Enabling the `decimal` feature by default when decimal types are configured.
 pub fn generate_cargo_toml(context: &GeneratorContext<'_>) -> anyhow::Result<()> {
+    let doc = cargo_toml_document(context)?;
+
+    // Writing the result
+    let output_path = context.output.join("Cargo.toml");
+    context.write_generated(&output_path, doc.to_string())?;
+    Ok(())
+}
+
+/// Builds the wrapper crate's `Cargo.toml` document as described in [`generate_cargo_toml`],
+/// without writing it
+pub fn cargo_toml_document(context: &GeneratorContext<'_>) -> anyhow::Result<DocumentMut> {
     // Loading the skeleton Cargo.toml file
     let cargo_toml = SKELETON
         .get_file("Cargo.toml_")
@@ -31,29 +46,142 @@ pub fn generate_cargo_toml(context: &GeneratorContext<'_>) -> anyhow::Result<()>
 
     change_package_name(context, &mut doc);
     add_wit_dependencies(&context, &mut doc)?;
+    // cargo-component only selects the world by itself in packages with a single world
+    if context.resolve.packages[context.root_package].worlds.len() > 1 {
+        doc["package"]["metadata"]["component"]["target"]["world"] = value(&context.world_name);
+    }
+    if let Some(features) = &context.options.default_features {
+        doc["features"]["default"] = value(features.iter().collect::<Array>());
+    }
+    if context.options.mock_imports {
+        doc["features"]["mock-imports"] = value(Array::new());
+    }
+    // The decimal types are converted to and from the `Decimal` class of the `decimal` feature
+    if !context.options.decimal_types.is_empty()
+        && let Some(default) = doc["features"]["default"].as_array_mut()
+        && !default
+            .iter()
+            .any(|feature| feature.as_str() == Some("decimal"))
+    {
+        default.push("decimal");
+    }
 
-    // Writing the result
-    let output_path = context.output.join("Cargo.toml");
-    std::fs::write(output_path, doc.to_string())?;
-    Ok(())
+    Ok(doc)
+}
+
+/// Gets the features enabled by default in a wrapper crate's `Cargo.toml` document
+pub fn default_features(doc: &DocumentMut) -> Vec<String> {
+    doc.get("features")
+        .and_then(|features| features.get("default"))
+        .and_then(|default| default.as_array())
+        .map(|default| {
+            default
+                .iter()
+                .filter_map(|feature| feature.as_str().map(|s| s.to_string()))
+                .collect()
+        })
+        .unwrap_or_default()
 }
 
+/// Gets the features defined in a wrapper crate's `Cargo.toml` document, except `default`
+pub fn defined_features(doc: &DocumentMut) -> Vec<String> {
+    doc.get("features")
+        .and_then(|features| features.as_table())
+        .map(|features| {
+            features
+                .iter()
+                .map(|(name, _)| name.to_string())
+                .filter(|name| name != "default")
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Generates a Golem application manifest (`golem.yaml`) for the wrapper crate in the
+/// `context.output` directory, unless disabled in the generator options.
 pub fn generate_app_manifest(context: &GeneratorContext<'_>) -> anyhow::Result<()> {
-    // Load the source YAML from the skeleton
-    let raw_yaml = SKELETON
-        .get_file("golem.yaml")
-        .ok_or_else(|| anyhow!("Missing golem.yaml skeleton"))?
-        .contents_utf8()
-        .ok_or_else(|| anyhow!("golem.yaml skeleton is not valid UTF-8"))?;
+    let options = &context.options.app_manifest;
+    if !options.enabled {
+        return Ok(());
+    }
+    if options.profiles.is_empty() {
+        bail!("At least one build profile is required for the app manifest");
+    }
 
-    // Replacing `component_name` with the crate's name
-    let raw_yaml = raw_yaml
-        .replace("component_name", &context.world_name.to_snake_case())
-        .replace("root:package", &context.root_package_name());
+    let crate_name = context.world_name.to_snake_case();
+
+    let mut yaml = String::new();
+    writeln!(
+        yaml,
+        "# $schema: https://schema.golem.cloud/app/golem/1.2.4/golem.schema.json"
+    )?;
+    writeln!(yaml)?;
+    writeln!(yaml, "components:")?;
+    writeln!(yaml, "  {}:", context.root_package_name())?;
+    if let Some(component_type) = &options.component_type {
+        writeln!(yaml, "    componentType: {}", component_type.as_str())?;
+    }
+    writeln!(yaml, "    profiles:")?;
+    for profile in &options.profiles {
+        write_app_manifest_profile(&mut yaml, *profile, &crate_name)?;
+    }
+    writeln!(yaml, "    defaultProfile: {}", options.profiles[0].as_str())?;
+    if let Some(fragment) = &options.component_fragment {
+        for line in fragment.lines() {
+            if line.trim().is_empty() {
+                writeln!(yaml)?;
+            } else {
+                writeln!(yaml, "    {line}")?;
+            }
+        }
+    }
+    writeln!(yaml)?;
+    writeln!(yaml, "customCommands:")?;
+    writeln!(yaml, "  cargo-clean:")?;
+    writeln!(yaml, "    - command: cargo clean")?;
+    writeln!(yaml, "witDeps:")?;
+    writeln!(yaml, "  - wit/deps")?;
+    if let Some(fragment) = &options.fragment {
+        writeln!(yaml)?;
+        writeln!(yaml, "{}", fragment.trim_end())?;
+    }
 
     // Writing the result
     let output_path = context.output.join("golem.yaml");
-    std::fs::write(output_path, &raw_yaml)?;
+    context.write_generated(&output_path, &yaml)?;
+    Ok(())
+}
+
+fn write_app_manifest_profile(
+    yaml: &mut String,
+    profile: BuildProfile,
+    crate_name: &str,
+) -> anyhow::Result<()> {
+    let name = profile.as_str();
+    let build_command = match profile {
+        BuildProfile::Debug => "cargo component build",
+        BuildProfile::Release => "cargo component build --release",
+    };
+    let component_wasm = format!("target/wasm32-wasip1/{name}/{crate_name}.wasm");
+
+    writeln!(yaml, "      {name}:")?;
+    writeln!(yaml, "        build:")?;
+    writeln!(yaml, "          - command: {build_command}")?;
+    writeln!(yaml, "            sources:")?;
+    writeln!(yaml, "              - src")?;
+    writeln!(yaml, "              - wit-generated")?;
+    writeln!(yaml, "              - Cargo.toml")?;
+    writeln!(yaml, "            targets:")?;
+    writeln!(yaml, "              - {component_wasm}")?;
+    writeln!(yaml, "        sourceWit: wit")?;
+    writeln!(yaml, "        generatedWit: wit-generated")?;
+    writeln!(yaml, "        componentWasm: {component_wasm}")?;
+    writeln!(
+        yaml,
+        "        linkedWasm: target/golem-temp/components/{crate_name}_{name}.wasm"
+    )?;
+    writeln!(yaml, "        clean:")?;
+    writeln!(yaml, "          - src/bindings.rs")?;
     Ok(())
 }
 
@@ -102,10 +230,16 @@ fn add_wit_dependencies(context: &&GeneratorContext, doc: &mut DocumentMut) -> a
         if let Some(paths) = context.source_map.package_paths(package_id) {
             let mut parents = BTreeSet::new();
             for path in paths {
-                let path = Utf8Path::from_path(path).ok_or_else(|| anyhow!("Invalid path"))?;
-                let relative_path = path.strip_prefix(context.wit_source_path).unwrap_or(path);
-                if let Some(parent) = relative_path.parent() {
-                    parents.insert(parent);
+                let relative_path =
+                    relative_slash_path(path, context.wit_source_path).ok_or_else(|| {
+                        anyhow!(
+                            "WIT file {} is outside of the WIT directory {}",
+                            path.display(),
+                            context.wit_source_path.display()
+                        )
+                    })?;
+                if let Some(parent) = Utf8Path::new(&relative_path).parent() {
+                    parents.insert(parent.to_string());
                 }
             }
 
@@ -116,11 +250,21 @@ fn add_wit_dependencies(context: &&GeneratorContext, doc: &mut DocumentMut) -> a
                     parents
                 ));
             } else if let Some(parent) = parents.first() {
-                if *parent != Path::new("") {
+                if !parent.is_empty() {
                     let mut package_name_without_version = package.name.clone();
                     package_name_without_version.version = None;
 
+                    // cargo-component identifies the dependencies by their unversioned name, so a
+                    // package cannot be depended on in more than one version
+                    if dependencies.contains_key(&package_name_without_version.to_string()) {
+                        bail!(
+                            "Package {package_name_without_version} is used in multiple versions, which cargo-component does not support; remove all but one version from {}",
+                            context.wit_source_path.join("deps").display()
+                        );
+                    }
+
                     // Adding the package as a dependency
+                    trace!(package = %package.name, path = parent, "Adding WIT dependency");
                     let mut target = Table::new();
                     target.insert("path", Item::Value(Value::from(format!("wit/{parent}"))));
 
@@ -142,9 +286,10 @@ fn add_wit_dependencies(context: &&GeneratorContext, doc: &mut DocumentMut) -> a
 }
 
 /// Copies all source files from the skeleton directory to `<output>/src`.
-pub fn copy_skeleton_sources(output: &Utf8Path) -> anyhow::Result<()> {
+pub fn copy_skeleton_sources(context: &GeneratorContext<'_>) -> anyhow::Result<()> {
+    let output = context.output;
     if let Some(src) = SKELETON.get_dir("src") {
-        copy_files_in_dir(src, output)?;
+        copy_files_in_dir(context, src)?;
 
         std::fs::create_dir_all(output.join("src/builtin"))?;
         for file in src
@@ -155,27 +300,52 @@ pub fn copy_skeleton_sources(output: &Utf8Path) -> anyhow::Result<()> {
             let src_path = Utf8Path::from_path(file.path())
                 .ok_or_else(|| anyhow!("Unexpected non-UTF-8 path in skeleton"))?;
             let dest_path = output.join(src_path);
-            std::fs::write(dest_path, file.contents())?;
+            context.write_generated(&dest_path, file.contents())?;
         }
     }
     Ok(())
 }
 
-pub fn copy_cargo_config(output: &Utf8Path) -> anyhow::Result<()> {
+/// Lists the skeleton files copied into the wrapper crate, relative to its root
+pub fn skeleton_file_paths(include_cargo_config: bool) -> anyhow::Result<Vec<Utf8PathBuf>> {
+    let mut dirs = Vec::new();
+    if let Some(src) = SKELETON.get_dir("src") {
+        dirs.push(src);
+        dirs.push(
+            src.get_dir("src/builtin")
+                .ok_or_else(|| anyhow!("Missing builtin module in skeleton"))?,
+        );
+    }
+    if include_cargo_config {
+        dirs.extend(SKELETON.get_dir(".cargo"));
+    }
+
+    let mut paths = Vec::new();
+    for dir in dirs {
+        for file in dir.files() {
+            let path = Utf8Path::from_path(file.path())
+                .ok_or_else(|| anyhow!("Unexpected non-UTF-8 path in skeleton"))?;
+            paths.push(path.to_path_buf());
+        }
+    }
+    Ok(paths)
+}
+
+pub fn copy_cargo_config(context: &GeneratorContext<'_>) -> anyhow::Result<()> {
     if let Some(src) = SKELETON.get_dir(".cargo") {
         // use create_dir_all so that if the directory already exists, it doesn't fail
-        std::fs::create_dir_all(output.join(".cargo"))?;
-        copy_files_in_dir(src, output)?;
+        std::fs::create_dir_all(context.output.join(".cargo"))?;
+        copy_files_in_dir(context, src)?;
     }
     Ok(())
 }
 
-fn copy_files_in_dir(src: &Dir<'_>, output: &Utf8Path) -> anyhow::Result<()> {
+fn copy_files_in_dir(context: &GeneratorContext<'_>, src: &Dir<'_>) -> anyhow::Result<()> {
     for file in src.files() {
         let src_path = Utf8Path::from_path(file.path())
             .ok_or_else(|| anyhow!("Unexpected non-UTF-8 path in skeleton"))?;
-        let dest_path = output.join(src_path);
-        std::fs::write(dest_path, file.contents())?;
+        let dest_path = context.output.join(src_path);
+        context.write_generated(&dest_path, file.contents())?;
     }
 
     Ok(())
@@ -5,6 +5,7 @@ use heck::{ToSnakeCase, ToUpperCamelCase};
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::quote;
 use syn::{Lit, LitInt};
+use wit_bindgen_core::dealias;
 use wit_parser::{
     Function, FunctionKind, Handle, Interface, PackageId, Type, TypeDef, TypeDefKind, TypeId,
     TypeOwner,
@@ -113,24 +114,20 @@ pub fn type_id_to_type_ref(
                         .get(interface_id)
                         .ok_or_else(|| anyhow!("Unknown interface id: {interface_id:?}"))?;
 
+                    let interface_name = context.interface_name(interface_id)?;
+
                     if context.is_exported_interface(interface_id) {
                         Ok(ident_in_exported_interface(
                             context,
                             name_ident,
-                            interface
-                                .name
-                                .as_ref()
-                                .ok_or_else(|| anyhow!("Interface export does not have a name"))?,
+                            interface_name,
                             interface,
                         ))
                     } else {
                         Ok(ident_in_imported_interface(
                             context,
                             name_ident,
-                            interface
-                                .name
-                                .as_ref()
-                                .ok_or_else(|| anyhow!("Interface does not have a name"))?,
+                            interface_name,
                             interface,
                         ))
                     }
@@ -193,7 +190,9 @@ pub fn ident_in_exported_interface(
     path.push(quote! { bindings });
     path.push(quote! { exports });
 
-    add_package_to_path(context, &mut path, &interface.package);
+    if interface.name.is_some() {
+        add_package_to_path(context, &mut path, &interface.package);
+    }
 
     path.push(quote! { #name_ident });
     path.push(quote! { #ident });
@@ -231,7 +230,10 @@ pub fn ident_in_imported_interface(
     path.push(quote! { crate });
     path.push(quote! { bindings });
 
-    add_package_to_path(context, &mut path, &interface.package);
+    // wit-bindgen generates anonymous (inline) interfaces directly under the bindings module
+    if interface.name.is_some() {
+        add_package_to_path(context, &mut path, &interface.package);
+    }
 
     path.push(quote! { #name_ident });
     path.push(quote! { #ident });
@@ -405,6 +407,9 @@ pub fn get_wrapped_type_internal(
                 TypeDefKind::Handle(Handle::Borrow(resource_type_id)) => {
                     get_wrapped_type_borrow_handle(ctx, resource_type_id)
                 }
+                TypeDefKind::Type(Type::String) if context.is_decimal_type(typ) => {
+                    get_wrapped_type_decimal(ctx)
+                }
                 TypeDefKind::Type(inner) => {
                     // Recursively dealiasing
                     let inner = get_wrapped_type_internal(
@@ -662,14 +667,14 @@ fn get_wrapped_type_list(
         let unwrapped_v = inner.unwrap.run(quote! { v });
 
         Ok(WrappedType {
-            wrap: TokenStreamWrapper::new(
-                move |ts| quote! { #ts.into_iter().map(|v| #wrapped_v).collect::<Vec<_>>() },
-            ),
+            wrap: TokenStreamWrapper::new(move |ts| {
+                quote! { crate::wrappers::JsList(#ts.into_iter().map(|v| #wrapped_v).collect::<Vec<_>>()) }
+            }),
             unwrap: TokenStreamWrapper::new(
-                move |ts| quote! { #ts.iter().map(|v| #unwrapped_v).collect::<Vec<_>>() },
+                move |ts| quote! { #ts.0.iter().map(|v| #unwrapped_v).collect::<Vec<_>>() },
             ),
             original_type_ref: ctx.original_type_ref,
-            wrapped_type_ref: quote! { Vec<#inner_wrapped_type_ref> },
+            wrapped_type_ref: quote! { crate::wrappers::JsList<#inner_wrapped_type_ref> },
         })
     } else {
         let inner = get_wrapped_type_internal(
@@ -685,14 +690,14 @@ fn get_wrapped_type_list(
         let unwrapped_v = inner.unwrap.run(quote! { v });
 
         Ok(WrappedType {
-            wrap: TokenStreamWrapper::new(
-                move |ts| quote! { #ts.into_iter().map(|v| #wrapped_v).collect::<Vec<_>>() },
-            ),
+            wrap: TokenStreamWrapper::new(move |ts| {
+                quote! { crate::wrappers::JsList(#ts.into_iter().map(|v| #wrapped_v).collect::<Vec<_>>()) }
+            }),
             unwrap: TokenStreamWrapper::new(
-                move |ts| quote! { #ts.into_iter().map(|v| #unwrapped_v).collect::<Vec<_>>() },
+                move |ts| quote! { #ts.0.into_iter().map(|v| #unwrapped_v).collect::<Vec<_>>() },
             ),
             original_type_ref: ctx.original_type_ref,
-            wrapped_type_ref: quote! { Vec<#inner_wrapped_type_ref> },
+            wrapped_type_ref: quote! { crate::wrappers::JsList<#inner_wrapped_type_ref> },
         })
     }
 }
@@ -728,7 +733,7 @@ fn get_wrapped_type_result(
                 export_ok_rust_type,
                 ok,
                 false,
-                ctx.forced_ref,
+                false, // the result is unwrapped by value, then converted as a whole
             )
         })
         .transpose()?
@@ -743,7 +748,7 @@ fn get_wrapped_type_result(
                 export_err_rust_type,
                 err,
                 false,
-                ctx.forced_ref,
+                false,
             )
         })
         .transpose()?
@@ -755,6 +760,21 @@ fn get_wrapped_type_result(
     let wrap_ok = ok.wrap.run(quote! { v });
     let wrap_err = err.wrap.run(quote! { v });
 
+    let unwrap_ok = match ok.unwrap {
+        TokenStreamWrapper::Identity => quote! {},
+        unwrap => {
+            let unwrapped_v = unwrap.run(quote! { v });
+            quote! { .map(|v| #unwrapped_v) }
+        }
+    };
+    let unwrap_err = match err.unwrap {
+        TokenStreamWrapper::Identity => quote! {},
+        unwrap => {
+            let unwrapped_v = unwrap.run(quote! { v });
+            quote! { .map_err(|v| #unwrapped_v) }
+        }
+    };
+
     Ok(WrappedType {
         wrap: TokenStreamWrapper::new(move |ts| {
             quote! {
@@ -766,7 +786,7 @@ fn get_wrapped_type_result(
                 )
             }
         }),
-        unwrap: TokenStreamWrapper::new(|ts| quote! { #ts.0 }),
+        unwrap: TokenStreamWrapper::new(move |ts| quote! { #ts.0 #unwrap_ok #unwrap_err }),
         original_type_ref: ctx.original_type_ref,
         wrapped_type_ref: quote! { crate::wrappers::JsResult<#wrapped_ok, #wrapped_err> },
     })
@@ -776,15 +796,19 @@ fn get_wrapped_type_borrow_handle(
     ctx: GetWrappedTypeContext<'_>,
     resource_type_id: &TypeId,
 ) -> anyhow::Result<WrappedType> {
+    // The resource may be used through an alias in an exported interface
+    let resource_type_id = &dealias(&ctx.context.resolve, *resource_type_id);
     if ctx.context.is_exported_type(*resource_type_id) {
         Ok(WrappedType::no_wrapping(ctx.original_type_ref))
     } else {
         let borrowed_resource_ref = borrowed_resource_ref(ctx.context, resource_type_id)?;
+        let owned_resource_ref = owned_resource_ref(ctx.context, resource_type_id)?;
         Ok(WrappedType {
-            original_type_ref: ctx.original_type_ref,
+            // Exported functions get borrowed handles of imported resources by reference
+            original_type_ref: quote! { &#owned_resource_ref },
             wrapped_type_ref: borrowed_resource_ref.clone(),
             wrap: TokenStreamWrapper::new(move |ts| {
-                quote! { #borrowed_resource_ref(#ts) }
+                quote! { #borrowed_resource_ref::from_ref(#ts) }
             }),
             unwrap: if ctx.forced_ref {
                 TokenStreamWrapper::new(|ts| {
@@ -804,25 +828,52 @@ fn get_wrapped_type_adt(ctx: GetWrappedTypeContext<'_>) -> anyhow::Result<Wrappe
 }
 
 fn get_wrapped_type_string(ctx: GetWrappedTypeContext<'_>) -> anyhow::Result<WrappedType> {
+    // Strings are wrapped to apply the lone surrogate policy when converting from JS
+    let wrap = TokenStreamWrapper::new(|ts| quote! { crate::wrappers::JsString(#ts) });
+    let wrapped_type_ref = quote! { crate::wrappers::JsString };
     if ctx.in_tuple {
         Ok(WrappedType {
-            wrap: TokenStreamWrapper::identity(),
-            unwrap: TokenStreamWrapper::new(|s| quote! { #s.clone() }),
-            original_type_ref: ctx.original_type_ref.clone(),
-            wrapped_type_ref: ctx.original_type_ref,
+            wrap,
+            unwrap: TokenStreamWrapper::new(|s| quote! { #s.0.clone() }),
+            original_type_ref: ctx.original_type_ref,
+            wrapped_type_ref,
         })
     } else if ctx.forced_ref {
         Ok(WrappedType {
-            wrap: TokenStreamWrapper::identity(),
-            unwrap: TokenStreamWrapper::reference(),
-            original_type_ref: ctx.original_type_ref.clone(),
-            wrapped_type_ref: ctx.original_type_ref,
+            wrap,
+            unwrap: TokenStreamWrapper::new(|s| quote! { &#s.0 }),
+            original_type_ref: ctx.original_type_ref,
+            wrapped_type_ref,
         })
     } else {
-        Ok(WrappedType::no_wrapping(ctx.original_type_ref))
+        Ok(WrappedType {
+            wrap,
+            unwrap: TokenStreamWrapper::new(|s| quote! { #s.0 }),
+            original_type_ref: ctx.original_type_ref,
+            wrapped_type_ref,
+        })
     }
 }
 
+fn get_wrapped_type_decimal(ctx: GetWrappedTypeContext<'_>) -> anyhow::Result<WrappedType> {
+    // String aliases configured as decimal types are represented by the builtin `Decimal` class
+    let wrap = TokenStreamWrapper::new(|ts| quote! { crate::wrappers::DecimalWrapper(#ts) });
+    let wrapped_type_ref = quote! { crate::wrappers::DecimalWrapper };
+    let unwrap = if ctx.in_tuple {
+        TokenStreamWrapper::new(|s| quote! { #s.0.clone() })
+    } else if ctx.forced_ref {
+        TokenStreamWrapper::new(|s| quote! { &#s.0 })
+    } else {
+        TokenStreamWrapper::new(|s| quote! { #s.0 })
+    };
+    Ok(WrappedType {
+        wrap,
+        unwrap,
+        original_type_ref: ctx.original_type_ref,
+        wrapped_type_ref,
+    })
+}
+
 fn owned_resource_ref(
     context: &GeneratorContext<'_>,
     resource_type_id: &TypeId,
@@ -885,12 +936,9 @@ fn analyse_resource_type_owner<'a>(
                 .interfaces
                 .get(*interface_id)
                 .ok_or_else(|| anyhow!("Unknown interface id: {interface_id:?}"))?;
-            let interface_name = interface
-                .name
-                .as_ref()
-                .ok_or_else(|| anyhow!("Interface export does not have a name"))?;
+            let interface_name = context.interface_name(*interface_id)?;
             Ok((
-                Some((interface_name.as_str(), interface)),
+                Some((interface_name, interface)),
                 context.is_exported_interface(*interface_id),
             ))
         }
@@ -925,20 +973,10 @@ fn borrowed_resource_ref(
             Span::call_site(),
         );
 
-        let module_path = if let Some((interface_name, interface)) = interface {
-            let package_id = interface
-                .package
-                .ok_or_else(|| anyhow::anyhow!("Interface does not have a package"))?;
-            let package = context
-                .resolve
-                .packages
-                .get(package_id)
-                .ok_or_else(|| anyhow::anyhow!("Unknown package id: {package_id:?}"))?;
-            let module_name = format!(
-                "{}_{}",
-                package.name.to_string().to_snake_case(),
-                interface_name.to_snake_case()
-            );
+        let module_path = if let TypeOwner::Interface(interface_id) = &resource_type.owner {
+            let module_name = context
+                .get_imported_interface(interface_id)?
+                .module_name()?;
             let module_ident = Ident::new(&module_name, Span::call_site());
             quote! { crate::modules::#module_ident }
         } else {
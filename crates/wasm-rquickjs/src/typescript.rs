@@ -1,18 +1,38 @@
-use crate::GeneratorContext;
+use crate::enums::collect_enums;
+use crate::handlers::{handler_function_name, is_handler_interface};
+use crate::helper_modules::enabled_helper_modules;
+use crate::imports::{ENUMS_MODULE_NAME, VALIDATORS_MODULE_NAME, WORLD_IMPORTS_MODULE_NAME};
 use crate::javascript::escape_js_ident;
+use crate::paths::{relative_slash_path, to_slash};
 use crate::types::get_function_name;
+use crate::validators::collect_validated_types;
+use crate::{DtsFormat, DtsLayout, GeneratorContext, ImportedInterface, JsNamingPolicy};
 use anyhow::anyhow;
 use camino::{Utf8Path, Utf8PathBuf};
 use heck::{ToLowerCamelCase, ToUpperCamelCase};
 use std::collections::{BTreeMap, BTreeSet, VecDeque};
 use std::fmt::Write;
+use std::path::PathBuf;
+use tracing::debug;
 use wit_parser::{
     Docs, Function, FunctionKind, InterfaceId, Type, TypeDef, TypeDefKind, TypeId, TypeOwner,
     WorldItem, WorldKey,
 };
 
-pub fn generate_export_module(context: &GeneratorContext) -> anyhow::Result<Vec<Utf8PathBuf>> {
-    let mut result = DtsWriter::new();
+/// The directory of the package directories of the nested layout
+const NESTED_TYPES_DIR: &str = "types";
+
+/// Type declarations of the builtin `Decimal` class, generated when decimal types are configured
+const DECIMAL_DTS: &str = include_str!("decimal.d.ts");
+
+/// Type declarations of the builtin `registerImportHook` function, generated with import hooks
+const IMPORT_HOOKS_DTS: &str = include_str!("import-hooks.d.ts");
+
+/// Type declarations of the builtin `__mocks` module, generated with mocked imports
+const MOCKS_DTS: &str = include_str!("mocks.d.ts");
+
+pub fn generate_export_module(context: &GeneratorContext) -> anyhow::Result<Vec<PathBuf>> {
+    let mut result = DtsWriter::new(context, dts_file_name(context, "exports"));
 
     let world = &context.resolve.worlds[context.world];
     result.begin_declare_module(&world.name);
@@ -20,10 +40,11 @@ pub fn generate_export_module(context: &GeneratorContext) -> anyhow::Result<Vec<
     let mut global_exports = Vec::new();
     let mut global_types = Vec::new();
     let mut interface_exports = Vec::new();
+    let mut handler_exports = Vec::new();
 
     // Enumerating all exports and separating them into global exports and interface exports.
-    for (name, export) in &world.exports {
-        let name = match name {
+    for (key, export) in &world.exports {
+        let name = match key {
             WorldKey::Name(name) => name.clone(),
             WorldKey::Interface(id) => {
                 let interface = &context.resolve.interfaces[*id];
@@ -35,8 +56,16 @@ pub fn generate_export_module(context: &GeneratorContext) -> anyhow::Result<Vec<
         };
         match export {
             WorldItem::Interface { id, .. } => {
+                // Skipped interfaces are not implemented in JS
+                if context.is_skipped_export(key) {
+                    continue;
+                }
                 let interface = &context.resolve.interfaces[*id];
-                interface_exports.push((name, interface, *id));
+                if is_handler_interface(&context.resolve, interface) {
+                    handler_exports.push((interface, *id));
+                } else {
+                    interface_exports.push((name, interface, *id));
+                }
             }
             WorldItem::Function(function) => {
                 global_exports.push((name, function));
@@ -54,11 +83,37 @@ pub fn generate_export_module(context: &GeneratorContext) -> anyhow::Result<Vec<
         &global_exports,
         &global_types,
         true,
+        context.options.export_naming,
         &VecDeque::new(),
     )?;
 
+    // The functions of handler interfaces are exported as top-level async functions, using the
+    // types of their interface
+    for (interface, interface_id) in &handler_exports {
+        let functions = handler_functions(context, interface);
+        let types: Vec<_> = interface.types.values().copied().collect();
+        let interface_stack: VecDeque<_> = vec![*interface_id].into_iter().collect();
+        declare_functions_and_resources(
+            &mut result,
+            context,
+            &functions,
+            &types,
+            true,
+            context.options.export_naming,
+            &interface_stack,
+        )?;
+        export_types(
+            &mut result,
+            context,
+            &types,
+            &functions.iter().map(|(_, f)| *f).collect::<Vec<_>>(),
+            &interface_stack,
+        )?;
+    }
+
     // Declaring each exported interface as a module
-    for (name, interface, interface_id) in interface_exports {
+    for (name, interface, interface_id) in &interface_exports {
+        let interface_id = *interface_id;
         let interface_exports: Vec<_> = interface
             .functions
             .iter()
@@ -80,6 +135,7 @@ pub fn generate_export_module(context: &GeneratorContext) -> anyhow::Result<Vec<
             &interface_exports,
             &interface_types,
             true,
+            context.options.export_naming,
             &interface_stack,
         )?;
 
@@ -101,28 +157,71 @@ pub fn generate_export_module(context: &GeneratorContext) -> anyhow::Result<Vec<
         &mut result,
         context,
         &global_types,
-        &global_exports
-            .into_iter()
-            .map(|(_, f)| f)
-            .collect::<Vec<_>>(),
+        &global_exports.iter().map(|(_, f)| *f).collect::<Vec<_>>(),
         &VecDeque::new(),
     )?;
 
+    if context.options.export_naming == JsNamingPolicy::Verbatim {
+        declare_verbatim_default_export(
+            &mut result,
+            context,
+            &global_exports,
+            &global_types,
+            &handler_exports,
+            &interface_exports,
+        )?;
+    }
+
     result.end_declare_module();
-    let target = context.output.join("exports.d.ts");
-    result.finish(&target)?;
-    Ok(vec![target])
+    Ok(vec![result.finish(context)?])
 }
 
-pub fn generate_import_modules(context: &GeneratorContext) -> anyhow::Result<Vec<Utf8PathBuf>> {
+pub fn generate_import_modules(context: &GeneratorContext) -> anyhow::Result<Vec<PathBuf>> {
     let mut results = Vec::new();
-    let (_global, interfaces) = crate::imports::collect_imported_interfaces(context)?;
+    let (global, interfaces) = crate::imports::collect_imported_interfaces(context)?;
 
-    for interface in &interfaces {
-        let module_name = interface.module_name()?;
-        let file_name = format!("{module_name}.d.ts");
+    // Functions imported directly into the world
+    if !global.functions.is_empty() {
+        let mut result = DtsWriter::new(context, dts_file_name(context, WORLD_IMPORTS_MODULE_NAME));
+        result.begin_declare_module(WORLD_IMPORTS_MODULE_NAME);
+
+        let world_imports = global
+            .functions
+            .iter()
+            .map(|(name, function)| (name.to_string(), *function))
+            .collect::<Vec<_>>();
+
+        declare_functions_and_resources(
+            &mut result,
+            context,
+            &world_imports,
+            &[],
+            false,
+            JsNamingPolicy::CamelCase,
+            &VecDeque::new(),
+        )?;
+
+        export_types(
+            &mut result,
+            context,
+            &[],
+            &world_imports
+                .into_iter()
+                .map(|(_, f)| f)
+                .collect::<Vec<_>>(),
+            &VecDeque::new(),
+        )?;
 
-        let mut result = DtsWriter::new();
+        result.end_declare_module();
+        results.push(result.finish(context)?);
+    }
+
+    for interface in &interfaces {
+        debug!(
+            interface = interface.fully_qualified_interface_name(),
+            "Generating the declarations of imported interface"
+        );
+        let mut result = DtsWriter::new(context, dts_interface_path(context, interface)?);
 
         if let Some(docs) = &interface.interface.as_ref().map(|i| &i.docs) {
             result.write_docs(docs);
@@ -147,6 +246,7 @@ pub fn generate_import_modules(context: &GeneratorContext) -> anyhow::Result<Vec
                 &interface_imports,
                 &interface_types,
                 false,
+                JsNamingPolicy::CamelCase,
                 &interface_stack,
             )?;
 
@@ -162,21 +262,223 @@ pub fn generate_import_modules(context: &GeneratorContext) -> anyhow::Result<Vec
             )?;
         }
         result.end_declare_module();
+        results.push(result.finish(context)?);
+    }
 
+    for helper in enabled_helper_modules(&interfaces) {
+        let file_name = dts_file_name(context, helper.module.name);
+        let helper_dts =
+            helper.dts(|interface| dts_module_specifier(context, &file_name, interface))?;
         let target = context.output.join(file_name);
-        result.finish(&target)?;
+        if module_files(context) {
+            context.write_generated(&target, unwrap_ambient_module(&helper_dts))?;
+        } else {
+            context.write_generated(&target, helper_dts)?;
+        }
         results.push(target);
     }
 
+    if !context.options.decimal_types.is_empty() {
+        let target = context.output.join(dts_file_name(context, "decimal"));
+        if declare_global(context) {
+            context.write_generated(&target, wrap_declare_global(DECIMAL_DTS))?;
+        } else {
+            context.write_generated(&target, DECIMAL_DTS)?;
+        }
+        results.push(target);
+    }
+
+    if context.options.mock_imports {
+        let target = context.output.join(dts_file_name(context, "__mocks"));
+        if module_files(context) {
+            context.write_generated(&target, unwrap_ambient_module(MOCKS_DTS))?;
+        } else {
+            context.write_generated(&target, MOCKS_DTS)?;
+        }
+        results.push(target);
+    }
+
+    if context.options.import_hooks {
+        let target = context.output.join(dts_file_name(context, "import-hooks"));
+        if declare_global(context) {
+            context.write_generated(&target, wrap_declare_global(IMPORT_HOOKS_DTS))?;
+        } else {
+            context.write_generated(&target, IMPORT_HOOKS_DTS)?;
+        }
+        results.push(target);
+    }
+
+    if context.options.validators {
+        let mut result = DtsWriter::new(context, dts_file_name(context, VALIDATORS_MODULE_NAME));
+        result.begin_declare_module(VALIDATORS_MODULE_NAME);
+        for typ in collect_validated_types(context)? {
+            let mut function = result.begin_export_function(&format!("validate{}", typ.name));
+            function.param("value", "unknown");
+        }
+        result.end_declare_module();
+        results.push(result.finish(context)?);
+    }
+
+    if context.options.enum_constants {
+        let mut result = DtsWriter::new(context, dts_file_name(context, ENUMS_MODULE_NAME));
+        result.begin_declare_module(ENUMS_MODULE_NAME);
+        for constants in collect_enums(context)? {
+            let cases = constants
+                .cases()
+                .map(|(name, value)| format!("readonly {name}: '{value}'"))
+                .collect::<Vec<_>>();
+            result.export_const(&constants.name, &format!("{{ {} }}", cases.join("; ")));
+        }
+        result.end_declare_module();
+        results.push(result.finish(context)?);
+    }
+
     Ok(results)
 }
 
+/// Generates the `index.d.ts` file of the nested layout, including all the other generated
+/// declaration `files`: by triple-slash references to the ambient module declarations, or by
+/// re-exporting the module files as namespaces.
+pub fn generate_index(context: &GeneratorContext, files: &[PathBuf]) -> anyhow::Result<PathBuf> {
+    let extension = context.options.dts_format.extension();
+    let import_extension = context.options.dts_format.import_extension();
+
+    let mut index = String::new();
+    for file in files {
+        let relative = relative_slash_path(file, context.output).ok_or_else(|| {
+            anyhow!(
+                "Generated declaration file {} is not in the output directory",
+                file.display()
+            )
+        })?;
+        let stem = relative.strip_suffix(extension).unwrap_or(&relative);
+
+        let is_script = !module_files(context) || (stem == "decimal" && !declare_global(context));
+        if is_script {
+            writeln!(index, "/// <reference path=\"./{relative}\" />")?;
+        } else if stem == "decimal" {
+            // Only declares globals
+            writeln!(index, "import './{stem}{import_extension}';")?;
+        } else {
+            let name = stem
+                .strip_prefix(NESTED_TYPES_DIR)
+                .and_then(|stem| stem.strip_prefix('/'))
+                .unwrap_or(stem)
+                .replace('/', "_");
+            let name = escape_js_ident(name.to_lower_camel_case());
+            writeln!(
+                index,
+                "export * as {name} from './{stem}{import_extension}';"
+            )?;
+        }
+    }
+
+    let target = context.output.join(dts_file_name(context, "index"));
+    context
+        .write_generated(&target, index)
+        .map_err(|e| anyhow!("Failed to write TypeScript definitions: {e}"))?;
+    Ok(target)
+}
+
+/// Whether the declaration files are ES modules on their own, instead of declaring ambient modules
+fn module_files(context: &GeneratorContext) -> bool {
+    context.options.dts_module_files || context.options.dts_format == DtsFormat::Mts
+}
+
+/// Whether the global declarations are wrapped into `declare global`
+fn declare_global(context: &GeneratorContext) -> bool {
+    context.options.dts_declare_global || context.options.dts_format == DtsFormat::Mts
+}
+
+fn dts_file_name(context: &GeneratorContext, stem: &str) -> Utf8PathBuf {
+    Utf8PathBuf::from(format!("{stem}{}", context.options.dts_format.extension()))
+}
+
+/// Gets the path of the declaration file of an imported interface, relative to the output
+/// directory
+fn dts_interface_path(
+    context: &GeneratorContext,
+    interface: &ImportedInterface,
+) -> anyhow::Result<Utf8PathBuf> {
+    match context.options.dts_layout {
+        DtsLayout::Flat => Ok(dts_file_name(context, &interface.module_name()?)),
+        DtsLayout::Nested => Ok(Utf8Path::new(NESTED_TYPES_DIR)
+            .join(dts_file_name(context, interface.module_path()?.as_str()))),
+    }
+}
+
+/// Gets the specifier the declarations of an imported interface are imported by from the
+/// declaration file at `from` (relative to the output directory)
+fn dts_module_specifier(
+    context: &GeneratorContext,
+    from: &Utf8Path,
+    interface: &ImportedInterface,
+) -> anyhow::Result<String> {
+    if module_files(context) {
+        let target = dts_interface_path(context, interface)?;
+        let target = to_slash(&target);
+        let target = target
+            .strip_suffix(context.options.dts_format.extension())
+            .unwrap_or(&target);
+
+        let depth = from.parent().map_or(0, |dir| dir.components().count());
+        let prefix = if depth == 0 {
+            "./".to_string()
+        } else {
+            "../".repeat(depth)
+        };
+        Ok(format!(
+            "{prefix}{target}{}",
+            context.options.dts_format.import_extension()
+        ))
+    } else {
+        Ok(interface.fully_qualified_interface_name())
+    }
+}
+
+/// Turns the declarations of a static ambient module (`declare module 'name' { ... }`) into the
+/// top-level declarations of a module file
+fn unwrap_ambient_module(source: &str) -> String {
+    let mut result = String::new();
+    let mut inside = false;
+    for line in source.lines() {
+        if !inside && line.starts_with("declare module ") {
+            inside = true;
+        } else if inside && line == "}" {
+            inside = false;
+        } else if inside {
+            result.push_str(line.strip_prefix("  ").unwrap_or(line));
+            result.push('\n');
+        } else {
+            result.push_str(line);
+            result.push('\n');
+        }
+    }
+    result
+}
+
+/// Turns the top-level declarations of a static script into global declarations of a module
+fn wrap_declare_global(source: &str) -> String {
+    let mut result = String::from("export {};\n\ndeclare global {\n");
+    for line in source.lines() {
+        let line = line.strip_prefix("declare ").unwrap_or(line);
+        if !line.is_empty() {
+            result.push_str("  ");
+            result.push_str(line);
+        }
+        result.push('\n');
+    }
+    result.push_str("}\n");
+    result
+}
+
 fn declare_functions_and_resources(
     result: &mut DtsWriter,
     context: &GeneratorContext,
     functions: &[(String, &Function)],
     types: &[TypeId],
     async_: bool,
+    naming: JsNamingPolicy,
     interface_stack: &VecDeque<InterfaceId>,
 ) -> anyhow::Result<()> {
     let mut resource_functions = BTreeMap::new();
@@ -195,6 +497,9 @@ fn declare_functions_and_resources(
 
     for (name, function) in functions {
         match &function.kind {
+            FunctionKind::Freestanding if naming == JsNamingPolicy::Verbatim => {
+                // Declared as a member of the default export instead
+            }
             FunctionKind::Freestanding => {
                 let docs =
                     add_throws_to_doc(context, interface_stack, &function.result, &function.docs)?;
@@ -250,7 +555,8 @@ fn declare_functions_and_resources(
             let docs =
                 add_throws_to_doc(context, interface_stack, &function.result, &function.docs)?;
             result.write_docs(&docs);
-            let js_name = escape_js_ident(get_function_name(name, function)?.to_lower_camel_case());
+            let js_name =
+                ts_member_name(&naming.function_name(&get_function_name(name, function)?));
             let mut fun = match &function.kind {
                 FunctionKind::Method(_) if async_ => result.begin_async_method(&js_name),
                 FunctionKind::Method(_) => result.begin_method(&js_name),
@@ -305,11 +611,177 @@ fn declare_functions_and_resources(
         }
 
         result.end_export_class();
+
+        if context.options.dts_branded_handles {
+            declare_branded_handles(result, &js_resource_name);
+        }
+    }
+
+    Ok(())
+}
+
+/// Declares the branded types of the owned and borrowed handles of a resource class, used by the
+/// function signatures to tell apart the parameters taking over the ownership of a handle. Owned
+/// handles carry a brand, so only the handles returned as owned by other functions (or values
+/// cast with `as`) can be passed where an owned handle is expected.
+fn declare_branded_handles(result: &mut DtsWriter, js_resource_name: &str) {
+    result.write_docs(&Docs {
+        contents: Some(format!(
+            "An owned handle of a `{js_resource_name}`, whose ownership is transferred when passed to a function"
+        )),
+    });
+    result.export_type(
+        &format!("Owned{js_resource_name}"),
+        &format!("{js_resource_name} & {{ readonly __owned: true }}"),
+    );
+    result.write_docs(&Docs {
+        contents: Some(format!(
+            "A borrowed handle of a `{js_resource_name}`, which remains usable after the call"
+        )),
+    });
+    result.export_type(&format!("Borrowed{js_resource_name}"), js_resource_name);
+}
+
+/// With the verbatim naming policy all exports are looked up from the JS module's default export
+/// by their original WIT names, so its shape is declared as a single object type.
+fn declare_verbatim_default_export(
+    result: &mut DtsWriter,
+    context: &GeneratorContext,
+    global_exports: &[(String, &Function)],
+    global_types: &[TypeId],
+    handler_exports: &[(&wit_parser::Interface, InterfaceId)],
+    interface_exports: &[(String, &wit_parser::Interface, InterfaceId)],
+) -> anyhow::Result<()> {
+    result.begin_default_export_object();
+
+    declare_verbatim_members(
+        result,
+        context,
+        global_exports,
+        global_types,
+        None,
+        &VecDeque::new(),
+    )?;
+
+    for (interface, interface_id) in handler_exports {
+        let interface_stack: VecDeque<_> = vec![*interface_id].into_iter().collect();
+        declare_verbatim_members(
+            result,
+            context,
+            &handler_functions(context, interface),
+            &[],
+            None,
+            &interface_stack,
+        )?;
+    }
+
+    for (name, interface, interface_id) in interface_exports {
+        let functions: Vec<_> = interface
+            .functions
+            .iter()
+            .map(|(name, function)| (name.clone(), function))
+            .collect();
+        let types: Vec<_> = interface.types.values().copied().collect();
+        let interface_stack: VecDeque<_> = vec![*interface_id].into_iter().collect();
+        let namespace = escape_js_ident(name.to_lower_camel_case());
+
+        result.write_docs(&interface.docs);
+        result.begin_object_member(&ts_member_name(name));
+        declare_verbatim_members(
+            result,
+            context,
+            &functions,
+            &types,
+            Some(&namespace),
+            &interface_stack,
+        )?;
+        result.end_object_member();
+    }
+
+    result.end_default_export_object();
+    Ok(())
+}
+
+/// Lists the functions of an exported handler interface by the names of the top-level JS
+/// functions implementing them
+fn handler_functions<'a>(
+    context: &GeneratorContext,
+    interface: &'a wit_parser::Interface,
+) -> Vec<(String, &'a Function)> {
+    interface
+        .functions
+        .iter()
+        .map(|(name, function)| {
+            let handler = handler_function_name(&context.resolve, interface, name);
+            (handler.unwrap_or(name).to_string(), function)
+        })
+        .collect()
+}
+
+fn declare_verbatim_members(
+    result: &mut DtsWriter,
+    context: &GeneratorContext,
+    functions: &[(String, &Function)],
+    types: &[TypeId],
+    namespace: Option<&str>,
+    interface_stack: &VecDeque<InterfaceId>,
+) -> anyhow::Result<()> {
+    for (name, function) in functions {
+        if function.kind != FunctionKind::Freestanding {
+            continue;
+        }
+        let docs = add_throws_to_doc(context, interface_stack, &function.result, &function.docs)?;
+        result.write_docs(&docs);
+        let mut fun = result.begin_async_method(&ts_member_name(name));
+        for (param_name, param_type) in &function.params {
+            let js_param_name = escape_js_ident(param_name.to_lower_camel_case());
+            fun.param(
+                &js_param_name,
+                &ts_type_reference(context, param_type, false, interface_stack)?,
+            );
+        }
+        define_return_type(context, interface_stack, function, &mut fun)?;
+    }
+
+    for type_id in types {
+        let typ = context
+            .resolve
+            .types
+            .get(*type_id)
+            .ok_or_else(|| anyhow!("Unknown type id {type_id:?}"))?;
+        if typ.kind == TypeDefKind::Resource {
+            let resource_name = typ
+                .name
+                .as_ref()
+                .ok_or_else(|| anyhow!("Resource type has no name"))?;
+            let class_name = resource_name.to_upper_camel_case();
+            let class_ref = match namespace {
+                Some(namespace) => format!("typeof {namespace}.{class_name}"),
+                None => format!("typeof {class_name}"),
+            };
+            result.object_member(&ts_member_name(resource_name), &class_ref);
+        }
     }
 
     Ok(())
 }
 
+/// Quotes a member name if it is not a valid identifier
+fn ts_member_name(name: &str) -> String {
+    let is_identifier = name
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_' || c == '$')
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+    if is_identifier {
+        name.to_string()
+    } else {
+        format!("\"{name}\"")
+    }
+}
+
 fn add_throws_to_doc(
     context: &GeneratorContext,
     interface_stack: &VecDeque<InterfaceId>,
@@ -469,10 +941,9 @@ fn export_type_definition(
                     let imported_module_name =
                         escape_js_ident(imported_interface.module_name()?.to_lower_camel_case());
 
-                    result.import_module(
-                        &imported_module_name,
-                        &imported_interface.fully_qualified_interface_name(),
-                    );
+                    let specifier =
+                        dts_module_specifier(context, result.path(), &imported_interface)?;
+                    result.import_module(&imported_module_name, &specifier);
                 }
             };
 
@@ -485,7 +956,7 @@ fn export_type_definition(
     }
 }
 
-fn ts_type_reference(
+pub fn ts_type_reference(
     context: &GeneratorContext,
     typ: &Type,
     is_field: bool,
@@ -650,13 +1121,18 @@ fn ts_type_definition(
             Ok(record_def)
         }
         TypeDefKind::Handle(handle) => {
-            let resource_type_id = match handle {
-                wit_parser::Handle::Borrow(id) => *id,
-                wit_parser::Handle::Own(id) => *id,
+            let (resource_type_id, brand) = match handle {
+                wit_parser::Handle::Borrow(id) => (*id, "Borrowed"),
+                wit_parser::Handle::Own(id) => (*id, "Owned"),
             };
             let resource_type = context.typ(resource_type_id)?;
+            let brand = if context.options.dts_branded_handles {
+                brand
+            } else {
+                ""
+            };
 
-            ts_resource_reference(context, resource_type, interface_stack)
+            ts_resource_reference(context, resource_type, brand, interface_stack)
         }
         TypeDefKind::Flags(flags) => {
             let mut flags_def = String::new();
@@ -731,24 +1207,30 @@ fn ts_type_definition(
             "{}[]",
             ts_type_reference(context, elem_type, false, interface_stack)?
         )),
+        TypeDefKind::Type(Type::String) if context.is_decimal_type(typ) => {
+            Ok("Decimal".to_string())
+        }
         TypeDefKind::Type(aliased) => ts_type_reference(context, aliased, false, interface_stack),
         TypeDefKind::Future(_) => Err(anyhow!("Future types are not supported yet")),
         TypeDefKind::Stream(_) => Err(anyhow!("Stream types are not supported yet")),
-        TypeDefKind::Resource => ts_resource_reference(context, typ, interface_stack),
+        TypeDefKind::Resource => ts_resource_reference(context, typ, "", interface_stack),
         TypeDefKind::Unknown => Err(anyhow!("Unknown type definition kind")),
     }
 }
 
+/// Refers to the class of a resource, or with a `brand` prefix (`Owned` or `Borrowed`) to its
+/// branded handle type declared by [`declare_branded_handles`]
 fn ts_resource_reference(
     context: &GeneratorContext,
     resource_type: &TypeDef,
+    brand: &str,
     interface_stack: &VecDeque<InterfaceId>,
 ) -> anyhow::Result<String> {
     let resource_name = resource_type
         .name
         .as_ref()
         .ok_or_else(|| anyhow!("Resource type has no name: {resource_type:?}"))?;
-    let js_resource_name = resource_name.to_upper_camel_case();
+    let js_resource_name = format!("{brand}{}", resource_name.to_upper_camel_case());
 
     match &resource_type.owner {
         TypeOwner::Interface(interface_id) => {
@@ -773,28 +1255,48 @@ struct DtsModuleState {
 }
 
 struct DtsWriter {
+    /// The path of the written file, relative to the output directory
+    path: Utf8PathBuf,
     content: String,
     current_indent: usize,
     module_stack: Vec<DtsModuleState>,
+    /// Whether the modules are the files themselves, instead of ambient module declarations
+    module_files: bool,
 }
 
 impl DtsWriter {
-    pub fn new() -> Self {
+    pub fn new(context: &GeneratorContext, path: Utf8PathBuf) -> Self {
         DtsWriter {
+            path,
             content: String::new(),
             current_indent: 0,
             module_stack: Vec::new(),
+            module_files: module_files(context),
         }
     }
 
-    pub fn finish(self, target: &Utf8Path) -> anyhow::Result<()> {
-        std::fs::write(target, self.content)
-            .map_err(|e| anyhow!("Failed to write TypeScript definitions: {e}"))
+    pub fn path(&self) -> &Utf8Path {
+        &self.path
+    }
+
+    /// Writes the declarations into the output directory, returning the path of the file
+    pub fn finish(self, context: &GeneratorContext<'_>) -> anyhow::Result<PathBuf> {
+        let target = context.output.join(&self.path);
+        if let Some(parent) = target.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| anyhow!("Failed to create directory {}: {e}", parent.display()))?;
+        }
+        context
+            .write_generated(&target, self.content)
+            .map_err(|e| anyhow!("Failed to write TypeScript definitions: {e}"))?;
+        Ok(target)
     }
 
     pub fn begin_declare_module(&mut self, name: &str) {
-        self.indented_write_line(format!("declare module '{name}' {{"));
-        self.current_indent += 1;
+        if !self.module_files {
+            self.indented_write_line(format!("declare module '{name}' {{"));
+            self.current_indent += 1;
+        }
         self.module_stack.push(DtsModuleState {
             imports: BTreeSet::new(),
             content: String::new(),
@@ -815,8 +1317,10 @@ impl DtsWriter {
         // Write all lines collected in this module
         self.write(module.content);
 
-        self.current_indent -= 1;
-        self.indented_write_line("}");
+        if !self.module_files {
+            self.current_indent -= 1;
+            self.indented_write_line("}");
+        }
     }
 
     pub fn end_export_module(&mut self) {
@@ -904,10 +1408,39 @@ impl DtsWriter {
         self.indented_write_line("}");
     }
 
+    pub fn begin_default_export_object(&mut self) {
+        self.indented_write_line("const _default: {");
+        self.current_indent += 1;
+    }
+
+    pub fn end_default_export_object(&mut self) {
+        self.current_indent -= 1;
+        self.indented_write_line("};");
+        self.indented_write_line("export default _default;");
+    }
+
+    pub fn begin_object_member(&mut self, name: &str) {
+        self.indented_write_line(format!("{name}: {{"));
+        self.current_indent += 1;
+    }
+
+    pub fn end_object_member(&mut self) {
+        self.current_indent -= 1;
+        self.indented_write_line("};");
+    }
+
+    pub fn object_member(&mut self, name: &str, typ: &str) {
+        self.indented_write_line(format!("{name}: {typ};"));
+    }
+
     pub fn export_type(&mut self, name: &str, definition: &str) {
         self.indented_write_line(format!("export type {name} = {definition};"));
     }
 
+    pub fn export_const(&mut self, name: &str, typ: &str) {
+        self.indented_write_line(format!("export const {name}: {typ};"));
+    }
+
     pub fn import_module(&mut self, name: &str, from: &str) {
         let import_line = format!("import * as {name} from '{from}';");
         if let Some(module) = self.module_stack.last_mut() {
@@ -0,0 +1,818 @@
+use crate::diagnostics::unavailable_apis;
+use crate::pragmas::read_module_variants;
+use crate::skeleton::{cargo_toml_document, defined_features};
+use crate::{Diagnostic, EmbeddingMode, GeneratorContext, JsModuleSpec};
+use anyhow::anyhow;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+use wit_parser::{
+    Function, FunctionKind, Handle, Interface, PackageId, Type, TypeDefKind, TypeId, WorldItem,
+    WorldKey,
+};
+
+/// Maximum number of parameters an exported or imported function can have
+/// (limited by `crate::wrappers::JsArgs` in the skeleton).
+const MAX_PARAMETERS: usize = 26;
+
+/// A single WIT construct of the selected world that cannot be mapped to JavaScript.
+struct UnsupportedItem {
+    /// Human-readable path of the item within the world
+    item: String,
+    /// Source location in the form `path:line`, if it could be found
+    location: Option<String>,
+    reason: String,
+    workaround: &'static str,
+    /// The direction (`import` or `export`) and name of the interface the item belongs to, if
+    /// any
+    interface: Option<(&'static str, String)>,
+}
+
+/// Checks the selected world for WIT constructs that the generator cannot handle, and reports
+/// all of them at once in a single error, instead of failing (or panicking) on the first one
+/// in the middle of code generation.
+pub fn check_world_support(context: &GeneratorContext<'_>) -> anyhow::Result<()> {
+    let mut checker = SupportChecker {
+        context,
+        items: Vec::new(),
+        interface: None,
+        stub_only: false,
+    };
+    checker.check_world();
+
+    if checker.items.is_empty() {
+        Ok(())
+    } else {
+        let mut message = String::new();
+        let _ = writeln!(
+            message,
+            "The selected world `{}` uses WIT constructs that are not supported by wasm-rquickjs:",
+            world_display_name(context)
+        );
+        for item in &checker.items {
+            let _ = writeln!(message);
+            let _ = writeln!(message, "  - {}: {}", item.item, item.reason);
+            if let Some(location) = &item.location {
+                let _ = writeln!(message, "      at {location}");
+            }
+            let _ = writeln!(message, "      workaround: {}", item.workaround);
+        }
+        Err(anyhow!(message.trim_end().to_string()))
+    }
+}
+
+/// Finds the imported and exported interfaces of the selected world using WIT constructs that
+/// are not supported, to be skipped in `keep_going` mode. Unsupported items outside of
+/// interfaces are not reported, as they cannot be skipped.
+pub fn unsupported_interfaces(context: &GeneratorContext<'_>) -> Vec<Diagnostic> {
+    let mut checker = SupportChecker {
+        context,
+        items: Vec::new(),
+        interface: None,
+        stub_only: false,
+    };
+    checker.check_world();
+
+    let mut interfaces: BTreeMap<(&str, String), Vec<String>> = BTreeMap::new();
+    for item in checker.items {
+        if let Some(interface) = item.interface {
+            interfaces
+                .entry(interface)
+                .or_default()
+                .push(format!("{}: {}", item.item, item.reason));
+        }
+    }
+    interfaces
+        .into_iter()
+        .map(
+            |((direction, interface), reasons)| Diagnostic::SkippedInterface {
+                interface,
+                exported: direction == "export",
+                reasons,
+            },
+        )
+        .collect()
+}
+
+/// Checks that the interfaces to skip or deny given in the generator options are imported or
+/// exported by the selected world
+pub fn check_skipped_interfaces(context: &GeneratorContext<'_>) -> anyhow::Result<()> {
+    let world = &context.resolve.worlds[context.world];
+    for (option, direction, selectors, items) in [
+        (
+            "skip-import",
+            "import",
+            &context.options.skip_imports,
+            &world.imports,
+        ),
+        (
+            "deny-import",
+            "import",
+            &context.options.deny_imports,
+            &world.imports,
+        ),
+        (
+            "skip-export",
+            "export",
+            &context.options.skip_exports,
+            &world.exports,
+        ),
+    ] {
+        for selector in selectors {
+            let found = items.iter().any(|(key, item)| {
+                matches!(item, WorldItem::Interface { .. })
+                    && context.matches_interface_selector(key, std::slice::from_ref(selector))
+            });
+            if !found {
+                return Err(anyhow!(
+                    "The {option} interface `{selector}` is not {direction}ed by the selected world `{}`",
+                    world_display_name(context)
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Checks that the types to represent by the `Decimal` class given in the generator options are
+/// aliases of `string`
+pub fn check_decimal_types(context: &GeneratorContext<'_>) -> anyhow::Result<()> {
+    for name in &context.options.decimal_types {
+        let found = context
+            .resolve
+            .types
+            .iter()
+            .any(|(_, typ)| typ.name.as_ref() == Some(name) && context.is_decimal_type(typ));
+        if !found {
+            return Err(anyhow!(
+                "The decimal type `{name}` is not an alias of `string` defined in the WIT package or its dependencies"
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Checks that the keys of the defines given in the generator options are JS identifiers, as only
+/// these can be written as `process.env.KEY`
+pub fn check_defines(context: &GeneratorContext<'_>) -> anyhow::Result<()> {
+    for key in context.options.defines.keys() {
+        let mut chars = key.chars();
+        let valid = chars
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '_' || c == '$')
+            && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+        if !valid {
+            return Err(anyhow!(
+                "Invalid define key `{key}`, it must be a JavaScript identifier such as `API_URL`"
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Checks that the base URL and gateway of `fetch` given in the generator options are HTTP URLs, as
+/// the generated component would otherwise fail on its first request
+pub fn check_http_urls(context: &GeneratorContext<'_>) -> anyhow::Result<()> {
+    for (option, url) in [
+        ("fetch base URL", &context.options.fetch_base_url),
+        ("fetch gateway", &context.options.fetch_gateway),
+    ] {
+        if let Some(url) = url
+            && !(url.starts_with("http://") || url.starts_with("https://"))
+        {
+            return Err(anyhow!(
+                "Invalid {option} `{url}`, it must be an absolute `http://` or `https://` URL"
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Checks that the builtin permissions given in the generator options refer to the embedded JS
+/// modules and to builtin modules
+pub fn check_builtin_permissions(
+    context: &GeneratorContext<'_>,
+    js_modules: &[JsModuleSpec],
+) -> anyhow::Result<()> {
+    for (module, builtins) in &context.options.builtin_permissions {
+        if !js_modules.iter().any(|js_module| &js_module.name == module) {
+            return Err(anyhow!(
+                "The builtin permissions are given for `{module}`, which is not one of the JS modules"
+            ));
+        }
+        for builtin in builtins {
+            if builtin_module_names(builtin).is_empty() {
+                return Err(anyhow!(
+                    "Unknown builtin module `{builtin}` in the builtin permissions of `{module}`, expected one of {}",
+                    BUILTIN_MODULES
+                        .iter()
+                        .map(|(name, _)| format!("`{name}`"))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// The builtin modules the JS modules can import, and whether they can also be imported with a
+/// `node:` prefix
+const BUILTIN_MODULES: &[(&str, bool)] = &[
+    ("assets", false),
+    ("base64-js", false),
+    ("buffer", true),
+    ("cancellation", false),
+    ("cbor", false),
+    ("compression", false),
+    ("csv", false),
+    ("fs", true),
+    ("grpc-web", false),
+    ("hashing", false),
+    ("html", false),
+    ("ieee754", false),
+    ("json-stream", false),
+    ("jwt", false),
+    ("messageformat", false),
+    ("msgpack", false),
+    ("passwords", false),
+    ("process", true),
+    ("protobuf", false),
+    ("re", false),
+    ("temporal", false),
+    ("templates", false),
+    ("toml", false),
+    ("ulid", false),
+    ("util", true),
+    ("uuid", false),
+    ("xml", false),
+    ("yaml", false),
+];
+
+/// Gets all the names a builtin module can be imported by, given by any of them, or nothing for
+/// unknown modules
+pub fn builtin_module_names(name: &str) -> Vec<String> {
+    let bare_name = name.strip_prefix("node:").unwrap_or(name);
+    match BUILTIN_MODULES
+        .iter()
+        .find(|(module, _)| *module == bare_name)
+    {
+        Some((module, true)) => vec![module.to_string(), format!("node:{module}")],
+        Some((module, false)) if bare_name == name => vec![module.to_string()],
+        _ => Vec::new(),
+    }
+}
+
+/// Checks that the `// #if feature(name)` pragmas of the embedded JS modules are well-formed and
+/// only refer to the features of the wrapper crate
+pub fn check_js_pragmas(
+    context: &GeneratorContext<'_>,
+    js_modules: &[JsModuleSpec],
+) -> anyhow::Result<()> {
+    let defined_features = defined_features(&cargo_toml_document(context)?);
+    for module in js_modules {
+        if let EmbeddingMode::EmbedFile(path) = &module.mode {
+            let variants = read_module_variants(module, path)?;
+            for (feature, _) in &variants[0].features {
+                if !defined_features.contains(feature) {
+                    return Err(anyhow!(
+                        "The JS module `{}` refers to the unknown feature `{feature}`, the features of the wrapper crate are {}",
+                        module.name,
+                        defined_features.join(", ")
+                    ));
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Checks that the default features given in the generator options are features of the wrapper
+/// crate
+pub fn check_default_features(context: &GeneratorContext<'_>) -> anyhow::Result<()> {
+    if let Some(features) = &context.options.default_features {
+        let defined_features = defined_features(&cargo_toml_document(context)?);
+        for feature in features {
+            if !defined_features.contains(feature) {
+                return Err(anyhow!(
+                    "Unknown feature `{feature}`, the features of the wrapper crate are {}",
+                    defined_features.join(", ")
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Fails if the embedded JS modules use APIs not available with the default features, when this
+/// is denied in the generator options
+pub fn check_api_availability(
+    context: &GeneratorContext<'_>,
+    js_modules: &[JsModuleSpec],
+) -> anyhow::Result<()> {
+    if !context.options.deny_unavailable_apis {
+        return Ok(());
+    }
+    let unavailable = unavailable_apis(context, js_modules)?;
+    if unavailable.is_empty() {
+        return Ok(());
+    }
+    let mut message =
+        "The JS modules use APIs not available with the default features of the wrapper crate:"
+            .to_string();
+    for diagnostic in unavailable {
+        write!(message, "\n  - {diagnostic}").unwrap();
+    }
+    Err(anyhow!(message))
+}
+
+/// Directories of the wrapper crate's `src` directory holding files of the skeleton or generated
+/// ones, which embedded JS modules cannot be written to
+const RESERVED_MODULE_DIRECTORIES: [&str; 2] = ["builtin", "modules"];
+
+/// Checks that exactly one of the JS modules is the entry module, that their names are
+/// `/`-separated relative paths (such as `lib/utils`), and that no two of them are written to the
+/// same path of the wrapper crate
+pub fn check_js_modules(js_modules: &[JsModuleSpec]) -> anyhow::Result<()> {
+    let entries = js_modules
+        .iter()
+        .filter(|module| module.entry)
+        .map(|module| format!("`{}`", module.name))
+        .collect::<Vec<_>>();
+    match entries.len() {
+        1 => {}
+        0 => {
+            return Err(anyhow!(
+                "None of the JS modules is marked as the entry module implementing the exports"
+            ));
+        }
+        _ => {
+            return Err(anyhow!(
+                "Only one JS module can be the entry module, but {} are marked as entry",
+                entries.join(", ")
+            ));
+        }
+    }
+
+    // The files and directories the modules are written to, compared case-insensitively as they
+    // could end up on a case-insensitive file system
+    let mut file_names = BTreeMap::new();
+    let mut directories = BTreeMap::new();
+    for module in js_modules {
+        let name = &module.name;
+        let segments = name.split('/').collect::<Vec<_>>();
+        let is_valid = segments
+            .iter()
+            .all(|segment| !segment.is_empty() && *segment != "." && *segment != "..")
+            && !name.contains('\\');
+        if !is_valid {
+            return Err(anyhow!(
+                "Invalid JS module name `{name}`, it must be a relative path such as `lib/utils`"
+            ));
+        }
+        if !matches!(module.mode, EmbeddingMode::EmbedFile(_)) {
+            continue;
+        }
+        if segments.len() > 1 && RESERVED_MODULE_DIRECTORIES.contains(&segments[0]) {
+            return Err(anyhow!(
+                "The JS module name `{name}` cannot start with `{}/`, it is reserved for the generated code",
+                segments[0]
+            ));
+        }
+        let file_name = module.file_name();
+        if let Some(other) = file_names.insert(file_name.to_lowercase(), name) {
+            return Err(anyhow!(
+                "The JS modules `{other}` and `{name}` would both be embedded as `{file_name}`"
+            ));
+        }
+        for end in 1..segments.len() {
+            directories.insert(segments[..end].join("/").to_lowercase(), name);
+        }
+    }
+    for (file_name, name) in &file_names {
+        if let Some(other) = directories.get(file_name) {
+            return Err(anyhow!(
+                "The JS module `{name}` would be embedded as `{name}.js`, which is a directory of the JS module `{other}`"
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn world_display_name(context: &GeneratorContext<'_>) -> String {
+    let world = &context.resolve.worlds[context.world];
+    match world.package {
+        Some(package_id) => context.resolve.packages[package_id]
+            .name
+            .interface_id(&world.name),
+        None => world.name.clone(),
+    }
+}
+
+struct SupportChecker<'a> {
+    context: &'a GeneratorContext<'a>,
+    items: Vec<UnsupportedItem>,
+    /// The interface whose items are being checked
+    interface: Option<(&'static str, String)>,
+    /// Whether only the constructs preventing the generation of trapping stubs are checked, for
+    /// skipped exports
+    stub_only: bool,
+}
+
+impl<'a> SupportChecker<'a> {
+    fn check_world(&mut self) {
+        let world = &self.context.resolve.worlds[self.context.world];
+
+        for (direction, items) in [("import", &world.imports), ("export", &world.exports)] {
+            for (key, item) in items {
+                // Skipped imports are not exposed to JS, so they may use anything
+                if direction == "import" && self.context.is_skipped_import(key) {
+                    continue;
+                }
+                // Skipped exports are implemented by stubs, which do not call JS
+                self.stub_only = direction == "export" && self.context.is_skipped_export(key);
+                self.check_world_item(direction, key, item);
+            }
+        }
+    }
+
+    fn check_world_item(&mut self, direction: &'static str, key: &WorldKey, item: &WorldItem) {
+        match item {
+            WorldItem::Interface { id, .. } => {
+                let interface = &self.context.resolve.interfaces[*id];
+                let name = self.context.resolve.name_world_key(key);
+                let display = format!("{direction} `{name}`");
+                self.interface = Some((direction, name));
+
+                let world = &self.context.resolve.worlds[self.context.world];
+                let (package, scope) = match &interface.name {
+                    Some(name) => (
+                        interface.package,
+                        vec![Anchor::Prefix(format!("interface {name}"))],
+                    ),
+                    None => (
+                        world.package,
+                        vec![
+                            Anchor::Prefix(format!("world {}", world.name)),
+                            Anchor::Prefix(format!("{direction} {}", key_name(key))),
+                        ],
+                    ),
+                };
+
+                // Anonymous exports are supported as long as they only contain functions
+                if interface.name.is_none() && direction == "export" && !interface.types.is_empty()
+                {
+                    let location = self.locate(package, &scope);
+                    self.items.push(UnsupportedItem {
+                        item: display.clone(),
+                        location,
+                        reason: "anonymous (inline) interface exports defining types are not supported".to_string(),
+                        workaround: "declare the interface with a name in the WIT package and refer to it by name in the world",
+                        interface: self.interface.clone(),
+                    });
+                }
+
+                self.check_interface(&display, interface, package, &scope);
+                self.interface = None;
+            }
+            WorldItem::Function(function) => {
+                let name = key_name(key);
+                let name = name.strip_prefix("[async]").unwrap_or(&name);
+                let display = format!("{direction} `{name}`");
+                let anchors = vec![Anchor::Prefix(format!("{direction} {name}"))];
+                let location = self.locate_in_world(&anchors);
+                self.check_function(&display, function, location);
+            }
+            WorldItem::Type(type_id) => {
+                let typ = &self.context.resolve.types[*type_id];
+                let name = typ.name.clone().unwrap_or_default();
+                let display = format!("type `{name}`");
+                let location = self.locate_in_world(&type_anchors(&name));
+                self.check_type_definition(&display, *type_id, location);
+            }
+        }
+    }
+
+    /// Checks all functions and types of an interface. The `scope` anchors locate the interface's
+    /// definition within the source files of `package`.
+    fn check_interface(
+        &mut self,
+        display: &str,
+        interface: &Interface,
+        package: Option<PackageId>,
+        scope: &[Anchor],
+    ) {
+        for (name, function) in &interface.functions {
+            let name = name.strip_prefix("[async]").unwrap_or(name);
+            let function_display = format!("{display}, function `{name}`");
+            let mut anchors = scope.to_vec();
+            anchors.extend(function_anchors(function));
+            let location = self.locate(package, &anchors);
+            self.check_function(&function_display, function, location);
+        }
+
+        for (name, type_id) in &interface.types {
+            let type_display = format!("{display}, type `{name}`");
+            let mut anchors = scope.to_vec();
+            anchors.extend(type_anchors(name));
+            let location = self.locate(package, &anchors);
+            self.check_type_definition(&type_display, *type_id, location);
+        }
+    }
+
+    fn check_function(&mut self, display: &str, function: &Function, location: Option<String>) {
+        if matches!(
+            function.kind,
+            FunctionKind::AsyncFreestanding
+                | FunctionKind::AsyncMethod(_)
+                | FunctionKind::AsyncStatic(_)
+        ) {
+            self.items.push(UnsupportedItem {
+                item: display.to_string(),
+                location: location.clone(),
+                reason: "async functions are not supported".to_string(),
+                workaround: "declare the function without `async`; the JavaScript implementation can still be async and return a Promise",
+                interface: self.interface.clone(),
+            });
+        }
+
+        let parameter_count = match function.kind {
+            FunctionKind::Method(_) | FunctionKind::AsyncMethod(_) => function.params.len() - 1,
+            _ => function.params.len(),
+        };
+        if parameter_count > MAX_PARAMETERS && !self.stub_only {
+            self.items.push(UnsupportedItem {
+                item: display.to_string(),
+                location: location.clone(),
+                reason: format!(
+                    "functions with more than {MAX_PARAMETERS} parameters are not supported (found {parameter_count})"
+                ),
+                workaround: "group some of the parameters into a record",
+                interface: self.interface.clone(),
+            });
+        }
+
+        let mut found = BTreeSet::new();
+        let mut visited = BTreeSet::new();
+        for (_, param_type) in &function.params {
+            self.collect_unsupported_types(param_type, &mut visited, &mut found);
+        }
+        if let Some(result) = &function.result {
+            self.collect_unsupported_types(result, &mut visited, &mut found);
+        }
+        self.report_unsupported_types(display, location, found);
+    }
+
+    fn check_type_definition(&mut self, display: &str, type_id: TypeId, location: Option<String>) {
+        let mut found = BTreeSet::new();
+        let mut visited = BTreeSet::new();
+        self.collect_unsupported_types(&Type::Id(type_id), &mut visited, &mut found);
+        self.report_unsupported_types(display, location, found);
+    }
+
+    fn report_unsupported_types(
+        &mut self,
+        display: &str,
+        location: Option<String>,
+        found: BTreeSet<UnsupportedType>,
+    ) {
+        for unsupported in found {
+            self.items.push(UnsupportedItem {
+                item: display.to_string(),
+                location: location.clone(),
+                reason: unsupported.reason().to_string(),
+                workaround: unsupported.workaround(),
+                interface: self.interface.clone(),
+            });
+        }
+    }
+
+    /// Recursively collects unsupported type constructs used by `typ`.
+    ///
+    /// Resource handles are not followed, as the resource's own methods are checked
+    /// as part of the owning interface.
+    fn collect_unsupported_types(
+        &self,
+        typ: &Type,
+        visited: &mut BTreeSet<TypeId>,
+        found: &mut BTreeSet<UnsupportedType>,
+    ) {
+        match typ {
+            Type::ErrorContext => {
+                found.insert(UnsupportedType::ErrorContext);
+            }
+            Type::Id(type_id) => {
+                if !visited.insert(*type_id) {
+                    return;
+                }
+                let Some(typ) = self.context.resolve.types.get(*type_id) else {
+                    return;
+                };
+                match &typ.kind {
+                    TypeDefKind::Record(record) => {
+                        for field in &record.fields {
+                            self.collect_unsupported_types(&field.ty, visited, found);
+                        }
+                    }
+                    TypeDefKind::Tuple(tuple) => {
+                        for item in &tuple.types {
+                            self.collect_unsupported_types(item, visited, found);
+                        }
+                    }
+                    TypeDefKind::Variant(variant) => {
+                        for case in &variant.cases {
+                            if let Some(ty) = &case.ty {
+                                self.collect_unsupported_types(ty, visited, found);
+                            }
+                        }
+                    }
+                    TypeDefKind::Option(inner) | TypeDefKind::List(inner) => {
+                        self.collect_unsupported_types(inner, visited, found);
+                    }
+                    TypeDefKind::Result(result) => {
+                        if let Some(ok) = &result.ok {
+                            self.collect_unsupported_types(ok, visited, found);
+                        }
+                        if let Some(err) = &result.err {
+                            self.collect_unsupported_types(err, visited, found);
+                        }
+                    }
+                    TypeDefKind::Type(inner) => {
+                        self.collect_unsupported_types(inner, visited, found);
+                    }
+                    TypeDefKind::FixedSizeList(inner, _) => {
+                        found.insert(UnsupportedType::FixedSizeList);
+                        self.collect_unsupported_types(inner, visited, found);
+                    }
+                    TypeDefKind::Future(_) => {
+                        found.insert(UnsupportedType::Future);
+                    }
+                    TypeDefKind::Stream(_) => {
+                        found.insert(UnsupportedType::Stream);
+                    }
+                    TypeDefKind::Unknown => {
+                        found.insert(UnsupportedType::Unknown);
+                    }
+                    TypeDefKind::Handle(Handle::Own(_) | Handle::Borrow(_))
+                    | TypeDefKind::Resource
+                    | TypeDefKind::Flags(_)
+                    | TypeDefKind::Enum(_) => {}
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn locate_in_world(&self, anchors: &[Anchor]) -> Option<String> {
+        let world = &self.context.resolve.worlds[self.context.world];
+        let mut world_anchors = vec![Anchor::Prefix(format!("world {}", world.name))];
+        world_anchors.extend(anchors.iter().cloned());
+        self.locate(world.package, &world_anchors)
+    }
+
+    /// Finds the source location of a WIT item by searching the source files of `package` for a
+    /// sequence of line prefixes (for example `interface api` followed by `fun1:`).
+    ///
+    /// Returns the location of the last matched anchor as `path:line`.
+    fn locate(&self, package: Option<PackageId>, anchors: &[Anchor]) -> Option<String> {
+        let package = package?;
+        let paths = self.context.source_map.package_paths(package)?;
+
+        for path in paths {
+            let Ok(source) = std::fs::read_to_string(path) else {
+                continue;
+            };
+            let lines: Vec<&str> = source.lines().collect();
+
+            let mut line_idx = 0;
+            let mut last_match = None;
+            for anchor in anchors {
+                match (line_idx..lines.len()).find(|idx| anchor.matches(lines[*idx])) {
+                    Some(idx) => {
+                        last_match = Some(idx);
+                        line_idx = idx + 1;
+                    }
+                    None => break,
+                }
+            }
+
+            if let Some(idx) = last_match {
+                return Some(format!("{}:{}", path.display(), idx + 1));
+            }
+        }
+
+        None
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum UnsupportedType {
+    Future,
+    Stream,
+    ErrorContext,
+    FixedSizeList,
+    Unknown,
+}
+
+impl UnsupportedType {
+    fn reason(&self) -> &'static str {
+        match self {
+            UnsupportedType::Future => "`future` types are not supported",
+            UnsupportedType::Stream => "`stream` types are not supported",
+            UnsupportedType::ErrorContext => "the `error-context` type is not supported",
+            UnsupportedType::FixedSizeList => "fixed-size lists are not supported",
+            UnsupportedType::Unknown => "the type could not be resolved",
+        }
+    }
+
+    fn workaround(&self) -> &'static str {
+        match self {
+            UnsupportedType::Future => {
+                "return the value directly; the JavaScript implementation can be async"
+            }
+            UnsupportedType::Stream => {
+                "use `wasi:io/streams` resources or a resource with explicit read/write methods"
+            }
+            UnsupportedType::ErrorContext => {
+                "use a `result` with a custom error type (for example a `string` or a variant)"
+            }
+            UnsupportedType::FixedSizeList => "use `list<T>` or a `tuple` instead",
+            UnsupportedType::Unknown => "check that all the WIT dependencies are available",
+        }
+    }
+}
+
+fn key_name(key: &WorldKey) -> String {
+    match key {
+        WorldKey::Name(name) => name.clone(),
+        WorldKey::Interface(_) => String::new(),
+    }
+}
+
+fn function_anchors(function: &Function) -> Vec<Anchor> {
+    let name = function
+        .name
+        .strip_prefix("[async]")
+        .unwrap_or(&function.name);
+    match &function.kind {
+        FunctionKind::Freestanding | FunctionKind::AsyncFreestanding => {
+            vec![Anchor::Prefix(format!("{name}:"))]
+        }
+        FunctionKind::Method(_)
+        | FunctionKind::AsyncMethod(_)
+        | FunctionKind::Static(_)
+        | FunctionKind::AsyncStatic(_) => {
+            let (resource, method) = name
+                .split_once(']')
+                .and_then(|(_, rest)| rest.split_once('.'))
+                .unwrap_or(("", name));
+            vec![
+                Anchor::Prefix(format!("resource {resource}")),
+                Anchor::Prefix(format!("{method}:")),
+            ]
+        }
+        FunctionKind::Constructor(_) => {
+            let resource = name.strip_prefix("[constructor]").unwrap_or(name);
+            vec![
+                Anchor::Prefix(format!("resource {resource}")),
+                Anchor::Prefix("constructor".to_string()),
+            ]
+        }
+    }
+}
+
+fn type_anchors(name: &str) -> Vec<Anchor> {
+    vec![Anchor::TypeDefinition(name.to_string())]
+}
+
+/// A line pattern used to find the source location of a WIT item
+#[derive(Clone)]
+enum Anchor {
+    /// A line starting with the given prefix
+    Prefix(String),
+    /// A line defining a type (`record`, `variant`, `type`, etc.) with the given name
+    TypeDefinition(String),
+}
+
+impl Anchor {
+    fn matches(&self, line: &str) -> bool {
+        match self {
+            Anchor::Prefix(prefix) => line_starts_with(line, prefix),
+            Anchor::TypeDefinition(name) => TYPE_KEYWORDS
+                .iter()
+                .any(|keyword| line_starts_with(line, &format!("{keyword} {name}"))),
+        }
+    }
+}
+
+const TYPE_KEYWORDS: [&str; 6] = ["record", "variant", "enum", "flags", "type", "resource"];
+
+/// Checks if the trimmed `line` starts with `anchor`, followed by a character that cannot be
+/// part of a WIT identifier (so `foo` does not match `foo-bar`).
+fn line_starts_with(line: &str, anchor: &str) -> bool {
+    let line = line.trim_start();
+    let line = line.strip_prefix('%').unwrap_or(line);
+    match line.strip_prefix(anchor) {
+        Some(rest) => !rest.starts_with(|c: char| c.is_alphanumeric() || c == '-' || c == '_'),
+        None => false,
+    }
+}
@@ -0,0 +1,211 @@
+// Runtime checks of JS values against WIT types. The generated `validateX` functions at the end
+// of the module are built from these, and throw a TypeError pointing to the first invalid value,
+// for example `orders[3].customer: expected string, got number`.
+
+function typeName(value) {
+    if (value === null) {
+        return 'null';
+    }
+    if (Array.isArray(value)) {
+        return 'array';
+    }
+    if (value instanceof Uint8Array) {
+        return 'Uint8Array';
+    }
+    return typeof value;
+}
+
+function invalid(path, message) {
+    throw new TypeError(path ? `${path}: ${message}` : message);
+}
+
+function mismatch(path, expected, value) {
+    invalid(path, `expected ${expected}, got ${typeName(value)}`);
+}
+
+function fieldPath(path, name) {
+    return path ? `${path}.${name}` : name;
+}
+
+function indexPath(path, index) {
+    return `${path}[${index}]`;
+}
+
+function isObject(value) {
+    return typeof value === 'object' && value !== null;
+}
+
+function integer(type, min, max) {
+    return (value, path) => {
+        if (typeof value !== 'number') {
+            mismatch(path, type, value);
+        }
+        if (!Number.isInteger(value) || value < min || value > max) {
+            invalid(path, `expected ${type}, got ${value}`);
+        }
+    };
+}
+
+function bigInteger(type, min, max) {
+    return (value, path) => {
+        if (typeof value !== 'bigint') {
+            mismatch(path, type, value);
+        }
+        if (value < min || value > max) {
+            invalid(path, `expected ${type}, got ${value}`);
+        }
+    };
+}
+
+function float(type) {
+    return (value, path) => {
+        if (typeof value !== 'number') {
+            mismatch(path, type, value);
+        }
+    };
+}
+
+const bool = (value, path) => {
+    if (typeof value !== 'boolean') {
+        mismatch(path, 'bool', value);
+    }
+};
+const u8 = integer('u8', 0, 0xff);
+const u16 = integer('u16', 0, 0xffff);
+const u32 = integer('u32', 0, 0xffffffff);
+const u64 = bigInteger('u64', 0n, 0xffffffffffffffffn);
+const s8 = integer('s8', -0x80, 0x7f);
+const s16 = integer('s16', -0x8000, 0x7fff);
+const s32 = integer('s32', -0x80000000, 0x7fffffff);
+const s64 = bigInteger('s64', -0x8000000000000000n, 0x7fffffffffffffffn);
+const f32 = float('f32');
+const f64 = float('f64');
+
+const char = (value, path) => {
+    if (typeof value !== 'string') {
+        mismatch(path, 'char', value);
+    }
+    if ([...value].length !== 1) {
+        invalid(path, `expected char, got a string of length ${value.length}`);
+    }
+};
+
+const string = (value, path) => {
+    if (typeof value !== 'string') {
+        mismatch(path, 'string', value);
+    }
+};
+
+const decimal = (value, path) => {
+    if (!(value instanceof Decimal) && typeof value !== 'string' && typeof value !== 'number') {
+        mismatch(path, 'Decimal', value);
+    }
+    try {
+        new Decimal(value);
+    } catch (error) {
+        invalid(path, `expected Decimal, got ${JSON.stringify(value)}`);
+    }
+};
+
+const bytes = (value, path) => {
+    if (!(value instanceof Uint8Array)) {
+        mismatch(path, 'list<u8> (Uint8Array)', value);
+    }
+};
+
+function option(inner) {
+    return (value, path) => {
+        if (value !== undefined && value !== null) {
+            inner(value, path);
+        }
+    };
+}
+
+function list(type, item) {
+    return (value, path) => {
+        if (!Array.isArray(value)) {
+            mismatch(path, type, value);
+        }
+        value.forEach((element, index) => item(element, indexPath(path, index)));
+    };
+}
+
+function tuple(type, items) {
+    return (value, path) => {
+        if (!Array.isArray(value)) {
+            mismatch(path, type, value);
+        }
+        if (value.length !== items.length) {
+            invalid(path, `expected ${type}, got an array of length ${value.length}`);
+        }
+        items.forEach((item, index) => item(value[index], indexPath(path, index)));
+    };
+}
+
+function handle(type) {
+    return (value, path) => {
+        if (!isObject(value)) {
+            mismatch(path, type, value);
+        }
+    };
+}
+
+function tagged(type, cases) {
+    return (value, path) => {
+        if (!isObject(value)) {
+            mismatch(path, type, value);
+        }
+        if (!Object.hasOwn(cases, value.tag)) {
+            const expected = Object.keys(cases).map((tag) => JSON.stringify(tag)).join(', ');
+            invalid(fieldPath(path, 'tag'), `expected one of ${expected}, got ${JSON.stringify(value.tag)}`);
+        }
+        const inner = cases[value.tag];
+        if (inner) {
+            inner(value.val, fieldPath(path, 'val'));
+        }
+    };
+}
+
+function result(type, ok, err) {
+    return tagged(type, { ok, err });
+}
+
+function variant(type, cases) {
+    return tagged(type, cases);
+}
+
+function enumeration(type, cases) {
+    return (value, path) => {
+        if (typeof value !== 'string') {
+            mismatch(path, type, value);
+        }
+        if (!cases.includes(value)) {
+            const expected = cases.map((name) => JSON.stringify(name)).join(', ');
+            invalid(path, `expected one of ${expected}, got ${JSON.stringify(value)}`);
+        }
+    };
+}
+
+function flags(type, names) {
+    return (value, path) => {
+        if (!isObject(value)) {
+            mismatch(path, type, value);
+        }
+        for (const name of names) {
+            if (typeof value[name] !== 'boolean') {
+                mismatch(fieldPath(path, name), 'bool', value[name]);
+            }
+        }
+    };
+}
+
+function record(type, fields) {
+    return (value, path) => {
+        if (!isObject(value)) {
+            mismatch(path, type, value);
+        }
+        for (const [name, field] of fields) {
+            field(value[name], fieldPath(path, name));
+        }
+    };
+}
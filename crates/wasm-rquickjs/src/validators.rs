@@ -0,0 +1,283 @@
+use crate::GeneratorContext;
+use crate::conversions::wit_type_name;
+use crate::javascript::escape_js_ident;
+use anyhow::{Context, anyhow};
+use heck::{ToLowerCamelCase, ToUpperCamelCase};
+use std::collections::HashMap;
+use wit_parser::{Handle, Type, TypeDefKind, TypeId, TypeOwner, WorldItem};
+
+/// The runtime checks the generated validator functions are built from
+const VALIDATORS_RUNTIME: &str = include_str!("validators.js");
+
+/// A WIT type with a generated `validateX` function in the `validators` module
+pub struct ValidatedType {
+    pub type_id: TypeId,
+    /// The type's name in the generated functions, `X` in `validateX`
+    pub name: String,
+}
+
+/// Writes the `validators` JS module to `<output>/src/modules/validators.js`
+pub fn generate_validators_module(context: &GeneratorContext<'_>) -> anyhow::Result<()> {
+    let types = collect_validated_types(context)?;
+    let names: HashMap<TypeId, &str> = types
+        .iter()
+        .map(|typ| (typ.type_id, typ.name.as_str()))
+        .collect();
+
+    let mut source = VALIDATORS_RUNTIME.to_string();
+    for typ in &types {
+        let definition = type_definition(context, typ.type_id, &names)?;
+        source.push_str(&format!(
+            "\nfunction check{name}(value, path) {{\n    {definition}(value, path);\n}}\n",
+            name = typ.name
+        ));
+    }
+    for typ in &types {
+        source.push_str(&format!(
+            "\nexport function validate{name}(value) {{\n    check{name}(value, '');\n}}\n",
+            name = typ.name
+        ));
+    }
+
+    context
+        .write_generated(
+            &context
+                .output
+                .join("src")
+                .join("modules")
+                .join("validators.js"),
+            source,
+        )
+        .context("Failed to write the validators module")
+}
+
+/// Collects the named types defined in the world and in its imported and exported interfaces,
+/// except the skipped ones. Resources have no validators, and types brought into scope with `use`
+/// are validated as the type they refer to.
+///
+/// Types are named by their UpperCamelCase WIT name, qualified with the name of their interface
+/// (or with its fully qualified name) when that is not unique.
+pub fn collect_validated_types(
+    context: &GeneratorContext<'_>,
+) -> anyhow::Result<Vec<ValidatedType>> {
+    let world = &context.resolve.worlds[context.world];
+
+    let mut type_ids = Vec::new();
+    let mut add_type = |type_id: TypeId| -> anyhow::Result<()> {
+        let type_id = used_type(context, type_id)?;
+        if !matches!(context.typ(type_id)?.kind, TypeDefKind::Resource)
+            && !type_ids.contains(&type_id)
+        {
+            type_ids.push(type_id);
+        }
+        Ok(())
+    };
+    for (key, item) in &world.imports {
+        match item {
+            WorldItem::Interface { id, .. } if !context.is_skipped_import(key) => {
+                for type_id in context.resolve.interfaces[*id].types.values() {
+                    add_type(*type_id)?;
+                }
+            }
+            WorldItem::Type(type_id) => add_type(*type_id)?,
+            _ => {}
+        }
+    }
+    for (key, item) in &world.exports {
+        if let WorldItem::Interface { id, .. } = item
+            && !context.is_skipped_export(key)
+        {
+            for type_id in context.resolve.interfaces[*id].types.values() {
+                add_type(*type_id)?;
+            }
+        }
+    }
+
+    let qualified_names = type_ids
+        .iter()
+        .map(|type_id| qualified_type_names(context, *type_id))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    let is_unique = |level: usize, name: &str| {
+        qualified_names
+            .iter()
+            .filter(|names| names[level] == name)
+            .count()
+            == 1
+    };
+
+    Ok(type_ids
+        .into_iter()
+        .zip(&qualified_names)
+        .map(|(type_id, names)| {
+            let level = (0..names.len() - 1)
+                .find(|level| is_unique(*level, &names[*level]))
+                .unwrap_or(names.len() - 1);
+            ValidatedType {
+                type_id,
+                name: names[level].clone(),
+            }
+        })
+        .collect())
+}
+
+/// Follows the `use` aliases of a type, which have the same name as the type they refer to
+fn used_type(context: &GeneratorContext<'_>, type_id: TypeId) -> anyhow::Result<TypeId> {
+    let typ = context.typ(type_id)?;
+    if let TypeDefKind::Type(Type::Id(target)) = &typ.kind
+        && context.typ(*target)?.name == typ.name
+    {
+        return used_type(context, *target);
+    }
+    Ok(type_id)
+}
+
+/// The name of a type, qualified with the name of its interface, and fully qualified
+fn qualified_type_names(
+    context: &GeneratorContext<'_>,
+    type_id: TypeId,
+) -> anyhow::Result<[String; 3]> {
+    let typ = context.typ(type_id)?;
+    let name = typ
+        .name
+        .as_deref()
+        .ok_or_else(|| anyhow!("Validated type has no name"))?;
+    let (owner, package) = match typ.owner {
+        TypeOwner::Interface(interface_id) => {
+            let interface = &context.resolve.interfaces[interface_id];
+            let package = interface
+                .package
+                .map(|package| context.resolve.packages[package].name.to_string())
+                .unwrap_or_default();
+            (context.interface_name(interface_id)?, package)
+        }
+        _ => (context.world_name.as_str(), String::new()),
+    };
+    Ok([
+        name.to_upper_camel_case(),
+        format!("{owner}-{name}").to_upper_camel_case(),
+        format!("{package}-{owner}-{name}").to_upper_camel_case(),
+    ])
+}
+
+/// Builds the JS expression checking a value of a named type
+fn type_definition(
+    context: &GeneratorContext<'_>,
+    type_id: TypeId,
+    names: &HashMap<TypeId, &str>,
+) -> anyhow::Result<String> {
+    let typ = context.typ(type_id)?;
+    let label = format!("'{}'", wit_type_name(context, &Type::Id(type_id))?);
+    let checker = |typ: &Type| type_checker(context, typ, names);
+
+    match &typ.kind {
+        TypeDefKind::Record(record) => {
+            let fields = record
+                .fields
+                .iter()
+                .map(|field| {
+                    let js_name = escape_js_ident(field.name.to_lower_camel_case());
+                    Ok(format!("['{js_name}', {}]", checker(&field.ty)?))
+                })
+                .collect::<anyhow::Result<Vec<_>>>()?;
+            Ok(format!("record({label}, [{}])", fields.join(", ")))
+        }
+        TypeDefKind::Flags(flags) => {
+            let names = flags
+                .flags
+                .iter()
+                .map(|flag| format!("'{}'", escape_js_ident(flag.name.to_lower_camel_case())))
+                .collect::<Vec<_>>();
+            Ok(format!("flags({label}, [{}])", names.join(", ")))
+        }
+        TypeDefKind::Variant(variant) => {
+            let cases = variant
+                .cases
+                .iter()
+                .map(|case| {
+                    let inner = match &case.ty {
+                        Some(ty) => checker(ty)?,
+                        None => "null".to_string(),
+                    };
+                    Ok(format!("'{}': {inner}", case.name))
+                })
+                .collect::<anyhow::Result<Vec<_>>>()?;
+            Ok(format!("variant({label}, {{ {} }})", cases.join(", ")))
+        }
+        TypeDefKind::Enum(enum_type) => {
+            let cases = enum_type
+                .cases
+                .iter()
+                .map(|case| format!("'{}'", case.name))
+                .collect::<Vec<_>>();
+            Ok(format!("enumeration({label}, [{}])", cases.join(", ")))
+        }
+        TypeDefKind::Option(inner) => Ok(format!("option({})", checker(inner)?)),
+        TypeDefKind::List(Type::U8) => Ok("bytes".to_string()),
+        TypeDefKind::List(inner) => Ok(format!("list({label}, {})", checker(inner)?)),
+        TypeDefKind::Tuple(tuple) => {
+            let items = tuple
+                .types
+                .iter()
+                .map(checker)
+                .collect::<anyhow::Result<Vec<_>>>()?;
+            Ok(format!("tuple({label}, [{}])", items.join(", ")))
+        }
+        TypeDefKind::Result(result) => {
+            let ok = match &result.ok {
+                Some(ok) => checker(ok)?,
+                None => "null".to_string(),
+            };
+            let err = match &result.err {
+                Some(err) => checker(err)?,
+                None => "null".to_string(),
+            };
+            Ok(format!("result({label}, {ok}, {err})"))
+        }
+        TypeDefKind::Handle(Handle::Own(_) | Handle::Borrow(_)) | TypeDefKind::Resource => {
+            Ok(format!("handle({label})"))
+        }
+        TypeDefKind::Type(Type::String) if context.is_decimal_type(typ) => {
+            Ok("decimal".to_string())
+        }
+        TypeDefKind::Type(inner) => checker(inner),
+        _ => Err(anyhow!(
+            "Generating a validator for {label} is not supported"
+        )),
+    }
+}
+
+/// Builds the JS expression checking a value of a type, referring to the checks of the validated
+/// named types, and inlining the rest
+fn type_checker(
+    context: &GeneratorContext<'_>,
+    typ: &Type,
+    names: &HashMap<TypeId, &str>,
+) -> anyhow::Result<String> {
+    let checker = match typ {
+        Type::Bool => "bool",
+        Type::U8 => "u8",
+        Type::U16 => "u16",
+        Type::U32 => "u32",
+        Type::U64 => "u64",
+        Type::S8 => "s8",
+        Type::S16 => "s16",
+        Type::S32 => "s32",
+        Type::S64 => "s64",
+        Type::F32 => "f32",
+        Type::F64 => "f64",
+        Type::Char => "char",
+        Type::String => "string",
+        Type::ErrorContext => {
+            return Err(anyhow!(
+                "Generating a validator for error-context is not supported"
+            ));
+        }
+        Type::Id(type_id) => {
+            return match names.get(type_id) {
+                Some(name) => Ok(format!("check{name}")),
+                None => type_definition(context, *type_id, names),
+            };
+        }
+    };
+    Ok(checker.to_string())
+}
@@ -1,16 +1,136 @@
 use anyhow::Context;
-use camino::Utf8Path;
-use wit_encoder::StandaloneFunc;
-use wit_parser::Resolve;
+use std::path::Path;
+use tracing::debug;
+use wit_encoder::{Params, StandaloneFunc};
+use wit_parser::{PackageId, Resolve, WorldId};
 
-pub fn add_get_script_import(wit_root: &Utf8Path, world: Option<&str>) -> anyhow::Result<()> {
+pub fn add_get_script_import(wit_root: &Path, world: Option<&str>) -> anyhow::Result<()> {
+    modify_world(wit_root, world, |world| {
+        let mut get_source_func = StandaloneFunc::new("get-script", false);
+        get_source_func.set_result(Some(wit_encoder::Type::String));
+
+        world.function_import(get_source_func);
+    })
+}
+
+/// Adds the `rquickjs-clock-now` import, getting the current time from the host in milliseconds
+/// since the Unix epoch, to a WIT world
+pub fn add_clock_import(wit_root: &Path, world: Option<&str>) -> anyhow::Result<()> {
+    modify_world(wit_root, world, |world| {
+        let mut now_func = StandaloneFunc::new("rquickjs-clock-now", false);
+        now_func.set_result(Some(wit_encoder::Type::U64));
+
+        world.function_import(now_func);
+    })
+}
+
+/// Adds the `repl-eval` export, evaluating a piece of JavaScript code, to a WIT world
+pub fn add_repl_export(wit_root: &Path, world: Option<&str>) -> anyhow::Result<()> {
+    modify_world(wit_root, world, |world| {
+        let mut repl_eval_func = StandaloneFunc::new("repl-eval", false);
+        repl_eval_func.set_params(("code", wit_encoder::Type::String));
+        repl_eval_func.set_result(Some(wit_encoder::Type::result_both(
+            wit_encoder::Type::String,
+            wit_encoder::Type::String,
+        )));
+
+        world.function_export(repl_eval_func);
+    })
+}
+
+/// Adds the `rquickjs-invoke` export, calling any exported function with JSON-encoded arguments,
+/// to a WIT world
+pub fn add_invoke_export(wit_root: &Path, world: Option<&str>) -> anyhow::Result<()> {
+    modify_world(wit_root, world, |world| {
+        let mut invoke_func = StandaloneFunc::new("rquickjs-invoke", false);
+        invoke_func.set_params(Params::from_iter([
+            ("name", wit_encoder::Type::String),
+            ("args-json", wit_encoder::Type::String),
+        ]));
+        invoke_func.set_result(Some(wit_encoder::Type::result_both(
+            wit_encoder::Type::String,
+            wit_encoder::Type::String,
+        )));
+
+        world.function_export(invoke_func);
+    })
+}
+
+/// Adds the `rquickjs-describe` export, returning a JSON description of the component, to a WIT
+/// world
+pub fn add_describe_export(wit_root: &Path, world: Option<&str>) -> anyhow::Result<()> {
+    modify_world(wit_root, world, |world| {
+        let mut describe_func = StandaloneFunc::new("rquickjs-describe", false);
+        describe_func.set_result(Some(wit_encoder::Type::String));
+
+        world.function_export(describe_func);
+    })
+}
+
+/// Selects the world of the root package to generate for. Without an explicit world name, a
+/// package with several worlds resolves to the one having the imports and exports of all the
+/// others, such as the last world of a chain of `include`s, if there is exactly one.
+pub fn select_world(
+    resolve: &Resolve,
+    package: PackageId,
+    world: Option<&str>,
+) -> anyhow::Result<WorldId> {
+    match resolve.select_world(package, world) {
+        Ok(world_id) => Ok(world_id),
+        Err(err) if world.is_none() => {
+            let union = union_world(resolve, package).ok_or(err)?;
+            debug!(
+                world = resolve.worlds[union].name,
+                "Selected the world including all the other worlds of the package"
+            );
+            Ok(union)
+        }
+        Err(err) => Err(err),
+    }
+    .context("Failed to select WIT world")
+}
+
+/// Finds the only world of a package including all the others
+fn union_world(resolve: &Resolve, package: PackageId) -> Option<WorldId> {
+    let worlds = resolve.packages[package]
+        .worlds
+        .values()
+        .copied()
+        .collect::<Vec<_>>();
+    let mut unions = worlds.iter().filter(|world| {
+        worlds
+            .iter()
+            .all(|other| includes_world(resolve, **world, *other))
+    });
+    let union = *unions.next()?;
+    unions.next().is_none().then_some(union)
+}
+
+/// Checks whether a world has all the imports and exports of an other world
+fn includes_world(resolve: &Resolve, world: WorldId, other: WorldId) -> bool {
+    let world = &resolve.worlds[world];
+    let other = &resolve.worlds[other];
+    other
+        .imports
+        .keys()
+        .all(|key| world.imports.contains_key(key))
+        && other
+            .exports
+            .keys()
+            .all(|key| world.exports.contains_key(key))
+}
+
+/// Rewrites the root package of a WIT directory after applying `f` to the selected world
+fn modify_world(
+    wit_root: &Path,
+    world: Option<&str>,
+    f: impl FnOnce(&mut wit_encoder::World),
+) -> anyhow::Result<()> {
     let mut resolve = Resolve::new();
     let (root_package_id, source_map) = resolve
         .push_path(wit_root)
         .context("Failed to resolve WIT package")?;
-    let world_id = resolve
-        .select_world(root_package_id, world)
-        .context("Failed to select WIT world")?;
+    let world_id = select_world(&resolve, root_package_id, world)?;
 
     let root_package_name = resolve.packages[root_package_id].name.clone();
     let world_name = resolve.worlds[world_id].name.clone();
@@ -52,10 +172,7 @@ pub fn add_get_script_import(wit_root: &Utf8Path, world: Option<&str>) -> anyhow
             )
         })?;
 
-    let mut get_source_func = StandaloneFunc::new("get-script", false);
-    get_source_func.set_result(Some(wit_encoder::Type::String));
-
-    world.function_import(get_source_func);
+    f(world);
 
     std::fs::write(root_package_path[0], root_package.to_string()).context(format!(
         "Overwriting root WIT package at {:?}",
@@ -0,0 +1,22 @@
+let lastPeeked = null;
+
+export const api = {
+    peek: (c) => {
+        lastPeeked = c;
+        return c.get();
+    },
+    peekAgain: () => {
+        try {
+            return `${lastPeeked.get()}`;
+        } catch (e) {
+            // The borrowed handle is only valid during the call it was passed to
+            return `${e.name}: ${e.message}`;
+        }
+    },
+    consume: (c) => {
+        c.increment();
+        const value = c.get();
+        c[Symbol.dispose]();
+        return value;
+    },
+};
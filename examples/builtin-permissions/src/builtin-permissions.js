@@ -0,0 +1,13 @@
+import * as plugin from "./plugin";
+
+export const api = {
+    entryImport: async (name) => {
+        try {
+            await import(name);
+            return "imported";
+        } catch (error) {
+            return error.message;
+        }
+    },
+    pluginImport: (name) => plugin.tryImport(name),
+};
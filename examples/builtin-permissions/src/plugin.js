@@ -0,0 +1,9 @@
+// Generated with `--builtin-permission plugin=buffer`, so this module can only import `buffer`
+export async function tryImport(name) {
+    try {
+        await import(name);
+        return "imported";
+    } catch (error) {
+        return error.message;
+    }
+}
@@ -0,0 +1,17 @@
+let last = new Uint8Array();
+
+export const api = {
+    echo: (bytes) => {
+        last = bytes;
+        return bytes;
+    },
+    echoTail: (bytes) => {
+        last = bytes;
+        return bytes.subarray(1);
+    },
+    copy: (bytes) => {
+        last = bytes;
+        return new Uint8Array(bytes);
+    },
+    lastByteLength: () => last.byteLength,
+};
@@ -0,0 +1,40 @@
+// Generated with `--cancel-background-work`, so the calls return without waiting for their timers
+import { callSignal, cancelCall, isCallCancelled } from 'cancellation';
+
+let previousSignal = undefined;
+
+export const api = {
+    leaveTimer: async () => {
+        setTimeout(() => console.log("late timer"), 60000);
+        return "returned";
+    },
+    cancelItself: async () => {
+        const signal = callSignal();
+        const aborted = new Promise((_, reject) => {
+            signal.addEventListener('abort', () => reject(signal.reason));
+        });
+        setTimeout(() => cancelCall(), 10);
+        setTimeout(() => console.log("cancelled timer"), 60000);
+        try {
+            await aborted;
+            return "not cancelled";
+        } catch (error) {
+            return `${error.name} ${isCallCancelled()}`;
+        }
+    },
+    signalPerCall: async () => {
+        const signal = callSignal();
+        const fresh = signal !== previousSignal && !signal.aborted;
+        previousSignal = signal;
+        return fresh;
+    },
+    cancelFetch: async (url) => {
+        setTimeout(() => cancelCall(), 10);
+        try {
+            await fetch(url);
+            return "not cancelled";
+        } catch (error) {
+            return `${error.name} ${isCallCancelled()}`;
+        }
+    },
+};
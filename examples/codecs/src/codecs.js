@@ -0,0 +1,72 @@
+import * as msgpack from 'msgpack';
+import * as cbor from 'cbor';
+
+const codecs = { msgpack, cbor };
+
+function toHex(bytes) {
+    return Array.from(bytes, (byte) => byte.toString(16).padStart(2, '0')).join('');
+}
+
+function fromHex(hex) {
+    return new Uint8Array(hex.match(/../g)?.map((byte) => parseInt(byte, 16)) ?? []);
+}
+
+// JSON with the types JSON cannot represent spelled out
+function describe(value) {
+    return JSON.stringify(value, (key, value) => {
+        if (typeof value === 'bigint') {
+            return `${value}n`;
+        } else if (value instanceof Uint8Array) {
+            return `bytes:${toHex(value)}`;
+        } else if (value instanceof Map) {
+            return { map: Array.from(value) };
+        } else if (value === undefined) {
+            return 'undefined';
+        }
+        return value;
+    }).replace(/"(\d{4}-[^"]+Z)"/g, 'date:$1');
+}
+
+export const encodeHex = (format, json) => {
+    return toHex(codecs[format].encode(JSON.parse(json)));
+};
+
+export const decodeHex = (format, hex) => {
+    try {
+        return describe(codecs[format].decode(fromHex(hex)));
+    } catch (error) {
+        return `${error.name}: ${error.message}`;
+    }
+};
+
+export const roundTrip = (format) => {
+    const codec = codecs[format];
+    const value = {
+        integers: [0, -1, 255, -129, 65536, 2 ** 40, -(2 ** 53) + 1],
+        big: [2n ** 63n, -(2n ** 63n)],
+        floats: [1.5, -0.25, NaN],
+        strings: ['', 'héllo wörld', 'x'.repeat(300)].map((string) => string.length),
+        bytes: new Uint8Array([0, 1, 254, 255]),
+        nested: { list: [true, false, null], empty: {} },
+        map: new Map([[1, 'one'], ['two', 2]]),
+        date: new Date(Date.UTC(2024, 0, 2, 3, 4, 5, 678)),
+    };
+    const decoded = codec.decode(codec.encode(value).buffer);
+
+    const results = Object.entries(decoded).map(([key, value]) => `${key} ${describe(value)}`);
+    for (const invalid of [() => 1, Symbol('symbol')]) {
+        try {
+            codec.encode({ invalid });
+        } catch (error) {
+            results.push(`${error.name}: ${error.message}`);
+        }
+    }
+    const cyclic = [];
+    cyclic.push(cyclic);
+    try {
+        codec.encode(cyclic);
+    } catch (error) {
+        results.push(`${error.name}: ${error.message}`);
+    }
+    return results;
+};
@@ -0,0 +1,36 @@
+import { compress, decompress } from 'compression';
+
+function toHex(bytes) {
+    return Array.from(bytes, (byte) => byte.toString(16).padStart(2, '0')).join('');
+}
+
+function fromHex(hex) {
+    return new Uint8Array(hex.match(/../g)?.map((byte) => parseInt(byte, 16)) ?? []);
+}
+
+export const compressHex = (format, text, level) => {
+    try {
+        return toHex(compress(text, format, { level }));
+    } catch (error) {
+        return `${error.name}: ${error.message}`;
+    }
+};
+
+export const decompressHex = (format, hex, maxLength) => {
+    try {
+        return new TextDecoder().decode(decompress(fromHex(hex), format, { maxLength }));
+    } catch (error) {
+        return `${error.name}: ${error.message}`;
+    }
+};
+
+export const roundTrip = (format, size) => {
+    const data = new Uint16Array(size);
+    for (let i = 0; i < size; i++) {
+        data[i] = i % 256;
+    }
+    const compressed = compress(data, format, { level: 9 });
+    const restored = new Uint16Array(decompress(compressed.buffer, format).buffer);
+    const equal = restored.length === data.length && restored.every((value, i) => value === data[i]);
+    return `restored: ${equal}, smaller: ${compressed.length < data.byteLength}`;
+};
@@ -0,0 +1,66 @@
+import * as toml from 'toml';
+import * as yaml from 'yaml';
+
+// JSON with the values JSON cannot represent tagged: BigInts, Dates and Maps
+function show(value) {
+    return JSON.stringify(value, function (key, value) {
+        if (typeof value === 'bigint') {
+            return `${value}n`;
+        }
+        if (this[key] instanceof Date) {
+            return `Date(${value})`;
+        }
+        if (value instanceof Map) {
+            return { Map: [...value] };
+        }
+        return value;
+    });
+}
+
+export const tomlToJson = (text) => show(toml.parse(text));
+
+export const jsonToToml = (json) => toml.stringify(JSON.parse(json));
+
+export const yamlToJson = (text) => show(yaml.parse(text));
+
+export const yamlDocuments = (text) => yaml.parseAll(text).map(show);
+
+export const jsonToYaml = (json) => yaml.stringify(JSON.parse(json));
+
+// Stringifies values that JSON cannot represent, and parses them back
+export const roundTrip = () => {
+    const value = {
+        name: 'service',
+        started: new Date(Date.UTC(2024, 0, 2, 3, 4, 5)),
+        limits: { requests: 2n ** 60n, ratio: 0.25 },
+        tags: ['a', 'b'],
+        skipped: undefined,
+    };
+    const asToml = toml.stringify(value);
+    const asYaml = yaml.stringify(value);
+    return [asToml, show(toml.parse(asToml)), asYaml, show(yaml.parse(asYaml))];
+};
+
+export const errors = () => {
+    const cyclic = {};
+    cyclic.self = cyclic;
+    const attempts = [
+        () => toml.stringify([1, 2]),
+        () => toml.stringify({ value: null }),
+        () => toml.stringify({ value: 2n ** 64n }),
+        () => toml.stringify(cyclic),
+        () => yaml.stringify({ value: 2n ** 64n }),
+        () => yaml.stringify(cyclic),
+        () => toml.parse('key = 1\nkey = 2\n'),
+        () => yaml.parse('list: [1, 2'),
+        () => yaml.parse('a: 1\n---\nb: 2\n'),
+    ];
+    return attempts.map((attempt) => {
+        try {
+            attempt();
+            return 'no error';
+        } catch (error) {
+            return `${error.name}: ${error.message}`;
+        }
+    });
+};
@@ -0,0 +1,9 @@
+export const visit = async (url, credentials) => {
+    const response = await fetch(url, { credentials });
+    return await response.text();
+};
+
+export const visitWithCookie = async (url, cookie) => {
+    const response = await fetch(url, { headers: { Cookie: cookie } });
+    return await response.text();
+};
@@ -0,0 +1,49 @@
+import { CsvParser, parse, parseRows, parseStream, stringify, stringifyStream } from 'csv';
+
+export const totalSales = async (chunks) => {
+    let count = 0;
+    let total = 0;
+    for await (const sale of parseRows(chunks, { headers: true, comment: '#' })) {
+        count += 1;
+        total += Number(sale.amount);
+    }
+    return [count, total];
+};
+
+export const parseTable = (text, delimiter) => parse(text, { delimiter });
+
+export const report = (products) =>
+    stringify(products.map(([name, price]) => ({ name, price, discounted: price > 10 ? price * 0.9 : null })));
+
+export const roundTrip = async (text) => {
+    const reader = stringifyStream(parseStream(text), { quoteAll: true, lineTerminator: '\r\n', chunkSize: 8 }).getReader();
+    let result = '';
+    for (;;) {
+        const { value, done } = await reader.read();
+        if (done) {
+            return result;
+        }
+        result += value;
+    }
+};
+
+export const errors = () => {
+    const messages = [];
+    for (const action of [
+        () => parse('a,b\n1,2\n3\n'),
+        () => {
+            const parser = new CsvParser();
+            parser.write('a,b\n1');
+            return parser.write(',2,3\n');
+        },
+        () => parse('a', { delimiter: '::' }),
+        () => new CsvParser().write(42),
+    ]) {
+        try {
+            messages.push(JSON.stringify(action()));
+        } catch (error) {
+            messages.push(`${error.name}: ${error.message}`);
+        }
+    }
+    return messages;
+};
@@ -0,0 +1,23 @@
+export const total = (items) =>
+    items.reduce((sum, item) => sum.add(item.price.mul(item.quantity)), new Decimal(0));
+
+export const withTax = (amount, rate) =>
+    amount.add(amount.mul(rate).div(100)).round(2);
+
+export const split = (amount, shares) => {
+    const share = amount.div(shares).round(2);
+    const result = Array.from({ length: shares - 1 }, () => share);
+    result.push(amount.sub(share.mul(shares - 1)));
+    return result;
+};
+
+export const largest = (amounts) =>
+    amounts.reduce((max, amount) => (max === undefined || amount.gt(max) ? amount : max), undefined);
+
+export const divideByZero = (amount) => {
+    try {
+        return amount.div('0').toString();
+    } catch (error) {
+        return `${error.name}: ${error.message}`;
+    }
+};
@@ -0,0 +1,11 @@
+// Generated with `--deny-import wasi:random/random`, so importing it throws the policy error
+export const api = {
+    tryRandom: async () => {
+        try {
+            await import("wasi:random/random@0.2.3");
+            return "imported";
+        } catch (error) {
+            return error.message;
+        }
+    },
+};
@@ -0,0 +1,19 @@
+import { LogLevel } from 'enums';
+
+export const api = {
+    levelFor: (verbosity) => {
+        switch (verbosity) {
+            case 0:
+                return LogLevel.Warning;
+            case 1:
+                return LogLevel.Info;
+            case 2:
+                return LogLevel.Debug;
+            default:
+                return LogLevel.OutOfRange;
+        }
+    },
+    isWarning: (level) => level === LogLevel.Warning,
+    describeConstants: () =>
+        `${Object.entries(LogLevel).map(([name, value]) => `${name}=${value}`).join(',')} frozen=${Object.isFrozen(LogLevel)}`,
+};
@@ -36,6 +36,27 @@ export const fun6 = () => {
     return "Hello, world!";
 }
 
+// strings with lone surrogates cannot be converted to WIT strings by default
+export const loneSurrogate = () => {
+    return "Hello, \uD800world!";
+}
+
+// fail throws an error of a custom class, reported with its name, message and stack
+class ValidationError extends Error {
+    constructor(message) {
+        super(message);
+        this.name = 'ValidationError';
+    }
+}
+
+function validate(input) {
+    throw new ValidationError(`invalid input: ${input}`);
+}
+
+export const fail = () => {
+    return validate("x");
+}
+
 // interface should be called `api2`
 export const api22 = {
     fun7: (name) => {
@@ -0,0 +1,18 @@
+// Collects the given number of events, then the number of errors reported by the reconnections
+export const collect = (url, count) => new Promise((resolve) => {
+    const events = [];
+    let errors = 0;
+    const source = new EventSource(url);
+    const record = (event) => {
+        events.push(`${event.type} ${event.lastEventId} ${event.data}`);
+        if (events.length === count) {
+            source.close();
+            resolve([...events, `errors: ${errors}`]);
+        }
+    };
+    source.onmessage = record;
+    source.addEventListener('custom', record);
+    source.onerror = () => {
+        errors += 1;
+    };
+});
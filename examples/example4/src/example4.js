@@ -0,0 +1,8 @@
+export const greeter = {
+    greet: (name) => {
+        return `Hello, ${name}!`;
+    },
+    greetAll: (names) => {
+        return names.map((name) => `Hello, ${name}!`);
+    }
+};
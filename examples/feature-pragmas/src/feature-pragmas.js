@@ -0,0 +1,27 @@
+const regions = [];
+
+// #if feature(http)
+regions.push('http');
+// #else
+regions.push('no-http');
+// #endif
+
+// #if feature(logging)
+regions.push('logging');
+// #if feature(http)
+regions.push('http-and-logging');
+// #endif
+// #else
+regions.push('no-logging');
+// #endif
+
+export const keptRegions = () => regions;
+
+export const report = (message) => {
+    // #if !feature(logging)
+    return `printed: ${message}`;
+    // #else
+    console.info(message);
+    return `logged: ${message}`;
+    // #endif
+};
@@ -0,0 +1,13 @@
+export const fetchText = async (url) => {
+    const response = await fetch(url);
+    return `${response.url} ${await response.text()}`;
+};
+
+export const fetchError = async (url) => {
+    try {
+        await fetch(url);
+        return 'no error';
+    } catch (error) {
+        return `${error.name}: ${error.message}`;
+    }
+};
@@ -238,4 +238,17 @@ export async function test10(port) {
 
     await dumpResponse(response1);
     await dumpResponse(response2);
-}
\ No newline at end of file
+}
+export async function test11(port) {
+    console.log("fetch test 11");
+    const response1 = await fetch(`http://localhost:${port}/slow-chunks`);
+    const decoder = new TextDecoder();
+    let text = '';
+    for await (const chunk of response1.body) {
+        text += decoder.decode(chunk, {stream: true});
+    }
+    console.log(`Chunked body: ${text}`);
+
+    const response2 = await fetch(`http://localhost:${port}/moved`, {redirect: 'manual'});
+    console.log(`Redirect: ${response2.status} ${response2.headers.get('location')}`);
+}
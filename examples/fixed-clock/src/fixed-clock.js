@@ -0,0 +1,7 @@
+// Generated with `--date-clock 1700000000000`, so the current time never advances
+export const api = {
+    now: () => Date.now(),
+    nowIso: () => new Date().toISOString(),
+    epochIso: () => new Date(0).toISOString(),
+    isDate: () => new Date() instanceof Date && Date().includes("2023"),
+};
@@ -0,0 +1,48 @@
+async function describe(formData) {
+    const entries = [];
+    for (const [name, value] of formData.entries()) {
+        if (typeof value === 'string') {
+            entries.push(`${name}=${value}`);
+        } else {
+            entries.push(`${name}=${value.name} (${value.type}): ${await value.text()}`);
+        }
+    }
+    return entries.join(', ');
+}
+
+export const roundTrip = async () => {
+    const formData = new FormData();
+    formData.append('title', 'hello');
+    formData.append('lines', 'first\nsecond');
+    formData.append('upload', new File(['file contents'], 'notes.txt', { type: 'text/plain' }));
+
+    // Serialized with a generated boundary, then parsed back
+    const blob = await new Request('http://localhost/form', { method: 'POST', body: formData }).blob();
+    const request = new Request('http://localhost/form', {
+        method: 'POST',
+        body: blob,
+        headers: { 'Content-Type': blob.type },
+    });
+    return await describe(await request.formData());
+};
+
+export const urlEncoded = async () => {
+    const request = new Request('http://localhost/form', {
+        method: 'POST',
+        body: new URLSearchParams({ a: '1', b: 'two words' }),
+    });
+    return `${request.headers.get('Content-Type')} ${await describe(await request.formData())}`;
+};
+
+export const postUrlEncoded = async (port) => {
+    const response = await fetch(`http://localhost:${port}/echo`, {
+        method: 'POST',
+        body: new URLSearchParams({ a: '1', b: 'two words' }),
+    });
+    return await response.text();
+};
+
+export const parseResponse = async (port) => {
+    const response = await fetch(`http://localhost:${port}/form`);
+    return await describe(await response.formData());
+};
@@ -0,0 +1,50 @@
+import { createHash, createHmac, crc32, hash, hmac, timingSafeEqual } from 'hashing';
+
+export const hashText = (algorithm, text, encoding) => {
+    return hash(algorithm, text, encoding);
+};
+
+export const hmacText = (algorithm, key, text) => {
+    const signature = hmac(algorithm, key, text, 'hex');
+    const incremental = createHmac(algorithm, key);
+    for (const char of text) {
+        incremental.update(char);
+    }
+    if (!timingSafeEqual(incremental.digest('hex'), signature)) {
+        throw new Error('The incremental HMAC differs');
+    }
+    return signature;
+};
+
+export const checksum = (chunks) => {
+    return chunks.reduce((crc, chunk) => crc32(chunk, crc), 0);
+};
+
+// Derives the key of AWS Signature Version 4 requests, chaining binary HMACs
+export const signingKey = (secret, date, region, service) => {
+    const dateKey = hmac('sha256', `AWS4${secret}`, date);
+    const regionKey = hmac('sha256', dateKey, region);
+    const serviceKey = hmac('sha256', regionKey, service);
+    return hmac('sha256', serviceKey, 'aws4_request', 'hex');
+};
+
+export const errors = () => {
+    const attempts = [
+        () => hash('md5', 'text'),
+        () => hash('sha256', 'text', 'latin1'),
+        () => hash('sha256', 42),
+        () => {
+            const hasher = createHash('sha256');
+            hasher.digest();
+            hasher.update('text');
+        },
+    ];
+    return attempts.map((attempt) => {
+        try {
+            attempt();
+            return 'no error';
+        } catch (error) {
+            return `${error.name}: ${error.message}`;
+        }
+    });
+};
@@ -0,0 +1,10 @@
+export const fetchWithCache = async (url, cache) => {
+    const response = await fetch(url, { cache });
+    return `${response.status} ${await response.text()}`;
+};
+
+export const fetchRequestWithCookie = async (url, requestCookie, cookie) => {
+    const request = new Request(url, { headers: { Cookie: requestCookie } });
+    const response = await fetch(request, { headers: { Cookie: cookie } });
+    return `${response.status} ${await response.text()}`;
+};
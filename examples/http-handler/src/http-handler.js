@@ -0,0 +1,29 @@
+import { readRequest } from 'incoming-http';
+import { Fields, OutgoingBody, OutgoingResponse, ResponseOutparam } from 'wasi:http/types@0.2.3';
+
+// Echoes the received request, or the error of reading it with a 413 status
+export const incomingHandler = {
+    handle: async (request, responseOut) => {
+        let status = 200;
+        let text;
+        try {
+            const received = readRequest(request);
+            text = `${received.method} ${received.pathWithQuery}: ${received.text()}`;
+        } catch (error) {
+            status = 413;
+            text = `${error.name}: ${error.message}`;
+        }
+
+        const bytes = new TextEncoder().encode(text);
+        const headers = new Fields();
+        headers.append('content-length', new TextEncoder().encode(String(bytes.length)));
+        const response = new OutgoingResponse(headers);
+        response.setStatusCode(status);
+        const body = response.body();
+        ResponseOutparam.set(responseOut, { tag: 'ok', val: response });
+        const stream = body.write();
+        stream.blockingWriteAndFlush(bytes);
+        stream[Symbol.dispose]();
+        OutgoingBody.finish(body, undefined);
+    },
+};
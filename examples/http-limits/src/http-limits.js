@@ -0,0 +1,31 @@
+// Generated with `--max-request-body-size 8 --max-response-body-size 16 --max-header-count 20`
+
+async function attempt(f) {
+    try {
+        return await f();
+    } catch (error) {
+        return `${error.name}: ${error.message}`;
+    }
+}
+
+export const fetchText = (url) => attempt(async () => {
+    const response = await fetch(url);
+    return await response.text();
+});
+
+export const readStream = (url) => attempt(async () => {
+    const response = await fetch(url);
+    const reader = response.body.getReader();
+    let size = 0;
+    while (true) {
+        const { done, value } = await reader.read();
+        if (done) break;
+        size += value.byteLength;
+    }
+    return `read ${size} bytes`;
+});
+
+export const postText = (url, body) => attempt(async () => {
+    const response = await fetch(url, { method: 'POST', body });
+    return await response.text();
+});
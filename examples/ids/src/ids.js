@@ -0,0 +1,20 @@
+import * as uuid from 'uuid';
+import { ulid as newUlid, decodeTime, isValid } from 'ulid';
+
+export const uuidV4 = () => uuid.v4();
+
+export const uuidV7 = () => uuid.v7();
+
+export const ulid = () => newUlid();
+
+export const uuidVersion = (id) => (uuid.validate(id) ? uuid.version(id) : undefined);
+
+export const ulidTime = (id) => (isValid(id) ? BigInt(decodeTime(id)) : undefined);
+
+// Whether the IDs generated in a row, mostly within the same millisecond, sort in that order
+export const inOrder = (count) => {
+    const uuids = Array.from({ length: count }, () => uuid.v7());
+    const ulids = Array.from({ length: count }, () => newUlid());
+    const sorted = (ids) => ids.every((id, index) => index === 0 || ids[index - 1] < id);
+    return sorted(uuids) && sorted(ulids);
+};
@@ -0,0 +1,23 @@
+// Generated with `--import-hooks`, so the calls of the imported interfaces go through the
+// hooks registered by `registerImportHook`
+import * as random from 'wasi:random/random@0.2.3';
+
+const calls = [];
+
+registerImportHook((iface, fn, args, next) => {
+    calls.push(`${iface}#${fn}(${args.join(', ')})`);
+    return next();
+});
+
+export const api = {
+    mockedNumber: () => {
+        const unregister = registerImportHook((iface, fn, args, next) => fn === 'getRandomU64' ? 42n : next());
+        try {
+            return random.getRandomU64();
+        } finally {
+            unregister();
+        }
+    },
+    byteCount: (len) => random.getRandomBytes(BigInt(len)).length,
+    calls: () => calls,
+};
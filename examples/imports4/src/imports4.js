@@ -0,0 +1,11 @@
+import * as greeter from 'quickjs:imports4/imports4/greeter';
+
+export const test = (input) => {
+    const greeting = greeter.greet(input);
+    console.log(greeting);
+
+    const greetings = greeter.greetAll([input, 'World']);
+    console.log(greetings.join(' '));
+
+    return greeting;
+};
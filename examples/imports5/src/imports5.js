@@ -0,0 +1,7 @@
+import { hello } from 'world-imports';
+
+export const test = (input) => {
+    const greeting = hello(input);
+    console.log(greeting);
+    return greeting;
+};
@@ -0,0 +1,23 @@
+import { log } from 'quickjs:include-worlds/logging';
+import { get } from 'quickjs:include-worlds/include-worlds/settings';
+import { currentTime } from 'world-imports';
+
+let count = 0n;
+
+export const counter = {
+    increment: () => {
+        count += 1n;
+        log(`count: ${count}`);
+        return { value: count };
+    },
+};
+
+export const greeter = {
+    greet: (name) => `${get('greeting') ?? 'Hello'}, ${name}! (${currentTime()})`,
+};
+
+export const stats = {
+    total: () => count,
+};
+
+export const version = () => '1.0.0';
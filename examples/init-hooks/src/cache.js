@@ -0,0 +1,9 @@
+export const cache = new Map();
+
+export async function __init() {
+    // Simulates loading the cached values asynchronously
+    await new Promise((resolve) => setTimeout(resolve, 10));
+    cache.set('answer', 42);
+    cache.set('lucky', 7);
+    globalThis.initOrder = [...(globalThis.initOrder ?? []), 'cache'];
+}
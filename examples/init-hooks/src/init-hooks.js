@@ -0,0 +1,11 @@
+import { cache } from './cache';
+
+export function __init() {
+    globalThis.initOrder = [...(globalThis.initOrder ?? []), 'init-hooks'];
+}
+
+export const shout = (text) => text.shout();
+
+export const cached = (key) => cache.get(key);
+
+export const initOrder = () => globalThis.initOrder;
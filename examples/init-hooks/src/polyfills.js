@@ -0,0 +1,8 @@
+export function __init() {
+    if (!String.prototype.shout) {
+        String.prototype.shout = function () {
+            return this.toUpperCase() + '!';
+        };
+    }
+    globalThis.initOrder = [...(globalThis.initOrder ?? []), 'polyfills'];
+}
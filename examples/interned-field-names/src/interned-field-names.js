@@ -0,0 +1,15 @@
+const movePoint = (p, dx, dy) => ({...p, x: p.x + dx, y: p.y + dy});
+
+export const api = {
+    movePoint,
+    moveShape: (s, dx, dy) => {
+        switch (s.tag) {
+            case "dot":
+                return {tag: "dot", val: movePoint(s.val, dx, dy)};
+            case "line":
+                return {tag: "line", val: [movePoint(s.val[0], dx, dy), movePoint(s.val[1], dx, dy)]};
+            default:
+                return s;
+        }
+    },
+};
@@ -0,0 +1,39 @@
+export const formatNumber = (locale, value, fractionDigits) => {
+    const options = fractionDigits === undefined
+        ? {}
+        : { minimumFractionDigits: fractionDigits, maximumFractionDigits: fractionDigits };
+    return new Intl.NumberFormat(locale, options).format(value);
+};
+
+export const formatPercent = (value) => {
+    return new Intl.NumberFormat('en-US', { style: 'percent' }).format(value);
+};
+
+export const formatDate = (locale, epochMillis, dateStyle, timeStyle) => {
+    const format = new Intl.DateTimeFormat(locale, { dateStyle, timeStyle }).format;
+    return format(new Date(epochMillis));
+};
+
+export const sortWords = (locale, words) => {
+    return [...words].sort(new Intl.Collator(locale, { numeric: true }).compare);
+};
+
+export const toLocaleStrings = () => {
+    const date = new Date(Date.UTC(2025, 0, 15, 16, 9, 35));
+    return [
+        (1234.5).toLocaleString('de-DE'),
+        date.toLocaleDateString('en-US', { year: 'numeric', month: 'long', day: 'numeric' }),
+        date.toLocaleTimeString('en-GB'),
+        String('a'.localeCompare('B')),
+        new Intl.DateTimeFormat().resolvedOptions().timeZone,
+    ];
+};
+
+export const invalidLocale = () => {
+    try {
+        new Intl.NumberFormat('not a locale!');
+        return 'no error';
+    } catch (error) {
+        return `${error.name}: ${error.message}`;
+    }
+};
@@ -0,0 +1,12 @@
+export const api = {
+    add: (a, b) => a + b,
+    translate: (p, dx) => ({ x: p.x + dx, y: p.y }),
+    big: () => 12345678901234567890n,
+    fail: async () => {
+        throw new Error("failed on purpose");
+    },
+};
+
+export async function greet(name) {
+    return `Hello, ${name}!`;
+}
@@ -0,0 +1,11 @@
+// Cutting a string in the middle of a surrogate pair leaves a lone surrogate behind
+const truncate = (s, len) => s.substring(0, len);
+
+export const api = {
+    truncate,
+    makeLabelled: (label) => ({
+        label: truncate(label, 1),
+        tags: ["\uDC00", "ok", "\uD800x"],
+    }),
+    wellFormed: (s) => s,
+};
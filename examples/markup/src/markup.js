@@ -0,0 +1,58 @@
+import * as xml from 'xml';
+import * as html from 'html';
+
+export const feedTitles = (feed) =>
+    xml.parse(feed)
+        .querySelectorAll('channel > item')
+        .filter((item) => item.getAttribute('draft') !== 'true')
+        .map((item) => item.querySelector('title').textContent.trim());
+
+export const links = (page) =>
+    html.parse(page)
+        .querySelectorAll('nav a[href^="https://"]:not(.hidden)')
+        .map((link) => `${link.textContent}: ${link.getAttribute('href')}`);
+
+export const rewrite = (page) => {
+    const document = html.parse(page);
+    for (const script of document.querySelectorAll('script')) {
+        script.remove();
+    }
+    for (const link of document.querySelectorAll('a[target]')) {
+        link.setAttribute('rel', 'noopener');
+    }
+    const note = document.createElement('p');
+    note.setAttribute('class', 'note');
+    note.appendChild(document.createTextNode('Rewritten <safely>'));
+    document.querySelector('main').appendChild(note);
+    return html.stringify(document);
+};
+
+export const buildFeed = (titles) => {
+    const document = xml.parse('<rss version="2.0"><channel/></rss>');
+    const channel = document.querySelector('channel');
+    for (const title of titles) {
+        const item = document.createElement('item');
+        const element = document.createElement('title');
+        element.textContent = title;
+        item.appendChild(element);
+        channel.appendChild(item);
+    }
+    return xml.stringify(document);
+};
+
+export const errors = () => {
+    const messages = [];
+    for (const action of [
+        () => xml.parse('<a>\n  <b>\n</a>'),
+        () => xml.parse('<a/><b/>'),
+        () => html.parse('<p>').querySelector('p >'),
+        () => html.parse('<p>').querySelector('p::before'),
+    ]) {
+        try {
+            messages.push(String(action()));
+        } catch (error) {
+            messages.push(`${error.name}: ${error.message}`);
+        }
+    }
+    return messages;
+};
@@ -0,0 +1,11 @@
+import { publish, readMessage } from 'messaging';
+
+// Echoes each received message to the `<topic>.echo` topic, counting the echoes in its metadata
+export async function onMessage(message) {
+    const { topic, data, contentType, metadata } = readMessage(message);
+    const echoes = Number(metadata.echoes ?? 0) + 1;
+    await publish(`${topic}.echo`, data, {
+        contentType,
+        metadata: { ...metadata, echoes: String(echoes) },
+    });
+}
@@ -0,0 +1,42 @@
+import { MessageFormat, formatMessage } from 'messageformat';
+
+const CART = {
+    en: '{name} has {count, plural, =0 {no items} one {# item} other {# items}} in the cart',
+    de: '{name} hat {count, plural, =0 {keine Artikel} one {# Artikel} other {# Artikel}} im Warenkorb',
+    pl: '{name} ma {count, plural, =0 {pusty koszyk} one {# produkt} few {# produkty} many {# produktów} other {# produktu}} w koszyku',
+};
+
+export const cart = (locale, name, count) =>
+    new MessageFormat(CART[locale.split('-')[0]] ?? CART.en, locale).format({ name, count });
+
+const RANKING = new MessageFormat('You finished {place, selectordinal, one {#st} two {#nd} few {#rd} other {#th}}!');
+
+export const ranking = (place) => RANKING.format({ place });
+
+export const invitation = (host, guests) =>
+    formatMessage(
+        "{count, plural, offset:1 =0 {{host} doesn''t invite anyone} =1 {{host} invites {guest}} " +
+            "one {{host} invites {guest} and # other person} other {{host} invites {guest} and # other people}}",
+        { host, guest: guests[0], count: guests.length },
+    );
+
+export const receipt = (locale, total, date) =>
+    formatMessage('{total, number} paid on {date, date, long}, \'{\'ref\'}\'', { total, date }, locale);
+
+export const errors = () => {
+    const messages = [];
+    for (const [message, values, locale] of [
+        ['Hello {name', {}],
+        ['{count, plural, one {# item}}', { count: 1 }],
+        ['{amount, currency}', { amount: 1 }],
+        ['Hello {name}', {}],
+        ['{name}', { name: 'x' }, 'not a locale!'],
+    ]) {
+        try {
+            messages.push(formatMessage(message, values, locale));
+        } catch (error) {
+            messages.push(`${error.name}: ${error.message}`);
+        }
+    }
+    return messages;
+};
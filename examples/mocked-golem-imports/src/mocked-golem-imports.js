@@ -0,0 +1,176 @@
+// Built with the `mock-imports` feature, so the helper modules run on top of the mocks set here
+import { mock, mockModule, calls, resetMocks } from '__mocks';
+import { createContainer } from 'blobstore';
+import { connect } from 'db';
+import { atomically, withIdempotenceMode } from 'golem';
+import * as llm from 'llm';
+
+const POSTGRES = 'golem:rdbms/postgres@0.0.1';
+const BLOBSTORE = 'wasi:blobstore/blobstore';
+const BLOBSTORE_TYPES = 'wasi:blobstore/types';
+const LLM = 'golem:llm/llm@1.0.0';
+const HOST = 'golem:api/host@1.1.7';
+
+function concat(a, b) {
+    const result = new Uint8Array(a.length + b.length);
+    result.set(a);
+    result.set(b, a.length);
+    return result;
+}
+
+// A container resource keeping its objects in memory
+function memoryContainer() {
+    const objects = new Map();
+    return {
+        writeData: (name, outgoing) => {
+            objects.set(name, outgoing.data);
+        },
+        objectInfo: (name) => ({ name, container: 'files', createdAt: 0n, size: BigInt(objects.get(name).length) }),
+        getData: (name, start, end) => {
+            const data = objects.get(name).slice(Number(start), Number(end) + 1);
+            return {
+                incomingValueConsumeSync: () => data,
+                incomingValueConsumeAsync: () => {
+                    let read = false;
+                    return {
+                        blockingRead: () => {
+                            if (read) {
+                                throw { tag: 'closed' };
+                            }
+                            read = true;
+                            return data;
+                        },
+                        [Symbol.dispose]: () => {},
+                    };
+                },
+            };
+        },
+        listObjects: () => ({
+            readStreamObjectNames: () => [[...objects.keys()], true],
+            [Symbol.dispose]: () => {},
+        }),
+    };
+}
+
+export const api = {
+    queryUsers: async () => {
+        resetMocks();
+        const statements = [];
+        mock(POSTGRES, 'DbConnection.open', () => ({
+            query: (statement, params) => {
+                statements.push({ statement, params });
+                return {
+                    columns: [{ name: 'id' }, { name: 'name' }],
+                    rows: [{ values: [{ tag: 'int4', val: 1 }, { tag: 'text', val: 'Alice' }] }],
+                };
+            },
+            [Symbol.dispose]: () => {},
+        }));
+
+        const db = await connect('postgres://localhost/app');
+        const rows = await db.query`SELECT id, name FROM users WHERE id = ${1}`;
+        db.close();
+
+        return JSON.stringify({
+            address: calls(POSTGRES, 'DbConnection.open')[0][0],
+            statements,
+            rows,
+        });
+    },
+    storeBlob: async () => {
+        resetMocks();
+        mock(BLOBSTORE, 'createContainer', () => memoryContainer());
+        mock(BLOBSTORE_TYPES, 'OutgoingValue.newOutgoingValue', () => {
+            const outgoing = {
+                data: new Uint8Array(0),
+                outgoingValueWriteBody: () => ({
+                    blockingWriteAndFlush: (chunk) => {
+                        outgoing.data = concat(outgoing.data, chunk);
+                    },
+                    [Symbol.dispose]: () => {},
+                }),
+            };
+            return outgoing;
+        });
+
+        const files = await createContainer('files');
+        await files.put('greeting.txt', 'Hello, blobstore!');
+        const text = await files.getText('greeting.txt');
+        const range = await files.getText('greeting.txt', { start: 7 });
+
+        let streamed = '';
+        const decoder = new TextDecoder();
+        for await (const chunk of files.readable('greeting.txt')) {
+            streamed += decoder.decode(chunk);
+        }
+
+        return JSON.stringify({
+            containers: calls(BLOBSTORE, 'createContainer'),
+            text,
+            range,
+            streamed,
+            names: await files.list(),
+        });
+    },
+    chat: async () => {
+        resetMocks();
+        mock(LLM, 'send', () => ({
+            tag: 'message',
+            val: {
+                id: 'response-1',
+                content: [{ tag: 'text', val: 'Hello' }, { tag: 'text', val: ' there!' }],
+                toolCalls: [],
+                metadata: { finishReason: 'stop', usage: { inputTokens: 3, outputTokens: 2 } },
+            },
+        }));
+        mock(LLM, 'stream', () => {
+            const batches = [
+                [{ tag: 'delta', val: { content: [{ tag: 'text', val: 'Hel' }] } }],
+                [{ tag: 'delta', val: { content: [{ tag: 'text', val: 'lo' }] } }, { tag: 'finish', val: { finishReason: 'stop' } }],
+            ];
+            return {
+                blockingGetNext: () => batches.shift(),
+                [Symbol.dispose]: () => {},
+            };
+        });
+
+        const messages = [{ role: 'user', content: 'Hi' }];
+        const response = await llm.chat(messages, { model: 'test-model' });
+        const tokens = [];
+        for await (const token of llm.streamText(messages, { model: 'test-model' })) {
+            tokens.push(token);
+        }
+
+        const [[sentMessages, config]] = calls(LLM, 'send');
+        return JSON.stringify({
+            sentMessages,
+            model: config.model,
+            text: response.text,
+            finishReason: response.finishReason,
+            usage: response.usage,
+            tokens,
+        });
+    },
+    runAtomically: async () => {
+        resetMocks();
+        let idempotent = true;
+        mockModule(HOST, {
+            markBeginOperation: () => 7n,
+            markEndOperation: () => {},
+            getIdempotenceMode: () => idempotent,
+            setIdempotenceMode: (value) => {
+                idempotent = value;
+            },
+        });
+
+        const result = await atomically(async () =>
+            withIdempotenceMode(false, () => `idempotent inside: ${idempotent}`));
+
+        return JSON.stringify({
+            result,
+            idempotentAfter: idempotent,
+            ends: calls(HOST, 'markEndOperation').map(([begin]) => String(begin)),
+            idempotenceModes: calls(HOST, 'setIdempotenceMode'),
+        });
+    },
+};
@@ -0,0 +1,26 @@
+// Built with the `mock-imports` feature, so the imported interfaces call the mocks set here
+import { mock, calls, resetMocks } from '__mocks';
+import * as random from 'wasi:random/random@0.2.3';
+
+const RANDOM = 'wasi:random/random@0.2.3';
+
+function rollDice() {
+    return Number(random.getRandomU64() % 6n) + 1;
+}
+
+export const api = {
+    rollWithMock: (value) => {
+        resetMocks();
+        mock(RANDOM, 'getRandomU64', () => value);
+        const result = rollDice();
+        return [result, calls(RANDOM, 'getRandomU64').length];
+    },
+    rollWithoutMock: () => {
+        resetMocks();
+        try {
+            return `rolled ${rollDice()}`;
+        } catch (error) {
+            return error.message;
+        }
+    },
+};
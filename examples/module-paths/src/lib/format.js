@@ -0,0 +1,9 @@
+import { repeat } from './utils';
+import { GREETING, SEPARATOR } from '../shared/constants.js';
+
+export const greet = (name) => `${GREETING}, ${name}!`;
+
+export const frame = (text) => {
+    const line = repeat(SEPARATOR, text.length + 4);
+    return `${line}\n${text}\n${line}`;
+};
@@ -0,0 +1,3 @@
+export const capitalize = (text) => text.charAt(0).toUpperCase() + text.slice(1);
+
+export const repeat = (text, count) => text.repeat(count);
@@ -0,0 +1,6 @@
+import { greet, frame } from './lib/format.js';
+import { capitalize } from 'lib/utils';
+
+export const hello = (name) => greet(capitalize(name));
+
+export const banner = (text) => frame(text);
@@ -0,0 +1,3 @@
+export const GREETING = 'Hello';
+
+export const SEPARATOR = '*';
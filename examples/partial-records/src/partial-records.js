@@ -0,0 +1,18 @@
+export const api = {
+    personWithoutNickname: () => {
+        return {name: "Alice", age: 30};
+    },
+    personWithExtraProperties: () => {
+        return {name: "Bob", age: 40, nickname: "bobby", email: "bob@example.com"};
+    },
+    personWithoutNameAndAge: () => {
+        return {nickname: "nobody"};
+    },
+    ordersWithInvalidZip: () => {
+        const order = (id, zip) => ({
+            id,
+            customer: {name: "Alice", address: {street: "Main Street 1", zip}},
+        });
+        return [order(0, "1000"), order(1, "2000"), order(2, "3000"), order(3, 4000)];
+    },
+};
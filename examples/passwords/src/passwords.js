@@ -0,0 +1,39 @@
+import { hashPassword, pbkdf2, verifyPassword } from 'passwords';
+
+// Hashes the password, returning the hash without its salt and digest, and whether the password and the
+// attempt verify against it
+export const hashAndVerify = (options, password, attempt) => {
+    const hash = hashPassword(password, JSON.parse(options));
+    const parameters = hash.slice(0, hash.lastIndexOf('$', hash.lastIndexOf('$') - 1));
+    const again = hashPassword(password, JSON.parse(options));
+    return [
+        parameters,
+        `password: ${verifyPassword(password, hash)}`,
+        `attempt: ${verifyPassword(attempt, hash)}`,
+        `salted: ${again !== hash}`,
+    ];
+};
+
+export const deriveKey = (password, salt, hash, iterations, length) => {
+    const key = pbkdf2(password, salt, { hash, iterations, length });
+    return Array.from(key, (byte) => byte.toString(16).padStart(2, '0')).join('');
+};
+
+export const errors = () => {
+    const attempts = [
+        () => hashPassword('secret', { algorithm: 'bcrypt' }),
+        () => hashPassword('secret', { timeCost: 0 }),
+        () => hashPassword(42),
+        () => pbkdf2('secret', 'salt', { hash: 'md5' }),
+        () => verifyPassword('secret', 'not a hash'),
+        () => hashPassword('secret', { memoryCost: 1 }),
+    ];
+    return attempts.map((attempt) => {
+        try {
+            attempt();
+            return 'no error';
+        } catch (error) {
+            return `${error.name}: ${error.message}`;
+        }
+    });
+};
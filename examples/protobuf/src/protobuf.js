@@ -0,0 +1,77 @@
+import { ProtobufReader, ProtobufWriter, WireType, decodeDelimited, encodeDelimited } from 'protobuf';
+import { GrpcError, GrpcWebClient } from 'grpc-web';
+
+// Encodes a `Greeting { string name = 1; }` message
+function greeting(name) {
+    return new ProtobufWriter().tag(1, WireType.LEN).string(name).finish();
+}
+
+function greetingName(bytes) {
+    const reader = new ProtobufReader(bytes);
+    let name = '';
+    while (!reader.eof()) {
+        const { fieldNumber, wireType } = reader.tag();
+        if (fieldNumber === 1 && wireType === WireType.LEN) {
+            name = reader.string();
+        } else {
+            reader.skip(wireType);
+        }
+    }
+    return name;
+}
+
+export const roundTrip = () => {
+    const bytes = new ProtobufWriter()
+        .tag(1, WireType.VARINT).uint32(300)
+        .tag(2, WireType.VARINT).int32(-1)
+        .tag(3, WireType.VARINT).sint64(-2n)
+        .tag(4, WireType.VARINT).uint64(18446744073709551615n)
+        .tag(5, WireType.I64).double(1.5)
+        .tag(6, WireType.I32).sfixed32(-7)
+        .tag(7, WireType.LEN).message((nested) => nested.tag(1, WireType.LEN).string('inner'))
+        .tag(8, WireType.VARINT).bool(true)
+        .finish();
+
+    const reader = new ProtobufReader(bytes);
+    const fields = [];
+    while (!reader.eof()) {
+        const { fieldNumber } = reader.tag();
+        switch (fieldNumber) {
+            case 1: fields.push(reader.uint32()); break;
+            case 2: fields.push(reader.int32()); break;
+            case 3: fields.push(reader.sint64()); break;
+            case 4: fields.push(reader.uint64()); break;
+            case 5: fields.push(reader.double()); break;
+            case 6: fields.push(reader.sfixed32()); break;
+            case 7: fields.push(greetingName(reader.bytes())); break;
+            case 8: fields.push(reader.bool()); break;
+        }
+    }
+
+    const frames = decodeDelimited(encodeDelimited([greeting('a'), greeting('b')])).map(greetingName);
+    return `${bytes.length} bytes: ${fields.join(', ')}; frames: ${frames.join(', ')}`;
+};
+
+export const grpcUnary = async (port) => {
+    const client = new GrpcWebClient(`http://localhost:${port}`);
+    return greetingName(await client.unary('test.Greeter/SayHello', greeting('world')));
+};
+
+export const grpcStreaming = async (port) => {
+    const client = new GrpcWebClient(`http://localhost:${port}`);
+    const names = [];
+    for await (const message of client.serverStreaming('test.Greeter/SayHellos', greeting('world'))) {
+        names.push(greetingName(message));
+    }
+    return names;
+};
+
+export const grpcError = async (port) => {
+    const client = new GrpcWebClient(`http://localhost:${port}/`);
+    try {
+        await client.unary('test.Greeter/Fail', greeting('world'));
+        return 'no error';
+    } catch (error) {
+        return error instanceof GrpcError ? `${error.name} ${error.code}: ${error.message}` : `${error}`;
+    }
+};
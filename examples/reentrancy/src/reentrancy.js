@@ -0,0 +1,12 @@
+import { reenter } from 'quickjs:reentrancy/host';
+
+export const run = () => {
+    reenter();
+    console.log("returned from the reentrant call");
+    return "run";
+};
+
+export const other = () => {
+    console.log("other export called");
+    return "other";
+};
@@ -0,0 +1,37 @@
+import { Regex, escape } from 're';
+
+function show(match) {
+    return match === null ? 'null' : JSON.stringify({ match: [...match], index: match.index, groups: match.groups });
+}
+
+export const exec = (pattern, flags, text, start) => show(new Regex(pattern, flags).exec(text, start));
+
+export const matchAll = (pattern, flags, text) => new Regex(pattern, flags).matchAll(text).map(show);
+
+export const replaceAll = (pattern, text, replacement) => new Regex(pattern).replaceAll(text, replacement);
+
+// Replaces with a function, getting the same arguments as with `String.prototype.replace`
+export const replaceWithFunction = (pattern, text) =>
+    new Regex(pattern).replaceAll(text, (...args) => `[${JSON.stringify(args.slice(0, -1)).slice(1, -1)}]`);
+
+export const split = (pattern, text) => new Regex(pattern).split(text);
+
+export const literal = (text, haystack) => new Regex(escape(text)).test(haystack);
+
+export const errors = () => {
+    const attempts = [
+        () => new Regex('a', 'g'),
+        () => new Regex('a').exec('a', -1),
+        () => new Regex('(a'),
+        () => new Regex('(a)\\1'),
+        () => new Regex('a(?=b)'),
+    ];
+    return attempts.map((attempt) => {
+        try {
+            attempt();
+            return 'no error';
+        } catch (error) {
+            return `${error.name}: ${error.message}`;
+        }
+    });
+};
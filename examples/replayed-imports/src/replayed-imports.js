@@ -0,0 +1,8 @@
+// Generated with `--replay-imports`, so the imported interfaces are not called, and return the
+// results recorded in `recording.jsonl` instead
+import * as random from 'wasi:random/random@0.2.3';
+
+export const api = {
+    randomNumbers: () => [random.getRandomU64(), random.getRandomU64()],
+    randomBytes: () => random.getRandomBytes(4n),
+};
@@ -0,0 +1,4 @@
+// Generated with `--math-random-seed 42`, so every instance returns the same numbers
+export const api = {
+    numbers: (count) => Array.from({ length: count }, () => Math.random()),
+};
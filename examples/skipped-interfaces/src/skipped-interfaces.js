@@ -0,0 +1,5 @@
+// Generated with `--skip-import wasi:random/random --skip-export quickjs:skipped-interfaces/admin`,
+// so only the `api` interface has to be implemented
+export const api = {
+    greet: (name) => `Hello, ${name}!`,
+};
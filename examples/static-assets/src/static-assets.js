@@ -0,0 +1,24 @@
+import * as assets from 'assets';
+
+export const renderPage = (title) => {
+    return assets.readText('templates/index.html').replaceAll('{{title}}', title);
+};
+
+export const logoSize = () => {
+    const logo = assets.readBytes('logo.png');
+    console.log(`logo starts with ${logo[1]} ${logo[2]} ${logo[3]}`);
+    return logo.length;
+};
+
+export const listAssets = () => {
+    return assets.list();
+};
+
+export const readMissing = () => {
+    try {
+        assets.readText('missing.txt');
+        return 'no error';
+    } catch (error) {
+        return `${assets.has('missing.txt')}: ${error.message}`;
+    }
+};
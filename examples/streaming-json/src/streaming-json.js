@@ -0,0 +1,56 @@
+import { JsonParser, parse, parseItems, parseValues, stringifyChunks as stringifyInChunks, stringifyStream } from 'json-stream';
+
+export const traceEvents = (chunks) => {
+    const events = [];
+    const parser = new JsonParser({
+        onStartObject: () => events.push('startObject'),
+        onEndObject: () => events.push('endObject'),
+        onStartArray: () => events.push('startArray'),
+        onEndArray: () => events.push('endArray'),
+        onKey: (key) => events.push(`key:${key}`),
+        onValue: (value) => events.push(`value:${JSON.stringify(value)}`),
+    });
+    for (const chunk of chunks) {
+        parser.write(chunk);
+    }
+    parser.end();
+    return events;
+};
+
+export const sumPrices = async (chunks) => {
+    let sum = 0;
+    for await (const item of parseItems(chunks)) {
+        sum += item.price;
+    }
+    return sum;
+};
+
+export const ids = async (ndjson) => {
+    const result = [];
+    for await (const value of parseValues(ndjson)) {
+        result.push(value.id);
+    }
+    return result;
+};
+
+export const stringifyChunks = (items, chunkSize) => {
+    const value = Array.from({ length: items }, (_, id) => ({ id, name: `item ${id}`, tags: ['a', 'b'] }));
+    const chunks = [...stringifyInChunks(value, { chunkSize })];
+    return [chunks.length, chunks.join('') === JSON.stringify(value)];
+};
+
+export const roundTrip = async (json) => {
+    const value = await parse(stringifyStream(JSON.parse(json), { chunkSize: 4 }));
+    return JSON.stringify(value);
+};
+
+export const parseError = (json) => {
+    try {
+        const parser = new JsonParser();
+        parser.write(json);
+        parser.end();
+        return 'valid';
+    } catch (error) {
+        return `${error.name}: ${error.message}`;
+    }
+};
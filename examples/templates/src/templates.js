@@ -0,0 +1,35 @@
+import { render, renderAsset } from 'templates';
+
+export const greeting = (name) => render('<p>Hello, {{ name }}!</p>', { name });
+
+const INVOICE = `Invoice for {{ customer }}
+{% for item in items %}{{ loop.index }}. {{ item.description }}: {{ item.quantity }} x {{ item.price|round(2) }}
+{% endfor %}Total: {{ total|round(2) }}`;
+
+export const invoice = (customer, items) =>
+    render(
+        INVOICE,
+        { customer, items, total: items.reduce((total, item) => total + item.quantity * item.price, 0) },
+        { autoescape: false },
+    );
+
+export const welcomeHtml = (name, url) => renderAsset('emails/welcome.html', { name, url });
+
+export const welcomeText = (name) => renderAsset('emails/welcome.txt', { name, team: 'Support' });
+
+export const errors = () => {
+    const messages = [];
+    for (const attempt of [
+        () => render('{% if %}', {}),
+        () => render('{{ user.greet() }}', { user: {} }),
+        () => renderAsset('emails/missing.html', {}),
+        () => render('{{ name }}', 'not an object'),
+    ]) {
+        try {
+            messages.push(attempt());
+        } catch (error) {
+            messages.push(`${error.name}: ${error.message}`);
+        }
+    }
+    return messages;
+};
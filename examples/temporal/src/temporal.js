@@ -0,0 +1,53 @@
+import { Duration, Instant, Now, PlainDate, ZonedDateTime } from 'temporal';
+
+export const addToZoned = (start, duration) => ZonedDateTime.from(start).add(duration).toString();
+
+export const difference = (start, end, largestUnit) =>
+    ZonedDateTime.from(start).until(end, { largestUnit }).toString();
+
+// A meeting at the same wall-clock hour every day, whatever the daylight saving time changes
+export const daily = (firstDay, hour, timeZone, days) => {
+    const first = PlainDate.from(firstDay).toZonedDateTime(timeZone).with({ hour });
+    return Array.from({ length: days }, (_, day) => first.add({ days: day }).toInstant().toString());
+};
+
+export const describeDate = (text) => {
+    const date = PlainDate.from(text);
+    return JSON.stringify({
+        dayOfWeek: date.dayOfWeek,
+        dayOfYear: date.dayOfYear,
+        daysInMonth: date.daysInMonth,
+        inLeapYear: date.inLeapYear,
+        nextMonth: date.add({ months: 1 }),
+        untilNewYear: date.until(PlainDate.from({ year: date.year + 1, month: 1, day: 1 })),
+    });
+};
+
+export const totalHours = (duration, relativeTo) =>
+    Duration.from(duration).total(relativeTo === undefined ? 'hours' : { unit: 'hours', relativeTo });
+
+export const nowMatchesDate = () => {
+    const before = Date.now();
+    const now = Now.instant().epochMilliseconds;
+    return before <= now && now <= Date.now();
+};
+
+export const errors = () => {
+    const attempts = [
+        () => Instant.from('2024-01-01T00:00:00'),
+        () => ZonedDateTime.from('2024-01-01T00:00:00+00:00[Mars/Olympus]'),
+        () => PlainDate.from({ year: 2023, month: 2, day: 29 }),
+        () => Duration.from({ days: 1, hours: -1 }),
+        () => Duration.from({ months: 1 }).total('days'),
+        () => Instant.from('2024-01-01T00:00:00Z').add({ days: 1 }),
+        () => PlainDate.from('2024-01-01').until('2024-02-01', { largestUnit: 'fortnights' }),
+        () => PlainDate.from('2024-01-01') < PlainDate.from('2024-02-01'),
+    ];
+    return attempts.map((attempt) => {
+        try {
+            return `no error: ${attempt()}`;
+        } catch (error) {
+            return `${error.name}: ${error.message}`;
+        }
+    });
+};
@@ -0,0 +1,7 @@
+// Generated with unversioned imports, so the module specifier has no package version
+import * as random from 'wasi:random/random';
+
+export const test = (input) => {
+    let num = random.getRandomU64();
+    return `${input} - ${num}`;
+};
@@ -0,0 +1,37 @@
+import { validateOrder } from 'validators';
+
+function sampleOrder(id, customer) {
+    return {
+        id,
+        customer: { name: customer },
+        items: [['apple', 3], ['pear', 1]],
+        status: 'pending',
+        labels: { urgent: false, gift: true },
+        payment: { tag: 'card', val: '4111' },
+    };
+}
+
+const brokenOrders = {
+    'wrong-id': { ...sampleOrder(1n, 'alice'), id: 1 },
+    'missing-name': { ...sampleOrder(1n, 'alice'), customer: {} },
+    'negative-quantity': { ...sampleOrder(1n, 'alice'), items: [['apple', 3], ['pear', -1]] },
+    'unknown-status': { ...sampleOrder(1n, 'alice'), status: 'lost' },
+    'unknown-payment': { ...sampleOrder(1n, 'alice'), payment: { tag: 'barter' } },
+    'wrong-attachment': { ...sampleOrder(1n, 'alice'), attachment: [1, 2, 3] },
+};
+
+export const api = {
+    createOrder: (id, customer) => {
+        const order = sampleOrder(id, customer);
+        validateOrder(order);
+        return order;
+    },
+    checkBrokenOrder: (name) => {
+        try {
+            validateOrder(brokenOrders[name]);
+            return 'valid';
+        } catch (error) {
+            return `${error.name}: ${error.message}`;
+        }
+    },
+};
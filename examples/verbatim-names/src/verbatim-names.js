@@ -0,0 +1,20 @@
+class WordCounter {
+    constructor(text) {
+        this.text = text;
+    }
+
+    "count-words"() {
+        return this.text.split(/\s+/).filter((word) => word.length > 0).length;
+    }
+}
+
+export default {
+    "say-hello": (name) => {
+        console.log(`say-hello called with ${name}`);
+        return `Hello, ${name}!`;
+    },
+    "text-tools": {
+        "word-counter": WordCounter,
+        "to-upper-case": (value) => value.toUpperCase(),
+    },
+};
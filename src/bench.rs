@@ -0,0 +1,103 @@
+use crate::run::{
+    ComponentSource, create_engine, create_linker, find_function, invoke, load_component,
+    new_quiet_store, parse_args,
+};
+use anyhow::Context;
+use std::time::{Duration, Instant};
+
+/// Settings of a benchmark run
+pub struct BenchOptions {
+    /// Number of measured instantiations of the component
+    pub instantiations: usize,
+    /// Number of calls before the measured ones
+    pub warmup: usize,
+    /// Number of measured calls
+    pub iterations: usize,
+}
+
+/// Gets (building it if necessary) the component and measures its compilation and instantiation
+/// time, and the latency of calling one of its exported functions with JSON-encoded arguments.
+///
+/// The guest's standard output and error are discarded, so logging in the called function does
+/// not distort the results.
+pub async fn bench(
+    source: &ComponentSource,
+    interface: Option<&str>,
+    function: &str,
+    args: &[String],
+    options: &BenchOptions,
+) -> anyhow::Result<()> {
+    let wasm_path = source.wasm_path()?;
+
+    let engine = create_engine()?;
+    let start = Instant::now();
+    let component = load_component(&engine, &wasm_path)?;
+    println!("Compilation: {:.2?}", start.elapsed());
+
+    let linker = create_linker(&engine, &component)?;
+
+    let mut instantiations = Vec::with_capacity(options.instantiations);
+    for _ in 0..options.instantiations {
+        let mut store = new_quiet_store(&engine);
+        let start = Instant::now();
+        linker
+            .instantiate_async(&mut store, &component)
+            .await
+            .context("Failed to instantiate component")?;
+        instantiations.push(start.elapsed());
+    }
+    print_stats("Instantiation", &mut instantiations);
+
+    let mut store = new_quiet_store(&engine);
+    let instance = linker
+        .instantiate_async(&mut store, &component)
+        .await
+        .context("Failed to instantiate component")?;
+    let func = find_function(&mut store, &instance, interface, function)?;
+    let params = parse_args(&store, func, function, args)?;
+
+    // The first call also initializes the JS runtime and evaluates the JS modules
+    let start = Instant::now();
+    invoke(&mut store, func, &params).await?;
+    println!("First call: {:.2?}", start.elapsed());
+
+    for _ in 0..options.warmup {
+        invoke(&mut store, func, &params).await?;
+    }
+
+    let mut calls = Vec::with_capacity(options.iterations);
+    for _ in 0..options.iterations {
+        let start = Instant::now();
+        invoke(&mut store, func, &params).await?;
+        calls.push(start.elapsed());
+    }
+    print_stats("Call", &mut calls);
+
+    let total = calls.iter().sum::<Duration>();
+    if !total.is_zero() {
+        println!(
+            "Throughput: {:.0} calls/s",
+            calls.len() as f64 / total.as_secs_f64()
+        );
+    }
+
+    Ok(())
+}
+
+fn print_stats(label: &str, samples: &mut [Duration]) {
+    if samples.is_empty() {
+        return;
+    }
+
+    samples.sort();
+    let mean = samples.iter().sum::<Duration>() / samples.len() as u32;
+    let percentile = |p: usize| samples[(samples.len() - 1) * p / 100];
+    println!(
+        "{label} ({} samples): mean {mean:.2?}, median {:.2?}, p99 {:.2?}, min {:.2?}, max {:.2?}",
+        samples.len(),
+        percentile(50),
+        percentile(99),
+        samples[0],
+        samples[samples.len() - 1],
+    );
+}
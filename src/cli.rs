@@ -1,30 +1,59 @@
-use camino::{Utf8Path, Utf8PathBuf};
-use clap::{Parser, Subcommand};
+use camino::Utf8PathBuf;
+use clap::{ArgAction, Parser, Subcommand, ValueEnum};
 use std::str::FromStr;
-use wasm_rquickjs::{EmbeddingMode, JsModuleSpec};
+use wasm_rquickjs::{
+    AssetSpec, BuildProfile, ComponentType, DateClock, DtsFormat, DtsLayout, EmbeddingMode,
+    JsModuleSpec, JsNamingPolicy, LoneSurrogatePolicy, MathRandomSeed, RecordTarget,
+};
 
 /// Wraps a JavaScript module as a WASM Component using Rust and the rquickjs crate
 #[derive(Parser, Debug)]
-#[command(version, about, long_about = None)]
+#[command(name = "wasm-rquickjs", version, about, long_about = None)]
 pub struct Args {
     #[command(subcommand)]
     pub command: Command,
+
+    /// Log the steps of the generation: `-v` for the resolved WIT packages and the generated
+    /// interfaces, `-vv` for every file written. `RUST_LOG` overrides it when set
+    #[arg(short, long, action = ArgAction::Count, global = true)]
+    pub verbose: u8,
+
+    /// Write the log into a file instead of stderr
+    #[arg(long, global = true)]
+    pub log_file: Option<Utf8PathBuf>,
 }
 
 #[derive(Subcommand, Debug)]
 pub enum Command {
     /// Generate the wrapper crate for a JavaScript module
-    GenerateWrapperCrate {
-        /// Path to the JavaScript module to wrap
-        #[arg(long, conflicts_with = "js_modules")]
-        js: Option<Utf8PathBuf>,
+    GenerateWrapperCrate(Box<GenerateWrapperCrateArgs>),
+    /// Generate and build a component exporting `get-script`, providing a JavaScript module to a
+    /// wrapper component using composition
+    ScriptComponent {
+        /// Path to the JavaScript module to provide
+        #[arg(long)]
+        js: Utf8PathBuf,
+
+        /// Path of the directory to generate the crate of the script component to
+        #[arg(long)]
+        output: Utf8PathBuf,
+    },
+    /// Compose a component with others satisfying its imports
+    Compose {
+        /// Path to the component whose imports are satisfied
+        #[arg(long)]
+        socket: Utf8PathBuf,
 
-        /// Advanced list of pairs consisting JS module names and how they should be loaded.
-        /// The format should be `name=from`, where `from` is either `@composition` or a path to
-        /// a JS module to be embedded
-        #[arg(long, conflicts_with = "js")]
-        js_modules: Vec<JsModuleSpecArg>,
+        /// Path to a component whose exports are plugged into the matching imports of the socket
+        #[arg(long = "plug", required = true)]
+        plugs: Vec<Utf8PathBuf>,
 
+        /// Path of the composed component to write
+        #[arg(short, long)]
+        output: Utf8PathBuf,
+    },
+    /// Generate TypeScript module definitions
+    GenerateDTS {
         /// Path to the WIT package the JavaScript module implements
         #[arg(long)]
         wit: Utf8PathBuf,
@@ -33,34 +62,586 @@ pub enum Command {
         #[arg(long)]
         output: Utf8PathBuf,
 
-        /// Whether to include the .cargo/config.toml file in the output directory
+        /// The WIT world to use
+        #[arg(long)]
+        world: Option<String>,
+
+        /// How the exported WIT names are mapped to the names of their JavaScript implementations
+        #[arg(long, value_enum, default_value_t = JsNamingPolicyArg::CamelCase)]
+        export_naming: JsNamingPolicyArg,
+
+        /// Omit the package versions from the module names of imported interfaces, unless an
+        /// interface is imported in several versions
+        #[arg(long, default_value = "false")]
+        unversioned_imports: bool,
+
+        /// Imported interface (such as `wasi:http/outgoing-handler`) not to expose to JavaScript.
+        /// Can be repeated
+        #[arg(long = "skip-import")]
+        skip_imports: Vec<String>,
+
+        /// Imported interface (such as `wasi:http/outgoing-handler`) that JavaScript is not allowed
+        /// to import, which throws an error instead. Can be repeated
+        #[arg(long = "deny-import")]
+        deny_imports: Vec<String>,
+
+        /// Exported interface not to implement in JavaScript; its functions trap when called.
+        /// Can be repeated
+        #[arg(long = "skip-export")]
+        skip_exports: Vec<String>,
+
+        /// Also generate the declarations of the `validators` JavaScript module
+        #[arg(long, default_value = "false")]
+        validators: bool,
+
+        /// Also generate the declarations of the `enums` JavaScript module
+        #[arg(long, default_value = "false")]
+        enum_constants: bool,
+
+        /// WIT type alias of `string` whose values are represented by the builtin `Decimal` class
+        /// in JavaScript. Can be repeated
+        #[arg(long = "decimal-type")]
+        decimal_types: Vec<String>,
+        /// Do not start the generated files with `@generated` header comments recording the
+        /// generator version, the WIT world and the hash of the WIT package
+        #[arg(long, default_value = "false")]
+        no_generated_headers: bool,
+
+        /// The file format of the declarations
+        #[arg(long, value_enum, default_value_t = DtsFormatArg::DTs)]
+        format: DtsFormatArg,
+
+        /// Generate every file as an ES module importing the others by relative paths, instead of
+        /// declaring ambient modules such as `declare module 'wasi:http/types'`. Implied by
+        /// `--format d-mts`
         #[arg(long, default_value = "false")]
-        include_cargo_config: bool,
+        module_files: bool,
+
+        /// Declare the globals (such as the `Decimal` class) in `declare global` blocks of modules
+        /// instead of scripts. Implied by `--format d-mts`
+        #[arg(long, default_value = "false")]
+        declare_global: bool,
+
+        /// Refer to resource handles by branded `OwnedFoo` and `BorrowedFoo` types, telling apart
+        /// the parameters taking over the ownership of a handle from the ones borrowing it
+        #[arg(long, default_value = "false")]
+        branded_handles: bool,
+
+        /// Where the declarations of the imported interfaces are placed. The nested layout also
+        /// generates an `index.d.ts` including all the declarations
+        #[arg(long, value_enum, default_value_t = DtsLayoutArg::Flat)]
+        layout: DtsLayoutArg,
+    },
+    /// Run an exported function of a component locally, printing its result as JSON
+    Run {
+        /// Path to a prebuilt WASM component to run
+        #[arg(long, conflicts_with_all = ["js", "wit"], required_unless_present = "js")]
+        component: Option<Utf8PathBuf>,
+
+        /// Path to a JavaScript module to generate a wrapper crate for, and build it
+        #[arg(long, requires = "wit")]
+        js: Option<Utf8PathBuf>,
+
+        /// Path to the WIT package the JavaScript module implements
+        #[arg(long, requires = "js")]
+        wit: Option<Utf8PathBuf>,
 
         /// The WIT world to use
         #[arg(long)]
         world: Option<String>,
+
+        /// Path of the directory to generate and build the wrapper crate in. Defaults to a
+        /// directory in the system's temporary directory, so subsequent runs can reuse the build
+        #[arg(long)]
+        output: Option<Utf8PathBuf>,
+
+        /// The exported interface of the function, for example `quickjs:example2/exp1`. Functions
+        /// exported directly from the world do not need it
+        #[arg(long)]
+        interface: Option<String>,
+
+        /// Name of the exported function to invoke
+        function: String,
+
+        /// JSON-encoded arguments to pass to the function
+        args: Vec<String>,
     },
-    /// Generate TypeScript module definitions
-    GenerateDTS {
+    /// Measure the compilation and instantiation time of a component, and the latency of calling
+    /// one of its exported functions
+    Bench {
+        /// Path to a prebuilt WASM component to benchmark
+        #[arg(long, conflicts_with_all = ["js", "wit"], required_unless_present = "js")]
+        component: Option<Utf8PathBuf>,
+
+        /// Path to a JavaScript module to generate a wrapper crate for, and build it
+        #[arg(long, requires = "wit")]
+        js: Option<Utf8PathBuf>,
+
         /// Path to the WIT package the JavaScript module implements
+        #[arg(long, requires = "js")]
+        wit: Option<Utf8PathBuf>,
+
+        /// The WIT world to use
+        #[arg(long)]
+        world: Option<String>,
+
+        /// Path of the directory to generate and build the wrapper crate in. Defaults to a
+        /// directory in the system's temporary directory, so subsequent runs can reuse the build
+        #[arg(long)]
+        output: Option<Utf8PathBuf>,
+
+        /// The exported interface of the function, for example `quickjs:example2/exp1`. Functions
+        /// exported directly from the world do not need it
+        #[arg(long)]
+        interface: Option<String>,
+
+        /// Number of measured instantiations of the component
+        #[arg(long, default_value_t = 20)]
+        instantiations: usize,
+
+        /// Number of calls of the function before the measured ones
+        #[arg(long, default_value_t = 100)]
+        warmup: usize,
+
+        /// Number of measured calls of the function
+        #[arg(long, default_value_t = 1000)]
+        iterations: usize,
+
+        /// Name of the exported function to call
+        function: String,
+
+        /// JSON-encoded arguments to pass to the function
+        args: Vec<String>,
+    },
+    /// Start an interactive prompt evaluating JavaScript code inside a component of a WIT world,
+    /// with the world's imports available as modules
+    Repl {
+        /// Path to the WIT package
         #[arg(long)]
         wit: Utf8PathBuf,
 
-        /// Path of the directory to generate the wrapper crate to
+        /// The WIT world to use
         #[arg(long)]
-        output: Utf8PathBuf,
+        world: Option<String>,
+
+        /// Path of the directory to generate and build the REPL crate in. Defaults to a
+        /// directory in the system's temporary directory, so subsequent runs can reuse the build
+        #[arg(long)]
+        output: Option<Utf8PathBuf>,
+    },
+    /// Build a component and rebuild it whenever its sources change, serving HTTP requests with
+    /// its `wasi:http/incoming-handler` export
+    Dev {
+        /// Path to the JavaScript module to wrap
+        #[arg(long)]
+        js: Utf8PathBuf,
+
+        /// Path to the WIT package the JavaScript module implements
+        #[arg(long)]
+        wit: Utf8PathBuf,
 
         /// The WIT world to use
         #[arg(long)]
         world: Option<String>,
+
+        /// Path of the directory to generate and build the wrapper crate in. Defaults to a
+        /// directory in the system's temporary directory, so subsequent runs can reuse the build
+        #[arg(long)]
+        output: Option<Utf8PathBuf>,
+
+        /// Port to serve HTTP requests on
+        #[arg(long, default_value = "8080")]
+        port: u16,
     },
+    /// Build and run the tests defined in `*.test.js` modules, reporting the result of each test
+    Test {
+        /// Directory to search for `*.test.js` modules in
+        #[arg(long, default_value = ".")]
+        dir: Utf8PathBuf,
+
+        /// Path of the directory to generate and build the test runner crate in. Defaults to a
+        /// directory in the system's temporary directory, so subsequent runs can reuse the build
+        #[arg(long)]
+        output: Option<Utf8PathBuf>,
+
+        /// Only run the tests whose `<module path> <export name>` contains this string
+        #[arg(long)]
+        filter: Option<String>,
+    },
+    /// Print the tab completion script of the CLI for a shell
+    Completions {
+        /// The shell to generate the completion script for
+        shell: clap_complete::Shell,
+    },
+    /// Generate the man pages of the CLI and its commands into a directory
+    Manpages {
+        /// The directory to write the man pages into, created if it does not exist
+        dir: Utf8PathBuf,
+    },
+    /// Create a new example for the test suites of this repository, with a minimal WIT world and
+    /// JS module
+    #[command(hide = true)]
+    NewExample {
+        /// Name of the example, used as the name of its WIT package, world and files
+        name: String,
+
+        /// The directory of the examples
+        #[arg(long, default_value = "examples")]
+        examples: Utf8PathBuf,
+    },
+}
+
+/// The options of the `generate-wrapper-crate` command, boxed in [`Command`] as they are many
+#[derive(clap::Args, Debug)]
+pub struct GenerateWrapperCrateArgs {
+    /// Path to the JavaScript module to wrap
+    #[arg(long, conflicts_with = "js_modules")]
+    pub js: Option<Utf8PathBuf>,
+
+    /// Advanced list of pairs consisting JS module names and how they should be loaded.
+    /// The format should be `name=from`, where `from` is either `@composition` or a path to
+    /// a JS module to be embedded. Names can be paths such as `lib/utils`, which the modules
+    /// can import each other by, also relatively (`./utils`). The module implementing the
+    /// exports is marked by an `@entry` suffix (`name=path@entry`), required if there are
+    /// several modules. The modules are evaluated in the given order, awaiting the `__init`
+    /// function each one may export before evaluating the next
+    #[arg(long, conflicts_with = "js")]
+    pub js_modules: Vec<JsModuleSpecArg>,
+
+    /// Path to the WIT package the JavaScript module implements
+    #[arg(long)]
+    pub wit: Utf8PathBuf,
+
+    /// Path of the directory to generate the wrapper crate to
+    #[arg(long)]
+    pub output: Utf8PathBuf,
+
+    /// Whether to include the .cargo/config.toml file in the output directory
+    #[arg(long, default_value = "false")]
+    pub include_cargo_config: bool,
+
+    /// The WIT world to use
+    #[arg(long)]
+    pub world: Option<String>,
+
+    /// How the exported WIT names are mapped to the names of their JavaScript implementations
+    #[arg(long, value_enum, default_value_t = JsNamingPolicyArg::CamelCase)]
+    pub export_naming: JsNamingPolicyArg,
+
+    /// Reject JS objects having properties not belonging to the WIT record they are converted to
+    #[arg(long, default_value = "false")]
+    pub strict_records: bool,
+
+    /// Cache the interned JS names of record fields and variant properties between
+    /// conversions, speeding up frequently called functions with large records
+    #[arg(long, default_value = "false")]
+    pub intern_field_names: bool,
+
+    /// How JS strings containing lone surrogates are converted to WIT strings
+    #[arg(long, value_enum, default_value_t = LoneSurrogatePolicyArg::Error)]
+    pub lone_surrogates: LoneSurrogatePolicyArg,
+
+    /// Make `Math.random` deterministic, generating its numbers from the given seed: a number,
+    /// or `host` to get the seed from the host through `wasi:random/insecure-seed`
+    #[arg(long)]
+    pub math_random_seed: Option<MathRandomSeedArg>,
+
+    /// Make `Date.now()` and `new Date()` use a clock other than the wall clock: a fixed time
+    /// in milliseconds since the Unix epoch, or `host` to import a `rquickjs-clock-now`
+    /// function returning it from the host
+    #[arg(long)]
+    pub date_clock: Option<DateClockArg>,
+
+    /// Call the imported interfaces through the hooks registered from JavaScript with
+    /// `registerImportHook`, for logging, mocking or caching the calls
+    #[arg(long, default_value = "false")]
+    pub import_hooks: bool,
+
+    /// Add a `mock-imports` cargo feature to the wrapper crate, replacing the imported interfaces
+    /// by mocks set from JavaScript through the `__mocks` module, for unit testing without a host
+    #[arg(long, default_value = "false")]
+    pub mock_imports: bool,
+
+    /// Record every call of an imported interface with its arguments and result as a JSON line,
+    /// written to `stderr` or appended to the given file path
+    #[arg(long, conflicts_with = "replay_imports")]
+    pub record_imports: Option<RecordTargetArg>,
+
+    /// Replay the results of the calls recorded by `--record-imports` in the given file
+    /// instead of calling the imported interfaces
+    #[arg(long)]
+    pub replay_imports: Option<Utf8PathBuf>,
+
+    /// Cancel the timers and fetches still pending when an exported function has its result,
+    /// instead of waiting for them before returning
+    #[arg(long, default_value = "false")]
+    pub cancel_background_work: bool,
+
+    /// Maximum size in bytes of the request bodies sent by `fetch`, overridable at runtime
+    /// with the `WASM_RQUICKJS_MAX_REQUEST_BODY_SIZE` environment variable
+    #[arg(long)]
+    pub max_request_body_size: Option<usize>,
+
+    /// Maximum size in bytes of the response bodies received by `fetch`, overridable at
+    /// runtime with the `WASM_RQUICKJS_MAX_RESPONSE_BODY_SIZE` environment variable
+    #[arg(long)]
+    pub max_response_body_size: Option<usize>,
+
+    /// Maximum number of headers of the requests and responses of `fetch`, overridable at
+    /// runtime with the `WASM_RQUICKJS_MAX_HEADER_COUNT` environment variable
+    #[arg(long)]
+    pub max_header_count: Option<usize>,
+
+    /// Keep the cookies set by the responses of `fetch` and send them with the subsequent
+    /// requests to the same site, unless a fetch has `credentials: 'omit'`
+    #[arg(long, default_value = "false")]
+    pub cookie_jar: bool,
+
+    /// Cache the responses of the GET requests of `fetch` in memory, honoring `Cache-Control`
+    /// and revalidating stale responses by their `ETag` or `Last-Modified` headers
+    #[arg(long, default_value = "false")]
+    pub http_cache: bool,
+
+    /// Maximum number of responses kept by the HTTP cache [default: 256]
+    #[arg(long, requires = "http_cache")]
+    pub http_cache_max_entries: Option<usize>,
+
+    /// Maximum total size in bytes of the response bodies kept by the HTTP cache
+    /// [default: 16777216]
+    #[arg(long, requires = "http_cache")]
+    pub http_cache_max_size: Option<usize>,
+
+    /// Maximum size in bytes of a response body kept by the HTTP cache, larger responses are
+    /// not cached [default: 1048576]
+    #[arg(long, requires = "http_cache")]
+    pub http_cache_max_body_size: Option<usize>,
+
+    /// Base URL the relative URLs of `fetch` are resolved against, overridable at runtime with
+    /// the `WASM_RQUICKJS_FETCH_BASE_URL` environment variable
+    #[arg(long)]
+    pub fetch_base_url: Option<String>,
+
+    /// URL of a gateway all outgoing HTTP requests of `fetch` are sent to, overridable at runtime
+    /// with the `WASM_RQUICKJS_FETCH_GATEWAY` and `WASM_RQUICKJS_FETCH_GATEWAY_BYPASS`
+    /// environment variables
+    #[arg(long)]
+    pub fetch_gateway: Option<String>,
+
+    /// Omit the package versions from the module names of imported interfaces, unless an
+    /// interface is imported in several versions
+    #[arg(long, default_value = "false")]
+    pub unversioned_imports: bool,
+
+    /// Imported interface (such as `wasi:http/outgoing-handler`) not to expose to JavaScript.
+    /// Can be repeated
+    #[arg(long = "skip-import")]
+    pub skip_imports: Vec<String>,
+
+    /// Imported interface (such as `wasi:http/outgoing-handler`) that JavaScript is not allowed
+    /// to import, which throws an error instead. Can be repeated
+    #[arg(long = "deny-import")]
+    pub deny_imports: Vec<String>,
+
+    /// Exported interface not to implement in JavaScript; its functions trap when called.
+    /// Can be repeated
+    #[arg(long = "skip-export")]
+    pub skip_exports: Vec<String>,
+
+    /// Add an extra `rquickjs-invoke` export calling any exported function with JSON-encoded
+    /// arguments, for debugging and generic tooling
+    #[arg(long, default_value = "false")]
+    pub invoke_export: bool,
+
+    /// Add an extra `rquickjs-describe` export returning a JSON description of the component's
+    /// exports, imports and enabled features
+    #[arg(long, default_value = "false")]
+    pub describe_export: bool,
+
+    /// Add a `validators` JavaScript module with `validateX(value)` functions checking values
+    /// against the WIT types
+    #[arg(long, default_value = "false")]
+    pub validators: bool,
+
+    /// Add an `enums` JavaScript module exporting a frozen constant object for each WIT enum,
+    /// such as `LogLevel.Debug`
+    #[arg(long, default_value = "false")]
+    pub enum_constants: bool,
+
+    /// Static file to embed into the component, readable from JavaScript through the `assets`
+    /// module. The format should be `name=path`, where `name` is the relative path the asset is
+    /// read by (such as `templates/index.html`). Can be repeated
+    #[arg(long = "asset")]
+    pub assets: Vec<AssetSpecArg>,
+
+    /// WIT type alias of `string` (such as `money` for `type money = string`) whose values are
+    /// represented by the builtin `Decimal` class in JavaScript. Can be repeated
+    #[arg(long = "decimal-type")]
+    pub decimal_types: Vec<String>,
+
+    /// Compile-time constant baked into the embedded JavaScript modules, replacing the
+    /// `process.env.KEY` and `__DEFINE__.KEY` expressions by the string `VALUE`. The format
+    /// should be `KEY=VALUE`. Can be repeated
+    #[arg(long = "define")]
+    pub defines: Vec<DefineArg>,
+
+    /// Restricts the builtin modules a JavaScript module can import. The format should be
+    /// `module=builtins`, where `builtins` is a comma-separated list of builtin modules (such
+    /// as `fs,buffer`), or empty to deny all of them. Can be repeated
+    #[arg(long = "builtin-permission")]
+    pub builtin_permissions: Vec<BuiltinPermissionArg>,
+
+    /// Comma-separated list of the cargo features enabled by default in the generated crate,
+    /// replacing the skeleton's defaults (`http,logging`). Pass an empty string to disable
+    /// all of them
+    #[arg(long, value_delimiter = ',')]
+    pub default_features: Option<Vec<String>>,
+
+    /// Fail instead of warning if the JavaScript modules use APIs that are not available with
+    /// the default features, such as `fetch` without the `http` feature
+    #[arg(long, default_value = "false")]
+    pub strict: bool,
+
+    /// Skip the interfaces using unsupported WIT constructs instead of failing, reporting
+    /// them as warnings: imported ones are not available to JavaScript, and the functions of
+    /// exported ones trap when called
+    #[arg(long, default_value = "false")]
+    pub keep_going: bool,
+
+    /// Do not start the generated files with `@generated` header comments recording the
+    /// generator version, the WIT world and the hashes of the inputs
+    #[arg(long, default_value = "false")]
+    pub no_generated_headers: bool,
+
+    /// Do not generate the Golem application manifest (golem.yaml)
+    #[arg(long, default_value = "false")]
+    pub no_app_manifest: bool,
+
+    /// The Golem component type written into the application manifest
+    #[arg(long, value_enum, conflicts_with = "no_app_manifest")]
+    pub component_type: Option<ComponentTypeArg>,
+
+    /// The build profiles defined in the application manifest, the first being the default one.
+    /// Defaults to `debug` and `release`
+    #[arg(long = "profile", value_enum, conflicts_with = "no_app_manifest")]
+    pub profiles: Vec<BuildProfileArg>,
+
+    /// Path to a YAML fragment added to the component's definition in the application manifest
+    #[arg(long, conflicts_with = "no_app_manifest")]
+    pub app_manifest_component_fragment: Option<Utf8PathBuf>,
+
+    /// Path to a YAML fragment with top-level keys appended to the application manifest
+    #[arg(long, conflicts_with = "no_app_manifest")]
+    pub app_manifest_fragment: Option<Utf8PathBuf>,
+
+    /// Print the files that would be generated, the crate's default features and the
+    /// embedded JS modules, without writing anything
+    #[arg(long, default_value = "false")]
+    pub dry_run: bool,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum JsNamingPolicyArg {
+    /// Functions and interfaces in camelCase, resources in UpperCamelCase, as named exports
+    CamelCase,
+    /// Names kept verbatim as in WIT, as fields of the default export
+    Verbatim,
+}
+
+impl From<JsNamingPolicyArg> for JsNamingPolicy {
+    fn from(value: JsNamingPolicyArg) -> Self {
+        match value {
+            JsNamingPolicyArg::CamelCase => JsNamingPolicy::CamelCase,
+            JsNamingPolicyArg::Verbatim => JsNamingPolicy::Verbatim,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum DtsFormatArg {
+    /// `.d.ts` files
+    DTs,
+    /// `.d.mts` files
+    DMts,
+}
+
+impl From<DtsFormatArg> for DtsFormat {
+    fn from(value: DtsFormatArg) -> Self {
+        match value {
+            DtsFormatArg::DTs => DtsFormat::Dts,
+            DtsFormatArg::DMts => DtsFormat::Mts,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum DtsLayoutArg {
+    /// One file per interface in the output directory
+    Flat,
+    /// One file per interface in `types/<package>/`, with an index
+    Nested,
+}
+
+impl From<DtsLayoutArg> for DtsLayout {
+    fn from(value: DtsLayoutArg) -> Self {
+        match value {
+            DtsLayoutArg::Flat => DtsLayout::Flat,
+            DtsLayoutArg::Nested => DtsLayout::Nested,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum LoneSurrogatePolicyArg {
+    /// Fail the conversion
+    Error,
+    /// Replace each lone surrogate with U+FFFD
+    Replace,
+}
+
+impl From<LoneSurrogatePolicyArg> for LoneSurrogatePolicy {
+    fn from(value: LoneSurrogatePolicyArg) -> Self {
+        match value {
+            LoneSurrogatePolicyArg::Error => LoneSurrogatePolicy::Error,
+            LoneSurrogatePolicyArg::Replace => LoneSurrogatePolicy::Replace,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum ComponentTypeArg {
+    Durable,
+    Ephemeral,
+}
+
+impl From<ComponentTypeArg> for ComponentType {
+    fn from(value: ComponentTypeArg) -> Self {
+        match value {
+            ComponentTypeArg::Durable => ComponentType::Durable,
+            ComponentTypeArg::Ephemeral => ComponentType::Ephemeral,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum BuildProfileArg {
+    Debug,
+    Release,
+}
+
+impl From<BuildProfileArg> for BuildProfile {
+    fn from(value: BuildProfileArg) -> Self {
+        match value {
+            BuildProfileArg::Debug => BuildProfile::Debug,
+            BuildProfileArg::Release => BuildProfile::Release,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct JsModuleSpecArg {
     pub name: String,
     pub mode: EmbeddingMode,
+    pub entry: bool,
 }
 
 impl From<JsModuleSpecArg> for JsModuleSpec {
@@ -68,6 +649,7 @@ impl From<JsModuleSpecArg> for JsModuleSpec {
         JsModuleSpec {
             name: value.name,
             mode: value.mode,
+            entry: value.entry,
         }
     }
 }
@@ -81,10 +663,160 @@ impl FromStr for JsModuleSpecArg {
             return Err(format!("Invalid JS module spec: {s}"));
         }
         let name = parts[0].to_string();
-        let mode = match parts[1] {
+        let (from, entry) = match parts[1].strip_suffix("@entry") {
+            Some(from) => (from, true),
+            None => (parts[1], false),
+        };
+        let mode = match from {
             "@composition" => EmbeddingMode::Composition,
-            path => EmbeddingMode::EmbedFile(Utf8Path::new(path).to_path_buf()),
+            path => EmbeddingMode::EmbedFile(path.into()),
         };
-        Ok(JsModuleSpecArg { name, mode })
+        Ok(JsModuleSpecArg { name, mode, entry })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct AssetSpecArg {
+    pub name: String,
+    pub path: Utf8PathBuf,
+}
+
+impl From<AssetSpecArg> for AssetSpec {
+    fn from(value: AssetSpecArg) -> Self {
+        AssetSpec {
+            name: value.name,
+            path: value.path.into(),
+        }
+    }
+}
+
+impl FromStr for AssetSpecArg {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once('=') {
+            Some((name, path)) if !name.is_empty() && !path.is_empty() => Ok(AssetSpecArg {
+                name: name.to_string(),
+                path: Utf8PathBuf::from(path),
+            }),
+            _ => Err(format!("Invalid asset spec: {s}")),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DefineArg {
+    pub key: String,
+    pub value: String,
+}
+
+impl FromStr for DefineArg {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once('=') {
+            Some((key, value)) if !key.is_empty() => Ok(DefineArg {
+                key: key.to_string(),
+                value: value.to_string(),
+            }),
+            _ => Err(format!("Invalid define: {s}, expected KEY=VALUE")),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct BuiltinPermissionArg {
+    pub module: String,
+    pub builtins: Vec<String>,
+}
+
+impl FromStr for BuiltinPermissionArg {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once('=') {
+            Some((module, builtins)) if !module.is_empty() => Ok(BuiltinPermissionArg {
+                module: module.to_string(),
+                builtins: builtins
+                    .split(',')
+                    .filter(|builtin| !builtin.is_empty())
+                    .map(|builtin| builtin.to_string())
+                    .collect(),
+            }),
+            _ => Err(format!(
+                "Invalid builtin permission: {s}, expected MODULE=BUILTIN,..."
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct MathRandomSeedArg(pub MathRandomSeed);
+
+impl From<MathRandomSeedArg> for MathRandomSeed {
+    fn from(value: MathRandomSeedArg) -> Self {
+        value.0
+    }
+}
+
+impl FromStr for MathRandomSeedArg {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "host" => Ok(MathRandomSeedArg(MathRandomSeed::Host)),
+            seed => seed
+                .parse()
+                .map(|seed| MathRandomSeedArg(MathRandomSeed::Fixed(seed)))
+                .map_err(|_| format!("Invalid seed: {s}, expected a number or `host`")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct DateClockArg(pub DateClock);
+
+impl From<DateClockArg> for DateClock {
+    fn from(value: DateClockArg) -> Self {
+        value.0
+    }
+}
+
+impl FromStr for DateClockArg {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "host" => Ok(DateClockArg(DateClock::Host)),
+            millis => millis
+                .parse()
+                .map(|millis| DateClockArg(DateClock::Fixed(millis)))
+                .map_err(|_| {
+                    format!(
+                        "Invalid clock: {s}, expected milliseconds since the Unix epoch or `host`"
+                    )
+                }),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RecordTargetArg(pub RecordTarget);
+
+impl From<RecordTargetArg> for RecordTarget {
+    fn from(value: RecordTargetArg) -> Self {
+        value.0
+    }
+}
+
+impl FromStr for RecordTargetArg {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "" => Err("Invalid recording target, expected `stderr` or a file path".to_string()),
+            "stderr" => Ok(RecordTargetArg(RecordTarget::Stderr)),
+            path => Ok(RecordTargetArg(RecordTarget::File(path.to_string()))),
+        }
     }
 }
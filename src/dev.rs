@@ -0,0 +1,245 @@
+use crate::run::{Host, build_component, create_engine, create_linker, load_component, new_store};
+use anyhow::{Context, anyhow};
+use bytes::Bytes;
+use camino::{Utf8Path, Utf8PathBuf};
+use http_body_util::{BodyExt, Full};
+use hyper::server::conn::http1;
+use std::collections::BTreeMap;
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant, SystemTime};
+use tokio::net::TcpListener;
+use wasm_rquickjs::{EmbeddingMode, JsModuleSpec};
+use wasmtime::Engine;
+use wasmtime_wasi_http::WasiHttpView;
+use wasmtime_wasi_http::bindings::ProxyPre;
+use wasmtime_wasi_http::bindings::http::types::Scheme;
+use wasmtime_wasi_http::body::HyperOutgoingBody;
+use wasmtime_wasi_http::io::TokioIo;
+
+/// How often the sources are checked for changes
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// The most recently built component, if it exports `wasi:http/incoming-handler`
+type CurrentProxy = Arc<RwLock<Option<ProxyPre<Host>>>>;
+
+/// Builds the component, and rebuilds it every time the JavaScript module or the WIT package
+/// changes. Meanwhile, HTTP requests received on `port` are handled by the most recently built
+/// component's `wasi:http/incoming-handler` export.
+pub async fn dev(
+    js: &Utf8Path,
+    wit: &Utf8Path,
+    world: Option<&str>,
+    output: &Utf8Path,
+    port: u16,
+) -> anyhow::Result<()> {
+    let engine = create_engine()?;
+    let current: CurrentProxy = Arc::new(RwLock::new(None));
+
+    let addr = SocketAddr::from(([127, 0, 0, 1], port));
+    let listener = TcpListener::bind(addr)
+        .await
+        .with_context(|| format!("Failed to listen on {addr}"))?;
+    println!("Serving HTTP requests on http://{addr}");
+    tokio::spawn(serve(listener, current.clone()));
+
+    let js_modules = vec![JsModuleSpec {
+        name: js
+            .file_stem()
+            .ok_or_else(|| anyhow!("Invalid JavaScript module path {js}"))?
+            .to_string(),
+        mode: EmbeddingMode::EmbedFile(js.into()),
+        entry: true,
+    }];
+
+    let mut last_snapshot = None;
+    loop {
+        let snapshot = snapshot_sources(js, wit)?;
+        if last_snapshot.as_ref() != Some(&snapshot) {
+            last_snapshot = Some(snapshot);
+            rebuild(&engine, &js_modules, wit, world, output, &current).await;
+        }
+        tokio::time::sleep(POLL_INTERVAL).await;
+    }
+}
+
+/// Rebuilds the component and replaces the served one with it. If the build fails, the previous
+/// component keeps being served.
+async fn rebuild(
+    engine: &Engine,
+    js_modules: &[JsModuleSpec],
+    wit: &Utf8Path,
+    world: Option<&str>,
+    output: &Utf8Path,
+    current: &CurrentProxy,
+) {
+    println!("Building component...");
+    let started = Instant::now();
+
+    let build = {
+        let js_modules = js_modules.to_vec();
+        let wit = wit.to_path_buf();
+        let world = world.map(|world| world.to_string());
+        let output = output.to_path_buf();
+        tokio::task::spawn_blocking(move || {
+            build_component(&js_modules, &wit, world.as_deref(), &output)
+        })
+    };
+    let result = match build.await {
+        Ok(result) => result.and_then(|wasm_path| prepare(engine, &wasm_path)),
+        Err(err) => Err(err.into()),
+    };
+
+    match result {
+        Ok(proxy) => {
+            if proxy.is_none() {
+                println!(
+                    "The component does not export wasi:http/incoming-handler, HTTP requests are not handled"
+                );
+            }
+            *current.write().unwrap() = proxy;
+            println!("Component ready in {:.1}s", started.elapsed().as_secs_f64());
+        }
+        Err(err) => eprintln!("Failed to build the component: {err:#}"),
+    }
+}
+
+fn prepare(engine: &Engine, wasm_path: &Utf8Path) -> anyhow::Result<Option<ProxyPre<Host>>> {
+    let component = load_component(engine, wasm_path)?;
+    let linker = create_linker(engine, &component)?;
+    let instance_pre = linker.instantiate_pre(&component)?;
+    Ok(ProxyPre::new(instance_pre).ok())
+}
+
+/// Gets the modification times of the JavaScript module and of all the files of the WIT package
+fn snapshot_sources(
+    js: &Utf8Path,
+    wit: &Utf8Path,
+) -> anyhow::Result<BTreeMap<Utf8PathBuf, SystemTime>> {
+    let mut result = BTreeMap::new();
+    result.insert(js.to_path_buf(), modified(js)?);
+    snapshot_dir(wit, &mut result)?;
+    Ok(result)
+}
+
+fn snapshot_dir(
+    dir: &Utf8Path,
+    result: &mut BTreeMap<Utf8PathBuf, SystemTime>,
+) -> anyhow::Result<()> {
+    for entry in dir
+        .read_dir_utf8()
+        .with_context(|| format!("Failed to read directory {dir}"))?
+    {
+        let entry = entry?;
+        if entry.file_type()?.is_dir() {
+            snapshot_dir(entry.path(), result)?;
+        } else {
+            result.insert(entry.path().to_path_buf(), modified(entry.path())?);
+        }
+    }
+    Ok(())
+}
+
+fn modified(path: &Utf8Path) -> anyhow::Result<SystemTime> {
+    path.metadata()
+        .and_then(|metadata| metadata.modified())
+        .with_context(|| format!("Failed to get the modification time of {path}"))
+}
+
+async fn serve(listener: TcpListener, current: CurrentProxy) {
+    loop {
+        let (client, addr) = match listener.accept().await {
+            Ok(accepted) => accepted,
+            Err(err) => {
+                eprintln!("Failed to accept connection: {err}");
+                continue;
+            }
+        };
+
+        let current = current.clone();
+        tokio::spawn(async move {
+            let service = hyper::service::service_fn(move |req| {
+                let current = current.clone();
+                async move { Ok::<_, Infallible>(handle_request(&current, req).await) }
+            });
+            if let Err(err) = http1::Builder::new()
+                .keep_alive(true)
+                .serve_connection(TokioIo::new(client), service)
+                .await
+            {
+                eprintln!("Error serving client {addr}: {err}");
+            }
+        });
+    }
+}
+
+async fn handle_request(
+    current: &CurrentProxy,
+    req: hyper::Request<hyper::body::Incoming>,
+) -> hyper::Response<HyperOutgoingBody> {
+    let method = req.method().clone();
+    let uri = req.uri().clone();
+
+    let pre = current.read().unwrap().clone();
+    let response = match pre {
+        Some(pre) => match call_handler(pre, req).await {
+            Ok(response) => response,
+            Err(err) => {
+                eprintln!("{method} {uri} failed: {err:#}");
+                text_response(500, format!("{err:#}"))
+            }
+        },
+        None => text_response(
+            503,
+            "No component exporting wasi:http/incoming-handler is available".to_string(),
+        ),
+    };
+
+    println!("{method} {uri} -> {}", response.status());
+    response
+}
+
+async fn call_handler(
+    pre: ProxyPre<Host>,
+    req: hyper::Request<hyper::body::Incoming>,
+) -> anyhow::Result<hyper::Response<HyperOutgoingBody>> {
+    let mut store = new_store(pre.engine());
+    let (sender, receiver) = tokio::sync::oneshot::channel();
+    let req = store.data_mut().new_incoming_request(Scheme::Http, req)?;
+    let out = store.data_mut().new_response_outparam(sender)?;
+
+    // Running the handler in a separate task, as it can continue streaming the response body
+    // after the response was returned
+    let task = tokio::spawn(async move {
+        let proxy = pre.instantiate_async(&mut store).await?;
+        proxy
+            .wasi_http_incoming_handler()
+            .call_handle(store, req, out)
+            .await
+    });
+
+    match receiver.await {
+        Ok(Ok(response)) => Ok(response),
+        Ok(Err(error_code)) => Err(anyhow!("The component returned an error: {error_code:?}")),
+        Err(_) => {
+            let err = match task.await {
+                Ok(Ok(())) => anyhow!("The component did not set a response"),
+                Ok(Err(err)) => err,
+                Err(err) => err.into(),
+            };
+            Err(err.context("The component did not set a response"))
+        }
+    }
+}
+
+fn text_response(status: u16, body: String) -> hyper::Response<HyperOutgoingBody> {
+    let mut response = hyper::Response::new(
+        Full::new(Bytes::from(body))
+            .map_err(|never| match never {})
+            .boxed(),
+    );
+    *response.status_mut() =
+        hyper::StatusCode::from_u16(status).expect("Status code must be valid");
+    response
+}
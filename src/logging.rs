@@ -0,0 +1,35 @@
+use anyhow::Context;
+use camino::Utf8Path;
+use std::sync::Mutex;
+use tracing_subscriber::EnvFilter;
+
+/// Sets up the logging of the library and the CLI at the level selected by the number of `-v`
+/// flags, into `log_file` if given, otherwise to stderr. Without `-v` only warnings are logged.
+pub fn init(verbose: u8, log_file: Option<&Utf8Path>) -> anyhow::Result<()> {
+    let filter = match std::env::var("RUST_LOG") {
+        Ok(directives) => EnvFilter::try_new(directives).context("Invalid RUST_LOG")?,
+        Err(_) => {
+            let level = match verbose {
+                0 => "warn",
+                1 => "debug",
+                _ => "trace",
+            };
+            // Only the crate's own logs are verbose, the runtime's ones are too noisy
+            EnvFilter::new(format!("warn,wasm_rquickjs={level}"))
+        }
+    };
+
+    let builder = tracing_subscriber::fmt().with_env_filter(filter);
+    match log_file {
+        Some(path) => {
+            let file = std::fs::File::create(path)
+                .with_context(|| format!("Failed to create the log file {path}"))?;
+            builder
+                .with_ansi(false)
+                .with_writer(Mutex::new(file))
+                .init();
+        }
+        None => builder.with_writer(std::io::stderr).init(),
+    }
+    Ok(())
+}
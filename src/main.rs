@@ -1,39 +1,529 @@
-use crate::cli::{Args, Command};
-use clap::Parser;
-use wasm_rquickjs::{EmbeddingMode, JsModuleSpec, generate_dts, generate_wrapper_crate};
+use crate::bench::BenchOptions;
+use crate::cli::{Args, BuildProfileArg, Command, ComponentTypeArg, GenerateWrapperCrateArgs};
+use crate::run::ComponentSource;
+use anyhow::Context;
+use camino::{Utf8Path, Utf8PathBuf};
+use clap::{CommandFactory, Parser};
+use wasm_rquickjs::{
+    AppManifestOptions, AssetSpec, BuildProfile, ComponentType, Diagnostic, EmbeddingMode,
+    GenerationPlan, GeneratorOptions, HttpCacheLimits, HttpLimits, ImportRecording, JsModuleSpec,
+    compose, generate_dts, generate_script_component_crate, generate_wrapper_crate,
+    plan_wrapper_crate,
+};
 
+mod bench;
 mod cli;
+mod dev;
+mod logging;
+mod manpages;
+mod new_example;
+mod repl;
+mod run;
+mod test_suites;
 
 fn main() {
     let args = Args::parse();
+    if let Err(err) = logging::init(args.verbose, args.log_file.as_deref()) {
+        eprintln!("Error setting up logging: {err:#}");
+        std::process::exit(1);
+    }
     match &args.command {
-        Command::GenerateWrapperCrate {
-            js: maybe_js,
-            js_modules,
-            wit,
-            output,
-            world,
-            include_cargo_config,
-        } => {
+        Command::GenerateWrapperCrate(generate_args) => {
+            let GenerateWrapperCrateArgs {
+                js: maybe_js,
+                js_modules,
+                wit,
+                output,
+                world,
+                include_cargo_config,
+                export_naming,
+                strict_records,
+                intern_field_names,
+                lone_surrogates,
+                math_random_seed,
+                date_clock,
+                import_hooks,
+                mock_imports,
+                record_imports,
+                replay_imports,
+                cancel_background_work,
+                max_request_body_size,
+                max_response_body_size,
+                max_header_count,
+                cookie_jar,
+                http_cache,
+                http_cache_max_entries,
+                http_cache_max_size,
+                http_cache_max_body_size,
+                fetch_base_url,
+                fetch_gateway,
+                unversioned_imports,
+                skip_imports,
+                deny_imports,
+                skip_exports,
+                invoke_export,
+                describe_export,
+                validators,
+                enum_constants,
+                assets,
+                decimal_types,
+                defines,
+                builtin_permissions,
+                default_features,
+                strict,
+                keep_going,
+                no_generated_headers,
+                no_app_manifest,
+                component_type,
+                profiles,
+                app_manifest_component_fragment,
+                app_manifest_fragment,
+                dry_run,
+            } = generate_args.as_ref();
             let modules = if let Some(js) = maybe_js {
                 vec![JsModuleSpec {
                     name: "bundle/script_module".to_string(),
-                    mode: EmbeddingMode::EmbedFile(js.clone()),
+                    mode: EmbeddingMode::EmbedFile(js.into()),
+                    entry: true,
                 }]
             } else {
-                js_modules.iter().cloned().map(JsModuleSpec::from).collect()
+                let mut modules = js_modules
+                    .iter()
+                    .cloned()
+                    .map(JsModuleSpec::from)
+                    .collect::<Vec<_>>();
+                // A single module is the entry even without the `@entry` marker
+                if let [module] = modules.as_mut_slice() {
+                    module.entry = true;
+                }
+                modules
+            };
+
+            let app_manifest = app_manifest_options(
+                *no_app_manifest,
+                *component_type,
+                profiles,
+                app_manifest_component_fragment.as_deref(),
+                app_manifest_fragment.as_deref(),
+            );
+            let app_manifest = match app_manifest {
+                Ok(app_manifest) => app_manifest,
+                Err(err) => {
+                    eprintln!("Error generating wrapper crate: {err:#}");
+                    std::process::exit(1);
+                }
             };
 
-            if let Err(err) = generate_wrapper_crate(wit, &modules, output, world.as_deref(), *include_cargo_config) {
+            let options = GeneratorOptions {
+                include_cargo_config: *include_cargo_config,
+                export_naming: (*export_naming).into(),
+                strict_records: *strict_records,
+                intern_field_names: *intern_field_names,
+                lone_surrogates: (*lone_surrogates).into(),
+                math_random_seed: math_random_seed.map(Into::into),
+                date_clock: date_clock.map(Into::into),
+                import_hooks: *import_hooks,
+                mock_imports: *mock_imports,
+                import_recording: match (record_imports, replay_imports) {
+                    (Some(target), _) => Some(ImportRecording::Record(target.clone().into())),
+                    (None, Some(path)) => Some(ImportRecording::Replay(path.into())),
+                    (None, None) => None,
+                },
+                cancel_background_work: *cancel_background_work,
+                http_limits: HttpLimits {
+                    max_request_body_size: *max_request_body_size,
+                    max_response_body_size: *max_response_body_size,
+                    max_header_count: *max_header_count,
+                },
+                cookie_jar: *cookie_jar,
+                http_cache: *http_cache,
+                http_cache_limits: {
+                    let defaults = HttpCacheLimits::default();
+                    HttpCacheLimits {
+                        max_entries: http_cache_max_entries.or(defaults.max_entries),
+                        max_size: http_cache_max_size.or(defaults.max_size),
+                        max_body_size: http_cache_max_body_size.or(defaults.max_body_size),
+                    }
+                },
+                fetch_base_url: fetch_base_url.clone(),
+                fetch_gateway: fetch_gateway.clone(),
+                unversioned_imports: *unversioned_imports,
+                skip_imports: skip_imports.clone(),
+                deny_imports: deny_imports.clone(),
+                skip_exports: skip_exports.clone(),
+                invoke_export: *invoke_export,
+                describe_export: *describe_export,
+                validators: *validators,
+                enum_constants: *enum_constants,
+                assets: assets.iter().cloned().map(AssetSpec::from).collect(),
+                decimal_types: decimal_types.clone(),
+                defines: defines
+                    .iter()
+                    .map(|define| (define.key.clone(), define.value.clone()))
+                    .collect(),
+                builtin_permissions: builtin_permissions
+                    .iter()
+                    .map(|permission| (permission.module.clone(), permission.builtins.clone()))
+                    .collect(),
+                default_features: default_features.as_ref().map(|features| {
+                    features
+                        .iter()
+                        .filter(|feature| !feature.is_empty())
+                        .cloned()
+                        .collect()
+                }),
+                deny_unavailable_apis: *strict,
+                keep_going: *keep_going,
+                omit_generated_headers: *no_generated_headers,
+                app_manifest,
+                backends: Vec::new(),
+                // The TypeScript declarations are generated by `generate-dts`
+                ..Default::default()
+            };
+
+            let result = if *dry_run {
+                plan_wrapper_crate(wit, &modules, output, world.as_deref(), &options)
+                    .map(|plan| print_generation_plan(&plan, output))
+            } else {
+                generate_wrapper_crate(wit, &modules, output, world.as_deref(), &options)
+                    .map(|diagnostics| print_diagnostics(&diagnostics))
+            };
+            if let Err(err) = result {
                 eprintln!("Error generating wrapper crate: {err:#}");
                 std::process::exit(1);
             }
         }
-        Command::GenerateDTS { wit, output, world } => {
-            if let Err(err) = generate_dts(wit, output, world.as_deref()) {
-                eprintln!("Error generating TypeScript .d.ts: {err:#}");
+        Command::ScriptComponent { js, output } => {
+            let result = generate_script_component_crate(js, output)
+                .context("Failed to generate the script component crate")
+                .and_then(|()| run::build_crate(output, &[]));
+            match result {
+                Ok(wasm_path) => println!("Built script component {wasm_path}"),
+                Err(err) => {
+                    eprintln!("Error generating script component: {err:#}");
+                    std::process::exit(1);
+                }
+            }
+        }
+        Command::Compose {
+            socket,
+            plugs,
+            output,
+        } => {
+            let result = compose(socket, plugs).and_then(|bytes| {
+                std::fs::write(output, bytes)
+                    .with_context(|| format!("Failed to write the composed component to {output}"))
+            });
+            if let Err(err) = result {
+                eprintln!("Error composing components: {err:#}");
+                std::process::exit(1);
+            }
+        }
+        Command::GenerateDTS {
+            wit,
+            output,
+            world,
+            export_naming,
+            unversioned_imports,
+            skip_imports,
+            deny_imports,
+            skip_exports,
+            validators,
+            enum_constants,
+            decimal_types,
+            no_generated_headers,
+            format,
+            module_files,
+            declare_global,
+            branded_handles,
+            layout,
+        } => {
+            let options = GeneratorOptions {
+                export_naming: (*export_naming).into(),
+                unversioned_imports: *unversioned_imports,
+                skip_imports: skip_imports.clone(),
+                deny_imports: deny_imports.clone(),
+                skip_exports: skip_exports.clone(),
+                validators: *validators,
+                enum_constants: *enum_constants,
+                decimal_types: decimal_types.clone(),
+                omit_generated_headers: *no_generated_headers,
+                dts_format: (*format).into(),
+                dts_module_files: *module_files,
+                dts_declare_global: *declare_global,
+                dts_branded_handles: *branded_handles,
+                dts_layout: (*layout).into(),
+                ..Default::default()
+            };
+
+            match generate_dts(wit, output, world.as_deref(), &options) {
+                Ok(generated) => print_diagnostics(&generated.diagnostics),
+                Err(err) => {
+                    eprintln!("Error generating TypeScript .d.ts: {err:#}");
+                    std::process::exit(1);
+                }
+            }
+        }
+        Command::Run {
+            component,
+            js,
+            wit,
+            world,
+            output,
+            interface,
+            function,
+            args,
+        } => {
+            let source = component_source("wasm-rquickjs-run", component, js, wit, world, output);
+
+            let result = tokio::runtime::Builder::new_multi_thread()
+                .enable_all()
+                .build()
+                .map_err(anyhow::Error::from)
+                .and_then(|runtime| {
+                    runtime.block_on(run::run(&source, interface.as_deref(), function, args))
+                });
+            if let Err(err) = result {
+                eprintln!("Error running component: {err:#}");
+                std::process::exit(1);
+            }
+        }
+        Command::Bench {
+            component,
+            js,
+            wit,
+            world,
+            output,
+            interface,
+            instantiations,
+            warmup,
+            iterations,
+            function,
+            args,
+        } => {
+            let source = component_source("wasm-rquickjs-bench", component, js, wit, world, output);
+            let options = BenchOptions {
+                instantiations: *instantiations,
+                warmup: *warmup,
+                iterations: *iterations,
+            };
+
+            let result = tokio::runtime::Builder::new_multi_thread()
+                .enable_all()
+                .build()
+                .map_err(anyhow::Error::from)
+                .and_then(|runtime| {
+                    runtime.block_on(bench::bench(
+                        &source,
+                        interface.as_deref(),
+                        function,
+                        args,
+                        &options,
+                    ))
+                });
+            if let Err(err) = result {
+                eprintln!("Error benchmarking component: {err:#}");
+                std::process::exit(1);
+            }
+        }
+        Command::Repl { wit, world, output } => {
+            let output = output
+                .clone()
+                .unwrap_or_else(|| default_output("wasm-rquickjs-repl", wit));
+
+            let result = tokio::runtime::Builder::new_multi_thread()
+                .enable_all()
+                .build()
+                .map_err(anyhow::Error::from)
+                .and_then(|runtime| runtime.block_on(repl::repl(wit, world.as_deref(), &output)));
+            if let Err(err) = result {
+                eprintln!("Error running the REPL: {err:#}");
+                std::process::exit(1);
+            }
+        }
+        Command::Dev {
+            js,
+            wit,
+            world,
+            output,
+            port,
+        } => {
+            let output = output
+                .clone()
+                .unwrap_or_else(|| default_output("wasm-rquickjs-dev", js));
+
+            let result = tokio::runtime::Builder::new_multi_thread()
+                .enable_all()
+                .build()
+                .map_err(anyhow::Error::from)
+                .and_then(|runtime| {
+                    runtime.block_on(dev::dev(js, wit, world.as_deref(), &output, *port))
+                });
+            if let Err(err) = result {
+                eprintln!("Error running the development server: {err:#}");
+                std::process::exit(1);
+            }
+        }
+        Command::Test {
+            dir,
+            output,
+            filter,
+        } => {
+            let output = output
+                .clone()
+                .unwrap_or_else(|| default_output("wasm-rquickjs-test", dir));
+
+            let result = tokio::runtime::Builder::new_multi_thread()
+                .enable_all()
+                .build()
+                .map_err(anyhow::Error::from)
+                .and_then(|runtime| {
+                    runtime.block_on(test_suites::test(dir, &output, filter.as_deref()))
+                });
+            match result {
+                Ok(true) => {}
+                Ok(false) => std::process::exit(1),
+                Err(err) => {
+                    eprintln!("Error running tests: {err:#}");
+                    std::process::exit(1);
+                }
+            }
+        }
+        Command::Completions { shell } => {
+            clap_complete::generate(
+                *shell,
+                &mut Args::command(),
+                "wasm-rquickjs",
+                &mut std::io::stdout(),
+            );
+        }
+        Command::Manpages { dir } => {
+            if let Err(err) = manpages::generate_manpages(dir) {
+                eprintln!("Error generating man pages: {err:#}");
+                std::process::exit(1);
+            }
+        }
+        Command::NewExample { name, examples } => {
+            if let Err(err) = new_example::new_example(examples, name) {
+                eprintln!("Error creating example: {err:#}");
                 std::process::exit(1);
             }
         }
     };
 }
+
+/// Prints the plan of a `generate-wrapper-crate --dry-run` invocation
+fn print_generation_plan(plan: &GenerationPlan, output: &Utf8Path) {
+    println!("World: {}", plan.world);
+
+    println!("JavaScript modules:");
+    for module in &plan.js_modules {
+        let entry = if module.entry { ", entry" } else { "" };
+        match (&module.source, module.size) {
+            (Some(source), Some(size)) => {
+                println!(
+                    "  {} (embedded from {}, {size} bytes{entry})",
+                    module.name,
+                    source.display()
+                )
+            }
+            _ => println!("  {} (provided through composition{entry})", module.name),
+        }
+    }
+
+    if !plan.import_modules.is_empty() {
+        println!("Imported modules:");
+        for name in &plan.import_modules {
+            println!("  {name}");
+        }
+    }
+
+    if !plan.world_additions.is_empty() {
+        println!("Added to the world:");
+        for addition in &plan.world_additions {
+            println!("  {addition}");
+        }
+    }
+
+    println!("Default features: {}", plan.default_features.join(", "));
+
+    print_diagnostics(&plan.diagnostics);
+
+    println!("Files ({}):", plan.files.len());
+    for file in &plan.files {
+        println!("  {}", output.join(file));
+    }
+}
+
+/// Prints the non-fatal diagnostics of the generator as warnings
+fn print_diagnostics(diagnostics: &[Diagnostic]) {
+    for diagnostic in diagnostics {
+        eprintln!("Warning: {diagnostic}");
+    }
+}
+
+/// Builds the app manifest options from the command line arguments, reading the fragment files
+fn app_manifest_options(
+    no_app_manifest: bool,
+    component_type: Option<ComponentTypeArg>,
+    profiles: &[BuildProfileArg],
+    component_fragment: Option<&Utf8Path>,
+    fragment: Option<&Utf8Path>,
+) -> anyhow::Result<AppManifestOptions> {
+    let read_fragment = |path: Option<&Utf8Path>| {
+        path.map(|path| {
+            std::fs::read_to_string(path)
+                .with_context(|| format!("Failed to read app manifest fragment {path}"))
+        })
+        .transpose()
+    };
+
+    let defaults = AppManifestOptions::default();
+    Ok(AppManifestOptions {
+        enabled: !no_app_manifest,
+        component_type: component_type.map(ComponentType::from),
+        profiles: if profiles.is_empty() {
+            defaults.profiles
+        } else {
+            profiles.iter().copied().map(BuildProfile::from).collect()
+        },
+        component_fragment: read_fragment(component_fragment)?,
+        fragment: read_fragment(fragment)?,
+    })
+}
+
+/// Gets a directory in the system's temporary directory to generate and build a crate in
+/// Selects between a prebuilt component and one built from a JavaScript module and WIT package
+fn component_source(
+    kind: &str,
+    component: &Option<Utf8PathBuf>,
+    js: &Option<Utf8PathBuf>,
+    wit: &Option<Utf8PathBuf>,
+    world: &Option<String>,
+    output: &Option<Utf8PathBuf>,
+) -> ComponentSource {
+    match (component, js, wit) {
+        (Some(component), _, _) => ComponentSource::Prebuilt(component.clone()),
+        (None, Some(js), Some(wit)) => {
+            let output = output.clone().unwrap_or_else(|| default_output(kind, js));
+            ComponentSource::Build {
+                js: js.clone(),
+                wit: wit.clone(),
+                world: world.clone(),
+                output,
+            }
+        }
+        _ => unreachable!("clap requires either --component or both --js and --wit"),
+    }
+}
+
+fn default_output(kind: &str, source: &Utf8PathBuf) -> Utf8PathBuf {
+    let temp_dir =
+        Utf8PathBuf::try_from(std::env::temp_dir()).unwrap_or_else(|_| Utf8PathBuf::from("/tmp"));
+    let name = source
+        .canonicalize_utf8()
+        .ok()
+        .and_then(|path| path.file_stem().map(|stem| stem.to_string()))
+        .unwrap_or_else(|| "component".to_string());
+    temp_dir.join(kind).join(name)
+}
@@ -0,0 +1,37 @@
+use crate::cli::Args;
+use anyhow::Context;
+use camino::Utf8Path;
+use clap::CommandFactory;
+use clap_mangen::Man;
+
+/// Writes the man page of the CLI as `<dir>/wasm-rquickjs.1`, and one page for each of its
+/// visible commands as `<dir>/wasm-rquickjs-<command>.1`
+pub fn generate_manpages(dir: &Utf8Path) -> anyhow::Result<()> {
+    std::fs::create_dir_all(dir).with_context(|| format!("Failed to create directory {dir}"))?;
+
+    let command = Args::command();
+    write_manpage(dir, command.clone())?;
+    for subcommand in command.get_subcommands() {
+        if subcommand.is_hide_set() {
+            continue;
+        }
+        let name = format!("{}-{}", command.get_name(), subcommand.get_name());
+        let mut subcommand = subcommand.clone().name(name);
+        if let Some(version) = command.get_version() {
+            subcommand = subcommand.version(version.to_string());
+        }
+        write_manpage(dir, subcommand)?;
+    }
+    Ok(())
+}
+
+fn write_manpage(dir: &Utf8Path, command: clap::Command) -> anyhow::Result<()> {
+    let path = dir.join(format!("{}.1", command.get_name()));
+    let mut page = Vec::new();
+    Man::new(command)
+        .render(&mut page)
+        .with_context(|| format!("Failed to render the man page {path}"))?;
+    std::fs::write(&path, page).with_context(|| format!("Failed to write {path}"))?;
+    println!("Generated {path}");
+    Ok(())
+}
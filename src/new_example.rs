@@ -0,0 +1,64 @@
+use anyhow::{Context, bail};
+use camino::Utf8Path;
+
+/// Creates `<examples>/<name>` with a minimal WIT world exporting a `hello` function and a JS
+/// module implementing it. The test suites pick up every directory of `examples`, so the new
+/// example is compiled and has its generated code compared to goldenfiles right away.
+pub fn new_example(examples: &Utf8Path, name: &str) -> anyhow::Result<()> {
+    if !is_valid_name(name) {
+        bail!("Invalid example name `{name}`, expected a lowercase kebab-case WIT identifier");
+    }
+    let root = examples.join(name);
+    if root.exists() {
+        bail!("Example {root} already exists");
+    }
+
+    std::fs::create_dir_all(root.join("wit")).context("Failed to create the wit directory")?;
+    std::fs::create_dir_all(root.join("src")).context("Failed to create the src directory")?;
+    std::fs::write(
+        root.join("wit").join(format!("{name}.wit")),
+        format!(
+            "package quickjs:{name};\n\
+             \n\
+             interface api {{\n\
+             \x20 hello: func(name: string) -> string;\n\
+             }}\n\
+             \n\
+             world {name} {{\n\
+             \x20 export api;\n\
+             }}\n"
+        ),
+    )
+    .context("Failed to write the WIT world")?;
+    std::fs::write(
+        root.join("src").join(format!("{name}.js")),
+        "export const api = {\n    hello: (name) => `Hello, ${name}!`,\n};\n",
+    )
+    .context("Failed to write the JavaScript module")?;
+
+    println!("Created example {root}");
+    println!("Next steps:");
+    println!("  - Extend wit/{name}.wit and src/{name}.js with the feature to cover");
+    println!(
+        "  - Add its generator options to `example_generator_options` in tests/common/mod.rs, if any"
+    );
+    println!(
+        "  - Generate its goldenfiles with `UPDATE_GOLDENFILES=1 cargo test --test dts --test codegen`"
+    );
+    println!(
+        "  - Add a `compiled_{}` test dependency and tests to tests/runtime.rs",
+        name.replace('-', "_")
+    );
+    Ok(())
+}
+
+/// Checks that a name is a WIT identifier, so it can name the package and the world
+fn is_valid_name(name: &str) -> bool {
+    !name.is_empty()
+        && name.split('-').all(|word| {
+            word.chars().next().is_some_and(|c| c.is_ascii_lowercase())
+                && word
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+        })
+}
@@ -0,0 +1,52 @@
+use crate::run::{build_wrapper_crate, find_function, instantiate, invoke};
+use anyhow::{Context, bail};
+use camino::Utf8Path;
+use std::io::{BufRead, Write};
+use wasm_rquickjs::{GeneratorOptions, generate_repl_wrapper_crate};
+use wasmtime::component::Val;
+
+/// Builds a component for the given WIT world evaluating JavaScript code, and reads code to
+/// evaluate in it from the standard input until it is closed or `.exit` is entered.
+pub async fn repl(wit: &Utf8Path, world: Option<&str>, output: &Utf8Path) -> anyhow::Result<()> {
+    generate_repl_wrapper_crate(wit, output, world, &GeneratorOptions::default())
+        .context("Failed to generate the REPL wrapper crate")?;
+    let wasm_path = build_wrapper_crate(output)?;
+
+    let (mut store, instance) = instantiate(&wasm_path).await?;
+    let func = find_function(&mut store, &instance, None, "repl-eval")?;
+
+    let stdin = std::io::stdin();
+    let mut lines = stdin.lock().lines();
+    loop {
+        print!("> ");
+        std::io::stdout().flush()?;
+
+        let Some(line) = lines.next() else {
+            println!();
+            break;
+        };
+        let line = line?;
+        let code = line.trim();
+        if code.is_empty() {
+            continue;
+        }
+        if code == ".exit" {
+            break;
+        }
+
+        let results = invoke(&mut store, func, &[Val::String(code.to_string())]).await?;
+        match results.as_slice() {
+            [Val::Result(Ok(Some(result)))] => match result.as_ref() {
+                Val::String(result) => println!("{result}"),
+                other => bail!("Unexpected result of repl-eval: {other:?}"),
+            },
+            [Val::Result(Err(Some(error)))] => match error.as_ref() {
+                Val::String(error) => eprintln!("Uncaught {error}"),
+                other => bail!("Unexpected error of repl-eval: {other:?}"),
+            },
+            _ => bail!("Unexpected result of repl-eval: {results:?}"),
+        }
+    }
+
+    Ok(())
+}
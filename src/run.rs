@@ -0,0 +1,588 @@
+use anyhow::{Context, anyhow, bail};
+use camino::{Utf8Path, Utf8PathBuf};
+use heck::ToLowerCamelCase;
+use serde_json::{Map, Number, Value as Json};
+use std::process::Command;
+use toml_edit::DocumentMut;
+use wasm_rquickjs::{EmbeddingMode, GeneratorOptions, JsModuleSpec, generate_wrapper_crate};
+use wasmtime::component::types::ComponentItem;
+use wasmtime::component::{
+    Component, Func, Instance, Linker, LinkerInstance, ResourceTable, ResourceType, Type, Val,
+};
+use wasmtime::{Engine, Store};
+use wasmtime_wasi::p2::{IoView, WasiCtx, WasiView, bindings};
+use wasmtime_wasi_http::{WasiHttpCtx, WasiHttpView};
+
+/// Where the component to be run comes from
+pub enum ComponentSource {
+    /// A prebuilt WASM component
+    Prebuilt(Utf8PathBuf),
+    /// A JavaScript module and WIT package to generate and build the wrapper crate from
+    Build {
+        js: Utf8PathBuf,
+        wit: Utf8PathBuf,
+        world: Option<String>,
+        output: Utf8PathBuf,
+    },
+}
+
+/// Gets (building it if necessary) the component, invokes one of its exported functions with
+/// JSON-encoded arguments and prints the JSON-encoded result to the standard output.
+pub async fn run(
+    source: &ComponentSource,
+    interface: Option<&str>,
+    function: &str,
+    args: &[String],
+) -> anyhow::Result<()> {
+    let wasm_path = source.wasm_path()?;
+
+    let (mut store, instance) = instantiate(&wasm_path).await?;
+    let func = find_function(&mut store, &instance, interface, function)?;
+    let params = parse_args(&store, func, function, args)?;
+
+    let results = invoke(&mut store, func, &params).await?;
+    let result = match results.as_slice() {
+        [] => Json::Null,
+        [single] => val_to_json(single)?,
+        _ => Json::Array(
+            results
+                .iter()
+                .map(val_to_json)
+                .collect::<anyhow::Result<Vec<_>>>()?,
+        ),
+    };
+    println!("{result}");
+
+    Ok(())
+}
+
+impl ComponentSource {
+    /// Gets the path of the component, generating and building its wrapper crate if necessary
+    pub fn wasm_path(&self) -> anyhow::Result<Utf8PathBuf> {
+        match self {
+            ComponentSource::Prebuilt(path) => Ok(path.clone()),
+            ComponentSource::Build {
+                js,
+                wit,
+                world,
+                output,
+            } => {
+                let name = js
+                    .file_stem()
+                    .ok_or_else(|| anyhow!("Invalid JavaScript module path {js}"))?;
+                let js_modules = [JsModuleSpec {
+                    name: name.to_string(),
+                    mode: EmbeddingMode::EmbedFile(js.into()),
+                    entry: true,
+                }];
+                build_component(&js_modules, wit, world.as_deref(), output)
+            }
+        }
+    }
+}
+
+/// Converts JSON-encoded arguments to the parameter types of an exported function
+pub fn parse_args(
+    store: &Store<Host>,
+    func: Func,
+    function: &str,
+    args: &[String],
+) -> anyhow::Result<Vec<Val>> {
+    let params = func.params(store);
+    if params.len() != args.len() {
+        bail!(
+            "Function {function} expects {} arguments, but {} were given",
+            params.len(),
+            args.len()
+        );
+    }
+    params
+        .iter()
+        .zip(args)
+        .map(|((name, ty), arg)| {
+            let json: Json = serde_json::from_str(arg)
+                .with_context(|| format!("Argument `{name}` is not valid JSON"))?;
+            json_to_val(&json, ty).with_context(|| format!("Invalid value for argument `{name}`"))
+        })
+        .collect()
+}
+
+/// Generates the wrapper crate for the given JavaScript modules and builds it with
+/// `cargo-component`, returning the path of the built component.
+pub fn build_component(
+    js_modules: &[JsModuleSpec],
+    wit: &Utf8Path,
+    world: Option<&str>,
+    output: &Utf8Path,
+) -> anyhow::Result<Utf8PathBuf> {
+    generate_wrapper_crate(wit, js_modules, output, world, &GeneratorOptions::default())
+        .context("Failed to generate the wrapper crate")?;
+
+    build_wrapper_crate(output)
+}
+
+/// Builds a generated wrapper crate with `cargo-component`, returning the path of the built
+/// component.
+pub fn build_wrapper_crate(output: &Utf8Path) -> anyhow::Result<Utf8PathBuf> {
+    build_crate(output, &["--no-default-features", "--features", "http"])
+}
+
+/// Builds a crate with `cargo-component` using the given extra arguments, returning the path of
+/// the built component.
+pub fn build_crate(output: &Utf8Path, args: &[&str]) -> anyhow::Result<Utf8PathBuf> {
+    let status = Command::new("cargo-component")
+        .arg("build")
+        .args(args)
+        .current_dir(output)
+        .status()
+        .context("Failed to run cargo-component")?;
+    if !status.success() {
+        bail!("Failed to build the crate in {output}");
+    }
+
+    let cargo_toml = std::fs::read_to_string(output.join("Cargo.toml"))?;
+    let doc = cargo_toml.parse::<DocumentMut>()?;
+    let package_name = doc["package"]["name"]
+        .as_str()
+        .ok_or_else(|| anyhow!("Generated Cargo.toml has no package name"))?;
+
+    Ok(output
+        .join("target")
+        .join("wasm32-wasip1")
+        .join("debug")
+        .join(format!("{}.wasm", package_name.replace('-', "_"))))
+}
+
+/// Instantiates a component in a store providing WASI, WASI HTTP and `wasi:logging`. All other
+/// imports trap when called.
+pub async fn instantiate(wasm_path: &Utf8Path) -> anyhow::Result<(Store<Host>, Instance)> {
+    let engine = create_engine()?;
+    let component = load_component(&engine, wasm_path)?;
+    let linker = create_linker(&engine, &component)?;
+
+    let mut store = new_store(&engine);
+    let instance = linker
+        .instantiate_async(&mut store, &component)
+        .await
+        .context("Failed to instantiate component")?;
+
+    Ok((store, instance))
+}
+
+pub fn create_engine() -> anyhow::Result<Engine> {
+    let mut config = wasmtime::Config::default();
+    config.async_support(true);
+    config.wasm_component_model(true);
+    Engine::new(&config)
+}
+
+pub fn load_component(engine: &Engine, wasm_path: &Utf8Path) -> anyhow::Result<Component> {
+    Component::from_file(engine, wasm_path)
+        .with_context(|| format!("Failed to load component {wasm_path}"))
+}
+
+/// Creates a linker providing WASI, WASI HTTP and `wasi:logging` for the given component
+pub fn create_linker(engine: &Engine, component: &Component) -> anyhow::Result<Linker<Host>> {
+    let mut linker: Linker<Host> = Linker::new(engine);
+    wasmtime_wasi::p2::add_to_linker_with_options_async(
+        &mut linker,
+        &bindings::LinkOptions::default(),
+    )?;
+    wasmtime_wasi_http::add_only_http_to_linker_async(&mut linker)?;
+    add_logging_to_linker(&mut linker)?;
+    // Imports other than WASI and logging can only be satisfied by a real host, so they trap
+    define_unknown_imports_as_traps(&mut linker, engine, component)?;
+    Ok(linker)
+}
+
+/// Defines all the imports of the component not belonging to the packages provided by
+/// `create_linker` as functions that trap.
+///
+/// Unlike `Linker::define_unknown_imports_as_traps`, this also works when the component imports
+/// an older version of the provided WASI interfaces, which the linker satisfies through semver
+/// compatible definitions.
+fn define_unknown_imports_as_traps(
+    linker: &mut Linker<Host>,
+    engine: &Engine,
+    component: &Component,
+) -> anyhow::Result<()> {
+    for (name, item) in component.component_type().imports(engine) {
+        if !PROVIDED_PACKAGES
+            .iter()
+            .any(|package| name.starts_with(package))
+        {
+            stub_import(&mut linker.root(), name, None, item, engine)?;
+        }
+    }
+    Ok(())
+}
+
+/// Prefixes of the names of the imported interfaces provided by `create_linker`
+const PROVIDED_PACKAGES: &[&str] = &[
+    "wasi:cli/",
+    "wasi:clocks/",
+    "wasi:filesystem/",
+    "wasi:http/",
+    "wasi:io/",
+    "wasi:logging/",
+    "wasi:random/",
+    "wasi:sockets/",
+];
+
+fn stub_import(
+    linker: &mut LinkerInstance<'_, Host>,
+    name: &str,
+    parent: Option<&str>,
+    item: ComponentItem,
+    engine: &Engine,
+) -> anyhow::Result<()> {
+    match item {
+        ComponentItem::ComponentFunc(_) => {
+            let qualified_name = match parent {
+                Some(parent) => format!("{parent}#{name}"),
+                None => name.to_string(),
+            };
+            linker.func_new(name, move |_, _, _| {
+                bail!("Import {qualified_name} is not available when running locally")
+            })?;
+        }
+        ComponentItem::ComponentInstance(instance) => {
+            let mut instance_linker = linker.instance(name)?;
+            for (export_name, export) in instance.exports(engine) {
+                stub_import(
+                    &mut instance_linker,
+                    export_name,
+                    Some(name),
+                    export,
+                    engine,
+                )?;
+            }
+        }
+        ComponentItem::Resource(_) => {
+            linker.resource(name, ResourceType::host::<()>(), |_, _| Ok(()))?;
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Creates a store with the standard IO and environment variables inherited from this process
+pub fn new_store(engine: &Engine) -> Store<Host> {
+    let host = Host {
+        table: ResourceTable::new(),
+        wasi: WasiCtx::builder().inherit_stdio().inherit_env().build(),
+        wasi_http: WasiHttpCtx::new(),
+    };
+    Store::new(engine, host)
+}
+
+/// Creates a store discarding the standard output and error of the component
+pub fn new_quiet_store(engine: &Engine) -> Store<Host> {
+    let host = Host {
+        table: ResourceTable::new(),
+        wasi: WasiCtx::builder().inherit_env().build(),
+        wasi_http: WasiHttpCtx::new(),
+    };
+    Store::new(engine, host)
+}
+
+/// Looks up an exported function, either from the given exported interface or from the world
+pub fn find_function(
+    store: &mut Store<Host>,
+    instance: &Instance,
+    interface: Option<&str>,
+    function: &str,
+) -> anyhow::Result<Func> {
+    match interface {
+        Some(interface) => {
+            let (_, interface_id) = instance
+                .get_export(&mut *store, None, interface)
+                .ok_or_else(|| anyhow!("Interface {interface} is not exported"))?;
+            let (_, func_id) = instance
+                .get_export(&mut *store, Some(&interface_id), function)
+                .ok_or_else(|| anyhow!("Function {function} not found in interface {interface}"))?;
+            instance
+                .get_func(&mut *store, func_id)
+                .ok_or_else(|| anyhow!("Export {function} is not a function"))
+        }
+        None => instance
+            .get_func(&mut *store, function)
+            .ok_or_else(|| anyhow!("Function {function} is not exported")),
+    }
+}
+
+pub async fn invoke(
+    store: &mut Store<Host>,
+    func: Func,
+    params: &[Val],
+) -> anyhow::Result<Vec<Val>> {
+    let mut results = vec![Val::Bool(false); func.results(&*store).len()];
+    func.call_async(&mut *store, params, &mut results).await?;
+    func.post_return_async(&mut *store).await?;
+    Ok(results)
+}
+
+/// Defines `wasi:logging/logging`, used by the `console` API of components built with the
+/// `logging` feature, writing all messages to the standard error.
+fn add_logging_to_linker(linker: &mut Linker<Host>) -> anyhow::Result<()> {
+    let mut logging = linker.instance("wasi:logging/logging")?;
+    logging.func_new("log", |_store, params, _results| {
+        if let [Val::Enum(level), Val::String(context), Val::String(message)] = params {
+            if context.is_empty() {
+                eprintln!("[{level}] {message}");
+            } else {
+                eprintln!("[{level}] {context}: {message}");
+            }
+        }
+        Ok(())
+    })?;
+    Ok(())
+}
+
+/// Converts a JSON value to a component model value of the given type, following the same
+/// mapping as the one used between WIT and JavaScript values.
+fn json_to_val(json: &Json, ty: &Type) -> anyhow::Result<Val> {
+    let unexpected = || anyhow!("Expected {}, got {json}", type_desc(ty));
+    match ty {
+        Type::Bool => json.as_bool().map(Val::Bool).ok_or_else(unexpected),
+        Type::S8 => Ok(Val::S8(json_int(json)?.try_into()?)),
+        Type::U8 => Ok(Val::U8(json_uint(json)?.try_into()?)),
+        Type::S16 => Ok(Val::S16(json_int(json)?.try_into()?)),
+        Type::U16 => Ok(Val::U16(json_uint(json)?.try_into()?)),
+        Type::S32 => Ok(Val::S32(json_int(json)?.try_into()?)),
+        Type::U32 => Ok(Val::U32(json_uint(json)?.try_into()?)),
+        Type::S64 => Ok(Val::S64(json_int(json)?)),
+        Type::U64 => Ok(Val::U64(json_uint(json)?)),
+        Type::Float32 => json
+            .as_f64()
+            .map(|f| Val::Float32(f as f32))
+            .ok_or_else(unexpected),
+        Type::Float64 => json.as_f64().map(Val::Float64).ok_or_else(unexpected),
+        Type::Char => {
+            let s = json.as_str().ok_or_else(unexpected)?;
+            let mut chars = s.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => Ok(Val::Char(c)),
+                _ => Err(unexpected()),
+            }
+        }
+        Type::String => json
+            .as_str()
+            .map(|s| Val::String(s.to_string()))
+            .ok_or_else(unexpected),
+        Type::List(list) => {
+            let items = json.as_array().ok_or_else(unexpected)?;
+            let elem_ty = list.ty();
+            Ok(Val::List(
+                items
+                    .iter()
+                    .map(|item| json_to_val(item, &elem_ty))
+                    .collect::<anyhow::Result<_>>()?,
+            ))
+        }
+        Type::Record(record) => {
+            let obj = json.as_object().ok_or_else(unexpected)?;
+            let mut fields = Vec::new();
+            for field in record.fields() {
+                let js_name = field.name.to_lower_camel_case();
+                let value = match obj.get(&js_name) {
+                    Some(value) => value,
+                    None if matches!(field.ty, Type::Option(_)) => &Json::Null,
+                    None => bail!("Missing field `{js_name}`"),
+                };
+                let value = json_to_val(value, &field.ty)
+                    .with_context(|| format!("Invalid value for field `{js_name}`"))?;
+                fields.push((field.name.to_string(), value));
+            }
+            Ok(Val::Record(fields))
+        }
+        Type::Tuple(tuple) => {
+            let items = json.as_array().ok_or_else(unexpected)?;
+            if items.len() != tuple.types().len() {
+                return Err(unexpected());
+            }
+            Ok(Val::Tuple(
+                items
+                    .iter()
+                    .zip(tuple.types())
+                    .map(|(item, ty)| json_to_val(item, &ty))
+                    .collect::<anyhow::Result<_>>()?,
+            ))
+        }
+        Type::Variant(variant) => {
+            let obj = json.as_object().ok_or_else(unexpected)?;
+            let tag = obj
+                .get("tag")
+                .and_then(Json::as_str)
+                .ok_or_else(unexpected)?;
+            let case = variant
+                .cases()
+                .find(|case| case.name == tag)
+                .ok_or_else(|| anyhow!("Unknown variant case `{tag}`"))?;
+            let value = match case.ty {
+                Some(ty) => Some(Box::new(json_to_val(
+                    obj.get("val").unwrap_or(&Json::Null),
+                    &ty,
+                )?)),
+                None => None,
+            };
+            Ok(Val::Variant(tag.to_string(), value))
+        }
+        Type::Enum(enm) => {
+            let case = json.as_str().ok_or_else(unexpected)?;
+            if enm.names().any(|name| name == case) {
+                Ok(Val::Enum(case.to_string()))
+            } else {
+                bail!("Unknown enum case `{case}`")
+            }
+        }
+        Type::Option(option) => match json {
+            Json::Null => Ok(Val::Option(None)),
+            _ => Ok(Val::Option(Some(Box::new(json_to_val(
+                json,
+                &option.ty(),
+            )?)))),
+        },
+        Type::Result(result) => {
+            let obj = json.as_object().ok_or_else(unexpected)?;
+            let tag = obj
+                .get("tag")
+                .and_then(Json::as_str)
+                .ok_or_else(unexpected)?;
+            let val = obj.get("val").unwrap_or(&Json::Null);
+            let payload = |ty: Option<Type>| -> anyhow::Result<Option<Box<Val>>> {
+                ty.map(|ty| json_to_val(val, &ty).map(Box::new)).transpose()
+            };
+            match tag {
+                "ok" => Ok(Val::Result(Ok(payload(result.ok())?))),
+                "err" => Ok(Val::Result(Err(payload(result.err())?))),
+                _ => Err(unexpected()),
+            }
+        }
+        Type::Flags(flags) => {
+            let obj = json.as_object().ok_or_else(unexpected)?;
+            Ok(Val::Flags(
+                flags
+                    .names()
+                    .filter(|name| {
+                        obj.get(&name.to_lower_camel_case())
+                            .and_then(Json::as_bool)
+                            .unwrap_or(false)
+                    })
+                    .map(|name| name.to_string())
+                    .collect(),
+            ))
+        }
+        Type::Own(_) | Type::Borrow(_) => bail!("Resource handles cannot be passed as arguments"),
+    }
+}
+
+/// Converts a component model value to JSON, following the same mapping as the one used between
+/// WIT and JavaScript values.
+fn val_to_json(val: &Val) -> anyhow::Result<Json> {
+    Ok(match val {
+        Val::Bool(b) => Json::Bool(*b),
+        Val::S8(n) => Json::from(*n),
+        Val::U8(n) => Json::from(*n),
+        Val::S16(n) => Json::from(*n),
+        Val::U16(n) => Json::from(*n),
+        Val::S32(n) => Json::from(*n),
+        Val::U32(n) => Json::from(*n),
+        Val::S64(n) => Json::from(*n),
+        Val::U64(n) => Json::from(*n),
+        Val::Float32(f) => float_to_json(*f as f64),
+        Val::Float64(f) => float_to_json(*f),
+        Val::Char(c) => Json::String(c.to_string()),
+        Val::String(s) => Json::String(s.clone()),
+        Val::List(items) | Val::Tuple(items) => Json::Array(
+            items
+                .iter()
+                .map(val_to_json)
+                .collect::<anyhow::Result<_>>()?,
+        ),
+        Val::Record(fields) => {
+            let mut obj = Map::new();
+            for (name, value) in fields {
+                obj.insert(name.to_lower_camel_case(), val_to_json(value)?);
+            }
+            Json::Object(obj)
+        }
+        Val::Variant(tag, value) => tagged(tag, value.as_deref())?,
+        Val::Enum(case) => Json::String(case.clone()),
+        Val::Option(None) => Json::Null,
+        Val::Option(Some(value)) => val_to_json(value)?,
+        Val::Result(Ok(value)) => tagged("ok", value.as_deref())?,
+        Val::Result(Err(value)) => tagged("err", value.as_deref())?,
+        Val::Flags(names) => Json::Object(
+            names
+                .iter()
+                .map(|name| (name.to_lower_camel_case(), Json::Bool(true)))
+                .collect(),
+        ),
+        Val::Resource(_) => bail!("Resource handles cannot be printed as JSON"),
+    })
+}
+
+fn tagged(tag: &str, value: Option<&Val>) -> anyhow::Result<Json> {
+    let mut obj = Map::new();
+    obj.insert("tag".to_string(), Json::String(tag.to_string()));
+    if let Some(value) = value {
+        obj.insert("val".to_string(), val_to_json(value)?);
+    }
+    Ok(Json::Object(obj))
+}
+
+fn float_to_json(f: f64) -> Json {
+    Number::from_f64(f).map(Json::Number).unwrap_or(Json::Null)
+}
+
+fn type_desc(ty: &Type) -> &'static str {
+    match ty {
+        Type::Bool => "a bool",
+        Type::S8 | Type::S16 | Type::S32 | Type::S64 => "an integer",
+        Type::U8 | Type::U16 | Type::U32 | Type::U64 => "a non-negative integer",
+        Type::Float32 | Type::Float64 => "a number",
+        Type::Char => "a single character string",
+        Type::String => "a string",
+        Type::List(_) | Type::Tuple(_) => "an array",
+        Type::Record(_) | Type::Flags(_) => "an object",
+        Type::Variant(_) | Type::Result(_) => "an object with a `tag` field",
+        Type::Enum(_) => "a string",
+        Type::Option(_) => "an optional value",
+        Type::Own(_) | Type::Borrow(_) => "a resource handle",
+    }
+}
+
+fn json_int(json: &Json) -> anyhow::Result<i64> {
+    json.as_i64()
+        .ok_or_else(|| anyhow!("Expected an integer, got {json}"))
+}
+
+fn json_uint(json: &Json) -> anyhow::Result<u64> {
+    json.as_u64()
+        .ok_or_else(|| anyhow!("Expected a non-negative integer, got {json}"))
+}
+
+pub struct Host {
+    table: ResourceTable,
+    wasi: WasiCtx,
+    wasi_http: WasiHttpCtx,
+}
+
+impl IoView for Host {
+    fn table(&mut self) -> &mut ResourceTable {
+        &mut self.table
+    }
+}
+
+impl WasiView for Host {
+    fn ctx(&mut self) -> &mut WasiCtx {
+        &mut self.wasi
+    }
+}
+
+impl WasiHttpView for Host {
+    fn ctx(&mut self) -> &mut WasiHttpCtx {
+        &mut self.wasi_http
+    }
+}
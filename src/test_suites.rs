@@ -0,0 +1,238 @@
+use crate::run::{build_component, find_function, instantiate, invoke};
+use anyhow::{Context, anyhow, bail};
+use camino::{Utf8Path, Utf8PathBuf};
+use std::time::Duration;
+use wasm_rquickjs::paths::{relative_slash_path, to_slash};
+use wasm_rquickjs::{EmbeddingMode, JsModuleSpec};
+use wasmtime::component::Val;
+
+/// File name suffix of the JavaScript modules containing tests
+const TEST_MODULE_SUFFIX: &str = ".test.js";
+
+/// Directories never searched for test modules
+const IGNORED_DIRECTORIES: &[&str] = &["node_modules", "target"];
+
+const TEST_WORLD_WIT: &str = r#"package wasm-rquickjs:test;
+
+interface runner {
+    record test-result {
+        suite: string,
+        name: string,
+        error: option<string>,
+        duration-ms: f64,
+    }
+
+    run-tests: func(filter: option<string>) -> list<test-result>;
+}
+
+world test-suite {
+    export runner;
+}
+"#;
+
+struct TestResult {
+    suite: String,
+    name: String,
+    error: Option<String>,
+    duration: Duration,
+}
+
+/// Collects the `*.test.js` modules in `dir`, builds a component running all the functions they
+/// export as tests, runs it and reports the results.
+///
+/// Returns whether all the tests passed.
+pub async fn test(dir: &Utf8Path, output: &Utf8Path, filter: Option<&str>) -> anyhow::Result<bool> {
+    let mut test_modules = Vec::new();
+    collect_test_modules(dir, &mut test_modules)?;
+    test_modules.sort();
+    if test_modules.is_empty() {
+        bail!("No test modules (*{TEST_MODULE_SUFFIX}) found in {dir}");
+    }
+
+    let runner_dir = output.join("test-runner");
+    let wit_dir = runner_dir.join("wit");
+    std::fs::create_dir_all(&wit_dir).context("Failed to create the test runner directory")?;
+    std::fs::write(wit_dir.join("test-suite.wit"), TEST_WORLD_WIT)
+        .context("Failed to write the test runner WIT package")?;
+
+    let suites = test_modules
+        .iter()
+        .map(|path| relative_slash_path(path, dir).unwrap_or_else(|| to_slash(path)))
+        .collect::<Vec<_>>();
+    let runner_js = runner_dir.join("runner.js");
+    std::fs::write(&runner_js, runner_module(&suites))
+        .context("Failed to write the test runner module")?;
+
+    let mut js_modules = vec![JsModuleSpec {
+        name: "test-runner".to_string(),
+        mode: EmbeddingMode::EmbedFile(runner_js.into()),
+        entry: true,
+    }];
+    for (idx, path) in test_modules.iter().enumerate() {
+        js_modules.push(JsModuleSpec {
+            name: suite_module_name(idx),
+            mode: EmbeddingMode::EmbedFile(path.into()),
+            entry: false,
+        });
+    }
+
+    let wasm_path = build_component(&js_modules, &wit_dir, Some("test-suite"), output)?;
+    let (mut store, instance) = instantiate(&wasm_path).await?;
+    let func = find_function(
+        &mut store,
+        &instance,
+        Some("wasm-rquickjs:test/runner"),
+        "run-tests",
+    )?;
+
+    let filter = Val::Option(filter.map(|f| Box::new(Val::String(f.to_string()))));
+    let results = invoke(&mut store, func, &[filter]).await?;
+    let results = match results.as_slice() {
+        [Val::List(results)] => results
+            .iter()
+            .map(test_result_from_val)
+            .collect::<anyhow::Result<Vec<_>>>()?,
+        _ => bail!("Unexpected result of run-tests: {results:?}"),
+    };
+
+    Ok(report(&results))
+}
+
+fn collect_test_modules(dir: &Utf8Path, result: &mut Vec<Utf8PathBuf>) -> anyhow::Result<()> {
+    for entry in dir
+        .read_dir_utf8()
+        .with_context(|| format!("Failed to read directory {dir}"))?
+    {
+        let entry = entry?;
+        let name = entry.file_name();
+        if entry.file_type()?.is_dir() {
+            if !name.starts_with('.') && !IGNORED_DIRECTORIES.contains(&name) {
+                collect_test_modules(entry.path(), result)?;
+            }
+        } else if name.ends_with(TEST_MODULE_SUFFIX) {
+            result.push(entry.path().to_path_buf());
+        }
+    }
+    Ok(())
+}
+
+fn suite_module_name(idx: usize) -> String {
+    format!("test-suite-{idx}")
+}
+
+/// Generates the JS module implementing `run-tests`, calling every function exported by the
+/// test modules
+fn runner_module(suites: &[String]) -> String {
+    let mut imports = String::new();
+    let mut entries = String::new();
+    for (idx, suite) in suites.iter().enumerate() {
+        imports.push_str(&format!(
+            "import * as suite{idx} from '{}';\n",
+            suite_module_name(idx)
+        ));
+        entries.push_str(&format!(
+            "    [{}, suite{idx}],\n",
+            serde_json::to_string(suite).expect("Failed to encode suite name")
+        ));
+    }
+
+    format!(
+        r#"{imports}
+const suites = [
+{entries}];
+
+function describeError(error) {{
+    if (error instanceof Error && error.stack) {{
+        return `${{error}}\n${{error.stack}}`;
+    }}
+    return `${{error}}`;
+}}
+
+export const runner = {{
+    async runTests(filter) {{
+        const results = [];
+        for (const [suite, tests] of suites) {{
+            for (const [name, test] of Object.entries(tests)) {{
+                if (typeof test !== 'function') {{
+                    continue;
+                }}
+                if (filter !== undefined && !`${{suite}} ${{name}}`.includes(filter)) {{
+                    continue;
+                }}
+                const start = Date.now();
+                let error = undefined;
+                try {{
+                    await test();
+                }} catch (e) {{
+                    error = describeError(e);
+                }}
+                results.push({{ suite, name, error, durationMs: Date.now() - start }});
+            }}
+        }}
+        return results;
+    }}
+}};
+"#
+    )
+}
+
+fn test_result_from_val(val: &Val) -> anyhow::Result<TestResult> {
+    let Val::Record(fields) = val else {
+        bail!("Unexpected test result: {val:?}");
+    };
+    let field = |name: &str| {
+        fields
+            .iter()
+            .find(|(field_name, _)| field_name == name)
+            .map(|(_, value)| value)
+            .ok_or_else(|| anyhow!("Test result has no field {name}"))
+    };
+
+    let (Val::String(suite), Val::String(name), Val::Option(error), Val::Float64(duration_ms)) = (
+        field("suite")?,
+        field("name")?,
+        field("error")?,
+        field("duration-ms")?,
+    ) else {
+        bail!("Unexpected test result: {val:?}");
+    };
+    let error = match error.as_deref() {
+        Some(Val::String(error)) => Some(error.clone()),
+        Some(other) => bail!("Unexpected test error: {other:?}"),
+        None => None,
+    };
+
+    Ok(TestResult {
+        suite: suite.clone(),
+        name: name.clone(),
+        error,
+        duration: Duration::from_secs_f64(duration_ms.max(0.0) / 1000.0),
+    })
+}
+
+fn report(results: &[TestResult]) -> bool {
+    let mut failed = 0;
+    for result in results {
+        let status = if result.error.is_some() { "FAIL" } else { "ok" };
+        println!(
+            "{status:<4} {} > {} ({} ms)",
+            result.suite,
+            result.name,
+            result.duration.as_millis()
+        );
+        if let Some(error) = &result.error {
+            failed += 1;
+            for line in error.lines() {
+                println!("     {line}");
+            }
+        }
+    }
+
+    println!();
+    println!(
+        "{} passed, {failed} failed, {} total",
+        results.len() - failed,
+        results.len()
+    );
+    failed == 0
+}
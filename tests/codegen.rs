@@ -0,0 +1,94 @@
+test_r::enable!();
+
+use crate::common::{collect_example_paths, example_generator_options, example_js_modules};
+use camino::{Utf8Path, Utf8PathBuf};
+use goldenfile::Mint;
+use test_r::core::{DynamicTestRegistration, TestProperties};
+use test_r::test_gen;
+use wasm_rquickjs::generate_wrapper_crate;
+
+#[allow(dead_code)]
+mod common;
+
+/// The generated files of the wrapper crates compared to goldenfiles, relative to the crate root.
+/// The files copied from the skeleton and the embedded JS modules are not snapshotted.
+const SNAPSHOTTED_FILES: &[&str] = &[
+    "Cargo.toml",
+    "src/lib.rs",
+    "src/conversions.rs",
+    "src/assets.rs",
+];
+
+/// The directory of the generated import modules, snapshotted as a whole
+const MODULES_DIR: &str = "src/modules";
+
+#[test_gen]
+fn gen_codegen_tests(r: &mut DynamicTestRegistration) {
+    for example_path in collect_example_paths().unwrap() {
+        let example_name = example_path.file_name().unwrap().to_string();
+
+        r.add_sync_test(
+            example_name.clone(),
+            TestProperties {
+                ..TestProperties::unit_test()
+            },
+            move |_deps| {
+                let mut mint = Mint::new("tests/goldenfiles");
+                let output = generate(&example_name, &example_path)?;
+
+                let mut files = SNAPSHOTTED_FILES
+                    .iter()
+                    .map(Utf8PathBuf::from)
+                    .filter(|file| output.join(file).exists())
+                    .collect::<Vec<_>>();
+                for entry in output.join(MODULES_DIR).read_dir_utf8()? {
+                    files.push(Utf8Path::new(MODULES_DIR).join(entry?.file_name()));
+                }
+
+                for file in files {
+                    assert_code_matches_goldenfile(&example_name, &output, &file, &mut mint)?;
+                }
+                Ok::<_, anyhow::Error>(())
+            },
+        );
+    }
+}
+
+fn generate(name: &str, path: &Utf8Path) -> anyhow::Result<Utf8PathBuf> {
+    let target = Utf8Path::new("tmp").join(name).join("codegen");
+    if target.exists() {
+        std::fs::remove_dir_all(&target)?;
+    }
+
+    println!("Generating the wrapper crate of example '{name}' to {target}");
+    generate_wrapper_crate(
+        path.join("wit"),
+        &example_js_modules(path),
+        &target,
+        None,
+        &example_generator_options(name),
+    )?;
+    Ok(target)
+}
+
+fn assert_code_matches_goldenfile(
+    example_name: &str,
+    output: &Utf8Path,
+    file: &Utf8Path,
+    mint: &mut Mint,
+) -> anyhow::Result<()> {
+    let differ = Box::new(goldenfile::differs::text_diff);
+
+    let flattened = file
+        .components()
+        .map(|component| component.as_str())
+        .collect::<Vec<_>>()
+        .join("_");
+    let path = mint.new_goldenpath_with_differ(
+        format!("generated_crate_{example_name}_{flattened}"),
+        differ,
+    )?;
+
+    std::fs::copy(output.join(file), path)?;
+    Ok(())
+}
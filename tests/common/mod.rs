@@ -1,23 +1,19 @@
 pub mod test_server;
 
-use crate::common::WasmSource::Precompiled;
 use anyhow::anyhow;
 use camino::{Utf8Path, Utf8PathBuf};
-use camino_tempfile::{NamedUtf8TempFile, Utf8TempDir};
-use heck::ToSnakeCase;
-use std::collections::{BTreeMap, BTreeSet};
+use std::collections::BTreeMap;
 use std::fs;
-use std::io::Write;
-use std::process::Command;
-use std::sync::{Arc, Mutex};
-use wac_graph::types::{Package, SubtypeChecker};
-use wac_graph::{CompositionGraph, EncodeOptions, PackageId, PlugError};
-use wasm_rquickjs::{EmbeddingMode, JsModuleSpec, generate_wrapper_crate};
-use wasmtime::component::{Component, Func, Instance, Linker, ResourceAny, ResourceTable, Val};
-use wasmtime::{Engine, Store};
-use wasmtime_wasi::p2::{IoView, OutputFile, WasiCtx, WasiView, bindings};
-use wasmtime_wasi::{DirPerms, FilePerms};
-use wasmtime_wasi_http::{WasiHttpCtx, WasiHttpView};
+use wasm_rquickjs::{
+    AssetSpec, DateClock, EmbeddingMode, GeneratorOptions, HttpCacheLimits, HttpLimits,
+    ImportRecording, JsModuleSpec, JsNamingPolicy, LoneSurrogatePolicy, MathRandomSeed,
+    generate_wrapper_crate,
+};
+use wasmtime::component::Val;
+
+pub use wasm_rquickjs_test::{
+    CompileTarget, CompiledTest, FeatureCombination, TestInstance, TestInstanceOptions,
+};
 
 pub fn collect_example_paths() -> anyhow::Result<Vec<Utf8PathBuf>> {
     let mut result = Vec::new();
@@ -34,195 +30,195 @@ pub fn collect_example_paths() -> anyhow::Result<Vec<Utf8PathBuf>> {
     Ok(result)
 }
 
-#[derive(Copy, Clone)]
-pub enum FeatureCombination {
-    None,
-    LogOnly,
-    HttpOnly,
-    Default,
-}
-
-impl FeatureCombination {
-    pub fn all() -> Vec<FeatureCombination> {
-        vec![Self::None, Self::LogOnly, Self::HttpOnly, Self::Default]
-    }
-
-    pub fn label(&self) -> &str {
-        match self {
-            Self::None => "none",
-            Self::LogOnly => "log",
-            Self::HttpOnly => "http",
-            Self::Default => "default",
-        }
-    }
-
-    pub fn cargo_args(&self) -> Vec<&'static str> {
-        match self {
-            FeatureCombination::None => vec!["--no-default-features"],
-            FeatureCombination::LogOnly => {
-                vec!["--no-default-features", "--features", "logging"]
-            }
-            FeatureCombination::HttpOnly => vec!["--no-default-features", "--features", "http"],
-            FeatureCombination::Default => vec![],
-        }
+/// Gets the generator options to be used for an example. Most of the examples use the defaults.
+pub fn example_generator_options(name: &str) -> GeneratorOptions {
+    match name {
+        "verbatim-names" => GeneratorOptions {
+            export_naming: JsNamingPolicy::Verbatim,
+            ..Default::default()
+        },
+        // A variant of the `partial-records` example
+        "strict-records" => GeneratorOptions {
+            strict_records: true,
+            ..Default::default()
+        },
+        "invoke-export" => GeneratorOptions {
+            invoke_export: true,
+            describe_export: true,
+            ..Default::default()
+        },
+        "interned-field-names" => GeneratorOptions {
+            intern_field_names: true,
+            ..Default::default()
+        },
+        "lone-surrogates" => GeneratorOptions {
+            lone_surrogates: LoneSurrogatePolicy::Replace,
+            ..Default::default()
+        },
+        "fixed-clock" => GeneratorOptions {
+            date_clock: Some(DateClock::Fixed(1_700_000_000_000)),
+            ..Default::default()
+        },
+        "seeded-random" => GeneratorOptions {
+            math_random_seed: Some(MathRandomSeed::Fixed(42)),
+            ..Default::default()
+        },
+        "import-hooks" => GeneratorOptions {
+            import_hooks: true,
+            ..Default::default()
+        },
+        "mocked-imports" | "mocked-golem-imports" => GeneratorOptions {
+            mock_imports: true,
+            default_features: Some(vec![
+                "http".to_string(),
+                "logging".to_string(),
+                "mock-imports".to_string(),
+            ]),
+            ..Default::default()
+        },
+        "replayed-imports" => GeneratorOptions {
+            import_recording: Some(ImportRecording::Replay(
+                Utf8PathBuf::from("examples/replayed-imports/recording.jsonl").into(),
+            )),
+            ..Default::default()
+        },
+        "call-cancellation" => GeneratorOptions {
+            cancel_background_work: true,
+            ..Default::default()
+        },
+        "http-limits" => GeneratorOptions {
+            http_limits: HttpLimits {
+                max_request_body_size: Some(8),
+                max_response_body_size: Some(16),
+                max_header_count: Some(20),
+            },
+            ..Default::default()
+        },
+        "cookie-jar" => GeneratorOptions {
+            cookie_jar: true,
+            ..Default::default()
+        },
+        "http-cache" => GeneratorOptions {
+            http_cache: true,
+            http_cache_limits: HttpCacheLimits {
+                max_entries: Some(2),
+                max_size: Some(1024),
+                max_body_size: Some(64),
+            },
+            ..Default::default()
+        },
+        "fetch-gateway" => GeneratorOptions {
+            fetch_base_url: Some("http://base.invalid".to_string()),
+            fetch_gateway: Some("http://gateway.invalid".to_string()),
+            ..Default::default()
+        },
+        "unversioned-imports" => GeneratorOptions {
+            unversioned_imports: true,
+            ..Default::default()
+        },
+        "skipped-interfaces" => GeneratorOptions {
+            skip_imports: vec!["wasi:random/random".to_string()],
+            skip_exports: vec!["quickjs:skipped-interfaces/admin".to_string()],
+            ..Default::default()
+        },
+        "denied-imports" => GeneratorOptions {
+            deny_imports: vec!["wasi:random/random".to_string()],
+            ..Default::default()
+        },
+        "builtin-permissions" => GeneratorOptions {
+            builtin_permissions: BTreeMap::from([(
+                "plugin".to_string(),
+                vec!["buffer".to_string()],
+            )]),
+            ..Default::default()
+        },
+        "static-assets" => GeneratorOptions {
+            assets: vec![
+                AssetSpec {
+                    name: "templates/index.html".to_string(),
+                    path: Utf8PathBuf::from("examples/static-assets/static/templates/index.html")
+                        .into(),
+                },
+                AssetSpec {
+                    name: "logo.png".to_string(),
+                    path: Utf8PathBuf::from("examples/static-assets/static/logo.png").into(),
+                },
+            ],
+            ..Default::default()
+        },
+        "templates" => GeneratorOptions {
+            assets: [
+                "emails/base.html",
+                "emails/macros.html",
+                "emails/signature.txt",
+                "emails/welcome.html",
+                "emails/welcome.txt",
+            ]
+            .into_iter()
+            .map(|name| AssetSpec {
+                name: name.to_string(),
+                path: Utf8PathBuf::from(format!("examples/templates/static/{name}")).into(),
+            })
+            .collect(),
+            ..Default::default()
+        },
+        "decimals" => GeneratorOptions {
+            decimal_types: vec!["money".to_string()],
+            ..Default::default()
+        },
+        "validators" => GeneratorOptions {
+            validators: true,
+            ..Default::default()
+        },
+        "enum-constants" => GeneratorOptions {
+            enum_constants: true,
+            ..Default::default()
+        },
+        _ => GeneratorOptions::default(),
     }
 }
 
-#[allow(dead_code)]
-pub struct TestInstance {
-    engine: Engine,
-    linker: Linker<Host>,
-    component: Component,
-    store: Store<Host>,
-    instance: Instance,
-    stdout_file: NamedUtf8TempFile,
-    stderr_file: NamedUtf8TempFile,
-    temp_dir: Utf8TempDir,
-}
-
-impl TestInstance {
-    pub async fn new(wasm_path: &Utf8Path) -> anyhow::Result<Self> {
-        let mut config = wasmtime::Config::default();
-        config.async_support(true);
-        config.wasm_component_model(true);
-        let engine = Engine::new(&config)?;
-        let mut linker: Linker<Host> = Linker::new(&engine);
-
-        wasmtime_wasi::p2::add_to_linker_with_options_async(
-            &mut linker,
-            &bindings::LinkOptions::default(),
-        )?;
-        wasmtime_wasi_http::add_only_http_to_linker_async(&mut linker)?;
-
-        let stdout_file = NamedUtf8TempFile::new()?;
-        let stderr_file = NamedUtf8TempFile::new()?;
-
-        let temp_dir = Utf8TempDir::new()?;
-        fs::write(temp_dir.path().join("input.txt"), "test file contents")?;
-        fs::create_dir(temp_dir.path().join("test"))?;
-
-        let ctx = WasiCtx::builder()
-            .stdout(OutputFile::new(stdout_file.reopen()?))
-            .stderr(OutputFile::new(stderr_file.reopen()?))
-            .arg("first-arg")
-            .arg("second-arg")
-            .env("TEST_KEY", "TEST_VALUE")
-            .env("TEST_KEY_2", "TEST_VALUE_2")
-            .preopened_dir(&temp_dir, "/", DirPerms::all(), FilePerms::all())?
-            .build();
-        let http_ctx = WasiHttpCtx::new();
-        let host = Host {
-            table: Arc::new(Mutex::new(ResourceTable::new())),
-            wasi: Arc::new(Mutex::new(ctx)),
-            wasi_http: Arc::new(http_ctx),
-        };
-
-        let component = Component::from_file(&engine, wasm_path)?;
-        let mut store = Store::new(&engine, host);
-
-        let instance = linker.instantiate_async(&mut store, &component).await?;
-
-        Ok(Self {
-            engine,
-            linker,
-            component,
-            store,
-            instance,
-            stdout_file,
-            stderr_file,
-            temp_dir,
+/// The JS modules embedded into the wrapper crate of an example: the modules its entry module
+/// imports, if any, followed by the entry module
+pub fn example_js_modules(path: &Utf8Path) -> Vec<JsModuleSpec> {
+    let name = path.file_name().unwrap();
+    let additional_modules: &[&str] = match name {
+        "module-paths" => &["lib/format", "lib/utils", "shared/constants"],
+        "init-hooks" => &["polyfills", "cache"],
+        "builtin-permissions" => &["plugin"],
+        _ => &[],
+    };
+    additional_modules
+        .iter()
+        .copied()
+        .chain(std::iter::once(name))
+        .map(|module| JsModuleSpec {
+            name: module.to_string(),
+            mode: EmbeddingMode::EmbedFile(path.join("src").join(format!("{module}.js")).into()),
+            entry: module == name,
         })
-    }
-
-    pub async fn invoke_and_capture_output(
-        &mut self,
-        interface_name: Option<&str>,
-        function_name: &str,
-        args: &[Val],
-    ) -> (anyhow::Result<Option<Val>>, String) {
-        let (results, stdout, _stderr) = self
-            .invoke_and_capture_output_with_stderr(interface_name, function_name, args)
-            .await;
-        (results, stdout)
-    }
-
-    pub async fn invoke_and_capture_output_with_stderr(
-        &mut self,
-        interface_name: Option<&str>,
-        function_name: &str,
-        args: &[Val],
-    ) -> (anyhow::Result<Option<Val>>, String, String) {
-        let results = self
-            .invoke_and_capture_output_inner(interface_name, function_name, args)
-            .await;
-
-        let stdout = fs::read_to_string(&self.stdout_file).expect("failed to read stdout");
-        let stderr = fs::read_to_string(&self.stderr_file).expect("failed to read stderr");
-
-        if results.is_err() {
-            for line in stdout.lines() {
-                println!("[stdout] {line}");
-            }
-        }
-
-        for line in stderr.lines() {
-            println!("[stderr] {line}");
-        }
-
-        (
-            results.map(|results| results.first().cloned()),
-            stdout,
-            stderr,
-        )
-    }
-
-    pub fn temp_dir_path(&self) -> &Utf8Path {
-        self.temp_dir.path()
-    }
-
-    async fn invoke_and_capture_output_inner(
-        &mut self,
-        interface_name: Option<&str>,
-        function_name: &str,
-        args: &[Val],
-    ) -> anyhow::Result<Vec<Val>> {
-        let func = match interface_name {
-            Some(interface_name) => {
-                let (_, exported_instance_id) = self
-                    .instance
-                    .get_export(&mut self.store, None, interface_name)
-                    .ok_or_else(|| anyhow!("Interface {interface_name} not found"))?;
-                let (_, func_id) = self
-                    .instance
-                    .get_export(&mut self.store, Some(&exported_instance_id), function_name)
-                    .ok_or_else(|| {
-                        anyhow!("Function {function_name} not found in interface {interface_name}")
-                    })?;
-                self.instance
-                    .get_func(&mut self.store, func_id)
-                    .ok_or_else(|| anyhow!("Function {function_name} not found"))?
-            }
-            None => self
-                .instance
-                .get_func(&mut self.store, function_name)
-                .ok_or_else(|| anyhow!("Function {function_name} not found"))?,
-        };
-
-        self.perform_invoke(func, args).await
-    }
+        .collect()
+}
 
-    async fn perform_invoke(&mut self, func: Func, args: &[Val]) -> anyhow::Result<Vec<Val>> {
-        let mut results = (0..func.results(&mut self.store).len())
-            .map(|_| Val::Bool(false))
-            .collect::<Vec<_>>();
-        func.call_async(&mut self.store, args, &mut results).await?;
-        func.post_return_async(&mut self.store).await?;
-        Ok(results)
+/// The WASI environment the examples are tested in
+pub fn test_instance_options() -> TestInstanceOptions {
+    TestInstanceOptions {
+        args: vec!["first-arg".to_string(), "second-arg".to_string()],
+        env: vec![
+            ("TEST_KEY".to_string(), "TEST_VALUE".to_string()),
+            ("TEST_KEY_2".to_string(), "TEST_VALUE_2".to_string()),
+        ],
+        files: vec![(
+            Utf8PathBuf::from("input.txt"),
+            b"test file contents".to_vec(),
+        )],
+        directories: vec![Utf8PathBuf::from("test")],
+        reentrant_imports: vec![],
     }
+}
 
-    pub async fn drop_resource(&mut self, resource: ResourceAny) -> anyhow::Result<()> {
-        resource.resource_drop_async::<Host>(&mut self.store).await
-    }
+pub async fn new_test_instance(wasm_path: &Utf8Path) -> anyhow::Result<TestInstance> {
+    TestInstance::with_options(wasm_path, &test_instance_options()).await
 }
 
 pub async fn invoke_and_capture_output(
@@ -231,14 +227,14 @@ pub async fn invoke_and_capture_output(
     function_name: &str,
     args: &[Val],
 ) -> (anyhow::Result<Option<Val>>, String) {
-    match TestInstance::new(wasm_path).await {
-        Ok(mut test_instance) => {
-            test_instance
-                .invoke_and_capture_output(interface_name, function_name, args)
-                .await
-        }
-        Err(e) => (Err(e), String::new()),
-    }
+    wasm_rquickjs_test::invoke_and_capture_output(
+        wasm_path,
+        &test_instance_options(),
+        interface_name,
+        function_name,
+        args,
+    )
+    .await
 }
 
 pub async fn invoke_and_capture_output_with_stderr(
@@ -247,208 +243,64 @@ pub async fn invoke_and_capture_output_with_stderr(
     function_name: &str,
     args: &[Val],
 ) -> (anyhow::Result<Option<Val>>, String, String) {
-    match TestInstance::new(wasm_path).await {
-        Ok(mut test_instance) => {
-            test_instance
-                .invoke_and_capture_output_with_stderr(interface_name, function_name, args)
-                .await
-        }
-        Err(e) => (Err(e), String::new(), String::new()),
-    }
+    wasm_rquickjs_test::invoke_and_capture_output_with_stderr(
+        wasm_path,
+        &test_instance_options(),
+        interface_name,
+        function_name,
+        args,
+    )
+    .await
 }
 
-enum WasmSource {
-    Precompiled(Utf8PathBuf),
-    OwnedTemporary(NamedUtf8TempFile),
+/// Generates and compiles the wrapper crate of an example, for the target selected by the
+/// `WASM_RQUICKJS_TEST_TARGET` environment variable (`wasip1` by default)
+pub fn compile_example(path: &Utf8Path, use_shared_target: bool) -> anyhow::Result<CompiledTest> {
+    compile_example_with_features(path, FeatureCombination::HttpOnly, use_shared_target)
 }
 
-pub struct CompiledTest {
-    wasm: WasmSource,
+pub fn compile_example_with_features(
+    path: &Utf8Path,
+    feature_combination: FeatureCombination,
+    use_shared_target: bool,
+) -> anyhow::Result<CompiledTest> {
+    compile_example_variant(
+        path,
+        path.file_name().unwrap(),
+        feature_combination,
+        use_shared_target,
+    )
 }
 
-impl CompiledTest {
-    pub fn new(path: &Utf8Path, use_shared_target: bool) -> anyhow::Result<CompiledTest> {
-        let feature_combination = FeatureCombination::HttpOnly;
-        let name = path.file_name().unwrap();
-        let wrapper_crate_root = Utf8Path::new("tmp")
-            .join(name)
-            .join(feature_combination.label());
-
-        // shared_target is relative to wrapper_crate_root.
-        // this is a _different_ shared target than the one used in the compilation tests to make
-        // sure different feature combinations do not interfere with these tests.
-        let shared_target = Utf8Path::new("..").join("..").join("rt-target");
-
-        println!("Generating wrapper create for example '{name}' to {wrapper_crate_root}");
-        generate_wrapper_crate(
-            &path.join("wit"),
-            &[JsModuleSpec {
-                name: name.to_string(),
-                mode: EmbeddingMode::EmbedFile(path.join("src").join(format!("{name}.js"))),
-            }],
-            &wrapper_crate_root,
-            None,
-            false
-        )?;
-
-        println!("Compiling wrapper crate in {wrapper_crate_root}");
-        let mut command = Command::new("cargo-component");
-        command.arg("build");
-        if use_shared_target {
-            command.arg("--target-dir");
-            command.arg(shared_target);
-        }
-        command
-            .args(feature_combination.cargo_args())
-            .current_dir(&wrapper_crate_root)
-            .status()?;
-
-        if use_shared_target {
-            Ok(CompiledTest {
-                wasm: Precompiled(
-                    Utf8Path::new("tmp")
-                        .join("rt-target")
-                        .join("wasm32-wasip1")
-                        .join("debug")
-                        .join(format!("{}.wasm", name.to_snake_case())),
-                ),
-            })
-        } else {
-            Ok(CompiledTest {
-                wasm: Precompiled(
-                    wrapper_crate_root
-                        .join("target")
-                        .join("wasm32-wasip1")
-                        .join("debug")
-                        .join(format!("{}.wasm", name.to_snake_case())),
-                ),
-            })
-        }
-    }
-
-    pub fn wasm_path(&self) -> &Utf8Path {
-        match &self.wasm {
-            WasmSource::Precompiled(path) => path,
-            WasmSource::OwnedTemporary(temp_file) => temp_file.path(),
-        }
-    }
-
-    pub fn plug_into(&self, other: &CompiledTest) -> anyhow::Result<CompiledTest> {
-        let mut graph = CompositionGraph::new();
-        let socket_package =
-            Package::from_file("socket", None, other.wasm_path(), graph.types_mut())?;
-        let socket_id = graph.register_package(socket_package)?;
-
-        let plug_package = Package::from_file("plug", None, self.wasm_path(), graph.types_mut())?;
-        let plug_id = graph.register_package(plug_package)?;
-
-        plug(
-            &mut graph,
-            vec![(self.wasm_path().to_string(), plug_id)],
-            socket_id,
-        )?;
-
-        let bytes = graph.encode(EncodeOptions::default())?;
-        let mut wasm_path = NamedUtf8TempFile::new()?;
-        wasm_path.write_all(bytes.as_slice())?;
-        wasm_path.flush()?;
-        Ok(CompiledTest {
-            wasm: WasmSource::OwnedTemporary(wasm_path),
-        })
-    }
-}
-
-#[derive(Clone)]
-struct Host {
-    pub table: Arc<Mutex<ResourceTable>>,
-    pub wasi: Arc<Mutex<WasiCtx>>,
-    pub wasi_http: Arc<WasiHttpCtx>,
-}
-
-impl IoView for Host {
-    fn table(&mut self) -> &mut ResourceTable {
-        Arc::get_mut(&mut self.table)
-            .expect("ResourceTable is shared and cannot be borrowed mutably")
-            .get_mut()
-            .expect("ResourceTable mutex must never fail")
-    }
-}
-
-impl WasiView for Host {
-    fn ctx(&mut self) -> &mut WasiCtx {
-        Arc::get_mut(&mut self.wasi)
-            .expect("WasiCtx is shared and cannot be borrowed mutably")
-            .get_mut()
-            .expect("WasiCtx mutex must never fail")
-    }
-}
-
-impl WasiHttpView for Host {
-    fn ctx(&mut self) -> &mut WasiHttpCtx {
-        Arc::get_mut(&mut self.wasi_http)
-            .expect("WasiHttpCtx is shared and cannot be borrowed mutably")
-    }
-}
-
-// Based on https://github.com/bytecodealliance/wac/blob/release-0.6.0/crates/wac-graph/src/plug.rs#L23
-// but instead of returning NoPlugError, it logs skipped instantiations
-fn plug(
-    graph: &mut CompositionGraph,
-    plugs: Vec<(String, PackageId)>,
-    socket: PackageId,
-) -> Result<(), PlugError> {
-    let socket_instantiation = graph.instantiate(socket);
-
-    let mut requested_plugs = BTreeSet::<String>::new();
-    let mut plug_exports_to_plug = BTreeMap::<String, String>::new();
-
-    for (plug_name, plug) in plugs {
-        requested_plugs.insert(plug_name.clone());
-
-        let mut plug_exports = Vec::new();
-        let mut cache = Default::default();
-        let mut checker = SubtypeChecker::new(&mut cache);
-        for (name, plug_ty) in &graph.types()[graph[plug].ty()].exports {
-            if let Some(socket_ty) = graph.types()[graph[socket].ty()].imports.get(name)
-                && checker
-                    .is_subtype(*plug_ty, graph.types(), *socket_ty, graph.types())
-                    .is_ok()
-            {
-                plug_exports.push(name.clone());
-            }
-        }
-
-        // Instantiate the plug component
-        let mut plug_instantiation = None;
-        for plug_export_name in plug_exports {
-            plug_exports_to_plug.insert(plug_export_name.clone(), plug_name.clone());
-
-            let plug_instantiation =
-                *plug_instantiation.get_or_insert_with(|| graph.instantiate(plug));
-            let export = graph
-                .alias_instance_export(plug_instantiation, &plug_export_name)
-                .map_err(|err| PlugError::GraphError { source: err.into() })?;
-            graph
-                .set_instantiation_argument(socket_instantiation, &plug_export_name, export)
-                .map_err(|err| PlugError::GraphError { source: err.into() })?;
-        }
-    }
-
-    // Export all exports from the socket component.
-    for name in graph.types()[graph[socket].ty()]
-        .exports
-        .keys()
-        .cloned()
-        .collect::<Vec<_>>()
-    {
-        let export = graph
-            .alias_instance_export(socket_instantiation, &name)
-            .map_err(|err| PlugError::GraphError { source: err.into() })?;
-
-        graph
-            .export(export, &name)
-            .map_err(|err| PlugError::GraphError { source: err.into() })?;
-    }
-
-    Ok(())
+/// Compiles an example generated with the [`example_generator_options`] of `name` instead of the
+/// example's own, to test the same example with different generator options
+pub fn compile_example_variant(
+    path: &Utf8Path,
+    name: &str,
+    feature_combination: FeatureCombination,
+    use_shared_target: bool,
+) -> anyhow::Result<CompiledTest> {
+    let wrapper_crate_root = Utf8Path::new("tmp")
+        .join(name)
+        .join(feature_combination.label());
+
+    // this is a _different_ shared target than the one used in the compilation tests to make
+    // sure different feature combinations do not interfere with these tests.
+    let shared_target = Utf8Path::new("tmp").join("rt-target");
+
+    println!("Generating wrapper create for example '{name}' to {wrapper_crate_root}");
+    generate_wrapper_crate(
+        path.join("wit"),
+        &example_js_modules(path),
+        &wrapper_crate_root,
+        None,
+        &example_generator_options(name),
+    )?;
+
+    CompiledTest::compile_for_target(
+        &wrapper_crate_root,
+        feature_combination,
+        CompileTarget::from_env()?,
+        use_shared_target.then_some(shared_target.as_path()),
+    )
 }
@@ -1,131 +1,341 @@
 use axum::body::Body;
 use axum::extract::{Multipart, Path};
-use axum::response::{AppendHeaders, IntoResponse};
-use axum::routing::{get, post};
+use axum::response::{AppendHeaders, IntoResponse, Response};
+use axum::routing::{any, get, post};
 use axum::{Json, Router};
 use bytes::Bytes;
-use http::{StatusCode, header};
+use futures::StreamExt;
+use http::{HeaderMap, HeaderName, HeaderValue, StatusCode, header};
 use serde::{Deserialize, Serialize};
 use std::io::Cursor;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
 use tokio::sync::Mutex;
 use tokio::task::JoinHandle;
 use tokio_util::io::ReaderStream;
 
+/// Starts the test server with only the builtin routes
 pub async fn start_test_server() -> (u16, JoinHandle<()>) {
-    let listener = tokio::net::TcpListener::bind("0.0.0.0:0").await.unwrap();
-    let host_http_port = listener.local_addr().unwrap().port();
-
-    let handle = tokio::spawn(async move {
-        let state_mutex = Arc::new(Mutex::new(State::default()));
-
-        let state_mutex_1 = state_mutex.clone();
-        let state_mutex_2 = state_mutex.clone();
-        let state_mutex_3 = state_mutex.clone();
-
-        let router = Router::new()
-            .route(
-                "/todos",
-                post(async move |body: Bytes| {
-                    let mut state = state_mutex_1.lock().await;
-
-                    let req = serde_json::from_slice::<NewTodo>(&body).unwrap();
-
-                    let todo_id = state.current_todo_id;
-                    let todo = Todo {
-                        id: todo_id,
-                        user_id: req.user_id,
-                        title: req.title,
-                        body: req.body,
-                        completed: false,
-                    };
-
-                    let response = (StatusCode::CREATED, Json(&todo)).into_response();
-
-                    state.todos.push(todo);
-                    state.current_todo_id += 1;
-
-                    response
-                }),
-            )
-            .route(
-                "/todos",
-                get(async move || {
-                    let state = state_mutex_2.lock().await;
-
-                    Json(&state.todos).into_response()
-                }),
-            )
-            .route(
-                "/todos/{todo_id}",
-                get(async move |Path((todo_id,)): Path<(usize,)>| {
-                    let state = state_mutex_3.lock().await;
-
-                    let todo = state.todos.get(todo_id);
-
-                    if let Some(todo) = todo {
-                        Json(todo).into_response()
-                    } else {
-                        StatusCode::NOT_FOUND.into_response()
-                    }
-                }),
-            )
-            .route(
-                "/todos-stream",
-                get(async move || {
-                    let mut todos = Vec::new();
-                    for i in 0..100 {
-                        todos.push(Todo {
-                            id: i,
-                            user_id: 1,
-                            title: format!("todo_title_{i}"),
-                            body: format!("todo_body_{i}"),
-                            completed: i % 2 == 0,
-                        });
-                    }
+    let server = TestServer::builder().start().await;
+    (server.port, server.handle)
+}
+
+/// An HTTP server on localhost for the fetch tests, serving the builtin routes (`/todos`,
+/// `/todos-stream`, `/echo`, `/echo-form`, `/echo-cookie`, `/echo-forwarded`, `/counter`,
+/// `/etag-counter`, `/events`) and the configured [`TestRoute`]s
+pub struct TestServer {
+    pub port: u16,
+    pub handle: JoinHandle<()>,
+}
+
+impl TestServer {
+    pub fn builder() -> TestServerBuilder {
+        TestServerBuilder { routes: Vec::new() }
+    }
+
+    /// The URL of a path on the server, as seen from the tested component
+    pub fn url(&self, path: &str) -> String {
+        format!("http://localhost:{}{path}", self.port)
+    }
+}
+
+pub struct TestServerBuilder {
+    routes: Vec<(String, TestRoute)>,
+}
+
+impl TestServerBuilder {
+    /// Serves `route` on `path` for every method, in addition to the builtin routes
+    pub fn route(mut self, path: &str, route: TestRoute) -> Self {
+        self.routes.push((path.to_string(), route));
+        self
+    }
+
+    pub async fn start(self) -> TestServer {
+        let listener = tokio::net::TcpListener::bind("0.0.0.0:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        let mut router = builtin_routes();
+        for (path, route) in self.routes {
+            let route = Arc::new(route);
+            router = router.route(&path, any(async move || route.respond().await));
+        }
+
+        let handle = tokio::spawn(async move {
+            axum::serve(listener, router).await.unwrap();
+        });
+
+        TestServer { port, handle }
+    }
+}
+
+/// A configured response of the [`TestServer`]
+#[derive(Clone)]
+pub struct TestRoute {
+    status: StatusCode,
+    headers: Vec<(HeaderName, HeaderValue)>,
+    chunks: Vec<Bytes>,
+    delay: Duration,
+    chunk_delay: Duration,
+}
+
+impl TestRoute {
+    /// Responds with `200 OK` and the given body
+    pub fn ok(body: impl Into<Bytes>) -> Self {
+        Self::with_status(StatusCode::OK).body(body)
+    }
+
+    /// Responds with the given status and an empty body
+    pub fn with_status(status: StatusCode) -> Self {
+        Self {
+            status,
+            headers: Vec::new(),
+            chunks: Vec::new(),
+            delay: Duration::ZERO,
+            chunk_delay: Duration::ZERO,
+        }
+    }
+
+    /// Responds with a redirect to `location`, which the client has to follow without TLS
+    pub fn redirect(status: StatusCode, location: &str) -> Self {
+        Self::with_status(status).header(header::LOCATION, location)
+    }
+
+    pub fn header(mut self, name: HeaderName, value: &str) -> Self {
+        self.headers
+            .push((name, HeaderValue::from_str(value).unwrap()));
+        self
+    }
+
+    pub fn body(mut self, body: impl Into<Bytes>) -> Self {
+        self.chunks = vec![body.into()];
+        self
+    }
+
+    /// Sends the body in chunks (with chunked transfer encoding), waiting `chunk_delay` before
+    /// each chunk
+    pub fn chunked(mut self, chunks: &[&str], chunk_delay: Duration) -> Self {
+        self.chunks = chunks
+            .iter()
+            .map(|chunk| Bytes::from(chunk.to_string()))
+            .collect();
+        self.chunk_delay = chunk_delay;
+        self
+    }
+
+    /// Waits before sending the response headers
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    async fn respond(&self) -> Response {
+        tokio::time::sleep(self.delay).await;
+
+        let chunk_delay = self.chunk_delay;
+        let body = if self.chunks.len() > 1 || !chunk_delay.is_zero() {
+            let chunks = self.chunks.clone();
+            Body::from_stream(futures::stream::iter(chunks).then(move |chunk| async move {
+                tokio::time::sleep(chunk_delay).await;
+                Ok::<_, std::io::Error>(chunk)
+            }))
+        } else {
+            Body::from(self.chunks.first().cloned().unwrap_or_default())
+        };
+
+        let mut response = Response::new(body);
+        *response.status_mut() = self.status;
+        for (name, value) in &self.headers {
+            response.headers_mut().append(name.clone(), value.clone());
+        }
+        response
+    }
+}
+
+/// The routes every test server has
+fn builtin_routes() -> Router {
+    let state_mutex = Arc::new(Mutex::new(State::default()));
+
+    let state_mutex_1 = state_mutex.clone();
+    let state_mutex_2 = state_mutex.clone();
+    let state_mutex_3 = state_mutex.clone();
+    let counter = Arc::new(AtomicUsize::new(0));
+    let etag_counter = Arc::new(AtomicUsize::new(0));
+
+    Router::new()
+        .route(
+            "/todos",
+            post(async move |body: Bytes| {
+                let mut state = state_mutex_1.lock().await;
+
+                let req = serde_json::from_slice::<NewTodo>(&body).unwrap();
+
+                let todo_id = state.current_todo_id;
+                let todo = Todo {
+                    id: todo_id,
+                    user_id: req.user_id,
+                    title: req.title,
+                    body: req.body,
+                    completed: false,
+                };
+
+                let response = (StatusCode::CREATED, Json(&todo)).into_response();
+
+                state.todos.push(todo);
+                state.current_todo_id += 1;
+
+                response
+            }),
+        )
+        .route(
+            "/todos",
+            get(async move || {
+                let state = state_mutex_2.lock().await;
+
+                Json(&state.todos).into_response()
+            }),
+        )
+        .route(
+            "/todos/{todo_id}",
+            get(async move |Path((todo_id,)): Path<(usize,)>| {
+                let state = state_mutex_3.lock().await;
+
+                let todo = state.todos.get(todo_id);
+
+                if let Some(todo) = todo {
+                    Json(todo).into_response()
+                } else {
+                    StatusCode::NOT_FOUND.into_response()
+                }
+            }),
+        )
+        .route(
+            "/todos-stream",
+            get(async move || {
+                let mut todos = Vec::new();
+                for i in 0..100 {
+                    todos.push(Todo {
+                        id: i,
+                        user_id: 1,
+                        title: format!("todo_title_{i}"),
+                        body: format!("todo_body_{i}"),
+                        completed: i % 2 == 0,
+                    });
+                }
 
-                    let json_bytes = serde_json::to_vec(&todos).unwrap();
+                let json_bytes = serde_json::to_vec(&todos).unwrap();
 
-                    let body_stream = ReaderStream::with_capacity(Cursor::new(json_bytes), 100);
+                let body_stream = ReaderStream::with_capacity(Cursor::new(json_bytes), 100);
 
+                (
+                    AppendHeaders([(header::CONTENT_TYPE, "application/json")]),
+                    Body::from_stream(body_stream),
+                )
+            }),
+        )
+        .route(
+            "/echo",
+            post(async move |body: Body| {
+                (
+                    AppendHeaders([(header::CONTENT_TYPE, "application/octet-stream")]),
+                    body,
+                )
+            }),
+        )
+        .route(
+            "/echo-cookie",
+            get(async move |headers: HeaderMap| {
+                headers
+                    .get(header::COOKIE)
+                    .and_then(|value| value.to_str().ok())
+                    .unwrap_or_default()
+                    .to_string()
+            }),
+        )
+        .route(
+            "/echo-forwarded",
+            get(async move |headers: HeaderMap| {
+                let header = |name: &str| {
+                    headers
+                        .get(name)
+                        .and_then(|value| value.to_str().ok())
+                        .map(String::from)
+                };
+                match (header("x-forwarded-host"), header("x-forwarded-proto")) {
+                    (Some(host), Some(proto)) => format!("{proto}://{host}"),
+                    _ => "direct".to_string(),
+                }
+            }),
+        )
+        .route(
+            "/counter",
+            get(async move || {
+                let count = counter.fetch_add(1, Ordering::SeqCst) + 1;
+                (
+                    AppendHeaders([(header::CACHE_CONTROL, "max-age=60")]),
+                    count.to_string(),
+                )
+                    .into_response()
+            }),
+        )
+        .route(
+            "/etag-counter",
+            get(async move |headers: HeaderMap| {
+                let count = etag_counter.fetch_add(1, Ordering::SeqCst) + 1;
+                let etag = "\"v1\"";
+                if headers
+                    .get(header::IF_NONE_MATCH)
+                    .is_some_and(|value| value == etag)
+                {
                     (
-                        AppendHeaders([(header::CONTENT_TYPE, "application/json")]),
-                        Body::from_stream(body_stream),
+                        StatusCode::NOT_MODIFIED,
+                        AppendHeaders([(header::ETAG, etag)]),
                     )
-                }),
-            )
-            .route(
-                "/echo",
-                post(async move |body: Body| {
+                        .into_response()
+                } else {
                     (
-                        AppendHeaders([(header::CONTENT_TYPE, "application/octet-stream")]),
-                        body,
+                        AppendHeaders([(header::ETAG, etag), (header::CACHE_CONTROL, "no-cache")]),
+                        count.to_string(),
                     )
-                }),
-            )
-            .route(
-                "/echo-form",
-                post(async move |mut multipart: Multipart| {
-                    let mut parts = Vec::new();
-
-                    while let Some(field) = multipart.next_field().await.unwrap() {
-                        let name = field.name().unwrap().to_string();
-                        let data = field.bytes().await.unwrap();
-
-                        parts.push(MultiPartPart {
-                            name,
-                            data: data.to_vec(),
-                        });
+                        .into_response()
+                }
+            }),
+        )
+        .route(
+            "/events",
+            get(async move |headers: HeaderMap| {
+                // The stream ends after two events, continuing from the last event ID on reconnect
+                let body = match headers.get("last-event-id") {
+                    Some(id) if id == "2" => "id: 3\r\ndata: third\r\n\r\n",
+                    _ => {
+                        "retry: 10\n\nid: 1\ndata: first\n\n: comment\nid: 2\nevent: custom\n\
+                         data: second\ndata: line\n\n"
                     }
+                };
+                (
+                    AppendHeaders([(header::CONTENT_TYPE, "text/event-stream")]),
+                    body,
+                )
+            }),
+        )
+        .route(
+            "/echo-form",
+            post(async move |mut multipart: Multipart| {
+                let mut parts = Vec::new();
 
-                    Json(parts)
-                }),
-            );
+                while let Some(field) = multipart.next_field().await.unwrap() {
+                    let name = field.name().unwrap().to_string();
+                    let data = field.bytes().await.unwrap();
 
-        axum::serve(listener, router).await.unwrap();
-    });
+                    parts.push(MultiPartPart {
+                        name,
+                        data: data.to_vec(),
+                    });
+                }
 
-    (host_http_port, handle)
+                Json(parts)
+            }),
+        )
 }
 
 #[derive(Debug, Clone, Serialize)]
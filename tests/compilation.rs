@@ -1,11 +1,23 @@
 test_r::enable!();
 
-use crate::common::{FeatureCombination, collect_example_paths};
-use camino::Utf8Path;
+use crate::common::{
+    CompileTarget, FeatureCombination, collect_example_paths, example_generator_options,
+    example_js_modules,
+};
+use camino::{Utf8Path, Utf8PathBuf};
+use camino_tempfile::Utf8TempDir;
+use indoc::indoc;
+use std::collections::BTreeSet;
+use std::path::Path;
 use std::process::Command;
+use std::sync::{Arc, Mutex};
 use test_r::core::{DynamicTestRegistration, TestProperties};
-use test_r::test_gen;
-use wasm_rquickjs::{EmbeddingMode, JsModuleSpec, generate_wrapper_crate};
+use test_r::{test, test_gen};
+use wasm_rquickjs::{
+    AssetSpec, CodegenBackend, DateClock, Diagnostic, EmbeddingMode, GenerationStage,
+    GeneratorOptions, JsModuleSpec, ProgressReporter, WorldModel, build_script,
+    generate_inline_module, generate_wrapper_crate, plan_wrapper_crate,
+};
 
 #[allow(dead_code)]
 mod common;
@@ -13,25 +25,33 @@ mod common;
 #[test_gen]
 fn gen_compilation_tests(r: &mut DynamicTestRegistration) {
     for example_path in collect_example_paths().unwrap() {
-        for feature_combination in FeatureCombination::all() {
-            let example_path_clone = example_path.clone();
-            let example_name = example_path.file_name().unwrap().to_string();
-            let label = feature_combination.label();
-
-            r.add_sync_test(
-                format!("{example_name}_{label}"),
-                TestProperties {
-                    ..TestProperties::unit_test()
-                },
-                move |_deps| {
-                    let example_name = example_name.clone();
-                    let example_path_clone = example_path_clone.clone();
-                    let gen_fn = move || {
-                        compilation_test(&example_name, &example_path_clone, feature_combination)
-                    };
-                    gen_fn()
-                },
-            );
+        for target in CompileTarget::all() {
+            for feature_combination in FeatureCombination::all() {
+                let example_path_clone = example_path.clone();
+                let example_name = example_path.file_name().unwrap().to_string();
+                let label = feature_combination.label();
+                let target_label = target.label();
+
+                r.add_sync_test(
+                    format!("{example_name}_{label}_{target_label}"),
+                    TestProperties {
+                        ..TestProperties::unit_test()
+                    },
+                    move |_deps| {
+                        let example_name = example_name.clone();
+                        let example_path_clone = example_path_clone.clone();
+                        let gen_fn = move || {
+                            compilation_test(
+                                &example_name,
+                                &example_path_clone,
+                                feature_combination,
+                                target,
+                            )
+                        };
+                        gen_fn()
+                    },
+                );
+            }
         }
     }
 }
@@ -40,10 +60,13 @@ fn compilation_test(
     name: &str,
     path: &Utf8Path,
     feature_combination: FeatureCombination,
+    target: CompileTarget,
 ) -> anyhow::Result<()> {
-    let wrapper_crate_root = Utf8Path::new("tmp")
-        .join(name)
-        .join(feature_combination.label());
+    let wrapper_crate_root = Utf8Path::new("tmp").join(name).join(format!(
+        "{}_{}",
+        feature_combination.label(),
+        target.label()
+    ));
 
     // shared_target is relative to wrapper_crate_root
     let share_target_dir = name != "pollable"; // <- exclude shared target dir for some examples
@@ -52,19 +75,16 @@ fn compilation_test(
 
     println!("Generating wrapper create for example '{name}' to {wrapper_crate_root}");
     generate_wrapper_crate(
-        &path.join("wit"),
-        &[JsModuleSpec {
-            name: name.to_string(),
-            mode: EmbeddingMode::EmbedFile(path.join("src").join(format!("{name}.js"))),
-        }],
+        path.join("wit"),
+        &example_js_modules(path),
         &wrapper_crate_root,
         None,
-        false
+        &example_generator_options(name),
     )?;
 
     println!("Compiling wrapper crate in {wrapper_crate_root}");
     let mut cmd = Command::new("cargo-component");
-    cmd.arg("build");
+    cmd.arg("build").arg("--target").arg(target.triple());
     if share_target_dir {
         cmd.arg("--target-dir").arg(shared_target);
     }
@@ -77,3 +97,885 @@ fn compilation_test(
 
     Ok(())
 }
+
+#[test]
+fn dry_run_plan_matches_generated_files() -> anyhow::Result<()> {
+    let path = Utf8Path::new("examples/imports1");
+    let dir = Utf8TempDir::new()?;
+    let output = dir.path().join("imports1");
+    let modules = [JsModuleSpec {
+        name: "imports1".to_string(),
+        mode: EmbeddingMode::EmbedFile(path.join("src").join("imports1.js").into()),
+        entry: true,
+    }];
+    let options = GeneratorOptions::default();
+
+    let plan = plan_wrapper_crate(path.join("wit"), &modules, &output, None, &options)?;
+    assert!(!output.exists());
+    assert_eq!(plan.js_modules.len(), 1);
+    assert_eq!(
+        plan.js_modules[0].size,
+        Some(std::fs::metadata(path.join("src").join("imports1.js"))?.len())
+    );
+
+    generate_wrapper_crate(path.join("wit"), &modules, &output, None, &options)?;
+    let mut generated = Vec::new();
+    collect_files(&output, &output, &mut generated)?;
+    generated.sort();
+
+    assert_eq!(plan.files, generated);
+    Ok(())
+}
+
+#[test]
+fn included_worlds_are_merged() -> anyhow::Result<()> {
+    let path = Utf8Path::new("examples/include-worlds");
+    let dir = Utf8TempDir::new()?;
+    let modules = example_js_modules(path);
+
+    for (world, expected_modules) in [
+        (
+            None,
+            vec![
+                "mod.rs",
+                "quickjs_include_worlds_include_worlds_settings.rs",
+                "quickjs_include_worlds_logging.rs",
+            ],
+        ),
+        (
+            Some("base"),
+            vec![
+                "mod.rs",
+                "quickjs_include_worlds_base_settings.rs",
+                "quickjs_include_worlds_logging.rs",
+            ],
+        ),
+    ] {
+        let output = dir.path().join(world.unwrap_or("default"));
+        generate_wrapper_crate(
+            path.join("wit"),
+            &modules,
+            &output,
+            world,
+            &GeneratorOptions::default(),
+        )?;
+
+        let mut generated = Vec::new();
+        collect_files(&output, &output.join("src").join("modules"), &mut generated)?;
+        generated.sort();
+        assert_eq!(
+            generated,
+            expected_modules
+                .iter()
+                .map(|module| Utf8Path::new("src").join("modules").join(module))
+                .collect::<Vec<_>>()
+        );
+
+        // The package has several worlds, so cargo-component has to be told which one to target
+        let cargo_toml = std::fs::read_to_string(output.join("Cargo.toml"))?;
+        assert!(cargo_toml.contains(&format!(
+            "world = \"{}\"",
+            world.unwrap_or("include-worlds")
+        )));
+    }
+    Ok(())
+}
+
+#[test]
+fn generated_files_have_provenance_headers() -> anyhow::Result<()> {
+    let path = Utf8Path::new("examples/imports1");
+    let dir = Utf8TempDir::new()?;
+    let modules = [JsModuleSpec {
+        name: "imports1".to_string(),
+        mode: EmbeddingMode::EmbedFile(path.join("src").join("imports1.js").into()),
+        entry: true,
+    }];
+
+    let output = dir.path().join("with-headers");
+    generate_wrapper_crate(
+        path.join("wit"),
+        &modules,
+        &output,
+        None,
+        &GeneratorOptions::default(),
+    )?;
+    for (file, comment) in [
+        ("Cargo.toml", "#"),
+        ("golem.yaml", "#"),
+        ("src/lib.rs", "//"),
+        ("src/conversions.rs", "//"),
+        ("src/modules/mod.rs", "//"),
+        ("src/internal.rs", "//"),
+    ] {
+        let contents = std::fs::read_to_string(output.join(file))?;
+        let header = contents.lines().take(4).collect::<Vec<_>>();
+        assert_eq!(
+            header[..2],
+            [
+                format!("{comment} @generated by wasm-rquickjs 0.0.0, do not edit"),
+                format!("{comment} world: quickjs:imports1/imports1"),
+            ],
+            "{file}"
+        );
+        assert!(
+            header[2].starts_with(&format!("{comment} wit: sha256:")),
+            "{file}"
+        );
+        assert!(
+            header[3].starts_with(&format!("{comment} js imports1: sha256:")),
+            "{file}"
+        );
+    }
+
+    let output = dir.path().join("without-headers");
+    generate_wrapper_crate(
+        path.join("wit"),
+        &modules,
+        &output,
+        None,
+        &GeneratorOptions {
+            omit_generated_headers: true,
+            ..Default::default()
+        },
+    )?;
+    let lib_rs = std::fs::read_to_string(output.join("src/lib.rs"))?;
+    assert!(lib_rs.starts_with("#[allow(static_mut_refs)]"));
+    Ok(())
+}
+
+#[test]
+fn diagnostics_report_renamed_identifiers_and_unused_imports() -> anyhow::Result<()> {
+    let dir = Utf8TempDir::new()?;
+    let wit = dir.path().join("wit");
+    std::fs::create_dir_all(&wit)?;
+    std::fs::write(
+        wit.join("diagnostics.wit"),
+        indoc!(
+            r#"
+            package quickjs:diagnostics;
+
+            interface logger {
+              log: func(message: string);
+            }
+
+            interface api {
+              record entry {
+                key: string,
+                delete: bool,
+              }
+
+              new: func(e: entry) -> entry;
+            }
+
+            world diagnostics {
+              import logger;
+              export api;
+            }
+            "#
+        ),
+    )?;
+    let js = dir.path().join("diagnostics.js");
+    std::fs::write(&js, "export const api = { new_: (e) => e };")?;
+
+    let plan = plan_wrapper_crate(
+        &wit,
+        &[JsModuleSpec {
+            name: "diagnostics".to_string(),
+            mode: EmbeddingMode::EmbedFile(js.into()),
+            entry: true,
+        }],
+        dir.path().join("out"),
+        None,
+        &GeneratorOptions::default(),
+    )?;
+
+    assert_eq!(
+        plan.diagnostics,
+        vec![
+            Diagnostic::RenamedIdentifier {
+                item: "the exported function".to_string(),
+                wit_name: "new".to_string(),
+                js_name: "new_".to_string(),
+            },
+            Diagnostic::RenamedIdentifier {
+                item: "a field of record `entry`".to_string(),
+                wit_name: "delete".to_string(),
+                js_name: "delete_".to_string(),
+            },
+            Diagnostic::UnusedImport {
+                module: "quickjs:diagnostics/logger".to_string(),
+            },
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn keyvalue_module_uses_the_keyvalue_imports() -> anyhow::Result<()> {
+    let dir = Utf8TempDir::new()?;
+    let js = dir.path().join("all-golem-imports.js");
+    std::fs::write(
+        &js,
+        "import { openBucket } from 'keyvalue';\nexport async function run() { await openBucket('b'); }",
+    )?;
+
+    let plan = plan_wrapper_crate(
+        Utf8Path::new("examples/all-golem-imports").join("wit"),
+        &[JsModuleSpec {
+            name: "all-golem-imports".to_string(),
+            mode: EmbeddingMode::EmbedFile(js.into()),
+            entry: true,
+        }],
+        dir.path().join("out"),
+        None,
+        &GeneratorOptions::default(),
+    )?;
+
+    assert!(
+        plan.files
+            .contains(&Utf8PathBuf::from("src/modules/keyvalue.js"))
+    );
+    for module in [
+        "wasi:keyvalue/eventual@0.1.0",
+        "wasi:keyvalue/eventual-batch@0.1.0",
+    ] {
+        assert!(
+            !plan.diagnostics.contains(&Diagnostic::UnusedImport {
+                module: module.to_string()
+            }),
+            "{module} is reported as unused"
+        );
+    }
+    assert!(plan.diagnostics.contains(&Diagnostic::UnusedImport {
+        module: "wasi:blobstore/blobstore".to_string()
+    }));
+    Ok(())
+}
+
+#[test]
+fn js_modules_are_checked() -> anyhow::Result<()> {
+    let path = Utf8Path::new("examples/module-paths");
+    let dir = Utf8TempDir::new()?;
+    let output = dir.path().join("module-paths");
+    let modules = example_js_modules(path);
+
+    let plan = plan_wrapper_crate(
+        path.join("wit"),
+        &modules,
+        &output,
+        None,
+        &GeneratorOptions::default(),
+    )?;
+    for file in [
+        "src/module-paths.js",
+        "src/lib/utils.js",
+        "src/shared/constants.js",
+    ] {
+        assert!(plan.files.contains(&Utf8PathBuf::from(file)), "{file}");
+    }
+
+    for (names, expected) in [
+        (
+            [("main", true), ("utils", true)],
+            "Only one JS module can be the entry module, but `main`, `utils` are marked as entry",
+        ),
+        (
+            [("main", false), ("utils", false)],
+            "None of the JS modules is marked as the entry module implementing the exports",
+        ),
+        (
+            [("main", true), ("lib/../utils", false)],
+            "Invalid JS module name `lib/../utils`, it must be a relative path such as `lib/utils`",
+        ),
+        (
+            [("main", true), ("modules/golem", false)],
+            "The JS module name `modules/golem` cannot start with `modules/`, it is reserved for the generated code",
+        ),
+        (
+            [("lib/Utils", true), ("lib/utils", false)],
+            "The JS modules `lib/Utils` and `lib/utils` would both be embedded as `lib/utils.js`",
+        ),
+        (
+            [("lib", true), ("lib.js/utils", false)],
+            "The JS module `lib` would be embedded as `lib.js`, which is a directory of the JS module `lib.js/utils`",
+        ),
+    ] {
+        let modules = names
+            .iter()
+            .map(|(name, entry)| JsModuleSpec {
+                name: name.to_string(),
+                mode: EmbeddingMode::EmbedFile(path.join("src").join("module-paths.js").into()),
+                entry: *entry,
+            })
+            .collect::<Vec<_>>();
+        let error = plan_wrapper_crate(
+            path.join("wit"),
+            &modules,
+            &output,
+            None,
+            &GeneratorOptions::default(),
+        )
+        .unwrap_err();
+        assert_eq!(error.to_string(), expected);
+    }
+    Ok(())
+}
+
+#[test]
+fn defines_are_substituted_into_the_embedded_modules() -> anyhow::Result<()> {
+    let path = Utf8Path::new("examples/example2");
+    let dir = Utf8TempDir::new()?;
+    let output = dir.path().join("example2");
+    let js = dir.path().join("defines.js");
+    std::fs::write(
+        &js,
+        indoc!(
+            r#"
+            // process.env.API_URL in a comment
+            const url = process.env.API_URL + '/items';
+            const debug = __DEFINE__.DEBUG === 'true';
+            const other = process.env.OTHER;
+            const text = `process.env.API_URL is ${process.env.API_URL}`;
+            const pattern = /process.env.API_URL/;
+            const nested = config.process.env.API_URL;
+            const quoted = "process.env.API_URL";
+            const same = process.env.API_URL == url && __DEFINE__.DEBUG === debug;
+            process.env.API_URL = 'https://example.org';
+            __DEFINE__.DEBUG += '!';
+            process.env.API_URL ||= url;
+            __DEFINE__.DEBUG++;
+            --process.env.API_URL;
+            delete process.env.API_URL;
+            "#
+        ),
+    )?;
+    let modules = [JsModuleSpec {
+        name: "defines".to_string(),
+        mode: EmbeddingMode::EmbedFile(js.into()),
+        entry: true,
+    }];
+
+    generate_wrapper_crate(
+        path.join("wit"),
+        &modules,
+        &output,
+        None,
+        &GeneratorOptions {
+            defines: [
+                (
+                    "API_URL".to_string(),
+                    "https://example.com/\"api\"".to_string(),
+                ),
+                ("DEBUG".to_string(), "true".to_string()),
+            ]
+            .into(),
+            ..Default::default()
+        },
+    )?;
+
+    assert_eq!(
+        std::fs::read_to_string(output.join("src").join("defines.js"))?,
+        indoc!(
+            r#"
+            // process.env.API_URL in a comment
+            const url = "https://example.com/\"api\"" + '/items';
+            const debug = "true" === 'true';
+            const other = process.env.OTHER;
+            const text = `process.env.API_URL is ${"https://example.com/\"api\""}`;
+            const pattern = /process.env.API_URL/;
+            const nested = config.process.env.API_URL;
+            const quoted = "process.env.API_URL";
+            const same = "https://example.com/\"api\"" == url && "true" === debug;
+            process.env.API_URL = 'https://example.org';
+            __DEFINE__.DEBUG += '!';
+            process.env.API_URL ||= url;
+            __DEFINE__.DEBUG++;
+            --process.env.API_URL;
+            delete process.env.API_URL;
+            "#
+        )
+    );
+
+    let error = plan_wrapper_crate(
+        path.join("wit"),
+        &modules,
+        &output,
+        None,
+        &GeneratorOptions {
+            defines: [("API-URL".to_string(), "x".to_string())].into(),
+            ..Default::default()
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Invalid define key `API-URL`, it must be a JavaScript identifier such as `API_URL`"
+    );
+    Ok(())
+}
+
+#[test]
+fn feature_pragmas_are_checked() -> anyhow::Result<()> {
+    let path = Utf8Path::new("examples/feature-pragmas");
+    let dir = Utf8TempDir::new()?;
+    let output = dir.path().join("feature-pragmas");
+
+    let plan = plan_wrapper_crate(
+        path.join("wit"),
+        &example_js_modules(path),
+        &output,
+        None,
+        &GeneratorOptions::default(),
+    )?;
+    for suffix in ["none", "http", "logging", "http-logging"] {
+        let file = format!("src/feature-pragmas.features-{suffix}.js");
+        assert!(plan.files.contains(&Utf8PathBuf::from(&file)), "{file}");
+    }
+
+    for (source, expected) in [
+        (
+            "// #if feature(threads)\nexport const a = 1;\n// #endif\n",
+            "The JS module `main` refers to the unknown feature `threads`, the features of the wrapper crate are http, logging, intl, codecs, compression, passwords, jwt, regex, temporal, messageformat, markup, config, templates, csv, hashing, decimal",
+        ),
+        (
+            "// #if feature(http)\nexport const a = 1;\n",
+            "Invalid pragma in JS module `main`: `#if` at line 1 is not closed by an `#endif`",
+        ),
+        (
+            "export const a = 1;\n// #endif\n",
+            "Invalid pragma in JS module `main`: `#endif` without a matching `#if` at line 2",
+        ),
+        (
+            "// #if http\n// #endif\n",
+            "Invalid pragma in JS module `main`: Expected `#if feature(name)` or `#if !feature(name)` at line 1",
+        ),
+    ] {
+        let js = dir.path().join("main.js");
+        std::fs::write(&js, source)?;
+        let error = plan_wrapper_crate(
+            path.join("wit"),
+            &[JsModuleSpec {
+                name: "main".to_string(),
+                mode: EmbeddingMode::EmbedFile(js.into()),
+                entry: true,
+            }],
+            &output,
+            None,
+            &GeneratorOptions::default(),
+        )
+        .unwrap_err();
+        assert_eq!(format!("{error:#}"), expected);
+    }
+    Ok(())
+}
+
+#[test]
+fn unavailable_apis_are_reported() -> anyhow::Result<()> {
+    let path = Utf8Path::new("examples/example2");
+    let dir = Utf8TempDir::new()?;
+    let js = dir.path().join("apis.js");
+    std::fs::write(
+        &js,
+        indoc!(
+            r#"
+            // fetch in a comment, and in a 'fetch' string
+            export async function get(url) {
+                if (typeof Intl === 'undefined') {
+                    return (await fetch(url)).text();
+                }
+                return new Intl.NumberFormat().format(1);
+            }
+            class Headers {}
+            // #if feature(http)
+            export const response = new Response('');
+            // #endif
+            "#
+        ),
+    )?;
+    let modules = [JsModuleSpec {
+        name: "apis".to_string(),
+        mode: EmbeddingMode::EmbedFile(js.clone().into()),
+        entry: true,
+    }];
+    let options = GeneratorOptions {
+        default_features: Some(vec!["logging".to_string()]),
+        ..Default::default()
+    };
+
+    let plan = plan_wrapper_crate(
+        path.join("wit"),
+        &modules,
+        dir.path().join("out"),
+        None,
+        &options,
+    )?;
+    assert_eq!(plan.default_features, vec!["logging".to_string()]);
+    assert_eq!(
+        plan.diagnostics,
+        vec![
+            Diagnostic::UnavailableApi {
+                api: "fetch".to_string(),
+                feature: "http".to_string(),
+                location: format!("{js}:4:23"),
+            },
+            Diagnostic::UnavailableApi {
+                api: "Intl".to_string(),
+                feature: "intl".to_string(),
+                location: format!("{js}:6:16"),
+            },
+        ]
+    );
+
+    let error = plan_wrapper_crate(
+        path.join("wit"),
+        &modules,
+        dir.path().join("out"),
+        None,
+        &GeneratorOptions {
+            deny_unavailable_apis: true,
+            ..options
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        format!(
+            "The JS modules use APIs not available with the default features of the wrapper crate:\n  \
+             - `fetch` is used at {js}:4:23, but it is only available with the `http` feature, which is not enabled by default\n  \
+             - `Intl` is used at {js}:6:16, but it is only available with the `intl` feature, which is not enabled by default"
+        )
+    );
+    Ok(())
+}
+
+#[test]
+fn keep_going_skips_unsupported_interfaces() -> anyhow::Result<()> {
+    let dir = Utf8TempDir::new()?;
+    let wit = dir.path().join("wit");
+    std::fs::create_dir_all(&wit)?;
+    std::fs::write(
+        wit.join("keep-going.wit"),
+        indoc!(
+            r#"
+            package quickjs:keep-going;
+
+            interface events {
+              next: func() -> future<string>;
+            }
+
+            interface api {
+              hello: func(name: string) -> string;
+            }
+
+            interface wide {
+              call: func(a: u8, b: u8, c: u8, d: u8, e: u8, f: u8, g: u8, h: u8, i: u8, j: u8,
+                         k: u8, l: u8, m: u8, n: u8, o: u8, p: u8, q: u8, r: u8, s: u8, t: u8,
+                         u: u8, v: u8, w: u8, x: u8, y: u8, z: u8, aa: u8);
+            }
+
+            world keep-going {
+              import events;
+              export api;
+              export wide;
+            }
+            "#
+        ),
+    )?;
+    let js = dir.path().join("keep-going.js");
+    std::fs::write(&js, "export const api = { hello: (name) => name };")?;
+    let modules = [JsModuleSpec {
+        name: "keep-going".to_string(),
+        mode: EmbeddingMode::EmbedFile(js.into()),
+        entry: true,
+    }];
+
+    let error = plan_wrapper_crate(
+        &wit,
+        &modules,
+        dir.path().join("out"),
+        None,
+        &GeneratorOptions::default(),
+    )
+    .unwrap_err();
+    assert!(
+        error
+            .to_string()
+            .contains("`future` types are not supported")
+    );
+
+    let plan = plan_wrapper_crate(
+        &wit,
+        &modules,
+        dir.path().join("out"),
+        None,
+        &GeneratorOptions {
+            keep_going: true,
+            ..Default::default()
+        },
+    )?;
+    assert!(plan.import_modules.is_empty());
+    assert_eq!(
+        plan.diagnostics,
+        vec![
+            Diagnostic::SkippedInterface {
+                interface: "quickjs:keep-going/wide".to_string(),
+                exported: true,
+                reasons: vec![
+                    "export `quickjs:keep-going/wide`, function `call`: functions with more than 26 parameters are not supported (found 27)".to_string()
+                ],
+            },
+            Diagnostic::SkippedInterface {
+                interface: "quickjs:keep-going/events".to_string(),
+                exported: false,
+                reasons: vec![
+                    "import `quickjs:keep-going/events`, function `next`: `future` types are not supported".to_string()
+                ],
+            },
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn build_script_inputs_are_listed() {
+    let path = Utf8Path::new("examples/module-paths");
+    let options = GeneratorOptions {
+        assets: vec![AssetSpec {
+            name: "data.json".to_string(),
+            path: Utf8PathBuf::from("assets/data.json").into(),
+        }],
+        ..Default::default()
+    };
+
+    assert_eq!(
+        build_script::input_paths(path.join("wit"), &example_js_modules(path), &options),
+        vec![
+            path.join("wit"),
+            path.join("src/lib/format.js"),
+            path.join("src/lib/utils.js"),
+            path.join("src/shared/constants.js"),
+            path.join("src/module-paths.js"),
+            Utf8PathBuf::from("assets/data.json"),
+        ]
+    );
+}
+
+#[test]
+fn inline_modules_declare_their_modules_by_absolute_paths() -> anyhow::Result<()> {
+    let path = Utf8Path::new("examples/example2");
+    let dir = Utf8TempDir::new()?;
+    let output = dir.path().join("example2");
+    let js = path.join("src").join("example2.js");
+
+    let inline = generate_inline_module(
+        path.join("wit"),
+        &[JsModuleSpec {
+            name: "example2".to_string(),
+            mode: EmbeddingMode::EmbedFile(js.clone().into()),
+            entry: true,
+        }],
+        &output,
+        None,
+        &GeneratorOptions::default(),
+    )?;
+
+    let src = output.canonicalize_utf8()?.join("src");
+    assert_eq!(inline.root, src.join("inline.rs"));
+    let root = std::fs::read_to_string(&inline.root)?;
+    assert!(!root.contains("mod bindings;"));
+    for declaration in [
+        format!(
+            "#[path = \"{}\"]\nmod builtin;",
+            src.join("builtin").join("mod.rs")
+        ),
+        format!("#[path = \"{}\"]\nmod wrappers;", src.join("wrappers.rs")),
+    ] {
+        assert!(root.contains(&declaration), "{declaration}");
+    }
+    assert_eq!(
+        inline.inputs,
+        vec![
+            path.join("wit").join("hello.wit").canonicalize_utf8()?,
+            js.canonicalize_utf8()?,
+        ]
+    );
+    Ok(())
+}
+
+/// Writes the list of exported functions, as a minimal example of a custom backend
+#[derive(Debug)]
+struct ExportListBackend;
+
+impl CodegenBackend for ExportListBackend {
+    fn name(&self) -> &str {
+        "export list"
+    }
+
+    fn generate(&self, model: &WorldModel, output: &Path) -> anyhow::Result<()> {
+        let mut lines = Vec::new();
+        for function in &model.exported_functions {
+            lines.push(function.js_name.clone());
+        }
+        for interface in &model.exports {
+            for function in &interface.functions {
+                lines.push(format!("{}.{}", interface.js_name, function.js_name));
+            }
+        }
+        std::fs::write(output.join("exports.txt"), lines.join("\n"))?;
+        Ok(())
+    }
+}
+
+#[test]
+fn custom_backends_are_run_after_generation() -> anyhow::Result<()> {
+    let path = Utf8Path::new("examples/example2");
+    let dir = Utf8TempDir::new()?;
+    let output = dir.path().join("example2");
+
+    generate_wrapper_crate(
+        path.join("wit"),
+        &[JsModuleSpec {
+            name: "example2".to_string(),
+            mode: EmbeddingMode::EmbedFile(path.join("src").join("example2.js").into()),
+            entry: true,
+        }],
+        &output,
+        None,
+        &GeneratorOptions {
+            backends: vec![Arc::new(ExportListBackend)],
+            ..Default::default()
+        },
+    )?;
+
+    assert_eq!(
+        std::fs::read_to_string(output.join("exports.txt"))?,
+        "version\nexp1.hello\nexp1.getConst\nexp2.asyncHello"
+    );
+    Ok(())
+}
+
+#[test]
+fn host_date_clock_is_imported() -> anyhow::Result<()> {
+    let path = Utf8Path::new("examples/fixed-clock");
+    let dir = Utf8TempDir::new()?;
+
+    generate_wrapper_crate(
+        path.join("wit"),
+        &example_js_modules(path),
+        dir.path(),
+        None,
+        &GeneratorOptions {
+            date_clock: Some(DateClock::Host),
+            ..Default::default()
+        },
+    )?;
+
+    let wit = std::fs::read_to_string(dir.path().join("wit").join("fixed-clock.wit"))?;
+    assert!(
+        wit.contains("import rquickjs-clock-now: func() -> u64;"),
+        "unexpected world:\n{wit}"
+    );
+    let lib = std::fs::read_to_string(dir.path().join("src").join("lib.rs"))?;
+    assert!(lib.contains("crate::bindings::rquickjs_clock_now()"));
+    Ok(())
+}
+
+/// Records the reported progress
+#[derive(Debug, Default)]
+struct RecordingReporter {
+    events: Mutex<Vec<(GenerationStage, usize, usize)>>,
+}
+
+impl ProgressReporter for RecordingReporter {
+    fn progress(&self, stage: GenerationStage, completed: usize, total: usize) {
+        self.events.lock().unwrap().push((stage, completed, total));
+    }
+}
+
+#[test]
+fn progress_is_reported_for_each_stage() -> anyhow::Result<()> {
+    let path = Utf8Path::new("examples/imports1");
+    let dir = Utf8TempDir::new()?;
+    let reporter = Arc::new(RecordingReporter::default());
+
+    generate_wrapper_crate(
+        path.join("wit"),
+        &example_js_modules(path),
+        dir.path(),
+        None,
+        &GeneratorOptions {
+            progress: Some(reporter.clone()),
+            ..Default::default()
+        },
+    )?;
+
+    let events = reporter.events.lock().unwrap().clone();
+    let stages = events
+        .iter()
+        .map(|(stage, _, _)| *stage)
+        .collect::<BTreeSet<_>>();
+    assert_eq!(
+        stages.into_iter().collect::<Vec<_>>(),
+        vec![
+            GenerationStage::Resolve,
+            GenerationStage::CargoToml,
+            GenerationStage::Skeleton,
+            GenerationStage::Exports,
+            GenerationStage::Imports,
+            GenerationStage::Conversions,
+        ]
+    );
+    assert!(events.is_sorted_by_key(|(stage, completed, _)| (*stage, *completed)));
+    assert!(
+        events
+            .iter()
+            .all(|(_, completed, total)| completed <= total)
+    );
+    assert!(events.contains(&(GenerationStage::Imports, 1, 1)));
+    Ok(())
+}
+
+#[test]
+fn fetch_gateway_must_be_an_http_url() -> anyhow::Result<()> {
+    let path = Utf8Path::new("examples/fetch");
+    let dir = Utf8TempDir::new()?;
+    let output = dir.path().join("fetch");
+    let modules = [JsModuleSpec {
+        name: "fetch".to_string(),
+        mode: EmbeddingMode::EmbedFile(path.join("src/fetch.js").into()),
+        entry: true,
+    }];
+
+    let error = plan_wrapper_crate(
+        path.join("wit"),
+        &modules,
+        &output,
+        None,
+        &GeneratorOptions {
+            fetch_gateway: Some("gateway:8080".to_string()),
+            ..Default::default()
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Invalid fetch gateway `gateway:8080`, it must be an absolute `http://` or `https://` URL"
+    );
+    Ok(())
+}
+
+fn collect_files(
+    root: &Utf8Path,
+    dir: &Utf8Path,
+    files: &mut Vec<Utf8PathBuf>,
+) -> anyhow::Result<()> {
+    for entry in dir.read_dir_utf8()? {
+        let entry = entry?;
+        if entry.file_type()?.is_dir() {
+            collect_files(root, entry.path(), files)?;
+        } else {
+            files.push(entry.path().strip_prefix(root)?.to_path_buf());
+        }
+    }
+    Ok(())
+}
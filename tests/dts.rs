@@ -1,11 +1,16 @@
 test_r::enable!();
 
-use crate::common::collect_example_paths;
+use crate::common::{collect_example_paths, example_generator_options};
 use camino::{Utf8Path, Utf8PathBuf};
+use camino_tempfile::Utf8TempDir;
 use goldenfile::Mint;
+use indoc::indoc;
 use test_r::core::{DynamicTestRegistration, TestProperties};
-use test_r::test_gen;
-use wasm_rquickjs::generate_dts;
+use test_r::{test, test_gen};
+use wasm_rquickjs::{
+    DtsFormat, DtsLayout, FieldModel, FunctionModelKind, GeneratorOptions, TypeModelKind,
+    analyze_world, generate_dts,
+};
 
 #[allow(dead_code)]
 mod common;
@@ -48,7 +53,17 @@ fn dts_test(name: &str, path: &Utf8Path) -> anyhow::Result<Vec<Utf8PathBuf>> {
     let target = Utf8Path::new("tmp").join(name).join("dts");
 
     println!("Generating d.ts for example '{name}' to {target}");
-    generate_dts(&path.join("wit"), &target, None)
+    let generated = generate_dts(
+        path.join("wit"),
+        &target,
+        None,
+        &example_generator_options(name),
+    )?;
+    generated
+        .files
+        .into_iter()
+        .map(|file| Ok(Utf8PathBuf::try_from(file)?))
+        .collect()
 }
 
 fn assert_types_match_goldenfile(
@@ -67,3 +82,268 @@ fn assert_types_match_goldenfile(
     std::fs::copy(generated_types, path)?;
     Ok(())
 }
+
+#[test]
+fn unsupported_wit_constructs_are_reported_together() -> anyhow::Result<()> {
+    let dir = Utf8TempDir::new()?;
+    let wit = dir.path().join("wit");
+    std::fs::create_dir_all(&wit)?;
+    std::fs::write(
+        wit.join("unsupported.wit"),
+        indoc!(
+            r#"
+            package quickjs:unsupported;
+
+            interface api {
+              record rec {
+                items: stream<u8>,
+              }
+
+              fun1: func(r: rec);
+              fun2: async func() -> string;
+            }
+
+            world unsupported {
+              export anon: interface {
+                record point { x: u32, y: u32 }
+                fun3: func() -> point;
+              }
+              export api;
+            }
+            "#
+        ),
+    )?;
+
+    let err = generate_dts(
+        &wit,
+        dir.path().join("dts"),
+        None,
+        &GeneratorOptions::default(),
+    )
+    .expect_err("Expected the unsupported world to be rejected");
+    let message = format!("{err:#}");
+
+    assert!(message.contains(
+        "The selected world `quickjs:unsupported/unsupported` uses WIT constructs that are not supported by wasm-rquickjs"
+    ));
+    assert!(message.contains(
+        "export `anon`: anonymous (inline) interface exports defining types are not supported"
+    ));
+    assert!(message.contains(
+        "export `quickjs:unsupported/api`, function `fun1`: `stream` types are not supported"
+    ));
+    assert!(message.contains(
+        "export `quickjs:unsupported/api`, type `rec`: `stream` types are not supported"
+    ));
+    assert!(message.contains(
+        "export `quickjs:unsupported/api`, function `fun2`: async functions are not supported"
+    ));
+    assert!(message.contains("unsupported.wit:9"));
+    assert!(message.contains("unsupported.wit:13"));
+    Ok(())
+}
+
+#[test]
+fn dts_can_be_generated_as_esm_module_files() -> anyhow::Result<()> {
+    let dir = Utf8TempDir::new()?;
+    let options = GeneratorOptions {
+        dts_format: DtsFormat::Mts,
+        omit_generated_headers: true,
+        ..example_generator_options("decimals")
+    };
+
+    let generated = generate_dts(
+        Utf8Path::new("examples/decimals").join("wit"),
+        dir.path(),
+        None,
+        &options,
+    )?;
+    let mut file_names = generated
+        .files
+        .iter()
+        .map(|file| file.file_name().unwrap().to_str().unwrap().to_string())
+        .collect::<Vec<_>>();
+    file_names.sort();
+    assert_eq!(
+        file_names,
+        vec![
+            "decimal.d.mts",
+            "exports.d.mts",
+            "quickjs_decimals_types.d.mts"
+        ]
+    );
+
+    let exports = std::fs::read_to_string(dir.path().join("exports.d.mts"))?;
+    assert!(
+        exports
+            .starts_with("import * as quickjsDecimalsTypes from './quickjs_decimals_types.mjs';\n")
+    );
+    assert!(!exports.contains("declare module"));
+
+    let decimal = std::fs::read_to_string(dir.path().join("decimal.d.mts"))?;
+    assert!(decimal.starts_with("export {};\n\ndeclare global {\n"));
+    assert!(decimal.contains("\n  class Decimal {\n"));
+    Ok(())
+}
+
+#[test]
+fn world_model_describes_js_and_typescript_names() -> anyhow::Result<()> {
+    let dir = Utf8TempDir::new()?;
+    let wit = dir.path().join("wit");
+    std::fs::create_dir_all(&wit)?;
+    std::fs::write(
+        wit.join("model.wit"),
+        indoc!(
+            r#"
+            package quickjs:model;
+
+            interface store {
+              record item-info {
+                item-name: string,
+                default: option<u64>,
+              }
+
+              resource shelf {
+                constructor(label: string);
+                add-item: func(info: item-info) -> u32;
+              }
+
+              get-info: func(id: u32) -> item-info;
+            }
+
+            world model {
+              export store;
+            }
+            "#
+        ),
+    )?;
+
+    let model = analyze_world(&wit, None, &GeneratorOptions::default())?;
+    assert_eq!(model.name, "model");
+    assert_eq!(model.package, "quickjs:model");
+
+    let store = &model.exports[0];
+    assert_eq!(store.wit_name, "quickjs:model/store");
+    assert_eq!(store.js_name, "store");
+
+    let get_info = store
+        .functions
+        .iter()
+        .find(|function| function.wit_name == "get-info")
+        .unwrap();
+    assert_eq!(get_info.kind, FunctionModelKind::Freestanding);
+    assert_eq!(get_info.js_name, "getInfo");
+    assert_eq!(get_info.rust_name, "get_info");
+    assert_eq!(get_info.params[0].ts_type, "number");
+    assert_eq!(get_info.ts_result.as_deref(), Some("ItemInfo"));
+
+    let add_item = store
+        .functions
+        .iter()
+        .find(|function| function.wit_name == "add-item")
+        .unwrap();
+    assert_eq!(
+        add_item.kind,
+        FunctionModelKind::Method {
+            resource: "shelf".to_string()
+        }
+    );
+    assert_eq!(add_item.js_name, "addItem");
+    assert_eq!(add_item.params.len(), 1);
+
+    let item_info = store
+        .types
+        .iter()
+        .find(|typ| typ.wit_name == "item-info")
+        .unwrap();
+    assert_eq!(item_info.ts_name, "ItemInfo");
+    assert_eq!(
+        item_info.kind,
+        TypeModelKind::Record {
+            fields: vec![
+                FieldModel {
+                    wit_name: "item-name".to_string(),
+                    js_name: "itemName".to_string(),
+                },
+                FieldModel {
+                    wit_name: "default".to_string(),
+                    js_name: "default_".to_string(),
+                },
+            ]
+        }
+    );
+    Ok(())
+}
+
+#[test]
+fn dts_can_brand_owned_and_borrowed_handles() -> anyhow::Result<()> {
+    let dir = Utf8TempDir::new()?;
+    let options = GeneratorOptions {
+        dts_branded_handles: true,
+        omit_generated_headers: true,
+        ..example_generator_options("example3")
+    };
+
+    generate_dts(
+        Utf8Path::new("examples/example3").join("wit"),
+        dir.path(),
+        None,
+        &options,
+    )?;
+
+    let exports = std::fs::read_to_string(dir.path().join("exports.d.ts"))?;
+    assert!(
+        exports.contains("static compare(h1: BorrowedHello, h2: BorrowedHello): Promise<number>;")
+    );
+    assert!(exports.contains("static merge(h1: OwnedHello, h2: OwnedHello): Promise<OwnedHello>;"));
+    assert!(
+        exports.contains("export function dump(h: BorrowedHello | undefined): Promise<string>;")
+    );
+    assert!(exports.contains("export type OwnedHello = Hello & { readonly __owned: true };"));
+    assert!(exports.contains("export type BorrowedHello = Hello;"));
+    Ok(())
+}
+
+#[test]
+fn dts_can_be_generated_in_nested_layout_with_index() -> anyhow::Result<()> {
+    let dir = Utf8TempDir::new()?;
+    let options = GeneratorOptions {
+        dts_layout: DtsLayout::Nested,
+        dts_module_files: true,
+        omit_generated_headers: true,
+        ..example_generator_options("all-golem-imports")
+    };
+
+    let generated = generate_dts(
+        Utf8Path::new("examples/all-golem-imports").join("wit"),
+        dir.path(),
+        None,
+        &options,
+    )?;
+    assert!(
+        generated
+            .files
+            .contains(&dir.path().join("index.d.ts").into_std_path_buf())
+    );
+    assert!(
+        generated.files.contains(
+            &dir.path()
+                .join("types/wasi_io_0_2_3/streams.d.ts")
+                .into_std_path_buf()
+        )
+    );
+    assert!(!dir.path().join("wasi_io_0_2_3_streams.d.ts").exists());
+
+    let streams = std::fs::read_to_string(dir.path().join("types/wasi_io_0_2_3/streams.d.ts"))?;
+    assert!(
+        streams.contains("import * as wasiIo023Poll from '../../types/wasi_io_0_2_3/poll.js';")
+    );
+
+    let index = std::fs::read_to_string(dir.path().join("index.d.ts"))?;
+    assert!(index.contains("export * as exports from './exports.js';\n"));
+    assert!(
+        index.contains("export * as wasiIo023Streams from './types/wasi_io_0_2_3/streams.js';\n")
+    );
+    assert_eq!(index.lines().count(), generated.files.len() - 1);
+    Ok(())
+}
@@ -1,6 +1,9 @@
 test_r::enable!();
 
-use crate::common::{CompiledTest, TestInstance, invoke_and_capture_output_with_stderr};
+use crate::common::{
+    CompiledTest, FeatureCombination, compile_example, compile_example_variant,
+    invoke_and_capture_output_with_stderr, new_test_instance,
+};
 use camino::Utf8Path;
 use indoc::indoc;
 use std::slice;
@@ -13,7 +16,20 @@ mod common;
 #[test_dep(tagged_as = "errors")]
 fn compiled_errors() -> CompiledTest {
     let path = Utf8Path::new("examples/errors");
-    CompiledTest::new(path, true).expect("Failed to compile errors")
+    compile_example(path, true).expect("Failed to compile errors")
+}
+
+#[test_dep(tagged_as = "partial_records")]
+fn compiled_partial_records() -> CompiledTest {
+    let path = Utf8Path::new("examples/partial-records");
+    compile_example(path, true).expect("Failed to compile partial-records")
+}
+
+#[test_dep(tagged_as = "strict_records")]
+fn compiled_strict_records() -> CompiledTest {
+    let path = Utf8Path::new("examples/partial-records");
+    compile_example_variant(path, "strict-records", FeatureCombination::HttpOnly, true)
+        .expect("Failed to compile strict-records")
 }
 
 #[test]
@@ -32,7 +48,7 @@ async fn missing_exported_top_level_function_in_js(
     assert!(stderr.contains(indoc!(
         r#"Cannot find exported JS function fun1 of WIT package quickjs:errors
            Provided exports:
-             api, api22, api3, fun3, fun4, fun5, fun6, wrongFun1
+             api, api22, api3, fail, fun3, fun4, fun5, fun6, loneSurrogate, wrongFun1
 
            Try adding an export `export const fun1 = ...`"#
     )));
@@ -56,7 +72,7 @@ async fn missing_exported_interface_function_in_js(
     assert!(stderr.contains(indoc!(
         r#"Cannot find exported JS function api.fun2 of WIT package quickjs:errors
            Provided exports:
-             api, api22, api3, fun3, fun4, fun5, fun6, wrongFun1
+             api, api22, api3, fail, fun3, fun4, fun5, fun6, loneSurrogate, wrongFun1
 
            Keys in api:
              wrongFun2
@@ -83,7 +99,7 @@ async fn missing_exported_interface_in_js(
     assert!(stderr.contains(indoc!(
         r#"Cannot find exported JS function api2.fun7 of WIT package quickjs:errors
            Provided exports:
-             api, api22, api3, fun3, fun4, fun5, fun6, wrongFun1
+             api, api22, api3, fail, fun3, fun4, fun5, fun6, loneSurrogate, wrongFun1
 
            Try adding an export `export const api2 = { ... }`"#
     )));
@@ -143,6 +159,26 @@ async fn js_expects_wrong_parameter_types(
     assert!(result.is_err());
     assert!(stderr.contains("Exception during call of fun5"));
     assert!(stderr.contains("not a function")); // calling a.substring throws an exception
+    assert!(stderr.contains("JavaScript error: TypeError: "));
+
+    Ok(())
+}
+
+#[test]
+async fn js_error_is_reported_with_name_message_and_stack(
+    #[tagged_as("errors")] compiled: &CompiledTest,
+) -> anyhow::Result<()> {
+    let (result, _stdout, stderr) =
+        invoke_and_capture_output_with_stderr(compiled.wasm_path(), None, "fail", &[]).await;
+
+    assert!(result.is_err());
+    assert!(stderr.contains("Exception during call of fail"));
+    assert!(
+        stderr.contains(
+            "JavaScript error: ValidationError: invalid input: x\nStack:\n    at validate"
+        )
+    );
+    assert!(stderr.contains("at fail"));
 
     Ok(())
 }
@@ -162,11 +198,27 @@ async fn js_returns_wrong_type(
     Ok(())
 }
 
+#[test]
+async fn js_returns_string_with_lone_surrogate(
+    #[tagged_as("errors")] compiled: &CompiledTest,
+) -> anyhow::Result<()> {
+    let (result, _stdout, stderr) =
+        invoke_and_capture_output_with_stderr(compiled.wasm_path(), None, "lone-surrogate", &[])
+            .await;
+
+    assert!(result.is_err());
+    assert!(stderr.contains(
+        r#"Unexpected result value for exported function lone-surrogate: FromJs { from: "string", to: "WIT string", message: Some("The string contains lone surrogates, which are not valid Unicode") }"#
+    ));
+
+    Ok(())
+}
+
 #[test]
 async fn wrong_exported_js_class(
     #[tagged_as("errors")] compiled: &CompiledTest,
 ) -> anyhow::Result<()> {
-    let mut instance = TestInstance::new(compiled.wasm_path()).await?;
+    let mut instance = new_test_instance(compiled.wasm_path()).await?;
     let (result, _stdout, stderr) = instance
         .invoke_and_capture_output_with_stderr(
             Some("quickjs:errors/api3"),
@@ -179,7 +231,7 @@ async fn wrong_exported_js_class(
     assert!(stderr.contains(indoc!(
         r#"Cannot find exported JS resource class api3.Res1 of WIT package quickjs:errors
            Provided exports:
-             api, api22, api3, fun3, fun4, fun5, fun6, wrongFun1
+             api, api22, api3, fail, fun3, fun4, fun5, fun6, loneSurrogate, wrongFun1
 
            Keys in api3:
              Res11, Res2, Res3
@@ -194,7 +246,7 @@ async fn wrong_exported_js_class(
 async fn wrong_parameter_count_in_js_constructor(
     #[tagged_as("errors")] compiled: &CompiledTest,
 ) -> anyhow::Result<()> {
-    let mut instance = TestInstance::new(compiled.wasm_path()).await?;
+    let mut instance = new_test_instance(compiled.wasm_path()).await?;
     let (result, _stdout, stderr) = instance
         .invoke_and_capture_output_with_stderr(
             Some("quickjs:errors/api3"),
@@ -216,7 +268,7 @@ async fn wrong_parameter_count_in_js_constructor(
 async fn missing_method_in_js_class(
     #[tagged_as("errors")] compiled: &CompiledTest,
 ) -> anyhow::Result<()> {
-    let mut instance = TestInstance::new(compiled.wasm_path()).await?;
+    let mut instance = new_test_instance(compiled.wasm_path()).await?;
     let (result, _stdout, _stderr) = instance
         .invoke_and_capture_output_with_stderr(
             Some("quickjs:errors/api3"),
@@ -250,7 +302,7 @@ async fn missing_method_in_js_class(
 async fn method_with_wrong_parameter_count_in_js_class(
     #[tagged_as("errors")] compiled: &CompiledTest,
 ) -> anyhow::Result<()> {
-    let mut instance = TestInstance::new(compiled.wasm_path()).await?;
+    let mut instance = new_test_instance(compiled.wasm_path()).await?;
     let (result, _stdout, _stderr) = instance
         .invoke_and_capture_output_with_stderr(
             Some("quickjs:errors/api3"),
@@ -280,7 +332,7 @@ async fn method_with_wrong_parameter_count_in_js_class(
 async fn method_with_wrong_return_type_in_js_class(
     #[tagged_as("errors")] compiled: &CompiledTest,
 ) -> anyhow::Result<()> {
-    let mut instance = TestInstance::new(compiled.wasm_path()).await?;
+    let mut instance = new_test_instance(compiled.wasm_path()).await?;
     let (result, _stdout, _stderr) = instance
         .invoke_and_capture_output_with_stderr(
             Some("quickjs:errors/api3"),
@@ -304,3 +356,61 @@ async fn method_with_wrong_return_type_in_js_class(
     )));
     Ok(())
 }
+
+#[test]
+async fn js_returns_record_with_missing_fields(
+    #[tagged_as("partial_records")] compiled: &CompiledTest,
+) -> anyhow::Result<()> {
+    let (result, _stdout, stderr) = invoke_and_capture_output_with_stderr(
+        compiled.wasm_path(),
+        Some("quickjs:partial-records/api"),
+        "person-without-name-and-age",
+        &[],
+    )
+    .await;
+
+    assert!(result.is_err());
+    assert!(stderr.contains(
+        r#"FromJs { from: "JS object", to: "WIT person", message: Some("Missing required fields: `name` (string), `age` (u32)") }"#
+    ));
+
+    Ok(())
+}
+
+#[test]
+async fn js_returns_nested_value_with_wrong_type(
+    #[tagged_as("partial_records")] compiled: &CompiledTest,
+) -> anyhow::Result<()> {
+    let (result, _stdout, stderr) = invoke_and_capture_output_with_stderr(
+        compiled.wasm_path(),
+        Some("quickjs:partial-records/api"),
+        "orders-with-invalid-zip",
+        &[],
+    )
+    .await;
+
+    assert!(result.is_err());
+    assert!(stderr.contains("[3].customer.address.zip: expected string, got number"));
+
+    Ok(())
+}
+
+#[test]
+async fn js_returns_record_with_extra_properties_in_strict_mode(
+    #[tagged_as("strict_records")] compiled: &CompiledTest,
+) -> anyhow::Result<()> {
+    let (result, _stdout, stderr) = invoke_and_capture_output_with_stderr(
+        compiled.wasm_path(),
+        Some("quickjs:partial-records/api"),
+        "person-with-extra-properties",
+        &[],
+    )
+    .await;
+
+    assert!(result.is_err());
+    assert!(stderr.contains(
+        r#"FromJs { from: "JS object", to: "WIT person", message: Some("Unexpected properties: `email`") }"#
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,6 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:all-golem-imports/all-golem-imports
+// wit: sha256:3ec76077ee8750a4a3d97cf8f89273803d43fba31aa02651185015b5648e2fc7
+// js all-golem-imports: sha256:1aa6d68c08d678e85c1f334bb55fc7062ce81fa0f90be1bc0d6a3965bfccc568
+
+pub static ASSETS: &[(&str, &[u8])] = &[];
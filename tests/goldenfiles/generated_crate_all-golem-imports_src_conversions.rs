@@ -0,0 +1,21204 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:all-golem-imports/all-golem-imports
+// wit: sha256:3ec76077ee8750a4a3d97cf8f89273803d43fba31aa02651185015b5648e2fc7
+// js all-golem-imports: sha256:1aa6d68c08d678e85c1f334bb55fc7062ce81fa0f90be1bc0d6a3965bfccc568
+
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::wasi::io::streams::StreamError {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::wasi::io::streams::StreamError::LastOperationFailed(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::wasi::io::streams::Error,
+                >(ctx, "last-operation-failed", inner)
+            }
+            crate::bindings::wasi::io::streams::StreamError::Closed => {
+                crate::wrappers::variant_case(ctx, "closed")
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::wasi::io::streams::StreamError {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "last-operation-failed" => {
+                let inner: crate::bindings::wasi::io::streams::Error = crate::wrappers::variant_value(
+                    &obj,
+                    "error",
+                )?;
+                Ok(
+                    crate::bindings::wasi::io::streams::StreamError::LastOperationFailed(
+                        inner,
+                    ),
+                )
+            }
+            "closed" => Ok(crate::bindings::wasi::io::streams::StreamError::Closed),
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS stream-error",
+                        "WIT stream-error",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::wasi::clocks::wall_clock::Datetime {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::BigIntWrapper<u64>,
+            >("seconds", crate::wrappers::BigIntWrapper(self.seconds))?;
+        obj.set::<_, u32>("nanoseconds", self.nanoseconds)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::wasi::clocks::wall_clock::Datetime {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT datetime",
+            &["seconds", "nanoseconds"],
+            &[("seconds", "u64"), ("nanoseconds", "u32")],
+            false,
+        )?;
+        let seconds: crate::wrappers::BigIntWrapper<u64> = crate::wrappers::get_field(
+            &obj,
+            "seconds",
+            "u64",
+        )?;
+        let seconds: u64 = seconds.0;
+        let nanoseconds: u32 = crate::wrappers::get_field(&obj, "nanoseconds", "u32")?;
+        let nanoseconds: u32 = nanoseconds;
+        Ok(Self { seconds, nanoseconds })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rpc::types::Uuid {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::BigIntWrapper<u64>,
+            >("highBits", crate::wrappers::BigIntWrapper(self.high_bits))?;
+        obj.set::<
+                _,
+                crate::wrappers::BigIntWrapper<u64>,
+            >("lowBits", crate::wrappers::BigIntWrapper(self.low_bits))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rpc::types::Uuid {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT uuid",
+            &["highBits", "lowBits"],
+            &[("highBits", "u64"), ("lowBits", "u64")],
+            false,
+        )?;
+        let high_bits: crate::wrappers::BigIntWrapper<u64> = crate::wrappers::get_field(
+            &obj,
+            "highBits",
+            "u64",
+        )?;
+        let high_bits: u64 = high_bits.0;
+        let low_bits: crate::wrappers::BigIntWrapper<u64> = crate::wrappers::get_field(
+            &obj,
+            "lowBits",
+            "u64",
+        )?;
+        let low_bits: u64 = low_bits.0;
+        Ok(Self { high_bits, low_bits })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rpc::types::ComponentId {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<_, crate::bindings::golem::rpc::types::Uuid>("uuid", self.uuid)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rpc::types::ComponentId {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT component-id",
+            &["uuid"],
+            &[("uuid", "uuid")],
+            false,
+        )?;
+        let uuid: crate::bindings::golem::rpc::types::Uuid = crate::wrappers::get_field(
+            &obj,
+            "uuid",
+            "uuid",
+        )?;
+        let uuid: crate::bindings::golem::rpc::types::Uuid = uuid;
+        Ok(Self { uuid })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rpc::types::WorkerId {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::golem::rpc::types::ComponentId,
+            >("componentId", self.component_id)?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("workerName", crate::wrappers::JsString(self.worker_name))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rpc::types::WorkerId {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT worker-id",
+            &["componentId", "workerName"],
+            &[("componentId", "component-id"), ("workerName", "string")],
+            false,
+        )?;
+        let component_id: crate::bindings::golem::rpc::types::ComponentId = crate::wrappers::get_field(
+            &obj,
+            "componentId",
+            "component-id",
+        )?;
+        let component_id: crate::bindings::golem::rpc::types::ComponentId = component_id;
+        let worker_name: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "workerName",
+            "string",
+        )?;
+        let worker_name: String = worker_name.0;
+        Ok(Self { component_id, worker_name })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rpc::types::ResourceMode {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::rpc::types::ResourceMode::Owned => {
+                "owned".into_js(ctx)
+            }
+            crate::bindings::golem::rpc::types::ResourceMode::Borrowed => {
+                "borrowed".into_js(ctx)
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rpc::types::ResourceMode {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let value = crate::wrappers::enum_case(
+            value,
+            "JS resource-mode",
+            "WIT resource-mode",
+        )?;
+        match value.as_str() {
+            "owned" => Ok(crate::bindings::golem::rpc::types::ResourceMode::Owned),
+            "borrowed" => Ok(crate::bindings::golem::rpc::types::ResourceMode::Borrowed),
+            _ => {
+                Err(
+                    crate::wrappers::unknown_enum_case(
+                        "JS resource-mode",
+                        "WIT resource-mode",
+                        &value,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rpc::types::WitTypeNode {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::rpc::types::WitTypeNode::RecordType(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsList<
+                        rquickjs::convert::List<(crate::wrappers::JsString, i32)>,
+                    >,
+                >(
+                    ctx,
+                    "record-type",
+                    crate::wrappers::JsList(
+                        inner
+                            .into_iter()
+                            .map(|v| rquickjs::convert::List((
+                                crate::wrappers::JsString(v.0),
+                                v.1,
+                            )))
+                            .collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            crate::bindings::golem::rpc::types::WitTypeNode::VariantType(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsList<
+                        rquickjs::convert::List<(crate::wrappers::JsString, Option<i32>)>,
+                    >,
+                >(
+                    ctx,
+                    "variant-type",
+                    crate::wrappers::JsList(
+                        inner
+                            .into_iter()
+                            .map(|v| rquickjs::convert::List((
+                                crate::wrappers::JsString(v.0),
+                                v.1.map(|v| v),
+                            )))
+                            .collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            crate::bindings::golem::rpc::types::WitTypeNode::EnumType(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsList<crate::wrappers::JsString>,
+                >(
+                    ctx,
+                    "enum-type",
+                    crate::wrappers::JsList(
+                        inner
+                            .into_iter()
+                            .map(|v| crate::wrappers::JsString(v))
+                            .collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            crate::bindings::golem::rpc::types::WitTypeNode::FlagsType(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsList<crate::wrappers::JsString>,
+                >(
+                    ctx,
+                    "flags-type",
+                    crate::wrappers::JsList(
+                        inner
+                            .into_iter()
+                            .map(|v| crate::wrappers::JsString(v))
+                            .collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            crate::bindings::golem::rpc::types::WitTypeNode::TupleType(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsList<i32>,
+                >(
+                    ctx,
+                    "tuple-type",
+                    crate::wrappers::JsList(
+                        inner.into_iter().map(|v| v).collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            crate::bindings::golem::rpc::types::WitTypeNode::ListType(inner) => {
+                crate::wrappers::variant_case_with_value::<i32>(ctx, "list-type", inner)
+            }
+            crate::bindings::golem::rpc::types::WitTypeNode::OptionType(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    i32,
+                >(ctx, "option-type", inner)
+            }
+            crate::bindings::golem::rpc::types::WitTypeNode::ResultType(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    rquickjs::convert::List<(Option<i32>, Option<i32>)>,
+                >(
+                    ctx,
+                    "result-type",
+                    rquickjs::convert::List((inner.0.map(|v| v), inner.1.map(|v| v))),
+                )
+            }
+            crate::bindings::golem::rpc::types::WitTypeNode::PrimU8Type => {
+                crate::wrappers::variant_case(ctx, "prim-u8-type")
+            }
+            crate::bindings::golem::rpc::types::WitTypeNode::PrimU16Type => {
+                crate::wrappers::variant_case(ctx, "prim-u16-type")
+            }
+            crate::bindings::golem::rpc::types::WitTypeNode::PrimU32Type => {
+                crate::wrappers::variant_case(ctx, "prim-u32-type")
+            }
+            crate::bindings::golem::rpc::types::WitTypeNode::PrimU64Type => {
+                crate::wrappers::variant_case(ctx, "prim-u64-type")
+            }
+            crate::bindings::golem::rpc::types::WitTypeNode::PrimS8Type => {
+                crate::wrappers::variant_case(ctx, "prim-s8-type")
+            }
+            crate::bindings::golem::rpc::types::WitTypeNode::PrimS16Type => {
+                crate::wrappers::variant_case(ctx, "prim-s16-type")
+            }
+            crate::bindings::golem::rpc::types::WitTypeNode::PrimS32Type => {
+                crate::wrappers::variant_case(ctx, "prim-s32-type")
+            }
+            crate::bindings::golem::rpc::types::WitTypeNode::PrimS64Type => {
+                crate::wrappers::variant_case(ctx, "prim-s64-type")
+            }
+            crate::bindings::golem::rpc::types::WitTypeNode::PrimF32Type => {
+                crate::wrappers::variant_case(ctx, "prim-f32-type")
+            }
+            crate::bindings::golem::rpc::types::WitTypeNode::PrimF64Type => {
+                crate::wrappers::variant_case(ctx, "prim-f64-type")
+            }
+            crate::bindings::golem::rpc::types::WitTypeNode::PrimCharType => {
+                crate::wrappers::variant_case(ctx, "prim-char-type")
+            }
+            crate::bindings::golem::rpc::types::WitTypeNode::PrimBoolType => {
+                crate::wrappers::variant_case(ctx, "prim-bool-type")
+            }
+            crate::bindings::golem::rpc::types::WitTypeNode::PrimStringType => {
+                crate::wrappers::variant_case(ctx, "prim-string-type")
+            }
+            crate::bindings::golem::rpc::types::WitTypeNode::HandleType(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    rquickjs::convert::List<
+                        (
+                            crate::wrappers::BigIntWrapper<u64>,
+                            crate::bindings::golem::rpc::types::ResourceMode,
+                        ),
+                    >,
+                >(
+                    ctx,
+                    "handle-type",
+                    rquickjs::convert::List((
+                        crate::wrappers::BigIntWrapper(inner.0),
+                        inner.1,
+                    )),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rpc::types::WitTypeNode {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "record-type" => {
+                let inner: crate::wrappers::JsList<
+                    rquickjs::convert::List<(crate::wrappers::JsString, i32)>,
+                > = crate::wrappers::variant_value(
+                    &obj,
+                    "list<tuple<string, node-index>>",
+                )?;
+                Ok(
+                    crate::bindings::golem::rpc::types::WitTypeNode::RecordType(
+                        inner
+                            .0
+                            .into_iter()
+                            .map(|v| (v.0.0.0.clone(), v.0.1))
+                            .collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            "variant-type" => {
+                let inner: crate::wrappers::JsList<
+                    rquickjs::convert::List<(crate::wrappers::JsString, Option<i32>)>,
+                > = crate::wrappers::variant_value(
+                    &obj,
+                    "list<tuple<string, option<node-index>>>",
+                )?;
+                Ok(
+                    crate::bindings::golem::rpc::types::WitTypeNode::VariantType(
+                        inner
+                            .0
+                            .into_iter()
+                            .map(|v| (v.0.0.0.clone(), v.0.1.map(|v| v)))
+                            .collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            "enum-type" => {
+                let inner: crate::wrappers::JsList<crate::wrappers::JsString> = crate::wrappers::variant_value(
+                    &obj,
+                    "list<string>",
+                )?;
+                Ok(
+                    crate::bindings::golem::rpc::types::WitTypeNode::EnumType(
+                        inner.0.into_iter().map(|v| v.0).collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            "flags-type" => {
+                let inner: crate::wrappers::JsList<crate::wrappers::JsString> = crate::wrappers::variant_value(
+                    &obj,
+                    "list<string>",
+                )?;
+                Ok(
+                    crate::bindings::golem::rpc::types::WitTypeNode::FlagsType(
+                        inner.0.into_iter().map(|v| v.0).collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            "tuple-type" => {
+                let inner: crate::wrappers::JsList<i32> = crate::wrappers::variant_value(
+                    &obj,
+                    "list<node-index>",
+                )?;
+                Ok(
+                    crate::bindings::golem::rpc::types::WitTypeNode::TupleType(
+                        inner.0.into_iter().map(|v| v).collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            "list-type" => {
+                let inner: i32 = crate::wrappers::variant_value(&obj, "node-index")?;
+                Ok(crate::bindings::golem::rpc::types::WitTypeNode::ListType(inner))
+            }
+            "option-type" => {
+                let inner: i32 = crate::wrappers::variant_value(&obj, "node-index")?;
+                Ok(crate::bindings::golem::rpc::types::WitTypeNode::OptionType(inner))
+            }
+            "result-type" => {
+                let inner: rquickjs::convert::List<(Option<i32>, Option<i32>)> = crate::wrappers::variant_value(
+                    &obj,
+                    "tuple<option<node-index>, option<node-index>>",
+                )?;
+                Ok(
+                    crate::bindings::golem::rpc::types::WitTypeNode::ResultType((
+                        inner.0.0.map(|v| v),
+                        inner.0.1.map(|v| v),
+                    )),
+                )
+            }
+            "prim-u8-type" => {
+                Ok(crate::bindings::golem::rpc::types::WitTypeNode::PrimU8Type)
+            }
+            "prim-u16-type" => {
+                Ok(crate::bindings::golem::rpc::types::WitTypeNode::PrimU16Type)
+            }
+            "prim-u32-type" => {
+                Ok(crate::bindings::golem::rpc::types::WitTypeNode::PrimU32Type)
+            }
+            "prim-u64-type" => {
+                Ok(crate::bindings::golem::rpc::types::WitTypeNode::PrimU64Type)
+            }
+            "prim-s8-type" => {
+                Ok(crate::bindings::golem::rpc::types::WitTypeNode::PrimS8Type)
+            }
+            "prim-s16-type" => {
+                Ok(crate::bindings::golem::rpc::types::WitTypeNode::PrimS16Type)
+            }
+            "prim-s32-type" => {
+                Ok(crate::bindings::golem::rpc::types::WitTypeNode::PrimS32Type)
+            }
+            "prim-s64-type" => {
+                Ok(crate::bindings::golem::rpc::types::WitTypeNode::PrimS64Type)
+            }
+            "prim-f32-type" => {
+                Ok(crate::bindings::golem::rpc::types::WitTypeNode::PrimF32Type)
+            }
+            "prim-f64-type" => {
+                Ok(crate::bindings::golem::rpc::types::WitTypeNode::PrimF64Type)
+            }
+            "prim-char-type" => {
+                Ok(crate::bindings::golem::rpc::types::WitTypeNode::PrimCharType)
+            }
+            "prim-bool-type" => {
+                Ok(crate::bindings::golem::rpc::types::WitTypeNode::PrimBoolType)
+            }
+            "prim-string-type" => {
+                Ok(crate::bindings::golem::rpc::types::WitTypeNode::PrimStringType)
+            }
+            "handle-type" => {
+                let inner: rquickjs::convert::List<
+                    (
+                        crate::wrappers::BigIntWrapper<u64>,
+                        crate::bindings::golem::rpc::types::ResourceMode,
+                    ),
+                > = crate::wrappers::variant_value(
+                    &obj,
+                    "tuple<resource-id, resource-mode>",
+                )?;
+                Ok(
+                    crate::bindings::golem::rpc::types::WitTypeNode::HandleType((
+                        inner.0.0.0,
+                        inner.0.1,
+                    )),
+                )
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS wit-type-node",
+                        "WIT wit-type-node",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::rpc::types::NamedWitTypeNode {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >("name", self.name.map(|v| crate::wrappers::JsString(v)))?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >("owner", self.owner.map(|v| crate::wrappers::JsString(v)))?;
+        obj.set::<
+                _,
+                crate::bindings::golem::rpc::types::WitTypeNode,
+            >("type", self.type_)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::rpc::types::NamedWitTypeNode {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT named-wit-type-node",
+            &["name", "owner", "type"],
+            &[("type", "wit-type-node")],
+            false,
+        )?;
+        let name: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "name",
+            "option<string>",
+        )?;
+        let name: Option<String> = name.map(|v| v.0);
+        let owner: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "owner",
+            "option<string>",
+        )?;
+        let owner: Option<String> = owner.map(|v| v.0);
+        let type_: crate::bindings::golem::rpc::types::WitTypeNode = crate::wrappers::get_field(
+            &obj,
+            "type",
+            "wit-type-node",
+        )?;
+        let type_: crate::bindings::golem::rpc::types::WitTypeNode = type_;
+        Ok(Self { name, owner, type_ })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rpc::types::WitType {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<
+                    crate::bindings::golem::rpc::types::NamedWitTypeNode,
+                >,
+            >(
+            "nodes",
+            crate::wrappers::JsList(
+                self.nodes.into_iter().map(|v| v).collect::<Vec<_>>(),
+            ),
+        )?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rpc::types::WitType {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT wit-type",
+            &["nodes"],
+            &[("nodes", "list<named-wit-type-node>")],
+            false,
+        )?;
+        let nodes: crate::wrappers::JsList<
+            crate::bindings::golem::rpc::types::NamedWitTypeNode,
+        > = crate::wrappers::get_field(&obj, "nodes", "list<named-wit-type-node>")?;
+        let nodes: Vec<crate::bindings::golem::rpc::types::NamedWitTypeNode> = nodes
+            .0
+            .into_iter()
+            .map(|v| v)
+            .collect::<Vec<_>>();
+        Ok(Self { nodes })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rpc::types::Uri {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("value", crate::wrappers::JsString(self.value))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rpc::types::Uri {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT uri",
+            &["value"],
+            &[("value", "string")],
+            false,
+        )?;
+        let value: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "value",
+            "string",
+        )?;
+        let value: String = value.0;
+        Ok(Self { value })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rpc::types::WitNode {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::rpc::types::WitNode::RecordValue(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsList<i32>,
+                >(
+                    ctx,
+                    "record-value",
+                    crate::wrappers::JsList(
+                        inner.into_iter().map(|v| v).collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            crate::bindings::golem::rpc::types::WitNode::VariantValue(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    rquickjs::convert::List<(u32, Option<i32>)>,
+                >(
+                    ctx,
+                    "variant-value",
+                    rquickjs::convert::List((inner.0, inner.1.map(|v| v))),
+                )
+            }
+            crate::bindings::golem::rpc::types::WitNode::EnumValue(inner) => {
+                crate::wrappers::variant_case_with_value::<u32>(ctx, "enum-value", inner)
+            }
+            crate::bindings::golem::rpc::types::WitNode::FlagsValue(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsList<bool>,
+                >(
+                    ctx,
+                    "flags-value",
+                    crate::wrappers::JsList(
+                        inner.into_iter().map(|v| v).collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            crate::bindings::golem::rpc::types::WitNode::TupleValue(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsList<i32>,
+                >(
+                    ctx,
+                    "tuple-value",
+                    crate::wrappers::JsList(
+                        inner.into_iter().map(|v| v).collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            crate::bindings::golem::rpc::types::WitNode::ListValue(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsList<i32>,
+                >(
+                    ctx,
+                    "list-value",
+                    crate::wrappers::JsList(
+                        inner.into_iter().map(|v| v).collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            crate::bindings::golem::rpc::types::WitNode::OptionValue(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    Option<i32>,
+                >(ctx, "option-value", inner.map(|v| v))
+            }
+            crate::bindings::golem::rpc::types::WitNode::ResultValue(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsResult<Option<i32>, Option<i32>>,
+                >(
+                    ctx,
+                    "result-value",
+                    crate::wrappers::JsResult(
+                        match inner {
+                            Ok(v) => Ok(v.map(|v| v)),
+                            Err(v) => Err(v.map(|v| v)),
+                        },
+                    ),
+                )
+            }
+            crate::bindings::golem::rpc::types::WitNode::PrimU8(inner) => {
+                crate::wrappers::variant_case_with_value::<u8>(ctx, "prim-u8", inner)
+            }
+            crate::bindings::golem::rpc::types::WitNode::PrimU16(inner) => {
+                crate::wrappers::variant_case_with_value::<u16>(ctx, "prim-u16", inner)
+            }
+            crate::bindings::golem::rpc::types::WitNode::PrimU32(inner) => {
+                crate::wrappers::variant_case_with_value::<u32>(ctx, "prim-u32", inner)
+            }
+            crate::bindings::golem::rpc::types::WitNode::PrimU64(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::BigIntWrapper<u64>,
+                >(ctx, "prim-u64", crate::wrappers::BigIntWrapper(inner))
+            }
+            crate::bindings::golem::rpc::types::WitNode::PrimS8(inner) => {
+                crate::wrappers::variant_case_with_value::<i8>(ctx, "prim-s8", inner)
+            }
+            crate::bindings::golem::rpc::types::WitNode::PrimS16(inner) => {
+                crate::wrappers::variant_case_with_value::<i16>(ctx, "prim-s16", inner)
+            }
+            crate::bindings::golem::rpc::types::WitNode::PrimS32(inner) => {
+                crate::wrappers::variant_case_with_value::<i32>(ctx, "prim-s32", inner)
+            }
+            crate::bindings::golem::rpc::types::WitNode::PrimS64(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::BigIntWrapper<i64>,
+                >(ctx, "prim-s64", crate::wrappers::BigIntWrapper(inner))
+            }
+            crate::bindings::golem::rpc::types::WitNode::PrimFloat32(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    f32,
+                >(ctx, "prim-float32", inner)
+            }
+            crate::bindings::golem::rpc::types::WitNode::PrimFloat64(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    f64,
+                >(ctx, "prim-float64", inner)
+            }
+            crate::bindings::golem::rpc::types::WitNode::PrimChar(inner) => {
+                crate::wrappers::variant_case_with_value::<char>(ctx, "prim-char", inner)
+            }
+            crate::bindings::golem::rpc::types::WitNode::PrimBool(inner) => {
+                crate::wrappers::variant_case_with_value::<bool>(ctx, "prim-bool", inner)
+            }
+            crate::bindings::golem::rpc::types::WitNode::PrimString(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "prim-string", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::rpc::types::WitNode::Handle(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    rquickjs::convert::List<
+                        (
+                            crate::bindings::golem::rpc::types::Uri,
+                            crate::wrappers::BigIntWrapper<u64>,
+                        ),
+                    >,
+                >(
+                    ctx,
+                    "handle",
+                    rquickjs::convert::List((
+                        inner.0,
+                        crate::wrappers::BigIntWrapper(inner.1),
+                    )),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rpc::types::WitNode {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "record-value" => {
+                let inner: crate::wrappers::JsList<i32> = crate::wrappers::variant_value(
+                    &obj,
+                    "list<node-index>",
+                )?;
+                Ok(
+                    crate::bindings::golem::rpc::types::WitNode::RecordValue(
+                        inner.0.into_iter().map(|v| v).collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            "variant-value" => {
+                let inner: rquickjs::convert::List<(u32, Option<i32>)> = crate::wrappers::variant_value(
+                    &obj,
+                    "tuple<u32, option<node-index>>",
+                )?;
+                Ok(
+                    crate::bindings::golem::rpc::types::WitNode::VariantValue((
+                        inner.0.0,
+                        inner.0.1.map(|v| v),
+                    )),
+                )
+            }
+            "enum-value" => {
+                let inner: u32 = crate::wrappers::variant_value(&obj, "u32")?;
+                Ok(crate::bindings::golem::rpc::types::WitNode::EnumValue(inner))
+            }
+            "flags-value" => {
+                let inner: crate::wrappers::JsList<bool> = crate::wrappers::variant_value(
+                    &obj,
+                    "list<bool>",
+                )?;
+                Ok(
+                    crate::bindings::golem::rpc::types::WitNode::FlagsValue(
+                        inner.0.into_iter().map(|v| v).collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            "tuple-value" => {
+                let inner: crate::wrappers::JsList<i32> = crate::wrappers::variant_value(
+                    &obj,
+                    "list<node-index>",
+                )?;
+                Ok(
+                    crate::bindings::golem::rpc::types::WitNode::TupleValue(
+                        inner.0.into_iter().map(|v| v).collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            "list-value" => {
+                let inner: crate::wrappers::JsList<i32> = crate::wrappers::variant_value(
+                    &obj,
+                    "list<node-index>",
+                )?;
+                Ok(
+                    crate::bindings::golem::rpc::types::WitNode::ListValue(
+                        inner.0.into_iter().map(|v| v).collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            "option-value" => {
+                let inner: Option<i32> = crate::wrappers::variant_value(
+                    &obj,
+                    "option<node-index>",
+                )?;
+                Ok(
+                    crate::bindings::golem::rpc::types::WitNode::OptionValue(
+                        inner.map(|v| v),
+                    ),
+                )
+            }
+            "result-value" => {
+                let inner: crate::wrappers::JsResult<Option<i32>, Option<i32>> = crate::wrappers::variant_value(
+                    &obj,
+                    "result<option<node-index>, option<node-index>>",
+                )?;
+                Ok(
+                    crate::bindings::golem::rpc::types::WitNode::ResultValue(
+                        inner.0.map(|v| v.map(|v| v)).map_err(|v| v.map(|v| v)),
+                    ),
+                )
+            }
+            "prim-u8" => {
+                let inner: u8 = crate::wrappers::variant_value(&obj, "u8")?;
+                Ok(crate::bindings::golem::rpc::types::WitNode::PrimU8(inner))
+            }
+            "prim-u16" => {
+                let inner: u16 = crate::wrappers::variant_value(&obj, "u16")?;
+                Ok(crate::bindings::golem::rpc::types::WitNode::PrimU16(inner))
+            }
+            "prim-u32" => {
+                let inner: u32 = crate::wrappers::variant_value(&obj, "u32")?;
+                Ok(crate::bindings::golem::rpc::types::WitNode::PrimU32(inner))
+            }
+            "prim-u64" => {
+                let inner: crate::wrappers::BigIntWrapper<u64> = crate::wrappers::variant_value(
+                    &obj,
+                    "u64",
+                )?;
+                Ok(crate::bindings::golem::rpc::types::WitNode::PrimU64(inner.0))
+            }
+            "prim-s8" => {
+                let inner: i8 = crate::wrappers::variant_value(&obj, "s8")?;
+                Ok(crate::bindings::golem::rpc::types::WitNode::PrimS8(inner))
+            }
+            "prim-s16" => {
+                let inner: i16 = crate::wrappers::variant_value(&obj, "s16")?;
+                Ok(crate::bindings::golem::rpc::types::WitNode::PrimS16(inner))
+            }
+            "prim-s32" => {
+                let inner: i32 = crate::wrappers::variant_value(&obj, "s32")?;
+                Ok(crate::bindings::golem::rpc::types::WitNode::PrimS32(inner))
+            }
+            "prim-s64" => {
+                let inner: crate::wrappers::BigIntWrapper<i64> = crate::wrappers::variant_value(
+                    &obj,
+                    "s64",
+                )?;
+                Ok(crate::bindings::golem::rpc::types::WitNode::PrimS64(inner.0))
+            }
+            "prim-float32" => {
+                let inner: f32 = crate::wrappers::variant_value(&obj, "f32")?;
+                Ok(crate::bindings::golem::rpc::types::WitNode::PrimFloat32(inner))
+            }
+            "prim-float64" => {
+                let inner: f64 = crate::wrappers::variant_value(&obj, "f64")?;
+                Ok(crate::bindings::golem::rpc::types::WitNode::PrimFloat64(inner))
+            }
+            "prim-char" => {
+                let inner: char = crate::wrappers::variant_value(&obj, "char")?;
+                Ok(crate::bindings::golem::rpc::types::WitNode::PrimChar(inner))
+            }
+            "prim-bool" => {
+                let inner: bool = crate::wrappers::variant_value(&obj, "bool")?;
+                Ok(crate::bindings::golem::rpc::types::WitNode::PrimBool(inner))
+            }
+            "prim-string" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(crate::bindings::golem::rpc::types::WitNode::PrimString(inner.0))
+            }
+            "handle" => {
+                let inner: rquickjs::convert::List<
+                    (
+                        crate::bindings::golem::rpc::types::Uri,
+                        crate::wrappers::BigIntWrapper<u64>,
+                    ),
+                > = crate::wrappers::variant_value(&obj, "tuple<uri, u64>")?;
+                Ok(
+                    crate::bindings::golem::rpc::types::WitNode::Handle((
+                        inner.0.0,
+                        inner.0.1.0,
+                    )),
+                )
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS wit-node",
+                        "WIT wit-node",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rpc::types::WitValue {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<crate::bindings::golem::rpc::types::WitNode>,
+            >(
+            "nodes",
+            crate::wrappers::JsList(
+                self.nodes.into_iter().map(|v| v).collect::<Vec<_>>(),
+            ),
+        )?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rpc::types::WitValue {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT wit-value",
+            &["nodes"],
+            &[("nodes", "list<wit-node>")],
+            false,
+        )?;
+        let nodes: crate::wrappers::JsList<
+            crate::bindings::golem::rpc::types::WitNode,
+        > = crate::wrappers::get_field(&obj, "nodes", "list<wit-node>")?;
+        let nodes: Vec<crate::bindings::golem::rpc::types::WitNode> = nodes
+            .0
+            .into_iter()
+            .map(|v| v)
+            .collect::<Vec<_>>();
+        Ok(Self { nodes })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rpc::types::ValueAndType {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<_, crate::bindings::golem::rpc::types::WitValue>("value", self.value)?;
+        obj.set::<_, crate::bindings::golem::rpc::types::WitType>("typ", self.typ)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rpc::types::ValueAndType {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT value-and-type",
+            &["value", "typ"],
+            &[("value", "wit-value"), ("typ", "wit-type")],
+            false,
+        )?;
+        let value: crate::bindings::golem::rpc::types::WitValue = crate::wrappers::get_field(
+            &obj,
+            "value",
+            "wit-value",
+        )?;
+        let value: crate::bindings::golem::rpc::types::WitValue = value;
+        let typ: crate::bindings::golem::rpc::types::WitType = crate::wrappers::get_field(
+            &obj,
+            "typ",
+            "wit-type",
+        )?;
+        let typ: crate::bindings::golem::rpc::types::WitType = typ;
+        Ok(Self { value, typ })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rpc::types::RpcError {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::rpc::types::RpcError::ProtocolError(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "protocol-error", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::rpc::types::RpcError::Denied(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "denied", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::rpc::types::RpcError::NotFound(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "not-found", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::rpc::types::RpcError::RemoteInternalError(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "remote-internal-error", crate::wrappers::JsString(inner))
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rpc::types::RpcError {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "protocol-error" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(crate::bindings::golem::rpc::types::RpcError::ProtocolError(inner.0))
+            }
+            "denied" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(crate::bindings::golem::rpc::types::RpcError::Denied(inner.0))
+            }
+            "not-found" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(crate::bindings::golem::rpc::types::RpcError::NotFound(inner.0))
+            }
+            "remote-internal-error" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(
+                    crate::bindings::golem::rpc::types::RpcError::RemoteInternalError(
+                        inner.0,
+                    ),
+                )
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS rpc-error",
+                        "WIT rpc-error",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::api::context::AttributeValue {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::api::context::AttributeValue::String(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "string", crate::wrappers::JsString(inner))
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::api::context::AttributeValue {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "string" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(crate::bindings::golem::api::context::AttributeValue::String(inner.0))
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS attribute-value",
+                        "WIT attribute-value",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::api::context::Attribute {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("key", crate::wrappers::JsString(self.key))?;
+        obj.set::<
+                _,
+                crate::bindings::golem::api::context::AttributeValue,
+            >("value", self.value)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::api::context::Attribute {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT attribute",
+            &["key", "value"],
+            &[("key", "string"), ("value", "attribute-value")],
+            false,
+        )?;
+        let key: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "key",
+            "string",
+        )?;
+        let key: String = key.0;
+        let value: crate::bindings::golem::api::context::AttributeValue = crate::wrappers::get_field(
+            &obj,
+            "value",
+            "attribute-value",
+        )?;
+        let value: crate::bindings::golem::api::context::AttributeValue = value;
+        Ok(Self { key, value })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::api::context::AttributeChain {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("key", crate::wrappers::JsString(self.key))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<
+                    crate::bindings::golem::api::context::AttributeValue,
+                >,
+            >(
+            "values",
+            crate::wrappers::JsList(
+                self.values.into_iter().map(|v| v).collect::<Vec<_>>(),
+            ),
+        )?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::api::context::AttributeChain {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT attribute-chain",
+            &["key", "values"],
+            &[("key", "string"), ("values", "list<attribute-value>")],
+            false,
+        )?;
+        let key: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "key",
+            "string",
+        )?;
+        let key: String = key.0;
+        let values: crate::wrappers::JsList<
+            crate::bindings::golem::api::context::AttributeValue,
+        > = crate::wrappers::get_field(&obj, "values", "list<attribute-value>")?;
+        let values: Vec<crate::bindings::golem::api::context::AttributeValue> = values
+            .0
+            .into_iter()
+            .map(|v| v)
+            .collect::<Vec<_>>();
+        Ok(Self { key, values })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::api::host::PromiseId {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::golem::rpc::types::WorkerId,
+            >("workerId", self.worker_id)?;
+        obj.set::<
+                _,
+                crate::wrappers::BigIntWrapper<u64>,
+            >("oplogIdx", crate::wrappers::BigIntWrapper(self.oplog_idx))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::api::host::PromiseId {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT promise-id",
+            &["workerId", "oplogIdx"],
+            &[("workerId", "worker-id"), ("oplogIdx", "oplog-index")],
+            false,
+        )?;
+        let worker_id: crate::bindings::golem::rpc::types::WorkerId = crate::wrappers::get_field(
+            &obj,
+            "workerId",
+            "worker-id",
+        )?;
+        let worker_id: crate::bindings::golem::api::host::WorkerId = worker_id;
+        let oplog_idx: crate::wrappers::BigIntWrapper<u64> = crate::wrappers::get_field(
+            &obj,
+            "oplogIdx",
+            "oplog-index",
+        )?;
+        let oplog_idx: crate::bindings::golem::api::host::OplogIndex = oplog_idx.0;
+        Ok(Self { worker_id, oplog_idx })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::api::host::RetryPolicy {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<_, u32>("maxAttempts", self.max_attempts)?;
+        obj.set::<
+                _,
+                crate::wrappers::BigIntWrapper<u64>,
+            >("minDelay", crate::wrappers::BigIntWrapper(self.min_delay))?;
+        obj.set::<
+                _,
+                crate::wrappers::BigIntWrapper<u64>,
+            >("maxDelay", crate::wrappers::BigIntWrapper(self.max_delay))?;
+        obj.set::<_, f64>("multiplier", self.multiplier)?;
+        obj.set::<_, Option<f64>>("maxJitterFactor", self.max_jitter_factor.map(|v| v))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::api::host::RetryPolicy {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT retry-policy",
+            &["maxAttempts", "minDelay", "maxDelay", "multiplier", "maxJitterFactor"],
+            &[
+                ("maxAttempts", "u32"),
+                ("minDelay", "duration"),
+                ("maxDelay", "duration"),
+                ("multiplier", "f64"),
+            ],
+            false,
+        )?;
+        let max_attempts: u32 = crate::wrappers::get_field(&obj, "maxAttempts", "u32")?;
+        let max_attempts: u32 = max_attempts;
+        let min_delay: crate::wrappers::BigIntWrapper<u64> = crate::wrappers::get_field(
+            &obj,
+            "minDelay",
+            "duration",
+        )?;
+        let min_delay: crate::bindings::golem::api::host::Duration = min_delay.0;
+        let max_delay: crate::wrappers::BigIntWrapper<u64> = crate::wrappers::get_field(
+            &obj,
+            "maxDelay",
+            "duration",
+        )?;
+        let max_delay: crate::bindings::golem::api::host::Duration = max_delay.0;
+        let multiplier: f64 = crate::wrappers::get_field(&obj, "multiplier", "f64")?;
+        let multiplier: f64 = multiplier;
+        let max_jitter_factor: Option<f64> = crate::wrappers::get_field(
+            &obj,
+            "maxJitterFactor",
+            "option<f64>",
+        )?;
+        let max_jitter_factor: Option<f64> = max_jitter_factor.map(|v| v);
+        Ok(Self {
+            max_attempts,
+            min_delay,
+            max_delay,
+            multiplier,
+            max_jitter_factor,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::api::host::PersistenceLevel {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::api::host::PersistenceLevel::PersistNothing => {
+                crate::wrappers::variant_case(ctx, "persist-nothing")
+            }
+            crate::bindings::golem::api::host::PersistenceLevel::PersistRemoteSideEffects => {
+                crate::wrappers::variant_case(ctx, "persist-remote-side-effects")
+            }
+            crate::bindings::golem::api::host::PersistenceLevel::Smart => {
+                crate::wrappers::variant_case(ctx, "smart")
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::api::host::PersistenceLevel {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (_, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "persist-nothing" => {
+                Ok(crate::bindings::golem::api::host::PersistenceLevel::PersistNothing)
+            }
+            "persist-remote-side-effects" => {
+                Ok(
+                    crate::bindings::golem::api::host::PersistenceLevel::PersistRemoteSideEffects,
+                )
+            }
+            "smart" => Ok(crate::bindings::golem::api::host::PersistenceLevel::Smart),
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS persistence-level",
+                        "WIT persistence-level",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::api::host::UpdateMode {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::api::host::UpdateMode::Automatic => {
+                "automatic".into_js(ctx)
+            }
+            crate::bindings::golem::api::host::UpdateMode::SnapshotBased => {
+                "snapshot-based".into_js(ctx)
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::api::host::UpdateMode {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let value = crate::wrappers::enum_case(
+            value,
+            "JS update-mode",
+            "WIT update-mode",
+        )?;
+        match value.as_str() {
+            "automatic" => Ok(crate::bindings::golem::api::host::UpdateMode::Automatic),
+            "snapshot-based" => {
+                Ok(crate::bindings::golem::api::host::UpdateMode::SnapshotBased)
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_enum_case(
+                        "JS update-mode",
+                        "WIT update-mode",
+                        &value,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::api::host::FilterComparator {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::api::host::FilterComparator::Equal => {
+                "equal".into_js(ctx)
+            }
+            crate::bindings::golem::api::host::FilterComparator::NotEqual => {
+                "not-equal".into_js(ctx)
+            }
+            crate::bindings::golem::api::host::FilterComparator::GreaterEqual => {
+                "greater-equal".into_js(ctx)
+            }
+            crate::bindings::golem::api::host::FilterComparator::Greater => {
+                "greater".into_js(ctx)
+            }
+            crate::bindings::golem::api::host::FilterComparator::LessEqual => {
+                "less-equal".into_js(ctx)
+            }
+            crate::bindings::golem::api::host::FilterComparator::Less => {
+                "less".into_js(ctx)
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::api::host::FilterComparator {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let value = crate::wrappers::enum_case(
+            value,
+            "JS filter-comparator",
+            "WIT filter-comparator",
+        )?;
+        match value.as_str() {
+            "equal" => Ok(crate::bindings::golem::api::host::FilterComparator::Equal),
+            "not-equal" => {
+                Ok(crate::bindings::golem::api::host::FilterComparator::NotEqual)
+            }
+            "greater-equal" => {
+                Ok(crate::bindings::golem::api::host::FilterComparator::GreaterEqual)
+            }
+            "greater" => Ok(crate::bindings::golem::api::host::FilterComparator::Greater),
+            "less-equal" => {
+                Ok(crate::bindings::golem::api::host::FilterComparator::LessEqual)
+            }
+            "less" => Ok(crate::bindings::golem::api::host::FilterComparator::Less),
+            _ => {
+                Err(
+                    crate::wrappers::unknown_enum_case(
+                        "JS filter-comparator",
+                        "WIT filter-comparator",
+                        &value,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::api::host::StringFilterComparator {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::api::host::StringFilterComparator::Equal => {
+                "equal".into_js(ctx)
+            }
+            crate::bindings::golem::api::host::StringFilterComparator::NotEqual => {
+                "not-equal".into_js(ctx)
+            }
+            crate::bindings::golem::api::host::StringFilterComparator::Like => {
+                "like".into_js(ctx)
+            }
+            crate::bindings::golem::api::host::StringFilterComparator::NotLike => {
+                "not-like".into_js(ctx)
+            }
+            crate::bindings::golem::api::host::StringFilterComparator::StartsWith => {
+                "starts-with".into_js(ctx)
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::api::host::StringFilterComparator {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let value = crate::wrappers::enum_case(
+            value,
+            "JS string-filter-comparator",
+            "WIT string-filter-comparator",
+        )?;
+        match value.as_str() {
+            "equal" => {
+                Ok(crate::bindings::golem::api::host::StringFilterComparator::Equal)
+            }
+            "not-equal" => {
+                Ok(crate::bindings::golem::api::host::StringFilterComparator::NotEqual)
+            }
+            "like" => Ok(crate::bindings::golem::api::host::StringFilterComparator::Like),
+            "not-like" => {
+                Ok(crate::bindings::golem::api::host::StringFilterComparator::NotLike)
+            }
+            "starts-with" => {
+                Ok(crate::bindings::golem::api::host::StringFilterComparator::StartsWith)
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_enum_case(
+                        "JS string-filter-comparator",
+                        "WIT string-filter-comparator",
+                        &value,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::api::host::WorkerStatus {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::api::host::WorkerStatus::Running => {
+                "running".into_js(ctx)
+            }
+            crate::bindings::golem::api::host::WorkerStatus::Idle => "idle".into_js(ctx),
+            crate::bindings::golem::api::host::WorkerStatus::Suspended => {
+                "suspended".into_js(ctx)
+            }
+            crate::bindings::golem::api::host::WorkerStatus::Interrupted => {
+                "interrupted".into_js(ctx)
+            }
+            crate::bindings::golem::api::host::WorkerStatus::Retrying => {
+                "retrying".into_js(ctx)
+            }
+            crate::bindings::golem::api::host::WorkerStatus::Failed => {
+                "failed".into_js(ctx)
+            }
+            crate::bindings::golem::api::host::WorkerStatus::Exited => {
+                "exited".into_js(ctx)
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::api::host::WorkerStatus {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let value = crate::wrappers::enum_case(
+            value,
+            "JS worker-status",
+            "WIT worker-status",
+        )?;
+        match value.as_str() {
+            "running" => Ok(crate::bindings::golem::api::host::WorkerStatus::Running),
+            "idle" => Ok(crate::bindings::golem::api::host::WorkerStatus::Idle),
+            "suspended" => Ok(crate::bindings::golem::api::host::WorkerStatus::Suspended),
+            "interrupted" => {
+                Ok(crate::bindings::golem::api::host::WorkerStatus::Interrupted)
+            }
+            "retrying" => Ok(crate::bindings::golem::api::host::WorkerStatus::Retrying),
+            "failed" => Ok(crate::bindings::golem::api::host::WorkerStatus::Failed),
+            "exited" => Ok(crate::bindings::golem::api::host::WorkerStatus::Exited),
+            _ => {
+                Err(
+                    crate::wrappers::unknown_enum_case(
+                        "JS worker-status",
+                        "WIT worker-status",
+                        &value,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::api::host::WorkerNameFilter {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::golem::api::host::StringFilterComparator,
+            >("comparator", self.comparator)?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("value", crate::wrappers::JsString(self.value))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::api::host::WorkerNameFilter {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT worker-name-filter",
+            &["comparator", "value"],
+            &[("comparator", "string-filter-comparator"), ("value", "string")],
+            false,
+        )?;
+        let comparator: crate::bindings::golem::api::host::StringFilterComparator = crate::wrappers::get_field(
+            &obj,
+            "comparator",
+            "string-filter-comparator",
+        )?;
+        let comparator: crate::bindings::golem::api::host::StringFilterComparator = comparator;
+        let value: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "value",
+            "string",
+        )?;
+        let value: String = value.0;
+        Ok(Self { comparator, value })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::api::host::WorkerStatusFilter {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::golem::api::host::FilterComparator,
+            >("comparator", self.comparator)?;
+        obj.set::<
+                _,
+                crate::bindings::golem::api::host::WorkerStatus,
+            >("value", self.value)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::api::host::WorkerStatusFilter {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT worker-status-filter",
+            &["comparator", "value"],
+            &[("comparator", "filter-comparator"), ("value", "worker-status")],
+            false,
+        )?;
+        let comparator: crate::bindings::golem::api::host::FilterComparator = crate::wrappers::get_field(
+            &obj,
+            "comparator",
+            "filter-comparator",
+        )?;
+        let comparator: crate::bindings::golem::api::host::FilterComparator = comparator;
+        let value: crate::bindings::golem::api::host::WorkerStatus = crate::wrappers::get_field(
+            &obj,
+            "value",
+            "worker-status",
+        )?;
+        let value: crate::bindings::golem::api::host::WorkerStatus = value;
+        Ok(Self { comparator, value })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::api::host::WorkerVersionFilter {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::golem::api::host::FilterComparator,
+            >("comparator", self.comparator)?;
+        obj.set::<
+                _,
+                crate::wrappers::BigIntWrapper<u64>,
+            >("value", crate::wrappers::BigIntWrapper(self.value))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::api::host::WorkerVersionFilter {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT worker-version-filter",
+            &["comparator", "value"],
+            &[("comparator", "filter-comparator"), ("value", "u64")],
+            false,
+        )?;
+        let comparator: crate::bindings::golem::api::host::FilterComparator = crate::wrappers::get_field(
+            &obj,
+            "comparator",
+            "filter-comparator",
+        )?;
+        let comparator: crate::bindings::golem::api::host::FilterComparator = comparator;
+        let value: crate::wrappers::BigIntWrapper<u64> = crate::wrappers::get_field(
+            &obj,
+            "value",
+            "u64",
+        )?;
+        let value: u64 = value.0;
+        Ok(Self { comparator, value })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::api::host::WorkerCreatedAtFilter {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::golem::api::host::FilterComparator,
+            >("comparator", self.comparator)?;
+        obj.set::<
+                _,
+                crate::wrappers::BigIntWrapper<u64>,
+            >("value", crate::wrappers::BigIntWrapper(self.value))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::api::host::WorkerCreatedAtFilter {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT worker-created-at-filter",
+            &["comparator", "value"],
+            &[("comparator", "filter-comparator"), ("value", "u64")],
+            false,
+        )?;
+        let comparator: crate::bindings::golem::api::host::FilterComparator = crate::wrappers::get_field(
+            &obj,
+            "comparator",
+            "filter-comparator",
+        )?;
+        let comparator: crate::bindings::golem::api::host::FilterComparator = comparator;
+        let value: crate::wrappers::BigIntWrapper<u64> = crate::wrappers::get_field(
+            &obj,
+            "value",
+            "u64",
+        )?;
+        let value: u64 = value.0;
+        Ok(Self { comparator, value })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::api::host::WorkerEnvFilter {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("name", crate::wrappers::JsString(self.name))?;
+        obj.set::<
+                _,
+                crate::bindings::golem::api::host::StringFilterComparator,
+            >("comparator", self.comparator)?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("value", crate::wrappers::JsString(self.value))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::api::host::WorkerEnvFilter {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT worker-env-filter",
+            &["name", "comparator", "value"],
+            &[
+                ("name", "string"),
+                ("comparator", "string-filter-comparator"),
+                ("value", "string"),
+            ],
+            false,
+        )?;
+        let name: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "name",
+            "string",
+        )?;
+        let name: String = name.0;
+        let comparator: crate::bindings::golem::api::host::StringFilterComparator = crate::wrappers::get_field(
+            &obj,
+            "comparator",
+            "string-filter-comparator",
+        )?;
+        let comparator: crate::bindings::golem::api::host::StringFilterComparator = comparator;
+        let value: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "value",
+            "string",
+        )?;
+        let value: String = value.0;
+        Ok(Self { name, comparator, value })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::api::host::WorkerWasiConfigVarsFilter {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("name", crate::wrappers::JsString(self.name))?;
+        obj.set::<
+                _,
+                crate::bindings::golem::api::host::StringFilterComparator,
+            >("comparator", self.comparator)?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("value", crate::wrappers::JsString(self.value))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::api::host::WorkerWasiConfigVarsFilter {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT worker-wasi-config-vars-filter",
+            &["name", "comparator", "value"],
+            &[
+                ("name", "string"),
+                ("comparator", "string-filter-comparator"),
+                ("value", "string"),
+            ],
+            false,
+        )?;
+        let name: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "name",
+            "string",
+        )?;
+        let name: String = name.0;
+        let comparator: crate::bindings::golem::api::host::StringFilterComparator = crate::wrappers::get_field(
+            &obj,
+            "comparator",
+            "string-filter-comparator",
+        )?;
+        let comparator: crate::bindings::golem::api::host::StringFilterComparator = comparator;
+        let value: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "value",
+            "string",
+        )?;
+        let value: String = value.0;
+        Ok(Self { name, comparator, value })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::api::host::WorkerPropertyFilter {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::api::host::WorkerPropertyFilter::Name(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::api::host::WorkerNameFilter,
+                >(ctx, "name", inner)
+            }
+            crate::bindings::golem::api::host::WorkerPropertyFilter::Status(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::api::host::WorkerStatusFilter,
+                >(ctx, "status", inner)
+            }
+            crate::bindings::golem::api::host::WorkerPropertyFilter::Version(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::api::host::WorkerVersionFilter,
+                >(ctx, "version", inner)
+            }
+            crate::bindings::golem::api::host::WorkerPropertyFilter::CreatedAt(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::api::host::WorkerCreatedAtFilter,
+                >(ctx, "created-at", inner)
+            }
+            crate::bindings::golem::api::host::WorkerPropertyFilter::Env(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::api::host::WorkerEnvFilter,
+                >(ctx, "env", inner)
+            }
+            crate::bindings::golem::api::host::WorkerPropertyFilter::WasiConfigVars(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::api::host::WorkerWasiConfigVarsFilter,
+                >(ctx, "wasi-config-vars", inner)
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::api::host::WorkerPropertyFilter {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "name" => {
+                let inner: crate::bindings::golem::api::host::WorkerNameFilter = crate::wrappers::variant_value(
+                    &obj,
+                    "worker-name-filter",
+                )?;
+                Ok(crate::bindings::golem::api::host::WorkerPropertyFilter::Name(inner))
+            }
+            "status" => {
+                let inner: crate::bindings::golem::api::host::WorkerStatusFilter = crate::wrappers::variant_value(
+                    &obj,
+                    "worker-status-filter",
+                )?;
+                Ok(
+                    crate::bindings::golem::api::host::WorkerPropertyFilter::Status(
+                        inner,
+                    ),
+                )
+            }
+            "version" => {
+                let inner: crate::bindings::golem::api::host::WorkerVersionFilter = crate::wrappers::variant_value(
+                    &obj,
+                    "worker-version-filter",
+                )?;
+                Ok(
+                    crate::bindings::golem::api::host::WorkerPropertyFilter::Version(
+                        inner,
+                    ),
+                )
+            }
+            "created-at" => {
+                let inner: crate::bindings::golem::api::host::WorkerCreatedAtFilter = crate::wrappers::variant_value(
+                    &obj,
+                    "worker-created-at-filter",
+                )?;
+                Ok(
+                    crate::bindings::golem::api::host::WorkerPropertyFilter::CreatedAt(
+                        inner,
+                    ),
+                )
+            }
+            "env" => {
+                let inner: crate::bindings::golem::api::host::WorkerEnvFilter = crate::wrappers::variant_value(
+                    &obj,
+                    "worker-env-filter",
+                )?;
+                Ok(crate::bindings::golem::api::host::WorkerPropertyFilter::Env(inner))
+            }
+            "wasi-config-vars" => {
+                let inner: crate::bindings::golem::api::host::WorkerWasiConfigVarsFilter = crate::wrappers::variant_value(
+                    &obj,
+                    "worker-wasi-config-vars-filter",
+                )?;
+                Ok(
+                    crate::bindings::golem::api::host::WorkerPropertyFilter::WasiConfigVars(
+                        inner,
+                    ),
+                )
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS worker-property-filter",
+                        "WIT worker-property-filter",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::api::host::WorkerAllFilter {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<
+                    crate::bindings::golem::api::host::WorkerPropertyFilter,
+                >,
+            >(
+            "filters",
+            crate::wrappers::JsList(
+                self.filters.into_iter().map(|v| v).collect::<Vec<_>>(),
+            ),
+        )?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::api::host::WorkerAllFilter {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT worker-all-filter",
+            &["filters"],
+            &[("filters", "list<worker-property-filter>")],
+            false,
+        )?;
+        let filters: crate::wrappers::JsList<
+            crate::bindings::golem::api::host::WorkerPropertyFilter,
+        > = crate::wrappers::get_field(&obj, "filters", "list<worker-property-filter>")?;
+        let filters: Vec<crate::bindings::golem::api::host::WorkerPropertyFilter> = filters
+            .0
+            .into_iter()
+            .map(|v| v)
+            .collect::<Vec<_>>();
+        Ok(Self { filters })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::api::host::WorkerAnyFilter {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<
+                    crate::bindings::golem::api::host::WorkerAllFilter,
+                >,
+            >(
+            "filters",
+            crate::wrappers::JsList(
+                self.filters.into_iter().map(|v| v).collect::<Vec<_>>(),
+            ),
+        )?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::api::host::WorkerAnyFilter {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT worker-any-filter",
+            &["filters"],
+            &[("filters", "list<worker-all-filter>")],
+            false,
+        )?;
+        let filters: crate::wrappers::JsList<
+            crate::bindings::golem::api::host::WorkerAllFilter,
+        > = crate::wrappers::get_field(&obj, "filters", "list<worker-all-filter>")?;
+        let filters: Vec<crate::bindings::golem::api::host::WorkerAllFilter> = filters
+            .0
+            .into_iter()
+            .map(|v| v)
+            .collect::<Vec<_>>();
+        Ok(Self { filters })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::api::host::WorkerMetadata {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::golem::rpc::types::WorkerId,
+            >("workerId", self.worker_id)?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<crate::wrappers::JsString>,
+            >(
+            "args",
+            crate::wrappers::JsList(
+                self
+                    .args
+                    .into_iter()
+                    .map(|v| crate::wrappers::JsString(v))
+                    .collect::<Vec<_>>(),
+            ),
+        )?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<
+                    rquickjs::convert::List<
+                        (crate::wrappers::JsString, crate::wrappers::JsString),
+                    >,
+                >,
+            >(
+            "env",
+            crate::wrappers::JsList(
+                self
+                    .env
+                    .into_iter()
+                    .map(|v| rquickjs::convert::List((
+                        crate::wrappers::JsString(v.0),
+                        crate::wrappers::JsString(v.1),
+                    )))
+                    .collect::<Vec<_>>(),
+            ),
+        )?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<
+                    rquickjs::convert::List<
+                        (crate::wrappers::JsString, crate::wrappers::JsString),
+                    >,
+                >,
+            >(
+            "wasiConfigVars",
+            crate::wrappers::JsList(
+                self
+                    .wasi_config_vars
+                    .into_iter()
+                    .map(|v| rquickjs::convert::List((
+                        crate::wrappers::JsString(v.0),
+                        crate::wrappers::JsString(v.1),
+                    )))
+                    .collect::<Vec<_>>(),
+            ),
+        )?;
+        obj.set::<
+                _,
+                crate::bindings::golem::api::host::WorkerStatus,
+            >("status", self.status)?;
+        obj.set::<
+                _,
+                crate::wrappers::BigIntWrapper<u64>,
+            >(
+            "componentVersion",
+            crate::wrappers::BigIntWrapper(self.component_version),
+        )?;
+        obj.set::<
+                _,
+                crate::wrappers::BigIntWrapper<u64>,
+            >("retryCount", crate::wrappers::BigIntWrapper(self.retry_count))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::api::host::WorkerMetadata {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT worker-metadata",
+            &[
+                "workerId",
+                "args",
+                "env",
+                "wasiConfigVars",
+                "status",
+                "componentVersion",
+                "retryCount",
+            ],
+            &[
+                ("workerId", "worker-id"),
+                ("args", "list<string>"),
+                ("env", "list<tuple<string, string>>"),
+                ("wasiConfigVars", "list<tuple<string, string>>"),
+                ("status", "worker-status"),
+                ("componentVersion", "u64"),
+                ("retryCount", "u64"),
+            ],
+            false,
+        )?;
+        let worker_id: crate::bindings::golem::rpc::types::WorkerId = crate::wrappers::get_field(
+            &obj,
+            "workerId",
+            "worker-id",
+        )?;
+        let worker_id: crate::bindings::golem::api::host::WorkerId = worker_id;
+        let args: crate::wrappers::JsList<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "args",
+            "list<string>",
+        )?;
+        let args: Vec<String> = args.0.into_iter().map(|v| v.0).collect::<Vec<_>>();
+        let env: crate::wrappers::JsList<
+            rquickjs::convert::List<
+                (crate::wrappers::JsString, crate::wrappers::JsString),
+            >,
+        > = crate::wrappers::get_field(&obj, "env", "list<tuple<string, string>>")?;
+        let env: Vec<(String, String)> = env
+            .0
+            .into_iter()
+            .map(|v| (v.0.0.0.clone(), v.0.1.0.clone()))
+            .collect::<Vec<_>>();
+        let wasi_config_vars: crate::wrappers::JsList<
+            rquickjs::convert::List<
+                (crate::wrappers::JsString, crate::wrappers::JsString),
+            >,
+        > = crate::wrappers::get_field(
+            &obj,
+            "wasiConfigVars",
+            "list<tuple<string, string>>",
+        )?;
+        let wasi_config_vars: Vec<(String, String)> = wasi_config_vars
+            .0
+            .into_iter()
+            .map(|v| (v.0.0.0.clone(), v.0.1.0.clone()))
+            .collect::<Vec<_>>();
+        let status: crate::bindings::golem::api::host::WorkerStatus = crate::wrappers::get_field(
+            &obj,
+            "status",
+            "worker-status",
+        )?;
+        let status: crate::bindings::golem::api::host::WorkerStatus = status;
+        let component_version: crate::wrappers::BigIntWrapper<u64> = crate::wrappers::get_field(
+            &obj,
+            "componentVersion",
+            "u64",
+        )?;
+        let component_version: u64 = component_version.0;
+        let retry_count: crate::wrappers::BigIntWrapper<u64> = crate::wrappers::get_field(
+            &obj,
+            "retryCount",
+            "u64",
+        )?;
+        let retry_count: u64 = retry_count.0;
+        Ok(Self {
+            worker_id,
+            args,
+            env,
+            wasi_config_vars,
+            status,
+            component_version,
+            retry_count,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::api::host::RevertWorkerTarget {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::api::host::RevertWorkerTarget::RevertToOplogIndex(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::BigIntWrapper<u64>,
+                >(ctx, "revert-to-oplog-index", crate::wrappers::BigIntWrapper(inner))
+            }
+            crate::bindings::golem::api::host::RevertWorkerTarget::RevertLastInvocations(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::BigIntWrapper<u64>,
+                >(ctx, "revert-last-invocations", crate::wrappers::BigIntWrapper(inner))
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::api::host::RevertWorkerTarget {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "revert-to-oplog-index" => {
+                let inner: crate::wrappers::BigIntWrapper<u64> = crate::wrappers::variant_value(
+                    &obj,
+                    "oplog-index",
+                )?;
+                Ok(
+                    crate::bindings::golem::api::host::RevertWorkerTarget::RevertToOplogIndex(
+                        inner.0,
+                    ),
+                )
+            }
+            "revert-last-invocations" => {
+                let inner: crate::wrappers::BigIntWrapper<u64> = crate::wrappers::variant_value(
+                    &obj,
+                    "u64",
+                )?;
+                Ok(
+                    crate::bindings::golem::api::host::RevertWorkerTarget::RevertLastInvocations(
+                        inner.0,
+                    ),
+                )
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS revert-worker-target",
+                        "WIT revert-worker-target",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::api::host::ForkResult {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::api::host::ForkResult::Original => {
+                "original".into_js(ctx)
+            }
+            crate::bindings::golem::api::host::ForkResult::Forked => {
+                "forked".into_js(ctx)
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::api::host::ForkResult {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let value = crate::wrappers::enum_case(
+            value,
+            "JS fork-result",
+            "WIT fork-result",
+        )?;
+        match value.as_str() {
+            "original" => Ok(crate::bindings::golem::api::host::ForkResult::Original),
+            "forked" => Ok(crate::bindings::golem::api::host::ForkResult::Forked),
+            _ => {
+                Err(
+                    crate::wrappers::unknown_enum_case(
+                        "JS fork-result",
+                        "WIT fork-result",
+                        &value,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::api::host::AccountId {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("value", crate::wrappers::JsString(self.value))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::api::host::AccountId {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT account-id",
+            &["value"],
+            &[("value", "string")],
+            false,
+        )?;
+        let value: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "value",
+            "string",
+        )?;
+        let value: String = value.0;
+        Ok(Self { value })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::api::host::ProjectId {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<_, crate::bindings::golem::rpc::types::Uuid>("uuid", self.uuid)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::api::host::ProjectId {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT project-id",
+            &["uuid"],
+            &[("uuid", "uuid")],
+            false,
+        )?;
+        let uuid: crate::bindings::golem::rpc::types::Uuid = crate::wrappers::get_field(
+            &obj,
+            "uuid",
+            "uuid",
+        )?;
+        let uuid: crate::bindings::golem::api::host::Uuid = uuid;
+        Ok(Self { uuid })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::api::oplog::WrappedFunctionType {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::api::oplog::WrappedFunctionType::ReadLocal => {
+                crate::wrappers::variant_case(ctx, "read-local")
+            }
+            crate::bindings::golem::api::oplog::WrappedFunctionType::WriteLocal => {
+                crate::wrappers::variant_case(ctx, "write-local")
+            }
+            crate::bindings::golem::api::oplog::WrappedFunctionType::ReadRemote => {
+                crate::wrappers::variant_case(ctx, "read-remote")
+            }
+            crate::bindings::golem::api::oplog::WrappedFunctionType::WriteRemote => {
+                crate::wrappers::variant_case(ctx, "write-remote")
+            }
+            crate::bindings::golem::api::oplog::WrappedFunctionType::WriteRemoteBatched(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    Option<crate::wrappers::BigIntWrapper<u64>>,
+                >(
+                    ctx,
+                    "write-remote-batched",
+                    inner.map(|v| crate::wrappers::BigIntWrapper(v)),
+                )
+            }
+            crate::bindings::golem::api::oplog::WrappedFunctionType::WriteRemoteTransaction(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    Option<crate::wrappers::BigIntWrapper<u64>>,
+                >(
+                    ctx,
+                    "write-remote-transaction",
+                    inner.map(|v| crate::wrappers::BigIntWrapper(v)),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::api::oplog::WrappedFunctionType {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "read-local" => {
+                Ok(crate::bindings::golem::api::oplog::WrappedFunctionType::ReadLocal)
+            }
+            "write-local" => {
+                Ok(crate::bindings::golem::api::oplog::WrappedFunctionType::WriteLocal)
+            }
+            "read-remote" => {
+                Ok(crate::bindings::golem::api::oplog::WrappedFunctionType::ReadRemote)
+            }
+            "write-remote" => {
+                Ok(crate::bindings::golem::api::oplog::WrappedFunctionType::WriteRemote)
+            }
+            "write-remote-batched" => {
+                let inner: Option<crate::wrappers::BigIntWrapper<u64>> = crate::wrappers::variant_value(
+                    &obj,
+                    "option<oplog-index>",
+                )?;
+                Ok(
+                    crate::bindings::golem::api::oplog::WrappedFunctionType::WriteRemoteBatched(
+                        inner.map(|v| v.0),
+                    ),
+                )
+            }
+            "write-remote-transaction" => {
+                let inner: Option<crate::wrappers::BigIntWrapper<u64>> = crate::wrappers::variant_value(
+                    &obj,
+                    "option<oplog-index>",
+                )?;
+                Ok(
+                    crate::bindings::golem::api::oplog::WrappedFunctionType::WriteRemoteTransaction(
+                        inner.map(|v| v.0),
+                    ),
+                )
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS wrapped-function-type",
+                        "WIT wrapped-function-type",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::api::oplog::PluginInstallationDescription {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::golem::rpc::types::Uuid,
+            >("installationId", self.installation_id)?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("name", crate::wrappers::JsString(self.name))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("version", crate::wrappers::JsString(self.version))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<
+                    rquickjs::convert::List<
+                        (crate::wrappers::JsString, crate::wrappers::JsString),
+                    >,
+                >,
+            >(
+            "parameters",
+            crate::wrappers::JsList(
+                self
+                    .parameters
+                    .into_iter()
+                    .map(|v| rquickjs::convert::List((
+                        crate::wrappers::JsString(v.0),
+                        crate::wrappers::JsString(v.1),
+                    )))
+                    .collect::<Vec<_>>(),
+            ),
+        )?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::api::oplog::PluginInstallationDescription {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT plugin-installation-description",
+            &["installationId", "name", "version", "parameters"],
+            &[
+                ("installationId", "uuid"),
+                ("name", "string"),
+                ("version", "string"),
+                ("parameters", "list<tuple<string, string>>"),
+            ],
+            false,
+        )?;
+        let installation_id: crate::bindings::golem::rpc::types::Uuid = crate::wrappers::get_field(
+            &obj,
+            "installationId",
+            "uuid",
+        )?;
+        let installation_id: crate::bindings::golem::api::oplog::Uuid = installation_id;
+        let name: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "name",
+            "string",
+        )?;
+        let name: String = name.0;
+        let version: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "version",
+            "string",
+        )?;
+        let version: String = version.0;
+        let parameters: crate::wrappers::JsList<
+            rquickjs::convert::List<
+                (crate::wrappers::JsString, crate::wrappers::JsString),
+            >,
+        > = crate::wrappers::get_field(
+            &obj,
+            "parameters",
+            "list<tuple<string, string>>",
+        )?;
+        let parameters: Vec<(String, String)> = parameters
+            .0
+            .into_iter()
+            .map(|v| (v.0.0.0.clone(), v.0.1.0.clone()))
+            .collect::<Vec<_>>();
+        Ok(Self {
+            installation_id,
+            name,
+            version,
+            parameters,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::api::oplog::CreateParameters {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::wasi::clocks::wall_clock::Datetime,
+            >("timestamp", self.timestamp)?;
+        obj.set::<
+                _,
+                crate::bindings::golem::rpc::types::WorkerId,
+            >("workerId", self.worker_id)?;
+        obj.set::<
+                _,
+                crate::wrappers::BigIntWrapper<u64>,
+            >(
+            "componentVersion",
+            crate::wrappers::BigIntWrapper(self.component_version),
+        )?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<crate::wrappers::JsString>,
+            >(
+            "args",
+            crate::wrappers::JsList(
+                self
+                    .args
+                    .into_iter()
+                    .map(|v| crate::wrappers::JsString(v))
+                    .collect::<Vec<_>>(),
+            ),
+        )?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<
+                    rquickjs::convert::List<
+                        (crate::wrappers::JsString, crate::wrappers::JsString),
+                    >,
+                >,
+            >(
+            "env",
+            crate::wrappers::JsList(
+                self
+                    .env
+                    .into_iter()
+                    .map(|v| rquickjs::convert::List((
+                        crate::wrappers::JsString(v.0),
+                        crate::wrappers::JsString(v.1),
+                    )))
+                    .collect::<Vec<_>>(),
+            ),
+        )?;
+        obj.set::<
+                _,
+                crate::bindings::golem::api::host::AccountId,
+            >("createdBy", self.created_by)?;
+        obj.set::<
+                _,
+                crate::bindings::golem::api::host::ProjectId,
+            >("projectId", self.project_id)?;
+        obj.set::<
+                _,
+                Option<crate::bindings::golem::rpc::types::WorkerId>,
+            >("parent", self.parent.map(|v| v))?;
+        obj.set::<
+                _,
+                crate::wrappers::BigIntWrapper<u64>,
+            >("componentSize", crate::wrappers::BigIntWrapper(self.component_size))?;
+        obj.set::<
+                _,
+                crate::wrappers::BigIntWrapper<u64>,
+            >(
+            "initialTotalLinearMemorySize",
+            crate::wrappers::BigIntWrapper(self.initial_total_linear_memory_size),
+        )?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<
+                    crate::bindings::golem::api::oplog::PluginInstallationDescription,
+                >,
+            >(
+            "initialActivePlugins",
+            crate::wrappers::JsList(
+                self.initial_active_plugins.into_iter().map(|v| v).collect::<Vec<_>>(),
+            ),
+        )?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::api::oplog::CreateParameters {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT create-parameters",
+            &[
+                "timestamp",
+                "workerId",
+                "componentVersion",
+                "args",
+                "env",
+                "createdBy",
+                "projectId",
+                "parent",
+                "componentSize",
+                "initialTotalLinearMemorySize",
+                "initialActivePlugins",
+            ],
+            &[
+                ("timestamp", "datetime"),
+                ("workerId", "worker-id"),
+                ("componentVersion", "component-version"),
+                ("args", "list<string>"),
+                ("env", "list<tuple<string, string>>"),
+                ("createdBy", "account-id"),
+                ("projectId", "project-id"),
+                ("componentSize", "u64"),
+                ("initialTotalLinearMemorySize", "u64"),
+                ("initialActivePlugins", "list<plugin-installation-description>"),
+            ],
+            false,
+        )?;
+        let timestamp: crate::bindings::wasi::clocks::wall_clock::Datetime = crate::wrappers::get_field(
+            &obj,
+            "timestamp",
+            "datetime",
+        )?;
+        let timestamp: crate::bindings::golem::api::oplog::Datetime = timestamp;
+        let worker_id: crate::bindings::golem::rpc::types::WorkerId = crate::wrappers::get_field(
+            &obj,
+            "workerId",
+            "worker-id",
+        )?;
+        let worker_id: crate::bindings::golem::api::oplog::WorkerId = worker_id;
+        let component_version: crate::wrappers::BigIntWrapper<u64> = crate::wrappers::get_field(
+            &obj,
+            "componentVersion",
+            "component-version",
+        )?;
+        let component_version: crate::bindings::golem::api::oplog::ComponentVersion = component_version
+            .0;
+        let args: crate::wrappers::JsList<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "args",
+            "list<string>",
+        )?;
+        let args: Vec<String> = args.0.into_iter().map(|v| v.0).collect::<Vec<_>>();
+        let env: crate::wrappers::JsList<
+            rquickjs::convert::List<
+                (crate::wrappers::JsString, crate::wrappers::JsString),
+            >,
+        > = crate::wrappers::get_field(&obj, "env", "list<tuple<string, string>>")?;
+        let env: Vec<(String, String)> = env
+            .0
+            .into_iter()
+            .map(|v| (v.0.0.0.clone(), v.0.1.0.clone()))
+            .collect::<Vec<_>>();
+        let created_by: crate::bindings::golem::api::host::AccountId = crate::wrappers::get_field(
+            &obj,
+            "createdBy",
+            "account-id",
+        )?;
+        let created_by: crate::bindings::golem::api::oplog::AccountId = created_by;
+        let project_id: crate::bindings::golem::api::host::ProjectId = crate::wrappers::get_field(
+            &obj,
+            "projectId",
+            "project-id",
+        )?;
+        let project_id: crate::bindings::golem::api::oplog::ProjectId = project_id;
+        let parent: Option<crate::bindings::golem::rpc::types::WorkerId> = crate::wrappers::get_field(
+            &obj,
+            "parent",
+            "option<worker-id>",
+        )?;
+        let parent: Option<crate::bindings::golem::api::oplog::WorkerId> = parent
+            .map(|v| v);
+        let component_size: crate::wrappers::BigIntWrapper<u64> = crate::wrappers::get_field(
+            &obj,
+            "componentSize",
+            "u64",
+        )?;
+        let component_size: u64 = component_size.0;
+        let initial_total_linear_memory_size: crate::wrappers::BigIntWrapper<u64> = crate::wrappers::get_field(
+            &obj,
+            "initialTotalLinearMemorySize",
+            "u64",
+        )?;
+        let initial_total_linear_memory_size: u64 = initial_total_linear_memory_size.0;
+        let initial_active_plugins: crate::wrappers::JsList<
+            crate::bindings::golem::api::oplog::PluginInstallationDescription,
+        > = crate::wrappers::get_field(
+            &obj,
+            "initialActivePlugins",
+            "list<plugin-installation-description>",
+        )?;
+        let initial_active_plugins: Vec<
+            crate::bindings::golem::api::oplog::PluginInstallationDescription,
+        > = initial_active_plugins.0.into_iter().map(|v| v).collect::<Vec<_>>();
+        Ok(Self {
+            timestamp,
+            worker_id,
+            component_version,
+            args,
+            env,
+            created_by,
+            project_id,
+            parent,
+            component_size,
+            initial_total_linear_memory_size,
+            initial_active_plugins,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::api::oplog::ImportedFunctionInvokedParameters {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::wasi::clocks::wall_clock::Datetime,
+            >("timestamp", self.timestamp)?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("functionName", crate::wrappers::JsString(self.function_name))?;
+        obj.set::<
+                _,
+                crate::bindings::golem::rpc::types::WitValue,
+            >("request", self.request)?;
+        obj.set::<
+                _,
+                crate::bindings::golem::rpc::types::WitValue,
+            >("response", self.response)?;
+        obj.set::<
+                _,
+                crate::bindings::golem::api::oplog::WrappedFunctionType,
+            >("wrappedFunctionType", self.wrapped_function_type)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::api::oplog::ImportedFunctionInvokedParameters {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT imported-function-invoked-parameters",
+            &["timestamp", "functionName", "request", "response", "wrappedFunctionType"],
+            &[
+                ("timestamp", "datetime"),
+                ("functionName", "string"),
+                ("request", "wit-value"),
+                ("response", "wit-value"),
+                ("wrappedFunctionType", "wrapped-function-type"),
+            ],
+            false,
+        )?;
+        let timestamp: crate::bindings::wasi::clocks::wall_clock::Datetime = crate::wrappers::get_field(
+            &obj,
+            "timestamp",
+            "datetime",
+        )?;
+        let timestamp: crate::bindings::golem::api::oplog::Datetime = timestamp;
+        let function_name: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "functionName",
+            "string",
+        )?;
+        let function_name: String = function_name.0;
+        let request: crate::bindings::golem::rpc::types::WitValue = crate::wrappers::get_field(
+            &obj,
+            "request",
+            "wit-value",
+        )?;
+        let request: crate::bindings::golem::api::oplog::WitValue = request;
+        let response: crate::bindings::golem::rpc::types::WitValue = crate::wrappers::get_field(
+            &obj,
+            "response",
+            "wit-value",
+        )?;
+        let response: crate::bindings::golem::api::oplog::WitValue = response;
+        let wrapped_function_type: crate::bindings::golem::api::oplog::WrappedFunctionType = crate::wrappers::get_field(
+            &obj,
+            "wrappedFunctionType",
+            "wrapped-function-type",
+        )?;
+        let wrapped_function_type: crate::bindings::golem::api::oplog::WrappedFunctionType = wrapped_function_type;
+        Ok(Self {
+            timestamp,
+            function_name,
+            request,
+            response,
+            wrapped_function_type,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::api::oplog::LocalSpanData {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("spanId", crate::wrappers::JsString(self.span_id))?;
+        obj.set::<
+                _,
+                crate::bindings::wasi::clocks::wall_clock::Datetime,
+            >("start", self.start)?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >("parent", self.parent.map(|v| crate::wrappers::JsString(v)))?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::BigIntWrapper<u64>>,
+            >(
+            "linkedContext",
+            self.linked_context.map(|v| crate::wrappers::BigIntWrapper(v)),
+        )?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<crate::bindings::golem::api::context::Attribute>,
+            >(
+            "attributes",
+            crate::wrappers::JsList(
+                self.attributes.into_iter().map(|v| v).collect::<Vec<_>>(),
+            ),
+        )?;
+        obj.set::<_, bool>("inherited", self.inherited)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::api::oplog::LocalSpanData {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT local-span-data",
+            &["spanId", "start", "parent", "linkedContext", "attributes", "inherited"],
+            &[
+                ("spanId", "span-id"),
+                ("start", "datetime"),
+                ("attributes", "list<attribute>"),
+                ("inherited", "bool"),
+            ],
+            false,
+        )?;
+        let span_id: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "spanId",
+            "span-id",
+        )?;
+        let span_id: crate::bindings::golem::api::oplog::SpanId = span_id.0;
+        let start: crate::bindings::wasi::clocks::wall_clock::Datetime = crate::wrappers::get_field(
+            &obj,
+            "start",
+            "datetime",
+        )?;
+        let start: crate::bindings::golem::api::oplog::Datetime = start;
+        let parent: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "parent",
+            "option<span-id>",
+        )?;
+        let parent: Option<crate::bindings::golem::api::oplog::SpanId> = parent
+            .map(|v| v.0);
+        let linked_context: Option<crate::wrappers::BigIntWrapper<u64>> = crate::wrappers::get_field(
+            &obj,
+            "linkedContext",
+            "option<u64>",
+        )?;
+        let linked_context: Option<u64> = linked_context.map(|v| v.0);
+        let attributes: crate::wrappers::JsList<
+            crate::bindings::golem::api::context::Attribute,
+        > = crate::wrappers::get_field(&obj, "attributes", "list<attribute>")?;
+        let attributes: Vec<crate::bindings::golem::api::oplog::Attribute> = attributes
+            .0
+            .into_iter()
+            .map(|v| v)
+            .collect::<Vec<_>>();
+        let inherited: bool = crate::wrappers::get_field(&obj, "inherited", "bool")?;
+        let inherited: bool = inherited;
+        Ok(Self {
+            span_id,
+            start,
+            parent,
+            linked_context,
+            attributes,
+            inherited,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::api::oplog::ExternalSpanData {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("spanId", crate::wrappers::JsString(self.span_id))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::api::oplog::ExternalSpanData {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT external-span-data",
+            &["spanId"],
+            &[("spanId", "span-id")],
+            false,
+        )?;
+        let span_id: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "spanId",
+            "span-id",
+        )?;
+        let span_id: crate::bindings::golem::api::oplog::SpanId = span_id.0;
+        Ok(Self { span_id })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::api::oplog::SpanData {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::api::oplog::SpanData::LocalSpan(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::api::oplog::LocalSpanData,
+                >(ctx, "local-span", inner)
+            }
+            crate::bindings::golem::api::oplog::SpanData::ExternalSpan(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::api::oplog::ExternalSpanData,
+                >(ctx, "external-span", inner)
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::api::oplog::SpanData {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "local-span" => {
+                let inner: crate::bindings::golem::api::oplog::LocalSpanData = crate::wrappers::variant_value(
+                    &obj,
+                    "local-span-data",
+                )?;
+                Ok(crate::bindings::golem::api::oplog::SpanData::LocalSpan(inner))
+            }
+            "external-span" => {
+                let inner: crate::bindings::golem::api::oplog::ExternalSpanData = crate::wrappers::variant_value(
+                    &obj,
+                    "external-span-data",
+                )?;
+                Ok(crate::bindings::golem::api::oplog::SpanData::ExternalSpan(inner))
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS span-data",
+                        "WIT span-data",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::api::oplog::ExportedFunctionInvokedParameters {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::wasi::clocks::wall_clock::Datetime,
+            >("timestamp", self.timestamp)?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("functionName", crate::wrappers::JsString(self.function_name))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<crate::bindings::golem::rpc::types::WitValue>,
+            >(
+            "request",
+            crate::wrappers::JsList(
+                self.request.into_iter().map(|v| v).collect::<Vec<_>>(),
+            ),
+        )?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("idempotencyKey", crate::wrappers::JsString(self.idempotency_key))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("traceId", crate::wrappers::JsString(self.trace_id))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<crate::wrappers::JsString>,
+            >(
+            "traceStates",
+            crate::wrappers::JsList(
+                self
+                    .trace_states
+                    .into_iter()
+                    .map(|v| crate::wrappers::JsString(v))
+                    .collect::<Vec<_>>(),
+            ),
+        )?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<
+                    crate::wrappers::JsList<crate::bindings::golem::api::oplog::SpanData>,
+                >,
+            >(
+            "invocationContext",
+            crate::wrappers::JsList(
+                self
+                    .invocation_context
+                    .into_iter()
+                    .map(|v| crate::wrappers::JsList(
+                        v.into_iter().map(|v| v).collect::<Vec<_>>(),
+                    ))
+                    .collect::<Vec<_>>(),
+            ),
+        )?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::api::oplog::ExportedFunctionInvokedParameters {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT exported-function-invoked-parameters",
+            &[
+                "timestamp",
+                "functionName",
+                "request",
+                "idempotencyKey",
+                "traceId",
+                "traceStates",
+                "invocationContext",
+            ],
+            &[
+                ("timestamp", "datetime"),
+                ("functionName", "string"),
+                ("request", "list<wit-value>"),
+                ("idempotencyKey", "string"),
+                ("traceId", "trace-id"),
+                ("traceStates", "list<string>"),
+                ("invocationContext", "list<list<span-data>>"),
+            ],
+            false,
+        )?;
+        let timestamp: crate::bindings::wasi::clocks::wall_clock::Datetime = crate::wrappers::get_field(
+            &obj,
+            "timestamp",
+            "datetime",
+        )?;
+        let timestamp: crate::bindings::golem::api::oplog::Datetime = timestamp;
+        let function_name: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "functionName",
+            "string",
+        )?;
+        let function_name: String = function_name.0;
+        let request: crate::wrappers::JsList<
+            crate::bindings::golem::rpc::types::WitValue,
+        > = crate::wrappers::get_field(&obj, "request", "list<wit-value>")?;
+        let request: Vec<crate::bindings::golem::api::oplog::WitValue> = request
+            .0
+            .into_iter()
+            .map(|v| v)
+            .collect::<Vec<_>>();
+        let idempotency_key: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "idempotencyKey",
+            "string",
+        )?;
+        let idempotency_key: String = idempotency_key.0;
+        let trace_id: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "traceId",
+            "trace-id",
+        )?;
+        let trace_id: crate::bindings::golem::api::oplog::TraceId = trace_id.0;
+        let trace_states: crate::wrappers::JsList<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "traceStates",
+            "list<string>",
+        )?;
+        let trace_states: Vec<String> = trace_states
+            .0
+            .into_iter()
+            .map(|v| v.0)
+            .collect::<Vec<_>>();
+        let invocation_context: crate::wrappers::JsList<
+            crate::wrappers::JsList<crate::bindings::golem::api::oplog::SpanData>,
+        > = crate::wrappers::get_field(
+            &obj,
+            "invocationContext",
+            "list<list<span-data>>",
+        )?;
+        let invocation_context: Vec<Vec<crate::bindings::golem::api::oplog::SpanData>> = invocation_context
+            .0
+            .into_iter()
+            .map(|v| v.0.into_iter().map(|v| v).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        Ok(Self {
+            timestamp,
+            function_name,
+            request,
+            idempotency_key,
+            trace_id,
+            trace_states,
+            invocation_context,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::api::oplog::ExportedFunctionCompletedParameters {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::wasi::clocks::wall_clock::Datetime,
+            >("timestamp", self.timestamp)?;
+        obj.set::<
+                _,
+                Option<crate::bindings::golem::rpc::types::WitValue>,
+            >("response", self.response.map(|v| v))?;
+        obj.set::<
+                _,
+                crate::wrappers::BigIntWrapper<i64>,
+            >("consumedFuel", crate::wrappers::BigIntWrapper(self.consumed_fuel))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::api::oplog::ExportedFunctionCompletedParameters {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT exported-function-completed-parameters",
+            &["timestamp", "response", "consumedFuel"],
+            &[("timestamp", "datetime"), ("consumedFuel", "s64")],
+            false,
+        )?;
+        let timestamp: crate::bindings::wasi::clocks::wall_clock::Datetime = crate::wrappers::get_field(
+            &obj,
+            "timestamp",
+            "datetime",
+        )?;
+        let timestamp: crate::bindings::golem::api::oplog::Datetime = timestamp;
+        let response: Option<crate::bindings::golem::rpc::types::WitValue> = crate::wrappers::get_field(
+            &obj,
+            "response",
+            "option<wit-value>",
+        )?;
+        let response: Option<crate::bindings::golem::api::oplog::WitValue> = response
+            .map(|v| v);
+        let consumed_fuel: crate::wrappers::BigIntWrapper<i64> = crate::wrappers::get_field(
+            &obj,
+            "consumedFuel",
+            "s64",
+        )?;
+        let consumed_fuel: i64 = consumed_fuel.0;
+        Ok(Self {
+            timestamp,
+            response,
+            consumed_fuel,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::api::oplog::ErrorParameters {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::wasi::clocks::wall_clock::Datetime,
+            >("timestamp", self.timestamp)?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("error", crate::wrappers::JsString(self.error))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::api::oplog::ErrorParameters {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT error-parameters",
+            &["timestamp", "error"],
+            &[("timestamp", "datetime"), ("error", "string")],
+            false,
+        )?;
+        let timestamp: crate::bindings::wasi::clocks::wall_clock::Datetime = crate::wrappers::get_field(
+            &obj,
+            "timestamp",
+            "datetime",
+        )?;
+        let timestamp: crate::bindings::golem::api::oplog::Datetime = timestamp;
+        let error: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "error",
+            "string",
+        )?;
+        let error: String = error.0;
+        Ok(Self { timestamp, error })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::api::oplog::JumpParameters {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::wasi::clocks::wall_clock::Datetime,
+            >("timestamp", self.timestamp)?;
+        obj.set::<
+                _,
+                crate::wrappers::BigIntWrapper<u64>,
+            >("start", crate::wrappers::BigIntWrapper(self.start))?;
+        obj.set::<
+                _,
+                crate::wrappers::BigIntWrapper<u64>,
+            >("end", crate::wrappers::BigIntWrapper(self.end))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::api::oplog::JumpParameters {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT jump-parameters",
+            &["timestamp", "start", "end"],
+            &[
+                ("timestamp", "datetime"),
+                ("start", "oplog-index"),
+                ("end", "oplog-index"),
+            ],
+            false,
+        )?;
+        let timestamp: crate::bindings::wasi::clocks::wall_clock::Datetime = crate::wrappers::get_field(
+            &obj,
+            "timestamp",
+            "datetime",
+        )?;
+        let timestamp: crate::bindings::golem::api::oplog::Datetime = timestamp;
+        let start: crate::wrappers::BigIntWrapper<u64> = crate::wrappers::get_field(
+            &obj,
+            "start",
+            "oplog-index",
+        )?;
+        let start: crate::bindings::golem::api::oplog::OplogIndex = start.0;
+        let end: crate::wrappers::BigIntWrapper<u64> = crate::wrappers::get_field(
+            &obj,
+            "end",
+            "oplog-index",
+        )?;
+        let end: crate::bindings::golem::api::oplog::OplogIndex = end.0;
+        Ok(Self { timestamp, start, end })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::api::oplog::ChangeRetryPolicyParameters {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::wasi::clocks::wall_clock::Datetime,
+            >("timestamp", self.timestamp)?;
+        obj.set::<
+                _,
+                crate::bindings::golem::api::host::RetryPolicy,
+            >("retryPolicy", self.retry_policy)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::api::oplog::ChangeRetryPolicyParameters {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT change-retry-policy-parameters",
+            &["timestamp", "retryPolicy"],
+            &[("timestamp", "datetime"), ("retryPolicy", "retry-policy")],
+            false,
+        )?;
+        let timestamp: crate::bindings::wasi::clocks::wall_clock::Datetime = crate::wrappers::get_field(
+            &obj,
+            "timestamp",
+            "datetime",
+        )?;
+        let timestamp: crate::bindings::golem::api::oplog::Datetime = timestamp;
+        let retry_policy: crate::bindings::golem::api::host::RetryPolicy = crate::wrappers::get_field(
+            &obj,
+            "retryPolicy",
+            "retry-policy",
+        )?;
+        let retry_policy: crate::bindings::golem::api::oplog::RetryPolicy = retry_policy;
+        Ok(Self { timestamp, retry_policy })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::api::oplog::EndAtomicRegionParameters {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::wasi::clocks::wall_clock::Datetime,
+            >("timestamp", self.timestamp)?;
+        obj.set::<
+                _,
+                crate::wrappers::BigIntWrapper<u64>,
+            >("beginIndex", crate::wrappers::BigIntWrapper(self.begin_index))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::api::oplog::EndAtomicRegionParameters {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT end-atomic-region-parameters",
+            &["timestamp", "beginIndex"],
+            &[("timestamp", "datetime"), ("beginIndex", "oplog-index")],
+            false,
+        )?;
+        let timestamp: crate::bindings::wasi::clocks::wall_clock::Datetime = crate::wrappers::get_field(
+            &obj,
+            "timestamp",
+            "datetime",
+        )?;
+        let timestamp: crate::bindings::golem::api::oplog::Datetime = timestamp;
+        let begin_index: crate::wrappers::BigIntWrapper<u64> = crate::wrappers::get_field(
+            &obj,
+            "beginIndex",
+            "oplog-index",
+        )?;
+        let begin_index: crate::bindings::golem::api::oplog::OplogIndex = begin_index.0;
+        Ok(Self { timestamp, begin_index })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::api::oplog::EndRemoteWriteParameters {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::wasi::clocks::wall_clock::Datetime,
+            >("timestamp", self.timestamp)?;
+        obj.set::<
+                _,
+                crate::wrappers::BigIntWrapper<u64>,
+            >("beginIndex", crate::wrappers::BigIntWrapper(self.begin_index))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::api::oplog::EndRemoteWriteParameters {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT end-remote-write-parameters",
+            &["timestamp", "beginIndex"],
+            &[("timestamp", "datetime"), ("beginIndex", "oplog-index")],
+            false,
+        )?;
+        let timestamp: crate::bindings::wasi::clocks::wall_clock::Datetime = crate::wrappers::get_field(
+            &obj,
+            "timestamp",
+            "datetime",
+        )?;
+        let timestamp: crate::bindings::golem::api::oplog::Datetime = timestamp;
+        let begin_index: crate::wrappers::BigIntWrapper<u64> = crate::wrappers::get_field(
+            &obj,
+            "beginIndex",
+            "oplog-index",
+        )?;
+        let begin_index: crate::bindings::golem::api::oplog::OplogIndex = begin_index.0;
+        Ok(Self { timestamp, begin_index })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::api::oplog::ExportedFunctionInvocationParameters {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("idempotencyKey", crate::wrappers::JsString(self.idempotency_key))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("functionName", crate::wrappers::JsString(self.function_name))?;
+        obj.set::<
+                _,
+                Option<
+                    crate::wrappers::JsList<crate::bindings::golem::rpc::types::WitValue>,
+                >,
+            >(
+            "input",
+            self
+                .input
+                .map(|v| crate::wrappers::JsList(
+                    v.into_iter().map(|v| v).collect::<Vec<_>>(),
+                )),
+        )?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::api::oplog::ExportedFunctionInvocationParameters {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT exported-function-invocation-parameters",
+            &["idempotencyKey", "functionName", "input"],
+            &[("idempotencyKey", "string"), ("functionName", "string")],
+            false,
+        )?;
+        let idempotency_key: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "idempotencyKey",
+            "string",
+        )?;
+        let idempotency_key: String = idempotency_key.0;
+        let function_name: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "functionName",
+            "string",
+        )?;
+        let function_name: String = function_name.0;
+        let input: Option<
+            crate::wrappers::JsList<crate::bindings::golem::rpc::types::WitValue>,
+        > = crate::wrappers::get_field(&obj, "input", "option<list<wit-value>>")?;
+        let input: Option<Vec<crate::bindings::golem::api::oplog::WitValue>> = input
+            .map(|v| v.0.into_iter().map(|v| v).collect::<Vec<_>>());
+        Ok(Self {
+            idempotency_key,
+            function_name,
+            input,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::api::oplog::WorkerInvocation {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::api::oplog::WorkerInvocation::ExportedFunction(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::api::oplog::ExportedFunctionInvocationParameters,
+                >(ctx, "exported-function", inner)
+            }
+            crate::bindings::golem::api::oplog::WorkerInvocation::ManualUpdate(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::BigIntWrapper<u64>,
+                >(ctx, "manual-update", crate::wrappers::BigIntWrapper(inner))
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::api::oplog::WorkerInvocation {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "exported-function" => {
+                let inner: crate::bindings::golem::api::oplog::ExportedFunctionInvocationParameters = crate::wrappers::variant_value(
+                    &obj,
+                    "exported-function-invocation-parameters",
+                )?;
+                Ok(
+                    crate::bindings::golem::api::oplog::WorkerInvocation::ExportedFunction(
+                        inner,
+                    ),
+                )
+            }
+            "manual-update" => {
+                let inner: crate::wrappers::BigIntWrapper<u64> = crate::wrappers::variant_value(
+                    &obj,
+                    "component-version",
+                )?;
+                Ok(
+                    crate::bindings::golem::api::oplog::WorkerInvocation::ManualUpdate(
+                        inner.0,
+                    ),
+                )
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS worker-invocation",
+                        "WIT worker-invocation",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::api::oplog::PendingWorkerInvocationParameters {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::wasi::clocks::wall_clock::Datetime,
+            >("timestamp", self.timestamp)?;
+        obj.set::<
+                _,
+                crate::bindings::golem::api::oplog::WorkerInvocation,
+            >("invocation", self.invocation)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::api::oplog::PendingWorkerInvocationParameters {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT pending-worker-invocation-parameters",
+            &["timestamp", "invocation"],
+            &[("timestamp", "datetime"), ("invocation", "worker-invocation")],
+            false,
+        )?;
+        let timestamp: crate::bindings::wasi::clocks::wall_clock::Datetime = crate::wrappers::get_field(
+            &obj,
+            "timestamp",
+            "datetime",
+        )?;
+        let timestamp: crate::bindings::golem::api::oplog::Datetime = timestamp;
+        let invocation: crate::bindings::golem::api::oplog::WorkerInvocation = crate::wrappers::get_field(
+            &obj,
+            "invocation",
+            "worker-invocation",
+        )?;
+        let invocation: crate::bindings::golem::api::oplog::WorkerInvocation = invocation;
+        Ok(Self { timestamp, invocation })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::api::oplog::UpdateDescription {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::api::oplog::UpdateDescription::AutoUpdate => {
+                crate::wrappers::variant_case(ctx, "auto-update")
+            }
+            crate::bindings::golem::api::oplog::UpdateDescription::SnapshotBased(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::UInt8Array,
+                >(ctx, "snapshot-based", crate::wrappers::UInt8Array(inner))
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::api::oplog::UpdateDescription {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "auto-update" => {
+                Ok(crate::bindings::golem::api::oplog::UpdateDescription::AutoUpdate)
+            }
+            "snapshot-based" => {
+                let inner: crate::wrappers::UInt8Array = crate::wrappers::variant_value(
+                    &obj,
+                    "list<u8>",
+                )?;
+                Ok(
+                    crate::bindings::golem::api::oplog::UpdateDescription::SnapshotBased(
+                        inner.0,
+                    ),
+                )
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS update-description",
+                        "WIT update-description",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::api::oplog::PendingUpdateParameters {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::wasi::clocks::wall_clock::Datetime,
+            >("timestamp", self.timestamp)?;
+        obj.set::<
+                _,
+                crate::wrappers::BigIntWrapper<u64>,
+            >("targetVersion", crate::wrappers::BigIntWrapper(self.target_version))?;
+        obj.set::<
+                _,
+                crate::bindings::golem::api::oplog::UpdateDescription,
+            >("updateDescription", self.update_description)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::api::oplog::PendingUpdateParameters {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT pending-update-parameters",
+            &["timestamp", "targetVersion", "updateDescription"],
+            &[
+                ("timestamp", "datetime"),
+                ("targetVersion", "component-version"),
+                ("updateDescription", "update-description"),
+            ],
+            false,
+        )?;
+        let timestamp: crate::bindings::wasi::clocks::wall_clock::Datetime = crate::wrappers::get_field(
+            &obj,
+            "timestamp",
+            "datetime",
+        )?;
+        let timestamp: crate::bindings::golem::api::oplog::Datetime = timestamp;
+        let target_version: crate::wrappers::BigIntWrapper<u64> = crate::wrappers::get_field(
+            &obj,
+            "targetVersion",
+            "component-version",
+        )?;
+        let target_version: crate::bindings::golem::api::oplog::ComponentVersion = target_version
+            .0;
+        let update_description: crate::bindings::golem::api::oplog::UpdateDescription = crate::wrappers::get_field(
+            &obj,
+            "updateDescription",
+            "update-description",
+        )?;
+        let update_description: crate::bindings::golem::api::oplog::UpdateDescription = update_description;
+        Ok(Self {
+            timestamp,
+            target_version,
+            update_description,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::api::oplog::SuccessfulUpdateParameters {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::wasi::clocks::wall_clock::Datetime,
+            >("timestamp", self.timestamp)?;
+        obj.set::<
+                _,
+                crate::wrappers::BigIntWrapper<u64>,
+            >("targetVersion", crate::wrappers::BigIntWrapper(self.target_version))?;
+        obj.set::<
+                _,
+                crate::wrappers::BigIntWrapper<u64>,
+            >(
+            "newComponentSize",
+            crate::wrappers::BigIntWrapper(self.new_component_size),
+        )?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<
+                    crate::bindings::golem::api::oplog::PluginInstallationDescription,
+                >,
+            >(
+            "newActivePlugins",
+            crate::wrappers::JsList(
+                self.new_active_plugins.into_iter().map(|v| v).collect::<Vec<_>>(),
+            ),
+        )?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::api::oplog::SuccessfulUpdateParameters {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT successful-update-parameters",
+            &["timestamp", "targetVersion", "newComponentSize", "newActivePlugins"],
+            &[
+                ("timestamp", "datetime"),
+                ("targetVersion", "component-version"),
+                ("newComponentSize", "u64"),
+                ("newActivePlugins", "list<plugin-installation-description>"),
+            ],
+            false,
+        )?;
+        let timestamp: crate::bindings::wasi::clocks::wall_clock::Datetime = crate::wrappers::get_field(
+            &obj,
+            "timestamp",
+            "datetime",
+        )?;
+        let timestamp: crate::bindings::golem::api::oplog::Datetime = timestamp;
+        let target_version: crate::wrappers::BigIntWrapper<u64> = crate::wrappers::get_field(
+            &obj,
+            "targetVersion",
+            "component-version",
+        )?;
+        let target_version: crate::bindings::golem::api::oplog::ComponentVersion = target_version
+            .0;
+        let new_component_size: crate::wrappers::BigIntWrapper<u64> = crate::wrappers::get_field(
+            &obj,
+            "newComponentSize",
+            "u64",
+        )?;
+        let new_component_size: u64 = new_component_size.0;
+        let new_active_plugins: crate::wrappers::JsList<
+            crate::bindings::golem::api::oplog::PluginInstallationDescription,
+        > = crate::wrappers::get_field(
+            &obj,
+            "newActivePlugins",
+            "list<plugin-installation-description>",
+        )?;
+        let new_active_plugins: Vec<
+            crate::bindings::golem::api::oplog::PluginInstallationDescription,
+        > = new_active_plugins.0.into_iter().map(|v| v).collect::<Vec<_>>();
+        Ok(Self {
+            timestamp,
+            target_version,
+            new_component_size,
+            new_active_plugins,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::api::oplog::FailedUpdateParameters {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::wasi::clocks::wall_clock::Datetime,
+            >("timestamp", self.timestamp)?;
+        obj.set::<
+                _,
+                crate::wrappers::BigIntWrapper<u64>,
+            >("targetVersion", crate::wrappers::BigIntWrapper(self.target_version))?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >("details", self.details.map(|v| crate::wrappers::JsString(v)))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::api::oplog::FailedUpdateParameters {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT failed-update-parameters",
+            &["timestamp", "targetVersion", "details"],
+            &[("timestamp", "datetime"), ("targetVersion", "component-version")],
+            false,
+        )?;
+        let timestamp: crate::bindings::wasi::clocks::wall_clock::Datetime = crate::wrappers::get_field(
+            &obj,
+            "timestamp",
+            "datetime",
+        )?;
+        let timestamp: crate::bindings::golem::api::oplog::Datetime = timestamp;
+        let target_version: crate::wrappers::BigIntWrapper<u64> = crate::wrappers::get_field(
+            &obj,
+            "targetVersion",
+            "component-version",
+        )?;
+        let target_version: crate::bindings::golem::api::oplog::ComponentVersion = target_version
+            .0;
+        let details: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "details",
+            "option<string>",
+        )?;
+        let details: Option<String> = details.map(|v| v.0);
+        Ok(Self {
+            timestamp,
+            target_version,
+            details,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::api::oplog::GrowMemoryParameters {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::wasi::clocks::wall_clock::Datetime,
+            >("timestamp", self.timestamp)?;
+        obj.set::<
+                _,
+                crate::wrappers::BigIntWrapper<u64>,
+            >("delta", crate::wrappers::BigIntWrapper(self.delta))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::api::oplog::GrowMemoryParameters {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT grow-memory-parameters",
+            &["timestamp", "delta"],
+            &[("timestamp", "datetime"), ("delta", "u64")],
+            false,
+        )?;
+        let timestamp: crate::bindings::wasi::clocks::wall_clock::Datetime = crate::wrappers::get_field(
+            &obj,
+            "timestamp",
+            "datetime",
+        )?;
+        let timestamp: crate::bindings::golem::api::oplog::Datetime = timestamp;
+        let delta: crate::wrappers::BigIntWrapper<u64> = crate::wrappers::get_field(
+            &obj,
+            "delta",
+            "u64",
+        )?;
+        let delta: u64 = delta.0;
+        Ok(Self { timestamp, delta })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::api::oplog::CreateResourceParameters {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::wasi::clocks::wall_clock::Datetime,
+            >("timestamp", self.timestamp)?;
+        obj.set::<
+                _,
+                crate::wrappers::BigIntWrapper<u64>,
+            >("resourceId", crate::wrappers::BigIntWrapper(self.resource_id))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("name", crate::wrappers::JsString(self.name))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("owner", crate::wrappers::JsString(self.owner))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::api::oplog::CreateResourceParameters {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT create-resource-parameters",
+            &["timestamp", "resourceId", "name", "owner"],
+            &[
+                ("timestamp", "datetime"),
+                ("resourceId", "worker-resource-id"),
+                ("name", "string"),
+                ("owner", "string"),
+            ],
+            false,
+        )?;
+        let timestamp: crate::bindings::wasi::clocks::wall_clock::Datetime = crate::wrappers::get_field(
+            &obj,
+            "timestamp",
+            "datetime",
+        )?;
+        let timestamp: crate::bindings::golem::api::oplog::Datetime = timestamp;
+        let resource_id: crate::wrappers::BigIntWrapper<u64> = crate::wrappers::get_field(
+            &obj,
+            "resourceId",
+            "worker-resource-id",
+        )?;
+        let resource_id: crate::bindings::golem::api::oplog::WorkerResourceId = resource_id
+            .0;
+        let name: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "name",
+            "string",
+        )?;
+        let name: String = name.0;
+        let owner: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "owner",
+            "string",
+        )?;
+        let owner: String = owner.0;
+        Ok(Self {
+            timestamp,
+            resource_id,
+            name,
+            owner,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::api::oplog::DropResourceParameters {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::wasi::clocks::wall_clock::Datetime,
+            >("timestamp", self.timestamp)?;
+        obj.set::<
+                _,
+                crate::wrappers::BigIntWrapper<u64>,
+            >("resourceId", crate::wrappers::BigIntWrapper(self.resource_id))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("name", crate::wrappers::JsString(self.name))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("owner", crate::wrappers::JsString(self.owner))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::api::oplog::DropResourceParameters {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT drop-resource-parameters",
+            &["timestamp", "resourceId", "name", "owner"],
+            &[
+                ("timestamp", "datetime"),
+                ("resourceId", "worker-resource-id"),
+                ("name", "string"),
+                ("owner", "string"),
+            ],
+            false,
+        )?;
+        let timestamp: crate::bindings::wasi::clocks::wall_clock::Datetime = crate::wrappers::get_field(
+            &obj,
+            "timestamp",
+            "datetime",
+        )?;
+        let timestamp: crate::bindings::golem::api::oplog::Datetime = timestamp;
+        let resource_id: crate::wrappers::BigIntWrapper<u64> = crate::wrappers::get_field(
+            &obj,
+            "resourceId",
+            "worker-resource-id",
+        )?;
+        let resource_id: crate::bindings::golem::api::oplog::WorkerResourceId = resource_id
+            .0;
+        let name: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "name",
+            "string",
+        )?;
+        let name: String = name.0;
+        let owner: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "owner",
+            "string",
+        )?;
+        let owner: String = owner.0;
+        Ok(Self {
+            timestamp,
+            resource_id,
+            name,
+            owner,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::api::oplog::LogLevel {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::api::oplog::LogLevel::Stdout => "stdout".into_js(ctx),
+            crate::bindings::golem::api::oplog::LogLevel::Stderr => "stderr".into_js(ctx),
+            crate::bindings::golem::api::oplog::LogLevel::Trace => "trace".into_js(ctx),
+            crate::bindings::golem::api::oplog::LogLevel::Debug => "debug".into_js(ctx),
+            crate::bindings::golem::api::oplog::LogLevel::Info => "info".into_js(ctx),
+            crate::bindings::golem::api::oplog::LogLevel::Warn => "warn".into_js(ctx),
+            crate::bindings::golem::api::oplog::LogLevel::Error => "error".into_js(ctx),
+            crate::bindings::golem::api::oplog::LogLevel::Critical => {
+                "critical".into_js(ctx)
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::api::oplog::LogLevel {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let value = crate::wrappers::enum_case(value, "JS log-level", "WIT log-level")?;
+        match value.as_str() {
+            "stdout" => Ok(crate::bindings::golem::api::oplog::LogLevel::Stdout),
+            "stderr" => Ok(crate::bindings::golem::api::oplog::LogLevel::Stderr),
+            "trace" => Ok(crate::bindings::golem::api::oplog::LogLevel::Trace),
+            "debug" => Ok(crate::bindings::golem::api::oplog::LogLevel::Debug),
+            "info" => Ok(crate::bindings::golem::api::oplog::LogLevel::Info),
+            "warn" => Ok(crate::bindings::golem::api::oplog::LogLevel::Warn),
+            "error" => Ok(crate::bindings::golem::api::oplog::LogLevel::Error),
+            "critical" => Ok(crate::bindings::golem::api::oplog::LogLevel::Critical),
+            _ => {
+                Err(
+                    crate::wrappers::unknown_enum_case(
+                        "JS log-level",
+                        "WIT log-level",
+                        &value,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::api::oplog::LogParameters {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::wasi::clocks::wall_clock::Datetime,
+            >("timestamp", self.timestamp)?;
+        obj.set::<_, crate::bindings::golem::api::oplog::LogLevel>("level", self.level)?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("context", crate::wrappers::JsString(self.context))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("message", crate::wrappers::JsString(self.message))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::api::oplog::LogParameters {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT log-parameters",
+            &["timestamp", "level", "context", "message"],
+            &[
+                ("timestamp", "datetime"),
+                ("level", "log-level"),
+                ("context", "string"),
+                ("message", "string"),
+            ],
+            false,
+        )?;
+        let timestamp: crate::bindings::wasi::clocks::wall_clock::Datetime = crate::wrappers::get_field(
+            &obj,
+            "timestamp",
+            "datetime",
+        )?;
+        let timestamp: crate::bindings::golem::api::oplog::Datetime = timestamp;
+        let level: crate::bindings::golem::api::oplog::LogLevel = crate::wrappers::get_field(
+            &obj,
+            "level",
+            "log-level",
+        )?;
+        let level: crate::bindings::golem::api::oplog::LogLevel = level;
+        let context: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "context",
+            "string",
+        )?;
+        let context: String = context.0;
+        let message: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "message",
+            "string",
+        )?;
+        let message: String = message.0;
+        Ok(Self {
+            timestamp,
+            level,
+            context,
+            message,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::api::oplog::ActivatePluginParameters {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::wasi::clocks::wall_clock::Datetime,
+            >("timestamp", self.timestamp)?;
+        obj.set::<
+                _,
+                crate::bindings::golem::api::oplog::PluginInstallationDescription,
+            >("plugin", self.plugin)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::api::oplog::ActivatePluginParameters {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT activate-plugin-parameters",
+            &["timestamp", "plugin"],
+            &[("timestamp", "datetime"), ("plugin", "plugin-installation-description")],
+            false,
+        )?;
+        let timestamp: crate::bindings::wasi::clocks::wall_clock::Datetime = crate::wrappers::get_field(
+            &obj,
+            "timestamp",
+            "datetime",
+        )?;
+        let timestamp: crate::bindings::golem::api::oplog::Datetime = timestamp;
+        let plugin: crate::bindings::golem::api::oplog::PluginInstallationDescription = crate::wrappers::get_field(
+            &obj,
+            "plugin",
+            "plugin-installation-description",
+        )?;
+        let plugin: crate::bindings::golem::api::oplog::PluginInstallationDescription = plugin;
+        Ok(Self { timestamp, plugin })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::api::oplog::DeactivatePluginParameters {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::wasi::clocks::wall_clock::Datetime,
+            >("timestamp", self.timestamp)?;
+        obj.set::<
+                _,
+                crate::bindings::golem::api::oplog::PluginInstallationDescription,
+            >("plugin", self.plugin)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::api::oplog::DeactivatePluginParameters {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT deactivate-plugin-parameters",
+            &["timestamp", "plugin"],
+            &[("timestamp", "datetime"), ("plugin", "plugin-installation-description")],
+            false,
+        )?;
+        let timestamp: crate::bindings::wasi::clocks::wall_clock::Datetime = crate::wrappers::get_field(
+            &obj,
+            "timestamp",
+            "datetime",
+        )?;
+        let timestamp: crate::bindings::golem::api::oplog::Datetime = timestamp;
+        let plugin: crate::bindings::golem::api::oplog::PluginInstallationDescription = crate::wrappers::get_field(
+            &obj,
+            "plugin",
+            "plugin-installation-description",
+        )?;
+        let plugin: crate::bindings::golem::api::oplog::PluginInstallationDescription = plugin;
+        Ok(Self { timestamp, plugin })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::api::oplog::RevertParameters {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::wasi::clocks::wall_clock::Datetime,
+            >("timestamp", self.timestamp)?;
+        obj.set::<
+                _,
+                crate::wrappers::BigIntWrapper<u64>,
+            >("start", crate::wrappers::BigIntWrapper(self.start))?;
+        obj.set::<
+                _,
+                crate::wrappers::BigIntWrapper<u64>,
+            >("end", crate::wrappers::BigIntWrapper(self.end))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::api::oplog::RevertParameters {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT revert-parameters",
+            &["timestamp", "start", "end"],
+            &[
+                ("timestamp", "datetime"),
+                ("start", "oplog-index"),
+                ("end", "oplog-index"),
+            ],
+            false,
+        )?;
+        let timestamp: crate::bindings::wasi::clocks::wall_clock::Datetime = crate::wrappers::get_field(
+            &obj,
+            "timestamp",
+            "datetime",
+        )?;
+        let timestamp: crate::bindings::golem::api::oplog::Datetime = timestamp;
+        let start: crate::wrappers::BigIntWrapper<u64> = crate::wrappers::get_field(
+            &obj,
+            "start",
+            "oplog-index",
+        )?;
+        let start: crate::bindings::golem::api::oplog::OplogIndex = start.0;
+        let end: crate::wrappers::BigIntWrapper<u64> = crate::wrappers::get_field(
+            &obj,
+            "end",
+            "oplog-index",
+        )?;
+        let end: crate::bindings::golem::api::oplog::OplogIndex = end.0;
+        Ok(Self { timestamp, start, end })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::api::oplog::CancelInvocationParameters {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::wasi::clocks::wall_clock::Datetime,
+            >("timestamp", self.timestamp)?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("idempotencyKey", crate::wrappers::JsString(self.idempotency_key))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::api::oplog::CancelInvocationParameters {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT cancel-invocation-parameters",
+            &["timestamp", "idempotencyKey"],
+            &[("timestamp", "datetime"), ("idempotencyKey", "string")],
+            false,
+        )?;
+        let timestamp: crate::bindings::wasi::clocks::wall_clock::Datetime = crate::wrappers::get_field(
+            &obj,
+            "timestamp",
+            "datetime",
+        )?;
+        let timestamp: crate::bindings::golem::api::oplog::Datetime = timestamp;
+        let idempotency_key: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "idempotencyKey",
+            "string",
+        )?;
+        let idempotency_key: String = idempotency_key.0;
+        Ok(Self { timestamp, idempotency_key })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::api::oplog::StartSpanParameters {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::wasi::clocks::wall_clock::Datetime,
+            >("timestamp", self.timestamp)?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("spanId", crate::wrappers::JsString(self.span_id))?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >("parent", self.parent.map(|v| crate::wrappers::JsString(v)))?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >(
+            "linkedContext",
+            self.linked_context.map(|v| crate::wrappers::JsString(v)),
+        )?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<crate::bindings::golem::api::context::Attribute>,
+            >(
+            "attributes",
+            crate::wrappers::JsList(
+                self.attributes.into_iter().map(|v| v).collect::<Vec<_>>(),
+            ),
+        )?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::api::oplog::StartSpanParameters {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT start-span-parameters",
+            &["timestamp", "spanId", "parent", "linkedContext", "attributes"],
+            &[
+                ("timestamp", "datetime"),
+                ("spanId", "span-id"),
+                ("attributes", "list<attribute>"),
+            ],
+            false,
+        )?;
+        let timestamp: crate::bindings::wasi::clocks::wall_clock::Datetime = crate::wrappers::get_field(
+            &obj,
+            "timestamp",
+            "datetime",
+        )?;
+        let timestamp: crate::bindings::golem::api::oplog::Datetime = timestamp;
+        let span_id: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "spanId",
+            "span-id",
+        )?;
+        let span_id: crate::bindings::golem::api::oplog::SpanId = span_id.0;
+        let parent: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "parent",
+            "option<span-id>",
+        )?;
+        let parent: Option<crate::bindings::golem::api::oplog::SpanId> = parent
+            .map(|v| v.0);
+        let linked_context: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "linkedContext",
+            "option<span-id>",
+        )?;
+        let linked_context: Option<crate::bindings::golem::api::oplog::SpanId> = linked_context
+            .map(|v| v.0);
+        let attributes: crate::wrappers::JsList<
+            crate::bindings::golem::api::context::Attribute,
+        > = crate::wrappers::get_field(&obj, "attributes", "list<attribute>")?;
+        let attributes: Vec<crate::bindings::golem::api::oplog::Attribute> = attributes
+            .0
+            .into_iter()
+            .map(|v| v)
+            .collect::<Vec<_>>();
+        Ok(Self {
+            timestamp,
+            span_id,
+            parent,
+            linked_context,
+            attributes,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::api::oplog::FinishSpanParameters {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::wasi::clocks::wall_clock::Datetime,
+            >("timestamp", self.timestamp)?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("spanId", crate::wrappers::JsString(self.span_id))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::api::oplog::FinishSpanParameters {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT finish-span-parameters",
+            &["timestamp", "spanId"],
+            &[("timestamp", "datetime"), ("spanId", "span-id")],
+            false,
+        )?;
+        let timestamp: crate::bindings::wasi::clocks::wall_clock::Datetime = crate::wrappers::get_field(
+            &obj,
+            "timestamp",
+            "datetime",
+        )?;
+        let timestamp: crate::bindings::golem::api::oplog::Datetime = timestamp;
+        let span_id: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "spanId",
+            "span-id",
+        )?;
+        let span_id: crate::bindings::golem::api::oplog::SpanId = span_id.0;
+        Ok(Self { timestamp, span_id })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::api::oplog::SetSpanAttributeParameters {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::wasi::clocks::wall_clock::Datetime,
+            >("timestamp", self.timestamp)?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("spanId", crate::wrappers::JsString(self.span_id))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("key", crate::wrappers::JsString(self.key))?;
+        obj.set::<
+                _,
+                crate::bindings::golem::api::context::AttributeValue,
+            >("value", self.value)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::api::oplog::SetSpanAttributeParameters {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT set-span-attribute-parameters",
+            &["timestamp", "spanId", "key", "value"],
+            &[
+                ("timestamp", "datetime"),
+                ("spanId", "span-id"),
+                ("key", "string"),
+                ("value", "attribute-value"),
+            ],
+            false,
+        )?;
+        let timestamp: crate::bindings::wasi::clocks::wall_clock::Datetime = crate::wrappers::get_field(
+            &obj,
+            "timestamp",
+            "datetime",
+        )?;
+        let timestamp: crate::bindings::golem::api::oplog::Datetime = timestamp;
+        let span_id: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "spanId",
+            "span-id",
+        )?;
+        let span_id: crate::bindings::golem::api::oplog::SpanId = span_id.0;
+        let key: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "key",
+            "string",
+        )?;
+        let key: String = key.0;
+        let value: crate::bindings::golem::api::context::AttributeValue = crate::wrappers::get_field(
+            &obj,
+            "value",
+            "attribute-value",
+        )?;
+        let value: crate::bindings::golem::api::oplog::AttributeValue = value;
+        Ok(Self {
+            timestamp,
+            span_id,
+            key,
+            value,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::api::oplog::ChangePersistenceLevelParameters {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::wasi::clocks::wall_clock::Datetime,
+            >("timestamp", self.timestamp)?;
+        obj.set::<
+                _,
+                crate::bindings::golem::api::host::PersistenceLevel,
+            >("persistenceLevel", self.persistence_level)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::api::oplog::ChangePersistenceLevelParameters {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT change-persistence-level-parameters",
+            &["timestamp", "persistenceLevel"],
+            &[("timestamp", "datetime"), ("persistenceLevel", "persistence-level")],
+            false,
+        )?;
+        let timestamp: crate::bindings::wasi::clocks::wall_clock::Datetime = crate::wrappers::get_field(
+            &obj,
+            "timestamp",
+            "datetime",
+        )?;
+        let timestamp: crate::bindings::golem::api::oplog::Datetime = timestamp;
+        let persistence_level: crate::bindings::golem::api::host::PersistenceLevel = crate::wrappers::get_field(
+            &obj,
+            "persistenceLevel",
+            "persistence-level",
+        )?;
+        let persistence_level: crate::bindings::golem::api::oplog::PersistenceLevel = persistence_level;
+        Ok(Self {
+            timestamp,
+            persistence_level,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::api::oplog::BeginRemoteTransactionParameters {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::wasi::clocks::wall_clock::Datetime,
+            >("timestamp", self.timestamp)?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("transactionId", crate::wrappers::JsString(self.transaction_id))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::api::oplog::BeginRemoteTransactionParameters {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT begin-remote-transaction-parameters",
+            &["timestamp", "transactionId"],
+            &[("timestamp", "datetime"), ("transactionId", "string")],
+            false,
+        )?;
+        let timestamp: crate::bindings::wasi::clocks::wall_clock::Datetime = crate::wrappers::get_field(
+            &obj,
+            "timestamp",
+            "datetime",
+        )?;
+        let timestamp: crate::bindings::golem::api::oplog::Datetime = timestamp;
+        let transaction_id: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "transactionId",
+            "string",
+        )?;
+        let transaction_id: String = transaction_id.0;
+        Ok(Self { timestamp, transaction_id })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::api::oplog::RemoteTransactionParameters {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::wasi::clocks::wall_clock::Datetime,
+            >("timestamp", self.timestamp)?;
+        obj.set::<
+                _,
+                crate::wrappers::BigIntWrapper<u64>,
+            >("beginIndex", crate::wrappers::BigIntWrapper(self.begin_index))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::api::oplog::RemoteTransactionParameters {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT remote-transaction-parameters",
+            &["timestamp", "beginIndex"],
+            &[("timestamp", "datetime"), ("beginIndex", "oplog-index")],
+            false,
+        )?;
+        let timestamp: crate::bindings::wasi::clocks::wall_clock::Datetime = crate::wrappers::get_field(
+            &obj,
+            "timestamp",
+            "datetime",
+        )?;
+        let timestamp: crate::bindings::golem::api::oplog::Datetime = timestamp;
+        let begin_index: crate::wrappers::BigIntWrapper<u64> = crate::wrappers::get_field(
+            &obj,
+            "beginIndex",
+            "oplog-index",
+        )?;
+        let begin_index: crate::bindings::golem::api::oplog::OplogIndex = begin_index.0;
+        Ok(Self { timestamp, begin_index })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::api::oplog::OplogEntry {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::api::oplog::OplogEntry::Create(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::api::oplog::CreateParameters,
+                >(ctx, "create", inner)
+            }
+            crate::bindings::golem::api::oplog::OplogEntry::ImportedFunctionInvoked(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::api::oplog::ImportedFunctionInvokedParameters,
+                >(ctx, "imported-function-invoked", inner)
+            }
+            crate::bindings::golem::api::oplog::OplogEntry::ExportedFunctionInvoked(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::api::oplog::ExportedFunctionInvokedParameters,
+                >(ctx, "exported-function-invoked", inner)
+            }
+            crate::bindings::golem::api::oplog::OplogEntry::ExportedFunctionCompleted(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::api::oplog::ExportedFunctionCompletedParameters,
+                >(ctx, "exported-function-completed", inner)
+            }
+            crate::bindings::golem::api::oplog::OplogEntry::Suspend(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::wasi::clocks::wall_clock::Datetime,
+                >(ctx, "suspend", inner)
+            }
+            crate::bindings::golem::api::oplog::OplogEntry::Error(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::api::oplog::ErrorParameters,
+                >(ctx, "error", inner)
+            }
+            crate::bindings::golem::api::oplog::OplogEntry::NoOp(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::wasi::clocks::wall_clock::Datetime,
+                >(ctx, "no-op", inner)
+            }
+            crate::bindings::golem::api::oplog::OplogEntry::Jump(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::api::oplog::JumpParameters,
+                >(ctx, "jump", inner)
+            }
+            crate::bindings::golem::api::oplog::OplogEntry::Interrupted(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::wasi::clocks::wall_clock::Datetime,
+                >(ctx, "interrupted", inner)
+            }
+            crate::bindings::golem::api::oplog::OplogEntry::Exited(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::wasi::clocks::wall_clock::Datetime,
+                >(ctx, "exited", inner)
+            }
+            crate::bindings::golem::api::oplog::OplogEntry::ChangeRetryPolicy(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::api::oplog::ChangeRetryPolicyParameters,
+                >(ctx, "change-retry-policy", inner)
+            }
+            crate::bindings::golem::api::oplog::OplogEntry::BeginAtomicRegion(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::wasi::clocks::wall_clock::Datetime,
+                >(ctx, "begin-atomic-region", inner)
+            }
+            crate::bindings::golem::api::oplog::OplogEntry::EndAtomicRegion(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::api::oplog::EndAtomicRegionParameters,
+                >(ctx, "end-atomic-region", inner)
+            }
+            crate::bindings::golem::api::oplog::OplogEntry::BeginRemoteWrite(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::wasi::clocks::wall_clock::Datetime,
+                >(ctx, "begin-remote-write", inner)
+            }
+            crate::bindings::golem::api::oplog::OplogEntry::EndRemoteWrite(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::api::oplog::EndRemoteWriteParameters,
+                >(ctx, "end-remote-write", inner)
+            }
+            crate::bindings::golem::api::oplog::OplogEntry::PendingWorkerInvocation(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::api::oplog::PendingWorkerInvocationParameters,
+                >(ctx, "pending-worker-invocation", inner)
+            }
+            crate::bindings::golem::api::oplog::OplogEntry::PendingUpdate(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::api::oplog::PendingUpdateParameters,
+                >(ctx, "pending-update", inner)
+            }
+            crate::bindings::golem::api::oplog::OplogEntry::SuccessfulUpdate(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::api::oplog::SuccessfulUpdateParameters,
+                >(ctx, "successful-update", inner)
+            }
+            crate::bindings::golem::api::oplog::OplogEntry::FailedUpdate(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::api::oplog::FailedUpdateParameters,
+                >(ctx, "failed-update", inner)
+            }
+            crate::bindings::golem::api::oplog::OplogEntry::GrowMemory(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::api::oplog::GrowMemoryParameters,
+                >(ctx, "grow-memory", inner)
+            }
+            crate::bindings::golem::api::oplog::OplogEntry::CreateResource(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::api::oplog::CreateResourceParameters,
+                >(ctx, "create-resource", inner)
+            }
+            crate::bindings::golem::api::oplog::OplogEntry::DropResource(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::api::oplog::DropResourceParameters,
+                >(ctx, "drop-resource", inner)
+            }
+            crate::bindings::golem::api::oplog::OplogEntry::Log(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::api::oplog::LogParameters,
+                >(ctx, "log", inner)
+            }
+            crate::bindings::golem::api::oplog::OplogEntry::Restart(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::wasi::clocks::wall_clock::Datetime,
+                >(ctx, "restart", inner)
+            }
+            crate::bindings::golem::api::oplog::OplogEntry::ActivatePlugin(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::api::oplog::ActivatePluginParameters,
+                >(ctx, "activate-plugin", inner)
+            }
+            crate::bindings::golem::api::oplog::OplogEntry::DeactivatePlugin(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::api::oplog::DeactivatePluginParameters,
+                >(ctx, "deactivate-plugin", inner)
+            }
+            crate::bindings::golem::api::oplog::OplogEntry::Revert(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::api::oplog::RevertParameters,
+                >(ctx, "revert", inner)
+            }
+            crate::bindings::golem::api::oplog::OplogEntry::CancelInvocation(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::api::oplog::CancelInvocationParameters,
+                >(ctx, "cancel-invocation", inner)
+            }
+            crate::bindings::golem::api::oplog::OplogEntry::StartSpan(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::api::oplog::StartSpanParameters,
+                >(ctx, "start-span", inner)
+            }
+            crate::bindings::golem::api::oplog::OplogEntry::FinishSpan(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::api::oplog::FinishSpanParameters,
+                >(ctx, "finish-span", inner)
+            }
+            crate::bindings::golem::api::oplog::OplogEntry::SetSpanAttribute(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::api::oplog::SetSpanAttributeParameters,
+                >(ctx, "set-span-attribute", inner)
+            }
+            crate::bindings::golem::api::oplog::OplogEntry::ChangePersistenceLevel(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::api::oplog::ChangePersistenceLevelParameters,
+                >(ctx, "change-persistence-level", inner)
+            }
+            crate::bindings::golem::api::oplog::OplogEntry::BeginRemoteTransaction(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::api::oplog::BeginRemoteTransactionParameters,
+                >(ctx, "begin-remote-transaction", inner)
+            }
+            crate::bindings::golem::api::oplog::OplogEntry::PreCommitRemoteTransaction(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::api::oplog::RemoteTransactionParameters,
+                >(ctx, "pre-commit-remote-transaction", inner)
+            }
+            crate::bindings::golem::api::oplog::OplogEntry::PreRollbackRemoteTransaction(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::api::oplog::RemoteTransactionParameters,
+                >(ctx, "pre-rollback-remote-transaction", inner)
+            }
+            crate::bindings::golem::api::oplog::OplogEntry::CommittedRemoteTransaction(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::api::oplog::RemoteTransactionParameters,
+                >(ctx, "committed-remote-transaction", inner)
+            }
+            crate::bindings::golem::api::oplog::OplogEntry::RolledBackRemoteTransaction(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::api::oplog::RemoteTransactionParameters,
+                >(ctx, "rolled-back-remote-transaction", inner)
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::api::oplog::OplogEntry {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "create" => {
+                let inner: crate::bindings::golem::api::oplog::CreateParameters = crate::wrappers::variant_value(
+                    &obj,
+                    "create-parameters",
+                )?;
+                Ok(crate::bindings::golem::api::oplog::OplogEntry::Create(inner))
+            }
+            "imported-function-invoked" => {
+                let inner: crate::bindings::golem::api::oplog::ImportedFunctionInvokedParameters = crate::wrappers::variant_value(
+                    &obj,
+                    "imported-function-invoked-parameters",
+                )?;
+                Ok(
+                    crate::bindings::golem::api::oplog::OplogEntry::ImportedFunctionInvoked(
+                        inner,
+                    ),
+                )
+            }
+            "exported-function-invoked" => {
+                let inner: crate::bindings::golem::api::oplog::ExportedFunctionInvokedParameters = crate::wrappers::variant_value(
+                    &obj,
+                    "exported-function-invoked-parameters",
+                )?;
+                Ok(
+                    crate::bindings::golem::api::oplog::OplogEntry::ExportedFunctionInvoked(
+                        inner,
+                    ),
+                )
+            }
+            "exported-function-completed" => {
+                let inner: crate::bindings::golem::api::oplog::ExportedFunctionCompletedParameters = crate::wrappers::variant_value(
+                    &obj,
+                    "exported-function-completed-parameters",
+                )?;
+                Ok(
+                    crate::bindings::golem::api::oplog::OplogEntry::ExportedFunctionCompleted(
+                        inner,
+                    ),
+                )
+            }
+            "suspend" => {
+                let inner: crate::bindings::wasi::clocks::wall_clock::Datetime = crate::wrappers::variant_value(
+                    &obj,
+                    "datetime",
+                )?;
+                Ok(crate::bindings::golem::api::oplog::OplogEntry::Suspend(inner))
+            }
+            "error" => {
+                let inner: crate::bindings::golem::api::oplog::ErrorParameters = crate::wrappers::variant_value(
+                    &obj,
+                    "error-parameters",
+                )?;
+                Ok(crate::bindings::golem::api::oplog::OplogEntry::Error(inner))
+            }
+            "no-op" => {
+                let inner: crate::bindings::wasi::clocks::wall_clock::Datetime = crate::wrappers::variant_value(
+                    &obj,
+                    "datetime",
+                )?;
+                Ok(crate::bindings::golem::api::oplog::OplogEntry::NoOp(inner))
+            }
+            "jump" => {
+                let inner: crate::bindings::golem::api::oplog::JumpParameters = crate::wrappers::variant_value(
+                    &obj,
+                    "jump-parameters",
+                )?;
+                Ok(crate::bindings::golem::api::oplog::OplogEntry::Jump(inner))
+            }
+            "interrupted" => {
+                let inner: crate::bindings::wasi::clocks::wall_clock::Datetime = crate::wrappers::variant_value(
+                    &obj,
+                    "datetime",
+                )?;
+                Ok(crate::bindings::golem::api::oplog::OplogEntry::Interrupted(inner))
+            }
+            "exited" => {
+                let inner: crate::bindings::wasi::clocks::wall_clock::Datetime = crate::wrappers::variant_value(
+                    &obj,
+                    "datetime",
+                )?;
+                Ok(crate::bindings::golem::api::oplog::OplogEntry::Exited(inner))
+            }
+            "change-retry-policy" => {
+                let inner: crate::bindings::golem::api::oplog::ChangeRetryPolicyParameters = crate::wrappers::variant_value(
+                    &obj,
+                    "change-retry-policy-parameters",
+                )?;
+                Ok(
+                    crate::bindings::golem::api::oplog::OplogEntry::ChangeRetryPolicy(
+                        inner,
+                    ),
+                )
+            }
+            "begin-atomic-region" => {
+                let inner: crate::bindings::wasi::clocks::wall_clock::Datetime = crate::wrappers::variant_value(
+                    &obj,
+                    "datetime",
+                )?;
+                Ok(
+                    crate::bindings::golem::api::oplog::OplogEntry::BeginAtomicRegion(
+                        inner,
+                    ),
+                )
+            }
+            "end-atomic-region" => {
+                let inner: crate::bindings::golem::api::oplog::EndAtomicRegionParameters = crate::wrappers::variant_value(
+                    &obj,
+                    "end-atomic-region-parameters",
+                )?;
+                Ok(
+                    crate::bindings::golem::api::oplog::OplogEntry::EndAtomicRegion(
+                        inner,
+                    ),
+                )
+            }
+            "begin-remote-write" => {
+                let inner: crate::bindings::wasi::clocks::wall_clock::Datetime = crate::wrappers::variant_value(
+                    &obj,
+                    "datetime",
+                )?;
+                Ok(
+                    crate::bindings::golem::api::oplog::OplogEntry::BeginRemoteWrite(
+                        inner,
+                    ),
+                )
+            }
+            "end-remote-write" => {
+                let inner: crate::bindings::golem::api::oplog::EndRemoteWriteParameters = crate::wrappers::variant_value(
+                    &obj,
+                    "end-remote-write-parameters",
+                )?;
+                Ok(crate::bindings::golem::api::oplog::OplogEntry::EndRemoteWrite(inner))
+            }
+            "pending-worker-invocation" => {
+                let inner: crate::bindings::golem::api::oplog::PendingWorkerInvocationParameters = crate::wrappers::variant_value(
+                    &obj,
+                    "pending-worker-invocation-parameters",
+                )?;
+                Ok(
+                    crate::bindings::golem::api::oplog::OplogEntry::PendingWorkerInvocation(
+                        inner,
+                    ),
+                )
+            }
+            "pending-update" => {
+                let inner: crate::bindings::golem::api::oplog::PendingUpdateParameters = crate::wrappers::variant_value(
+                    &obj,
+                    "pending-update-parameters",
+                )?;
+                Ok(crate::bindings::golem::api::oplog::OplogEntry::PendingUpdate(inner))
+            }
+            "successful-update" => {
+                let inner: crate::bindings::golem::api::oplog::SuccessfulUpdateParameters = crate::wrappers::variant_value(
+                    &obj,
+                    "successful-update-parameters",
+                )?;
+                Ok(
+                    crate::bindings::golem::api::oplog::OplogEntry::SuccessfulUpdate(
+                        inner,
+                    ),
+                )
+            }
+            "failed-update" => {
+                let inner: crate::bindings::golem::api::oplog::FailedUpdateParameters = crate::wrappers::variant_value(
+                    &obj,
+                    "failed-update-parameters",
+                )?;
+                Ok(crate::bindings::golem::api::oplog::OplogEntry::FailedUpdate(inner))
+            }
+            "grow-memory" => {
+                let inner: crate::bindings::golem::api::oplog::GrowMemoryParameters = crate::wrappers::variant_value(
+                    &obj,
+                    "grow-memory-parameters",
+                )?;
+                Ok(crate::bindings::golem::api::oplog::OplogEntry::GrowMemory(inner))
+            }
+            "create-resource" => {
+                let inner: crate::bindings::golem::api::oplog::CreateResourceParameters = crate::wrappers::variant_value(
+                    &obj,
+                    "create-resource-parameters",
+                )?;
+                Ok(crate::bindings::golem::api::oplog::OplogEntry::CreateResource(inner))
+            }
+            "drop-resource" => {
+                let inner: crate::bindings::golem::api::oplog::DropResourceParameters = crate::wrappers::variant_value(
+                    &obj,
+                    "drop-resource-parameters",
+                )?;
+                Ok(crate::bindings::golem::api::oplog::OplogEntry::DropResource(inner))
+            }
+            "log" => {
+                let inner: crate::bindings::golem::api::oplog::LogParameters = crate::wrappers::variant_value(
+                    &obj,
+                    "log-parameters",
+                )?;
+                Ok(crate::bindings::golem::api::oplog::OplogEntry::Log(inner))
+            }
+            "restart" => {
+                let inner: crate::bindings::wasi::clocks::wall_clock::Datetime = crate::wrappers::variant_value(
+                    &obj,
+                    "datetime",
+                )?;
+                Ok(crate::bindings::golem::api::oplog::OplogEntry::Restart(inner))
+            }
+            "activate-plugin" => {
+                let inner: crate::bindings::golem::api::oplog::ActivatePluginParameters = crate::wrappers::variant_value(
+                    &obj,
+                    "activate-plugin-parameters",
+                )?;
+                Ok(crate::bindings::golem::api::oplog::OplogEntry::ActivatePlugin(inner))
+            }
+            "deactivate-plugin" => {
+                let inner: crate::bindings::golem::api::oplog::DeactivatePluginParameters = crate::wrappers::variant_value(
+                    &obj,
+                    "deactivate-plugin-parameters",
+                )?;
+                Ok(
+                    crate::bindings::golem::api::oplog::OplogEntry::DeactivatePlugin(
+                        inner,
+                    ),
+                )
+            }
+            "revert" => {
+                let inner: crate::bindings::golem::api::oplog::RevertParameters = crate::wrappers::variant_value(
+                    &obj,
+                    "revert-parameters",
+                )?;
+                Ok(crate::bindings::golem::api::oplog::OplogEntry::Revert(inner))
+            }
+            "cancel-invocation" => {
+                let inner: crate::bindings::golem::api::oplog::CancelInvocationParameters = crate::wrappers::variant_value(
+                    &obj,
+                    "cancel-invocation-parameters",
+                )?;
+                Ok(
+                    crate::bindings::golem::api::oplog::OplogEntry::CancelInvocation(
+                        inner,
+                    ),
+                )
+            }
+            "start-span" => {
+                let inner: crate::bindings::golem::api::oplog::StartSpanParameters = crate::wrappers::variant_value(
+                    &obj,
+                    "start-span-parameters",
+                )?;
+                Ok(crate::bindings::golem::api::oplog::OplogEntry::StartSpan(inner))
+            }
+            "finish-span" => {
+                let inner: crate::bindings::golem::api::oplog::FinishSpanParameters = crate::wrappers::variant_value(
+                    &obj,
+                    "finish-span-parameters",
+                )?;
+                Ok(crate::bindings::golem::api::oplog::OplogEntry::FinishSpan(inner))
+            }
+            "set-span-attribute" => {
+                let inner: crate::bindings::golem::api::oplog::SetSpanAttributeParameters = crate::wrappers::variant_value(
+                    &obj,
+                    "set-span-attribute-parameters",
+                )?;
+                Ok(
+                    crate::bindings::golem::api::oplog::OplogEntry::SetSpanAttribute(
+                        inner,
+                    ),
+                )
+            }
+            "change-persistence-level" => {
+                let inner: crate::bindings::golem::api::oplog::ChangePersistenceLevelParameters = crate::wrappers::variant_value(
+                    &obj,
+                    "change-persistence-level-parameters",
+                )?;
+                Ok(
+                    crate::bindings::golem::api::oplog::OplogEntry::ChangePersistenceLevel(
+                        inner,
+                    ),
+                )
+            }
+            "begin-remote-transaction" => {
+                let inner: crate::bindings::golem::api::oplog::BeginRemoteTransactionParameters = crate::wrappers::variant_value(
+                    &obj,
+                    "begin-remote-transaction-parameters",
+                )?;
+                Ok(
+                    crate::bindings::golem::api::oplog::OplogEntry::BeginRemoteTransaction(
+                        inner,
+                    ),
+                )
+            }
+            "pre-commit-remote-transaction" => {
+                let inner: crate::bindings::golem::api::oplog::RemoteTransactionParameters = crate::wrappers::variant_value(
+                    &obj,
+                    "remote-transaction-parameters",
+                )?;
+                Ok(
+                    crate::bindings::golem::api::oplog::OplogEntry::PreCommitRemoteTransaction(
+                        inner,
+                    ),
+                )
+            }
+            "pre-rollback-remote-transaction" => {
+                let inner: crate::bindings::golem::api::oplog::RemoteTransactionParameters = crate::wrappers::variant_value(
+                    &obj,
+                    "remote-transaction-parameters",
+                )?;
+                Ok(
+                    crate::bindings::golem::api::oplog::OplogEntry::PreRollbackRemoteTransaction(
+                        inner,
+                    ),
+                )
+            }
+            "committed-remote-transaction" => {
+                let inner: crate::bindings::golem::api::oplog::RemoteTransactionParameters = crate::wrappers::variant_value(
+                    &obj,
+                    "remote-transaction-parameters",
+                )?;
+                Ok(
+                    crate::bindings::golem::api::oplog::OplogEntry::CommittedRemoteTransaction(
+                        inner,
+                    ),
+                )
+            }
+            "rolled-back-remote-transaction" => {
+                let inner: crate::bindings::golem::api::oplog::RemoteTransactionParameters = crate::wrappers::variant_value(
+                    &obj,
+                    "remote-transaction-parameters",
+                )?;
+                Ok(
+                    crate::bindings::golem::api::oplog::OplogEntry::RolledBackRemoteTransaction(
+                        inner,
+                    ),
+                )
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS oplog-entry",
+                        "WIT oplog-entry",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::durability::durability::DurableExecutionState {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<_, bool>("isLive", self.is_live)?;
+        obj.set::<
+                _,
+                crate::bindings::golem::api::host::PersistenceLevel,
+            >("persistenceLevel", self.persistence_level)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::durability::durability::DurableExecutionState {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT durable-execution-state",
+            &["isLive", "persistenceLevel"],
+            &[("isLive", "bool"), ("persistenceLevel", "persistence-level")],
+            false,
+        )?;
+        let is_live: bool = crate::wrappers::get_field(&obj, "isLive", "bool")?;
+        let is_live: bool = is_live;
+        let persistence_level: crate::bindings::golem::api::host::PersistenceLevel = crate::wrappers::get_field(
+            &obj,
+            "persistenceLevel",
+            "persistence-level",
+        )?;
+        let persistence_level: crate::bindings::golem::durability::durability::PersistenceLevel = persistence_level;
+        Ok(Self { is_live, persistence_level })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::durability::durability::OplogEntryVersion {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::durability::durability::OplogEntryVersion::V1 => {
+                "v1".into_js(ctx)
+            }
+            crate::bindings::golem::durability::durability::OplogEntryVersion::V2 => {
+                "v2".into_js(ctx)
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::durability::durability::OplogEntryVersion {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let value = crate::wrappers::enum_case(
+            value,
+            "JS oplog-entry-version",
+            "WIT oplog-entry-version",
+        )?;
+        match value.as_str() {
+            "v1" => {
+                Ok(crate::bindings::golem::durability::durability::OplogEntryVersion::V1)
+            }
+            "v2" => {
+                Ok(crate::bindings::golem::durability::durability::OplogEntryVersion::V2)
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_enum_case(
+                        "JS oplog-entry-version",
+                        "WIT oplog-entry-version",
+                        &value,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::durability::durability::PersistedDurableFunctionInvocation {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::wasi::clocks::wall_clock::Datetime,
+            >("timestamp", self.timestamp)?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("functionName", crate::wrappers::JsString(self.function_name))?;
+        obj.set::<
+                _,
+                crate::wrappers::UInt8Array,
+            >("response", crate::wrappers::UInt8Array(self.response))?;
+        obj.set::<
+                _,
+                crate::bindings::golem::api::oplog::WrappedFunctionType,
+            >("functionType", self.function_type)?;
+        obj.set::<
+                _,
+                crate::bindings::golem::durability::durability::OplogEntryVersion,
+            >("entryVersion", self.entry_version)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::durability::durability::PersistedDurableFunctionInvocation {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT persisted-durable-function-invocation",
+            &["timestamp", "functionName", "response", "functionType", "entryVersion"],
+            &[
+                ("timestamp", "datetime"),
+                ("functionName", "string"),
+                ("response", "list<u8>"),
+                ("functionType", "durable-function-type"),
+                ("entryVersion", "oplog-entry-version"),
+            ],
+            false,
+        )?;
+        let timestamp: crate::bindings::wasi::clocks::wall_clock::Datetime = crate::wrappers::get_field(
+            &obj,
+            "timestamp",
+            "datetime",
+        )?;
+        let timestamp: crate::bindings::golem::durability::durability::Datetime = timestamp;
+        let function_name: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "functionName",
+            "string",
+        )?;
+        let function_name: String = function_name.0;
+        let response: crate::wrappers::UInt8Array = crate::wrappers::get_field(
+            &obj,
+            "response",
+            "list<u8>",
+        )?;
+        let response: Vec<u8> = response.0;
+        let function_type: crate::bindings::golem::api::oplog::WrappedFunctionType = crate::wrappers::get_field(
+            &obj,
+            "functionType",
+            "durable-function-type",
+        )?;
+        let function_type: crate::bindings::golem::durability::durability::DurableFunctionType = function_type;
+        let entry_version: crate::bindings::golem::durability::durability::OplogEntryVersion = crate::wrappers::get_field(
+            &obj,
+            "entryVersion",
+            "oplog-entry-version",
+        )?;
+        let entry_version: crate::bindings::golem::durability::durability::OplogEntryVersion = entry_version;
+        Ok(Self {
+            timestamp,
+            function_name,
+            response,
+            function_type,
+            entry_version,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::durability::durability::PersistedTypedDurableFunctionInvocation {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::wasi::clocks::wall_clock::Datetime,
+            >("timestamp", self.timestamp)?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("functionName", crate::wrappers::JsString(self.function_name))?;
+        obj.set::<
+                _,
+                crate::bindings::golem::rpc::types::ValueAndType,
+            >("response", self.response)?;
+        obj.set::<
+                _,
+                crate::bindings::golem::api::oplog::WrappedFunctionType,
+            >("functionType", self.function_type)?;
+        obj.set::<
+                _,
+                crate::bindings::golem::durability::durability::OplogEntryVersion,
+            >("entryVersion", self.entry_version)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::durability::durability::PersistedTypedDurableFunctionInvocation {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT persisted-typed-durable-function-invocation",
+            &["timestamp", "functionName", "response", "functionType", "entryVersion"],
+            &[
+                ("timestamp", "datetime"),
+                ("functionName", "string"),
+                ("response", "value-and-type"),
+                ("functionType", "durable-function-type"),
+                ("entryVersion", "oplog-entry-version"),
+            ],
+            false,
+        )?;
+        let timestamp: crate::bindings::wasi::clocks::wall_clock::Datetime = crate::wrappers::get_field(
+            &obj,
+            "timestamp",
+            "datetime",
+        )?;
+        let timestamp: crate::bindings::golem::durability::durability::Datetime = timestamp;
+        let function_name: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "functionName",
+            "string",
+        )?;
+        let function_name: String = function_name.0;
+        let response: crate::bindings::golem::rpc::types::ValueAndType = crate::wrappers::get_field(
+            &obj,
+            "response",
+            "value-and-type",
+        )?;
+        let response: crate::bindings::golem::durability::durability::ValueAndType = response;
+        let function_type: crate::bindings::golem::api::oplog::WrappedFunctionType = crate::wrappers::get_field(
+            &obj,
+            "functionType",
+            "durable-function-type",
+        )?;
+        let function_type: crate::bindings::golem::durability::durability::DurableFunctionType = function_type;
+        let entry_version: crate::bindings::golem::durability::durability::OplogEntryVersion = crate::wrappers::get_field(
+            &obj,
+            "entryVersion",
+            "oplog-entry-version",
+        )?;
+        let entry_version: crate::bindings::golem::durability::durability::OplogEntryVersion = entry_version;
+        Ok(Self {
+            timestamp,
+            function_name,
+            response,
+            function_type,
+            entry_version,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::exec::types::LanguageKind {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::exec::types::LanguageKind::Javascript => {
+                "javascript".into_js(ctx)
+            }
+            crate::bindings::golem::exec::types::LanguageKind::Python => {
+                "python".into_js(ctx)
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::exec::types::LanguageKind {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let value = crate::wrappers::enum_case(
+            value,
+            "JS language-kind",
+            "WIT language-kind",
+        )?;
+        match value.as_str() {
+            "javascript" => {
+                Ok(crate::bindings::golem::exec::types::LanguageKind::Javascript)
+            }
+            "python" => Ok(crate::bindings::golem::exec::types::LanguageKind::Python),
+            _ => {
+                Err(
+                    crate::wrappers::unknown_enum_case(
+                        "JS language-kind",
+                        "WIT language-kind",
+                        &value,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::exec::types::Language {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::golem::exec::types::LanguageKind,
+            >("kind", self.kind)?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >("version", self.version.map(|v| crate::wrappers::JsString(v)))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::exec::types::Language {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT language",
+            &["kind", "version"],
+            &[("kind", "language-kind")],
+            false,
+        )?;
+        let kind: crate::bindings::golem::exec::types::LanguageKind = crate::wrappers::get_field(
+            &obj,
+            "kind",
+            "language-kind",
+        )?;
+        let kind: crate::bindings::golem::exec::types::LanguageKind = kind;
+        let version: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "version",
+            "option<string>",
+        )?;
+        let version: Option<String> = version.map(|v| v.0);
+        Ok(Self { kind, version })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::exec::types::Encoding {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::exec::types::Encoding::Utf8 => "utf8".into_js(ctx),
+            crate::bindings::golem::exec::types::Encoding::Base64 => {
+                "base64".into_js(ctx)
+            }
+            crate::bindings::golem::exec::types::Encoding::Hex => "hex".into_js(ctx),
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::exec::types::Encoding {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let value = crate::wrappers::enum_case(value, "JS encoding", "WIT encoding")?;
+        match value.as_str() {
+            "utf8" => Ok(crate::bindings::golem::exec::types::Encoding::Utf8),
+            "base64" => Ok(crate::bindings::golem::exec::types::Encoding::Base64),
+            "hex" => Ok(crate::bindings::golem::exec::types::Encoding::Hex),
+            _ => {
+                Err(
+                    crate::wrappers::unknown_enum_case(
+                        "JS encoding",
+                        "WIT encoding",
+                        &value,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::exec::types::File {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("name", crate::wrappers::JsString(self.name))?;
+        obj.set::<
+                _,
+                crate::wrappers::UInt8Array,
+            >("content", crate::wrappers::UInt8Array(self.content))?;
+        obj.set::<
+                _,
+                Option<crate::bindings::golem::exec::types::Encoding>,
+            >("encoding", self.encoding.map(|v| v))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::exec::types::File {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT file",
+            &["name", "content", "encoding"],
+            &[("name", "string"), ("content", "list<u8>")],
+            false,
+        )?;
+        let name: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "name",
+            "string",
+        )?;
+        let name: String = name.0;
+        let content: crate::wrappers::UInt8Array = crate::wrappers::get_field(
+            &obj,
+            "content",
+            "list<u8>",
+        )?;
+        let content: Vec<u8> = content.0;
+        let encoding: Option<crate::bindings::golem::exec::types::Encoding> = crate::wrappers::get_field(
+            &obj,
+            "encoding",
+            "option<encoding>",
+        )?;
+        let encoding: Option<crate::bindings::golem::exec::types::Encoding> = encoding
+            .map(|v| v);
+        Ok(Self { name, content, encoding })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::exec::types::Limits {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::BigIntWrapper<u64>>,
+            >("timeMs", self.time_ms.map(|v| crate::wrappers::BigIntWrapper(v)))?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::BigIntWrapper<u64>>,
+            >(
+            "memoryBytes",
+            self.memory_bytes.map(|v| crate::wrappers::BigIntWrapper(v)),
+        )?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::BigIntWrapper<u64>>,
+            >(
+            "fileSizeBytes",
+            self.file_size_bytes.map(|v| crate::wrappers::BigIntWrapper(v)),
+        )?;
+        obj.set::<_, Option<u32>>("maxProcesses", self.max_processes.map(|v| v))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::exec::types::Limits {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT limits",
+            &["timeMs", "memoryBytes", "fileSizeBytes", "maxProcesses"],
+            &[],
+            false,
+        )?;
+        let time_ms: Option<crate::wrappers::BigIntWrapper<u64>> = crate::wrappers::get_field(
+            &obj,
+            "timeMs",
+            "option<u64>",
+        )?;
+        let time_ms: Option<u64> = time_ms.map(|v| v.0);
+        let memory_bytes: Option<crate::wrappers::BigIntWrapper<u64>> = crate::wrappers::get_field(
+            &obj,
+            "memoryBytes",
+            "option<u64>",
+        )?;
+        let memory_bytes: Option<u64> = memory_bytes.map(|v| v.0);
+        let file_size_bytes: Option<crate::wrappers::BigIntWrapper<u64>> = crate::wrappers::get_field(
+            &obj,
+            "fileSizeBytes",
+            "option<u64>",
+        )?;
+        let file_size_bytes: Option<u64> = file_size_bytes.map(|v| v.0);
+        let max_processes: Option<u32> = crate::wrappers::get_field(
+            &obj,
+            "maxProcesses",
+            "option<u32>",
+        )?;
+        let max_processes: Option<u32> = max_processes.map(|v| v);
+        Ok(Self {
+            time_ms,
+            memory_bytes,
+            file_size_bytes,
+            max_processes,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::exec::types::StageResult {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("stdout", crate::wrappers::JsString(self.stdout))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("stderr", crate::wrappers::JsString(self.stderr))?;
+        obj.set::<_, Option<i32>>("exitCode", self.exit_code.map(|v| v))?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >("signal", self.signal.map(|v| crate::wrappers::JsString(v)))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::exec::types::StageResult {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT stage-result",
+            &["stdout", "stderr", "exitCode", "signal"],
+            &[("stdout", "string"), ("stderr", "string")],
+            false,
+        )?;
+        let stdout: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "stdout",
+            "string",
+        )?;
+        let stdout: String = stdout.0;
+        let stderr: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "stderr",
+            "string",
+        )?;
+        let stderr: String = stderr.0;
+        let exit_code: Option<i32> = crate::wrappers::get_field(
+            &obj,
+            "exitCode",
+            "option<s32>",
+        )?;
+        let exit_code: Option<i32> = exit_code.map(|v| v);
+        let signal: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "signal",
+            "option<string>",
+        )?;
+        let signal: Option<String> = signal.map(|v| v.0);
+        Ok(Self {
+            stdout,
+            stderr,
+            exit_code,
+            signal,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::exec::types::ExecResult {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                Option<crate::bindings::golem::exec::types::StageResult>,
+            >("compile", self.compile.map(|v| v))?;
+        obj.set::<_, crate::bindings::golem::exec::types::StageResult>("run", self.run)?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::BigIntWrapper<u64>>,
+            >("timeMs", self.time_ms.map(|v| crate::wrappers::BigIntWrapper(v)))?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::BigIntWrapper<u64>>,
+            >(
+            "memoryBytes",
+            self.memory_bytes.map(|v| crate::wrappers::BigIntWrapper(v)),
+        )?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::exec::types::ExecResult {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT exec-result",
+            &["compile", "run", "timeMs", "memoryBytes"],
+            &[("run", "stage-result")],
+            false,
+        )?;
+        let compile: Option<crate::bindings::golem::exec::types::StageResult> = crate::wrappers::get_field(
+            &obj,
+            "compile",
+            "option<stage-result>",
+        )?;
+        let compile: Option<crate::bindings::golem::exec::types::StageResult> = compile
+            .map(|v| v);
+        let run: crate::bindings::golem::exec::types::StageResult = crate::wrappers::get_field(
+            &obj,
+            "run",
+            "stage-result",
+        )?;
+        let run: crate::bindings::golem::exec::types::StageResult = run;
+        let time_ms: Option<crate::wrappers::BigIntWrapper<u64>> = crate::wrappers::get_field(
+            &obj,
+            "timeMs",
+            "option<u64>",
+        )?;
+        let time_ms: Option<u64> = time_ms.map(|v| v.0);
+        let memory_bytes: Option<crate::wrappers::BigIntWrapper<u64>> = crate::wrappers::get_field(
+            &obj,
+            "memoryBytes",
+            "option<u64>",
+        )?;
+        let memory_bytes: Option<u64> = memory_bytes.map(|v| v.0);
+        Ok(Self {
+            compile,
+            run,
+            time_ms,
+            memory_bytes,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::exec::types::Error {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::exec::types::Error::UnsupportedLanguage => {
+                crate::wrappers::variant_case(ctx, "unsupported-language")
+            }
+            crate::bindings::golem::exec::types::Error::CompilationFailed(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::exec::types::StageResult,
+                >(ctx, "compilation-failed", inner)
+            }
+            crate::bindings::golem::exec::types::Error::RuntimeFailed(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::exec::types::StageResult,
+                >(ctx, "runtime-failed", inner)
+            }
+            crate::bindings::golem::exec::types::Error::Timeout => {
+                crate::wrappers::variant_case(ctx, "timeout")
+            }
+            crate::bindings::golem::exec::types::Error::ResourceExceeded => {
+                crate::wrappers::variant_case(ctx, "resource-exceeded")
+            }
+            crate::bindings::golem::exec::types::Error::Internal(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "internal", crate::wrappers::JsString(inner))
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::exec::types::Error {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "unsupported-language" => {
+                Ok(crate::bindings::golem::exec::types::Error::UnsupportedLanguage)
+            }
+            "compilation-failed" => {
+                let inner: crate::bindings::golem::exec::types::StageResult = crate::wrappers::variant_value(
+                    &obj,
+                    "stage-result",
+                )?;
+                Ok(crate::bindings::golem::exec::types::Error::CompilationFailed(inner))
+            }
+            "runtime-failed" => {
+                let inner: crate::bindings::golem::exec::types::StageResult = crate::wrappers::variant_value(
+                    &obj,
+                    "stage-result",
+                )?;
+                Ok(crate::bindings::golem::exec::types::Error::RuntimeFailed(inner))
+            }
+            "timeout" => Ok(crate::bindings::golem::exec::types::Error::Timeout),
+            "resource-exceeded" => {
+                Ok(crate::bindings::golem::exec::types::Error::ResourceExceeded)
+            }
+            "internal" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(crate::bindings::golem::exec::types::Error::Internal(inner.0))
+            }
+            _ => {
+                Err(crate::wrappers::unknown_variant_case("JS error", "WIT error", &tag))
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::graph::types::Date {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<_, u32>("year", self.year)?;
+        obj.set::<_, u8>("month", self.month)?;
+        obj.set::<_, u8>("day", self.day)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::graph::types::Date {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT date",
+            &["year", "month", "day"],
+            &[("year", "u32"), ("month", "u8"), ("day", "u8")],
+            false,
+        )?;
+        let year: u32 = crate::wrappers::get_field(&obj, "year", "u32")?;
+        let year: u32 = year;
+        let month: u8 = crate::wrappers::get_field(&obj, "month", "u8")?;
+        let month: u8 = month;
+        let day: u8 = crate::wrappers::get_field(&obj, "day", "u8")?;
+        let day: u8 = day;
+        Ok(Self { year, month, day })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::graph::types::Time {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<_, u8>("hour", self.hour)?;
+        obj.set::<_, u8>("minute", self.minute)?;
+        obj.set::<_, u8>("second", self.second)?;
+        obj.set::<_, u32>("nanosecond", self.nanosecond)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::graph::types::Time {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT time",
+            &["hour", "minute", "second", "nanosecond"],
+            &[("hour", "u8"), ("minute", "u8"), ("second", "u8"), ("nanosecond", "u32")],
+            false,
+        )?;
+        let hour: u8 = crate::wrappers::get_field(&obj, "hour", "u8")?;
+        let hour: u8 = hour;
+        let minute: u8 = crate::wrappers::get_field(&obj, "minute", "u8")?;
+        let minute: u8 = minute;
+        let second: u8 = crate::wrappers::get_field(&obj, "second", "u8")?;
+        let second: u8 = second;
+        let nanosecond: u32 = crate::wrappers::get_field(&obj, "nanosecond", "u32")?;
+        let nanosecond: u32 = nanosecond;
+        Ok(Self {
+            hour,
+            minute,
+            second,
+            nanosecond,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::graph::types::Datetime {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<_, crate::bindings::golem::graph::types::Date>("date", self.date)?;
+        obj.set::<_, crate::bindings::golem::graph::types::Time>("time", self.time)?;
+        obj.set::<
+                _,
+                Option<i16>,
+            >("timezoneOffsetMinutes", self.timezone_offset_minutes.map(|v| v))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::graph::types::Datetime {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT datetime",
+            &["date", "time", "timezoneOffsetMinutes"],
+            &[("date", "date"), ("time", "time")],
+            false,
+        )?;
+        let date: crate::bindings::golem::graph::types::Date = crate::wrappers::get_field(
+            &obj,
+            "date",
+            "date",
+        )?;
+        let date: crate::bindings::golem::graph::types::Date = date;
+        let time: crate::bindings::golem::graph::types::Time = crate::wrappers::get_field(
+            &obj,
+            "time",
+            "time",
+        )?;
+        let time: crate::bindings::golem::graph::types::Time = time;
+        let timezone_offset_minutes: Option<i16> = crate::wrappers::get_field(
+            &obj,
+            "timezoneOffsetMinutes",
+            "option<s16>",
+        )?;
+        let timezone_offset_minutes: Option<i16> = timezone_offset_minutes.map(|v| v);
+        Ok(Self {
+            date,
+            time,
+            timezone_offset_minutes,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::graph::types::Duration {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::BigIntWrapper<i64>,
+            >("seconds", crate::wrappers::BigIntWrapper(self.seconds))?;
+        obj.set::<_, u32>("nanoseconds", self.nanoseconds)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::graph::types::Duration {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT duration",
+            &["seconds", "nanoseconds"],
+            &[("seconds", "s64"), ("nanoseconds", "u32")],
+            false,
+        )?;
+        let seconds: crate::wrappers::BigIntWrapper<i64> = crate::wrappers::get_field(
+            &obj,
+            "seconds",
+            "s64",
+        )?;
+        let seconds: i64 = seconds.0;
+        let nanoseconds: u32 = crate::wrappers::get_field(&obj, "nanoseconds", "u32")?;
+        let nanoseconds: u32 = nanoseconds;
+        Ok(Self { seconds, nanoseconds })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::graph::types::Point {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<_, f64>("longitude", self.longitude)?;
+        obj.set::<_, f64>("latitude", self.latitude)?;
+        obj.set::<_, Option<f64>>("altitude", self.altitude.map(|v| v))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::graph::types::Point {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT point",
+            &["longitude", "latitude", "altitude"],
+            &[("longitude", "f64"), ("latitude", "f64")],
+            false,
+        )?;
+        let longitude: f64 = crate::wrappers::get_field(&obj, "longitude", "f64")?;
+        let longitude: f64 = longitude;
+        let latitude: f64 = crate::wrappers::get_field(&obj, "latitude", "f64")?;
+        let latitude: f64 = latitude;
+        let altitude: Option<f64> = crate::wrappers::get_field(
+            &obj,
+            "altitude",
+            "option<f64>",
+        )?;
+        let altitude: Option<f64> = altitude.map(|v| v);
+        Ok(Self {
+            longitude,
+            latitude,
+            altitude,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::graph::types::Linestring {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<crate::bindings::golem::graph::types::Point>,
+            >(
+            "coordinates",
+            crate::wrappers::JsList(
+                self.coordinates.into_iter().map(|v| v).collect::<Vec<_>>(),
+            ),
+        )?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::graph::types::Linestring {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT linestring",
+            &["coordinates"],
+            &[("coordinates", "list<point>")],
+            false,
+        )?;
+        let coordinates: crate::wrappers::JsList<
+            crate::bindings::golem::graph::types::Point,
+        > = crate::wrappers::get_field(&obj, "coordinates", "list<point>")?;
+        let coordinates: Vec<crate::bindings::golem::graph::types::Point> = coordinates
+            .0
+            .into_iter()
+            .map(|v| v)
+            .collect::<Vec<_>>();
+        Ok(Self { coordinates })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::graph::types::Polygon {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<crate::bindings::golem::graph::types::Point>,
+            >(
+            "exterior",
+            crate::wrappers::JsList(
+                self.exterior.into_iter().map(|v| v).collect::<Vec<_>>(),
+            ),
+        )?;
+        obj.set::<
+                _,
+                Option<
+                    crate::wrappers::JsList<
+                        crate::wrappers::JsList<
+                            crate::bindings::golem::graph::types::Point,
+                        >,
+                    >,
+                >,
+            >(
+            "holes",
+            self
+                .holes
+                .map(|v| crate::wrappers::JsList(
+                    v
+                        .into_iter()
+                        .map(|v| crate::wrappers::JsList(
+                            v.into_iter().map(|v| v).collect::<Vec<_>>(),
+                        ))
+                        .collect::<Vec<_>>(),
+                )),
+        )?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::graph::types::Polygon {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT polygon",
+            &["exterior", "holes"],
+            &[("exterior", "list<point>")],
+            false,
+        )?;
+        let exterior: crate::wrappers::JsList<
+            crate::bindings::golem::graph::types::Point,
+        > = crate::wrappers::get_field(&obj, "exterior", "list<point>")?;
+        let exterior: Vec<crate::bindings::golem::graph::types::Point> = exterior
+            .0
+            .into_iter()
+            .map(|v| v)
+            .collect::<Vec<_>>();
+        let holes: Option<
+            crate::wrappers::JsList<
+                crate::wrappers::JsList<crate::bindings::golem::graph::types::Point>,
+            >,
+        > = crate::wrappers::get_field(&obj, "holes", "option<list<list<point>>>")?;
+        let holes: Option<Vec<Vec<crate::bindings::golem::graph::types::Point>>> = holes
+            .map(|v| {
+                v
+                    .0
+                    .into_iter()
+                    .map(|v| v.0.into_iter().map(|v| v).collect::<Vec<_>>())
+                    .collect::<Vec<_>>()
+            });
+        Ok(Self { exterior, holes })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::graph::types::PropertyValue {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::graph::types::PropertyValue::NullValue => {
+                crate::wrappers::variant_case(ctx, "null-value")
+            }
+            crate::bindings::golem::graph::types::PropertyValue::Boolean(inner) => {
+                crate::wrappers::variant_case_with_value::<bool>(ctx, "boolean", inner)
+            }
+            crate::bindings::golem::graph::types::PropertyValue::Int8(inner) => {
+                crate::wrappers::variant_case_with_value::<i8>(ctx, "int8", inner)
+            }
+            crate::bindings::golem::graph::types::PropertyValue::Int16(inner) => {
+                crate::wrappers::variant_case_with_value::<i16>(ctx, "int16", inner)
+            }
+            crate::bindings::golem::graph::types::PropertyValue::Int32(inner) => {
+                crate::wrappers::variant_case_with_value::<i32>(ctx, "int32", inner)
+            }
+            crate::bindings::golem::graph::types::PropertyValue::Int64(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::BigIntWrapper<i64>,
+                >(ctx, "int64", crate::wrappers::BigIntWrapper(inner))
+            }
+            crate::bindings::golem::graph::types::PropertyValue::Uint8(inner) => {
+                crate::wrappers::variant_case_with_value::<u8>(ctx, "uint8", inner)
+            }
+            crate::bindings::golem::graph::types::PropertyValue::Uint16(inner) => {
+                crate::wrappers::variant_case_with_value::<u16>(ctx, "uint16", inner)
+            }
+            crate::bindings::golem::graph::types::PropertyValue::Uint32(inner) => {
+                crate::wrappers::variant_case_with_value::<u32>(ctx, "uint32", inner)
+            }
+            crate::bindings::golem::graph::types::PropertyValue::Uint64(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::BigIntWrapper<u64>,
+                >(ctx, "uint64", crate::wrappers::BigIntWrapper(inner))
+            }
+            crate::bindings::golem::graph::types::PropertyValue::Float32Value(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    f32,
+                >(ctx, "float32-value", inner)
+            }
+            crate::bindings::golem::graph::types::PropertyValue::Float64Value(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    f64,
+                >(ctx, "float64-value", inner)
+            }
+            crate::bindings::golem::graph::types::PropertyValue::StringValue(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "string-value", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::graph::types::PropertyValue::Bytes(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::UInt8Array,
+                >(ctx, "bytes", crate::wrappers::UInt8Array(inner))
+            }
+            crate::bindings::golem::graph::types::PropertyValue::Date(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::graph::types::Date,
+                >(ctx, "date", inner)
+            }
+            crate::bindings::golem::graph::types::PropertyValue::Time(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::graph::types::Time,
+                >(ctx, "time", inner)
+            }
+            crate::bindings::golem::graph::types::PropertyValue::Datetime(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::graph::types::Datetime,
+                >(ctx, "datetime", inner)
+            }
+            crate::bindings::golem::graph::types::PropertyValue::Duration(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::graph::types::Duration,
+                >(ctx, "duration", inner)
+            }
+            crate::bindings::golem::graph::types::PropertyValue::Point(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::graph::types::Point,
+                >(ctx, "point", inner)
+            }
+            crate::bindings::golem::graph::types::PropertyValue::Linestring(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::graph::types::Linestring,
+                >(ctx, "linestring", inner)
+            }
+            crate::bindings::golem::graph::types::PropertyValue::Polygon(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::graph::types::Polygon,
+                >(ctx, "polygon", inner)
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::graph::types::PropertyValue {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "null-value" => {
+                Ok(crate::bindings::golem::graph::types::PropertyValue::NullValue)
+            }
+            "boolean" => {
+                let inner: bool = crate::wrappers::variant_value(&obj, "bool")?;
+                Ok(crate::bindings::golem::graph::types::PropertyValue::Boolean(inner))
+            }
+            "int8" => {
+                let inner: i8 = crate::wrappers::variant_value(&obj, "s8")?;
+                Ok(crate::bindings::golem::graph::types::PropertyValue::Int8(inner))
+            }
+            "int16" => {
+                let inner: i16 = crate::wrappers::variant_value(&obj, "s16")?;
+                Ok(crate::bindings::golem::graph::types::PropertyValue::Int16(inner))
+            }
+            "int32" => {
+                let inner: i32 = crate::wrappers::variant_value(&obj, "s32")?;
+                Ok(crate::bindings::golem::graph::types::PropertyValue::Int32(inner))
+            }
+            "int64" => {
+                let inner: crate::wrappers::BigIntWrapper<i64> = crate::wrappers::variant_value(
+                    &obj,
+                    "s64",
+                )?;
+                Ok(crate::bindings::golem::graph::types::PropertyValue::Int64(inner.0))
+            }
+            "uint8" => {
+                let inner: u8 = crate::wrappers::variant_value(&obj, "u8")?;
+                Ok(crate::bindings::golem::graph::types::PropertyValue::Uint8(inner))
+            }
+            "uint16" => {
+                let inner: u16 = crate::wrappers::variant_value(&obj, "u16")?;
+                Ok(crate::bindings::golem::graph::types::PropertyValue::Uint16(inner))
+            }
+            "uint32" => {
+                let inner: u32 = crate::wrappers::variant_value(&obj, "u32")?;
+                Ok(crate::bindings::golem::graph::types::PropertyValue::Uint32(inner))
+            }
+            "uint64" => {
+                let inner: crate::wrappers::BigIntWrapper<u64> = crate::wrappers::variant_value(
+                    &obj,
+                    "u64",
+                )?;
+                Ok(crate::bindings::golem::graph::types::PropertyValue::Uint64(inner.0))
+            }
+            "float32-value" => {
+                let inner: f32 = crate::wrappers::variant_value(&obj, "f32")?;
+                Ok(
+                    crate::bindings::golem::graph::types::PropertyValue::Float32Value(
+                        inner,
+                    ),
+                )
+            }
+            "float64-value" => {
+                let inner: f64 = crate::wrappers::variant_value(&obj, "f64")?;
+                Ok(
+                    crate::bindings::golem::graph::types::PropertyValue::Float64Value(
+                        inner,
+                    ),
+                )
+            }
+            "string-value" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(
+                    crate::bindings::golem::graph::types::PropertyValue::StringValue(
+                        inner.0,
+                    ),
+                )
+            }
+            "bytes" => {
+                let inner: crate::wrappers::UInt8Array = crate::wrappers::variant_value(
+                    &obj,
+                    "list<u8>",
+                )?;
+                Ok(crate::bindings::golem::graph::types::PropertyValue::Bytes(inner.0))
+            }
+            "date" => {
+                let inner: crate::bindings::golem::graph::types::Date = crate::wrappers::variant_value(
+                    &obj,
+                    "date",
+                )?;
+                Ok(crate::bindings::golem::graph::types::PropertyValue::Date(inner))
+            }
+            "time" => {
+                let inner: crate::bindings::golem::graph::types::Time = crate::wrappers::variant_value(
+                    &obj,
+                    "time",
+                )?;
+                Ok(crate::bindings::golem::graph::types::PropertyValue::Time(inner))
+            }
+            "datetime" => {
+                let inner: crate::bindings::golem::graph::types::Datetime = crate::wrappers::variant_value(
+                    &obj,
+                    "datetime",
+                )?;
+                Ok(crate::bindings::golem::graph::types::PropertyValue::Datetime(inner))
+            }
+            "duration" => {
+                let inner: crate::bindings::golem::graph::types::Duration = crate::wrappers::variant_value(
+                    &obj,
+                    "duration",
+                )?;
+                Ok(crate::bindings::golem::graph::types::PropertyValue::Duration(inner))
+            }
+            "point" => {
+                let inner: crate::bindings::golem::graph::types::Point = crate::wrappers::variant_value(
+                    &obj,
+                    "point",
+                )?;
+                Ok(crate::bindings::golem::graph::types::PropertyValue::Point(inner))
+            }
+            "linestring" => {
+                let inner: crate::bindings::golem::graph::types::Linestring = crate::wrappers::variant_value(
+                    &obj,
+                    "linestring",
+                )?;
+                Ok(
+                    crate::bindings::golem::graph::types::PropertyValue::Linestring(
+                        inner,
+                    ),
+                )
+            }
+            "polygon" => {
+                let inner: crate::bindings::golem::graph::types::Polygon = crate::wrappers::variant_value(
+                    &obj,
+                    "polygon",
+                )?;
+                Ok(crate::bindings::golem::graph::types::PropertyValue::Polygon(inner))
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS property-value",
+                        "WIT property-value",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::graph::types::ElementId {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::graph::types::ElementId::StringValue(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "string-value", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::graph::types::ElementId::Int64(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::BigIntWrapper<i64>,
+                >(ctx, "int64", crate::wrappers::BigIntWrapper(inner))
+            }
+            crate::bindings::golem::graph::types::ElementId::Uuid(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "uuid", crate::wrappers::JsString(inner))
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::graph::types::ElementId {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "string-value" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(crate::bindings::golem::graph::types::ElementId::StringValue(inner.0))
+            }
+            "int64" => {
+                let inner: crate::wrappers::BigIntWrapper<i64> = crate::wrappers::variant_value(
+                    &obj,
+                    "s64",
+                )?;
+                Ok(crate::bindings::golem::graph::types::ElementId::Int64(inner.0))
+            }
+            "uuid" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(crate::bindings::golem::graph::types::ElementId::Uuid(inner.0))
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS element-id",
+                        "WIT element-id",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::graph::types::Vertex {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<_, crate::bindings::golem::graph::types::ElementId>("id", self.id)?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("vertexType", crate::wrappers::JsString(self.vertex_type))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<crate::wrappers::JsString>,
+            >(
+            "additionalLabels",
+            crate::wrappers::JsList(
+                self
+                    .additional_labels
+                    .into_iter()
+                    .map(|v| crate::wrappers::JsString(v))
+                    .collect::<Vec<_>>(),
+            ),
+        )?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<
+                    rquickjs::convert::List<
+                        (
+                            crate::wrappers::JsString,
+                            crate::bindings::golem::graph::types::PropertyValue,
+                        ),
+                    >,
+                >,
+            >(
+            "properties",
+            crate::wrappers::JsList(
+                self
+                    .properties
+                    .into_iter()
+                    .map(|v| rquickjs::convert::List((
+                        crate::wrappers::JsString(v.0),
+                        v.1,
+                    )))
+                    .collect::<Vec<_>>(),
+            ),
+        )?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::graph::types::Vertex {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT vertex",
+            &["id", "vertexType", "additionalLabels", "properties"],
+            &[
+                ("id", "element-id"),
+                ("vertexType", "string"),
+                ("additionalLabels", "list<string>"),
+                ("properties", "property-map"),
+            ],
+            false,
+        )?;
+        let id: crate::bindings::golem::graph::types::ElementId = crate::wrappers::get_field(
+            &obj,
+            "id",
+            "element-id",
+        )?;
+        let id: crate::bindings::golem::graph::types::ElementId = id;
+        let vertex_type: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "vertexType",
+            "string",
+        )?;
+        let vertex_type: String = vertex_type.0;
+        let additional_labels: crate::wrappers::JsList<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "additionalLabels",
+            "list<string>",
+        )?;
+        let additional_labels: Vec<String> = additional_labels
+            .0
+            .into_iter()
+            .map(|v| v.0)
+            .collect::<Vec<_>>();
+        let properties: crate::wrappers::JsList<
+            rquickjs::convert::List<
+                (
+                    crate::wrappers::JsString,
+                    crate::bindings::golem::graph::types::PropertyValue,
+                ),
+            >,
+        > = crate::wrappers::get_field(&obj, "properties", "property-map")?;
+        let properties: Vec<
+            (String, crate::bindings::golem::graph::types::PropertyValue),
+        > = properties
+            .0
+            .into_iter()
+            .map(|v| (v.0.0.0.clone(), v.0.1))
+            .collect::<Vec<_>>();
+        Ok(Self {
+            id,
+            vertex_type,
+            additional_labels,
+            properties,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::graph::types::Edge {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<_, crate::bindings::golem::graph::types::ElementId>("id", self.id)?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("edgeType", crate::wrappers::JsString(self.edge_type))?;
+        obj.set::<
+                _,
+                crate::bindings::golem::graph::types::ElementId,
+            >("fromVertex", self.from_vertex)?;
+        obj.set::<
+                _,
+                crate::bindings::golem::graph::types::ElementId,
+            >("toVertex", self.to_vertex)?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<
+                    rquickjs::convert::List<
+                        (
+                            crate::wrappers::JsString,
+                            crate::bindings::golem::graph::types::PropertyValue,
+                        ),
+                    >,
+                >,
+            >(
+            "properties",
+            crate::wrappers::JsList(
+                self
+                    .properties
+                    .into_iter()
+                    .map(|v| rquickjs::convert::List((
+                        crate::wrappers::JsString(v.0),
+                        v.1,
+                    )))
+                    .collect::<Vec<_>>(),
+            ),
+        )?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::graph::types::Edge {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT edge",
+            &["id", "edgeType", "fromVertex", "toVertex", "properties"],
+            &[
+                ("id", "element-id"),
+                ("edgeType", "string"),
+                ("fromVertex", "element-id"),
+                ("toVertex", "element-id"),
+                ("properties", "property-map"),
+            ],
+            false,
+        )?;
+        let id: crate::bindings::golem::graph::types::ElementId = crate::wrappers::get_field(
+            &obj,
+            "id",
+            "element-id",
+        )?;
+        let id: crate::bindings::golem::graph::types::ElementId = id;
+        let edge_type: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "edgeType",
+            "string",
+        )?;
+        let edge_type: String = edge_type.0;
+        let from_vertex: crate::bindings::golem::graph::types::ElementId = crate::wrappers::get_field(
+            &obj,
+            "fromVertex",
+            "element-id",
+        )?;
+        let from_vertex: crate::bindings::golem::graph::types::ElementId = from_vertex;
+        let to_vertex: crate::bindings::golem::graph::types::ElementId = crate::wrappers::get_field(
+            &obj,
+            "toVertex",
+            "element-id",
+        )?;
+        let to_vertex: crate::bindings::golem::graph::types::ElementId = to_vertex;
+        let properties: crate::wrappers::JsList<
+            rquickjs::convert::List<
+                (
+                    crate::wrappers::JsString,
+                    crate::bindings::golem::graph::types::PropertyValue,
+                ),
+            >,
+        > = crate::wrappers::get_field(&obj, "properties", "property-map")?;
+        let properties: Vec<
+            (String, crate::bindings::golem::graph::types::PropertyValue),
+        > = properties
+            .0
+            .into_iter()
+            .map(|v| (v.0.0.0.clone(), v.0.1))
+            .collect::<Vec<_>>();
+        Ok(Self {
+            id,
+            edge_type,
+            from_vertex,
+            to_vertex,
+            properties,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::graph::types::Path {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<crate::bindings::golem::graph::types::Vertex>,
+            >(
+            "vertices",
+            crate::wrappers::JsList(
+                self.vertices.into_iter().map(|v| v).collect::<Vec<_>>(),
+            ),
+        )?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<crate::bindings::golem::graph::types::Edge>,
+            >(
+            "edges",
+            crate::wrappers::JsList(
+                self.edges.into_iter().map(|v| v).collect::<Vec<_>>(),
+            ),
+        )?;
+        obj.set::<_, u32>("length", self.length)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::graph::types::Path {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT path",
+            &["vertices", "edges", "length"],
+            &[("vertices", "list<vertex>"), ("edges", "list<edge>"), ("length", "u32")],
+            false,
+        )?;
+        let vertices: crate::wrappers::JsList<
+            crate::bindings::golem::graph::types::Vertex,
+        > = crate::wrappers::get_field(&obj, "vertices", "list<vertex>")?;
+        let vertices: Vec<crate::bindings::golem::graph::types::Vertex> = vertices
+            .0
+            .into_iter()
+            .map(|v| v)
+            .collect::<Vec<_>>();
+        let edges: crate::wrappers::JsList<crate::bindings::golem::graph::types::Edge> = crate::wrappers::get_field(
+            &obj,
+            "edges",
+            "list<edge>",
+        )?;
+        let edges: Vec<crate::bindings::golem::graph::types::Edge> = edges
+            .0
+            .into_iter()
+            .map(|v| v)
+            .collect::<Vec<_>>();
+        let length: u32 = crate::wrappers::get_field(&obj, "length", "u32")?;
+        let length: u32 = length;
+        Ok(Self { vertices, edges, length })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::graph::types::Direction {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::graph::types::Direction::Outgoing => {
+                "outgoing".into_js(ctx)
+            }
+            crate::bindings::golem::graph::types::Direction::Incoming => {
+                "incoming".into_js(ctx)
+            }
+            crate::bindings::golem::graph::types::Direction::Both => "both".into_js(ctx),
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::graph::types::Direction {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let value = crate::wrappers::enum_case(value, "JS direction", "WIT direction")?;
+        match value.as_str() {
+            "outgoing" => Ok(crate::bindings::golem::graph::types::Direction::Outgoing),
+            "incoming" => Ok(crate::bindings::golem::graph::types::Direction::Incoming),
+            "both" => Ok(crate::bindings::golem::graph::types::Direction::Both),
+            _ => {
+                Err(
+                    crate::wrappers::unknown_enum_case(
+                        "JS direction",
+                        "WIT direction",
+                        &value,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::graph::types::ComparisonOperator {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::graph::types::ComparisonOperator::Equal => {
+                "equal".into_js(ctx)
+            }
+            crate::bindings::golem::graph::types::ComparisonOperator::NotEqual => {
+                "not-equal".into_js(ctx)
+            }
+            crate::bindings::golem::graph::types::ComparisonOperator::LessThan => {
+                "less-than".into_js(ctx)
+            }
+            crate::bindings::golem::graph::types::ComparisonOperator::LessThanOrEqual => {
+                "less-than-or-equal".into_js(ctx)
+            }
+            crate::bindings::golem::graph::types::ComparisonOperator::GreaterThan => {
+                "greater-than".into_js(ctx)
+            }
+            crate::bindings::golem::graph::types::ComparisonOperator::GreaterThanOrEqual => {
+                "greater-than-or-equal".into_js(ctx)
+            }
+            crate::bindings::golem::graph::types::ComparisonOperator::Contains => {
+                "contains".into_js(ctx)
+            }
+            crate::bindings::golem::graph::types::ComparisonOperator::StartsWith => {
+                "starts-with".into_js(ctx)
+            }
+            crate::bindings::golem::graph::types::ComparisonOperator::EndsWith => {
+                "ends-with".into_js(ctx)
+            }
+            crate::bindings::golem::graph::types::ComparisonOperator::RegexMatch => {
+                "regex-match".into_js(ctx)
+            }
+            crate::bindings::golem::graph::types::ComparisonOperator::InList => {
+                "in-list".into_js(ctx)
+            }
+            crate::bindings::golem::graph::types::ComparisonOperator::NotInList => {
+                "not-in-list".into_js(ctx)
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::graph::types::ComparisonOperator {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let value = crate::wrappers::enum_case(
+            value,
+            "JS comparison-operator",
+            "WIT comparison-operator",
+        )?;
+        match value.as_str() {
+            "equal" => {
+                Ok(crate::bindings::golem::graph::types::ComparisonOperator::Equal)
+            }
+            "not-equal" => {
+                Ok(crate::bindings::golem::graph::types::ComparisonOperator::NotEqual)
+            }
+            "less-than" => {
+                Ok(crate::bindings::golem::graph::types::ComparisonOperator::LessThan)
+            }
+            "less-than-or-equal" => {
+                Ok(
+                    crate::bindings::golem::graph::types::ComparisonOperator::LessThanOrEqual,
+                )
+            }
+            "greater-than" => {
+                Ok(crate::bindings::golem::graph::types::ComparisonOperator::GreaterThan)
+            }
+            "greater-than-or-equal" => {
+                Ok(
+                    crate::bindings::golem::graph::types::ComparisonOperator::GreaterThanOrEqual,
+                )
+            }
+            "contains" => {
+                Ok(crate::bindings::golem::graph::types::ComparisonOperator::Contains)
+            }
+            "starts-with" => {
+                Ok(crate::bindings::golem::graph::types::ComparisonOperator::StartsWith)
+            }
+            "ends-with" => {
+                Ok(crate::bindings::golem::graph::types::ComparisonOperator::EndsWith)
+            }
+            "regex-match" => {
+                Ok(crate::bindings::golem::graph::types::ComparisonOperator::RegexMatch)
+            }
+            "in-list" => {
+                Ok(crate::bindings::golem::graph::types::ComparisonOperator::InList)
+            }
+            "not-in-list" => {
+                Ok(crate::bindings::golem::graph::types::ComparisonOperator::NotInList)
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_enum_case(
+                        "JS comparison-operator",
+                        "WIT comparison-operator",
+                        &value,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::graph::types::FilterCondition {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("property", crate::wrappers::JsString(self.property))?;
+        obj.set::<
+                _,
+                crate::bindings::golem::graph::types::ComparisonOperator,
+            >("operator", self.operator)?;
+        obj.set::<
+                _,
+                crate::bindings::golem::graph::types::PropertyValue,
+            >("value", self.value)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::graph::types::FilterCondition {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT filter-condition",
+            &["property", "operator", "value"],
+            &[
+                ("property", "string"),
+                ("operator", "comparison-operator"),
+                ("value", "property-value"),
+            ],
+            false,
+        )?;
+        let property: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "property",
+            "string",
+        )?;
+        let property: String = property.0;
+        let operator: crate::bindings::golem::graph::types::ComparisonOperator = crate::wrappers::get_field(
+            &obj,
+            "operator",
+            "comparison-operator",
+        )?;
+        let operator: crate::bindings::golem::graph::types::ComparisonOperator = operator;
+        let value: crate::bindings::golem::graph::types::PropertyValue = crate::wrappers::get_field(
+            &obj,
+            "value",
+            "property-value",
+        )?;
+        let value: crate::bindings::golem::graph::types::PropertyValue = value;
+        Ok(Self { property, operator, value })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::graph::types::SortSpec {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("property", crate::wrappers::JsString(self.property))?;
+        obj.set::<_, bool>("ascending", self.ascending)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::graph::types::SortSpec {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT sort-spec",
+            &["property", "ascending"],
+            &[("property", "string"), ("ascending", "bool")],
+            false,
+        )?;
+        let property: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "property",
+            "string",
+        )?;
+        let property: String = property.0;
+        let ascending: bool = crate::wrappers::get_field(&obj, "ascending", "bool")?;
+        let ascending: bool = ascending;
+        Ok(Self { property, ascending })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::graph::errors::GraphError {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::graph::errors::GraphError::UnsupportedOperation(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "unsupported-operation", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::graph::errors::GraphError::ConnectionFailed(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "connection-failed", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::graph::errors::GraphError::AuthenticationFailed(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "authentication-failed", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::graph::errors::GraphError::AuthorizationFailed(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "authorization-failed", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::graph::errors::GraphError::ElementNotFound(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::graph::types::ElementId,
+                >(ctx, "element-not-found", inner)
+            }
+            crate::bindings::golem::graph::errors::GraphError::DuplicateElement(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::graph::types::ElementId,
+                >(ctx, "duplicate-element", inner)
+            }
+            crate::bindings::golem::graph::errors::GraphError::SchemaViolation(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "schema-violation", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::graph::errors::GraphError::ConstraintViolation(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "constraint-violation", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::graph::errors::GraphError::InvalidPropertyType(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "invalid-property-type", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::graph::errors::GraphError::InvalidQuery(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "invalid-query", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::graph::errors::GraphError::TransactionFailed(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "transaction-failed", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::graph::errors::GraphError::TransactionConflict => {
+                crate::wrappers::variant_case(ctx, "transaction-conflict")
+            }
+            crate::bindings::golem::graph::errors::GraphError::TransactionTimeout => {
+                crate::wrappers::variant_case(ctx, "transaction-timeout")
+            }
+            crate::bindings::golem::graph::errors::GraphError::DeadlockDetected => {
+                crate::wrappers::variant_case(ctx, "deadlock-detected")
+            }
+            crate::bindings::golem::graph::errors::GraphError::Timeout => {
+                crate::wrappers::variant_case(ctx, "timeout")
+            }
+            crate::bindings::golem::graph::errors::GraphError::ResourceExhausted(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "resource-exhausted", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::graph::errors::GraphError::InternalError(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "internal-error", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::graph::errors::GraphError::ServiceUnavailable(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "service-unavailable", crate::wrappers::JsString(inner))
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::graph::errors::GraphError {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "unsupported-operation" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(
+                    crate::bindings::golem::graph::errors::GraphError::UnsupportedOperation(
+                        inner.0,
+                    ),
+                )
+            }
+            "connection-failed" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(
+                    crate::bindings::golem::graph::errors::GraphError::ConnectionFailed(
+                        inner.0,
+                    ),
+                )
+            }
+            "authentication-failed" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(
+                    crate::bindings::golem::graph::errors::GraphError::AuthenticationFailed(
+                        inner.0,
+                    ),
+                )
+            }
+            "authorization-failed" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(
+                    crate::bindings::golem::graph::errors::GraphError::AuthorizationFailed(
+                        inner.0,
+                    ),
+                )
+            }
+            "element-not-found" => {
+                let inner: crate::bindings::golem::graph::types::ElementId = crate::wrappers::variant_value(
+                    &obj,
+                    "element-id",
+                )?;
+                Ok(
+                    crate::bindings::golem::graph::errors::GraphError::ElementNotFound(
+                        inner,
+                    ),
+                )
+            }
+            "duplicate-element" => {
+                let inner: crate::bindings::golem::graph::types::ElementId = crate::wrappers::variant_value(
+                    &obj,
+                    "element-id",
+                )?;
+                Ok(
+                    crate::bindings::golem::graph::errors::GraphError::DuplicateElement(
+                        inner,
+                    ),
+                )
+            }
+            "schema-violation" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(
+                    crate::bindings::golem::graph::errors::GraphError::SchemaViolation(
+                        inner.0,
+                    ),
+                )
+            }
+            "constraint-violation" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(
+                    crate::bindings::golem::graph::errors::GraphError::ConstraintViolation(
+                        inner.0,
+                    ),
+                )
+            }
+            "invalid-property-type" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(
+                    crate::bindings::golem::graph::errors::GraphError::InvalidPropertyType(
+                        inner.0,
+                    ),
+                )
+            }
+            "invalid-query" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(
+                    crate::bindings::golem::graph::errors::GraphError::InvalidQuery(
+                        inner.0,
+                    ),
+                )
+            }
+            "transaction-failed" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(
+                    crate::bindings::golem::graph::errors::GraphError::TransactionFailed(
+                        inner.0,
+                    ),
+                )
+            }
+            "transaction-conflict" => {
+                Ok(
+                    crate::bindings::golem::graph::errors::GraphError::TransactionConflict,
+                )
+            }
+            "transaction-timeout" => {
+                Ok(crate::bindings::golem::graph::errors::GraphError::TransactionTimeout)
+            }
+            "deadlock-detected" => {
+                Ok(crate::bindings::golem::graph::errors::GraphError::DeadlockDetected)
+            }
+            "timeout" => Ok(crate::bindings::golem::graph::errors::GraphError::Timeout),
+            "resource-exhausted" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(
+                    crate::bindings::golem::graph::errors::GraphError::ResourceExhausted(
+                        inner.0,
+                    ),
+                )
+            }
+            "internal-error" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(
+                    crate::bindings::golem::graph::errors::GraphError::InternalError(
+                        inner.0,
+                    ),
+                )
+            }
+            "service-unavailable" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(
+                    crate::bindings::golem::graph::errors::GraphError::ServiceUnavailable(
+                        inner.0,
+                    ),
+                )
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS graph-error",
+                        "WIT graph-error",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::graph::transactions::VertexSpec {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("vertexType", crate::wrappers::JsString(self.vertex_type))?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsList<crate::wrappers::JsString>>,
+            >(
+            "additionalLabels",
+            self
+                .additional_labels
+                .map(|v| crate::wrappers::JsList(
+                    v
+                        .into_iter()
+                        .map(|v| crate::wrappers::JsString(v))
+                        .collect::<Vec<_>>(),
+                )),
+        )?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<
+                    rquickjs::convert::List<
+                        (
+                            crate::wrappers::JsString,
+                            crate::bindings::golem::graph::types::PropertyValue,
+                        ),
+                    >,
+                >,
+            >(
+            "properties",
+            crate::wrappers::JsList(
+                self
+                    .properties
+                    .into_iter()
+                    .map(|v| rquickjs::convert::List((
+                        crate::wrappers::JsString(v.0),
+                        v.1,
+                    )))
+                    .collect::<Vec<_>>(),
+            ),
+        )?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::graph::transactions::VertexSpec {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT vertex-spec",
+            &["vertexType", "additionalLabels", "properties"],
+            &[("vertexType", "string"), ("properties", "property-map")],
+            false,
+        )?;
+        let vertex_type: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "vertexType",
+            "string",
+        )?;
+        let vertex_type: String = vertex_type.0;
+        let additional_labels: Option<
+            crate::wrappers::JsList<crate::wrappers::JsString>,
+        > = crate::wrappers::get_field(
+            &obj,
+            "additionalLabels",
+            "option<list<string>>",
+        )?;
+        let additional_labels: Option<Vec<String>> = additional_labels
+            .map(|v| v.0.into_iter().map(|v| v.0).collect::<Vec<_>>());
+        let properties: crate::wrappers::JsList<
+            rquickjs::convert::List<
+                (
+                    crate::wrappers::JsString,
+                    crate::bindings::golem::graph::types::PropertyValue,
+                ),
+            >,
+        > = crate::wrappers::get_field(&obj, "properties", "property-map")?;
+        let properties: crate::bindings::golem::graph::transactions::PropertyMap = properties
+            .0
+            .into_iter()
+            .map(|v| (v.0.0.0.clone(), v.0.1))
+            .collect::<Vec<_>>();
+        Ok(Self {
+            vertex_type,
+            additional_labels,
+            properties,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::graph::transactions::EdgeSpec {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("edgeType", crate::wrappers::JsString(self.edge_type))?;
+        obj.set::<
+                _,
+                crate::bindings::golem::graph::types::ElementId,
+            >("fromVertex", self.from_vertex)?;
+        obj.set::<
+                _,
+                crate::bindings::golem::graph::types::ElementId,
+            >("toVertex", self.to_vertex)?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<
+                    rquickjs::convert::List<
+                        (
+                            crate::wrappers::JsString,
+                            crate::bindings::golem::graph::types::PropertyValue,
+                        ),
+                    >,
+                >,
+            >(
+            "properties",
+            crate::wrappers::JsList(
+                self
+                    .properties
+                    .into_iter()
+                    .map(|v| rquickjs::convert::List((
+                        crate::wrappers::JsString(v.0),
+                        v.1,
+                    )))
+                    .collect::<Vec<_>>(),
+            ),
+        )?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::graph::transactions::EdgeSpec {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT edge-spec",
+            &["edgeType", "fromVertex", "toVertex", "properties"],
+            &[
+                ("edgeType", "string"),
+                ("fromVertex", "element-id"),
+                ("toVertex", "element-id"),
+                ("properties", "property-map"),
+            ],
+            false,
+        )?;
+        let edge_type: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "edgeType",
+            "string",
+        )?;
+        let edge_type: String = edge_type.0;
+        let from_vertex: crate::bindings::golem::graph::types::ElementId = crate::wrappers::get_field(
+            &obj,
+            "fromVertex",
+            "element-id",
+        )?;
+        let from_vertex: crate::bindings::golem::graph::transactions::ElementId = from_vertex;
+        let to_vertex: crate::bindings::golem::graph::types::ElementId = crate::wrappers::get_field(
+            &obj,
+            "toVertex",
+            "element-id",
+        )?;
+        let to_vertex: crate::bindings::golem::graph::transactions::ElementId = to_vertex;
+        let properties: crate::wrappers::JsList<
+            rquickjs::convert::List<
+                (
+                    crate::wrappers::JsString,
+                    crate::bindings::golem::graph::types::PropertyValue,
+                ),
+            >,
+        > = crate::wrappers::get_field(&obj, "properties", "property-map")?;
+        let properties: crate::bindings::golem::graph::transactions::PropertyMap = properties
+            .0
+            .into_iter()
+            .map(|v| (v.0.0.0.clone(), v.0.1))
+            .collect::<Vec<_>>();
+        Ok(Self {
+            edge_type,
+            from_vertex,
+            to_vertex,
+            properties,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::graph::connection::ConnectionConfig {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<crate::wrappers::JsString>,
+            >(
+            "hosts",
+            crate::wrappers::JsList(
+                self
+                    .hosts
+                    .into_iter()
+                    .map(|v| crate::wrappers::JsString(v))
+                    .collect::<Vec<_>>(),
+            ),
+        )?;
+        obj.set::<_, Option<u16>>("port", self.port.map(|v| v))?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >("databaseName", self.database_name.map(|v| crate::wrappers::JsString(v)))?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >("username", self.username.map(|v| crate::wrappers::JsString(v)))?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >("password", self.password.map(|v| crate::wrappers::JsString(v)))?;
+        obj.set::<_, Option<u32>>("timeoutSeconds", self.timeout_seconds.map(|v| v))?;
+        obj.set::<_, Option<u32>>("maxConnections", self.max_connections.map(|v| v))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<
+                    rquickjs::convert::List<
+                        (crate::wrappers::JsString, crate::wrappers::JsString),
+                    >,
+                >,
+            >(
+            "providerConfig",
+            crate::wrappers::JsList(
+                self
+                    .provider_config
+                    .into_iter()
+                    .map(|v| rquickjs::convert::List((
+                        crate::wrappers::JsString(v.0),
+                        crate::wrappers::JsString(v.1),
+                    )))
+                    .collect::<Vec<_>>(),
+            ),
+        )?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::graph::connection::ConnectionConfig {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT connection-config",
+            &[
+                "hosts",
+                "port",
+                "databaseName",
+                "username",
+                "password",
+                "timeoutSeconds",
+                "maxConnections",
+                "providerConfig",
+            ],
+            &[
+                ("hosts", "list<string>"),
+                ("providerConfig", "list<tuple<string, string>>"),
+            ],
+            false,
+        )?;
+        let hosts: crate::wrappers::JsList<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "hosts",
+            "list<string>",
+        )?;
+        let hosts: Vec<String> = hosts.0.into_iter().map(|v| v.0).collect::<Vec<_>>();
+        let port: Option<u16> = crate::wrappers::get_field(&obj, "port", "option<u16>")?;
+        let port: Option<u16> = port.map(|v| v);
+        let database_name: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "databaseName",
+            "option<string>",
+        )?;
+        let database_name: Option<String> = database_name.map(|v| v.0);
+        let username: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "username",
+            "option<string>",
+        )?;
+        let username: Option<String> = username.map(|v| v.0);
+        let password: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "password",
+            "option<string>",
+        )?;
+        let password: Option<String> = password.map(|v| v.0);
+        let timeout_seconds: Option<u32> = crate::wrappers::get_field(
+            &obj,
+            "timeoutSeconds",
+            "option<u32>",
+        )?;
+        let timeout_seconds: Option<u32> = timeout_seconds.map(|v| v);
+        let max_connections: Option<u32> = crate::wrappers::get_field(
+            &obj,
+            "maxConnections",
+            "option<u32>",
+        )?;
+        let max_connections: Option<u32> = max_connections.map(|v| v);
+        let provider_config: crate::wrappers::JsList<
+            rquickjs::convert::List<
+                (crate::wrappers::JsString, crate::wrappers::JsString),
+            >,
+        > = crate::wrappers::get_field(
+            &obj,
+            "providerConfig",
+            "list<tuple<string, string>>",
+        )?;
+        let provider_config: Vec<(String, String)> = provider_config
+            .0
+            .into_iter()
+            .map(|v| (v.0.0.0.clone(), v.0.1.0.clone()))
+            .collect::<Vec<_>>();
+        Ok(Self {
+            hosts,
+            port,
+            database_name,
+            username,
+            password,
+            timeout_seconds,
+            max_connections,
+            provider_config,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::graph::connection::GraphStatistics {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::BigIntWrapper<u64>>,
+            >(
+            "vertexCount",
+            self.vertex_count.map(|v| crate::wrappers::BigIntWrapper(v)),
+        )?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::BigIntWrapper<u64>>,
+            >("edgeCount", self.edge_count.map(|v| crate::wrappers::BigIntWrapper(v)))?;
+        obj.set::<_, Option<u32>>("labelCount", self.label_count.map(|v| v))?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::BigIntWrapper<u64>>,
+            >(
+            "propertyCount",
+            self.property_count.map(|v| crate::wrappers::BigIntWrapper(v)),
+        )?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::graph::connection::GraphStatistics {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT graph-statistics",
+            &["vertexCount", "edgeCount", "labelCount", "propertyCount"],
+            &[],
+            false,
+        )?;
+        let vertex_count: Option<crate::wrappers::BigIntWrapper<u64>> = crate::wrappers::get_field(
+            &obj,
+            "vertexCount",
+            "option<u64>",
+        )?;
+        let vertex_count: Option<u64> = vertex_count.map(|v| v.0);
+        let edge_count: Option<crate::wrappers::BigIntWrapper<u64>> = crate::wrappers::get_field(
+            &obj,
+            "edgeCount",
+            "option<u64>",
+        )?;
+        let edge_count: Option<u64> = edge_count.map(|v| v.0);
+        let label_count: Option<u32> = crate::wrappers::get_field(
+            &obj,
+            "labelCount",
+            "option<u32>",
+        )?;
+        let label_count: Option<u32> = label_count.map(|v| v);
+        let property_count: Option<crate::wrappers::BigIntWrapper<u64>> = crate::wrappers::get_field(
+            &obj,
+            "propertyCount",
+            "option<u64>",
+        )?;
+        let property_count: Option<u64> = property_count.map(|v| v.0);
+        Ok(Self {
+            vertex_count,
+            edge_count,
+            label_count,
+            property_count,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::graph::schema::PropertyType {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::graph::schema::PropertyType::Boolean => {
+                "boolean".into_js(ctx)
+            }
+            crate::bindings::golem::graph::schema::PropertyType::Int32 => {
+                "int32".into_js(ctx)
+            }
+            crate::bindings::golem::graph::schema::PropertyType::Int64 => {
+                "int64".into_js(ctx)
+            }
+            crate::bindings::golem::graph::schema::PropertyType::Float32Type => {
+                "float32-type".into_js(ctx)
+            }
+            crate::bindings::golem::graph::schema::PropertyType::Float64Type => {
+                "float64-type".into_js(ctx)
+            }
+            crate::bindings::golem::graph::schema::PropertyType::StringType => {
+                "string-type".into_js(ctx)
+            }
+            crate::bindings::golem::graph::schema::PropertyType::Bytes => {
+                "bytes".into_js(ctx)
+            }
+            crate::bindings::golem::graph::schema::PropertyType::Date => {
+                "date".into_js(ctx)
+            }
+            crate::bindings::golem::graph::schema::PropertyType::Datetime => {
+                "datetime".into_js(ctx)
+            }
+            crate::bindings::golem::graph::schema::PropertyType::Point => {
+                "point".into_js(ctx)
+            }
+            crate::bindings::golem::graph::schema::PropertyType::ListType => {
+                "list-type".into_js(ctx)
+            }
+            crate::bindings::golem::graph::schema::PropertyType::MapType => {
+                "map-type".into_js(ctx)
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::graph::schema::PropertyType {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let value = crate::wrappers::enum_case(
+            value,
+            "JS property-type",
+            "WIT property-type",
+        )?;
+        match value.as_str() {
+            "boolean" => Ok(crate::bindings::golem::graph::schema::PropertyType::Boolean),
+            "int32" => Ok(crate::bindings::golem::graph::schema::PropertyType::Int32),
+            "int64" => Ok(crate::bindings::golem::graph::schema::PropertyType::Int64),
+            "float32-type" => {
+                Ok(crate::bindings::golem::graph::schema::PropertyType::Float32Type)
+            }
+            "float64-type" => {
+                Ok(crate::bindings::golem::graph::schema::PropertyType::Float64Type)
+            }
+            "string-type" => {
+                Ok(crate::bindings::golem::graph::schema::PropertyType::StringType)
+            }
+            "bytes" => Ok(crate::bindings::golem::graph::schema::PropertyType::Bytes),
+            "date" => Ok(crate::bindings::golem::graph::schema::PropertyType::Date),
+            "datetime" => {
+                Ok(crate::bindings::golem::graph::schema::PropertyType::Datetime)
+            }
+            "point" => Ok(crate::bindings::golem::graph::schema::PropertyType::Point),
+            "list-type" => {
+                Ok(crate::bindings::golem::graph::schema::PropertyType::ListType)
+            }
+            "map-type" => {
+                Ok(crate::bindings::golem::graph::schema::PropertyType::MapType)
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_enum_case(
+                        "JS property-type",
+                        "WIT property-type",
+                        &value,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::graph::schema::IndexType {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::graph::schema::IndexType::Exact => {
+                "exact".into_js(ctx)
+            }
+            crate::bindings::golem::graph::schema::IndexType::Range => {
+                "range".into_js(ctx)
+            }
+            crate::bindings::golem::graph::schema::IndexType::Text => "text".into_js(ctx),
+            crate::bindings::golem::graph::schema::IndexType::Geospatial => {
+                "geospatial".into_js(ctx)
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::graph::schema::IndexType {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let value = crate::wrappers::enum_case(
+            value,
+            "JS index-type",
+            "WIT index-type",
+        )?;
+        match value.as_str() {
+            "exact" => Ok(crate::bindings::golem::graph::schema::IndexType::Exact),
+            "range" => Ok(crate::bindings::golem::graph::schema::IndexType::Range),
+            "text" => Ok(crate::bindings::golem::graph::schema::IndexType::Text),
+            "geospatial" => {
+                Ok(crate::bindings::golem::graph::schema::IndexType::Geospatial)
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_enum_case(
+                        "JS index-type",
+                        "WIT index-type",
+                        &value,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::graph::schema::PropertyDefinition {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("name", crate::wrappers::JsString(self.name))?;
+        obj.set::<
+                _,
+                crate::bindings::golem::graph::schema::PropertyType,
+            >("propertyType", self.property_type)?;
+        obj.set::<_, bool>("required", self.required)?;
+        obj.set::<_, bool>("unique", self.unique)?;
+        obj.set::<
+                _,
+                Option<crate::bindings::golem::graph::types::PropertyValue>,
+            >("defaultValue", self.default_value.map(|v| v))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::graph::schema::PropertyDefinition {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT property-definition",
+            &["name", "propertyType", "required", "unique", "defaultValue"],
+            &[
+                ("name", "string"),
+                ("propertyType", "property-type"),
+                ("required", "bool"),
+                ("unique", "bool"),
+            ],
+            false,
+        )?;
+        let name: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "name",
+            "string",
+        )?;
+        let name: String = name.0;
+        let property_type: crate::bindings::golem::graph::schema::PropertyType = crate::wrappers::get_field(
+            &obj,
+            "propertyType",
+            "property-type",
+        )?;
+        let property_type: crate::bindings::golem::graph::schema::PropertyType = property_type;
+        let required: bool = crate::wrappers::get_field(&obj, "required", "bool")?;
+        let required: bool = required;
+        let unique: bool = crate::wrappers::get_field(&obj, "unique", "bool")?;
+        let unique: bool = unique;
+        let default_value: Option<crate::bindings::golem::graph::types::PropertyValue> = crate::wrappers::get_field(
+            &obj,
+            "defaultValue",
+            "option<property-value>",
+        )?;
+        let default_value: Option<
+            crate::bindings::golem::graph::schema::PropertyValue,
+        > = default_value.map(|v| v);
+        Ok(Self {
+            name,
+            property_type,
+            required,
+            unique,
+            default_value,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::graph::schema::VertexLabelSchema {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("label", crate::wrappers::JsString(self.label))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<
+                    crate::bindings::golem::graph::schema::PropertyDefinition,
+                >,
+            >(
+            "properties",
+            crate::wrappers::JsList(
+                self.properties.into_iter().map(|v| v).collect::<Vec<_>>(),
+            ),
+        )?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >("container", self.container.map(|v| crate::wrappers::JsString(v)))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::graph::schema::VertexLabelSchema {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT vertex-label-schema",
+            &["label", "properties", "container"],
+            &[("label", "string"), ("properties", "list<property-definition>")],
+            false,
+        )?;
+        let label: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "label",
+            "string",
+        )?;
+        let label: String = label.0;
+        let properties: crate::wrappers::JsList<
+            crate::bindings::golem::graph::schema::PropertyDefinition,
+        > = crate::wrappers::get_field(&obj, "properties", "list<property-definition>")?;
+        let properties: Vec<crate::bindings::golem::graph::schema::PropertyDefinition> = properties
+            .0
+            .into_iter()
+            .map(|v| v)
+            .collect::<Vec<_>>();
+        let container: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "container",
+            "option<string>",
+        )?;
+        let container: Option<String> = container.map(|v| v.0);
+        Ok(Self {
+            label,
+            properties,
+            container,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::graph::schema::EdgeLabelSchema {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("label", crate::wrappers::JsString(self.label))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<
+                    crate::bindings::golem::graph::schema::PropertyDefinition,
+                >,
+            >(
+            "properties",
+            crate::wrappers::JsList(
+                self.properties.into_iter().map(|v| v).collect::<Vec<_>>(),
+            ),
+        )?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsList<crate::wrappers::JsString>>,
+            >(
+            "fromLabels",
+            self
+                .from_labels
+                .map(|v| crate::wrappers::JsList(
+                    v
+                        .into_iter()
+                        .map(|v| crate::wrappers::JsString(v))
+                        .collect::<Vec<_>>(),
+                )),
+        )?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsList<crate::wrappers::JsString>>,
+            >(
+            "toLabels",
+            self
+                .to_labels
+                .map(|v| crate::wrappers::JsList(
+                    v
+                        .into_iter()
+                        .map(|v| crate::wrappers::JsString(v))
+                        .collect::<Vec<_>>(),
+                )),
+        )?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >("container", self.container.map(|v| crate::wrappers::JsString(v)))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::graph::schema::EdgeLabelSchema {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT edge-label-schema",
+            &["label", "properties", "fromLabels", "toLabels", "container"],
+            &[("label", "string"), ("properties", "list<property-definition>")],
+            false,
+        )?;
+        let label: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "label",
+            "string",
+        )?;
+        let label: String = label.0;
+        let properties: crate::wrappers::JsList<
+            crate::bindings::golem::graph::schema::PropertyDefinition,
+        > = crate::wrappers::get_field(&obj, "properties", "list<property-definition>")?;
+        let properties: Vec<crate::bindings::golem::graph::schema::PropertyDefinition> = properties
+            .0
+            .into_iter()
+            .map(|v| v)
+            .collect::<Vec<_>>();
+        let from_labels: Option<crate::wrappers::JsList<crate::wrappers::JsString>> = crate::wrappers::get_field(
+            &obj,
+            "fromLabels",
+            "option<list<string>>",
+        )?;
+        let from_labels: Option<Vec<String>> = from_labels
+            .map(|v| v.0.into_iter().map(|v| v.0).collect::<Vec<_>>());
+        let to_labels: Option<crate::wrappers::JsList<crate::wrappers::JsString>> = crate::wrappers::get_field(
+            &obj,
+            "toLabels",
+            "option<list<string>>",
+        )?;
+        let to_labels: Option<Vec<String>> = to_labels
+            .map(|v| v.0.into_iter().map(|v| v.0).collect::<Vec<_>>());
+        let container: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "container",
+            "option<string>",
+        )?;
+        let container: Option<String> = container.map(|v| v.0);
+        Ok(Self {
+            label,
+            properties,
+            from_labels,
+            to_labels,
+            container,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::graph::schema::IndexDefinition {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("name", crate::wrappers::JsString(self.name))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("label", crate::wrappers::JsString(self.label))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<crate::wrappers::JsString>,
+            >(
+            "properties",
+            crate::wrappers::JsList(
+                self
+                    .properties
+                    .into_iter()
+                    .map(|v| crate::wrappers::JsString(v))
+                    .collect::<Vec<_>>(),
+            ),
+        )?;
+        obj.set::<
+                _,
+                crate::bindings::golem::graph::schema::IndexType,
+            >("indexType", self.index_type)?;
+        obj.set::<_, bool>("unique", self.unique)?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >("container", self.container.map(|v| crate::wrappers::JsString(v)))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::graph::schema::IndexDefinition {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT index-definition",
+            &["name", "label", "properties", "indexType", "unique", "container"],
+            &[
+                ("name", "string"),
+                ("label", "string"),
+                ("properties", "list<string>"),
+                ("indexType", "index-type"),
+                ("unique", "bool"),
+            ],
+            false,
+        )?;
+        let name: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "name",
+            "string",
+        )?;
+        let name: String = name.0;
+        let label: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "label",
+            "string",
+        )?;
+        let label: String = label.0;
+        let properties: crate::wrappers::JsList<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "properties",
+            "list<string>",
+        )?;
+        let properties: Vec<String> = properties
+            .0
+            .into_iter()
+            .map(|v| v.0)
+            .collect::<Vec<_>>();
+        let index_type: crate::bindings::golem::graph::schema::IndexType = crate::wrappers::get_field(
+            &obj,
+            "indexType",
+            "index-type",
+        )?;
+        let index_type: crate::bindings::golem::graph::schema::IndexType = index_type;
+        let unique: bool = crate::wrappers::get_field(&obj, "unique", "bool")?;
+        let unique: bool = unique;
+        let container: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "container",
+            "option<string>",
+        )?;
+        let container: Option<String> = container.map(|v| v.0);
+        Ok(Self {
+            name,
+            label,
+            properties,
+            index_type,
+            unique,
+            container,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::graph::schema::EdgeTypeDefinition {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("collection", crate::wrappers::JsString(self.collection))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<crate::wrappers::JsString>,
+            >(
+            "fromCollections",
+            crate::wrappers::JsList(
+                self
+                    .from_collections
+                    .into_iter()
+                    .map(|v| crate::wrappers::JsString(v))
+                    .collect::<Vec<_>>(),
+            ),
+        )?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<crate::wrappers::JsString>,
+            >(
+            "toCollections",
+            crate::wrappers::JsList(
+                self
+                    .to_collections
+                    .into_iter()
+                    .map(|v| crate::wrappers::JsString(v))
+                    .collect::<Vec<_>>(),
+            ),
+        )?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::graph::schema::EdgeTypeDefinition {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT edge-type-definition",
+            &["collection", "fromCollections", "toCollections"],
+            &[
+                ("collection", "string"),
+                ("fromCollections", "list<string>"),
+                ("toCollections", "list<string>"),
+            ],
+            false,
+        )?;
+        let collection: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "collection",
+            "string",
+        )?;
+        let collection: String = collection.0;
+        let from_collections: crate::wrappers::JsList<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "fromCollections",
+            "list<string>",
+        )?;
+        let from_collections: Vec<String> = from_collections
+            .0
+            .into_iter()
+            .map(|v| v.0)
+            .collect::<Vec<_>>();
+        let to_collections: crate::wrappers::JsList<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "toCollections",
+            "list<string>",
+        )?;
+        let to_collections: Vec<String> = to_collections
+            .0
+            .into_iter()
+            .map(|v| v.0)
+            .collect::<Vec<_>>();
+        Ok(Self {
+            collection,
+            from_collections,
+            to_collections,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::graph::schema::ContainerType {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::graph::schema::ContainerType::VertexContainer => {
+                "vertex-container".into_js(ctx)
+            }
+            crate::bindings::golem::graph::schema::ContainerType::EdgeContainer => {
+                "edge-container".into_js(ctx)
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::graph::schema::ContainerType {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let value = crate::wrappers::enum_case(
+            value,
+            "JS container-type",
+            "WIT container-type",
+        )?;
+        match value.as_str() {
+            "vertex-container" => {
+                Ok(crate::bindings::golem::graph::schema::ContainerType::VertexContainer)
+            }
+            "edge-container" => {
+                Ok(crate::bindings::golem::graph::schema::ContainerType::EdgeContainer)
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_enum_case(
+                        "JS container-type",
+                        "WIT container-type",
+                        &value,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::graph::schema::ContainerInfo {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("name", crate::wrappers::JsString(self.name))?;
+        obj.set::<
+                _,
+                crate::bindings::golem::graph::schema::ContainerType,
+            >("containerType", self.container_type)?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::BigIntWrapper<u64>>,
+            >(
+            "elementCount",
+            self.element_count.map(|v| crate::wrappers::BigIntWrapper(v)),
+        )?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::graph::schema::ContainerInfo {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT container-info",
+            &["name", "containerType", "elementCount"],
+            &[("name", "string"), ("containerType", "container-type")],
+            false,
+        )?;
+        let name: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "name",
+            "string",
+        )?;
+        let name: String = name.0;
+        let container_type: crate::bindings::golem::graph::schema::ContainerType = crate::wrappers::get_field(
+            &obj,
+            "containerType",
+            "container-type",
+        )?;
+        let container_type: crate::bindings::golem::graph::schema::ContainerType = container_type;
+        let element_count: Option<crate::wrappers::BigIntWrapper<u64>> = crate::wrappers::get_field(
+            &obj,
+            "elementCount",
+            "option<u64>",
+        )?;
+        let element_count: Option<u64> = element_count.map(|v| v.0);
+        Ok(Self {
+            name,
+            container_type,
+            element_count,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::graph::query::QueryResult {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::graph::query::QueryResult::Vertices(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsList<crate::bindings::golem::graph::types::Vertex>,
+                >(
+                    ctx,
+                    "vertices",
+                    crate::wrappers::JsList(
+                        inner.into_iter().map(|v| v).collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            crate::bindings::golem::graph::query::QueryResult::Edges(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsList<crate::bindings::golem::graph::types::Edge>,
+                >(
+                    ctx,
+                    "edges",
+                    crate::wrappers::JsList(
+                        inner.into_iter().map(|v| v).collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            crate::bindings::golem::graph::query::QueryResult::Paths(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsList<crate::bindings::golem::graph::types::Path>,
+                >(
+                    ctx,
+                    "paths",
+                    crate::wrappers::JsList(
+                        inner.into_iter().map(|v| v).collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            crate::bindings::golem::graph::query::QueryResult::Values(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsList<
+                        crate::bindings::golem::graph::types::PropertyValue,
+                    >,
+                >(
+                    ctx,
+                    "values",
+                    crate::wrappers::JsList(
+                        inner.into_iter().map(|v| v).collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            crate::bindings::golem::graph::query::QueryResult::Maps(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsList<
+                        crate::wrappers::JsList<
+                            rquickjs::convert::List<
+                                (
+                                    crate::wrappers::JsString,
+                                    crate::bindings::golem::graph::types::PropertyValue,
+                                ),
+                            >,
+                        >,
+                    >,
+                >(
+                    ctx,
+                    "maps",
+                    crate::wrappers::JsList(
+                        inner
+                            .into_iter()
+                            .map(|v| crate::wrappers::JsList(
+                                v
+                                    .into_iter()
+                                    .map(|v| rquickjs::convert::List((
+                                        crate::wrappers::JsString(v.0),
+                                        v.1,
+                                    )))
+                                    .collect::<Vec<_>>(),
+                            ))
+                            .collect::<Vec<_>>(),
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::graph::query::QueryResult {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "vertices" => {
+                let inner: crate::wrappers::JsList<
+                    crate::bindings::golem::graph::types::Vertex,
+                > = crate::wrappers::variant_value(&obj, "list<vertex>")?;
+                Ok(
+                    crate::bindings::golem::graph::query::QueryResult::Vertices(
+                        inner.0.into_iter().map(|v| v).collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            "edges" => {
+                let inner: crate::wrappers::JsList<
+                    crate::bindings::golem::graph::types::Edge,
+                > = crate::wrappers::variant_value(&obj, "list<edge>")?;
+                Ok(
+                    crate::bindings::golem::graph::query::QueryResult::Edges(
+                        inner.0.into_iter().map(|v| v).collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            "paths" => {
+                let inner: crate::wrappers::JsList<
+                    crate::bindings::golem::graph::types::Path,
+                > = crate::wrappers::variant_value(&obj, "list<path>")?;
+                Ok(
+                    crate::bindings::golem::graph::query::QueryResult::Paths(
+                        inner.0.into_iter().map(|v| v).collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            "values" => {
+                let inner: crate::wrappers::JsList<
+                    crate::bindings::golem::graph::types::PropertyValue,
+                > = crate::wrappers::variant_value(&obj, "list<property-value>")?;
+                Ok(
+                    crate::bindings::golem::graph::query::QueryResult::Values(
+                        inner.0.into_iter().map(|v| v).collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            "maps" => {
+                let inner: crate::wrappers::JsList<
+                    crate::wrappers::JsList<
+                        rquickjs::convert::List<
+                            (
+                                crate::wrappers::JsString,
+                                crate::bindings::golem::graph::types::PropertyValue,
+                            ),
+                        >,
+                    >,
+                > = crate::wrappers::variant_value(
+                    &obj,
+                    "list<list<tuple<string, property-value>>>",
+                )?;
+                Ok(
+                    crate::bindings::golem::graph::query::QueryResult::Maps(
+                        inner
+                            .0
+                            .into_iter()
+                            .map(|v| {
+                                v
+                                    .0
+                                    .into_iter()
+                                    .map(|v| (v.0.0.0.clone(), v.0.1))
+                                    .collect::<Vec<_>>()
+                            })
+                            .collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS query-result",
+                        "WIT query-result",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::graph::query::QueryOptions {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<_, Option<u32>>("timeoutSeconds", self.timeout_seconds.map(|v| v))?;
+        obj.set::<_, Option<u32>>("maxResults", self.max_results.map(|v| v))?;
+        obj.set::<_, bool>("explain", self.explain)?;
+        obj.set::<_, bool>("profile", self.profile)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::graph::query::QueryOptions {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT query-options",
+            &["timeoutSeconds", "maxResults", "explain", "profile"],
+            &[("explain", "bool"), ("profile", "bool")],
+            false,
+        )?;
+        let timeout_seconds: Option<u32> = crate::wrappers::get_field(
+            &obj,
+            "timeoutSeconds",
+            "option<u32>",
+        )?;
+        let timeout_seconds: Option<u32> = timeout_seconds.map(|v| v);
+        let max_results: Option<u32> = crate::wrappers::get_field(
+            &obj,
+            "maxResults",
+            "option<u32>",
+        )?;
+        let max_results: Option<u32> = max_results.map(|v| v);
+        let explain: bool = crate::wrappers::get_field(&obj, "explain", "bool")?;
+        let explain: bool = explain;
+        let profile: bool = crate::wrappers::get_field(&obj, "profile", "bool")?;
+        let profile: bool = profile;
+        Ok(Self {
+            timeout_seconds,
+            max_results,
+            explain,
+            profile,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::graph::query::QueryExecutionResult {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::golem::graph::query::QueryResult,
+            >("queryResultValue", self.query_result_value)?;
+        obj.set::<_, Option<u32>>("executionTimeMs", self.execution_time_ms.map(|v| v))?;
+        obj.set::<_, Option<u32>>("rowsAffected", self.rows_affected.map(|v| v))?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >("explanation", self.explanation.map(|v| crate::wrappers::JsString(v)))?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >("profileData", self.profile_data.map(|v| crate::wrappers::JsString(v)))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::graph::query::QueryExecutionResult {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT query-execution-result",
+            &[
+                "queryResultValue",
+                "executionTimeMs",
+                "rowsAffected",
+                "explanation",
+                "profileData",
+            ],
+            &[("queryResultValue", "query-result")],
+            false,
+        )?;
+        let query_result_value: crate::bindings::golem::graph::query::QueryResult = crate::wrappers::get_field(
+            &obj,
+            "queryResultValue",
+            "query-result",
+        )?;
+        let query_result_value: crate::bindings::golem::graph::query::QueryResult = query_result_value;
+        let execution_time_ms: Option<u32> = crate::wrappers::get_field(
+            &obj,
+            "executionTimeMs",
+            "option<u32>",
+        )?;
+        let execution_time_ms: Option<u32> = execution_time_ms.map(|v| v);
+        let rows_affected: Option<u32> = crate::wrappers::get_field(
+            &obj,
+            "rowsAffected",
+            "option<u32>",
+        )?;
+        let rows_affected: Option<u32> = rows_affected.map(|v| v);
+        let explanation: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "explanation",
+            "option<string>",
+        )?;
+        let explanation: Option<String> = explanation.map(|v| v.0);
+        let profile_data: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "profileData",
+            "option<string>",
+        )?;
+        let profile_data: Option<String> = profile_data.map(|v| v.0);
+        Ok(Self {
+            query_result_value,
+            execution_time_ms,
+            rows_affected,
+            explanation,
+            profile_data,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::graph::traversal::PathOptions {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<_, Option<u32>>("maxDepth", self.max_depth.map(|v| v))?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsList<crate::wrappers::JsString>>,
+            >(
+            "edgeTypes",
+            self
+                .edge_types
+                .map(|v| crate::wrappers::JsList(
+                    v
+                        .into_iter()
+                        .map(|v| crate::wrappers::JsString(v))
+                        .collect::<Vec<_>>(),
+                )),
+        )?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsList<crate::wrappers::JsString>>,
+            >(
+            "vertexTypes",
+            self
+                .vertex_types
+                .map(|v| crate::wrappers::JsList(
+                    v
+                        .into_iter()
+                        .map(|v| crate::wrappers::JsString(v))
+                        .collect::<Vec<_>>(),
+                )),
+        )?;
+        obj.set::<
+                _,
+                Option<
+                    crate::wrappers::JsList<
+                        crate::bindings::golem::graph::types::FilterCondition,
+                    >,
+                >,
+            >(
+            "vertexFilters",
+            self
+                .vertex_filters
+                .map(|v| crate::wrappers::JsList(
+                    v.into_iter().map(|v| v).collect::<Vec<_>>(),
+                )),
+        )?;
+        obj.set::<
+                _,
+                Option<
+                    crate::wrappers::JsList<
+                        crate::bindings::golem::graph::types::FilterCondition,
+                    >,
+                >,
+            >(
+            "edgeFilters",
+            self
+                .edge_filters
+                .map(|v| crate::wrappers::JsList(
+                    v.into_iter().map(|v| v).collect::<Vec<_>>(),
+                )),
+        )?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::graph::traversal::PathOptions {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT path-options",
+            &["maxDepth", "edgeTypes", "vertexTypes", "vertexFilters", "edgeFilters"],
+            &[],
+            false,
+        )?;
+        let max_depth: Option<u32> = crate::wrappers::get_field(
+            &obj,
+            "maxDepth",
+            "option<u32>",
+        )?;
+        let max_depth: Option<u32> = max_depth.map(|v| v);
+        let edge_types: Option<crate::wrappers::JsList<crate::wrappers::JsString>> = crate::wrappers::get_field(
+            &obj,
+            "edgeTypes",
+            "option<list<string>>",
+        )?;
+        let edge_types: Option<Vec<String>> = edge_types
+            .map(|v| v.0.into_iter().map(|v| v.0).collect::<Vec<_>>());
+        let vertex_types: Option<crate::wrappers::JsList<crate::wrappers::JsString>> = crate::wrappers::get_field(
+            &obj,
+            "vertexTypes",
+            "option<list<string>>",
+        )?;
+        let vertex_types: Option<Vec<String>> = vertex_types
+            .map(|v| v.0.into_iter().map(|v| v.0).collect::<Vec<_>>());
+        let vertex_filters: Option<
+            crate::wrappers::JsList<
+                crate::bindings::golem::graph::types::FilterCondition,
+            >,
+        > = crate::wrappers::get_field(
+            &obj,
+            "vertexFilters",
+            "option<list<filter-condition>>",
+        )?;
+        let vertex_filters: Option<
+            Vec<crate::bindings::golem::graph::traversal::FilterCondition>,
+        > = vertex_filters.map(|v| v.0.into_iter().map(|v| v).collect::<Vec<_>>());
+        let edge_filters: Option<
+            crate::wrappers::JsList<
+                crate::bindings::golem::graph::types::FilterCondition,
+            >,
+        > = crate::wrappers::get_field(
+            &obj,
+            "edgeFilters",
+            "option<list<filter-condition>>",
+        )?;
+        let edge_filters: Option<
+            Vec<crate::bindings::golem::graph::traversal::FilterCondition>,
+        > = edge_filters.map(|v| v.0.into_iter().map(|v| v).collect::<Vec<_>>());
+        Ok(Self {
+            max_depth,
+            edge_types,
+            vertex_types,
+            vertex_filters,
+            edge_filters,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::graph::traversal::NeighborhoodOptions {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<_, u32>("depth", self.depth)?;
+        obj.set::<
+                _,
+                crate::bindings::golem::graph::types::Direction,
+            >("direction", self.direction)?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsList<crate::wrappers::JsString>>,
+            >(
+            "edgeTypes",
+            self
+                .edge_types
+                .map(|v| crate::wrappers::JsList(
+                    v
+                        .into_iter()
+                        .map(|v| crate::wrappers::JsString(v))
+                        .collect::<Vec<_>>(),
+                )),
+        )?;
+        obj.set::<_, Option<u32>>("maxVertices", self.max_vertices.map(|v| v))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::graph::traversal::NeighborhoodOptions {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT neighborhood-options",
+            &["depth", "direction", "edgeTypes", "maxVertices"],
+            &[("depth", "u32"), ("direction", "direction")],
+            false,
+        )?;
+        let depth: u32 = crate::wrappers::get_field(&obj, "depth", "u32")?;
+        let depth: u32 = depth;
+        let direction: crate::bindings::golem::graph::types::Direction = crate::wrappers::get_field(
+            &obj,
+            "direction",
+            "direction",
+        )?;
+        let direction: crate::bindings::golem::graph::traversal::Direction = direction;
+        let edge_types: Option<crate::wrappers::JsList<crate::wrappers::JsString>> = crate::wrappers::get_field(
+            &obj,
+            "edgeTypes",
+            "option<list<string>>",
+        )?;
+        let edge_types: Option<Vec<String>> = edge_types
+            .map(|v| v.0.into_iter().map(|v| v.0).collect::<Vec<_>>());
+        let max_vertices: Option<u32> = crate::wrappers::get_field(
+            &obj,
+            "maxVertices",
+            "option<u32>",
+        )?;
+        let max_vertices: Option<u32> = max_vertices.map(|v| v);
+        Ok(Self {
+            depth,
+            direction,
+            edge_types,
+            max_vertices,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::graph::traversal::Subgraph {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<crate::bindings::golem::graph::types::Vertex>,
+            >(
+            "vertices",
+            crate::wrappers::JsList(
+                self.vertices.into_iter().map(|v| v).collect::<Vec<_>>(),
+            ),
+        )?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<crate::bindings::golem::graph::types::Edge>,
+            >(
+            "edges",
+            crate::wrappers::JsList(
+                self.edges.into_iter().map(|v| v).collect::<Vec<_>>(),
+            ),
+        )?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::graph::traversal::Subgraph {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT subgraph",
+            &["vertices", "edges"],
+            &[("vertices", "list<vertex>"), ("edges", "list<edge>")],
+            false,
+        )?;
+        let vertices: crate::wrappers::JsList<
+            crate::bindings::golem::graph::types::Vertex,
+        > = crate::wrappers::get_field(&obj, "vertices", "list<vertex>")?;
+        let vertices: Vec<crate::bindings::golem::graph::traversal::Vertex> = vertices
+            .0
+            .into_iter()
+            .map(|v| v)
+            .collect::<Vec<_>>();
+        let edges: crate::wrappers::JsList<crate::bindings::golem::graph::types::Edge> = crate::wrappers::get_field(
+            &obj,
+            "edges",
+            "list<edge>",
+        )?;
+        let edges: Vec<crate::bindings::golem::graph::traversal::Edge> = edges
+            .0
+            .into_iter()
+            .map(|v| v)
+            .collect::<Vec<_>>();
+        Ok(Self { vertices, edges })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::llm::llm::Role {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::llm::llm::Role::User => "user".into_js(ctx),
+            crate::bindings::golem::llm::llm::Role::Assistant => "assistant".into_js(ctx),
+            crate::bindings::golem::llm::llm::Role::System => "system".into_js(ctx),
+            crate::bindings::golem::llm::llm::Role::Tool => "tool".into_js(ctx),
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::llm::llm::Role {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let value = crate::wrappers::enum_case(value, "JS role", "WIT role")?;
+        match value.as_str() {
+            "user" => Ok(crate::bindings::golem::llm::llm::Role::User),
+            "assistant" => Ok(crate::bindings::golem::llm::llm::Role::Assistant),
+            "system" => Ok(crate::bindings::golem::llm::llm::Role::System),
+            "tool" => Ok(crate::bindings::golem::llm::llm::Role::Tool),
+            _ => Err(crate::wrappers::unknown_enum_case("JS role", "WIT role", &value)),
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::llm::llm::ErrorCode {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::llm::llm::ErrorCode::InvalidRequest => {
+                "invalid-request".into_js(ctx)
+            }
+            crate::bindings::golem::llm::llm::ErrorCode::AuthenticationFailed => {
+                "authentication-failed".into_js(ctx)
+            }
+            crate::bindings::golem::llm::llm::ErrorCode::RateLimitExceeded => {
+                "rate-limit-exceeded".into_js(ctx)
+            }
+            crate::bindings::golem::llm::llm::ErrorCode::InternalError => {
+                "internal-error".into_js(ctx)
+            }
+            crate::bindings::golem::llm::llm::ErrorCode::Unsupported => {
+                "unsupported".into_js(ctx)
+            }
+            crate::bindings::golem::llm::llm::ErrorCode::Unknown => {
+                "unknown".into_js(ctx)
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::llm::llm::ErrorCode {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let value = crate::wrappers::enum_case(
+            value,
+            "JS error-code",
+            "WIT error-code",
+        )?;
+        match value.as_str() {
+            "invalid-request" => {
+                Ok(crate::bindings::golem::llm::llm::ErrorCode::InvalidRequest)
+            }
+            "authentication-failed" => {
+                Ok(crate::bindings::golem::llm::llm::ErrorCode::AuthenticationFailed)
+            }
+            "rate-limit-exceeded" => {
+                Ok(crate::bindings::golem::llm::llm::ErrorCode::RateLimitExceeded)
+            }
+            "internal-error" => {
+                Ok(crate::bindings::golem::llm::llm::ErrorCode::InternalError)
+            }
+            "unsupported" => Ok(crate::bindings::golem::llm::llm::ErrorCode::Unsupported),
+            "unknown" => Ok(crate::bindings::golem::llm::llm::ErrorCode::Unknown),
+            _ => {
+                Err(
+                    crate::wrappers::unknown_enum_case(
+                        "JS error-code",
+                        "WIT error-code",
+                        &value,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::llm::llm::FinishReason {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::llm::llm::FinishReason::Stop => "stop".into_js(ctx),
+            crate::bindings::golem::llm::llm::FinishReason::Length => {
+                "length".into_js(ctx)
+            }
+            crate::bindings::golem::llm::llm::FinishReason::ToolCalls => {
+                "tool-calls".into_js(ctx)
+            }
+            crate::bindings::golem::llm::llm::FinishReason::ContentFilter => {
+                "content-filter".into_js(ctx)
+            }
+            crate::bindings::golem::llm::llm::FinishReason::Error => "error".into_js(ctx),
+            crate::bindings::golem::llm::llm::FinishReason::Other => "other".into_js(ctx),
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::llm::llm::FinishReason {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let value = crate::wrappers::enum_case(
+            value,
+            "JS finish-reason",
+            "WIT finish-reason",
+        )?;
+        match value.as_str() {
+            "stop" => Ok(crate::bindings::golem::llm::llm::FinishReason::Stop),
+            "length" => Ok(crate::bindings::golem::llm::llm::FinishReason::Length),
+            "tool-calls" => Ok(crate::bindings::golem::llm::llm::FinishReason::ToolCalls),
+            "content-filter" => {
+                Ok(crate::bindings::golem::llm::llm::FinishReason::ContentFilter)
+            }
+            "error" => Ok(crate::bindings::golem::llm::llm::FinishReason::Error),
+            "other" => Ok(crate::bindings::golem::llm::llm::FinishReason::Other),
+            _ => {
+                Err(
+                    crate::wrappers::unknown_enum_case(
+                        "JS finish-reason",
+                        "WIT finish-reason",
+                        &value,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::llm::llm::ImageDetail {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::llm::llm::ImageDetail::Low => "low".into_js(ctx),
+            crate::bindings::golem::llm::llm::ImageDetail::High => "high".into_js(ctx),
+            crate::bindings::golem::llm::llm::ImageDetail::Auto => "auto".into_js(ctx),
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::llm::llm::ImageDetail {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let value = crate::wrappers::enum_case(
+            value,
+            "JS image-detail",
+            "WIT image-detail",
+        )?;
+        match value.as_str() {
+            "low" => Ok(crate::bindings::golem::llm::llm::ImageDetail::Low),
+            "high" => Ok(crate::bindings::golem::llm::llm::ImageDetail::High),
+            "auto" => Ok(crate::bindings::golem::llm::llm::ImageDetail::Auto),
+            _ => {
+                Err(
+                    crate::wrappers::unknown_enum_case(
+                        "JS image-detail",
+                        "WIT image-detail",
+                        &value,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::llm::llm::ImageUrl {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("url", crate::wrappers::JsString(self.url))?;
+        obj.set::<
+                _,
+                Option<crate::bindings::golem::llm::llm::ImageDetail>,
+            >("detail", self.detail.map(|v| v))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::llm::llm::ImageUrl {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT image-url",
+            &["url", "detail"],
+            &[("url", "string")],
+            false,
+        )?;
+        let url: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "url",
+            "string",
+        )?;
+        let url: String = url.0;
+        let detail: Option<crate::bindings::golem::llm::llm::ImageDetail> = crate::wrappers::get_field(
+            &obj,
+            "detail",
+            "option<image-detail>",
+        )?;
+        let detail: Option<crate::bindings::golem::llm::llm::ImageDetail> = detail
+            .map(|v| v);
+        Ok(Self { url, detail })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::llm::llm::ImageSource {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::UInt8Array,
+            >("data", crate::wrappers::UInt8Array(self.data))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("mimeType", crate::wrappers::JsString(self.mime_type))?;
+        obj.set::<
+                _,
+                Option<crate::bindings::golem::llm::llm::ImageDetail>,
+            >("detail", self.detail.map(|v| v))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::llm::llm::ImageSource {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT image-source",
+            &["data", "mimeType", "detail"],
+            &[("data", "list<u8>"), ("mimeType", "string")],
+            false,
+        )?;
+        let data: crate::wrappers::UInt8Array = crate::wrappers::get_field(
+            &obj,
+            "data",
+            "list<u8>",
+        )?;
+        let data: Vec<u8> = data.0;
+        let mime_type: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "mimeType",
+            "string",
+        )?;
+        let mime_type: String = mime_type.0;
+        let detail: Option<crate::bindings::golem::llm::llm::ImageDetail> = crate::wrappers::get_field(
+            &obj,
+            "detail",
+            "option<image-detail>",
+        )?;
+        let detail: Option<crate::bindings::golem::llm::llm::ImageDetail> = detail
+            .map(|v| v);
+        Ok(Self { data, mime_type, detail })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::llm::llm::ImageReference {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::llm::llm::ImageReference::Url(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::llm::llm::ImageUrl,
+                >(ctx, "url", inner)
+            }
+            crate::bindings::golem::llm::llm::ImageReference::Inline(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::llm::llm::ImageSource,
+                >(ctx, "inline", inner)
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::llm::llm::ImageReference {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "url" => {
+                let inner: crate::bindings::golem::llm::llm::ImageUrl = crate::wrappers::variant_value(
+                    &obj,
+                    "image-url",
+                )?;
+                Ok(crate::bindings::golem::llm::llm::ImageReference::Url(inner))
+            }
+            "inline" => {
+                let inner: crate::bindings::golem::llm::llm::ImageSource = crate::wrappers::variant_value(
+                    &obj,
+                    "image-source",
+                )?;
+                Ok(crate::bindings::golem::llm::llm::ImageReference::Inline(inner))
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS image-reference",
+                        "WIT image-reference",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::llm::llm::ContentPart {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::llm::llm::ContentPart::Text(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "text", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::llm::llm::ContentPart::Image(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::llm::llm::ImageReference,
+                >(ctx, "image", inner)
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::llm::llm::ContentPart {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "text" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(crate::bindings::golem::llm::llm::ContentPart::Text(inner.0))
+            }
+            "image" => {
+                let inner: crate::bindings::golem::llm::llm::ImageReference = crate::wrappers::variant_value(
+                    &obj,
+                    "image-reference",
+                )?;
+                Ok(crate::bindings::golem::llm::llm::ContentPart::Image(inner))
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS content-part",
+                        "WIT content-part",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::llm::llm::Message {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<_, crate::bindings::golem::llm::llm::Role>("role", self.role)?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >("name", self.name.map(|v| crate::wrappers::JsString(v)))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<crate::bindings::golem::llm::llm::ContentPart>,
+            >(
+            "content",
+            crate::wrappers::JsList(
+                self.content.into_iter().map(|v| v).collect::<Vec<_>>(),
+            ),
+        )?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::llm::llm::Message {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT message",
+            &["role", "name", "content"],
+            &[("role", "role"), ("content", "list<content-part>")],
+            false,
+        )?;
+        let role: crate::bindings::golem::llm::llm::Role = crate::wrappers::get_field(
+            &obj,
+            "role",
+            "role",
+        )?;
+        let role: crate::bindings::golem::llm::llm::Role = role;
+        let name: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "name",
+            "option<string>",
+        )?;
+        let name: Option<String> = name.map(|v| v.0);
+        let content: crate::wrappers::JsList<
+            crate::bindings::golem::llm::llm::ContentPart,
+        > = crate::wrappers::get_field(&obj, "content", "list<content-part>")?;
+        let content: Vec<crate::bindings::golem::llm::llm::ContentPart> = content
+            .0
+            .into_iter()
+            .map(|v| v)
+            .collect::<Vec<_>>();
+        Ok(Self { role, name, content })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::llm::llm::ToolDefinition {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("name", crate::wrappers::JsString(self.name))?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >("description", self.description.map(|v| crate::wrappers::JsString(v)))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("parametersSchema", crate::wrappers::JsString(self.parameters_schema))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::llm::llm::ToolDefinition {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT tool-definition",
+            &["name", "description", "parametersSchema"],
+            &[("name", "string"), ("parametersSchema", "string")],
+            false,
+        )?;
+        let name: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "name",
+            "string",
+        )?;
+        let name: String = name.0;
+        let description: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "description",
+            "option<string>",
+        )?;
+        let description: Option<String> = description.map(|v| v.0);
+        let parameters_schema: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "parametersSchema",
+            "string",
+        )?;
+        let parameters_schema: String = parameters_schema.0;
+        Ok(Self {
+            name,
+            description,
+            parameters_schema,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::llm::llm::ToolCall {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("id", crate::wrappers::JsString(self.id))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("name", crate::wrappers::JsString(self.name))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("argumentsJson", crate::wrappers::JsString(self.arguments_json))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::llm::llm::ToolCall {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT tool-call",
+            &["id", "name", "argumentsJson"],
+            &[("id", "string"), ("name", "string"), ("argumentsJson", "string")],
+            false,
+        )?;
+        let id: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "id",
+            "string",
+        )?;
+        let id: String = id.0;
+        let name: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "name",
+            "string",
+        )?;
+        let name: String = name.0;
+        let arguments_json: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "argumentsJson",
+            "string",
+        )?;
+        let arguments_json: String = arguments_json.0;
+        Ok(Self { id, name, arguments_json })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::llm::llm::ToolSuccess {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("id", crate::wrappers::JsString(self.id))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("name", crate::wrappers::JsString(self.name))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("resultJson", crate::wrappers::JsString(self.result_json))?;
+        obj.set::<_, Option<u32>>("executionTimeMs", self.execution_time_ms.map(|v| v))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::llm::llm::ToolSuccess {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT tool-success",
+            &["id", "name", "resultJson", "executionTimeMs"],
+            &[("id", "string"), ("name", "string"), ("resultJson", "string")],
+            false,
+        )?;
+        let id: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "id",
+            "string",
+        )?;
+        let id: String = id.0;
+        let name: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "name",
+            "string",
+        )?;
+        let name: String = name.0;
+        let result_json: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "resultJson",
+            "string",
+        )?;
+        let result_json: String = result_json.0;
+        let execution_time_ms: Option<u32> = crate::wrappers::get_field(
+            &obj,
+            "executionTimeMs",
+            "option<u32>",
+        )?;
+        let execution_time_ms: Option<u32> = execution_time_ms.map(|v| v);
+        Ok(Self {
+            id,
+            name,
+            result_json,
+            execution_time_ms,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::llm::llm::ToolFailure {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("id", crate::wrappers::JsString(self.id))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("name", crate::wrappers::JsString(self.name))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("errorMessage", crate::wrappers::JsString(self.error_message))?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >("errorCode", self.error_code.map(|v| crate::wrappers::JsString(v)))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::llm::llm::ToolFailure {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT tool-failure",
+            &["id", "name", "errorMessage", "errorCode"],
+            &[("id", "string"), ("name", "string"), ("errorMessage", "string")],
+            false,
+        )?;
+        let id: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "id",
+            "string",
+        )?;
+        let id: String = id.0;
+        let name: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "name",
+            "string",
+        )?;
+        let name: String = name.0;
+        let error_message: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "errorMessage",
+            "string",
+        )?;
+        let error_message: String = error_message.0;
+        let error_code: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "errorCode",
+            "option<string>",
+        )?;
+        let error_code: Option<String> = error_code.map(|v| v.0);
+        Ok(Self {
+            id,
+            name,
+            error_message,
+            error_code,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::llm::llm::ToolResult {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::llm::llm::ToolResult::Success(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::llm::llm::ToolSuccess,
+                >(ctx, "success", inner)
+            }
+            crate::bindings::golem::llm::llm::ToolResult::Error(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::llm::llm::ToolFailure,
+                >(ctx, "error", inner)
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::llm::llm::ToolResult {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "success" => {
+                let inner: crate::bindings::golem::llm::llm::ToolSuccess = crate::wrappers::variant_value(
+                    &obj,
+                    "tool-success",
+                )?;
+                Ok(crate::bindings::golem::llm::llm::ToolResult::Success(inner))
+            }
+            "error" => {
+                let inner: crate::bindings::golem::llm::llm::ToolFailure = crate::wrappers::variant_value(
+                    &obj,
+                    "tool-failure",
+                )?;
+                Ok(crate::bindings::golem::llm::llm::ToolResult::Error(inner))
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS tool-result",
+                        "WIT tool-result",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::llm::llm::Kv {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("key", crate::wrappers::JsString(self.key))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("value", crate::wrappers::JsString(self.value))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::llm::llm::Kv {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT kv",
+            &["key", "value"],
+            &[("key", "string"), ("value", "string")],
+            false,
+        )?;
+        let key: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "key",
+            "string",
+        )?;
+        let key: String = key.0;
+        let value: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "value",
+            "string",
+        )?;
+        let value: String = value.0;
+        Ok(Self { key, value })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::llm::llm::Config {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("model", crate::wrappers::JsString(self.model))?;
+        obj.set::<_, Option<f32>>("temperature", self.temperature.map(|v| v))?;
+        obj.set::<_, Option<u32>>("maxTokens", self.max_tokens.map(|v| v))?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsList<crate::wrappers::JsString>>,
+            >(
+            "stopSequences",
+            self
+                .stop_sequences
+                .map(|v| crate::wrappers::JsList(
+                    v
+                        .into_iter()
+                        .map(|v| crate::wrappers::JsString(v))
+                        .collect::<Vec<_>>(),
+                )),
+        )?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<crate::bindings::golem::llm::llm::ToolDefinition>,
+            >(
+            "tools",
+            crate::wrappers::JsList(
+                self.tools.into_iter().map(|v| v).collect::<Vec<_>>(),
+            ),
+        )?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >("toolChoice", self.tool_choice.map(|v| crate::wrappers::JsString(v)))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<crate::bindings::golem::llm::llm::Kv>,
+            >(
+            "providerOptions",
+            crate::wrappers::JsList(
+                self.provider_options.into_iter().map(|v| v).collect::<Vec<_>>(),
+            ),
+        )?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::llm::llm::Config {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT config",
+            &[
+                "model",
+                "temperature",
+                "maxTokens",
+                "stopSequences",
+                "tools",
+                "toolChoice",
+                "providerOptions",
+            ],
+            &[
+                ("model", "string"),
+                ("tools", "list<tool-definition>"),
+                ("providerOptions", "list<kv>"),
+            ],
+            false,
+        )?;
+        let model: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "model",
+            "string",
+        )?;
+        let model: String = model.0;
+        let temperature: Option<f32> = crate::wrappers::get_field(
+            &obj,
+            "temperature",
+            "option<f32>",
+        )?;
+        let temperature: Option<f32> = temperature.map(|v| v);
+        let max_tokens: Option<u32> = crate::wrappers::get_field(
+            &obj,
+            "maxTokens",
+            "option<u32>",
+        )?;
+        let max_tokens: Option<u32> = max_tokens.map(|v| v);
+        let stop_sequences: Option<crate::wrappers::JsList<crate::wrappers::JsString>> = crate::wrappers::get_field(
+            &obj,
+            "stopSequences",
+            "option<list<string>>",
+        )?;
+        let stop_sequences: Option<Vec<String>> = stop_sequences
+            .map(|v| v.0.into_iter().map(|v| v.0).collect::<Vec<_>>());
+        let tools: crate::wrappers::JsList<
+            crate::bindings::golem::llm::llm::ToolDefinition,
+        > = crate::wrappers::get_field(&obj, "tools", "list<tool-definition>")?;
+        let tools: Vec<crate::bindings::golem::llm::llm::ToolDefinition> = tools
+            .0
+            .into_iter()
+            .map(|v| v)
+            .collect::<Vec<_>>();
+        let tool_choice: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "toolChoice",
+            "option<string>",
+        )?;
+        let tool_choice: Option<String> = tool_choice.map(|v| v.0);
+        let provider_options: crate::wrappers::JsList<
+            crate::bindings::golem::llm::llm::Kv,
+        > = crate::wrappers::get_field(&obj, "providerOptions", "list<kv>")?;
+        let provider_options: Vec<crate::bindings::golem::llm::llm::Kv> = provider_options
+            .0
+            .into_iter()
+            .map(|v| v)
+            .collect::<Vec<_>>();
+        Ok(Self {
+            model,
+            temperature,
+            max_tokens,
+            stop_sequences,
+            tools,
+            tool_choice,
+            provider_options,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::llm::llm::Usage {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<_, Option<u32>>("inputTokens", self.input_tokens.map(|v| v))?;
+        obj.set::<_, Option<u32>>("outputTokens", self.output_tokens.map(|v| v))?;
+        obj.set::<_, Option<u32>>("totalTokens", self.total_tokens.map(|v| v))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::llm::llm::Usage {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT usage",
+            &["inputTokens", "outputTokens", "totalTokens"],
+            &[],
+            false,
+        )?;
+        let input_tokens: Option<u32> = crate::wrappers::get_field(
+            &obj,
+            "inputTokens",
+            "option<u32>",
+        )?;
+        let input_tokens: Option<u32> = input_tokens.map(|v| v);
+        let output_tokens: Option<u32> = crate::wrappers::get_field(
+            &obj,
+            "outputTokens",
+            "option<u32>",
+        )?;
+        let output_tokens: Option<u32> = output_tokens.map(|v| v);
+        let total_tokens: Option<u32> = crate::wrappers::get_field(
+            &obj,
+            "totalTokens",
+            "option<u32>",
+        )?;
+        let total_tokens: Option<u32> = total_tokens.map(|v| v);
+        Ok(Self {
+            input_tokens,
+            output_tokens,
+            total_tokens,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::llm::llm::ResponseMetadata {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                Option<crate::bindings::golem::llm::llm::FinishReason>,
+            >("finishReason", self.finish_reason.map(|v| v))?;
+        obj.set::<
+                _,
+                Option<crate::bindings::golem::llm::llm::Usage>,
+            >("usage", self.usage.map(|v| v))?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >("providerId", self.provider_id.map(|v| crate::wrappers::JsString(v)))?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >("timestamp", self.timestamp.map(|v| crate::wrappers::JsString(v)))?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >(
+            "providerMetadataJson",
+            self.provider_metadata_json.map(|v| crate::wrappers::JsString(v)),
+        )?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::llm::llm::ResponseMetadata {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT response-metadata",
+            &[
+                "finishReason",
+                "usage",
+                "providerId",
+                "timestamp",
+                "providerMetadataJson",
+            ],
+            &[],
+            false,
+        )?;
+        let finish_reason: Option<crate::bindings::golem::llm::llm::FinishReason> = crate::wrappers::get_field(
+            &obj,
+            "finishReason",
+            "option<finish-reason>",
+        )?;
+        let finish_reason: Option<crate::bindings::golem::llm::llm::FinishReason> = finish_reason
+            .map(|v| v);
+        let usage: Option<crate::bindings::golem::llm::llm::Usage> = crate::wrappers::get_field(
+            &obj,
+            "usage",
+            "option<usage>",
+        )?;
+        let usage: Option<crate::bindings::golem::llm::llm::Usage> = usage.map(|v| v);
+        let provider_id: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "providerId",
+            "option<string>",
+        )?;
+        let provider_id: Option<String> = provider_id.map(|v| v.0);
+        let timestamp: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "timestamp",
+            "option<string>",
+        )?;
+        let timestamp: Option<String> = timestamp.map(|v| v.0);
+        let provider_metadata_json: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "providerMetadataJson",
+            "option<string>",
+        )?;
+        let provider_metadata_json: Option<String> = provider_metadata_json.map(|v| v.0);
+        Ok(Self {
+            finish_reason,
+            usage,
+            provider_id,
+            timestamp,
+            provider_metadata_json,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::llm::llm::CompleteResponse {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("id", crate::wrappers::JsString(self.id))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<crate::bindings::golem::llm::llm::ContentPart>,
+            >(
+            "content",
+            crate::wrappers::JsList(
+                self.content.into_iter().map(|v| v).collect::<Vec<_>>(),
+            ),
+        )?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<crate::bindings::golem::llm::llm::ToolCall>,
+            >(
+            "toolCalls",
+            crate::wrappers::JsList(
+                self.tool_calls.into_iter().map(|v| v).collect::<Vec<_>>(),
+            ),
+        )?;
+        obj.set::<
+                _,
+                crate::bindings::golem::llm::llm::ResponseMetadata,
+            >("metadata", self.metadata)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::llm::llm::CompleteResponse {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT complete-response",
+            &["id", "content", "toolCalls", "metadata"],
+            &[
+                ("id", "string"),
+                ("content", "list<content-part>"),
+                ("toolCalls", "list<tool-call>"),
+                ("metadata", "response-metadata"),
+            ],
+            false,
+        )?;
+        let id: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "id",
+            "string",
+        )?;
+        let id: String = id.0;
+        let content: crate::wrappers::JsList<
+            crate::bindings::golem::llm::llm::ContentPart,
+        > = crate::wrappers::get_field(&obj, "content", "list<content-part>")?;
+        let content: Vec<crate::bindings::golem::llm::llm::ContentPart> = content
+            .0
+            .into_iter()
+            .map(|v| v)
+            .collect::<Vec<_>>();
+        let tool_calls: crate::wrappers::JsList<
+            crate::bindings::golem::llm::llm::ToolCall,
+        > = crate::wrappers::get_field(&obj, "toolCalls", "list<tool-call>")?;
+        let tool_calls: Vec<crate::bindings::golem::llm::llm::ToolCall> = tool_calls
+            .0
+            .into_iter()
+            .map(|v| v)
+            .collect::<Vec<_>>();
+        let metadata: crate::bindings::golem::llm::llm::ResponseMetadata = crate::wrappers::get_field(
+            &obj,
+            "metadata",
+            "response-metadata",
+        )?;
+        let metadata: crate::bindings::golem::llm::llm::ResponseMetadata = metadata;
+        Ok(Self {
+            id,
+            content,
+            tool_calls,
+            metadata,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::llm::llm::Error {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<_, crate::bindings::golem::llm::llm::ErrorCode>("code", self.code)?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("message", crate::wrappers::JsString(self.message))?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >(
+            "providerErrorJson",
+            self.provider_error_json.map(|v| crate::wrappers::JsString(v)),
+        )?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::llm::llm::Error {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT error",
+            &["code", "message", "providerErrorJson"],
+            &[("code", "error-code"), ("message", "string")],
+            false,
+        )?;
+        let code: crate::bindings::golem::llm::llm::ErrorCode = crate::wrappers::get_field(
+            &obj,
+            "code",
+            "error-code",
+        )?;
+        let code: crate::bindings::golem::llm::llm::ErrorCode = code;
+        let message: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "message",
+            "string",
+        )?;
+        let message: String = message.0;
+        let provider_error_json: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "providerErrorJson",
+            "option<string>",
+        )?;
+        let provider_error_json: Option<String> = provider_error_json.map(|v| v.0);
+        Ok(Self {
+            code,
+            message,
+            provider_error_json,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::llm::llm::ChatEvent {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::llm::llm::ChatEvent::Message(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::llm::llm::CompleteResponse,
+                >(ctx, "message", inner)
+            }
+            crate::bindings::golem::llm::llm::ChatEvent::ToolRequest(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsList<crate::bindings::golem::llm::llm::ToolCall>,
+                >(
+                    ctx,
+                    "tool-request",
+                    crate::wrappers::JsList(
+                        inner.into_iter().map(|v| v).collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            crate::bindings::golem::llm::llm::ChatEvent::Error(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::llm::llm::Error,
+                >(ctx, "error", inner)
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::llm::llm::ChatEvent {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "message" => {
+                let inner: crate::bindings::golem::llm::llm::CompleteResponse = crate::wrappers::variant_value(
+                    &obj,
+                    "complete-response",
+                )?;
+                Ok(crate::bindings::golem::llm::llm::ChatEvent::Message(inner))
+            }
+            "tool-request" => {
+                let inner: crate::wrappers::JsList<
+                    crate::bindings::golem::llm::llm::ToolCall,
+                > = crate::wrappers::variant_value(&obj, "list<tool-call>")?;
+                Ok(
+                    crate::bindings::golem::llm::llm::ChatEvent::ToolRequest(
+                        inner.0.into_iter().map(|v| v).collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            "error" => {
+                let inner: crate::bindings::golem::llm::llm::Error = crate::wrappers::variant_value(
+                    &obj,
+                    "error",
+                )?;
+                Ok(crate::bindings::golem::llm::llm::ChatEvent::Error(inner))
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS chat-event",
+                        "WIT chat-event",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::llm::llm::StreamDelta {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                Option<
+                    crate::wrappers::JsList<
+                        crate::bindings::golem::llm::llm::ContentPart,
+                    >,
+                >,
+            >(
+            "content",
+            self
+                .content
+                .map(|v| crate::wrappers::JsList(
+                    v.into_iter().map(|v| v).collect::<Vec<_>>(),
+                )),
+        )?;
+        obj.set::<
+                _,
+                Option<
+                    crate::wrappers::JsList<crate::bindings::golem::llm::llm::ToolCall>,
+                >,
+            >(
+            "toolCalls",
+            self
+                .tool_calls
+                .map(|v| crate::wrappers::JsList(
+                    v.into_iter().map(|v| v).collect::<Vec<_>>(),
+                )),
+        )?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::llm::llm::StreamDelta {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT stream-delta",
+            &["content", "toolCalls"],
+            &[],
+            false,
+        )?;
+        let content: Option<
+            crate::wrappers::JsList<crate::bindings::golem::llm::llm::ContentPart>,
+        > = crate::wrappers::get_field(&obj, "content", "option<list<content-part>>")?;
+        let content: Option<Vec<crate::bindings::golem::llm::llm::ContentPart>> = content
+            .map(|v| v.0.into_iter().map(|v| v).collect::<Vec<_>>());
+        let tool_calls: Option<
+            crate::wrappers::JsList<crate::bindings::golem::llm::llm::ToolCall>,
+        > = crate::wrappers::get_field(&obj, "toolCalls", "option<list<tool-call>>")?;
+        let tool_calls: Option<Vec<crate::bindings::golem::llm::llm::ToolCall>> = tool_calls
+            .map(|v| v.0.into_iter().map(|v| v).collect::<Vec<_>>());
+        Ok(Self { content, tool_calls })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::llm::llm::StreamEvent {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::llm::llm::StreamEvent::Delta(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::llm::llm::StreamDelta,
+                >(ctx, "delta", inner)
+            }
+            crate::bindings::golem::llm::llm::StreamEvent::Finish(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::llm::llm::ResponseMetadata,
+                >(ctx, "finish", inner)
+            }
+            crate::bindings::golem::llm::llm::StreamEvent::Error(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::llm::llm::Error,
+                >(ctx, "error", inner)
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::llm::llm::StreamEvent {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "delta" => {
+                let inner: crate::bindings::golem::llm::llm::StreamDelta = crate::wrappers::variant_value(
+                    &obj,
+                    "stream-delta",
+                )?;
+                Ok(crate::bindings::golem::llm::llm::StreamEvent::Delta(inner))
+            }
+            "finish" => {
+                let inner: crate::bindings::golem::llm::llm::ResponseMetadata = crate::wrappers::variant_value(
+                    &obj,
+                    "response-metadata",
+                )?;
+                Ok(crate::bindings::golem::llm::llm::StreamEvent::Finish(inner))
+            }
+            "error" => {
+                let inner: crate::bindings::golem::llm::llm::Error = crate::wrappers::variant_value(
+                    &obj,
+                    "error",
+                )?;
+                Ok(crate::bindings::golem::llm::llm::StreamEvent::Error(inner))
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS stream-event",
+                        "WIT stream-event",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::types::Date {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<_, i32>("year", self.year)?;
+        obj.set::<_, u8>("month", self.month)?;
+        obj.set::<_, u8>("day", self.day)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::types::Date {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT date",
+            &["year", "month", "day"],
+            &[("year", "s32"), ("month", "u8"), ("day", "u8")],
+            false,
+        )?;
+        let year: i32 = crate::wrappers::get_field(&obj, "year", "s32")?;
+        let year: i32 = year;
+        let month: u8 = crate::wrappers::get_field(&obj, "month", "u8")?;
+        let month: u8 = month;
+        let day: u8 = crate::wrappers::get_field(&obj, "day", "u8")?;
+        let day: u8 = day;
+        Ok(Self { year, month, day })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::types::Time {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<_, u8>("hour", self.hour)?;
+        obj.set::<_, u8>("minute", self.minute)?;
+        obj.set::<_, u8>("second", self.second)?;
+        obj.set::<_, u32>("nanosecond", self.nanosecond)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::types::Time {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT time",
+            &["hour", "minute", "second", "nanosecond"],
+            &[("hour", "u8"), ("minute", "u8"), ("second", "u8"), ("nanosecond", "u32")],
+            false,
+        )?;
+        let hour: u8 = crate::wrappers::get_field(&obj, "hour", "u8")?;
+        let hour: u8 = hour;
+        let minute: u8 = crate::wrappers::get_field(&obj, "minute", "u8")?;
+        let minute: u8 = minute;
+        let second: u8 = crate::wrappers::get_field(&obj, "second", "u8")?;
+        let second: u8 = second;
+        let nanosecond: u32 = crate::wrappers::get_field(&obj, "nanosecond", "u32")?;
+        let nanosecond: u32 = nanosecond;
+        Ok(Self {
+            hour,
+            minute,
+            second,
+            nanosecond,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::types::Timestamp {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<_, crate::bindings::golem::rdbms::types::Date>("date", self.date)?;
+        obj.set::<_, crate::bindings::golem::rdbms::types::Time>("time", self.time)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::types::Timestamp {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT timestamp",
+            &["date", "time"],
+            &[("date", "date"), ("time", "time")],
+            false,
+        )?;
+        let date: crate::bindings::golem::rdbms::types::Date = crate::wrappers::get_field(
+            &obj,
+            "date",
+            "date",
+        )?;
+        let date: crate::bindings::golem::rdbms::types::Date = date;
+        let time: crate::bindings::golem::rdbms::types::Time = crate::wrappers::get_field(
+            &obj,
+            "time",
+            "time",
+        )?;
+        let time: crate::bindings::golem::rdbms::types::Time = time;
+        Ok(Self { date, time })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::mysql::Error {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::rdbms::mysql::Error::ConnectionFailure(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "connection-failure", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::rdbms::mysql::Error::QueryParameterFailure(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "query-parameter-failure", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::rdbms::mysql::Error::QueryExecutionFailure(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "query-execution-failure", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::rdbms::mysql::Error::QueryResponseFailure(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "query-response-failure", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::rdbms::mysql::Error::Other(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "other", crate::wrappers::JsString(inner))
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::mysql::Error {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "connection-failure" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(
+                    crate::bindings::golem::rdbms::mysql::Error::ConnectionFailure(
+                        inner.0,
+                    ),
+                )
+            }
+            "query-parameter-failure" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(
+                    crate::bindings::golem::rdbms::mysql::Error::QueryParameterFailure(
+                        inner.0,
+                    ),
+                )
+            }
+            "query-execution-failure" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(
+                    crate::bindings::golem::rdbms::mysql::Error::QueryExecutionFailure(
+                        inner.0,
+                    ),
+                )
+            }
+            "query-response-failure" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(
+                    crate::bindings::golem::rdbms::mysql::Error::QueryResponseFailure(
+                        inner.0,
+                    ),
+                )
+            }
+            "other" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(crate::bindings::golem::rdbms::mysql::Error::Other(inner.0))
+            }
+            _ => {
+                Err(crate::wrappers::unknown_variant_case("JS error", "WIT error", &tag))
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::mysql::DbColumnType {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::rdbms::mysql::DbColumnType::Boolean => {
+                crate::wrappers::variant_case(ctx, "boolean")
+            }
+            crate::bindings::golem::rdbms::mysql::DbColumnType::Tinyint => {
+                crate::wrappers::variant_case(ctx, "tinyint")
+            }
+            crate::bindings::golem::rdbms::mysql::DbColumnType::Smallint => {
+                crate::wrappers::variant_case(ctx, "smallint")
+            }
+            crate::bindings::golem::rdbms::mysql::DbColumnType::Mediumint => {
+                crate::wrappers::variant_case(ctx, "mediumint")
+            }
+            crate::bindings::golem::rdbms::mysql::DbColumnType::Int => {
+                crate::wrappers::variant_case(ctx, "int")
+            }
+            crate::bindings::golem::rdbms::mysql::DbColumnType::Bigint => {
+                crate::wrappers::variant_case(ctx, "bigint")
+            }
+            crate::bindings::golem::rdbms::mysql::DbColumnType::TinyintUnsigned => {
+                crate::wrappers::variant_case(ctx, "tinyint-unsigned")
+            }
+            crate::bindings::golem::rdbms::mysql::DbColumnType::SmallintUnsigned => {
+                crate::wrappers::variant_case(ctx, "smallint-unsigned")
+            }
+            crate::bindings::golem::rdbms::mysql::DbColumnType::MediumintUnsigned => {
+                crate::wrappers::variant_case(ctx, "mediumint-unsigned")
+            }
+            crate::bindings::golem::rdbms::mysql::DbColumnType::IntUnsigned => {
+                crate::wrappers::variant_case(ctx, "int-unsigned")
+            }
+            crate::bindings::golem::rdbms::mysql::DbColumnType::BigintUnsigned => {
+                crate::wrappers::variant_case(ctx, "bigint-unsigned")
+            }
+            crate::bindings::golem::rdbms::mysql::DbColumnType::Float => {
+                crate::wrappers::variant_case(ctx, "float")
+            }
+            crate::bindings::golem::rdbms::mysql::DbColumnType::Double => {
+                crate::wrappers::variant_case(ctx, "double")
+            }
+            crate::bindings::golem::rdbms::mysql::DbColumnType::Decimal => {
+                crate::wrappers::variant_case(ctx, "decimal")
+            }
+            crate::bindings::golem::rdbms::mysql::DbColumnType::Date => {
+                crate::wrappers::variant_case(ctx, "date")
+            }
+            crate::bindings::golem::rdbms::mysql::DbColumnType::Datetime => {
+                crate::wrappers::variant_case(ctx, "datetime")
+            }
+            crate::bindings::golem::rdbms::mysql::DbColumnType::Timestamp => {
+                crate::wrappers::variant_case(ctx, "timestamp")
+            }
+            crate::bindings::golem::rdbms::mysql::DbColumnType::Time => {
+                crate::wrappers::variant_case(ctx, "time")
+            }
+            crate::bindings::golem::rdbms::mysql::DbColumnType::Year => {
+                crate::wrappers::variant_case(ctx, "year")
+            }
+            crate::bindings::golem::rdbms::mysql::DbColumnType::Fixchar => {
+                crate::wrappers::variant_case(ctx, "fixchar")
+            }
+            crate::bindings::golem::rdbms::mysql::DbColumnType::Varchar => {
+                crate::wrappers::variant_case(ctx, "varchar")
+            }
+            crate::bindings::golem::rdbms::mysql::DbColumnType::Tinytext => {
+                crate::wrappers::variant_case(ctx, "tinytext")
+            }
+            crate::bindings::golem::rdbms::mysql::DbColumnType::Text => {
+                crate::wrappers::variant_case(ctx, "text")
+            }
+            crate::bindings::golem::rdbms::mysql::DbColumnType::Mediumtext => {
+                crate::wrappers::variant_case(ctx, "mediumtext")
+            }
+            crate::bindings::golem::rdbms::mysql::DbColumnType::Longtext => {
+                crate::wrappers::variant_case(ctx, "longtext")
+            }
+            crate::bindings::golem::rdbms::mysql::DbColumnType::Binary => {
+                crate::wrappers::variant_case(ctx, "binary")
+            }
+            crate::bindings::golem::rdbms::mysql::DbColumnType::Varbinary => {
+                crate::wrappers::variant_case(ctx, "varbinary")
+            }
+            crate::bindings::golem::rdbms::mysql::DbColumnType::Tinyblob => {
+                crate::wrappers::variant_case(ctx, "tinyblob")
+            }
+            crate::bindings::golem::rdbms::mysql::DbColumnType::Blob => {
+                crate::wrappers::variant_case(ctx, "blob")
+            }
+            crate::bindings::golem::rdbms::mysql::DbColumnType::Mediumblob => {
+                crate::wrappers::variant_case(ctx, "mediumblob")
+            }
+            crate::bindings::golem::rdbms::mysql::DbColumnType::Longblob => {
+                crate::wrappers::variant_case(ctx, "longblob")
+            }
+            crate::bindings::golem::rdbms::mysql::DbColumnType::Enumeration => {
+                crate::wrappers::variant_case(ctx, "enumeration")
+            }
+            crate::bindings::golem::rdbms::mysql::DbColumnType::Set => {
+                crate::wrappers::variant_case(ctx, "set")
+            }
+            crate::bindings::golem::rdbms::mysql::DbColumnType::Bit => {
+                crate::wrappers::variant_case(ctx, "bit")
+            }
+            crate::bindings::golem::rdbms::mysql::DbColumnType::Json => {
+                crate::wrappers::variant_case(ctx, "json")
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::mysql::DbColumnType {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (_, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "boolean" => Ok(crate::bindings::golem::rdbms::mysql::DbColumnType::Boolean),
+            "tinyint" => Ok(crate::bindings::golem::rdbms::mysql::DbColumnType::Tinyint),
+            "smallint" => {
+                Ok(crate::bindings::golem::rdbms::mysql::DbColumnType::Smallint)
+            }
+            "mediumint" => {
+                Ok(crate::bindings::golem::rdbms::mysql::DbColumnType::Mediumint)
+            }
+            "int" => Ok(crate::bindings::golem::rdbms::mysql::DbColumnType::Int),
+            "bigint" => Ok(crate::bindings::golem::rdbms::mysql::DbColumnType::Bigint),
+            "tinyint-unsigned" => {
+                Ok(crate::bindings::golem::rdbms::mysql::DbColumnType::TinyintUnsigned)
+            }
+            "smallint-unsigned" => {
+                Ok(crate::bindings::golem::rdbms::mysql::DbColumnType::SmallintUnsigned)
+            }
+            "mediumint-unsigned" => {
+                Ok(crate::bindings::golem::rdbms::mysql::DbColumnType::MediumintUnsigned)
+            }
+            "int-unsigned" => {
+                Ok(crate::bindings::golem::rdbms::mysql::DbColumnType::IntUnsigned)
+            }
+            "bigint-unsigned" => {
+                Ok(crate::bindings::golem::rdbms::mysql::DbColumnType::BigintUnsigned)
+            }
+            "float" => Ok(crate::bindings::golem::rdbms::mysql::DbColumnType::Float),
+            "double" => Ok(crate::bindings::golem::rdbms::mysql::DbColumnType::Double),
+            "decimal" => Ok(crate::bindings::golem::rdbms::mysql::DbColumnType::Decimal),
+            "date" => Ok(crate::bindings::golem::rdbms::mysql::DbColumnType::Date),
+            "datetime" => {
+                Ok(crate::bindings::golem::rdbms::mysql::DbColumnType::Datetime)
+            }
+            "timestamp" => {
+                Ok(crate::bindings::golem::rdbms::mysql::DbColumnType::Timestamp)
+            }
+            "time" => Ok(crate::bindings::golem::rdbms::mysql::DbColumnType::Time),
+            "year" => Ok(crate::bindings::golem::rdbms::mysql::DbColumnType::Year),
+            "fixchar" => Ok(crate::bindings::golem::rdbms::mysql::DbColumnType::Fixchar),
+            "varchar" => Ok(crate::bindings::golem::rdbms::mysql::DbColumnType::Varchar),
+            "tinytext" => {
+                Ok(crate::bindings::golem::rdbms::mysql::DbColumnType::Tinytext)
+            }
+            "text" => Ok(crate::bindings::golem::rdbms::mysql::DbColumnType::Text),
+            "mediumtext" => {
+                Ok(crate::bindings::golem::rdbms::mysql::DbColumnType::Mediumtext)
+            }
+            "longtext" => {
+                Ok(crate::bindings::golem::rdbms::mysql::DbColumnType::Longtext)
+            }
+            "binary" => Ok(crate::bindings::golem::rdbms::mysql::DbColumnType::Binary),
+            "varbinary" => {
+                Ok(crate::bindings::golem::rdbms::mysql::DbColumnType::Varbinary)
+            }
+            "tinyblob" => {
+                Ok(crate::bindings::golem::rdbms::mysql::DbColumnType::Tinyblob)
+            }
+            "blob" => Ok(crate::bindings::golem::rdbms::mysql::DbColumnType::Blob),
+            "mediumblob" => {
+                Ok(crate::bindings::golem::rdbms::mysql::DbColumnType::Mediumblob)
+            }
+            "longblob" => {
+                Ok(crate::bindings::golem::rdbms::mysql::DbColumnType::Longblob)
+            }
+            "enumeration" => {
+                Ok(crate::bindings::golem::rdbms::mysql::DbColumnType::Enumeration)
+            }
+            "set" => Ok(crate::bindings::golem::rdbms::mysql::DbColumnType::Set),
+            "bit" => Ok(crate::bindings::golem::rdbms::mysql::DbColumnType::Bit),
+            "json" => Ok(crate::bindings::golem::rdbms::mysql::DbColumnType::Json),
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS db-column-type",
+                        "WIT db-column-type",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::mysql::DbColumn {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::BigIntWrapper<u64>,
+            >("ordinal", crate::wrappers::BigIntWrapper(self.ordinal))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("name", crate::wrappers::JsString(self.name))?;
+        obj.set::<
+                _,
+                crate::bindings::golem::rdbms::mysql::DbColumnType,
+            >("dbType", self.db_type)?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("dbTypeName", crate::wrappers::JsString(self.db_type_name))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::mysql::DbColumn {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT db-column",
+            &["ordinal", "name", "dbType", "dbTypeName"],
+            &[
+                ("ordinal", "u64"),
+                ("name", "string"),
+                ("dbType", "db-column-type"),
+                ("dbTypeName", "string"),
+            ],
+            false,
+        )?;
+        let ordinal: crate::wrappers::BigIntWrapper<u64> = crate::wrappers::get_field(
+            &obj,
+            "ordinal",
+            "u64",
+        )?;
+        let ordinal: u64 = ordinal.0;
+        let name: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "name",
+            "string",
+        )?;
+        let name: String = name.0;
+        let db_type: crate::bindings::golem::rdbms::mysql::DbColumnType = crate::wrappers::get_field(
+            &obj,
+            "dbType",
+            "db-column-type",
+        )?;
+        let db_type: crate::bindings::golem::rdbms::mysql::DbColumnType = db_type;
+        let db_type_name: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "dbTypeName",
+            "string",
+        )?;
+        let db_type_name: String = db_type_name.0;
+        Ok(Self {
+            ordinal,
+            name,
+            db_type,
+            db_type_name,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::mysql::DbValue {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::rdbms::mysql::DbValue::Boolean(inner) => {
+                crate::wrappers::variant_case_with_value::<bool>(ctx, "boolean", inner)
+            }
+            crate::bindings::golem::rdbms::mysql::DbValue::Tinyint(inner) => {
+                crate::wrappers::variant_case_with_value::<i8>(ctx, "tinyint", inner)
+            }
+            crate::bindings::golem::rdbms::mysql::DbValue::Smallint(inner) => {
+                crate::wrappers::variant_case_with_value::<i16>(ctx, "smallint", inner)
+            }
+            crate::bindings::golem::rdbms::mysql::DbValue::Mediumint(inner) => {
+                crate::wrappers::variant_case_with_value::<i32>(ctx, "mediumint", inner)
+            }
+            crate::bindings::golem::rdbms::mysql::DbValue::Int(inner) => {
+                crate::wrappers::variant_case_with_value::<i32>(ctx, "int", inner)
+            }
+            crate::bindings::golem::rdbms::mysql::DbValue::Bigint(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::BigIntWrapper<i64>,
+                >(ctx, "bigint", crate::wrappers::BigIntWrapper(inner))
+            }
+            crate::bindings::golem::rdbms::mysql::DbValue::TinyintUnsigned(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    u8,
+                >(ctx, "tinyint-unsigned", inner)
+            }
+            crate::bindings::golem::rdbms::mysql::DbValue::SmallintUnsigned(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    u16,
+                >(ctx, "smallint-unsigned", inner)
+            }
+            crate::bindings::golem::rdbms::mysql::DbValue::MediumintUnsigned(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    u32,
+                >(ctx, "mediumint-unsigned", inner)
+            }
+            crate::bindings::golem::rdbms::mysql::DbValue::IntUnsigned(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    u32,
+                >(ctx, "int-unsigned", inner)
+            }
+            crate::bindings::golem::rdbms::mysql::DbValue::BigintUnsigned(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::BigIntWrapper<u64>,
+                >(ctx, "bigint-unsigned", crate::wrappers::BigIntWrapper(inner))
+            }
+            crate::bindings::golem::rdbms::mysql::DbValue::Float(inner) => {
+                crate::wrappers::variant_case_with_value::<f32>(ctx, "float", inner)
+            }
+            crate::bindings::golem::rdbms::mysql::DbValue::Double(inner) => {
+                crate::wrappers::variant_case_with_value::<f64>(ctx, "double", inner)
+            }
+            crate::bindings::golem::rdbms::mysql::DbValue::Decimal(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "decimal", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::rdbms::mysql::DbValue::Date(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::types::Date,
+                >(ctx, "date", inner)
+            }
+            crate::bindings::golem::rdbms::mysql::DbValue::Datetime(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::types::Timestamp,
+                >(ctx, "datetime", inner)
+            }
+            crate::bindings::golem::rdbms::mysql::DbValue::Timestamp(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::types::Timestamp,
+                >(ctx, "timestamp", inner)
+            }
+            crate::bindings::golem::rdbms::mysql::DbValue::Time(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::types::Time,
+                >(ctx, "time", inner)
+            }
+            crate::bindings::golem::rdbms::mysql::DbValue::Year(inner) => {
+                crate::wrappers::variant_case_with_value::<u16>(ctx, "year", inner)
+            }
+            crate::bindings::golem::rdbms::mysql::DbValue::Fixchar(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "fixchar", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::rdbms::mysql::DbValue::Varchar(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "varchar", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::rdbms::mysql::DbValue::Tinytext(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "tinytext", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::rdbms::mysql::DbValue::Text(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "text", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::rdbms::mysql::DbValue::Mediumtext(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "mediumtext", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::rdbms::mysql::DbValue::Longtext(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "longtext", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::rdbms::mysql::DbValue::Binary(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::UInt8Array,
+                >(ctx, "binary", crate::wrappers::UInt8Array(inner))
+            }
+            crate::bindings::golem::rdbms::mysql::DbValue::Varbinary(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::UInt8Array,
+                >(ctx, "varbinary", crate::wrappers::UInt8Array(inner))
+            }
+            crate::bindings::golem::rdbms::mysql::DbValue::Tinyblob(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::UInt8Array,
+                >(ctx, "tinyblob", crate::wrappers::UInt8Array(inner))
+            }
+            crate::bindings::golem::rdbms::mysql::DbValue::Blob(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::UInt8Array,
+                >(ctx, "blob", crate::wrappers::UInt8Array(inner))
+            }
+            crate::bindings::golem::rdbms::mysql::DbValue::Mediumblob(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::UInt8Array,
+                >(ctx, "mediumblob", crate::wrappers::UInt8Array(inner))
+            }
+            crate::bindings::golem::rdbms::mysql::DbValue::Longblob(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::UInt8Array,
+                >(ctx, "longblob", crate::wrappers::UInt8Array(inner))
+            }
+            crate::bindings::golem::rdbms::mysql::DbValue::Enumeration(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "enumeration", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::rdbms::mysql::DbValue::Set(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "set", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::rdbms::mysql::DbValue::Bit(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsList<bool>,
+                >(
+                    ctx,
+                    "bit",
+                    crate::wrappers::JsList(
+                        inner.into_iter().map(|v| v).collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            crate::bindings::golem::rdbms::mysql::DbValue::Json(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "json", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::rdbms::mysql::DbValue::Null => {
+                crate::wrappers::variant_case(ctx, "null")
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::mysql::DbValue {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "boolean" => {
+                let inner: bool = crate::wrappers::variant_value(&obj, "bool")?;
+                Ok(crate::bindings::golem::rdbms::mysql::DbValue::Boolean(inner))
+            }
+            "tinyint" => {
+                let inner: i8 = crate::wrappers::variant_value(&obj, "s8")?;
+                Ok(crate::bindings::golem::rdbms::mysql::DbValue::Tinyint(inner))
+            }
+            "smallint" => {
+                let inner: i16 = crate::wrappers::variant_value(&obj, "s16")?;
+                Ok(crate::bindings::golem::rdbms::mysql::DbValue::Smallint(inner))
+            }
+            "mediumint" => {
+                let inner: i32 = crate::wrappers::variant_value(&obj, "s32")?;
+                Ok(crate::bindings::golem::rdbms::mysql::DbValue::Mediumint(inner))
+            }
+            "int" => {
+                let inner: i32 = crate::wrappers::variant_value(&obj, "s32")?;
+                Ok(crate::bindings::golem::rdbms::mysql::DbValue::Int(inner))
+            }
+            "bigint" => {
+                let inner: crate::wrappers::BigIntWrapper<i64> = crate::wrappers::variant_value(
+                    &obj,
+                    "s64",
+                )?;
+                Ok(crate::bindings::golem::rdbms::mysql::DbValue::Bigint(inner.0))
+            }
+            "tinyint-unsigned" => {
+                let inner: u8 = crate::wrappers::variant_value(&obj, "u8")?;
+                Ok(crate::bindings::golem::rdbms::mysql::DbValue::TinyintUnsigned(inner))
+            }
+            "smallint-unsigned" => {
+                let inner: u16 = crate::wrappers::variant_value(&obj, "u16")?;
+                Ok(
+                    crate::bindings::golem::rdbms::mysql::DbValue::SmallintUnsigned(
+                        inner,
+                    ),
+                )
+            }
+            "mediumint-unsigned" => {
+                let inner: u32 = crate::wrappers::variant_value(&obj, "u32")?;
+                Ok(
+                    crate::bindings::golem::rdbms::mysql::DbValue::MediumintUnsigned(
+                        inner,
+                    ),
+                )
+            }
+            "int-unsigned" => {
+                let inner: u32 = crate::wrappers::variant_value(&obj, "u32")?;
+                Ok(crate::bindings::golem::rdbms::mysql::DbValue::IntUnsigned(inner))
+            }
+            "bigint-unsigned" => {
+                let inner: crate::wrappers::BigIntWrapper<u64> = crate::wrappers::variant_value(
+                    &obj,
+                    "u64",
+                )?;
+                Ok(
+                    crate::bindings::golem::rdbms::mysql::DbValue::BigintUnsigned(
+                        inner.0,
+                    ),
+                )
+            }
+            "float" => {
+                let inner: f32 = crate::wrappers::variant_value(&obj, "f32")?;
+                Ok(crate::bindings::golem::rdbms::mysql::DbValue::Float(inner))
+            }
+            "double" => {
+                let inner: f64 = crate::wrappers::variant_value(&obj, "f64")?;
+                Ok(crate::bindings::golem::rdbms::mysql::DbValue::Double(inner))
+            }
+            "decimal" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(crate::bindings::golem::rdbms::mysql::DbValue::Decimal(inner.0))
+            }
+            "date" => {
+                let inner: crate::bindings::golem::rdbms::types::Date = crate::wrappers::variant_value(
+                    &obj,
+                    "date",
+                )?;
+                Ok(crate::bindings::golem::rdbms::mysql::DbValue::Date(inner))
+            }
+            "datetime" => {
+                let inner: crate::bindings::golem::rdbms::types::Timestamp = crate::wrappers::variant_value(
+                    &obj,
+                    "timestamp",
+                )?;
+                Ok(crate::bindings::golem::rdbms::mysql::DbValue::Datetime(inner))
+            }
+            "timestamp" => {
+                let inner: crate::bindings::golem::rdbms::types::Timestamp = crate::wrappers::variant_value(
+                    &obj,
+                    "timestamp",
+                )?;
+                Ok(crate::bindings::golem::rdbms::mysql::DbValue::Timestamp(inner))
+            }
+            "time" => {
+                let inner: crate::bindings::golem::rdbms::types::Time = crate::wrappers::variant_value(
+                    &obj,
+                    "time",
+                )?;
+                Ok(crate::bindings::golem::rdbms::mysql::DbValue::Time(inner))
+            }
+            "year" => {
+                let inner: u16 = crate::wrappers::variant_value(&obj, "u16")?;
+                Ok(crate::bindings::golem::rdbms::mysql::DbValue::Year(inner))
+            }
+            "fixchar" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(crate::bindings::golem::rdbms::mysql::DbValue::Fixchar(inner.0))
+            }
+            "varchar" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(crate::bindings::golem::rdbms::mysql::DbValue::Varchar(inner.0))
+            }
+            "tinytext" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(crate::bindings::golem::rdbms::mysql::DbValue::Tinytext(inner.0))
+            }
+            "text" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(crate::bindings::golem::rdbms::mysql::DbValue::Text(inner.0))
+            }
+            "mediumtext" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(crate::bindings::golem::rdbms::mysql::DbValue::Mediumtext(inner.0))
+            }
+            "longtext" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(crate::bindings::golem::rdbms::mysql::DbValue::Longtext(inner.0))
+            }
+            "binary" => {
+                let inner: crate::wrappers::UInt8Array = crate::wrappers::variant_value(
+                    &obj,
+                    "list<u8>",
+                )?;
+                Ok(crate::bindings::golem::rdbms::mysql::DbValue::Binary(inner.0))
+            }
+            "varbinary" => {
+                let inner: crate::wrappers::UInt8Array = crate::wrappers::variant_value(
+                    &obj,
+                    "list<u8>",
+                )?;
+                Ok(crate::bindings::golem::rdbms::mysql::DbValue::Varbinary(inner.0))
+            }
+            "tinyblob" => {
+                let inner: crate::wrappers::UInt8Array = crate::wrappers::variant_value(
+                    &obj,
+                    "list<u8>",
+                )?;
+                Ok(crate::bindings::golem::rdbms::mysql::DbValue::Tinyblob(inner.0))
+            }
+            "blob" => {
+                let inner: crate::wrappers::UInt8Array = crate::wrappers::variant_value(
+                    &obj,
+                    "list<u8>",
+                )?;
+                Ok(crate::bindings::golem::rdbms::mysql::DbValue::Blob(inner.0))
+            }
+            "mediumblob" => {
+                let inner: crate::wrappers::UInt8Array = crate::wrappers::variant_value(
+                    &obj,
+                    "list<u8>",
+                )?;
+                Ok(crate::bindings::golem::rdbms::mysql::DbValue::Mediumblob(inner.0))
+            }
+            "longblob" => {
+                let inner: crate::wrappers::UInt8Array = crate::wrappers::variant_value(
+                    &obj,
+                    "list<u8>",
+                )?;
+                Ok(crate::bindings::golem::rdbms::mysql::DbValue::Longblob(inner.0))
+            }
+            "enumeration" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(crate::bindings::golem::rdbms::mysql::DbValue::Enumeration(inner.0))
+            }
+            "set" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(crate::bindings::golem::rdbms::mysql::DbValue::Set(inner.0))
+            }
+            "bit" => {
+                let inner: crate::wrappers::JsList<bool> = crate::wrappers::variant_value(
+                    &obj,
+                    "list<bool>",
+                )?;
+                Ok(
+                    crate::bindings::golem::rdbms::mysql::DbValue::Bit(
+                        inner.0.into_iter().map(|v| v).collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            "json" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(crate::bindings::golem::rdbms::mysql::DbValue::Json(inner.0))
+            }
+            "null" => Ok(crate::bindings::golem::rdbms::mysql::DbValue::Null),
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS db-value",
+                        "WIT db-value",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::mysql::DbRow {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<crate::bindings::golem::rdbms::mysql::DbValue>,
+            >(
+            "values",
+            crate::wrappers::JsList(
+                self.values.into_iter().map(|v| v).collect::<Vec<_>>(),
+            ),
+        )?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::mysql::DbRow {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT db-row",
+            &["values"],
+            &[("values", "list<db-value>")],
+            false,
+        )?;
+        let values: crate::wrappers::JsList<
+            crate::bindings::golem::rdbms::mysql::DbValue,
+        > = crate::wrappers::get_field(&obj, "values", "list<db-value>")?;
+        let values: Vec<crate::bindings::golem::rdbms::mysql::DbValue> = values
+            .0
+            .into_iter()
+            .map(|v| v)
+            .collect::<Vec<_>>();
+        Ok(Self { values })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::mysql::DbResult {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<crate::bindings::golem::rdbms::mysql::DbColumn>,
+            >(
+            "columns",
+            crate::wrappers::JsList(
+                self.columns.into_iter().map(|v| v).collect::<Vec<_>>(),
+            ),
+        )?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<crate::bindings::golem::rdbms::mysql::DbRow>,
+            >(
+            "rows",
+            crate::wrappers::JsList(self.rows.into_iter().map(|v| v).collect::<Vec<_>>()),
+        )?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::mysql::DbResult {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT db-result",
+            &["columns", "rows"],
+            &[("columns", "list<db-column>"), ("rows", "list<db-row>")],
+            false,
+        )?;
+        let columns: crate::wrappers::JsList<
+            crate::bindings::golem::rdbms::mysql::DbColumn,
+        > = crate::wrappers::get_field(&obj, "columns", "list<db-column>")?;
+        let columns: Vec<crate::bindings::golem::rdbms::mysql::DbColumn> = columns
+            .0
+            .into_iter()
+            .map(|v| v)
+            .collect::<Vec<_>>();
+        let rows: crate::wrappers::JsList<crate::bindings::golem::rdbms::mysql::DbRow> = crate::wrappers::get_field(
+            &obj,
+            "rows",
+            "list<db-row>",
+        )?;
+        let rows: Vec<crate::bindings::golem::rdbms::mysql::DbRow> = rows
+            .0
+            .into_iter()
+            .map(|v| v)
+            .collect::<Vec<_>>();
+        Ok(Self { columns, rows })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::types::Timetz {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<_, crate::bindings::golem::rdbms::types::Time>("time", self.time)?;
+        obj.set::<_, i32>("offset", self.offset)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::types::Timetz {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT timetz",
+            &["time", "offset"],
+            &[("time", "time"), ("offset", "s32")],
+            false,
+        )?;
+        let time: crate::bindings::golem::rdbms::types::Time = crate::wrappers::get_field(
+            &obj,
+            "time",
+            "time",
+        )?;
+        let time: crate::bindings::golem::rdbms::types::Time = time;
+        let offset: i32 = crate::wrappers::get_field(&obj, "offset", "s32")?;
+        let offset: i32 = offset;
+        Ok(Self { time, offset })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::types::Timestamptz {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::golem::rdbms::types::Timestamp,
+            >("timestamp", self.timestamp)?;
+        obj.set::<_, i32>("offset", self.offset)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::types::Timestamptz {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT timestamptz",
+            &["timestamp", "offset"],
+            &[("timestamp", "timestamp"), ("offset", "s32")],
+            false,
+        )?;
+        let timestamp: crate::bindings::golem::rdbms::types::Timestamp = crate::wrappers::get_field(
+            &obj,
+            "timestamp",
+            "timestamp",
+        )?;
+        let timestamp: crate::bindings::golem::rdbms::types::Timestamp = timestamp;
+        let offset: i32 = crate::wrappers::get_field(&obj, "offset", "s32")?;
+        let offset: i32 = offset;
+        Ok(Self { timestamp, offset })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::types::Uuid {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::BigIntWrapper<u64>,
+            >("highBits", crate::wrappers::BigIntWrapper(self.high_bits))?;
+        obj.set::<
+                _,
+                crate::wrappers::BigIntWrapper<u64>,
+            >("lowBits", crate::wrappers::BigIntWrapper(self.low_bits))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::types::Uuid {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT uuid",
+            &["highBits", "lowBits"],
+            &[("highBits", "u64"), ("lowBits", "u64")],
+            false,
+        )?;
+        let high_bits: crate::wrappers::BigIntWrapper<u64> = crate::wrappers::get_field(
+            &obj,
+            "highBits",
+            "u64",
+        )?;
+        let high_bits: u64 = high_bits.0;
+        let low_bits: crate::wrappers::BigIntWrapper<u64> = crate::wrappers::get_field(
+            &obj,
+            "lowBits",
+            "u64",
+        )?;
+        let low_bits: u64 = low_bits.0;
+        Ok(Self { high_bits, low_bits })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::types::IpAddress {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::rdbms::types::IpAddress::Ipv4(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    rquickjs::convert::List<(u8, u8, u8, u8)>,
+                >(
+                    ctx,
+                    "ipv4",
+                    rquickjs::convert::List((inner.0, inner.1, inner.2, inner.3)),
+                )
+            }
+            crate::bindings::golem::rdbms::types::IpAddress::Ipv6(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    rquickjs::convert::List<(u16, u16, u16, u16, u16, u16, u16, u16)>,
+                >(
+                    ctx,
+                    "ipv6",
+                    rquickjs::convert::List((
+                        inner.0,
+                        inner.1,
+                        inner.2,
+                        inner.3,
+                        inner.4,
+                        inner.5,
+                        inner.6,
+                        inner.7,
+                    )),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::types::IpAddress {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "ipv4" => {
+                let inner: rquickjs::convert::List<(u8, u8, u8, u8)> = crate::wrappers::variant_value(
+                    &obj,
+                    "tuple<u8, u8, u8, u8>",
+                )?;
+                Ok(
+                    crate::bindings::golem::rdbms::types::IpAddress::Ipv4((
+                        inner.0.0,
+                        inner.0.1,
+                        inner.0.2,
+                        inner.0.3,
+                    )),
+                )
+            }
+            "ipv6" => {
+                let inner: rquickjs::convert::List<
+                    (u16, u16, u16, u16, u16, u16, u16, u16),
+                > = crate::wrappers::variant_value(
+                    &obj,
+                    "tuple<u16, u16, u16, u16, u16, u16, u16, u16>",
+                )?;
+                Ok(
+                    crate::bindings::golem::rdbms::types::IpAddress::Ipv6((
+                        inner.0.0,
+                        inner.0.1,
+                        inner.0.2,
+                        inner.0.3,
+                        inner.0.4,
+                        inner.0.5,
+                        inner.0.6,
+                        inner.0.7,
+                    )),
+                )
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS ip-address",
+                        "WIT ip-address",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::types::MacAddress {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                rquickjs::convert::List<(u8, u8, u8, u8, u8, u8)>,
+            >(
+            "octets",
+            rquickjs::convert::List((
+                self.octets.0,
+                self.octets.1,
+                self.octets.2,
+                self.octets.3,
+                self.octets.4,
+                self.octets.5,
+            )),
+        )?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::types::MacAddress {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT mac-address",
+            &["octets"],
+            &[("octets", "tuple<u8, u8, u8, u8, u8, u8>")],
+            false,
+        )?;
+        let octets: rquickjs::convert::List<(u8, u8, u8, u8, u8, u8)> = crate::wrappers::get_field(
+            &obj,
+            "octets",
+            "tuple<u8, u8, u8, u8, u8, u8>",
+        )?;
+        let octets: (u8, u8, u8, u8, u8, u8) = (
+            octets.0.0,
+            octets.0.1,
+            octets.0.2,
+            octets.0.3,
+            octets.0.4,
+            octets.0.5,
+        );
+        Ok(Self { octets })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::postgres::Error {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::rdbms::postgres::Error::ConnectionFailure(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "connection-failure", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::rdbms::postgres::Error::QueryParameterFailure(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "query-parameter-failure", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::rdbms::postgres::Error::QueryExecutionFailure(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "query-execution-failure", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::rdbms::postgres::Error::QueryResponseFailure(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "query-response-failure", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::rdbms::postgres::Error::Other(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "other", crate::wrappers::JsString(inner))
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::postgres::Error {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "connection-failure" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(
+                    crate::bindings::golem::rdbms::postgres::Error::ConnectionFailure(
+                        inner.0,
+                    ),
+                )
+            }
+            "query-parameter-failure" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(
+                    crate::bindings::golem::rdbms::postgres::Error::QueryParameterFailure(
+                        inner.0,
+                    ),
+                )
+            }
+            "query-execution-failure" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(
+                    crate::bindings::golem::rdbms::postgres::Error::QueryExecutionFailure(
+                        inner.0,
+                    ),
+                )
+            }
+            "query-response-failure" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(
+                    crate::bindings::golem::rdbms::postgres::Error::QueryResponseFailure(
+                        inner.0,
+                    ),
+                )
+            }
+            "other" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::Error::Other(inner.0))
+            }
+            _ => {
+                Err(crate::wrappers::unknown_variant_case("JS error", "WIT error", &tag))
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::postgres::Interval {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<_, i32>("months", self.months)?;
+        obj.set::<_, i32>("days", self.days)?;
+        obj.set::<
+                _,
+                crate::wrappers::BigIntWrapper<i64>,
+            >("microseconds", crate::wrappers::BigIntWrapper(self.microseconds))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::postgres::Interval {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT interval",
+            &["months", "days", "microseconds"],
+            &[("months", "s32"), ("days", "s32"), ("microseconds", "s64")],
+            false,
+        )?;
+        let months: i32 = crate::wrappers::get_field(&obj, "months", "s32")?;
+        let months: i32 = months;
+        let days: i32 = crate::wrappers::get_field(&obj, "days", "s32")?;
+        let days: i32 = days;
+        let microseconds: crate::wrappers::BigIntWrapper<i64> = crate::wrappers::get_field(
+            &obj,
+            "microseconds",
+            "s64",
+        )?;
+        let microseconds: i64 = microseconds.0;
+        Ok(Self { months, days, microseconds })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::postgres::Int4bound {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::rdbms::postgres::Int4bound::Included(inner) => {
+                crate::wrappers::variant_case_with_value::<i32>(ctx, "included", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::Int4bound::Excluded(inner) => {
+                crate::wrappers::variant_case_with_value::<i32>(ctx, "excluded", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::Int4bound::Unbounded => {
+                crate::wrappers::variant_case(ctx, "unbounded")
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::postgres::Int4bound {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "included" => {
+                let inner: i32 = crate::wrappers::variant_value(&obj, "s32")?;
+                Ok(crate::bindings::golem::rdbms::postgres::Int4bound::Included(inner))
+            }
+            "excluded" => {
+                let inner: i32 = crate::wrappers::variant_value(&obj, "s32")?;
+                Ok(crate::bindings::golem::rdbms::postgres::Int4bound::Excluded(inner))
+            }
+            "unbounded" => {
+                Ok(crate::bindings::golem::rdbms::postgres::Int4bound::Unbounded)
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS int4bound",
+                        "WIT int4bound",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::postgres::Int8bound {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::rdbms::postgres::Int8bound::Included(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::BigIntWrapper<i64>,
+                >(ctx, "included", crate::wrappers::BigIntWrapper(inner))
+            }
+            crate::bindings::golem::rdbms::postgres::Int8bound::Excluded(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::BigIntWrapper<i64>,
+                >(ctx, "excluded", crate::wrappers::BigIntWrapper(inner))
+            }
+            crate::bindings::golem::rdbms::postgres::Int8bound::Unbounded => {
+                crate::wrappers::variant_case(ctx, "unbounded")
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::postgres::Int8bound {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "included" => {
+                let inner: crate::wrappers::BigIntWrapper<i64> = crate::wrappers::variant_value(
+                    &obj,
+                    "s64",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::Int8bound::Included(inner.0))
+            }
+            "excluded" => {
+                let inner: crate::wrappers::BigIntWrapper<i64> = crate::wrappers::variant_value(
+                    &obj,
+                    "s64",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::Int8bound::Excluded(inner.0))
+            }
+            "unbounded" => {
+                Ok(crate::bindings::golem::rdbms::postgres::Int8bound::Unbounded)
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS int8bound",
+                        "WIT int8bound",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::postgres::Numbound {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::rdbms::postgres::Numbound::Included(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "included", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::rdbms::postgres::Numbound::Excluded(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "excluded", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::rdbms::postgres::Numbound::Unbounded => {
+                crate::wrappers::variant_case(ctx, "unbounded")
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::postgres::Numbound {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "included" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::Numbound::Included(inner.0))
+            }
+            "excluded" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::Numbound::Excluded(inner.0))
+            }
+            "unbounded" => {
+                Ok(crate::bindings::golem::rdbms::postgres::Numbound::Unbounded)
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS numbound",
+                        "WIT numbound",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::postgres::Tsbound {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::rdbms::postgres::Tsbound::Included(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::types::Timestamp,
+                >(ctx, "included", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::Tsbound::Excluded(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::types::Timestamp,
+                >(ctx, "excluded", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::Tsbound::Unbounded => {
+                crate::wrappers::variant_case(ctx, "unbounded")
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::postgres::Tsbound {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "included" => {
+                let inner: crate::bindings::golem::rdbms::types::Timestamp = crate::wrappers::variant_value(
+                    &obj,
+                    "timestamp",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::Tsbound::Included(inner))
+            }
+            "excluded" => {
+                let inner: crate::bindings::golem::rdbms::types::Timestamp = crate::wrappers::variant_value(
+                    &obj,
+                    "timestamp",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::Tsbound::Excluded(inner))
+            }
+            "unbounded" => {
+                Ok(crate::bindings::golem::rdbms::postgres::Tsbound::Unbounded)
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS tsbound",
+                        "WIT tsbound",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::postgres::Tstzbound {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::rdbms::postgres::Tstzbound::Included(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::types::Timestamptz,
+                >(ctx, "included", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::Tstzbound::Excluded(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::types::Timestamptz,
+                >(ctx, "excluded", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::Tstzbound::Unbounded => {
+                crate::wrappers::variant_case(ctx, "unbounded")
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::postgres::Tstzbound {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "included" => {
+                let inner: crate::bindings::golem::rdbms::types::Timestamptz = crate::wrappers::variant_value(
+                    &obj,
+                    "timestamptz",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::Tstzbound::Included(inner))
+            }
+            "excluded" => {
+                let inner: crate::bindings::golem::rdbms::types::Timestamptz = crate::wrappers::variant_value(
+                    &obj,
+                    "timestamptz",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::Tstzbound::Excluded(inner))
+            }
+            "unbounded" => {
+                Ok(crate::bindings::golem::rdbms::postgres::Tstzbound::Unbounded)
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS tstzbound",
+                        "WIT tstzbound",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::postgres::Datebound {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::rdbms::postgres::Datebound::Included(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::types::Date,
+                >(ctx, "included", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::Datebound::Excluded(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::types::Date,
+                >(ctx, "excluded", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::Datebound::Unbounded => {
+                crate::wrappers::variant_case(ctx, "unbounded")
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::postgres::Datebound {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "included" => {
+                let inner: crate::bindings::golem::rdbms::types::Date = crate::wrappers::variant_value(
+                    &obj,
+                    "date",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::Datebound::Included(inner))
+            }
+            "excluded" => {
+                let inner: crate::bindings::golem::rdbms::types::Date = crate::wrappers::variant_value(
+                    &obj,
+                    "date",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::Datebound::Excluded(inner))
+            }
+            "unbounded" => {
+                Ok(crate::bindings::golem::rdbms::postgres::Datebound::Unbounded)
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS datebound",
+                        "WIT datebound",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::postgres::Int4range {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::golem::rdbms::postgres::Int4bound,
+            >("start", self.start)?;
+        obj.set::<
+                _,
+                crate::bindings::golem::rdbms::postgres::Int4bound,
+            >("end", self.end)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::postgres::Int4range {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT int4range",
+            &["start", "end"],
+            &[("start", "int4bound"), ("end", "int4bound")],
+            false,
+        )?;
+        let start: crate::bindings::golem::rdbms::postgres::Int4bound = crate::wrappers::get_field(
+            &obj,
+            "start",
+            "int4bound",
+        )?;
+        let start: crate::bindings::golem::rdbms::postgres::Int4bound = start;
+        let end: crate::bindings::golem::rdbms::postgres::Int4bound = crate::wrappers::get_field(
+            &obj,
+            "end",
+            "int4bound",
+        )?;
+        let end: crate::bindings::golem::rdbms::postgres::Int4bound = end;
+        Ok(Self { start, end })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::postgres::Int8range {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::golem::rdbms::postgres::Int8bound,
+            >("start", self.start)?;
+        obj.set::<
+                _,
+                crate::bindings::golem::rdbms::postgres::Int8bound,
+            >("end", self.end)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::postgres::Int8range {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT int8range",
+            &["start", "end"],
+            &[("start", "int8bound"), ("end", "int8bound")],
+            false,
+        )?;
+        let start: crate::bindings::golem::rdbms::postgres::Int8bound = crate::wrappers::get_field(
+            &obj,
+            "start",
+            "int8bound",
+        )?;
+        let start: crate::bindings::golem::rdbms::postgres::Int8bound = start;
+        let end: crate::bindings::golem::rdbms::postgres::Int8bound = crate::wrappers::get_field(
+            &obj,
+            "end",
+            "int8bound",
+        )?;
+        let end: crate::bindings::golem::rdbms::postgres::Int8bound = end;
+        Ok(Self { start, end })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::postgres::Numrange {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::golem::rdbms::postgres::Numbound,
+            >("start", self.start)?;
+        obj.set::<
+                _,
+                crate::bindings::golem::rdbms::postgres::Numbound,
+            >("end", self.end)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::postgres::Numrange {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT numrange",
+            &["start", "end"],
+            &[("start", "numbound"), ("end", "numbound")],
+            false,
+        )?;
+        let start: crate::bindings::golem::rdbms::postgres::Numbound = crate::wrappers::get_field(
+            &obj,
+            "start",
+            "numbound",
+        )?;
+        let start: crate::bindings::golem::rdbms::postgres::Numbound = start;
+        let end: crate::bindings::golem::rdbms::postgres::Numbound = crate::wrappers::get_field(
+            &obj,
+            "end",
+            "numbound",
+        )?;
+        let end: crate::bindings::golem::rdbms::postgres::Numbound = end;
+        Ok(Self { start, end })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::postgres::Tsrange {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::golem::rdbms::postgres::Tsbound,
+            >("start", self.start)?;
+        obj.set::<_, crate::bindings::golem::rdbms::postgres::Tsbound>("end", self.end)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::postgres::Tsrange {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT tsrange",
+            &["start", "end"],
+            &[("start", "tsbound"), ("end", "tsbound")],
+            false,
+        )?;
+        let start: crate::bindings::golem::rdbms::postgres::Tsbound = crate::wrappers::get_field(
+            &obj,
+            "start",
+            "tsbound",
+        )?;
+        let start: crate::bindings::golem::rdbms::postgres::Tsbound = start;
+        let end: crate::bindings::golem::rdbms::postgres::Tsbound = crate::wrappers::get_field(
+            &obj,
+            "end",
+            "tsbound",
+        )?;
+        let end: crate::bindings::golem::rdbms::postgres::Tsbound = end;
+        Ok(Self { start, end })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::postgres::Tstzrange {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::golem::rdbms::postgres::Tstzbound,
+            >("start", self.start)?;
+        obj.set::<
+                _,
+                crate::bindings::golem::rdbms::postgres::Tstzbound,
+            >("end", self.end)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::postgres::Tstzrange {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT tstzrange",
+            &["start", "end"],
+            &[("start", "tstzbound"), ("end", "tstzbound")],
+            false,
+        )?;
+        let start: crate::bindings::golem::rdbms::postgres::Tstzbound = crate::wrappers::get_field(
+            &obj,
+            "start",
+            "tstzbound",
+        )?;
+        let start: crate::bindings::golem::rdbms::postgres::Tstzbound = start;
+        let end: crate::bindings::golem::rdbms::postgres::Tstzbound = crate::wrappers::get_field(
+            &obj,
+            "end",
+            "tstzbound",
+        )?;
+        let end: crate::bindings::golem::rdbms::postgres::Tstzbound = end;
+        Ok(Self { start, end })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::postgres::Daterange {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::golem::rdbms::postgres::Datebound,
+            >("start", self.start)?;
+        obj.set::<
+                _,
+                crate::bindings::golem::rdbms::postgres::Datebound,
+            >("end", self.end)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::postgres::Daterange {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT daterange",
+            &["start", "end"],
+            &[("start", "datebound"), ("end", "datebound")],
+            false,
+        )?;
+        let start: crate::bindings::golem::rdbms::postgres::Datebound = crate::wrappers::get_field(
+            &obj,
+            "start",
+            "datebound",
+        )?;
+        let start: crate::bindings::golem::rdbms::postgres::Datebound = start;
+        let end: crate::bindings::golem::rdbms::postgres::Datebound = crate::wrappers::get_field(
+            &obj,
+            "end",
+            "datebound",
+        )?;
+        let end: crate::bindings::golem::rdbms::postgres::Datebound = end;
+        Ok(Self { start, end })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::rdbms::postgres::EnumerationType {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("name", crate::wrappers::JsString(self.name))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::rdbms::postgres::EnumerationType {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT enumeration-type",
+            &["name"],
+            &[("name", "string")],
+            false,
+        )?;
+        let name: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "name",
+            "string",
+        )?;
+        let name: String = name.0;
+        Ok(Self { name })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::rdbms::postgres::Enumeration {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("name", crate::wrappers::JsString(self.name))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("value", crate::wrappers::JsString(self.value))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::rdbms::postgres::Enumeration {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT enumeration",
+            &["name", "value"],
+            &[("name", "string"), ("value", "string")],
+            false,
+        )?;
+        let name: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "name",
+            "string",
+        )?;
+        let name: String = name.0;
+        let value: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "value",
+            "string",
+        )?;
+        let value: String = value.0;
+        Ok(Self { name, value })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::postgres::Composite {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("name", crate::wrappers::JsString(self.name))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<
+                    crate::bindings::golem::rdbms::postgres::LazyDbValue,
+                >,
+            >(
+            "values",
+            crate::wrappers::JsList(
+                self.values.into_iter().map(|v| v).collect::<Vec<_>>(),
+            ),
+        )?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::postgres::Composite {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT composite",
+            &["name", "values"],
+            &[("name", "string"), ("values", "list<lazy-db-value>")],
+            false,
+        )?;
+        let name: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "name",
+            "string",
+        )?;
+        let name: String = name.0;
+        let values: crate::wrappers::JsList<
+            crate::bindings::golem::rdbms::postgres::LazyDbValue,
+        > = crate::wrappers::get_field(&obj, "values", "list<lazy-db-value>")?;
+        let values: Vec<crate::bindings::golem::rdbms::postgres::LazyDbValue> = values
+            .0
+            .into_iter()
+            .map(|v| v)
+            .collect::<Vec<_>>();
+        Ok(Self { name, values })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::postgres::Domain {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("name", crate::wrappers::JsString(self.name))?;
+        obj.set::<
+                _,
+                crate::bindings::golem::rdbms::postgres::LazyDbValue,
+            >("value", self.value)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::postgres::Domain {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT domain",
+            &["name", "value"],
+            &[("name", "string"), ("value", "lazy-db-value")],
+            false,
+        )?;
+        let name: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "name",
+            "string",
+        )?;
+        let name: String = name.0;
+        let value: crate::bindings::golem::rdbms::postgres::LazyDbValue = crate::wrappers::get_field(
+            &obj,
+            "value",
+            "lazy-db-value",
+        )?;
+        let value: crate::bindings::golem::rdbms::postgres::LazyDbValue = value;
+        Ok(Self { name, value })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::postgres::ValueBound {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::rdbms::postgres::ValueBound::Included(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::postgres::LazyDbValue,
+                >(ctx, "included", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::ValueBound::Excluded(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::postgres::LazyDbValue,
+                >(ctx, "excluded", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::ValueBound::Unbounded => {
+                crate::wrappers::variant_case(ctx, "unbounded")
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::postgres::ValueBound {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "included" => {
+                let inner: crate::bindings::golem::rdbms::postgres::LazyDbValue = crate::wrappers::variant_value(
+                    &obj,
+                    "lazy-db-value",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::ValueBound::Included(inner))
+            }
+            "excluded" => {
+                let inner: crate::bindings::golem::rdbms::postgres::LazyDbValue = crate::wrappers::variant_value(
+                    &obj,
+                    "lazy-db-value",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::ValueBound::Excluded(inner))
+            }
+            "unbounded" => {
+                Ok(crate::bindings::golem::rdbms::postgres::ValueBound::Unbounded)
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS value-bound",
+                        "WIT value-bound",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::rdbms::postgres::ValuesRange {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::golem::rdbms::postgres::ValueBound,
+            >("start", self.start)?;
+        obj.set::<
+                _,
+                crate::bindings::golem::rdbms::postgres::ValueBound,
+            >("end", self.end)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::rdbms::postgres::ValuesRange {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT values-range",
+            &["start", "end"],
+            &[("start", "value-bound"), ("end", "value-bound")],
+            false,
+        )?;
+        let start: crate::bindings::golem::rdbms::postgres::ValueBound = crate::wrappers::get_field(
+            &obj,
+            "start",
+            "value-bound",
+        )?;
+        let start: crate::bindings::golem::rdbms::postgres::ValueBound = start;
+        let end: crate::bindings::golem::rdbms::postgres::ValueBound = crate::wrappers::get_field(
+            &obj,
+            "end",
+            "value-bound",
+        )?;
+        let end: crate::bindings::golem::rdbms::postgres::ValueBound = end;
+        Ok(Self { start, end })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::postgres::Range {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("name", crate::wrappers::JsString(self.name))?;
+        obj.set::<
+                _,
+                crate::bindings::golem::rdbms::postgres::ValuesRange,
+            >("value", self.value)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::postgres::Range {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT range",
+            &["name", "value"],
+            &[("name", "string"), ("value", "values-range")],
+            false,
+        )?;
+        let name: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "name",
+            "string",
+        )?;
+        let name: String = name.0;
+        let value: crate::bindings::golem::rdbms::postgres::ValuesRange = crate::wrappers::get_field(
+            &obj,
+            "value",
+            "values-range",
+        )?;
+        let value: crate::bindings::golem::rdbms::postgres::ValuesRange = value;
+        Ok(Self { name, value })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::postgres::DbValue {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::rdbms::postgres::DbValue::Character(inner) => {
+                crate::wrappers::variant_case_with_value::<i8>(ctx, "character", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Int2(inner) => {
+                crate::wrappers::variant_case_with_value::<i16>(ctx, "int2", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Int4(inner) => {
+                crate::wrappers::variant_case_with_value::<i32>(ctx, "int4", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Int8(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::BigIntWrapper<i64>,
+                >(ctx, "int8", crate::wrappers::BigIntWrapper(inner))
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Float4(inner) => {
+                crate::wrappers::variant_case_with_value::<f32>(ctx, "float4", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Float8(inner) => {
+                crate::wrappers::variant_case_with_value::<f64>(ctx, "float8", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Numeric(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "numeric", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Boolean(inner) => {
+                crate::wrappers::variant_case_with_value::<bool>(ctx, "boolean", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Text(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "text", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Varchar(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "varchar", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Bpchar(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "bpchar", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Timestamp(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::types::Timestamp,
+                >(ctx, "timestamp", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Timestamptz(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::types::Timestamptz,
+                >(ctx, "timestamptz", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Date(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::types::Date,
+                >(ctx, "date", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Time(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::types::Time,
+                >(ctx, "time", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Timetz(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::types::Timetz,
+                >(ctx, "timetz", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Interval(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::postgres::Interval,
+                >(ctx, "interval", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Bytea(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::UInt8Array,
+                >(ctx, "bytea", crate::wrappers::UInt8Array(inner))
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Json(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "json", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Jsonb(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "jsonb", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Jsonpath(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "jsonpath", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Xml(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "xml", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Uuid(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::types::Uuid,
+                >(ctx, "uuid", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Inet(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::types::IpAddress,
+                >(ctx, "inet", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Cidr(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::types::IpAddress,
+                >(ctx, "cidr", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Macaddr(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::types::MacAddress,
+                >(ctx, "macaddr", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Bit(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsList<bool>,
+                >(
+                    ctx,
+                    "bit",
+                    crate::wrappers::JsList(
+                        inner.into_iter().map(|v| v).collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Varbit(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsList<bool>,
+                >(
+                    ctx,
+                    "varbit",
+                    crate::wrappers::JsList(
+                        inner.into_iter().map(|v| v).collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Int4range(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::postgres::Int4range,
+                >(ctx, "int4range", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Int8range(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::postgres::Int8range,
+                >(ctx, "int8range", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Numrange(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::postgres::Numrange,
+                >(ctx, "numrange", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Tsrange(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::postgres::Tsrange,
+                >(ctx, "tsrange", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Tstzrange(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::postgres::Tstzrange,
+                >(ctx, "tstzrange", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Daterange(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::postgres::Daterange,
+                >(ctx, "daterange", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Money(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::BigIntWrapper<i64>,
+                >(ctx, "money", crate::wrappers::BigIntWrapper(inner))
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Oid(inner) => {
+                crate::wrappers::variant_case_with_value::<u32>(ctx, "oid", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Enumeration(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::postgres::Enumeration,
+                >(ctx, "enumeration", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Composite(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::postgres::Composite,
+                >(ctx, "composite", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Domain(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::postgres::Domain,
+                >(ctx, "domain", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Array(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsList<
+                        crate::bindings::golem::rdbms::postgres::LazyDbValue,
+                    >,
+                >(
+                    ctx,
+                    "array",
+                    crate::wrappers::JsList(
+                        inner.into_iter().map(|v| v).collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Range(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::postgres::Range,
+                >(ctx, "range", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Null => {
+                crate::wrappers::variant_case(ctx, "null")
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::postgres::DbValue {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "character" => {
+                let inner: i8 = crate::wrappers::variant_value(&obj, "s8")?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Character(inner))
+            }
+            "int2" => {
+                let inner: i16 = crate::wrappers::variant_value(&obj, "s16")?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Int2(inner))
+            }
+            "int4" => {
+                let inner: i32 = crate::wrappers::variant_value(&obj, "s32")?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Int4(inner))
+            }
+            "int8" => {
+                let inner: crate::wrappers::BigIntWrapper<i64> = crate::wrappers::variant_value(
+                    &obj,
+                    "s64",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Int8(inner.0))
+            }
+            "float4" => {
+                let inner: f32 = crate::wrappers::variant_value(&obj, "f32")?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Float4(inner))
+            }
+            "float8" => {
+                let inner: f64 = crate::wrappers::variant_value(&obj, "f64")?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Float8(inner))
+            }
+            "numeric" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Numeric(inner.0))
+            }
+            "boolean" => {
+                let inner: bool = crate::wrappers::variant_value(&obj, "bool")?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Boolean(inner))
+            }
+            "text" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Text(inner.0))
+            }
+            "varchar" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Varchar(inner.0))
+            }
+            "bpchar" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Bpchar(inner.0))
+            }
+            "timestamp" => {
+                let inner: crate::bindings::golem::rdbms::types::Timestamp = crate::wrappers::variant_value(
+                    &obj,
+                    "timestamp",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Timestamp(inner))
+            }
+            "timestamptz" => {
+                let inner: crate::bindings::golem::rdbms::types::Timestamptz = crate::wrappers::variant_value(
+                    &obj,
+                    "timestamptz",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Timestamptz(inner))
+            }
+            "date" => {
+                let inner: crate::bindings::golem::rdbms::types::Date = crate::wrappers::variant_value(
+                    &obj,
+                    "date",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Date(inner))
+            }
+            "time" => {
+                let inner: crate::bindings::golem::rdbms::types::Time = crate::wrappers::variant_value(
+                    &obj,
+                    "time",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Time(inner))
+            }
+            "timetz" => {
+                let inner: crate::bindings::golem::rdbms::types::Timetz = crate::wrappers::variant_value(
+                    &obj,
+                    "timetz",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Timetz(inner))
+            }
+            "interval" => {
+                let inner: crate::bindings::golem::rdbms::postgres::Interval = crate::wrappers::variant_value(
+                    &obj,
+                    "interval",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Interval(inner))
+            }
+            "bytea" => {
+                let inner: crate::wrappers::UInt8Array = crate::wrappers::variant_value(
+                    &obj,
+                    "list<u8>",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Bytea(inner.0))
+            }
+            "json" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Json(inner.0))
+            }
+            "jsonb" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Jsonb(inner.0))
+            }
+            "jsonpath" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Jsonpath(inner.0))
+            }
+            "xml" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Xml(inner.0))
+            }
+            "uuid" => {
+                let inner: crate::bindings::golem::rdbms::types::Uuid = crate::wrappers::variant_value(
+                    &obj,
+                    "uuid",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Uuid(inner))
+            }
+            "inet" => {
+                let inner: crate::bindings::golem::rdbms::types::IpAddress = crate::wrappers::variant_value(
+                    &obj,
+                    "ip-address",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Inet(inner))
+            }
+            "cidr" => {
+                let inner: crate::bindings::golem::rdbms::types::IpAddress = crate::wrappers::variant_value(
+                    &obj,
+                    "ip-address",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Cidr(inner))
+            }
+            "macaddr" => {
+                let inner: crate::bindings::golem::rdbms::types::MacAddress = crate::wrappers::variant_value(
+                    &obj,
+                    "mac-address",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Macaddr(inner))
+            }
+            "bit" => {
+                let inner: crate::wrappers::JsList<bool> = crate::wrappers::variant_value(
+                    &obj,
+                    "list<bool>",
+                )?;
+                Ok(
+                    crate::bindings::golem::rdbms::postgres::DbValue::Bit(
+                        inner.0.into_iter().map(|v| v).collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            "varbit" => {
+                let inner: crate::wrappers::JsList<bool> = crate::wrappers::variant_value(
+                    &obj,
+                    "list<bool>",
+                )?;
+                Ok(
+                    crate::bindings::golem::rdbms::postgres::DbValue::Varbit(
+                        inner.0.into_iter().map(|v| v).collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            "int4range" => {
+                let inner: crate::bindings::golem::rdbms::postgres::Int4range = crate::wrappers::variant_value(
+                    &obj,
+                    "int4range",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Int4range(inner))
+            }
+            "int8range" => {
+                let inner: crate::bindings::golem::rdbms::postgres::Int8range = crate::wrappers::variant_value(
+                    &obj,
+                    "int8range",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Int8range(inner))
+            }
+            "numrange" => {
+                let inner: crate::bindings::golem::rdbms::postgres::Numrange = crate::wrappers::variant_value(
+                    &obj,
+                    "numrange",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Numrange(inner))
+            }
+            "tsrange" => {
+                let inner: crate::bindings::golem::rdbms::postgres::Tsrange = crate::wrappers::variant_value(
+                    &obj,
+                    "tsrange",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Tsrange(inner))
+            }
+            "tstzrange" => {
+                let inner: crate::bindings::golem::rdbms::postgres::Tstzrange = crate::wrappers::variant_value(
+                    &obj,
+                    "tstzrange",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Tstzrange(inner))
+            }
+            "daterange" => {
+                let inner: crate::bindings::golem::rdbms::postgres::Daterange = crate::wrappers::variant_value(
+                    &obj,
+                    "daterange",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Daterange(inner))
+            }
+            "money" => {
+                let inner: crate::wrappers::BigIntWrapper<i64> = crate::wrappers::variant_value(
+                    &obj,
+                    "s64",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Money(inner.0))
+            }
+            "oid" => {
+                let inner: u32 = crate::wrappers::variant_value(&obj, "u32")?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Oid(inner))
+            }
+            "enumeration" => {
+                let inner: crate::bindings::golem::rdbms::postgres::Enumeration = crate::wrappers::variant_value(
+                    &obj,
+                    "enumeration",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Enumeration(inner))
+            }
+            "composite" => {
+                let inner: crate::bindings::golem::rdbms::postgres::Composite = crate::wrappers::variant_value(
+                    &obj,
+                    "composite",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Composite(inner))
+            }
+            "domain" => {
+                let inner: crate::bindings::golem::rdbms::postgres::Domain = crate::wrappers::variant_value(
+                    &obj,
+                    "domain",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Domain(inner))
+            }
+            "array" => {
+                let inner: crate::wrappers::JsList<
+                    crate::bindings::golem::rdbms::postgres::LazyDbValue,
+                > = crate::wrappers::variant_value(&obj, "list<lazy-db-value>")?;
+                Ok(
+                    crate::bindings::golem::rdbms::postgres::DbValue::Array(
+                        inner.0.into_iter().map(|v| v).collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            "range" => {
+                let inner: crate::bindings::golem::rdbms::postgres::Range = crate::wrappers::variant_value(
+                    &obj,
+                    "range",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Range(inner))
+            }
+            "null" => Ok(crate::bindings::golem::rdbms::postgres::DbValue::Null),
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS db-value",
+                        "WIT db-value",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::rdbms::postgres::CompositeType {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("name", crate::wrappers::JsString(self.name))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<
+                    rquickjs::convert::List<
+                        (
+                            crate::wrappers::JsString,
+                            crate::bindings::golem::rdbms::postgres::LazyDbColumnType,
+                        ),
+                    >,
+                >,
+            >(
+            "attributes",
+            crate::wrappers::JsList(
+                self
+                    .attributes
+                    .into_iter()
+                    .map(|v| rquickjs::convert::List((
+                        crate::wrappers::JsString(v.0),
+                        v.1,
+                    )))
+                    .collect::<Vec<_>>(),
+            ),
+        )?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::rdbms::postgres::CompositeType {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT composite-type",
+            &["name", "attributes"],
+            &[
+                ("name", "string"),
+                ("attributes", "list<tuple<string, lazy-db-column-type>>"),
+            ],
+            false,
+        )?;
+        let name: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "name",
+            "string",
+        )?;
+        let name: String = name.0;
+        let attributes: crate::wrappers::JsList<
+            rquickjs::convert::List<
+                (
+                    crate::wrappers::JsString,
+                    crate::bindings::golem::rdbms::postgres::LazyDbColumnType,
+                ),
+            >,
+        > = crate::wrappers::get_field(
+            &obj,
+            "attributes",
+            "list<tuple<string, lazy-db-column-type>>",
+        )?;
+        let attributes: Vec<
+            (String, crate::bindings::golem::rdbms::postgres::LazyDbColumnType),
+        > = attributes
+            .0
+            .into_iter()
+            .map(|v| (v.0.0.0.clone(), v.0.1))
+            .collect::<Vec<_>>();
+        Ok(Self { name, attributes })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::postgres::DomainType {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("name", crate::wrappers::JsString(self.name))?;
+        obj.set::<
+                _,
+                crate::bindings::golem::rdbms::postgres::LazyDbColumnType,
+            >("baseType", self.base_type)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::postgres::DomainType {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT domain-type",
+            &["name", "baseType"],
+            &[("name", "string"), ("baseType", "lazy-db-column-type")],
+            false,
+        )?;
+        let name: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "name",
+            "string",
+        )?;
+        let name: String = name.0;
+        let base_type: crate::bindings::golem::rdbms::postgres::LazyDbColumnType = crate::wrappers::get_field(
+            &obj,
+            "baseType",
+            "lazy-db-column-type",
+        )?;
+        let base_type: crate::bindings::golem::rdbms::postgres::LazyDbColumnType = base_type;
+        Ok(Self { name, base_type })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::postgres::RangeType {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("name", crate::wrappers::JsString(self.name))?;
+        obj.set::<
+                _,
+                crate::bindings::golem::rdbms::postgres::LazyDbColumnType,
+            >("baseType", self.base_type)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::postgres::RangeType {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT range-type",
+            &["name", "baseType"],
+            &[("name", "string"), ("baseType", "lazy-db-column-type")],
+            false,
+        )?;
+        let name: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "name",
+            "string",
+        )?;
+        let name: String = name.0;
+        let base_type: crate::bindings::golem::rdbms::postgres::LazyDbColumnType = crate::wrappers::get_field(
+            &obj,
+            "baseType",
+            "lazy-db-column-type",
+        )?;
+        let base_type: crate::bindings::golem::rdbms::postgres::LazyDbColumnType = base_type;
+        Ok(Self { name, base_type })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::rdbms::postgres::DbColumnType {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Character => {
+                crate::wrappers::variant_case(ctx, "character")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Int2 => {
+                crate::wrappers::variant_case(ctx, "int2")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Int4 => {
+                crate::wrappers::variant_case(ctx, "int4")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Int8 => {
+                crate::wrappers::variant_case(ctx, "int8")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Float4 => {
+                crate::wrappers::variant_case(ctx, "float4")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Float8 => {
+                crate::wrappers::variant_case(ctx, "float8")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Numeric => {
+                crate::wrappers::variant_case(ctx, "numeric")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Boolean => {
+                crate::wrappers::variant_case(ctx, "boolean")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Text => {
+                crate::wrappers::variant_case(ctx, "text")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Varchar => {
+                crate::wrappers::variant_case(ctx, "varchar")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Bpchar => {
+                crate::wrappers::variant_case(ctx, "bpchar")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Timestamp => {
+                crate::wrappers::variant_case(ctx, "timestamp")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Timestamptz => {
+                crate::wrappers::variant_case(ctx, "timestamptz")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Date => {
+                crate::wrappers::variant_case(ctx, "date")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Time => {
+                crate::wrappers::variant_case(ctx, "time")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Timetz => {
+                crate::wrappers::variant_case(ctx, "timetz")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Interval => {
+                crate::wrappers::variant_case(ctx, "interval")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Bytea => {
+                crate::wrappers::variant_case(ctx, "bytea")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Uuid => {
+                crate::wrappers::variant_case(ctx, "uuid")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Xml => {
+                crate::wrappers::variant_case(ctx, "xml")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Json => {
+                crate::wrappers::variant_case(ctx, "json")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Jsonb => {
+                crate::wrappers::variant_case(ctx, "jsonb")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Jsonpath => {
+                crate::wrappers::variant_case(ctx, "jsonpath")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Inet => {
+                crate::wrappers::variant_case(ctx, "inet")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Cidr => {
+                crate::wrappers::variant_case(ctx, "cidr")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Macaddr => {
+                crate::wrappers::variant_case(ctx, "macaddr")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Bit => {
+                crate::wrappers::variant_case(ctx, "bit")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Varbit => {
+                crate::wrappers::variant_case(ctx, "varbit")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Int4range => {
+                crate::wrappers::variant_case(ctx, "int4range")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Int8range => {
+                crate::wrappers::variant_case(ctx, "int8range")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Numrange => {
+                crate::wrappers::variant_case(ctx, "numrange")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Tsrange => {
+                crate::wrappers::variant_case(ctx, "tsrange")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Tstzrange => {
+                crate::wrappers::variant_case(ctx, "tstzrange")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Daterange => {
+                crate::wrappers::variant_case(ctx, "daterange")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Money => {
+                crate::wrappers::variant_case(ctx, "money")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Oid => {
+                crate::wrappers::variant_case(ctx, "oid")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Enumeration(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::postgres::EnumerationType,
+                >(ctx, "enumeration", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Composite(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::postgres::CompositeType,
+                >(ctx, "composite", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Domain(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::postgres::DomainType,
+                >(ctx, "domain", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Array(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::postgres::LazyDbColumnType,
+                >(ctx, "array", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Range(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::postgres::RangeType,
+                >(ctx, "range", inner)
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::rdbms::postgres::DbColumnType {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "character" => {
+                Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Character)
+            }
+            "int2" => Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Int2),
+            "int4" => Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Int4),
+            "int8" => Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Int8),
+            "float4" => Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Float4),
+            "float8" => Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Float8),
+            "numeric" => {
+                Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Numeric)
+            }
+            "boolean" => {
+                Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Boolean)
+            }
+            "text" => Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Text),
+            "varchar" => {
+                Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Varchar)
+            }
+            "bpchar" => Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Bpchar),
+            "timestamp" => {
+                Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Timestamp)
+            }
+            "timestamptz" => {
+                Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Timestamptz)
+            }
+            "date" => Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Date),
+            "time" => Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Time),
+            "timetz" => Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Timetz),
+            "interval" => {
+                Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Interval)
+            }
+            "bytea" => Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Bytea),
+            "uuid" => Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Uuid),
+            "xml" => Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Xml),
+            "json" => Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Json),
+            "jsonb" => Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Jsonb),
+            "jsonpath" => {
+                Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Jsonpath)
+            }
+            "inet" => Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Inet),
+            "cidr" => Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Cidr),
+            "macaddr" => {
+                Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Macaddr)
+            }
+            "bit" => Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Bit),
+            "varbit" => Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Varbit),
+            "int4range" => {
+                Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Int4range)
+            }
+            "int8range" => {
+                Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Int8range)
+            }
+            "numrange" => {
+                Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Numrange)
+            }
+            "tsrange" => {
+                Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Tsrange)
+            }
+            "tstzrange" => {
+                Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Tstzrange)
+            }
+            "daterange" => {
+                Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Daterange)
+            }
+            "money" => Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Money),
+            "oid" => Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Oid),
+            "enumeration" => {
+                let inner: crate::bindings::golem::rdbms::postgres::EnumerationType = crate::wrappers::variant_value(
+                    &obj,
+                    "enumeration-type",
+                )?;
+                Ok(
+                    crate::bindings::golem::rdbms::postgres::DbColumnType::Enumeration(
+                        inner,
+                    ),
+                )
+            }
+            "composite" => {
+                let inner: crate::bindings::golem::rdbms::postgres::CompositeType = crate::wrappers::variant_value(
+                    &obj,
+                    "composite-type",
+                )?;
+                Ok(
+                    crate::bindings::golem::rdbms::postgres::DbColumnType::Composite(
+                        inner,
+                    ),
+                )
+            }
+            "domain" => {
+                let inner: crate::bindings::golem::rdbms::postgres::DomainType = crate::wrappers::variant_value(
+                    &obj,
+                    "domain-type",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Domain(inner))
+            }
+            "array" => {
+                let inner: crate::bindings::golem::rdbms::postgres::LazyDbColumnType = crate::wrappers::variant_value(
+                    &obj,
+                    "lazy-db-column-type",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Array(inner))
+            }
+            "range" => {
+                let inner: crate::bindings::golem::rdbms::postgres::RangeType = crate::wrappers::variant_value(
+                    &obj,
+                    "range-type",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Range(inner))
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS db-column-type",
+                        "WIT db-column-type",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::postgres::DbColumn {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::BigIntWrapper<u64>,
+            >("ordinal", crate::wrappers::BigIntWrapper(self.ordinal))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("name", crate::wrappers::JsString(self.name))?;
+        obj.set::<
+                _,
+                crate::bindings::golem::rdbms::postgres::DbColumnType,
+            >("dbType", self.db_type)?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("dbTypeName", crate::wrappers::JsString(self.db_type_name))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::postgres::DbColumn {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT db-column",
+            &["ordinal", "name", "dbType", "dbTypeName"],
+            &[
+                ("ordinal", "u64"),
+                ("name", "string"),
+                ("dbType", "db-column-type"),
+                ("dbTypeName", "string"),
+            ],
+            false,
+        )?;
+        let ordinal: crate::wrappers::BigIntWrapper<u64> = crate::wrappers::get_field(
+            &obj,
+            "ordinal",
+            "u64",
+        )?;
+        let ordinal: u64 = ordinal.0;
+        let name: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "name",
+            "string",
+        )?;
+        let name: String = name.0;
+        let db_type: crate::bindings::golem::rdbms::postgres::DbColumnType = crate::wrappers::get_field(
+            &obj,
+            "dbType",
+            "db-column-type",
+        )?;
+        let db_type: crate::bindings::golem::rdbms::postgres::DbColumnType = db_type;
+        let db_type_name: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "dbTypeName",
+            "string",
+        )?;
+        let db_type_name: String = db_type_name.0;
+        Ok(Self {
+            ordinal,
+            name,
+            db_type,
+            db_type_name,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::postgres::DbRow {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<crate::bindings::golem::rdbms::postgres::DbValue>,
+            >(
+            "values",
+            crate::wrappers::JsList(
+                self.values.into_iter().map(|v| v).collect::<Vec<_>>(),
+            ),
+        )?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::postgres::DbRow {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT db-row",
+            &["values"],
+            &[("values", "list<db-value>")],
+            false,
+        )?;
+        let values: crate::wrappers::JsList<
+            crate::bindings::golem::rdbms::postgres::DbValue,
+        > = crate::wrappers::get_field(&obj, "values", "list<db-value>")?;
+        let values: Vec<crate::bindings::golem::rdbms::postgres::DbValue> = values
+            .0
+            .into_iter()
+            .map(|v| v)
+            .collect::<Vec<_>>();
+        Ok(Self { values })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::postgres::DbResult {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<
+                    crate::bindings::golem::rdbms::postgres::DbColumn,
+                >,
+            >(
+            "columns",
+            crate::wrappers::JsList(
+                self.columns.into_iter().map(|v| v).collect::<Vec<_>>(),
+            ),
+        )?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<crate::bindings::golem::rdbms::postgres::DbRow>,
+            >(
+            "rows",
+            crate::wrappers::JsList(self.rows.into_iter().map(|v| v).collect::<Vec<_>>()),
+        )?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::postgres::DbResult {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT db-result",
+            &["columns", "rows"],
+            &[("columns", "list<db-column>"), ("rows", "list<db-row>")],
+            false,
+        )?;
+        let columns: crate::wrappers::JsList<
+            crate::bindings::golem::rdbms::postgres::DbColumn,
+        > = crate::wrappers::get_field(&obj, "columns", "list<db-column>")?;
+        let columns: Vec<crate::bindings::golem::rdbms::postgres::DbColumn> = columns
+            .0
+            .into_iter()
+            .map(|v| v)
+            .collect::<Vec<_>>();
+        let rows: crate::wrappers::JsList<
+            crate::bindings::golem::rdbms::postgres::DbRow,
+        > = crate::wrappers::get_field(&obj, "rows", "list<db-row>")?;
+        let rows: Vec<crate::bindings::golem::rdbms::postgres::DbRow> = rows
+            .0
+            .into_iter()
+            .map(|v| v)
+            .collect::<Vec<_>>();
+        Ok(Self { columns, rows })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::search::types::SearchError {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::search::types::SearchError::IndexNotFound => {
+                crate::wrappers::variant_case(ctx, "index-not-found")
+            }
+            crate::bindings::golem::search::types::SearchError::InvalidQuery(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "invalid-query", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::search::types::SearchError::Unsupported => {
+                crate::wrappers::variant_case(ctx, "unsupported")
+            }
+            crate::bindings::golem::search::types::SearchError::Internal(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "internal", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::search::types::SearchError::Timeout => {
+                crate::wrappers::variant_case(ctx, "timeout")
+            }
+            crate::bindings::golem::search::types::SearchError::RateLimited => {
+                crate::wrappers::variant_case(ctx, "rate-limited")
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::search::types::SearchError {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "index-not-found" => {
+                Ok(crate::bindings::golem::search::types::SearchError::IndexNotFound)
+            }
+            "invalid-query" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(
+                    crate::bindings::golem::search::types::SearchError::InvalidQuery(
+                        inner.0,
+                    ),
+                )
+            }
+            "unsupported" => {
+                Ok(crate::bindings::golem::search::types::SearchError::Unsupported)
+            }
+            "internal" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(crate::bindings::golem::search::types::SearchError::Internal(inner.0))
+            }
+            "timeout" => Ok(crate::bindings::golem::search::types::SearchError::Timeout),
+            "rate-limited" => {
+                Ok(crate::bindings::golem::search::types::SearchError::RateLimited)
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS search-error",
+                        "WIT search-error",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::search::types::Doc {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("id", crate::wrappers::JsString(self.id))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("content", crate::wrappers::JsString(self.content))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::search::types::Doc {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT doc",
+            &["id", "content"],
+            &[("id", "document-id"), ("content", "json")],
+            false,
+        )?;
+        let id: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "id",
+            "document-id",
+        )?;
+        let id: crate::bindings::golem::search::types::DocumentId = id.0;
+        let content: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "content",
+            "json",
+        )?;
+        let content: crate::bindings::golem::search::types::Json = content.0;
+        Ok(Self { id, content })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::search::types::HighlightConfig {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<crate::wrappers::JsString>,
+            >(
+            "fields",
+            crate::wrappers::JsList(
+                self
+                    .fields
+                    .into_iter()
+                    .map(|v| crate::wrappers::JsString(v))
+                    .collect::<Vec<_>>(),
+            ),
+        )?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >("preTag", self.pre_tag.map(|v| crate::wrappers::JsString(v)))?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >("postTag", self.post_tag.map(|v| crate::wrappers::JsString(v)))?;
+        obj.set::<_, Option<u32>>("maxLength", self.max_length.map(|v| v))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::search::types::HighlightConfig {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT highlight-config",
+            &["fields", "preTag", "postTag", "maxLength"],
+            &[("fields", "list<string>")],
+            false,
+        )?;
+        let fields: crate::wrappers::JsList<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "fields",
+            "list<string>",
+        )?;
+        let fields: Vec<String> = fields.0.into_iter().map(|v| v.0).collect::<Vec<_>>();
+        let pre_tag: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "preTag",
+            "option<string>",
+        )?;
+        let pre_tag: Option<String> = pre_tag.map(|v| v.0);
+        let post_tag: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "postTag",
+            "option<string>",
+        )?;
+        let post_tag: Option<String> = post_tag.map(|v| v.0);
+        let max_length: Option<u32> = crate::wrappers::get_field(
+            &obj,
+            "maxLength",
+            "option<u32>",
+        )?;
+        let max_length: Option<u32> = max_length.map(|v| v);
+        Ok(Self {
+            fields,
+            pre_tag,
+            post_tag,
+            max_length,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::search::types::SearchConfig {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<_, Option<u32>>("timeoutMs", self.timeout_ms.map(|v| v))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<
+                    rquickjs::convert::List<(crate::wrappers::JsString, f32)>,
+                >,
+            >(
+            "boostFields",
+            crate::wrappers::JsList(
+                self
+                    .boost_fields
+                    .into_iter()
+                    .map(|v| rquickjs::convert::List((
+                        crate::wrappers::JsString(v.0),
+                        v.1,
+                    )))
+                    .collect::<Vec<_>>(),
+            ),
+        )?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<crate::wrappers::JsString>,
+            >(
+            "attributesToRetrieve",
+            crate::wrappers::JsList(
+                self
+                    .attributes_to_retrieve
+                    .into_iter()
+                    .map(|v| crate::wrappers::JsString(v))
+                    .collect::<Vec<_>>(),
+            ),
+        )?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >("language", self.language.map(|v| crate::wrappers::JsString(v)))?;
+        obj.set::<_, Option<bool>>("typoTolerance", self.typo_tolerance.map(|v| v))?;
+        obj.set::<_, Option<f32>>("exactMatchBoost", self.exact_match_boost.map(|v| v))?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >(
+            "providerParams",
+            self.provider_params.map(|v| crate::wrappers::JsString(v)),
+        )?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::search::types::SearchConfig {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT search-config",
+            &[
+                "timeoutMs",
+                "boostFields",
+                "attributesToRetrieve",
+                "language",
+                "typoTolerance",
+                "exactMatchBoost",
+                "providerParams",
+            ],
+            &[
+                ("boostFields", "list<tuple<string, f32>>"),
+                ("attributesToRetrieve", "list<string>"),
+            ],
+            false,
+        )?;
+        let timeout_ms: Option<u32> = crate::wrappers::get_field(
+            &obj,
+            "timeoutMs",
+            "option<u32>",
+        )?;
+        let timeout_ms: Option<u32> = timeout_ms.map(|v| v);
+        let boost_fields: crate::wrappers::JsList<
+            rquickjs::convert::List<(crate::wrappers::JsString, f32)>,
+        > = crate::wrappers::get_field(&obj, "boostFields", "list<tuple<string, f32>>")?;
+        let boost_fields: Vec<(String, f32)> = boost_fields
+            .0
+            .into_iter()
+            .map(|v| (v.0.0.0.clone(), v.0.1))
+            .collect::<Vec<_>>();
+        let attributes_to_retrieve: crate::wrappers::JsList<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "attributesToRetrieve",
+            "list<string>",
+        )?;
+        let attributes_to_retrieve: Vec<String> = attributes_to_retrieve
+            .0
+            .into_iter()
+            .map(|v| v.0)
+            .collect::<Vec<_>>();
+        let language: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "language",
+            "option<string>",
+        )?;
+        let language: Option<String> = language.map(|v| v.0);
+        let typo_tolerance: Option<bool> = crate::wrappers::get_field(
+            &obj,
+            "typoTolerance",
+            "option<bool>",
+        )?;
+        let typo_tolerance: Option<bool> = typo_tolerance.map(|v| v);
+        let exact_match_boost: Option<f32> = crate::wrappers::get_field(
+            &obj,
+            "exactMatchBoost",
+            "option<f32>",
+        )?;
+        let exact_match_boost: Option<f32> = exact_match_boost.map(|v| v);
+        let provider_params: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "providerParams",
+            "option<json>",
+        )?;
+        let provider_params: Option<crate::bindings::golem::search::types::Json> = provider_params
+            .map(|v| v.0);
+        Ok(Self {
+            timeout_ms,
+            boost_fields,
+            attributes_to_retrieve,
+            language,
+            typo_tolerance,
+            exact_match_boost,
+            provider_params,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::search::types::SearchQuery {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >("q", self.q.map(|v| crate::wrappers::JsString(v)))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<crate::wrappers::JsString>,
+            >(
+            "filters",
+            crate::wrappers::JsList(
+                self
+                    .filters
+                    .into_iter()
+                    .map(|v| crate::wrappers::JsString(v))
+                    .collect::<Vec<_>>(),
+            ),
+        )?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<crate::wrappers::JsString>,
+            >(
+            "sort",
+            crate::wrappers::JsList(
+                self
+                    .sort
+                    .into_iter()
+                    .map(|v| crate::wrappers::JsString(v))
+                    .collect::<Vec<_>>(),
+            ),
+        )?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<crate::wrappers::JsString>,
+            >(
+            "facets",
+            crate::wrappers::JsList(
+                self
+                    .facets
+                    .into_iter()
+                    .map(|v| crate::wrappers::JsString(v))
+                    .collect::<Vec<_>>(),
+            ),
+        )?;
+        obj.set::<_, Option<u32>>("page", self.page.map(|v| v))?;
+        obj.set::<_, Option<u32>>("perPage", self.per_page.map(|v| v))?;
+        obj.set::<_, Option<u32>>("offset", self.offset.map(|v| v))?;
+        obj.set::<
+                _,
+                Option<crate::bindings::golem::search::types::HighlightConfig>,
+            >("highlight", self.highlight.map(|v| v))?;
+        obj.set::<
+                _,
+                Option<crate::bindings::golem::search::types::SearchConfig>,
+            >("config", self.config.map(|v| v))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::search::types::SearchQuery {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT search-query",
+            &[
+                "q",
+                "filters",
+                "sort",
+                "facets",
+                "page",
+                "perPage",
+                "offset",
+                "highlight",
+                "config",
+            ],
+            &[
+                ("filters", "list<string>"),
+                ("sort", "list<string>"),
+                ("facets", "list<string>"),
+            ],
+            false,
+        )?;
+        let q: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "q",
+            "option<string>",
+        )?;
+        let q: Option<String> = q.map(|v| v.0);
+        let filters: crate::wrappers::JsList<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "filters",
+            "list<string>",
+        )?;
+        let filters: Vec<String> = filters
+            .0
+            .into_iter()
+            .map(|v| v.0)
+            .collect::<Vec<_>>();
+        let sort: crate::wrappers::JsList<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "sort",
+            "list<string>",
+        )?;
+        let sort: Vec<String> = sort.0.into_iter().map(|v| v.0).collect::<Vec<_>>();
+        let facets: crate::wrappers::JsList<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "facets",
+            "list<string>",
+        )?;
+        let facets: Vec<String> = facets.0.into_iter().map(|v| v.0).collect::<Vec<_>>();
+        let page: Option<u32> = crate::wrappers::get_field(&obj, "page", "option<u32>")?;
+        let page: Option<u32> = page.map(|v| v);
+        let per_page: Option<u32> = crate::wrappers::get_field(
+            &obj,
+            "perPage",
+            "option<u32>",
+        )?;
+        let per_page: Option<u32> = per_page.map(|v| v);
+        let offset: Option<u32> = crate::wrappers::get_field(
+            &obj,
+            "offset",
+            "option<u32>",
+        )?;
+        let offset: Option<u32> = offset.map(|v| v);
+        let highlight: Option<crate::bindings::golem::search::types::HighlightConfig> = crate::wrappers::get_field(
+            &obj,
+            "highlight",
+            "option<highlight-config>",
+        )?;
+        let highlight: Option<crate::bindings::golem::search::types::HighlightConfig> = highlight
+            .map(|v| v);
+        let config: Option<crate::bindings::golem::search::types::SearchConfig> = crate::wrappers::get_field(
+            &obj,
+            "config",
+            "option<search-config>",
+        )?;
+        let config: Option<crate::bindings::golem::search::types::SearchConfig> = config
+            .map(|v| v);
+        Ok(Self {
+            q,
+            filters,
+            sort,
+            facets,
+            page,
+            per_page,
+            offset,
+            highlight,
+            config,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::search::types::SearchHit {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("id", crate::wrappers::JsString(self.id))?;
+        obj.set::<_, Option<f64>>("score", self.score.map(|v| v))?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >("content", self.content.map(|v| crate::wrappers::JsString(v)))?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >("highlights", self.highlights.map(|v| crate::wrappers::JsString(v)))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::search::types::SearchHit {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT search-hit",
+            &["id", "score", "content", "highlights"],
+            &[("id", "document-id")],
+            false,
+        )?;
+        let id: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "id",
+            "document-id",
+        )?;
+        let id: crate::bindings::golem::search::types::DocumentId = id.0;
+        let score: Option<f64> = crate::wrappers::get_field(
+            &obj,
+            "score",
+            "option<f64>",
+        )?;
+        let score: Option<f64> = score.map(|v| v);
+        let content: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "content",
+            "option<json>",
+        )?;
+        let content: Option<crate::bindings::golem::search::types::Json> = content
+            .map(|v| v.0);
+        let highlights: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "highlights",
+            "option<json>",
+        )?;
+        let highlights: Option<crate::bindings::golem::search::types::Json> = highlights
+            .map(|v| v.0);
+        Ok(Self {
+            id,
+            score,
+            content,
+            highlights,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::search::types::SearchResults {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<_, Option<u32>>("total", self.total.map(|v| v))?;
+        obj.set::<_, Option<u32>>("page", self.page.map(|v| v))?;
+        obj.set::<_, Option<u32>>("perPage", self.per_page.map(|v| v))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<crate::bindings::golem::search::types::SearchHit>,
+            >(
+            "hits",
+            crate::wrappers::JsList(self.hits.into_iter().map(|v| v).collect::<Vec<_>>()),
+        )?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >("facets", self.facets.map(|v| crate::wrappers::JsString(v)))?;
+        obj.set::<_, Option<u32>>("tookMs", self.took_ms.map(|v| v))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::search::types::SearchResults {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT search-results",
+            &["total", "page", "perPage", "hits", "facets", "tookMs"],
+            &[("hits", "list<search-hit>")],
+            false,
+        )?;
+        let total: Option<u32> = crate::wrappers::get_field(
+            &obj,
+            "total",
+            "option<u32>",
+        )?;
+        let total: Option<u32> = total.map(|v| v);
+        let page: Option<u32> = crate::wrappers::get_field(&obj, "page", "option<u32>")?;
+        let page: Option<u32> = page.map(|v| v);
+        let per_page: Option<u32> = crate::wrappers::get_field(
+            &obj,
+            "perPage",
+            "option<u32>",
+        )?;
+        let per_page: Option<u32> = per_page.map(|v| v);
+        let hits: crate::wrappers::JsList<
+            crate::bindings::golem::search::types::SearchHit,
+        > = crate::wrappers::get_field(&obj, "hits", "list<search-hit>")?;
+        let hits: Vec<crate::bindings::golem::search::types::SearchHit> = hits
+            .0
+            .into_iter()
+            .map(|v| v)
+            .collect::<Vec<_>>();
+        let facets: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "facets",
+            "option<json>",
+        )?;
+        let facets: Option<crate::bindings::golem::search::types::Json> = facets
+            .map(|v| v.0);
+        let took_ms: Option<u32> = crate::wrappers::get_field(
+            &obj,
+            "tookMs",
+            "option<u32>",
+        )?;
+        let took_ms: Option<u32> = took_ms.map(|v| v);
+        Ok(Self {
+            total,
+            page,
+            per_page,
+            hits,
+            facets,
+            took_ms,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::search::types::FieldType {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::search::types::FieldType::Text => "text".into_js(ctx),
+            crate::bindings::golem::search::types::FieldType::Keyword => {
+                "keyword".into_js(ctx)
+            }
+            crate::bindings::golem::search::types::FieldType::Integer => {
+                "integer".into_js(ctx)
+            }
+            crate::bindings::golem::search::types::FieldType::Float => {
+                "float".into_js(ctx)
+            }
+            crate::bindings::golem::search::types::FieldType::Boolean => {
+                "boolean".into_js(ctx)
+            }
+            crate::bindings::golem::search::types::FieldType::Date => "date".into_js(ctx),
+            crate::bindings::golem::search::types::FieldType::GeoPoint => {
+                "geo-point".into_js(ctx)
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::search::types::FieldType {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let value = crate::wrappers::enum_case(
+            value,
+            "JS field-type",
+            "WIT field-type",
+        )?;
+        match value.as_str() {
+            "text" => Ok(crate::bindings::golem::search::types::FieldType::Text),
+            "keyword" => Ok(crate::bindings::golem::search::types::FieldType::Keyword),
+            "integer" => Ok(crate::bindings::golem::search::types::FieldType::Integer),
+            "float" => Ok(crate::bindings::golem::search::types::FieldType::Float),
+            "boolean" => Ok(crate::bindings::golem::search::types::FieldType::Boolean),
+            "date" => Ok(crate::bindings::golem::search::types::FieldType::Date),
+            "geo-point" => Ok(crate::bindings::golem::search::types::FieldType::GeoPoint),
+            _ => {
+                Err(
+                    crate::wrappers::unknown_enum_case(
+                        "JS field-type",
+                        "WIT field-type",
+                        &value,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::search::types::SchemaField {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("name", crate::wrappers::JsString(self.name))?;
+        obj.set::<
+                _,
+                crate::bindings::golem::search::types::FieldType,
+            >("fieldType", self.field_type)?;
+        obj.set::<_, bool>("required", self.required)?;
+        obj.set::<_, bool>("facet", self.facet)?;
+        obj.set::<_, bool>("sort", self.sort)?;
+        obj.set::<_, bool>("index", self.index)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::search::types::SchemaField {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT schema-field",
+            &["name", "fieldType", "required", "facet", "sort", "index"],
+            &[
+                ("name", "string"),
+                ("fieldType", "field-type"),
+                ("required", "bool"),
+                ("facet", "bool"),
+                ("sort", "bool"),
+                ("index", "bool"),
+            ],
+            false,
+        )?;
+        let name: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "name",
+            "string",
+        )?;
+        let name: String = name.0;
+        let field_type: crate::bindings::golem::search::types::FieldType = crate::wrappers::get_field(
+            &obj,
+            "fieldType",
+            "field-type",
+        )?;
+        let field_type: crate::bindings::golem::search::types::FieldType = field_type;
+        let required: bool = crate::wrappers::get_field(&obj, "required", "bool")?;
+        let required: bool = required;
+        let facet: bool = crate::wrappers::get_field(&obj, "facet", "bool")?;
+        let facet: bool = facet;
+        let sort: bool = crate::wrappers::get_field(&obj, "sort", "bool")?;
+        let sort: bool = sort;
+        let index: bool = crate::wrappers::get_field(&obj, "index", "bool")?;
+        let index: bool = index;
+        Ok(Self {
+            name,
+            field_type,
+            required,
+            facet,
+            sort,
+            index,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::search::types::Schema {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<
+                    crate::bindings::golem::search::types::SchemaField,
+                >,
+            >(
+            "fields",
+            crate::wrappers::JsList(
+                self.fields.into_iter().map(|v| v).collect::<Vec<_>>(),
+            ),
+        )?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >("primaryKey", self.primary_key.map(|v| crate::wrappers::JsString(v)))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::search::types::Schema {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT schema",
+            &["fields", "primaryKey"],
+            &[("fields", "list<schema-field>")],
+            false,
+        )?;
+        let fields: crate::wrappers::JsList<
+            crate::bindings::golem::search::types::SchemaField,
+        > = crate::wrappers::get_field(&obj, "fields", "list<schema-field>")?;
+        let fields: Vec<crate::bindings::golem::search::types::SchemaField> = fields
+            .0
+            .into_iter()
+            .map(|v| v)
+            .collect::<Vec<_>>();
+        let primary_key: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "primaryKey",
+            "option<string>",
+        )?;
+        let primary_key: Option<String> = primary_key.map(|v| v.0);
+        Ok(Self { fields, primary_key })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::stt::types::SttError {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::stt::types::SttError::InvalidAudio(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "invalid-audio", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::stt::types::SttError::UnsupportedFormat(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "unsupported-format", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::stt::types::SttError::UnsupportedLanguage(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "unsupported-language", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::stt::types::SttError::TranscriptionFailed(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "transcription-failed", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::stt::types::SttError::Unauthorized(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "unauthorized", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::stt::types::SttError::AccessDenied(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "access-denied", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::stt::types::SttError::RateLimited(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "rate-limited", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::stt::types::SttError::InsufficientCredits => {
+                crate::wrappers::variant_case(ctx, "insufficient-credits")
+            }
+            crate::bindings::golem::stt::types::SttError::UnsupportedOperation(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "unsupported-operation", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::stt::types::SttError::ServiceUnavailable(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "service-unavailable", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::stt::types::SttError::NetworkError(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "network-error", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::stt::types::SttError::InternalError(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "internal-error", crate::wrappers::JsString(inner))
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::stt::types::SttError {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "invalid-audio" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(crate::bindings::golem::stt::types::SttError::InvalidAudio(inner.0))
+            }
+            "unsupported-format" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(
+                    crate::bindings::golem::stt::types::SttError::UnsupportedFormat(
+                        inner.0,
+                    ),
+                )
+            }
+            "unsupported-language" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(
+                    crate::bindings::golem::stt::types::SttError::UnsupportedLanguage(
+                        inner.0,
+                    ),
+                )
+            }
+            "transcription-failed" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(
+                    crate::bindings::golem::stt::types::SttError::TranscriptionFailed(
+                        inner.0,
+                    ),
+                )
+            }
+            "unauthorized" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(crate::bindings::golem::stt::types::SttError::Unauthorized(inner.0))
+            }
+            "access-denied" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(crate::bindings::golem::stt::types::SttError::AccessDenied(inner.0))
+            }
+            "rate-limited" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(crate::bindings::golem::stt::types::SttError::RateLimited(inner.0))
+            }
+            "insufficient-credits" => {
+                Ok(crate::bindings::golem::stt::types::SttError::InsufficientCredits)
+            }
+            "unsupported-operation" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(
+                    crate::bindings::golem::stt::types::SttError::UnsupportedOperation(
+                        inner.0,
+                    ),
+                )
+            }
+            "service-unavailable" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(
+                    crate::bindings::golem::stt::types::SttError::ServiceUnavailable(
+                        inner.0,
+                    ),
+                )
+            }
+            "network-error" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(crate::bindings::golem::stt::types::SttError::NetworkError(inner.0))
+            }
+            "internal-error" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(crate::bindings::golem::stt::types::SttError::InternalError(inner.0))
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS stt-error",
+                        "WIT stt-error",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::stt::types::AudioFormat {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::stt::types::AudioFormat::Wav => "wav".into_js(ctx),
+            crate::bindings::golem::stt::types::AudioFormat::Mp3 => "mp3".into_js(ctx),
+            crate::bindings::golem::stt::types::AudioFormat::Flac => "flac".into_js(ctx),
+            crate::bindings::golem::stt::types::AudioFormat::Ogg => "ogg".into_js(ctx),
+            crate::bindings::golem::stt::types::AudioFormat::Aac => "aac".into_js(ctx),
+            crate::bindings::golem::stt::types::AudioFormat::Pcm => "pcm".into_js(ctx),
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::stt::types::AudioFormat {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let value = crate::wrappers::enum_case(
+            value,
+            "JS audio-format",
+            "WIT audio-format",
+        )?;
+        match value.as_str() {
+            "wav" => Ok(crate::bindings::golem::stt::types::AudioFormat::Wav),
+            "mp3" => Ok(crate::bindings::golem::stt::types::AudioFormat::Mp3),
+            "flac" => Ok(crate::bindings::golem::stt::types::AudioFormat::Flac),
+            "ogg" => Ok(crate::bindings::golem::stt::types::AudioFormat::Ogg),
+            "aac" => Ok(crate::bindings::golem::stt::types::AudioFormat::Aac),
+            "pcm" => Ok(crate::bindings::golem::stt::types::AudioFormat::Pcm),
+            _ => {
+                Err(
+                    crate::wrappers::unknown_enum_case(
+                        "JS audio-format",
+                        "WIT audio-format",
+                        &value,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::stt::types::TimingInfo {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<_, f32>("startTimeSeconds", self.start_time_seconds)?;
+        obj.set::<_, f32>("endTimeSeconds", self.end_time_seconds)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::stt::types::TimingInfo {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT timing-info",
+            &["startTimeSeconds", "endTimeSeconds"],
+            &[("startTimeSeconds", "f32"), ("endTimeSeconds", "f32")],
+            false,
+        )?;
+        let start_time_seconds: f32 = crate::wrappers::get_field(
+            &obj,
+            "startTimeSeconds",
+            "f32",
+        )?;
+        let start_time_seconds: f32 = start_time_seconds;
+        let end_time_seconds: f32 = crate::wrappers::get_field(
+            &obj,
+            "endTimeSeconds",
+            "f32",
+        )?;
+        let end_time_seconds: f32 = end_time_seconds;
+        Ok(Self {
+            start_time_seconds,
+            end_time_seconds,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::stt::types::WordSegment {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("text", crate::wrappers::JsString(self.text))?;
+        obj.set::<
+                _,
+                Option<crate::bindings::golem::stt::types::TimingInfo>,
+            >("timingInfo", self.timing_info.map(|v| v))?;
+        obj.set::<_, Option<f32>>("confidence", self.confidence.map(|v| v))?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >("speakerId", self.speaker_id.map(|v| crate::wrappers::JsString(v)))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::stt::types::WordSegment {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT word-segment",
+            &["text", "timingInfo", "confidence", "speakerId"],
+            &[("text", "string")],
+            false,
+        )?;
+        let text: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "text",
+            "string",
+        )?;
+        let text: String = text.0;
+        let timing_info: Option<crate::bindings::golem::stt::types::TimingInfo> = crate::wrappers::get_field(
+            &obj,
+            "timingInfo",
+            "option<timing-info>",
+        )?;
+        let timing_info: Option<crate::bindings::golem::stt::types::TimingInfo> = timing_info
+            .map(|v| v);
+        let confidence: Option<f32> = crate::wrappers::get_field(
+            &obj,
+            "confidence",
+            "option<f32>",
+        )?;
+        let confidence: Option<f32> = confidence.map(|v| v);
+        let speaker_id: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "speakerId",
+            "option<string>",
+        )?;
+        let speaker_id: Option<String> = speaker_id.map(|v| v.0);
+        Ok(Self {
+            text,
+            timing_info,
+            confidence,
+            speaker_id,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::stt::types::TranscriptionMetadata {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<_, f32>("durationSeconds", self.duration_seconds)?;
+        obj.set::<_, u32>("audioSizeBytes", self.audio_size_bytes)?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("requestId", crate::wrappers::JsString(self.request_id))?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >("model", self.model.map(|v| crate::wrappers::JsString(v)))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("language", crate::wrappers::JsString(self.language))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::stt::types::TranscriptionMetadata {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT transcription-metadata",
+            &["durationSeconds", "audioSizeBytes", "requestId", "model", "language"],
+            &[
+                ("durationSeconds", "f32"),
+                ("audioSizeBytes", "u32"),
+                ("requestId", "string"),
+                ("language", "language-code"),
+            ],
+            false,
+        )?;
+        let duration_seconds: f32 = crate::wrappers::get_field(
+            &obj,
+            "durationSeconds",
+            "f32",
+        )?;
+        let duration_seconds: f32 = duration_seconds;
+        let audio_size_bytes: u32 = crate::wrappers::get_field(
+            &obj,
+            "audioSizeBytes",
+            "u32",
+        )?;
+        let audio_size_bytes: u32 = audio_size_bytes;
+        let request_id: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "requestId",
+            "string",
+        )?;
+        let request_id: String = request_id.0;
+        let model: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "model",
+            "option<string>",
+        )?;
+        let model: Option<String> = model.map(|v| v.0);
+        let language: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "language",
+            "language-code",
+        )?;
+        let language: crate::bindings::golem::stt::types::LanguageCode = language.0;
+        Ok(Self {
+            duration_seconds,
+            audio_size_bytes,
+            request_id,
+            model,
+            language,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::stt::types::TranscriptionSegment {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("transcript", crate::wrappers::JsString(self.transcript))?;
+        obj.set::<
+                _,
+                Option<crate::bindings::golem::stt::types::TimingInfo>,
+            >("timingInfo", self.timing_info.map(|v| v))?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >("speakerId", self.speaker_id.map(|v| crate::wrappers::JsString(v)))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<crate::bindings::golem::stt::types::WordSegment>,
+            >(
+            "words",
+            crate::wrappers::JsList(
+                self.words.into_iter().map(|v| v).collect::<Vec<_>>(),
+            ),
+        )?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::stt::types::TranscriptionSegment {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT transcription-segment",
+            &["transcript", "timingInfo", "speakerId", "words"],
+            &[("transcript", "string"), ("words", "list<word-segment>")],
+            false,
+        )?;
+        let transcript: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "transcript",
+            "string",
+        )?;
+        let transcript: String = transcript.0;
+        let timing_info: Option<crate::bindings::golem::stt::types::TimingInfo> = crate::wrappers::get_field(
+            &obj,
+            "timingInfo",
+            "option<timing-info>",
+        )?;
+        let timing_info: Option<crate::bindings::golem::stt::types::TimingInfo> = timing_info
+            .map(|v| v);
+        let speaker_id: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "speakerId",
+            "option<string>",
+        )?;
+        let speaker_id: Option<String> = speaker_id.map(|v| v.0);
+        let words: crate::wrappers::JsList<
+            crate::bindings::golem::stt::types::WordSegment,
+        > = crate::wrappers::get_field(&obj, "words", "list<word-segment>")?;
+        let words: Vec<crate::bindings::golem::stt::types::WordSegment> = words
+            .0
+            .into_iter()
+            .map(|v| v)
+            .collect::<Vec<_>>();
+        Ok(Self {
+            transcript,
+            timing_info,
+            speaker_id,
+            words,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::stt::types::TranscriptionChannel {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("id", crate::wrappers::JsString(self.id))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("transcript", crate::wrappers::JsString(self.transcript))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<
+                    crate::bindings::golem::stt::types::TranscriptionSegment,
+                >,
+            >(
+            "segments",
+            crate::wrappers::JsList(
+                self.segments.into_iter().map(|v| v).collect::<Vec<_>>(),
+            ),
+        )?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::stt::types::TranscriptionChannel {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT transcription-channel",
+            &["id", "transcript", "segments"],
+            &[
+                ("id", "string"),
+                ("transcript", "string"),
+                ("segments", "list<transcription-segment>"),
+            ],
+            false,
+        )?;
+        let id: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "id",
+            "string",
+        )?;
+        let id: String = id.0;
+        let transcript: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "transcript",
+            "string",
+        )?;
+        let transcript: String = transcript.0;
+        let segments: crate::wrappers::JsList<
+            crate::bindings::golem::stt::types::TranscriptionSegment,
+        > = crate::wrappers::get_field(&obj, "segments", "list<transcription-segment>")?;
+        let segments: Vec<crate::bindings::golem::stt::types::TranscriptionSegment> = segments
+            .0
+            .into_iter()
+            .map(|v| v)
+            .collect::<Vec<_>>();
+        Ok(Self { id, transcript, segments })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::stt::types::TranscriptionResult {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::golem::stt::types::TranscriptionMetadata,
+            >("transcriptMetadata", self.transcript_metadata)?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<
+                    crate::bindings::golem::stt::types::TranscriptionChannel,
+                >,
+            >(
+            "channels",
+            crate::wrappers::JsList(
+                self.channels.into_iter().map(|v| v).collect::<Vec<_>>(),
+            ),
+        )?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::stt::types::TranscriptionResult {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT transcription-result",
+            &["transcriptMetadata", "channels"],
+            &[
+                ("transcriptMetadata", "transcription-metadata"),
+                ("channels", "list<transcription-channel>"),
+            ],
+            false,
+        )?;
+        let transcript_metadata: crate::bindings::golem::stt::types::TranscriptionMetadata = crate::wrappers::get_field(
+            &obj,
+            "transcriptMetadata",
+            "transcription-metadata",
+        )?;
+        let transcript_metadata: crate::bindings::golem::stt::types::TranscriptionMetadata = transcript_metadata;
+        let channels: crate::wrappers::JsList<
+            crate::bindings::golem::stt::types::TranscriptionChannel,
+        > = crate::wrappers::get_field(&obj, "channels", "list<transcription-channel>")?;
+        let channels: Vec<crate::bindings::golem::stt::types::TranscriptionChannel> = channels
+            .0
+            .into_iter()
+            .map(|v| v)
+            .collect::<Vec<_>>();
+        Ok(Self {
+            transcript_metadata,
+            channels,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::stt::languages::LanguageInfo {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("code", crate::wrappers::JsString(self.code))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("name", crate::wrappers::JsString(self.name))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("nativeName", crate::wrappers::JsString(self.native_name))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::stt::languages::LanguageInfo {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT language-info",
+            &["code", "name", "nativeName"],
+            &[("code", "language-code"), ("name", "string"), ("nativeName", "string")],
+            false,
+        )?;
+        let code: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "code",
+            "language-code",
+        )?;
+        let code: crate::bindings::golem::stt::languages::LanguageCode = code.0;
+        let name: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "name",
+            "string",
+        )?;
+        let name: String = name.0;
+        let native_name: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "nativeName",
+            "string",
+        )?;
+        let native_name: String = native_name.0;
+        Ok(Self { code, name, native_name })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::stt::types::AudioConfig {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::golem::stt::types::AudioFormat,
+            >("format", self.format)?;
+        obj.set::<_, Option<u32>>("sampleRate", self.sample_rate.map(|v| v))?;
+        obj.set::<_, Option<u8>>("channels", self.channels.map(|v| v))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::stt::types::AudioConfig {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT audio-config",
+            &["format", "sampleRate", "channels"],
+            &[("format", "audio-format")],
+            false,
+        )?;
+        let format: crate::bindings::golem::stt::types::AudioFormat = crate::wrappers::get_field(
+            &obj,
+            "format",
+            "audio-format",
+        )?;
+        let format: crate::bindings::golem::stt::types::AudioFormat = format;
+        let sample_rate: Option<u32> = crate::wrappers::get_field(
+            &obj,
+            "sampleRate",
+            "option<u32>",
+        )?;
+        let sample_rate: Option<u32> = sample_rate.map(|v| v);
+        let channels: Option<u8> = crate::wrappers::get_field(
+            &obj,
+            "channels",
+            "option<u8>",
+        )?;
+        let channels: Option<u8> = channels.map(|v| v);
+        Ok(Self {
+            format,
+            sample_rate,
+            channels,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::stt::transcription::Phrase {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("value", crate::wrappers::JsString(self.value))?;
+        obj.set::<_, Option<f32>>("boost", self.boost.map(|v| v))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::stt::transcription::Phrase {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT phrase",
+            &["value", "boost"],
+            &[("value", "string")],
+            false,
+        )?;
+        let value: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "value",
+            "string",
+        )?;
+        let value: String = value.0;
+        let boost: Option<f32> = crate::wrappers::get_field(
+            &obj,
+            "boost",
+            "option<f32>",
+        )?;
+        let boost: Option<f32> = boost.map(|v| v);
+        Ok(Self { value, boost })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::stt::transcription::Vocabulary {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<
+                    crate::bindings::golem::stt::transcription::Phrase,
+                >,
+            >(
+            "phrases",
+            crate::wrappers::JsList(
+                self.phrases.into_iter().map(|v| v).collect::<Vec<_>>(),
+            ),
+        )?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::stt::transcription::Vocabulary {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT vocabulary",
+            &["phrases"],
+            &[("phrases", "list<phrase>")],
+            false,
+        )?;
+        let phrases: crate::wrappers::JsList<
+            crate::bindings::golem::stt::transcription::Phrase,
+        > = crate::wrappers::get_field(&obj, "phrases", "list<phrase>")?;
+        let phrases: Vec<crate::bindings::golem::stt::transcription::Phrase> = phrases
+            .0
+            .into_iter()
+            .map(|v| v)
+            .collect::<Vec<_>>();
+        Ok(Self { phrases })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::stt::transcription::DiarizationOptions {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<_, bool>("enabled", self.enabled)?;
+        obj.set::<_, Option<u32>>("minSpeakerCount", self.min_speaker_count.map(|v| v))?;
+        obj.set::<_, Option<u32>>("maxSpeakerCount", self.max_speaker_count.map(|v| v))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::stt::transcription::DiarizationOptions {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT diarization-options",
+            &["enabled", "minSpeakerCount", "maxSpeakerCount"],
+            &[("enabled", "bool")],
+            false,
+        )?;
+        let enabled: bool = crate::wrappers::get_field(&obj, "enabled", "bool")?;
+        let enabled: bool = enabled;
+        let min_speaker_count: Option<u32> = crate::wrappers::get_field(
+            &obj,
+            "minSpeakerCount",
+            "option<u32>",
+        )?;
+        let min_speaker_count: Option<u32> = min_speaker_count.map(|v| v);
+        let max_speaker_count: Option<u32> = crate::wrappers::get_field(
+            &obj,
+            "maxSpeakerCount",
+            "option<u32>",
+        )?;
+        let max_speaker_count: Option<u32> = max_speaker_count.map(|v| v);
+        Ok(Self {
+            enabled,
+            min_speaker_count,
+            max_speaker_count,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::stt::transcription::TranscribeOptions {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >("language", self.language.map(|v| crate::wrappers::JsString(v)))?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >("model", self.model.map(|v| crate::wrappers::JsString(v)))?;
+        obj.set::<_, Option<bool>>("profanityFilter", self.profanity_filter.map(|v| v))?;
+        obj.set::<
+                _,
+                Option<crate::bindings::golem::stt::transcription::Vocabulary>,
+            >("vocabulary", self.vocabulary.map(|v| v))?;
+        obj.set::<
+                _,
+                Option<crate::bindings::golem::stt::transcription::DiarizationOptions>,
+            >("diarization", self.diarization.map(|v| v))?;
+        obj.set::<
+                _,
+                Option<bool>,
+            >("enableMultiChannel", self.enable_multi_channel.map(|v| v))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::stt::transcription::TranscribeOptions {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT transcribe-options",
+            &[
+                "language",
+                "model",
+                "profanityFilter",
+                "vocabulary",
+                "diarization",
+                "enableMultiChannel",
+            ],
+            &[],
+            false,
+        )?;
+        let language: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "language",
+            "option<language-code>",
+        )?;
+        let language: Option<crate::bindings::golem::stt::transcription::LanguageCode> = language
+            .map(|v| v.0);
+        let model: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "model",
+            "option<string>",
+        )?;
+        let model: Option<String> = model.map(|v| v.0);
+        let profanity_filter: Option<bool> = crate::wrappers::get_field(
+            &obj,
+            "profanityFilter",
+            "option<bool>",
+        )?;
+        let profanity_filter: Option<bool> = profanity_filter.map(|v| v);
+        let vocabulary: Option<crate::bindings::golem::stt::transcription::Vocabulary> = crate::wrappers::get_field(
+            &obj,
+            "vocabulary",
+            "option<vocabulary>",
+        )?;
+        let vocabulary: Option<crate::bindings::golem::stt::transcription::Vocabulary> = vocabulary
+            .map(|v| v);
+        let diarization: Option<
+            crate::bindings::golem::stt::transcription::DiarizationOptions,
+        > = crate::wrappers::get_field(
+            &obj,
+            "diarization",
+            "option<diarization-options>",
+        )?;
+        let diarization: Option<
+            crate::bindings::golem::stt::transcription::DiarizationOptions,
+        > = diarization.map(|v| v);
+        let enable_multi_channel: Option<bool> = crate::wrappers::get_field(
+            &obj,
+            "enableMultiChannel",
+            "option<bool>",
+        )?;
+        let enable_multi_channel: Option<bool> = enable_multi_channel.map(|v| v);
+        Ok(Self {
+            language,
+            model,
+            profanity_filter,
+            vocabulary,
+            diarization,
+            enable_multi_channel,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::stt::transcription::TranscriptionRequest {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("requestId", crate::wrappers::JsString(self.request_id))?;
+        obj.set::<
+                _,
+                crate::wrappers::UInt8Array,
+            >("audio", crate::wrappers::UInt8Array(self.audio))?;
+        obj.set::<
+                _,
+                crate::bindings::golem::stt::types::AudioConfig,
+            >("config", self.config)?;
+        obj.set::<
+                _,
+                Option<crate::bindings::golem::stt::transcription::TranscribeOptions>,
+            >("options", self.options.map(|v| v))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::stt::transcription::TranscriptionRequest {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT transcription-request",
+            &["requestId", "audio", "config", "options"],
+            &[
+                ("requestId", "string"),
+                ("audio", "list<u8>"),
+                ("config", "audio-config"),
+            ],
+            false,
+        )?;
+        let request_id: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "requestId",
+            "string",
+        )?;
+        let request_id: String = request_id.0;
+        let audio: crate::wrappers::UInt8Array = crate::wrappers::get_field(
+            &obj,
+            "audio",
+            "list<u8>",
+        )?;
+        let audio: Vec<u8> = audio.0;
+        let config: crate::bindings::golem::stt::types::AudioConfig = crate::wrappers::get_field(
+            &obj,
+            "config",
+            "audio-config",
+        )?;
+        let config: crate::bindings::golem::stt::transcription::AudioConfig = config;
+        let options: Option<
+            crate::bindings::golem::stt::transcription::TranscribeOptions,
+        > = crate::wrappers::get_field(&obj, "options", "option<transcribe-options>")?;
+        let options: Option<
+            crate::bindings::golem::stt::transcription::TranscribeOptions,
+        > = options.map(|v| v);
+        Ok(Self {
+            request_id,
+            audio,
+            config,
+            options,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::stt::transcription::FailedTranscription {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("requestId", crate::wrappers::JsString(self.request_id))?;
+        obj.set::<_, crate::bindings::golem::stt::types::SttError>("error", self.error)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::stt::transcription::FailedTranscription {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT failed-transcription",
+            &["requestId", "error"],
+            &[("requestId", "string"), ("error", "stt-error")],
+            false,
+        )?;
+        let request_id: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "requestId",
+            "string",
+        )?;
+        let request_id: String = request_id.0;
+        let error: crate::bindings::golem::stt::types::SttError = crate::wrappers::get_field(
+            &obj,
+            "error",
+            "stt-error",
+        )?;
+        let error: crate::bindings::golem::stt::transcription::SttError = error;
+        Ok(Self { request_id, error })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::stt::transcription::MultiTranscriptionResult {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<
+                    crate::bindings::golem::stt::types::TranscriptionResult,
+                >,
+            >(
+            "successes",
+            crate::wrappers::JsList(
+                self.successes.into_iter().map(|v| v).collect::<Vec<_>>(),
+            ),
+        )?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<
+                    crate::bindings::golem::stt::transcription::FailedTranscription,
+                >,
+            >(
+            "failures",
+            crate::wrappers::JsList(
+                self.failures.into_iter().map(|v| v).collect::<Vec<_>>(),
+            ),
+        )?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::stt::transcription::MultiTranscriptionResult {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT multi-transcription-result",
+            &["successes", "failures"],
+            &[
+                ("successes", "list<transcription-result>"),
+                ("failures", "list<failed-transcription>"),
+            ],
+            false,
+        )?;
+        let successes: crate::wrappers::JsList<
+            crate::bindings::golem::stt::types::TranscriptionResult,
+        > = crate::wrappers::get_field(&obj, "successes", "list<transcription-result>")?;
+        let successes: Vec<
+            crate::bindings::golem::stt::transcription::TranscriptionResult,
+        > = successes.0.into_iter().map(|v| v).collect::<Vec<_>>();
+        let failures: crate::wrappers::JsList<
+            crate::bindings::golem::stt::transcription::FailedTranscription,
+        > = crate::wrappers::get_field(&obj, "failures", "list<failed-transcription>")?;
+        let failures: Vec<
+            crate::bindings::golem::stt::transcription::FailedTranscription,
+        > = failures.0.into_iter().map(|v| v).collect::<Vec<_>>();
+        Ok(Self { successes, failures })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::video_generation::types::VideoError {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::video_generation::types::VideoError::InvalidInput(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "invalid-input", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::video_generation::types::VideoError::UnsupportedFeature(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "unsupported-feature", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::video_generation::types::VideoError::QuotaExceeded => {
+                crate::wrappers::variant_case(ctx, "quota-exceeded")
+            }
+            crate::bindings::golem::video_generation::types::VideoError::GenerationFailed(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "generation-failed", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::video_generation::types::VideoError::Cancelled => {
+                crate::wrappers::variant_case(ctx, "cancelled")
+            }
+            crate::bindings::golem::video_generation::types::VideoError::InternalError(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "internal-error", crate::wrappers::JsString(inner))
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::video_generation::types::VideoError {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "invalid-input" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(
+                    crate::bindings::golem::video_generation::types::VideoError::InvalidInput(
+                        inner.0,
+                    ),
+                )
+            }
+            "unsupported-feature" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(
+                    crate::bindings::golem::video_generation::types::VideoError::UnsupportedFeature(
+                        inner.0,
+                    ),
+                )
+            }
+            "quota-exceeded" => {
+                Ok(
+                    crate::bindings::golem::video_generation::types::VideoError::QuotaExceeded,
+                )
+            }
+            "generation-failed" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(
+                    crate::bindings::golem::video_generation::types::VideoError::GenerationFailed(
+                        inner.0,
+                    ),
+                )
+            }
+            "cancelled" => {
+                Ok(
+                    crate::bindings::golem::video_generation::types::VideoError::Cancelled,
+                )
+            }
+            "internal-error" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(
+                    crate::bindings::golem::video_generation::types::VideoError::InternalError(
+                        inner.0,
+                    ),
+                )
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS video-error",
+                        "WIT video-error",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::video_generation::types::ImageRole {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::video_generation::types::ImageRole::First => {
+                "first".into_js(ctx)
+            }
+            crate::bindings::golem::video_generation::types::ImageRole::Last => {
+                "last".into_js(ctx)
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::video_generation::types::ImageRole {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let value = crate::wrappers::enum_case(
+            value,
+            "JS image-role",
+            "WIT image-role",
+        )?;
+        match value.as_str() {
+            "first" => {
+                Ok(crate::bindings::golem::video_generation::types::ImageRole::First)
+            }
+            "last" => {
+                Ok(crate::bindings::golem::video_generation::types::ImageRole::Last)
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_enum_case(
+                        "JS image-role",
+                        "WIT image-role",
+                        &value,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::video_generation::types::RawBytes {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::UInt8Array,
+            >("bytes", crate::wrappers::UInt8Array(self.bytes))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("mimeType", crate::wrappers::JsString(self.mime_type))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::video_generation::types::RawBytes {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT raw-bytes",
+            &["bytes", "mimeType"],
+            &[("bytes", "list<u8>"), ("mimeType", "string")],
+            false,
+        )?;
+        let bytes: crate::wrappers::UInt8Array = crate::wrappers::get_field(
+            &obj,
+            "bytes",
+            "list<u8>",
+        )?;
+        let bytes: Vec<u8> = bytes.0;
+        let mime_type: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "mimeType",
+            "string",
+        )?;
+        let mime_type: String = mime_type.0;
+        Ok(Self { bytes, mime_type })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::video_generation::types::MediaData {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::video_generation::types::MediaData::Url(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "url", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::video_generation::types::MediaData::Bytes(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::video_generation::types::RawBytes,
+                >(ctx, "bytes", inner)
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::video_generation::types::MediaData {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "url" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(
+                    crate::bindings::golem::video_generation::types::MediaData::Url(
+                        inner.0,
+                    ),
+                )
+            }
+            "bytes" => {
+                let inner: crate::bindings::golem::video_generation::types::RawBytes = crate::wrappers::variant_value(
+                    &obj,
+                    "raw-bytes",
+                )?;
+                Ok(
+                    crate::bindings::golem::video_generation::types::MediaData::Bytes(
+                        inner,
+                    ),
+                )
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS media-data",
+                        "WIT media-data",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::video_generation::types::InputImage {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::golem::video_generation::types::MediaData,
+            >("data", self.data)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::video_generation::types::InputImage {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT input-image",
+            &["data"],
+            &[("data", "media-data")],
+            false,
+        )?;
+        let data: crate::bindings::golem::video_generation::types::MediaData = crate::wrappers::get_field(
+            &obj,
+            "data",
+            "media-data",
+        )?;
+        let data: crate::bindings::golem::video_generation::types::MediaData = data;
+        Ok(Self { data })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::video_generation::types::Reference {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::golem::video_generation::types::InputImage,
+            >("data", self.data)?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >("prompt", self.prompt.map(|v| crate::wrappers::JsString(v)))?;
+        obj.set::<
+                _,
+                Option<crate::bindings::golem::video_generation::types::ImageRole>,
+            >("role", self.role.map(|v| v))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::video_generation::types::Reference {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT reference",
+            &["data", "prompt", "role"],
+            &[("data", "input-image")],
+            false,
+        )?;
+        let data: crate::bindings::golem::video_generation::types::InputImage = crate::wrappers::get_field(
+            &obj,
+            "data",
+            "input-image",
+        )?;
+        let data: crate::bindings::golem::video_generation::types::InputImage = data;
+        let prompt: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "prompt",
+            "option<string>",
+        )?;
+        let prompt: Option<String> = prompt.map(|v| v.0);
+        let role: Option<crate::bindings::golem::video_generation::types::ImageRole> = crate::wrappers::get_field(
+            &obj,
+            "role",
+            "option<image-role>",
+        )?;
+        let role: Option<crate::bindings::golem::video_generation::types::ImageRole> = role
+            .map(|v| v);
+        Ok(Self { data, prompt, role })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::video_generation::types::BaseVideo {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::golem::video_generation::types::MediaData,
+            >("data", self.data)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::video_generation::types::BaseVideo {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT base-video",
+            &["data"],
+            &[("data", "media-data")],
+            false,
+        )?;
+        let data: crate::bindings::golem::video_generation::types::MediaData = crate::wrappers::get_field(
+            &obj,
+            "data",
+            "media-data",
+        )?;
+        let data: crate::bindings::golem::video_generation::types::MediaData = data;
+        Ok(Self { data })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::video_generation::types::MediaInput {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::video_generation::types::MediaInput::Text(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "text", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::video_generation::types::MediaInput::Image(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::video_generation::types::Reference,
+                >(ctx, "image", inner)
+            }
+            crate::bindings::golem::video_generation::types::MediaInput::Video(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::video_generation::types::BaseVideo,
+                >(ctx, "video", inner)
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::video_generation::types::MediaInput {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "text" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(
+                    crate::bindings::golem::video_generation::types::MediaInput::Text(
+                        inner.0,
+                    ),
+                )
+            }
+            "image" => {
+                let inner: crate::bindings::golem::video_generation::types::Reference = crate::wrappers::variant_value(
+                    &obj,
+                    "reference",
+                )?;
+                Ok(
+                    crate::bindings::golem::video_generation::types::MediaInput::Image(
+                        inner,
+                    ),
+                )
+            }
+            "video" => {
+                let inner: crate::bindings::golem::video_generation::types::BaseVideo = crate::wrappers::variant_value(
+                    &obj,
+                    "base-video",
+                )?;
+                Ok(
+                    crate::bindings::golem::video_generation::types::MediaInput::Video(
+                        inner,
+                    ),
+                )
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS media-input",
+                        "WIT media-input",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::video_generation::types::Narration {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::golem::video_generation::types::MediaData,
+            >("data", self.data)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::video_generation::types::Narration {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT narration",
+            &["data"],
+            &[("data", "media-data")],
+            false,
+        )?;
+        let data: crate::bindings::golem::video_generation::types::MediaData = crate::wrappers::get_field(
+            &obj,
+            "data",
+            "media-data",
+        )?;
+        let data: crate::bindings::golem::video_generation::types::MediaData = data;
+        Ok(Self { data })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::video_generation::types::StaticMask {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::golem::video_generation::types::InputImage,
+            >("mask", self.mask)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::video_generation::types::StaticMask {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT static-mask",
+            &["mask"],
+            &[("mask", "input-image")],
+            false,
+        )?;
+        let mask: crate::bindings::golem::video_generation::types::InputImage = crate::wrappers::get_field(
+            &obj,
+            "mask",
+            "input-image",
+        )?;
+        let mask: crate::bindings::golem::video_generation::types::InputImage = mask;
+        Ok(Self { mask })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::video_generation::types::Position {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<_, u32>("x", self.x)?;
+        obj.set::<_, u32>("y", self.y)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::video_generation::types::Position {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT position",
+            &["x", "y"],
+            &[("x", "u32"), ("y", "u32")],
+            false,
+        )?;
+        let x: u32 = crate::wrappers::get_field(&obj, "x", "u32")?;
+        let x: u32 = x;
+        let y: u32 = crate::wrappers::get_field(&obj, "y", "u32")?;
+        let y: u32 = y;
+        Ok(Self { x, y })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::video_generation::types::DynamicMask {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::golem::video_generation::types::InputImage,
+            >("mask", self.mask)?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<
+                    crate::bindings::golem::video_generation::types::Position,
+                >,
+            >(
+            "trajectories",
+            crate::wrappers::JsList(
+                self.trajectories.into_iter().map(|v| v).collect::<Vec<_>>(),
+            ),
+        )?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::video_generation::types::DynamicMask {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT dynamic-mask",
+            &["mask", "trajectories"],
+            &[("mask", "input-image"), ("trajectories", "list<position>")],
+            false,
+        )?;
+        let mask: crate::bindings::golem::video_generation::types::InputImage = crate::wrappers::get_field(
+            &obj,
+            "mask",
+            "input-image",
+        )?;
+        let mask: crate::bindings::golem::video_generation::types::InputImage = mask;
+        let trajectories: crate::wrappers::JsList<
+            crate::bindings::golem::video_generation::types::Position,
+        > = crate::wrappers::get_field(&obj, "trajectories", "list<position>")?;
+        let trajectories: Vec<
+            crate::bindings::golem::video_generation::types::Position,
+        > = trajectories.0.into_iter().map(|v| v).collect::<Vec<_>>();
+        Ok(Self { mask, trajectories })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::video_generation::types::CameraConfig {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<_, f32>("horizontal", self.horizontal)?;
+        obj.set::<_, f32>("vertical", self.vertical)?;
+        obj.set::<_, f32>("pan", self.pan)?;
+        obj.set::<_, f32>("tilt", self.tilt)?;
+        obj.set::<_, f32>("zoom", self.zoom)?;
+        obj.set::<_, f32>("roll", self.roll)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::video_generation::types::CameraConfig {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT camera-config",
+            &["horizontal", "vertical", "pan", "tilt", "zoom", "roll"],
+            &[
+                ("horizontal", "f32"),
+                ("vertical", "f32"),
+                ("pan", "f32"),
+                ("tilt", "f32"),
+                ("zoom", "f32"),
+                ("roll", "f32"),
+            ],
+            false,
+        )?;
+        let horizontal: f32 = crate::wrappers::get_field(&obj, "horizontal", "f32")?;
+        let horizontal: f32 = horizontal;
+        let vertical: f32 = crate::wrappers::get_field(&obj, "vertical", "f32")?;
+        let vertical: f32 = vertical;
+        let pan: f32 = crate::wrappers::get_field(&obj, "pan", "f32")?;
+        let pan: f32 = pan;
+        let tilt: f32 = crate::wrappers::get_field(&obj, "tilt", "f32")?;
+        let tilt: f32 = tilt;
+        let zoom: f32 = crate::wrappers::get_field(&obj, "zoom", "f32")?;
+        let zoom: f32 = zoom;
+        let roll: f32 = crate::wrappers::get_field(&obj, "roll", "f32")?;
+        let roll: f32 = roll;
+        Ok(Self {
+            horizontal,
+            vertical,
+            pan,
+            tilt,
+            zoom,
+            roll,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::video_generation::types::CameraMovement {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::video_generation::types::CameraMovement::Simple(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::video_generation::types::CameraConfig,
+                >(ctx, "simple", inner)
+            }
+            crate::bindings::golem::video_generation::types::CameraMovement::DownBack => {
+                crate::wrappers::variant_case(ctx, "down-back")
+            }
+            crate::bindings::golem::video_generation::types::CameraMovement::ForwardUp => {
+                crate::wrappers::variant_case(ctx, "forward-up")
+            }
+            crate::bindings::golem::video_generation::types::CameraMovement::RightTurnForward => {
+                crate::wrappers::variant_case(ctx, "right-turn-forward")
+            }
+            crate::bindings::golem::video_generation::types::CameraMovement::LeftTurnForward => {
+                crate::wrappers::variant_case(ctx, "left-turn-forward")
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::video_generation::types::CameraMovement {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "simple" => {
+                let inner: crate::bindings::golem::video_generation::types::CameraConfig = crate::wrappers::variant_value(
+                    &obj,
+                    "camera-config",
+                )?;
+                Ok(
+                    crate::bindings::golem::video_generation::types::CameraMovement::Simple(
+                        inner,
+                    ),
+                )
+            }
+            "down-back" => {
+                Ok(
+                    crate::bindings::golem::video_generation::types::CameraMovement::DownBack,
+                )
+            }
+            "forward-up" => {
+                Ok(
+                    crate::bindings::golem::video_generation::types::CameraMovement::ForwardUp,
+                )
+            }
+            "right-turn-forward" => {
+                Ok(
+                    crate::bindings::golem::video_generation::types::CameraMovement::RightTurnForward,
+                )
+            }
+            "left-turn-forward" => {
+                Ok(
+                    crate::bindings::golem::video_generation::types::CameraMovement::LeftTurnForward,
+                )
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS camera-movement",
+                        "WIT camera-movement",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::video_generation::types::AspectRatio {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::video_generation::types::AspectRatio::Square => {
+                "square".into_js(ctx)
+            }
+            crate::bindings::golem::video_generation::types::AspectRatio::Portrait => {
+                "portrait".into_js(ctx)
+            }
+            crate::bindings::golem::video_generation::types::AspectRatio::Landscape => {
+                "landscape".into_js(ctx)
+            }
+            crate::bindings::golem::video_generation::types::AspectRatio::Cinema => {
+                "cinema".into_js(ctx)
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::video_generation::types::AspectRatio {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let value = crate::wrappers::enum_case(
+            value,
+            "JS aspect-ratio",
+            "WIT aspect-ratio",
+        )?;
+        match value.as_str() {
+            "square" => {
+                Ok(crate::bindings::golem::video_generation::types::AspectRatio::Square)
+            }
+            "portrait" => {
+                Ok(
+                    crate::bindings::golem::video_generation::types::AspectRatio::Portrait,
+                )
+            }
+            "landscape" => {
+                Ok(
+                    crate::bindings::golem::video_generation::types::AspectRatio::Landscape,
+                )
+            }
+            "cinema" => {
+                Ok(crate::bindings::golem::video_generation::types::AspectRatio::Cinema)
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_enum_case(
+                        "JS aspect-ratio",
+                        "WIT aspect-ratio",
+                        &value,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::video_generation::types::Resolution {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::video_generation::types::Resolution::Sd => {
+                "sd".into_js(ctx)
+            }
+            crate::bindings::golem::video_generation::types::Resolution::Hd => {
+                "hd".into_js(ctx)
+            }
+            crate::bindings::golem::video_generation::types::Resolution::Fhd => {
+                "fhd".into_js(ctx)
+            }
+            crate::bindings::golem::video_generation::types::Resolution::Uhd => {
+                "uhd".into_js(ctx)
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::video_generation::types::Resolution {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let value = crate::wrappers::enum_case(
+            value,
+            "JS resolution",
+            "WIT resolution",
+        )?;
+        match value.as_str() {
+            "sd" => Ok(crate::bindings::golem::video_generation::types::Resolution::Sd),
+            "hd" => Ok(crate::bindings::golem::video_generation::types::Resolution::Hd),
+            "fhd" => Ok(crate::bindings::golem::video_generation::types::Resolution::Fhd),
+            "uhd" => Ok(crate::bindings::golem::video_generation::types::Resolution::Uhd),
+            _ => {
+                Err(
+                    crate::wrappers::unknown_enum_case(
+                        "JS resolution",
+                        "WIT resolution",
+                        &value,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::video_generation::types::Kv {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("key", crate::wrappers::JsString(self.key))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("value", crate::wrappers::JsString(self.value))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::video_generation::types::Kv {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT kv",
+            &["key", "value"],
+            &[("key", "string"), ("value", "string")],
+            false,
+        )?;
+        let key: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "key",
+            "string",
+        )?;
+        let key: String = key.0;
+        let value: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "value",
+            "string",
+        )?;
+        let value: String = value.0;
+        Ok(Self { key, value })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::video_generation::types::GenerationConfig {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >(
+            "negativePrompt",
+            self.negative_prompt.map(|v| crate::wrappers::JsString(v)),
+        )?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::BigIntWrapper<u64>>,
+            >("seed", self.seed.map(|v| crate::wrappers::BigIntWrapper(v)))?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >("scheduler", self.scheduler.map(|v| crate::wrappers::JsString(v)))?;
+        obj.set::<_, Option<f32>>("guidanceScale", self.guidance_scale.map(|v| v))?;
+        obj.set::<
+                _,
+                Option<crate::bindings::golem::video_generation::types::AspectRatio>,
+            >("aspectRatio", self.aspect_ratio.map(|v| v))?;
+        obj.set::<_, Option<f32>>("durationSeconds", self.duration_seconds.map(|v| v))?;
+        obj.set::<
+                _,
+                Option<crate::bindings::golem::video_generation::types::Resolution>,
+            >("resolution", self.resolution.map(|v| v))?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >("model", self.model.map(|v| crate::wrappers::JsString(v)))?;
+        obj.set::<_, Option<bool>>("enableAudio", self.enable_audio.map(|v| v))?;
+        obj.set::<_, Option<bool>>("enhancePrompt", self.enhance_prompt.map(|v| v))?;
+        obj.set::<
+                _,
+                Option<
+                    crate::wrappers::JsList<
+                        crate::bindings::golem::video_generation::types::Kv,
+                    >,
+                >,
+            >(
+            "providerOptions",
+            self
+                .provider_options
+                .map(|v| crate::wrappers::JsList(
+                    v.into_iter().map(|v| v).collect::<Vec<_>>(),
+                )),
+        )?;
+        obj.set::<
+                _,
+                Option<crate::bindings::golem::video_generation::types::InputImage>,
+            >("lastframe", self.lastframe.map(|v| v))?;
+        obj.set::<
+                _,
+                Option<crate::bindings::golem::video_generation::types::StaticMask>,
+            >("staticMask", self.static_mask.map(|v| v))?;
+        obj.set::<
+                _,
+                Option<crate::bindings::golem::video_generation::types::DynamicMask>,
+            >("dynamicMask", self.dynamic_mask.map(|v| v))?;
+        obj.set::<
+                _,
+                Option<crate::bindings::golem::video_generation::types::CameraMovement>,
+            >("cameraControl", self.camera_control.map(|v| v))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::video_generation::types::GenerationConfig {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT generation-config",
+            &[
+                "negativePrompt",
+                "seed",
+                "scheduler",
+                "guidanceScale",
+                "aspectRatio",
+                "durationSeconds",
+                "resolution",
+                "model",
+                "enableAudio",
+                "enhancePrompt",
+                "providerOptions",
+                "lastframe",
+                "staticMask",
+                "dynamicMask",
+                "cameraControl",
+            ],
+            &[],
+            false,
+        )?;
+        let negative_prompt: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "negativePrompt",
+            "option<string>",
+        )?;
+        let negative_prompt: Option<String> = negative_prompt.map(|v| v.0);
+        let seed: Option<crate::wrappers::BigIntWrapper<u64>> = crate::wrappers::get_field(
+            &obj,
+            "seed",
+            "option<u64>",
+        )?;
+        let seed: Option<u64> = seed.map(|v| v.0);
+        let scheduler: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "scheduler",
+            "option<string>",
+        )?;
+        let scheduler: Option<String> = scheduler.map(|v| v.0);
+        let guidance_scale: Option<f32> = crate::wrappers::get_field(
+            &obj,
+            "guidanceScale",
+            "option<f32>",
+        )?;
+        let guidance_scale: Option<f32> = guidance_scale.map(|v| v);
+        let aspect_ratio: Option<
+            crate::bindings::golem::video_generation::types::AspectRatio,
+        > = crate::wrappers::get_field(&obj, "aspectRatio", "option<aspect-ratio>")?;
+        let aspect_ratio: Option<
+            crate::bindings::golem::video_generation::types::AspectRatio,
+        > = aspect_ratio.map(|v| v);
+        let duration_seconds: Option<f32> = crate::wrappers::get_field(
+            &obj,
+            "durationSeconds",
+            "option<f32>",
+        )?;
+        let duration_seconds: Option<f32> = duration_seconds.map(|v| v);
+        let resolution: Option<
+            crate::bindings::golem::video_generation::types::Resolution,
+        > = crate::wrappers::get_field(&obj, "resolution", "option<resolution>")?;
+        let resolution: Option<
+            crate::bindings::golem::video_generation::types::Resolution,
+        > = resolution.map(|v| v);
+        let model: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "model",
+            "option<string>",
+        )?;
+        let model: Option<String> = model.map(|v| v.0);
+        let enable_audio: Option<bool> = crate::wrappers::get_field(
+            &obj,
+            "enableAudio",
+            "option<bool>",
+        )?;
+        let enable_audio: Option<bool> = enable_audio.map(|v| v);
+        let enhance_prompt: Option<bool> = crate::wrappers::get_field(
+            &obj,
+            "enhancePrompt",
+            "option<bool>",
+        )?;
+        let enhance_prompt: Option<bool> = enhance_prompt.map(|v| v);
+        let provider_options: Option<
+            crate::wrappers::JsList<crate::bindings::golem::video_generation::types::Kv>,
+        > = crate::wrappers::get_field(&obj, "providerOptions", "option<list<kv>>")?;
+        let provider_options: Option<
+            Vec<crate::bindings::golem::video_generation::types::Kv>,
+        > = provider_options.map(|v| v.0.into_iter().map(|v| v).collect::<Vec<_>>());
+        let lastframe: Option<
+            crate::bindings::golem::video_generation::types::InputImage,
+        > = crate::wrappers::get_field(&obj, "lastframe", "option<input-image>")?;
+        let lastframe: Option<
+            crate::bindings::golem::video_generation::types::InputImage,
+        > = lastframe.map(|v| v);
+        let static_mask: Option<
+            crate::bindings::golem::video_generation::types::StaticMask,
+        > = crate::wrappers::get_field(&obj, "staticMask", "option<static-mask>")?;
+        let static_mask: Option<
+            crate::bindings::golem::video_generation::types::StaticMask,
+        > = static_mask.map(|v| v);
+        let dynamic_mask: Option<
+            crate::bindings::golem::video_generation::types::DynamicMask,
+        > = crate::wrappers::get_field(&obj, "dynamicMask", "option<dynamic-mask>")?;
+        let dynamic_mask: Option<
+            crate::bindings::golem::video_generation::types::DynamicMask,
+        > = dynamic_mask.map(|v| v);
+        let camera_control: Option<
+            crate::bindings::golem::video_generation::types::CameraMovement,
+        > = crate::wrappers::get_field(
+            &obj,
+            "cameraControl",
+            "option<camera-movement>",
+        )?;
+        let camera_control: Option<
+            crate::bindings::golem::video_generation::types::CameraMovement,
+        > = camera_control.map(|v| v);
+        Ok(Self {
+            negative_prompt,
+            seed,
+            scheduler,
+            guidance_scale,
+            aspect_ratio,
+            duration_seconds,
+            resolution,
+            model,
+            enable_audio,
+            enhance_prompt,
+            provider_options,
+            lastframe,
+            static_mask,
+            dynamic_mask,
+            camera_control,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::video_generation::types::Video {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >("uri", self.uri.map(|v| crate::wrappers::JsString(v)))?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::UInt8Array>,
+            >("base64Bytes", self.base64_bytes.map(|v| crate::wrappers::UInt8Array(v)))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("mimeType", crate::wrappers::JsString(self.mime_type))?;
+        obj.set::<_, Option<u32>>("width", self.width.map(|v| v))?;
+        obj.set::<_, Option<u32>>("height", self.height.map(|v| v))?;
+        obj.set::<_, Option<f32>>("fps", self.fps.map(|v| v))?;
+        obj.set::<_, Option<f32>>("durationSeconds", self.duration_seconds.map(|v| v))?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >("generationId", self.generation_id.map(|v| crate::wrappers::JsString(v)))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::video_generation::types::Video {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT video",
+            &[
+                "uri",
+                "base64Bytes",
+                "mimeType",
+                "width",
+                "height",
+                "fps",
+                "durationSeconds",
+                "generationId",
+            ],
+            &[("mimeType", "string")],
+            false,
+        )?;
+        let uri: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "uri",
+            "option<string>",
+        )?;
+        let uri: Option<String> = uri.map(|v| v.0);
+        let base64_bytes: Option<crate::wrappers::UInt8Array> = crate::wrappers::get_field(
+            &obj,
+            "base64Bytes",
+            "option<list<u8>>",
+        )?;
+        let base64_bytes: Option<Vec<u8>> = base64_bytes.map(|v| v.0);
+        let mime_type: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "mimeType",
+            "string",
+        )?;
+        let mime_type: String = mime_type.0;
+        let width: Option<u32> = crate::wrappers::get_field(
+            &obj,
+            "width",
+            "option<u32>",
+        )?;
+        let width: Option<u32> = width.map(|v| v);
+        let height: Option<u32> = crate::wrappers::get_field(
+            &obj,
+            "height",
+            "option<u32>",
+        )?;
+        let height: Option<u32> = height.map(|v| v);
+        let fps: Option<f32> = crate::wrappers::get_field(&obj, "fps", "option<f32>")?;
+        let fps: Option<f32> = fps.map(|v| v);
+        let duration_seconds: Option<f32> = crate::wrappers::get_field(
+            &obj,
+            "durationSeconds",
+            "option<f32>",
+        )?;
+        let duration_seconds: Option<f32> = duration_seconds.map(|v| v);
+        let generation_id: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "generationId",
+            "option<string>",
+        )?;
+        let generation_id: Option<String> = generation_id.map(|v| v.0);
+        Ok(Self {
+            uri,
+            base64_bytes,
+            mime_type,
+            width,
+            height,
+            fps,
+            duration_seconds,
+            generation_id,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::video_generation::types::JobStatus {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::video_generation::types::JobStatus::Pending => {
+                crate::wrappers::variant_case(ctx, "pending")
+            }
+            crate::bindings::golem::video_generation::types::JobStatus::Running => {
+                crate::wrappers::variant_case(ctx, "running")
+            }
+            crate::bindings::golem::video_generation::types::JobStatus::Succeeded => {
+                crate::wrappers::variant_case(ctx, "succeeded")
+            }
+            crate::bindings::golem::video_generation::types::JobStatus::Failed(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "failed", crate::wrappers::JsString(inner))
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::video_generation::types::JobStatus {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "pending" => {
+                Ok(crate::bindings::golem::video_generation::types::JobStatus::Pending)
+            }
+            "running" => {
+                Ok(crate::bindings::golem::video_generation::types::JobStatus::Running)
+            }
+            "succeeded" => {
+                Ok(crate::bindings::golem::video_generation::types::JobStatus::Succeeded)
+            }
+            "failed" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(
+                    crate::bindings::golem::video_generation::types::JobStatus::Failed(
+                        inner.0,
+                    ),
+                )
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS job-status",
+                        "WIT job-status",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::video_generation::types::VideoResult {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::golem::video_generation::types::JobStatus,
+            >("status", self.status)?;
+        obj.set::<
+                _,
+                Option<
+                    crate::wrappers::JsList<
+                        crate::bindings::golem::video_generation::types::Video,
+                    >,
+                >,
+            >(
+            "videos",
+            self
+                .videos
+                .map(|v| crate::wrappers::JsList(
+                    v.into_iter().map(|v| v).collect::<Vec<_>>(),
+                )),
+        )?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::video_generation::types::VideoResult {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT video-result",
+            &["status", "videos"],
+            &[("status", "job-status")],
+            false,
+        )?;
+        let status: crate::bindings::golem::video_generation::types::JobStatus = crate::wrappers::get_field(
+            &obj,
+            "status",
+            "job-status",
+        )?;
+        let status: crate::bindings::golem::video_generation::types::JobStatus = status;
+        let videos: Option<
+            crate::wrappers::JsList<
+                crate::bindings::golem::video_generation::types::Video,
+            >,
+        > = crate::wrappers::get_field(&obj, "videos", "option<list<video>>")?;
+        let videos: Option<
+            Vec<crate::bindings::golem::video_generation::types::Video>,
+        > = videos.map(|v| v.0.into_iter().map(|v| v).collect::<Vec<_>>());
+        Ok(Self { status, videos })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::video_generation::types::VoiceLanguage {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::video_generation::types::VoiceLanguage::En => {
+                "en".into_js(ctx)
+            }
+            crate::bindings::golem::video_generation::types::VoiceLanguage::Zh => {
+                "zh".into_js(ctx)
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::video_generation::types::VoiceLanguage {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let value = crate::wrappers::enum_case(
+            value,
+            "JS voice-language",
+            "WIT voice-language",
+        )?;
+        match value.as_str() {
+            "en" => {
+                Ok(crate::bindings::golem::video_generation::types::VoiceLanguage::En)
+            }
+            "zh" => {
+                Ok(crate::bindings::golem::video_generation::types::VoiceLanguage::Zh)
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_enum_case(
+                        "JS voice-language",
+                        "WIT voice-language",
+                        &value,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::video_generation::types::TextToSpeech {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("text", crate::wrappers::JsString(self.text))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("voiceId", crate::wrappers::JsString(self.voice_id))?;
+        obj.set::<
+                _,
+                crate::bindings::golem::video_generation::types::VoiceLanguage,
+            >("language", self.language)?;
+        obj.set::<_, f32>("speed", self.speed)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::video_generation::types::TextToSpeech {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT text-to-speech",
+            &["text", "voiceId", "language", "speed"],
+            &[
+                ("text", "string"),
+                ("voiceId", "string"),
+                ("language", "voice-language"),
+                ("speed", "f32"),
+            ],
+            false,
+        )?;
+        let text: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "text",
+            "string",
+        )?;
+        let text: String = text.0;
+        let voice_id: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "voiceId",
+            "string",
+        )?;
+        let voice_id: String = voice_id.0;
+        let language: crate::bindings::golem::video_generation::types::VoiceLanguage = crate::wrappers::get_field(
+            &obj,
+            "language",
+            "voice-language",
+        )?;
+        let language: crate::bindings::golem::video_generation::types::VoiceLanguage = language;
+        let speed: f32 = crate::wrappers::get_field(&obj, "speed", "f32")?;
+        let speed: f32 = speed;
+        Ok(Self {
+            text,
+            voice_id,
+            language,
+            speed,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::video_generation::types::AudioSource {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::video_generation::types::AudioSource::FromText(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::video_generation::types::TextToSpeech,
+                >(ctx, "from-text", inner)
+            }
+            crate::bindings::golem::video_generation::types::AudioSource::FromAudio(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::video_generation::types::Narration,
+                >(ctx, "from-audio", inner)
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::video_generation::types::AudioSource {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "from-text" => {
+                let inner: crate::bindings::golem::video_generation::types::TextToSpeech = crate::wrappers::variant_value(
+                    &obj,
+                    "text-to-speech",
+                )?;
+                Ok(
+                    crate::bindings::golem::video_generation::types::AudioSource::FromText(
+                        inner,
+                    ),
+                )
+            }
+            "from-audio" => {
+                let inner: crate::bindings::golem::video_generation::types::Narration = crate::wrappers::variant_value(
+                    &obj,
+                    "narration",
+                )?;
+                Ok(
+                    crate::bindings::golem::video_generation::types::AudioSource::FromAudio(
+                        inner,
+                    ),
+                )
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS audio-source",
+                        "WIT audio-source",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::video_generation::types::VoiceInfo {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("voiceId", crate::wrappers::JsString(self.voice_id))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("name", crate::wrappers::JsString(self.name))?;
+        obj.set::<
+                _,
+                crate::bindings::golem::video_generation::types::VoiceLanguage,
+            >("language", self.language)?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >("previewUrl", self.preview_url.map(|v| crate::wrappers::JsString(v)))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::video_generation::types::VoiceInfo {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT voice-info",
+            &["voiceId", "name", "language", "previewUrl"],
+            &[("voiceId", "string"), ("name", "string"), ("language", "voice-language")],
+            false,
+        )?;
+        let voice_id: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "voiceId",
+            "string",
+        )?;
+        let voice_id: String = voice_id.0;
+        let name: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "name",
+            "string",
+        )?;
+        let name: String = name.0;
+        let language: crate::bindings::golem::video_generation::types::VoiceLanguage = crate::wrappers::get_field(
+            &obj,
+            "language",
+            "voice-language",
+        )?;
+        let language: crate::bindings::golem::video_generation::types::VoiceLanguage = language;
+        let preview_url: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "previewUrl",
+            "option<string>",
+        )?;
+        let preview_url: Option<String> = preview_url.map(|v| v.0);
+        Ok(Self {
+            voice_id,
+            name,
+            language,
+            preview_url,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::video_generation::types::SingleImageEffects {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::video_generation::types::SingleImageEffects::Bloombloom => {
+                "bloombloom".into_js(ctx)
+            }
+            crate::bindings::golem::video_generation::types::SingleImageEffects::Dizzydizzy => {
+                "dizzydizzy".into_js(ctx)
+            }
+            crate::bindings::golem::video_generation::types::SingleImageEffects::Fuzzyfuzzy => {
+                "fuzzyfuzzy".into_js(ctx)
+            }
+            crate::bindings::golem::video_generation::types::SingleImageEffects::Squish => {
+                "squish".into_js(ctx)
+            }
+            crate::bindings::golem::video_generation::types::SingleImageEffects::Expansion => {
+                "expansion".into_js(ctx)
+            }
+            crate::bindings::golem::video_generation::types::SingleImageEffects::AnimeFigure => {
+                "anime-figure".into_js(ctx)
+            }
+            crate::bindings::golem::video_generation::types::SingleImageEffects::Rocketrocket => {
+                "rocketrocket".into_js(ctx)
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::video_generation::types::SingleImageEffects {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let value = crate::wrappers::enum_case(
+            value,
+            "JS single-image-effects",
+            "WIT single-image-effects",
+        )?;
+        match value.as_str() {
+            "bloombloom" => {
+                Ok(
+                    crate::bindings::golem::video_generation::types::SingleImageEffects::Bloombloom,
+                )
+            }
+            "dizzydizzy" => {
+                Ok(
+                    crate::bindings::golem::video_generation::types::SingleImageEffects::Dizzydizzy,
+                )
+            }
+            "fuzzyfuzzy" => {
+                Ok(
+                    crate::bindings::golem::video_generation::types::SingleImageEffects::Fuzzyfuzzy,
+                )
+            }
+            "squish" => {
+                Ok(
+                    crate::bindings::golem::video_generation::types::SingleImageEffects::Squish,
+                )
+            }
+            "expansion" => {
+                Ok(
+                    crate::bindings::golem::video_generation::types::SingleImageEffects::Expansion,
+                )
+            }
+            "anime-figure" => {
+                Ok(
+                    crate::bindings::golem::video_generation::types::SingleImageEffects::AnimeFigure,
+                )
+            }
+            "rocketrocket" => {
+                Ok(
+                    crate::bindings::golem::video_generation::types::SingleImageEffects::Rocketrocket,
+                )
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_enum_case(
+                        "JS single-image-effects",
+                        "WIT single-image-effects",
+                        &value,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::video_generation::types::DualImageEffects {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::video_generation::types::DualImageEffects::Hug => {
+                "hug".into_js(ctx)
+            }
+            crate::bindings::golem::video_generation::types::DualImageEffects::Kiss => {
+                "kiss".into_js(ctx)
+            }
+            crate::bindings::golem::video_generation::types::DualImageEffects::HeartGesture => {
+                "heart-gesture".into_js(ctx)
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::video_generation::types::DualImageEffects {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let value = crate::wrappers::enum_case(
+            value,
+            "JS dual-image-effects",
+            "WIT dual-image-effects",
+        )?;
+        match value.as_str() {
+            "hug" => {
+                Ok(
+                    crate::bindings::golem::video_generation::types::DualImageEffects::Hug,
+                )
+            }
+            "kiss" => {
+                Ok(
+                    crate::bindings::golem::video_generation::types::DualImageEffects::Kiss,
+                )
+            }
+            "heart-gesture" => {
+                Ok(
+                    crate::bindings::golem::video_generation::types::DualImageEffects::HeartGesture,
+                )
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_enum_case(
+                        "JS dual-image-effects",
+                        "WIT dual-image-effects",
+                        &value,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::video_generation::types::DualEffect {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::golem::video_generation::types::DualImageEffects,
+            >("effect", self.effect)?;
+        obj.set::<
+                _,
+                crate::bindings::golem::video_generation::types::InputImage,
+            >("secondImage", self.second_image)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::video_generation::types::DualEffect {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT dual-effect",
+            &["effect", "secondImage"],
+            &[("effect", "dual-image-effects"), ("secondImage", "input-image")],
+            false,
+        )?;
+        let effect: crate::bindings::golem::video_generation::types::DualImageEffects = crate::wrappers::get_field(
+            &obj,
+            "effect",
+            "dual-image-effects",
+        )?;
+        let effect: crate::bindings::golem::video_generation::types::DualImageEffects = effect;
+        let second_image: crate::bindings::golem::video_generation::types::InputImage = crate::wrappers::get_field(
+            &obj,
+            "secondImage",
+            "input-image",
+        )?;
+        let second_image: crate::bindings::golem::video_generation::types::InputImage = second_image;
+        Ok(Self { effect, second_image })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::video_generation::types::EffectType {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::video_generation::types::EffectType::Single(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::video_generation::types::SingleImageEffects,
+                >(ctx, "single", inner)
+            }
+            crate::bindings::golem::video_generation::types::EffectType::Dual(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::video_generation::types::DualEffect,
+                >(ctx, "dual", inner)
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::video_generation::types::EffectType {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "single" => {
+                let inner: crate::bindings::golem::video_generation::types::SingleImageEffects = crate::wrappers::variant_value(
+                    &obj,
+                    "single-image-effects",
+                )?;
+                Ok(
+                    crate::bindings::golem::video_generation::types::EffectType::Single(
+                        inner,
+                    ),
+                )
+            }
+            "dual" => {
+                let inner: crate::bindings::golem::video_generation::types::DualEffect = crate::wrappers::variant_value(
+                    &obj,
+                    "dual-effect",
+                )?;
+                Ok(
+                    crate::bindings::golem::video_generation::types::EffectType::Dual(
+                        inner,
+                    ),
+                )
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS effect-type",
+                        "WIT effect-type",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::video_generation::types::LipSyncVideo {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::video_generation::types::LipSyncVideo::Video(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::video_generation::types::BaseVideo,
+                >(ctx, "video", inner)
+            }
+            crate::bindings::golem::video_generation::types::LipSyncVideo::VideoId(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "video-id", crate::wrappers::JsString(inner))
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::video_generation::types::LipSyncVideo {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "video" => {
+                let inner: crate::bindings::golem::video_generation::types::BaseVideo = crate::wrappers::variant_value(
+                    &obj,
+                    "base-video",
+                )?;
+                Ok(
+                    crate::bindings::golem::video_generation::types::LipSyncVideo::Video(
+                        inner,
+                    ),
+                )
+            }
+            "video-id" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(
+                    crate::bindings::golem::video_generation::types::LipSyncVideo::VideoId(
+                        inner.0,
+                    ),
+                )
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS lip-sync-video",
+                        "WIT lip-sync-video",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::web_search::types::ImageResult {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("url", crate::wrappers::JsString(self.url))?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >("description", self.description.map(|v| crate::wrappers::JsString(v)))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::web_search::types::ImageResult {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT image-result",
+            &["url", "description"],
+            &[("url", "string")],
+            false,
+        )?;
+        let url: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "url",
+            "string",
+        )?;
+        let url: String = url.0;
+        let description: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "description",
+            "option<string>",
+        )?;
+        let description: Option<String> = description.map(|v| v.0);
+        Ok(Self { url, description })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::web_search::types::SearchResult {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("title", crate::wrappers::JsString(self.title))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("url", crate::wrappers::JsString(self.url))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("snippet", crate::wrappers::JsString(self.snippet))?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >("displayUrl", self.display_url.map(|v| crate::wrappers::JsString(v)))?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >("source", self.source.map(|v| crate::wrappers::JsString(v)))?;
+        obj.set::<_, Option<f64>>("score", self.score.map(|v| v))?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >("htmlSnippet", self.html_snippet.map(|v| crate::wrappers::JsString(v)))?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >(
+            "datePublished",
+            self.date_published.map(|v| crate::wrappers::JsString(v)),
+        )?;
+        obj.set::<
+                _,
+                Option<
+                    crate::wrappers::JsList<
+                        crate::bindings::golem::web_search::types::ImageResult,
+                    >,
+                >,
+            >(
+            "images",
+            self
+                .images
+                .map(|v| crate::wrappers::JsList(
+                    v.into_iter().map(|v| v).collect::<Vec<_>>(),
+                )),
+        )?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsList<crate::wrappers::JsString>>,
+            >(
+            "contentChunks",
+            self
+                .content_chunks
+                .map(|v| crate::wrappers::JsList(
+                    v
+                        .into_iter()
+                        .map(|v| crate::wrappers::JsString(v))
+                        .collect::<Vec<_>>(),
+                )),
+        )?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::web_search::types::SearchResult {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT search-result",
+            &[
+                "title",
+                "url",
+                "snippet",
+                "displayUrl",
+                "source",
+                "score",
+                "htmlSnippet",
+                "datePublished",
+                "images",
+                "contentChunks",
+            ],
+            &[("title", "string"), ("url", "string"), ("snippet", "string")],
+            false,
+        )?;
+        let title: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "title",
+            "string",
+        )?;
+        let title: String = title.0;
+        let url: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "url",
+            "string",
+        )?;
+        let url: String = url.0;
+        let snippet: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "snippet",
+            "string",
+        )?;
+        let snippet: String = snippet.0;
+        let display_url: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "displayUrl",
+            "option<string>",
+        )?;
+        let display_url: Option<String> = display_url.map(|v| v.0);
+        let source: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "source",
+            "option<string>",
+        )?;
+        let source: Option<String> = source.map(|v| v.0);
+        let score: Option<f64> = crate::wrappers::get_field(
+            &obj,
+            "score",
+            "option<f64>",
+        )?;
+        let score: Option<f64> = score.map(|v| v);
+        let html_snippet: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "htmlSnippet",
+            "option<string>",
+        )?;
+        let html_snippet: Option<String> = html_snippet.map(|v| v.0);
+        let date_published: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "datePublished",
+            "option<string>",
+        )?;
+        let date_published: Option<String> = date_published.map(|v| v.0);
+        let images: Option<
+            crate::wrappers::JsList<
+                crate::bindings::golem::web_search::types::ImageResult,
+            >,
+        > = crate::wrappers::get_field(&obj, "images", "option<list<image-result>>")?;
+        let images: Option<
+            Vec<crate::bindings::golem::web_search::types::ImageResult>,
+        > = images.map(|v| v.0.into_iter().map(|v| v).collect::<Vec<_>>());
+        let content_chunks: Option<crate::wrappers::JsList<crate::wrappers::JsString>> = crate::wrappers::get_field(
+            &obj,
+            "contentChunks",
+            "option<list<string>>",
+        )?;
+        let content_chunks: Option<Vec<String>> = content_chunks
+            .map(|v| v.0.into_iter().map(|v| v.0).collect::<Vec<_>>());
+        Ok(Self {
+            title,
+            url,
+            snippet,
+            display_url,
+            source,
+            score,
+            html_snippet,
+            date_published,
+            images,
+            content_chunks,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::web_search::types::SafeSearchLevel {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::web_search::types::SafeSearchLevel::Off => {
+                "off".into_js(ctx)
+            }
+            crate::bindings::golem::web_search::types::SafeSearchLevel::Medium => {
+                "medium".into_js(ctx)
+            }
+            crate::bindings::golem::web_search::types::SafeSearchLevel::High => {
+                "high".into_js(ctx)
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::web_search::types::SafeSearchLevel {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let value = crate::wrappers::enum_case(
+            value,
+            "JS safe-search-level",
+            "WIT safe-search-level",
+        )?;
+        match value.as_str() {
+            "off" => Ok(crate::bindings::golem::web_search::types::SafeSearchLevel::Off),
+            "medium" => {
+                Ok(crate::bindings::golem::web_search::types::SafeSearchLevel::Medium)
+            }
+            "high" => {
+                Ok(crate::bindings::golem::web_search::types::SafeSearchLevel::High)
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_enum_case(
+                        "JS safe-search-level",
+                        "WIT safe-search-level",
+                        &value,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::web_search::types::RateLimitInfo {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<_, u32>("limit", self.limit)?;
+        obj.set::<_, u32>("remaining", self.remaining)?;
+        obj.set::<
+                _,
+                crate::wrappers::BigIntWrapper<u64>,
+            >("resetTimestamp", crate::wrappers::BigIntWrapper(self.reset_timestamp))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::web_search::types::RateLimitInfo {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT rate-limit-info",
+            &["limit", "remaining", "resetTimestamp"],
+            &[("limit", "u32"), ("remaining", "u32"), ("resetTimestamp", "u64")],
+            false,
+        )?;
+        let limit: u32 = crate::wrappers::get_field(&obj, "limit", "u32")?;
+        let limit: u32 = limit;
+        let remaining: u32 = crate::wrappers::get_field(&obj, "remaining", "u32")?;
+        let remaining: u32 = remaining;
+        let reset_timestamp: crate::wrappers::BigIntWrapper<u64> = crate::wrappers::get_field(
+            &obj,
+            "resetTimestamp",
+            "u64",
+        )?;
+        let reset_timestamp: u64 = reset_timestamp.0;
+        Ok(Self {
+            limit,
+            remaining,
+            reset_timestamp,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::web_search::types::SearchMetadata {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("query", crate::wrappers::JsString(self.query))?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::BigIntWrapper<u64>>,
+            >(
+            "totalResults",
+            self.total_results.map(|v| crate::wrappers::BigIntWrapper(v)),
+        )?;
+        obj.set::<_, Option<f64>>("searchTimeMs", self.search_time_ms.map(|v| v))?;
+        obj.set::<
+                _,
+                Option<crate::bindings::golem::web_search::types::SafeSearchLevel>,
+            >("safeSearch", self.safe_search.map(|v| v))?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >("language", self.language.map(|v| crate::wrappers::JsString(v)))?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >("region", self.region.map(|v| crate::wrappers::JsString(v)))?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >(
+            "nextPageToken",
+            self.next_page_token.map(|v| crate::wrappers::JsString(v)),
+        )?;
+        obj.set::<
+                _,
+                Option<crate::bindings::golem::web_search::types::RateLimitInfo>,
+            >("rateLimits", self.rate_limits.map(|v| v))?;
+        obj.set::<_, u32>("currentPage", self.current_page)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::web_search::types::SearchMetadata {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT search-metadata",
+            &[
+                "query",
+                "totalResults",
+                "searchTimeMs",
+                "safeSearch",
+                "language",
+                "region",
+                "nextPageToken",
+                "rateLimits",
+                "currentPage",
+            ],
+            &[("query", "string"), ("currentPage", "u32")],
+            false,
+        )?;
+        let query: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "query",
+            "string",
+        )?;
+        let query: String = query.0;
+        let total_results: Option<crate::wrappers::BigIntWrapper<u64>> = crate::wrappers::get_field(
+            &obj,
+            "totalResults",
+            "option<u64>",
+        )?;
+        let total_results: Option<u64> = total_results.map(|v| v.0);
+        let search_time_ms: Option<f64> = crate::wrappers::get_field(
+            &obj,
+            "searchTimeMs",
+            "option<f64>",
+        )?;
+        let search_time_ms: Option<f64> = search_time_ms.map(|v| v);
+        let safe_search: Option<
+            crate::bindings::golem::web_search::types::SafeSearchLevel,
+        > = crate::wrappers::get_field(&obj, "safeSearch", "option<safe-search-level>")?;
+        let safe_search: Option<
+            crate::bindings::golem::web_search::types::SafeSearchLevel,
+        > = safe_search.map(|v| v);
+        let language: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "language",
+            "option<string>",
+        )?;
+        let language: Option<String> = language.map(|v| v.0);
+        let region: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "region",
+            "option<string>",
+        )?;
+        let region: Option<String> = region.map(|v| v.0);
+        let next_page_token: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "nextPageToken",
+            "option<string>",
+        )?;
+        let next_page_token: Option<String> = next_page_token.map(|v| v.0);
+        let rate_limits: Option<
+            crate::bindings::golem::web_search::types::RateLimitInfo,
+        > = crate::wrappers::get_field(&obj, "rateLimits", "option<rate-limit-info>")?;
+        let rate_limits: Option<
+            crate::bindings::golem::web_search::types::RateLimitInfo,
+        > = rate_limits.map(|v| v);
+        let current_page: u32 = crate::wrappers::get_field(&obj, "currentPage", "u32")?;
+        let current_page: u32 = current_page;
+        Ok(Self {
+            query,
+            total_results,
+            search_time_ms,
+            safe_search,
+            language,
+            region,
+            next_page_token,
+            rate_limits,
+            current_page,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::web_search::types::TimeRange {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::web_search::types::TimeRange::Day => {
+                "day".into_js(ctx)
+            }
+            crate::bindings::golem::web_search::types::TimeRange::Week => {
+                "week".into_js(ctx)
+            }
+            crate::bindings::golem::web_search::types::TimeRange::Month => {
+                "month".into_js(ctx)
+            }
+            crate::bindings::golem::web_search::types::TimeRange::Year => {
+                "year".into_js(ctx)
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::web_search::types::TimeRange {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let value = crate::wrappers::enum_case(
+            value,
+            "JS time-range",
+            "WIT time-range",
+        )?;
+        match value.as_str() {
+            "day" => Ok(crate::bindings::golem::web_search::types::TimeRange::Day),
+            "week" => Ok(crate::bindings::golem::web_search::types::TimeRange::Week),
+            "month" => Ok(crate::bindings::golem::web_search::types::TimeRange::Month),
+            "year" => Ok(crate::bindings::golem::web_search::types::TimeRange::Year),
+            _ => {
+                Err(
+                    crate::wrappers::unknown_enum_case(
+                        "JS time-range",
+                        "WIT time-range",
+                        &value,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::web_search::types::SearchParams {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("query", crate::wrappers::JsString(self.query))?;
+        obj.set::<
+                _,
+                Option<crate::bindings::golem::web_search::types::SafeSearchLevel>,
+            >("safeSearch", self.safe_search.map(|v| v))?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >("language", self.language.map(|v| crate::wrappers::JsString(v)))?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >("region", self.region.map(|v| crate::wrappers::JsString(v)))?;
+        obj.set::<_, Option<u32>>("maxResults", self.max_results.map(|v| v))?;
+        obj.set::<
+                _,
+                Option<crate::bindings::golem::web_search::types::TimeRange>,
+            >("timeRange", self.time_range.map(|v| v))?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsList<crate::wrappers::JsString>>,
+            >(
+            "includeDomains",
+            self
+                .include_domains
+                .map(|v| crate::wrappers::JsList(
+                    v
+                        .into_iter()
+                        .map(|v| crate::wrappers::JsString(v))
+                        .collect::<Vec<_>>(),
+                )),
+        )?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsList<crate::wrappers::JsString>>,
+            >(
+            "excludeDomains",
+            self
+                .exclude_domains
+                .map(|v| crate::wrappers::JsList(
+                    v
+                        .into_iter()
+                        .map(|v| crate::wrappers::JsString(v))
+                        .collect::<Vec<_>>(),
+                )),
+        )?;
+        obj.set::<_, Option<bool>>("includeImages", self.include_images.map(|v| v))?;
+        obj.set::<_, Option<bool>>("includeHtml", self.include_html.map(|v| v))?;
+        obj.set::<_, Option<bool>>("advancedAnswer", self.advanced_answer.map(|v| v))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::web_search::types::SearchParams {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT search-params",
+            &[
+                "query",
+                "safeSearch",
+                "language",
+                "region",
+                "maxResults",
+                "timeRange",
+                "includeDomains",
+                "excludeDomains",
+                "includeImages",
+                "includeHtml",
+                "advancedAnswer",
+            ],
+            &[("query", "string")],
+            false,
+        )?;
+        let query: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "query",
+            "string",
+        )?;
+        let query: String = query.0;
+        let safe_search: Option<
+            crate::bindings::golem::web_search::types::SafeSearchLevel,
+        > = crate::wrappers::get_field(&obj, "safeSearch", "option<safe-search-level>")?;
+        let safe_search: Option<
+            crate::bindings::golem::web_search::types::SafeSearchLevel,
+        > = safe_search.map(|v| v);
+        let language: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "language",
+            "option<string>",
+        )?;
+        let language: Option<String> = language.map(|v| v.0);
+        let region: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "region",
+            "option<string>",
+        )?;
+        let region: Option<String> = region.map(|v| v.0);
+        let max_results: Option<u32> = crate::wrappers::get_field(
+            &obj,
+            "maxResults",
+            "option<u32>",
+        )?;
+        let max_results: Option<u32> = max_results.map(|v| v);
+        let time_range: Option<crate::bindings::golem::web_search::types::TimeRange> = crate::wrappers::get_field(
+            &obj,
+            "timeRange",
+            "option<time-range>",
+        )?;
+        let time_range: Option<crate::bindings::golem::web_search::types::TimeRange> = time_range
+            .map(|v| v);
+        let include_domains: Option<
+            crate::wrappers::JsList<crate::wrappers::JsString>,
+        > = crate::wrappers::get_field(&obj, "includeDomains", "option<list<string>>")?;
+        let include_domains: Option<Vec<String>> = include_domains
+            .map(|v| v.0.into_iter().map(|v| v.0).collect::<Vec<_>>());
+        let exclude_domains: Option<
+            crate::wrappers::JsList<crate::wrappers::JsString>,
+        > = crate::wrappers::get_field(&obj, "excludeDomains", "option<list<string>>")?;
+        let exclude_domains: Option<Vec<String>> = exclude_domains
+            .map(|v| v.0.into_iter().map(|v| v.0).collect::<Vec<_>>());
+        let include_images: Option<bool> = crate::wrappers::get_field(
+            &obj,
+            "includeImages",
+            "option<bool>",
+        )?;
+        let include_images: Option<bool> = include_images.map(|v| v);
+        let include_html: Option<bool> = crate::wrappers::get_field(
+            &obj,
+            "includeHtml",
+            "option<bool>",
+        )?;
+        let include_html: Option<bool> = include_html.map(|v| v);
+        let advanced_answer: Option<bool> = crate::wrappers::get_field(
+            &obj,
+            "advancedAnswer",
+            "option<bool>",
+        )?;
+        let advanced_answer: Option<bool> = advanced_answer.map(|v| v);
+        Ok(Self {
+            query,
+            safe_search,
+            language,
+            region,
+            max_results,
+            time_range,
+            include_domains,
+            exclude_domains,
+            include_images,
+            include_html,
+            advanced_answer,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::web_search::types::SearchError {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::web_search::types::SearchError::InvalidQuery => {
+                crate::wrappers::variant_case(ctx, "invalid-query")
+            }
+            crate::bindings::golem::web_search::types::SearchError::RateLimited(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    u32,
+                >(ctx, "rate-limited", inner)
+            }
+            crate::bindings::golem::web_search::types::SearchError::UnsupportedFeature(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "unsupported-feature", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::web_search::types::SearchError::BackendError(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "backend-error", crate::wrappers::JsString(inner))
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::web_search::types::SearchError {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "invalid-query" => {
+                Ok(crate::bindings::golem::web_search::types::SearchError::InvalidQuery)
+            }
+            "rate-limited" => {
+                let inner: u32 = crate::wrappers::variant_value(&obj, "u32")?;
+                Ok(
+                    crate::bindings::golem::web_search::types::SearchError::RateLimited(
+                        inner,
+                    ),
+                )
+            }
+            "unsupported-feature" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(
+                    crate::bindings::golem::web_search::types::SearchError::UnsupportedFeature(
+                        inner.0,
+                    ),
+                )
+            }
+            "backend-error" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(
+                    crate::bindings::golem::web_search::types::SearchError::BackendError(
+                        inner.0,
+                    ),
+                )
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS search-error",
+                        "WIT search-error",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::wasi::blobstore::types::ContainerMetadata {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("name", crate::wrappers::JsString(self.name))?;
+        obj.set::<
+                _,
+                crate::wrappers::BigIntWrapper<u64>,
+            >("createdAt", crate::wrappers::BigIntWrapper(self.created_at))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::wasi::blobstore::types::ContainerMetadata {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT container-metadata",
+            &["name", "createdAt"],
+            &[("name", "container-name"), ("createdAt", "timestamp")],
+            false,
+        )?;
+        let name: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "name",
+            "container-name",
+        )?;
+        let name: crate::bindings::wasi::blobstore::types::ContainerName = name.0;
+        let created_at: crate::wrappers::BigIntWrapper<u64> = crate::wrappers::get_field(
+            &obj,
+            "createdAt",
+            "timestamp",
+        )?;
+        let created_at: crate::bindings::wasi::blobstore::types::Timestamp = created_at
+            .0;
+        Ok(Self { name, created_at })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::wasi::blobstore::types::ObjectMetadata {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("name", crate::wrappers::JsString(self.name))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("container", crate::wrappers::JsString(self.container))?;
+        obj.set::<
+                _,
+                crate::wrappers::BigIntWrapper<u64>,
+            >("createdAt", crate::wrappers::BigIntWrapper(self.created_at))?;
+        obj.set::<
+                _,
+                crate::wrappers::BigIntWrapper<u64>,
+            >("size", crate::wrappers::BigIntWrapper(self.size))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::wasi::blobstore::types::ObjectMetadata {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT object-metadata",
+            &["name", "container", "createdAt", "size"],
+            &[
+                ("name", "object-name"),
+                ("container", "container-name"),
+                ("createdAt", "timestamp"),
+                ("size", "object-size"),
+            ],
+            false,
+        )?;
+        let name: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "name",
+            "object-name",
+        )?;
+        let name: crate::bindings::wasi::blobstore::types::ObjectName = name.0;
+        let container: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "container",
+            "container-name",
+        )?;
+        let container: crate::bindings::wasi::blobstore::types::ContainerName = container
+            .0;
+        let created_at: crate::wrappers::BigIntWrapper<u64> = crate::wrappers::get_field(
+            &obj,
+            "createdAt",
+            "timestamp",
+        )?;
+        let created_at: crate::bindings::wasi::blobstore::types::Timestamp = created_at
+            .0;
+        let size: crate::wrappers::BigIntWrapper<u64> = crate::wrappers::get_field(
+            &obj,
+            "size",
+            "object-size",
+        )?;
+        let size: crate::bindings::wasi::blobstore::types::ObjectSize = size.0;
+        Ok(Self {
+            name,
+            container,
+            created_at,
+            size,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::wasi::blobstore::types::ObjectId {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("container", crate::wrappers::JsString(self.container))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("object", crate::wrappers::JsString(self.object))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::wasi::blobstore::types::ObjectId {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT object-id",
+            &["container", "object"],
+            &[("container", "container-name"), ("object", "object-name")],
+            false,
+        )?;
+        let container: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "container",
+            "container-name",
+        )?;
+        let container: crate::bindings::wasi::blobstore::types::ContainerName = container
+            .0;
+        let object: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "object",
+            "object-name",
+        )?;
+        let object: crate::bindings::wasi::blobstore::types::ObjectName = object.0;
+        Ok(Self { container, object })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::wasi::filesystem::types::DescriptorType {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::wasi::filesystem::types::DescriptorType::Unknown => {
+                "unknown".into_js(ctx)
+            }
+            crate::bindings::wasi::filesystem::types::DescriptorType::BlockDevice => {
+                "block-device".into_js(ctx)
+            }
+            crate::bindings::wasi::filesystem::types::DescriptorType::CharacterDevice => {
+                "character-device".into_js(ctx)
+            }
+            crate::bindings::wasi::filesystem::types::DescriptorType::Directory => {
+                "directory".into_js(ctx)
+            }
+            crate::bindings::wasi::filesystem::types::DescriptorType::Fifo => {
+                "fifo".into_js(ctx)
+            }
+            crate::bindings::wasi::filesystem::types::DescriptorType::SymbolicLink => {
+                "symbolic-link".into_js(ctx)
+            }
+            crate::bindings::wasi::filesystem::types::DescriptorType::RegularFile => {
+                "regular-file".into_js(ctx)
+            }
+            crate::bindings::wasi::filesystem::types::DescriptorType::Socket => {
+                "socket".into_js(ctx)
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::wasi::filesystem::types::DescriptorType {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let value = crate::wrappers::enum_case(
+            value,
+            "JS descriptor-type",
+            "WIT descriptor-type",
+        )?;
+        match value.as_str() {
+            "unknown" => {
+                Ok(crate::bindings::wasi::filesystem::types::DescriptorType::Unknown)
+            }
+            "block-device" => {
+                Ok(crate::bindings::wasi::filesystem::types::DescriptorType::BlockDevice)
+            }
+            "character-device" => {
+                Ok(
+                    crate::bindings::wasi::filesystem::types::DescriptorType::CharacterDevice,
+                )
+            }
+            "directory" => {
+                Ok(crate::bindings::wasi::filesystem::types::DescriptorType::Directory)
+            }
+            "fifo" => Ok(crate::bindings::wasi::filesystem::types::DescriptorType::Fifo),
+            "symbolic-link" => {
+                Ok(
+                    crate::bindings::wasi::filesystem::types::DescriptorType::SymbolicLink,
+                )
+            }
+            "regular-file" => {
+                Ok(crate::bindings::wasi::filesystem::types::DescriptorType::RegularFile)
+            }
+            "socket" => {
+                Ok(crate::bindings::wasi::filesystem::types::DescriptorType::Socket)
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_enum_case(
+                        "JS descriptor-type",
+                        "WIT descriptor-type",
+                        &value,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::wasi::filesystem::types::DescriptorFlags {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set(
+            "read",
+            self & crate::bindings::wasi::filesystem::types::DescriptorFlags::READ
+                == crate::bindings::wasi::filesystem::types::DescriptorFlags::READ,
+        )?;
+        obj.set(
+            "write",
+            self & crate::bindings::wasi::filesystem::types::DescriptorFlags::WRITE
+                == crate::bindings::wasi::filesystem::types::DescriptorFlags::WRITE,
+        )?;
+        obj.set(
+            "fileIntegritySync",
+            self
+                & crate::bindings::wasi::filesystem::types::DescriptorFlags::FILE_INTEGRITY_SYNC
+                == crate::bindings::wasi::filesystem::types::DescriptorFlags::FILE_INTEGRITY_SYNC,
+        )?;
+        obj.set(
+            "dataIntegritySync",
+            self
+                & crate::bindings::wasi::filesystem::types::DescriptorFlags::DATA_INTEGRITY_SYNC
+                == crate::bindings::wasi::filesystem::types::DescriptorFlags::DATA_INTEGRITY_SYNC,
+        )?;
+        obj.set(
+            "requestedWriteSync",
+            self
+                & crate::bindings::wasi::filesystem::types::DescriptorFlags::REQUESTED_WRITE_SYNC
+                == crate::bindings::wasi::filesystem::types::DescriptorFlags::REQUESTED_WRITE_SYNC,
+        )?;
+        obj.set(
+            "mutateDirectory",
+            self
+                & crate::bindings::wasi::filesystem::types::DescriptorFlags::MUTATE_DIRECTORY
+                == crate::bindings::wasi::filesystem::types::DescriptorFlags::MUTATE_DIRECTORY,
+        )?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::wasi::filesystem::types::DescriptorFlags {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        let mut result = crate::bindings::wasi::filesystem::types::DescriptorFlags::empty();
+        if obj.get("read")? {
+            result |= crate::bindings::wasi::filesystem::types::DescriptorFlags::READ;
+        }
+        if obj.get("write")? {
+            result |= crate::bindings::wasi::filesystem::types::DescriptorFlags::WRITE;
+        }
+        if obj.get("fileIntegritySync")? {
+            result
+                |= crate::bindings::wasi::filesystem::types::DescriptorFlags::FILE_INTEGRITY_SYNC;
+        }
+        if obj.get("dataIntegritySync")? {
+            result
+                |= crate::bindings::wasi::filesystem::types::DescriptorFlags::DATA_INTEGRITY_SYNC;
+        }
+        if obj.get("requestedWriteSync")? {
+            result
+                |= crate::bindings::wasi::filesystem::types::DescriptorFlags::REQUESTED_WRITE_SYNC;
+        }
+        if obj.get("mutateDirectory")? {
+            result
+                |= crate::bindings::wasi::filesystem::types::DescriptorFlags::MUTATE_DIRECTORY;
+        }
+        Ok(result)
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::wasi::filesystem::types::PathFlags {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set(
+            "symlinkFollow",
+            self & crate::bindings::wasi::filesystem::types::PathFlags::SYMLINK_FOLLOW
+                == crate::bindings::wasi::filesystem::types::PathFlags::SYMLINK_FOLLOW,
+        )?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::wasi::filesystem::types::PathFlags {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        let mut result = crate::bindings::wasi::filesystem::types::PathFlags::empty();
+        if obj.get("symlinkFollow")? {
+            result
+                |= crate::bindings::wasi::filesystem::types::PathFlags::SYMLINK_FOLLOW;
+        }
+        Ok(result)
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::wasi::filesystem::types::OpenFlags {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set(
+            "create",
+            self & crate::bindings::wasi::filesystem::types::OpenFlags::CREATE
+                == crate::bindings::wasi::filesystem::types::OpenFlags::CREATE,
+        )?;
+        obj.set(
+            "directory",
+            self & crate::bindings::wasi::filesystem::types::OpenFlags::DIRECTORY
+                == crate::bindings::wasi::filesystem::types::OpenFlags::DIRECTORY,
+        )?;
+        obj.set(
+            "exclusive",
+            self & crate::bindings::wasi::filesystem::types::OpenFlags::EXCLUSIVE
+                == crate::bindings::wasi::filesystem::types::OpenFlags::EXCLUSIVE,
+        )?;
+        obj.set(
+            "truncate",
+            self & crate::bindings::wasi::filesystem::types::OpenFlags::TRUNCATE
+                == crate::bindings::wasi::filesystem::types::OpenFlags::TRUNCATE,
+        )?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::wasi::filesystem::types::OpenFlags {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        let mut result = crate::bindings::wasi::filesystem::types::OpenFlags::empty();
+        if obj.get("create")? {
+            result |= crate::bindings::wasi::filesystem::types::OpenFlags::CREATE;
+        }
+        if obj.get("directory")? {
+            result |= crate::bindings::wasi::filesystem::types::OpenFlags::DIRECTORY;
+        }
+        if obj.get("exclusive")? {
+            result |= crate::bindings::wasi::filesystem::types::OpenFlags::EXCLUSIVE;
+        }
+        if obj.get("truncate")? {
+            result |= crate::bindings::wasi::filesystem::types::OpenFlags::TRUNCATE;
+        }
+        Ok(result)
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::wasi::filesystem::types::DescriptorStat {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::wasi::filesystem::types::DescriptorType,
+            >("type", self.type_)?;
+        obj.set::<
+                _,
+                crate::wrappers::BigIntWrapper<u64>,
+            >("linkCount", crate::wrappers::BigIntWrapper(self.link_count))?;
+        obj.set::<
+                _,
+                crate::wrappers::BigIntWrapper<u64>,
+            >("size", crate::wrappers::BigIntWrapper(self.size))?;
+        obj.set::<
+                _,
+                Option<crate::bindings::wasi::clocks::wall_clock::Datetime>,
+            >("dataAccessTimestamp", self.data_access_timestamp.map(|v| v))?;
+        obj.set::<
+                _,
+                Option<crate::bindings::wasi::clocks::wall_clock::Datetime>,
+            >("dataModificationTimestamp", self.data_modification_timestamp.map(|v| v))?;
+        obj.set::<
+                _,
+                Option<crate::bindings::wasi::clocks::wall_clock::Datetime>,
+            >("statusChangeTimestamp", self.status_change_timestamp.map(|v| v))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::wasi::filesystem::types::DescriptorStat {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT descriptor-stat",
+            &[
+                "type",
+                "linkCount",
+                "size",
+                "dataAccessTimestamp",
+                "dataModificationTimestamp",
+                "statusChangeTimestamp",
+            ],
+            &[
+                ("type", "descriptor-type"),
+                ("linkCount", "link-count"),
+                ("size", "filesize"),
+            ],
+            false,
+        )?;
+        let type_: crate::bindings::wasi::filesystem::types::DescriptorType = crate::wrappers::get_field(
+            &obj,
+            "type",
+            "descriptor-type",
+        )?;
+        let type_: crate::bindings::wasi::filesystem::types::DescriptorType = type_;
+        let link_count: crate::wrappers::BigIntWrapper<u64> = crate::wrappers::get_field(
+            &obj,
+            "linkCount",
+            "link-count",
+        )?;
+        let link_count: crate::bindings::wasi::filesystem::types::LinkCount = link_count
+            .0;
+        let size: crate::wrappers::BigIntWrapper<u64> = crate::wrappers::get_field(
+            &obj,
+            "size",
+            "filesize",
+        )?;
+        let size: crate::bindings::wasi::filesystem::types::Filesize = size.0;
+        let data_access_timestamp: Option<
+            crate::bindings::wasi::clocks::wall_clock::Datetime,
+        > = crate::wrappers::get_field(&obj, "dataAccessTimestamp", "option<datetime>")?;
+        let data_access_timestamp: Option<
+            crate::bindings::wasi::filesystem::types::Datetime,
+        > = data_access_timestamp.map(|v| v);
+        let data_modification_timestamp: Option<
+            crate::bindings::wasi::clocks::wall_clock::Datetime,
+        > = crate::wrappers::get_field(
+            &obj,
+            "dataModificationTimestamp",
+            "option<datetime>",
+        )?;
+        let data_modification_timestamp: Option<
+            crate::bindings::wasi::filesystem::types::Datetime,
+        > = data_modification_timestamp.map(|v| v);
+        let status_change_timestamp: Option<
+            crate::bindings::wasi::clocks::wall_clock::Datetime,
+        > = crate::wrappers::get_field(
+            &obj,
+            "statusChangeTimestamp",
+            "option<datetime>",
+        )?;
+        let status_change_timestamp: Option<
+            crate::bindings::wasi::filesystem::types::Datetime,
+        > = status_change_timestamp.map(|v| v);
+        Ok(Self {
+            type_,
+            link_count,
+            size,
+            data_access_timestamp,
+            data_modification_timestamp,
+            status_change_timestamp,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::wasi::filesystem::types::NewTimestamp {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::wasi::filesystem::types::NewTimestamp::NoChange => {
+                crate::wrappers::variant_case(ctx, "no-change")
+            }
+            crate::bindings::wasi::filesystem::types::NewTimestamp::Now => {
+                crate::wrappers::variant_case(ctx, "now")
+            }
+            crate::bindings::wasi::filesystem::types::NewTimestamp::Timestamp(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::wasi::clocks::wall_clock::Datetime,
+                >(ctx, "timestamp", inner)
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::wasi::filesystem::types::NewTimestamp {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "no-change" => {
+                Ok(crate::bindings::wasi::filesystem::types::NewTimestamp::NoChange)
+            }
+            "now" => Ok(crate::bindings::wasi::filesystem::types::NewTimestamp::Now),
+            "timestamp" => {
+                let inner: crate::bindings::wasi::clocks::wall_clock::Datetime = crate::wrappers::variant_value(
+                    &obj,
+                    "datetime",
+                )?;
+                Ok(
+                    crate::bindings::wasi::filesystem::types::NewTimestamp::Timestamp(
+                        inner,
+                    ),
+                )
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS new-timestamp",
+                        "WIT new-timestamp",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::wasi::filesystem::types::DirectoryEntry {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::wasi::filesystem::types::DescriptorType,
+            >("type", self.type_)?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("name", crate::wrappers::JsString(self.name))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::wasi::filesystem::types::DirectoryEntry {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT directory-entry",
+            &["type", "name"],
+            &[("type", "descriptor-type"), ("name", "string")],
+            false,
+        )?;
+        let type_: crate::bindings::wasi::filesystem::types::DescriptorType = crate::wrappers::get_field(
+            &obj,
+            "type",
+            "descriptor-type",
+        )?;
+        let type_: crate::bindings::wasi::filesystem::types::DescriptorType = type_;
+        let name: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "name",
+            "string",
+        )?;
+        let name: String = name.0;
+        Ok(Self { type_, name })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::wasi::filesystem::types::ErrorCode {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::wasi::filesystem::types::ErrorCode::Access => {
+                "access".into_js(ctx)
+            }
+            crate::bindings::wasi::filesystem::types::ErrorCode::WouldBlock => {
+                "would-block".into_js(ctx)
+            }
+            crate::bindings::wasi::filesystem::types::ErrorCode::Already => {
+                "already".into_js(ctx)
+            }
+            crate::bindings::wasi::filesystem::types::ErrorCode::BadDescriptor => {
+                "bad-descriptor".into_js(ctx)
+            }
+            crate::bindings::wasi::filesystem::types::ErrorCode::Busy => {
+                "busy".into_js(ctx)
+            }
+            crate::bindings::wasi::filesystem::types::ErrorCode::Deadlock => {
+                "deadlock".into_js(ctx)
+            }
+            crate::bindings::wasi::filesystem::types::ErrorCode::Quota => {
+                "quota".into_js(ctx)
+            }
+            crate::bindings::wasi::filesystem::types::ErrorCode::Exist => {
+                "exist".into_js(ctx)
+            }
+            crate::bindings::wasi::filesystem::types::ErrorCode::FileTooLarge => {
+                "file-too-large".into_js(ctx)
+            }
+            crate::bindings::wasi::filesystem::types::ErrorCode::IllegalByteSequence => {
+                "illegal-byte-sequence".into_js(ctx)
+            }
+            crate::bindings::wasi::filesystem::types::ErrorCode::InProgress => {
+                "in-progress".into_js(ctx)
+            }
+            crate::bindings::wasi::filesystem::types::ErrorCode::Interrupted => {
+                "interrupted".into_js(ctx)
+            }
+            crate::bindings::wasi::filesystem::types::ErrorCode::Invalid => {
+                "invalid".into_js(ctx)
+            }
+            crate::bindings::wasi::filesystem::types::ErrorCode::Io => "io".into_js(ctx),
+            crate::bindings::wasi::filesystem::types::ErrorCode::IsDirectory => {
+                "is-directory".into_js(ctx)
+            }
+            crate::bindings::wasi::filesystem::types::ErrorCode::Loop => {
+                "loop".into_js(ctx)
+            }
+            crate::bindings::wasi::filesystem::types::ErrorCode::TooManyLinks => {
+                "too-many-links".into_js(ctx)
+            }
+            crate::bindings::wasi::filesystem::types::ErrorCode::MessageSize => {
+                "message-size".into_js(ctx)
+            }
+            crate::bindings::wasi::filesystem::types::ErrorCode::NameTooLong => {
+                "name-too-long".into_js(ctx)
+            }
+            crate::bindings::wasi::filesystem::types::ErrorCode::NoDevice => {
+                "no-device".into_js(ctx)
+            }
+            crate::bindings::wasi::filesystem::types::ErrorCode::NoEntry => {
+                "no-entry".into_js(ctx)
+            }
+            crate::bindings::wasi::filesystem::types::ErrorCode::NoLock => {
+                "no-lock".into_js(ctx)
+            }
+            crate::bindings::wasi::filesystem::types::ErrorCode::InsufficientMemory => {
+                "insufficient-memory".into_js(ctx)
+            }
+            crate::bindings::wasi::filesystem::types::ErrorCode::InsufficientSpace => {
+                "insufficient-space".into_js(ctx)
+            }
+            crate::bindings::wasi::filesystem::types::ErrorCode::NotDirectory => {
+                "not-directory".into_js(ctx)
+            }
+            crate::bindings::wasi::filesystem::types::ErrorCode::NotEmpty => {
+                "not-empty".into_js(ctx)
+            }
+            crate::bindings::wasi::filesystem::types::ErrorCode::NotRecoverable => {
+                "not-recoverable".into_js(ctx)
+            }
+            crate::bindings::wasi::filesystem::types::ErrorCode::Unsupported => {
+                "unsupported".into_js(ctx)
+            }
+            crate::bindings::wasi::filesystem::types::ErrorCode::NoTty => {
+                "no-tty".into_js(ctx)
+            }
+            crate::bindings::wasi::filesystem::types::ErrorCode::NoSuchDevice => {
+                "no-such-device".into_js(ctx)
+            }
+            crate::bindings::wasi::filesystem::types::ErrorCode::Overflow => {
+                "overflow".into_js(ctx)
+            }
+            crate::bindings::wasi::filesystem::types::ErrorCode::NotPermitted => {
+                "not-permitted".into_js(ctx)
+            }
+            crate::bindings::wasi::filesystem::types::ErrorCode::Pipe => {
+                "pipe".into_js(ctx)
+            }
+            crate::bindings::wasi::filesystem::types::ErrorCode::ReadOnly => {
+                "read-only".into_js(ctx)
+            }
+            crate::bindings::wasi::filesystem::types::ErrorCode::InvalidSeek => {
+                "invalid-seek".into_js(ctx)
+            }
+            crate::bindings::wasi::filesystem::types::ErrorCode::TextFileBusy => {
+                "text-file-busy".into_js(ctx)
+            }
+            crate::bindings::wasi::filesystem::types::ErrorCode::CrossDevice => {
+                "cross-device".into_js(ctx)
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::wasi::filesystem::types::ErrorCode {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let value = crate::wrappers::enum_case(
+            value,
+            "JS error-code",
+            "WIT error-code",
+        )?;
+        match value.as_str() {
+            "access" => Ok(crate::bindings::wasi::filesystem::types::ErrorCode::Access),
+            "would-block" => {
+                Ok(crate::bindings::wasi::filesystem::types::ErrorCode::WouldBlock)
+            }
+            "already" => Ok(crate::bindings::wasi::filesystem::types::ErrorCode::Already),
+            "bad-descriptor" => {
+                Ok(crate::bindings::wasi::filesystem::types::ErrorCode::BadDescriptor)
+            }
+            "busy" => Ok(crate::bindings::wasi::filesystem::types::ErrorCode::Busy),
+            "deadlock" => {
+                Ok(crate::bindings::wasi::filesystem::types::ErrorCode::Deadlock)
+            }
+            "quota" => Ok(crate::bindings::wasi::filesystem::types::ErrorCode::Quota),
+            "exist" => Ok(crate::bindings::wasi::filesystem::types::ErrorCode::Exist),
+            "file-too-large" => {
+                Ok(crate::bindings::wasi::filesystem::types::ErrorCode::FileTooLarge)
+            }
+            "illegal-byte-sequence" => {
+                Ok(
+                    crate::bindings::wasi::filesystem::types::ErrorCode::IllegalByteSequence,
+                )
+            }
+            "in-progress" => {
+                Ok(crate::bindings::wasi::filesystem::types::ErrorCode::InProgress)
+            }
+            "interrupted" => {
+                Ok(crate::bindings::wasi::filesystem::types::ErrorCode::Interrupted)
+            }
+            "invalid" => Ok(crate::bindings::wasi::filesystem::types::ErrorCode::Invalid),
+            "io" => Ok(crate::bindings::wasi::filesystem::types::ErrorCode::Io),
+            "is-directory" => {
+                Ok(crate::bindings::wasi::filesystem::types::ErrorCode::IsDirectory)
+            }
+            "loop" => Ok(crate::bindings::wasi::filesystem::types::ErrorCode::Loop),
+            "too-many-links" => {
+                Ok(crate::bindings::wasi::filesystem::types::ErrorCode::TooManyLinks)
+            }
+            "message-size" => {
+                Ok(crate::bindings::wasi::filesystem::types::ErrorCode::MessageSize)
+            }
+            "name-too-long" => {
+                Ok(crate::bindings::wasi::filesystem::types::ErrorCode::NameTooLong)
+            }
+            "no-device" => {
+                Ok(crate::bindings::wasi::filesystem::types::ErrorCode::NoDevice)
+            }
+            "no-entry" => {
+                Ok(crate::bindings::wasi::filesystem::types::ErrorCode::NoEntry)
+            }
+            "no-lock" => Ok(crate::bindings::wasi::filesystem::types::ErrorCode::NoLock),
+            "insufficient-memory" => {
+                Ok(
+                    crate::bindings::wasi::filesystem::types::ErrorCode::InsufficientMemory,
+                )
+            }
+            "insufficient-space" => {
+                Ok(
+                    crate::bindings::wasi::filesystem::types::ErrorCode::InsufficientSpace,
+                )
+            }
+            "not-directory" => {
+                Ok(crate::bindings::wasi::filesystem::types::ErrorCode::NotDirectory)
+            }
+            "not-empty" => {
+                Ok(crate::bindings::wasi::filesystem::types::ErrorCode::NotEmpty)
+            }
+            "not-recoverable" => {
+                Ok(crate::bindings::wasi::filesystem::types::ErrorCode::NotRecoverable)
+            }
+            "unsupported" => {
+                Ok(crate::bindings::wasi::filesystem::types::ErrorCode::Unsupported)
+            }
+            "no-tty" => Ok(crate::bindings::wasi::filesystem::types::ErrorCode::NoTty),
+            "no-such-device" => {
+                Ok(crate::bindings::wasi::filesystem::types::ErrorCode::NoSuchDevice)
+            }
+            "overflow" => {
+                Ok(crate::bindings::wasi::filesystem::types::ErrorCode::Overflow)
+            }
+            "not-permitted" => {
+                Ok(crate::bindings::wasi::filesystem::types::ErrorCode::NotPermitted)
+            }
+            "pipe" => Ok(crate::bindings::wasi::filesystem::types::ErrorCode::Pipe),
+            "read-only" => {
+                Ok(crate::bindings::wasi::filesystem::types::ErrorCode::ReadOnly)
+            }
+            "invalid-seek" => {
+                Ok(crate::bindings::wasi::filesystem::types::ErrorCode::InvalidSeek)
+            }
+            "text-file-busy" => {
+                Ok(crate::bindings::wasi::filesystem::types::ErrorCode::TextFileBusy)
+            }
+            "cross-device" => {
+                Ok(crate::bindings::wasi::filesystem::types::ErrorCode::CrossDevice)
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_enum_case(
+                        "JS error-code",
+                        "WIT error-code",
+                        &value,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::wasi::filesystem::types::Advice {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::wasi::filesystem::types::Advice::Normal => {
+                "normal".into_js(ctx)
+            }
+            crate::bindings::wasi::filesystem::types::Advice::Sequential => {
+                "sequential".into_js(ctx)
+            }
+            crate::bindings::wasi::filesystem::types::Advice::Random => {
+                "random".into_js(ctx)
+            }
+            crate::bindings::wasi::filesystem::types::Advice::WillNeed => {
+                "will-need".into_js(ctx)
+            }
+            crate::bindings::wasi::filesystem::types::Advice::DontNeed => {
+                "dont-need".into_js(ctx)
+            }
+            crate::bindings::wasi::filesystem::types::Advice::NoReuse => {
+                "no-reuse".into_js(ctx)
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::wasi::filesystem::types::Advice {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let value = crate::wrappers::enum_case(value, "JS advice", "WIT advice")?;
+        match value.as_str() {
+            "normal" => Ok(crate::bindings::wasi::filesystem::types::Advice::Normal),
+            "sequential" => {
+                Ok(crate::bindings::wasi::filesystem::types::Advice::Sequential)
+            }
+            "random" => Ok(crate::bindings::wasi::filesystem::types::Advice::Random),
+            "will-need" => Ok(crate::bindings::wasi::filesystem::types::Advice::WillNeed),
+            "dont-need" => Ok(crate::bindings::wasi::filesystem::types::Advice::DontNeed),
+            "no-reuse" => Ok(crate::bindings::wasi::filesystem::types::Advice::NoReuse),
+            _ => {
+                Err(
+                    crate::wrappers::unknown_enum_case("JS advice", "WIT advice", &value),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::wasi::filesystem::types::MetadataHashValue {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::BigIntWrapper<u64>,
+            >("lower", crate::wrappers::BigIntWrapper(self.lower))?;
+        obj.set::<
+                _,
+                crate::wrappers::BigIntWrapper<u64>,
+            >("upper", crate::wrappers::BigIntWrapper(self.upper))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::wasi::filesystem::types::MetadataHashValue {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT metadata-hash-value",
+            &["lower", "upper"],
+            &[("lower", "u64"), ("upper", "u64")],
+            false,
+        )?;
+        let lower: crate::wrappers::BigIntWrapper<u64> = crate::wrappers::get_field(
+            &obj,
+            "lower",
+            "u64",
+        )?;
+        let lower: u64 = lower.0;
+        let upper: crate::wrappers::BigIntWrapper<u64> = crate::wrappers::get_field(
+            &obj,
+            "upper",
+            "u64",
+        )?;
+        let upper: u64 = upper.0;
+        Ok(Self { lower, upper })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::wasi::sockets::network::ErrorCode {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::wasi::sockets::network::ErrorCode::Unknown => {
+                "unknown".into_js(ctx)
+            }
+            crate::bindings::wasi::sockets::network::ErrorCode::AccessDenied => {
+                "access-denied".into_js(ctx)
+            }
+            crate::bindings::wasi::sockets::network::ErrorCode::NotSupported => {
+                "not-supported".into_js(ctx)
+            }
+            crate::bindings::wasi::sockets::network::ErrorCode::InvalidArgument => {
+                "invalid-argument".into_js(ctx)
+            }
+            crate::bindings::wasi::sockets::network::ErrorCode::OutOfMemory => {
+                "out-of-memory".into_js(ctx)
+            }
+            crate::bindings::wasi::sockets::network::ErrorCode::Timeout => {
+                "timeout".into_js(ctx)
+            }
+            crate::bindings::wasi::sockets::network::ErrorCode::ConcurrencyConflict => {
+                "concurrency-conflict".into_js(ctx)
+            }
+            crate::bindings::wasi::sockets::network::ErrorCode::NotInProgress => {
+                "not-in-progress".into_js(ctx)
+            }
+            crate::bindings::wasi::sockets::network::ErrorCode::WouldBlock => {
+                "would-block".into_js(ctx)
+            }
+            crate::bindings::wasi::sockets::network::ErrorCode::InvalidState => {
+                "invalid-state".into_js(ctx)
+            }
+            crate::bindings::wasi::sockets::network::ErrorCode::NewSocketLimit => {
+                "new-socket-limit".into_js(ctx)
+            }
+            crate::bindings::wasi::sockets::network::ErrorCode::AddressNotBindable => {
+                "address-not-bindable".into_js(ctx)
+            }
+            crate::bindings::wasi::sockets::network::ErrorCode::AddressInUse => {
+                "address-in-use".into_js(ctx)
+            }
+            crate::bindings::wasi::sockets::network::ErrorCode::RemoteUnreachable => {
+                "remote-unreachable".into_js(ctx)
+            }
+            crate::bindings::wasi::sockets::network::ErrorCode::ConnectionRefused => {
+                "connection-refused".into_js(ctx)
+            }
+            crate::bindings::wasi::sockets::network::ErrorCode::ConnectionReset => {
+                "connection-reset".into_js(ctx)
+            }
+            crate::bindings::wasi::sockets::network::ErrorCode::ConnectionAborted => {
+                "connection-aborted".into_js(ctx)
+            }
+            crate::bindings::wasi::sockets::network::ErrorCode::DatagramTooLarge => {
+                "datagram-too-large".into_js(ctx)
+            }
+            crate::bindings::wasi::sockets::network::ErrorCode::NameUnresolvable => {
+                "name-unresolvable".into_js(ctx)
+            }
+            crate::bindings::wasi::sockets::network::ErrorCode::TemporaryResolverFailure => {
+                "temporary-resolver-failure".into_js(ctx)
+            }
+            crate::bindings::wasi::sockets::network::ErrorCode::PermanentResolverFailure => {
+                "permanent-resolver-failure".into_js(ctx)
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::wasi::sockets::network::ErrorCode {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let value = crate::wrappers::enum_case(
+            value,
+            "JS error-code",
+            "WIT error-code",
+        )?;
+        match value.as_str() {
+            "unknown" => Ok(crate::bindings::wasi::sockets::network::ErrorCode::Unknown),
+            "access-denied" => {
+                Ok(crate::bindings::wasi::sockets::network::ErrorCode::AccessDenied)
+            }
+            "not-supported" => {
+                Ok(crate::bindings::wasi::sockets::network::ErrorCode::NotSupported)
+            }
+            "invalid-argument" => {
+                Ok(crate::bindings::wasi::sockets::network::ErrorCode::InvalidArgument)
+            }
+            "out-of-memory" => {
+                Ok(crate::bindings::wasi::sockets::network::ErrorCode::OutOfMemory)
+            }
+            "timeout" => Ok(crate::bindings::wasi::sockets::network::ErrorCode::Timeout),
+            "concurrency-conflict" => {
+                Ok(
+                    crate::bindings::wasi::sockets::network::ErrorCode::ConcurrencyConflict,
+                )
+            }
+            "not-in-progress" => {
+                Ok(crate::bindings::wasi::sockets::network::ErrorCode::NotInProgress)
+            }
+            "would-block" => {
+                Ok(crate::bindings::wasi::sockets::network::ErrorCode::WouldBlock)
+            }
+            "invalid-state" => {
+                Ok(crate::bindings::wasi::sockets::network::ErrorCode::InvalidState)
+            }
+            "new-socket-limit" => {
+                Ok(crate::bindings::wasi::sockets::network::ErrorCode::NewSocketLimit)
+            }
+            "address-not-bindable" => {
+                Ok(
+                    crate::bindings::wasi::sockets::network::ErrorCode::AddressNotBindable,
+                )
+            }
+            "address-in-use" => {
+                Ok(crate::bindings::wasi::sockets::network::ErrorCode::AddressInUse)
+            }
+            "remote-unreachable" => {
+                Ok(crate::bindings::wasi::sockets::network::ErrorCode::RemoteUnreachable)
+            }
+            "connection-refused" => {
+                Ok(crate::bindings::wasi::sockets::network::ErrorCode::ConnectionRefused)
+            }
+            "connection-reset" => {
+                Ok(crate::bindings::wasi::sockets::network::ErrorCode::ConnectionReset)
+            }
+            "connection-aborted" => {
+                Ok(crate::bindings::wasi::sockets::network::ErrorCode::ConnectionAborted)
+            }
+            "datagram-too-large" => {
+                Ok(crate::bindings::wasi::sockets::network::ErrorCode::DatagramTooLarge)
+            }
+            "name-unresolvable" => {
+                Ok(crate::bindings::wasi::sockets::network::ErrorCode::NameUnresolvable)
+            }
+            "temporary-resolver-failure" => {
+                Ok(
+                    crate::bindings::wasi::sockets::network::ErrorCode::TemporaryResolverFailure,
+                )
+            }
+            "permanent-resolver-failure" => {
+                Ok(
+                    crate::bindings::wasi::sockets::network::ErrorCode::PermanentResolverFailure,
+                )
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_enum_case(
+                        "JS error-code",
+                        "WIT error-code",
+                        &value,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::wasi::sockets::network::IpAddress {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::wasi::sockets::network::IpAddress::Ipv4(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    rquickjs::convert::List<(u8, u8, u8, u8)>,
+                >(
+                    ctx,
+                    "ipv4",
+                    rquickjs::convert::List((inner.0, inner.1, inner.2, inner.3)),
+                )
+            }
+            crate::bindings::wasi::sockets::network::IpAddress::Ipv6(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    rquickjs::convert::List<(u16, u16, u16, u16, u16, u16, u16, u16)>,
+                >(
+                    ctx,
+                    "ipv6",
+                    rquickjs::convert::List((
+                        inner.0,
+                        inner.1,
+                        inner.2,
+                        inner.3,
+                        inner.4,
+                        inner.5,
+                        inner.6,
+                        inner.7,
+                    )),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::wasi::sockets::network::IpAddress {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "ipv4" => {
+                let inner: rquickjs::convert::List<(u8, u8, u8, u8)> = crate::wrappers::variant_value(
+                    &obj,
+                    "ipv4-address",
+                )?;
+                Ok(
+                    crate::bindings::wasi::sockets::network::IpAddress::Ipv4((
+                        inner.0.0,
+                        inner.0.1,
+                        inner.0.2,
+                        inner.0.3,
+                    )),
+                )
+            }
+            "ipv6" => {
+                let inner: rquickjs::convert::List<
+                    (u16, u16, u16, u16, u16, u16, u16, u16),
+                > = crate::wrappers::variant_value(&obj, "ipv6-address")?;
+                Ok(
+                    crate::bindings::wasi::sockets::network::IpAddress::Ipv6((
+                        inner.0.0,
+                        inner.0.1,
+                        inner.0.2,
+                        inner.0.3,
+                        inner.0.4,
+                        inner.0.5,
+                        inner.0.6,
+                        inner.0.7,
+                    )),
+                )
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS ip-address",
+                        "WIT ip-address",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::wasi::http::types::Method {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::wasi::http::types::Method::Get => {
+                crate::wrappers::variant_case(ctx, "get")
+            }
+            crate::bindings::wasi::http::types::Method::Head => {
+                crate::wrappers::variant_case(ctx, "head")
+            }
+            crate::bindings::wasi::http::types::Method::Post => {
+                crate::wrappers::variant_case(ctx, "post")
+            }
+            crate::bindings::wasi::http::types::Method::Put => {
+                crate::wrappers::variant_case(ctx, "put")
+            }
+            crate::bindings::wasi::http::types::Method::Delete => {
+                crate::wrappers::variant_case(ctx, "delete")
+            }
+            crate::bindings::wasi::http::types::Method::Connect => {
+                crate::wrappers::variant_case(ctx, "connect")
+            }
+            crate::bindings::wasi::http::types::Method::Options => {
+                crate::wrappers::variant_case(ctx, "options")
+            }
+            crate::bindings::wasi::http::types::Method::Trace => {
+                crate::wrappers::variant_case(ctx, "trace")
+            }
+            crate::bindings::wasi::http::types::Method::Patch => {
+                crate::wrappers::variant_case(ctx, "patch")
+            }
+            crate::bindings::wasi::http::types::Method::Other(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "other", crate::wrappers::JsString(inner))
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::wasi::http::types::Method {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "get" => Ok(crate::bindings::wasi::http::types::Method::Get),
+            "head" => Ok(crate::bindings::wasi::http::types::Method::Head),
+            "post" => Ok(crate::bindings::wasi::http::types::Method::Post),
+            "put" => Ok(crate::bindings::wasi::http::types::Method::Put),
+            "delete" => Ok(crate::bindings::wasi::http::types::Method::Delete),
+            "connect" => Ok(crate::bindings::wasi::http::types::Method::Connect),
+            "options" => Ok(crate::bindings::wasi::http::types::Method::Options),
+            "trace" => Ok(crate::bindings::wasi::http::types::Method::Trace),
+            "patch" => Ok(crate::bindings::wasi::http::types::Method::Patch),
+            "other" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(crate::bindings::wasi::http::types::Method::Other(inner.0))
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS method",
+                        "WIT method",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::wasi::http::types::Scheme {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::wasi::http::types::Scheme::Http => {
+                crate::wrappers::variant_case(ctx, "HTTP")
+            }
+            crate::bindings::wasi::http::types::Scheme::Https => {
+                crate::wrappers::variant_case(ctx, "HTTPS")
+            }
+            crate::bindings::wasi::http::types::Scheme::Other(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "other", crate::wrappers::JsString(inner))
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::wasi::http::types::Scheme {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "HTTP" => Ok(crate::bindings::wasi::http::types::Scheme::Http),
+            "HTTPS" => Ok(crate::bindings::wasi::http::types::Scheme::Https),
+            "other" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(crate::bindings::wasi::http::types::Scheme::Other(inner.0))
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS scheme",
+                        "WIT scheme",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::wasi::http::types::DnsErrorPayload {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >("rcode", self.rcode.map(|v| crate::wrappers::JsString(v)))?;
+        obj.set::<_, Option<u16>>("infoCode", self.info_code.map(|v| v))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::wasi::http::types::DnsErrorPayload {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT DNS-error-payload",
+            &["rcode", "infoCode"],
+            &[],
+            false,
+        )?;
+        let rcode: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "rcode",
+            "option<string>",
+        )?;
+        let rcode: Option<String> = rcode.map(|v| v.0);
+        let info_code: Option<u16> = crate::wrappers::get_field(
+            &obj,
+            "infoCode",
+            "option<u16>",
+        )?;
+        let info_code: Option<u16> = info_code.map(|v| v);
+        Ok(Self { rcode, info_code })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::wasi::http::types::TlsAlertReceivedPayload {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<_, Option<u8>>("alertId", self.alert_id.map(|v| v))?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >("alertMessage", self.alert_message.map(|v| crate::wrappers::JsString(v)))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::wasi::http::types::TlsAlertReceivedPayload {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT TLS-alert-received-payload",
+            &["alertId", "alertMessage"],
+            &[],
+            false,
+        )?;
+        let alert_id: Option<u8> = crate::wrappers::get_field(
+            &obj,
+            "alertId",
+            "option<u8>",
+        )?;
+        let alert_id: Option<u8> = alert_id.map(|v| v);
+        let alert_message: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "alertMessage",
+            "option<string>",
+        )?;
+        let alert_message: Option<String> = alert_message.map(|v| v.0);
+        Ok(Self { alert_id, alert_message })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::wasi::http::types::FieldSizePayload {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >("fieldName", self.field_name.map(|v| crate::wrappers::JsString(v)))?;
+        obj.set::<_, Option<u32>>("fieldSize", self.field_size.map(|v| v))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::wasi::http::types::FieldSizePayload {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT field-size-payload",
+            &["fieldName", "fieldSize"],
+            &[],
+            false,
+        )?;
+        let field_name: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "fieldName",
+            "option<string>",
+        )?;
+        let field_name: Option<String> = field_name.map(|v| v.0);
+        let field_size: Option<u32> = crate::wrappers::get_field(
+            &obj,
+            "fieldSize",
+            "option<u32>",
+        )?;
+        let field_size: Option<u32> = field_size.map(|v| v);
+        Ok(Self { field_name, field_size })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::wasi::http::types::ErrorCode {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::wasi::http::types::ErrorCode::DnsTimeout => {
+                crate::wrappers::variant_case(ctx, "DNS-timeout")
+            }
+            crate::bindings::wasi::http::types::ErrorCode::DnsError(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::wasi::http::types::DnsErrorPayload,
+                >(ctx, "DNS-error", inner)
+            }
+            crate::bindings::wasi::http::types::ErrorCode::DestinationNotFound => {
+                crate::wrappers::variant_case(ctx, "destination-not-found")
+            }
+            crate::bindings::wasi::http::types::ErrorCode::DestinationUnavailable => {
+                crate::wrappers::variant_case(ctx, "destination-unavailable")
+            }
+            crate::bindings::wasi::http::types::ErrorCode::DestinationIpProhibited => {
+                crate::wrappers::variant_case(ctx, "destination-IP-prohibited")
+            }
+            crate::bindings::wasi::http::types::ErrorCode::DestinationIpUnroutable => {
+                crate::wrappers::variant_case(ctx, "destination-IP-unroutable")
+            }
+            crate::bindings::wasi::http::types::ErrorCode::ConnectionRefused => {
+                crate::wrappers::variant_case(ctx, "connection-refused")
+            }
+            crate::bindings::wasi::http::types::ErrorCode::ConnectionTerminated => {
+                crate::wrappers::variant_case(ctx, "connection-terminated")
+            }
+            crate::bindings::wasi::http::types::ErrorCode::ConnectionTimeout => {
+                crate::wrappers::variant_case(ctx, "connection-timeout")
+            }
+            crate::bindings::wasi::http::types::ErrorCode::ConnectionReadTimeout => {
+                crate::wrappers::variant_case(ctx, "connection-read-timeout")
+            }
+            crate::bindings::wasi::http::types::ErrorCode::ConnectionWriteTimeout => {
+                crate::wrappers::variant_case(ctx, "connection-write-timeout")
+            }
+            crate::bindings::wasi::http::types::ErrorCode::ConnectionLimitReached => {
+                crate::wrappers::variant_case(ctx, "connection-limit-reached")
+            }
+            crate::bindings::wasi::http::types::ErrorCode::TlsProtocolError => {
+                crate::wrappers::variant_case(ctx, "TLS-protocol-error")
+            }
+            crate::bindings::wasi::http::types::ErrorCode::TlsCertificateError => {
+                crate::wrappers::variant_case(ctx, "TLS-certificate-error")
+            }
+            crate::bindings::wasi::http::types::ErrorCode::TlsAlertReceived(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::wasi::http::types::TlsAlertReceivedPayload,
+                >(ctx, "TLS-alert-received", inner)
+            }
+            crate::bindings::wasi::http::types::ErrorCode::HttpRequestDenied => {
+                crate::wrappers::variant_case(ctx, "HTTP-request-denied")
+            }
+            crate::bindings::wasi::http::types::ErrorCode::HttpRequestLengthRequired => {
+                crate::wrappers::variant_case(ctx, "HTTP-request-length-required")
+            }
+            crate::bindings::wasi::http::types::ErrorCode::HttpRequestBodySize(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    Option<crate::wrappers::BigIntWrapper<u64>>,
+                >(
+                    ctx,
+                    "HTTP-request-body-size",
+                    inner.map(|v| crate::wrappers::BigIntWrapper(v)),
+                )
+            }
+            crate::bindings::wasi::http::types::ErrorCode::HttpRequestMethodInvalid => {
+                crate::wrappers::variant_case(ctx, "HTTP-request-method-invalid")
+            }
+            crate::bindings::wasi::http::types::ErrorCode::HttpRequestUriInvalid => {
+                crate::wrappers::variant_case(ctx, "HTTP-request-URI-invalid")
+            }
+            crate::bindings::wasi::http::types::ErrorCode::HttpRequestUriTooLong => {
+                crate::wrappers::variant_case(ctx, "HTTP-request-URI-too-long")
+            }
+            crate::bindings::wasi::http::types::ErrorCode::HttpRequestHeaderSectionSize(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    Option<u32>,
+                >(ctx, "HTTP-request-header-section-size", inner.map(|v| v))
+            }
+            crate::bindings::wasi::http::types::ErrorCode::HttpRequestHeaderSize(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    Option<crate::bindings::wasi::http::types::FieldSizePayload>,
+                >(ctx, "HTTP-request-header-size", inner.map(|v| v))
+            }
+            crate::bindings::wasi::http::types::ErrorCode::HttpRequestTrailerSectionSize(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    Option<u32>,
+                >(ctx, "HTTP-request-trailer-section-size", inner.map(|v| v))
+            }
+            crate::bindings::wasi::http::types::ErrorCode::HttpRequestTrailerSize(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::wasi::http::types::FieldSizePayload,
+                >(ctx, "HTTP-request-trailer-size", inner)
+            }
+            crate::bindings::wasi::http::types::ErrorCode::HttpResponseIncomplete => {
+                crate::wrappers::variant_case(ctx, "HTTP-response-incomplete")
+            }
+            crate::bindings::wasi::http::types::ErrorCode::HttpResponseHeaderSectionSize(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    Option<u32>,
+                >(ctx, "HTTP-response-header-section-size", inner.map(|v| v))
+            }
+            crate::bindings::wasi::http::types::ErrorCode::HttpResponseHeaderSize(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::wasi::http::types::FieldSizePayload,
+                >(ctx, "HTTP-response-header-size", inner)
+            }
+            crate::bindings::wasi::http::types::ErrorCode::HttpResponseBodySize(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    Option<crate::wrappers::BigIntWrapper<u64>>,
+                >(
+                    ctx,
+                    "HTTP-response-body-size",
+                    inner.map(|v| crate::wrappers::BigIntWrapper(v)),
+                )
+            }
+            crate::bindings::wasi::http::types::ErrorCode::HttpResponseTrailerSectionSize(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    Option<u32>,
+                >(ctx, "HTTP-response-trailer-section-size", inner.map(|v| v))
+            }
+            crate::bindings::wasi::http::types::ErrorCode::HttpResponseTrailerSize(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::wasi::http::types::FieldSizePayload,
+                >(ctx, "HTTP-response-trailer-size", inner)
+            }
+            crate::bindings::wasi::http::types::ErrorCode::HttpResponseTransferCoding(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    Option<crate::wrappers::JsString>,
+                >(
+                    ctx,
+                    "HTTP-response-transfer-coding",
+                    inner.map(|v| crate::wrappers::JsString(v)),
+                )
+            }
+            crate::bindings::wasi::http::types::ErrorCode::HttpResponseContentCoding(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    Option<crate::wrappers::JsString>,
+                >(
+                    ctx,
+                    "HTTP-response-content-coding",
+                    inner.map(|v| crate::wrappers::JsString(v)),
+                )
+            }
+            crate::bindings::wasi::http::types::ErrorCode::HttpResponseTimeout => {
+                crate::wrappers::variant_case(ctx, "HTTP-response-timeout")
+            }
+            crate::bindings::wasi::http::types::ErrorCode::HttpUpgradeFailed => {
+                crate::wrappers::variant_case(ctx, "HTTP-upgrade-failed")
+            }
+            crate::bindings::wasi::http::types::ErrorCode::HttpProtocolError => {
+                crate::wrappers::variant_case(ctx, "HTTP-protocol-error")
+            }
+            crate::bindings::wasi::http::types::ErrorCode::LoopDetected => {
+                crate::wrappers::variant_case(ctx, "loop-detected")
+            }
+            crate::bindings::wasi::http::types::ErrorCode::ConfigurationError => {
+                crate::wrappers::variant_case(ctx, "configuration-error")
+            }
+            crate::bindings::wasi::http::types::ErrorCode::InternalError(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    Option<crate::wrappers::JsString>,
+                >(ctx, "internal-error", inner.map(|v| crate::wrappers::JsString(v)))
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::wasi::http::types::ErrorCode {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "DNS-timeout" => {
+                Ok(crate::bindings::wasi::http::types::ErrorCode::DnsTimeout)
+            }
+            "DNS-error" => {
+                let inner: crate::bindings::wasi::http::types::DnsErrorPayload = crate::wrappers::variant_value(
+                    &obj,
+                    "DNS-error-payload",
+                )?;
+                Ok(crate::bindings::wasi::http::types::ErrorCode::DnsError(inner))
+            }
+            "destination-not-found" => {
+                Ok(crate::bindings::wasi::http::types::ErrorCode::DestinationNotFound)
+            }
+            "destination-unavailable" => {
+                Ok(crate::bindings::wasi::http::types::ErrorCode::DestinationUnavailable)
+            }
+            "destination-IP-prohibited" => {
+                Ok(
+                    crate::bindings::wasi::http::types::ErrorCode::DestinationIpProhibited,
+                )
+            }
+            "destination-IP-unroutable" => {
+                Ok(
+                    crate::bindings::wasi::http::types::ErrorCode::DestinationIpUnroutable,
+                )
+            }
+            "connection-refused" => {
+                Ok(crate::bindings::wasi::http::types::ErrorCode::ConnectionRefused)
+            }
+            "connection-terminated" => {
+                Ok(crate::bindings::wasi::http::types::ErrorCode::ConnectionTerminated)
+            }
+            "connection-timeout" => {
+                Ok(crate::bindings::wasi::http::types::ErrorCode::ConnectionTimeout)
+            }
+            "connection-read-timeout" => {
+                Ok(crate::bindings::wasi::http::types::ErrorCode::ConnectionReadTimeout)
+            }
+            "connection-write-timeout" => {
+                Ok(crate::bindings::wasi::http::types::ErrorCode::ConnectionWriteTimeout)
+            }
+            "connection-limit-reached" => {
+                Ok(crate::bindings::wasi::http::types::ErrorCode::ConnectionLimitReached)
+            }
+            "TLS-protocol-error" => {
+                Ok(crate::bindings::wasi::http::types::ErrorCode::TlsProtocolError)
+            }
+            "TLS-certificate-error" => {
+                Ok(crate::bindings::wasi::http::types::ErrorCode::TlsCertificateError)
+            }
+            "TLS-alert-received" => {
+                let inner: crate::bindings::wasi::http::types::TlsAlertReceivedPayload = crate::wrappers::variant_value(
+                    &obj,
+                    "TLS-alert-received-payload",
+                )?;
+                Ok(
+                    crate::bindings::wasi::http::types::ErrorCode::TlsAlertReceived(
+                        inner,
+                    ),
+                )
+            }
+            "HTTP-request-denied" => {
+                Ok(crate::bindings::wasi::http::types::ErrorCode::HttpRequestDenied)
+            }
+            "HTTP-request-length-required" => {
+                Ok(
+                    crate::bindings::wasi::http::types::ErrorCode::HttpRequestLengthRequired,
+                )
+            }
+            "HTTP-request-body-size" => {
+                let inner: Option<crate::wrappers::BigIntWrapper<u64>> = crate::wrappers::variant_value(
+                    &obj,
+                    "option<u64>",
+                )?;
+                Ok(
+                    crate::bindings::wasi::http::types::ErrorCode::HttpRequestBodySize(
+                        inner.map(|v| v.0),
+                    ),
+                )
+            }
+            "HTTP-request-method-invalid" => {
+                Ok(
+                    crate::bindings::wasi::http::types::ErrorCode::HttpRequestMethodInvalid,
+                )
+            }
+            "HTTP-request-URI-invalid" => {
+                Ok(crate::bindings::wasi::http::types::ErrorCode::HttpRequestUriInvalid)
+            }
+            "HTTP-request-URI-too-long" => {
+                Ok(crate::bindings::wasi::http::types::ErrorCode::HttpRequestUriTooLong)
+            }
+            "HTTP-request-header-section-size" => {
+                let inner: Option<u32> = crate::wrappers::variant_value(
+                    &obj,
+                    "option<u32>",
+                )?;
+                Ok(
+                    crate::bindings::wasi::http::types::ErrorCode::HttpRequestHeaderSectionSize(
+                        inner.map(|v| v),
+                    ),
+                )
+            }
+            "HTTP-request-header-size" => {
+                let inner: Option<
+                    crate::bindings::wasi::http::types::FieldSizePayload,
+                > = crate::wrappers::variant_value(&obj, "option<field-size-payload>")?;
+                Ok(
+                    crate::bindings::wasi::http::types::ErrorCode::HttpRequestHeaderSize(
+                        inner.map(|v| v),
+                    ),
+                )
+            }
+            "HTTP-request-trailer-section-size" => {
+                let inner: Option<u32> = crate::wrappers::variant_value(
+                    &obj,
+                    "option<u32>",
+                )?;
+                Ok(
+                    crate::bindings::wasi::http::types::ErrorCode::HttpRequestTrailerSectionSize(
+                        inner.map(|v| v),
+                    ),
+                )
+            }
+            "HTTP-request-trailer-size" => {
+                let inner: crate::bindings::wasi::http::types::FieldSizePayload = crate::wrappers::variant_value(
+                    &obj,
+                    "field-size-payload",
+                )?;
+                Ok(
+                    crate::bindings::wasi::http::types::ErrorCode::HttpRequestTrailerSize(
+                        inner,
+                    ),
+                )
+            }
+            "HTTP-response-incomplete" => {
+                Ok(crate::bindings::wasi::http::types::ErrorCode::HttpResponseIncomplete)
+            }
+            "HTTP-response-header-section-size" => {
+                let inner: Option<u32> = crate::wrappers::variant_value(
+                    &obj,
+                    "option<u32>",
+                )?;
+                Ok(
+                    crate::bindings::wasi::http::types::ErrorCode::HttpResponseHeaderSectionSize(
+                        inner.map(|v| v),
+                    ),
+                )
+            }
+            "HTTP-response-header-size" => {
+                let inner: crate::bindings::wasi::http::types::FieldSizePayload = crate::wrappers::variant_value(
+                    &obj,
+                    "field-size-payload",
+                )?;
+                Ok(
+                    crate::bindings::wasi::http::types::ErrorCode::HttpResponseHeaderSize(
+                        inner,
+                    ),
+                )
+            }
+            "HTTP-response-body-size" => {
+                let inner: Option<crate::wrappers::BigIntWrapper<u64>> = crate::wrappers::variant_value(
+                    &obj,
+                    "option<u64>",
+                )?;
+                Ok(
+                    crate::bindings::wasi::http::types::ErrorCode::HttpResponseBodySize(
+                        inner.map(|v| v.0),
+                    ),
+                )
+            }
+            "HTTP-response-trailer-section-size" => {
+                let inner: Option<u32> = crate::wrappers::variant_value(
+                    &obj,
+                    "option<u32>",
+                )?;
+                Ok(
+                    crate::bindings::wasi::http::types::ErrorCode::HttpResponseTrailerSectionSize(
+                        inner.map(|v| v),
+                    ),
+                )
+            }
+            "HTTP-response-trailer-size" => {
+                let inner: crate::bindings::wasi::http::types::FieldSizePayload = crate::wrappers::variant_value(
+                    &obj,
+                    "field-size-payload",
+                )?;
+                Ok(
+                    crate::bindings::wasi::http::types::ErrorCode::HttpResponseTrailerSize(
+                        inner,
+                    ),
+                )
+            }
+            "HTTP-response-transfer-coding" => {
+                let inner: Option<crate::wrappers::JsString> = crate::wrappers::variant_value(
+                    &obj,
+                    "option<string>",
+                )?;
+                Ok(
+                    crate::bindings::wasi::http::types::ErrorCode::HttpResponseTransferCoding(
+                        inner.map(|v| v.0),
+                    ),
+                )
+            }
+            "HTTP-response-content-coding" => {
+                let inner: Option<crate::wrappers::JsString> = crate::wrappers::variant_value(
+                    &obj,
+                    "option<string>",
+                )?;
+                Ok(
+                    crate::bindings::wasi::http::types::ErrorCode::HttpResponseContentCoding(
+                        inner.map(|v| v.0),
+                    ),
+                )
+            }
+            "HTTP-response-timeout" => {
+                Ok(crate::bindings::wasi::http::types::ErrorCode::HttpResponseTimeout)
+            }
+            "HTTP-upgrade-failed" => {
+                Ok(crate::bindings::wasi::http::types::ErrorCode::HttpUpgradeFailed)
+            }
+            "HTTP-protocol-error" => {
+                Ok(crate::bindings::wasi::http::types::ErrorCode::HttpProtocolError)
+            }
+            "loop-detected" => {
+                Ok(crate::bindings::wasi::http::types::ErrorCode::LoopDetected)
+            }
+            "configuration-error" => {
+                Ok(crate::bindings::wasi::http::types::ErrorCode::ConfigurationError)
+            }
+            "internal-error" => {
+                let inner: Option<crate::wrappers::JsString> = crate::wrappers::variant_value(
+                    &obj,
+                    "option<string>",
+                )?;
+                Ok(
+                    crate::bindings::wasi::http::types::ErrorCode::InternalError(
+                        inner.map(|v| v.0),
+                    ),
+                )
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS error-code",
+                        "WIT error-code",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::wasi::http::types::HeaderError {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::wasi::http::types::HeaderError::InvalidSyntax => {
+                crate::wrappers::variant_case(ctx, "invalid-syntax")
+            }
+            crate::bindings::wasi::http::types::HeaderError::Forbidden => {
+                crate::wrappers::variant_case(ctx, "forbidden")
+            }
+            crate::bindings::wasi::http::types::HeaderError::Immutable => {
+                crate::wrappers::variant_case(ctx, "immutable")
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::wasi::http::types::HeaderError {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (_, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "invalid-syntax" => {
+                Ok(crate::bindings::wasi::http::types::HeaderError::InvalidSyntax)
+            }
+            "forbidden" => Ok(crate::bindings::wasi::http::types::HeaderError::Forbidden),
+            "immutable" => Ok(crate::bindings::wasi::http::types::HeaderError::Immutable),
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS header-error",
+                        "WIT header-error",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::wasi::logging::logging::Level {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::wasi::logging::logging::Level::Trace => "trace".into_js(ctx),
+            crate::bindings::wasi::logging::logging::Level::Debug => "debug".into_js(ctx),
+            crate::bindings::wasi::logging::logging::Level::Info => "info".into_js(ctx),
+            crate::bindings::wasi::logging::logging::Level::Warn => "warn".into_js(ctx),
+            crate::bindings::wasi::logging::logging::Level::Error => "error".into_js(ctx),
+            crate::bindings::wasi::logging::logging::Level::Critical => {
+                "critical".into_js(ctx)
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::wasi::logging::logging::Level {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let value = crate::wrappers::enum_case(value, "JS level", "WIT level")?;
+        match value.as_str() {
+            "trace" => Ok(crate::bindings::wasi::logging::logging::Level::Trace),
+            "debug" => Ok(crate::bindings::wasi::logging::logging::Level::Debug),
+            "info" => Ok(crate::bindings::wasi::logging::logging::Level::Info),
+            "warn" => Ok(crate::bindings::wasi::logging::logging::Level::Warn),
+            "error" => Ok(crate::bindings::wasi::logging::logging::Level::Error),
+            "critical" => Ok(crate::bindings::wasi::logging::logging::Level::Critical),
+            _ => Err(crate::wrappers::unknown_enum_case("JS level", "WIT level", &value)),
+        }
+    }
+}
@@ -0,0 +1,64 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:all-golem-imports/all-golem-imports
+// wit: sha256:3ec76077ee8750a4a3d97cf8f89273803d43fba31aa02651185015b5648e2fc7
+// js all-golem-imports: sha256:1aa6d68c08d678e85c1f334bb55fc7062ce81fa0f90be1bc0d6a3965bfccc568
+
+#[allow(static_mut_refs)]
+#[allow(unsafe_op_in_unsafe_fn)]
+mod bindings;
+mod assets;
+mod builtin;
+mod conversions;
+#[allow(unused)]
+mod internal;
+#[allow(unused)]
+mod modules;
+mod wrappers;
+static JS_EXPORT_MODULE_NAME: &str = "all-golem-imports";
+static JS_EXPORT_NAMES_VERBATIM: bool = false;
+static INTERN_FIELD_NAMES: bool = false;
+#[allow(dead_code)]
+static REPLACE_LONE_SURROGATES: bool = false;
+static MATH_RANDOM_SEED: crate::builtin::MathRandomSeed = crate::builtin::MathRandomSeed::Default;
+static DATE_CLOCK: Option<fn() -> f64> = None;
+static IMPORT_HOOKS: bool = false;
+static MOCK_IMPORTS: bool = false;
+static IMPORT_RECORDING: crate::builtin::ImportRecording = crate::builtin::ImportRecording::None;
+static CANCEL_BACKGROUND_WORK: bool = false;
+static HTTP_LIMITS: crate::builtin::HttpLimits = crate::builtin::HttpLimits::UNLIMITED;
+#[allow(dead_code)]
+static COOKIE_JAR: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE_LIMITS: crate::builtin::HttpCacheLimits = crate::builtin::HttpCacheLimits {
+    max_entries: Some(256usize),
+    max_size: Some(16777216usize),
+    max_body_size: Some(1048576usize),
+};
+#[allow(dead_code)]
+static FETCH_BASE_URL: Option<&str> = None;
+#[allow(dead_code)]
+static FETCH_GATEWAY: Option<&str> = None;
+static JS_EXPORT_MODULE: &str = include_str!("all-golem-imports.js");
+static JS_ADDITIONAL_MODULES: std::sync::LazyLock<
+    Vec<(&str, Box<dyn (Fn() -> String) + Send + Sync>)>,
+> = std::sync::LazyLock::new(|| { vec![] });
+static JS_MODULE_ORDER: &[&str] = &["all-golem-imports"];
+static JS_BUILTIN_PERMISSIONS: &[(&str, &[&str])] = &[];
+struct Component;
+impl crate::bindings::Guest for Component {
+    fn test() -> () {
+        crate::internal::async_exported_function(async move {
+            let result: () = crate::internal::call_js_export(
+                    "quickjs:all-golem-imports",
+                    0usize,
+                    &["test"],
+                    crate::wrappers::JsArgs(()),
+                )
+                .await;
+            result
+        })
+    }
+}
+bindings::export!(Component with_types_in bindings);
@@ -0,0 +1,263 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:all-golem-imports/all-golem-imports
+// wit: sha256:3ec76077ee8750a4a3d97cf8f89273803d43fba31aa02651185015b5648e2fc7
+// js all-golem-imports: sha256:1aa6d68c08d678e85c1f334bb55fc7062ce81fa0f90be1bc0d6a3965bfccc568
+
+import * as blobstore from 'wasi:blobstore/blobstore';
+import * as types from 'wasi:blobstore/types';
+
+// Size of the reads from the host's input streams
+const READ_CHUNK_SIZE = 65536n;
+// The most bytes `blocking-write-and-flush` accepts in a single call
+const WRITE_CHUNK_SIZE = 4096;
+// Number of object names read from the host in a single call when listing the objects
+const LIST_BATCH_SIZE = 100n;
+
+export class BlobstoreError extends Error {
+    constructor(message, options) {
+        super(message, options);
+        this.name = 'BlobstoreError';
+    }
+}
+
+// Runs a call of the host interface, converting the thrown error strings to `BlobstoreError`s
+function call(operation, f) {
+    try {
+        return f();
+    } catch (error) {
+        if (error instanceof Error) {
+            throw error;
+        }
+        throw new BlobstoreError(`Failed to ${operation}: ${describe(error)}`, { cause: error });
+    }
+}
+
+// Describes the errors of the blobstore (strings) and of the I/O streams (`stream-error` variants)
+function describe(error) {
+    if (error?.tag === 'closed') {
+        return 'the stream is closed';
+    }
+    if (error?.tag === 'last-operation-failed') {
+        const inner = error.val;
+        return typeof inner?.toDebugString === 'function' ? inner.toDebugString() : String(inner);
+    }
+    return String(error);
+}
+
+function toBytes(value) {
+    if (typeof value === 'string') {
+        return new TextEncoder().encode(value);
+    }
+    if (value instanceof Uint8Array) {
+        return value;
+    }
+    if (ArrayBuffer.isView(value)) {
+        return new Uint8Array(value.buffer, value.byteOffset, value.byteLength);
+    }
+    if (value instanceof ArrayBuffer) {
+        return new Uint8Array(value);
+    }
+    throw new TypeError('The data must be a string, an ArrayBuffer or a typed array');
+}
+
+function toDate(seconds) {
+    return new Date(Number(seconds) * 1000);
+}
+
+function toObjectInfo(metadata) {
+    return {
+        name: metadata.name,
+        container: metadata.container,
+        createdAt: toDate(metadata.createdAt),
+        size: Number(metadata.size),
+    };
+}
+
+function writeAll(stream, bytes, operation) {
+    for (let offset = 0; offset < bytes.length; offset += WRITE_CHUNK_SIZE) {
+        const chunk = bytes.subarray(offset, offset + WRITE_CHUNK_SIZE);
+        call(operation, () => stream.blockingWriteAndFlush(chunk));
+    }
+}
+
+export class Container {
+    #container;
+
+    constructor(name, container) {
+        this.name = name;
+        this.#container = container;
+    }
+
+    async info() {
+        const metadata = call(`get the info of the container ${this.name}`, () => this.#container.info());
+        return { name: metadata.name, createdAt: toDate(metadata.createdAt) };
+    }
+
+    // Resolves the inclusive byte range of `get-data`, or `undefined` if the range is empty
+    #range(name, options) {
+        const start = BigInt(options?.start ?? 0);
+        let end;
+        if (options?.end !== undefined) {
+            end = BigInt(options.end) - 1n;
+        } else {
+            const size = call(`get the info of the object ${name}`, () => this.#container.objectInfo(name)).size;
+            end = size - 1n;
+        }
+        return end < start ? undefined : [start, end];
+    }
+
+    async get(name, options) {
+        const range = this.#range(name, options);
+        if (range === undefined) {
+            return new Uint8Array(0);
+        }
+        const incoming = call(`get the object ${name}`, () => this.#container.getData(name, range[0], range[1]));
+        return call(`read the object ${name}`, () => incoming.incomingValueConsumeSync());
+    }
+
+    async getText(name, options) {
+        return new TextDecoder().decode(await this.get(name, options));
+    }
+
+    readable(name, options) {
+        let stream;
+        return new ReadableStream({
+            start: () => {
+                const range = this.#range(name, options);
+                if (range !== undefined) {
+                    const incoming = call(`get the object ${name}`, () => this.#container.getData(name, range[0], range[1]));
+                    stream = call(`read the object ${name}`, () => incoming.incomingValueConsumeAsync());
+                }
+            },
+            pull: (controller) => {
+                if (stream === undefined) {
+                    controller.close();
+                    return;
+                }
+                try {
+                    controller.enqueue(stream.blockingRead(READ_CHUNK_SIZE));
+                } catch (error) {
+                    stream[Symbol.dispose]();
+                    stream = undefined;
+                    if (error?.tag === 'closed') {
+                        controller.close();
+                    } else {
+                        throw new BlobstoreError(`Failed to read the object ${name}: ${describe(error)}`, { cause: error });
+                    }
+                }
+            },
+            cancel: () => {
+                stream?.[Symbol.dispose]();
+                stream = undefined;
+            },
+        });
+    }
+
+    async put(name, data) {
+        if (data instanceof ReadableStream) {
+            await data.pipeTo(this.writable(name));
+            return;
+        }
+        const outgoing = types.OutgoingValue.newOutgoingValue();
+        const body = call(`write the object ${name}`, () => outgoing.outgoingValueWriteBody());
+        try {
+            writeAll(body, toBytes(data), `write the object ${name}`);
+        } finally {
+            body[Symbol.dispose]();
+        }
+        call(`write the object ${name}`, () => this.#container.writeData(name, outgoing));
+    }
+
+    writable(name) {
+        let outgoing;
+        let body;
+        const operation = `write the object ${name}`;
+        return new WritableStream({
+            start: () => {
+                outgoing = types.OutgoingValue.newOutgoingValue();
+                body = call(operation, () => outgoing.outgoingValueWriteBody());
+            },
+            write: (chunk) => {
+                writeAll(body, toBytes(chunk), operation);
+            },
+            close: () => {
+                body[Symbol.dispose]();
+                call(operation, () => this.#container.writeData(name, outgoing));
+            },
+            abort: () => {
+                body[Symbol.dispose]();
+            },
+        });
+    }
+
+    async delete(name) {
+        call(`delete the object ${name}`, () => this.#container.deleteObject(name));
+    }
+
+    async deleteMany(names) {
+        call(`delete the objects of the container ${this.name}`, () => this.#container.deleteObjects(names));
+    }
+
+    async has(name) {
+        return call(`check the object ${name}`, () => this.#container.hasObject(name));
+    }
+
+    async objectInfo(name) {
+        return toObjectInfo(call(`get the info of the object ${name}`, () => this.#container.objectInfo(name)));
+    }
+
+    async* objects() {
+        const operation = `list the objects of the container ${this.name}`;
+        const names = call(operation, () => this.#container.listObjects());
+        try {
+            let finished = false;
+            while (!finished) {
+                const [batch, end] = call(operation, () => names.readStreamObjectNames(LIST_BATCH_SIZE));
+                yield* batch;
+                finished = end;
+            }
+        } finally {
+            names[Symbol.dispose]();
+        }
+    }
+
+    async list() {
+        const result = [];
+        for await (const name of this.objects()) {
+            result.push(name);
+        }
+        return result;
+    }
+
+    async clear() {
+        call(`clear the container ${this.name}`, () => this.#container.clear());
+    }
+
+    [Symbol.asyncIterator]() {
+        return this.objects();
+    }
+}
+
+export async function createContainer(name) {
+    return new Container(name, call(`create the container ${name}`, () => blobstore.createContainer(name)));
+}
+
+export async function getContainer(name) {
+    return new Container(name, call(`get the container ${name}`, () => blobstore.getContainer(name)));
+}
+
+export async function deleteContainer(name) {
+    call(`delete the container ${name}`, () => blobstore.deleteContainer(name));
+}
+
+export async function containerExists(name) {
+    return call(`check the container ${name}`, () => blobstore.containerExists(name));
+}
+
+export async function copyObject(src, dest) {
+    call(`copy the object ${src.object}`, () => blobstore.copyObject(src, dest));
+}
+
+export async function moveObject(src, dest) {
+    call(`move the object ${src.object}`, () => blobstore.moveObject(src, dest));
+}
@@ -0,0 +1,53 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:all-golem-imports/all-golem-imports
+// wit: sha256:3ec76077ee8750a4a3d97cf8f89273803d43fba31aa02651185015b5648e2fc7
+// js all-golem-imports: sha256:1aa6d68c08d678e85c1f334bb55fc7062ce81fa0f90be1bc0d6a3965bfccc568
+
+import * as host from 'golem:api/host@1.1.7';
+
+// Runs `f`, and runs `cleanup` when it finished - if `f` returns a promise, after it settled
+function withCleanup(f, cleanup) {
+    let result;
+    try {
+        result = f();
+    } catch (error) {
+        cleanup();
+        throw error;
+    }
+    if (result instanceof Promise) {
+        return result.finally(cleanup);
+    }
+    cleanup();
+    return result;
+}
+
+export function atomically(f) {
+    const begin = host.markBeginOperation();
+    return withCleanup(f, () => host.markEndOperation(begin));
+}
+
+export function withIdempotenceMode(idempotent, f) {
+    const original = host.getIdempotenceMode();
+    host.setIdempotenceMode(idempotent);
+    return withCleanup(f, () => host.setIdempotenceMode(original));
+}
+
+export function withRetryPolicy(policy, f) {
+    const original = host.getRetryPolicy();
+    host.setRetryPolicy(policy);
+    return withCleanup(f, () => host.setRetryPolicy(original));
+}
+
+export function withPersistenceLevel(level, f) {
+    const original = host.getOplogPersistenceLevel();
+    host.setOplogPersistenceLevel(typeof level === 'string' ? { tag: level } : level);
+    return withCleanup(f, () => host.setOplogPersistenceLevel(original));
+}
+
+export function generateIdempotencyKey() {
+    return host.generateIdempotencyKey();
+}
+
+export function oplogCommit(replicas) {
+    host.oplogCommit(replicas);
+}
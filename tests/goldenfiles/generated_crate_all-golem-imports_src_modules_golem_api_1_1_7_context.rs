@@ -0,0 +1,642 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:all-golem-imports/all-golem-imports
+// wit: sha256:3ec76077ee8750a4a3d97cf8f89273803d43fba31aa02651185015b5648e2fc7
+// js all-golem-imports: sha256:1aa6d68c08d678e85c1f334bb55fc7062ce81fa0f90be1bc0d6a3965bfccc568
+
+use rquickjs::JsLifetime;
+use rquickjs::class::{JsClass, Trace};
+use std::ops::Deref;
+#[rquickjs::function]
+fn start_span(
+    name: crate::wrappers::JsString,
+) -> crate::bindings::golem::api::context::Span {
+    let result: crate::bindings::golem::api::context::Span = crate::bindings::golem::api::context::start_span(
+        name.0.as_str(),
+    );
+    result
+}
+#[rquickjs::function]
+fn current_context() -> crate::bindings::golem::api::context::InvocationContext {
+    let result: crate::bindings::golem::api::context::InvocationContext = crate::bindings::golem::api::context::current_context();
+    result
+}
+#[rquickjs::function]
+fn allow_forwarding_trace_context_headers(allow: bool) -> bool {
+    let result: bool = crate::bindings::golem::api::context::allow_forwarding_trace_context_headers(
+        allow,
+    );
+    result
+}
+#[derive(Clone, JsLifetime, Trace)]
+pub struct Span {
+    #[qjs(skip_trace = true)]
+    inner: Option<std::rc::Rc<crate::bindings::golem::api::context::Span>>,
+    /// The export call epoch if the handle is only borrowed from the host
+    #[qjs(skip_trace = true)]
+    borrow_epoch: Option<usize>,
+}
+mod __impl_class_span_ {
+    pub use super::*;
+    use rquickjs::{Atom, Symbol, Value};
+    impl<'js> rquickjs::class::JsClass<'js> for Span {
+        const NAME: &'static str = "Span";
+        type Mutable = rquickjs::class::Writable;
+        fn prototype(
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<Option<rquickjs::Object<'js>>> {
+            use rquickjs::class::impl_::MethodImplementor;
+            let proto = rquickjs::Object::new(ctx.clone())?;
+            let implementor = rquickjs::class::impl_::MethodImpl::<Self>::new();
+            (&implementor).implement(&proto)?;
+            let dispose_symbol: Symbol = ctx
+                .globals()
+                .get(crate::internal::DISPOSE_SYMBOL)?;
+            let dispose_fn: Value = proto.get("__dispose")?;
+            proto.set(dispose_symbol, dispose_fn)?;
+            Ok(Some(proto))
+        }
+        fn constructor(
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<Option<rquickjs::function::Constructor<'js>>> {
+            use rquickjs::class::impl_::ConstructorCreator;
+            let implementor = rquickjs::class::impl_::ConstructorCreate::<Self>::new();
+            (&implementor).create_constructor(ctx)
+        }
+    }
+    impl<'js> rquickjs::IntoJs<'js> for Span {
+        fn into_js(
+            self,
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<rquickjs::Value<'js>> {
+            let cls = rquickjs::class::Class::<Self>::instance(ctx.clone(), self)?;
+            rquickjs::IntoJs::into_js(cls, ctx)
+        }
+    }
+    impl<'js> rquickjs::FromJs<'js> for Span
+    where
+        for<'a> rquickjs::class::impl_::CloneWrapper<
+            'a,
+            Self,
+        >: rquickjs::class::impl_::CloneTrait<Self>,
+    {
+        fn from_js(
+            ctx: &rquickjs::Ctx<'js>,
+            value: rquickjs::Value<'js>,
+        ) -> rquickjs::Result<Self> {
+            use rquickjs::class::impl_::CloneTrait;
+            let value = rquickjs::class::Class::<Self>::from_js(ctx, value)?;
+            let borrow = value.try_borrow()?;
+            Ok(rquickjs::class::impl_::CloneWrapper(&*borrow).wrap_clone())
+        }
+    }
+}
+impl Span {
+    /// Gets the wrapped resource, failing if it has been disposed or if it is a
+    /// borrowed handle and the call it was passed to has already returned
+    fn checked_inner(
+        &self,
+    ) -> Result<&std::rc::Rc<crate::bindings::golem::api::context::Span>, &'static str> {
+        if self
+            .borrow_epoch
+            .is_some_and(|epoch| !crate::internal::is_borrow_active(epoch))
+        {
+            return Err(
+                "Borrowed resource handle cannot be used after the call it was passed to has returned",
+            );
+        }
+        self.inner.as_ref().ok_or("Resource has already been disposed")
+    }
+    /// Gets the wrapped resource for calling one of its methods, throwing a
+    /// `ReferenceError` if it cannot be used
+    fn resource(
+        &self,
+        ctx: &rquickjs::Ctx<'_>,
+    ) -> rquickjs::Result<&crate::bindings::golem::api::context::Span> {
+        self.checked_inner()
+            .map(|inner| inner.deref())
+            .map_err(|message| rquickjs::Exception::throw_reference(ctx, message))
+    }
+    /// Releases the wrapped resource. Borrowed handles belong to the host, so they
+    /// are never dropped, only forgotten.
+    fn release(&mut self) {
+        if let Some(inner) = self.inner.take() {
+            if self.borrow_epoch.is_some() {
+                if let Ok(inner) = std::rc::Rc::try_unwrap(inner) {
+                    let _ = inner.take_handle();
+                }
+            }
+        }
+    }
+}
+impl Drop for Span {
+    fn drop(&mut self) {
+        self.release();
+    }
+}
+#[rquickjs::methods(rename_all = "camelCase")]
+impl Span {
+    #[qjs(constructor)]
+    pub fn new() -> Self {
+        Self {
+            inner: None,
+            borrow_epoch: None,
+        }
+    }
+    pub fn started_at(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+    ) -> rquickjs::Result<crate::bindings::wasi::clocks::wall_clock::Datetime> {
+        let result: crate::bindings::golem::api::context::Datetime = self
+            .resource(&ctx)?
+            .started_at();
+        Ok(result)
+    }
+    pub fn set_attribute(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        name: crate::wrappers::JsString,
+        value: crate::bindings::golem::api::context::AttributeValue,
+    ) -> rquickjs::Result<()> {
+        let result: () = self.resource(&ctx)?.set_attribute(name.0.as_str(), &value);
+        Ok(result)
+    }
+    pub fn set_attributes(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        attributes: crate::wrappers::JsList<
+            crate::bindings::golem::api::context::Attribute,
+        >,
+    ) -> rquickjs::Result<()> {
+        let result: () = self
+            .resource(&ctx)?
+            .set_attributes(
+                attributes.0.into_iter().map(|v| v).collect::<Vec<_>>().as_slice(),
+            );
+        Ok(result)
+    }
+    pub fn finish(&self, ctx: rquickjs::Ctx<'_>) -> rquickjs::Result<()> {
+        let result: () = self.resource(&ctx)?.finish();
+        Ok(result)
+    }
+    #[qjs(rename = "__dispose")]
+    pub fn __dispose(&mut self) {
+        self.release();
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::api::context::Span {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        Span {
+            inner: Some(std::rc::Rc::new(self)),
+            borrow_epoch: None,
+        }
+            .into_js(ctx)
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::api::context::Span {
+    fn from_js(
+        ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let wrapper = Span::from_js(ctx, value)?;
+        if wrapper.borrow_epoch.is_some() {
+            return Err(rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "Span",
+                message: Some(
+                    "The ownership of a borrowed resource handle cannot be transferred"
+                        .to_string(),
+                ),
+            });
+        }
+        let inner = wrapper
+            .checked_inner()
+            .map_err(|message| rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "Span",
+                message: Some(message.to_string()),
+            })?;
+        unsafe {
+            Ok(
+                crate::bindings::golem::api::context::Span::from_handle(
+                    inner.take_handle(),
+                ),
+            )
+        }
+    }
+}
+pub struct BorrowSpanWrapper(pub crate::bindings::golem::api::context::Span);
+impl<'js> rquickjs::FromJs<'js> for BorrowSpanWrapper {
+    fn from_js(
+        ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let wrapper = Span::from_js(ctx, value)?;
+        let inner = wrapper
+            .checked_inner()
+            .map_err(|message| rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "Span",
+                message: Some(message.to_string()),
+            })?;
+        unsafe {
+            Ok(
+                BorrowSpanWrapper(
+                    crate::bindings::golem::api::context::Span::from_handle(
+                        inner.handle(),
+                    ),
+                ),
+            )
+        }
+    }
+}
+impl BorrowSpanWrapper {
+    /// Borrows a handle passed to an exported function, without taking its ownership
+    pub fn from_ref(resource: &crate::bindings::golem::api::context::Span) -> Self {
+        unsafe {
+            BorrowSpanWrapper(
+                crate::bindings::golem::api::context::Span::from_handle(
+                    resource.handle(),
+                ),
+            )
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for BorrowSpanWrapper {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let resource = unsafe {
+            crate::bindings::golem::api::context::Span::from_handle(self.0.take_handle())
+        };
+        Span {
+            inner: Some(std::rc::Rc::new(resource)),
+            borrow_epoch: Some(crate::internal::current_borrow_epoch()),
+        }
+            .into_js(ctx)
+    }
+}
+impl Drop for BorrowSpanWrapper {
+    fn drop(&mut self) {
+        let _ = self.0.take_handle();
+    }
+}
+#[derive(Clone, JsLifetime, Trace)]
+pub struct InvocationContext {
+    #[qjs(skip_trace = true)]
+    inner: Option<std::rc::Rc<crate::bindings::golem::api::context::InvocationContext>>,
+    /// The export call epoch if the handle is only borrowed from the host
+    #[qjs(skip_trace = true)]
+    borrow_epoch: Option<usize>,
+}
+mod __impl_class_invocation_context_ {
+    pub use super::*;
+    use rquickjs::{Atom, Symbol, Value};
+    impl<'js> rquickjs::class::JsClass<'js> for InvocationContext {
+        const NAME: &'static str = "InvocationContext";
+        type Mutable = rquickjs::class::Writable;
+        fn prototype(
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<Option<rquickjs::Object<'js>>> {
+            use rquickjs::class::impl_::MethodImplementor;
+            let proto = rquickjs::Object::new(ctx.clone())?;
+            let implementor = rquickjs::class::impl_::MethodImpl::<Self>::new();
+            (&implementor).implement(&proto)?;
+            let dispose_symbol: Symbol = ctx
+                .globals()
+                .get(crate::internal::DISPOSE_SYMBOL)?;
+            let dispose_fn: Value = proto.get("__dispose")?;
+            proto.set(dispose_symbol, dispose_fn)?;
+            Ok(Some(proto))
+        }
+        fn constructor(
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<Option<rquickjs::function::Constructor<'js>>> {
+            use rquickjs::class::impl_::ConstructorCreator;
+            let implementor = rquickjs::class::impl_::ConstructorCreate::<Self>::new();
+            (&implementor).create_constructor(ctx)
+        }
+    }
+    impl<'js> rquickjs::IntoJs<'js> for InvocationContext {
+        fn into_js(
+            self,
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<rquickjs::Value<'js>> {
+            let cls = rquickjs::class::Class::<Self>::instance(ctx.clone(), self)?;
+            rquickjs::IntoJs::into_js(cls, ctx)
+        }
+    }
+    impl<'js> rquickjs::FromJs<'js> for InvocationContext
+    where
+        for<'a> rquickjs::class::impl_::CloneWrapper<
+            'a,
+            Self,
+        >: rquickjs::class::impl_::CloneTrait<Self>,
+    {
+        fn from_js(
+            ctx: &rquickjs::Ctx<'js>,
+            value: rquickjs::Value<'js>,
+        ) -> rquickjs::Result<Self> {
+            use rquickjs::class::impl_::CloneTrait;
+            let value = rquickjs::class::Class::<Self>::from_js(ctx, value)?;
+            let borrow = value.try_borrow()?;
+            Ok(rquickjs::class::impl_::CloneWrapper(&*borrow).wrap_clone())
+        }
+    }
+}
+impl InvocationContext {
+    /// Gets the wrapped resource, failing if it has been disposed or if it is a
+    /// borrowed handle and the call it was passed to has already returned
+    fn checked_inner(
+        &self,
+    ) -> Result<
+        &std::rc::Rc<crate::bindings::golem::api::context::InvocationContext>,
+        &'static str,
+    > {
+        if self
+            .borrow_epoch
+            .is_some_and(|epoch| !crate::internal::is_borrow_active(epoch))
+        {
+            return Err(
+                "Borrowed resource handle cannot be used after the call it was passed to has returned",
+            );
+        }
+        self.inner.as_ref().ok_or("Resource has already been disposed")
+    }
+    /// Gets the wrapped resource for calling one of its methods, throwing a
+    /// `ReferenceError` if it cannot be used
+    fn resource(
+        &self,
+        ctx: &rquickjs::Ctx<'_>,
+    ) -> rquickjs::Result<&crate::bindings::golem::api::context::InvocationContext> {
+        self.checked_inner()
+            .map(|inner| inner.deref())
+            .map_err(|message| rquickjs::Exception::throw_reference(ctx, message))
+    }
+    /// Releases the wrapped resource. Borrowed handles belong to the host, so they
+    /// are never dropped, only forgotten.
+    fn release(&mut self) {
+        if let Some(inner) = self.inner.take() {
+            if self.borrow_epoch.is_some() {
+                if let Ok(inner) = std::rc::Rc::try_unwrap(inner) {
+                    let _ = inner.take_handle();
+                }
+            }
+        }
+    }
+}
+impl Drop for InvocationContext {
+    fn drop(&mut self) {
+        self.release();
+    }
+}
+#[rquickjs::methods(rename_all = "camelCase")]
+impl InvocationContext {
+    #[qjs(constructor)]
+    pub fn new() -> Self {
+        Self {
+            inner: None,
+            borrow_epoch: None,
+        }
+    }
+    pub fn trace_id(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+    ) -> rquickjs::Result<crate::wrappers::JsString> {
+        let result: crate::bindings::golem::api::context::TraceId = self
+            .resource(&ctx)?
+            .trace_id();
+        Ok(crate::wrappers::JsString(result))
+    }
+    pub fn span_id(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+    ) -> rquickjs::Result<crate::wrappers::JsString> {
+        let result: crate::bindings::golem::api::context::SpanId = self
+            .resource(&ctx)?
+            .span_id();
+        Ok(crate::wrappers::JsString(result))
+    }
+    pub fn parent(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+    ) -> rquickjs::Result<
+        Option<crate::bindings::golem::api::context::InvocationContext>,
+    > {
+        let result: Option<crate::bindings::golem::api::context::InvocationContext> = self
+            .resource(&ctx)?
+            .parent();
+        Ok(result.map(|v| v))
+    }
+    pub fn get_attribute(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        key: crate::wrappers::JsString,
+        inherited: bool,
+    ) -> rquickjs::Result<Option<crate::bindings::golem::api::context::AttributeValue>> {
+        let result: Option<crate::bindings::golem::api::context::AttributeValue> = self
+            .resource(&ctx)?
+            .get_attribute(key.0.as_str(), inherited);
+        Ok(result.map(|v| v))
+    }
+    pub fn get_attributes(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        inherited: bool,
+    ) -> rquickjs::Result<
+        crate::wrappers::JsList<crate::bindings::golem::api::context::Attribute>,
+    > {
+        let result: Vec<crate::bindings::golem::api::context::Attribute> = self
+            .resource(&ctx)?
+            .get_attributes(inherited);
+        Ok(crate::wrappers::JsList(result.into_iter().map(|v| v).collect::<Vec<_>>()))
+    }
+    pub fn get_attribute_chain(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        key: crate::wrappers::JsString,
+    ) -> rquickjs::Result<
+        crate::wrappers::JsList<crate::bindings::golem::api::context::AttributeValue>,
+    > {
+        let result: Vec<crate::bindings::golem::api::context::AttributeValue> = self
+            .resource(&ctx)?
+            .get_attribute_chain(key.0.as_str());
+        Ok(crate::wrappers::JsList(result.into_iter().map(|v| v).collect::<Vec<_>>()))
+    }
+    pub fn get_attribute_chains(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+    ) -> rquickjs::Result<
+        crate::wrappers::JsList<crate::bindings::golem::api::context::AttributeChain>,
+    > {
+        let result: Vec<crate::bindings::golem::api::context::AttributeChain> = self
+            .resource(&ctx)?
+            .get_attribute_chains();
+        Ok(crate::wrappers::JsList(result.into_iter().map(|v| v).collect::<Vec<_>>()))
+    }
+    pub fn trace_context_headers(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+    ) -> rquickjs::Result<
+        crate::wrappers::JsList<
+            rquickjs::convert::List<
+                (crate::wrappers::JsString, crate::wrappers::JsString),
+            >,
+        >,
+    > {
+        let result: Vec<(String, String)> = self.resource(&ctx)?.trace_context_headers();
+        Ok(
+            crate::wrappers::JsList(
+                result
+                    .into_iter()
+                    .map(|v| rquickjs::convert::List((
+                        crate::wrappers::JsString(v.0),
+                        crate::wrappers::JsString(v.1),
+                    )))
+                    .collect::<Vec<_>>(),
+            ),
+        )
+    }
+    #[qjs(rename = "__dispose")]
+    pub fn __dispose(&mut self) {
+        self.release();
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::api::context::InvocationContext {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        InvocationContext {
+            inner: Some(std::rc::Rc::new(self)),
+            borrow_epoch: None,
+        }
+            .into_js(ctx)
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::api::context::InvocationContext {
+    fn from_js(
+        ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let wrapper = InvocationContext::from_js(ctx, value)?;
+        if wrapper.borrow_epoch.is_some() {
+            return Err(rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "InvocationContext",
+                message: Some(
+                    "The ownership of a borrowed resource handle cannot be transferred"
+                        .to_string(),
+                ),
+            });
+        }
+        let inner = wrapper
+            .checked_inner()
+            .map_err(|message| rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "InvocationContext",
+                message: Some(message.to_string()),
+            })?;
+        unsafe {
+            Ok(
+                crate::bindings::golem::api::context::InvocationContext::from_handle(
+                    inner.take_handle(),
+                ),
+            )
+        }
+    }
+}
+pub struct BorrowInvocationContextWrapper(
+    pub crate::bindings::golem::api::context::InvocationContext,
+);
+impl<'js> rquickjs::FromJs<'js> for BorrowInvocationContextWrapper {
+    fn from_js(
+        ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let wrapper = InvocationContext::from_js(ctx, value)?;
+        let inner = wrapper
+            .checked_inner()
+            .map_err(|message| rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "InvocationContext",
+                message: Some(message.to_string()),
+            })?;
+        unsafe {
+            Ok(
+                BorrowInvocationContextWrapper(
+                    crate::bindings::golem::api::context::InvocationContext::from_handle(
+                        inner.handle(),
+                    ),
+                ),
+            )
+        }
+    }
+}
+impl BorrowInvocationContextWrapper {
+    /// Borrows a handle passed to an exported function, without taking its ownership
+    pub fn from_ref(
+        resource: &crate::bindings::golem::api::context::InvocationContext,
+    ) -> Self {
+        unsafe {
+            BorrowInvocationContextWrapper(
+                crate::bindings::golem::api::context::InvocationContext::from_handle(
+                    resource.handle(),
+                ),
+            )
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for BorrowInvocationContextWrapper {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let resource = unsafe {
+            crate::bindings::golem::api::context::InvocationContext::from_handle(
+                self.0.take_handle(),
+            )
+        };
+        InvocationContext {
+            inner: Some(std::rc::Rc::new(resource)),
+            borrow_epoch: Some(crate::internal::current_borrow_epoch()),
+        }
+            .into_js(ctx)
+    }
+}
+impl Drop for BorrowInvocationContextWrapper {
+    fn drop(&mut self) {
+        let _ = self.0.take_handle();
+    }
+}
+pub struct JsContextModule;
+impl rquickjs::module::ModuleDef for JsContextModule {
+    fn declare(decl: &rquickjs::module::Declarations) -> rquickjs::Result<()> {
+        decl.declare("startSpan")?;
+        decl.declare("currentContext")?;
+        decl.declare("allowForwardingTraceContextHeaders")?;
+        decl.declare("Span")?;
+        decl.declare("InvocationContext")?;
+        Ok(())
+    }
+    fn evaluate<'js>(
+        ctx: &rquickjs::Ctx<'js>,
+        exports: &rquickjs::module::Exports<'js>,
+    ) -> rquickjs::Result<()> {
+        exports.export("startSpan", js_start_span)?;
+        exports.export("currentContext", js_current_context)?;
+        exports
+            .export(
+                "allowForwardingTraceContextHeaders",
+                js_allow_forwarding_trace_context_headers,
+            )?;
+        exports.export("Span", Span::constructor(ctx)?)?;
+        exports.export("InvocationContext", InvocationContext::constructor(ctx)?)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,548 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:all-golem-imports/all-golem-imports
+// wit: sha256:3ec76077ee8750a4a3d97cf8f89273803d43fba31aa02651185015b5648e2fc7
+// js all-golem-imports: sha256:1aa6d68c08d678e85c1f334bb55fc7062ce81fa0f90be1bc0d6a3965bfccc568
+
+use rquickjs::JsLifetime;
+use rquickjs::class::{JsClass, Trace};
+use std::ops::Deref;
+#[derive(Clone, JsLifetime, Trace)]
+pub struct GetOplog {
+    #[qjs(skip_trace = true)]
+    inner: Option<std::rc::Rc<crate::bindings::golem::api::oplog::GetOplog>>,
+    /// The export call epoch if the handle is only borrowed from the host
+    #[qjs(skip_trace = true)]
+    borrow_epoch: Option<usize>,
+}
+mod __impl_class_get_oplog_ {
+    pub use super::*;
+    use rquickjs::{Atom, Symbol, Value};
+    impl<'js> rquickjs::class::JsClass<'js> for GetOplog {
+        const NAME: &'static str = "GetOplog";
+        type Mutable = rquickjs::class::Writable;
+        fn prototype(
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<Option<rquickjs::Object<'js>>> {
+            use rquickjs::class::impl_::MethodImplementor;
+            let proto = rquickjs::Object::new(ctx.clone())?;
+            let implementor = rquickjs::class::impl_::MethodImpl::<Self>::new();
+            (&implementor).implement(&proto)?;
+            let dispose_symbol: Symbol = ctx
+                .globals()
+                .get(crate::internal::DISPOSE_SYMBOL)?;
+            let dispose_fn: Value = proto.get("__dispose")?;
+            proto.set(dispose_symbol, dispose_fn)?;
+            Ok(Some(proto))
+        }
+        fn constructor(
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<Option<rquickjs::function::Constructor<'js>>> {
+            use rquickjs::class::impl_::ConstructorCreator;
+            let implementor = rquickjs::class::impl_::ConstructorCreate::<Self>::new();
+            (&implementor).create_constructor(ctx)
+        }
+    }
+    impl<'js> rquickjs::IntoJs<'js> for GetOplog {
+        fn into_js(
+            self,
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<rquickjs::Value<'js>> {
+            let cls = rquickjs::class::Class::<Self>::instance(ctx.clone(), self)?;
+            rquickjs::IntoJs::into_js(cls, ctx)
+        }
+    }
+    impl<'js> rquickjs::FromJs<'js> for GetOplog
+    where
+        for<'a> rquickjs::class::impl_::CloneWrapper<
+            'a,
+            Self,
+        >: rquickjs::class::impl_::CloneTrait<Self>,
+    {
+        fn from_js(
+            ctx: &rquickjs::Ctx<'js>,
+            value: rquickjs::Value<'js>,
+        ) -> rquickjs::Result<Self> {
+            use rquickjs::class::impl_::CloneTrait;
+            let value = rquickjs::class::Class::<Self>::from_js(ctx, value)?;
+            let borrow = value.try_borrow()?;
+            Ok(rquickjs::class::impl_::CloneWrapper(&*borrow).wrap_clone())
+        }
+    }
+}
+impl GetOplog {
+    /// Gets the wrapped resource, failing if it has been disposed or if it is a
+    /// borrowed handle and the call it was passed to has already returned
+    fn checked_inner(
+        &self,
+    ) -> Result<
+        &std::rc::Rc<crate::bindings::golem::api::oplog::GetOplog>,
+        &'static str,
+    > {
+        if self
+            .borrow_epoch
+            .is_some_and(|epoch| !crate::internal::is_borrow_active(epoch))
+        {
+            return Err(
+                "Borrowed resource handle cannot be used after the call it was passed to has returned",
+            );
+        }
+        self.inner.as_ref().ok_or("Resource has already been disposed")
+    }
+    /// Gets the wrapped resource for calling one of its methods, throwing a
+    /// `ReferenceError` if it cannot be used
+    fn resource(
+        &self,
+        ctx: &rquickjs::Ctx<'_>,
+    ) -> rquickjs::Result<&crate::bindings::golem::api::oplog::GetOplog> {
+        self.checked_inner()
+            .map(|inner| inner.deref())
+            .map_err(|message| rquickjs::Exception::throw_reference(ctx, message))
+    }
+    /// Releases the wrapped resource. Borrowed handles belong to the host, so they
+    /// are never dropped, only forgotten.
+    fn release(&mut self) {
+        if let Some(inner) = self.inner.take() {
+            if self.borrow_epoch.is_some() {
+                if let Ok(inner) = std::rc::Rc::try_unwrap(inner) {
+                    let _ = inner.take_handle();
+                }
+            }
+        }
+    }
+}
+impl Drop for GetOplog {
+    fn drop(&mut self) {
+        self.release();
+    }
+}
+#[rquickjs::methods(rename_all = "camelCase")]
+impl GetOplog {
+    #[qjs(constructor)]
+    pub fn new(
+        worker_id: crate::bindings::golem::rpc::types::WorkerId,
+        start: crate::wrappers::BigIntWrapper<u64>,
+    ) -> Self {
+        Self {
+            inner: Some(
+                std::rc::Rc::new(
+                    crate::bindings::golem::api::oplog::GetOplog::new(
+                        &worker_id,
+                        start.0,
+                    ),
+                ),
+            ),
+            borrow_epoch: None,
+        }
+    }
+    pub fn get_next(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+    ) -> rquickjs::Result<
+        Option<crate::wrappers::JsList<crate::bindings::golem::api::oplog::OplogEntry>>,
+    > {
+        let result: Option<Vec<crate::bindings::golem::api::oplog::OplogEntry>> = self
+            .resource(&ctx)?
+            .get_next();
+        Ok(
+            result
+                .map(|v| crate::wrappers::JsList(
+                    v.into_iter().map(|v| v).collect::<Vec<_>>(),
+                )),
+        )
+    }
+    #[qjs(rename = "__dispose")]
+    pub fn __dispose(&mut self) {
+        self.release();
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::api::oplog::GetOplog {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        GetOplog {
+            inner: Some(std::rc::Rc::new(self)),
+            borrow_epoch: None,
+        }
+            .into_js(ctx)
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::api::oplog::GetOplog {
+    fn from_js(
+        ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let wrapper = GetOplog::from_js(ctx, value)?;
+        if wrapper.borrow_epoch.is_some() {
+            return Err(rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "GetOplog",
+                message: Some(
+                    "The ownership of a borrowed resource handle cannot be transferred"
+                        .to_string(),
+                ),
+            });
+        }
+        let inner = wrapper
+            .checked_inner()
+            .map_err(|message| rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "GetOplog",
+                message: Some(message.to_string()),
+            })?;
+        unsafe {
+            Ok(
+                crate::bindings::golem::api::oplog::GetOplog::from_handle(
+                    inner.take_handle(),
+                ),
+            )
+        }
+    }
+}
+pub struct BorrowGetOplogWrapper(pub crate::bindings::golem::api::oplog::GetOplog);
+impl<'js> rquickjs::FromJs<'js> for BorrowGetOplogWrapper {
+    fn from_js(
+        ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let wrapper = GetOplog::from_js(ctx, value)?;
+        let inner = wrapper
+            .checked_inner()
+            .map_err(|message| rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "GetOplog",
+                message: Some(message.to_string()),
+            })?;
+        unsafe {
+            Ok(
+                BorrowGetOplogWrapper(
+                    crate::bindings::golem::api::oplog::GetOplog::from_handle(
+                        inner.handle(),
+                    ),
+                ),
+            )
+        }
+    }
+}
+impl BorrowGetOplogWrapper {
+    /// Borrows a handle passed to an exported function, without taking its ownership
+    pub fn from_ref(resource: &crate::bindings::golem::api::oplog::GetOplog) -> Self {
+        unsafe {
+            BorrowGetOplogWrapper(
+                crate::bindings::golem::api::oplog::GetOplog::from_handle(
+                    resource.handle(),
+                ),
+            )
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for BorrowGetOplogWrapper {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let resource = unsafe {
+            crate::bindings::golem::api::oplog::GetOplog::from_handle(
+                self.0.take_handle(),
+            )
+        };
+        GetOplog {
+            inner: Some(std::rc::Rc::new(resource)),
+            borrow_epoch: Some(crate::internal::current_borrow_epoch()),
+        }
+            .into_js(ctx)
+    }
+}
+impl Drop for BorrowGetOplogWrapper {
+    fn drop(&mut self) {
+        let _ = self.0.take_handle();
+    }
+}
+#[derive(Clone, JsLifetime, Trace)]
+pub struct SearchOplog {
+    #[qjs(skip_trace = true)]
+    inner: Option<std::rc::Rc<crate::bindings::golem::api::oplog::SearchOplog>>,
+    /// The export call epoch if the handle is only borrowed from the host
+    #[qjs(skip_trace = true)]
+    borrow_epoch: Option<usize>,
+}
+mod __impl_class_search_oplog_ {
+    pub use super::*;
+    use rquickjs::{Atom, Symbol, Value};
+    impl<'js> rquickjs::class::JsClass<'js> for SearchOplog {
+        const NAME: &'static str = "SearchOplog";
+        type Mutable = rquickjs::class::Writable;
+        fn prototype(
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<Option<rquickjs::Object<'js>>> {
+            use rquickjs::class::impl_::MethodImplementor;
+            let proto = rquickjs::Object::new(ctx.clone())?;
+            let implementor = rquickjs::class::impl_::MethodImpl::<Self>::new();
+            (&implementor).implement(&proto)?;
+            let dispose_symbol: Symbol = ctx
+                .globals()
+                .get(crate::internal::DISPOSE_SYMBOL)?;
+            let dispose_fn: Value = proto.get("__dispose")?;
+            proto.set(dispose_symbol, dispose_fn)?;
+            Ok(Some(proto))
+        }
+        fn constructor(
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<Option<rquickjs::function::Constructor<'js>>> {
+            use rquickjs::class::impl_::ConstructorCreator;
+            let implementor = rquickjs::class::impl_::ConstructorCreate::<Self>::new();
+            (&implementor).create_constructor(ctx)
+        }
+    }
+    impl<'js> rquickjs::IntoJs<'js> for SearchOplog {
+        fn into_js(
+            self,
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<rquickjs::Value<'js>> {
+            let cls = rquickjs::class::Class::<Self>::instance(ctx.clone(), self)?;
+            rquickjs::IntoJs::into_js(cls, ctx)
+        }
+    }
+    impl<'js> rquickjs::FromJs<'js> for SearchOplog
+    where
+        for<'a> rquickjs::class::impl_::CloneWrapper<
+            'a,
+            Self,
+        >: rquickjs::class::impl_::CloneTrait<Self>,
+    {
+        fn from_js(
+            ctx: &rquickjs::Ctx<'js>,
+            value: rquickjs::Value<'js>,
+        ) -> rquickjs::Result<Self> {
+            use rquickjs::class::impl_::CloneTrait;
+            let value = rquickjs::class::Class::<Self>::from_js(ctx, value)?;
+            let borrow = value.try_borrow()?;
+            Ok(rquickjs::class::impl_::CloneWrapper(&*borrow).wrap_clone())
+        }
+    }
+}
+impl SearchOplog {
+    /// Gets the wrapped resource, failing if it has been disposed or if it is a
+    /// borrowed handle and the call it was passed to has already returned
+    fn checked_inner(
+        &self,
+    ) -> Result<
+        &std::rc::Rc<crate::bindings::golem::api::oplog::SearchOplog>,
+        &'static str,
+    > {
+        if self
+            .borrow_epoch
+            .is_some_and(|epoch| !crate::internal::is_borrow_active(epoch))
+        {
+            return Err(
+                "Borrowed resource handle cannot be used after the call it was passed to has returned",
+            );
+        }
+        self.inner.as_ref().ok_or("Resource has already been disposed")
+    }
+    /// Gets the wrapped resource for calling one of its methods, throwing a
+    /// `ReferenceError` if it cannot be used
+    fn resource(
+        &self,
+        ctx: &rquickjs::Ctx<'_>,
+    ) -> rquickjs::Result<&crate::bindings::golem::api::oplog::SearchOplog> {
+        self.checked_inner()
+            .map(|inner| inner.deref())
+            .map_err(|message| rquickjs::Exception::throw_reference(ctx, message))
+    }
+    /// Releases the wrapped resource. Borrowed handles belong to the host, so they
+    /// are never dropped, only forgotten.
+    fn release(&mut self) {
+        if let Some(inner) = self.inner.take() {
+            if self.borrow_epoch.is_some() {
+                if let Ok(inner) = std::rc::Rc::try_unwrap(inner) {
+                    let _ = inner.take_handle();
+                }
+            }
+        }
+    }
+}
+impl Drop for SearchOplog {
+    fn drop(&mut self) {
+        self.release();
+    }
+}
+#[rquickjs::methods(rename_all = "camelCase")]
+impl SearchOplog {
+    #[qjs(constructor)]
+    pub fn new(
+        worker_id: crate::bindings::golem::rpc::types::WorkerId,
+        text: crate::wrappers::JsString,
+    ) -> Self {
+        Self {
+            inner: Some(
+                std::rc::Rc::new(
+                    crate::bindings::golem::api::oplog::SearchOplog::new(
+                        &worker_id,
+                        text.0.as_str(),
+                    ),
+                ),
+            ),
+            borrow_epoch: None,
+        }
+    }
+    pub fn get_next(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+    ) -> rquickjs::Result<
+        Option<
+            crate::wrappers::JsList<
+                rquickjs::convert::List<
+                    (
+                        crate::wrappers::BigIntWrapper<u64>,
+                        crate::bindings::golem::api::oplog::OplogEntry,
+                    ),
+                >,
+            >,
+        >,
+    > {
+        let result: Option<
+            Vec<
+                (
+                    crate::bindings::golem::api::oplog::OplogIndex,
+                    crate::bindings::golem::api::oplog::OplogEntry,
+                ),
+            >,
+        > = self.resource(&ctx)?.get_next();
+        Ok(
+            result
+                .map(|v| crate::wrappers::JsList(
+                    v
+                        .into_iter()
+                        .map(|v| rquickjs::convert::List((
+                            crate::wrappers::BigIntWrapper(v.0),
+                            v.1,
+                        )))
+                        .collect::<Vec<_>>(),
+                )),
+        )
+    }
+    #[qjs(rename = "__dispose")]
+    pub fn __dispose(&mut self) {
+        self.release();
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::api::oplog::SearchOplog {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        SearchOplog {
+            inner: Some(std::rc::Rc::new(self)),
+            borrow_epoch: None,
+        }
+            .into_js(ctx)
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::api::oplog::SearchOplog {
+    fn from_js(
+        ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let wrapper = SearchOplog::from_js(ctx, value)?;
+        if wrapper.borrow_epoch.is_some() {
+            return Err(rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "SearchOplog",
+                message: Some(
+                    "The ownership of a borrowed resource handle cannot be transferred"
+                        .to_string(),
+                ),
+            });
+        }
+        let inner = wrapper
+            .checked_inner()
+            .map_err(|message| rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "SearchOplog",
+                message: Some(message.to_string()),
+            })?;
+        unsafe {
+            Ok(
+                crate::bindings::golem::api::oplog::SearchOplog::from_handle(
+                    inner.take_handle(),
+                ),
+            )
+        }
+    }
+}
+pub struct BorrowSearchOplogWrapper(pub crate::bindings::golem::api::oplog::SearchOplog);
+impl<'js> rquickjs::FromJs<'js> for BorrowSearchOplogWrapper {
+    fn from_js(
+        ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let wrapper = SearchOplog::from_js(ctx, value)?;
+        let inner = wrapper
+            .checked_inner()
+            .map_err(|message| rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "SearchOplog",
+                message: Some(message.to_string()),
+            })?;
+        unsafe {
+            Ok(
+                BorrowSearchOplogWrapper(
+                    crate::bindings::golem::api::oplog::SearchOplog::from_handle(
+                        inner.handle(),
+                    ),
+                ),
+            )
+        }
+    }
+}
+impl BorrowSearchOplogWrapper {
+    /// Borrows a handle passed to an exported function, without taking its ownership
+    pub fn from_ref(resource: &crate::bindings::golem::api::oplog::SearchOplog) -> Self {
+        unsafe {
+            BorrowSearchOplogWrapper(
+                crate::bindings::golem::api::oplog::SearchOplog::from_handle(
+                    resource.handle(),
+                ),
+            )
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for BorrowSearchOplogWrapper {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let resource = unsafe {
+            crate::bindings::golem::api::oplog::SearchOplog::from_handle(
+                self.0.take_handle(),
+            )
+        };
+        SearchOplog {
+            inner: Some(std::rc::Rc::new(resource)),
+            borrow_epoch: Some(crate::internal::current_borrow_epoch()),
+        }
+            .into_js(ctx)
+    }
+}
+impl Drop for BorrowSearchOplogWrapper {
+    fn drop(&mut self) {
+        let _ = self.0.take_handle();
+    }
+}
+pub struct JsOplogModule;
+impl rquickjs::module::ModuleDef for JsOplogModule {
+    fn declare(decl: &rquickjs::module::Declarations) -> rquickjs::Result<()> {
+        decl.declare("GetOplog")?;
+        decl.declare("SearchOplog")?;
+        Ok(())
+    }
+    fn evaluate<'js>(
+        ctx: &rquickjs::Ctx<'js>,
+        exports: &rquickjs::module::Exports<'js>,
+    ) -> rquickjs::Result<()> {
+        exports.export("GetOplog", GetOplog::constructor(ctx)?)?;
+        exports.export("SearchOplog", SearchOplog::constructor(ctx)?)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,398 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:all-golem-imports/all-golem-imports
+// wit: sha256:3ec76077ee8750a4a3d97cf8f89273803d43fba31aa02651185015b5648e2fc7
+// js all-golem-imports: sha256:1aa6d68c08d678e85c1f334bb55fc7062ce81fa0f90be1bc0d6a3965bfccc568
+
+use rquickjs::JsLifetime;
+use rquickjs::class::{JsClass, Trace};
+use std::ops::Deref;
+#[rquickjs::function]
+fn observe_function_call(
+    iface: crate::wrappers::JsString,
+    function: crate::wrappers::JsString,
+) -> () {
+    let result: () = crate::bindings::golem::durability::durability::observe_function_call(
+        iface.0.as_str(),
+        function.0.as_str(),
+    );
+    result
+}
+#[rquickjs::function]
+fn begin_durable_function(
+    function_type: crate::bindings::golem::api::oplog::WrappedFunctionType,
+) -> crate::wrappers::BigIntWrapper<u64> {
+    let result: crate::bindings::golem::durability::durability::OplogIndex = crate::bindings::golem::durability::durability::begin_durable_function(
+        function_type,
+    );
+    crate::wrappers::BigIntWrapper(result)
+}
+#[rquickjs::function]
+fn end_durable_function(
+    function_type: crate::bindings::golem::api::oplog::WrappedFunctionType,
+    begin_index: crate::wrappers::BigIntWrapper<u64>,
+    forced_commit: bool,
+) -> () {
+    let result: () = crate::bindings::golem::durability::durability::end_durable_function(
+        function_type,
+        begin_index.0,
+        forced_commit,
+    );
+    result
+}
+#[rquickjs::function]
+fn current_durable_execution_state() -> crate::bindings::golem::durability::durability::DurableExecutionState {
+    let result: crate::bindings::golem::durability::durability::DurableExecutionState = crate::bindings::golem::durability::durability::current_durable_execution_state();
+    result
+}
+#[rquickjs::function]
+fn persist_durable_function_invocation(
+    function_name: crate::wrappers::JsString,
+    request: crate::wrappers::UInt8Array,
+    response: crate::wrappers::UInt8Array,
+    function_type: crate::bindings::golem::api::oplog::WrappedFunctionType,
+) -> () {
+    let result: () = crate::bindings::golem::durability::durability::persist_durable_function_invocation(
+        function_name.0.as_str(),
+        request.0.as_slice(),
+        response.0.as_slice(),
+        function_type,
+    );
+    result
+}
+#[rquickjs::function]
+fn persist_typed_durable_function_invocation(
+    function_name: crate::wrappers::JsString,
+    request: crate::bindings::golem::rpc::types::ValueAndType,
+    response: crate::bindings::golem::rpc::types::ValueAndType,
+    function_type: crate::bindings::golem::api::oplog::WrappedFunctionType,
+) -> () {
+    let result: () = crate::bindings::golem::durability::durability::persist_typed_durable_function_invocation(
+        function_name.0.as_str(),
+        &request,
+        &response,
+        function_type,
+    );
+    result
+}
+#[rquickjs::function]
+fn read_persisted_durable_function_invocation() -> crate::bindings::golem::durability::durability::PersistedDurableFunctionInvocation {
+    let result: crate::bindings::golem::durability::durability::PersistedDurableFunctionInvocation = crate::bindings::golem::durability::durability::read_persisted_durable_function_invocation();
+    result
+}
+#[rquickjs::function]
+fn read_persisted_typed_durable_function_invocation() -> crate::bindings::golem::durability::durability::PersistedTypedDurableFunctionInvocation {
+    let result: crate::bindings::golem::durability::durability::PersistedTypedDurableFunctionInvocation = crate::bindings::golem::durability::durability::read_persisted_typed_durable_function_invocation();
+    result
+}
+#[derive(Clone, JsLifetime, Trace)]
+pub struct LazyInitializedPollable {
+    #[qjs(skip_trace = true)]
+    inner: Option<
+        std::rc::Rc<
+            crate::bindings::golem::durability::durability::LazyInitializedPollable,
+        >,
+    >,
+    /// The export call epoch if the handle is only borrowed from the host
+    #[qjs(skip_trace = true)]
+    borrow_epoch: Option<usize>,
+}
+mod __impl_class_lazy_initialized_pollable_ {
+    pub use super::*;
+    use rquickjs::{Atom, Symbol, Value};
+    impl<'js> rquickjs::class::JsClass<'js> for LazyInitializedPollable {
+        const NAME: &'static str = "LazyInitializedPollable";
+        type Mutable = rquickjs::class::Writable;
+        fn prototype(
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<Option<rquickjs::Object<'js>>> {
+            use rquickjs::class::impl_::MethodImplementor;
+            let proto = rquickjs::Object::new(ctx.clone())?;
+            let implementor = rquickjs::class::impl_::MethodImpl::<Self>::new();
+            (&implementor).implement(&proto)?;
+            let dispose_symbol: Symbol = ctx
+                .globals()
+                .get(crate::internal::DISPOSE_SYMBOL)?;
+            let dispose_fn: Value = proto.get("__dispose")?;
+            proto.set(dispose_symbol, dispose_fn)?;
+            Ok(Some(proto))
+        }
+        fn constructor(
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<Option<rquickjs::function::Constructor<'js>>> {
+            use rquickjs::class::impl_::ConstructorCreator;
+            let implementor = rquickjs::class::impl_::ConstructorCreate::<Self>::new();
+            (&implementor).create_constructor(ctx)
+        }
+    }
+    impl<'js> rquickjs::IntoJs<'js> for LazyInitializedPollable {
+        fn into_js(
+            self,
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<rquickjs::Value<'js>> {
+            let cls = rquickjs::class::Class::<Self>::instance(ctx.clone(), self)?;
+            rquickjs::IntoJs::into_js(cls, ctx)
+        }
+    }
+    impl<'js> rquickjs::FromJs<'js> for LazyInitializedPollable
+    where
+        for<'a> rquickjs::class::impl_::CloneWrapper<
+            'a,
+            Self,
+        >: rquickjs::class::impl_::CloneTrait<Self>,
+    {
+        fn from_js(
+            ctx: &rquickjs::Ctx<'js>,
+            value: rquickjs::Value<'js>,
+        ) -> rquickjs::Result<Self> {
+            use rquickjs::class::impl_::CloneTrait;
+            let value = rquickjs::class::Class::<Self>::from_js(ctx, value)?;
+            let borrow = value.try_borrow()?;
+            Ok(rquickjs::class::impl_::CloneWrapper(&*borrow).wrap_clone())
+        }
+    }
+}
+impl LazyInitializedPollable {
+    /// Gets the wrapped resource, failing if it has been disposed or if it is a
+    /// borrowed handle and the call it was passed to has already returned
+    fn checked_inner(
+        &self,
+    ) -> Result<
+        &std::rc::Rc<
+            crate::bindings::golem::durability::durability::LazyInitializedPollable,
+        >,
+        &'static str,
+    > {
+        if self
+            .borrow_epoch
+            .is_some_and(|epoch| !crate::internal::is_borrow_active(epoch))
+        {
+            return Err(
+                "Borrowed resource handle cannot be used after the call it was passed to has returned",
+            );
+        }
+        self.inner.as_ref().ok_or("Resource has already been disposed")
+    }
+    /// Gets the wrapped resource for calling one of its methods, throwing a
+    /// `ReferenceError` if it cannot be used
+    fn resource(
+        &self,
+        ctx: &rquickjs::Ctx<'_>,
+    ) -> rquickjs::Result<
+        &crate::bindings::golem::durability::durability::LazyInitializedPollable,
+    > {
+        self.checked_inner()
+            .map(|inner| inner.deref())
+            .map_err(|message| rquickjs::Exception::throw_reference(ctx, message))
+    }
+    /// Releases the wrapped resource. Borrowed handles belong to the host, so they
+    /// are never dropped, only forgotten.
+    fn release(&mut self) {
+        if let Some(inner) = self.inner.take() {
+            if self.borrow_epoch.is_some() {
+                if let Ok(inner) = std::rc::Rc::try_unwrap(inner) {
+                    let _ = inner.take_handle();
+                }
+            }
+        }
+    }
+}
+impl Drop for LazyInitializedPollable {
+    fn drop(&mut self) {
+        self.release();
+    }
+}
+#[rquickjs::methods(rename_all = "camelCase")]
+impl LazyInitializedPollable {
+    #[qjs(constructor)]
+    pub fn new() -> Self {
+        Self {
+            inner: Some(
+                std::rc::Rc::new(
+                    crate::bindings::golem::durability::durability::LazyInitializedPollable::new(),
+                ),
+            ),
+            borrow_epoch: None,
+        }
+    }
+    pub fn set(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        pollable: crate::bindings::golem::durability::durability::Pollable,
+    ) -> rquickjs::Result<()> {
+        let result: () = self.resource(&ctx)?.set(pollable);
+        Ok(result)
+    }
+    pub fn subscribe(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+    ) -> rquickjs::Result<crate::bindings::golem::durability::durability::Pollable> {
+        let result: crate::bindings::golem::durability::durability::Pollable = self
+            .resource(&ctx)?
+            .subscribe();
+        Ok(result)
+    }
+    #[qjs(rename = "__dispose")]
+    pub fn __dispose(&mut self) {
+        self.release();
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::durability::durability::LazyInitializedPollable {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        LazyInitializedPollable {
+            inner: Some(std::rc::Rc::new(self)),
+            borrow_epoch: None,
+        }
+            .into_js(ctx)
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::durability::durability::LazyInitializedPollable {
+    fn from_js(
+        ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let wrapper = LazyInitializedPollable::from_js(ctx, value)?;
+        if wrapper.borrow_epoch.is_some() {
+            return Err(rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "LazyInitializedPollable",
+                message: Some(
+                    "The ownership of a borrowed resource handle cannot be transferred"
+                        .to_string(),
+                ),
+            });
+        }
+        let inner = wrapper
+            .checked_inner()
+            .map_err(|message| rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "LazyInitializedPollable",
+                message: Some(message.to_string()),
+            })?;
+        unsafe {
+            Ok(
+                crate::bindings::golem::durability::durability::LazyInitializedPollable::from_handle(
+                    inner.take_handle(),
+                ),
+            )
+        }
+    }
+}
+pub struct BorrowLazyInitializedPollableWrapper(
+    pub crate::bindings::golem::durability::durability::LazyInitializedPollable,
+);
+impl<'js> rquickjs::FromJs<'js> for BorrowLazyInitializedPollableWrapper {
+    fn from_js(
+        ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let wrapper = LazyInitializedPollable::from_js(ctx, value)?;
+        let inner = wrapper
+            .checked_inner()
+            .map_err(|message| rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "LazyInitializedPollable",
+                message: Some(message.to_string()),
+            })?;
+        unsafe {
+            Ok(
+                BorrowLazyInitializedPollableWrapper(
+                    crate::bindings::golem::durability::durability::LazyInitializedPollable::from_handle(
+                        inner.handle(),
+                    ),
+                ),
+            )
+        }
+    }
+}
+impl BorrowLazyInitializedPollableWrapper {
+    /// Borrows a handle passed to an exported function, without taking its ownership
+    pub fn from_ref(
+        resource: &crate::bindings::golem::durability::durability::LazyInitializedPollable,
+    ) -> Self {
+        unsafe {
+            BorrowLazyInitializedPollableWrapper(
+                crate::bindings::golem::durability::durability::LazyInitializedPollable::from_handle(
+                    resource.handle(),
+                ),
+            )
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for BorrowLazyInitializedPollableWrapper {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let resource = unsafe {
+            crate::bindings::golem::durability::durability::LazyInitializedPollable::from_handle(
+                self.0.take_handle(),
+            )
+        };
+        LazyInitializedPollable {
+            inner: Some(std::rc::Rc::new(resource)),
+            borrow_epoch: Some(crate::internal::current_borrow_epoch()),
+        }
+            .into_js(ctx)
+    }
+}
+impl Drop for BorrowLazyInitializedPollableWrapper {
+    fn drop(&mut self) {
+        let _ = self.0.take_handle();
+    }
+}
+pub struct JsDurabilityModule;
+impl rquickjs::module::ModuleDef for JsDurabilityModule {
+    fn declare(decl: &rquickjs::module::Declarations) -> rquickjs::Result<()> {
+        decl.declare("observeFunctionCall")?;
+        decl.declare("beginDurableFunction")?;
+        decl.declare("endDurableFunction")?;
+        decl.declare("currentDurableExecutionState")?;
+        decl.declare("persistDurableFunctionInvocation")?;
+        decl.declare("persistTypedDurableFunctionInvocation")?;
+        decl.declare("readPersistedDurableFunctionInvocation")?;
+        decl.declare("readPersistedTypedDurableFunctionInvocation")?;
+        decl.declare("LazyInitializedPollable")?;
+        Ok(())
+    }
+    fn evaluate<'js>(
+        ctx: &rquickjs::Ctx<'js>,
+        exports: &rquickjs::module::Exports<'js>,
+    ) -> rquickjs::Result<()> {
+        exports.export("observeFunctionCall", js_observe_function_call)?;
+        exports.export("beginDurableFunction", js_begin_durable_function)?;
+        exports.export("endDurableFunction", js_end_durable_function)?;
+        exports
+            .export("currentDurableExecutionState", js_current_durable_execution_state)?;
+        exports
+            .export(
+                "persistDurableFunctionInvocation",
+                js_persist_durable_function_invocation,
+            )?;
+        exports
+            .export(
+                "persistTypedDurableFunctionInvocation",
+                js_persist_typed_durable_function_invocation,
+            )?;
+        exports
+            .export(
+                "readPersistedDurableFunctionInvocation",
+                js_read_persisted_durable_function_invocation,
+            )?;
+        exports
+            .export(
+                "readPersistedTypedDurableFunctionInvocation",
+                js_read_persisted_typed_durable_function_invocation,
+            )?;
+        exports
+            .export(
+                "LazyInitializedPollable",
+                LazyInitializedPollable::constructor(ctx)?,
+            )?;
+        Ok(())
+    }
+}
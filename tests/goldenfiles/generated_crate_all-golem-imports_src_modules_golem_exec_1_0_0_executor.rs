@@ -0,0 +1,409 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:all-golem-imports/all-golem-imports
+// wit: sha256:3ec76077ee8750a4a3d97cf8f89273803d43fba31aa02651185015b5648e2fc7
+// js all-golem-imports: sha256:1aa6d68c08d678e85c1f334bb55fc7062ce81fa0f90be1bc0d6a3965bfccc568
+
+use rquickjs::JsLifetime;
+use rquickjs::class::{JsClass, Trace};
+use std::ops::Deref;
+#[rquickjs::function]
+fn run(
+    ctx: rquickjs::Ctx<'_>,
+    lang: crate::bindings::golem::exec::types::Language,
+    snippet: crate::wrappers::JsString,
+    modules: crate::wrappers::JsList<crate::bindings::golem::exec::types::File>,
+    stdin: Option<crate::wrappers::JsString>,
+    args: crate::wrappers::JsList<crate::wrappers::JsString>,
+    env: crate::wrappers::JsList<
+        rquickjs::convert::List<(crate::wrappers::JsString, crate::wrappers::JsString)>,
+    >,
+    constraints: Option<crate::bindings::golem::exec::types::Limits>,
+) -> rquickjs::Result<crate::bindings::golem::exec::types::ExecResult> {
+    let result: Result<
+        crate::bindings::golem::exec::executor::ExecResult,
+        crate::bindings::golem::exec::executor::Error,
+    > = crate::bindings::golem::exec::executor::run(
+        &lang,
+        snippet.0.as_str(),
+        modules.0.into_iter().map(|v| v).collect::<Vec<_>>().as_slice(),
+        stdin.map(|v| v.0).as_deref(),
+        args.0.into_iter().map(|v| v.0).collect::<Vec<_>>().as_slice(),
+        env
+            .0
+            .into_iter()
+            .map(|v| (v.0.0.0.clone(), v.0.1.0.clone()))
+            .collect::<Vec<_>>()
+            .as_slice(),
+        constraints.map(|v| v),
+    );
+    match result {
+        Ok(result) => Ok(result),
+        Err(error) => {
+            let error: crate::bindings::golem::exec::types::Error = error;
+            Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+        }
+    }
+}
+#[derive(Clone, JsLifetime, Trace)]
+pub struct Session {
+    #[qjs(skip_trace = true)]
+    inner: Option<std::rc::Rc<crate::bindings::golem::exec::executor::Session>>,
+    /// The export call epoch if the handle is only borrowed from the host
+    #[qjs(skip_trace = true)]
+    borrow_epoch: Option<usize>,
+}
+mod __impl_class_session_ {
+    pub use super::*;
+    use rquickjs::{Atom, Symbol, Value};
+    impl<'js> rquickjs::class::JsClass<'js> for Session {
+        const NAME: &'static str = "Session";
+        type Mutable = rquickjs::class::Writable;
+        fn prototype(
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<Option<rquickjs::Object<'js>>> {
+            use rquickjs::class::impl_::MethodImplementor;
+            let proto = rquickjs::Object::new(ctx.clone())?;
+            let implementor = rquickjs::class::impl_::MethodImpl::<Self>::new();
+            (&implementor).implement(&proto)?;
+            let dispose_symbol: Symbol = ctx
+                .globals()
+                .get(crate::internal::DISPOSE_SYMBOL)?;
+            let dispose_fn: Value = proto.get("__dispose")?;
+            proto.set(dispose_symbol, dispose_fn)?;
+            Ok(Some(proto))
+        }
+        fn constructor(
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<Option<rquickjs::function::Constructor<'js>>> {
+            use rquickjs::class::impl_::ConstructorCreator;
+            let implementor = rquickjs::class::impl_::ConstructorCreate::<Self>::new();
+            (&implementor).create_constructor(ctx)
+        }
+    }
+    impl<'js> rquickjs::IntoJs<'js> for Session {
+        fn into_js(
+            self,
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<rquickjs::Value<'js>> {
+            let cls = rquickjs::class::Class::<Self>::instance(ctx.clone(), self)?;
+            rquickjs::IntoJs::into_js(cls, ctx)
+        }
+    }
+    impl<'js> rquickjs::FromJs<'js> for Session
+    where
+        for<'a> rquickjs::class::impl_::CloneWrapper<
+            'a,
+            Self,
+        >: rquickjs::class::impl_::CloneTrait<Self>,
+    {
+        fn from_js(
+            ctx: &rquickjs::Ctx<'js>,
+            value: rquickjs::Value<'js>,
+        ) -> rquickjs::Result<Self> {
+            use rquickjs::class::impl_::CloneTrait;
+            let value = rquickjs::class::Class::<Self>::from_js(ctx, value)?;
+            let borrow = value.try_borrow()?;
+            Ok(rquickjs::class::impl_::CloneWrapper(&*borrow).wrap_clone())
+        }
+    }
+}
+impl Session {
+    /// Gets the wrapped resource, failing if it has been disposed or if it is a
+    /// borrowed handle and the call it was passed to has already returned
+    fn checked_inner(
+        &self,
+    ) -> Result<
+        &std::rc::Rc<crate::bindings::golem::exec::executor::Session>,
+        &'static str,
+    > {
+        if self
+            .borrow_epoch
+            .is_some_and(|epoch| !crate::internal::is_borrow_active(epoch))
+        {
+            return Err(
+                "Borrowed resource handle cannot be used after the call it was passed to has returned",
+            );
+        }
+        self.inner.as_ref().ok_or("Resource has already been disposed")
+    }
+    /// Gets the wrapped resource for calling one of its methods, throwing a
+    /// `ReferenceError` if it cannot be used
+    fn resource(
+        &self,
+        ctx: &rquickjs::Ctx<'_>,
+    ) -> rquickjs::Result<&crate::bindings::golem::exec::executor::Session> {
+        self.checked_inner()
+            .map(|inner| inner.deref())
+            .map_err(|message| rquickjs::Exception::throw_reference(ctx, message))
+    }
+    /// Releases the wrapped resource. Borrowed handles belong to the host, so they
+    /// are never dropped, only forgotten.
+    fn release(&mut self) {
+        if let Some(inner) = self.inner.take() {
+            if self.borrow_epoch.is_some() {
+                if let Ok(inner) = std::rc::Rc::try_unwrap(inner) {
+                    let _ = inner.take_handle();
+                }
+            }
+        }
+    }
+}
+impl Drop for Session {
+    fn drop(&mut self) {
+        self.release();
+    }
+}
+#[rquickjs::methods(rename_all = "camelCase")]
+impl Session {
+    #[qjs(constructor)]
+    pub fn new(
+        lang: crate::bindings::golem::exec::types::Language,
+        modules: crate::wrappers::JsList<crate::bindings::golem::exec::types::File>,
+    ) -> Self {
+        Self {
+            inner: Some(
+                std::rc::Rc::new(
+                    crate::bindings::golem::exec::executor::Session::new(
+                        &lang,
+                        modules.0.into_iter().map(|v| v).collect::<Vec<_>>().as_slice(),
+                    ),
+                ),
+            ),
+            borrow_epoch: None,
+        }
+    }
+    pub fn upload(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        file: crate::bindings::golem::exec::types::File,
+    ) -> rquickjs::Result<()> {
+        let result: Result<(), crate::bindings::golem::exec::executor::Error> = self
+            .resource(&ctx)?
+            .upload(&file);
+        match result {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                let error: crate::bindings::golem::exec::types::Error = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn run(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        snippet: crate::wrappers::JsString,
+        args: crate::wrappers::JsList<crate::wrappers::JsString>,
+        stdin: Option<crate::wrappers::JsString>,
+        env: crate::wrappers::JsList<
+            rquickjs::convert::List<
+                (crate::wrappers::JsString, crate::wrappers::JsString),
+            >,
+        >,
+        constraints: Option<crate::bindings::golem::exec::types::Limits>,
+    ) -> rquickjs::Result<crate::bindings::golem::exec::types::ExecResult> {
+        let result: Result<
+            crate::bindings::golem::exec::executor::ExecResult,
+            crate::bindings::golem::exec::executor::Error,
+        > = self
+            .resource(&ctx)?
+            .run(
+                snippet.0.as_str(),
+                args.0.into_iter().map(|v| v.0).collect::<Vec<_>>().as_slice(),
+                stdin.map(|v| v.0).as_deref(),
+                env
+                    .0
+                    .into_iter()
+                    .map(|v| (v.0.0.0.clone(), v.0.1.0.clone()))
+                    .collect::<Vec<_>>()
+                    .as_slice(),
+                constraints.map(|v| v),
+            );
+        match result {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                let error: crate::bindings::golem::exec::types::Error = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn download(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        path: crate::wrappers::JsString,
+    ) -> rquickjs::Result<crate::wrappers::UInt8Array> {
+        let result: Result<Vec<u8>, crate::bindings::golem::exec::executor::Error> = self
+            .resource(&ctx)?
+            .download(path.0.as_str());
+        match result {
+            Ok(result) => Ok(crate::wrappers::UInt8Array(result)),
+            Err(error) => {
+                let error: crate::bindings::golem::exec::types::Error = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn list_files(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        dir: crate::wrappers::JsString,
+    ) -> rquickjs::Result<crate::wrappers::JsList<crate::wrappers::JsString>> {
+        let result: Result<Vec<String>, crate::bindings::golem::exec::executor::Error> = self
+            .resource(&ctx)?
+            .list_files(dir.0.as_str());
+        match result {
+            Ok(result) => {
+                Ok(
+                    crate::wrappers::JsList(
+                        result
+                            .into_iter()
+                            .map(|v| crate::wrappers::JsString(v))
+                            .collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            Err(error) => {
+                let error: crate::bindings::golem::exec::types::Error = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn set_working_dir(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        path: crate::wrappers::JsString,
+    ) -> rquickjs::Result<()> {
+        let result: Result<(), crate::bindings::golem::exec::executor::Error> = self
+            .resource(&ctx)?
+            .set_working_dir(path.0.as_str());
+        match result {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                let error: crate::bindings::golem::exec::types::Error = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    #[qjs(rename = "__dispose")]
+    pub fn __dispose(&mut self) {
+        self.release();
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::exec::executor::Session {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        Session {
+            inner: Some(std::rc::Rc::new(self)),
+            borrow_epoch: None,
+        }
+            .into_js(ctx)
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::exec::executor::Session {
+    fn from_js(
+        ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let wrapper = Session::from_js(ctx, value)?;
+        if wrapper.borrow_epoch.is_some() {
+            return Err(rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "Session",
+                message: Some(
+                    "The ownership of a borrowed resource handle cannot be transferred"
+                        .to_string(),
+                ),
+            });
+        }
+        let inner = wrapper
+            .checked_inner()
+            .map_err(|message| rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "Session",
+                message: Some(message.to_string()),
+            })?;
+        unsafe {
+            Ok(
+                crate::bindings::golem::exec::executor::Session::from_handle(
+                    inner.take_handle(),
+                ),
+            )
+        }
+    }
+}
+pub struct BorrowSessionWrapper(pub crate::bindings::golem::exec::executor::Session);
+impl<'js> rquickjs::FromJs<'js> for BorrowSessionWrapper {
+    fn from_js(
+        ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let wrapper = Session::from_js(ctx, value)?;
+        let inner = wrapper
+            .checked_inner()
+            .map_err(|message| rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "Session",
+                message: Some(message.to_string()),
+            })?;
+        unsafe {
+            Ok(
+                BorrowSessionWrapper(
+                    crate::bindings::golem::exec::executor::Session::from_handle(
+                        inner.handle(),
+                    ),
+                ),
+            )
+        }
+    }
+}
+impl BorrowSessionWrapper {
+    /// Borrows a handle passed to an exported function, without taking its ownership
+    pub fn from_ref(resource: &crate::bindings::golem::exec::executor::Session) -> Self {
+        unsafe {
+            BorrowSessionWrapper(
+                crate::bindings::golem::exec::executor::Session::from_handle(
+                    resource.handle(),
+                ),
+            )
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for BorrowSessionWrapper {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let resource = unsafe {
+            crate::bindings::golem::exec::executor::Session::from_handle(
+                self.0.take_handle(),
+            )
+        };
+        Session {
+            inner: Some(std::rc::Rc::new(resource)),
+            borrow_epoch: Some(crate::internal::current_borrow_epoch()),
+        }
+            .into_js(ctx)
+    }
+}
+impl Drop for BorrowSessionWrapper {
+    fn drop(&mut self) {
+        let _ = self.0.take_handle();
+    }
+}
+pub struct JsExecutorModule;
+impl rquickjs::module::ModuleDef for JsExecutorModule {
+    fn declare(decl: &rquickjs::module::Declarations) -> rquickjs::Result<()> {
+        decl.declare("run")?;
+        decl.declare("Session")?;
+        Ok(())
+    }
+    fn evaluate<'js>(
+        ctx: &rquickjs::Ctx<'js>,
+        exports: &rquickjs::module::Exports<'js>,
+    ) -> rquickjs::Result<()> {
+        exports.export("run", js_run)?;
+        exports.export("Session", Session::constructor(ctx)?)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,60 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:all-golem-imports/all-golem-imports
+// wit: sha256:3ec76077ee8750a4a3d97cf8f89273803d43fba31aa02651185015b5648e2fc7
+// js all-golem-imports: sha256:1aa6d68c08d678e85c1f334bb55fc7062ce81fa0f90be1bc0d6a3965bfccc568
+
+use rquickjs::JsLifetime;
+use rquickjs::class::{JsClass, Trace};
+use std::ops::Deref;
+#[rquickjs::function]
+fn execute_query(
+    ctx: rquickjs::Ctx<'_>,
+    transaction: crate::modules::golem_graph_1_0_0_transactions::BorrowTransactionWrapper,
+    query: crate::wrappers::JsString,
+    parameters: Option<
+        crate::wrappers::JsList<
+            rquickjs::convert::List<
+                (
+                    crate::wrappers::JsString,
+                    crate::bindings::golem::graph::types::PropertyValue,
+                ),
+            >,
+        >,
+    >,
+    options: Option<crate::bindings::golem::graph::query::QueryOptions>,
+) -> rquickjs::Result<crate::bindings::golem::graph::query::QueryExecutionResult> {
+    let result: Result<
+        crate::bindings::golem::graph::query::QueryExecutionResult,
+        crate::bindings::golem::graph::query::GraphError,
+    > = crate::bindings::golem::graph::query::execute_query(
+        &transaction.0,
+        query.0.as_str(),
+        parameters
+            .map(|v| {
+                v.0.into_iter().map(|v| (v.0.0.0.clone(), v.0.1)).collect::<Vec<_>>()
+            })
+            .as_deref(),
+        options.map(|v| v),
+    );
+    match result {
+        Ok(result) => Ok(result),
+        Err(error) => {
+            let error: crate::bindings::golem::graph::errors::GraphError = error;
+            Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+        }
+    }
+}
+pub struct JsQueryModule;
+impl rquickjs::module::ModuleDef for JsQueryModule {
+    fn declare(decl: &rquickjs::module::Declarations) -> rquickjs::Result<()> {
+        decl.declare("executeQuery")?;
+        Ok(())
+    }
+    fn evaluate<'js>(
+        ctx: &rquickjs::Ctx<'js>,
+        exports: &rquickjs::module::Exports<'js>,
+    ) -> rquickjs::Result<()> {
+        exports.export("executeQuery", js_execute_query)?;
+        Ok(())
+    }
+}
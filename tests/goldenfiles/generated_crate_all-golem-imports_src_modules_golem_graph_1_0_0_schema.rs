@@ -0,0 +1,553 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:all-golem-imports/all-golem-imports
+// wit: sha256:3ec76077ee8750a4a3d97cf8f89273803d43fba31aa02651185015b5648e2fc7
+// js all-golem-imports: sha256:1aa6d68c08d678e85c1f334bb55fc7062ce81fa0f90be1bc0d6a3965bfccc568
+
+use rquickjs::JsLifetime;
+use rquickjs::class::{JsClass, Trace};
+use std::ops::Deref;
+#[rquickjs::function]
+fn get_schema_manager(
+    ctx: rquickjs::Ctx<'_>,
+    config: Option<crate::bindings::golem::graph::connection::ConnectionConfig>,
+) -> rquickjs::Result<crate::bindings::golem::graph::schema::SchemaManager> {
+    let result: Result<
+        crate::bindings::golem::graph::schema::SchemaManager,
+        crate::bindings::golem::graph::schema::GraphError,
+    > = crate::bindings::golem::graph::schema::get_schema_manager(
+        config.map(|v| v).as_ref(),
+    );
+    match result {
+        Ok(result) => Ok(result),
+        Err(error) => {
+            let error: crate::bindings::golem::graph::errors::GraphError = error;
+            Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+        }
+    }
+}
+#[derive(Clone, JsLifetime, Trace)]
+pub struct SchemaManager {
+    #[qjs(skip_trace = true)]
+    inner: Option<std::rc::Rc<crate::bindings::golem::graph::schema::SchemaManager>>,
+    /// The export call epoch if the handle is only borrowed from the host
+    #[qjs(skip_trace = true)]
+    borrow_epoch: Option<usize>,
+}
+mod __impl_class_schema_manager_ {
+    pub use super::*;
+    use rquickjs::{Atom, Symbol, Value};
+    impl<'js> rquickjs::class::JsClass<'js> for SchemaManager {
+        const NAME: &'static str = "SchemaManager";
+        type Mutable = rquickjs::class::Writable;
+        fn prototype(
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<Option<rquickjs::Object<'js>>> {
+            use rquickjs::class::impl_::MethodImplementor;
+            let proto = rquickjs::Object::new(ctx.clone())?;
+            let implementor = rquickjs::class::impl_::MethodImpl::<Self>::new();
+            (&implementor).implement(&proto)?;
+            let dispose_symbol: Symbol = ctx
+                .globals()
+                .get(crate::internal::DISPOSE_SYMBOL)?;
+            let dispose_fn: Value = proto.get("__dispose")?;
+            proto.set(dispose_symbol, dispose_fn)?;
+            Ok(Some(proto))
+        }
+        fn constructor(
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<Option<rquickjs::function::Constructor<'js>>> {
+            use rquickjs::class::impl_::ConstructorCreator;
+            let implementor = rquickjs::class::impl_::ConstructorCreate::<Self>::new();
+            (&implementor).create_constructor(ctx)
+        }
+    }
+    impl<'js> rquickjs::IntoJs<'js> for SchemaManager {
+        fn into_js(
+            self,
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<rquickjs::Value<'js>> {
+            let cls = rquickjs::class::Class::<Self>::instance(ctx.clone(), self)?;
+            rquickjs::IntoJs::into_js(cls, ctx)
+        }
+    }
+    impl<'js> rquickjs::FromJs<'js> for SchemaManager
+    where
+        for<'a> rquickjs::class::impl_::CloneWrapper<
+            'a,
+            Self,
+        >: rquickjs::class::impl_::CloneTrait<Self>,
+    {
+        fn from_js(
+            ctx: &rquickjs::Ctx<'js>,
+            value: rquickjs::Value<'js>,
+        ) -> rquickjs::Result<Self> {
+            use rquickjs::class::impl_::CloneTrait;
+            let value = rquickjs::class::Class::<Self>::from_js(ctx, value)?;
+            let borrow = value.try_borrow()?;
+            Ok(rquickjs::class::impl_::CloneWrapper(&*borrow).wrap_clone())
+        }
+    }
+}
+impl SchemaManager {
+    /// Gets the wrapped resource, failing if it has been disposed or if it is a
+    /// borrowed handle and the call it was passed to has already returned
+    fn checked_inner(
+        &self,
+    ) -> Result<
+        &std::rc::Rc<crate::bindings::golem::graph::schema::SchemaManager>,
+        &'static str,
+    > {
+        if self
+            .borrow_epoch
+            .is_some_and(|epoch| !crate::internal::is_borrow_active(epoch))
+        {
+            return Err(
+                "Borrowed resource handle cannot be used after the call it was passed to has returned",
+            );
+        }
+        self.inner.as_ref().ok_or("Resource has already been disposed")
+    }
+    /// Gets the wrapped resource for calling one of its methods, throwing a
+    /// `ReferenceError` if it cannot be used
+    fn resource(
+        &self,
+        ctx: &rquickjs::Ctx<'_>,
+    ) -> rquickjs::Result<&crate::bindings::golem::graph::schema::SchemaManager> {
+        self.checked_inner()
+            .map(|inner| inner.deref())
+            .map_err(|message| rquickjs::Exception::throw_reference(ctx, message))
+    }
+    /// Releases the wrapped resource. Borrowed handles belong to the host, so they
+    /// are never dropped, only forgotten.
+    fn release(&mut self) {
+        if let Some(inner) = self.inner.take() {
+            if self.borrow_epoch.is_some() {
+                if let Ok(inner) = std::rc::Rc::try_unwrap(inner) {
+                    let _ = inner.take_handle();
+                }
+            }
+        }
+    }
+}
+impl Drop for SchemaManager {
+    fn drop(&mut self) {
+        self.release();
+    }
+}
+#[rquickjs::methods(rename_all = "camelCase")]
+impl SchemaManager {
+    #[qjs(constructor)]
+    pub fn new() -> Self {
+        Self {
+            inner: None,
+            borrow_epoch: None,
+        }
+    }
+    pub fn define_vertex_label(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        schema: crate::bindings::golem::graph::schema::VertexLabelSchema,
+    ) -> rquickjs::Result<()> {
+        let result: Result<(), crate::bindings::golem::graph::schema::GraphError> = self
+            .resource(&ctx)?
+            .define_vertex_label(&schema);
+        match result {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                let error: crate::bindings::golem::graph::errors::GraphError = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn define_edge_label(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        schema: crate::bindings::golem::graph::schema::EdgeLabelSchema,
+    ) -> rquickjs::Result<()> {
+        let result: Result<(), crate::bindings::golem::graph::schema::GraphError> = self
+            .resource(&ctx)?
+            .define_edge_label(&schema);
+        match result {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                let error: crate::bindings::golem::graph::errors::GraphError = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn get_vertex_label_schema(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        label: crate::wrappers::JsString,
+    ) -> rquickjs::Result<
+        Option<crate::bindings::golem::graph::schema::VertexLabelSchema>,
+    > {
+        let result: Result<
+            Option<crate::bindings::golem::graph::schema::VertexLabelSchema>,
+            crate::bindings::golem::graph::schema::GraphError,
+        > = self.resource(&ctx)?.get_vertex_label_schema(label.0.as_str());
+        match result {
+            Ok(result) => Ok(result.map(|v| v)),
+            Err(error) => {
+                let error: crate::bindings::golem::graph::errors::GraphError = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn get_edge_label_schema(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        label: crate::wrappers::JsString,
+    ) -> rquickjs::Result<
+        Option<crate::bindings::golem::graph::schema::EdgeLabelSchema>,
+    > {
+        let result: Result<
+            Option<crate::bindings::golem::graph::schema::EdgeLabelSchema>,
+            crate::bindings::golem::graph::schema::GraphError,
+        > = self.resource(&ctx)?.get_edge_label_schema(label.0.as_str());
+        match result {
+            Ok(result) => Ok(result.map(|v| v)),
+            Err(error) => {
+                let error: crate::bindings::golem::graph::errors::GraphError = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn list_vertex_labels(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+    ) -> rquickjs::Result<crate::wrappers::JsList<crate::wrappers::JsString>> {
+        let result: Result<
+            Vec<String>,
+            crate::bindings::golem::graph::schema::GraphError,
+        > = self.resource(&ctx)?.list_vertex_labels();
+        match result {
+            Ok(result) => {
+                Ok(
+                    crate::wrappers::JsList(
+                        result
+                            .into_iter()
+                            .map(|v| crate::wrappers::JsString(v))
+                            .collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            Err(error) => {
+                let error: crate::bindings::golem::graph::errors::GraphError = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn list_edge_labels(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+    ) -> rquickjs::Result<crate::wrappers::JsList<crate::wrappers::JsString>> {
+        let result: Result<
+            Vec<String>,
+            crate::bindings::golem::graph::schema::GraphError,
+        > = self.resource(&ctx)?.list_edge_labels();
+        match result {
+            Ok(result) => {
+                Ok(
+                    crate::wrappers::JsList(
+                        result
+                            .into_iter()
+                            .map(|v| crate::wrappers::JsString(v))
+                            .collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            Err(error) => {
+                let error: crate::bindings::golem::graph::errors::GraphError = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn create_index(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        index: crate::bindings::golem::graph::schema::IndexDefinition,
+    ) -> rquickjs::Result<()> {
+        let result: Result<(), crate::bindings::golem::graph::schema::GraphError> = self
+            .resource(&ctx)?
+            .create_index(&index);
+        match result {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                let error: crate::bindings::golem::graph::errors::GraphError = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn drop_index(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        name: crate::wrappers::JsString,
+    ) -> rquickjs::Result<()> {
+        let result: Result<(), crate::bindings::golem::graph::schema::GraphError> = self
+            .resource(&ctx)?
+            .drop_index(name.0.as_str());
+        match result {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                let error: crate::bindings::golem::graph::errors::GraphError = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn list_indexes(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+    ) -> rquickjs::Result<
+        crate::wrappers::JsList<crate::bindings::golem::graph::schema::IndexDefinition>,
+    > {
+        let result: Result<
+            Vec<crate::bindings::golem::graph::schema::IndexDefinition>,
+            crate::bindings::golem::graph::schema::GraphError,
+        > = self.resource(&ctx)?.list_indexes();
+        match result {
+            Ok(result) => {
+                Ok(
+                    crate::wrappers::JsList(
+                        result.into_iter().map(|v| v).collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            Err(error) => {
+                let error: crate::bindings::golem::graph::errors::GraphError = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn get_index(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        name: crate::wrappers::JsString,
+    ) -> rquickjs::Result<
+        Option<crate::bindings::golem::graph::schema::IndexDefinition>,
+    > {
+        let result: Result<
+            Option<crate::bindings::golem::graph::schema::IndexDefinition>,
+            crate::bindings::golem::graph::schema::GraphError,
+        > = self.resource(&ctx)?.get_index(name.0.as_str());
+        match result {
+            Ok(result) => Ok(result.map(|v| v)),
+            Err(error) => {
+                let error: crate::bindings::golem::graph::errors::GraphError = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn define_edge_type(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        definition: crate::bindings::golem::graph::schema::EdgeTypeDefinition,
+    ) -> rquickjs::Result<()> {
+        let result: Result<(), crate::bindings::golem::graph::schema::GraphError> = self
+            .resource(&ctx)?
+            .define_edge_type(&definition);
+        match result {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                let error: crate::bindings::golem::graph::errors::GraphError = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn list_edge_types(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+    ) -> rquickjs::Result<
+        crate::wrappers::JsList<
+            crate::bindings::golem::graph::schema::EdgeTypeDefinition,
+        >,
+    > {
+        let result: Result<
+            Vec<crate::bindings::golem::graph::schema::EdgeTypeDefinition>,
+            crate::bindings::golem::graph::schema::GraphError,
+        > = self.resource(&ctx)?.list_edge_types();
+        match result {
+            Ok(result) => {
+                Ok(
+                    crate::wrappers::JsList(
+                        result.into_iter().map(|v| v).collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            Err(error) => {
+                let error: crate::bindings::golem::graph::errors::GraphError = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn create_container(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        name: crate::wrappers::JsString,
+        container_type: crate::bindings::golem::graph::schema::ContainerType,
+    ) -> rquickjs::Result<()> {
+        let result: Result<(), crate::bindings::golem::graph::schema::GraphError> = self
+            .resource(&ctx)?
+            .create_container(name.0.as_str(), container_type);
+        match result {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                let error: crate::bindings::golem::graph::errors::GraphError = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn list_containers(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+    ) -> rquickjs::Result<
+        crate::wrappers::JsList<crate::bindings::golem::graph::schema::ContainerInfo>,
+    > {
+        let result: Result<
+            Vec<crate::bindings::golem::graph::schema::ContainerInfo>,
+            crate::bindings::golem::graph::schema::GraphError,
+        > = self.resource(&ctx)?.list_containers();
+        match result {
+            Ok(result) => {
+                Ok(
+                    crate::wrappers::JsList(
+                        result.into_iter().map(|v| v).collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            Err(error) => {
+                let error: crate::bindings::golem::graph::errors::GraphError = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    #[qjs(rename = "__dispose")]
+    pub fn __dispose(&mut self) {
+        self.release();
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::graph::schema::SchemaManager {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        SchemaManager {
+            inner: Some(std::rc::Rc::new(self)),
+            borrow_epoch: None,
+        }
+            .into_js(ctx)
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::graph::schema::SchemaManager {
+    fn from_js(
+        ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let wrapper = SchemaManager::from_js(ctx, value)?;
+        if wrapper.borrow_epoch.is_some() {
+            return Err(rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "SchemaManager",
+                message: Some(
+                    "The ownership of a borrowed resource handle cannot be transferred"
+                        .to_string(),
+                ),
+            });
+        }
+        let inner = wrapper
+            .checked_inner()
+            .map_err(|message| rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "SchemaManager",
+                message: Some(message.to_string()),
+            })?;
+        unsafe {
+            Ok(
+                crate::bindings::golem::graph::schema::SchemaManager::from_handle(
+                    inner.take_handle(),
+                ),
+            )
+        }
+    }
+}
+pub struct BorrowSchemaManagerWrapper(
+    pub crate::bindings::golem::graph::schema::SchemaManager,
+);
+impl<'js> rquickjs::FromJs<'js> for BorrowSchemaManagerWrapper {
+    fn from_js(
+        ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let wrapper = SchemaManager::from_js(ctx, value)?;
+        let inner = wrapper
+            .checked_inner()
+            .map_err(|message| rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "SchemaManager",
+                message: Some(message.to_string()),
+            })?;
+        unsafe {
+            Ok(
+                BorrowSchemaManagerWrapper(
+                    crate::bindings::golem::graph::schema::SchemaManager::from_handle(
+                        inner.handle(),
+                    ),
+                ),
+            )
+        }
+    }
+}
+impl BorrowSchemaManagerWrapper {
+    /// Borrows a handle passed to an exported function, without taking its ownership
+    pub fn from_ref(
+        resource: &crate::bindings::golem::graph::schema::SchemaManager,
+    ) -> Self {
+        unsafe {
+            BorrowSchemaManagerWrapper(
+                crate::bindings::golem::graph::schema::SchemaManager::from_handle(
+                    resource.handle(),
+                ),
+            )
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for BorrowSchemaManagerWrapper {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let resource = unsafe {
+            crate::bindings::golem::graph::schema::SchemaManager::from_handle(
+                self.0.take_handle(),
+            )
+        };
+        SchemaManager {
+            inner: Some(std::rc::Rc::new(resource)),
+            borrow_epoch: Some(crate::internal::current_borrow_epoch()),
+        }
+            .into_js(ctx)
+    }
+}
+impl Drop for BorrowSchemaManagerWrapper {
+    fn drop(&mut self) {
+        let _ = self.0.take_handle();
+    }
+}
+pub struct JsSchemaModule;
+impl rquickjs::module::ModuleDef for JsSchemaModule {
+    fn declare(decl: &rquickjs::module::Declarations) -> rquickjs::Result<()> {
+        decl.declare("getSchemaManager")?;
+        decl.declare("SchemaManager")?;
+        Ok(())
+    }
+    fn evaluate<'js>(
+        ctx: &rquickjs::Ctx<'js>,
+        exports: &rquickjs::module::Exports<'js>,
+    ) -> rquickjs::Result<()> {
+        exports.export("getSchemaManager", js_get_schema_manager)?;
+        exports.export("SchemaManager", SchemaManager::constructor(ctx)?)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,913 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:all-golem-imports/all-golem-imports
+// wit: sha256:3ec76077ee8750a4a3d97cf8f89273803d43fba31aa02651185015b5648e2fc7
+// js all-golem-imports: sha256:1aa6d68c08d678e85c1f334bb55fc7062ce81fa0f90be1bc0d6a3965bfccc568
+
+use rquickjs::JsLifetime;
+use rquickjs::class::{JsClass, Trace};
+use std::ops::Deref;
+#[derive(Clone, JsLifetime, Trace)]
+pub struct Transaction {
+    #[qjs(skip_trace = true)]
+    inner: Option<std::rc::Rc<crate::bindings::golem::graph::transactions::Transaction>>,
+    /// The export call epoch if the handle is only borrowed from the host
+    #[qjs(skip_trace = true)]
+    borrow_epoch: Option<usize>,
+}
+mod __impl_class_transaction_ {
+    pub use super::*;
+    use rquickjs::{Atom, Symbol, Value};
+    impl<'js> rquickjs::class::JsClass<'js> for Transaction {
+        const NAME: &'static str = "Transaction";
+        type Mutable = rquickjs::class::Writable;
+        fn prototype(
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<Option<rquickjs::Object<'js>>> {
+            use rquickjs::class::impl_::MethodImplementor;
+            let proto = rquickjs::Object::new(ctx.clone())?;
+            let implementor = rquickjs::class::impl_::MethodImpl::<Self>::new();
+            (&implementor).implement(&proto)?;
+            let dispose_symbol: Symbol = ctx
+                .globals()
+                .get(crate::internal::DISPOSE_SYMBOL)?;
+            let dispose_fn: Value = proto.get("__dispose")?;
+            proto.set(dispose_symbol, dispose_fn)?;
+            Ok(Some(proto))
+        }
+        fn constructor(
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<Option<rquickjs::function::Constructor<'js>>> {
+            use rquickjs::class::impl_::ConstructorCreator;
+            let implementor = rquickjs::class::impl_::ConstructorCreate::<Self>::new();
+            (&implementor).create_constructor(ctx)
+        }
+    }
+    impl<'js> rquickjs::IntoJs<'js> for Transaction {
+        fn into_js(
+            self,
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<rquickjs::Value<'js>> {
+            let cls = rquickjs::class::Class::<Self>::instance(ctx.clone(), self)?;
+            rquickjs::IntoJs::into_js(cls, ctx)
+        }
+    }
+    impl<'js> rquickjs::FromJs<'js> for Transaction
+    where
+        for<'a> rquickjs::class::impl_::CloneWrapper<
+            'a,
+            Self,
+        >: rquickjs::class::impl_::CloneTrait<Self>,
+    {
+        fn from_js(
+            ctx: &rquickjs::Ctx<'js>,
+            value: rquickjs::Value<'js>,
+        ) -> rquickjs::Result<Self> {
+            use rquickjs::class::impl_::CloneTrait;
+            let value = rquickjs::class::Class::<Self>::from_js(ctx, value)?;
+            let borrow = value.try_borrow()?;
+            Ok(rquickjs::class::impl_::CloneWrapper(&*borrow).wrap_clone())
+        }
+    }
+}
+impl Transaction {
+    /// Gets the wrapped resource, failing if it has been disposed or if it is a
+    /// borrowed handle and the call it was passed to has already returned
+    fn checked_inner(
+        &self,
+    ) -> Result<
+        &std::rc::Rc<crate::bindings::golem::graph::transactions::Transaction>,
+        &'static str,
+    > {
+        if self
+            .borrow_epoch
+            .is_some_and(|epoch| !crate::internal::is_borrow_active(epoch))
+        {
+            return Err(
+                "Borrowed resource handle cannot be used after the call it was passed to has returned",
+            );
+        }
+        self.inner.as_ref().ok_or("Resource has already been disposed")
+    }
+    /// Gets the wrapped resource for calling one of its methods, throwing a
+    /// `ReferenceError` if it cannot be used
+    fn resource(
+        &self,
+        ctx: &rquickjs::Ctx<'_>,
+    ) -> rquickjs::Result<&crate::bindings::golem::graph::transactions::Transaction> {
+        self.checked_inner()
+            .map(|inner| inner.deref())
+            .map_err(|message| rquickjs::Exception::throw_reference(ctx, message))
+    }
+    /// Releases the wrapped resource. Borrowed handles belong to the host, so they
+    /// are never dropped, only forgotten.
+    fn release(&mut self) {
+        if let Some(inner) = self.inner.take() {
+            if self.borrow_epoch.is_some() {
+                if let Ok(inner) = std::rc::Rc::try_unwrap(inner) {
+                    let _ = inner.take_handle();
+                }
+            }
+        }
+    }
+}
+impl Drop for Transaction {
+    fn drop(&mut self) {
+        self.release();
+    }
+}
+#[rquickjs::methods(rename_all = "camelCase")]
+impl Transaction {
+    #[qjs(constructor)]
+    pub fn new() -> Self {
+        Self {
+            inner: None,
+            borrow_epoch: None,
+        }
+    }
+    pub fn create_vertex(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        vertex_type: crate::wrappers::JsString,
+        properties: crate::wrappers::JsList<
+            rquickjs::convert::List<
+                (
+                    crate::wrappers::JsString,
+                    crate::bindings::golem::graph::types::PropertyValue,
+                ),
+            >,
+        >,
+    ) -> rquickjs::Result<crate::bindings::golem::graph::types::Vertex> {
+        let result: Result<
+            crate::bindings::golem::graph::transactions::Vertex,
+            crate::bindings::golem::graph::transactions::GraphError,
+        > = self
+            .resource(&ctx)?
+            .create_vertex(
+                vertex_type.0.as_str(),
+                properties
+                    .0
+                    .into_iter()
+                    .map(|v| (v.0.0.0.clone(), v.0.1))
+                    .collect::<Vec<_>>()
+                    .as_slice(),
+            );
+        match result {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                let error: crate::bindings::golem::graph::errors::GraphError = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn create_vertex_with_labels(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        vertex_type: crate::wrappers::JsString,
+        additional_labels: crate::wrappers::JsList<crate::wrappers::JsString>,
+        properties: crate::wrappers::JsList<
+            rquickjs::convert::List<
+                (
+                    crate::wrappers::JsString,
+                    crate::bindings::golem::graph::types::PropertyValue,
+                ),
+            >,
+        >,
+    ) -> rquickjs::Result<crate::bindings::golem::graph::types::Vertex> {
+        let result: Result<
+            crate::bindings::golem::graph::transactions::Vertex,
+            crate::bindings::golem::graph::transactions::GraphError,
+        > = self
+            .resource(&ctx)?
+            .create_vertex_with_labels(
+                vertex_type.0.as_str(),
+                additional_labels
+                    .0
+                    .into_iter()
+                    .map(|v| v.0)
+                    .collect::<Vec<_>>()
+                    .as_slice(),
+                properties
+                    .0
+                    .into_iter()
+                    .map(|v| (v.0.0.0.clone(), v.0.1))
+                    .collect::<Vec<_>>()
+                    .as_slice(),
+            );
+        match result {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                let error: crate::bindings::golem::graph::errors::GraphError = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn get_vertex(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        id: crate::bindings::golem::graph::types::ElementId,
+    ) -> rquickjs::Result<Option<crate::bindings::golem::graph::types::Vertex>> {
+        let result: Result<
+            Option<crate::bindings::golem::graph::transactions::Vertex>,
+            crate::bindings::golem::graph::transactions::GraphError,
+        > = self.resource(&ctx)?.get_vertex(&id);
+        match result {
+            Ok(result) => Ok(result.map(|v| v)),
+            Err(error) => {
+                let error: crate::bindings::golem::graph::errors::GraphError = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn update_vertex(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        id: crate::bindings::golem::graph::types::ElementId,
+        properties: crate::wrappers::JsList<
+            rquickjs::convert::List<
+                (
+                    crate::wrappers::JsString,
+                    crate::bindings::golem::graph::types::PropertyValue,
+                ),
+            >,
+        >,
+    ) -> rquickjs::Result<crate::bindings::golem::graph::types::Vertex> {
+        let result: Result<
+            crate::bindings::golem::graph::transactions::Vertex,
+            crate::bindings::golem::graph::transactions::GraphError,
+        > = self
+            .resource(&ctx)?
+            .update_vertex(
+                &id,
+                properties
+                    .0
+                    .into_iter()
+                    .map(|v| (v.0.0.0.clone(), v.0.1))
+                    .collect::<Vec<_>>()
+                    .as_slice(),
+            );
+        match result {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                let error: crate::bindings::golem::graph::errors::GraphError = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn update_vertex_properties(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        id: crate::bindings::golem::graph::types::ElementId,
+        updates: crate::wrappers::JsList<
+            rquickjs::convert::List<
+                (
+                    crate::wrappers::JsString,
+                    crate::bindings::golem::graph::types::PropertyValue,
+                ),
+            >,
+        >,
+    ) -> rquickjs::Result<crate::bindings::golem::graph::types::Vertex> {
+        let result: Result<
+            crate::bindings::golem::graph::transactions::Vertex,
+            crate::bindings::golem::graph::transactions::GraphError,
+        > = self
+            .resource(&ctx)?
+            .update_vertex_properties(
+                &id,
+                updates
+                    .0
+                    .into_iter()
+                    .map(|v| (v.0.0.0.clone(), v.0.1))
+                    .collect::<Vec<_>>()
+                    .as_slice(),
+            );
+        match result {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                let error: crate::bindings::golem::graph::errors::GraphError = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn delete_vertex(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        id: crate::bindings::golem::graph::types::ElementId,
+        delete_edges: bool,
+    ) -> rquickjs::Result<()> {
+        let result: Result<
+            (),
+            crate::bindings::golem::graph::transactions::GraphError,
+        > = self.resource(&ctx)?.delete_vertex(&id, delete_edges);
+        match result {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                let error: crate::bindings::golem::graph::errors::GraphError = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn find_vertices(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        vertex_type: Option<crate::wrappers::JsString>,
+        filters: Option<
+            crate::wrappers::JsList<
+                crate::bindings::golem::graph::types::FilterCondition,
+            >,
+        >,
+        sort: Option<
+            crate::wrappers::JsList<crate::bindings::golem::graph::types::SortSpec>,
+        >,
+        limit: Option<u32>,
+        offset: Option<u32>,
+    ) -> rquickjs::Result<
+        crate::wrappers::JsList<crate::bindings::golem::graph::types::Vertex>,
+    > {
+        let result: Result<
+            Vec<crate::bindings::golem::graph::transactions::Vertex>,
+            crate::bindings::golem::graph::transactions::GraphError,
+        > = self
+            .resource(&ctx)?
+            .find_vertices(
+                vertex_type.map(|v| v.0).as_deref(),
+                filters
+                    .map(|v| v.0.into_iter().map(|v| v).collect::<Vec<_>>())
+                    .as_deref(),
+                sort.map(|v| v.0.into_iter().map(|v| v).collect::<Vec<_>>()).as_deref(),
+                limit.map(|v| v),
+                offset.map(|v| v),
+            );
+        match result {
+            Ok(result) => {
+                Ok(
+                    crate::wrappers::JsList(
+                        result.into_iter().map(|v| v).collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            Err(error) => {
+                let error: crate::bindings::golem::graph::errors::GraphError = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn create_edge(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        edge_type: crate::wrappers::JsString,
+        from_vertex: crate::bindings::golem::graph::types::ElementId,
+        to_vertex: crate::bindings::golem::graph::types::ElementId,
+        properties: crate::wrappers::JsList<
+            rquickjs::convert::List<
+                (
+                    crate::wrappers::JsString,
+                    crate::bindings::golem::graph::types::PropertyValue,
+                ),
+            >,
+        >,
+    ) -> rquickjs::Result<crate::bindings::golem::graph::types::Edge> {
+        let result: Result<
+            crate::bindings::golem::graph::transactions::Edge,
+            crate::bindings::golem::graph::transactions::GraphError,
+        > = self
+            .resource(&ctx)?
+            .create_edge(
+                edge_type.0.as_str(),
+                &from_vertex,
+                &to_vertex,
+                properties
+                    .0
+                    .into_iter()
+                    .map(|v| (v.0.0.0.clone(), v.0.1))
+                    .collect::<Vec<_>>()
+                    .as_slice(),
+            );
+        match result {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                let error: crate::bindings::golem::graph::errors::GraphError = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn get_edge(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        id: crate::bindings::golem::graph::types::ElementId,
+    ) -> rquickjs::Result<Option<crate::bindings::golem::graph::types::Edge>> {
+        let result: Result<
+            Option<crate::bindings::golem::graph::transactions::Edge>,
+            crate::bindings::golem::graph::transactions::GraphError,
+        > = self.resource(&ctx)?.get_edge(&id);
+        match result {
+            Ok(result) => Ok(result.map(|v| v)),
+            Err(error) => {
+                let error: crate::bindings::golem::graph::errors::GraphError = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn update_edge(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        id: crate::bindings::golem::graph::types::ElementId,
+        properties: crate::wrappers::JsList<
+            rquickjs::convert::List<
+                (
+                    crate::wrappers::JsString,
+                    crate::bindings::golem::graph::types::PropertyValue,
+                ),
+            >,
+        >,
+    ) -> rquickjs::Result<crate::bindings::golem::graph::types::Edge> {
+        let result: Result<
+            crate::bindings::golem::graph::transactions::Edge,
+            crate::bindings::golem::graph::transactions::GraphError,
+        > = self
+            .resource(&ctx)?
+            .update_edge(
+                &id,
+                properties
+                    .0
+                    .into_iter()
+                    .map(|v| (v.0.0.0.clone(), v.0.1))
+                    .collect::<Vec<_>>()
+                    .as_slice(),
+            );
+        match result {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                let error: crate::bindings::golem::graph::errors::GraphError = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn update_edge_properties(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        id: crate::bindings::golem::graph::types::ElementId,
+        updates: crate::wrappers::JsList<
+            rquickjs::convert::List<
+                (
+                    crate::wrappers::JsString,
+                    crate::bindings::golem::graph::types::PropertyValue,
+                ),
+            >,
+        >,
+    ) -> rquickjs::Result<crate::bindings::golem::graph::types::Edge> {
+        let result: Result<
+            crate::bindings::golem::graph::transactions::Edge,
+            crate::bindings::golem::graph::transactions::GraphError,
+        > = self
+            .resource(&ctx)?
+            .update_edge_properties(
+                &id,
+                updates
+                    .0
+                    .into_iter()
+                    .map(|v| (v.0.0.0.clone(), v.0.1))
+                    .collect::<Vec<_>>()
+                    .as_slice(),
+            );
+        match result {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                let error: crate::bindings::golem::graph::errors::GraphError = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn delete_edge(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        id: crate::bindings::golem::graph::types::ElementId,
+    ) -> rquickjs::Result<()> {
+        let result: Result<
+            (),
+            crate::bindings::golem::graph::transactions::GraphError,
+        > = self.resource(&ctx)?.delete_edge(&id);
+        match result {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                let error: crate::bindings::golem::graph::errors::GraphError = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn find_edges(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        edge_types: Option<crate::wrappers::JsList<crate::wrappers::JsString>>,
+        filters: Option<
+            crate::wrappers::JsList<
+                crate::bindings::golem::graph::types::FilterCondition,
+            >,
+        >,
+        sort: Option<
+            crate::wrappers::JsList<crate::bindings::golem::graph::types::SortSpec>,
+        >,
+        limit: Option<u32>,
+        offset: Option<u32>,
+    ) -> rquickjs::Result<
+        crate::wrappers::JsList<crate::bindings::golem::graph::types::Edge>,
+    > {
+        let result: Result<
+            Vec<crate::bindings::golem::graph::transactions::Edge>,
+            crate::bindings::golem::graph::transactions::GraphError,
+        > = self
+            .resource(&ctx)?
+            .find_edges(
+                edge_types
+                    .map(|v| v.0.into_iter().map(|v| v.0).collect::<Vec<_>>())
+                    .as_deref(),
+                filters
+                    .map(|v| v.0.into_iter().map(|v| v).collect::<Vec<_>>())
+                    .as_deref(),
+                sort.map(|v| v.0.into_iter().map(|v| v).collect::<Vec<_>>()).as_deref(),
+                limit.map(|v| v),
+                offset.map(|v| v),
+            );
+        match result {
+            Ok(result) => {
+                Ok(
+                    crate::wrappers::JsList(
+                        result.into_iter().map(|v| v).collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            Err(error) => {
+                let error: crate::bindings::golem::graph::errors::GraphError = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn get_adjacent_vertices(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        vertex_id: crate::bindings::golem::graph::types::ElementId,
+        direction: crate::bindings::golem::graph::types::Direction,
+        edge_types: Option<crate::wrappers::JsList<crate::wrappers::JsString>>,
+        limit: Option<u32>,
+    ) -> rquickjs::Result<
+        crate::wrappers::JsList<crate::bindings::golem::graph::types::Vertex>,
+    > {
+        let result: Result<
+            Vec<crate::bindings::golem::graph::transactions::Vertex>,
+            crate::bindings::golem::graph::transactions::GraphError,
+        > = self
+            .resource(&ctx)?
+            .get_adjacent_vertices(
+                &vertex_id,
+                direction,
+                edge_types
+                    .map(|v| v.0.into_iter().map(|v| v.0).collect::<Vec<_>>())
+                    .as_deref(),
+                limit.map(|v| v),
+            );
+        match result {
+            Ok(result) => {
+                Ok(
+                    crate::wrappers::JsList(
+                        result.into_iter().map(|v| v).collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            Err(error) => {
+                let error: crate::bindings::golem::graph::errors::GraphError = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn get_connected_edges(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        vertex_id: crate::bindings::golem::graph::types::ElementId,
+        direction: crate::bindings::golem::graph::types::Direction,
+        edge_types: Option<crate::wrappers::JsList<crate::wrappers::JsString>>,
+        limit: Option<u32>,
+    ) -> rquickjs::Result<
+        crate::wrappers::JsList<crate::bindings::golem::graph::types::Edge>,
+    > {
+        let result: Result<
+            Vec<crate::bindings::golem::graph::transactions::Edge>,
+            crate::bindings::golem::graph::transactions::GraphError,
+        > = self
+            .resource(&ctx)?
+            .get_connected_edges(
+                &vertex_id,
+                direction,
+                edge_types
+                    .map(|v| v.0.into_iter().map(|v| v.0).collect::<Vec<_>>())
+                    .as_deref(),
+                limit.map(|v| v),
+            );
+        match result {
+            Ok(result) => {
+                Ok(
+                    crate::wrappers::JsList(
+                        result.into_iter().map(|v| v).collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            Err(error) => {
+                let error: crate::bindings::golem::graph::errors::GraphError = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn create_vertices(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        vertices: crate::wrappers::JsList<
+            crate::bindings::golem::graph::transactions::VertexSpec,
+        >,
+    ) -> rquickjs::Result<
+        crate::wrappers::JsList<crate::bindings::golem::graph::types::Vertex>,
+    > {
+        let result: Result<
+            Vec<crate::bindings::golem::graph::transactions::Vertex>,
+            crate::bindings::golem::graph::transactions::GraphError,
+        > = self
+            .resource(&ctx)?
+            .create_vertices(
+                vertices.0.into_iter().map(|v| v).collect::<Vec<_>>().as_slice(),
+            );
+        match result {
+            Ok(result) => {
+                Ok(
+                    crate::wrappers::JsList(
+                        result.into_iter().map(|v| v).collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            Err(error) => {
+                let error: crate::bindings::golem::graph::errors::GraphError = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn create_edges(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        edges: crate::wrappers::JsList<
+            crate::bindings::golem::graph::transactions::EdgeSpec,
+        >,
+    ) -> rquickjs::Result<
+        crate::wrappers::JsList<crate::bindings::golem::graph::types::Edge>,
+    > {
+        let result: Result<
+            Vec<crate::bindings::golem::graph::transactions::Edge>,
+            crate::bindings::golem::graph::transactions::GraphError,
+        > = self
+            .resource(&ctx)?
+            .create_edges(edges.0.into_iter().map(|v| v).collect::<Vec<_>>().as_slice());
+        match result {
+            Ok(result) => {
+                Ok(
+                    crate::wrappers::JsList(
+                        result.into_iter().map(|v| v).collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            Err(error) => {
+                let error: crate::bindings::golem::graph::errors::GraphError = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn upsert_vertex(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        id: Option<crate::bindings::golem::graph::types::ElementId>,
+        vertex_type: crate::wrappers::JsString,
+        properties: crate::wrappers::JsList<
+            rquickjs::convert::List<
+                (
+                    crate::wrappers::JsString,
+                    crate::bindings::golem::graph::types::PropertyValue,
+                ),
+            >,
+        >,
+    ) -> rquickjs::Result<crate::bindings::golem::graph::types::Vertex> {
+        let result: Result<
+            crate::bindings::golem::graph::transactions::Vertex,
+            crate::bindings::golem::graph::transactions::GraphError,
+        > = self
+            .resource(&ctx)?
+            .upsert_vertex(
+                id.map(|v| v).as_ref(),
+                vertex_type.0.as_str(),
+                properties
+                    .0
+                    .into_iter()
+                    .map(|v| (v.0.0.0.clone(), v.0.1))
+                    .collect::<Vec<_>>()
+                    .as_slice(),
+            );
+        match result {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                let error: crate::bindings::golem::graph::errors::GraphError = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn upsert_edge(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        id: Option<crate::bindings::golem::graph::types::ElementId>,
+        edge_type: crate::wrappers::JsString,
+        from_vertex: crate::bindings::golem::graph::types::ElementId,
+        to_vertex: crate::bindings::golem::graph::types::ElementId,
+        properties: crate::wrappers::JsList<
+            rquickjs::convert::List<
+                (
+                    crate::wrappers::JsString,
+                    crate::bindings::golem::graph::types::PropertyValue,
+                ),
+            >,
+        >,
+    ) -> rquickjs::Result<crate::bindings::golem::graph::types::Edge> {
+        let result: Result<
+            crate::bindings::golem::graph::transactions::Edge,
+            crate::bindings::golem::graph::transactions::GraphError,
+        > = self
+            .resource(&ctx)?
+            .upsert_edge(
+                id.map(|v| v).as_ref(),
+                edge_type.0.as_str(),
+                &from_vertex,
+                &to_vertex,
+                properties
+                    .0
+                    .into_iter()
+                    .map(|v| (v.0.0.0.clone(), v.0.1))
+                    .collect::<Vec<_>>()
+                    .as_slice(),
+            );
+        match result {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                let error: crate::bindings::golem::graph::errors::GraphError = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn commit(&self, ctx: rquickjs::Ctx<'_>) -> rquickjs::Result<()> {
+        let result: Result<
+            (),
+            crate::bindings::golem::graph::transactions::GraphError,
+        > = self.resource(&ctx)?.commit();
+        match result {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                let error: crate::bindings::golem::graph::errors::GraphError = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn rollback(&self, ctx: rquickjs::Ctx<'_>) -> rquickjs::Result<()> {
+        let result: Result<
+            (),
+            crate::bindings::golem::graph::transactions::GraphError,
+        > = self.resource(&ctx)?.rollback();
+        match result {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                let error: crate::bindings::golem::graph::errors::GraphError = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn is_active(&self, ctx: rquickjs::Ctx<'_>) -> rquickjs::Result<bool> {
+        let result: bool = self.resource(&ctx)?.is_active();
+        Ok(result)
+    }
+    #[qjs(rename = "__dispose")]
+    pub fn __dispose(&mut self) {
+        self.release();
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::graph::transactions::Transaction {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        Transaction {
+            inner: Some(std::rc::Rc::new(self)),
+            borrow_epoch: None,
+        }
+            .into_js(ctx)
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::graph::transactions::Transaction {
+    fn from_js(
+        ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let wrapper = Transaction::from_js(ctx, value)?;
+        if wrapper.borrow_epoch.is_some() {
+            return Err(rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "Transaction",
+                message: Some(
+                    "The ownership of a borrowed resource handle cannot be transferred"
+                        .to_string(),
+                ),
+            });
+        }
+        let inner = wrapper
+            .checked_inner()
+            .map_err(|message| rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "Transaction",
+                message: Some(message.to_string()),
+            })?;
+        unsafe {
+            Ok(
+                crate::bindings::golem::graph::transactions::Transaction::from_handle(
+                    inner.take_handle(),
+                ),
+            )
+        }
+    }
+}
+pub struct BorrowTransactionWrapper(
+    pub crate::bindings::golem::graph::transactions::Transaction,
+);
+impl<'js> rquickjs::FromJs<'js> for BorrowTransactionWrapper {
+    fn from_js(
+        ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let wrapper = Transaction::from_js(ctx, value)?;
+        let inner = wrapper
+            .checked_inner()
+            .map_err(|message| rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "Transaction",
+                message: Some(message.to_string()),
+            })?;
+        unsafe {
+            Ok(
+                BorrowTransactionWrapper(
+                    crate::bindings::golem::graph::transactions::Transaction::from_handle(
+                        inner.handle(),
+                    ),
+                ),
+            )
+        }
+    }
+}
+impl BorrowTransactionWrapper {
+    /// Borrows a handle passed to an exported function, without taking its ownership
+    pub fn from_ref(
+        resource: &crate::bindings::golem::graph::transactions::Transaction,
+    ) -> Self {
+        unsafe {
+            BorrowTransactionWrapper(
+                crate::bindings::golem::graph::transactions::Transaction::from_handle(
+                    resource.handle(),
+                ),
+            )
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for BorrowTransactionWrapper {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let resource = unsafe {
+            crate::bindings::golem::graph::transactions::Transaction::from_handle(
+                self.0.take_handle(),
+            )
+        };
+        Transaction {
+            inner: Some(std::rc::Rc::new(resource)),
+            borrow_epoch: Some(crate::internal::current_borrow_epoch()),
+        }
+            .into_js(ctx)
+    }
+}
+impl Drop for BorrowTransactionWrapper {
+    fn drop(&mut self) {
+        let _ = self.0.take_handle();
+    }
+}
+pub struct JsTransactionsModule;
+impl rquickjs::module::ModuleDef for JsTransactionsModule {
+    fn declare(decl: &rquickjs::module::Declarations) -> rquickjs::Result<()> {
+        decl.declare("Transaction")?;
+        Ok(())
+    }
+    fn evaluate<'js>(
+        ctx: &rquickjs::Ctx<'js>,
+        exports: &rquickjs::module::Exports<'js>,
+    ) -> rquickjs::Result<()> {
+        exports.export("Transaction", Transaction::constructor(ctx)?)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,170 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:all-golem-imports/all-golem-imports
+// wit: sha256:3ec76077ee8750a4a3d97cf8f89273803d43fba31aa02651185015b5648e2fc7
+// js all-golem-imports: sha256:1aa6d68c08d678e85c1f334bb55fc7062ce81fa0f90be1bc0d6a3965bfccc568
+
+use rquickjs::JsLifetime;
+use rquickjs::class::{JsClass, Trace};
+use std::ops::Deref;
+#[rquickjs::function]
+fn find_shortest_path(
+    ctx: rquickjs::Ctx<'_>,
+    transaction: crate::modules::golem_graph_1_0_0_transactions::BorrowTransactionWrapper,
+    from_vertex: crate::bindings::golem::graph::types::ElementId,
+    to_vertex: crate::bindings::golem::graph::types::ElementId,
+    options: Option<crate::bindings::golem::graph::traversal::PathOptions>,
+) -> rquickjs::Result<Option<crate::bindings::golem::graph::types::Path>> {
+    let result: Result<
+        Option<crate::bindings::golem::graph::traversal::Path>,
+        crate::bindings::golem::graph::traversal::GraphError,
+    > = crate::bindings::golem::graph::traversal::find_shortest_path(
+        &transaction.0,
+        &from_vertex,
+        &to_vertex,
+        options.map(|v| v).as_ref(),
+    );
+    match result {
+        Ok(result) => Ok(result.map(|v| v)),
+        Err(error) => {
+            let error: crate::bindings::golem::graph::errors::GraphError = error;
+            Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+        }
+    }
+}
+#[rquickjs::function]
+fn find_all_paths(
+    ctx: rquickjs::Ctx<'_>,
+    transaction: crate::modules::golem_graph_1_0_0_transactions::BorrowTransactionWrapper,
+    from_vertex: crate::bindings::golem::graph::types::ElementId,
+    to_vertex: crate::bindings::golem::graph::types::ElementId,
+    options: Option<crate::bindings::golem::graph::traversal::PathOptions>,
+    limit: Option<u32>,
+) -> rquickjs::Result<
+    crate::wrappers::JsList<crate::bindings::golem::graph::types::Path>,
+> {
+    let result: Result<
+        Vec<crate::bindings::golem::graph::traversal::Path>,
+        crate::bindings::golem::graph::traversal::GraphError,
+    > = crate::bindings::golem::graph::traversal::find_all_paths(
+        &transaction.0,
+        &from_vertex,
+        &to_vertex,
+        options.map(|v| v).as_ref(),
+        limit.map(|v| v),
+    );
+    match result {
+        Ok(result) => {
+            Ok(
+                crate::wrappers::JsList(
+                    result.into_iter().map(|v| v).collect::<Vec<_>>(),
+                ),
+            )
+        }
+        Err(error) => {
+            let error: crate::bindings::golem::graph::errors::GraphError = error;
+            Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+        }
+    }
+}
+#[rquickjs::function]
+fn get_neighborhood(
+    ctx: rquickjs::Ctx<'_>,
+    transaction: crate::modules::golem_graph_1_0_0_transactions::BorrowTransactionWrapper,
+    center: crate::bindings::golem::graph::types::ElementId,
+    options: crate::bindings::golem::graph::traversal::NeighborhoodOptions,
+) -> rquickjs::Result<crate::bindings::golem::graph::traversal::Subgraph> {
+    let result: Result<
+        crate::bindings::golem::graph::traversal::Subgraph,
+        crate::bindings::golem::graph::traversal::GraphError,
+    > = crate::bindings::golem::graph::traversal::get_neighborhood(
+        &transaction.0,
+        &center,
+        &options,
+    );
+    match result {
+        Ok(result) => Ok(result),
+        Err(error) => {
+            let error: crate::bindings::golem::graph::errors::GraphError = error;
+            Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+        }
+    }
+}
+#[rquickjs::function]
+fn path_exists(
+    ctx: rquickjs::Ctx<'_>,
+    transaction: crate::modules::golem_graph_1_0_0_transactions::BorrowTransactionWrapper,
+    from_vertex: crate::bindings::golem::graph::types::ElementId,
+    to_vertex: crate::bindings::golem::graph::types::ElementId,
+    options: Option<crate::bindings::golem::graph::traversal::PathOptions>,
+) -> rquickjs::Result<bool> {
+    let result: Result<bool, crate::bindings::golem::graph::traversal::GraphError> = crate::bindings::golem::graph::traversal::path_exists(
+        &transaction.0,
+        &from_vertex,
+        &to_vertex,
+        options.map(|v| v).as_ref(),
+    );
+    match result {
+        Ok(result) => Ok(result),
+        Err(error) => {
+            let error: crate::bindings::golem::graph::errors::GraphError = error;
+            Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+        }
+    }
+}
+#[rquickjs::function]
+fn get_vertices_at_distance(
+    ctx: rquickjs::Ctx<'_>,
+    transaction: crate::modules::golem_graph_1_0_0_transactions::BorrowTransactionWrapper,
+    source: crate::bindings::golem::graph::types::ElementId,
+    distance: u32,
+    direction: crate::bindings::golem::graph::types::Direction,
+    edge_types: Option<crate::wrappers::JsList<crate::wrappers::JsString>>,
+) -> rquickjs::Result<
+    crate::wrappers::JsList<crate::bindings::golem::graph::types::Vertex>,
+> {
+    let result: Result<
+        Vec<crate::bindings::golem::graph::traversal::Vertex>,
+        crate::bindings::golem::graph::traversal::GraphError,
+    > = crate::bindings::golem::graph::traversal::get_vertices_at_distance(
+        &transaction.0,
+        &source,
+        distance,
+        direction,
+        edge_types.map(|v| v.0.into_iter().map(|v| v.0).collect::<Vec<_>>()).as_deref(),
+    );
+    match result {
+        Ok(result) => {
+            Ok(
+                crate::wrappers::JsList(
+                    result.into_iter().map(|v| v).collect::<Vec<_>>(),
+                ),
+            )
+        }
+        Err(error) => {
+            let error: crate::bindings::golem::graph::errors::GraphError = error;
+            Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+        }
+    }
+}
+pub struct JsTraversalModule;
+impl rquickjs::module::ModuleDef for JsTraversalModule {
+    fn declare(decl: &rquickjs::module::Declarations) -> rquickjs::Result<()> {
+        decl.declare("findShortestPath")?;
+        decl.declare("findAllPaths")?;
+        decl.declare("getNeighborhood")?;
+        decl.declare("pathExists")?;
+        decl.declare("getVerticesAtDistance")?;
+        Ok(())
+    }
+    fn evaluate<'js>(
+        ctx: &rquickjs::Ctx<'js>,
+        exports: &rquickjs::module::Exports<'js>,
+    ) -> rquickjs::Result<()> {
+        exports.export("findShortestPath", js_find_shortest_path)?;
+        exports.export("findAllPaths", js_find_all_paths)?;
+        exports.export("getNeighborhood", js_get_neighborhood)?;
+        exports.export("pathExists", js_path_exists)?;
+        exports.export("getVerticesAtDistance", js_get_vertices_at_distance)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,934 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:all-golem-imports/all-golem-imports
+// wit: sha256:3ec76077ee8750a4a3d97cf8f89273803d43fba31aa02651185015b5648e2fc7
+// js all-golem-imports: sha256:1aa6d68c08d678e85c1f334bb55fc7062ce81fa0f90be1bc0d6a3965bfccc568
+
+use rquickjs::JsLifetime;
+use rquickjs::class::{JsClass, Trace};
+use std::ops::Deref;
+#[derive(Clone, JsLifetime, Trace)]
+pub struct DbResultStream {
+    #[qjs(skip_trace = true)]
+    inner: Option<std::rc::Rc<crate::bindings::golem::rdbms::mysql::DbResultStream>>,
+    /// The export call epoch if the handle is only borrowed from the host
+    #[qjs(skip_trace = true)]
+    borrow_epoch: Option<usize>,
+}
+mod __impl_class_db_result_stream_ {
+    pub use super::*;
+    use rquickjs::{Atom, Symbol, Value};
+    impl<'js> rquickjs::class::JsClass<'js> for DbResultStream {
+        const NAME: &'static str = "DbResultStream";
+        type Mutable = rquickjs::class::Writable;
+        fn prototype(
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<Option<rquickjs::Object<'js>>> {
+            use rquickjs::class::impl_::MethodImplementor;
+            let proto = rquickjs::Object::new(ctx.clone())?;
+            let implementor = rquickjs::class::impl_::MethodImpl::<Self>::new();
+            (&implementor).implement(&proto)?;
+            let dispose_symbol: Symbol = ctx
+                .globals()
+                .get(crate::internal::DISPOSE_SYMBOL)?;
+            let dispose_fn: Value = proto.get("__dispose")?;
+            proto.set(dispose_symbol, dispose_fn)?;
+            Ok(Some(proto))
+        }
+        fn constructor(
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<Option<rquickjs::function::Constructor<'js>>> {
+            use rquickjs::class::impl_::ConstructorCreator;
+            let implementor = rquickjs::class::impl_::ConstructorCreate::<Self>::new();
+            (&implementor).create_constructor(ctx)
+        }
+    }
+    impl<'js> rquickjs::IntoJs<'js> for DbResultStream {
+        fn into_js(
+            self,
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<rquickjs::Value<'js>> {
+            let cls = rquickjs::class::Class::<Self>::instance(ctx.clone(), self)?;
+            rquickjs::IntoJs::into_js(cls, ctx)
+        }
+    }
+    impl<'js> rquickjs::FromJs<'js> for DbResultStream
+    where
+        for<'a> rquickjs::class::impl_::CloneWrapper<
+            'a,
+            Self,
+        >: rquickjs::class::impl_::CloneTrait<Self>,
+    {
+        fn from_js(
+            ctx: &rquickjs::Ctx<'js>,
+            value: rquickjs::Value<'js>,
+        ) -> rquickjs::Result<Self> {
+            use rquickjs::class::impl_::CloneTrait;
+            let value = rquickjs::class::Class::<Self>::from_js(ctx, value)?;
+            let borrow = value.try_borrow()?;
+            Ok(rquickjs::class::impl_::CloneWrapper(&*borrow).wrap_clone())
+        }
+    }
+}
+impl DbResultStream {
+    /// Gets the wrapped resource, failing if it has been disposed or if it is a
+    /// borrowed handle and the call it was passed to has already returned
+    fn checked_inner(
+        &self,
+    ) -> Result<
+        &std::rc::Rc<crate::bindings::golem::rdbms::mysql::DbResultStream>,
+        &'static str,
+    > {
+        if self
+            .borrow_epoch
+            .is_some_and(|epoch| !crate::internal::is_borrow_active(epoch))
+        {
+            return Err(
+                "Borrowed resource handle cannot be used after the call it was passed to has returned",
+            );
+        }
+        self.inner.as_ref().ok_or("Resource has already been disposed")
+    }
+    /// Gets the wrapped resource for calling one of its methods, throwing a
+    /// `ReferenceError` if it cannot be used
+    fn resource(
+        &self,
+        ctx: &rquickjs::Ctx<'_>,
+    ) -> rquickjs::Result<&crate::bindings::golem::rdbms::mysql::DbResultStream> {
+        self.checked_inner()
+            .map(|inner| inner.deref())
+            .map_err(|message| rquickjs::Exception::throw_reference(ctx, message))
+    }
+    /// Releases the wrapped resource. Borrowed handles belong to the host, so they
+    /// are never dropped, only forgotten.
+    fn release(&mut self) {
+        if let Some(inner) = self.inner.take() {
+            if self.borrow_epoch.is_some() {
+                if let Ok(inner) = std::rc::Rc::try_unwrap(inner) {
+                    let _ = inner.take_handle();
+                }
+            }
+        }
+    }
+}
+impl Drop for DbResultStream {
+    fn drop(&mut self) {
+        self.release();
+    }
+}
+#[rquickjs::methods(rename_all = "camelCase")]
+impl DbResultStream {
+    #[qjs(constructor)]
+    pub fn new() -> Self {
+        Self {
+            inner: None,
+            borrow_epoch: None,
+        }
+    }
+    pub fn get_columns(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+    ) -> rquickjs::Result<
+        crate::wrappers::JsList<crate::bindings::golem::rdbms::mysql::DbColumn>,
+    > {
+        let result: Vec<crate::bindings::golem::rdbms::mysql::DbColumn> = self
+            .resource(&ctx)?
+            .get_columns();
+        Ok(crate::wrappers::JsList(result.into_iter().map(|v| v).collect::<Vec<_>>()))
+    }
+    pub fn get_next(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+    ) -> rquickjs::Result<
+        Option<crate::wrappers::JsList<crate::bindings::golem::rdbms::mysql::DbRow>>,
+    > {
+        let result: Option<Vec<crate::bindings::golem::rdbms::mysql::DbRow>> = self
+            .resource(&ctx)?
+            .get_next();
+        Ok(
+            result
+                .map(|v| crate::wrappers::JsList(
+                    v.into_iter().map(|v| v).collect::<Vec<_>>(),
+                )),
+        )
+    }
+    #[qjs(rename = "__dispose")]
+    pub fn __dispose(&mut self) {
+        self.release();
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::rdbms::mysql::DbResultStream {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        DbResultStream {
+            inner: Some(std::rc::Rc::new(self)),
+            borrow_epoch: None,
+        }
+            .into_js(ctx)
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::rdbms::mysql::DbResultStream {
+    fn from_js(
+        ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let wrapper = DbResultStream::from_js(ctx, value)?;
+        if wrapper.borrow_epoch.is_some() {
+            return Err(rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "DbResultStream",
+                message: Some(
+                    "The ownership of a borrowed resource handle cannot be transferred"
+                        .to_string(),
+                ),
+            });
+        }
+        let inner = wrapper
+            .checked_inner()
+            .map_err(|message| rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "DbResultStream",
+                message: Some(message.to_string()),
+            })?;
+        unsafe {
+            Ok(
+                crate::bindings::golem::rdbms::mysql::DbResultStream::from_handle(
+                    inner.take_handle(),
+                ),
+            )
+        }
+    }
+}
+pub struct BorrowDbResultStreamWrapper(
+    pub crate::bindings::golem::rdbms::mysql::DbResultStream,
+);
+impl<'js> rquickjs::FromJs<'js> for BorrowDbResultStreamWrapper {
+    fn from_js(
+        ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let wrapper = DbResultStream::from_js(ctx, value)?;
+        let inner = wrapper
+            .checked_inner()
+            .map_err(|message| rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "DbResultStream",
+                message: Some(message.to_string()),
+            })?;
+        unsafe {
+            Ok(
+                BorrowDbResultStreamWrapper(
+                    crate::bindings::golem::rdbms::mysql::DbResultStream::from_handle(
+                        inner.handle(),
+                    ),
+                ),
+            )
+        }
+    }
+}
+impl BorrowDbResultStreamWrapper {
+    /// Borrows a handle passed to an exported function, without taking its ownership
+    pub fn from_ref(
+        resource: &crate::bindings::golem::rdbms::mysql::DbResultStream,
+    ) -> Self {
+        unsafe {
+            BorrowDbResultStreamWrapper(
+                crate::bindings::golem::rdbms::mysql::DbResultStream::from_handle(
+                    resource.handle(),
+                ),
+            )
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for BorrowDbResultStreamWrapper {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let resource = unsafe {
+            crate::bindings::golem::rdbms::mysql::DbResultStream::from_handle(
+                self.0.take_handle(),
+            )
+        };
+        DbResultStream {
+            inner: Some(std::rc::Rc::new(resource)),
+            borrow_epoch: Some(crate::internal::current_borrow_epoch()),
+        }
+            .into_js(ctx)
+    }
+}
+impl Drop for BorrowDbResultStreamWrapper {
+    fn drop(&mut self) {
+        let _ = self.0.take_handle();
+    }
+}
+#[derive(Clone, JsLifetime, Trace)]
+pub struct DbConnection {
+    #[qjs(skip_trace = true)]
+    inner: Option<std::rc::Rc<crate::bindings::golem::rdbms::mysql::DbConnection>>,
+    /// The export call epoch if the handle is only borrowed from the host
+    #[qjs(skip_trace = true)]
+    borrow_epoch: Option<usize>,
+}
+mod __impl_class_db_connection_ {
+    pub use super::*;
+    use rquickjs::{Atom, Symbol, Value};
+    impl<'js> rquickjs::class::JsClass<'js> for DbConnection {
+        const NAME: &'static str = "DbConnection";
+        type Mutable = rquickjs::class::Writable;
+        fn prototype(
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<Option<rquickjs::Object<'js>>> {
+            use rquickjs::class::impl_::MethodImplementor;
+            let proto = rquickjs::Object::new(ctx.clone())?;
+            let implementor = rquickjs::class::impl_::MethodImpl::<Self>::new();
+            (&implementor).implement(&proto)?;
+            let dispose_symbol: Symbol = ctx
+                .globals()
+                .get(crate::internal::DISPOSE_SYMBOL)?;
+            let dispose_fn: Value = proto.get("__dispose")?;
+            proto.set(dispose_symbol, dispose_fn)?;
+            Ok(Some(proto))
+        }
+        fn constructor(
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<Option<rquickjs::function::Constructor<'js>>> {
+            use rquickjs::class::impl_::ConstructorCreator;
+            let implementor = rquickjs::class::impl_::ConstructorCreate::<Self>::new();
+            (&implementor).create_constructor(ctx)
+        }
+    }
+    impl<'js> rquickjs::IntoJs<'js> for DbConnection {
+        fn into_js(
+            self,
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<rquickjs::Value<'js>> {
+            let cls = rquickjs::class::Class::<Self>::instance(ctx.clone(), self)?;
+            rquickjs::IntoJs::into_js(cls, ctx)
+        }
+    }
+    impl<'js> rquickjs::FromJs<'js> for DbConnection
+    where
+        for<'a> rquickjs::class::impl_::CloneWrapper<
+            'a,
+            Self,
+        >: rquickjs::class::impl_::CloneTrait<Self>,
+    {
+        fn from_js(
+            ctx: &rquickjs::Ctx<'js>,
+            value: rquickjs::Value<'js>,
+        ) -> rquickjs::Result<Self> {
+            use rquickjs::class::impl_::CloneTrait;
+            let value = rquickjs::class::Class::<Self>::from_js(ctx, value)?;
+            let borrow = value.try_borrow()?;
+            Ok(rquickjs::class::impl_::CloneWrapper(&*borrow).wrap_clone())
+        }
+    }
+}
+impl DbConnection {
+    /// Gets the wrapped resource, failing if it has been disposed or if it is a
+    /// borrowed handle and the call it was passed to has already returned
+    fn checked_inner(
+        &self,
+    ) -> Result<
+        &std::rc::Rc<crate::bindings::golem::rdbms::mysql::DbConnection>,
+        &'static str,
+    > {
+        if self
+            .borrow_epoch
+            .is_some_and(|epoch| !crate::internal::is_borrow_active(epoch))
+        {
+            return Err(
+                "Borrowed resource handle cannot be used after the call it was passed to has returned",
+            );
+        }
+        self.inner.as_ref().ok_or("Resource has already been disposed")
+    }
+    /// Gets the wrapped resource for calling one of its methods, throwing a
+    /// `ReferenceError` if it cannot be used
+    fn resource(
+        &self,
+        ctx: &rquickjs::Ctx<'_>,
+    ) -> rquickjs::Result<&crate::bindings::golem::rdbms::mysql::DbConnection> {
+        self.checked_inner()
+            .map(|inner| inner.deref())
+            .map_err(|message| rquickjs::Exception::throw_reference(ctx, message))
+    }
+    /// Releases the wrapped resource. Borrowed handles belong to the host, so they
+    /// are never dropped, only forgotten.
+    fn release(&mut self) {
+        if let Some(inner) = self.inner.take() {
+            if self.borrow_epoch.is_some() {
+                if let Ok(inner) = std::rc::Rc::try_unwrap(inner) {
+                    let _ = inner.take_handle();
+                }
+            }
+        }
+    }
+}
+impl Drop for DbConnection {
+    fn drop(&mut self) {
+        self.release();
+    }
+}
+#[rquickjs::methods(rename_all = "camelCase")]
+impl DbConnection {
+    #[qjs(constructor)]
+    pub fn new() -> Self {
+        Self {
+            inner: None,
+            borrow_epoch: None,
+        }
+    }
+    #[qjs(static)]
+    pub fn open(
+        ctx: rquickjs::Ctx<'_>,
+        address: crate::wrappers::JsString,
+    ) -> rquickjs::Result<crate::bindings::golem::rdbms::mysql::DbConnection> {
+        let result: Result<
+            crate::bindings::golem::rdbms::mysql::DbConnection,
+            crate::bindings::golem::rdbms::mysql::Error,
+        > = crate::bindings::golem::rdbms::mysql::DbConnection::open(address.0.as_str());
+        match result {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                let error: crate::bindings::golem::rdbms::mysql::Error = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn query(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        statement: crate::wrappers::JsString,
+        params: crate::wrappers::JsList<crate::bindings::golem::rdbms::mysql::DbValue>,
+    ) -> rquickjs::Result<crate::bindings::golem::rdbms::mysql::DbResult> {
+        let result: Result<
+            crate::bindings::golem::rdbms::mysql::DbResult,
+            crate::bindings::golem::rdbms::mysql::Error,
+        > = self
+            .resource(&ctx)?
+            .query(
+                statement.0.as_str(),
+                params.0.into_iter().map(|v| v).collect::<Vec<_>>().as_slice(),
+            );
+        match result {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                let error: crate::bindings::golem::rdbms::mysql::Error = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn query_stream(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        statement: crate::wrappers::JsString,
+        params: crate::wrappers::JsList<crate::bindings::golem::rdbms::mysql::DbValue>,
+    ) -> rquickjs::Result<crate::bindings::golem::rdbms::mysql::DbResultStream> {
+        let result: Result<
+            crate::bindings::golem::rdbms::mysql::DbResultStream,
+            crate::bindings::golem::rdbms::mysql::Error,
+        > = self
+            .resource(&ctx)?
+            .query_stream(
+                statement.0.as_str(),
+                params.0.into_iter().map(|v| v).collect::<Vec<_>>().as_slice(),
+            );
+        match result {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                let error: crate::bindings::golem::rdbms::mysql::Error = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn execute(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        statement: crate::wrappers::JsString,
+        params: crate::wrappers::JsList<crate::bindings::golem::rdbms::mysql::DbValue>,
+    ) -> rquickjs::Result<crate::wrappers::BigIntWrapper<u64>> {
+        let result: Result<u64, crate::bindings::golem::rdbms::mysql::Error> = self
+            .resource(&ctx)?
+            .execute(
+                statement.0.as_str(),
+                params.0.into_iter().map(|v| v).collect::<Vec<_>>().as_slice(),
+            );
+        match result {
+            Ok(result) => Ok(crate::wrappers::BigIntWrapper(result)),
+            Err(error) => {
+                let error: crate::bindings::golem::rdbms::mysql::Error = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn begin_transaction(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+    ) -> rquickjs::Result<crate::bindings::golem::rdbms::mysql::DbTransaction> {
+        let result: Result<
+            crate::bindings::golem::rdbms::mysql::DbTransaction,
+            crate::bindings::golem::rdbms::mysql::Error,
+        > = self.resource(&ctx)?.begin_transaction();
+        match result {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                let error: crate::bindings::golem::rdbms::mysql::Error = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    #[qjs(rename = "__dispose")]
+    pub fn __dispose(&mut self) {
+        self.release();
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::mysql::DbConnection {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        DbConnection {
+            inner: Some(std::rc::Rc::new(self)),
+            borrow_epoch: None,
+        }
+            .into_js(ctx)
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::mysql::DbConnection {
+    fn from_js(
+        ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let wrapper = DbConnection::from_js(ctx, value)?;
+        if wrapper.borrow_epoch.is_some() {
+            return Err(rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "DbConnection",
+                message: Some(
+                    "The ownership of a borrowed resource handle cannot be transferred"
+                        .to_string(),
+                ),
+            });
+        }
+        let inner = wrapper
+            .checked_inner()
+            .map_err(|message| rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "DbConnection",
+                message: Some(message.to_string()),
+            })?;
+        unsafe {
+            Ok(
+                crate::bindings::golem::rdbms::mysql::DbConnection::from_handle(
+                    inner.take_handle(),
+                ),
+            )
+        }
+    }
+}
+pub struct BorrowDbConnectionWrapper(
+    pub crate::bindings::golem::rdbms::mysql::DbConnection,
+);
+impl<'js> rquickjs::FromJs<'js> for BorrowDbConnectionWrapper {
+    fn from_js(
+        ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let wrapper = DbConnection::from_js(ctx, value)?;
+        let inner = wrapper
+            .checked_inner()
+            .map_err(|message| rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "DbConnection",
+                message: Some(message.to_string()),
+            })?;
+        unsafe {
+            Ok(
+                BorrowDbConnectionWrapper(
+                    crate::bindings::golem::rdbms::mysql::DbConnection::from_handle(
+                        inner.handle(),
+                    ),
+                ),
+            )
+        }
+    }
+}
+impl BorrowDbConnectionWrapper {
+    /// Borrows a handle passed to an exported function, without taking its ownership
+    pub fn from_ref(
+        resource: &crate::bindings::golem::rdbms::mysql::DbConnection,
+    ) -> Self {
+        unsafe {
+            BorrowDbConnectionWrapper(
+                crate::bindings::golem::rdbms::mysql::DbConnection::from_handle(
+                    resource.handle(),
+                ),
+            )
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for BorrowDbConnectionWrapper {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let resource = unsafe {
+            crate::bindings::golem::rdbms::mysql::DbConnection::from_handle(
+                self.0.take_handle(),
+            )
+        };
+        DbConnection {
+            inner: Some(std::rc::Rc::new(resource)),
+            borrow_epoch: Some(crate::internal::current_borrow_epoch()),
+        }
+            .into_js(ctx)
+    }
+}
+impl Drop for BorrowDbConnectionWrapper {
+    fn drop(&mut self) {
+        let _ = self.0.take_handle();
+    }
+}
+#[derive(Clone, JsLifetime, Trace)]
+pub struct DbTransaction {
+    #[qjs(skip_trace = true)]
+    inner: Option<std::rc::Rc<crate::bindings::golem::rdbms::mysql::DbTransaction>>,
+    /// The export call epoch if the handle is only borrowed from the host
+    #[qjs(skip_trace = true)]
+    borrow_epoch: Option<usize>,
+}
+mod __impl_class_db_transaction_ {
+    pub use super::*;
+    use rquickjs::{Atom, Symbol, Value};
+    impl<'js> rquickjs::class::JsClass<'js> for DbTransaction {
+        const NAME: &'static str = "DbTransaction";
+        type Mutable = rquickjs::class::Writable;
+        fn prototype(
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<Option<rquickjs::Object<'js>>> {
+            use rquickjs::class::impl_::MethodImplementor;
+            let proto = rquickjs::Object::new(ctx.clone())?;
+            let implementor = rquickjs::class::impl_::MethodImpl::<Self>::new();
+            (&implementor).implement(&proto)?;
+            let dispose_symbol: Symbol = ctx
+                .globals()
+                .get(crate::internal::DISPOSE_SYMBOL)?;
+            let dispose_fn: Value = proto.get("__dispose")?;
+            proto.set(dispose_symbol, dispose_fn)?;
+            Ok(Some(proto))
+        }
+        fn constructor(
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<Option<rquickjs::function::Constructor<'js>>> {
+            use rquickjs::class::impl_::ConstructorCreator;
+            let implementor = rquickjs::class::impl_::ConstructorCreate::<Self>::new();
+            (&implementor).create_constructor(ctx)
+        }
+    }
+    impl<'js> rquickjs::IntoJs<'js> for DbTransaction {
+        fn into_js(
+            self,
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<rquickjs::Value<'js>> {
+            let cls = rquickjs::class::Class::<Self>::instance(ctx.clone(), self)?;
+            rquickjs::IntoJs::into_js(cls, ctx)
+        }
+    }
+    impl<'js> rquickjs::FromJs<'js> for DbTransaction
+    where
+        for<'a> rquickjs::class::impl_::CloneWrapper<
+            'a,
+            Self,
+        >: rquickjs::class::impl_::CloneTrait<Self>,
+    {
+        fn from_js(
+            ctx: &rquickjs::Ctx<'js>,
+            value: rquickjs::Value<'js>,
+        ) -> rquickjs::Result<Self> {
+            use rquickjs::class::impl_::CloneTrait;
+            let value = rquickjs::class::Class::<Self>::from_js(ctx, value)?;
+            let borrow = value.try_borrow()?;
+            Ok(rquickjs::class::impl_::CloneWrapper(&*borrow).wrap_clone())
+        }
+    }
+}
+impl DbTransaction {
+    /// Gets the wrapped resource, failing if it has been disposed or if it is a
+    /// borrowed handle and the call it was passed to has already returned
+    fn checked_inner(
+        &self,
+    ) -> Result<
+        &std::rc::Rc<crate::bindings::golem::rdbms::mysql::DbTransaction>,
+        &'static str,
+    > {
+        if self
+            .borrow_epoch
+            .is_some_and(|epoch| !crate::internal::is_borrow_active(epoch))
+        {
+            return Err(
+                "Borrowed resource handle cannot be used after the call it was passed to has returned",
+            );
+        }
+        self.inner.as_ref().ok_or("Resource has already been disposed")
+    }
+    /// Gets the wrapped resource for calling one of its methods, throwing a
+    /// `ReferenceError` if it cannot be used
+    fn resource(
+        &self,
+        ctx: &rquickjs::Ctx<'_>,
+    ) -> rquickjs::Result<&crate::bindings::golem::rdbms::mysql::DbTransaction> {
+        self.checked_inner()
+            .map(|inner| inner.deref())
+            .map_err(|message| rquickjs::Exception::throw_reference(ctx, message))
+    }
+    /// Releases the wrapped resource. Borrowed handles belong to the host, so they
+    /// are never dropped, only forgotten.
+    fn release(&mut self) {
+        if let Some(inner) = self.inner.take() {
+            if self.borrow_epoch.is_some() {
+                if let Ok(inner) = std::rc::Rc::try_unwrap(inner) {
+                    let _ = inner.take_handle();
+                }
+            }
+        }
+    }
+}
+impl Drop for DbTransaction {
+    fn drop(&mut self) {
+        self.release();
+    }
+}
+#[rquickjs::methods(rename_all = "camelCase")]
+impl DbTransaction {
+    #[qjs(constructor)]
+    pub fn new() -> Self {
+        Self {
+            inner: None,
+            borrow_epoch: None,
+        }
+    }
+    pub fn query(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        statement: crate::wrappers::JsString,
+        params: crate::wrappers::JsList<crate::bindings::golem::rdbms::mysql::DbValue>,
+    ) -> rquickjs::Result<crate::bindings::golem::rdbms::mysql::DbResult> {
+        let result: Result<
+            crate::bindings::golem::rdbms::mysql::DbResult,
+            crate::bindings::golem::rdbms::mysql::Error,
+        > = self
+            .resource(&ctx)?
+            .query(
+                statement.0.as_str(),
+                params.0.into_iter().map(|v| v).collect::<Vec<_>>().as_slice(),
+            );
+        match result {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                let error: crate::bindings::golem::rdbms::mysql::Error = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn query_stream(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        statement: crate::wrappers::JsString,
+        params: crate::wrappers::JsList<crate::bindings::golem::rdbms::mysql::DbValue>,
+    ) -> rquickjs::Result<crate::bindings::golem::rdbms::mysql::DbResultStream> {
+        let result: Result<
+            crate::bindings::golem::rdbms::mysql::DbResultStream,
+            crate::bindings::golem::rdbms::mysql::Error,
+        > = self
+            .resource(&ctx)?
+            .query_stream(
+                statement.0.as_str(),
+                params.0.into_iter().map(|v| v).collect::<Vec<_>>().as_slice(),
+            );
+        match result {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                let error: crate::bindings::golem::rdbms::mysql::Error = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn execute(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        statement: crate::wrappers::JsString,
+        params: crate::wrappers::JsList<crate::bindings::golem::rdbms::mysql::DbValue>,
+    ) -> rquickjs::Result<crate::wrappers::BigIntWrapper<u64>> {
+        let result: Result<u64, crate::bindings::golem::rdbms::mysql::Error> = self
+            .resource(&ctx)?
+            .execute(
+                statement.0.as_str(),
+                params.0.into_iter().map(|v| v).collect::<Vec<_>>().as_slice(),
+            );
+        match result {
+            Ok(result) => Ok(crate::wrappers::BigIntWrapper(result)),
+            Err(error) => {
+                let error: crate::bindings::golem::rdbms::mysql::Error = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn commit(&self, ctx: rquickjs::Ctx<'_>) -> rquickjs::Result<()> {
+        let result: Result<(), crate::bindings::golem::rdbms::mysql::Error> = self
+            .resource(&ctx)?
+            .commit();
+        match result {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                let error: crate::bindings::golem::rdbms::mysql::Error = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn rollback(&self, ctx: rquickjs::Ctx<'_>) -> rquickjs::Result<()> {
+        let result: Result<(), crate::bindings::golem::rdbms::mysql::Error> = self
+            .resource(&ctx)?
+            .rollback();
+        match result {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                let error: crate::bindings::golem::rdbms::mysql::Error = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    #[qjs(rename = "__dispose")]
+    pub fn __dispose(&mut self) {
+        self.release();
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::mysql::DbTransaction {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        DbTransaction {
+            inner: Some(std::rc::Rc::new(self)),
+            borrow_epoch: None,
+        }
+            .into_js(ctx)
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::mysql::DbTransaction {
+    fn from_js(
+        ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let wrapper = DbTransaction::from_js(ctx, value)?;
+        if wrapper.borrow_epoch.is_some() {
+            return Err(rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "DbTransaction",
+                message: Some(
+                    "The ownership of a borrowed resource handle cannot be transferred"
+                        .to_string(),
+                ),
+            });
+        }
+        let inner = wrapper
+            .checked_inner()
+            .map_err(|message| rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "DbTransaction",
+                message: Some(message.to_string()),
+            })?;
+        unsafe {
+            Ok(
+                crate::bindings::golem::rdbms::mysql::DbTransaction::from_handle(
+                    inner.take_handle(),
+                ),
+            )
+        }
+    }
+}
+pub struct BorrowDbTransactionWrapper(
+    pub crate::bindings::golem::rdbms::mysql::DbTransaction,
+);
+impl<'js> rquickjs::FromJs<'js> for BorrowDbTransactionWrapper {
+    fn from_js(
+        ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let wrapper = DbTransaction::from_js(ctx, value)?;
+        let inner = wrapper
+            .checked_inner()
+            .map_err(|message| rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "DbTransaction",
+                message: Some(message.to_string()),
+            })?;
+        unsafe {
+            Ok(
+                BorrowDbTransactionWrapper(
+                    crate::bindings::golem::rdbms::mysql::DbTransaction::from_handle(
+                        inner.handle(),
+                    ),
+                ),
+            )
+        }
+    }
+}
+impl BorrowDbTransactionWrapper {
+    /// Borrows a handle passed to an exported function, without taking its ownership
+    pub fn from_ref(
+        resource: &crate::bindings::golem::rdbms::mysql::DbTransaction,
+    ) -> Self {
+        unsafe {
+            BorrowDbTransactionWrapper(
+                crate::bindings::golem::rdbms::mysql::DbTransaction::from_handle(
+                    resource.handle(),
+                ),
+            )
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for BorrowDbTransactionWrapper {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let resource = unsafe {
+            crate::bindings::golem::rdbms::mysql::DbTransaction::from_handle(
+                self.0.take_handle(),
+            )
+        };
+        DbTransaction {
+            inner: Some(std::rc::Rc::new(resource)),
+            borrow_epoch: Some(crate::internal::current_borrow_epoch()),
+        }
+            .into_js(ctx)
+    }
+}
+impl Drop for BorrowDbTransactionWrapper {
+    fn drop(&mut self) {
+        let _ = self.0.take_handle();
+    }
+}
+pub struct JsMysqlModule;
+impl rquickjs::module::ModuleDef for JsMysqlModule {
+    fn declare(decl: &rquickjs::module::Declarations) -> rquickjs::Result<()> {
+        decl.declare("DbResultStream")?;
+        decl.declare("DbConnection")?;
+        decl.declare("DbTransaction")?;
+        Ok(())
+    }
+    fn evaluate<'js>(
+        ctx: &rquickjs::Ctx<'js>,
+        exports: &rquickjs::module::Exports<'js>,
+    ) -> rquickjs::Result<()> {
+        exports.export("DbResultStream", DbResultStream::constructor(ctx)?)?;
+        exports.export("DbConnection", DbConnection::constructor(ctx)?)?;
+        exports.export("DbTransaction", DbTransaction::constructor(ctx)?)?;
+        Ok(())
+    }
+}
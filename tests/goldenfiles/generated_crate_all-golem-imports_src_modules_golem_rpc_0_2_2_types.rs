@@ -0,0 +1,908 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:all-golem-imports/all-golem-imports
+// wit: sha256:3ec76077ee8750a4a3d97cf8f89273803d43fba31aa02651185015b5648e2fc7
+// js all-golem-imports: sha256:1aa6d68c08d678e85c1f334bb55fc7062ce81fa0f90be1bc0d6a3965bfccc568
+
+use rquickjs::JsLifetime;
+use rquickjs::class::{JsClass, Trace};
+use std::ops::Deref;
+#[rquickjs::function]
+fn parse_uuid(
+    ctx: rquickjs::Ctx<'_>,
+    uuid: crate::wrappers::JsString,
+) -> rquickjs::Result<crate::bindings::golem::rpc::types::Uuid> {
+    let result: Result<crate::bindings::golem::rpc::types::Uuid, String> = crate::bindings::golem::rpc::types::parse_uuid(
+        uuid.0.as_str(),
+    );
+    match result {
+        Ok(result) => Ok(result),
+        Err(error) => {
+            let error: crate::wrappers::JsString = crate::wrappers::JsString(error);
+            Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+        }
+    }
+}
+#[rquickjs::function]
+fn uuid_to_string(
+    uuid: crate::bindings::golem::rpc::types::Uuid,
+) -> crate::wrappers::JsString {
+    let result: String = crate::bindings::golem::rpc::types::uuid_to_string(uuid);
+    crate::wrappers::JsString(result)
+}
+#[rquickjs::function]
+fn extract_value(
+    vnt: crate::bindings::golem::rpc::types::ValueAndType,
+) -> crate::bindings::golem::rpc::types::WitValue {
+    let result: crate::bindings::golem::rpc::types::WitValue = crate::bindings::golem::rpc::types::extract_value(
+        &vnt,
+    );
+    result
+}
+#[rquickjs::function]
+fn extract_type(
+    vnt: crate::bindings::golem::rpc::types::ValueAndType,
+) -> crate::bindings::golem::rpc::types::WitType {
+    let result: crate::bindings::golem::rpc::types::WitType = crate::bindings::golem::rpc::types::extract_type(
+        &vnt,
+    );
+    result
+}
+#[derive(Clone, JsLifetime, Trace)]
+pub struct WasmRpc {
+    #[qjs(skip_trace = true)]
+    inner: Option<std::rc::Rc<crate::bindings::golem::rpc::types::WasmRpc>>,
+    /// The export call epoch if the handle is only borrowed from the host
+    #[qjs(skip_trace = true)]
+    borrow_epoch: Option<usize>,
+}
+mod __impl_class_wasm_rpc_ {
+    pub use super::*;
+    use rquickjs::{Atom, Symbol, Value};
+    impl<'js> rquickjs::class::JsClass<'js> for WasmRpc {
+        const NAME: &'static str = "WasmRpc";
+        type Mutable = rquickjs::class::Writable;
+        fn prototype(
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<Option<rquickjs::Object<'js>>> {
+            use rquickjs::class::impl_::MethodImplementor;
+            let proto = rquickjs::Object::new(ctx.clone())?;
+            let implementor = rquickjs::class::impl_::MethodImpl::<Self>::new();
+            (&implementor).implement(&proto)?;
+            let dispose_symbol: Symbol = ctx
+                .globals()
+                .get(crate::internal::DISPOSE_SYMBOL)?;
+            let dispose_fn: Value = proto.get("__dispose")?;
+            proto.set(dispose_symbol, dispose_fn)?;
+            Ok(Some(proto))
+        }
+        fn constructor(
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<Option<rquickjs::function::Constructor<'js>>> {
+            use rquickjs::class::impl_::ConstructorCreator;
+            let implementor = rquickjs::class::impl_::ConstructorCreate::<Self>::new();
+            (&implementor).create_constructor(ctx)
+        }
+    }
+    impl<'js> rquickjs::IntoJs<'js> for WasmRpc {
+        fn into_js(
+            self,
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<rquickjs::Value<'js>> {
+            let cls = rquickjs::class::Class::<Self>::instance(ctx.clone(), self)?;
+            rquickjs::IntoJs::into_js(cls, ctx)
+        }
+    }
+    impl<'js> rquickjs::FromJs<'js> for WasmRpc
+    where
+        for<'a> rquickjs::class::impl_::CloneWrapper<
+            'a,
+            Self,
+        >: rquickjs::class::impl_::CloneTrait<Self>,
+    {
+        fn from_js(
+            ctx: &rquickjs::Ctx<'js>,
+            value: rquickjs::Value<'js>,
+        ) -> rquickjs::Result<Self> {
+            use rquickjs::class::impl_::CloneTrait;
+            let value = rquickjs::class::Class::<Self>::from_js(ctx, value)?;
+            let borrow = value.try_borrow()?;
+            Ok(rquickjs::class::impl_::CloneWrapper(&*borrow).wrap_clone())
+        }
+    }
+}
+impl WasmRpc {
+    /// Gets the wrapped resource, failing if it has been disposed or if it is a
+    /// borrowed handle and the call it was passed to has already returned
+    fn checked_inner(
+        &self,
+    ) -> Result<
+        &std::rc::Rc<crate::bindings::golem::rpc::types::WasmRpc>,
+        &'static str,
+    > {
+        if self
+            .borrow_epoch
+            .is_some_and(|epoch| !crate::internal::is_borrow_active(epoch))
+        {
+            return Err(
+                "Borrowed resource handle cannot be used after the call it was passed to has returned",
+            );
+        }
+        self.inner.as_ref().ok_or("Resource has already been disposed")
+    }
+    /// Gets the wrapped resource for calling one of its methods, throwing a
+    /// `ReferenceError` if it cannot be used
+    fn resource(
+        &self,
+        ctx: &rquickjs::Ctx<'_>,
+    ) -> rquickjs::Result<&crate::bindings::golem::rpc::types::WasmRpc> {
+        self.checked_inner()
+            .map(|inner| inner.deref())
+            .map_err(|message| rquickjs::Exception::throw_reference(ctx, message))
+    }
+    /// Releases the wrapped resource. Borrowed handles belong to the host, so they
+    /// are never dropped, only forgotten.
+    fn release(&mut self) {
+        if let Some(inner) = self.inner.take() {
+            if self.borrow_epoch.is_some() {
+                if let Ok(inner) = std::rc::Rc::try_unwrap(inner) {
+                    let _ = inner.take_handle();
+                }
+            }
+        }
+    }
+}
+impl Drop for WasmRpc {
+    fn drop(&mut self) {
+        self.release();
+    }
+}
+#[rquickjs::methods(rename_all = "camelCase")]
+impl WasmRpc {
+    #[qjs(constructor)]
+    pub fn new(worker_id: crate::bindings::golem::rpc::types::WorkerId) -> Self {
+        Self {
+            inner: Some(
+                std::rc::Rc::new(
+                    crate::bindings::golem::rpc::types::WasmRpc::new(&worker_id),
+                ),
+            ),
+            borrow_epoch: None,
+        }
+    }
+    pub fn invoke_and_await(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        function_name: crate::wrappers::JsString,
+        function_params: crate::wrappers::JsList<
+            crate::bindings::golem::rpc::types::WitValue,
+        >,
+    ) -> rquickjs::Result<crate::bindings::golem::rpc::types::WitValue> {
+        let result: Result<
+            crate::bindings::golem::rpc::types::WitValue,
+            crate::bindings::golem::rpc::types::RpcError,
+        > = self
+            .resource(&ctx)?
+            .invoke_and_await(
+                function_name.0.as_str(),
+                function_params.0.into_iter().map(|v| v).collect::<Vec<_>>().as_slice(),
+            );
+        match result {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                let error: crate::bindings::golem::rpc::types::RpcError = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn invoke(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        function_name: crate::wrappers::JsString,
+        function_params: crate::wrappers::JsList<
+            crate::bindings::golem::rpc::types::WitValue,
+        >,
+    ) -> rquickjs::Result<()> {
+        let result: Result<(), crate::bindings::golem::rpc::types::RpcError> = self
+            .resource(&ctx)?
+            .invoke(
+                function_name.0.as_str(),
+                function_params.0.into_iter().map(|v| v).collect::<Vec<_>>().as_slice(),
+            );
+        match result {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                let error: crate::bindings::golem::rpc::types::RpcError = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn async_invoke_and_await(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        function_name: crate::wrappers::JsString,
+        function_params: crate::wrappers::JsList<
+            crate::bindings::golem::rpc::types::WitValue,
+        >,
+    ) -> rquickjs::Result<crate::bindings::golem::rpc::types::FutureInvokeResult> {
+        let result: crate::bindings::golem::rpc::types::FutureInvokeResult = self
+            .resource(&ctx)?
+            .async_invoke_and_await(
+                function_name.0.as_str(),
+                function_params.0.into_iter().map(|v| v).collect::<Vec<_>>().as_slice(),
+            );
+        Ok(result)
+    }
+    pub fn schedule_invocation(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        scheduled_time: crate::bindings::wasi::clocks::wall_clock::Datetime,
+        function_name: crate::wrappers::JsString,
+        function_params: crate::wrappers::JsList<
+            crate::bindings::golem::rpc::types::WitValue,
+        >,
+    ) -> rquickjs::Result<()> {
+        let result: () = self
+            .resource(&ctx)?
+            .schedule_invocation(
+                scheduled_time,
+                function_name.0.as_str(),
+                function_params.0.into_iter().map(|v| v).collect::<Vec<_>>().as_slice(),
+            );
+        Ok(result)
+    }
+    pub fn schedule_cancelable_invocation(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        scheduled_time: crate::bindings::wasi::clocks::wall_clock::Datetime,
+        function_name: crate::wrappers::JsString,
+        function_params: crate::wrappers::JsList<
+            crate::bindings::golem::rpc::types::WitValue,
+        >,
+    ) -> rquickjs::Result<crate::bindings::golem::rpc::types::CancellationToken> {
+        let result: crate::bindings::golem::rpc::types::CancellationToken = self
+            .resource(&ctx)?
+            .schedule_cancelable_invocation(
+                scheduled_time,
+                function_name.0.as_str(),
+                function_params.0.into_iter().map(|v| v).collect::<Vec<_>>().as_slice(),
+            );
+        Ok(result)
+    }
+    #[qjs(rename = "__dispose")]
+    pub fn __dispose(&mut self) {
+        self.release();
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rpc::types::WasmRpc {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        WasmRpc {
+            inner: Some(std::rc::Rc::new(self)),
+            borrow_epoch: None,
+        }
+            .into_js(ctx)
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rpc::types::WasmRpc {
+    fn from_js(
+        ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let wrapper = WasmRpc::from_js(ctx, value)?;
+        if wrapper.borrow_epoch.is_some() {
+            return Err(rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "WasmRpc",
+                message: Some(
+                    "The ownership of a borrowed resource handle cannot be transferred"
+                        .to_string(),
+                ),
+            });
+        }
+        let inner = wrapper
+            .checked_inner()
+            .map_err(|message| rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "WasmRpc",
+                message: Some(message.to_string()),
+            })?;
+        unsafe {
+            Ok(
+                crate::bindings::golem::rpc::types::WasmRpc::from_handle(
+                    inner.take_handle(),
+                ),
+            )
+        }
+    }
+}
+pub struct BorrowWasmRpcWrapper(pub crate::bindings::golem::rpc::types::WasmRpc);
+impl<'js> rquickjs::FromJs<'js> for BorrowWasmRpcWrapper {
+    fn from_js(
+        ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let wrapper = WasmRpc::from_js(ctx, value)?;
+        let inner = wrapper
+            .checked_inner()
+            .map_err(|message| rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "WasmRpc",
+                message: Some(message.to_string()),
+            })?;
+        unsafe {
+            Ok(
+                BorrowWasmRpcWrapper(
+                    crate::bindings::golem::rpc::types::WasmRpc::from_handle(
+                        inner.handle(),
+                    ),
+                ),
+            )
+        }
+    }
+}
+impl BorrowWasmRpcWrapper {
+    /// Borrows a handle passed to an exported function, without taking its ownership
+    pub fn from_ref(resource: &crate::bindings::golem::rpc::types::WasmRpc) -> Self {
+        unsafe {
+            BorrowWasmRpcWrapper(
+                crate::bindings::golem::rpc::types::WasmRpc::from_handle(
+                    resource.handle(),
+                ),
+            )
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for BorrowWasmRpcWrapper {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let resource = unsafe {
+            crate::bindings::golem::rpc::types::WasmRpc::from_handle(
+                self.0.take_handle(),
+            )
+        };
+        WasmRpc {
+            inner: Some(std::rc::Rc::new(resource)),
+            borrow_epoch: Some(crate::internal::current_borrow_epoch()),
+        }
+            .into_js(ctx)
+    }
+}
+impl Drop for BorrowWasmRpcWrapper {
+    fn drop(&mut self) {
+        let _ = self.0.take_handle();
+    }
+}
+#[derive(Clone, JsLifetime, Trace)]
+pub struct FutureInvokeResult {
+    #[qjs(skip_trace = true)]
+    inner: Option<std::rc::Rc<crate::bindings::golem::rpc::types::FutureInvokeResult>>,
+    /// The export call epoch if the handle is only borrowed from the host
+    #[qjs(skip_trace = true)]
+    borrow_epoch: Option<usize>,
+}
+mod __impl_class_future_invoke_result_ {
+    pub use super::*;
+    use rquickjs::{Atom, Symbol, Value};
+    impl<'js> rquickjs::class::JsClass<'js> for FutureInvokeResult {
+        const NAME: &'static str = "FutureInvokeResult";
+        type Mutable = rquickjs::class::Writable;
+        fn prototype(
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<Option<rquickjs::Object<'js>>> {
+            use rquickjs::class::impl_::MethodImplementor;
+            let proto = rquickjs::Object::new(ctx.clone())?;
+            let implementor = rquickjs::class::impl_::MethodImpl::<Self>::new();
+            (&implementor).implement(&proto)?;
+            let dispose_symbol: Symbol = ctx
+                .globals()
+                .get(crate::internal::DISPOSE_SYMBOL)?;
+            let dispose_fn: Value = proto.get("__dispose")?;
+            proto.set(dispose_symbol, dispose_fn)?;
+            Ok(Some(proto))
+        }
+        fn constructor(
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<Option<rquickjs::function::Constructor<'js>>> {
+            use rquickjs::class::impl_::ConstructorCreator;
+            let implementor = rquickjs::class::impl_::ConstructorCreate::<Self>::new();
+            (&implementor).create_constructor(ctx)
+        }
+    }
+    impl<'js> rquickjs::IntoJs<'js> for FutureInvokeResult {
+        fn into_js(
+            self,
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<rquickjs::Value<'js>> {
+            let cls = rquickjs::class::Class::<Self>::instance(ctx.clone(), self)?;
+            rquickjs::IntoJs::into_js(cls, ctx)
+        }
+    }
+    impl<'js> rquickjs::FromJs<'js> for FutureInvokeResult
+    where
+        for<'a> rquickjs::class::impl_::CloneWrapper<
+            'a,
+            Self,
+        >: rquickjs::class::impl_::CloneTrait<Self>,
+    {
+        fn from_js(
+            ctx: &rquickjs::Ctx<'js>,
+            value: rquickjs::Value<'js>,
+        ) -> rquickjs::Result<Self> {
+            use rquickjs::class::impl_::CloneTrait;
+            let value = rquickjs::class::Class::<Self>::from_js(ctx, value)?;
+            let borrow = value.try_borrow()?;
+            Ok(rquickjs::class::impl_::CloneWrapper(&*borrow).wrap_clone())
+        }
+    }
+}
+impl FutureInvokeResult {
+    /// Gets the wrapped resource, failing if it has been disposed or if it is a
+    /// borrowed handle and the call it was passed to has already returned
+    fn checked_inner(
+        &self,
+    ) -> Result<
+        &std::rc::Rc<crate::bindings::golem::rpc::types::FutureInvokeResult>,
+        &'static str,
+    > {
+        if self
+            .borrow_epoch
+            .is_some_and(|epoch| !crate::internal::is_borrow_active(epoch))
+        {
+            return Err(
+                "Borrowed resource handle cannot be used after the call it was passed to has returned",
+            );
+        }
+        self.inner.as_ref().ok_or("Resource has already been disposed")
+    }
+    /// Gets the wrapped resource for calling one of its methods, throwing a
+    /// `ReferenceError` if it cannot be used
+    fn resource(
+        &self,
+        ctx: &rquickjs::Ctx<'_>,
+    ) -> rquickjs::Result<&crate::bindings::golem::rpc::types::FutureInvokeResult> {
+        self.checked_inner()
+            .map(|inner| inner.deref())
+            .map_err(|message| rquickjs::Exception::throw_reference(ctx, message))
+    }
+    /// Releases the wrapped resource. Borrowed handles belong to the host, so they
+    /// are never dropped, only forgotten.
+    fn release(&mut self) {
+        if let Some(inner) = self.inner.take() {
+            if self.borrow_epoch.is_some() {
+                if let Ok(inner) = std::rc::Rc::try_unwrap(inner) {
+                    let _ = inner.take_handle();
+                }
+            }
+        }
+    }
+}
+impl Drop for FutureInvokeResult {
+    fn drop(&mut self) {
+        self.release();
+    }
+}
+#[rquickjs::methods(rename_all = "camelCase")]
+impl FutureInvokeResult {
+    #[qjs(constructor)]
+    pub fn new() -> Self {
+        Self {
+            inner: None,
+            borrow_epoch: None,
+        }
+    }
+    pub fn subscribe(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+    ) -> rquickjs::Result<crate::bindings::golem::rpc::types::Pollable> {
+        let result: crate::bindings::golem::rpc::types::Pollable = self
+            .resource(&ctx)?
+            .subscribe();
+        Ok(result)
+    }
+    pub fn get(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+    ) -> rquickjs::Result<
+        Option<
+            crate::wrappers::JsResult<
+                crate::bindings::golem::rpc::types::WitValue,
+                crate::bindings::golem::rpc::types::RpcError,
+            >,
+        >,
+    > {
+        let result: Option<
+            Result<
+                crate::bindings::golem::rpc::types::WitValue,
+                crate::bindings::golem::rpc::types::RpcError,
+            >,
+        > = self.resource(&ctx)?.get();
+        Ok(
+            result
+                .map(|v| crate::wrappers::JsResult(
+                    match v {
+                        Ok(v) => Ok(v),
+                        Err(v) => Err(v),
+                    },
+                )),
+        )
+    }
+    #[qjs(rename = "__dispose")]
+    pub fn __dispose(&mut self) {
+        self.release();
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::rpc::types::FutureInvokeResult {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        FutureInvokeResult {
+            inner: Some(std::rc::Rc::new(self)),
+            borrow_epoch: None,
+        }
+            .into_js(ctx)
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::rpc::types::FutureInvokeResult {
+    fn from_js(
+        ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let wrapper = FutureInvokeResult::from_js(ctx, value)?;
+        if wrapper.borrow_epoch.is_some() {
+            return Err(rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "FutureInvokeResult",
+                message: Some(
+                    "The ownership of a borrowed resource handle cannot be transferred"
+                        .to_string(),
+                ),
+            });
+        }
+        let inner = wrapper
+            .checked_inner()
+            .map_err(|message| rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "FutureInvokeResult",
+                message: Some(message.to_string()),
+            })?;
+        unsafe {
+            Ok(
+                crate::bindings::golem::rpc::types::FutureInvokeResult::from_handle(
+                    inner.take_handle(),
+                ),
+            )
+        }
+    }
+}
+pub struct BorrowFutureInvokeResultWrapper(
+    pub crate::bindings::golem::rpc::types::FutureInvokeResult,
+);
+impl<'js> rquickjs::FromJs<'js> for BorrowFutureInvokeResultWrapper {
+    fn from_js(
+        ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let wrapper = FutureInvokeResult::from_js(ctx, value)?;
+        let inner = wrapper
+            .checked_inner()
+            .map_err(|message| rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "FutureInvokeResult",
+                message: Some(message.to_string()),
+            })?;
+        unsafe {
+            Ok(
+                BorrowFutureInvokeResultWrapper(
+                    crate::bindings::golem::rpc::types::FutureInvokeResult::from_handle(
+                        inner.handle(),
+                    ),
+                ),
+            )
+        }
+    }
+}
+impl BorrowFutureInvokeResultWrapper {
+    /// Borrows a handle passed to an exported function, without taking its ownership
+    pub fn from_ref(
+        resource: &crate::bindings::golem::rpc::types::FutureInvokeResult,
+    ) -> Self {
+        unsafe {
+            BorrowFutureInvokeResultWrapper(
+                crate::bindings::golem::rpc::types::FutureInvokeResult::from_handle(
+                    resource.handle(),
+                ),
+            )
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for BorrowFutureInvokeResultWrapper {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let resource = unsafe {
+            crate::bindings::golem::rpc::types::FutureInvokeResult::from_handle(
+                self.0.take_handle(),
+            )
+        };
+        FutureInvokeResult {
+            inner: Some(std::rc::Rc::new(resource)),
+            borrow_epoch: Some(crate::internal::current_borrow_epoch()),
+        }
+            .into_js(ctx)
+    }
+}
+impl Drop for BorrowFutureInvokeResultWrapper {
+    fn drop(&mut self) {
+        let _ = self.0.take_handle();
+    }
+}
+#[derive(Clone, JsLifetime, Trace)]
+pub struct CancellationToken {
+    #[qjs(skip_trace = true)]
+    inner: Option<std::rc::Rc<crate::bindings::golem::rpc::types::CancellationToken>>,
+    /// The export call epoch if the handle is only borrowed from the host
+    #[qjs(skip_trace = true)]
+    borrow_epoch: Option<usize>,
+}
+mod __impl_class_cancellation_token_ {
+    pub use super::*;
+    use rquickjs::{Atom, Symbol, Value};
+    impl<'js> rquickjs::class::JsClass<'js> for CancellationToken {
+        const NAME: &'static str = "CancellationToken";
+        type Mutable = rquickjs::class::Writable;
+        fn prototype(
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<Option<rquickjs::Object<'js>>> {
+            use rquickjs::class::impl_::MethodImplementor;
+            let proto = rquickjs::Object::new(ctx.clone())?;
+            let implementor = rquickjs::class::impl_::MethodImpl::<Self>::new();
+            (&implementor).implement(&proto)?;
+            let dispose_symbol: Symbol = ctx
+                .globals()
+                .get(crate::internal::DISPOSE_SYMBOL)?;
+            let dispose_fn: Value = proto.get("__dispose")?;
+            proto.set(dispose_symbol, dispose_fn)?;
+            Ok(Some(proto))
+        }
+        fn constructor(
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<Option<rquickjs::function::Constructor<'js>>> {
+            use rquickjs::class::impl_::ConstructorCreator;
+            let implementor = rquickjs::class::impl_::ConstructorCreate::<Self>::new();
+            (&implementor).create_constructor(ctx)
+        }
+    }
+    impl<'js> rquickjs::IntoJs<'js> for CancellationToken {
+        fn into_js(
+            self,
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<rquickjs::Value<'js>> {
+            let cls = rquickjs::class::Class::<Self>::instance(ctx.clone(), self)?;
+            rquickjs::IntoJs::into_js(cls, ctx)
+        }
+    }
+    impl<'js> rquickjs::FromJs<'js> for CancellationToken
+    where
+        for<'a> rquickjs::class::impl_::CloneWrapper<
+            'a,
+            Self,
+        >: rquickjs::class::impl_::CloneTrait<Self>,
+    {
+        fn from_js(
+            ctx: &rquickjs::Ctx<'js>,
+            value: rquickjs::Value<'js>,
+        ) -> rquickjs::Result<Self> {
+            use rquickjs::class::impl_::CloneTrait;
+            let value = rquickjs::class::Class::<Self>::from_js(ctx, value)?;
+            let borrow = value.try_borrow()?;
+            Ok(rquickjs::class::impl_::CloneWrapper(&*borrow).wrap_clone())
+        }
+    }
+}
+impl CancellationToken {
+    /// Gets the wrapped resource, failing if it has been disposed or if it is a
+    /// borrowed handle and the call it was passed to has already returned
+    fn checked_inner(
+        &self,
+    ) -> Result<
+        &std::rc::Rc<crate::bindings::golem::rpc::types::CancellationToken>,
+        &'static str,
+    > {
+        if self
+            .borrow_epoch
+            .is_some_and(|epoch| !crate::internal::is_borrow_active(epoch))
+        {
+            return Err(
+                "Borrowed resource handle cannot be used after the call it was passed to has returned",
+            );
+        }
+        self.inner.as_ref().ok_or("Resource has already been disposed")
+    }
+    /// Gets the wrapped resource for calling one of its methods, throwing a
+    /// `ReferenceError` if it cannot be used
+    fn resource(
+        &self,
+        ctx: &rquickjs::Ctx<'_>,
+    ) -> rquickjs::Result<&crate::bindings::golem::rpc::types::CancellationToken> {
+        self.checked_inner()
+            .map(|inner| inner.deref())
+            .map_err(|message| rquickjs::Exception::throw_reference(ctx, message))
+    }
+    /// Releases the wrapped resource. Borrowed handles belong to the host, so they
+    /// are never dropped, only forgotten.
+    fn release(&mut self) {
+        if let Some(inner) = self.inner.take() {
+            if self.borrow_epoch.is_some() {
+                if let Ok(inner) = std::rc::Rc::try_unwrap(inner) {
+                    let _ = inner.take_handle();
+                }
+            }
+        }
+    }
+}
+impl Drop for CancellationToken {
+    fn drop(&mut self) {
+        self.release();
+    }
+}
+#[rquickjs::methods(rename_all = "camelCase")]
+impl CancellationToken {
+    #[qjs(constructor)]
+    pub fn new() -> Self {
+        Self {
+            inner: None,
+            borrow_epoch: None,
+        }
+    }
+    pub fn cancel(&self, ctx: rquickjs::Ctx<'_>) -> rquickjs::Result<()> {
+        let result: () = self.resource(&ctx)?.cancel();
+        Ok(result)
+    }
+    #[qjs(rename = "__dispose")]
+    pub fn __dispose(&mut self) {
+        self.release();
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::rpc::types::CancellationToken {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        CancellationToken {
+            inner: Some(std::rc::Rc::new(self)),
+            borrow_epoch: None,
+        }
+            .into_js(ctx)
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::rpc::types::CancellationToken {
+    fn from_js(
+        ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let wrapper = CancellationToken::from_js(ctx, value)?;
+        if wrapper.borrow_epoch.is_some() {
+            return Err(rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "CancellationToken",
+                message: Some(
+                    "The ownership of a borrowed resource handle cannot be transferred"
+                        .to_string(),
+                ),
+            });
+        }
+        let inner = wrapper
+            .checked_inner()
+            .map_err(|message| rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "CancellationToken",
+                message: Some(message.to_string()),
+            })?;
+        unsafe {
+            Ok(
+                crate::bindings::golem::rpc::types::CancellationToken::from_handle(
+                    inner.take_handle(),
+                ),
+            )
+        }
+    }
+}
+pub struct BorrowCancellationTokenWrapper(
+    pub crate::bindings::golem::rpc::types::CancellationToken,
+);
+impl<'js> rquickjs::FromJs<'js> for BorrowCancellationTokenWrapper {
+    fn from_js(
+        ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let wrapper = CancellationToken::from_js(ctx, value)?;
+        let inner = wrapper
+            .checked_inner()
+            .map_err(|message| rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "CancellationToken",
+                message: Some(message.to_string()),
+            })?;
+        unsafe {
+            Ok(
+                BorrowCancellationTokenWrapper(
+                    crate::bindings::golem::rpc::types::CancellationToken::from_handle(
+                        inner.handle(),
+                    ),
+                ),
+            )
+        }
+    }
+}
+impl BorrowCancellationTokenWrapper {
+    /// Borrows a handle passed to an exported function, without taking its ownership
+    pub fn from_ref(
+        resource: &crate::bindings::golem::rpc::types::CancellationToken,
+    ) -> Self {
+        unsafe {
+            BorrowCancellationTokenWrapper(
+                crate::bindings::golem::rpc::types::CancellationToken::from_handle(
+                    resource.handle(),
+                ),
+            )
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for BorrowCancellationTokenWrapper {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let resource = unsafe {
+            crate::bindings::golem::rpc::types::CancellationToken::from_handle(
+                self.0.take_handle(),
+            )
+        };
+        CancellationToken {
+            inner: Some(std::rc::Rc::new(resource)),
+            borrow_epoch: Some(crate::internal::current_borrow_epoch()),
+        }
+            .into_js(ctx)
+    }
+}
+impl Drop for BorrowCancellationTokenWrapper {
+    fn drop(&mut self) {
+        let _ = self.0.take_handle();
+    }
+}
+pub struct JsTypesModule;
+impl rquickjs::module::ModuleDef for JsTypesModule {
+    fn declare(decl: &rquickjs::module::Declarations) -> rquickjs::Result<()> {
+        decl.declare("parseUuid")?;
+        decl.declare("uuidToString")?;
+        decl.declare("extractValue")?;
+        decl.declare("extractType")?;
+        decl.declare("WasmRpc")?;
+        decl.declare("FutureInvokeResult")?;
+        decl.declare("CancellationToken")?;
+        Ok(())
+    }
+    fn evaluate<'js>(
+        ctx: &rquickjs::Ctx<'js>,
+        exports: &rquickjs::module::Exports<'js>,
+    ) -> rquickjs::Result<()> {
+        exports.export("parseUuid", js_parse_uuid)?;
+        exports.export("uuidToString", js_uuid_to_string)?;
+        exports.export("extractValue", js_extract_value)?;
+        exports.export("extractType", js_extract_type)?;
+        exports.export("WasmRpc", WasmRpc::constructor(ctx)?)?;
+        exports.export("FutureInvokeResult", FutureInvokeResult::constructor(ctx)?)?;
+        exports.export("CancellationToken", CancellationToken::constructor(ctx)?)?;
+        Ok(())
+    }
+}
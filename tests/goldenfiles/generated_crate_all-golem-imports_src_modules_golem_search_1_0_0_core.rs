@@ -0,0 +1,523 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:all-golem-imports/all-golem-imports
+// wit: sha256:3ec76077ee8750a4a3d97cf8f89273803d43fba31aa02651185015b5648e2fc7
+// js all-golem-imports: sha256:1aa6d68c08d678e85c1f334bb55fc7062ce81fa0f90be1bc0d6a3965bfccc568
+
+use rquickjs::JsLifetime;
+use rquickjs::class::{JsClass, Trace};
+use std::ops::Deref;
+#[rquickjs::function]
+fn create_index(
+    ctx: rquickjs::Ctx<'_>,
+    name: crate::wrappers::JsString,
+    schema: Option<crate::bindings::golem::search::types::Schema>,
+) -> rquickjs::Result<()> {
+    let result: Result<(), crate::bindings::golem::search::core::SearchError> = crate::bindings::golem::search::core::create_index(
+        name.0.as_str(),
+        schema.map(|v| v).as_ref(),
+    );
+    match result {
+        Ok(result) => Ok(result),
+        Err(error) => {
+            let error: crate::bindings::golem::search::types::SearchError = error;
+            Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+        }
+    }
+}
+#[rquickjs::function]
+fn delete_index(
+    ctx: rquickjs::Ctx<'_>,
+    name: crate::wrappers::JsString,
+) -> rquickjs::Result<()> {
+    let result: Result<(), crate::bindings::golem::search::core::SearchError> = crate::bindings::golem::search::core::delete_index(
+        name.0.as_str(),
+    );
+    match result {
+        Ok(result) => Ok(result),
+        Err(error) => {
+            let error: crate::bindings::golem::search::types::SearchError = error;
+            Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+        }
+    }
+}
+#[rquickjs::function]
+fn list_indexes(
+    ctx: rquickjs::Ctx<'_>,
+) -> rquickjs::Result<crate::wrappers::JsList<crate::wrappers::JsString>> {
+    let result: Result<
+        Vec<crate::bindings::golem::search::core::IndexName>,
+        crate::bindings::golem::search::core::SearchError,
+    > = crate::bindings::golem::search::core::list_indexes();
+    match result {
+        Ok(result) => {
+            Ok(
+                crate::wrappers::JsList(
+                    result
+                        .into_iter()
+                        .map(|v| crate::wrappers::JsString(v))
+                        .collect::<Vec<_>>(),
+                ),
+            )
+        }
+        Err(error) => {
+            let error: crate::bindings::golem::search::types::SearchError = error;
+            Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+        }
+    }
+}
+#[rquickjs::function]
+fn upsert(
+    ctx: rquickjs::Ctx<'_>,
+    index: crate::wrappers::JsString,
+    doc: crate::bindings::golem::search::types::Doc,
+) -> rquickjs::Result<()> {
+    let result: Result<(), crate::bindings::golem::search::core::SearchError> = crate::bindings::golem::search::core::upsert(
+        index.0.as_str(),
+        &doc,
+    );
+    match result {
+        Ok(result) => Ok(result),
+        Err(error) => {
+            let error: crate::bindings::golem::search::types::SearchError = error;
+            Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+        }
+    }
+}
+#[rquickjs::function]
+fn upsert_many(
+    ctx: rquickjs::Ctx<'_>,
+    index: crate::wrappers::JsString,
+    docs: crate::wrappers::JsList<crate::bindings::golem::search::types::Doc>,
+) -> rquickjs::Result<()> {
+    let result: Result<(), crate::bindings::golem::search::core::SearchError> = crate::bindings::golem::search::core::upsert_many(
+        index.0.as_str(),
+        docs.0.into_iter().map(|v| v).collect::<Vec<_>>().as_slice(),
+    );
+    match result {
+        Ok(result) => Ok(result),
+        Err(error) => {
+            let error: crate::bindings::golem::search::types::SearchError = error;
+            Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+        }
+    }
+}
+#[rquickjs::function]
+fn delete(
+    ctx: rquickjs::Ctx<'_>,
+    index: crate::wrappers::JsString,
+    id: crate::wrappers::JsString,
+) -> rquickjs::Result<()> {
+    let result: Result<(), crate::bindings::golem::search::core::SearchError> = crate::bindings::golem::search::core::delete(
+        index.0.as_str(),
+        id.0.as_str(),
+    );
+    match result {
+        Ok(result) => Ok(result),
+        Err(error) => {
+            let error: crate::bindings::golem::search::types::SearchError = error;
+            Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+        }
+    }
+}
+#[rquickjs::function]
+fn delete_many(
+    ctx: rquickjs::Ctx<'_>,
+    index: crate::wrappers::JsString,
+    ids: crate::wrappers::JsList<crate::wrappers::JsString>,
+) -> rquickjs::Result<()> {
+    let result: Result<(), crate::bindings::golem::search::core::SearchError> = crate::bindings::golem::search::core::delete_many(
+        index.0.as_str(),
+        ids.0.into_iter().map(|v| v.0).collect::<Vec<_>>().as_slice(),
+    );
+    match result {
+        Ok(result) => Ok(result),
+        Err(error) => {
+            let error: crate::bindings::golem::search::types::SearchError = error;
+            Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+        }
+    }
+}
+#[rquickjs::function]
+fn get(
+    ctx: rquickjs::Ctx<'_>,
+    index: crate::wrappers::JsString,
+    id: crate::wrappers::JsString,
+) -> rquickjs::Result<Option<crate::bindings::golem::search::types::Doc>> {
+    let result: Result<
+        Option<crate::bindings::golem::search::core::Doc>,
+        crate::bindings::golem::search::core::SearchError,
+    > = crate::bindings::golem::search::core::get(index.0.as_str(), id.0.as_str());
+    match result {
+        Ok(result) => Ok(result.map(|v| v)),
+        Err(error) => {
+            let error: crate::bindings::golem::search::types::SearchError = error;
+            Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+        }
+    }
+}
+#[rquickjs::function]
+fn search(
+    ctx: rquickjs::Ctx<'_>,
+    index: crate::wrappers::JsString,
+    query: crate::bindings::golem::search::types::SearchQuery,
+) -> rquickjs::Result<crate::bindings::golem::search::types::SearchResults> {
+    let result: Result<
+        crate::bindings::golem::search::core::SearchResults,
+        crate::bindings::golem::search::core::SearchError,
+    > = crate::bindings::golem::search::core::search(index.0.as_str(), &query);
+    match result {
+        Ok(result) => Ok(result),
+        Err(error) => {
+            let error: crate::bindings::golem::search::types::SearchError = error;
+            Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+        }
+    }
+}
+#[rquickjs::function]
+fn stream_search(
+    ctx: rquickjs::Ctx<'_>,
+    index: crate::wrappers::JsString,
+    query: crate::bindings::golem::search::types::SearchQuery,
+) -> rquickjs::Result<crate::bindings::golem::search::core::SearchStream> {
+    let result: Result<
+        crate::bindings::golem::search::core::SearchStream,
+        crate::bindings::golem::search::core::SearchError,
+    > = crate::bindings::golem::search::core::stream_search(index.0.as_str(), &query);
+    match result {
+        Ok(result) => Ok(result),
+        Err(error) => {
+            let error: crate::bindings::golem::search::types::SearchError = error;
+            Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+        }
+    }
+}
+#[rquickjs::function]
+fn get_schema(
+    ctx: rquickjs::Ctx<'_>,
+    index: crate::wrappers::JsString,
+) -> rquickjs::Result<crate::bindings::golem::search::types::Schema> {
+    let result: Result<
+        crate::bindings::golem::search::core::Schema,
+        crate::bindings::golem::search::core::SearchError,
+    > = crate::bindings::golem::search::core::get_schema(index.0.as_str());
+    match result {
+        Ok(result) => Ok(result),
+        Err(error) => {
+            let error: crate::bindings::golem::search::types::SearchError = error;
+            Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+        }
+    }
+}
+#[rquickjs::function]
+fn update_schema(
+    ctx: rquickjs::Ctx<'_>,
+    index: crate::wrappers::JsString,
+    schema: crate::bindings::golem::search::types::Schema,
+) -> rquickjs::Result<()> {
+    let result: Result<(), crate::bindings::golem::search::core::SearchError> = crate::bindings::golem::search::core::update_schema(
+        index.0.as_str(),
+        &schema,
+    );
+    match result {
+        Ok(result) => Ok(result),
+        Err(error) => {
+            let error: crate::bindings::golem::search::types::SearchError = error;
+            Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+        }
+    }
+}
+#[derive(Clone, JsLifetime, Trace)]
+pub struct SearchStream {
+    #[qjs(skip_trace = true)]
+    inner: Option<std::rc::Rc<crate::bindings::golem::search::core::SearchStream>>,
+    /// The export call epoch if the handle is only borrowed from the host
+    #[qjs(skip_trace = true)]
+    borrow_epoch: Option<usize>,
+}
+mod __impl_class_search_stream_ {
+    pub use super::*;
+    use rquickjs::{Atom, Symbol, Value};
+    impl<'js> rquickjs::class::JsClass<'js> for SearchStream {
+        const NAME: &'static str = "SearchStream";
+        type Mutable = rquickjs::class::Writable;
+        fn prototype(
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<Option<rquickjs::Object<'js>>> {
+            use rquickjs::class::impl_::MethodImplementor;
+            let proto = rquickjs::Object::new(ctx.clone())?;
+            let implementor = rquickjs::class::impl_::MethodImpl::<Self>::new();
+            (&implementor).implement(&proto)?;
+            let dispose_symbol: Symbol = ctx
+                .globals()
+                .get(crate::internal::DISPOSE_SYMBOL)?;
+            let dispose_fn: Value = proto.get("__dispose")?;
+            proto.set(dispose_symbol, dispose_fn)?;
+            Ok(Some(proto))
+        }
+        fn constructor(
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<Option<rquickjs::function::Constructor<'js>>> {
+            use rquickjs::class::impl_::ConstructorCreator;
+            let implementor = rquickjs::class::impl_::ConstructorCreate::<Self>::new();
+            (&implementor).create_constructor(ctx)
+        }
+    }
+    impl<'js> rquickjs::IntoJs<'js> for SearchStream {
+        fn into_js(
+            self,
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<rquickjs::Value<'js>> {
+            let cls = rquickjs::class::Class::<Self>::instance(ctx.clone(), self)?;
+            rquickjs::IntoJs::into_js(cls, ctx)
+        }
+    }
+    impl<'js> rquickjs::FromJs<'js> for SearchStream
+    where
+        for<'a> rquickjs::class::impl_::CloneWrapper<
+            'a,
+            Self,
+        >: rquickjs::class::impl_::CloneTrait<Self>,
+    {
+        fn from_js(
+            ctx: &rquickjs::Ctx<'js>,
+            value: rquickjs::Value<'js>,
+        ) -> rquickjs::Result<Self> {
+            use rquickjs::class::impl_::CloneTrait;
+            let value = rquickjs::class::Class::<Self>::from_js(ctx, value)?;
+            let borrow = value.try_borrow()?;
+            Ok(rquickjs::class::impl_::CloneWrapper(&*borrow).wrap_clone())
+        }
+    }
+}
+impl SearchStream {
+    /// Gets the wrapped resource, failing if it has been disposed or if it is a
+    /// borrowed handle and the call it was passed to has already returned
+    fn checked_inner(
+        &self,
+    ) -> Result<
+        &std::rc::Rc<crate::bindings::golem::search::core::SearchStream>,
+        &'static str,
+    > {
+        if self
+            .borrow_epoch
+            .is_some_and(|epoch| !crate::internal::is_borrow_active(epoch))
+        {
+            return Err(
+                "Borrowed resource handle cannot be used after the call it was passed to has returned",
+            );
+        }
+        self.inner.as_ref().ok_or("Resource has already been disposed")
+    }
+    /// Gets the wrapped resource for calling one of its methods, throwing a
+    /// `ReferenceError` if it cannot be used
+    fn resource(
+        &self,
+        ctx: &rquickjs::Ctx<'_>,
+    ) -> rquickjs::Result<&crate::bindings::golem::search::core::SearchStream> {
+        self.checked_inner()
+            .map(|inner| inner.deref())
+            .map_err(|message| rquickjs::Exception::throw_reference(ctx, message))
+    }
+    /// Releases the wrapped resource. Borrowed handles belong to the host, so they
+    /// are never dropped, only forgotten.
+    fn release(&mut self) {
+        if let Some(inner) = self.inner.take() {
+            if self.borrow_epoch.is_some() {
+                if let Ok(inner) = std::rc::Rc::try_unwrap(inner) {
+                    let _ = inner.take_handle();
+                }
+            }
+        }
+    }
+}
+impl Drop for SearchStream {
+    fn drop(&mut self) {
+        self.release();
+    }
+}
+#[rquickjs::methods(rename_all = "camelCase")]
+impl SearchStream {
+    #[qjs(constructor)]
+    pub fn new() -> Self {
+        Self {
+            inner: None,
+            borrow_epoch: None,
+        }
+    }
+    pub fn get_next(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+    ) -> rquickjs::Result<
+        Option<crate::wrappers::JsList<crate::bindings::golem::search::types::SearchHit>>,
+    > {
+        let result: Option<Vec<crate::bindings::golem::search::core::SearchHit>> = self
+            .resource(&ctx)?
+            .get_next();
+        Ok(
+            result
+                .map(|v| crate::wrappers::JsList(
+                    v.into_iter().map(|v| v).collect::<Vec<_>>(),
+                )),
+        )
+    }
+    pub fn blocking_get_next(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+    ) -> rquickjs::Result<
+        crate::wrappers::JsList<crate::bindings::golem::search::types::SearchHit>,
+    > {
+        let result: Vec<crate::bindings::golem::search::core::SearchHit> = self
+            .resource(&ctx)?
+            .blocking_get_next();
+        Ok(crate::wrappers::JsList(result.into_iter().map(|v| v).collect::<Vec<_>>()))
+    }
+    #[qjs(rename = "__dispose")]
+    pub fn __dispose(&mut self) {
+        self.release();
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::search::core::SearchStream {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        SearchStream {
+            inner: Some(std::rc::Rc::new(self)),
+            borrow_epoch: None,
+        }
+            .into_js(ctx)
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::search::core::SearchStream {
+    fn from_js(
+        ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let wrapper = SearchStream::from_js(ctx, value)?;
+        if wrapper.borrow_epoch.is_some() {
+            return Err(rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "SearchStream",
+                message: Some(
+                    "The ownership of a borrowed resource handle cannot be transferred"
+                        .to_string(),
+                ),
+            });
+        }
+        let inner = wrapper
+            .checked_inner()
+            .map_err(|message| rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "SearchStream",
+                message: Some(message.to_string()),
+            })?;
+        unsafe {
+            Ok(
+                crate::bindings::golem::search::core::SearchStream::from_handle(
+                    inner.take_handle(),
+                ),
+            )
+        }
+    }
+}
+pub struct BorrowSearchStreamWrapper(
+    pub crate::bindings::golem::search::core::SearchStream,
+);
+impl<'js> rquickjs::FromJs<'js> for BorrowSearchStreamWrapper {
+    fn from_js(
+        ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let wrapper = SearchStream::from_js(ctx, value)?;
+        let inner = wrapper
+            .checked_inner()
+            .map_err(|message| rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "SearchStream",
+                message: Some(message.to_string()),
+            })?;
+        unsafe {
+            Ok(
+                BorrowSearchStreamWrapper(
+                    crate::bindings::golem::search::core::SearchStream::from_handle(
+                        inner.handle(),
+                    ),
+                ),
+            )
+        }
+    }
+}
+impl BorrowSearchStreamWrapper {
+    /// Borrows a handle passed to an exported function, without taking its ownership
+    pub fn from_ref(
+        resource: &crate::bindings::golem::search::core::SearchStream,
+    ) -> Self {
+        unsafe {
+            BorrowSearchStreamWrapper(
+                crate::bindings::golem::search::core::SearchStream::from_handle(
+                    resource.handle(),
+                ),
+            )
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for BorrowSearchStreamWrapper {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let resource = unsafe {
+            crate::bindings::golem::search::core::SearchStream::from_handle(
+                self.0.take_handle(),
+            )
+        };
+        SearchStream {
+            inner: Some(std::rc::Rc::new(resource)),
+            borrow_epoch: Some(crate::internal::current_borrow_epoch()),
+        }
+            .into_js(ctx)
+    }
+}
+impl Drop for BorrowSearchStreamWrapper {
+    fn drop(&mut self) {
+        let _ = self.0.take_handle();
+    }
+}
+pub struct JsCoreModule;
+impl rquickjs::module::ModuleDef for JsCoreModule {
+    fn declare(decl: &rquickjs::module::Declarations) -> rquickjs::Result<()> {
+        decl.declare("createIndex")?;
+        decl.declare("deleteIndex")?;
+        decl.declare("listIndexes")?;
+        decl.declare("upsert")?;
+        decl.declare("upsertMany")?;
+        decl.declare("delete_")?;
+        decl.declare("deleteMany")?;
+        decl.declare("get")?;
+        decl.declare("search")?;
+        decl.declare("streamSearch")?;
+        decl.declare("getSchema")?;
+        decl.declare("updateSchema")?;
+        decl.declare("SearchStream")?;
+        Ok(())
+    }
+    fn evaluate<'js>(
+        ctx: &rquickjs::Ctx<'js>,
+        exports: &rquickjs::module::Exports<'js>,
+    ) -> rquickjs::Result<()> {
+        exports.export("createIndex", js_create_index)?;
+        exports.export("deleteIndex", js_delete_index)?;
+        exports.export("listIndexes", js_list_indexes)?;
+        exports.export("upsert", js_upsert)?;
+        exports.export("upsertMany", js_upsert_many)?;
+        exports.export("delete_", js_delete)?;
+        exports.export("deleteMany", js_delete_many)?;
+        exports.export("get", js_get)?;
+        exports.export("search", js_search)?;
+        exports.export("streamSearch", js_stream_search)?;
+        exports.export("getSchema", js_get_schema)?;
+        exports.export("updateSchema", js_update_schema)?;
+        exports.export("SearchStream", SearchStream::constructor(ctx)?)?;
+        Ok(())
+    }
+}
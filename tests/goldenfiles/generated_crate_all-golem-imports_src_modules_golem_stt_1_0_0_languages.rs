@@ -0,0 +1,46 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:all-golem-imports/all-golem-imports
+// wit: sha256:3ec76077ee8750a4a3d97cf8f89273803d43fba31aa02651185015b5648e2fc7
+// js all-golem-imports: sha256:1aa6d68c08d678e85c1f334bb55fc7062ce81fa0f90be1bc0d6a3965bfccc568
+
+use rquickjs::JsLifetime;
+use rquickjs::class::{JsClass, Trace};
+use std::ops::Deref;
+#[rquickjs::function]
+fn list_languages(
+    ctx: rquickjs::Ctx<'_>,
+) -> rquickjs::Result<
+    crate::wrappers::JsList<crate::bindings::golem::stt::languages::LanguageInfo>,
+> {
+    let result: Result<
+        Vec<crate::bindings::golem::stt::languages::LanguageInfo>,
+        crate::bindings::golem::stt::languages::SttError,
+    > = crate::bindings::golem::stt::languages::list_languages();
+    match result {
+        Ok(result) => {
+            Ok(
+                crate::wrappers::JsList(
+                    result.into_iter().map(|v| v).collect::<Vec<_>>(),
+                ),
+            )
+        }
+        Err(error) => {
+            let error: crate::bindings::golem::stt::types::SttError = error;
+            Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+        }
+    }
+}
+pub struct JsLanguagesModule;
+impl rquickjs::module::ModuleDef for JsLanguagesModule {
+    fn declare(decl: &rquickjs::module::Declarations) -> rquickjs::Result<()> {
+        decl.declare("listLanguages")?;
+        Ok(())
+    }
+    fn evaluate<'js>(
+        ctx: &rquickjs::Ctx<'js>,
+        exports: &rquickjs::module::Exports<'js>,
+    ) -> rquickjs::Result<()> {
+        exports.export("listLanguages", js_list_languages)?;
+        Ok(())
+    }
+}
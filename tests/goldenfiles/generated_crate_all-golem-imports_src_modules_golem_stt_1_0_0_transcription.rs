@@ -0,0 +1,64 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:all-golem-imports/all-golem-imports
+// wit: sha256:3ec76077ee8750a4a3d97cf8f89273803d43fba31aa02651185015b5648e2fc7
+// js all-golem-imports: sha256:1aa6d68c08d678e85c1f334bb55fc7062ce81fa0f90be1bc0d6a3965bfccc568
+
+use rquickjs::JsLifetime;
+use rquickjs::class::{JsClass, Trace};
+use std::ops::Deref;
+#[rquickjs::function]
+fn transcribe(
+    ctx: rquickjs::Ctx<'_>,
+    request: crate::bindings::golem::stt::transcription::TranscriptionRequest,
+) -> rquickjs::Result<crate::bindings::golem::stt::types::TranscriptionResult> {
+    let result: Result<
+        crate::bindings::golem::stt::transcription::TranscriptionResult,
+        crate::bindings::golem::stt::transcription::SttError,
+    > = crate::bindings::golem::stt::transcription::transcribe(&request);
+    match result {
+        Ok(result) => Ok(result),
+        Err(error) => {
+            let error: crate::bindings::golem::stt::types::SttError = error;
+            Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+        }
+    }
+}
+#[rquickjs::function]
+fn transcribe_many(
+    ctx: rquickjs::Ctx<'_>,
+    requests: crate::wrappers::JsList<
+        crate::bindings::golem::stt::transcription::TranscriptionRequest,
+    >,
+) -> rquickjs::Result<
+    crate::bindings::golem::stt::transcription::MultiTranscriptionResult,
+> {
+    let result: Result<
+        crate::bindings::golem::stt::transcription::MultiTranscriptionResult,
+        crate::bindings::golem::stt::transcription::SttError,
+    > = crate::bindings::golem::stt::transcription::transcribe_many(
+        requests.0.into_iter().map(|v| v).collect::<Vec<_>>().as_slice(),
+    );
+    match result {
+        Ok(result) => Ok(result),
+        Err(error) => {
+            let error: crate::bindings::golem::stt::types::SttError = error;
+            Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+        }
+    }
+}
+pub struct JsTranscriptionModule;
+impl rquickjs::module::ModuleDef for JsTranscriptionModule {
+    fn declare(decl: &rquickjs::module::Declarations) -> rquickjs::Result<()> {
+        decl.declare("transcribe")?;
+        decl.declare("transcribeMany")?;
+        Ok(())
+    }
+    fn evaluate<'js>(
+        ctx: &rquickjs::Ctx<'js>,
+        exports: &rquickjs::module::Exports<'js>,
+    ) -> rquickjs::Result<()> {
+        exports.export("transcribe", js_transcribe)?;
+        exports.export("transcribeMany", js_transcribe_many)?;
+        Ok(())
+    }
+}
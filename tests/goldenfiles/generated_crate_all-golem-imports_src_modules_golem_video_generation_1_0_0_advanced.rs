@@ -0,0 +1,128 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:all-golem-imports/all-golem-imports
+// wit: sha256:3ec76077ee8750a4a3d97cf8f89273803d43fba31aa02651185015b5648e2fc7
+// js all-golem-imports: sha256:1aa6d68c08d678e85c1f334bb55fc7062ce81fa0f90be1bc0d6a3965bfccc568
+
+use rquickjs::JsLifetime;
+use rquickjs::class::{JsClass, Trace};
+use std::ops::Deref;
+#[rquickjs::function]
+fn extend_video(
+    ctx: rquickjs::Ctx<'_>,
+    video_id: crate::wrappers::JsString,
+    prompt: Option<crate::wrappers::JsString>,
+    negative_prompt: Option<crate::wrappers::JsString>,
+    cfg_scale: Option<f32>,
+    provider_options: Option<
+        crate::wrappers::JsList<crate::bindings::golem::video_generation::types::Kv>,
+    >,
+) -> rquickjs::Result<crate::wrappers::JsString> {
+    let result: Result<
+        String,
+        crate::bindings::golem::video_generation::advanced::VideoError,
+    > = crate::bindings::golem::video_generation::advanced::extend_video(
+        video_id.0.as_str(),
+        prompt.map(|v| v.0).as_deref(),
+        negative_prompt.map(|v| v.0).as_deref(),
+        cfg_scale.map(|v| v),
+        provider_options
+            .map(|v| v.0.into_iter().map(|v| v).collect::<Vec<_>>())
+            .as_deref(),
+    );
+    match result {
+        Ok(result) => Ok(crate::wrappers::JsString(result)),
+        Err(error) => {
+            let error: crate::bindings::golem::video_generation::types::VideoError = error;
+            Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+        }
+    }
+}
+#[rquickjs::function]
+fn upscale_video(
+    ctx: rquickjs::Ctx<'_>,
+    input: crate::bindings::golem::video_generation::types::BaseVideo,
+) -> rquickjs::Result<crate::wrappers::JsString> {
+    let result: Result<
+        String,
+        crate::bindings::golem::video_generation::advanced::VideoError,
+    > = crate::bindings::golem::video_generation::advanced::upscale_video(&input);
+    match result {
+        Ok(result) => Ok(crate::wrappers::JsString(result)),
+        Err(error) => {
+            let error: crate::bindings::golem::video_generation::types::VideoError = error;
+            Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+        }
+    }
+}
+#[rquickjs::function]
+fn generate_video_effects(
+    ctx: rquickjs::Ctx<'_>,
+    input: crate::bindings::golem::video_generation::types::InputImage,
+    effect: crate::bindings::golem::video_generation::types::EffectType,
+    model: Option<crate::wrappers::JsString>,
+    duration: Option<f32>,
+    mode: Option<crate::wrappers::JsString>,
+) -> rquickjs::Result<crate::wrappers::JsString> {
+    let result: Result<
+        String,
+        crate::bindings::golem::video_generation::advanced::VideoError,
+    > = crate::bindings::golem::video_generation::advanced::generate_video_effects(
+        &input,
+        &effect,
+        model.map(|v| v.0).as_deref(),
+        duration.map(|v| v),
+        mode.map(|v| v.0).as_deref(),
+    );
+    match result {
+        Ok(result) => Ok(crate::wrappers::JsString(result)),
+        Err(error) => {
+            let error: crate::bindings::golem::video_generation::types::VideoError = error;
+            Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+        }
+    }
+}
+#[rquickjs::function]
+fn multi_image_generation(
+    ctx: rquickjs::Ctx<'_>,
+    input_images: crate::wrappers::JsList<
+        crate::bindings::golem::video_generation::types::InputImage,
+    >,
+    prompt: Option<crate::wrappers::JsString>,
+    config: crate::bindings::golem::video_generation::types::GenerationConfig,
+) -> rquickjs::Result<crate::wrappers::JsString> {
+    let result: Result<
+        String,
+        crate::bindings::golem::video_generation::advanced::VideoError,
+    > = crate::bindings::golem::video_generation::advanced::multi_image_generation(
+        input_images.0.into_iter().map(|v| v).collect::<Vec<_>>().as_slice(),
+        prompt.map(|v| v.0).as_deref(),
+        &config,
+    );
+    match result {
+        Ok(result) => Ok(crate::wrappers::JsString(result)),
+        Err(error) => {
+            let error: crate::bindings::golem::video_generation::types::VideoError = error;
+            Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+        }
+    }
+}
+pub struct JsAdvancedModule;
+impl rquickjs::module::ModuleDef for JsAdvancedModule {
+    fn declare(decl: &rquickjs::module::Declarations) -> rquickjs::Result<()> {
+        decl.declare("extendVideo")?;
+        decl.declare("upscaleVideo")?;
+        decl.declare("generateVideoEffects")?;
+        decl.declare("multiImageGeneration")?;
+        Ok(())
+    }
+    fn evaluate<'js>(
+        ctx: &rquickjs::Ctx<'js>,
+        exports: &rquickjs::module::Exports<'js>,
+    ) -> rquickjs::Result<()> {
+        exports.export("extendVideo", js_extend_video)?;
+        exports.export("upscaleVideo", js_upscale_video)?;
+        exports.export("generateVideoEffects", js_generate_video_effects)?;
+        exports.export("multiImageGeneration", js_multi_image_generation)?;
+        Ok(())
+    }
+}
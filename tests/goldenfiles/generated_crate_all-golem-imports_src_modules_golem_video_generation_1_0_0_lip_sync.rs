@@ -0,0 +1,72 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:all-golem-imports/all-golem-imports
+// wit: sha256:3ec76077ee8750a4a3d97cf8f89273803d43fba31aa02651185015b5648e2fc7
+// js all-golem-imports: sha256:1aa6d68c08d678e85c1f334bb55fc7062ce81fa0f90be1bc0d6a3965bfccc568
+
+use rquickjs::JsLifetime;
+use rquickjs::class::{JsClass, Trace};
+use std::ops::Deref;
+#[rquickjs::function]
+fn generate_lip_sync(
+    ctx: rquickjs::Ctx<'_>,
+    video: crate::bindings::golem::video_generation::types::LipSyncVideo,
+    audio: crate::bindings::golem::video_generation::types::AudioSource,
+) -> rquickjs::Result<crate::wrappers::JsString> {
+    let result: Result<
+        String,
+        crate::bindings::golem::video_generation::lip_sync::VideoError,
+    > = crate::bindings::golem::video_generation::lip_sync::generate_lip_sync(
+        &video,
+        &audio,
+    );
+    match result {
+        Ok(result) => Ok(crate::wrappers::JsString(result)),
+        Err(error) => {
+            let error: crate::bindings::golem::video_generation::types::VideoError = error;
+            Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+        }
+    }
+}
+#[rquickjs::function]
+fn list_voices(
+    ctx: rquickjs::Ctx<'_>,
+    language: Option<crate::wrappers::JsString>,
+) -> rquickjs::Result<
+    crate::wrappers::JsList<crate::bindings::golem::video_generation::types::VoiceInfo>,
+> {
+    let result: Result<
+        Vec<crate::bindings::golem::video_generation::lip_sync::VoiceInfo>,
+        crate::bindings::golem::video_generation::lip_sync::VideoError,
+    > = crate::bindings::golem::video_generation::lip_sync::list_voices(
+        language.map(|v| v.0).as_deref(),
+    );
+    match result {
+        Ok(result) => {
+            Ok(
+                crate::wrappers::JsList(
+                    result.into_iter().map(|v| v).collect::<Vec<_>>(),
+                ),
+            )
+        }
+        Err(error) => {
+            let error: crate::bindings::golem::video_generation::types::VideoError = error;
+            Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+        }
+    }
+}
+pub struct JsLipSyncModule;
+impl rquickjs::module::ModuleDef for JsLipSyncModule {
+    fn declare(decl: &rquickjs::module::Declarations) -> rquickjs::Result<()> {
+        decl.declare("generateLipSync")?;
+        decl.declare("listVoices")?;
+        Ok(())
+    }
+    fn evaluate<'js>(
+        ctx: &rquickjs::Ctx<'js>,
+        exports: &rquickjs::module::Exports<'js>,
+    ) -> rquickjs::Result<()> {
+        exports.export("generateLipSync", js_generate_lip_sync)?;
+        exports.export("listVoices", js_list_voices)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,85 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:all-golem-imports/all-golem-imports
+// wit: sha256:3ec76077ee8750a4a3d97cf8f89273803d43fba31aa02651185015b5648e2fc7
+// js all-golem-imports: sha256:1aa6d68c08d678e85c1f334bb55fc7062ce81fa0f90be1bc0d6a3965bfccc568
+
+use rquickjs::JsLifetime;
+use rquickjs::class::{JsClass, Trace};
+use std::ops::Deref;
+#[rquickjs::function]
+fn generate(
+    ctx: rquickjs::Ctx<'_>,
+    input: crate::bindings::golem::video_generation::types::MediaInput,
+    config: crate::bindings::golem::video_generation::types::GenerationConfig,
+) -> rquickjs::Result<crate::wrappers::JsString> {
+    let result: Result<
+        String,
+        crate::bindings::golem::video_generation::video_generation::VideoError,
+    > = crate::bindings::golem::video_generation::video_generation::generate(
+        &input,
+        &config,
+    );
+    match result {
+        Ok(result) => Ok(crate::wrappers::JsString(result)),
+        Err(error) => {
+            let error: crate::bindings::golem::video_generation::types::VideoError = error;
+            Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+        }
+    }
+}
+#[rquickjs::function]
+fn poll(
+    ctx: rquickjs::Ctx<'_>,
+    job_id: crate::wrappers::JsString,
+) -> rquickjs::Result<crate::bindings::golem::video_generation::types::VideoResult> {
+    let result: Result<
+        crate::bindings::golem::video_generation::video_generation::VideoResult,
+        crate::bindings::golem::video_generation::video_generation::VideoError,
+    > = crate::bindings::golem::video_generation::video_generation::poll(
+        job_id.0.as_str(),
+    );
+    match result {
+        Ok(result) => Ok(result),
+        Err(error) => {
+            let error: crate::bindings::golem::video_generation::types::VideoError = error;
+            Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+        }
+    }
+}
+#[rquickjs::function]
+fn cancel(
+    ctx: rquickjs::Ctx<'_>,
+    job_id: crate::wrappers::JsString,
+) -> rquickjs::Result<crate::wrappers::JsString> {
+    let result: Result<
+        String,
+        crate::bindings::golem::video_generation::video_generation::VideoError,
+    > = crate::bindings::golem::video_generation::video_generation::cancel(
+        job_id.0.as_str(),
+    );
+    match result {
+        Ok(result) => Ok(crate::wrappers::JsString(result)),
+        Err(error) => {
+            let error: crate::bindings::golem::video_generation::types::VideoError = error;
+            Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+        }
+    }
+}
+pub struct JsVideoGenerationModule;
+impl rquickjs::module::ModuleDef for JsVideoGenerationModule {
+    fn declare(decl: &rquickjs::module::Declarations) -> rquickjs::Result<()> {
+        decl.declare("generate")?;
+        decl.declare("poll")?;
+        decl.declare("cancel")?;
+        Ok(())
+    }
+    fn evaluate<'js>(
+        ctx: &rquickjs::Ctx<'js>,
+        exports: &rquickjs::module::Exports<'js>,
+    ) -> rquickjs::Result<()> {
+        exports.export("generate", js_generate)?;
+        exports.export("poll", js_poll)?;
+        exports.export("cancel", js_cancel)?;
+        Ok(())
+    }
+}
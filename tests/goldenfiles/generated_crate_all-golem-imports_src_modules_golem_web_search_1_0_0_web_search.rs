@@ -0,0 +1,352 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:all-golem-imports/all-golem-imports
+// wit: sha256:3ec76077ee8750a4a3d97cf8f89273803d43fba31aa02651185015b5648e2fc7
+// js all-golem-imports: sha256:1aa6d68c08d678e85c1f334bb55fc7062ce81fa0f90be1bc0d6a3965bfccc568
+
+use rquickjs::JsLifetime;
+use rquickjs::class::{JsClass, Trace};
+use std::ops::Deref;
+#[rquickjs::function]
+fn start_search(
+    ctx: rquickjs::Ctx<'_>,
+    params: crate::bindings::golem::web_search::types::SearchParams,
+) -> rquickjs::Result<crate::bindings::golem::web_search::web_search::SearchSession> {
+    let result: Result<
+        crate::bindings::golem::web_search::web_search::SearchSession,
+        crate::bindings::golem::web_search::web_search::SearchError,
+    > = crate::bindings::golem::web_search::web_search::start_search(&params);
+    match result {
+        Ok(result) => Ok(result),
+        Err(error) => {
+            let error: crate::bindings::golem::web_search::types::SearchError = error;
+            Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+        }
+    }
+}
+#[rquickjs::function]
+fn search_once(
+    ctx: rquickjs::Ctx<'_>,
+    params: crate::bindings::golem::web_search::types::SearchParams,
+) -> rquickjs::Result<
+    rquickjs::convert::List<
+        (
+            crate::wrappers::JsList<
+                crate::bindings::golem::web_search::types::SearchResult,
+            >,
+            Option<crate::bindings::golem::web_search::types::SearchMetadata>,
+        ),
+    >,
+> {
+    let result: Result<
+        (
+            Vec<crate::bindings::golem::web_search::web_search::SearchResult>,
+            Option<crate::bindings::golem::web_search::web_search::SearchMetadata>,
+        ),
+        crate::bindings::golem::web_search::web_search::SearchError,
+    > = crate::bindings::golem::web_search::web_search::search_once(&params);
+    match result {
+        Ok(result) => {
+            Ok(
+                rquickjs::convert::List((
+                    crate::wrappers::JsList(
+                        result.0.into_iter().map(|v| v).collect::<Vec<_>>(),
+                    ),
+                    result.1.map(|v| v),
+                )),
+            )
+        }
+        Err(error) => {
+            let error: crate::bindings::golem::web_search::types::SearchError = error;
+            Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+        }
+    }
+}
+#[derive(Clone, JsLifetime, Trace)]
+pub struct SearchSession {
+    #[qjs(skip_trace = true)]
+    inner: Option<
+        std::rc::Rc<crate::bindings::golem::web_search::web_search::SearchSession>,
+    >,
+    /// The export call epoch if the handle is only borrowed from the host
+    #[qjs(skip_trace = true)]
+    borrow_epoch: Option<usize>,
+}
+mod __impl_class_search_session_ {
+    pub use super::*;
+    use rquickjs::{Atom, Symbol, Value};
+    impl<'js> rquickjs::class::JsClass<'js> for SearchSession {
+        const NAME: &'static str = "SearchSession";
+        type Mutable = rquickjs::class::Writable;
+        fn prototype(
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<Option<rquickjs::Object<'js>>> {
+            use rquickjs::class::impl_::MethodImplementor;
+            let proto = rquickjs::Object::new(ctx.clone())?;
+            let implementor = rquickjs::class::impl_::MethodImpl::<Self>::new();
+            (&implementor).implement(&proto)?;
+            let dispose_symbol: Symbol = ctx
+                .globals()
+                .get(crate::internal::DISPOSE_SYMBOL)?;
+            let dispose_fn: Value = proto.get("__dispose")?;
+            proto.set(dispose_symbol, dispose_fn)?;
+            Ok(Some(proto))
+        }
+        fn constructor(
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<Option<rquickjs::function::Constructor<'js>>> {
+            use rquickjs::class::impl_::ConstructorCreator;
+            let implementor = rquickjs::class::impl_::ConstructorCreate::<Self>::new();
+            (&implementor).create_constructor(ctx)
+        }
+    }
+    impl<'js> rquickjs::IntoJs<'js> for SearchSession {
+        fn into_js(
+            self,
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<rquickjs::Value<'js>> {
+            let cls = rquickjs::class::Class::<Self>::instance(ctx.clone(), self)?;
+            rquickjs::IntoJs::into_js(cls, ctx)
+        }
+    }
+    impl<'js> rquickjs::FromJs<'js> for SearchSession
+    where
+        for<'a> rquickjs::class::impl_::CloneWrapper<
+            'a,
+            Self,
+        >: rquickjs::class::impl_::CloneTrait<Self>,
+    {
+        fn from_js(
+            ctx: &rquickjs::Ctx<'js>,
+            value: rquickjs::Value<'js>,
+        ) -> rquickjs::Result<Self> {
+            use rquickjs::class::impl_::CloneTrait;
+            let value = rquickjs::class::Class::<Self>::from_js(ctx, value)?;
+            let borrow = value.try_borrow()?;
+            Ok(rquickjs::class::impl_::CloneWrapper(&*borrow).wrap_clone())
+        }
+    }
+}
+impl SearchSession {
+    /// Gets the wrapped resource, failing if it has been disposed or if it is a
+    /// borrowed handle and the call it was passed to has already returned
+    fn checked_inner(
+        &self,
+    ) -> Result<
+        &std::rc::Rc<crate::bindings::golem::web_search::web_search::SearchSession>,
+        &'static str,
+    > {
+        if self
+            .borrow_epoch
+            .is_some_and(|epoch| !crate::internal::is_borrow_active(epoch))
+        {
+            return Err(
+                "Borrowed resource handle cannot be used after the call it was passed to has returned",
+            );
+        }
+        self.inner.as_ref().ok_or("Resource has already been disposed")
+    }
+    /// Gets the wrapped resource for calling one of its methods, throwing a
+    /// `ReferenceError` if it cannot be used
+    fn resource(
+        &self,
+        ctx: &rquickjs::Ctx<'_>,
+    ) -> rquickjs::Result<
+        &crate::bindings::golem::web_search::web_search::SearchSession,
+    > {
+        self.checked_inner()
+            .map(|inner| inner.deref())
+            .map_err(|message| rquickjs::Exception::throw_reference(ctx, message))
+    }
+    /// Releases the wrapped resource. Borrowed handles belong to the host, so they
+    /// are never dropped, only forgotten.
+    fn release(&mut self) {
+        if let Some(inner) = self.inner.take() {
+            if self.borrow_epoch.is_some() {
+                if let Ok(inner) = std::rc::Rc::try_unwrap(inner) {
+                    let _ = inner.take_handle();
+                }
+            }
+        }
+    }
+}
+impl Drop for SearchSession {
+    fn drop(&mut self) {
+        self.release();
+    }
+}
+#[rquickjs::methods(rename_all = "camelCase")]
+impl SearchSession {
+    #[qjs(constructor)]
+    pub fn new() -> Self {
+        Self {
+            inner: None,
+            borrow_epoch: None,
+        }
+    }
+    pub fn next_page(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+    ) -> rquickjs::Result<
+        crate::wrappers::JsList<crate::bindings::golem::web_search::types::SearchResult>,
+    > {
+        let result: Result<
+            Vec<crate::bindings::golem::web_search::web_search::SearchResult>,
+            crate::bindings::golem::web_search::web_search::SearchError,
+        > = self.resource(&ctx)?.next_page();
+        match result {
+            Ok(result) => {
+                Ok(
+                    crate::wrappers::JsList(
+                        result.into_iter().map(|v| v).collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            Err(error) => {
+                let error: crate::bindings::golem::web_search::types::SearchError = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn get_metadata(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+    ) -> rquickjs::Result<
+        Option<crate::bindings::golem::web_search::types::SearchMetadata>,
+    > {
+        let result: Option<
+            crate::bindings::golem::web_search::web_search::SearchMetadata,
+        > = self.resource(&ctx)?.get_metadata();
+        Ok(result.map(|v| v))
+    }
+    #[qjs(rename = "__dispose")]
+    pub fn __dispose(&mut self) {
+        self.release();
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::web_search::web_search::SearchSession {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        SearchSession {
+            inner: Some(std::rc::Rc::new(self)),
+            borrow_epoch: None,
+        }
+            .into_js(ctx)
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::web_search::web_search::SearchSession {
+    fn from_js(
+        ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let wrapper = SearchSession::from_js(ctx, value)?;
+        if wrapper.borrow_epoch.is_some() {
+            return Err(rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "SearchSession",
+                message: Some(
+                    "The ownership of a borrowed resource handle cannot be transferred"
+                        .to_string(),
+                ),
+            });
+        }
+        let inner = wrapper
+            .checked_inner()
+            .map_err(|message| rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "SearchSession",
+                message: Some(message.to_string()),
+            })?;
+        unsafe {
+            Ok(
+                crate::bindings::golem::web_search::web_search::SearchSession::from_handle(
+                    inner.take_handle(),
+                ),
+            )
+        }
+    }
+}
+pub struct BorrowSearchSessionWrapper(
+    pub crate::bindings::golem::web_search::web_search::SearchSession,
+);
+impl<'js> rquickjs::FromJs<'js> for BorrowSearchSessionWrapper {
+    fn from_js(
+        ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let wrapper = SearchSession::from_js(ctx, value)?;
+        let inner = wrapper
+            .checked_inner()
+            .map_err(|message| rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "SearchSession",
+                message: Some(message.to_string()),
+            })?;
+        unsafe {
+            Ok(
+                BorrowSearchSessionWrapper(
+                    crate::bindings::golem::web_search::web_search::SearchSession::from_handle(
+                        inner.handle(),
+                    ),
+                ),
+            )
+        }
+    }
+}
+impl BorrowSearchSessionWrapper {
+    /// Borrows a handle passed to an exported function, without taking its ownership
+    pub fn from_ref(
+        resource: &crate::bindings::golem::web_search::web_search::SearchSession,
+    ) -> Self {
+        unsafe {
+            BorrowSearchSessionWrapper(
+                crate::bindings::golem::web_search::web_search::SearchSession::from_handle(
+                    resource.handle(),
+                ),
+            )
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for BorrowSearchSessionWrapper {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let resource = unsafe {
+            crate::bindings::golem::web_search::web_search::SearchSession::from_handle(
+                self.0.take_handle(),
+            )
+        };
+        SearchSession {
+            inner: Some(std::rc::Rc::new(resource)),
+            borrow_epoch: Some(crate::internal::current_borrow_epoch()),
+        }
+            .into_js(ctx)
+    }
+}
+impl Drop for BorrowSearchSessionWrapper {
+    fn drop(&mut self) {
+        let _ = self.0.take_handle();
+    }
+}
+pub struct JsWebSearchModule;
+impl rquickjs::module::ModuleDef for JsWebSearchModule {
+    fn declare(decl: &rquickjs::module::Declarations) -> rquickjs::Result<()> {
+        decl.declare("startSearch")?;
+        decl.declare("searchOnce")?;
+        decl.declare("SearchSession")?;
+        Ok(())
+    }
+    fn evaluate<'js>(
+        ctx: &rquickjs::Ctx<'js>,
+        exports: &rquickjs::module::Exports<'js>,
+    ) -> rquickjs::Result<()> {
+        exports.export("startSearch", js_start_search)?;
+        exports.export("searchOnce", js_search_once)?;
+        exports.export("SearchSession", SearchSession::constructor(ctx)?)?;
+        Ok(())
+    }
+}
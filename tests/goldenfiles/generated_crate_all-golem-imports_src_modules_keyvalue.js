@@ -0,0 +1,117 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:all-golem-imports/all-golem-imports
+// wit: sha256:3ec76077ee8750a4a3d97cf8f89273803d43fba31aa02651185015b5648e2fc7
+// js all-golem-imports: sha256:1aa6d68c08d678e85c1f334bb55fc7062ce81fa0f90be1bc0d6a3965bfccc568
+
+import * as eventual from 'wasi:keyvalue/eventual@0.1.0';
+import * as types from 'wasi:keyvalue/types@0.1.0';
+
+// Empty if the world does not import the batch interface
+const EVENTUAL_BATCH_MODULE = 'wasi:keyvalue/eventual-batch@0.1.0';
+
+export class KeyValueError extends Error {
+    constructor(message, options) {
+        super(message, options);
+        this.name = 'KeyValueError';
+    }
+}
+
+// Runs a call of the host interface, converting the thrown `error` resources to `KeyValueError`s
+function call(operation, f) {
+    try {
+        return f();
+    } catch (error) {
+        if (error instanceof Error) {
+            throw error;
+        }
+        const trace = typeof error?.trace === 'function' ? error.trace() : String(error);
+        throw new KeyValueError(`Failed to ${operation}: ${trace}`, { cause: error });
+    }
+}
+
+function toBytes(value) {
+    if (typeof value === 'string') {
+        return new TextEncoder().encode(value);
+    }
+    if (value instanceof Uint8Array) {
+        return value;
+    }
+    if (ArrayBuffer.isView(value)) {
+        return new Uint8Array(value.buffer, value.byteOffset, value.byteLength);
+    }
+    if (value instanceof ArrayBuffer) {
+        return new Uint8Array(value);
+    }
+    throw new TypeError('The value must be a string, an ArrayBuffer or a typed array');
+}
+
+export class Bucket {
+    #bucket;
+
+    constructor(name, bucket) {
+        this.name = name;
+        this.#bucket = bucket;
+    }
+
+    async get(key) {
+        const incoming = call(`get the key ${key}`, () => eventual.get(this.#bucket, key));
+        if (incoming === undefined) {
+            return undefined;
+        }
+        return call(`read the value of the key ${key}`, () => incoming.incomingValueConsumeSync());
+    }
+
+    async getText(key) {
+        const bytes = await this.get(key);
+        return bytes === undefined ? undefined : new TextDecoder().decode(bytes);
+    }
+
+    async getJson(key) {
+        const text = await this.getText(key);
+        return text === undefined ? undefined : JSON.parse(text);
+    }
+
+    async set(key, value) {
+        const outgoing = types.OutgoingValue.newOutgoingValue();
+        call(`write the value of the key ${key}`, () => outgoing.outgoingValueWriteBodySync(toBytes(value)));
+        call(`set the key ${key}`, () => eventual.set(this.#bucket, key, outgoing));
+    }
+
+    async setJson(key, value) {
+        await this.set(key, JSON.stringify(value));
+    }
+
+    async delete(key) {
+        call(`delete the key ${key}`, () => eventual.delete_(this.#bucket, key));
+    }
+
+    async has(key) {
+        return call(`check the key ${key}`, () => eventual.exists(this.#bucket, key));
+    }
+
+    async keys() {
+        if (EVENTUAL_BATCH_MODULE === '') {
+            throw new KeyValueError('Listing the keys requires the world to import wasi:keyvalue/eventual-batch');
+        }
+        const batch = await import(EVENTUAL_BATCH_MODULE);
+        return call(`list the keys of the bucket ${this.name}`, () => batch.keys(this.#bucket));
+    }
+
+    async* entries() {
+        for (const key of await this.keys()) {
+            const value = await this.get(key);
+            if (value !== undefined) {
+                yield [key, value];
+            }
+        }
+    }
+
+    [Symbol.asyncIterator]() {
+        return this.entries();
+    }
+}
+
+export async function openBucket(name) {
+    const bucket = call(`open the bucket ${name}`, () => types.Bucket.openBucket(name));
+    return new Bucket(name, bucket);
+}
@@ -0,0 +1,501 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:all-golem-imports/all-golem-imports
+// wit: sha256:3ec76077ee8750a4a3d97cf8f89273803d43fba31aa02651185015b5648e2fc7
+// js all-golem-imports: sha256:1aa6d68c08d678e85c1f334bb55fc7062ce81fa0f90be1bc0d6a3965bfccc568
+
+use rquickjs::JsLifetime;
+use rquickjs::class::{JsClass, Trace};
+use std::ops::Deref;
+pub mod wasi_io_0_2_3_poll;
+pub mod wasi_clocks_0_2_3_monotonic_clock;
+pub mod wasi_clocks_0_2_3_wall_clock;
+pub mod golem_rpc_0_2_2_types;
+pub mod golem_api_1_1_7_host;
+pub mod golem_api_1_1_7_context;
+pub mod golem_api_1_1_7_oplog;
+pub mod golem_durability_1_2_1_durability;
+pub mod golem_rdbms_0_0_1_types;
+pub mod golem_rdbms_0_0_1_mysql;
+pub mod golem_rdbms_0_0_1_postgres;
+pub mod wasi_io_0_2_3_error;
+pub mod wasi_io_0_2_3_streams;
+pub mod wasi_blobstore_types;
+pub mod wasi_blobstore_container;
+pub mod wasi_blobstore_blobstore;
+pub mod wasi_cli_0_2_3_environment;
+pub mod wasi_filesystem_0_2_3_types;
+pub mod wasi_filesystem_0_2_3_preopens;
+pub mod wasi_http_0_2_3_types;
+pub mod wasi_http_0_2_3_outgoing_handler;
+pub mod wasi_keyvalue_0_1_0_wasi_keyvalue_error;
+pub mod wasi_keyvalue_0_1_0_types;
+pub mod wasi_keyvalue_0_1_0_eventual_batch;
+pub mod wasi_keyvalue_0_1_0_eventual;
+pub mod wasi_logging_logging;
+pub mod wasi_random_0_2_3_random;
+pub mod wasi_random_0_2_3_insecure;
+pub mod wasi_random_0_2_3_insecure_seed;
+pub mod wasi_sockets_0_2_3_network;
+pub mod wasi_sockets_0_2_3_ip_name_lookup;
+pub mod wasi_sockets_0_2_3_instance_network;
+pub mod golem_exec_1_0_0_types;
+pub mod golem_exec_1_0_0_executor;
+pub mod golem_graph_1_0_0_types;
+pub mod golem_graph_1_0_0_errors;
+pub mod golem_graph_1_0_0_transactions;
+pub mod golem_graph_1_0_0_connection;
+pub mod golem_graph_1_0_0_schema;
+pub mod golem_graph_1_0_0_traversal;
+pub mod golem_graph_1_0_0_query;
+pub mod golem_llm_1_0_0_llm;
+pub mod golem_search_1_0_0_types;
+pub mod golem_search_1_0_0_core;
+pub mod golem_stt_1_0_0_types;
+pub mod golem_stt_1_0_0_languages;
+pub mod golem_stt_1_0_0_transcription;
+pub mod golem_video_generation_1_0_0_types;
+pub mod golem_video_generation_1_0_0_advanced;
+pub mod golem_video_generation_1_0_0_lip_sync;
+pub mod golem_video_generation_1_0_0_video_generation;
+pub mod golem_web_search_1_0_0_types;
+pub mod golem_web_search_1_0_0_web_search;
+const NATIVE_MODULE_NAMES: &[&str] = &[
+    "wasi:io/poll@0.2.3",
+    "wasi:clocks/monotonic-clock@0.2.3",
+    "wasi:clocks/wall-clock@0.2.3",
+    "golem:rpc/types@0.2.2",
+    "golem:api/host@1.1.7",
+    "golem:api/context@1.1.7",
+    "golem:api/oplog@1.1.7",
+    "golem:durability/durability@1.2.1",
+    "golem:rdbms/types@0.0.1",
+    "golem:rdbms/mysql@0.0.1",
+    "golem:rdbms/postgres@0.0.1",
+    "wasi:io/error@0.2.3",
+    "wasi:io/streams@0.2.3",
+    "wasi:blobstore/types",
+    "wasi:blobstore/container",
+    "wasi:blobstore/blobstore",
+    "wasi:cli/environment@0.2.3",
+    "wasi:filesystem/types@0.2.3",
+    "wasi:filesystem/preopens@0.2.3",
+    "wasi:http/types@0.2.3",
+    "wasi:http/outgoing-handler@0.2.3",
+    "wasi:keyvalue/wasi-keyvalue-error@0.1.0",
+    "wasi:keyvalue/types@0.1.0",
+    "wasi:keyvalue/eventual-batch@0.1.0",
+    "wasi:keyvalue/eventual@0.1.0",
+    "wasi:logging/logging",
+    "wasi:random/random@0.2.3",
+    "wasi:random/insecure@0.2.3",
+    "wasi:random/insecure-seed@0.2.3",
+    "wasi:sockets/network@0.2.3",
+    "wasi:sockets/ip-name-lookup@0.2.3",
+    "wasi:sockets/instance-network@0.2.3",
+    "golem:exec/types@1.0.0",
+    "golem:exec/executor@1.0.0",
+    "golem:graph/types@1.0.0",
+    "golem:graph/errors@1.0.0",
+    "golem:graph/transactions@1.0.0",
+    "golem:graph/connection@1.0.0",
+    "golem:graph/schema@1.0.0",
+    "golem:graph/traversal@1.0.0",
+    "golem:graph/query@1.0.0",
+    "golem:llm/llm@1.0.0",
+    "golem:search/types@1.0.0",
+    "golem:search/core@1.0.0",
+    "golem:stt/types@1.0.0",
+    "golem:stt/languages@1.0.0",
+    "golem:stt/transcription@1.0.0",
+    "golem:video-generation/types@1.0.0",
+    "golem:video-generation/advanced@1.0.0",
+    "golem:video-generation/lip-sync@1.0.0",
+    "golem:video-generation/video-generation@1.0.0",
+    "golem:web-search/types@1.0.0",
+    "golem:web-search/web-search@1.0.0",
+    "golem",
+    "keyvalue",
+    "blobstore",
+    "incoming-http",
+    "db",
+    "llm",
+];
+pub struct NativeModuleResolver;
+impl rquickjs::loader::Resolver for NativeModuleResolver {
+    fn resolve<'js>(
+        &mut self,
+        _ctx: &rquickjs::Ctx<'js>,
+        base: &str,
+        name: &str,
+    ) -> rquickjs::Result<String> {
+        if NATIVE_MODULE_NAMES.contains(&name) {
+            Ok(name.to_string())
+        } else {
+            Err(rquickjs::Error::new_resolving(base, name))
+        }
+    }
+}
+pub struct NativeModuleLoader;
+impl rquickjs::loader::Loader for NativeModuleLoader {
+    fn load<'js>(
+        &mut self,
+        ctx: &rquickjs::Ctx<'js>,
+        name: &str,
+    ) -> rquickjs::Result<rquickjs::Module<'js, rquickjs::module::Declared>> {
+        match name {
+            "wasi:io/poll@0.2.3" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::wasi_io_0_2_3_poll::JsPollModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "wasi:clocks/monotonic-clock@0.2.3" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::wasi_clocks_0_2_3_monotonic_clock::JsMonotonicClockModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "wasi:clocks/wall-clock@0.2.3" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::wasi_clocks_0_2_3_wall_clock::JsWallClockModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "golem:rpc/types@0.2.2" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::golem_rpc_0_2_2_types::JsTypesModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "golem:api/host@1.1.7" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::golem_api_1_1_7_host::JsHostModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "golem:api/context@1.1.7" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::golem_api_1_1_7_context::JsContextModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "golem:api/oplog@1.1.7" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::golem_api_1_1_7_oplog::JsOplogModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "golem:durability/durability@1.2.1" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::golem_durability_1_2_1_durability::JsDurabilityModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "golem:rdbms/types@0.0.1" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::golem_rdbms_0_0_1_types::JsTypesModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "golem:rdbms/mysql@0.0.1" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::golem_rdbms_0_0_1_mysql::JsMysqlModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "golem:rdbms/postgres@0.0.1" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::golem_rdbms_0_0_1_postgres::JsPostgresModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "wasi:io/error@0.2.3" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::wasi_io_0_2_3_error::JsErrorModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "wasi:io/streams@0.2.3" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::wasi_io_0_2_3_streams::JsStreamsModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "wasi:blobstore/types" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::wasi_blobstore_types::JsTypesModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "wasi:blobstore/container" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::wasi_blobstore_container::JsContainerModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "wasi:blobstore/blobstore" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::wasi_blobstore_blobstore::JsBlobstoreModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "wasi:cli/environment@0.2.3" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::wasi_cli_0_2_3_environment::JsEnvironmentModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "wasi:filesystem/types@0.2.3" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::wasi_filesystem_0_2_3_types::JsTypesModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "wasi:filesystem/preopens@0.2.3" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::wasi_filesystem_0_2_3_preopens::JsPreopensModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "wasi:http/types@0.2.3" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::wasi_http_0_2_3_types::JsTypesModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "wasi:http/outgoing-handler@0.2.3" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::wasi_http_0_2_3_outgoing_handler::JsOutgoingHandlerModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "wasi:keyvalue/wasi-keyvalue-error@0.1.0" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::wasi_keyvalue_0_1_0_wasi_keyvalue_error::JsWasiKeyvalueErrorModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "wasi:keyvalue/types@0.1.0" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::wasi_keyvalue_0_1_0_types::JsTypesModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "wasi:keyvalue/eventual-batch@0.1.0" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::wasi_keyvalue_0_1_0_eventual_batch::JsEventualBatchModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "wasi:keyvalue/eventual@0.1.0" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::wasi_keyvalue_0_1_0_eventual::JsEventualModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "wasi:logging/logging" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::wasi_logging_logging::JsLoggingModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "wasi:random/random@0.2.3" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::wasi_random_0_2_3_random::JsRandomModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "wasi:random/insecure@0.2.3" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::wasi_random_0_2_3_insecure::JsInsecureModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "wasi:random/insecure-seed@0.2.3" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::wasi_random_0_2_3_insecure_seed::JsInsecureSeedModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "wasi:sockets/network@0.2.3" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::wasi_sockets_0_2_3_network::JsNetworkModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "wasi:sockets/ip-name-lookup@0.2.3" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::wasi_sockets_0_2_3_ip_name_lookup::JsIpNameLookupModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "wasi:sockets/instance-network@0.2.3" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::wasi_sockets_0_2_3_instance_network::JsInstanceNetworkModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "golem:exec/types@1.0.0" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::golem_exec_1_0_0_types::JsTypesModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "golem:exec/executor@1.0.0" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::golem_exec_1_0_0_executor::JsExecutorModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "golem:graph/types@1.0.0" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::golem_graph_1_0_0_types::JsTypesModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "golem:graph/errors@1.0.0" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::golem_graph_1_0_0_errors::JsErrorsModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "golem:graph/transactions@1.0.0" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::golem_graph_1_0_0_transactions::JsTransactionsModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "golem:graph/connection@1.0.0" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::golem_graph_1_0_0_connection::JsConnectionModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "golem:graph/schema@1.0.0" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::golem_graph_1_0_0_schema::JsSchemaModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "golem:graph/traversal@1.0.0" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::golem_graph_1_0_0_traversal::JsTraversalModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "golem:graph/query@1.0.0" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::golem_graph_1_0_0_query::JsQueryModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "golem:llm/llm@1.0.0" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::golem_llm_1_0_0_llm::JsLlmModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "golem:search/types@1.0.0" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::golem_search_1_0_0_types::JsTypesModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "golem:search/core@1.0.0" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::golem_search_1_0_0_core::JsCoreModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "golem:stt/types@1.0.0" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::golem_stt_1_0_0_types::JsTypesModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "golem:stt/languages@1.0.0" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::golem_stt_1_0_0_languages::JsLanguagesModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "golem:stt/transcription@1.0.0" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::golem_stt_1_0_0_transcription::JsTranscriptionModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "golem:video-generation/types@1.0.0" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::golem_video_generation_1_0_0_types::JsTypesModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "golem:video-generation/advanced@1.0.0" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::golem_video_generation_1_0_0_advanced::JsAdvancedModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "golem:video-generation/lip-sync@1.0.0" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::golem_video_generation_1_0_0_lip_sync::JsLipSyncModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "golem:video-generation/video-generation@1.0.0" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::golem_video_generation_1_0_0_video_generation::JsVideoGenerationModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "golem:web-search/types@1.0.0" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::golem_web_search_1_0_0_types::JsTypesModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "golem:web-search/web-search@1.0.0" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::golem_web_search_1_0_0_web_search::JsWebSearchModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "golem" => {
+                rquickjs::Module::declare(ctx.clone(), name, include_str!("golem.js"))
+            }
+            "keyvalue" => {
+                rquickjs::Module::declare(ctx.clone(), name, include_str!("keyvalue.js"))
+            }
+            "blobstore" => {
+                rquickjs::Module::declare(
+                    ctx.clone(),
+                    name,
+                    include_str!("blobstore.js"),
+                )
+            }
+            "incoming-http" => {
+                rquickjs::Module::declare(
+                    ctx.clone(),
+                    name,
+                    include_str!("incoming-http.js"),
+                )
+            }
+            "db" => rquickjs::Module::declare(ctx.clone(), name, include_str!("db.js")),
+            "llm" => rquickjs::Module::declare(ctx.clone(), name, include_str!("llm.js")),
+            _ => Err(rquickjs::Error::new_loading(name)),
+        }
+    }
+}
+pub struct JsAllGolemImportsModule;
+impl rquickjs::module::ModuleDef for JsAllGolemImportsModule {
+    fn declare(decl: &rquickjs::module::Declarations) -> rquickjs::Result<()> {
+        Ok(())
+    }
+    fn evaluate<'js>(
+        ctx: &rquickjs::Ctx<'js>,
+        exports: &rquickjs::module::Exports<'js>,
+    ) -> rquickjs::Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,134 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:all-golem-imports/all-golem-imports
+// wit: sha256:3ec76077ee8750a4a3d97cf8f89273803d43fba31aa02651185015b5648e2fc7
+// js all-golem-imports: sha256:1aa6d68c08d678e85c1f334bb55fc7062ce81fa0f90be1bc0d6a3965bfccc568
+
+use rquickjs::JsLifetime;
+use rquickjs::class::{JsClass, Trace};
+use std::ops::Deref;
+#[rquickjs::function]
+fn create_container(
+    ctx: rquickjs::Ctx<'_>,
+    name: crate::wrappers::JsString,
+) -> rquickjs::Result<crate::bindings::wasi::blobstore::blobstore::Container> {
+    let result: Result<
+        crate::bindings::wasi::blobstore::blobstore::Container,
+        crate::bindings::wasi::blobstore::blobstore::Error,
+    > = crate::bindings::wasi::blobstore::blobstore::create_container(name.0.as_str());
+    match result {
+        Ok(result) => Ok(result),
+        Err(error) => {
+            let error: crate::wrappers::JsString = crate::wrappers::JsString(error);
+            Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+        }
+    }
+}
+#[rquickjs::function]
+fn get_container(
+    ctx: rquickjs::Ctx<'_>,
+    name: crate::wrappers::JsString,
+) -> rquickjs::Result<crate::bindings::wasi::blobstore::blobstore::Container> {
+    let result: Result<
+        crate::bindings::wasi::blobstore::blobstore::Container,
+        crate::bindings::wasi::blobstore::blobstore::Error,
+    > = crate::bindings::wasi::blobstore::blobstore::get_container(name.0.as_str());
+    match result {
+        Ok(result) => Ok(result),
+        Err(error) => {
+            let error: crate::wrappers::JsString = crate::wrappers::JsString(error);
+            Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+        }
+    }
+}
+#[rquickjs::function]
+fn delete_container(
+    ctx: rquickjs::Ctx<'_>,
+    name: crate::wrappers::JsString,
+) -> rquickjs::Result<()> {
+    let result: Result<(), crate::bindings::wasi::blobstore::blobstore::Error> = crate::bindings::wasi::blobstore::blobstore::delete_container(
+        name.0.as_str(),
+    );
+    match result {
+        Ok(result) => Ok(result),
+        Err(error) => {
+            let error: crate::wrappers::JsString = crate::wrappers::JsString(error);
+            Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+        }
+    }
+}
+#[rquickjs::function]
+fn container_exists(
+    ctx: rquickjs::Ctx<'_>,
+    name: crate::wrappers::JsString,
+) -> rquickjs::Result<bool> {
+    let result: Result<bool, crate::bindings::wasi::blobstore::blobstore::Error> = crate::bindings::wasi::blobstore::blobstore::container_exists(
+        name.0.as_str(),
+    );
+    match result {
+        Ok(result) => Ok(result),
+        Err(error) => {
+            let error: crate::wrappers::JsString = crate::wrappers::JsString(error);
+            Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+        }
+    }
+}
+#[rquickjs::function]
+fn copy_object(
+    ctx: rquickjs::Ctx<'_>,
+    src: crate::bindings::wasi::blobstore::types::ObjectId,
+    dest: crate::bindings::wasi::blobstore::types::ObjectId,
+) -> rquickjs::Result<()> {
+    let result: Result<(), crate::bindings::wasi::blobstore::blobstore::Error> = crate::bindings::wasi::blobstore::blobstore::copy_object(
+        &src,
+        &dest,
+    );
+    match result {
+        Ok(result) => Ok(result),
+        Err(error) => {
+            let error: crate::wrappers::JsString = crate::wrappers::JsString(error);
+            Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+        }
+    }
+}
+#[rquickjs::function]
+fn move_object(
+    ctx: rquickjs::Ctx<'_>,
+    src: crate::bindings::wasi::blobstore::types::ObjectId,
+    dest: crate::bindings::wasi::blobstore::types::ObjectId,
+) -> rquickjs::Result<()> {
+    let result: Result<(), crate::bindings::wasi::blobstore::blobstore::Error> = crate::bindings::wasi::blobstore::blobstore::move_object(
+        &src,
+        &dest,
+    );
+    match result {
+        Ok(result) => Ok(result),
+        Err(error) => {
+            let error: crate::wrappers::JsString = crate::wrappers::JsString(error);
+            Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+        }
+    }
+}
+pub struct JsBlobstoreModule;
+impl rquickjs::module::ModuleDef for JsBlobstoreModule {
+    fn declare(decl: &rquickjs::module::Declarations) -> rquickjs::Result<()> {
+        decl.declare("createContainer")?;
+        decl.declare("getContainer")?;
+        decl.declare("deleteContainer")?;
+        decl.declare("containerExists")?;
+        decl.declare("copyObject")?;
+        decl.declare("moveObject")?;
+        Ok(())
+    }
+    fn evaluate<'js>(
+        ctx: &rquickjs::Ctx<'js>,
+        exports: &rquickjs::module::Exports<'js>,
+    ) -> rquickjs::Result<()> {
+        exports.export("createContainer", js_create_container)?;
+        exports.export("getContainer", js_get_container)?;
+        exports.export("deleteContainer", js_delete_container)?;
+        exports.export("containerExists", js_container_exists)?;
+        exports.export("copyObject", js_copy_object)?;
+        exports.export("moveObject", js_move_object)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,716 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:all-golem-imports/all-golem-imports
+// wit: sha256:3ec76077ee8750a4a3d97cf8f89273803d43fba31aa02651185015b5648e2fc7
+// js all-golem-imports: sha256:1aa6d68c08d678e85c1f334bb55fc7062ce81fa0f90be1bc0d6a3965bfccc568
+
+use rquickjs::JsLifetime;
+use rquickjs::class::{JsClass, Trace};
+use std::ops::Deref;
+#[derive(Clone, JsLifetime, Trace)]
+pub struct Container {
+    #[qjs(skip_trace = true)]
+    inner: Option<std::rc::Rc<crate::bindings::wasi::blobstore::container::Container>>,
+    /// The export call epoch if the handle is only borrowed from the host
+    #[qjs(skip_trace = true)]
+    borrow_epoch: Option<usize>,
+}
+mod __impl_class_container_ {
+    pub use super::*;
+    use rquickjs::{Atom, Symbol, Value};
+    impl<'js> rquickjs::class::JsClass<'js> for Container {
+        const NAME: &'static str = "Container";
+        type Mutable = rquickjs::class::Writable;
+        fn prototype(
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<Option<rquickjs::Object<'js>>> {
+            use rquickjs::class::impl_::MethodImplementor;
+            let proto = rquickjs::Object::new(ctx.clone())?;
+            let implementor = rquickjs::class::impl_::MethodImpl::<Self>::new();
+            (&implementor).implement(&proto)?;
+            let dispose_symbol: Symbol = ctx
+                .globals()
+                .get(crate::internal::DISPOSE_SYMBOL)?;
+            let dispose_fn: Value = proto.get("__dispose")?;
+            proto.set(dispose_symbol, dispose_fn)?;
+            Ok(Some(proto))
+        }
+        fn constructor(
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<Option<rquickjs::function::Constructor<'js>>> {
+            use rquickjs::class::impl_::ConstructorCreator;
+            let implementor = rquickjs::class::impl_::ConstructorCreate::<Self>::new();
+            (&implementor).create_constructor(ctx)
+        }
+    }
+    impl<'js> rquickjs::IntoJs<'js> for Container {
+        fn into_js(
+            self,
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<rquickjs::Value<'js>> {
+            let cls = rquickjs::class::Class::<Self>::instance(ctx.clone(), self)?;
+            rquickjs::IntoJs::into_js(cls, ctx)
+        }
+    }
+    impl<'js> rquickjs::FromJs<'js> for Container
+    where
+        for<'a> rquickjs::class::impl_::CloneWrapper<
+            'a,
+            Self,
+        >: rquickjs::class::impl_::CloneTrait<Self>,
+    {
+        fn from_js(
+            ctx: &rquickjs::Ctx<'js>,
+            value: rquickjs::Value<'js>,
+        ) -> rquickjs::Result<Self> {
+            use rquickjs::class::impl_::CloneTrait;
+            let value = rquickjs::class::Class::<Self>::from_js(ctx, value)?;
+            let borrow = value.try_borrow()?;
+            Ok(rquickjs::class::impl_::CloneWrapper(&*borrow).wrap_clone())
+        }
+    }
+}
+impl Container {
+    /// Gets the wrapped resource, failing if it has been disposed or if it is a
+    /// borrowed handle and the call it was passed to has already returned
+    fn checked_inner(
+        &self,
+    ) -> Result<
+        &std::rc::Rc<crate::bindings::wasi::blobstore::container::Container>,
+        &'static str,
+    > {
+        if self
+            .borrow_epoch
+            .is_some_and(|epoch| !crate::internal::is_borrow_active(epoch))
+        {
+            return Err(
+                "Borrowed resource handle cannot be used after the call it was passed to has returned",
+            );
+        }
+        self.inner.as_ref().ok_or("Resource has already been disposed")
+    }
+    /// Gets the wrapped resource for calling one of its methods, throwing a
+    /// `ReferenceError` if it cannot be used
+    fn resource(
+        &self,
+        ctx: &rquickjs::Ctx<'_>,
+    ) -> rquickjs::Result<&crate::bindings::wasi::blobstore::container::Container> {
+        self.checked_inner()
+            .map(|inner| inner.deref())
+            .map_err(|message| rquickjs::Exception::throw_reference(ctx, message))
+    }
+    /// Releases the wrapped resource. Borrowed handles belong to the host, so they
+    /// are never dropped, only forgotten.
+    fn release(&mut self) {
+        if let Some(inner) = self.inner.take() {
+            if self.borrow_epoch.is_some() {
+                if let Ok(inner) = std::rc::Rc::try_unwrap(inner) {
+                    let _ = inner.take_handle();
+                }
+            }
+        }
+    }
+}
+impl Drop for Container {
+    fn drop(&mut self) {
+        self.release();
+    }
+}
+#[rquickjs::methods(rename_all = "camelCase")]
+impl Container {
+    #[qjs(constructor)]
+    pub fn new() -> Self {
+        Self {
+            inner: None,
+            borrow_epoch: None,
+        }
+    }
+    pub fn name(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+    ) -> rquickjs::Result<crate::wrappers::JsString> {
+        let result: Result<String, crate::bindings::wasi::blobstore::container::Error> = self
+            .resource(&ctx)?
+            .name();
+        match result {
+            Ok(result) => Ok(crate::wrappers::JsString(result)),
+            Err(error) => {
+                let error: crate::wrappers::JsString = crate::wrappers::JsString(error);
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn info(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+    ) -> rquickjs::Result<crate::bindings::wasi::blobstore::types::ContainerMetadata> {
+        let result: Result<
+            crate::bindings::wasi::blobstore::container::ContainerMetadata,
+            crate::bindings::wasi::blobstore::container::Error,
+        > = self.resource(&ctx)?.info();
+        match result {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                let error: crate::wrappers::JsString = crate::wrappers::JsString(error);
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn get_data(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        name: crate::wrappers::JsString,
+        start: crate::wrappers::BigIntWrapper<u64>,
+        end: crate::wrappers::BigIntWrapper<u64>,
+    ) -> rquickjs::Result<crate::bindings::wasi::blobstore::container::IncomingValue> {
+        let result: Result<
+            crate::bindings::wasi::blobstore::container::IncomingValue,
+            crate::bindings::wasi::blobstore::container::Error,
+        > = self.resource(&ctx)?.get_data(name.0.as_str(), start.0, end.0);
+        match result {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                let error: crate::wrappers::JsString = crate::wrappers::JsString(error);
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn write_data(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        name: crate::wrappers::JsString,
+        data: crate::modules::wasi_blobstore_types::BorrowOutgoingValueWrapper,
+    ) -> rquickjs::Result<()> {
+        let result: Result<(), crate::bindings::wasi::blobstore::container::Error> = self
+            .resource(&ctx)?
+            .write_data(name.0.as_str(), &data.0);
+        match result {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                let error: crate::wrappers::JsString = crate::wrappers::JsString(error);
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn list_objects(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+    ) -> rquickjs::Result<
+        crate::bindings::wasi::blobstore::container::StreamObjectNames,
+    > {
+        let result: Result<
+            crate::bindings::wasi::blobstore::container::StreamObjectNames,
+            crate::bindings::wasi::blobstore::container::Error,
+        > = self.resource(&ctx)?.list_objects();
+        match result {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                let error: crate::wrappers::JsString = crate::wrappers::JsString(error);
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn delete_object(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        name: crate::wrappers::JsString,
+    ) -> rquickjs::Result<()> {
+        let result: Result<(), crate::bindings::wasi::blobstore::container::Error> = self
+            .resource(&ctx)?
+            .delete_object(name.0.as_str());
+        match result {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                let error: crate::wrappers::JsString = crate::wrappers::JsString(error);
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn delete_objects(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        names: crate::wrappers::JsList<crate::wrappers::JsString>,
+    ) -> rquickjs::Result<()> {
+        let result: Result<(), crate::bindings::wasi::blobstore::container::Error> = self
+            .resource(&ctx)?
+            .delete_objects(
+                names.0.into_iter().map(|v| v.0).collect::<Vec<_>>().as_slice(),
+            );
+        match result {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                let error: crate::wrappers::JsString = crate::wrappers::JsString(error);
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn has_object(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        name: crate::wrappers::JsString,
+    ) -> rquickjs::Result<bool> {
+        let result: Result<bool, crate::bindings::wasi::blobstore::container::Error> = self
+            .resource(&ctx)?
+            .has_object(name.0.as_str());
+        match result {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                let error: crate::wrappers::JsString = crate::wrappers::JsString(error);
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn object_info(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        name: crate::wrappers::JsString,
+    ) -> rquickjs::Result<crate::bindings::wasi::blobstore::types::ObjectMetadata> {
+        let result: Result<
+            crate::bindings::wasi::blobstore::container::ObjectMetadata,
+            crate::bindings::wasi::blobstore::container::Error,
+        > = self.resource(&ctx)?.object_info(name.0.as_str());
+        match result {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                let error: crate::wrappers::JsString = crate::wrappers::JsString(error);
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn clear(&self, ctx: rquickjs::Ctx<'_>) -> rquickjs::Result<()> {
+        let result: Result<(), crate::bindings::wasi::blobstore::container::Error> = self
+            .resource(&ctx)?
+            .clear();
+        match result {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                let error: crate::wrappers::JsString = crate::wrappers::JsString(error);
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    #[qjs(rename = "__dispose")]
+    pub fn __dispose(&mut self) {
+        self.release();
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::wasi::blobstore::container::Container {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        Container {
+            inner: Some(std::rc::Rc::new(self)),
+            borrow_epoch: None,
+        }
+            .into_js(ctx)
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::wasi::blobstore::container::Container {
+    fn from_js(
+        ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let wrapper = Container::from_js(ctx, value)?;
+        if wrapper.borrow_epoch.is_some() {
+            return Err(rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "Container",
+                message: Some(
+                    "The ownership of a borrowed resource handle cannot be transferred"
+                        .to_string(),
+                ),
+            });
+        }
+        let inner = wrapper
+            .checked_inner()
+            .map_err(|message| rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "Container",
+                message: Some(message.to_string()),
+            })?;
+        unsafe {
+            Ok(
+                crate::bindings::wasi::blobstore::container::Container::from_handle(
+                    inner.take_handle(),
+                ),
+            )
+        }
+    }
+}
+pub struct BorrowContainerWrapper(
+    pub crate::bindings::wasi::blobstore::container::Container,
+);
+impl<'js> rquickjs::FromJs<'js> for BorrowContainerWrapper {
+    fn from_js(
+        ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let wrapper = Container::from_js(ctx, value)?;
+        let inner = wrapper
+            .checked_inner()
+            .map_err(|message| rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "Container",
+                message: Some(message.to_string()),
+            })?;
+        unsafe {
+            Ok(
+                BorrowContainerWrapper(
+                    crate::bindings::wasi::blobstore::container::Container::from_handle(
+                        inner.handle(),
+                    ),
+                ),
+            )
+        }
+    }
+}
+impl BorrowContainerWrapper {
+    /// Borrows a handle passed to an exported function, without taking its ownership
+    pub fn from_ref(
+        resource: &crate::bindings::wasi::blobstore::container::Container,
+    ) -> Self {
+        unsafe {
+            BorrowContainerWrapper(
+                crate::bindings::wasi::blobstore::container::Container::from_handle(
+                    resource.handle(),
+                ),
+            )
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for BorrowContainerWrapper {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let resource = unsafe {
+            crate::bindings::wasi::blobstore::container::Container::from_handle(
+                self.0.take_handle(),
+            )
+        };
+        Container {
+            inner: Some(std::rc::Rc::new(resource)),
+            borrow_epoch: Some(crate::internal::current_borrow_epoch()),
+        }
+            .into_js(ctx)
+    }
+}
+impl Drop for BorrowContainerWrapper {
+    fn drop(&mut self) {
+        let _ = self.0.take_handle();
+    }
+}
+#[derive(Clone, JsLifetime, Trace)]
+pub struct StreamObjectNames {
+    #[qjs(skip_trace = true)]
+    inner: Option<
+        std::rc::Rc<crate::bindings::wasi::blobstore::container::StreamObjectNames>,
+    >,
+    /// The export call epoch if the handle is only borrowed from the host
+    #[qjs(skip_trace = true)]
+    borrow_epoch: Option<usize>,
+}
+mod __impl_class_stream_object_names_ {
+    pub use super::*;
+    use rquickjs::{Atom, Symbol, Value};
+    impl<'js> rquickjs::class::JsClass<'js> for StreamObjectNames {
+        const NAME: &'static str = "StreamObjectNames";
+        type Mutable = rquickjs::class::Writable;
+        fn prototype(
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<Option<rquickjs::Object<'js>>> {
+            use rquickjs::class::impl_::MethodImplementor;
+            let proto = rquickjs::Object::new(ctx.clone())?;
+            let implementor = rquickjs::class::impl_::MethodImpl::<Self>::new();
+            (&implementor).implement(&proto)?;
+            let dispose_symbol: Symbol = ctx
+                .globals()
+                .get(crate::internal::DISPOSE_SYMBOL)?;
+            let dispose_fn: Value = proto.get("__dispose")?;
+            proto.set(dispose_symbol, dispose_fn)?;
+            Ok(Some(proto))
+        }
+        fn constructor(
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<Option<rquickjs::function::Constructor<'js>>> {
+            use rquickjs::class::impl_::ConstructorCreator;
+            let implementor = rquickjs::class::impl_::ConstructorCreate::<Self>::new();
+            (&implementor).create_constructor(ctx)
+        }
+    }
+    impl<'js> rquickjs::IntoJs<'js> for StreamObjectNames {
+        fn into_js(
+            self,
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<rquickjs::Value<'js>> {
+            let cls = rquickjs::class::Class::<Self>::instance(ctx.clone(), self)?;
+            rquickjs::IntoJs::into_js(cls, ctx)
+        }
+    }
+    impl<'js> rquickjs::FromJs<'js> for StreamObjectNames
+    where
+        for<'a> rquickjs::class::impl_::CloneWrapper<
+            'a,
+            Self,
+        >: rquickjs::class::impl_::CloneTrait<Self>,
+    {
+        fn from_js(
+            ctx: &rquickjs::Ctx<'js>,
+            value: rquickjs::Value<'js>,
+        ) -> rquickjs::Result<Self> {
+            use rquickjs::class::impl_::CloneTrait;
+            let value = rquickjs::class::Class::<Self>::from_js(ctx, value)?;
+            let borrow = value.try_borrow()?;
+            Ok(rquickjs::class::impl_::CloneWrapper(&*borrow).wrap_clone())
+        }
+    }
+}
+impl StreamObjectNames {
+    /// Gets the wrapped resource, failing if it has been disposed or if it is a
+    /// borrowed handle and the call it was passed to has already returned
+    fn checked_inner(
+        &self,
+    ) -> Result<
+        &std::rc::Rc<crate::bindings::wasi::blobstore::container::StreamObjectNames>,
+        &'static str,
+    > {
+        if self
+            .borrow_epoch
+            .is_some_and(|epoch| !crate::internal::is_borrow_active(epoch))
+        {
+            return Err(
+                "Borrowed resource handle cannot be used after the call it was passed to has returned",
+            );
+        }
+        self.inner.as_ref().ok_or("Resource has already been disposed")
+    }
+    /// Gets the wrapped resource for calling one of its methods, throwing a
+    /// `ReferenceError` if it cannot be used
+    fn resource(
+        &self,
+        ctx: &rquickjs::Ctx<'_>,
+    ) -> rquickjs::Result<
+        &crate::bindings::wasi::blobstore::container::StreamObjectNames,
+    > {
+        self.checked_inner()
+            .map(|inner| inner.deref())
+            .map_err(|message| rquickjs::Exception::throw_reference(ctx, message))
+    }
+    /// Releases the wrapped resource. Borrowed handles belong to the host, so they
+    /// are never dropped, only forgotten.
+    fn release(&mut self) {
+        if let Some(inner) = self.inner.take() {
+            if self.borrow_epoch.is_some() {
+                if let Ok(inner) = std::rc::Rc::try_unwrap(inner) {
+                    let _ = inner.take_handle();
+                }
+            }
+        }
+    }
+}
+impl Drop for StreamObjectNames {
+    fn drop(&mut self) {
+        self.release();
+    }
+}
+#[rquickjs::methods(rename_all = "camelCase")]
+impl StreamObjectNames {
+    #[qjs(constructor)]
+    pub fn new() -> Self {
+        Self {
+            inner: None,
+            borrow_epoch: None,
+        }
+    }
+    pub fn read_stream_object_names(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        len: crate::wrappers::BigIntWrapper<u64>,
+    ) -> rquickjs::Result<
+        rquickjs::convert::List<
+            (crate::wrappers::JsList<crate::wrappers::JsString>, bool),
+        >,
+    > {
+        let result: Result<
+            (Vec<crate::bindings::wasi::blobstore::container::ObjectName>, bool),
+            crate::bindings::wasi::blobstore::container::Error,
+        > = self.resource(&ctx)?.read_stream_object_names(len.0);
+        match result {
+            Ok(result) => {
+                Ok(
+                    rquickjs::convert::List((
+                        crate::wrappers::JsList(
+                            result
+                                .0
+                                .into_iter()
+                                .map(|v| crate::wrappers::JsString(v))
+                                .collect::<Vec<_>>(),
+                        ),
+                        result.1,
+                    )),
+                )
+            }
+            Err(error) => {
+                let error: crate::wrappers::JsString = crate::wrappers::JsString(error);
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn skip_stream_object_names(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        num: crate::wrappers::BigIntWrapper<u64>,
+    ) -> rquickjs::Result<
+        rquickjs::convert::List<(crate::wrappers::BigIntWrapper<u64>, bool)>,
+    > {
+        let result: Result<
+            (u64, bool),
+            crate::bindings::wasi::blobstore::container::Error,
+        > = self.resource(&ctx)?.skip_stream_object_names(num.0);
+        match result {
+            Ok(result) => {
+                Ok(
+                    rquickjs::convert::List((
+                        crate::wrappers::BigIntWrapper(result.0),
+                        result.1,
+                    )),
+                )
+            }
+            Err(error) => {
+                let error: crate::wrappers::JsString = crate::wrappers::JsString(error);
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    #[qjs(rename = "__dispose")]
+    pub fn __dispose(&mut self) {
+        self.release();
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::wasi::blobstore::container::StreamObjectNames {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        StreamObjectNames {
+            inner: Some(std::rc::Rc::new(self)),
+            borrow_epoch: None,
+        }
+            .into_js(ctx)
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::wasi::blobstore::container::StreamObjectNames {
+    fn from_js(
+        ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let wrapper = StreamObjectNames::from_js(ctx, value)?;
+        if wrapper.borrow_epoch.is_some() {
+            return Err(rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "StreamObjectNames",
+                message: Some(
+                    "The ownership of a borrowed resource handle cannot be transferred"
+                        .to_string(),
+                ),
+            });
+        }
+        let inner = wrapper
+            .checked_inner()
+            .map_err(|message| rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "StreamObjectNames",
+                message: Some(message.to_string()),
+            })?;
+        unsafe {
+            Ok(
+                crate::bindings::wasi::blobstore::container::StreamObjectNames::from_handle(
+                    inner.take_handle(),
+                ),
+            )
+        }
+    }
+}
+pub struct BorrowStreamObjectNamesWrapper(
+    pub crate::bindings::wasi::blobstore::container::StreamObjectNames,
+);
+impl<'js> rquickjs::FromJs<'js> for BorrowStreamObjectNamesWrapper {
+    fn from_js(
+        ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let wrapper = StreamObjectNames::from_js(ctx, value)?;
+        let inner = wrapper
+            .checked_inner()
+            .map_err(|message| rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "StreamObjectNames",
+                message: Some(message.to_string()),
+            })?;
+        unsafe {
+            Ok(
+                BorrowStreamObjectNamesWrapper(
+                    crate::bindings::wasi::blobstore::container::StreamObjectNames::from_handle(
+                        inner.handle(),
+                    ),
+                ),
+            )
+        }
+    }
+}
+impl BorrowStreamObjectNamesWrapper {
+    /// Borrows a handle passed to an exported function, without taking its ownership
+    pub fn from_ref(
+        resource: &crate::bindings::wasi::blobstore::container::StreamObjectNames,
+    ) -> Self {
+        unsafe {
+            BorrowStreamObjectNamesWrapper(
+                crate::bindings::wasi::blobstore::container::StreamObjectNames::from_handle(
+                    resource.handle(),
+                ),
+            )
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for BorrowStreamObjectNamesWrapper {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let resource = unsafe {
+            crate::bindings::wasi::blobstore::container::StreamObjectNames::from_handle(
+                self.0.take_handle(),
+            )
+        };
+        StreamObjectNames {
+            inner: Some(std::rc::Rc::new(resource)),
+            borrow_epoch: Some(crate::internal::current_borrow_epoch()),
+        }
+            .into_js(ctx)
+    }
+}
+impl Drop for BorrowStreamObjectNamesWrapper {
+    fn drop(&mut self) {
+        let _ = self.0.take_handle();
+    }
+}
+pub struct JsContainerModule;
+impl rquickjs::module::ModuleDef for JsContainerModule {
+    fn declare(decl: &rquickjs::module::Declarations) -> rquickjs::Result<()> {
+        decl.declare("Container")?;
+        decl.declare("StreamObjectNames")?;
+        Ok(())
+    }
+    fn evaluate<'js>(
+        ctx: &rquickjs::Ctx<'js>,
+        exports: &rquickjs::module::Exports<'js>,
+    ) -> rquickjs::Result<()> {
+        exports.export("Container", Container::constructor(ctx)?)?;
+        exports.export("StreamObjectNames", StreamObjectNames::constructor(ctx)?)?;
+        Ok(())
+    }
+}
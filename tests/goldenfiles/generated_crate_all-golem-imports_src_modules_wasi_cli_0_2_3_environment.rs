@@ -0,0 +1,53 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:all-golem-imports/all-golem-imports
+// wit: sha256:3ec76077ee8750a4a3d97cf8f89273803d43fba31aa02651185015b5648e2fc7
+// js all-golem-imports: sha256:1aa6d68c08d678e85c1f334bb55fc7062ce81fa0f90be1bc0d6a3965bfccc568
+
+use rquickjs::JsLifetime;
+use rquickjs::class::{JsClass, Trace};
+use std::ops::Deref;
+#[rquickjs::function]
+fn get_environment() -> crate::wrappers::JsList<
+    rquickjs::convert::List<(crate::wrappers::JsString, crate::wrappers::JsString)>,
+> {
+    let result: Vec<(String, String)> = crate::bindings::wasi::cli::environment::get_environment();
+    crate::wrappers::JsList(
+        result
+            .into_iter()
+            .map(|v| rquickjs::convert::List((
+                crate::wrappers::JsString(v.0),
+                crate::wrappers::JsString(v.1),
+            )))
+            .collect::<Vec<_>>(),
+    )
+}
+#[rquickjs::function]
+fn get_arguments() -> crate::wrappers::JsList<crate::wrappers::JsString> {
+    let result: Vec<String> = crate::bindings::wasi::cli::environment::get_arguments();
+    crate::wrappers::JsList(
+        result.into_iter().map(|v| crate::wrappers::JsString(v)).collect::<Vec<_>>(),
+    )
+}
+#[rquickjs::function]
+fn initial_cwd() -> Option<crate::wrappers::JsString> {
+    let result: Option<String> = crate::bindings::wasi::cli::environment::initial_cwd();
+    result.map(|v| crate::wrappers::JsString(v))
+}
+pub struct JsEnvironmentModule;
+impl rquickjs::module::ModuleDef for JsEnvironmentModule {
+    fn declare(decl: &rquickjs::module::Declarations) -> rquickjs::Result<()> {
+        decl.declare("getEnvironment")?;
+        decl.declare("getArguments")?;
+        decl.declare("initialCwd")?;
+        Ok(())
+    }
+    fn evaluate<'js>(
+        ctx: &rquickjs::Ctx<'js>,
+        exports: &rquickjs::module::Exports<'js>,
+    ) -> rquickjs::Result<()> {
+        exports.export("getEnvironment", js_get_environment)?;
+        exports.export("getArguments", js_get_arguments)?;
+        exports.export("initialCwd", js_initial_cwd)?;
+        Ok(())
+    }
+}
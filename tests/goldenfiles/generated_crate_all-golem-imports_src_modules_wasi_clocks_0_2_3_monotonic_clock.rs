@@ -0,0 +1,56 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:all-golem-imports/all-golem-imports
+// wit: sha256:3ec76077ee8750a4a3d97cf8f89273803d43fba31aa02651185015b5648e2fc7
+// js all-golem-imports: sha256:1aa6d68c08d678e85c1f334bb55fc7062ce81fa0f90be1bc0d6a3965bfccc568
+
+use rquickjs::JsLifetime;
+use rquickjs::class::{JsClass, Trace};
+use std::ops::Deref;
+#[rquickjs::function]
+fn now() -> crate::wrappers::BigIntWrapper<u64> {
+    let result: crate::bindings::wasi::clocks::monotonic_clock::Instant = crate::bindings::wasi::clocks::monotonic_clock::now();
+    crate::wrappers::BigIntWrapper(result)
+}
+#[rquickjs::function]
+fn resolution() -> crate::wrappers::BigIntWrapper<u64> {
+    let result: crate::bindings::wasi::clocks::monotonic_clock::Duration = crate::bindings::wasi::clocks::monotonic_clock::resolution();
+    crate::wrappers::BigIntWrapper(result)
+}
+#[rquickjs::function]
+fn subscribe_instant(
+    when: crate::wrappers::BigIntWrapper<u64>,
+) -> crate::bindings::wasi::clocks::monotonic_clock::Pollable {
+    let result: crate::bindings::wasi::clocks::monotonic_clock::Pollable = crate::bindings::wasi::clocks::monotonic_clock::subscribe_instant(
+        when.0,
+    );
+    result
+}
+#[rquickjs::function]
+fn subscribe_duration(
+    when: crate::wrappers::BigIntWrapper<u64>,
+) -> crate::bindings::wasi::clocks::monotonic_clock::Pollable {
+    let result: crate::bindings::wasi::clocks::monotonic_clock::Pollable = crate::bindings::wasi::clocks::monotonic_clock::subscribe_duration(
+        when.0,
+    );
+    result
+}
+pub struct JsMonotonicClockModule;
+impl rquickjs::module::ModuleDef for JsMonotonicClockModule {
+    fn declare(decl: &rquickjs::module::Declarations) -> rquickjs::Result<()> {
+        decl.declare("now")?;
+        decl.declare("resolution")?;
+        decl.declare("subscribeInstant")?;
+        decl.declare("subscribeDuration")?;
+        Ok(())
+    }
+    fn evaluate<'js>(
+        ctx: &rquickjs::Ctx<'js>,
+        exports: &rquickjs::module::Exports<'js>,
+    ) -> rquickjs::Result<()> {
+        exports.export("now", js_now)?;
+        exports.export("resolution", js_resolution)?;
+        exports.export("subscribeInstant", js_subscribe_instant)?;
+        exports.export("subscribeDuration", js_subscribe_duration)?;
+        Ok(())
+    }
+}
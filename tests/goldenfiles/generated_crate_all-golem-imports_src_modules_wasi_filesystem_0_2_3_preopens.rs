@@ -0,0 +1,39 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:all-golem-imports/all-golem-imports
+// wit: sha256:3ec76077ee8750a4a3d97cf8f89273803d43fba31aa02651185015b5648e2fc7
+// js all-golem-imports: sha256:1aa6d68c08d678e85c1f334bb55fc7062ce81fa0f90be1bc0d6a3965bfccc568
+
+use rquickjs::JsLifetime;
+use rquickjs::class::{JsClass, Trace};
+use std::ops::Deref;
+#[rquickjs::function]
+fn get_directories() -> crate::wrappers::JsList<
+    rquickjs::convert::List<
+        (
+            crate::bindings::wasi::filesystem::preopens::Descriptor,
+            crate::wrappers::JsString,
+        ),
+    >,
+> {
+    let result: Vec<(crate::bindings::wasi::filesystem::preopens::Descriptor, String)> = crate::bindings::wasi::filesystem::preopens::get_directories();
+    crate::wrappers::JsList(
+        result
+            .into_iter()
+            .map(|v| rquickjs::convert::List((v.0, crate::wrappers::JsString(v.1))))
+            .collect::<Vec<_>>(),
+    )
+}
+pub struct JsPreopensModule;
+impl rquickjs::module::ModuleDef for JsPreopensModule {
+    fn declare(decl: &rquickjs::module::Declarations) -> rquickjs::Result<()> {
+        decl.declare("getDirectories")?;
+        Ok(())
+    }
+    fn evaluate<'js>(
+        ctx: &rquickjs::Ctx<'js>,
+        exports: &rquickjs::module::Exports<'js>,
+    ) -> rquickjs::Result<()> {
+        exports.export("getDirectories", js_get_directories)?;
+        Ok(())
+    }
+}
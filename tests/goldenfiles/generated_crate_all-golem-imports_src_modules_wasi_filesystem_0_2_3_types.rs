@@ -0,0 +1,983 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:all-golem-imports/all-golem-imports
+// wit: sha256:3ec76077ee8750a4a3d97cf8f89273803d43fba31aa02651185015b5648e2fc7
+// js all-golem-imports: sha256:1aa6d68c08d678e85c1f334bb55fc7062ce81fa0f90be1bc0d6a3965bfccc568
+
+use rquickjs::JsLifetime;
+use rquickjs::class::{JsClass, Trace};
+use std::ops::Deref;
+#[rquickjs::function]
+fn filesystem_error_code(
+    err: crate::modules::wasi_io_0_2_3_error::BorrowErrorWrapper,
+) -> Option<crate::bindings::wasi::filesystem::types::ErrorCode> {
+    let result: Option<crate::bindings::wasi::filesystem::types::ErrorCode> = crate::bindings::wasi::filesystem::types::filesystem_error_code(
+        &err.0,
+    );
+    result.map(|v| v)
+}
+#[derive(Clone, JsLifetime, Trace)]
+pub struct Descriptor {
+    #[qjs(skip_trace = true)]
+    inner: Option<std::rc::Rc<crate::bindings::wasi::filesystem::types::Descriptor>>,
+    /// The export call epoch if the handle is only borrowed from the host
+    #[qjs(skip_trace = true)]
+    borrow_epoch: Option<usize>,
+}
+mod __impl_class_descriptor_ {
+    pub use super::*;
+    use rquickjs::{Atom, Symbol, Value};
+    impl<'js> rquickjs::class::JsClass<'js> for Descriptor {
+        const NAME: &'static str = "Descriptor";
+        type Mutable = rquickjs::class::Writable;
+        fn prototype(
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<Option<rquickjs::Object<'js>>> {
+            use rquickjs::class::impl_::MethodImplementor;
+            let proto = rquickjs::Object::new(ctx.clone())?;
+            let implementor = rquickjs::class::impl_::MethodImpl::<Self>::new();
+            (&implementor).implement(&proto)?;
+            let dispose_symbol: Symbol = ctx
+                .globals()
+                .get(crate::internal::DISPOSE_SYMBOL)?;
+            let dispose_fn: Value = proto.get("__dispose")?;
+            proto.set(dispose_symbol, dispose_fn)?;
+            Ok(Some(proto))
+        }
+        fn constructor(
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<Option<rquickjs::function::Constructor<'js>>> {
+            use rquickjs::class::impl_::ConstructorCreator;
+            let implementor = rquickjs::class::impl_::ConstructorCreate::<Self>::new();
+            (&implementor).create_constructor(ctx)
+        }
+    }
+    impl<'js> rquickjs::IntoJs<'js> for Descriptor {
+        fn into_js(
+            self,
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<rquickjs::Value<'js>> {
+            let cls = rquickjs::class::Class::<Self>::instance(ctx.clone(), self)?;
+            rquickjs::IntoJs::into_js(cls, ctx)
+        }
+    }
+    impl<'js> rquickjs::FromJs<'js> for Descriptor
+    where
+        for<'a> rquickjs::class::impl_::CloneWrapper<
+            'a,
+            Self,
+        >: rquickjs::class::impl_::CloneTrait<Self>,
+    {
+        fn from_js(
+            ctx: &rquickjs::Ctx<'js>,
+            value: rquickjs::Value<'js>,
+        ) -> rquickjs::Result<Self> {
+            use rquickjs::class::impl_::CloneTrait;
+            let value = rquickjs::class::Class::<Self>::from_js(ctx, value)?;
+            let borrow = value.try_borrow()?;
+            Ok(rquickjs::class::impl_::CloneWrapper(&*borrow).wrap_clone())
+        }
+    }
+}
+impl Descriptor {
+    /// Gets the wrapped resource, failing if it has been disposed or if it is a
+    /// borrowed handle and the call it was passed to has already returned
+    fn checked_inner(
+        &self,
+    ) -> Result<
+        &std::rc::Rc<crate::bindings::wasi::filesystem::types::Descriptor>,
+        &'static str,
+    > {
+        if self
+            .borrow_epoch
+            .is_some_and(|epoch| !crate::internal::is_borrow_active(epoch))
+        {
+            return Err(
+                "Borrowed resource handle cannot be used after the call it was passed to has returned",
+            );
+        }
+        self.inner.as_ref().ok_or("Resource has already been disposed")
+    }
+    /// Gets the wrapped resource for calling one of its methods, throwing a
+    /// `ReferenceError` if it cannot be used
+    fn resource(
+        &self,
+        ctx: &rquickjs::Ctx<'_>,
+    ) -> rquickjs::Result<&crate::bindings::wasi::filesystem::types::Descriptor> {
+        self.checked_inner()
+            .map(|inner| inner.deref())
+            .map_err(|message| rquickjs::Exception::throw_reference(ctx, message))
+    }
+    /// Releases the wrapped resource. Borrowed handles belong to the host, so they
+    /// are never dropped, only forgotten.
+    fn release(&mut self) {
+        if let Some(inner) = self.inner.take() {
+            if self.borrow_epoch.is_some() {
+                if let Ok(inner) = std::rc::Rc::try_unwrap(inner) {
+                    let _ = inner.take_handle();
+                }
+            }
+        }
+    }
+}
+impl Drop for Descriptor {
+    fn drop(&mut self) {
+        self.release();
+    }
+}
+#[rquickjs::methods(rename_all = "camelCase")]
+impl Descriptor {
+    #[qjs(constructor)]
+    pub fn new() -> Self {
+        Self {
+            inner: None,
+            borrow_epoch: None,
+        }
+    }
+    pub fn read_via_stream(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        offset: crate::wrappers::BigIntWrapper<u64>,
+    ) -> rquickjs::Result<crate::bindings::wasi::filesystem::types::InputStream> {
+        let result: Result<
+            crate::bindings::wasi::filesystem::types::InputStream,
+            crate::bindings::wasi::filesystem::types::ErrorCode,
+        > = self.resource(&ctx)?.read_via_stream(offset.0);
+        match result {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                let error: crate::bindings::wasi::filesystem::types::ErrorCode = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn write_via_stream(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        offset: crate::wrappers::BigIntWrapper<u64>,
+    ) -> rquickjs::Result<crate::bindings::wasi::filesystem::types::OutputStream> {
+        let result: Result<
+            crate::bindings::wasi::filesystem::types::OutputStream,
+            crate::bindings::wasi::filesystem::types::ErrorCode,
+        > = self.resource(&ctx)?.write_via_stream(offset.0);
+        match result {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                let error: crate::bindings::wasi::filesystem::types::ErrorCode = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn append_via_stream(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+    ) -> rquickjs::Result<crate::bindings::wasi::filesystem::types::OutputStream> {
+        let result: Result<
+            crate::bindings::wasi::filesystem::types::OutputStream,
+            crate::bindings::wasi::filesystem::types::ErrorCode,
+        > = self.resource(&ctx)?.append_via_stream();
+        match result {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                let error: crate::bindings::wasi::filesystem::types::ErrorCode = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn advise(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        offset: crate::wrappers::BigIntWrapper<u64>,
+        length: crate::wrappers::BigIntWrapper<u64>,
+        advice: crate::bindings::wasi::filesystem::types::Advice,
+    ) -> rquickjs::Result<()> {
+        let result: Result<(), crate::bindings::wasi::filesystem::types::ErrorCode> = self
+            .resource(&ctx)?
+            .advise(offset.0, length.0, advice);
+        match result {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                let error: crate::bindings::wasi::filesystem::types::ErrorCode = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn sync_data(&self, ctx: rquickjs::Ctx<'_>) -> rquickjs::Result<()> {
+        let result: Result<(), crate::bindings::wasi::filesystem::types::ErrorCode> = self
+            .resource(&ctx)?
+            .sync_data();
+        match result {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                let error: crate::bindings::wasi::filesystem::types::ErrorCode = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn get_flags(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+    ) -> rquickjs::Result<crate::bindings::wasi::filesystem::types::DescriptorFlags> {
+        let result: Result<
+            crate::bindings::wasi::filesystem::types::DescriptorFlags,
+            crate::bindings::wasi::filesystem::types::ErrorCode,
+        > = self.resource(&ctx)?.get_flags();
+        match result {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                let error: crate::bindings::wasi::filesystem::types::ErrorCode = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn get_type(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+    ) -> rquickjs::Result<crate::bindings::wasi::filesystem::types::DescriptorType> {
+        let result: Result<
+            crate::bindings::wasi::filesystem::types::DescriptorType,
+            crate::bindings::wasi::filesystem::types::ErrorCode,
+        > = self.resource(&ctx)?.get_type();
+        match result {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                let error: crate::bindings::wasi::filesystem::types::ErrorCode = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn set_size(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        size: crate::wrappers::BigIntWrapper<u64>,
+    ) -> rquickjs::Result<()> {
+        let result: Result<(), crate::bindings::wasi::filesystem::types::ErrorCode> = self
+            .resource(&ctx)?
+            .set_size(size.0);
+        match result {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                let error: crate::bindings::wasi::filesystem::types::ErrorCode = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn set_times(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        data_access_timestamp: crate::bindings::wasi::filesystem::types::NewTimestamp,
+        data_modification_timestamp: crate::bindings::wasi::filesystem::types::NewTimestamp,
+    ) -> rquickjs::Result<()> {
+        let result: Result<(), crate::bindings::wasi::filesystem::types::ErrorCode> = self
+            .resource(&ctx)?
+            .set_times(data_access_timestamp, data_modification_timestamp);
+        match result {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                let error: crate::bindings::wasi::filesystem::types::ErrorCode = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn read(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        length: crate::wrappers::BigIntWrapper<u64>,
+        offset: crate::wrappers::BigIntWrapper<u64>,
+    ) -> rquickjs::Result<rquickjs::convert::List<(crate::wrappers::UInt8Array, bool)>> {
+        let result: Result<
+            (Vec<u8>, bool),
+            crate::bindings::wasi::filesystem::types::ErrorCode,
+        > = self.resource(&ctx)?.read(length.0, offset.0);
+        match result {
+            Ok(result) => {
+                Ok(
+                    rquickjs::convert::List((
+                        crate::wrappers::UInt8Array(result.0),
+                        result.1,
+                    )),
+                )
+            }
+            Err(error) => {
+                let error: crate::bindings::wasi::filesystem::types::ErrorCode = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn write(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        buffer: crate::wrappers::UInt8Array,
+        offset: crate::wrappers::BigIntWrapper<u64>,
+    ) -> rquickjs::Result<crate::wrappers::BigIntWrapper<u64>> {
+        let result: Result<
+            crate::bindings::wasi::filesystem::types::Filesize,
+            crate::bindings::wasi::filesystem::types::ErrorCode,
+        > = self.resource(&ctx)?.write(buffer.0.as_slice(), offset.0);
+        match result {
+            Ok(result) => Ok(crate::wrappers::BigIntWrapper(result)),
+            Err(error) => {
+                let error: crate::bindings::wasi::filesystem::types::ErrorCode = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn read_directory(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+    ) -> rquickjs::Result<
+        crate::bindings::wasi::filesystem::types::DirectoryEntryStream,
+    > {
+        let result: Result<
+            crate::bindings::wasi::filesystem::types::DirectoryEntryStream,
+            crate::bindings::wasi::filesystem::types::ErrorCode,
+        > = self.resource(&ctx)?.read_directory();
+        match result {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                let error: crate::bindings::wasi::filesystem::types::ErrorCode = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn sync(&self, ctx: rquickjs::Ctx<'_>) -> rquickjs::Result<()> {
+        let result: Result<(), crate::bindings::wasi::filesystem::types::ErrorCode> = self
+            .resource(&ctx)?
+            .sync();
+        match result {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                let error: crate::bindings::wasi::filesystem::types::ErrorCode = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn create_directory_at(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        path: crate::wrappers::JsString,
+    ) -> rquickjs::Result<()> {
+        let result: Result<(), crate::bindings::wasi::filesystem::types::ErrorCode> = self
+            .resource(&ctx)?
+            .create_directory_at(path.0.as_str());
+        match result {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                let error: crate::bindings::wasi::filesystem::types::ErrorCode = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn stat(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+    ) -> rquickjs::Result<crate::bindings::wasi::filesystem::types::DescriptorStat> {
+        let result: Result<
+            crate::bindings::wasi::filesystem::types::DescriptorStat,
+            crate::bindings::wasi::filesystem::types::ErrorCode,
+        > = self.resource(&ctx)?.stat();
+        match result {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                let error: crate::bindings::wasi::filesystem::types::ErrorCode = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn stat_at(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        path_flags: crate::bindings::wasi::filesystem::types::PathFlags,
+        path: crate::wrappers::JsString,
+    ) -> rquickjs::Result<crate::bindings::wasi::filesystem::types::DescriptorStat> {
+        let result: Result<
+            crate::bindings::wasi::filesystem::types::DescriptorStat,
+            crate::bindings::wasi::filesystem::types::ErrorCode,
+        > = self.resource(&ctx)?.stat_at(path_flags, path.0.as_str());
+        match result {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                let error: crate::bindings::wasi::filesystem::types::ErrorCode = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn set_times_at(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        path_flags: crate::bindings::wasi::filesystem::types::PathFlags,
+        path: crate::wrappers::JsString,
+        data_access_timestamp: crate::bindings::wasi::filesystem::types::NewTimestamp,
+        data_modification_timestamp: crate::bindings::wasi::filesystem::types::NewTimestamp,
+    ) -> rquickjs::Result<()> {
+        let result: Result<(), crate::bindings::wasi::filesystem::types::ErrorCode> = self
+            .resource(&ctx)?
+            .set_times_at(
+                path_flags,
+                path.0.as_str(),
+                data_access_timestamp,
+                data_modification_timestamp,
+            );
+        match result {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                let error: crate::bindings::wasi::filesystem::types::ErrorCode = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn link_at(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        old_path_flags: crate::bindings::wasi::filesystem::types::PathFlags,
+        old_path: crate::wrappers::JsString,
+        new_descriptor: crate::modules::wasi_filesystem_0_2_3_types::BorrowDescriptorWrapper,
+        new_path: crate::wrappers::JsString,
+    ) -> rquickjs::Result<()> {
+        let result: Result<(), crate::bindings::wasi::filesystem::types::ErrorCode> = self
+            .resource(&ctx)?
+            .link_at(
+                old_path_flags,
+                old_path.0.as_str(),
+                &new_descriptor.0,
+                new_path.0.as_str(),
+            );
+        match result {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                let error: crate::bindings::wasi::filesystem::types::ErrorCode = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn open_at(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        path_flags: crate::bindings::wasi::filesystem::types::PathFlags,
+        path: crate::wrappers::JsString,
+        open_flags: crate::bindings::wasi::filesystem::types::OpenFlags,
+        flags: crate::bindings::wasi::filesystem::types::DescriptorFlags,
+    ) -> rquickjs::Result<crate::bindings::wasi::filesystem::types::Descriptor> {
+        let result: Result<
+            crate::bindings::wasi::filesystem::types::Descriptor,
+            crate::bindings::wasi::filesystem::types::ErrorCode,
+        > = self.resource(&ctx)?.open_at(path_flags, path.0.as_str(), open_flags, flags);
+        match result {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                let error: crate::bindings::wasi::filesystem::types::ErrorCode = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn readlink_at(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        path: crate::wrappers::JsString,
+    ) -> rquickjs::Result<crate::wrappers::JsString> {
+        let result: Result<
+            String,
+            crate::bindings::wasi::filesystem::types::ErrorCode,
+        > = self.resource(&ctx)?.readlink_at(path.0.as_str());
+        match result {
+            Ok(result) => Ok(crate::wrappers::JsString(result)),
+            Err(error) => {
+                let error: crate::bindings::wasi::filesystem::types::ErrorCode = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn remove_directory_at(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        path: crate::wrappers::JsString,
+    ) -> rquickjs::Result<()> {
+        let result: Result<(), crate::bindings::wasi::filesystem::types::ErrorCode> = self
+            .resource(&ctx)?
+            .remove_directory_at(path.0.as_str());
+        match result {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                let error: crate::bindings::wasi::filesystem::types::ErrorCode = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn rename_at(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        old_path: crate::wrappers::JsString,
+        new_descriptor: crate::modules::wasi_filesystem_0_2_3_types::BorrowDescriptorWrapper,
+        new_path: crate::wrappers::JsString,
+    ) -> rquickjs::Result<()> {
+        let result: Result<(), crate::bindings::wasi::filesystem::types::ErrorCode> = self
+            .resource(&ctx)?
+            .rename_at(old_path.0.as_str(), &new_descriptor.0, new_path.0.as_str());
+        match result {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                let error: crate::bindings::wasi::filesystem::types::ErrorCode = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn symlink_at(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        old_path: crate::wrappers::JsString,
+        new_path: crate::wrappers::JsString,
+    ) -> rquickjs::Result<()> {
+        let result: Result<(), crate::bindings::wasi::filesystem::types::ErrorCode> = self
+            .resource(&ctx)?
+            .symlink_at(old_path.0.as_str(), new_path.0.as_str());
+        match result {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                let error: crate::bindings::wasi::filesystem::types::ErrorCode = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn unlink_file_at(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        path: crate::wrappers::JsString,
+    ) -> rquickjs::Result<()> {
+        let result: Result<(), crate::bindings::wasi::filesystem::types::ErrorCode> = self
+            .resource(&ctx)?
+            .unlink_file_at(path.0.as_str());
+        match result {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                let error: crate::bindings::wasi::filesystem::types::ErrorCode = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn is_same_object(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        other: crate::modules::wasi_filesystem_0_2_3_types::BorrowDescriptorWrapper,
+    ) -> rquickjs::Result<bool> {
+        let result: bool = self.resource(&ctx)?.is_same_object(&other.0);
+        Ok(result)
+    }
+    pub fn metadata_hash(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+    ) -> rquickjs::Result<crate::bindings::wasi::filesystem::types::MetadataHashValue> {
+        let result: Result<
+            crate::bindings::wasi::filesystem::types::MetadataHashValue,
+            crate::bindings::wasi::filesystem::types::ErrorCode,
+        > = self.resource(&ctx)?.metadata_hash();
+        match result {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                let error: crate::bindings::wasi::filesystem::types::ErrorCode = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    pub fn metadata_hash_at(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+        path_flags: crate::bindings::wasi::filesystem::types::PathFlags,
+        path: crate::wrappers::JsString,
+    ) -> rquickjs::Result<crate::bindings::wasi::filesystem::types::MetadataHashValue> {
+        let result: Result<
+            crate::bindings::wasi::filesystem::types::MetadataHashValue,
+            crate::bindings::wasi::filesystem::types::ErrorCode,
+        > = self.resource(&ctx)?.metadata_hash_at(path_flags, path.0.as_str());
+        match result {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                let error: crate::bindings::wasi::filesystem::types::ErrorCode = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    #[qjs(rename = "__dispose")]
+    pub fn __dispose(&mut self) {
+        self.release();
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::wasi::filesystem::types::Descriptor {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        Descriptor {
+            inner: Some(std::rc::Rc::new(self)),
+            borrow_epoch: None,
+        }
+            .into_js(ctx)
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::wasi::filesystem::types::Descriptor {
+    fn from_js(
+        ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let wrapper = Descriptor::from_js(ctx, value)?;
+        if wrapper.borrow_epoch.is_some() {
+            return Err(rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "Descriptor",
+                message: Some(
+                    "The ownership of a borrowed resource handle cannot be transferred"
+                        .to_string(),
+                ),
+            });
+        }
+        let inner = wrapper
+            .checked_inner()
+            .map_err(|message| rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "Descriptor",
+                message: Some(message.to_string()),
+            })?;
+        unsafe {
+            Ok(
+                crate::bindings::wasi::filesystem::types::Descriptor::from_handle(
+                    inner.take_handle(),
+                ),
+            )
+        }
+    }
+}
+pub struct BorrowDescriptorWrapper(
+    pub crate::bindings::wasi::filesystem::types::Descriptor,
+);
+impl<'js> rquickjs::FromJs<'js> for BorrowDescriptorWrapper {
+    fn from_js(
+        ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let wrapper = Descriptor::from_js(ctx, value)?;
+        let inner = wrapper
+            .checked_inner()
+            .map_err(|message| rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "Descriptor",
+                message: Some(message.to_string()),
+            })?;
+        unsafe {
+            Ok(
+                BorrowDescriptorWrapper(
+                    crate::bindings::wasi::filesystem::types::Descriptor::from_handle(
+                        inner.handle(),
+                    ),
+                ),
+            )
+        }
+    }
+}
+impl BorrowDescriptorWrapper {
+    /// Borrows a handle passed to an exported function, without taking its ownership
+    pub fn from_ref(
+        resource: &crate::bindings::wasi::filesystem::types::Descriptor,
+    ) -> Self {
+        unsafe {
+            BorrowDescriptorWrapper(
+                crate::bindings::wasi::filesystem::types::Descriptor::from_handle(
+                    resource.handle(),
+                ),
+            )
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for BorrowDescriptorWrapper {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let resource = unsafe {
+            crate::bindings::wasi::filesystem::types::Descriptor::from_handle(
+                self.0.take_handle(),
+            )
+        };
+        Descriptor {
+            inner: Some(std::rc::Rc::new(resource)),
+            borrow_epoch: Some(crate::internal::current_borrow_epoch()),
+        }
+            .into_js(ctx)
+    }
+}
+impl Drop for BorrowDescriptorWrapper {
+    fn drop(&mut self) {
+        let _ = self.0.take_handle();
+    }
+}
+#[derive(Clone, JsLifetime, Trace)]
+pub struct DirectoryEntryStream {
+    #[qjs(skip_trace = true)]
+    inner: Option<
+        std::rc::Rc<crate::bindings::wasi::filesystem::types::DirectoryEntryStream>,
+    >,
+    /// The export call epoch if the handle is only borrowed from the host
+    #[qjs(skip_trace = true)]
+    borrow_epoch: Option<usize>,
+}
+mod __impl_class_directory_entry_stream_ {
+    pub use super::*;
+    use rquickjs::{Atom, Symbol, Value};
+    impl<'js> rquickjs::class::JsClass<'js> for DirectoryEntryStream {
+        const NAME: &'static str = "DirectoryEntryStream";
+        type Mutable = rquickjs::class::Writable;
+        fn prototype(
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<Option<rquickjs::Object<'js>>> {
+            use rquickjs::class::impl_::MethodImplementor;
+            let proto = rquickjs::Object::new(ctx.clone())?;
+            let implementor = rquickjs::class::impl_::MethodImpl::<Self>::new();
+            (&implementor).implement(&proto)?;
+            let dispose_symbol: Symbol = ctx
+                .globals()
+                .get(crate::internal::DISPOSE_SYMBOL)?;
+            let dispose_fn: Value = proto.get("__dispose")?;
+            proto.set(dispose_symbol, dispose_fn)?;
+            Ok(Some(proto))
+        }
+        fn constructor(
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<Option<rquickjs::function::Constructor<'js>>> {
+            use rquickjs::class::impl_::ConstructorCreator;
+            let implementor = rquickjs::class::impl_::ConstructorCreate::<Self>::new();
+            (&implementor).create_constructor(ctx)
+        }
+    }
+    impl<'js> rquickjs::IntoJs<'js> for DirectoryEntryStream {
+        fn into_js(
+            self,
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<rquickjs::Value<'js>> {
+            let cls = rquickjs::class::Class::<Self>::instance(ctx.clone(), self)?;
+            rquickjs::IntoJs::into_js(cls, ctx)
+        }
+    }
+    impl<'js> rquickjs::FromJs<'js> for DirectoryEntryStream
+    where
+        for<'a> rquickjs::class::impl_::CloneWrapper<
+            'a,
+            Self,
+        >: rquickjs::class::impl_::CloneTrait<Self>,
+    {
+        fn from_js(
+            ctx: &rquickjs::Ctx<'js>,
+            value: rquickjs::Value<'js>,
+        ) -> rquickjs::Result<Self> {
+            use rquickjs::class::impl_::CloneTrait;
+            let value = rquickjs::class::Class::<Self>::from_js(ctx, value)?;
+            let borrow = value.try_borrow()?;
+            Ok(rquickjs::class::impl_::CloneWrapper(&*borrow).wrap_clone())
+        }
+    }
+}
+impl DirectoryEntryStream {
+    /// Gets the wrapped resource, failing if it has been disposed or if it is a
+    /// borrowed handle and the call it was passed to has already returned
+    fn checked_inner(
+        &self,
+    ) -> Result<
+        &std::rc::Rc<crate::bindings::wasi::filesystem::types::DirectoryEntryStream>,
+        &'static str,
+    > {
+        if self
+            .borrow_epoch
+            .is_some_and(|epoch| !crate::internal::is_borrow_active(epoch))
+        {
+            return Err(
+                "Borrowed resource handle cannot be used after the call it was passed to has returned",
+            );
+        }
+        self.inner.as_ref().ok_or("Resource has already been disposed")
+    }
+    /// Gets the wrapped resource for calling one of its methods, throwing a
+    /// `ReferenceError` if it cannot be used
+    fn resource(
+        &self,
+        ctx: &rquickjs::Ctx<'_>,
+    ) -> rquickjs::Result<
+        &crate::bindings::wasi::filesystem::types::DirectoryEntryStream,
+    > {
+        self.checked_inner()
+            .map(|inner| inner.deref())
+            .map_err(|message| rquickjs::Exception::throw_reference(ctx, message))
+    }
+    /// Releases the wrapped resource. Borrowed handles belong to the host, so they
+    /// are never dropped, only forgotten.
+    fn release(&mut self) {
+        if let Some(inner) = self.inner.take() {
+            if self.borrow_epoch.is_some() {
+                if let Ok(inner) = std::rc::Rc::try_unwrap(inner) {
+                    let _ = inner.take_handle();
+                }
+            }
+        }
+    }
+}
+impl Drop for DirectoryEntryStream {
+    fn drop(&mut self) {
+        self.release();
+    }
+}
+#[rquickjs::methods(rename_all = "camelCase")]
+impl DirectoryEntryStream {
+    #[qjs(constructor)]
+    pub fn new() -> Self {
+        Self {
+            inner: None,
+            borrow_epoch: None,
+        }
+    }
+    pub fn read_directory_entry(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+    ) -> rquickjs::Result<
+        Option<crate::bindings::wasi::filesystem::types::DirectoryEntry>,
+    > {
+        let result: Result<
+            Option<crate::bindings::wasi::filesystem::types::DirectoryEntry>,
+            crate::bindings::wasi::filesystem::types::ErrorCode,
+        > = self.resource(&ctx)?.read_directory_entry();
+        match result {
+            Ok(result) => Ok(result.map(|v| v)),
+            Err(error) => {
+                let error: crate::bindings::wasi::filesystem::types::ErrorCode = error;
+                Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+            }
+        }
+    }
+    #[qjs(rename = "__dispose")]
+    pub fn __dispose(&mut self) {
+        self.release();
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::wasi::filesystem::types::DirectoryEntryStream {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        DirectoryEntryStream {
+            inner: Some(std::rc::Rc::new(self)),
+            borrow_epoch: None,
+        }
+            .into_js(ctx)
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::wasi::filesystem::types::DirectoryEntryStream {
+    fn from_js(
+        ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let wrapper = DirectoryEntryStream::from_js(ctx, value)?;
+        if wrapper.borrow_epoch.is_some() {
+            return Err(rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "DirectoryEntryStream",
+                message: Some(
+                    "The ownership of a borrowed resource handle cannot be transferred"
+                        .to_string(),
+                ),
+            });
+        }
+        let inner = wrapper
+            .checked_inner()
+            .map_err(|message| rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "DirectoryEntryStream",
+                message: Some(message.to_string()),
+            })?;
+        unsafe {
+            Ok(
+                crate::bindings::wasi::filesystem::types::DirectoryEntryStream::from_handle(
+                    inner.take_handle(),
+                ),
+            )
+        }
+    }
+}
+pub struct BorrowDirectoryEntryStreamWrapper(
+    pub crate::bindings::wasi::filesystem::types::DirectoryEntryStream,
+);
+impl<'js> rquickjs::FromJs<'js> for BorrowDirectoryEntryStreamWrapper {
+    fn from_js(
+        ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let wrapper = DirectoryEntryStream::from_js(ctx, value)?;
+        let inner = wrapper
+            .checked_inner()
+            .map_err(|message| rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "DirectoryEntryStream",
+                message: Some(message.to_string()),
+            })?;
+        unsafe {
+            Ok(
+                BorrowDirectoryEntryStreamWrapper(
+                    crate::bindings::wasi::filesystem::types::DirectoryEntryStream::from_handle(
+                        inner.handle(),
+                    ),
+                ),
+            )
+        }
+    }
+}
+impl BorrowDirectoryEntryStreamWrapper {
+    /// Borrows a handle passed to an exported function, without taking its ownership
+    pub fn from_ref(
+        resource: &crate::bindings::wasi::filesystem::types::DirectoryEntryStream,
+    ) -> Self {
+        unsafe {
+            BorrowDirectoryEntryStreamWrapper(
+                crate::bindings::wasi::filesystem::types::DirectoryEntryStream::from_handle(
+                    resource.handle(),
+                ),
+            )
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for BorrowDirectoryEntryStreamWrapper {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let resource = unsafe {
+            crate::bindings::wasi::filesystem::types::DirectoryEntryStream::from_handle(
+                self.0.take_handle(),
+            )
+        };
+        DirectoryEntryStream {
+            inner: Some(std::rc::Rc::new(resource)),
+            borrow_epoch: Some(crate::internal::current_borrow_epoch()),
+        }
+            .into_js(ctx)
+    }
+}
+impl Drop for BorrowDirectoryEntryStreamWrapper {
+    fn drop(&mut self) {
+        let _ = self.0.take_handle();
+    }
+}
+pub struct JsTypesModule;
+impl rquickjs::module::ModuleDef for JsTypesModule {
+    fn declare(decl: &rquickjs::module::Declarations) -> rquickjs::Result<()> {
+        decl.declare("filesystemErrorCode")?;
+        decl.declare("Descriptor")?;
+        decl.declare("DirectoryEntryStream")?;
+        Ok(())
+    }
+    fn evaluate<'js>(
+        ctx: &rquickjs::Ctx<'js>,
+        exports: &rquickjs::module::Exports<'js>,
+    ) -> rquickjs::Result<()> {
+        exports.export("filesystemErrorCode", js_filesystem_error_code)?;
+        exports.export("Descriptor", Descriptor::constructor(ctx)?)?;
+        exports.export("DirectoryEntryStream", DirectoryEntryStream::constructor(ctx)?)?;
+        Ok(())
+    }
+}
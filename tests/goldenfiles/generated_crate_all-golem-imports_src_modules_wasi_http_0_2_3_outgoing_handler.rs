@@ -0,0 +1,45 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:all-golem-imports/all-golem-imports
+// wit: sha256:3ec76077ee8750a4a3d97cf8f89273803d43fba31aa02651185015b5648e2fc7
+// js all-golem-imports: sha256:1aa6d68c08d678e85c1f334bb55fc7062ce81fa0f90be1bc0d6a3965bfccc568
+
+use rquickjs::JsLifetime;
+use rquickjs::class::{JsClass, Trace};
+use std::ops::Deref;
+#[rquickjs::function]
+fn handle(
+    ctx: rquickjs::Ctx<'_>,
+    request: crate::bindings::wasi::http::outgoing_handler::OutgoingRequest,
+    options: Option<crate::bindings::wasi::http::outgoing_handler::RequestOptions>,
+) -> rquickjs::Result<
+    crate::bindings::wasi::http::outgoing_handler::FutureIncomingResponse,
+> {
+    let result: Result<
+        crate::bindings::wasi::http::outgoing_handler::FutureIncomingResponse,
+        crate::bindings::wasi::http::outgoing_handler::ErrorCode,
+    > = crate::bindings::wasi::http::outgoing_handler::handle(
+        request,
+        options.map(|v| v),
+    );
+    match result {
+        Ok(result) => Ok(result),
+        Err(error) => {
+            let error: crate::bindings::wasi::http::types::ErrorCode = error;
+            Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+        }
+    }
+}
+pub struct JsOutgoingHandlerModule;
+impl rquickjs::module::ModuleDef for JsOutgoingHandlerModule {
+    fn declare(decl: &rquickjs::module::Declarations) -> rquickjs::Result<()> {
+        decl.declare("handle")?;
+        Ok(())
+    }
+    fn evaluate<'js>(
+        ctx: &rquickjs::Ctx<'js>,
+        exports: &rquickjs::module::Exports<'js>,
+    ) -> rquickjs::Result<()> {
+        exports.export("handle", js_handle)?;
+        Ok(())
+    }
+}
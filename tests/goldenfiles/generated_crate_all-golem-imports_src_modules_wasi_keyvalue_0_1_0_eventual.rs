@@ -0,0 +1,102 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:all-golem-imports/all-golem-imports
+// wit: sha256:3ec76077ee8750a4a3d97cf8f89273803d43fba31aa02651185015b5648e2fc7
+// js all-golem-imports: sha256:1aa6d68c08d678e85c1f334bb55fc7062ce81fa0f90be1bc0d6a3965bfccc568
+
+use rquickjs::JsLifetime;
+use rquickjs::class::{JsClass, Trace};
+use std::ops::Deref;
+#[rquickjs::function]
+fn get(
+    ctx: rquickjs::Ctx<'_>,
+    bucket: crate::modules::wasi_keyvalue_0_1_0_types::BorrowBucketWrapper,
+    key: crate::wrappers::JsString,
+) -> rquickjs::Result<Option<crate::bindings::wasi::keyvalue::eventual::IncomingValue>> {
+    let result: Result<
+        Option<crate::bindings::wasi::keyvalue::eventual::IncomingValue>,
+        crate::bindings::wasi::keyvalue::eventual::Error,
+    > = crate::bindings::wasi::keyvalue::eventual::get(&bucket.0, key.0.as_str());
+    match result {
+        Ok(result) => Ok(result.map(|v| v)),
+        Err(error) => {
+            let error: crate::bindings::wasi::keyvalue::eventual::Error = error;
+            Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+        }
+    }
+}
+#[rquickjs::function]
+fn set(
+    ctx: rquickjs::Ctx<'_>,
+    bucket: crate::modules::wasi_keyvalue_0_1_0_types::BorrowBucketWrapper,
+    key: crate::wrappers::JsString,
+    outgoing_value: crate::modules::wasi_keyvalue_0_1_0_types::BorrowOutgoingValueWrapper,
+) -> rquickjs::Result<()> {
+    let result: Result<(), crate::bindings::wasi::keyvalue::eventual::Error> = crate::bindings::wasi::keyvalue::eventual::set(
+        &bucket.0,
+        key.0.as_str(),
+        &outgoing_value.0,
+    );
+    match result {
+        Ok(result) => Ok(result),
+        Err(error) => {
+            let error: crate::bindings::wasi::keyvalue::eventual::Error = error;
+            Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+        }
+    }
+}
+#[rquickjs::function]
+fn delete(
+    ctx: rquickjs::Ctx<'_>,
+    bucket: crate::modules::wasi_keyvalue_0_1_0_types::BorrowBucketWrapper,
+    key: crate::wrappers::JsString,
+) -> rquickjs::Result<()> {
+    let result: Result<(), crate::bindings::wasi::keyvalue::eventual::Error> = crate::bindings::wasi::keyvalue::eventual::delete(
+        &bucket.0,
+        key.0.as_str(),
+    );
+    match result {
+        Ok(result) => Ok(result),
+        Err(error) => {
+            let error: crate::bindings::wasi::keyvalue::eventual::Error = error;
+            Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+        }
+    }
+}
+#[rquickjs::function]
+fn exists(
+    ctx: rquickjs::Ctx<'_>,
+    bucket: crate::modules::wasi_keyvalue_0_1_0_types::BorrowBucketWrapper,
+    key: crate::wrappers::JsString,
+) -> rquickjs::Result<bool> {
+    let result: Result<bool, crate::bindings::wasi::keyvalue::eventual::Error> = crate::bindings::wasi::keyvalue::eventual::exists(
+        &bucket.0,
+        key.0.as_str(),
+    );
+    match result {
+        Ok(result) => Ok(result),
+        Err(error) => {
+            let error: crate::bindings::wasi::keyvalue::eventual::Error = error;
+            Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+        }
+    }
+}
+pub struct JsEventualModule;
+impl rquickjs::module::ModuleDef for JsEventualModule {
+    fn declare(decl: &rquickjs::module::Declarations) -> rquickjs::Result<()> {
+        decl.declare("get")?;
+        decl.declare("set")?;
+        decl.declare("delete_")?;
+        decl.declare("exists")?;
+        Ok(())
+    }
+    fn evaluate<'js>(
+        ctx: &rquickjs::Ctx<'js>,
+        exports: &rquickjs::module::Exports<'js>,
+    ) -> rquickjs::Result<()> {
+        exports.export("get", js_get)?;
+        exports.export("set", js_set)?;
+        exports.export("delete_", js_delete)?;
+        exports.export("exists", js_exists)?;
+        Ok(())
+    }
+}
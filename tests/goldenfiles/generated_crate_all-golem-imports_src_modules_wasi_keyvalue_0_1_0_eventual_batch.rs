@@ -0,0 +1,133 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:all-golem-imports/all-golem-imports
+// wit: sha256:3ec76077ee8750a4a3d97cf8f89273803d43fba31aa02651185015b5648e2fc7
+// js all-golem-imports: sha256:1aa6d68c08d678e85c1f334bb55fc7062ce81fa0f90be1bc0d6a3965bfccc568
+
+use rquickjs::JsLifetime;
+use rquickjs::class::{JsClass, Trace};
+use std::ops::Deref;
+#[rquickjs::function]
+fn get_many(
+    ctx: rquickjs::Ctx<'_>,
+    bucket: crate::modules::wasi_keyvalue_0_1_0_types::BorrowBucketWrapper,
+    keys: crate::wrappers::JsList<crate::wrappers::JsString>,
+) -> rquickjs::Result<
+    crate::wrappers::JsList<
+        Option<crate::bindings::wasi::keyvalue::eventual_batch::IncomingValue>,
+    >,
+> {
+    let result: Result<
+        Vec<Option<crate::bindings::wasi::keyvalue::eventual_batch::IncomingValue>>,
+        crate::bindings::wasi::keyvalue::eventual_batch::Error,
+    > = crate::bindings::wasi::keyvalue::eventual_batch::get_many(
+        &bucket.0,
+        keys.0.into_iter().map(|v| v.0).collect::<Vec<_>>().as_slice(),
+    );
+    match result {
+        Ok(result) => {
+            Ok(
+                crate::wrappers::JsList(
+                    result.into_iter().map(|v| v.map(|v| v)).collect::<Vec<_>>(),
+                ),
+            )
+        }
+        Err(error) => {
+            let error: crate::bindings::wasi::keyvalue::eventual_batch::Error = error;
+            Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+        }
+    }
+}
+#[rquickjs::function]
+fn keys(
+    ctx: rquickjs::Ctx<'_>,
+    bucket: crate::modules::wasi_keyvalue_0_1_0_types::BorrowBucketWrapper,
+) -> rquickjs::Result<crate::wrappers::JsList<crate::wrappers::JsString>> {
+    let result: Result<
+        Vec<crate::bindings::wasi::keyvalue::eventual_batch::Key>,
+        crate::bindings::wasi::keyvalue::eventual_batch::Error,
+    > = crate::bindings::wasi::keyvalue::eventual_batch::keys(&bucket.0);
+    match result {
+        Ok(result) => {
+            Ok(
+                crate::wrappers::JsList(
+                    result
+                        .into_iter()
+                        .map(|v| crate::wrappers::JsString(v))
+                        .collect::<Vec<_>>(),
+                ),
+            )
+        }
+        Err(error) => {
+            let error: crate::bindings::wasi::keyvalue::eventual_batch::Error = error;
+            Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+        }
+    }
+}
+#[rquickjs::function]
+fn set_many(
+    ctx: rquickjs::Ctx<'_>,
+    bucket: crate::modules::wasi_keyvalue_0_1_0_types::BorrowBucketWrapper,
+    key_values: crate::wrappers::JsList<
+        rquickjs::convert::List<
+            (
+                crate::wrappers::JsString,
+                crate::modules::wasi_keyvalue_0_1_0_types::BorrowOutgoingValueWrapper,
+            ),
+        >,
+    >,
+) -> rquickjs::Result<()> {
+    let result: Result<(), crate::bindings::wasi::keyvalue::eventual_batch::Error> = crate::bindings::wasi::keyvalue::eventual_batch::set_many(
+        &bucket.0,
+        key_values
+            .0
+            .iter()
+            .map(|v| (v.0.0.0.clone(), &v.0.1.0))
+            .collect::<Vec<_>>()
+            .as_slice(),
+    );
+    match result {
+        Ok(result) => Ok(result),
+        Err(error) => {
+            let error: crate::bindings::wasi::keyvalue::eventual_batch::Error = error;
+            Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+        }
+    }
+}
+#[rquickjs::function]
+fn delete_many(
+    ctx: rquickjs::Ctx<'_>,
+    bucket: crate::modules::wasi_keyvalue_0_1_0_types::BorrowBucketWrapper,
+    keys: crate::wrappers::JsList<crate::wrappers::JsString>,
+) -> rquickjs::Result<()> {
+    let result: Result<(), crate::bindings::wasi::keyvalue::eventual_batch::Error> = crate::bindings::wasi::keyvalue::eventual_batch::delete_many(
+        &bucket.0,
+        keys.0.into_iter().map(|v| v.0).collect::<Vec<_>>().as_slice(),
+    );
+    match result {
+        Ok(result) => Ok(result),
+        Err(error) => {
+            let error: crate::bindings::wasi::keyvalue::eventual_batch::Error = error;
+            Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+        }
+    }
+}
+pub struct JsEventualBatchModule;
+impl rquickjs::module::ModuleDef for JsEventualBatchModule {
+    fn declare(decl: &rquickjs::module::Declarations) -> rquickjs::Result<()> {
+        decl.declare("getMany")?;
+        decl.declare("keys")?;
+        decl.declare("setMany")?;
+        decl.declare("deleteMany")?;
+        Ok(())
+    }
+    fn evaluate<'js>(
+        ctx: &rquickjs::Ctx<'js>,
+        exports: &rquickjs::module::Exports<'js>,
+    ) -> rquickjs::Result<()> {
+        exports.export("getMany", js_get_many)?;
+        exports.export("keys", js_keys)?;
+        exports.export("setMany", js_set_many)?;
+        exports.export("deleteMany", js_delete_many)?;
+        Ok(())
+    }
+}
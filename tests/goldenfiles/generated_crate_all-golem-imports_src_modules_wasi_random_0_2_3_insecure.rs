@@ -0,0 +1,38 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:all-golem-imports/all-golem-imports
+// wit: sha256:3ec76077ee8750a4a3d97cf8f89273803d43fba31aa02651185015b5648e2fc7
+// js all-golem-imports: sha256:1aa6d68c08d678e85c1f334bb55fc7062ce81fa0f90be1bc0d6a3965bfccc568
+
+use rquickjs::JsLifetime;
+use rquickjs::class::{JsClass, Trace};
+use std::ops::Deref;
+#[rquickjs::function]
+fn get_insecure_random_bytes(
+    len: crate::wrappers::BigIntWrapper<u64>,
+) -> crate::wrappers::UInt8Array {
+    let result: Vec<u8> = crate::bindings::wasi::random::insecure::get_insecure_random_bytes(
+        len.0,
+    );
+    crate::wrappers::UInt8Array(result)
+}
+#[rquickjs::function]
+fn get_insecure_random_u64() -> crate::wrappers::BigIntWrapper<u64> {
+    let result: u64 = crate::bindings::wasi::random::insecure::get_insecure_random_u64();
+    crate::wrappers::BigIntWrapper(result)
+}
+pub struct JsInsecureModule;
+impl rquickjs::module::ModuleDef for JsInsecureModule {
+    fn declare(decl: &rquickjs::module::Declarations) -> rquickjs::Result<()> {
+        decl.declare("getInsecureRandomBytes")?;
+        decl.declare("getInsecureRandomU64")?;
+        Ok(())
+    }
+    fn evaluate<'js>(
+        ctx: &rquickjs::Ctx<'js>,
+        exports: &rquickjs::module::Exports<'js>,
+    ) -> rquickjs::Result<()> {
+        exports.export("getInsecureRandomBytes", js_get_insecure_random_bytes)?;
+        exports.export("getInsecureRandomU64", js_get_insecure_random_u64)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,32 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:all-golem-imports/all-golem-imports
+// wit: sha256:3ec76077ee8750a4a3d97cf8f89273803d43fba31aa02651185015b5648e2fc7
+// js all-golem-imports: sha256:1aa6d68c08d678e85c1f334bb55fc7062ce81fa0f90be1bc0d6a3965bfccc568
+
+use rquickjs::JsLifetime;
+use rquickjs::class::{JsClass, Trace};
+use std::ops::Deref;
+#[rquickjs::function]
+fn insecure_seed() -> rquickjs::convert::List<
+    (crate::wrappers::BigIntWrapper<u64>, crate::wrappers::BigIntWrapper<u64>),
+> {
+    let result: (u64, u64) = crate::bindings::wasi::random::insecure_seed::insecure_seed();
+    rquickjs::convert::List((
+        crate::wrappers::BigIntWrapper(result.0),
+        crate::wrappers::BigIntWrapper(result.1),
+    ))
+}
+pub struct JsInsecureSeedModule;
+impl rquickjs::module::ModuleDef for JsInsecureSeedModule {
+    fn declare(decl: &rquickjs::module::Declarations) -> rquickjs::Result<()> {
+        decl.declare("insecureSeed")?;
+        Ok(())
+    }
+    fn evaluate<'js>(
+        ctx: &rquickjs::Ctx<'js>,
+        exports: &rquickjs::module::Exports<'js>,
+    ) -> rquickjs::Result<()> {
+        exports.export("insecureSeed", js_insecure_seed)?;
+        Ok(())
+    }
+}
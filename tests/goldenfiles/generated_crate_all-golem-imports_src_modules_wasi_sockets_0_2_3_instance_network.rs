@@ -0,0 +1,27 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:all-golem-imports/all-golem-imports
+// wit: sha256:3ec76077ee8750a4a3d97cf8f89273803d43fba31aa02651185015b5648e2fc7
+// js all-golem-imports: sha256:1aa6d68c08d678e85c1f334bb55fc7062ce81fa0f90be1bc0d6a3965bfccc568
+
+use rquickjs::JsLifetime;
+use rquickjs::class::{JsClass, Trace};
+use std::ops::Deref;
+#[rquickjs::function]
+fn instance_network() -> crate::bindings::wasi::sockets::instance_network::Network {
+    let result: crate::bindings::wasi::sockets::instance_network::Network = crate::bindings::wasi::sockets::instance_network::instance_network();
+    result
+}
+pub struct JsInstanceNetworkModule;
+impl rquickjs::module::ModuleDef for JsInstanceNetworkModule {
+    fn declare(decl: &rquickjs::module::Declarations) -> rquickjs::Result<()> {
+        decl.declare("instanceNetwork")?;
+        Ok(())
+    }
+    fn evaluate<'js>(
+        ctx: &rquickjs::Ctx<'js>,
+        exports: &rquickjs::module::Exports<'js>,
+    ) -> rquickjs::Result<()> {
+        exports.export("instanceNetwork", js_instance_network)?;
+        Ok(())
+    }
+}
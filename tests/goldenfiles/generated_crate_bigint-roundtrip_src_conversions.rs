@@ -0,0 +1,5 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:bigint-roundtrip/bigint-roundtrip
+// wit: sha256:c4100f6fe2994b9b3d4b908577d5287c1d52bb412e618731e7761a4285305511
+// js bigint-roundtrip: sha256:52f28302a140e0c166ffc940886300ee22f7c03def909bb75f58a75796049e02
+
@@ -0,0 +1,76 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:bigint-roundtrip/bigint-roundtrip
+// wit: sha256:c4100f6fe2994b9b3d4b908577d5287c1d52bb412e618731e7761a4285305511
+// js bigint-roundtrip: sha256:52f28302a140e0c166ffc940886300ee22f7c03def909bb75f58a75796049e02
+
+#[allow(static_mut_refs)]
+#[allow(unsafe_op_in_unsafe_fn)]
+mod bindings;
+mod assets;
+mod builtin;
+mod conversions;
+#[allow(unused)]
+mod internal;
+#[allow(unused)]
+mod modules;
+mod wrappers;
+static JS_EXPORT_MODULE_NAME: &str = "bigint-roundtrip";
+static JS_EXPORT_NAMES_VERBATIM: bool = false;
+static INTERN_FIELD_NAMES: bool = false;
+#[allow(dead_code)]
+static REPLACE_LONE_SURROGATES: bool = false;
+static MATH_RANDOM_SEED: crate::builtin::MathRandomSeed = crate::builtin::MathRandomSeed::Default;
+static DATE_CLOCK: Option<fn() -> f64> = None;
+static IMPORT_HOOKS: bool = false;
+static MOCK_IMPORTS: bool = false;
+static IMPORT_RECORDING: crate::builtin::ImportRecording = crate::builtin::ImportRecording::None;
+static CANCEL_BACKGROUND_WORK: bool = false;
+static HTTP_LIMITS: crate::builtin::HttpLimits = crate::builtin::HttpLimits::UNLIMITED;
+#[allow(dead_code)]
+static COOKIE_JAR: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE_LIMITS: crate::builtin::HttpCacheLimits = crate::builtin::HttpCacheLimits {
+    max_entries: Some(256usize),
+    max_size: Some(16777216usize),
+    max_body_size: Some(1048576usize),
+};
+#[allow(dead_code)]
+static FETCH_BASE_URL: Option<&str> = None;
+#[allow(dead_code)]
+static FETCH_GATEWAY: Option<&str> = None;
+static JS_EXPORT_MODULE: &str = include_str!("bigint-roundtrip.js");
+static JS_ADDITIONAL_MODULES: std::sync::LazyLock<
+    Vec<(&str, Box<dyn (Fn() -> String) + Send + Sync>)>,
+> = std::sync::LazyLock::new(|| { vec![] });
+static JS_MODULE_ORDER: &[&str] = &["bigint-roundtrip"];
+static JS_BUILTIN_PERMISSIONS: &[(&str, &[&str])] = &[];
+struct Component;
+impl crate::bindings::Guest for Component {
+    fn roundtrip_u64(v: u64) -> u64 {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::BigIntWrapper<u64> = crate::internal::call_js_export(
+                    "quickjs:bigint-roundtrip",
+                    0usize,
+                    &["roundtripU64"],
+                    (crate::wrappers::BigIntWrapper(v),),
+                )
+                .await;
+            result.0
+        })
+    }
+    fn roundtrip_s64(v: i64) -> i64 {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::BigIntWrapper<i64> = crate::internal::call_js_export(
+                    "quickjs:bigint-roundtrip",
+                    1usize,
+                    &["roundtripS64"],
+                    (crate::wrappers::BigIntWrapper(v),),
+                )
+                .await;
+            result.0
+        })
+    }
+}
+bindings::export!(Component with_types_in bindings);
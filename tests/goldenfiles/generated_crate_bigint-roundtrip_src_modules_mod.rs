@@ -0,0 +1,48 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:bigint-roundtrip/bigint-roundtrip
+// wit: sha256:c4100f6fe2994b9b3d4b908577d5287c1d52bb412e618731e7761a4285305511
+// js bigint-roundtrip: sha256:52f28302a140e0c166ffc940886300ee22f7c03def909bb75f58a75796049e02
+
+use rquickjs::JsLifetime;
+use rquickjs::class::{JsClass, Trace};
+use std::ops::Deref;
+const NATIVE_MODULE_NAMES: &[&str] = &[];
+pub struct NativeModuleResolver;
+impl rquickjs::loader::Resolver for NativeModuleResolver {
+    fn resolve<'js>(
+        &mut self,
+        _ctx: &rquickjs::Ctx<'js>,
+        base: &str,
+        name: &str,
+    ) -> rquickjs::Result<String> {
+        if NATIVE_MODULE_NAMES.contains(&name) {
+            Ok(name.to_string())
+        } else {
+            Err(rquickjs::Error::new_resolving(base, name))
+        }
+    }
+}
+pub struct NativeModuleLoader;
+impl rquickjs::loader::Loader for NativeModuleLoader {
+    fn load<'js>(
+        &mut self,
+        ctx: &rquickjs::Ctx<'js>,
+        name: &str,
+    ) -> rquickjs::Result<rquickjs::Module<'js, rquickjs::module::Declared>> {
+        match name {
+            _ => Err(rquickjs::Error::new_loading(name)),
+        }
+    }
+}
+pub struct JsBigintRoundtripModule;
+impl rquickjs::module::ModuleDef for JsBigintRoundtripModule {
+    fn declare(decl: &rquickjs::module::Declarations) -> rquickjs::Result<()> {
+        Ok(())
+    }
+    fn evaluate<'js>(
+        ctx: &rquickjs::Ctx<'js>,
+        exports: &rquickjs::module::Exports<'js>,
+    ) -> rquickjs::Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,5 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:borrowed-handles/borrowed-handles
+// wit: sha256:44131dc2e3018218c9da8dd7da6eb227e15d9aec9a7422e89f82bb50a4cbecb5
+// js borrowed-handles: sha256:f9074132a34814cbb3c40f75d0b443ac080a02975f74925eca06262fb935057c
+
@@ -0,0 +1,94 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:borrowed-handles/borrowed-handles
+// wit: sha256:44131dc2e3018218c9da8dd7da6eb227e15d9aec9a7422e89f82bb50a4cbecb5
+// js borrowed-handles: sha256:f9074132a34814cbb3c40f75d0b443ac080a02975f74925eca06262fb935057c
+
+#[allow(static_mut_refs)]
+#[allow(unsafe_op_in_unsafe_fn)]
+mod bindings;
+mod assets;
+mod builtin;
+mod conversions;
+#[allow(unused)]
+mod internal;
+#[allow(unused)]
+mod modules;
+mod wrappers;
+static JS_EXPORT_MODULE_NAME: &str = "borrowed-handles";
+static JS_EXPORT_NAMES_VERBATIM: bool = false;
+static INTERN_FIELD_NAMES: bool = false;
+#[allow(dead_code)]
+static REPLACE_LONE_SURROGATES: bool = false;
+static MATH_RANDOM_SEED: crate::builtin::MathRandomSeed = crate::builtin::MathRandomSeed::Default;
+static DATE_CLOCK: Option<fn() -> f64> = None;
+static IMPORT_HOOKS: bool = false;
+static MOCK_IMPORTS: bool = false;
+static IMPORT_RECORDING: crate::builtin::ImportRecording = crate::builtin::ImportRecording::None;
+static CANCEL_BACKGROUND_WORK: bool = false;
+static HTTP_LIMITS: crate::builtin::HttpLimits = crate::builtin::HttpLimits::UNLIMITED;
+#[allow(dead_code)]
+static COOKIE_JAR: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE_LIMITS: crate::builtin::HttpCacheLimits = crate::builtin::HttpCacheLimits {
+    max_entries: Some(256usize),
+    max_size: Some(16777216usize),
+    max_body_size: Some(1048576usize),
+};
+#[allow(dead_code)]
+static FETCH_BASE_URL: Option<&str> = None;
+#[allow(dead_code)]
+static FETCH_GATEWAY: Option<&str> = None;
+static JS_EXPORT_MODULE: &str = include_str!("borrowed-handles.js");
+static JS_ADDITIONAL_MODULES: std::sync::LazyLock<
+    Vec<(&str, Box<dyn (Fn() -> String) + Send + Sync>)>,
+> = std::sync::LazyLock::new(|| { vec![] });
+static JS_MODULE_ORDER: &[&str] = &["borrowed-handles"];
+static JS_BUILTIN_PERMISSIONS: &[(&str, &[&str])] = &[];
+struct Component;
+impl crate::bindings::exports::quickjs::borrowed_handles::api::Guest for Component {
+    fn peek(c: &crate::bindings::quickjs::borrowed_handles::counters::Counter) -> u32 {
+        crate::internal::async_exported_function(async move {
+            let result: u32 = crate::internal::call_js_export(
+                    "quickjs:borrowed-handles",
+                    0usize,
+                    &["api", "peek"],
+                    (
+                        crate::modules::quickjs_borrowed_handles_counters::BorrowCounterWrapper::from_ref(
+                            c,
+                        ),
+                    ),
+                )
+                .await;
+            result
+        })
+    }
+    fn peek_again() -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:borrowed-handles",
+                    1usize,
+                    &["api", "peekAgain"],
+                    crate::wrappers::JsArgs(()),
+                )
+                .await;
+            result.0
+        })
+    }
+    fn consume(
+        c: crate::bindings::exports::quickjs::borrowed_handles::api::Counter,
+    ) -> u32 {
+        crate::internal::async_exported_function(async move {
+            let result: u32 = crate::internal::call_js_export(
+                    "quickjs:borrowed-handles",
+                    2usize,
+                    &["api", "consume"],
+                    (c,),
+                )
+                .await;
+            result
+        })
+    }
+}
+bindings::export!(Component with_types_in bindings);
@@ -0,0 +1,55 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:borrowed-handles/borrowed-handles
+// wit: sha256:44131dc2e3018218c9da8dd7da6eb227e15d9aec9a7422e89f82bb50a4cbecb5
+// js borrowed-handles: sha256:f9074132a34814cbb3c40f75d0b443ac080a02975f74925eca06262fb935057c
+
+use rquickjs::JsLifetime;
+use rquickjs::class::{JsClass, Trace};
+use std::ops::Deref;
+pub mod quickjs_borrowed_handles_counters;
+const NATIVE_MODULE_NAMES: &[&str] = &["quickjs:borrowed-handles/counters"];
+pub struct NativeModuleResolver;
+impl rquickjs::loader::Resolver for NativeModuleResolver {
+    fn resolve<'js>(
+        &mut self,
+        _ctx: &rquickjs::Ctx<'js>,
+        base: &str,
+        name: &str,
+    ) -> rquickjs::Result<String> {
+        if NATIVE_MODULE_NAMES.contains(&name) {
+            Ok(name.to_string())
+        } else {
+            Err(rquickjs::Error::new_resolving(base, name))
+        }
+    }
+}
+pub struct NativeModuleLoader;
+impl rquickjs::loader::Loader for NativeModuleLoader {
+    fn load<'js>(
+        &mut self,
+        ctx: &rquickjs::Ctx<'js>,
+        name: &str,
+    ) -> rquickjs::Result<rquickjs::Module<'js, rquickjs::module::Declared>> {
+        match name {
+            "quickjs:borrowed-handles/counters" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::quickjs_borrowed_handles_counters::JsCountersModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            _ => Err(rquickjs::Error::new_loading(name)),
+        }
+    }
+}
+pub struct JsBorrowedHandlesModule;
+impl rquickjs::module::ModuleDef for JsBorrowedHandlesModule {
+    fn declare(decl: &rquickjs::module::Declarations) -> rquickjs::Result<()> {
+        Ok(())
+    }
+    fn evaluate<'js>(
+        ctx: &rquickjs::Ctx<'js>,
+        exports: &rquickjs::module::Exports<'js>,
+    ) -> rquickjs::Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,7 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:builtin-permissions/builtin-permissions
+// wit: sha256:4485e35180497639ddae8cfe68b6d701f58d865522be090e1182374ceaeee681
+// js plugin: sha256:40f560fcd31f904a2da6da367fb4e6330bc16dd64f54bafe6a8f39d52ed3516b
+// js builtin-permissions: sha256:fb300a5b3aba6444f1af3cbb95d08ebb412988a256536763edd63e5d69a393f0
+
+pub static ASSETS: &[(&str, &[u8])] = &[];
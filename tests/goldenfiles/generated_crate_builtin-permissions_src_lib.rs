@@ -0,0 +1,81 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:builtin-permissions/builtin-permissions
+// wit: sha256:4485e35180497639ddae8cfe68b6d701f58d865522be090e1182374ceaeee681
+// js plugin: sha256:40f560fcd31f904a2da6da367fb4e6330bc16dd64f54bafe6a8f39d52ed3516b
+// js builtin-permissions: sha256:fb300a5b3aba6444f1af3cbb95d08ebb412988a256536763edd63e5d69a393f0
+
+#[allow(static_mut_refs)]
+#[allow(unsafe_op_in_unsafe_fn)]
+mod bindings;
+mod assets;
+mod builtin;
+mod conversions;
+#[allow(unused)]
+mod internal;
+#[allow(unused)]
+mod modules;
+mod wrappers;
+static JS_EXPORT_MODULE_NAME: &str = "builtin-permissions";
+static JS_EXPORT_NAMES_VERBATIM: bool = false;
+static INTERN_FIELD_NAMES: bool = false;
+#[allow(dead_code)]
+static REPLACE_LONE_SURROGATES: bool = false;
+static MATH_RANDOM_SEED: crate::builtin::MathRandomSeed = crate::builtin::MathRandomSeed::Default;
+static DATE_CLOCK: Option<fn() -> f64> = None;
+static IMPORT_HOOKS: bool = false;
+static MOCK_IMPORTS: bool = false;
+static IMPORT_RECORDING: crate::builtin::ImportRecording = crate::builtin::ImportRecording::None;
+static CANCEL_BACKGROUND_WORK: bool = false;
+static HTTP_LIMITS: crate::builtin::HttpLimits = crate::builtin::HttpLimits::UNLIMITED;
+#[allow(dead_code)]
+static COOKIE_JAR: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE_LIMITS: crate::builtin::HttpCacheLimits = crate::builtin::HttpCacheLimits {
+    max_entries: Some(256usize),
+    max_size: Some(16777216usize),
+    max_body_size: Some(1048576usize),
+};
+#[allow(dead_code)]
+static FETCH_BASE_URL: Option<&str> = None;
+#[allow(dead_code)]
+static FETCH_GATEWAY: Option<&str> = None;
+static JS_EXPORT_MODULE: &str = include_str!("builtin-permissions.js");
+static JS_ADDITIONAL_MODULES: std::sync::LazyLock<
+    Vec<(&str, Box<dyn (Fn() -> String) + Send + Sync>)>,
+> = std::sync::LazyLock::new(|| {
+    vec![("plugin", Box::new(|| { include_str!("plugin.js") .to_string() }))]
+});
+static JS_MODULE_ORDER: &[&str] = &["plugin", "builtin-permissions"];
+static JS_BUILTIN_PERMISSIONS: &[(&str, &[&str])] = &[
+    ("plugin", &["buffer", "node:buffer"]),
+];
+struct Component;
+impl crate::bindings::exports::quickjs::builtin_permissions::api::Guest for Component {
+    fn entry_import(name: String) -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:builtin-permissions",
+                    0usize,
+                    &["api", "entryImport"],
+                    (crate::wrappers::JsString(name),),
+                )
+                .await;
+            result.0
+        })
+    }
+    fn plugin_import(name: String) -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:builtin-permissions",
+                    1usize,
+                    &["api", "pluginImport"],
+                    (crate::wrappers::JsString(name),),
+                )
+                .await;
+            result.0
+        })
+    }
+}
+bindings::export!(Component with_types_in bindings);
@@ -0,0 +1,49 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:builtin-permissions/builtin-permissions
+// wit: sha256:4485e35180497639ddae8cfe68b6d701f58d865522be090e1182374ceaeee681
+// js plugin: sha256:40f560fcd31f904a2da6da367fb4e6330bc16dd64f54bafe6a8f39d52ed3516b
+// js builtin-permissions: sha256:fb300a5b3aba6444f1af3cbb95d08ebb412988a256536763edd63e5d69a393f0
+
+use rquickjs::JsLifetime;
+use rquickjs::class::{JsClass, Trace};
+use std::ops::Deref;
+const NATIVE_MODULE_NAMES: &[&str] = &[];
+pub struct NativeModuleResolver;
+impl rquickjs::loader::Resolver for NativeModuleResolver {
+    fn resolve<'js>(
+        &mut self,
+        _ctx: &rquickjs::Ctx<'js>,
+        base: &str,
+        name: &str,
+    ) -> rquickjs::Result<String> {
+        if NATIVE_MODULE_NAMES.contains(&name) {
+            Ok(name.to_string())
+        } else {
+            Err(rquickjs::Error::new_resolving(base, name))
+        }
+    }
+}
+pub struct NativeModuleLoader;
+impl rquickjs::loader::Loader for NativeModuleLoader {
+    fn load<'js>(
+        &mut self,
+        ctx: &rquickjs::Ctx<'js>,
+        name: &str,
+    ) -> rquickjs::Result<rquickjs::Module<'js, rquickjs::module::Declared>> {
+        match name {
+            _ => Err(rquickjs::Error::new_loading(name)),
+        }
+    }
+}
+pub struct JsBuiltinPermissionsModule;
+impl rquickjs::module::ModuleDef for JsBuiltinPermissionsModule {
+    fn declare(decl: &rquickjs::module::Declarations) -> rquickjs::Result<()> {
+        Ok(())
+    }
+    fn evaluate<'js>(
+        ctx: &rquickjs::Ctx<'js>,
+        exports: &rquickjs::module::Exports<'js>,
+    ) -> rquickjs::Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,6 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:byte-buffers/byte-buffers
+// wit: sha256:b0744d7b93ab700d61a0cd92c6aec8133655ca920488b1eb84da85c65e528188
+// js byte-buffers: sha256:971eb68a32dcc44381bd495c1afe76412919d8eced4cda820759460258cb11a8
+
+pub static ASSETS: &[(&str, &[u8])] = &[];
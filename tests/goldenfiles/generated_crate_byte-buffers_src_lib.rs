@@ -0,0 +1,100 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:byte-buffers/byte-buffers
+// wit: sha256:b0744d7b93ab700d61a0cd92c6aec8133655ca920488b1eb84da85c65e528188
+// js byte-buffers: sha256:971eb68a32dcc44381bd495c1afe76412919d8eced4cda820759460258cb11a8
+
+#[allow(static_mut_refs)]
+#[allow(unsafe_op_in_unsafe_fn)]
+mod bindings;
+mod assets;
+mod builtin;
+mod conversions;
+#[allow(unused)]
+mod internal;
+#[allow(unused)]
+mod modules;
+mod wrappers;
+static JS_EXPORT_MODULE_NAME: &str = "byte-buffers";
+static JS_EXPORT_NAMES_VERBATIM: bool = false;
+static INTERN_FIELD_NAMES: bool = false;
+#[allow(dead_code)]
+static REPLACE_LONE_SURROGATES: bool = false;
+static MATH_RANDOM_SEED: crate::builtin::MathRandomSeed = crate::builtin::MathRandomSeed::Default;
+static DATE_CLOCK: Option<fn() -> f64> = None;
+static IMPORT_HOOKS: bool = false;
+static MOCK_IMPORTS: bool = false;
+static IMPORT_RECORDING: crate::builtin::ImportRecording = crate::builtin::ImportRecording::None;
+static CANCEL_BACKGROUND_WORK: bool = false;
+static HTTP_LIMITS: crate::builtin::HttpLimits = crate::builtin::HttpLimits::UNLIMITED;
+#[allow(dead_code)]
+static COOKIE_JAR: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE_LIMITS: crate::builtin::HttpCacheLimits = crate::builtin::HttpCacheLimits {
+    max_entries: Some(256usize),
+    max_size: Some(16777216usize),
+    max_body_size: Some(1048576usize),
+};
+#[allow(dead_code)]
+static FETCH_BASE_URL: Option<&str> = None;
+#[allow(dead_code)]
+static FETCH_GATEWAY: Option<&str> = None;
+static JS_EXPORT_MODULE: &str = include_str!("byte-buffers.js");
+static JS_ADDITIONAL_MODULES: std::sync::LazyLock<
+    Vec<(&str, Box<dyn (Fn() -> String) + Send + Sync>)>,
+> = std::sync::LazyLock::new(|| { vec![] });
+static JS_MODULE_ORDER: &[&str] = &["byte-buffers"];
+static JS_BUILTIN_PERMISSIONS: &[(&str, &[&str])] = &[];
+struct Component;
+impl crate::bindings::exports::quickjs::byte_buffers::api::Guest for Component {
+    fn echo(bytes: Vec<u8>) -> Vec<u8> {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::UInt8Array = crate::internal::call_js_export(
+                    "quickjs:byte-buffers",
+                    0usize,
+                    &["api", "echo"],
+                    (crate::wrappers::UInt8Array(bytes),),
+                )
+                .await;
+            result.0
+        })
+    }
+    fn echo_tail(bytes: Vec<u8>) -> Vec<u8> {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::UInt8Array = crate::internal::call_js_export(
+                    "quickjs:byte-buffers",
+                    1usize,
+                    &["api", "echoTail"],
+                    (crate::wrappers::UInt8Array(bytes),),
+                )
+                .await;
+            result.0
+        })
+    }
+    fn copy(bytes: Vec<u8>) -> Vec<u8> {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::UInt8Array = crate::internal::call_js_export(
+                    "quickjs:byte-buffers",
+                    2usize,
+                    &["api", "copy"],
+                    (crate::wrappers::UInt8Array(bytes),),
+                )
+                .await;
+            result.0
+        })
+    }
+    fn last_byte_length() -> u32 {
+        crate::internal::async_exported_function(async move {
+            let result: u32 = crate::internal::call_js_export(
+                    "quickjs:byte-buffers",
+                    3usize,
+                    &["api", "lastByteLength"],
+                    crate::wrappers::JsArgs(()),
+                )
+                .await;
+            result
+        })
+    }
+}
+bindings::export!(Component with_types_in bindings);
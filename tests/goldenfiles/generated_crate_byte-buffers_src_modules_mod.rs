@@ -0,0 +1,48 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:byte-buffers/byte-buffers
+// wit: sha256:b0744d7b93ab700d61a0cd92c6aec8133655ca920488b1eb84da85c65e528188
+// js byte-buffers: sha256:971eb68a32dcc44381bd495c1afe76412919d8eced4cda820759460258cb11a8
+
+use rquickjs::JsLifetime;
+use rquickjs::class::{JsClass, Trace};
+use std::ops::Deref;
+const NATIVE_MODULE_NAMES: &[&str] = &[];
+pub struct NativeModuleResolver;
+impl rquickjs::loader::Resolver for NativeModuleResolver {
+    fn resolve<'js>(
+        &mut self,
+        _ctx: &rquickjs::Ctx<'js>,
+        base: &str,
+        name: &str,
+    ) -> rquickjs::Result<String> {
+        if NATIVE_MODULE_NAMES.contains(&name) {
+            Ok(name.to_string())
+        } else {
+            Err(rquickjs::Error::new_resolving(base, name))
+        }
+    }
+}
+pub struct NativeModuleLoader;
+impl rquickjs::loader::Loader for NativeModuleLoader {
+    fn load<'js>(
+        &mut self,
+        ctx: &rquickjs::Ctx<'js>,
+        name: &str,
+    ) -> rquickjs::Result<rquickjs::Module<'js, rquickjs::module::Declared>> {
+        match name {
+            _ => Err(rquickjs::Error::new_loading(name)),
+        }
+    }
+}
+pub struct JsByteBuffersModule;
+impl rquickjs::module::ModuleDef for JsByteBuffersModule {
+    fn declare(decl: &rquickjs::module::Declarations) -> rquickjs::Result<()> {
+        Ok(())
+    }
+    fn evaluate<'js>(
+        ctx: &rquickjs::Ctx<'js>,
+        exports: &rquickjs::module::Exports<'js>,
+    ) -> rquickjs::Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,5 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:call-cancellation/call-cancellation
+// wit: sha256:2c3b11fa6f26c198e13d36cebb40d72335bc364fe7b734ed960c686f4eaf16dd
+// js call-cancellation: sha256:d32cbc9795e8e032a2b234a35422140d86d6ca45eaabe4c949ac75f23edb8a0c
+
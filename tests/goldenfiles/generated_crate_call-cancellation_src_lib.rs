@@ -0,0 +1,100 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:call-cancellation/call-cancellation
+// wit: sha256:2c3b11fa6f26c198e13d36cebb40d72335bc364fe7b734ed960c686f4eaf16dd
+// js call-cancellation: sha256:d32cbc9795e8e032a2b234a35422140d86d6ca45eaabe4c949ac75f23edb8a0c
+
+#[allow(static_mut_refs)]
+#[allow(unsafe_op_in_unsafe_fn)]
+mod bindings;
+mod assets;
+mod builtin;
+mod conversions;
+#[allow(unused)]
+mod internal;
+#[allow(unused)]
+mod modules;
+mod wrappers;
+static JS_EXPORT_MODULE_NAME: &str = "call-cancellation";
+static JS_EXPORT_NAMES_VERBATIM: bool = false;
+static INTERN_FIELD_NAMES: bool = false;
+#[allow(dead_code)]
+static REPLACE_LONE_SURROGATES: bool = false;
+static MATH_RANDOM_SEED: crate::builtin::MathRandomSeed = crate::builtin::MathRandomSeed::Default;
+static DATE_CLOCK: Option<fn() -> f64> = None;
+static IMPORT_HOOKS: bool = false;
+static MOCK_IMPORTS: bool = false;
+static IMPORT_RECORDING: crate::builtin::ImportRecording = crate::builtin::ImportRecording::None;
+static CANCEL_BACKGROUND_WORK: bool = true;
+static HTTP_LIMITS: crate::builtin::HttpLimits = crate::builtin::HttpLimits::UNLIMITED;
+#[allow(dead_code)]
+static COOKIE_JAR: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE_LIMITS: crate::builtin::HttpCacheLimits = crate::builtin::HttpCacheLimits {
+    max_entries: Some(256usize),
+    max_size: Some(16777216usize),
+    max_body_size: Some(1048576usize),
+};
+#[allow(dead_code)]
+static FETCH_BASE_URL: Option<&str> = None;
+#[allow(dead_code)]
+static FETCH_GATEWAY: Option<&str> = None;
+static JS_EXPORT_MODULE: &str = include_str!("call-cancellation.js");
+static JS_ADDITIONAL_MODULES: std::sync::LazyLock<
+    Vec<(&str, Box<dyn (Fn() -> String) + Send + Sync>)>,
+> = std::sync::LazyLock::new(|| { vec![] });
+static JS_MODULE_ORDER: &[&str] = &["call-cancellation"];
+static JS_BUILTIN_PERMISSIONS: &[(&str, &[&str])] = &[];
+struct Component;
+impl crate::bindings::exports::quickjs::call_cancellation::api::Guest for Component {
+    fn leave_timer() -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:call-cancellation",
+                    0usize,
+                    &["api", "leaveTimer"],
+                    crate::wrappers::JsArgs(()),
+                )
+                .await;
+            result.0
+        })
+    }
+    fn cancel_itself() -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:call-cancellation",
+                    1usize,
+                    &["api", "cancelItself"],
+                    crate::wrappers::JsArgs(()),
+                )
+                .await;
+            result.0
+        })
+    }
+    fn signal_per_call() -> bool {
+        crate::internal::async_exported_function(async move {
+            let result: bool = crate::internal::call_js_export(
+                    "quickjs:call-cancellation",
+                    2usize,
+                    &["api", "signalPerCall"],
+                    crate::wrappers::JsArgs(()),
+                )
+                .await;
+            result
+        })
+    }
+    fn cancel_fetch(url: String) -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:call-cancellation",
+                    3usize,
+                    &["api", "cancelFetch"],
+                    (crate::wrappers::JsString(url),),
+                )
+                .await;
+            result.0
+        })
+    }
+}
+bindings::export!(Component with_types_in bindings);
@@ -0,0 +1,6 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:codecs/codecs
+// wit: sha256:f307449766ddeb49fd6f3ceb53e53fb58a878ed53a61eb85fe0cb34dcbfd9cc8
+// js codecs: sha256:34663a860385562c30d0799363f70d3818fb42844b00a833e46749ef1f5c53e7
+
+pub static ASSETS: &[(&str, &[u8])] = &[];
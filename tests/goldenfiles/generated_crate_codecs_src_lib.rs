@@ -0,0 +1,94 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:codecs/codecs
+// wit: sha256:f307449766ddeb49fd6f3ceb53e53fb58a878ed53a61eb85fe0cb34dcbfd9cc8
+// js codecs: sha256:34663a860385562c30d0799363f70d3818fb42844b00a833e46749ef1f5c53e7
+
+#[allow(static_mut_refs)]
+#[allow(unsafe_op_in_unsafe_fn)]
+mod bindings;
+mod assets;
+mod builtin;
+mod conversions;
+#[allow(unused)]
+mod internal;
+#[allow(unused)]
+mod modules;
+mod wrappers;
+static JS_EXPORT_MODULE_NAME: &str = "codecs";
+static JS_EXPORT_NAMES_VERBATIM: bool = false;
+static INTERN_FIELD_NAMES: bool = false;
+#[allow(dead_code)]
+static REPLACE_LONE_SURROGATES: bool = false;
+static MATH_RANDOM_SEED: crate::builtin::MathRandomSeed = crate::builtin::MathRandomSeed::Default;
+static DATE_CLOCK: Option<fn() -> f64> = None;
+static IMPORT_HOOKS: bool = false;
+static MOCK_IMPORTS: bool = false;
+static IMPORT_RECORDING: crate::builtin::ImportRecording = crate::builtin::ImportRecording::None;
+static CANCEL_BACKGROUND_WORK: bool = false;
+static HTTP_LIMITS: crate::builtin::HttpLimits = crate::builtin::HttpLimits::UNLIMITED;
+#[allow(dead_code)]
+static COOKIE_JAR: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE_LIMITS: crate::builtin::HttpCacheLimits = crate::builtin::HttpCacheLimits {
+    max_entries: Some(256usize),
+    max_size: Some(16777216usize),
+    max_body_size: Some(1048576usize),
+};
+#[allow(dead_code)]
+static FETCH_BASE_URL: Option<&str> = None;
+#[allow(dead_code)]
+static FETCH_GATEWAY: Option<&str> = None;
+static JS_EXPORT_MODULE: &str = include_str!("codecs.js");
+static JS_ADDITIONAL_MODULES: std::sync::LazyLock<
+    Vec<(&str, Box<dyn (Fn() -> String) + Send + Sync>)>,
+> = std::sync::LazyLock::new(|| { vec![] });
+static JS_MODULE_ORDER: &[&str] = &["codecs"];
+static JS_BUILTIN_PERMISSIONS: &[(&str, &[&str])] = &[];
+struct Component;
+impl crate::bindings::Guest for Component {
+    fn encode_hex(format: String, json: String) -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:codecs",
+                    0usize,
+                    &["encodeHex"],
+                    crate::wrappers::JsArgs((
+                        crate::wrappers::JsString(format),
+                        crate::wrappers::JsString(json),
+                    )),
+                )
+                .await;
+            result.0
+        })
+    }
+    fn decode_hex(format: String, hex: String) -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:codecs",
+                    1usize,
+                    &["decodeHex"],
+                    crate::wrappers::JsArgs((
+                        crate::wrappers::JsString(format),
+                        crate::wrappers::JsString(hex),
+                    )),
+                )
+                .await;
+            result.0
+        })
+    }
+    fn round_trip(format: String) -> Vec<String> {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsList<crate::wrappers::JsString> = crate::internal::call_js_export(
+                    "quickjs:codecs",
+                    2usize,
+                    &["roundTrip"],
+                    (crate::wrappers::JsString(format),),
+                )
+                .await;
+            result.0.into_iter().map(|v| v.0).collect::<Vec<_>>()
+        })
+    }
+}
+bindings::export!(Component with_types_in bindings);
@@ -0,0 +1,5 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:compression/compression
+// wit: sha256:6c59e8bbac2274db62808ac40562fb159b6b92a09a36b42a27b0f2b22910709d
+// js compression: sha256:9fdf3d3affcecbc349a9ec1e961ffeef2cf91be77636c24209a7f880e13627c6
+
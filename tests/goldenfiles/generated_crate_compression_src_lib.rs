@@ -0,0 +1,96 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:compression/compression
+// wit: sha256:6c59e8bbac2274db62808ac40562fb159b6b92a09a36b42a27b0f2b22910709d
+// js compression: sha256:9fdf3d3affcecbc349a9ec1e961ffeef2cf91be77636c24209a7f880e13627c6
+
+#[allow(static_mut_refs)]
+#[allow(unsafe_op_in_unsafe_fn)]
+mod bindings;
+mod assets;
+mod builtin;
+mod conversions;
+#[allow(unused)]
+mod internal;
+#[allow(unused)]
+mod modules;
+mod wrappers;
+static JS_EXPORT_MODULE_NAME: &str = "compression";
+static JS_EXPORT_NAMES_VERBATIM: bool = false;
+static INTERN_FIELD_NAMES: bool = false;
+#[allow(dead_code)]
+static REPLACE_LONE_SURROGATES: bool = false;
+static MATH_RANDOM_SEED: crate::builtin::MathRandomSeed = crate::builtin::MathRandomSeed::Default;
+static DATE_CLOCK: Option<fn() -> f64> = None;
+static IMPORT_HOOKS: bool = false;
+static MOCK_IMPORTS: bool = false;
+static IMPORT_RECORDING: crate::builtin::ImportRecording = crate::builtin::ImportRecording::None;
+static CANCEL_BACKGROUND_WORK: bool = false;
+static HTTP_LIMITS: crate::builtin::HttpLimits = crate::builtin::HttpLimits::UNLIMITED;
+#[allow(dead_code)]
+static COOKIE_JAR: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE_LIMITS: crate::builtin::HttpCacheLimits = crate::builtin::HttpCacheLimits {
+    max_entries: Some(256usize),
+    max_size: Some(16777216usize),
+    max_body_size: Some(1048576usize),
+};
+#[allow(dead_code)]
+static FETCH_BASE_URL: Option<&str> = None;
+#[allow(dead_code)]
+static FETCH_GATEWAY: Option<&str> = None;
+static JS_EXPORT_MODULE: &str = include_str!("compression.js");
+static JS_ADDITIONAL_MODULES: std::sync::LazyLock<
+    Vec<(&str, Box<dyn (Fn() -> String) + Send + Sync>)>,
+> = std::sync::LazyLock::new(|| { vec![] });
+static JS_MODULE_ORDER: &[&str] = &["compression"];
+static JS_BUILTIN_PERMISSIONS: &[(&str, &[&str])] = &[];
+struct Component;
+impl crate::bindings::Guest for Component {
+    fn compress_hex(format: String, text: String, level: Option<i32>) -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:compression",
+                    0usize,
+                    &["compressHex"],
+                    crate::wrappers::JsArgs((
+                        crate::wrappers::JsString(format),
+                        crate::wrappers::JsString(text),
+                        level.map(|v| v),
+                    )),
+                )
+                .await;
+            result.0
+        })
+    }
+    fn decompress_hex(format: String, hex: String, max_length: Option<u32>) -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:compression",
+                    1usize,
+                    &["decompressHex"],
+                    crate::wrappers::JsArgs((
+                        crate::wrappers::JsString(format),
+                        crate::wrappers::JsString(hex),
+                        max_length.map(|v| v),
+                    )),
+                )
+                .await;
+            result.0
+        })
+    }
+    fn round_trip(format: String, size: u32) -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:compression",
+                    2usize,
+                    &["roundTrip"],
+                    crate::wrappers::JsArgs((crate::wrappers::JsString(format), size)),
+                )
+                .await;
+            result.0
+        })
+    }
+}
+bindings::export!(Component with_types_in bindings);
@@ -0,0 +1,6 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:config-formats/config-formats
+// wit: sha256:14359d0ab36111fcab5b2adec25804cc06da87f00150d87440a4de350eb99f20
+// js config-formats: sha256:ad073e28a7bfc795bf88cab863a364a515075ef33aebec5f87afebfda3de86b6
+
+pub static ASSETS: &[(&str, &[u8])] = &[];
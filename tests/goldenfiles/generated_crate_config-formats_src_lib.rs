@@ -0,0 +1,136 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:config-formats/config-formats
+// wit: sha256:14359d0ab36111fcab5b2adec25804cc06da87f00150d87440a4de350eb99f20
+// js config-formats: sha256:ad073e28a7bfc795bf88cab863a364a515075ef33aebec5f87afebfda3de86b6
+
+#[allow(static_mut_refs)]
+#[allow(unsafe_op_in_unsafe_fn)]
+mod bindings;
+mod assets;
+mod builtin;
+mod conversions;
+#[allow(unused)]
+mod internal;
+#[allow(unused)]
+mod modules;
+mod wrappers;
+static JS_EXPORT_MODULE_NAME: &str = "config-formats";
+static JS_EXPORT_NAMES_VERBATIM: bool = false;
+static INTERN_FIELD_NAMES: bool = false;
+#[allow(dead_code)]
+static REPLACE_LONE_SURROGATES: bool = false;
+static MATH_RANDOM_SEED: crate::builtin::MathRandomSeed = crate::builtin::MathRandomSeed::Default;
+static DATE_CLOCK: Option<fn() -> f64> = None;
+static IMPORT_HOOKS: bool = false;
+static MOCK_IMPORTS: bool = false;
+static IMPORT_RECORDING: crate::builtin::ImportRecording = crate::builtin::ImportRecording::None;
+static CANCEL_BACKGROUND_WORK: bool = false;
+static HTTP_LIMITS: crate::builtin::HttpLimits = crate::builtin::HttpLimits::UNLIMITED;
+#[allow(dead_code)]
+static COOKIE_JAR: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE_LIMITS: crate::builtin::HttpCacheLimits = crate::builtin::HttpCacheLimits {
+    max_entries: Some(256usize),
+    max_size: Some(16777216usize),
+    max_body_size: Some(1048576usize),
+};
+#[allow(dead_code)]
+static FETCH_BASE_URL: Option<&str> = None;
+#[allow(dead_code)]
+static FETCH_GATEWAY: Option<&str> = None;
+static JS_EXPORT_MODULE: &str = include_str!("config-formats.js");
+static JS_ADDITIONAL_MODULES: std::sync::LazyLock<
+    Vec<(&str, Box<dyn (Fn() -> String) + Send + Sync>)>,
+> = std::sync::LazyLock::new(|| { vec![] });
+static JS_MODULE_ORDER: &[&str] = &["config-formats"];
+static JS_BUILTIN_PERMISSIONS: &[(&str, &[&str])] = &[];
+struct Component;
+impl crate::bindings::Guest for Component {
+    fn toml_to_json(text: String) -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:config-formats",
+                    0usize,
+                    &["tomlToJson"],
+                    (crate::wrappers::JsString(text),),
+                )
+                .await;
+            result.0
+        })
+    }
+    fn json_to_toml(json: String) -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:config-formats",
+                    1usize,
+                    &["jsonToToml"],
+                    (crate::wrappers::JsString(json),),
+                )
+                .await;
+            result.0
+        })
+    }
+    fn yaml_to_json(text: String) -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:config-formats",
+                    2usize,
+                    &["yamlToJson"],
+                    (crate::wrappers::JsString(text),),
+                )
+                .await;
+            result.0
+        })
+    }
+    fn yaml_documents(text: String) -> Vec<String> {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsList<crate::wrappers::JsString> = crate::internal::call_js_export(
+                    "quickjs:config-formats",
+                    3usize,
+                    &["yamlDocuments"],
+                    (crate::wrappers::JsString(text),),
+                )
+                .await;
+            result.0.into_iter().map(|v| v.0).collect::<Vec<_>>()
+        })
+    }
+    fn json_to_yaml(json: String) -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:config-formats",
+                    4usize,
+                    &["jsonToYaml"],
+                    (crate::wrappers::JsString(json),),
+                )
+                .await;
+            result.0
+        })
+    }
+    fn round_trip() -> Vec<String> {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsList<crate::wrappers::JsString> = crate::internal::call_js_export(
+                    "quickjs:config-formats",
+                    5usize,
+                    &["roundTrip"],
+                    crate::wrappers::JsArgs(()),
+                )
+                .await;
+            result.0.into_iter().map(|v| v.0).collect::<Vec<_>>()
+        })
+    }
+    fn errors() -> Vec<String> {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsList<crate::wrappers::JsString> = crate::internal::call_js_export(
+                    "quickjs:config-formats",
+                    6usize,
+                    &["errors"],
+                    crate::wrappers::JsArgs(()),
+                )
+                .await;
+            result.0.into_iter().map(|v| v.0).collect::<Vec<_>>()
+        })
+    }
+}
+bindings::export!(Component with_types_in bindings);
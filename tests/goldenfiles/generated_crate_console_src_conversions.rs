@@ -0,0 +1,5 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:console/console
+// wit: sha256:f151c94359b908b01a7e2a9851002559516ce82bf9d03329c4f62b3a22919f1f
+// js console: sha256:48bf38dfddfa10b4add89bf0a9c266744848db183258b9a378db71735d8c5ee8
+
@@ -0,0 +1,5 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:cookie-jar/cookie-jar
+// wit: sha256:bfd54239329fd2ba781065e0a34a657c57d75f387b13965a60c64ab3ed3528c9
+// js cookie-jar: sha256:5b8ab1c2862d9ba65d9dd17251656aa1e0ff4832a8410983758497ebbdc8f5a1
+
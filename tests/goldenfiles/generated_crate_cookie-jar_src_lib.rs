@@ -0,0 +1,82 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:cookie-jar/cookie-jar
+// wit: sha256:bfd54239329fd2ba781065e0a34a657c57d75f387b13965a60c64ab3ed3528c9
+// js cookie-jar: sha256:5b8ab1c2862d9ba65d9dd17251656aa1e0ff4832a8410983758497ebbdc8f5a1
+
+#[allow(static_mut_refs)]
+#[allow(unsafe_op_in_unsafe_fn)]
+mod bindings;
+mod assets;
+mod builtin;
+mod conversions;
+#[allow(unused)]
+mod internal;
+#[allow(unused)]
+mod modules;
+mod wrappers;
+static JS_EXPORT_MODULE_NAME: &str = "cookie-jar";
+static JS_EXPORT_NAMES_VERBATIM: bool = false;
+static INTERN_FIELD_NAMES: bool = false;
+#[allow(dead_code)]
+static REPLACE_LONE_SURROGATES: bool = false;
+static MATH_RANDOM_SEED: crate::builtin::MathRandomSeed = crate::builtin::MathRandomSeed::Default;
+static DATE_CLOCK: Option<fn() -> f64> = None;
+static IMPORT_HOOKS: bool = false;
+static MOCK_IMPORTS: bool = false;
+static IMPORT_RECORDING: crate::builtin::ImportRecording = crate::builtin::ImportRecording::None;
+static CANCEL_BACKGROUND_WORK: bool = false;
+static HTTP_LIMITS: crate::builtin::HttpLimits = crate::builtin::HttpLimits::UNLIMITED;
+#[allow(dead_code)]
+static COOKIE_JAR: bool = true;
+#[allow(dead_code)]
+static HTTP_CACHE: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE_LIMITS: crate::builtin::HttpCacheLimits = crate::builtin::HttpCacheLimits {
+    max_entries: Some(256usize),
+    max_size: Some(16777216usize),
+    max_body_size: Some(1048576usize),
+};
+#[allow(dead_code)]
+static FETCH_BASE_URL: Option<&str> = None;
+#[allow(dead_code)]
+static FETCH_GATEWAY: Option<&str> = None;
+static JS_EXPORT_MODULE: &str = include_str!("cookie-jar.js");
+static JS_ADDITIONAL_MODULES: std::sync::LazyLock<
+    Vec<(&str, Box<dyn (Fn() -> String) + Send + Sync>)>,
+> = std::sync::LazyLock::new(|| { vec![] });
+static JS_MODULE_ORDER: &[&str] = &["cookie-jar"];
+static JS_BUILTIN_PERMISSIONS: &[(&str, &[&str])] = &[];
+struct Component;
+impl crate::bindings::Guest for Component {
+    fn visit(url: String, credentials: String) -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:cookie-jar",
+                    0usize,
+                    &["visit"],
+                    crate::wrappers::JsArgs((
+                        crate::wrappers::JsString(url),
+                        crate::wrappers::JsString(credentials),
+                    )),
+                )
+                .await;
+            result.0
+        })
+    }
+    fn visit_with_cookie(url: String, cookie: String) -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:cookie-jar",
+                    1usize,
+                    &["visitWithCookie"],
+                    crate::wrappers::JsArgs((
+                        crate::wrappers::JsString(url),
+                        crate::wrappers::JsString(cookie),
+                    )),
+                )
+                .await;
+            result.0
+        })
+    }
+}
+bindings::export!(Component with_types_in bindings);
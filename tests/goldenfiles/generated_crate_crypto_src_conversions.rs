@@ -0,0 +1,5 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:crypto/crypto
+// wit: sha256:ec77b117e15693650db63c56521441c57e396c69db4aeec2508f3f476c6fe992
+// js crypto: sha256:05f8013cf2438c6b918c46962c344381f6768a48dcef73f90aeb5fdbdb024819
+
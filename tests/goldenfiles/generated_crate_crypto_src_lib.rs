@@ -0,0 +1,90 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:crypto/crypto
+// wit: sha256:ec77b117e15693650db63c56521441c57e396c69db4aeec2508f3f476c6fe992
+// js crypto: sha256:05f8013cf2438c6b918c46962c344381f6768a48dcef73f90aeb5fdbdb024819
+
+#[allow(static_mut_refs)]
+#[allow(unsafe_op_in_unsafe_fn)]
+mod bindings;
+mod assets;
+mod builtin;
+mod conversions;
+#[allow(unused)]
+mod internal;
+#[allow(unused)]
+mod modules;
+mod wrappers;
+static JS_EXPORT_MODULE_NAME: &str = "crypto";
+static JS_EXPORT_NAMES_VERBATIM: bool = false;
+static INTERN_FIELD_NAMES: bool = false;
+#[allow(dead_code)]
+static REPLACE_LONE_SURROGATES: bool = false;
+static MATH_RANDOM_SEED: crate::builtin::MathRandomSeed = crate::builtin::MathRandomSeed::Default;
+static DATE_CLOCK: Option<fn() -> f64> = None;
+static IMPORT_HOOKS: bool = false;
+static MOCK_IMPORTS: bool = false;
+static IMPORT_RECORDING: crate::builtin::ImportRecording = crate::builtin::ImportRecording::None;
+static CANCEL_BACKGROUND_WORK: bool = false;
+static HTTP_LIMITS: crate::builtin::HttpLimits = crate::builtin::HttpLimits::UNLIMITED;
+#[allow(dead_code)]
+static COOKIE_JAR: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE_LIMITS: crate::builtin::HttpCacheLimits = crate::builtin::HttpCacheLimits {
+    max_entries: Some(256usize),
+    max_size: Some(16777216usize),
+    max_body_size: Some(1048576usize),
+};
+#[allow(dead_code)]
+static FETCH_BASE_URL: Option<&str> = None;
+#[allow(dead_code)]
+static FETCH_GATEWAY: Option<&str> = None;
+static JS_EXPORT_MODULE: &str = include_str!("crypto.js");
+static JS_ADDITIONAL_MODULES: std::sync::LazyLock<
+    Vec<(&str, Box<dyn (Fn() -> String) + Send + Sync>)>,
+> = std::sync::LazyLock::new(|| { vec![] });
+static JS_MODULE_ORDER: &[&str] = &["crypto"];
+static JS_BUILTIN_PERMISSIONS: &[(&str, &[&str])] = &[];
+struct Component;
+impl crate::bindings::Guest for Component {
+    fn new_uuids() -> (String, String) {
+        crate::internal::async_exported_function(async move {
+            let result: rquickjs::convert::List<
+                (crate::wrappers::JsString, crate::wrappers::JsString),
+            > = crate::internal::call_js_export(
+                    "quickjs:crypto",
+                    0usize,
+                    &["newUuids"],
+                    crate::wrappers::JsArgs(()),
+                )
+                .await;
+            (result.0.0.0.clone(), result.0.1.0.clone())
+        })
+    }
+    fn random_s8(count: u32) -> Vec<i8> {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsList<i8> = crate::internal::call_js_export(
+                    "quickjs:crypto",
+                    1usize,
+                    &["randomS8"],
+                    (count,),
+                )
+                .await;
+            result.0.into_iter().map(|v| v).collect::<Vec<_>>()
+        })
+    }
+    fn random_u32(count: u32) -> Vec<u32> {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsList<u32> = crate::internal::call_js_export(
+                    "quickjs:crypto",
+                    2usize,
+                    &["randomU32"],
+                    (count,),
+                )
+                .await;
+            result.0.into_iter().map(|v| v).collect::<Vec<_>>()
+        })
+    }
+}
+bindings::export!(Component with_types_in bindings);
@@ -0,0 +1,6 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:csv/csv
+// wit: sha256:2d3e97f0573d80a338b6bf82a3ed57b6c909c9cbf630fe099a26cdc706c542ba
+// js csv: sha256:103537f0daa75f6f5e63bbe297719dc347e782263968b7fda612d7fdc4d78e32
+
+pub static ASSETS: &[(&str, &[u8])] = &[];
@@ -0,0 +1,138 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:csv/csv
+// wit: sha256:2d3e97f0573d80a338b6bf82a3ed57b6c909c9cbf630fe099a26cdc706c542ba
+// js csv: sha256:103537f0daa75f6f5e63bbe297719dc347e782263968b7fda612d7fdc4d78e32
+
+#[allow(static_mut_refs)]
+#[allow(unsafe_op_in_unsafe_fn)]
+mod bindings;
+mod assets;
+mod builtin;
+mod conversions;
+#[allow(unused)]
+mod internal;
+#[allow(unused)]
+mod modules;
+mod wrappers;
+static JS_EXPORT_MODULE_NAME: &str = "csv";
+static JS_EXPORT_NAMES_VERBATIM: bool = false;
+static INTERN_FIELD_NAMES: bool = false;
+#[allow(dead_code)]
+static REPLACE_LONE_SURROGATES: bool = false;
+static MATH_RANDOM_SEED: crate::builtin::MathRandomSeed = crate::builtin::MathRandomSeed::Default;
+static DATE_CLOCK: Option<fn() -> f64> = None;
+static IMPORT_HOOKS: bool = false;
+static MOCK_IMPORTS: bool = false;
+static IMPORT_RECORDING: crate::builtin::ImportRecording = crate::builtin::ImportRecording::None;
+static CANCEL_BACKGROUND_WORK: bool = false;
+static HTTP_LIMITS: crate::builtin::HttpLimits = crate::builtin::HttpLimits::UNLIMITED;
+#[allow(dead_code)]
+static COOKIE_JAR: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE_LIMITS: crate::builtin::HttpCacheLimits = crate::builtin::HttpCacheLimits {
+    max_entries: Some(256usize),
+    max_size: Some(16777216usize),
+    max_body_size: Some(1048576usize),
+};
+#[allow(dead_code)]
+static FETCH_BASE_URL: Option<&str> = None;
+#[allow(dead_code)]
+static FETCH_GATEWAY: Option<&str> = None;
+static JS_EXPORT_MODULE: &str = include_str!("csv.js");
+static JS_ADDITIONAL_MODULES: std::sync::LazyLock<
+    Vec<(&str, Box<dyn (Fn() -> String) + Send + Sync>)>,
+> = std::sync::LazyLock::new(|| { vec![] });
+static JS_MODULE_ORDER: &[&str] = &["csv"];
+static JS_BUILTIN_PERMISSIONS: &[(&str, &[&str])] = &[];
+struct Component;
+impl crate::bindings::Guest for Component {
+    fn total_sales(chunks: Vec<Vec<u8>>) -> (u32, f64) {
+        crate::internal::async_exported_function(async move {
+            let result: rquickjs::convert::List<(u32, f64)> = crate::internal::call_js_export(
+                    "quickjs:csv",
+                    0usize,
+                    &["totalSales"],
+                    (
+                        crate::wrappers::JsList(
+                            chunks
+                                .into_iter()
+                                .map(|v| crate::wrappers::UInt8Array(v))
+                                .collect::<Vec<_>>(),
+                        ),
+                    ),
+                )
+                .await;
+            (result.0.0, result.0.1)
+        })
+    }
+    fn parse_table(text: String, delimiter: String) -> Vec<Vec<String>> {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsList<
+                crate::wrappers::JsList<crate::wrappers::JsString>,
+            > = crate::internal::call_js_export(
+                    "quickjs:csv",
+                    1usize,
+                    &["parseTable"],
+                    crate::wrappers::JsArgs((
+                        crate::wrappers::JsString(text),
+                        crate::wrappers::JsString(delimiter),
+                    )),
+                )
+                .await;
+            result
+                .0
+                .into_iter()
+                .map(|v| v.0.into_iter().map(|v| v.0).collect::<Vec<_>>())
+                .collect::<Vec<_>>()
+        })
+    }
+    fn report(products: Vec<(String, f64)>) -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:csv",
+                    2usize,
+                    &["report"],
+                    (
+                        crate::wrappers::JsList(
+                            products
+                                .into_iter()
+                                .map(|v| rquickjs::convert::List((
+                                    crate::wrappers::JsString(v.0),
+                                    v.1,
+                                )))
+                                .collect::<Vec<_>>(),
+                        ),
+                    ),
+                )
+                .await;
+            result.0
+        })
+    }
+    fn round_trip(text: String) -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:csv",
+                    3usize,
+                    &["roundTrip"],
+                    (crate::wrappers::JsString(text),),
+                )
+                .await;
+            result.0
+        })
+    }
+    fn errors() -> Vec<String> {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsList<crate::wrappers::JsString> = crate::internal::call_js_export(
+                    "quickjs:csv",
+                    4usize,
+                    &["errors"],
+                    crate::wrappers::JsArgs(()),
+                )
+                .await;
+            result.0.into_iter().map(|v| v.0).collect::<Vec<_>>()
+        })
+    }
+}
+bindings::export!(Component with_types_in bindings);
@@ -0,0 +1,6 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:decimals/decimals
+// wit: sha256:29dd8e1abe0b85347997eda142ce6c7376ea40fb53cf6a82d3b248c0e746ad8d
+// js decimals: sha256:270b4ee1c87acc3c574e701d2b303dc5ec38cd4440139d98496f6f37971f7ceb
+
+pub static ASSETS: &[(&str, &[u8])] = &[];
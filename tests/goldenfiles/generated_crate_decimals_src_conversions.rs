@@ -0,0 +1,53 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:decimals/decimals
+// wit: sha256:29dd8e1abe0b85347997eda142ce6c7376ea40fb53cf6a82d3b248c0e746ad8d
+// js decimals: sha256:270b4ee1c87acc3c574e701d2b303dc5ec38cd4440139d98496f6f37971f7ceb
+
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::quickjs::decimals::types::LineItem {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("name", crate::wrappers::JsString(self.name))?;
+        obj.set::<
+                _,
+                crate::wrappers::DecimalWrapper,
+            >("price", crate::wrappers::DecimalWrapper(self.price))?;
+        obj.set::<_, u32>("quantity", self.quantity)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::quickjs::decimals::types::LineItem {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT line-item",
+            &["name", "price", "quantity"],
+            &[("name", "string"), ("price", "money"), ("quantity", "u32")],
+            false,
+        )?;
+        let name: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "name",
+            "string",
+        )?;
+        let name: String = name.0;
+        let price: crate::wrappers::DecimalWrapper = crate::wrappers::get_field(
+            &obj,
+            "price",
+            "money",
+        )?;
+        let price: crate::bindings::quickjs::decimals::types::Money = price.0;
+        let quantity: u32 = crate::wrappers::get_field(&obj, "quantity", "u32")?;
+        let quantity: u32 = quantity;
+        Ok(Self { name, price, quantity })
+    }
+}
@@ -0,0 +1,135 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:decimals/decimals
+// wit: sha256:29dd8e1abe0b85347997eda142ce6c7376ea40fb53cf6a82d3b248c0e746ad8d
+// js decimals: sha256:270b4ee1c87acc3c574e701d2b303dc5ec38cd4440139d98496f6f37971f7ceb
+
+#[allow(static_mut_refs)]
+#[allow(unsafe_op_in_unsafe_fn)]
+mod bindings;
+mod assets;
+mod builtin;
+mod conversions;
+#[allow(unused)]
+mod internal;
+#[allow(unused)]
+mod modules;
+mod wrappers;
+static JS_EXPORT_MODULE_NAME: &str = "decimals";
+static JS_EXPORT_NAMES_VERBATIM: bool = false;
+static INTERN_FIELD_NAMES: bool = false;
+#[allow(dead_code)]
+static REPLACE_LONE_SURROGATES: bool = false;
+static MATH_RANDOM_SEED: crate::builtin::MathRandomSeed = crate::builtin::MathRandomSeed::Default;
+static DATE_CLOCK: Option<fn() -> f64> = None;
+static IMPORT_HOOKS: bool = false;
+static MOCK_IMPORTS: bool = false;
+static IMPORT_RECORDING: crate::builtin::ImportRecording = crate::builtin::ImportRecording::None;
+static CANCEL_BACKGROUND_WORK: bool = false;
+static HTTP_LIMITS: crate::builtin::HttpLimits = crate::builtin::HttpLimits::UNLIMITED;
+#[allow(dead_code)]
+static COOKIE_JAR: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE_LIMITS: crate::builtin::HttpCacheLimits = crate::builtin::HttpCacheLimits {
+    max_entries: Some(256usize),
+    max_size: Some(16777216usize),
+    max_body_size: Some(1048576usize),
+};
+#[allow(dead_code)]
+static FETCH_BASE_URL: Option<&str> = None;
+#[allow(dead_code)]
+static FETCH_GATEWAY: Option<&str> = None;
+static JS_EXPORT_MODULE: &str = include_str!("decimals.js");
+static JS_ADDITIONAL_MODULES: std::sync::LazyLock<
+    Vec<(&str, Box<dyn (Fn() -> String) + Send + Sync>)>,
+> = std::sync::LazyLock::new(|| { vec![] });
+static JS_MODULE_ORDER: &[&str] = &["decimals"];
+static JS_BUILTIN_PERMISSIONS: &[(&str, &[&str])] = &[];
+struct Component;
+impl crate::bindings::Guest for Component {
+    fn total(items: Vec<crate::bindings::LineItem>) -> crate::bindings::Money {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::DecimalWrapper = crate::internal::call_js_export(
+                    "quickjs:decimals",
+                    0usize,
+                    &["total"],
+                    (
+                        crate::wrappers::JsList(
+                            items.into_iter().map(|v| v).collect::<Vec<_>>(),
+                        ),
+                    ),
+                )
+                .await;
+            result.0
+        })
+    }
+    fn with_tax(
+        amount: crate::bindings::Money,
+        rate: crate::bindings::Money,
+    ) -> crate::bindings::Money {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::DecimalWrapper = crate::internal::call_js_export(
+                    "quickjs:decimals",
+                    1usize,
+                    &["withTax"],
+                    crate::wrappers::JsArgs((
+                        crate::wrappers::DecimalWrapper(amount),
+                        crate::wrappers::DecimalWrapper(rate),
+                    )),
+                )
+                .await;
+            result.0
+        })
+    }
+    fn split(
+        amount: crate::bindings::Money,
+        shares: u32,
+    ) -> Vec<crate::bindings::Money> {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsList<crate::wrappers::DecimalWrapper> = crate::internal::call_js_export(
+                    "quickjs:decimals",
+                    2usize,
+                    &["split"],
+                    crate::wrappers::JsArgs((
+                        crate::wrappers::DecimalWrapper(amount),
+                        shares,
+                    )),
+                )
+                .await;
+            result.0.into_iter().map(|v| v.0).collect::<Vec<_>>()
+        })
+    }
+    fn largest(amounts: Vec<crate::bindings::Money>) -> Option<crate::bindings::Money> {
+        crate::internal::async_exported_function(async move {
+            let result: Option<crate::wrappers::DecimalWrapper> = crate::internal::call_js_export(
+                    "quickjs:decimals",
+                    3usize,
+                    &["largest"],
+                    (
+                        crate::wrappers::JsList(
+                            amounts
+                                .into_iter()
+                                .map(|v| crate::wrappers::DecimalWrapper(v))
+                                .collect::<Vec<_>>(),
+                        ),
+                    ),
+                )
+                .await;
+            result.map(|v| v.0)
+        })
+    }
+    fn divide_by_zero(amount: crate::bindings::Money) -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:decimals",
+                    4usize,
+                    &["divideByZero"],
+                    (crate::wrappers::DecimalWrapper(amount),),
+                )
+                .await;
+            result.0
+        })
+    }
+}
+bindings::export!(Component with_types_in bindings);
@@ -0,0 +1,55 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:decimals/decimals
+// wit: sha256:29dd8e1abe0b85347997eda142ce6c7376ea40fb53cf6a82d3b248c0e746ad8d
+// js decimals: sha256:270b4ee1c87acc3c574e701d2b303dc5ec38cd4440139d98496f6f37971f7ceb
+
+use rquickjs::JsLifetime;
+use rquickjs::class::{JsClass, Trace};
+use std::ops::Deref;
+pub mod quickjs_decimals_types;
+const NATIVE_MODULE_NAMES: &[&str] = &["quickjs:decimals/types"];
+pub struct NativeModuleResolver;
+impl rquickjs::loader::Resolver for NativeModuleResolver {
+    fn resolve<'js>(
+        &mut self,
+        _ctx: &rquickjs::Ctx<'js>,
+        base: &str,
+        name: &str,
+    ) -> rquickjs::Result<String> {
+        if NATIVE_MODULE_NAMES.contains(&name) {
+            Ok(name.to_string())
+        } else {
+            Err(rquickjs::Error::new_resolving(base, name))
+        }
+    }
+}
+pub struct NativeModuleLoader;
+impl rquickjs::loader::Loader for NativeModuleLoader {
+    fn load<'js>(
+        &mut self,
+        ctx: &rquickjs::Ctx<'js>,
+        name: &str,
+    ) -> rquickjs::Result<rquickjs::Module<'js, rquickjs::module::Declared>> {
+        match name {
+            "quickjs:decimals/types" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::quickjs_decimals_types::JsTypesModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            _ => Err(rquickjs::Error::new_loading(name)),
+        }
+    }
+}
+pub struct JsDecimalsModule;
+impl rquickjs::module::ModuleDef for JsDecimalsModule {
+    fn declare(decl: &rquickjs::module::Declarations) -> rquickjs::Result<()> {
+        Ok(())
+    }
+    fn evaluate<'js>(
+        ctx: &rquickjs::Ctx<'js>,
+        exports: &rquickjs::module::Exports<'js>,
+    ) -> rquickjs::Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,5 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:denied-imports/denied-imports
+// wit: sha256:8064a397f806e1fb91160569cf35339ea24128cef90b8f324214ecbc03b4a583
+// js denied-imports: sha256:57e684682186d2c0f3770af1deadc47b6d14c0fcacd96140a1161ab257be5aa1
+
@@ -0,0 +1,55 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:denied-imports/denied-imports
+// wit: sha256:8064a397f806e1fb91160569cf35339ea24128cef90b8f324214ecbc03b4a583
+// js denied-imports: sha256:57e684682186d2c0f3770af1deadc47b6d14c0fcacd96140a1161ab257be5aa1
+
+use rquickjs::JsLifetime;
+use rquickjs::class::{JsClass, Trace};
+use std::ops::Deref;
+const NATIVE_MODULE_NAMES: &[&str] = &["wasi:random/random@0.2.3"];
+pub struct NativeModuleResolver;
+impl rquickjs::loader::Resolver for NativeModuleResolver {
+    fn resolve<'js>(
+        &mut self,
+        _ctx: &rquickjs::Ctx<'js>,
+        base: &str,
+        name: &str,
+    ) -> rquickjs::Result<String> {
+        if NATIVE_MODULE_NAMES.contains(&name) {
+            Ok(name.to_string())
+        } else {
+            Err(rquickjs::Error::new_resolving(base, name))
+        }
+    }
+}
+pub struct NativeModuleLoader;
+impl rquickjs::loader::Loader for NativeModuleLoader {
+    fn load<'js>(
+        &mut self,
+        ctx: &rquickjs::Ctx<'js>,
+        name: &str,
+    ) -> rquickjs::Result<rquickjs::Module<'js, rquickjs::module::Declared>> {
+        match name {
+            "wasi:random/random@0.2.3" => {
+                rquickjs::Module::declare(
+                    ctx.clone(),
+                    name,
+                    "throw new Error(\"Importing the wasi:random/random@0.2.3 interface is denied by the capability policy of the component\");",
+                )
+            }
+            _ => Err(rquickjs::Error::new_loading(name)),
+        }
+    }
+}
+pub struct JsDeniedImportsModule;
+impl rquickjs::module::ModuleDef for JsDeniedImportsModule {
+    fn declare(decl: &rquickjs::module::Declarations) -> rquickjs::Result<()> {
+        Ok(())
+    }
+    fn evaluate<'js>(
+        ctx: &rquickjs::Ctx<'js>,
+        exports: &rquickjs::module::Exports<'js>,
+    ) -> rquickjs::Result<()> {
+        Ok(())
+    }
+}
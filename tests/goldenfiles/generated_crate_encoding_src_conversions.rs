@@ -0,0 +1,5 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:encoding/encoding
+// wit: sha256:81acc88a00756cd4a7fcb55d4f0071927926a09e90af362cc28eec033c1c0ea9
+// js encoding: sha256:dd713e94ecd8497b6cf4f57cbdba5f598e6996f78f0f968338ff3f9392cd36dd
+
@@ -0,0 +1,57 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:enum-constants/enum-constants
+// wit: sha256:0832cec2e3fa69df3985a31a1798ba9d4a363df8664e63f5488b3d07bd2ead6a
+// js enum-constants: sha256:0873b620109d8cfec7fda9f8f02ccb362d9db44e53f1b1c986d867dba2ed5832
+
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::quickjs::enum_constants::types::LogLevel {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::quickjs::enum_constants::types::LogLevel::Debug => {
+                "debug".into_js(ctx)
+            }
+            crate::bindings::quickjs::enum_constants::types::LogLevel::Info => {
+                "info".into_js(ctx)
+            }
+            crate::bindings::quickjs::enum_constants::types::LogLevel::Warning => {
+                "warning".into_js(ctx)
+            }
+            crate::bindings::quickjs::enum_constants::types::LogLevel::OutOfRange => {
+                "out-of-range".into_js(ctx)
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::quickjs::enum_constants::types::LogLevel {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let value = crate::wrappers::enum_case(value, "JS log-level", "WIT log-level")?;
+        match value.as_str() {
+            "debug" => {
+                Ok(crate::bindings::quickjs::enum_constants::types::LogLevel::Debug)
+            }
+            "info" => Ok(crate::bindings::quickjs::enum_constants::types::LogLevel::Info),
+            "warning" => {
+                Ok(crate::bindings::quickjs::enum_constants::types::LogLevel::Warning)
+            }
+            "out-of-range" => {
+                Ok(crate::bindings::quickjs::enum_constants::types::LogLevel::OutOfRange)
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_enum_case(
+                        "JS log-level",
+                        "WIT log-level",
+                        &value,
+                    ),
+                )
+            }
+        }
+    }
+}
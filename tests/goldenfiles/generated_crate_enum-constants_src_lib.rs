@@ -0,0 +1,92 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:enum-constants/enum-constants
+// wit: sha256:0832cec2e3fa69df3985a31a1798ba9d4a363df8664e63f5488b3d07bd2ead6a
+// js enum-constants: sha256:0873b620109d8cfec7fda9f8f02ccb362d9db44e53f1b1c986d867dba2ed5832
+
+#[allow(static_mut_refs)]
+#[allow(unsafe_op_in_unsafe_fn)]
+mod bindings;
+mod assets;
+mod builtin;
+mod conversions;
+#[allow(unused)]
+mod internal;
+#[allow(unused)]
+mod modules;
+mod wrappers;
+static JS_EXPORT_MODULE_NAME: &str = "enum-constants";
+static JS_EXPORT_NAMES_VERBATIM: bool = false;
+static INTERN_FIELD_NAMES: bool = false;
+#[allow(dead_code)]
+static REPLACE_LONE_SURROGATES: bool = false;
+static MATH_RANDOM_SEED: crate::builtin::MathRandomSeed = crate::builtin::MathRandomSeed::Default;
+static DATE_CLOCK: Option<fn() -> f64> = None;
+static IMPORT_HOOKS: bool = false;
+static MOCK_IMPORTS: bool = false;
+static IMPORT_RECORDING: crate::builtin::ImportRecording = crate::builtin::ImportRecording::None;
+static CANCEL_BACKGROUND_WORK: bool = false;
+static HTTP_LIMITS: crate::builtin::HttpLimits = crate::builtin::HttpLimits::UNLIMITED;
+#[allow(dead_code)]
+static COOKIE_JAR: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE_LIMITS: crate::builtin::HttpCacheLimits = crate::builtin::HttpCacheLimits {
+    max_entries: Some(256usize),
+    max_size: Some(16777216usize),
+    max_body_size: Some(1048576usize),
+};
+#[allow(dead_code)]
+static FETCH_BASE_URL: Option<&str> = None;
+#[allow(dead_code)]
+static FETCH_GATEWAY: Option<&str> = None;
+static JS_EXPORT_MODULE: &str = include_str!("enum-constants.js");
+static JS_ADDITIONAL_MODULES: std::sync::LazyLock<
+    Vec<(&str, Box<dyn (Fn() -> String) + Send + Sync>)>,
+> = std::sync::LazyLock::new(|| { vec![] });
+static JS_MODULE_ORDER: &[&str] = &["enum-constants"];
+static JS_BUILTIN_PERMISSIONS: &[(&str, &[&str])] = &[];
+struct Component;
+impl crate::bindings::exports::quickjs::enum_constants::api::Guest for Component {
+    fn level_for(
+        verbosity: u8,
+    ) -> crate::bindings::exports::quickjs::enum_constants::api::LogLevel {
+        crate::internal::async_exported_function(async move {
+            let result: crate::bindings::quickjs::enum_constants::types::LogLevel = crate::internal::call_js_export(
+                    "quickjs:enum-constants",
+                    0usize,
+                    &["api", "levelFor"],
+                    (verbosity,),
+                )
+                .await;
+            result
+        })
+    }
+    fn is_warning(
+        level: crate::bindings::exports::quickjs::enum_constants::api::LogLevel,
+    ) -> bool {
+        crate::internal::async_exported_function(async move {
+            let result: bool = crate::internal::call_js_export(
+                    "quickjs:enum-constants",
+                    1usize,
+                    &["api", "isWarning"],
+                    (level,),
+                )
+                .await;
+            result
+        })
+    }
+    fn describe_constants() -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:enum-constants",
+                    2usize,
+                    &["api", "describeConstants"],
+                    crate::wrappers::JsArgs(()),
+                )
+                .await;
+            result.0
+        })
+    }
+}
+bindings::export!(Component with_types_in bindings);
@@ -0,0 +1,11 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:enum-constants/enum-constants
+// wit: sha256:0832cec2e3fa69df3985a31a1798ba9d4a363df8664e63f5488b3d07bd2ead6a
+// js enum-constants: sha256:0873b620109d8cfec7fda9f8f02ccb362d9db44e53f1b1c986d867dba2ed5832
+
+export const LogLevel = Object.freeze({
+    Debug: 'debug',
+    Info: 'info',
+    Warning: 'warning',
+    OutOfRange: 'out-of-range',
+});
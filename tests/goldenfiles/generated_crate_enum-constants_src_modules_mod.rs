@@ -0,0 +1,58 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:enum-constants/enum-constants
+// wit: sha256:0832cec2e3fa69df3985a31a1798ba9d4a363df8664e63f5488b3d07bd2ead6a
+// js enum-constants: sha256:0873b620109d8cfec7fda9f8f02ccb362d9db44e53f1b1c986d867dba2ed5832
+
+use rquickjs::JsLifetime;
+use rquickjs::class::{JsClass, Trace};
+use std::ops::Deref;
+pub mod quickjs_enum_constants_types;
+const NATIVE_MODULE_NAMES: &[&str] = &["quickjs:enum-constants/types", "enums"];
+pub struct NativeModuleResolver;
+impl rquickjs::loader::Resolver for NativeModuleResolver {
+    fn resolve<'js>(
+        &mut self,
+        _ctx: &rquickjs::Ctx<'js>,
+        base: &str,
+        name: &str,
+    ) -> rquickjs::Result<String> {
+        if NATIVE_MODULE_NAMES.contains(&name) {
+            Ok(name.to_string())
+        } else {
+            Err(rquickjs::Error::new_resolving(base, name))
+        }
+    }
+}
+pub struct NativeModuleLoader;
+impl rquickjs::loader::Loader for NativeModuleLoader {
+    fn load<'js>(
+        &mut self,
+        ctx: &rquickjs::Ctx<'js>,
+        name: &str,
+    ) -> rquickjs::Result<rquickjs::Module<'js, rquickjs::module::Declared>> {
+        match name {
+            "quickjs:enum-constants/types" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::quickjs_enum_constants_types::JsTypesModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "enums" => {
+                rquickjs::Module::declare(ctx.clone(), name, include_str!("enums.js"))
+            }
+            _ => Err(rquickjs::Error::new_loading(name)),
+        }
+    }
+}
+pub struct JsEnumConstantsModule;
+impl rquickjs::module::ModuleDef for JsEnumConstantsModule {
+    fn declare(decl: &rquickjs::module::Declarations) -> rquickjs::Result<()> {
+        Ok(())
+    }
+    fn evaluate<'js>(
+        ctx: &rquickjs::Ctx<'js>,
+        exports: &rquickjs::module::Exports<'js>,
+    ) -> rquickjs::Result<()> {
+        Ok(())
+    }
+}
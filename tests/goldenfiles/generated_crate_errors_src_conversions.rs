@@ -0,0 +1,5 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:errors/errors
+// wit: sha256:e4ff3efde332e0690c88f95f019091d8f02d906d4b1234aefcfbf0aab7b0ef56
+// js errors: sha256:167b7dc03570f21aed01f03c4af5855f352846627d2b44cd0739a853781e71b6
+
@@ -0,0 +1,487 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:errors/errors
+// wit: sha256:e4ff3efde332e0690c88f95f019091d8f02d906d4b1234aefcfbf0aab7b0ef56
+// js errors: sha256:167b7dc03570f21aed01f03c4af5855f352846627d2b44cd0739a853781e71b6
+
+#[allow(static_mut_refs)]
+#[allow(unsafe_op_in_unsafe_fn)]
+mod bindings;
+mod assets;
+mod builtin;
+mod conversions;
+#[allow(unused)]
+mod internal;
+#[allow(unused)]
+mod modules;
+mod wrappers;
+static JS_EXPORT_MODULE_NAME: &str = "errors";
+static JS_EXPORT_NAMES_VERBATIM: bool = false;
+static INTERN_FIELD_NAMES: bool = false;
+#[allow(dead_code)]
+static REPLACE_LONE_SURROGATES: bool = false;
+static MATH_RANDOM_SEED: crate::builtin::MathRandomSeed = crate::builtin::MathRandomSeed::Default;
+static DATE_CLOCK: Option<fn() -> f64> = None;
+static IMPORT_HOOKS: bool = false;
+static MOCK_IMPORTS: bool = false;
+static IMPORT_RECORDING: crate::builtin::ImportRecording = crate::builtin::ImportRecording::None;
+static CANCEL_BACKGROUND_WORK: bool = false;
+static HTTP_LIMITS: crate::builtin::HttpLimits = crate::builtin::HttpLimits::UNLIMITED;
+#[allow(dead_code)]
+static COOKIE_JAR: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE_LIMITS: crate::builtin::HttpCacheLimits = crate::builtin::HttpCacheLimits {
+    max_entries: Some(256usize),
+    max_size: Some(16777216usize),
+    max_body_size: Some(1048576usize),
+};
+#[allow(dead_code)]
+static FETCH_BASE_URL: Option<&str> = None;
+#[allow(dead_code)]
+static FETCH_GATEWAY: Option<&str> = None;
+static JS_EXPORT_MODULE: &str = include_str!("errors.js");
+static JS_ADDITIONAL_MODULES: std::sync::LazyLock<
+    Vec<(&str, Box<dyn (Fn() -> String) + Send + Sync>)>,
+> = std::sync::LazyLock::new(|| { vec![] });
+static JS_MODULE_ORDER: &[&str] = &["errors"];
+static JS_BUILTIN_PERMISSIONS: &[(&str, &[&str])] = &[];
+struct Component;
+impl crate::bindings::Guest for Component {
+    fn fun1(name: String) -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:errors",
+                    0usize,
+                    &["fun1"],
+                    (crate::wrappers::JsString(name),),
+                )
+                .await;
+            result.0
+        })
+    }
+    fn fun3() -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:errors",
+                    1usize,
+                    &["fun3"],
+                    crate::wrappers::JsArgs(()),
+                )
+                .await;
+            result.0
+        })
+    }
+    fn fun4(a: u32, b: u32) -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:errors",
+                    2usize,
+                    &["fun4"],
+                    crate::wrappers::JsArgs((a, b)),
+                )
+                .await;
+            result.0
+        })
+    }
+    fn fun5(a: u32, b: String) -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:errors",
+                    3usize,
+                    &["fun5"],
+                    crate::wrappers::JsArgs((a, crate::wrappers::JsString(b))),
+                )
+                .await;
+            result.0
+        })
+    }
+    fn fun6() -> f32 {
+        crate::internal::async_exported_function(async move {
+            let result: f32 = crate::internal::call_js_export(
+                    "quickjs:errors",
+                    4usize,
+                    &["fun6"],
+                    crate::wrappers::JsArgs(()),
+                )
+                .await;
+            result
+        })
+    }
+    fn lone_surrogate() -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:errors",
+                    5usize,
+                    &["loneSurrogate"],
+                    crate::wrappers::JsArgs(()),
+                )
+                .await;
+            result.0
+        })
+    }
+    fn fail() -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:errors",
+                    6usize,
+                    &["fail"],
+                    crate::wrappers::JsArgs(()),
+                )
+                .await;
+            result.0
+        })
+    }
+}
+impl crate::bindings::exports::quickjs::errors::api::Guest for Component {
+    fn fun2(name: String) -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:errors",
+                    7usize,
+                    &["api", "fun2"],
+                    (crate::wrappers::JsString(name),),
+                )
+                .await;
+            result.0
+        })
+    }
+}
+impl crate::bindings::exports::quickjs::errors::api2::Guest for Component {
+    fn fun7() -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:errors",
+                    8usize,
+                    &["api2", "fun7"],
+                    crate::wrappers::JsArgs(()),
+                )
+                .await;
+            result.0
+        })
+    }
+}
+struct Res1 {
+    resource_id: usize,
+}
+impl crate::bindings::exports::quickjs::errors::api3::GuestRes1 for Res1 {
+    fn new(name: String) -> Self {
+        crate::internal::async_exported_function(async move {
+            let resource_id = crate::internal::call_js_resource_constructor(
+                    "quickjs:errors",
+                    &["api3", "Res1"],
+                    (crate::wrappers::JsString(name),),
+                )
+                .await;
+            Self { resource_id }
+        })
+    }
+}
+impl Drop for Res1 {
+    fn drop(&mut self) {
+        crate::internal::enqueue_drop_js_resource(self.resource_id);
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::exports::quickjs::errors::api3::Res1Borrow<'_> {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let inner: &Res1 = self.get();
+        let resource_table: rquickjs::Object = ctx
+            .globals()
+            .get(crate::internal::RESOURCE_TABLE_NAME)
+            .expect("Failed to get the resource table");
+        let resource_instance: rquickjs::Object = resource_table
+            .get(inner.resource_id.to_string())
+            .expect(
+                &format!("Failed to get resource instance with id {}", inner.resource_id),
+            );
+        Ok(resource_instance.into_value())
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::exports::quickjs::errors::api3::Res1 {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let inner: &Res1 = self.get();
+        let resource_table: rquickjs::Object = ctx
+            .globals()
+            .get(crate::internal::RESOURCE_TABLE_NAME)
+            .expect("Failed to get the resource table");
+        let resource_instance: rquickjs::Object = resource_table
+            .get(inner.resource_id.to_string())
+            .expect(
+                &format!("Failed to get resource instance with id {}", inner.resource_id),
+            );
+        Ok(resource_instance.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::exports::quickjs::errors::api3::Res1 {
+    fn from_js(
+        ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let resource = value
+            .into_object()
+            .ok_or_else(|| {
+                rquickjs::Error::new_from_js_message(
+                    "JS Resource instance",
+                    "WASM resource instance",
+                    "The value is not an object",
+                )
+            })?;
+        let already_registered = resource
+            .contains_key(crate::internal::RESOURCE_ID_KEY)?;
+        let resource_id: usize = if already_registered {
+            resource.get(crate::internal::RESOURCE_ID_KEY)?
+        } else {
+            let resource_table: rquickjs::Object = ctx
+                .globals()
+                .get(crate::internal::RESOURCE_TABLE_NAME)?;
+            let resource_id = crate::internal::get_free_resource_id();
+            resource_table.set(resource_id.to_string(), resource)?;
+            resource_id
+        };
+        Ok(
+            crate::bindings::exports::quickjs::errors::api3::Res1::new(Res1 {
+                resource_id,
+            }),
+        )
+    }
+}
+struct Res2 {
+    resource_id: usize,
+}
+impl crate::bindings::exports::quickjs::errors::api3::GuestRes2 for Res2 {
+    fn new() -> Self {
+        crate::internal::async_exported_function(async move {
+            let resource_id = crate::internal::call_js_resource_constructor(
+                    "quickjs:errors",
+                    &["api3", "Res2"],
+                    crate::wrappers::JsArgs(()),
+                )
+                .await;
+            Self { resource_id }
+        })
+    }
+}
+impl Drop for Res2 {
+    fn drop(&mut self) {
+        crate::internal::enqueue_drop_js_resource(self.resource_id);
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::exports::quickjs::errors::api3::Res2Borrow<'_> {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let inner: &Res2 = self.get();
+        let resource_table: rquickjs::Object = ctx
+            .globals()
+            .get(crate::internal::RESOURCE_TABLE_NAME)
+            .expect("Failed to get the resource table");
+        let resource_instance: rquickjs::Object = resource_table
+            .get(inner.resource_id.to_string())
+            .expect(
+                &format!("Failed to get resource instance with id {}", inner.resource_id),
+            );
+        Ok(resource_instance.into_value())
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::exports::quickjs::errors::api3::Res2 {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let inner: &Res2 = self.get();
+        let resource_table: rquickjs::Object = ctx
+            .globals()
+            .get(crate::internal::RESOURCE_TABLE_NAME)
+            .expect("Failed to get the resource table");
+        let resource_instance: rquickjs::Object = resource_table
+            .get(inner.resource_id.to_string())
+            .expect(
+                &format!("Failed to get resource instance with id {}", inner.resource_id),
+            );
+        Ok(resource_instance.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::exports::quickjs::errors::api3::Res2 {
+    fn from_js(
+        ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let resource = value
+            .into_object()
+            .ok_or_else(|| {
+                rquickjs::Error::new_from_js_message(
+                    "JS Resource instance",
+                    "WASM resource instance",
+                    "The value is not an object",
+                )
+            })?;
+        let already_registered = resource
+            .contains_key(crate::internal::RESOURCE_ID_KEY)?;
+        let resource_id: usize = if already_registered {
+            resource.get(crate::internal::RESOURCE_ID_KEY)?
+        } else {
+            let resource_table: rquickjs::Object = ctx
+                .globals()
+                .get(crate::internal::RESOURCE_TABLE_NAME)?;
+            let resource_id = crate::internal::get_free_resource_id();
+            resource_table.set(resource_id.to_string(), resource)?;
+            resource_id
+        };
+        Ok(
+            crate::bindings::exports::quickjs::errors::api3::Res2::new(Res2 {
+                resource_id,
+            }),
+        )
+    }
+}
+struct Res3 {
+    resource_id: usize,
+}
+impl crate::bindings::exports::quickjs::errors::api3::GuestRes3 for Res3 {
+    fn new() -> Self {
+        crate::internal::async_exported_function(async move {
+            let resource_id = crate::internal::call_js_resource_constructor(
+                    "quickjs:errors",
+                    &["api3", "Res3"],
+                    crate::wrappers::JsArgs(()),
+                )
+                .await;
+            Self { resource_id }
+        })
+    }
+    fn m1(&self) -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_resource_method(
+                    "quickjs:errors",
+                    &["api3", "Res3"],
+                    self.resource_id,
+                    "m1",
+                    crate::wrappers::JsArgs(()),
+                )
+                .await;
+            result.0
+        })
+    }
+    fn m2(&self, a: u32, b: u32) -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_resource_method(
+                    "quickjs:errors",
+                    &["api3", "Res3"],
+                    self.resource_id,
+                    "m2",
+                    crate::wrappers::JsArgs((a, b)),
+                )
+                .await;
+            result.0
+        })
+    }
+    fn m3(&self) -> f32 {
+        crate::internal::async_exported_function(async move {
+            let result: f32 = crate::internal::call_js_resource_method(
+                    "quickjs:errors",
+                    &["api3", "Res3"],
+                    self.resource_id,
+                    "m3",
+                    crate::wrappers::JsArgs(()),
+                )
+                .await;
+            result
+        })
+    }
+}
+impl Drop for Res3 {
+    fn drop(&mut self) {
+        crate::internal::enqueue_drop_js_resource(self.resource_id);
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::exports::quickjs::errors::api3::Res3Borrow<'_> {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let inner: &Res3 = self.get();
+        let resource_table: rquickjs::Object = ctx
+            .globals()
+            .get(crate::internal::RESOURCE_TABLE_NAME)
+            .expect("Failed to get the resource table");
+        let resource_instance: rquickjs::Object = resource_table
+            .get(inner.resource_id.to_string())
+            .expect(
+                &format!("Failed to get resource instance with id {}", inner.resource_id),
+            );
+        Ok(resource_instance.into_value())
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::exports::quickjs::errors::api3::Res3 {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let inner: &Res3 = self.get();
+        let resource_table: rquickjs::Object = ctx
+            .globals()
+            .get(crate::internal::RESOURCE_TABLE_NAME)
+            .expect("Failed to get the resource table");
+        let resource_instance: rquickjs::Object = resource_table
+            .get(inner.resource_id.to_string())
+            .expect(
+                &format!("Failed to get resource instance with id {}", inner.resource_id),
+            );
+        Ok(resource_instance.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::exports::quickjs::errors::api3::Res3 {
+    fn from_js(
+        ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let resource = value
+            .into_object()
+            .ok_or_else(|| {
+                rquickjs::Error::new_from_js_message(
+                    "JS Resource instance",
+                    "WASM resource instance",
+                    "The value is not an object",
+                )
+            })?;
+        let already_registered = resource
+            .contains_key(crate::internal::RESOURCE_ID_KEY)?;
+        let resource_id: usize = if already_registered {
+            resource.get(crate::internal::RESOURCE_ID_KEY)?
+        } else {
+            let resource_table: rquickjs::Object = ctx
+                .globals()
+                .get(crate::internal::RESOURCE_TABLE_NAME)?;
+            let resource_id = crate::internal::get_free_resource_id();
+            resource_table.set(resource_id.to_string(), resource)?;
+            resource_id
+        };
+        Ok(
+            crate::bindings::exports::quickjs::errors::api3::Res3::new(Res3 {
+                resource_id,
+            }),
+        )
+    }
+}
+impl crate::bindings::exports::quickjs::errors::api3::Guest for Component {
+    type Res1 = Res1;
+    type Res2 = Res2;
+    type Res3 = Res3;
+}
+bindings::export!(Component with_types_in bindings);
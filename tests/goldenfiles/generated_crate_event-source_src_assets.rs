@@ -0,0 +1,6 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:event-source/event-source
+// wit: sha256:01ab3c824b47d3902e36d53f239b9e6ce7b79c56cb94f59868de8f98c5e32505
+// js event-source: sha256:903a7a31c1b42c2143c41442b2f71fb348d65a2216877daa7269655dfe05b33a
+
+pub static ASSETS: &[(&str, &[u8])] = &[];
@@ -0,0 +1,5 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:example1/example1
+// wit: sha256:96fc5a64a24d27e91e54808e45f1f1700c743d020d3596e6b286637e51eac4e2
+// js example1: sha256:ae619abd325c69a169c9602d63abe176e15027b7a7735703908e6bacbac43f7f
+
@@ -0,0 +1,6 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:example2/example2
+// wit: sha256:59ec9a4d559e00f0833eb3f79ec92f404ca4554c1a5fbbf8930db050836d24cb
+// js example2: sha256:3ba82879a988c24b8e969d5f52f7804c9a98471d19fe6e59cc5225582baed1cf
+
+pub static ASSETS: &[(&str, &[u8])] = &[];
@@ -0,0 +1,6 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:example3/example3
+// wit: sha256:c5b869b96ba916e25c7d6b3e6d9b60ad23271e17fc766dd386f9229e1c1e6827
+// js example3: sha256:1f28cbc80254447e720eff47ba8c50d79ed9a0eedb8d135d7ced12a33b653a52
+
+pub static ASSETS: &[(&str, &[u8])] = &[];
@@ -0,0 +1,362 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:example3/example3
+// wit: sha256:c5b869b96ba916e25c7d6b3e6d9b60ad23271e17fc766dd386f9229e1c1e6827
+// js example3: sha256:1f28cbc80254447e720eff47ba8c50d79ed9a0eedb8d135d7ced12a33b653a52
+
+#[allow(static_mut_refs)]
+#[allow(unsafe_op_in_unsafe_fn)]
+mod bindings;
+mod assets;
+mod builtin;
+mod conversions;
+#[allow(unused)]
+mod internal;
+#[allow(unused)]
+mod modules;
+mod wrappers;
+static JS_EXPORT_MODULE_NAME: &str = "example3";
+static JS_EXPORT_NAMES_VERBATIM: bool = false;
+static INTERN_FIELD_NAMES: bool = false;
+#[allow(dead_code)]
+static REPLACE_LONE_SURROGATES: bool = false;
+static MATH_RANDOM_SEED: crate::builtin::MathRandomSeed = crate::builtin::MathRandomSeed::Default;
+static DATE_CLOCK: Option<fn() -> f64> = None;
+static IMPORT_HOOKS: bool = false;
+static MOCK_IMPORTS: bool = false;
+static IMPORT_RECORDING: crate::builtin::ImportRecording = crate::builtin::ImportRecording::None;
+static CANCEL_BACKGROUND_WORK: bool = false;
+static HTTP_LIMITS: crate::builtin::HttpLimits = crate::builtin::HttpLimits::UNLIMITED;
+#[allow(dead_code)]
+static COOKIE_JAR: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE_LIMITS: crate::builtin::HttpCacheLimits = crate::builtin::HttpCacheLimits {
+    max_entries: Some(256usize),
+    max_size: Some(16777216usize),
+    max_body_size: Some(1048576usize),
+};
+#[allow(dead_code)]
+static FETCH_BASE_URL: Option<&str> = None;
+#[allow(dead_code)]
+static FETCH_GATEWAY: Option<&str> = None;
+static JS_EXPORT_MODULE: &str = include_str!("example3.js");
+static JS_ADDITIONAL_MODULES: std::sync::LazyLock<
+    Vec<(&str, Box<dyn (Fn() -> String) + Send + Sync>)>,
+> = std::sync::LazyLock::new(|| { vec![] });
+static JS_MODULE_ORDER: &[&str] = &["example3"];
+static JS_BUILTIN_PERMISSIONS: &[(&str, &[&str])] = &[];
+struct Component;
+struct Hello {
+    resource_id: usize,
+}
+impl crate::bindings::exports::quickjs::example3::iface::GuestHello for Hello {
+    fn new(name: String) -> Self {
+        crate::internal::async_exported_function(async move {
+            let resource_id = crate::internal::call_js_resource_constructor(
+                    "quickjs:example3",
+                    &["iface", "Hello"],
+                    (crate::wrappers::JsString(name),),
+                )
+                .await;
+            Self { resource_id }
+        })
+    }
+    fn get_name(&self) -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_resource_method(
+                    "quickjs:example3",
+                    &["iface", "Hello"],
+                    self.resource_id,
+                    "getName",
+                    crate::wrappers::JsArgs(()),
+                )
+                .await;
+            result.0
+        })
+    }
+    fn compare(
+        h1: crate::bindings::exports::quickjs::example3::iface::HelloBorrow<'_>,
+        h2: crate::bindings::exports::quickjs::example3::iface::HelloBorrow<'_>,
+    ) -> i32 {
+        crate::internal::async_exported_function(async move {
+            let result: i32 = crate::internal::call_js_export(
+                    "quickjs:example3",
+                    4usize,
+                    &["iface", "Hello", "compare"],
+                    crate::wrappers::JsArgs((h1, h2)),
+                )
+                .await;
+            result
+        })
+    }
+    fn merge(
+        h1: crate::bindings::exports::quickjs::example3::iface::Hello,
+        h2: crate::bindings::exports::quickjs::example3::iface::Hello,
+    ) -> crate::bindings::exports::quickjs::example3::iface::Hello {
+        crate::internal::async_exported_function(async move {
+            let result: crate::bindings::exports::quickjs::example3::iface::Hello = crate::internal::call_js_export(
+                    "quickjs:example3",
+                    5usize,
+                    &["iface", "Hello", "merge"],
+                    crate::wrappers::JsArgs((h1, h2)),
+                )
+                .await;
+            result
+        })
+    }
+}
+impl Drop for Hello {
+    fn drop(&mut self) {
+        crate::internal::enqueue_drop_js_resource(self.resource_id);
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::exports::quickjs::example3::iface::HelloBorrow<'_> {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let inner: &Hello = self.get();
+        let resource_table: rquickjs::Object = ctx
+            .globals()
+            .get(crate::internal::RESOURCE_TABLE_NAME)
+            .expect("Failed to get the resource table");
+        let resource_instance: rquickjs::Object = resource_table
+            .get(inner.resource_id.to_string())
+            .expect(
+                &format!("Failed to get resource instance with id {}", inner.resource_id),
+            );
+        Ok(resource_instance.into_value())
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::exports::quickjs::example3::iface::Hello {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let inner: &Hello = self.get();
+        let resource_table: rquickjs::Object = ctx
+            .globals()
+            .get(crate::internal::RESOURCE_TABLE_NAME)
+            .expect("Failed to get the resource table");
+        let resource_instance: rquickjs::Object = resource_table
+            .get(inner.resource_id.to_string())
+            .expect(
+                &format!("Failed to get resource instance with id {}", inner.resource_id),
+            );
+        Ok(resource_instance.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::exports::quickjs::example3::iface::Hello {
+    fn from_js(
+        ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let resource = value
+            .into_object()
+            .ok_or_else(|| {
+                rquickjs::Error::new_from_js_message(
+                    "JS Resource instance",
+                    "WASM resource instance",
+                    "The value is not an object",
+                )
+            })?;
+        let already_registered = resource
+            .contains_key(crate::internal::RESOURCE_ID_KEY)?;
+        let resource_id: usize = if already_registered {
+            resource.get(crate::internal::RESOURCE_ID_KEY)?
+        } else {
+            let resource_table: rquickjs::Object = ctx
+                .globals()
+                .get(crate::internal::RESOURCE_TABLE_NAME)?;
+            let resource_id = crate::internal::get_free_resource_id();
+            resource_table.set(resource_id.to_string(), resource)?;
+            resource_id
+        };
+        Ok(
+            crate::bindings::exports::quickjs::example3::iface::Hello::new(Hello {
+                resource_id,
+            }),
+        )
+    }
+}
+struct HelloWithStaticCreate {
+    resource_id: usize,
+}
+impl crate::bindings::exports::quickjs::example3::iface::GuestHelloWithStaticCreate
+for HelloWithStaticCreate {
+    fn create(
+        name: String,
+    ) -> crate::bindings::exports::quickjs::example3::iface::HelloWithStaticCreate {
+        crate::internal::async_exported_function(async move {
+            let result: crate::bindings::exports::quickjs::example3::iface::HelloWithStaticCreate = crate::internal::call_js_export(
+                    "quickjs:example3",
+                    6usize,
+                    &["iface", "HelloWithStaticCreate", "create"],
+                    (crate::wrappers::JsString(name),),
+                )
+                .await;
+            result
+        })
+    }
+    fn get_name(&self) -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_resource_method(
+                    "quickjs:example3",
+                    &["iface", "HelloWithStaticCreate"],
+                    self.resource_id,
+                    "getName",
+                    crate::wrappers::JsArgs(()),
+                )
+                .await;
+            result.0
+        })
+    }
+    fn compare(
+        h1: crate::bindings::exports::quickjs::example3::iface::HelloBorrow<'_>,
+        h2: crate::bindings::exports::quickjs::example3::iface::HelloBorrow<'_>,
+    ) -> i32 {
+        crate::internal::async_exported_function(async move {
+            let result: i32 = crate::internal::call_js_export(
+                    "quickjs:example3",
+                    8usize,
+                    &["iface", "HelloWithStaticCreate", "compare"],
+                    crate::wrappers::JsArgs((h1, h2)),
+                )
+                .await;
+            result
+        })
+    }
+    fn merge(
+        h1: crate::bindings::exports::quickjs::example3::iface::Hello,
+        h2: crate::bindings::exports::quickjs::example3::iface::Hello,
+    ) -> crate::bindings::exports::quickjs::example3::iface::Hello {
+        crate::internal::async_exported_function(async move {
+            let result: crate::bindings::exports::quickjs::example3::iface::Hello = crate::internal::call_js_export(
+                    "quickjs:example3",
+                    9usize,
+                    &["iface", "HelloWithStaticCreate", "merge"],
+                    crate::wrappers::JsArgs((h1, h2)),
+                )
+                .await;
+            result
+        })
+    }
+}
+impl Drop for HelloWithStaticCreate {
+    fn drop(&mut self) {
+        crate::internal::enqueue_drop_js_resource(self.resource_id);
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::exports::quickjs::example3::iface::HelloWithStaticCreateBorrow<'_> {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let inner: &HelloWithStaticCreate = self.get();
+        let resource_table: rquickjs::Object = ctx
+            .globals()
+            .get(crate::internal::RESOURCE_TABLE_NAME)
+            .expect("Failed to get the resource table");
+        let resource_instance: rquickjs::Object = resource_table
+            .get(inner.resource_id.to_string())
+            .expect(
+                &format!("Failed to get resource instance with id {}", inner.resource_id),
+            );
+        Ok(resource_instance.into_value())
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::exports::quickjs::example3::iface::HelloWithStaticCreate {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let inner: &HelloWithStaticCreate = self.get();
+        let resource_table: rquickjs::Object = ctx
+            .globals()
+            .get(crate::internal::RESOURCE_TABLE_NAME)
+            .expect("Failed to get the resource table");
+        let resource_instance: rquickjs::Object = resource_table
+            .get(inner.resource_id.to_string())
+            .expect(
+                &format!("Failed to get resource instance with id {}", inner.resource_id),
+            );
+        Ok(resource_instance.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::exports::quickjs::example3::iface::HelloWithStaticCreate {
+    fn from_js(
+        ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let resource = value
+            .into_object()
+            .ok_or_else(|| {
+                rquickjs::Error::new_from_js_message(
+                    "JS Resource instance",
+                    "WASM resource instance",
+                    "The value is not an object",
+                )
+            })?;
+        let already_registered = resource
+            .contains_key(crate::internal::RESOURCE_ID_KEY)?;
+        let resource_id: usize = if already_registered {
+            resource.get(crate::internal::RESOURCE_ID_KEY)?
+        } else {
+            let resource_table: rquickjs::Object = ctx
+                .globals()
+                .get(crate::internal::RESOURCE_TABLE_NAME)?;
+            let resource_id = crate::internal::get_free_resource_id();
+            resource_table.set(resource_id.to_string(), resource)?;
+            resource_id
+        };
+        Ok(
+            crate::bindings::exports::quickjs::example3::iface::HelloWithStaticCreate::new(HelloWithStaticCreate {
+                resource_id,
+            }),
+        )
+    }
+}
+impl crate::bindings::exports::quickjs::example3::iface::Guest for Component {
+    type Hello = Hello;
+    type HelloWithStaticCreate = HelloWithStaticCreate;
+    fn dump(
+        h: Option<crate::bindings::exports::quickjs::example3::iface::HelloBorrow<'_>>,
+    ) -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:example3",
+                    0usize,
+                    &["iface", "dump"],
+                    (h.map(|v| v),),
+                )
+                .await;
+            result.0
+        })
+    }
+    fn dump_all(
+        hs: Vec<crate::bindings::exports::quickjs::example3::iface::HelloBorrow<'_>>,
+    ) -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:example3",
+                    1usize,
+                    &["iface", "dumpAll"],
+                    (
+                        crate::wrappers::JsList(
+                            hs.into_iter().map(|v| v).collect::<Vec<_>>(),
+                        ),
+                    ),
+                )
+                .await;
+            result.0
+        })
+    }
+}
+bindings::export!(Component with_types_in bindings);
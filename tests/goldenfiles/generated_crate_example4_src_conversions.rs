@@ -0,0 +1,5 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:example4/example4
+// wit: sha256:0b8fd1289107302bf93945e9633742a7a31e303bc2a469003bf1243c215255a1
+// js example4: sha256:f083bcaac053fbf0d99b820c9de3b39d499917659d73a630573e91e78cdc55c3
+
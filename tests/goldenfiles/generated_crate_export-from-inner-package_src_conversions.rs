@@ -0,0 +1,5 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:export-from-inner-package/export-from-inner-package
+// wit: sha256:c0baee3fee9ee6ff541710364bfabd25ff445057e8cf3b2af9497824dcfd305e
+// js export-from-inner-package: sha256:4411aa1a777006d5bebda48d21f6d91a943b0ea0abea16077f3c731caaf89b71
+
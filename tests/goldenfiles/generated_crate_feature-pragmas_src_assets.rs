@@ -0,0 +1,6 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:feature-pragmas/feature-pragmas
+// wit: sha256:458887e9f528536c1ff2fe2fca732b9b5f6bee748dae40f9fa18a22448509a3d
+// js feature-pragmas: sha256:c06ce14a415a60520169e676f3ded74ca0db807f49b345b279934a2c19d19e81
+
+pub static ASSETS: &[(&str, &[u8])] = &[];
@@ -0,0 +1,86 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:feature-pragmas/feature-pragmas
+// wit: sha256:458887e9f528536c1ff2fe2fca732b9b5f6bee748dae40f9fa18a22448509a3d
+// js feature-pragmas: sha256:c06ce14a415a60520169e676f3ded74ca0db807f49b345b279934a2c19d19e81
+
+#[allow(static_mut_refs)]
+#[allow(unsafe_op_in_unsafe_fn)]
+mod bindings;
+mod assets;
+mod builtin;
+mod conversions;
+#[allow(unused)]
+mod internal;
+#[allow(unused)]
+mod modules;
+mod wrappers;
+static JS_EXPORT_MODULE_NAME: &str = "feature-pragmas";
+static JS_EXPORT_NAMES_VERBATIM: bool = false;
+static INTERN_FIELD_NAMES: bool = false;
+#[allow(dead_code)]
+static REPLACE_LONE_SURROGATES: bool = false;
+static MATH_RANDOM_SEED: crate::builtin::MathRandomSeed = crate::builtin::MathRandomSeed::Default;
+static DATE_CLOCK: Option<fn() -> f64> = None;
+static IMPORT_HOOKS: bool = false;
+static MOCK_IMPORTS: bool = false;
+static IMPORT_RECORDING: crate::builtin::ImportRecording = crate::builtin::ImportRecording::None;
+static CANCEL_BACKGROUND_WORK: bool = false;
+static HTTP_LIMITS: crate::builtin::HttpLimits = crate::builtin::HttpLimits::UNLIMITED;
+#[allow(dead_code)]
+static COOKIE_JAR: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE_LIMITS: crate::builtin::HttpCacheLimits = crate::builtin::HttpCacheLimits {
+    max_entries: Some(256usize),
+    max_size: Some(16777216usize),
+    max_body_size: Some(1048576usize),
+};
+#[allow(dead_code)]
+static FETCH_BASE_URL: Option<&str> = None;
+#[allow(dead_code)]
+static FETCH_GATEWAY: Option<&str> = None;
+static JS_EXPORT_MODULE: &str = {
+    #[cfg(all(not(feature = "http"), not(feature = "logging")))]
+    let source = include_str!("feature-pragmas.features-none.js");
+    #[cfg(all(feature = "http", not(feature = "logging")))]
+    let source = include_str!("feature-pragmas.features-http.js");
+    #[cfg(all(not(feature = "http"), feature = "logging"))]
+    let source = include_str!("feature-pragmas.features-logging.js");
+    #[cfg(all(feature = "http", feature = "logging"))]
+    let source = include_str!("feature-pragmas.features-http-logging.js");
+    source
+};
+static JS_ADDITIONAL_MODULES: std::sync::LazyLock<
+    Vec<(&str, Box<dyn (Fn() -> String) + Send + Sync>)>,
+> = std::sync::LazyLock::new(|| { vec![] });
+static JS_MODULE_ORDER: &[&str] = &["feature-pragmas"];
+static JS_BUILTIN_PERMISSIONS: &[(&str, &[&str])] = &[];
+struct Component;
+impl crate::bindings::Guest for Component {
+    fn kept_regions() -> Vec<String> {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsList<crate::wrappers::JsString> = crate::internal::call_js_export(
+                    "quickjs:feature-pragmas",
+                    0usize,
+                    &["keptRegions"],
+                    crate::wrappers::JsArgs(()),
+                )
+                .await;
+            result.0.into_iter().map(|v| v.0).collect::<Vec<_>>()
+        })
+    }
+    fn report(message: String) -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:feature-pragmas",
+                    1usize,
+                    &["report"],
+                    (crate::wrappers::JsString(message),),
+                )
+                .await;
+            result.0
+        })
+    }
+}
+bindings::export!(Component with_types_in bindings);
@@ -0,0 +1,6 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:fetch-gateway/fetch-gateway
+// wit: sha256:bda89df07bf8b272800c86e93a4b4d55be44a0fa588caa63460998fbbdce3d60
+// js fetch-gateway: sha256:d721ff41dcd567ac4b3e5fd6841d0273fe828a0d95872ce304319816d856d421
+
+pub static ASSETS: &[(&str, &[u8])] = &[];
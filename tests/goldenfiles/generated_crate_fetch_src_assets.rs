@@ -0,0 +1,6 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:fetch/fetch
+// wit: sha256:7acd73112f80f7af6788a708677e18b167b512561e9c9ce53ee3d36f0159e30e
+// js fetch: sha256:979a3defa8e728edfedb8e26d290060ac4338bdae11fc766796d99fabbb53a02
+
+pub static ASSETS: &[(&str, &[u8])] = &[];
@@ -0,0 +1,196 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:fetch/fetch
+// wit: sha256:7acd73112f80f7af6788a708677e18b167b512561e9c9ce53ee3d36f0159e30e
+// js fetch: sha256:979a3defa8e728edfedb8e26d290060ac4338bdae11fc766796d99fabbb53a02
+
+#[allow(static_mut_refs)]
+#[allow(unsafe_op_in_unsafe_fn)]
+mod bindings;
+mod assets;
+mod builtin;
+mod conversions;
+#[allow(unused)]
+mod internal;
+#[allow(unused)]
+mod modules;
+mod wrappers;
+static JS_EXPORT_MODULE_NAME: &str = "fetch";
+static JS_EXPORT_NAMES_VERBATIM: bool = false;
+static INTERN_FIELD_NAMES: bool = false;
+#[allow(dead_code)]
+static REPLACE_LONE_SURROGATES: bool = false;
+static MATH_RANDOM_SEED: crate::builtin::MathRandomSeed = crate::builtin::MathRandomSeed::Default;
+static DATE_CLOCK: Option<fn() -> f64> = None;
+static IMPORT_HOOKS: bool = false;
+static MOCK_IMPORTS: bool = false;
+static IMPORT_RECORDING: crate::builtin::ImportRecording = crate::builtin::ImportRecording::None;
+static CANCEL_BACKGROUND_WORK: bool = false;
+static HTTP_LIMITS: crate::builtin::HttpLimits = crate::builtin::HttpLimits::UNLIMITED;
+#[allow(dead_code)]
+static COOKIE_JAR: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE_LIMITS: crate::builtin::HttpCacheLimits = crate::builtin::HttpCacheLimits {
+    max_entries: Some(256usize),
+    max_size: Some(16777216usize),
+    max_body_size: Some(1048576usize),
+};
+#[allow(dead_code)]
+static FETCH_BASE_URL: Option<&str> = None;
+#[allow(dead_code)]
+static FETCH_GATEWAY: Option<&str> = None;
+static JS_EXPORT_MODULE: &str = include_str!("fetch.js");
+static JS_ADDITIONAL_MODULES: std::sync::LazyLock<
+    Vec<(&str, Box<dyn (Fn() -> String) + Send + Sync>)>,
+> = std::sync::LazyLock::new(|| { vec![] });
+static JS_MODULE_ORDER: &[&str] = &["fetch"];
+static JS_BUILTIN_PERMISSIONS: &[(&str, &[&str])] = &[];
+struct Component;
+impl crate::bindings::Guest for Component {
+    fn test1(port: u16) -> () {
+        crate::internal::async_exported_function(async move {
+            let result: () = crate::internal::call_js_export(
+                    "quickjs:fetch",
+                    0usize,
+                    &["test1"],
+                    (port,),
+                )
+                .await;
+            result
+        })
+    }
+    fn test2(port: u16) -> () {
+        crate::internal::async_exported_function(async move {
+            let result: () = crate::internal::call_js_export(
+                    "quickjs:fetch",
+                    1usize,
+                    &["test2"],
+                    (port,),
+                )
+                .await;
+            result
+        })
+    }
+    fn test3(port: u16) -> () {
+        crate::internal::async_exported_function(async move {
+            let result: () = crate::internal::call_js_export(
+                    "quickjs:fetch",
+                    2usize,
+                    &["test3"],
+                    (port,),
+                )
+                .await;
+            result
+        })
+    }
+    fn test4(port: u16) -> () {
+        crate::internal::async_exported_function(async move {
+            let result: () = crate::internal::call_js_export(
+                    "quickjs:fetch",
+                    3usize,
+                    &["test4"],
+                    (port,),
+                )
+                .await;
+            result
+        })
+    }
+    fn test4_buffered(port: u16) -> () {
+        crate::internal::async_exported_function(async move {
+            let result: () = crate::internal::call_js_export(
+                    "quickjs:fetch",
+                    4usize,
+                    &["test4Buffered"],
+                    (port,),
+                )
+                .await;
+            result
+        })
+    }
+    fn test5(port: u16) -> () {
+        crate::internal::async_exported_function(async move {
+            let result: () = crate::internal::call_js_export(
+                    "quickjs:fetch",
+                    5usize,
+                    &["test5"],
+                    (port,),
+                )
+                .await;
+            result
+        })
+    }
+    fn test6(port: u16) -> () {
+        crate::internal::async_exported_function(async move {
+            let result: () = crate::internal::call_js_export(
+                    "quickjs:fetch",
+                    6usize,
+                    &["test6"],
+                    (port,),
+                )
+                .await;
+            result
+        })
+    }
+    fn test7() -> () {
+        crate::internal::async_exported_function(async move {
+            let result: () = crate::internal::call_js_export(
+                    "quickjs:fetch",
+                    7usize,
+                    &["test7"],
+                    crate::wrappers::JsArgs(()),
+                )
+                .await;
+            result
+        })
+    }
+    fn test8(port: u16) -> () {
+        crate::internal::async_exported_function(async move {
+            let result: () = crate::internal::call_js_export(
+                    "quickjs:fetch",
+                    8usize,
+                    &["test8"],
+                    (port,),
+                )
+                .await;
+            result
+        })
+    }
+    fn test9(port: u16) -> () {
+        crate::internal::async_exported_function(async move {
+            let result: () = crate::internal::call_js_export(
+                    "quickjs:fetch",
+                    9usize,
+                    &["test9"],
+                    (port,),
+                )
+                .await;
+            result
+        })
+    }
+    fn test10(port: u16) -> () {
+        crate::internal::async_exported_function(async move {
+            let result: () = crate::internal::call_js_export(
+                    "quickjs:fetch",
+                    10usize,
+                    &["test10"],
+                    (port,),
+                )
+                .await;
+            result
+        })
+    }
+    fn test11(port: u16) -> () {
+        crate::internal::async_exported_function(async move {
+            let result: () = crate::internal::call_js_export(
+                    "quickjs:fetch",
+                    11usize,
+                    &["test11"],
+                    (port,),
+                )
+                .await;
+            result
+        })
+    }
+}
+bindings::export!(Component with_types_in bindings);
@@ -0,0 +1,5 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:fixed-clock/fixed-clock
+// wit: sha256:94e6822168aac66dbcbc6234723f25e4c5b4ff93d4663491486c614d7e3c0496
+// js fixed-clock: sha256:29715418748d23e187c49c0e33000defdf3f9dbc3af0386bd159583e7c0f8d4f
+
@@ -0,0 +1,6 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:form-data/form-data
+// wit: sha256:99861966886a1a111a14c49fcc7b3dd1131cfaf804749b883be6db072fbaf258
+// js form-data: sha256:c418d72f5470162c2d925cc08df90e99cc57b36c45685cc4828837fe734b08b6
+
+pub static ASSETS: &[(&str, &[u8])] = &[];
@@ -0,0 +1,6 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:fs/fs
+// wit: sha256:9edc15a24f4e17a7aa02d28ec7a8fcd333e189899cf01a6a6fbcfa2490e3c7f5
+// js fs: sha256:d17b4153b5940861588e1feca1d879cc0019c9da181b450fbfcecb2ef3bb2914
+
+pub static ASSETS: &[(&str, &[u8])] = &[];
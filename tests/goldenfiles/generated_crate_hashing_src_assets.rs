@@ -0,0 +1,6 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:hashing/hashing
+// wit: sha256:90415c6ba73da793277116b376f0a670a98be7a523181b76def118e05caf9c31
+// js hashing: sha256:ab8e38dfe3c176160115c415e79432d46b544284ca6cb6529b1fda56e60db670
+
+pub static ASSETS: &[(&str, &[u8])] = &[];
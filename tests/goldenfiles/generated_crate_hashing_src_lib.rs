@@ -0,0 +1,137 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:hashing/hashing
+// wit: sha256:90415c6ba73da793277116b376f0a670a98be7a523181b76def118e05caf9c31
+// js hashing: sha256:ab8e38dfe3c176160115c415e79432d46b544284ca6cb6529b1fda56e60db670
+
+#[allow(static_mut_refs)]
+#[allow(unsafe_op_in_unsafe_fn)]
+mod bindings;
+mod assets;
+mod builtin;
+mod conversions;
+#[allow(unused)]
+mod internal;
+#[allow(unused)]
+mod modules;
+mod wrappers;
+static JS_EXPORT_MODULE_NAME: &str = "hashing";
+static JS_EXPORT_NAMES_VERBATIM: bool = false;
+static INTERN_FIELD_NAMES: bool = false;
+#[allow(dead_code)]
+static REPLACE_LONE_SURROGATES: bool = false;
+static MATH_RANDOM_SEED: crate::builtin::MathRandomSeed = crate::builtin::MathRandomSeed::Default;
+static DATE_CLOCK: Option<fn() -> f64> = None;
+static IMPORT_HOOKS: bool = false;
+static MOCK_IMPORTS: bool = false;
+static IMPORT_RECORDING: crate::builtin::ImportRecording = crate::builtin::ImportRecording::None;
+static CANCEL_BACKGROUND_WORK: bool = false;
+static HTTP_LIMITS: crate::builtin::HttpLimits = crate::builtin::HttpLimits::UNLIMITED;
+#[allow(dead_code)]
+static COOKIE_JAR: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE_LIMITS: crate::builtin::HttpCacheLimits = crate::builtin::HttpCacheLimits {
+    max_entries: Some(256usize),
+    max_size: Some(16777216usize),
+    max_body_size: Some(1048576usize),
+};
+#[allow(dead_code)]
+static FETCH_BASE_URL: Option<&str> = None;
+#[allow(dead_code)]
+static FETCH_GATEWAY: Option<&str> = None;
+static JS_EXPORT_MODULE: &str = include_str!("hashing.js");
+static JS_ADDITIONAL_MODULES: std::sync::LazyLock<
+    Vec<(&str, Box<dyn (Fn() -> String) + Send + Sync>)>,
+> = std::sync::LazyLock::new(|| { vec![] });
+static JS_MODULE_ORDER: &[&str] = &["hashing"];
+static JS_BUILTIN_PERMISSIONS: &[(&str, &[&str])] = &[];
+struct Component;
+impl crate::bindings::Guest for Component {
+    fn hash_text(algorithm: String, text: String, encoding: String) -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:hashing",
+                    0usize,
+                    &["hashText"],
+                    crate::wrappers::JsArgs((
+                        crate::wrappers::JsString(algorithm),
+                        crate::wrappers::JsString(text),
+                        crate::wrappers::JsString(encoding),
+                    )),
+                )
+                .await;
+            result.0
+        })
+    }
+    fn hmac_text(algorithm: String, key: String, text: String) -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:hashing",
+                    1usize,
+                    &["hmacText"],
+                    crate::wrappers::JsArgs((
+                        crate::wrappers::JsString(algorithm),
+                        crate::wrappers::JsString(key),
+                        crate::wrappers::JsString(text),
+                    )),
+                )
+                .await;
+            result.0
+        })
+    }
+    fn checksum(chunks: Vec<String>) -> u32 {
+        crate::internal::async_exported_function(async move {
+            let result: u32 = crate::internal::call_js_export(
+                    "quickjs:hashing",
+                    2usize,
+                    &["checksum"],
+                    (
+                        crate::wrappers::JsList(
+                            chunks
+                                .into_iter()
+                                .map(|v| crate::wrappers::JsString(v))
+                                .collect::<Vec<_>>(),
+                        ),
+                    ),
+                )
+                .await;
+            result
+        })
+    }
+    fn signing_key(
+        secret: String,
+        date: String,
+        region: String,
+        service: String,
+    ) -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:hashing",
+                    3usize,
+                    &["signingKey"],
+                    crate::wrappers::JsArgs((
+                        crate::wrappers::JsString(secret),
+                        crate::wrappers::JsString(date),
+                        crate::wrappers::JsString(region),
+                        crate::wrappers::JsString(service),
+                    )),
+                )
+                .await;
+            result.0
+        })
+    }
+    fn errors() -> Vec<String> {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsList<crate::wrappers::JsString> = crate::internal::call_js_export(
+                    "quickjs:hashing",
+                    4usize,
+                    &["errors"],
+                    crate::wrappers::JsArgs(()),
+                )
+                .await;
+            result.0.into_iter().map(|v| v.0).collect::<Vec<_>>()
+        })
+    }
+}
+bindings::export!(Component with_types_in bindings);
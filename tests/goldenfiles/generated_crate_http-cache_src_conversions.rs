@@ -0,0 +1,5 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:http-cache/http-cache
+// wit: sha256:8d56384f2409063e66a24fb92e5cbacf1e85974df2e6e6c7a9400f576b9be6cb
+// js http-cache: sha256:c645d6a0eb0fbdf5896f826cda03d36b6f0f782e0f36c4d348b059a7ae5a81a1
+
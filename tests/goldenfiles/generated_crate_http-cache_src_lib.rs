@@ -0,0 +1,87 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:http-cache/http-cache
+// wit: sha256:8d56384f2409063e66a24fb92e5cbacf1e85974df2e6e6c7a9400f576b9be6cb
+// js http-cache: sha256:c645d6a0eb0fbdf5896f826cda03d36b6f0f782e0f36c4d348b059a7ae5a81a1
+
+#[allow(static_mut_refs)]
+#[allow(unsafe_op_in_unsafe_fn)]
+mod bindings;
+mod assets;
+mod builtin;
+mod conversions;
+#[allow(unused)]
+mod internal;
+#[allow(unused)]
+mod modules;
+mod wrappers;
+static JS_EXPORT_MODULE_NAME: &str = "http-cache";
+static JS_EXPORT_NAMES_VERBATIM: bool = false;
+static INTERN_FIELD_NAMES: bool = false;
+#[allow(dead_code)]
+static REPLACE_LONE_SURROGATES: bool = false;
+static MATH_RANDOM_SEED: crate::builtin::MathRandomSeed = crate::builtin::MathRandomSeed::Default;
+static DATE_CLOCK: Option<fn() -> f64> = None;
+static IMPORT_HOOKS: bool = false;
+static MOCK_IMPORTS: bool = false;
+static IMPORT_RECORDING: crate::builtin::ImportRecording = crate::builtin::ImportRecording::None;
+static CANCEL_BACKGROUND_WORK: bool = false;
+static HTTP_LIMITS: crate::builtin::HttpLimits = crate::builtin::HttpLimits::UNLIMITED;
+#[allow(dead_code)]
+static COOKIE_JAR: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE: bool = true;
+#[allow(dead_code)]
+static HTTP_CACHE_LIMITS: crate::builtin::HttpCacheLimits = crate::builtin::HttpCacheLimits {
+    max_entries: Some(2usize),
+    max_size: Some(1024usize),
+    max_body_size: Some(64usize),
+};
+#[allow(dead_code)]
+static FETCH_BASE_URL: Option<&str> = None;
+#[allow(dead_code)]
+static FETCH_GATEWAY: Option<&str> = None;
+static JS_EXPORT_MODULE: &str = include_str!("http-cache.js");
+static JS_ADDITIONAL_MODULES: std::sync::LazyLock<
+    Vec<(&str, Box<dyn (Fn() -> String) + Send + Sync>)>,
+> = std::sync::LazyLock::new(|| { vec![] });
+static JS_MODULE_ORDER: &[&str] = &["http-cache"];
+static JS_BUILTIN_PERMISSIONS: &[(&str, &[&str])] = &[];
+struct Component;
+impl crate::bindings::Guest for Component {
+    fn fetch_with_cache(url: String, cache: String) -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:http-cache",
+                    0usize,
+                    &["fetchWithCache"],
+                    crate::wrappers::JsArgs((
+                        crate::wrappers::JsString(url),
+                        crate::wrappers::JsString(cache),
+                    )),
+                )
+                .await;
+            result.0
+        })
+    }
+    fn fetch_request_with_cookie(
+        url: String,
+        request_cookie: String,
+        cookie: String,
+    ) -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:http-cache",
+                    1usize,
+                    &["fetchRequestWithCookie"],
+                    crate::wrappers::JsArgs((
+                        crate::wrappers::JsString(url),
+                        crate::wrappers::JsString(request_cookie),
+                        crate::wrappers::JsString(cookie),
+                    )),
+                )
+                .await;
+            result.0
+        })
+    }
+}
+bindings::export!(Component with_types_in bindings);
@@ -0,0 +1,6 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:http-handler/http-handler
+// wit: sha256:50e4a1bebab498e752ae328835ec3c85cfd815c061dfa531fe9a22921a588468
+// js http-handler: sha256:ab1143b2ac83255c843b7f985e768100c0f6825bbfa8176896d9f51451db357e
+
+pub static ASSETS: &[(&str, &[u8])] = &[];
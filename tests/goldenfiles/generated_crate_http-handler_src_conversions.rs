@@ -0,0 +1,804 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:http-handler/http-handler
+// wit: sha256:50e4a1bebab498e752ae328835ec3c85cfd815c061dfa531fe9a22921a588468
+// js http-handler: sha256:ab1143b2ac83255c843b7f985e768100c0f6825bbfa8176896d9f51451db357e
+
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::wasi::io::streams::StreamError {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::wasi::io::streams::StreamError::LastOperationFailed(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::wasi::io::streams::Error,
+                >(ctx, "last-operation-failed", inner)
+            }
+            crate::bindings::wasi::io::streams::StreamError::Closed => {
+                crate::wrappers::variant_case(ctx, "closed")
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::wasi::io::streams::StreamError {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "last-operation-failed" => {
+                let inner: crate::bindings::wasi::io::streams::Error = crate::wrappers::variant_value(
+                    &obj,
+                    "error",
+                )?;
+                Ok(
+                    crate::bindings::wasi::io::streams::StreamError::LastOperationFailed(
+                        inner,
+                    ),
+                )
+            }
+            "closed" => Ok(crate::bindings::wasi::io::streams::StreamError::Closed),
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS stream-error",
+                        "WIT stream-error",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::wasi::http::types::Method {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::wasi::http::types::Method::Get => {
+                crate::wrappers::variant_case(ctx, "get")
+            }
+            crate::bindings::wasi::http::types::Method::Head => {
+                crate::wrappers::variant_case(ctx, "head")
+            }
+            crate::bindings::wasi::http::types::Method::Post => {
+                crate::wrappers::variant_case(ctx, "post")
+            }
+            crate::bindings::wasi::http::types::Method::Put => {
+                crate::wrappers::variant_case(ctx, "put")
+            }
+            crate::bindings::wasi::http::types::Method::Delete => {
+                crate::wrappers::variant_case(ctx, "delete")
+            }
+            crate::bindings::wasi::http::types::Method::Connect => {
+                crate::wrappers::variant_case(ctx, "connect")
+            }
+            crate::bindings::wasi::http::types::Method::Options => {
+                crate::wrappers::variant_case(ctx, "options")
+            }
+            crate::bindings::wasi::http::types::Method::Trace => {
+                crate::wrappers::variant_case(ctx, "trace")
+            }
+            crate::bindings::wasi::http::types::Method::Patch => {
+                crate::wrappers::variant_case(ctx, "patch")
+            }
+            crate::bindings::wasi::http::types::Method::Other(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "other", crate::wrappers::JsString(inner))
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::wasi::http::types::Method {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "get" => Ok(crate::bindings::wasi::http::types::Method::Get),
+            "head" => Ok(crate::bindings::wasi::http::types::Method::Head),
+            "post" => Ok(crate::bindings::wasi::http::types::Method::Post),
+            "put" => Ok(crate::bindings::wasi::http::types::Method::Put),
+            "delete" => Ok(crate::bindings::wasi::http::types::Method::Delete),
+            "connect" => Ok(crate::bindings::wasi::http::types::Method::Connect),
+            "options" => Ok(crate::bindings::wasi::http::types::Method::Options),
+            "trace" => Ok(crate::bindings::wasi::http::types::Method::Trace),
+            "patch" => Ok(crate::bindings::wasi::http::types::Method::Patch),
+            "other" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(crate::bindings::wasi::http::types::Method::Other(inner.0))
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS method",
+                        "WIT method",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::wasi::http::types::Scheme {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::wasi::http::types::Scheme::Http => {
+                crate::wrappers::variant_case(ctx, "HTTP")
+            }
+            crate::bindings::wasi::http::types::Scheme::Https => {
+                crate::wrappers::variant_case(ctx, "HTTPS")
+            }
+            crate::bindings::wasi::http::types::Scheme::Other(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "other", crate::wrappers::JsString(inner))
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::wasi::http::types::Scheme {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "HTTP" => Ok(crate::bindings::wasi::http::types::Scheme::Http),
+            "HTTPS" => Ok(crate::bindings::wasi::http::types::Scheme::Https),
+            "other" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(crate::bindings::wasi::http::types::Scheme::Other(inner.0))
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS scheme",
+                        "WIT scheme",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::wasi::http::types::DnsErrorPayload {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >("rcode", self.rcode.map(|v| crate::wrappers::JsString(v)))?;
+        obj.set::<_, Option<u16>>("infoCode", self.info_code.map(|v| v))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::wasi::http::types::DnsErrorPayload {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT DNS-error-payload",
+            &["rcode", "infoCode"],
+            &[],
+            false,
+        )?;
+        let rcode: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "rcode",
+            "option<string>",
+        )?;
+        let rcode: Option<String> = rcode.map(|v| v.0);
+        let info_code: Option<u16> = crate::wrappers::get_field(
+            &obj,
+            "infoCode",
+            "option<u16>",
+        )?;
+        let info_code: Option<u16> = info_code.map(|v| v);
+        Ok(Self { rcode, info_code })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::wasi::http::types::TlsAlertReceivedPayload {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<_, Option<u8>>("alertId", self.alert_id.map(|v| v))?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >("alertMessage", self.alert_message.map(|v| crate::wrappers::JsString(v)))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::wasi::http::types::TlsAlertReceivedPayload {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT TLS-alert-received-payload",
+            &["alertId", "alertMessage"],
+            &[],
+            false,
+        )?;
+        let alert_id: Option<u8> = crate::wrappers::get_field(
+            &obj,
+            "alertId",
+            "option<u8>",
+        )?;
+        let alert_id: Option<u8> = alert_id.map(|v| v);
+        let alert_message: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "alertMessage",
+            "option<string>",
+        )?;
+        let alert_message: Option<String> = alert_message.map(|v| v.0);
+        Ok(Self { alert_id, alert_message })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::wasi::http::types::FieldSizePayload {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >("fieldName", self.field_name.map(|v| crate::wrappers::JsString(v)))?;
+        obj.set::<_, Option<u32>>("fieldSize", self.field_size.map(|v| v))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::wasi::http::types::FieldSizePayload {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT field-size-payload",
+            &["fieldName", "fieldSize"],
+            &[],
+            false,
+        )?;
+        let field_name: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "fieldName",
+            "option<string>",
+        )?;
+        let field_name: Option<String> = field_name.map(|v| v.0);
+        let field_size: Option<u32> = crate::wrappers::get_field(
+            &obj,
+            "fieldSize",
+            "option<u32>",
+        )?;
+        let field_size: Option<u32> = field_size.map(|v| v);
+        Ok(Self { field_name, field_size })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::wasi::http::types::ErrorCode {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::wasi::http::types::ErrorCode::DnsTimeout => {
+                crate::wrappers::variant_case(ctx, "DNS-timeout")
+            }
+            crate::bindings::wasi::http::types::ErrorCode::DnsError(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::wasi::http::types::DnsErrorPayload,
+                >(ctx, "DNS-error", inner)
+            }
+            crate::bindings::wasi::http::types::ErrorCode::DestinationNotFound => {
+                crate::wrappers::variant_case(ctx, "destination-not-found")
+            }
+            crate::bindings::wasi::http::types::ErrorCode::DestinationUnavailable => {
+                crate::wrappers::variant_case(ctx, "destination-unavailable")
+            }
+            crate::bindings::wasi::http::types::ErrorCode::DestinationIpProhibited => {
+                crate::wrappers::variant_case(ctx, "destination-IP-prohibited")
+            }
+            crate::bindings::wasi::http::types::ErrorCode::DestinationIpUnroutable => {
+                crate::wrappers::variant_case(ctx, "destination-IP-unroutable")
+            }
+            crate::bindings::wasi::http::types::ErrorCode::ConnectionRefused => {
+                crate::wrappers::variant_case(ctx, "connection-refused")
+            }
+            crate::bindings::wasi::http::types::ErrorCode::ConnectionTerminated => {
+                crate::wrappers::variant_case(ctx, "connection-terminated")
+            }
+            crate::bindings::wasi::http::types::ErrorCode::ConnectionTimeout => {
+                crate::wrappers::variant_case(ctx, "connection-timeout")
+            }
+            crate::bindings::wasi::http::types::ErrorCode::ConnectionReadTimeout => {
+                crate::wrappers::variant_case(ctx, "connection-read-timeout")
+            }
+            crate::bindings::wasi::http::types::ErrorCode::ConnectionWriteTimeout => {
+                crate::wrappers::variant_case(ctx, "connection-write-timeout")
+            }
+            crate::bindings::wasi::http::types::ErrorCode::ConnectionLimitReached => {
+                crate::wrappers::variant_case(ctx, "connection-limit-reached")
+            }
+            crate::bindings::wasi::http::types::ErrorCode::TlsProtocolError => {
+                crate::wrappers::variant_case(ctx, "TLS-protocol-error")
+            }
+            crate::bindings::wasi::http::types::ErrorCode::TlsCertificateError => {
+                crate::wrappers::variant_case(ctx, "TLS-certificate-error")
+            }
+            crate::bindings::wasi::http::types::ErrorCode::TlsAlertReceived(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::wasi::http::types::TlsAlertReceivedPayload,
+                >(ctx, "TLS-alert-received", inner)
+            }
+            crate::bindings::wasi::http::types::ErrorCode::HttpRequestDenied => {
+                crate::wrappers::variant_case(ctx, "HTTP-request-denied")
+            }
+            crate::bindings::wasi::http::types::ErrorCode::HttpRequestLengthRequired => {
+                crate::wrappers::variant_case(ctx, "HTTP-request-length-required")
+            }
+            crate::bindings::wasi::http::types::ErrorCode::HttpRequestBodySize(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    Option<crate::wrappers::BigIntWrapper<u64>>,
+                >(
+                    ctx,
+                    "HTTP-request-body-size",
+                    inner.map(|v| crate::wrappers::BigIntWrapper(v)),
+                )
+            }
+            crate::bindings::wasi::http::types::ErrorCode::HttpRequestMethodInvalid => {
+                crate::wrappers::variant_case(ctx, "HTTP-request-method-invalid")
+            }
+            crate::bindings::wasi::http::types::ErrorCode::HttpRequestUriInvalid => {
+                crate::wrappers::variant_case(ctx, "HTTP-request-URI-invalid")
+            }
+            crate::bindings::wasi::http::types::ErrorCode::HttpRequestUriTooLong => {
+                crate::wrappers::variant_case(ctx, "HTTP-request-URI-too-long")
+            }
+            crate::bindings::wasi::http::types::ErrorCode::HttpRequestHeaderSectionSize(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    Option<u32>,
+                >(ctx, "HTTP-request-header-section-size", inner.map(|v| v))
+            }
+            crate::bindings::wasi::http::types::ErrorCode::HttpRequestHeaderSize(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    Option<crate::bindings::wasi::http::types::FieldSizePayload>,
+                >(ctx, "HTTP-request-header-size", inner.map(|v| v))
+            }
+            crate::bindings::wasi::http::types::ErrorCode::HttpRequestTrailerSectionSize(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    Option<u32>,
+                >(ctx, "HTTP-request-trailer-section-size", inner.map(|v| v))
+            }
+            crate::bindings::wasi::http::types::ErrorCode::HttpRequestTrailerSize(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::wasi::http::types::FieldSizePayload,
+                >(ctx, "HTTP-request-trailer-size", inner)
+            }
+            crate::bindings::wasi::http::types::ErrorCode::HttpResponseIncomplete => {
+                crate::wrappers::variant_case(ctx, "HTTP-response-incomplete")
+            }
+            crate::bindings::wasi::http::types::ErrorCode::HttpResponseHeaderSectionSize(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    Option<u32>,
+                >(ctx, "HTTP-response-header-section-size", inner.map(|v| v))
+            }
+            crate::bindings::wasi::http::types::ErrorCode::HttpResponseHeaderSize(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::wasi::http::types::FieldSizePayload,
+                >(ctx, "HTTP-response-header-size", inner)
+            }
+            crate::bindings::wasi::http::types::ErrorCode::HttpResponseBodySize(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    Option<crate::wrappers::BigIntWrapper<u64>>,
+                >(
+                    ctx,
+                    "HTTP-response-body-size",
+                    inner.map(|v| crate::wrappers::BigIntWrapper(v)),
+                )
+            }
+            crate::bindings::wasi::http::types::ErrorCode::HttpResponseTrailerSectionSize(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    Option<u32>,
+                >(ctx, "HTTP-response-trailer-section-size", inner.map(|v| v))
+            }
+            crate::bindings::wasi::http::types::ErrorCode::HttpResponseTrailerSize(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::wasi::http::types::FieldSizePayload,
+                >(ctx, "HTTP-response-trailer-size", inner)
+            }
+            crate::bindings::wasi::http::types::ErrorCode::HttpResponseTransferCoding(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    Option<crate::wrappers::JsString>,
+                >(
+                    ctx,
+                    "HTTP-response-transfer-coding",
+                    inner.map(|v| crate::wrappers::JsString(v)),
+                )
+            }
+            crate::bindings::wasi::http::types::ErrorCode::HttpResponseContentCoding(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    Option<crate::wrappers::JsString>,
+                >(
+                    ctx,
+                    "HTTP-response-content-coding",
+                    inner.map(|v| crate::wrappers::JsString(v)),
+                )
+            }
+            crate::bindings::wasi::http::types::ErrorCode::HttpResponseTimeout => {
+                crate::wrappers::variant_case(ctx, "HTTP-response-timeout")
+            }
+            crate::bindings::wasi::http::types::ErrorCode::HttpUpgradeFailed => {
+                crate::wrappers::variant_case(ctx, "HTTP-upgrade-failed")
+            }
+            crate::bindings::wasi::http::types::ErrorCode::HttpProtocolError => {
+                crate::wrappers::variant_case(ctx, "HTTP-protocol-error")
+            }
+            crate::bindings::wasi::http::types::ErrorCode::LoopDetected => {
+                crate::wrappers::variant_case(ctx, "loop-detected")
+            }
+            crate::bindings::wasi::http::types::ErrorCode::ConfigurationError => {
+                crate::wrappers::variant_case(ctx, "configuration-error")
+            }
+            crate::bindings::wasi::http::types::ErrorCode::InternalError(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    Option<crate::wrappers::JsString>,
+                >(ctx, "internal-error", inner.map(|v| crate::wrappers::JsString(v)))
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::wasi::http::types::ErrorCode {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "DNS-timeout" => {
+                Ok(crate::bindings::wasi::http::types::ErrorCode::DnsTimeout)
+            }
+            "DNS-error" => {
+                let inner: crate::bindings::wasi::http::types::DnsErrorPayload = crate::wrappers::variant_value(
+                    &obj,
+                    "DNS-error-payload",
+                )?;
+                Ok(crate::bindings::wasi::http::types::ErrorCode::DnsError(inner))
+            }
+            "destination-not-found" => {
+                Ok(crate::bindings::wasi::http::types::ErrorCode::DestinationNotFound)
+            }
+            "destination-unavailable" => {
+                Ok(crate::bindings::wasi::http::types::ErrorCode::DestinationUnavailable)
+            }
+            "destination-IP-prohibited" => {
+                Ok(
+                    crate::bindings::wasi::http::types::ErrorCode::DestinationIpProhibited,
+                )
+            }
+            "destination-IP-unroutable" => {
+                Ok(
+                    crate::bindings::wasi::http::types::ErrorCode::DestinationIpUnroutable,
+                )
+            }
+            "connection-refused" => {
+                Ok(crate::bindings::wasi::http::types::ErrorCode::ConnectionRefused)
+            }
+            "connection-terminated" => {
+                Ok(crate::bindings::wasi::http::types::ErrorCode::ConnectionTerminated)
+            }
+            "connection-timeout" => {
+                Ok(crate::bindings::wasi::http::types::ErrorCode::ConnectionTimeout)
+            }
+            "connection-read-timeout" => {
+                Ok(crate::bindings::wasi::http::types::ErrorCode::ConnectionReadTimeout)
+            }
+            "connection-write-timeout" => {
+                Ok(crate::bindings::wasi::http::types::ErrorCode::ConnectionWriteTimeout)
+            }
+            "connection-limit-reached" => {
+                Ok(crate::bindings::wasi::http::types::ErrorCode::ConnectionLimitReached)
+            }
+            "TLS-protocol-error" => {
+                Ok(crate::bindings::wasi::http::types::ErrorCode::TlsProtocolError)
+            }
+            "TLS-certificate-error" => {
+                Ok(crate::bindings::wasi::http::types::ErrorCode::TlsCertificateError)
+            }
+            "TLS-alert-received" => {
+                let inner: crate::bindings::wasi::http::types::TlsAlertReceivedPayload = crate::wrappers::variant_value(
+                    &obj,
+                    "TLS-alert-received-payload",
+                )?;
+                Ok(
+                    crate::bindings::wasi::http::types::ErrorCode::TlsAlertReceived(
+                        inner,
+                    ),
+                )
+            }
+            "HTTP-request-denied" => {
+                Ok(crate::bindings::wasi::http::types::ErrorCode::HttpRequestDenied)
+            }
+            "HTTP-request-length-required" => {
+                Ok(
+                    crate::bindings::wasi::http::types::ErrorCode::HttpRequestLengthRequired,
+                )
+            }
+            "HTTP-request-body-size" => {
+                let inner: Option<crate::wrappers::BigIntWrapper<u64>> = crate::wrappers::variant_value(
+                    &obj,
+                    "option<u64>",
+                )?;
+                Ok(
+                    crate::bindings::wasi::http::types::ErrorCode::HttpRequestBodySize(
+                        inner.map(|v| v.0),
+                    ),
+                )
+            }
+            "HTTP-request-method-invalid" => {
+                Ok(
+                    crate::bindings::wasi::http::types::ErrorCode::HttpRequestMethodInvalid,
+                )
+            }
+            "HTTP-request-URI-invalid" => {
+                Ok(crate::bindings::wasi::http::types::ErrorCode::HttpRequestUriInvalid)
+            }
+            "HTTP-request-URI-too-long" => {
+                Ok(crate::bindings::wasi::http::types::ErrorCode::HttpRequestUriTooLong)
+            }
+            "HTTP-request-header-section-size" => {
+                let inner: Option<u32> = crate::wrappers::variant_value(
+                    &obj,
+                    "option<u32>",
+                )?;
+                Ok(
+                    crate::bindings::wasi::http::types::ErrorCode::HttpRequestHeaderSectionSize(
+                        inner.map(|v| v),
+                    ),
+                )
+            }
+            "HTTP-request-header-size" => {
+                let inner: Option<
+                    crate::bindings::wasi::http::types::FieldSizePayload,
+                > = crate::wrappers::variant_value(&obj, "option<field-size-payload>")?;
+                Ok(
+                    crate::bindings::wasi::http::types::ErrorCode::HttpRequestHeaderSize(
+                        inner.map(|v| v),
+                    ),
+                )
+            }
+            "HTTP-request-trailer-section-size" => {
+                let inner: Option<u32> = crate::wrappers::variant_value(
+                    &obj,
+                    "option<u32>",
+                )?;
+                Ok(
+                    crate::bindings::wasi::http::types::ErrorCode::HttpRequestTrailerSectionSize(
+                        inner.map(|v| v),
+                    ),
+                )
+            }
+            "HTTP-request-trailer-size" => {
+                let inner: crate::bindings::wasi::http::types::FieldSizePayload = crate::wrappers::variant_value(
+                    &obj,
+                    "field-size-payload",
+                )?;
+                Ok(
+                    crate::bindings::wasi::http::types::ErrorCode::HttpRequestTrailerSize(
+                        inner,
+                    ),
+                )
+            }
+            "HTTP-response-incomplete" => {
+                Ok(crate::bindings::wasi::http::types::ErrorCode::HttpResponseIncomplete)
+            }
+            "HTTP-response-header-section-size" => {
+                let inner: Option<u32> = crate::wrappers::variant_value(
+                    &obj,
+                    "option<u32>",
+                )?;
+                Ok(
+                    crate::bindings::wasi::http::types::ErrorCode::HttpResponseHeaderSectionSize(
+                        inner.map(|v| v),
+                    ),
+                )
+            }
+            "HTTP-response-header-size" => {
+                let inner: crate::bindings::wasi::http::types::FieldSizePayload = crate::wrappers::variant_value(
+                    &obj,
+                    "field-size-payload",
+                )?;
+                Ok(
+                    crate::bindings::wasi::http::types::ErrorCode::HttpResponseHeaderSize(
+                        inner,
+                    ),
+                )
+            }
+            "HTTP-response-body-size" => {
+                let inner: Option<crate::wrappers::BigIntWrapper<u64>> = crate::wrappers::variant_value(
+                    &obj,
+                    "option<u64>",
+                )?;
+                Ok(
+                    crate::bindings::wasi::http::types::ErrorCode::HttpResponseBodySize(
+                        inner.map(|v| v.0),
+                    ),
+                )
+            }
+            "HTTP-response-trailer-section-size" => {
+                let inner: Option<u32> = crate::wrappers::variant_value(
+                    &obj,
+                    "option<u32>",
+                )?;
+                Ok(
+                    crate::bindings::wasi::http::types::ErrorCode::HttpResponseTrailerSectionSize(
+                        inner.map(|v| v),
+                    ),
+                )
+            }
+            "HTTP-response-trailer-size" => {
+                let inner: crate::bindings::wasi::http::types::FieldSizePayload = crate::wrappers::variant_value(
+                    &obj,
+                    "field-size-payload",
+                )?;
+                Ok(
+                    crate::bindings::wasi::http::types::ErrorCode::HttpResponseTrailerSize(
+                        inner,
+                    ),
+                )
+            }
+            "HTTP-response-transfer-coding" => {
+                let inner: Option<crate::wrappers::JsString> = crate::wrappers::variant_value(
+                    &obj,
+                    "option<string>",
+                )?;
+                Ok(
+                    crate::bindings::wasi::http::types::ErrorCode::HttpResponseTransferCoding(
+                        inner.map(|v| v.0),
+                    ),
+                )
+            }
+            "HTTP-response-content-coding" => {
+                let inner: Option<crate::wrappers::JsString> = crate::wrappers::variant_value(
+                    &obj,
+                    "option<string>",
+                )?;
+                Ok(
+                    crate::bindings::wasi::http::types::ErrorCode::HttpResponseContentCoding(
+                        inner.map(|v| v.0),
+                    ),
+                )
+            }
+            "HTTP-response-timeout" => {
+                Ok(crate::bindings::wasi::http::types::ErrorCode::HttpResponseTimeout)
+            }
+            "HTTP-upgrade-failed" => {
+                Ok(crate::bindings::wasi::http::types::ErrorCode::HttpUpgradeFailed)
+            }
+            "HTTP-protocol-error" => {
+                Ok(crate::bindings::wasi::http::types::ErrorCode::HttpProtocolError)
+            }
+            "loop-detected" => {
+                Ok(crate::bindings::wasi::http::types::ErrorCode::LoopDetected)
+            }
+            "configuration-error" => {
+                Ok(crate::bindings::wasi::http::types::ErrorCode::ConfigurationError)
+            }
+            "internal-error" => {
+                let inner: Option<crate::wrappers::JsString> = crate::wrappers::variant_value(
+                    &obj,
+                    "option<string>",
+                )?;
+                Ok(
+                    crate::bindings::wasi::http::types::ErrorCode::InternalError(
+                        inner.map(|v| v.0),
+                    ),
+                )
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS error-code",
+                        "WIT error-code",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::wasi::http::types::HeaderError {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::wasi::http::types::HeaderError::InvalidSyntax => {
+                crate::wrappers::variant_case(ctx, "invalid-syntax")
+            }
+            crate::bindings::wasi::http::types::HeaderError::Forbidden => {
+                crate::wrappers::variant_case(ctx, "forbidden")
+            }
+            crate::bindings::wasi::http::types::HeaderError::Immutable => {
+                crate::wrappers::variant_case(ctx, "immutable")
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::wasi::http::types::HeaderError {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (_, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "invalid-syntax" => {
+                Ok(crate::bindings::wasi::http::types::HeaderError::InvalidSyntax)
+            }
+            "forbidden" => Ok(crate::bindings::wasi::http::types::HeaderError::Forbidden),
+            "immutable" => Ok(crate::bindings::wasi::http::types::HeaderError::Immutable),
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS header-error",
+                        "WIT header-error",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
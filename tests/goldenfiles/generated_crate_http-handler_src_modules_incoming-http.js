@@ -0,0 +1,91 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:http-handler/http-handler
+// wit: sha256:50e4a1bebab498e752ae328835ec3c85cfd815c061dfa531fe9a22921a588468
+// js http-handler: sha256:ab1143b2ac83255c843b7f985e768100c0f6825bbfa8176896d9f51451db357e
+
+import * as types from 'wasi:http/types@0.2.3';
+import * as httpNative from '__wasm_rquickjs_builtin/http_native';
+
+// The number of bytes read from the body stream at once
+const CHUNK_SIZE = 4096n;
+
+// The limits of the `http` feature, read for every request as they can be overridden by
+// environment variables. Without the feature, nothing is limited.
+function limit(name) {
+    return typeof httpNative[name] === 'function' ? httpNative[name]() : undefined;
+}
+
+function readHeaders(request) {
+    const fields = request.headers();
+    const entries = fields.entries();
+    fields[Symbol.dispose]();
+
+    const maxHeaderCount = limit('max_header_count');
+    if (maxHeaderCount !== undefined && entries.length > maxHeaderCount) {
+        throw new TypeError(`The HTTP request has ${entries.length} headers, exceeding the limit of ${maxHeaderCount} headers`);
+    }
+    const headers = {};
+    const decoder = new TextDecoder();
+    for (const [name, value] of entries) {
+        const text = decoder.decode(value);
+        headers[name] = headers[name] === undefined ? text : `${headers[name]}, ${text}`;
+    }
+    return headers;
+}
+
+function readBody(request) {
+    const maxBodySize = limit('max_request_body_size');
+    const body = request.consume();
+    const stream = body.stream();
+    const chunks = [];
+    let size = 0;
+    try {
+        for (;;) {
+            let chunk;
+            try {
+                chunk = stream.blockingRead(CHUNK_SIZE);
+            } catch (error) {
+                if (error?.tag === 'closed') {
+                    break;
+                }
+                throw new TypeError(`Failed to read the HTTP request body: ${error?.tag ?? error}`, { cause: error });
+            }
+            size += chunk.length;
+            // Checked for every chunk, so an oversized body is never kept in memory as a whole
+            if (maxBodySize !== undefined && size > maxBodySize) {
+                throw new TypeError(`The HTTP request body exceeds the limit of ${maxBodySize} bytes`);
+            }
+            chunks.push(chunk);
+        }
+    } finally {
+        // The stream must be dropped before its body
+        stream[Symbol.dispose]();
+        body[Symbol.dispose]();
+    }
+
+    const bytes = new Uint8Array(size);
+    let offset = 0;
+    for (const chunk of chunks) {
+        bytes.set(chunk, offset);
+        offset += chunk.length;
+    }
+    return bytes;
+}
+
+function method(request) {
+    const method = request.method();
+    return method.tag === 'other' ? method.val : method.tag.toUpperCase();
+}
+
+export function readRequest(request) {
+    const headers = readHeaders(request);
+    const body = readBody(request);
+    return {
+        method: method(request),
+        pathWithQuery: request.pathWithQuery(),
+        headers,
+        body,
+        text: () => new TextDecoder().decode(body),
+        json: () => JSON.parse(new TextDecoder().decode(body)),
+    };
+}
@@ -0,0 +1,97 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:http-handler/http-handler
+// wit: sha256:50e4a1bebab498e752ae328835ec3c85cfd815c061dfa531fe9a22921a588468
+// js http-handler: sha256:ab1143b2ac83255c843b7f985e768100c0f6825bbfa8176896d9f51451db357e
+
+use rquickjs::JsLifetime;
+use rquickjs::class::{JsClass, Trace};
+use std::ops::Deref;
+pub mod wasi_io_0_2_3_poll;
+pub mod wasi_clocks_0_2_3_monotonic_clock;
+pub mod wasi_io_0_2_3_error;
+pub mod wasi_io_0_2_3_streams;
+pub mod wasi_http_0_2_3_types;
+const NATIVE_MODULE_NAMES: &[&str] = &[
+    "wasi:io/poll@0.2.3",
+    "wasi:clocks/monotonic-clock@0.2.3",
+    "wasi:io/error@0.2.3",
+    "wasi:io/streams@0.2.3",
+    "wasi:http/types@0.2.3",
+    "incoming-http",
+];
+pub struct NativeModuleResolver;
+impl rquickjs::loader::Resolver for NativeModuleResolver {
+    fn resolve<'js>(
+        &mut self,
+        _ctx: &rquickjs::Ctx<'js>,
+        base: &str,
+        name: &str,
+    ) -> rquickjs::Result<String> {
+        if NATIVE_MODULE_NAMES.contains(&name) {
+            Ok(name.to_string())
+        } else {
+            Err(rquickjs::Error::new_resolving(base, name))
+        }
+    }
+}
+pub struct NativeModuleLoader;
+impl rquickjs::loader::Loader for NativeModuleLoader {
+    fn load<'js>(
+        &mut self,
+        ctx: &rquickjs::Ctx<'js>,
+        name: &str,
+    ) -> rquickjs::Result<rquickjs::Module<'js, rquickjs::module::Declared>> {
+        match name {
+            "wasi:io/poll@0.2.3" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::wasi_io_0_2_3_poll::JsPollModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "wasi:clocks/monotonic-clock@0.2.3" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::wasi_clocks_0_2_3_monotonic_clock::JsMonotonicClockModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "wasi:io/error@0.2.3" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::wasi_io_0_2_3_error::JsErrorModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "wasi:io/streams@0.2.3" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::wasi_io_0_2_3_streams::JsStreamsModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "wasi:http/types@0.2.3" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::wasi_http_0_2_3_types::JsTypesModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "incoming-http" => {
+                rquickjs::Module::declare(
+                    ctx.clone(),
+                    name,
+                    include_str!("incoming-http.js"),
+                )
+            }
+            _ => Err(rquickjs::Error::new_loading(name)),
+        }
+    }
+}
+pub struct JsHttpHandlerModule;
+impl rquickjs::module::ModuleDef for JsHttpHandlerModule {
+    fn declare(decl: &rquickjs::module::Declarations) -> rquickjs::Result<()> {
+        Ok(())
+    }
+    fn evaluate<'js>(
+        ctx: &rquickjs::Ctx<'js>,
+        exports: &rquickjs::module::Exports<'js>,
+    ) -> rquickjs::Result<()> {
+        Ok(())
+    }
+}
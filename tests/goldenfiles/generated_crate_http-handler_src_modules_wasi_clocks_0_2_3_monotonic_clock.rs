@@ -0,0 +1,56 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:http-handler/http-handler
+// wit: sha256:50e4a1bebab498e752ae328835ec3c85cfd815c061dfa531fe9a22921a588468
+// js http-handler: sha256:ab1143b2ac83255c843b7f985e768100c0f6825bbfa8176896d9f51451db357e
+
+use rquickjs::JsLifetime;
+use rquickjs::class::{JsClass, Trace};
+use std::ops::Deref;
+#[rquickjs::function]
+fn now() -> crate::wrappers::BigIntWrapper<u64> {
+    let result: crate::bindings::wasi::clocks::monotonic_clock::Instant = crate::bindings::wasi::clocks::monotonic_clock::now();
+    crate::wrappers::BigIntWrapper(result)
+}
+#[rquickjs::function]
+fn resolution() -> crate::wrappers::BigIntWrapper<u64> {
+    let result: crate::bindings::wasi::clocks::monotonic_clock::Duration = crate::bindings::wasi::clocks::monotonic_clock::resolution();
+    crate::wrappers::BigIntWrapper(result)
+}
+#[rquickjs::function]
+fn subscribe_instant(
+    when: crate::wrappers::BigIntWrapper<u64>,
+) -> crate::bindings::wasi::clocks::monotonic_clock::Pollable {
+    let result: crate::bindings::wasi::clocks::monotonic_clock::Pollable = crate::bindings::wasi::clocks::monotonic_clock::subscribe_instant(
+        when.0,
+    );
+    result
+}
+#[rquickjs::function]
+fn subscribe_duration(
+    when: crate::wrappers::BigIntWrapper<u64>,
+) -> crate::bindings::wasi::clocks::monotonic_clock::Pollable {
+    let result: crate::bindings::wasi::clocks::monotonic_clock::Pollable = crate::bindings::wasi::clocks::monotonic_clock::subscribe_duration(
+        when.0,
+    );
+    result
+}
+pub struct JsMonotonicClockModule;
+impl rquickjs::module::ModuleDef for JsMonotonicClockModule {
+    fn declare(decl: &rquickjs::module::Declarations) -> rquickjs::Result<()> {
+        decl.declare("now")?;
+        decl.declare("resolution")?;
+        decl.declare("subscribeInstant")?;
+        decl.declare("subscribeDuration")?;
+        Ok(())
+    }
+    fn evaluate<'js>(
+        ctx: &rquickjs::Ctx<'js>,
+        exports: &rquickjs::module::Exports<'js>,
+    ) -> rquickjs::Result<()> {
+        exports.export("now", js_now)?;
+        exports.export("resolution", js_resolution)?;
+        exports.export("subscribeInstant", js_subscribe_instant)?;
+        exports.export("subscribeDuration", js_subscribe_duration)?;
+        Ok(())
+    }
+}
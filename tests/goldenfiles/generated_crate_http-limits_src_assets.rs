@@ -0,0 +1,6 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:http-limits/http-limits
+// wit: sha256:c3bbe48ee3128d9072ebddc87d61f44df250dc4168acec302de144c09c900b94
+// js http-limits: sha256:ae83e3b274822933fe5aa24dd99fa02d05a61194122cdc15681a373c437af0f1
+
+pub static ASSETS: &[(&str, &[u8])] = &[];
@@ -0,0 +1,6 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:ids/ids
+// wit: sha256:5cf723036b16e4c612e8f00bde9f13ee92036edcbe849d35aa1f9a258a0680d1
+// js ids: sha256:b6921dce37576b0e720396b48a8cd22a3834bb23ecea0106c1b4418ad961ed6e
+
+pub static ASSETS: &[(&str, &[u8])] = &[];
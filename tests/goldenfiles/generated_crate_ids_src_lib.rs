@@ -0,0 +1,124 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:ids/ids
+// wit: sha256:5cf723036b16e4c612e8f00bde9f13ee92036edcbe849d35aa1f9a258a0680d1
+// js ids: sha256:b6921dce37576b0e720396b48a8cd22a3834bb23ecea0106c1b4418ad961ed6e
+
+#[allow(static_mut_refs)]
+#[allow(unsafe_op_in_unsafe_fn)]
+mod bindings;
+mod assets;
+mod builtin;
+mod conversions;
+#[allow(unused)]
+mod internal;
+#[allow(unused)]
+mod modules;
+mod wrappers;
+static JS_EXPORT_MODULE_NAME: &str = "ids";
+static JS_EXPORT_NAMES_VERBATIM: bool = false;
+static INTERN_FIELD_NAMES: bool = false;
+#[allow(dead_code)]
+static REPLACE_LONE_SURROGATES: bool = false;
+static MATH_RANDOM_SEED: crate::builtin::MathRandomSeed = crate::builtin::MathRandomSeed::Default;
+static DATE_CLOCK: Option<fn() -> f64> = None;
+static IMPORT_HOOKS: bool = false;
+static MOCK_IMPORTS: bool = false;
+static IMPORT_RECORDING: crate::builtin::ImportRecording = crate::builtin::ImportRecording::None;
+static CANCEL_BACKGROUND_WORK: bool = false;
+static HTTP_LIMITS: crate::builtin::HttpLimits = crate::builtin::HttpLimits::UNLIMITED;
+#[allow(dead_code)]
+static COOKIE_JAR: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE_LIMITS: crate::builtin::HttpCacheLimits = crate::builtin::HttpCacheLimits {
+    max_entries: Some(256usize),
+    max_size: Some(16777216usize),
+    max_body_size: Some(1048576usize),
+};
+#[allow(dead_code)]
+static FETCH_BASE_URL: Option<&str> = None;
+#[allow(dead_code)]
+static FETCH_GATEWAY: Option<&str> = None;
+static JS_EXPORT_MODULE: &str = include_str!("ids.js");
+static JS_ADDITIONAL_MODULES: std::sync::LazyLock<
+    Vec<(&str, Box<dyn (Fn() -> String) + Send + Sync>)>,
+> = std::sync::LazyLock::new(|| { vec![] });
+static JS_MODULE_ORDER: &[&str] = &["ids"];
+static JS_BUILTIN_PERMISSIONS: &[(&str, &[&str])] = &[];
+struct Component;
+impl crate::bindings::Guest for Component {
+    fn uuid_v4() -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:ids",
+                    0usize,
+                    &["uuidV4"],
+                    crate::wrappers::JsArgs(()),
+                )
+                .await;
+            result.0
+        })
+    }
+    fn uuid_v7() -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:ids",
+                    1usize,
+                    &["uuidV7"],
+                    crate::wrappers::JsArgs(()),
+                )
+                .await;
+            result.0
+        })
+    }
+    fn ulid() -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:ids",
+                    2usize,
+                    &["ulid"],
+                    crate::wrappers::JsArgs(()),
+                )
+                .await;
+            result.0
+        })
+    }
+    fn uuid_version(id: String) -> Option<u8> {
+        crate::internal::async_exported_function(async move {
+            let result: Option<u8> = crate::internal::call_js_export(
+                    "quickjs:ids",
+                    3usize,
+                    &["uuidVersion"],
+                    (crate::wrappers::JsString(id),),
+                )
+                .await;
+            result.map(|v| v)
+        })
+    }
+    fn ulid_time(id: String) -> Option<u64> {
+        crate::internal::async_exported_function(async move {
+            let result: Option<crate::wrappers::BigIntWrapper<u64>> = crate::internal::call_js_export(
+                    "quickjs:ids",
+                    4usize,
+                    &["ulidTime"],
+                    (crate::wrappers::JsString(id),),
+                )
+                .await;
+            result.map(|v| v.0)
+        })
+    }
+    fn in_order(count: u32) -> bool {
+        crate::internal::async_exported_function(async move {
+            let result: bool = crate::internal::call_js_export(
+                    "quickjs:ids",
+                    5usize,
+                    &["inOrder"],
+                    (count,),
+                )
+                .await;
+            result
+        })
+    }
+}
+bindings::export!(Component with_types_in bindings);
@@ -0,0 +1,6 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:import-hooks/import-hooks
+// wit: sha256:5630e7138f5d717cdfd1ad0a41669e0cca54dba0349fd0d45efa784d6c34ab77
+// js import-hooks: sha256:5d72fefc2cf19993d699ae0f9947b7b4e8bfaabaf98adb3cd4e5d7f167f33c37
+
+pub static ASSETS: &[(&str, &[u8])] = &[];
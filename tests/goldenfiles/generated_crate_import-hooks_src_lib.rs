@@ -0,0 +1,88 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:import-hooks/import-hooks
+// wit: sha256:5630e7138f5d717cdfd1ad0a41669e0cca54dba0349fd0d45efa784d6c34ab77
+// js import-hooks: sha256:5d72fefc2cf19993d699ae0f9947b7b4e8bfaabaf98adb3cd4e5d7f167f33c37
+
+#[allow(static_mut_refs)]
+#[allow(unsafe_op_in_unsafe_fn)]
+mod bindings;
+mod assets;
+mod builtin;
+mod conversions;
+#[allow(unused)]
+mod internal;
+#[allow(unused)]
+mod modules;
+mod wrappers;
+static JS_EXPORT_MODULE_NAME: &str = "import-hooks";
+static JS_EXPORT_NAMES_VERBATIM: bool = false;
+static INTERN_FIELD_NAMES: bool = false;
+#[allow(dead_code)]
+static REPLACE_LONE_SURROGATES: bool = false;
+static MATH_RANDOM_SEED: crate::builtin::MathRandomSeed = crate::builtin::MathRandomSeed::Default;
+static DATE_CLOCK: Option<fn() -> f64> = None;
+static IMPORT_HOOKS: bool = true;
+static MOCK_IMPORTS: bool = false;
+static IMPORT_RECORDING: crate::builtin::ImportRecording = crate::builtin::ImportRecording::None;
+static CANCEL_BACKGROUND_WORK: bool = false;
+static HTTP_LIMITS: crate::builtin::HttpLimits = crate::builtin::HttpLimits::UNLIMITED;
+#[allow(dead_code)]
+static COOKIE_JAR: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE_LIMITS: crate::builtin::HttpCacheLimits = crate::builtin::HttpCacheLimits {
+    max_entries: Some(256usize),
+    max_size: Some(16777216usize),
+    max_body_size: Some(1048576usize),
+};
+#[allow(dead_code)]
+static FETCH_BASE_URL: Option<&str> = None;
+#[allow(dead_code)]
+static FETCH_GATEWAY: Option<&str> = None;
+static JS_EXPORT_MODULE: &str = include_str!("import-hooks.js");
+static JS_ADDITIONAL_MODULES: std::sync::LazyLock<
+    Vec<(&str, Box<dyn (Fn() -> String) + Send + Sync>)>,
+> = std::sync::LazyLock::new(|| { vec![] });
+static JS_MODULE_ORDER: &[&str] = &["import-hooks"];
+static JS_BUILTIN_PERMISSIONS: &[(&str, &[&str])] = &[];
+struct Component;
+impl crate::bindings::exports::quickjs::import_hooks::api::Guest for Component {
+    fn mocked_number() -> u64 {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::BigIntWrapper<u64> = crate::internal::call_js_export(
+                    "quickjs:import-hooks",
+                    0usize,
+                    &["api", "mockedNumber"],
+                    crate::wrappers::JsArgs(()),
+                )
+                .await;
+            result.0
+        })
+    }
+    fn byte_count(len: u32) -> u32 {
+        crate::internal::async_exported_function(async move {
+            let result: u32 = crate::internal::call_js_export(
+                    "quickjs:import-hooks",
+                    1usize,
+                    &["api", "byteCount"],
+                    (len,),
+                )
+                .await;
+            result
+        })
+    }
+    fn calls() -> Vec<String> {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsList<crate::wrappers::JsString> = crate::internal::call_js_export(
+                    "quickjs:import-hooks",
+                    2usize,
+                    &["api", "calls"],
+                    crate::wrappers::JsArgs(()),
+                )
+                .await;
+            result.0.into_iter().map(|v| v.0).collect::<Vec<_>>()
+        })
+    }
+}
+bindings::export!(Component with_types_in bindings);
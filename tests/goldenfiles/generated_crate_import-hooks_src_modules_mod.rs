@@ -0,0 +1,55 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:import-hooks/import-hooks
+// wit: sha256:5630e7138f5d717cdfd1ad0a41669e0cca54dba0349fd0d45efa784d6c34ab77
+// js import-hooks: sha256:5d72fefc2cf19993d699ae0f9947b7b4e8bfaabaf98adb3cd4e5d7f167f33c37
+
+use rquickjs::JsLifetime;
+use rquickjs::class::{JsClass, Trace};
+use std::ops::Deref;
+pub mod wasi_random_0_2_3_random;
+const NATIVE_MODULE_NAMES: &[&str] = &["wasi:random/random@0.2.3"];
+pub struct NativeModuleResolver;
+impl rquickjs::loader::Resolver for NativeModuleResolver {
+    fn resolve<'js>(
+        &mut self,
+        _ctx: &rquickjs::Ctx<'js>,
+        base: &str,
+        name: &str,
+    ) -> rquickjs::Result<String> {
+        if NATIVE_MODULE_NAMES.contains(&name) {
+            Ok(name.to_string())
+        } else {
+            Err(rquickjs::Error::new_resolving(base, name))
+        }
+    }
+}
+pub struct NativeModuleLoader;
+impl rquickjs::loader::Loader for NativeModuleLoader {
+    fn load<'js>(
+        &mut self,
+        ctx: &rquickjs::Ctx<'js>,
+        name: &str,
+    ) -> rquickjs::Result<rquickjs::Module<'js, rquickjs::module::Declared>> {
+        match name {
+            "wasi:random/random@0.2.3" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::wasi_random_0_2_3_random::JsRandomModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            _ => Err(rquickjs::Error::new_loading(name)),
+        }
+    }
+}
+pub struct JsImportHooksModule;
+impl rquickjs::module::ModuleDef for JsImportHooksModule {
+    fn declare(decl: &rquickjs::module::Declarations) -> rquickjs::Result<()> {
+        Ok(())
+    }
+    fn evaluate<'js>(
+        ctx: &rquickjs::Ctx<'js>,
+        exports: &rquickjs::module::Exports<'js>,
+    ) -> rquickjs::Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,54 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:import-hooks/import-hooks
+// wit: sha256:5630e7138f5d717cdfd1ad0a41669e0cca54dba0349fd0d45efa784d6c34ab77
+// js import-hooks: sha256:5d72fefc2cf19993d699ae0f9947b7b4e8bfaabaf98adb3cd4e5d7f167f33c37
+
+use rquickjs::JsLifetime;
+use rquickjs::class::{JsClass, Trace};
+use std::ops::Deref;
+#[rquickjs::function]
+fn get_random_bytes(
+    len: crate::wrappers::BigIntWrapper<u64>,
+) -> crate::wrappers::UInt8Array {
+    let result: Vec<u8> = crate::bindings::wasi::random::random::get_random_bytes(len.0);
+    crate::wrappers::UInt8Array(result)
+}
+#[rquickjs::function]
+fn get_random_u64() -> crate::wrappers::BigIntWrapper<u64> {
+    let result: u64 = crate::bindings::wasi::random::random::get_random_u64();
+    crate::wrappers::BigIntWrapper(result)
+}
+pub struct JsRandomModule;
+impl rquickjs::module::ModuleDef for JsRandomModule {
+    fn declare(decl: &rquickjs::module::Declarations) -> rquickjs::Result<()> {
+        decl.declare("getRandomBytes")?;
+        decl.declare("getRandomU64")?;
+        Ok(())
+    }
+    fn evaluate<'js>(
+        ctx: &rquickjs::Ctx<'js>,
+        exports: &rquickjs::module::Exports<'js>,
+    ) -> rquickjs::Result<()> {
+        exports
+            .export(
+                "getRandomBytes",
+                crate::builtin::hook_import_function(
+                    ctx,
+                    "wasi:random/random@0.2.3",
+                    "getRandomBytes",
+                    js_get_random_bytes,
+                )?,
+            )?;
+        exports
+            .export(
+                "getRandomU64",
+                crate::builtin::hook_import_function(
+                    ctx,
+                    "wasi:random/random@0.2.3",
+                    "getRandomU64",
+                    js_get_random_u64,
+                )?,
+            )?;
+        Ok(())
+    }
+}
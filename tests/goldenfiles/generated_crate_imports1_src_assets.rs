@@ -0,0 +1,6 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:imports1/imports1
+// wit: sha256:0ae3d4616db4990e5b7d986efc2ee213fdbb16a3c1ad0f5e87114f65106ed128
+// js imports1: sha256:cc07042d95d8373b56cd87c92e565c23f3b2bea58804b216a877b44394e94caa
+
+pub static ASSETS: &[(&str, &[u8])] = &[];
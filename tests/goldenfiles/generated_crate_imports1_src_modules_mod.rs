@@ -0,0 +1,55 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:imports1/imports1
+// wit: sha256:0ae3d4616db4990e5b7d986efc2ee213fdbb16a3c1ad0f5e87114f65106ed128
+// js imports1: sha256:cc07042d95d8373b56cd87c92e565c23f3b2bea58804b216a877b44394e94caa
+
+use rquickjs::JsLifetime;
+use rquickjs::class::{JsClass, Trace};
+use std::ops::Deref;
+pub mod wasi_random_0_2_3_random;
+const NATIVE_MODULE_NAMES: &[&str] = &["wasi:random/random@0.2.3"];
+pub struct NativeModuleResolver;
+impl rquickjs::loader::Resolver for NativeModuleResolver {
+    fn resolve<'js>(
+        &mut self,
+        _ctx: &rquickjs::Ctx<'js>,
+        base: &str,
+        name: &str,
+    ) -> rquickjs::Result<String> {
+        if NATIVE_MODULE_NAMES.contains(&name) {
+            Ok(name.to_string())
+        } else {
+            Err(rquickjs::Error::new_resolving(base, name))
+        }
+    }
+}
+pub struct NativeModuleLoader;
+impl rquickjs::loader::Loader for NativeModuleLoader {
+    fn load<'js>(
+        &mut self,
+        ctx: &rquickjs::Ctx<'js>,
+        name: &str,
+    ) -> rquickjs::Result<rquickjs::Module<'js, rquickjs::module::Declared>> {
+        match name {
+            "wasi:random/random@0.2.3" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::wasi_random_0_2_3_random::JsRandomModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            _ => Err(rquickjs::Error::new_loading(name)),
+        }
+    }
+}
+pub struct JsImports1Module;
+impl rquickjs::module::ModuleDef for JsImports1Module {
+    fn declare(decl: &rquickjs::module::Declarations) -> rquickjs::Result<()> {
+        Ok(())
+    }
+    fn evaluate<'js>(
+        ctx: &rquickjs::Ctx<'js>,
+        exports: &rquickjs::module::Exports<'js>,
+    ) -> rquickjs::Result<()> {
+        Ok(())
+    }
+}
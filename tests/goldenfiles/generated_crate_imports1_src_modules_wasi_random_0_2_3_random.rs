@@ -0,0 +1,36 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:imports1/imports1
+// wit: sha256:0ae3d4616db4990e5b7d986efc2ee213fdbb16a3c1ad0f5e87114f65106ed128
+// js imports1: sha256:cc07042d95d8373b56cd87c92e565c23f3b2bea58804b216a877b44394e94caa
+
+use rquickjs::JsLifetime;
+use rquickjs::class::{JsClass, Trace};
+use std::ops::Deref;
+#[rquickjs::function]
+fn get_random_bytes(
+    len: crate::wrappers::BigIntWrapper<u64>,
+) -> crate::wrappers::UInt8Array {
+    let result: Vec<u8> = crate::bindings::wasi::random::random::get_random_bytes(len.0);
+    crate::wrappers::UInt8Array(result)
+}
+#[rquickjs::function]
+fn get_random_u64() -> crate::wrappers::BigIntWrapper<u64> {
+    let result: u64 = crate::bindings::wasi::random::random::get_random_u64();
+    crate::wrappers::BigIntWrapper(result)
+}
+pub struct JsRandomModule;
+impl rquickjs::module::ModuleDef for JsRandomModule {
+    fn declare(decl: &rquickjs::module::Declarations) -> rquickjs::Result<()> {
+        decl.declare("getRandomBytes")?;
+        decl.declare("getRandomU64")?;
+        Ok(())
+    }
+    fn evaluate<'js>(
+        ctx: &rquickjs::Ctx<'js>,
+        exports: &rquickjs::module::Exports<'js>,
+    ) -> rquickjs::Result<()> {
+        exports.export("getRandomBytes", js_get_random_bytes)?;
+        exports.export("getRandomU64", js_get_random_u64)?;
+        Ok(())
+    }
+}
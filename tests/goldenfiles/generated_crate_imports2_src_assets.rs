@@ -0,0 +1,6 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:imports2/imports2
+// wit: sha256:4578efe1209456a7fa4b644612afec7f426af4990809f0b0a9f91a5a0d51dce7
+// js imports2: sha256:70b2646fa1a0999ae4ba9fc7c5533ae89992c37a78935db1f83e262df29e7262
+
+pub static ASSETS: &[(&str, &[u8])] = &[];
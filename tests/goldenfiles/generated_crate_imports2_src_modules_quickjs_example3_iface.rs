@@ -0,0 +1,586 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:imports2/imports2
+// wit: sha256:4578efe1209456a7fa4b644612afec7f426af4990809f0b0a9f91a5a0d51dce7
+// js imports2: sha256:70b2646fa1a0999ae4ba9fc7c5533ae89992c37a78935db1f83e262df29e7262
+
+use rquickjs::JsLifetime;
+use rquickjs::class::{JsClass, Trace};
+use std::ops::Deref;
+#[rquickjs::function]
+fn dump(
+    h: Option<crate::modules::quickjs_example3_iface::BorrowHelloWrapper>,
+) -> crate::wrappers::JsString {
+    let result: String = crate::bindings::quickjs::example3::iface::dump(
+        h.as_ref().map(|v| &v.0),
+    );
+    crate::wrappers::JsString(result)
+}
+#[rquickjs::function]
+fn dump_all(
+    hs: crate::wrappers::JsList<
+        crate::modules::quickjs_example3_iface::BorrowHelloWrapper,
+    >,
+) -> crate::wrappers::JsString {
+    let result: String = crate::bindings::quickjs::example3::iface::dump_all(
+        hs.0.iter().map(|v| &v.0).collect::<Vec<_>>().as_slice(),
+    );
+    crate::wrappers::JsString(result)
+}
+#[derive(Clone, JsLifetime, Trace)]
+pub struct Hello {
+    #[qjs(skip_trace = true)]
+    inner: Option<std::rc::Rc<crate::bindings::quickjs::example3::iface::Hello>>,
+    /// The export call epoch if the handle is only borrowed from the host
+    #[qjs(skip_trace = true)]
+    borrow_epoch: Option<usize>,
+}
+mod __impl_class_hello_ {
+    pub use super::*;
+    use rquickjs::{Atom, Symbol, Value};
+    impl<'js> rquickjs::class::JsClass<'js> for Hello {
+        const NAME: &'static str = "Hello";
+        type Mutable = rquickjs::class::Writable;
+        fn prototype(
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<Option<rquickjs::Object<'js>>> {
+            use rquickjs::class::impl_::MethodImplementor;
+            let proto = rquickjs::Object::new(ctx.clone())?;
+            let implementor = rquickjs::class::impl_::MethodImpl::<Self>::new();
+            (&implementor).implement(&proto)?;
+            let dispose_symbol: Symbol = ctx
+                .globals()
+                .get(crate::internal::DISPOSE_SYMBOL)?;
+            let dispose_fn: Value = proto.get("__dispose")?;
+            proto.set(dispose_symbol, dispose_fn)?;
+            Ok(Some(proto))
+        }
+        fn constructor(
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<Option<rquickjs::function::Constructor<'js>>> {
+            use rquickjs::class::impl_::ConstructorCreator;
+            let implementor = rquickjs::class::impl_::ConstructorCreate::<Self>::new();
+            (&implementor).create_constructor(ctx)
+        }
+    }
+    impl<'js> rquickjs::IntoJs<'js> for Hello {
+        fn into_js(
+            self,
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<rquickjs::Value<'js>> {
+            let cls = rquickjs::class::Class::<Self>::instance(ctx.clone(), self)?;
+            rquickjs::IntoJs::into_js(cls, ctx)
+        }
+    }
+    impl<'js> rquickjs::FromJs<'js> for Hello
+    where
+        for<'a> rquickjs::class::impl_::CloneWrapper<
+            'a,
+            Self,
+        >: rquickjs::class::impl_::CloneTrait<Self>,
+    {
+        fn from_js(
+            ctx: &rquickjs::Ctx<'js>,
+            value: rquickjs::Value<'js>,
+        ) -> rquickjs::Result<Self> {
+            use rquickjs::class::impl_::CloneTrait;
+            let value = rquickjs::class::Class::<Self>::from_js(ctx, value)?;
+            let borrow = value.try_borrow()?;
+            Ok(rquickjs::class::impl_::CloneWrapper(&*borrow).wrap_clone())
+        }
+    }
+}
+impl Hello {
+    /// Gets the wrapped resource, failing if it has been disposed or if it is a
+    /// borrowed handle and the call it was passed to has already returned
+    fn checked_inner(
+        &self,
+    ) -> Result<
+        &std::rc::Rc<crate::bindings::quickjs::example3::iface::Hello>,
+        &'static str,
+    > {
+        if self
+            .borrow_epoch
+            .is_some_and(|epoch| !crate::internal::is_borrow_active(epoch))
+        {
+            return Err(
+                "Borrowed resource handle cannot be used after the call it was passed to has returned",
+            );
+        }
+        self.inner.as_ref().ok_or("Resource has already been disposed")
+    }
+    /// Gets the wrapped resource for calling one of its methods, throwing a
+    /// `ReferenceError` if it cannot be used
+    fn resource(
+        &self,
+        ctx: &rquickjs::Ctx<'_>,
+    ) -> rquickjs::Result<&crate::bindings::quickjs::example3::iface::Hello> {
+        self.checked_inner()
+            .map(|inner| inner.deref())
+            .map_err(|message| rquickjs::Exception::throw_reference(ctx, message))
+    }
+    /// Releases the wrapped resource. Borrowed handles belong to the host, so they
+    /// are never dropped, only forgotten.
+    fn release(&mut self) {
+        if let Some(inner) = self.inner.take() {
+            if self.borrow_epoch.is_some() {
+                if let Ok(inner) = std::rc::Rc::try_unwrap(inner) {
+                    let _ = inner.take_handle();
+                }
+            }
+        }
+    }
+}
+impl Drop for Hello {
+    fn drop(&mut self) {
+        self.release();
+    }
+}
+#[rquickjs::methods(rename_all = "camelCase")]
+impl Hello {
+    #[qjs(constructor)]
+    pub fn new(name: crate::wrappers::JsString) -> Self {
+        Self {
+            inner: Some(
+                std::rc::Rc::new(
+                    crate::bindings::quickjs::example3::iface::Hello::new(
+                        name.0.as_str(),
+                    ),
+                ),
+            ),
+            borrow_epoch: None,
+        }
+    }
+    pub fn get_name(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+    ) -> rquickjs::Result<crate::wrappers::JsString> {
+        let result: String = self.resource(&ctx)?.get_name();
+        Ok(crate::wrappers::JsString(result))
+    }
+    #[qjs(static)]
+    pub fn compare(
+        h1: crate::modules::quickjs_example3_iface::BorrowHelloWrapper,
+        h2: crate::modules::quickjs_example3_iface::BorrowHelloWrapper,
+    ) -> i32 {
+        let result: i32 = crate::bindings::quickjs::example3::iface::Hello::compare(
+            &h1.0,
+            &h2.0,
+        );
+        result
+    }
+    #[qjs(static)]
+    pub fn merge(
+        h1: crate::bindings::quickjs::example3::iface::Hello,
+        h2: crate::bindings::quickjs::example3::iface::Hello,
+    ) -> crate::bindings::quickjs::example3::iface::Hello {
+        let result: crate::bindings::quickjs::example3::iface::Hello = crate::bindings::quickjs::example3::iface::Hello::merge(
+            h1,
+            h2,
+        );
+        result
+    }
+    #[qjs(rename = "__dispose")]
+    pub fn __dispose(&mut self) {
+        self.release();
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::quickjs::example3::iface::Hello {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        Hello {
+            inner: Some(std::rc::Rc::new(self)),
+            borrow_epoch: None,
+        }
+            .into_js(ctx)
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::quickjs::example3::iface::Hello {
+    fn from_js(
+        ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let wrapper = Hello::from_js(ctx, value)?;
+        if wrapper.borrow_epoch.is_some() {
+            return Err(rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "Hello",
+                message: Some(
+                    "The ownership of a borrowed resource handle cannot be transferred"
+                        .to_string(),
+                ),
+            });
+        }
+        let inner = wrapper
+            .checked_inner()
+            .map_err(|message| rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "Hello",
+                message: Some(message.to_string()),
+            })?;
+        unsafe {
+            Ok(
+                crate::bindings::quickjs::example3::iface::Hello::from_handle(
+                    inner.take_handle(),
+                ),
+            )
+        }
+    }
+}
+pub struct BorrowHelloWrapper(pub crate::bindings::quickjs::example3::iface::Hello);
+impl<'js> rquickjs::FromJs<'js> for BorrowHelloWrapper {
+    fn from_js(
+        ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let wrapper = Hello::from_js(ctx, value)?;
+        let inner = wrapper
+            .checked_inner()
+            .map_err(|message| rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "Hello",
+                message: Some(message.to_string()),
+            })?;
+        unsafe {
+            Ok(
+                BorrowHelloWrapper(
+                    crate::bindings::quickjs::example3::iface::Hello::from_handle(
+                        inner.handle(),
+                    ),
+                ),
+            )
+        }
+    }
+}
+impl BorrowHelloWrapper {
+    /// Borrows a handle passed to an exported function, without taking its ownership
+    pub fn from_ref(
+        resource: &crate::bindings::quickjs::example3::iface::Hello,
+    ) -> Self {
+        unsafe {
+            BorrowHelloWrapper(
+                crate::bindings::quickjs::example3::iface::Hello::from_handle(
+                    resource.handle(),
+                ),
+            )
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for BorrowHelloWrapper {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let resource = unsafe {
+            crate::bindings::quickjs::example3::iface::Hello::from_handle(
+                self.0.take_handle(),
+            )
+        };
+        Hello {
+            inner: Some(std::rc::Rc::new(resource)),
+            borrow_epoch: Some(crate::internal::current_borrow_epoch()),
+        }
+            .into_js(ctx)
+    }
+}
+impl Drop for BorrowHelloWrapper {
+    fn drop(&mut self) {
+        let _ = self.0.take_handle();
+    }
+}
+#[derive(Clone, JsLifetime, Trace)]
+pub struct HelloWithStaticCreate {
+    #[qjs(skip_trace = true)]
+    inner: Option<
+        std::rc::Rc<crate::bindings::quickjs::example3::iface::HelloWithStaticCreate>,
+    >,
+    /// The export call epoch if the handle is only borrowed from the host
+    #[qjs(skip_trace = true)]
+    borrow_epoch: Option<usize>,
+}
+mod __impl_class_hello_with_static_create_ {
+    pub use super::*;
+    use rquickjs::{Atom, Symbol, Value};
+    impl<'js> rquickjs::class::JsClass<'js> for HelloWithStaticCreate {
+        const NAME: &'static str = "HelloWithStaticCreate";
+        type Mutable = rquickjs::class::Writable;
+        fn prototype(
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<Option<rquickjs::Object<'js>>> {
+            use rquickjs::class::impl_::MethodImplementor;
+            let proto = rquickjs::Object::new(ctx.clone())?;
+            let implementor = rquickjs::class::impl_::MethodImpl::<Self>::new();
+            (&implementor).implement(&proto)?;
+            let dispose_symbol: Symbol = ctx
+                .globals()
+                .get(crate::internal::DISPOSE_SYMBOL)?;
+            let dispose_fn: Value = proto.get("__dispose")?;
+            proto.set(dispose_symbol, dispose_fn)?;
+            Ok(Some(proto))
+        }
+        fn constructor(
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<Option<rquickjs::function::Constructor<'js>>> {
+            use rquickjs::class::impl_::ConstructorCreator;
+            let implementor = rquickjs::class::impl_::ConstructorCreate::<Self>::new();
+            (&implementor).create_constructor(ctx)
+        }
+    }
+    impl<'js> rquickjs::IntoJs<'js> for HelloWithStaticCreate {
+        fn into_js(
+            self,
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<rquickjs::Value<'js>> {
+            let cls = rquickjs::class::Class::<Self>::instance(ctx.clone(), self)?;
+            rquickjs::IntoJs::into_js(cls, ctx)
+        }
+    }
+    impl<'js> rquickjs::FromJs<'js> for HelloWithStaticCreate
+    where
+        for<'a> rquickjs::class::impl_::CloneWrapper<
+            'a,
+            Self,
+        >: rquickjs::class::impl_::CloneTrait<Self>,
+    {
+        fn from_js(
+            ctx: &rquickjs::Ctx<'js>,
+            value: rquickjs::Value<'js>,
+        ) -> rquickjs::Result<Self> {
+            use rquickjs::class::impl_::CloneTrait;
+            let value = rquickjs::class::Class::<Self>::from_js(ctx, value)?;
+            let borrow = value.try_borrow()?;
+            Ok(rquickjs::class::impl_::CloneWrapper(&*borrow).wrap_clone())
+        }
+    }
+}
+impl HelloWithStaticCreate {
+    /// Gets the wrapped resource, failing if it has been disposed or if it is a
+    /// borrowed handle and the call it was passed to has already returned
+    fn checked_inner(
+        &self,
+    ) -> Result<
+        &std::rc::Rc<crate::bindings::quickjs::example3::iface::HelloWithStaticCreate>,
+        &'static str,
+    > {
+        if self
+            .borrow_epoch
+            .is_some_and(|epoch| !crate::internal::is_borrow_active(epoch))
+        {
+            return Err(
+                "Borrowed resource handle cannot be used after the call it was passed to has returned",
+            );
+        }
+        self.inner.as_ref().ok_or("Resource has already been disposed")
+    }
+    /// Gets the wrapped resource for calling one of its methods, throwing a
+    /// `ReferenceError` if it cannot be used
+    fn resource(
+        &self,
+        ctx: &rquickjs::Ctx<'_>,
+    ) -> rquickjs::Result<
+        &crate::bindings::quickjs::example3::iface::HelloWithStaticCreate,
+    > {
+        self.checked_inner()
+            .map(|inner| inner.deref())
+            .map_err(|message| rquickjs::Exception::throw_reference(ctx, message))
+    }
+    /// Releases the wrapped resource. Borrowed handles belong to the host, so they
+    /// are never dropped, only forgotten.
+    fn release(&mut self) {
+        if let Some(inner) = self.inner.take() {
+            if self.borrow_epoch.is_some() {
+                if let Ok(inner) = std::rc::Rc::try_unwrap(inner) {
+                    let _ = inner.take_handle();
+                }
+            }
+        }
+    }
+}
+impl Drop for HelloWithStaticCreate {
+    fn drop(&mut self) {
+        self.release();
+    }
+}
+#[rquickjs::methods(rename_all = "camelCase")]
+impl HelloWithStaticCreate {
+    #[qjs(constructor)]
+    pub fn new() -> Self {
+        Self {
+            inner: None,
+            borrow_epoch: None,
+        }
+    }
+    #[qjs(static)]
+    pub fn create(
+        name: crate::wrappers::JsString,
+    ) -> crate::bindings::quickjs::example3::iface::HelloWithStaticCreate {
+        let result: crate::bindings::quickjs::example3::iface::HelloWithStaticCreate = crate::bindings::quickjs::example3::iface::HelloWithStaticCreate::create(
+            name.0.as_str(),
+        );
+        result
+    }
+    pub fn get_name(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+    ) -> rquickjs::Result<crate::wrappers::JsString> {
+        let result: String = self.resource(&ctx)?.get_name();
+        Ok(crate::wrappers::JsString(result))
+    }
+    #[qjs(static)]
+    pub fn compare(
+        h1: crate::modules::quickjs_example3_iface::BorrowHelloWrapper,
+        h2: crate::modules::quickjs_example3_iface::BorrowHelloWrapper,
+    ) -> i32 {
+        let result: i32 = crate::bindings::quickjs::example3::iface::HelloWithStaticCreate::compare(
+            &h1.0,
+            &h2.0,
+        );
+        result
+    }
+    #[qjs(static)]
+    pub fn merge(
+        h1: crate::bindings::quickjs::example3::iface::Hello,
+        h2: crate::bindings::quickjs::example3::iface::Hello,
+    ) -> crate::bindings::quickjs::example3::iface::Hello {
+        let result: crate::bindings::quickjs::example3::iface::Hello = crate::bindings::quickjs::example3::iface::HelloWithStaticCreate::merge(
+            h1,
+            h2,
+        );
+        result
+    }
+    #[qjs(rename = "__dispose")]
+    pub fn __dispose(&mut self) {
+        self.release();
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::quickjs::example3::iface::HelloWithStaticCreate {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        HelloWithStaticCreate {
+            inner: Some(std::rc::Rc::new(self)),
+            borrow_epoch: None,
+        }
+            .into_js(ctx)
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::quickjs::example3::iface::HelloWithStaticCreate {
+    fn from_js(
+        ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let wrapper = HelloWithStaticCreate::from_js(ctx, value)?;
+        if wrapper.borrow_epoch.is_some() {
+            return Err(rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "HelloWithStaticCreate",
+                message: Some(
+                    "The ownership of a borrowed resource handle cannot be transferred"
+                        .to_string(),
+                ),
+            });
+        }
+        let inner = wrapper
+            .checked_inner()
+            .map_err(|message| rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "HelloWithStaticCreate",
+                message: Some(message.to_string()),
+            })?;
+        unsafe {
+            Ok(
+                crate::bindings::quickjs::example3::iface::HelloWithStaticCreate::from_handle(
+                    inner.take_handle(),
+                ),
+            )
+        }
+    }
+}
+pub struct BorrowHelloWithStaticCreateWrapper(
+    pub crate::bindings::quickjs::example3::iface::HelloWithStaticCreate,
+);
+impl<'js> rquickjs::FromJs<'js> for BorrowHelloWithStaticCreateWrapper {
+    fn from_js(
+        ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let wrapper = HelloWithStaticCreate::from_js(ctx, value)?;
+        let inner = wrapper
+            .checked_inner()
+            .map_err(|message| rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "HelloWithStaticCreate",
+                message: Some(message.to_string()),
+            })?;
+        unsafe {
+            Ok(
+                BorrowHelloWithStaticCreateWrapper(
+                    crate::bindings::quickjs::example3::iface::HelloWithStaticCreate::from_handle(
+                        inner.handle(),
+                    ),
+                ),
+            )
+        }
+    }
+}
+impl BorrowHelloWithStaticCreateWrapper {
+    /// Borrows a handle passed to an exported function, without taking its ownership
+    pub fn from_ref(
+        resource: &crate::bindings::quickjs::example3::iface::HelloWithStaticCreate,
+    ) -> Self {
+        unsafe {
+            BorrowHelloWithStaticCreateWrapper(
+                crate::bindings::quickjs::example3::iface::HelloWithStaticCreate::from_handle(
+                    resource.handle(),
+                ),
+            )
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for BorrowHelloWithStaticCreateWrapper {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let resource = unsafe {
+            crate::bindings::quickjs::example3::iface::HelloWithStaticCreate::from_handle(
+                self.0.take_handle(),
+            )
+        };
+        HelloWithStaticCreate {
+            inner: Some(std::rc::Rc::new(resource)),
+            borrow_epoch: Some(crate::internal::current_borrow_epoch()),
+        }
+            .into_js(ctx)
+    }
+}
+impl Drop for BorrowHelloWithStaticCreateWrapper {
+    fn drop(&mut self) {
+        let _ = self.0.take_handle();
+    }
+}
+pub struct JsIfaceModule;
+impl rquickjs::module::ModuleDef for JsIfaceModule {
+    fn declare(decl: &rquickjs::module::Declarations) -> rquickjs::Result<()> {
+        decl.declare("dump")?;
+        decl.declare("dumpAll")?;
+        decl.declare("Hello")?;
+        decl.declare("HelloWithStaticCreate")?;
+        Ok(())
+    }
+    fn evaluate<'js>(
+        ctx: &rquickjs::Ctx<'js>,
+        exports: &rquickjs::module::Exports<'js>,
+    ) -> rquickjs::Result<()> {
+        exports.export("dump", js_dump)?;
+        exports.export("dumpAll", js_dump_all)?;
+        exports.export("Hello", Hello::constructor(ctx)?)?;
+        exports
+            .export("HelloWithStaticCreate", HelloWithStaticCreate::constructor(ctx)?)?;
+        Ok(())
+    }
+}
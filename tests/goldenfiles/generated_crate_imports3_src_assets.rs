@@ -0,0 +1,6 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:imports3/imports3
+// wit: sha256:167b2b1d4f217517a49747ec35541ed70720785a02845803b2062c9e2f32b52b
+// js imports3: sha256:9004cb1797821beaab6b45a53965213cacc3607d79b91ac8faf92ba631168742
+
+pub static ASSETS: &[(&str, &[u8])] = &[];
@@ -0,0 +1,65 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:imports3/imports3
+// wit: sha256:167b2b1d4f217517a49747ec35541ed70720785a02845803b2062c9e2f32b52b
+// js imports3: sha256:9004cb1797821beaab6b45a53965213cacc3607d79b91ac8faf92ba631168742
+
+use rquickjs::JsLifetime;
+use rquickjs::class::{JsClass, Trace};
+use std::ops::Deref;
+pub mod quickjs_types_in_exports_other_types;
+pub mod quickjs_types_in_exports_types;
+const NATIVE_MODULE_NAMES: &[&str] = &[
+    "quickjs:types-in-exports/other-types",
+    "quickjs:types-in-exports/types",
+];
+pub struct NativeModuleResolver;
+impl rquickjs::loader::Resolver for NativeModuleResolver {
+    fn resolve<'js>(
+        &mut self,
+        _ctx: &rquickjs::Ctx<'js>,
+        base: &str,
+        name: &str,
+    ) -> rquickjs::Result<String> {
+        if NATIVE_MODULE_NAMES.contains(&name) {
+            Ok(name.to_string())
+        } else {
+            Err(rquickjs::Error::new_resolving(base, name))
+        }
+    }
+}
+pub struct NativeModuleLoader;
+impl rquickjs::loader::Loader for NativeModuleLoader {
+    fn load<'js>(
+        &mut self,
+        ctx: &rquickjs::Ctx<'js>,
+        name: &str,
+    ) -> rquickjs::Result<rquickjs::Module<'js, rquickjs::module::Declared>> {
+        match name {
+            "quickjs:types-in-exports/other-types" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::quickjs_types_in_exports_other_types::JsOtherTypesModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "quickjs:types-in-exports/types" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::quickjs_types_in_exports_types::JsTypesModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            _ => Err(rquickjs::Error::new_loading(name)),
+        }
+    }
+}
+pub struct JsImports3Module;
+impl rquickjs::module::ModuleDef for JsImports3Module {
+    fn declare(decl: &rquickjs::module::Declarations) -> rquickjs::Result<()> {
+        Ok(())
+    }
+    fn evaluate<'js>(
+        ctx: &rquickjs::Ctx<'js>,
+        exports: &rquickjs::module::Exports<'js>,
+    ) -> rquickjs::Result<()> {
+        Ok(())
+    }
+}
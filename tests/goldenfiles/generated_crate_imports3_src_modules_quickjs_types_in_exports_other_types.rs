@@ -0,0 +1,20 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:imports3/imports3
+// wit: sha256:167b2b1d4f217517a49747ec35541ed70720785a02845803b2062c9e2f32b52b
+// js imports3: sha256:9004cb1797821beaab6b45a53965213cacc3607d79b91ac8faf92ba631168742
+
+use rquickjs::JsLifetime;
+use rquickjs::class::{JsClass, Trace};
+use std::ops::Deref;
+pub struct JsOtherTypesModule;
+impl rquickjs::module::ModuleDef for JsOtherTypesModule {
+    fn declare(decl: &rquickjs::module::Declarations) -> rquickjs::Result<()> {
+        Ok(())
+    }
+    fn evaluate<'js>(
+        ctx: &rquickjs::Ctx<'js>,
+        exports: &rquickjs::module::Exports<'js>,
+    ) -> rquickjs::Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,296 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:imports3/imports3
+// wit: sha256:167b2b1d4f217517a49747ec35541ed70720785a02845803b2062c9e2f32b52b
+// js imports3: sha256:9004cb1797821beaab6b45a53965213cacc3607d79b91ac8faf92ba631168742
+
+use rquickjs::JsLifetime;
+use rquickjs::class::{JsClass, Trace};
+use std::ops::Deref;
+#[rquickjs::function]
+fn f1(
+    a: crate::wrappers::JsList<f32>,
+    b: crate::wrappers::JsList<crate::wrappers::JsString>,
+    c: crate::wrappers::JsList<crate::wrappers::JsString>,
+) -> crate::wrappers::JsList<crate::wrappers::JsString> {
+    let result: Vec<String> = crate::bindings::quickjs::types_in_exports::types::f1(
+        a.0.into_iter().map(|v| v).collect::<Vec<_>>().as_slice(),
+        b.0.into_iter().map(|v| v.0).collect::<Vec<_>>().as_slice(),
+        c.0.into_iter().map(|v| v.0).collect::<Vec<_>>().as_slice(),
+    );
+    crate::wrappers::JsList(
+        result.into_iter().map(|v| crate::wrappers::JsString(v)).collect::<Vec<_>>(),
+    )
+}
+#[rquickjs::function]
+fn f2(a: Option<crate::wrappers::JsString>) -> Option<u32> {
+    let result: Option<u32> = crate::bindings::quickjs::types_in_exports::types::f2(
+        a.map(|v| v.0).as_deref(),
+    );
+    result.map(|v| v)
+}
+#[rquickjs::function]
+fn f3(
+    a: bool,
+    b: i8,
+    c: i16,
+    d: i32,
+    e: crate::wrappers::BigIntWrapper<i64>,
+    f: u8,
+    g: u16,
+    h: u32,
+    i: crate::wrappers::BigIntWrapper<u64>,
+    j: f32,
+    k: f64,
+    l: char,
+    m: crate::wrappers::JsString,
+) -> rquickjs::convert::List<
+    (
+        bool,
+        i8,
+        i16,
+        i32,
+        crate::wrappers::BigIntWrapper<i64>,
+        u8,
+        u16,
+        u32,
+        crate::wrappers::BigIntWrapper<u64>,
+        f32,
+        f64,
+        char,
+        crate::wrappers::JsString,
+    ),
+> {
+    let result: (bool, i8, i16, i32, i64, u8, u16, u32, u64, f32, f64, char, String) = crate::bindings::quickjs::types_in_exports::types::f3(
+        a,
+        b,
+        c,
+        d,
+        e.0,
+        f,
+        g,
+        h,
+        i.0,
+        j,
+        k,
+        l,
+        m.0.as_str(),
+    );
+    rquickjs::convert::List((
+        result.0,
+        result.1,
+        result.2,
+        result.3,
+        crate::wrappers::BigIntWrapper(result.4),
+        result.5,
+        result.6,
+        result.7,
+        crate::wrappers::BigIntWrapper(result.8),
+        result.9,
+        result.10,
+        result.11,
+        crate::wrappers::JsString(result.12),
+    ))
+}
+#[rquickjs::function]
+fn f4(
+    ctx: rquickjs::Ctx<'_>,
+    a: crate::wrappers::JsResult<i32, crate::wrappers::JsString>,
+) -> rquickjs::Result<i32> {
+    let result: Result<i32, String> = crate::bindings::quickjs::types_in_exports::types::f4(
+        a.0.map_err(|v| v.0).as_ref().map(|v| *v).map_err(|v| v.as_str()),
+    );
+    match result {
+        Ok(result) => Ok(result),
+        Err(error) => {
+            let error: crate::wrappers::JsString = crate::wrappers::JsString(error);
+            Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+        }
+    }
+}
+#[rquickjs::function]
+fn f5(
+    ctx: rquickjs::Ctx<'_>,
+    a: crate::wrappers::JsResult<(), crate::wrappers::JsString>,
+) -> rquickjs::Result<()> {
+    let result: Result<(), String> = crate::bindings::quickjs::types_in_exports::types::f5(
+        a.0.map_err(|v| v.0).as_ref().map(|v| *v).map_err(|v| v.as_str()),
+    );
+    match result {
+        Ok(result) => Ok(result),
+        Err(error) => {
+            let error: crate::wrappers::JsString = crate::wrappers::JsString(error);
+            Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+        }
+    }
+}
+#[rquickjs::function]
+fn f6(
+    ctx: rquickjs::Ctx<'_>,
+    a: crate::wrappers::JsResult<crate::wrappers::JsString, ()>,
+) -> rquickjs::Result<crate::wrappers::JsString> {
+    let result: Result<String, ()> = crate::bindings::quickjs::types_in_exports::types::f6(
+        a.0.map(|v| v.0).as_ref().map_err(|v| *v).map(|v| v.as_str()),
+    );
+    match result {
+        Ok(result) => Ok(crate::wrappers::JsString(result)),
+        Err(error) => {
+            let error: () = error;
+            Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+        }
+    }
+}
+#[rquickjs::function]
+fn f7(
+    ctx: rquickjs::Ctx<'_>,
+    a: crate::wrappers::JsResult<(), ()>,
+) -> rquickjs::Result<()> {
+    let result: Result<(), ()> = crate::bindings::quickjs::types_in_exports::types::f7(
+        a.0,
+    );
+    match result {
+        Ok(result) => Ok(result),
+        Err(error) => {
+            let error: () = error;
+            Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+        }
+    }
+}
+#[rquickjs::function]
+fn f8(a: rquickjs::convert::List<(crate::wrappers::JsString, u32, f32)>) -> () {
+    let result: () = crate::bindings::quickjs::types_in_exports::types::f8((
+        a.0.0.0.clone().as_str(),
+        a.0.1,
+        a.0.2,
+    ));
+    result
+}
+#[rquickjs::function]
+fn f9(
+    a: crate::bindings::quickjs::types_in_exports::types::Rec1,
+) -> Option<crate::bindings::quickjs::types_in_exports::types::Rec1> {
+    let result: Option<crate::bindings::quickjs::types_in_exports::types::Rec1> = crate::bindings::quickjs::types_in_exports::types::f9(
+        &a,
+    );
+    result.map(|v| v)
+}
+#[rquickjs::function]
+fn f10(
+    a: crate::bindings::quickjs::types_in_exports::types::Var1,
+) -> crate::bindings::quickjs::types_in_exports::types::Var1 {
+    let result: crate::bindings::quickjs::types_in_exports::types::Var1 = crate::bindings::quickjs::types_in_exports::types::f10(
+        &a,
+    );
+    result
+}
+#[rquickjs::function]
+fn f11(
+    a: crate::bindings::quickjs::types_in_exports::other_types::Color,
+) -> crate::wrappers::JsString {
+    let result: String = crate::bindings::quickjs::types_in_exports::types::f11(a);
+    crate::wrappers::JsString(result)
+}
+#[rquickjs::function]
+fn f12(
+    a: crate::wrappers::JsString,
+) -> Option<crate::bindings::quickjs::types_in_exports::other_types::Color> {
+    let result: Option<crate::bindings::quickjs::types_in_exports::types::Color> = crate::bindings::quickjs::types_in_exports::types::f12(
+        a.0.as_str(),
+    );
+    result.map(|v| v)
+}
+#[rquickjs::function]
+fn f13(
+    a: crate::bindings::quickjs::types_in_exports::types::Permissions,
+) -> crate::bindings::quickjs::types_in_exports::types::Permissions {
+    let result: crate::bindings::quickjs::types_in_exports::types::Permissions = crate::bindings::quickjs::types_in_exports::types::f13(
+        a,
+    );
+    result
+}
+#[rquickjs::function]
+fn f14(a: crate::wrappers::BigIntWrapper<u64>) -> crate::wrappers::BigIntWrapper<u64> {
+    let result: u64 = crate::bindings::quickjs::types_in_exports::types::f14(a.0);
+    crate::wrappers::BigIntWrapper(result)
+}
+#[rquickjs::function]
+fn f15(bytes: crate::wrappers::UInt8Array) -> crate::wrappers::UInt8Array {
+    let result: Vec<u8> = crate::bindings::quickjs::types_in_exports::types::f15(
+        bytes.0.as_slice(),
+    );
+    crate::wrappers::UInt8Array(result)
+}
+#[rquickjs::function]
+fn f16(
+    in_: Option<
+        crate::wrappers::JsList<
+            crate::bindings::quickjs::types_in_exports::other_types::Rec2,
+        >,
+    >,
+) -> Option<crate::wrappers::JsList<crate::wrappers::JsString>> {
+    let result: Option<Vec<String>> = crate::bindings::quickjs::types_in_exports::types::f16(
+        in_.map(|v| v.0.into_iter().map(|v| v).collect::<Vec<_>>()).as_deref(),
+    );
+    result
+        .map(|v| crate::wrappers::JsList(
+            v.into_iter().map(|v| crate::wrappers::JsString(v)).collect::<Vec<_>>(),
+        ))
+}
+#[rquickjs::function]
+fn f17(
+    in_: Option<crate::wrappers::JsList<crate::wrappers::JsString>>,
+) -> Option<
+    crate::wrappers::JsList<
+        crate::bindings::quickjs::types_in_exports::other_types::Rec2,
+    >,
+> {
+    let result: Option<Vec<crate::bindings::quickjs::types_in_exports::types::Rec2>> = crate::bindings::quickjs::types_in_exports::types::f17(
+        in_.map(|v| v.0.into_iter().map(|v| v.0).collect::<Vec<_>>()).as_deref(),
+    );
+    result.map(|v| crate::wrappers::JsList(v.into_iter().map(|v| v).collect::<Vec<_>>()))
+}
+pub struct JsTypesModule;
+impl rquickjs::module::ModuleDef for JsTypesModule {
+    fn declare(decl: &rquickjs::module::Declarations) -> rquickjs::Result<()> {
+        decl.declare("f1")?;
+        decl.declare("f2")?;
+        decl.declare("f3")?;
+        decl.declare("f4")?;
+        decl.declare("f5")?;
+        decl.declare("f6")?;
+        decl.declare("f7")?;
+        decl.declare("f8")?;
+        decl.declare("f9")?;
+        decl.declare("f10")?;
+        decl.declare("f11")?;
+        decl.declare("f12")?;
+        decl.declare("f13")?;
+        decl.declare("f14")?;
+        decl.declare("f15")?;
+        decl.declare("f16")?;
+        decl.declare("f17")?;
+        Ok(())
+    }
+    fn evaluate<'js>(
+        ctx: &rquickjs::Ctx<'js>,
+        exports: &rquickjs::module::Exports<'js>,
+    ) -> rquickjs::Result<()> {
+        exports.export("f1", js_f1)?;
+        exports.export("f2", js_f2)?;
+        exports.export("f3", js_f3)?;
+        exports.export("f4", js_f4)?;
+        exports.export("f5", js_f5)?;
+        exports.export("f6", js_f6)?;
+        exports.export("f7", js_f7)?;
+        exports.export("f8", js_f8)?;
+        exports.export("f9", js_f9)?;
+        exports.export("f10", js_f10)?;
+        exports.export("f11", js_f11)?;
+        exports.export("f12", js_f12)?;
+        exports.export("f13", js_f13)?;
+        exports.export("f14", js_f14)?;
+        exports.export("f15", js_f15)?;
+        exports.export("f16", js_f16)?;
+        exports.export("f17", js_f17)?;
+        Ok(())
+    }
+}
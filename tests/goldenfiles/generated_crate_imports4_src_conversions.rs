@@ -0,0 +1,5 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:imports4/imports4
+// wit: sha256:7e1432460751d1890c6ee3207247e424befb4a1e864b6bf7c1d76dbf23d21eba
+// js imports4: sha256:7507ce08bd92e0a6633c88a8d94a638dd950a481013dc5beda01331b97d87280
+
@@ -0,0 +1,40 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:imports4/imports4
+// wit: sha256:7e1432460751d1890c6ee3207247e424befb4a1e864b6bf7c1d76dbf23d21eba
+// js imports4: sha256:7507ce08bd92e0a6633c88a8d94a638dd950a481013dc5beda01331b97d87280
+
+use rquickjs::JsLifetime;
+use rquickjs::class::{JsClass, Trace};
+use std::ops::Deref;
+#[rquickjs::function]
+fn greet(name: crate::wrappers::JsString) -> crate::wrappers::JsString {
+    let result: String = crate::bindings::greeter::greet(name.0.as_str());
+    crate::wrappers::JsString(result)
+}
+#[rquickjs::function]
+fn greet_all(
+    names: crate::wrappers::JsList<crate::wrappers::JsString>,
+) -> crate::wrappers::JsList<crate::wrappers::JsString> {
+    let result: Vec<String> = crate::bindings::greeter::greet_all(
+        names.0.into_iter().map(|v| v.0).collect::<Vec<_>>().as_slice(),
+    );
+    crate::wrappers::JsList(
+        result.into_iter().map(|v| crate::wrappers::JsString(v)).collect::<Vec<_>>(),
+    )
+}
+pub struct JsGreeterModule;
+impl rquickjs::module::ModuleDef for JsGreeterModule {
+    fn declare(decl: &rquickjs::module::Declarations) -> rquickjs::Result<()> {
+        decl.declare("greet")?;
+        decl.declare("greetAll")?;
+        Ok(())
+    }
+    fn evaluate<'js>(
+        ctx: &rquickjs::Ctx<'js>,
+        exports: &rquickjs::module::Exports<'js>,
+    ) -> rquickjs::Result<()> {
+        exports.export("greet", js_greet)?;
+        exports.export("greetAll", js_greet_all)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,6 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:imports5/imports5
+// wit: sha256:0fff48cb2b529f47fd07ca2a7b171e0bc1a79090d40eb427eb7e46713d6bb129
+// js imports5: sha256:b73fa01d11c6992cb548ba2fac34a759958b97a54eca0d72ccd14d578b8e6d22
+
+pub static ASSETS: &[(&str, &[u8])] = &[];
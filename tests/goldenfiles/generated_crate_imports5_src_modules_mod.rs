@@ -0,0 +1,61 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:imports5/imports5
+// wit: sha256:0fff48cb2b529f47fd07ca2a7b171e0bc1a79090d40eb427eb7e46713d6bb129
+// js imports5: sha256:b73fa01d11c6992cb548ba2fac34a759958b97a54eca0d72ccd14d578b8e6d22
+
+use rquickjs::JsLifetime;
+use rquickjs::class::{JsClass, Trace};
+use std::ops::Deref;
+const NATIVE_MODULE_NAMES: &[&str] = &["world-imports"];
+pub struct NativeModuleResolver;
+impl rquickjs::loader::Resolver for NativeModuleResolver {
+    fn resolve<'js>(
+        &mut self,
+        _ctx: &rquickjs::Ctx<'js>,
+        base: &str,
+        name: &str,
+    ) -> rquickjs::Result<String> {
+        if NATIVE_MODULE_NAMES.contains(&name) {
+            Ok(name.to_string())
+        } else {
+            Err(rquickjs::Error::new_resolving(base, name))
+        }
+    }
+}
+pub struct NativeModuleLoader;
+impl rquickjs::loader::Loader for NativeModuleLoader {
+    fn load<'js>(
+        &mut self,
+        ctx: &rquickjs::Ctx<'js>,
+        name: &str,
+    ) -> rquickjs::Result<rquickjs::Module<'js, rquickjs::module::Declared>> {
+        match name {
+            "world-imports" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::JsImports5Module,
+                    _,
+                >(ctx.clone(), name)
+            }
+            _ => Err(rquickjs::Error::new_loading(name)),
+        }
+    }
+}
+#[rquickjs::function]
+fn hello(name: crate::wrappers::JsString) -> crate::wrappers::JsString {
+    let result: String = crate::bindings::hello(name.0.as_str());
+    crate::wrappers::JsString(result)
+}
+pub struct JsImports5Module;
+impl rquickjs::module::ModuleDef for JsImports5Module {
+    fn declare(decl: &rquickjs::module::Declarations) -> rquickjs::Result<()> {
+        decl.declare("hello")?;
+        Ok(())
+    }
+    fn evaluate<'js>(
+        ctx: &rquickjs::Ctx<'js>,
+        exports: &rquickjs::module::Exports<'js>,
+    ) -> rquickjs::Result<()> {
+        exports.export("hello", js_hello)?;
+        Ok(())
+    }
+}
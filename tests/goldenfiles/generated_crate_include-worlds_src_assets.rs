@@ -0,0 +1,6 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:include-worlds/include-worlds
+// wit: sha256:8e584a8d5f2bf90843c1c48df96bb4063c97beafe940d1982e857642444ecbc1
+// js include-worlds: sha256:39b877260a667bbcc6eaaf4c6c05f77f3d9d6b4436b78801a7465d558e403f69
+
+pub static ASSETS: &[(&str, &[u8])] = &[];
@@ -0,0 +1,42 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:include-worlds/include-worlds
+// wit: sha256:8e584a8d5f2bf90843c1c48df96bb4063c97beafe940d1982e857642444ecbc1
+// js include-worlds: sha256:39b877260a667bbcc6eaaf4c6c05f77f3d9d6b4436b78801a7465d558e403f69
+
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::exports::quickjs::include_worlds::counter::Snapshot {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::BigIntWrapper<u64>,
+            >("value", crate::wrappers::BigIntWrapper(self.value))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::exports::quickjs::include_worlds::counter::Snapshot {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT snapshot",
+            &["value"],
+            &[("value", "u64")],
+            false,
+        )?;
+        let value: crate::wrappers::BigIntWrapper<u64> = crate::wrappers::get_field(
+            &obj,
+            "value",
+            "u64",
+        )?;
+        let value: u64 = value.0;
+        Ok(Self { value })
+    }
+}
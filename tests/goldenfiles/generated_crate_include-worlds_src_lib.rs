@@ -0,0 +1,106 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:include-worlds/include-worlds
+// wit: sha256:8e584a8d5f2bf90843c1c48df96bb4063c97beafe940d1982e857642444ecbc1
+// js include-worlds: sha256:39b877260a667bbcc6eaaf4c6c05f77f3d9d6b4436b78801a7465d558e403f69
+
+#[allow(static_mut_refs)]
+#[allow(unsafe_op_in_unsafe_fn)]
+mod bindings;
+mod assets;
+mod builtin;
+mod conversions;
+#[allow(unused)]
+mod internal;
+#[allow(unused)]
+mod modules;
+mod wrappers;
+static JS_EXPORT_MODULE_NAME: &str = "include-worlds";
+static JS_EXPORT_NAMES_VERBATIM: bool = false;
+static INTERN_FIELD_NAMES: bool = false;
+#[allow(dead_code)]
+static REPLACE_LONE_SURROGATES: bool = false;
+static MATH_RANDOM_SEED: crate::builtin::MathRandomSeed = crate::builtin::MathRandomSeed::Default;
+static DATE_CLOCK: Option<fn() -> f64> = None;
+static IMPORT_HOOKS: bool = false;
+static MOCK_IMPORTS: bool = false;
+static IMPORT_RECORDING: crate::builtin::ImportRecording = crate::builtin::ImportRecording::None;
+static CANCEL_BACKGROUND_WORK: bool = false;
+static HTTP_LIMITS: crate::builtin::HttpLimits = crate::builtin::HttpLimits::UNLIMITED;
+#[allow(dead_code)]
+static COOKIE_JAR: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE_LIMITS: crate::builtin::HttpCacheLimits = crate::builtin::HttpCacheLimits {
+    max_entries: Some(256usize),
+    max_size: Some(16777216usize),
+    max_body_size: Some(1048576usize),
+};
+#[allow(dead_code)]
+static FETCH_BASE_URL: Option<&str> = None;
+#[allow(dead_code)]
+static FETCH_GATEWAY: Option<&str> = None;
+static JS_EXPORT_MODULE: &str = include_str!("include-worlds.js");
+static JS_ADDITIONAL_MODULES: std::sync::LazyLock<
+    Vec<(&str, Box<dyn (Fn() -> String) + Send + Sync>)>,
+> = std::sync::LazyLock::new(|| { vec![] });
+static JS_MODULE_ORDER: &[&str] = &["include-worlds"];
+static JS_BUILTIN_PERMISSIONS: &[(&str, &[&str])] = &[];
+struct Component;
+impl crate::bindings::Guest for Component {
+    fn version() -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:include-worlds",
+                    0usize,
+                    &["version"],
+                    crate::wrappers::JsArgs(()),
+                )
+                .await;
+            result.0
+        })
+    }
+}
+impl crate::bindings::exports::quickjs::include_worlds::counter::Guest for Component {
+    fn increment() -> crate::bindings::exports::quickjs::include_worlds::counter::Snapshot {
+        crate::internal::async_exported_function(async move {
+            let result: crate::bindings::exports::quickjs::include_worlds::counter::Snapshot = crate::internal::call_js_export(
+                    "quickjs:include-worlds",
+                    1usize,
+                    &["counter", "increment"],
+                    crate::wrappers::JsArgs(()),
+                )
+                .await;
+            result
+        })
+    }
+}
+impl crate::bindings::exports::quickjs::include_worlds::greeter::Guest for Component {
+    fn greet(name: String) -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:include-worlds",
+                    2usize,
+                    &["greeter", "greet"],
+                    (crate::wrappers::JsString(name),),
+                )
+                .await;
+            result.0
+        })
+    }
+}
+impl crate::bindings::exports::stats::Guest for Component {
+    fn total() -> u64 {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::BigIntWrapper<u64> = crate::internal::call_js_export(
+                    "quickjs:include-worlds",
+                    3usize,
+                    &["stats", "total"],
+                    crate::wrappers::JsArgs(()),
+                )
+                .await;
+            result.0
+        })
+    }
+}
+bindings::export!(Component with_types_in bindings);
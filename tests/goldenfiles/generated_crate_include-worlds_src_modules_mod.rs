@@ -0,0 +1,79 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:include-worlds/include-worlds
+// wit: sha256:8e584a8d5f2bf90843c1c48df96bb4063c97beafe940d1982e857642444ecbc1
+// js include-worlds: sha256:39b877260a667bbcc6eaaf4c6c05f77f3d9d6b4436b78801a7465d558e403f69
+
+use rquickjs::JsLifetime;
+use rquickjs::class::{JsClass, Trace};
+use std::ops::Deref;
+pub mod quickjs_include_worlds_logging;
+pub mod quickjs_include_worlds_include_worlds_settings;
+const NATIVE_MODULE_NAMES: &[&str] = &[
+    "quickjs:include-worlds/logging",
+    "quickjs:include-worlds/include-worlds/settings",
+    "world-imports",
+];
+pub struct NativeModuleResolver;
+impl rquickjs::loader::Resolver for NativeModuleResolver {
+    fn resolve<'js>(
+        &mut self,
+        _ctx: &rquickjs::Ctx<'js>,
+        base: &str,
+        name: &str,
+    ) -> rquickjs::Result<String> {
+        if NATIVE_MODULE_NAMES.contains(&name) {
+            Ok(name.to_string())
+        } else {
+            Err(rquickjs::Error::new_resolving(base, name))
+        }
+    }
+}
+pub struct NativeModuleLoader;
+impl rquickjs::loader::Loader for NativeModuleLoader {
+    fn load<'js>(
+        &mut self,
+        ctx: &rquickjs::Ctx<'js>,
+        name: &str,
+    ) -> rquickjs::Result<rquickjs::Module<'js, rquickjs::module::Declared>> {
+        match name {
+            "quickjs:include-worlds/logging" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::quickjs_include_worlds_logging::JsLoggingModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "quickjs:include-worlds/include-worlds/settings" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::quickjs_include_worlds_include_worlds_settings::JsSettingsModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "world-imports" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::JsIncludeWorldsModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            _ => Err(rquickjs::Error::new_loading(name)),
+        }
+    }
+}
+#[rquickjs::function]
+fn current_time() -> crate::wrappers::BigIntWrapper<u64> {
+    let result: u64 = crate::bindings::current_time();
+    crate::wrappers::BigIntWrapper(result)
+}
+pub struct JsIncludeWorldsModule;
+impl rquickjs::module::ModuleDef for JsIncludeWorldsModule {
+    fn declare(decl: &rquickjs::module::Declarations) -> rquickjs::Result<()> {
+        decl.declare("currentTime")?;
+        Ok(())
+    }
+    fn evaluate<'js>(
+        ctx: &rquickjs::Ctx<'js>,
+        exports: &rquickjs::module::Exports<'js>,
+    ) -> rquickjs::Result<()> {
+        exports.export("currentTime", js_current_time)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,27 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:include-worlds/include-worlds
+// wit: sha256:8e584a8d5f2bf90843c1c48df96bb4063c97beafe940d1982e857642444ecbc1
+// js include-worlds: sha256:39b877260a667bbcc6eaaf4c6c05f77f3d9d6b4436b78801a7465d558e403f69
+
+use rquickjs::JsLifetime;
+use rquickjs::class::{JsClass, Trace};
+use std::ops::Deref;
+#[rquickjs::function]
+fn get(key: crate::wrappers::JsString) -> Option<crate::wrappers::JsString> {
+    let result: Option<String> = crate::bindings::settings::get(key.0.as_str());
+    result.map(|v| crate::wrappers::JsString(v))
+}
+pub struct JsSettingsModule;
+impl rquickjs::module::ModuleDef for JsSettingsModule {
+    fn declare(decl: &rquickjs::module::Declarations) -> rquickjs::Result<()> {
+        decl.declare("get")?;
+        Ok(())
+    }
+    fn evaluate<'js>(
+        ctx: &rquickjs::Ctx<'js>,
+        exports: &rquickjs::module::Exports<'js>,
+    ) -> rquickjs::Result<()> {
+        exports.export("get", js_get)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,7 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:init-hooks/init-hooks
+// wit: sha256:90497acf63cc2069edd2650516cd4d54373563591b925544df8f89047e80af83
+// js polyfills: sha256:aef69bf9a0a4eac0219f46a1f3ec73f445f3203fc142bab70a7795a0ec64f7f4
+// js cache: sha256:37661c3e7c14a5c0ec8cdf202d4c4e5d6986b8867f97bafd45d7cb7432d4bbf9
+// js init-hooks: sha256:a7efee44b4a922ae0645a693702696d27738735301d0372bfbee154cf5e8eade
+
@@ -0,0 +1,95 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:init-hooks/init-hooks
+// wit: sha256:90497acf63cc2069edd2650516cd4d54373563591b925544df8f89047e80af83
+// js polyfills: sha256:aef69bf9a0a4eac0219f46a1f3ec73f445f3203fc142bab70a7795a0ec64f7f4
+// js cache: sha256:37661c3e7c14a5c0ec8cdf202d4c4e5d6986b8867f97bafd45d7cb7432d4bbf9
+// js init-hooks: sha256:a7efee44b4a922ae0645a693702696d27738735301d0372bfbee154cf5e8eade
+
+#[allow(static_mut_refs)]
+#[allow(unsafe_op_in_unsafe_fn)]
+mod bindings;
+mod assets;
+mod builtin;
+mod conversions;
+#[allow(unused)]
+mod internal;
+#[allow(unused)]
+mod modules;
+mod wrappers;
+static JS_EXPORT_MODULE_NAME: &str = "init-hooks";
+static JS_EXPORT_NAMES_VERBATIM: bool = false;
+static INTERN_FIELD_NAMES: bool = false;
+#[allow(dead_code)]
+static REPLACE_LONE_SURROGATES: bool = false;
+static MATH_RANDOM_SEED: crate::builtin::MathRandomSeed = crate::builtin::MathRandomSeed::Default;
+static DATE_CLOCK: Option<fn() -> f64> = None;
+static IMPORT_HOOKS: bool = false;
+static MOCK_IMPORTS: bool = false;
+static IMPORT_RECORDING: crate::builtin::ImportRecording = crate::builtin::ImportRecording::None;
+static CANCEL_BACKGROUND_WORK: bool = false;
+static HTTP_LIMITS: crate::builtin::HttpLimits = crate::builtin::HttpLimits::UNLIMITED;
+#[allow(dead_code)]
+static COOKIE_JAR: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE_LIMITS: crate::builtin::HttpCacheLimits = crate::builtin::HttpCacheLimits {
+    max_entries: Some(256usize),
+    max_size: Some(16777216usize),
+    max_body_size: Some(1048576usize),
+};
+#[allow(dead_code)]
+static FETCH_BASE_URL: Option<&str> = None;
+#[allow(dead_code)]
+static FETCH_GATEWAY: Option<&str> = None;
+static JS_EXPORT_MODULE: &str = include_str!("init-hooks.js");
+static JS_ADDITIONAL_MODULES: std::sync::LazyLock<
+    Vec<(&str, Box<dyn (Fn() -> String) + Send + Sync>)>,
+> = std::sync::LazyLock::new(|| {
+    vec![
+        ("polyfills", Box::new(|| { include_str!("polyfills.js") .to_string() })),
+        ("cache", Box::new(|| { include_str!("cache.js") .to_string() }))
+    ]
+});
+static JS_MODULE_ORDER: &[&str] = &["polyfills", "cache", "init-hooks"];
+static JS_BUILTIN_PERMISSIONS: &[(&str, &[&str])] = &[];
+struct Component;
+impl crate::bindings::Guest for Component {
+    fn shout(text: String) -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:init-hooks",
+                    0usize,
+                    &["shout"],
+                    (crate::wrappers::JsString(text),),
+                )
+                .await;
+            result.0
+        })
+    }
+    fn cached(key: String) -> Option<u32> {
+        crate::internal::async_exported_function(async move {
+            let result: Option<u32> = crate::internal::call_js_export(
+                    "quickjs:init-hooks",
+                    1usize,
+                    &["cached"],
+                    (crate::wrappers::JsString(key),),
+                )
+                .await;
+            result.map(|v| v)
+        })
+    }
+    fn init_order() -> Vec<String> {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsList<crate::wrappers::JsString> = crate::internal::call_js_export(
+                    "quickjs:init-hooks",
+                    2usize,
+                    &["initOrder"],
+                    crate::wrappers::JsArgs(()),
+                )
+                .await;
+            result.0.into_iter().map(|v| v.0).collect::<Vec<_>>()
+        })
+    }
+}
+bindings::export!(Component with_types_in bindings);
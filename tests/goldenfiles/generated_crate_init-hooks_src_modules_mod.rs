@@ -0,0 +1,50 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:init-hooks/init-hooks
+// wit: sha256:90497acf63cc2069edd2650516cd4d54373563591b925544df8f89047e80af83
+// js polyfills: sha256:aef69bf9a0a4eac0219f46a1f3ec73f445f3203fc142bab70a7795a0ec64f7f4
+// js cache: sha256:37661c3e7c14a5c0ec8cdf202d4c4e5d6986b8867f97bafd45d7cb7432d4bbf9
+// js init-hooks: sha256:a7efee44b4a922ae0645a693702696d27738735301d0372bfbee154cf5e8eade
+
+use rquickjs::JsLifetime;
+use rquickjs::class::{JsClass, Trace};
+use std::ops::Deref;
+const NATIVE_MODULE_NAMES: &[&str] = &[];
+pub struct NativeModuleResolver;
+impl rquickjs::loader::Resolver for NativeModuleResolver {
+    fn resolve<'js>(
+        &mut self,
+        _ctx: &rquickjs::Ctx<'js>,
+        base: &str,
+        name: &str,
+    ) -> rquickjs::Result<String> {
+        if NATIVE_MODULE_NAMES.contains(&name) {
+            Ok(name.to_string())
+        } else {
+            Err(rquickjs::Error::new_resolving(base, name))
+        }
+    }
+}
+pub struct NativeModuleLoader;
+impl rquickjs::loader::Loader for NativeModuleLoader {
+    fn load<'js>(
+        &mut self,
+        ctx: &rquickjs::Ctx<'js>,
+        name: &str,
+    ) -> rquickjs::Result<rquickjs::Module<'js, rquickjs::module::Declared>> {
+        match name {
+            _ => Err(rquickjs::Error::new_loading(name)),
+        }
+    }
+}
+pub struct JsInitHooksModule;
+impl rquickjs::module::ModuleDef for JsInitHooksModule {
+    fn declare(decl: &rquickjs::module::Declarations) -> rquickjs::Result<()> {
+        Ok(())
+    }
+    fn evaluate<'js>(
+        ctx: &rquickjs::Ctx<'js>,
+        exports: &rquickjs::module::Exports<'js>,
+    ) -> rquickjs::Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,6 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:interned-field-names/interned-field-names
+// wit: sha256:f0607874fbba2a73f2f5912de72982100c4b7cea838fdd887aa4b55f106f6d39
+// js interned-field-names: sha256:27f121ab9d41acc4fb94d41a3ed27a874076586df974f62eceeedb1906a82a3d
+
+pub static ASSETS: &[(&str, &[u8])] = &[];
@@ -0,0 +1,137 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:interned-field-names/interned-field-names
+// wit: sha256:f0607874fbba2a73f2f5912de72982100c4b7cea838fdd887aa4b55f106f6d39
+// js interned-field-names: sha256:27f121ab9d41acc4fb94d41a3ed27a874076586df974f62eceeedb1906a82a3d
+
+const _: () = {
+    thread_local! {
+        static FIELD_ATOMS : crate ::wrappers::AtomCache = const { crate
+        ::wrappers::AtomCache::new(& ["x", "y", "label"]) };
+    }
+    impl<'js> rquickjs::IntoJs<'js>
+    for crate::bindings::exports::quickjs::interned_field_names::api::Point {
+        fn into_js(
+            self,
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<rquickjs::Value<'js>> {
+            let obj = rquickjs::Object::new(ctx.clone())?;
+            let __key = FIELD_ATOMS.with(|atoms| atoms.get(ctx, 0usize))?;
+            obj.set::<_, i32>(__key, self.x)?;
+            let __key = FIELD_ATOMS.with(|atoms| atoms.get(ctx, 1usize))?;
+            obj.set::<_, i32>(__key, self.y)?;
+            let __key = FIELD_ATOMS.with(|atoms| atoms.get(ctx, 2usize))?;
+            obj.set::<
+                    _,
+                    Option<crate::wrappers::JsString>,
+                >(__key, self.label.map(|v| crate::wrappers::JsString(v)))?;
+            Ok(obj.into_value())
+        }
+    }
+    impl<'js> rquickjs::FromJs<'js>
+    for crate::bindings::exports::quickjs::interned_field_names::api::Point {
+        fn from_js(
+            _ctx: &rquickjs::Ctx<'js>,
+            value: rquickjs::Value<'js>,
+        ) -> rquickjs::Result<Self> {
+            let obj = rquickjs::Object::from_value(value)?;
+            crate::wrappers::check_record_fields(
+                &obj,
+                "WIT point",
+                &["x", "y", "label"],
+                &[("x", "s32"), ("y", "s32")],
+                false,
+            )?;
+            let __key = FIELD_ATOMS.with(|atoms| atoms.get(obj.ctx(), 0usize))?;
+            let x: i32 = crate::wrappers::get_interned_field(&obj, __key, "x", "s32")?;
+            let x: i32 = x;
+            let __key = FIELD_ATOMS.with(|atoms| atoms.get(obj.ctx(), 1usize))?;
+            let y: i32 = crate::wrappers::get_interned_field(&obj, __key, "y", "s32")?;
+            let y: i32 = y;
+            let __key = FIELD_ATOMS.with(|atoms| atoms.get(obj.ctx(), 2usize))?;
+            let label: Option<crate::wrappers::JsString> = crate::wrappers::get_interned_field(
+                &obj,
+                __key,
+                "label",
+                "option<string>",
+            )?;
+            let label: Option<String> = label.map(|v| v.0);
+            Ok(Self { x, y, label })
+        }
+    }
+};
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::exports::quickjs::interned_field_names::api::Shape {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::exports::quickjs::interned_field_names::api::Shape::Dot(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::exports::quickjs::interned_field_names::api::Point,
+                >(ctx, "dot", inner)
+            }
+            crate::bindings::exports::quickjs::interned_field_names::api::Shape::Line(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    rquickjs::convert::List<
+                        (
+                            crate::bindings::exports::quickjs::interned_field_names::api::Point,
+                            crate::bindings::exports::quickjs::interned_field_names::api::Point,
+                        ),
+                    >,
+                >(ctx, "line", rquickjs::convert::List((inner.0, inner.1)))
+            }
+            crate::bindings::exports::quickjs::interned_field_names::api::Shape::Empty => {
+                crate::wrappers::variant_case(ctx, "empty")
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::exports::quickjs::interned_field_names::api::Shape {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "dot" => {
+                let inner: crate::bindings::exports::quickjs::interned_field_names::api::Point = crate::wrappers::variant_value(
+                    &obj,
+                    "point",
+                )?;
+                Ok(
+                    crate::bindings::exports::quickjs::interned_field_names::api::Shape::Dot(
+                        inner,
+                    ),
+                )
+            }
+            "line" => {
+                let inner: rquickjs::convert::List<
+                    (
+                        crate::bindings::exports::quickjs::interned_field_names::api::Point,
+                        crate::bindings::exports::quickjs::interned_field_names::api::Point,
+                    ),
+                > = crate::wrappers::variant_value(&obj, "tuple<point, point>")?;
+                Ok(
+                    crate::bindings::exports::quickjs::interned_field_names::api::Shape::Line((
+                        inner.0.0,
+                        inner.0.1,
+                    )),
+                )
+            }
+            "empty" => {
+                Ok(
+                    crate::bindings::exports::quickjs::interned_field_names::api::Shape::Empty,
+                )
+            }
+            _ => {
+                Err(crate::wrappers::unknown_variant_case("JS shape", "WIT shape", &tag))
+            }
+        }
+    }
+}
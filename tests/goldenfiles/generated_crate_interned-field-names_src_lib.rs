@@ -0,0 +1,84 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:interned-field-names/interned-field-names
+// wit: sha256:f0607874fbba2a73f2f5912de72982100c4b7cea838fdd887aa4b55f106f6d39
+// js interned-field-names: sha256:27f121ab9d41acc4fb94d41a3ed27a874076586df974f62eceeedb1906a82a3d
+
+#[allow(static_mut_refs)]
+#[allow(unsafe_op_in_unsafe_fn)]
+mod bindings;
+mod assets;
+mod builtin;
+mod conversions;
+#[allow(unused)]
+mod internal;
+#[allow(unused)]
+mod modules;
+mod wrappers;
+static JS_EXPORT_MODULE_NAME: &str = "interned-field-names";
+static JS_EXPORT_NAMES_VERBATIM: bool = false;
+static INTERN_FIELD_NAMES: bool = true;
+#[allow(dead_code)]
+static REPLACE_LONE_SURROGATES: bool = false;
+static MATH_RANDOM_SEED: crate::builtin::MathRandomSeed = crate::builtin::MathRandomSeed::Default;
+static DATE_CLOCK: Option<fn() -> f64> = None;
+static IMPORT_HOOKS: bool = false;
+static MOCK_IMPORTS: bool = false;
+static IMPORT_RECORDING: crate::builtin::ImportRecording = crate::builtin::ImportRecording::None;
+static CANCEL_BACKGROUND_WORK: bool = false;
+static HTTP_LIMITS: crate::builtin::HttpLimits = crate::builtin::HttpLimits::UNLIMITED;
+#[allow(dead_code)]
+static COOKIE_JAR: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE_LIMITS: crate::builtin::HttpCacheLimits = crate::builtin::HttpCacheLimits {
+    max_entries: Some(256usize),
+    max_size: Some(16777216usize),
+    max_body_size: Some(1048576usize),
+};
+#[allow(dead_code)]
+static FETCH_BASE_URL: Option<&str> = None;
+#[allow(dead_code)]
+static FETCH_GATEWAY: Option<&str> = None;
+static JS_EXPORT_MODULE: &str = include_str!("interned-field-names.js");
+static JS_ADDITIONAL_MODULES: std::sync::LazyLock<
+    Vec<(&str, Box<dyn (Fn() -> String) + Send + Sync>)>,
+> = std::sync::LazyLock::new(|| { vec![] });
+static JS_MODULE_ORDER: &[&str] = &["interned-field-names"];
+static JS_BUILTIN_PERMISSIONS: &[(&str, &[&str])] = &[];
+struct Component;
+impl crate::bindings::exports::quickjs::interned_field_names::api::Guest for Component {
+    fn move_point(
+        p: crate::bindings::exports::quickjs::interned_field_names::api::Point,
+        dx: i32,
+        dy: i32,
+    ) -> crate::bindings::exports::quickjs::interned_field_names::api::Point {
+        crate::internal::async_exported_function(async move {
+            let result: crate::bindings::exports::quickjs::interned_field_names::api::Point = crate::internal::call_js_export(
+                    "quickjs:interned-field-names",
+                    0usize,
+                    &["api", "movePoint"],
+                    crate::wrappers::JsArgs((p, dx, dy)),
+                )
+                .await;
+            result
+        })
+    }
+    fn move_shape(
+        s: crate::bindings::exports::quickjs::interned_field_names::api::Shape,
+        dx: i32,
+        dy: i32,
+    ) -> crate::bindings::exports::quickjs::interned_field_names::api::Shape {
+        crate::internal::async_exported_function(async move {
+            let result: crate::bindings::exports::quickjs::interned_field_names::api::Shape = crate::internal::call_js_export(
+                    "quickjs:interned-field-names",
+                    1usize,
+                    &["api", "moveShape"],
+                    crate::wrappers::JsArgs((s, dx, dy)),
+                )
+                .await;
+            result
+        })
+    }
+}
+bindings::export!(Component with_types_in bindings);
@@ -0,0 +1,6 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:intl/intl
+// wit: sha256:272eb82085c30a5021309da827fdffb2272e081314f0aff14f326390febd229b
+// js intl: sha256:cbd4601b030e32e8744ab68ea167e130cb128873491b97a3309f3818cb09055d
+
+pub static ASSETS: &[(&str, &[u8])] = &[];
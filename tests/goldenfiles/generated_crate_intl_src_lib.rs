@@ -0,0 +1,146 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:intl/intl
+// wit: sha256:272eb82085c30a5021309da827fdffb2272e081314f0aff14f326390febd229b
+// js intl: sha256:cbd4601b030e32e8744ab68ea167e130cb128873491b97a3309f3818cb09055d
+
+#[allow(static_mut_refs)]
+#[allow(unsafe_op_in_unsafe_fn)]
+mod bindings;
+mod assets;
+mod builtin;
+mod conversions;
+#[allow(unused)]
+mod internal;
+#[allow(unused)]
+mod modules;
+mod wrappers;
+static JS_EXPORT_MODULE_NAME: &str = "intl";
+static JS_EXPORT_NAMES_VERBATIM: bool = false;
+static INTERN_FIELD_NAMES: bool = false;
+#[allow(dead_code)]
+static REPLACE_LONE_SURROGATES: bool = false;
+static MATH_RANDOM_SEED: crate::builtin::MathRandomSeed = crate::builtin::MathRandomSeed::Default;
+static DATE_CLOCK: Option<fn() -> f64> = None;
+static IMPORT_HOOKS: bool = false;
+static MOCK_IMPORTS: bool = false;
+static IMPORT_RECORDING: crate::builtin::ImportRecording = crate::builtin::ImportRecording::None;
+static CANCEL_BACKGROUND_WORK: bool = false;
+static HTTP_LIMITS: crate::builtin::HttpLimits = crate::builtin::HttpLimits::UNLIMITED;
+#[allow(dead_code)]
+static COOKIE_JAR: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE_LIMITS: crate::builtin::HttpCacheLimits = crate::builtin::HttpCacheLimits {
+    max_entries: Some(256usize),
+    max_size: Some(16777216usize),
+    max_body_size: Some(1048576usize),
+};
+#[allow(dead_code)]
+static FETCH_BASE_URL: Option<&str> = None;
+#[allow(dead_code)]
+static FETCH_GATEWAY: Option<&str> = None;
+static JS_EXPORT_MODULE: &str = include_str!("intl.js");
+static JS_ADDITIONAL_MODULES: std::sync::LazyLock<
+    Vec<(&str, Box<dyn (Fn() -> String) + Send + Sync>)>,
+> = std::sync::LazyLock::new(|| { vec![] });
+static JS_MODULE_ORDER: &[&str] = &["intl"];
+static JS_BUILTIN_PERMISSIONS: &[(&str, &[&str])] = &[];
+struct Component;
+impl crate::bindings::Guest for Component {
+    fn format_number(locale: String, value: f64, fraction_digits: Option<u8>) -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:intl",
+                    0usize,
+                    &["formatNumber"],
+                    crate::wrappers::JsArgs((
+                        crate::wrappers::JsString(locale),
+                        value,
+                        fraction_digits.map(|v| v),
+                    )),
+                )
+                .await;
+            result.0
+        })
+    }
+    fn format_percent(value: f64) -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:intl",
+                    1usize,
+                    &["formatPercent"],
+                    (value,),
+                )
+                .await;
+            result.0
+        })
+    }
+    fn format_date(
+        locale: String,
+        epoch_millis: f64,
+        date_style: String,
+        time_style: Option<String>,
+    ) -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:intl",
+                    2usize,
+                    &["formatDate"],
+                    crate::wrappers::JsArgs((
+                        crate::wrappers::JsString(locale),
+                        epoch_millis,
+                        crate::wrappers::JsString(date_style),
+                        time_style.map(|v| crate::wrappers::JsString(v)),
+                    )),
+                )
+                .await;
+            result.0
+        })
+    }
+    fn sort_words(locale: String, words: Vec<String>) -> Vec<String> {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsList<crate::wrappers::JsString> = crate::internal::call_js_export(
+                    "quickjs:intl",
+                    3usize,
+                    &["sortWords"],
+                    crate::wrappers::JsArgs((
+                        crate::wrappers::JsString(locale),
+                        crate::wrappers::JsList(
+                            words
+                                .into_iter()
+                                .map(|v| crate::wrappers::JsString(v))
+                                .collect::<Vec<_>>(),
+                        ),
+                    )),
+                )
+                .await;
+            result.0.into_iter().map(|v| v.0).collect::<Vec<_>>()
+        })
+    }
+    fn to_locale_strings() -> Vec<String> {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsList<crate::wrappers::JsString> = crate::internal::call_js_export(
+                    "quickjs:intl",
+                    4usize,
+                    &["toLocaleStrings"],
+                    crate::wrappers::JsArgs(()),
+                )
+                .await;
+            result.0.into_iter().map(|v| v.0).collect::<Vec<_>>()
+        })
+    }
+    fn invalid_locale() -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:intl",
+                    5usize,
+                    &["invalidLocale"],
+                    crate::wrappers::JsArgs(()),
+                )
+                .await;
+            result.0
+        })
+    }
+}
+bindings::export!(Component with_types_in bindings);
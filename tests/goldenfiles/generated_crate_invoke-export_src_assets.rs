@@ -0,0 +1,6 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:invoke-export/invoke-export
+// wit: sha256:e5bf6400e40a07945adc81c344f28e12277ef2dd8eda6415e0a50f5fb8945c2f
+// js invoke-export: sha256:7575f9028e70cafaa80f96dfd3807a070e01337e3dc30e3d8bb74233213f9ebd
+
+pub static ASSETS: &[(&str, &[u8])] = &[];
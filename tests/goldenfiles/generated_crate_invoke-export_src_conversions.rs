@@ -0,0 +1,38 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:invoke-export/invoke-export
+// wit: sha256:e5bf6400e40a07945adc81c344f28e12277ef2dd8eda6415e0a50f5fb8945c2f
+// js invoke-export: sha256:7575f9028e70cafaa80f96dfd3807a070e01337e3dc30e3d8bb74233213f9ebd
+
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::exports::quickjs::invoke_export::api::Point {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<_, i32>("x", self.x)?;
+        obj.set::<_, i32>("y", self.y)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::exports::quickjs::invoke_export::api::Point {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT point",
+            &["x", "y"],
+            &[("x", "s32"), ("y", "s32")],
+            false,
+        )?;
+        let x: i32 = crate::wrappers::get_field(&obj, "x", "s32")?;
+        let x: i32 = x;
+        let y: i32 = crate::wrappers::get_field(&obj, "y", "s32")?;
+        let y: i32 = y;
+        Ok(Self { x, y })
+    }
+}
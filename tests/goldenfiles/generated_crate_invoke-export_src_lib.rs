@@ -0,0 +1,143 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:invoke-export/invoke-export
+// wit: sha256:e5bf6400e40a07945adc81c344f28e12277ef2dd8eda6415e0a50f5fb8945c2f
+// js invoke-export: sha256:7575f9028e70cafaa80f96dfd3807a070e01337e3dc30e3d8bb74233213f9ebd
+
+#[allow(static_mut_refs)]
+#[allow(unsafe_op_in_unsafe_fn)]
+mod bindings;
+mod assets;
+mod builtin;
+mod conversions;
+#[allow(unused)]
+mod internal;
+#[allow(unused)]
+mod modules;
+mod wrappers;
+static JS_EXPORT_MODULE_NAME: &str = "invoke-export";
+static JS_EXPORT_NAMES_VERBATIM: bool = false;
+static INTERN_FIELD_NAMES: bool = false;
+#[allow(dead_code)]
+static REPLACE_LONE_SURROGATES: bool = false;
+static MATH_RANDOM_SEED: crate::builtin::MathRandomSeed = crate::builtin::MathRandomSeed::Default;
+static DATE_CLOCK: Option<fn() -> f64> = None;
+static IMPORT_HOOKS: bool = false;
+static MOCK_IMPORTS: bool = false;
+static IMPORT_RECORDING: crate::builtin::ImportRecording = crate::builtin::ImportRecording::None;
+static CANCEL_BACKGROUND_WORK: bool = false;
+static HTTP_LIMITS: crate::builtin::HttpLimits = crate::builtin::HttpLimits::UNLIMITED;
+#[allow(dead_code)]
+static COOKIE_JAR: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE_LIMITS: crate::builtin::HttpCacheLimits = crate::builtin::HttpCacheLimits {
+    max_entries: Some(256usize),
+    max_size: Some(16777216usize),
+    max_body_size: Some(1048576usize),
+};
+#[allow(dead_code)]
+static FETCH_BASE_URL: Option<&str> = None;
+#[allow(dead_code)]
+static FETCH_GATEWAY: Option<&str> = None;
+static JS_EXPORT_MODULE: &str = include_str!("invoke-export.js");
+static JS_ADDITIONAL_MODULES: std::sync::LazyLock<
+    Vec<(&str, Box<dyn (Fn() -> String) + Send + Sync>)>,
+> = std::sync::LazyLock::new(|| { vec![] });
+static JS_MODULE_ORDER: &[&str] = &["invoke-export"];
+static JS_BUILTIN_PERMISSIONS: &[(&str, &[&str])] = &[];
+struct Component;
+impl crate::bindings::Guest for Component {
+    fn rquickjs_invoke(name: String, args_json: String) -> Result<String, String> {
+        const TARGETS: &[(&str, usize, &[&str])] = &[
+            ("greet", 0usize, &["greet"]),
+            ("quickjs:invoke-export/api#add", 1usize, &["api", "add"]),
+            ("quickjs:invoke-export/api#big", 2usize, &["api", "big"]),
+            ("quickjs:invoke-export/api#fail", 3usize, &["api", "fail"]),
+            ("quickjs:invoke-export/api#translate", 4usize, &["api", "translate"]),
+        ];
+        let Ok(target) = TARGETS
+            .binary_search_by(|(target, _, _)| (*target).cmp(name.as_str())) else {
+            return Err(format!("Unknown exported function: {name}"));
+        };
+        let (_, export_index, function_path) = TARGETS[target];
+        crate::internal::try_async_exported_function(
+            crate::internal::call_js_export_with_json(
+                export_index,
+                function_path,
+                args_json,
+            ),
+        )?
+    }
+    fn rquickjs_describe() -> String {
+        crate::internal::describe_component(
+            "{\"exports\":{\"functions\":[{\"jsName\":\"greet\",\"kind\":\"function\",\"name\":\"greet\",\"params\":[{\"name\":\"name\",\"type\":\"string\"}],\"resource\":null,\"result\":\"string\"}],\"interfaces\":[{\"functions\":[{\"jsName\":\"add\",\"kind\":\"function\",\"name\":\"add\",\"params\":[{\"name\":\"a\",\"type\":\"number\"},{\"name\":\"b\",\"type\":\"number\"}],\"resource\":null,\"result\":\"number\"},{\"jsName\":\"translate\",\"kind\":\"function\",\"name\":\"translate\",\"params\":[{\"name\":\"p\",\"type\":\"Point\"},{\"name\":\"dx\",\"type\":\"number\"}],\"resource\":null,\"result\":\"Point\"},{\"jsName\":\"big\",\"kind\":\"function\",\"name\":\"big\",\"params\":[],\"resource\":null,\"result\":\"bigint\"},{\"jsName\":\"fail\",\"kind\":\"function\",\"name\":\"fail\",\"params\":[],\"resource\":null,\"result\":\"string\"}],\"handler\":false,\"jsName\":\"api\",\"name\":\"quickjs:invoke-export/api\",\"skipped\":false}]},\"extensions\":[\"rquickjs-describe\",\"rquickjs-invoke\"],\"features\":\"__RQUICKJS_FEATURES__\",\"generatorVersion\":\"0.0.0\",\"imports\":{\"functions\":[],\"interfaces\":[]},\"package\":\"quickjs:invoke-export\",\"world\":\"invoke-export\"}",
+        )
+    }
+    fn greet(name: String) -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:invoke-export",
+                    0usize,
+                    &["greet"],
+                    (crate::wrappers::JsString(name),),
+                )
+                .await;
+            result.0
+        })
+    }
+}
+impl crate::bindings::exports::quickjs::invoke_export::api::Guest for Component {
+    fn add(a: i32, b: i32) -> i32 {
+        crate::internal::async_exported_function(async move {
+            let result: i32 = crate::internal::call_js_export(
+                    "quickjs:invoke-export",
+                    1usize,
+                    &["api", "add"],
+                    crate::wrappers::JsArgs((a, b)),
+                )
+                .await;
+            result
+        })
+    }
+    fn translate(
+        p: crate::bindings::exports::quickjs::invoke_export::api::Point,
+        dx: i32,
+    ) -> crate::bindings::exports::quickjs::invoke_export::api::Point {
+        crate::internal::async_exported_function(async move {
+            let result: crate::bindings::exports::quickjs::invoke_export::api::Point = crate::internal::call_js_export(
+                    "quickjs:invoke-export",
+                    4usize,
+                    &["api", "translate"],
+                    crate::wrappers::JsArgs((p, dx)),
+                )
+                .await;
+            result
+        })
+    }
+    fn big() -> u64 {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::BigIntWrapper<u64> = crate::internal::call_js_export(
+                    "quickjs:invoke-export",
+                    2usize,
+                    &["api", "big"],
+                    crate::wrappers::JsArgs(()),
+                )
+                .await;
+            result.0
+        })
+    }
+    fn fail() -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:invoke-export",
+                    3usize,
+                    &["api", "fail"],
+                    crate::wrappers::JsArgs(()),
+                )
+                .await;
+            result.0
+        })
+    }
+}
+bindings::export!(Component with_types_in bindings);
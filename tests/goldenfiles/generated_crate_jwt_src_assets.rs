@@ -0,0 +1,6 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:jwt/jwt
+// wit: sha256:67b48c472da33d4ef750c4ef254e97868f7f8de96994119882bbd0d900c7af89
+// js jwt: sha256:ec1e279fc0ed8f15c646c3b4ad668798485b1b416eb3c6d3e9a6a11701649526
+
+pub static ASSETS: &[(&str, &[u8])] = &[];
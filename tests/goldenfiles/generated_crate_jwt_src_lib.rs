@@ -0,0 +1,116 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:jwt/jwt
+// wit: sha256:67b48c472da33d4ef750c4ef254e97868f7f8de96994119882bbd0d900c7af89
+// js jwt: sha256:ec1e279fc0ed8f15c646c3b4ad668798485b1b416eb3c6d3e9a6a11701649526
+
+#[allow(static_mut_refs)]
+#[allow(unsafe_op_in_unsafe_fn)]
+mod bindings;
+mod assets;
+mod builtin;
+mod conversions;
+#[allow(unused)]
+mod internal;
+#[allow(unused)]
+mod modules;
+mod wrappers;
+static JS_EXPORT_MODULE_NAME: &str = "jwt";
+static JS_EXPORT_NAMES_VERBATIM: bool = false;
+static INTERN_FIELD_NAMES: bool = false;
+#[allow(dead_code)]
+static REPLACE_LONE_SURROGATES: bool = false;
+static MATH_RANDOM_SEED: crate::builtin::MathRandomSeed = crate::builtin::MathRandomSeed::Default;
+static DATE_CLOCK: Option<fn() -> f64> = None;
+static IMPORT_HOOKS: bool = false;
+static MOCK_IMPORTS: bool = false;
+static IMPORT_RECORDING: crate::builtin::ImportRecording = crate::builtin::ImportRecording::None;
+static CANCEL_BACKGROUND_WORK: bool = false;
+static HTTP_LIMITS: crate::builtin::HttpLimits = crate::builtin::HttpLimits::UNLIMITED;
+#[allow(dead_code)]
+static COOKIE_JAR: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE_LIMITS: crate::builtin::HttpCacheLimits = crate::builtin::HttpCacheLimits {
+    max_entries: Some(256usize),
+    max_size: Some(16777216usize),
+    max_body_size: Some(1048576usize),
+};
+#[allow(dead_code)]
+static FETCH_BASE_URL: Option<&str> = None;
+#[allow(dead_code)]
+static FETCH_GATEWAY: Option<&str> = None;
+static JS_EXPORT_MODULE: &str = include_str!("jwt.js");
+static JS_ADDITIONAL_MODULES: std::sync::LazyLock<
+    Vec<(&str, Box<dyn (Fn() -> String) + Send + Sync>)>,
+> = std::sync::LazyLock::new(|| { vec![] });
+static JS_MODULE_ORDER: &[&str] = &["jwt"];
+static JS_BUILTIN_PERMISSIONS: &[(&str, &[&str])] = &[];
+struct Component;
+impl crate::bindings::Guest for Component {
+    fn sign_token(payload: String, key_name: String, options: String) -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:jwt",
+                    0usize,
+                    &["signToken"],
+                    crate::wrappers::JsArgs((
+                        crate::wrappers::JsString(payload),
+                        crate::wrappers::JsString(key_name),
+                        crate::wrappers::JsString(options),
+                    )),
+                )
+                .await;
+            result.0
+        })
+    }
+    fn verify_token(token: String, key_name: String, options: String) -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:jwt",
+                    1usize,
+                    &["verifyToken"],
+                    crate::wrappers::JsArgs((
+                        crate::wrappers::JsString(token),
+                        crate::wrappers::JsString(key_name),
+                        crate::wrappers::JsString(options),
+                    )),
+                )
+                .await;
+            result.0
+        })
+    }
+    fn round_trip(
+        algorithm: String,
+        private_key: String,
+        public_key: String,
+    ) -> Vec<String> {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsList<crate::wrappers::JsString> = crate::internal::call_js_export(
+                    "quickjs:jwt",
+                    2usize,
+                    &["roundTrip"],
+                    crate::wrappers::JsArgs((
+                        crate::wrappers::JsString(algorithm),
+                        crate::wrappers::JsString(private_key),
+                        crate::wrappers::JsString(public_key),
+                    )),
+                )
+                .await;
+            result.0.into_iter().map(|v| v.0).collect::<Vec<_>>()
+        })
+    }
+    fn errors() -> Vec<String> {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsList<crate::wrappers::JsString> = crate::internal::call_js_export(
+                    "quickjs:jwt",
+                    3usize,
+                    &["errors"],
+                    crate::wrappers::JsArgs(()),
+                )
+                .await;
+            result.0.into_iter().map(|v| v.0).collect::<Vec<_>>()
+        })
+    }
+}
+bindings::export!(Component with_types_in bindings);
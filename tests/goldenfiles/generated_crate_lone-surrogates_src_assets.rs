@@ -0,0 +1,6 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:lone-surrogates/lone-surrogates
+// wit: sha256:9be526801f4d02b25db35ffe89ab1cfe24ca663351567fd25c022fb10c75255b
+// js lone-surrogates: sha256:140e59a7fde8707742139458dc179ec6ba0b27604e025cd109ae5adcab922a71
+
+pub static ASSETS: &[(&str, &[u8])] = &[];
@@ -0,0 +1,61 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:lone-surrogates/lone-surrogates
+// wit: sha256:9be526801f4d02b25db35ffe89ab1cfe24ca663351567fd25c022fb10c75255b
+// js lone-surrogates: sha256:140e59a7fde8707742139458dc179ec6ba0b27604e025cd109ae5adcab922a71
+
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::exports::quickjs::lone_surrogates::api::Labelled {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("label", crate::wrappers::JsString(self.label))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<crate::wrappers::JsString>,
+            >(
+            "tags",
+            crate::wrappers::JsList(
+                self
+                    .tags
+                    .into_iter()
+                    .map(|v| crate::wrappers::JsString(v))
+                    .collect::<Vec<_>>(),
+            ),
+        )?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::exports::quickjs::lone_surrogates::api::Labelled {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT labelled",
+            &["label", "tags"],
+            &[("label", "string"), ("tags", "list<string>")],
+            false,
+        )?;
+        let label: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "label",
+            "string",
+        )?;
+        let label: String = label.0;
+        let tags: crate::wrappers::JsList<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "tags",
+            "list<string>",
+        )?;
+        let tags: Vec<String> = tags.0.into_iter().map(|v| v.0).collect::<Vec<_>>();
+        Ok(Self { label, tags })
+    }
+}
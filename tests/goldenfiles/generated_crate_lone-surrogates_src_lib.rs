@@ -0,0 +1,90 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:lone-surrogates/lone-surrogates
+// wit: sha256:9be526801f4d02b25db35ffe89ab1cfe24ca663351567fd25c022fb10c75255b
+// js lone-surrogates: sha256:140e59a7fde8707742139458dc179ec6ba0b27604e025cd109ae5adcab922a71
+
+#[allow(static_mut_refs)]
+#[allow(unsafe_op_in_unsafe_fn)]
+mod bindings;
+mod assets;
+mod builtin;
+mod conversions;
+#[allow(unused)]
+mod internal;
+#[allow(unused)]
+mod modules;
+mod wrappers;
+static JS_EXPORT_MODULE_NAME: &str = "lone-surrogates";
+static JS_EXPORT_NAMES_VERBATIM: bool = false;
+static INTERN_FIELD_NAMES: bool = false;
+#[allow(dead_code)]
+static REPLACE_LONE_SURROGATES: bool = true;
+static MATH_RANDOM_SEED: crate::builtin::MathRandomSeed = crate::builtin::MathRandomSeed::Default;
+static DATE_CLOCK: Option<fn() -> f64> = None;
+static IMPORT_HOOKS: bool = false;
+static MOCK_IMPORTS: bool = false;
+static IMPORT_RECORDING: crate::builtin::ImportRecording = crate::builtin::ImportRecording::None;
+static CANCEL_BACKGROUND_WORK: bool = false;
+static HTTP_LIMITS: crate::builtin::HttpLimits = crate::builtin::HttpLimits::UNLIMITED;
+#[allow(dead_code)]
+static COOKIE_JAR: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE_LIMITS: crate::builtin::HttpCacheLimits = crate::builtin::HttpCacheLimits {
+    max_entries: Some(256usize),
+    max_size: Some(16777216usize),
+    max_body_size: Some(1048576usize),
+};
+#[allow(dead_code)]
+static FETCH_BASE_URL: Option<&str> = None;
+#[allow(dead_code)]
+static FETCH_GATEWAY: Option<&str> = None;
+static JS_EXPORT_MODULE: &str = include_str!("lone-surrogates.js");
+static JS_ADDITIONAL_MODULES: std::sync::LazyLock<
+    Vec<(&str, Box<dyn (Fn() -> String) + Send + Sync>)>,
+> = std::sync::LazyLock::new(|| { vec![] });
+static JS_MODULE_ORDER: &[&str] = &["lone-surrogates"];
+static JS_BUILTIN_PERMISSIONS: &[(&str, &[&str])] = &[];
+struct Component;
+impl crate::bindings::exports::quickjs::lone_surrogates::api::Guest for Component {
+    fn truncate(s: String, len: u32) -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:lone-surrogates",
+                    0usize,
+                    &["api", "truncate"],
+                    crate::wrappers::JsArgs((crate::wrappers::JsString(s), len)),
+                )
+                .await;
+            result.0
+        })
+    }
+    fn make_labelled(
+        label: String,
+    ) -> crate::bindings::exports::quickjs::lone_surrogates::api::Labelled {
+        crate::internal::async_exported_function(async move {
+            let result: crate::bindings::exports::quickjs::lone_surrogates::api::Labelled = crate::internal::call_js_export(
+                    "quickjs:lone-surrogates",
+                    1usize,
+                    &["api", "makeLabelled"],
+                    (crate::wrappers::JsString(label),),
+                )
+                .await;
+            result
+        })
+    }
+    fn well_formed(s: String) -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:lone-surrogates",
+                    2usize,
+                    &["api", "wellFormed"],
+                    (crate::wrappers::JsString(s),),
+                )
+                .await;
+            result.0
+        })
+    }
+}
+bindings::export!(Component with_types_in bindings);
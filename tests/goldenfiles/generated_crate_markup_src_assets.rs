@@ -0,0 +1,6 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:markup/markup
+// wit: sha256:59e525a38727bcd494faad4f4c84fb64376a93e63d8519ad8a85ebce4c403c6b
+// js markup: sha256:2807d5344b4b57d820ee50f235be7a33ee261db2da1a07b641ddbea6787efd60
+
+pub static ASSETS: &[(&str, &[u8])] = &[];
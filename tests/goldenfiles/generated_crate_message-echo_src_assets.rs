@@ -0,0 +1,6 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:message-echo/message-echo
+// wit: sha256:d132c4ae6ebd79459a3d0ee38bef99ec9a4ebe33e67d5da352ea0295a8398103
+// js message-echo: sha256:a321e05af93ce40c3f821d32a7f01b6234b7839f7fb9de62a6c8baeec9860e7d
+
+pub static ASSETS: &[(&str, &[u8])] = &[];
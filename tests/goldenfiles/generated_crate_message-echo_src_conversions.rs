@@ -0,0 +1,71 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:message-echo/message-echo
+// wit: sha256:d132c4ae6ebd79459a3d0ee38bef99ec9a4ebe33e67d5da352ea0295a8398103
+// js message-echo: sha256:a321e05af93ce40c3f821d32a7f01b6234b7839f7fb9de62a6c8baeec9860e7d
+
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::wasi::messaging::types::Error {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::wasi::messaging::types::Error::Timeout => {
+                crate::wrappers::variant_case(ctx, "timeout")
+            }
+            crate::bindings::wasi::messaging::types::Error::Connection(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "connection", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::wasi::messaging::types::Error::PermissionDenied(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "permission-denied", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::wasi::messaging::types::Error::Other(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "other", crate::wrappers::JsString(inner))
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::wasi::messaging::types::Error {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "timeout" => Ok(crate::bindings::wasi::messaging::types::Error::Timeout),
+            "connection" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(crate::bindings::wasi::messaging::types::Error::Connection(inner.0))
+            }
+            "permission-denied" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(
+                    crate::bindings::wasi::messaging::types::Error::PermissionDenied(
+                        inner.0,
+                    ),
+                )
+            }
+            "other" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(crate::bindings::wasi::messaging::types::Error::Other(inner.0))
+            }
+            _ => {
+                Err(crate::wrappers::unknown_variant_case("JS error", "WIT error", &tag))
+            }
+        }
+    }
+}
@@ -0,0 +1,69 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:message-echo/message-echo
+// wit: sha256:d132c4ae6ebd79459a3d0ee38bef99ec9a4ebe33e67d5da352ea0295a8398103
+// js message-echo: sha256:a321e05af93ce40c3f821d32a7f01b6234b7839f7fb9de62a6c8baeec9860e7d
+
+#[allow(static_mut_refs)]
+#[allow(unsafe_op_in_unsafe_fn)]
+mod bindings;
+mod assets;
+mod builtin;
+mod conversions;
+#[allow(unused)]
+mod internal;
+#[allow(unused)]
+mod modules;
+mod wrappers;
+static JS_EXPORT_MODULE_NAME: &str = "message-echo";
+static JS_EXPORT_NAMES_VERBATIM: bool = false;
+static INTERN_FIELD_NAMES: bool = false;
+#[allow(dead_code)]
+static REPLACE_LONE_SURROGATES: bool = false;
+static MATH_RANDOM_SEED: crate::builtin::MathRandomSeed = crate::builtin::MathRandomSeed::Default;
+static DATE_CLOCK: Option<fn() -> f64> = None;
+static IMPORT_HOOKS: bool = false;
+static MOCK_IMPORTS: bool = false;
+static IMPORT_RECORDING: crate::builtin::ImportRecording = crate::builtin::ImportRecording::None;
+static CANCEL_BACKGROUND_WORK: bool = false;
+static HTTP_LIMITS: crate::builtin::HttpLimits = crate::builtin::HttpLimits::UNLIMITED;
+#[allow(dead_code)]
+static COOKIE_JAR: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE_LIMITS: crate::builtin::HttpCacheLimits = crate::builtin::HttpCacheLimits {
+    max_entries: Some(256usize),
+    max_size: Some(16777216usize),
+    max_body_size: Some(1048576usize),
+};
+#[allow(dead_code)]
+static FETCH_BASE_URL: Option<&str> = None;
+#[allow(dead_code)]
+static FETCH_GATEWAY: Option<&str> = None;
+static JS_EXPORT_MODULE: &str = include_str!("message-echo.js");
+static JS_ADDITIONAL_MODULES: std::sync::LazyLock<
+    Vec<(&str, Box<dyn (Fn() -> String) + Send + Sync>)>,
+> = std::sync::LazyLock::new(|| { vec![] });
+static JS_MODULE_ORDER: &[&str] = &["message-echo"];
+static JS_BUILTIN_PERMISSIONS: &[(&str, &[&str])] = &[];
+struct Component;
+impl crate::bindings::exports::wasi::messaging::incoming_handler::Guest for Component {
+    fn handle(
+        message: crate::bindings::exports::wasi::messaging::incoming_handler::Message,
+    ) -> Result<(), crate::bindings::exports::wasi::messaging::incoming_handler::Error> {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsResult<
+                (),
+                crate::bindings::wasi::messaging::types::Error,
+            > = crate::internal::call_js_export_returning_result(
+                    "wasi:messaging@0.2.0-draft",
+                    0usize,
+                    &["onMessage"],
+                    (message,),
+                )
+                .await;
+            result.0
+        })
+    }
+}
+bindings::export!(Component with_types_in bindings);
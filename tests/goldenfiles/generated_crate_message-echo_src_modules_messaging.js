@@ -0,0 +1,92 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:message-echo/message-echo
+// wit: sha256:d132c4ae6ebd79459a3d0ee38bef99ec9a4ebe33e67d5da352ea0295a8398103
+// js message-echo: sha256:a321e05af93ce40c3f821d32a7f01b6234b7839f7fb9de62a6c8baeec9860e7d
+
+import * as producer from 'wasi:messaging/producer@0.2.0-draft';
+import * as types from 'wasi:messaging/types@0.2.0-draft';
+
+// Name of the client connection used when none is given
+const DEFAULT_CONNECTION = 'default';
+
+export class MessagingError extends Error {
+    constructor(message, options) {
+        super(message, options);
+        this.name = 'MessagingError';
+    }
+}
+
+// Runs a call of the host interface, converting the thrown `error` variants to `MessagingError`s
+function call(operation, f) {
+    try {
+        return f();
+    } catch (error) {
+        if (error instanceof Error) {
+            throw error;
+        }
+        const reason = error?.tag === undefined
+            ? String(error)
+            : error.val === undefined ? error.tag : `${error.tag}: ${error.val}`;
+        throw new MessagingError(`Failed to ${operation}: ${reason}`, { cause: error });
+    }
+}
+
+function toBytes(value) {
+    if (typeof value === 'string') {
+        return new TextEncoder().encode(value);
+    }
+    if (value instanceof Uint8Array) {
+        return value;
+    }
+    if (ArrayBuffer.isView(value)) {
+        return new Uint8Array(value.buffer, value.byteOffset, value.byteLength);
+    }
+    if (value instanceof ArrayBuffer) {
+        return new Uint8Array(value);
+    }
+    throw new TypeError('The data must be a string, an ArrayBuffer or a typed array');
+}
+
+// The connected clients by their names, reused by all publishes
+const clients = new Map();
+
+function client(name) {
+    let connected = clients.get(name);
+    if (connected === undefined) {
+        connected = call(`connect to ${name}`, () => types.Client.connect(name));
+        clients.set(name, connected);
+    }
+    return connected;
+}
+
+export async function publish(topic, data, options) {
+    const message = new types.Message(toBytes(data));
+    if (options?.contentType !== undefined) {
+        message.setContentType(options.contentType);
+    }
+    if (options?.metadata !== undefined) {
+        message.setMetadata(Object.entries(options.metadata).map(([key, value]) => [key, String(value)]));
+    }
+    const connection = options?.connection ?? DEFAULT_CONNECTION;
+    call(`publish to ${topic}`, () => producer.send(client(connection), topic, message));
+}
+
+export async function disconnect(connection = DEFAULT_CONNECTION) {
+    const connected = clients.get(connection);
+    if (connected !== undefined) {
+        clients.delete(connection);
+        connected.disconnect();
+    }
+}
+
+export function readMessage(message) {
+    const data = message.data();
+    return {
+        topic: message.topic(),
+        contentType: message.contentType(),
+        data,
+        metadata: Object.fromEntries(message.metadata() ?? []),
+        text: () => new TextDecoder().decode(data),
+        json: () => JSON.parse(new TextDecoder().decode(data)),
+    };
+}
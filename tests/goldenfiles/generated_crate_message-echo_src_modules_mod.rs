@@ -0,0 +1,73 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:message-echo/message-echo
+// wit: sha256:d132c4ae6ebd79459a3d0ee38bef99ec9a4ebe33e67d5da352ea0295a8398103
+// js message-echo: sha256:a321e05af93ce40c3f821d32a7f01b6234b7839f7fb9de62a6c8baeec9860e7d
+
+use rquickjs::JsLifetime;
+use rquickjs::class::{JsClass, Trace};
+use std::ops::Deref;
+pub mod wasi_messaging_0_2_0_draft_types;
+pub mod wasi_messaging_0_2_0_draft_producer;
+const NATIVE_MODULE_NAMES: &[&str] = &[
+    "wasi:messaging/types@0.2.0-draft",
+    "wasi:messaging/producer@0.2.0-draft",
+    "messaging",
+];
+pub struct NativeModuleResolver;
+impl rquickjs::loader::Resolver for NativeModuleResolver {
+    fn resolve<'js>(
+        &mut self,
+        _ctx: &rquickjs::Ctx<'js>,
+        base: &str,
+        name: &str,
+    ) -> rquickjs::Result<String> {
+        if NATIVE_MODULE_NAMES.contains(&name) {
+            Ok(name.to_string())
+        } else {
+            Err(rquickjs::Error::new_resolving(base, name))
+        }
+    }
+}
+pub struct NativeModuleLoader;
+impl rquickjs::loader::Loader for NativeModuleLoader {
+    fn load<'js>(
+        &mut self,
+        ctx: &rquickjs::Ctx<'js>,
+        name: &str,
+    ) -> rquickjs::Result<rquickjs::Module<'js, rquickjs::module::Declared>> {
+        match name {
+            "wasi:messaging/types@0.2.0-draft" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::wasi_messaging_0_2_0_draft_types::JsTypesModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "wasi:messaging/producer@0.2.0-draft" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::wasi_messaging_0_2_0_draft_producer::JsProducerModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "messaging" => {
+                rquickjs::Module::declare(
+                    ctx.clone(),
+                    name,
+                    include_str!("messaging.js"),
+                )
+            }
+            _ => Err(rquickjs::Error::new_loading(name)),
+        }
+    }
+}
+pub struct JsMessageEchoModule;
+impl rquickjs::module::ModuleDef for JsMessageEchoModule {
+    fn declare(decl: &rquickjs::module::Declarations) -> rquickjs::Result<()> {
+        Ok(())
+    }
+    fn evaluate<'js>(
+        ctx: &rquickjs::Ctx<'js>,
+        exports: &rquickjs::module::Exports<'js>,
+    ) -> rquickjs::Result<()> {
+        Ok(())
+    }
+}
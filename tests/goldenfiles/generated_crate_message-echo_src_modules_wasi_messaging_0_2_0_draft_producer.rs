@@ -0,0 +1,42 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:message-echo/message-echo
+// wit: sha256:d132c4ae6ebd79459a3d0ee38bef99ec9a4ebe33e67d5da352ea0295a8398103
+// js message-echo: sha256:a321e05af93ce40c3f821d32a7f01b6234b7839f7fb9de62a6c8baeec9860e7d
+
+use rquickjs::JsLifetime;
+use rquickjs::class::{JsClass, Trace};
+use std::ops::Deref;
+#[rquickjs::function]
+fn send(
+    ctx: rquickjs::Ctx<'_>,
+    c: crate::modules::wasi_messaging_0_2_0_draft_types::BorrowClientWrapper,
+    topic: crate::wrappers::JsString,
+    message: crate::bindings::wasi::messaging::producer::Message,
+) -> rquickjs::Result<()> {
+    let result: Result<(), crate::bindings::wasi::messaging::producer::Error> = crate::bindings::wasi::messaging::producer::send(
+        &c.0,
+        topic.0.as_str(),
+        message,
+    );
+    match result {
+        Ok(result) => Ok(result),
+        Err(error) => {
+            let error: crate::bindings::wasi::messaging::types::Error = error;
+            Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+        }
+    }
+}
+pub struct JsProducerModule;
+impl rquickjs::module::ModuleDef for JsProducerModule {
+    fn declare(decl: &rquickjs::module::Declarations) -> rquickjs::Result<()> {
+        decl.declare("send")?;
+        Ok(())
+    }
+    fn evaluate<'js>(
+        ctx: &rquickjs::Ctx<'js>,
+        exports: &rquickjs::module::Exports<'js>,
+    ) -> rquickjs::Result<()> {
+        exports.export("send", js_send)?;
+        Ok(())
+    }
+}
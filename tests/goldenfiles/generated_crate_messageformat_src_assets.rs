@@ -0,0 +1,6 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:messageformat/messageformat
+// wit: sha256:72c61b1c24da26ef232e94167bea17b8c2a8f08d64ab9395c4dd48585a4abc68
+// js messageformat: sha256:d6a86daf7a8a003d7dc424cad66e0c6e9aadd73f90bb42827a5237b1b83ea2a1
+
+pub static ASSETS: &[(&str, &[u8])] = &[];
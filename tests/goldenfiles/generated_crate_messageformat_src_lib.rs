@@ -0,0 +1,128 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:messageformat/messageformat
+// wit: sha256:72c61b1c24da26ef232e94167bea17b8c2a8f08d64ab9395c4dd48585a4abc68
+// js messageformat: sha256:d6a86daf7a8a003d7dc424cad66e0c6e9aadd73f90bb42827a5237b1b83ea2a1
+
+#[allow(static_mut_refs)]
+#[allow(unsafe_op_in_unsafe_fn)]
+mod bindings;
+mod assets;
+mod builtin;
+mod conversions;
+#[allow(unused)]
+mod internal;
+#[allow(unused)]
+mod modules;
+mod wrappers;
+static JS_EXPORT_MODULE_NAME: &str = "messageformat";
+static JS_EXPORT_NAMES_VERBATIM: bool = false;
+static INTERN_FIELD_NAMES: bool = false;
+#[allow(dead_code)]
+static REPLACE_LONE_SURROGATES: bool = false;
+static MATH_RANDOM_SEED: crate::builtin::MathRandomSeed = crate::builtin::MathRandomSeed::Default;
+static DATE_CLOCK: Option<fn() -> f64> = None;
+static IMPORT_HOOKS: bool = false;
+static MOCK_IMPORTS: bool = false;
+static IMPORT_RECORDING: crate::builtin::ImportRecording = crate::builtin::ImportRecording::None;
+static CANCEL_BACKGROUND_WORK: bool = false;
+static HTTP_LIMITS: crate::builtin::HttpLimits = crate::builtin::HttpLimits::UNLIMITED;
+#[allow(dead_code)]
+static COOKIE_JAR: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE_LIMITS: crate::builtin::HttpCacheLimits = crate::builtin::HttpCacheLimits {
+    max_entries: Some(256usize),
+    max_size: Some(16777216usize),
+    max_body_size: Some(1048576usize),
+};
+#[allow(dead_code)]
+static FETCH_BASE_URL: Option<&str> = None;
+#[allow(dead_code)]
+static FETCH_GATEWAY: Option<&str> = None;
+static JS_EXPORT_MODULE: &str = include_str!("messageformat.js");
+static JS_ADDITIONAL_MODULES: std::sync::LazyLock<
+    Vec<(&str, Box<dyn (Fn() -> String) + Send + Sync>)>,
+> = std::sync::LazyLock::new(|| { vec![] });
+static JS_MODULE_ORDER: &[&str] = &["messageformat"];
+static JS_BUILTIN_PERMISSIONS: &[(&str, &[&str])] = &[];
+struct Component;
+impl crate::bindings::Guest for Component {
+    fn cart(locale: String, name: String, count: f64) -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:messageformat",
+                    0usize,
+                    &["cart"],
+                    crate::wrappers::JsArgs((
+                        crate::wrappers::JsString(locale),
+                        crate::wrappers::JsString(name),
+                        count,
+                    )),
+                )
+                .await;
+            result.0
+        })
+    }
+    fn ranking(place: u32) -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:messageformat",
+                    1usize,
+                    &["ranking"],
+                    (place,),
+                )
+                .await;
+            result.0
+        })
+    }
+    fn invitation(host: String, guests: Vec<String>) -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:messageformat",
+                    2usize,
+                    &["invitation"],
+                    crate::wrappers::JsArgs((
+                        crate::wrappers::JsString(host),
+                        crate::wrappers::JsList(
+                            guests
+                                .into_iter()
+                                .map(|v| crate::wrappers::JsString(v))
+                                .collect::<Vec<_>>(),
+                        ),
+                    )),
+                )
+                .await;
+            result.0
+        })
+    }
+    fn receipt(locale: String, total: f64, date: f64) -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:messageformat",
+                    3usize,
+                    &["receipt"],
+                    crate::wrappers::JsArgs((
+                        crate::wrappers::JsString(locale),
+                        total,
+                        date,
+                    )),
+                )
+                .await;
+            result.0
+        })
+    }
+    fn errors() -> Vec<String> {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsList<crate::wrappers::JsString> = crate::internal::call_js_export(
+                    "quickjs:messageformat",
+                    4usize,
+                    &["errors"],
+                    crate::wrappers::JsArgs(()),
+                )
+                .await;
+            result.0.into_iter().map(|v| v.0).collect::<Vec<_>>()
+        })
+    }
+}
+bindings::export!(Component with_types_in bindings);
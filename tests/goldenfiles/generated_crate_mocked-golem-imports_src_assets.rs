@@ -0,0 +1,6 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:mocked-golem-imports/mocked-golem-imports
+// wit: sha256:e2a5c934071279725dae2fd75e09aa4ebe6992aaedf7cac0976199427144664e
+// js mocked-golem-imports: sha256:1505fb2c5e8bcc2584584e3f366a93497be44e32b3a6e7d2f5bd49771d3ff4b2
+
+pub static ASSETS: &[(&str, &[u8])] = &[];
@@ -0,0 +1,6568 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:mocked-golem-imports/mocked-golem-imports
+// wit: sha256:e2a5c934071279725dae2fd75e09aa4ebe6992aaedf7cac0976199427144664e
+// js mocked-golem-imports: sha256:1505fb2c5e8bcc2584584e3f366a93497be44e32b3a6e7d2f5bd49771d3ff4b2
+
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::wasi::io::streams::StreamError {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::wasi::io::streams::StreamError::LastOperationFailed(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::wasi::io::streams::Error,
+                >(ctx, "last-operation-failed", inner)
+            }
+            crate::bindings::wasi::io::streams::StreamError::Closed => {
+                crate::wrappers::variant_case(ctx, "closed")
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::wasi::io::streams::StreamError {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "last-operation-failed" => {
+                let inner: crate::bindings::wasi::io::streams::Error = crate::wrappers::variant_value(
+                    &obj,
+                    "error",
+                )?;
+                Ok(
+                    crate::bindings::wasi::io::streams::StreamError::LastOperationFailed(
+                        inner,
+                    ),
+                )
+            }
+            "closed" => Ok(crate::bindings::wasi::io::streams::StreamError::Closed),
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS stream-error",
+                        "WIT stream-error",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::wasi::clocks::wall_clock::Datetime {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::BigIntWrapper<u64>,
+            >("seconds", crate::wrappers::BigIntWrapper(self.seconds))?;
+        obj.set::<_, u32>("nanoseconds", self.nanoseconds)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::wasi::clocks::wall_clock::Datetime {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT datetime",
+            &["seconds", "nanoseconds"],
+            &[("seconds", "u64"), ("nanoseconds", "u32")],
+            false,
+        )?;
+        let seconds: crate::wrappers::BigIntWrapper<u64> = crate::wrappers::get_field(
+            &obj,
+            "seconds",
+            "u64",
+        )?;
+        let seconds: u64 = seconds.0;
+        let nanoseconds: u32 = crate::wrappers::get_field(&obj, "nanoseconds", "u32")?;
+        let nanoseconds: u32 = nanoseconds;
+        Ok(Self { seconds, nanoseconds })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rpc::types::Uuid {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::BigIntWrapper<u64>,
+            >("highBits", crate::wrappers::BigIntWrapper(self.high_bits))?;
+        obj.set::<
+                _,
+                crate::wrappers::BigIntWrapper<u64>,
+            >("lowBits", crate::wrappers::BigIntWrapper(self.low_bits))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rpc::types::Uuid {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT uuid",
+            &["highBits", "lowBits"],
+            &[("highBits", "u64"), ("lowBits", "u64")],
+            false,
+        )?;
+        let high_bits: crate::wrappers::BigIntWrapper<u64> = crate::wrappers::get_field(
+            &obj,
+            "highBits",
+            "u64",
+        )?;
+        let high_bits: u64 = high_bits.0;
+        let low_bits: crate::wrappers::BigIntWrapper<u64> = crate::wrappers::get_field(
+            &obj,
+            "lowBits",
+            "u64",
+        )?;
+        let low_bits: u64 = low_bits.0;
+        Ok(Self { high_bits, low_bits })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rpc::types::ComponentId {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<_, crate::bindings::golem::rpc::types::Uuid>("uuid", self.uuid)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rpc::types::ComponentId {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT component-id",
+            &["uuid"],
+            &[("uuid", "uuid")],
+            false,
+        )?;
+        let uuid: crate::bindings::golem::rpc::types::Uuid = crate::wrappers::get_field(
+            &obj,
+            "uuid",
+            "uuid",
+        )?;
+        let uuid: crate::bindings::golem::rpc::types::Uuid = uuid;
+        Ok(Self { uuid })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rpc::types::WorkerId {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::golem::rpc::types::ComponentId,
+            >("componentId", self.component_id)?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("workerName", crate::wrappers::JsString(self.worker_name))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rpc::types::WorkerId {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT worker-id",
+            &["componentId", "workerName"],
+            &[("componentId", "component-id"), ("workerName", "string")],
+            false,
+        )?;
+        let component_id: crate::bindings::golem::rpc::types::ComponentId = crate::wrappers::get_field(
+            &obj,
+            "componentId",
+            "component-id",
+        )?;
+        let component_id: crate::bindings::golem::rpc::types::ComponentId = component_id;
+        let worker_name: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "workerName",
+            "string",
+        )?;
+        let worker_name: String = worker_name.0;
+        Ok(Self { component_id, worker_name })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rpc::types::ResourceMode {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::rpc::types::ResourceMode::Owned => {
+                "owned".into_js(ctx)
+            }
+            crate::bindings::golem::rpc::types::ResourceMode::Borrowed => {
+                "borrowed".into_js(ctx)
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rpc::types::ResourceMode {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let value = crate::wrappers::enum_case(
+            value,
+            "JS resource-mode",
+            "WIT resource-mode",
+        )?;
+        match value.as_str() {
+            "owned" => Ok(crate::bindings::golem::rpc::types::ResourceMode::Owned),
+            "borrowed" => Ok(crate::bindings::golem::rpc::types::ResourceMode::Borrowed),
+            _ => {
+                Err(
+                    crate::wrappers::unknown_enum_case(
+                        "JS resource-mode",
+                        "WIT resource-mode",
+                        &value,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rpc::types::WitTypeNode {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::rpc::types::WitTypeNode::RecordType(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsList<
+                        rquickjs::convert::List<(crate::wrappers::JsString, i32)>,
+                    >,
+                >(
+                    ctx,
+                    "record-type",
+                    crate::wrappers::JsList(
+                        inner
+                            .into_iter()
+                            .map(|v| rquickjs::convert::List((
+                                crate::wrappers::JsString(v.0),
+                                v.1,
+                            )))
+                            .collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            crate::bindings::golem::rpc::types::WitTypeNode::VariantType(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsList<
+                        rquickjs::convert::List<(crate::wrappers::JsString, Option<i32>)>,
+                    >,
+                >(
+                    ctx,
+                    "variant-type",
+                    crate::wrappers::JsList(
+                        inner
+                            .into_iter()
+                            .map(|v| rquickjs::convert::List((
+                                crate::wrappers::JsString(v.0),
+                                v.1.map(|v| v),
+                            )))
+                            .collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            crate::bindings::golem::rpc::types::WitTypeNode::EnumType(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsList<crate::wrappers::JsString>,
+                >(
+                    ctx,
+                    "enum-type",
+                    crate::wrappers::JsList(
+                        inner
+                            .into_iter()
+                            .map(|v| crate::wrappers::JsString(v))
+                            .collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            crate::bindings::golem::rpc::types::WitTypeNode::FlagsType(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsList<crate::wrappers::JsString>,
+                >(
+                    ctx,
+                    "flags-type",
+                    crate::wrappers::JsList(
+                        inner
+                            .into_iter()
+                            .map(|v| crate::wrappers::JsString(v))
+                            .collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            crate::bindings::golem::rpc::types::WitTypeNode::TupleType(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsList<i32>,
+                >(
+                    ctx,
+                    "tuple-type",
+                    crate::wrappers::JsList(
+                        inner.into_iter().map(|v| v).collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            crate::bindings::golem::rpc::types::WitTypeNode::ListType(inner) => {
+                crate::wrappers::variant_case_with_value::<i32>(ctx, "list-type", inner)
+            }
+            crate::bindings::golem::rpc::types::WitTypeNode::OptionType(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    i32,
+                >(ctx, "option-type", inner)
+            }
+            crate::bindings::golem::rpc::types::WitTypeNode::ResultType(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    rquickjs::convert::List<(Option<i32>, Option<i32>)>,
+                >(
+                    ctx,
+                    "result-type",
+                    rquickjs::convert::List((inner.0.map(|v| v), inner.1.map(|v| v))),
+                )
+            }
+            crate::bindings::golem::rpc::types::WitTypeNode::PrimU8Type => {
+                crate::wrappers::variant_case(ctx, "prim-u8-type")
+            }
+            crate::bindings::golem::rpc::types::WitTypeNode::PrimU16Type => {
+                crate::wrappers::variant_case(ctx, "prim-u16-type")
+            }
+            crate::bindings::golem::rpc::types::WitTypeNode::PrimU32Type => {
+                crate::wrappers::variant_case(ctx, "prim-u32-type")
+            }
+            crate::bindings::golem::rpc::types::WitTypeNode::PrimU64Type => {
+                crate::wrappers::variant_case(ctx, "prim-u64-type")
+            }
+            crate::bindings::golem::rpc::types::WitTypeNode::PrimS8Type => {
+                crate::wrappers::variant_case(ctx, "prim-s8-type")
+            }
+            crate::bindings::golem::rpc::types::WitTypeNode::PrimS16Type => {
+                crate::wrappers::variant_case(ctx, "prim-s16-type")
+            }
+            crate::bindings::golem::rpc::types::WitTypeNode::PrimS32Type => {
+                crate::wrappers::variant_case(ctx, "prim-s32-type")
+            }
+            crate::bindings::golem::rpc::types::WitTypeNode::PrimS64Type => {
+                crate::wrappers::variant_case(ctx, "prim-s64-type")
+            }
+            crate::bindings::golem::rpc::types::WitTypeNode::PrimF32Type => {
+                crate::wrappers::variant_case(ctx, "prim-f32-type")
+            }
+            crate::bindings::golem::rpc::types::WitTypeNode::PrimF64Type => {
+                crate::wrappers::variant_case(ctx, "prim-f64-type")
+            }
+            crate::bindings::golem::rpc::types::WitTypeNode::PrimCharType => {
+                crate::wrappers::variant_case(ctx, "prim-char-type")
+            }
+            crate::bindings::golem::rpc::types::WitTypeNode::PrimBoolType => {
+                crate::wrappers::variant_case(ctx, "prim-bool-type")
+            }
+            crate::bindings::golem::rpc::types::WitTypeNode::PrimStringType => {
+                crate::wrappers::variant_case(ctx, "prim-string-type")
+            }
+            crate::bindings::golem::rpc::types::WitTypeNode::HandleType(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    rquickjs::convert::List<
+                        (
+                            crate::wrappers::BigIntWrapper<u64>,
+                            crate::bindings::golem::rpc::types::ResourceMode,
+                        ),
+                    >,
+                >(
+                    ctx,
+                    "handle-type",
+                    rquickjs::convert::List((
+                        crate::wrappers::BigIntWrapper(inner.0),
+                        inner.1,
+                    )),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rpc::types::WitTypeNode {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "record-type" => {
+                let inner: crate::wrappers::JsList<
+                    rquickjs::convert::List<(crate::wrappers::JsString, i32)>,
+                > = crate::wrappers::variant_value(
+                    &obj,
+                    "list<tuple<string, node-index>>",
+                )?;
+                Ok(
+                    crate::bindings::golem::rpc::types::WitTypeNode::RecordType(
+                        inner
+                            .0
+                            .into_iter()
+                            .map(|v| (v.0.0.0.clone(), v.0.1))
+                            .collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            "variant-type" => {
+                let inner: crate::wrappers::JsList<
+                    rquickjs::convert::List<(crate::wrappers::JsString, Option<i32>)>,
+                > = crate::wrappers::variant_value(
+                    &obj,
+                    "list<tuple<string, option<node-index>>>",
+                )?;
+                Ok(
+                    crate::bindings::golem::rpc::types::WitTypeNode::VariantType(
+                        inner
+                            .0
+                            .into_iter()
+                            .map(|v| (v.0.0.0.clone(), v.0.1.map(|v| v)))
+                            .collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            "enum-type" => {
+                let inner: crate::wrappers::JsList<crate::wrappers::JsString> = crate::wrappers::variant_value(
+                    &obj,
+                    "list<string>",
+                )?;
+                Ok(
+                    crate::bindings::golem::rpc::types::WitTypeNode::EnumType(
+                        inner.0.into_iter().map(|v| v.0).collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            "flags-type" => {
+                let inner: crate::wrappers::JsList<crate::wrappers::JsString> = crate::wrappers::variant_value(
+                    &obj,
+                    "list<string>",
+                )?;
+                Ok(
+                    crate::bindings::golem::rpc::types::WitTypeNode::FlagsType(
+                        inner.0.into_iter().map(|v| v.0).collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            "tuple-type" => {
+                let inner: crate::wrappers::JsList<i32> = crate::wrappers::variant_value(
+                    &obj,
+                    "list<node-index>",
+                )?;
+                Ok(
+                    crate::bindings::golem::rpc::types::WitTypeNode::TupleType(
+                        inner.0.into_iter().map(|v| v).collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            "list-type" => {
+                let inner: i32 = crate::wrappers::variant_value(&obj, "node-index")?;
+                Ok(crate::bindings::golem::rpc::types::WitTypeNode::ListType(inner))
+            }
+            "option-type" => {
+                let inner: i32 = crate::wrappers::variant_value(&obj, "node-index")?;
+                Ok(crate::bindings::golem::rpc::types::WitTypeNode::OptionType(inner))
+            }
+            "result-type" => {
+                let inner: rquickjs::convert::List<(Option<i32>, Option<i32>)> = crate::wrappers::variant_value(
+                    &obj,
+                    "tuple<option<node-index>, option<node-index>>",
+                )?;
+                Ok(
+                    crate::bindings::golem::rpc::types::WitTypeNode::ResultType((
+                        inner.0.0.map(|v| v),
+                        inner.0.1.map(|v| v),
+                    )),
+                )
+            }
+            "prim-u8-type" => {
+                Ok(crate::bindings::golem::rpc::types::WitTypeNode::PrimU8Type)
+            }
+            "prim-u16-type" => {
+                Ok(crate::bindings::golem::rpc::types::WitTypeNode::PrimU16Type)
+            }
+            "prim-u32-type" => {
+                Ok(crate::bindings::golem::rpc::types::WitTypeNode::PrimU32Type)
+            }
+            "prim-u64-type" => {
+                Ok(crate::bindings::golem::rpc::types::WitTypeNode::PrimU64Type)
+            }
+            "prim-s8-type" => {
+                Ok(crate::bindings::golem::rpc::types::WitTypeNode::PrimS8Type)
+            }
+            "prim-s16-type" => {
+                Ok(crate::bindings::golem::rpc::types::WitTypeNode::PrimS16Type)
+            }
+            "prim-s32-type" => {
+                Ok(crate::bindings::golem::rpc::types::WitTypeNode::PrimS32Type)
+            }
+            "prim-s64-type" => {
+                Ok(crate::bindings::golem::rpc::types::WitTypeNode::PrimS64Type)
+            }
+            "prim-f32-type" => {
+                Ok(crate::bindings::golem::rpc::types::WitTypeNode::PrimF32Type)
+            }
+            "prim-f64-type" => {
+                Ok(crate::bindings::golem::rpc::types::WitTypeNode::PrimF64Type)
+            }
+            "prim-char-type" => {
+                Ok(crate::bindings::golem::rpc::types::WitTypeNode::PrimCharType)
+            }
+            "prim-bool-type" => {
+                Ok(crate::bindings::golem::rpc::types::WitTypeNode::PrimBoolType)
+            }
+            "prim-string-type" => {
+                Ok(crate::bindings::golem::rpc::types::WitTypeNode::PrimStringType)
+            }
+            "handle-type" => {
+                let inner: rquickjs::convert::List<
+                    (
+                        crate::wrappers::BigIntWrapper<u64>,
+                        crate::bindings::golem::rpc::types::ResourceMode,
+                    ),
+                > = crate::wrappers::variant_value(
+                    &obj,
+                    "tuple<resource-id, resource-mode>",
+                )?;
+                Ok(
+                    crate::bindings::golem::rpc::types::WitTypeNode::HandleType((
+                        inner.0.0.0,
+                        inner.0.1,
+                    )),
+                )
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS wit-type-node",
+                        "WIT wit-type-node",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::rpc::types::NamedWitTypeNode {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >("name", self.name.map(|v| crate::wrappers::JsString(v)))?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >("owner", self.owner.map(|v| crate::wrappers::JsString(v)))?;
+        obj.set::<
+                _,
+                crate::bindings::golem::rpc::types::WitTypeNode,
+            >("type", self.type_)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::rpc::types::NamedWitTypeNode {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT named-wit-type-node",
+            &["name", "owner", "type"],
+            &[("type", "wit-type-node")],
+            false,
+        )?;
+        let name: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "name",
+            "option<string>",
+        )?;
+        let name: Option<String> = name.map(|v| v.0);
+        let owner: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "owner",
+            "option<string>",
+        )?;
+        let owner: Option<String> = owner.map(|v| v.0);
+        let type_: crate::bindings::golem::rpc::types::WitTypeNode = crate::wrappers::get_field(
+            &obj,
+            "type",
+            "wit-type-node",
+        )?;
+        let type_: crate::bindings::golem::rpc::types::WitTypeNode = type_;
+        Ok(Self { name, owner, type_ })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rpc::types::WitType {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<
+                    crate::bindings::golem::rpc::types::NamedWitTypeNode,
+                >,
+            >(
+            "nodes",
+            crate::wrappers::JsList(
+                self.nodes.into_iter().map(|v| v).collect::<Vec<_>>(),
+            ),
+        )?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rpc::types::WitType {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT wit-type",
+            &["nodes"],
+            &[("nodes", "list<named-wit-type-node>")],
+            false,
+        )?;
+        let nodes: crate::wrappers::JsList<
+            crate::bindings::golem::rpc::types::NamedWitTypeNode,
+        > = crate::wrappers::get_field(&obj, "nodes", "list<named-wit-type-node>")?;
+        let nodes: Vec<crate::bindings::golem::rpc::types::NamedWitTypeNode> = nodes
+            .0
+            .into_iter()
+            .map(|v| v)
+            .collect::<Vec<_>>();
+        Ok(Self { nodes })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rpc::types::Uri {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("value", crate::wrappers::JsString(self.value))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rpc::types::Uri {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT uri",
+            &["value"],
+            &[("value", "string")],
+            false,
+        )?;
+        let value: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "value",
+            "string",
+        )?;
+        let value: String = value.0;
+        Ok(Self { value })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rpc::types::WitNode {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::rpc::types::WitNode::RecordValue(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsList<i32>,
+                >(
+                    ctx,
+                    "record-value",
+                    crate::wrappers::JsList(
+                        inner.into_iter().map(|v| v).collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            crate::bindings::golem::rpc::types::WitNode::VariantValue(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    rquickjs::convert::List<(u32, Option<i32>)>,
+                >(
+                    ctx,
+                    "variant-value",
+                    rquickjs::convert::List((inner.0, inner.1.map(|v| v))),
+                )
+            }
+            crate::bindings::golem::rpc::types::WitNode::EnumValue(inner) => {
+                crate::wrappers::variant_case_with_value::<u32>(ctx, "enum-value", inner)
+            }
+            crate::bindings::golem::rpc::types::WitNode::FlagsValue(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsList<bool>,
+                >(
+                    ctx,
+                    "flags-value",
+                    crate::wrappers::JsList(
+                        inner.into_iter().map(|v| v).collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            crate::bindings::golem::rpc::types::WitNode::TupleValue(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsList<i32>,
+                >(
+                    ctx,
+                    "tuple-value",
+                    crate::wrappers::JsList(
+                        inner.into_iter().map(|v| v).collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            crate::bindings::golem::rpc::types::WitNode::ListValue(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsList<i32>,
+                >(
+                    ctx,
+                    "list-value",
+                    crate::wrappers::JsList(
+                        inner.into_iter().map(|v| v).collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            crate::bindings::golem::rpc::types::WitNode::OptionValue(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    Option<i32>,
+                >(ctx, "option-value", inner.map(|v| v))
+            }
+            crate::bindings::golem::rpc::types::WitNode::ResultValue(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsResult<Option<i32>, Option<i32>>,
+                >(
+                    ctx,
+                    "result-value",
+                    crate::wrappers::JsResult(
+                        match inner {
+                            Ok(v) => Ok(v.map(|v| v)),
+                            Err(v) => Err(v.map(|v| v)),
+                        },
+                    ),
+                )
+            }
+            crate::bindings::golem::rpc::types::WitNode::PrimU8(inner) => {
+                crate::wrappers::variant_case_with_value::<u8>(ctx, "prim-u8", inner)
+            }
+            crate::bindings::golem::rpc::types::WitNode::PrimU16(inner) => {
+                crate::wrappers::variant_case_with_value::<u16>(ctx, "prim-u16", inner)
+            }
+            crate::bindings::golem::rpc::types::WitNode::PrimU32(inner) => {
+                crate::wrappers::variant_case_with_value::<u32>(ctx, "prim-u32", inner)
+            }
+            crate::bindings::golem::rpc::types::WitNode::PrimU64(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::BigIntWrapper<u64>,
+                >(ctx, "prim-u64", crate::wrappers::BigIntWrapper(inner))
+            }
+            crate::bindings::golem::rpc::types::WitNode::PrimS8(inner) => {
+                crate::wrappers::variant_case_with_value::<i8>(ctx, "prim-s8", inner)
+            }
+            crate::bindings::golem::rpc::types::WitNode::PrimS16(inner) => {
+                crate::wrappers::variant_case_with_value::<i16>(ctx, "prim-s16", inner)
+            }
+            crate::bindings::golem::rpc::types::WitNode::PrimS32(inner) => {
+                crate::wrappers::variant_case_with_value::<i32>(ctx, "prim-s32", inner)
+            }
+            crate::bindings::golem::rpc::types::WitNode::PrimS64(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::BigIntWrapper<i64>,
+                >(ctx, "prim-s64", crate::wrappers::BigIntWrapper(inner))
+            }
+            crate::bindings::golem::rpc::types::WitNode::PrimFloat32(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    f32,
+                >(ctx, "prim-float32", inner)
+            }
+            crate::bindings::golem::rpc::types::WitNode::PrimFloat64(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    f64,
+                >(ctx, "prim-float64", inner)
+            }
+            crate::bindings::golem::rpc::types::WitNode::PrimChar(inner) => {
+                crate::wrappers::variant_case_with_value::<char>(ctx, "prim-char", inner)
+            }
+            crate::bindings::golem::rpc::types::WitNode::PrimBool(inner) => {
+                crate::wrappers::variant_case_with_value::<bool>(ctx, "prim-bool", inner)
+            }
+            crate::bindings::golem::rpc::types::WitNode::PrimString(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "prim-string", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::rpc::types::WitNode::Handle(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    rquickjs::convert::List<
+                        (
+                            crate::bindings::golem::rpc::types::Uri,
+                            crate::wrappers::BigIntWrapper<u64>,
+                        ),
+                    >,
+                >(
+                    ctx,
+                    "handle",
+                    rquickjs::convert::List((
+                        inner.0,
+                        crate::wrappers::BigIntWrapper(inner.1),
+                    )),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rpc::types::WitNode {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "record-value" => {
+                let inner: crate::wrappers::JsList<i32> = crate::wrappers::variant_value(
+                    &obj,
+                    "list<node-index>",
+                )?;
+                Ok(
+                    crate::bindings::golem::rpc::types::WitNode::RecordValue(
+                        inner.0.into_iter().map(|v| v).collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            "variant-value" => {
+                let inner: rquickjs::convert::List<(u32, Option<i32>)> = crate::wrappers::variant_value(
+                    &obj,
+                    "tuple<u32, option<node-index>>",
+                )?;
+                Ok(
+                    crate::bindings::golem::rpc::types::WitNode::VariantValue((
+                        inner.0.0,
+                        inner.0.1.map(|v| v),
+                    )),
+                )
+            }
+            "enum-value" => {
+                let inner: u32 = crate::wrappers::variant_value(&obj, "u32")?;
+                Ok(crate::bindings::golem::rpc::types::WitNode::EnumValue(inner))
+            }
+            "flags-value" => {
+                let inner: crate::wrappers::JsList<bool> = crate::wrappers::variant_value(
+                    &obj,
+                    "list<bool>",
+                )?;
+                Ok(
+                    crate::bindings::golem::rpc::types::WitNode::FlagsValue(
+                        inner.0.into_iter().map(|v| v).collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            "tuple-value" => {
+                let inner: crate::wrappers::JsList<i32> = crate::wrappers::variant_value(
+                    &obj,
+                    "list<node-index>",
+                )?;
+                Ok(
+                    crate::bindings::golem::rpc::types::WitNode::TupleValue(
+                        inner.0.into_iter().map(|v| v).collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            "list-value" => {
+                let inner: crate::wrappers::JsList<i32> = crate::wrappers::variant_value(
+                    &obj,
+                    "list<node-index>",
+                )?;
+                Ok(
+                    crate::bindings::golem::rpc::types::WitNode::ListValue(
+                        inner.0.into_iter().map(|v| v).collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            "option-value" => {
+                let inner: Option<i32> = crate::wrappers::variant_value(
+                    &obj,
+                    "option<node-index>",
+                )?;
+                Ok(
+                    crate::bindings::golem::rpc::types::WitNode::OptionValue(
+                        inner.map(|v| v),
+                    ),
+                )
+            }
+            "result-value" => {
+                let inner: crate::wrappers::JsResult<Option<i32>, Option<i32>> = crate::wrappers::variant_value(
+                    &obj,
+                    "result<option<node-index>, option<node-index>>",
+                )?;
+                Ok(
+                    crate::bindings::golem::rpc::types::WitNode::ResultValue(
+                        inner.0.map(|v| v.map(|v| v)).map_err(|v| v.map(|v| v)),
+                    ),
+                )
+            }
+            "prim-u8" => {
+                let inner: u8 = crate::wrappers::variant_value(&obj, "u8")?;
+                Ok(crate::bindings::golem::rpc::types::WitNode::PrimU8(inner))
+            }
+            "prim-u16" => {
+                let inner: u16 = crate::wrappers::variant_value(&obj, "u16")?;
+                Ok(crate::bindings::golem::rpc::types::WitNode::PrimU16(inner))
+            }
+            "prim-u32" => {
+                let inner: u32 = crate::wrappers::variant_value(&obj, "u32")?;
+                Ok(crate::bindings::golem::rpc::types::WitNode::PrimU32(inner))
+            }
+            "prim-u64" => {
+                let inner: crate::wrappers::BigIntWrapper<u64> = crate::wrappers::variant_value(
+                    &obj,
+                    "u64",
+                )?;
+                Ok(crate::bindings::golem::rpc::types::WitNode::PrimU64(inner.0))
+            }
+            "prim-s8" => {
+                let inner: i8 = crate::wrappers::variant_value(&obj, "s8")?;
+                Ok(crate::bindings::golem::rpc::types::WitNode::PrimS8(inner))
+            }
+            "prim-s16" => {
+                let inner: i16 = crate::wrappers::variant_value(&obj, "s16")?;
+                Ok(crate::bindings::golem::rpc::types::WitNode::PrimS16(inner))
+            }
+            "prim-s32" => {
+                let inner: i32 = crate::wrappers::variant_value(&obj, "s32")?;
+                Ok(crate::bindings::golem::rpc::types::WitNode::PrimS32(inner))
+            }
+            "prim-s64" => {
+                let inner: crate::wrappers::BigIntWrapper<i64> = crate::wrappers::variant_value(
+                    &obj,
+                    "s64",
+                )?;
+                Ok(crate::bindings::golem::rpc::types::WitNode::PrimS64(inner.0))
+            }
+            "prim-float32" => {
+                let inner: f32 = crate::wrappers::variant_value(&obj, "f32")?;
+                Ok(crate::bindings::golem::rpc::types::WitNode::PrimFloat32(inner))
+            }
+            "prim-float64" => {
+                let inner: f64 = crate::wrappers::variant_value(&obj, "f64")?;
+                Ok(crate::bindings::golem::rpc::types::WitNode::PrimFloat64(inner))
+            }
+            "prim-char" => {
+                let inner: char = crate::wrappers::variant_value(&obj, "char")?;
+                Ok(crate::bindings::golem::rpc::types::WitNode::PrimChar(inner))
+            }
+            "prim-bool" => {
+                let inner: bool = crate::wrappers::variant_value(&obj, "bool")?;
+                Ok(crate::bindings::golem::rpc::types::WitNode::PrimBool(inner))
+            }
+            "prim-string" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(crate::bindings::golem::rpc::types::WitNode::PrimString(inner.0))
+            }
+            "handle" => {
+                let inner: rquickjs::convert::List<
+                    (
+                        crate::bindings::golem::rpc::types::Uri,
+                        crate::wrappers::BigIntWrapper<u64>,
+                    ),
+                > = crate::wrappers::variant_value(&obj, "tuple<uri, u64>")?;
+                Ok(
+                    crate::bindings::golem::rpc::types::WitNode::Handle((
+                        inner.0.0,
+                        inner.0.1.0,
+                    )),
+                )
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS wit-node",
+                        "WIT wit-node",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rpc::types::WitValue {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<crate::bindings::golem::rpc::types::WitNode>,
+            >(
+            "nodes",
+            crate::wrappers::JsList(
+                self.nodes.into_iter().map(|v| v).collect::<Vec<_>>(),
+            ),
+        )?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rpc::types::WitValue {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT wit-value",
+            &["nodes"],
+            &[("nodes", "list<wit-node>")],
+            false,
+        )?;
+        let nodes: crate::wrappers::JsList<
+            crate::bindings::golem::rpc::types::WitNode,
+        > = crate::wrappers::get_field(&obj, "nodes", "list<wit-node>")?;
+        let nodes: Vec<crate::bindings::golem::rpc::types::WitNode> = nodes
+            .0
+            .into_iter()
+            .map(|v| v)
+            .collect::<Vec<_>>();
+        Ok(Self { nodes })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rpc::types::ValueAndType {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<_, crate::bindings::golem::rpc::types::WitValue>("value", self.value)?;
+        obj.set::<_, crate::bindings::golem::rpc::types::WitType>("typ", self.typ)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rpc::types::ValueAndType {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT value-and-type",
+            &["value", "typ"],
+            &[("value", "wit-value"), ("typ", "wit-type")],
+            false,
+        )?;
+        let value: crate::bindings::golem::rpc::types::WitValue = crate::wrappers::get_field(
+            &obj,
+            "value",
+            "wit-value",
+        )?;
+        let value: crate::bindings::golem::rpc::types::WitValue = value;
+        let typ: crate::bindings::golem::rpc::types::WitType = crate::wrappers::get_field(
+            &obj,
+            "typ",
+            "wit-type",
+        )?;
+        let typ: crate::bindings::golem::rpc::types::WitType = typ;
+        Ok(Self { value, typ })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rpc::types::RpcError {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::rpc::types::RpcError::ProtocolError(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "protocol-error", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::rpc::types::RpcError::Denied(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "denied", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::rpc::types::RpcError::NotFound(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "not-found", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::rpc::types::RpcError::RemoteInternalError(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "remote-internal-error", crate::wrappers::JsString(inner))
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rpc::types::RpcError {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "protocol-error" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(crate::bindings::golem::rpc::types::RpcError::ProtocolError(inner.0))
+            }
+            "denied" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(crate::bindings::golem::rpc::types::RpcError::Denied(inner.0))
+            }
+            "not-found" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(crate::bindings::golem::rpc::types::RpcError::NotFound(inner.0))
+            }
+            "remote-internal-error" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(
+                    crate::bindings::golem::rpc::types::RpcError::RemoteInternalError(
+                        inner.0,
+                    ),
+                )
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS rpc-error",
+                        "WIT rpc-error",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::api::host::PromiseId {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::golem::rpc::types::WorkerId,
+            >("workerId", self.worker_id)?;
+        obj.set::<
+                _,
+                crate::wrappers::BigIntWrapper<u64>,
+            >("oplogIdx", crate::wrappers::BigIntWrapper(self.oplog_idx))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::api::host::PromiseId {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT promise-id",
+            &["workerId", "oplogIdx"],
+            &[("workerId", "worker-id"), ("oplogIdx", "oplog-index")],
+            false,
+        )?;
+        let worker_id: crate::bindings::golem::rpc::types::WorkerId = crate::wrappers::get_field(
+            &obj,
+            "workerId",
+            "worker-id",
+        )?;
+        let worker_id: crate::bindings::golem::api::host::WorkerId = worker_id;
+        let oplog_idx: crate::wrappers::BigIntWrapper<u64> = crate::wrappers::get_field(
+            &obj,
+            "oplogIdx",
+            "oplog-index",
+        )?;
+        let oplog_idx: crate::bindings::golem::api::host::OplogIndex = oplog_idx.0;
+        Ok(Self { worker_id, oplog_idx })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::api::host::RetryPolicy {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<_, u32>("maxAttempts", self.max_attempts)?;
+        obj.set::<
+                _,
+                crate::wrappers::BigIntWrapper<u64>,
+            >("minDelay", crate::wrappers::BigIntWrapper(self.min_delay))?;
+        obj.set::<
+                _,
+                crate::wrappers::BigIntWrapper<u64>,
+            >("maxDelay", crate::wrappers::BigIntWrapper(self.max_delay))?;
+        obj.set::<_, f64>("multiplier", self.multiplier)?;
+        obj.set::<_, Option<f64>>("maxJitterFactor", self.max_jitter_factor.map(|v| v))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::api::host::RetryPolicy {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT retry-policy",
+            &["maxAttempts", "minDelay", "maxDelay", "multiplier", "maxJitterFactor"],
+            &[
+                ("maxAttempts", "u32"),
+                ("minDelay", "duration"),
+                ("maxDelay", "duration"),
+                ("multiplier", "f64"),
+            ],
+            false,
+        )?;
+        let max_attempts: u32 = crate::wrappers::get_field(&obj, "maxAttempts", "u32")?;
+        let max_attempts: u32 = max_attempts;
+        let min_delay: crate::wrappers::BigIntWrapper<u64> = crate::wrappers::get_field(
+            &obj,
+            "minDelay",
+            "duration",
+        )?;
+        let min_delay: crate::bindings::golem::api::host::Duration = min_delay.0;
+        let max_delay: crate::wrappers::BigIntWrapper<u64> = crate::wrappers::get_field(
+            &obj,
+            "maxDelay",
+            "duration",
+        )?;
+        let max_delay: crate::bindings::golem::api::host::Duration = max_delay.0;
+        let multiplier: f64 = crate::wrappers::get_field(&obj, "multiplier", "f64")?;
+        let multiplier: f64 = multiplier;
+        let max_jitter_factor: Option<f64> = crate::wrappers::get_field(
+            &obj,
+            "maxJitterFactor",
+            "option<f64>",
+        )?;
+        let max_jitter_factor: Option<f64> = max_jitter_factor.map(|v| v);
+        Ok(Self {
+            max_attempts,
+            min_delay,
+            max_delay,
+            multiplier,
+            max_jitter_factor,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::api::host::PersistenceLevel {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::api::host::PersistenceLevel::PersistNothing => {
+                crate::wrappers::variant_case(ctx, "persist-nothing")
+            }
+            crate::bindings::golem::api::host::PersistenceLevel::PersistRemoteSideEffects => {
+                crate::wrappers::variant_case(ctx, "persist-remote-side-effects")
+            }
+            crate::bindings::golem::api::host::PersistenceLevel::Smart => {
+                crate::wrappers::variant_case(ctx, "smart")
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::api::host::PersistenceLevel {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (_, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "persist-nothing" => {
+                Ok(crate::bindings::golem::api::host::PersistenceLevel::PersistNothing)
+            }
+            "persist-remote-side-effects" => {
+                Ok(
+                    crate::bindings::golem::api::host::PersistenceLevel::PersistRemoteSideEffects,
+                )
+            }
+            "smart" => Ok(crate::bindings::golem::api::host::PersistenceLevel::Smart),
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS persistence-level",
+                        "WIT persistence-level",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::api::host::UpdateMode {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::api::host::UpdateMode::Automatic => {
+                "automatic".into_js(ctx)
+            }
+            crate::bindings::golem::api::host::UpdateMode::SnapshotBased => {
+                "snapshot-based".into_js(ctx)
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::api::host::UpdateMode {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let value = crate::wrappers::enum_case(
+            value,
+            "JS update-mode",
+            "WIT update-mode",
+        )?;
+        match value.as_str() {
+            "automatic" => Ok(crate::bindings::golem::api::host::UpdateMode::Automatic),
+            "snapshot-based" => {
+                Ok(crate::bindings::golem::api::host::UpdateMode::SnapshotBased)
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_enum_case(
+                        "JS update-mode",
+                        "WIT update-mode",
+                        &value,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::api::host::FilterComparator {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::api::host::FilterComparator::Equal => {
+                "equal".into_js(ctx)
+            }
+            crate::bindings::golem::api::host::FilterComparator::NotEqual => {
+                "not-equal".into_js(ctx)
+            }
+            crate::bindings::golem::api::host::FilterComparator::GreaterEqual => {
+                "greater-equal".into_js(ctx)
+            }
+            crate::bindings::golem::api::host::FilterComparator::Greater => {
+                "greater".into_js(ctx)
+            }
+            crate::bindings::golem::api::host::FilterComparator::LessEqual => {
+                "less-equal".into_js(ctx)
+            }
+            crate::bindings::golem::api::host::FilterComparator::Less => {
+                "less".into_js(ctx)
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::api::host::FilterComparator {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let value = crate::wrappers::enum_case(
+            value,
+            "JS filter-comparator",
+            "WIT filter-comparator",
+        )?;
+        match value.as_str() {
+            "equal" => Ok(crate::bindings::golem::api::host::FilterComparator::Equal),
+            "not-equal" => {
+                Ok(crate::bindings::golem::api::host::FilterComparator::NotEqual)
+            }
+            "greater-equal" => {
+                Ok(crate::bindings::golem::api::host::FilterComparator::GreaterEqual)
+            }
+            "greater" => Ok(crate::bindings::golem::api::host::FilterComparator::Greater),
+            "less-equal" => {
+                Ok(crate::bindings::golem::api::host::FilterComparator::LessEqual)
+            }
+            "less" => Ok(crate::bindings::golem::api::host::FilterComparator::Less),
+            _ => {
+                Err(
+                    crate::wrappers::unknown_enum_case(
+                        "JS filter-comparator",
+                        "WIT filter-comparator",
+                        &value,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::api::host::StringFilterComparator {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::api::host::StringFilterComparator::Equal => {
+                "equal".into_js(ctx)
+            }
+            crate::bindings::golem::api::host::StringFilterComparator::NotEqual => {
+                "not-equal".into_js(ctx)
+            }
+            crate::bindings::golem::api::host::StringFilterComparator::Like => {
+                "like".into_js(ctx)
+            }
+            crate::bindings::golem::api::host::StringFilterComparator::NotLike => {
+                "not-like".into_js(ctx)
+            }
+            crate::bindings::golem::api::host::StringFilterComparator::StartsWith => {
+                "starts-with".into_js(ctx)
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::api::host::StringFilterComparator {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let value = crate::wrappers::enum_case(
+            value,
+            "JS string-filter-comparator",
+            "WIT string-filter-comparator",
+        )?;
+        match value.as_str() {
+            "equal" => {
+                Ok(crate::bindings::golem::api::host::StringFilterComparator::Equal)
+            }
+            "not-equal" => {
+                Ok(crate::bindings::golem::api::host::StringFilterComparator::NotEqual)
+            }
+            "like" => Ok(crate::bindings::golem::api::host::StringFilterComparator::Like),
+            "not-like" => {
+                Ok(crate::bindings::golem::api::host::StringFilterComparator::NotLike)
+            }
+            "starts-with" => {
+                Ok(crate::bindings::golem::api::host::StringFilterComparator::StartsWith)
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_enum_case(
+                        "JS string-filter-comparator",
+                        "WIT string-filter-comparator",
+                        &value,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::api::host::WorkerStatus {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::api::host::WorkerStatus::Running => {
+                "running".into_js(ctx)
+            }
+            crate::bindings::golem::api::host::WorkerStatus::Idle => "idle".into_js(ctx),
+            crate::bindings::golem::api::host::WorkerStatus::Suspended => {
+                "suspended".into_js(ctx)
+            }
+            crate::bindings::golem::api::host::WorkerStatus::Interrupted => {
+                "interrupted".into_js(ctx)
+            }
+            crate::bindings::golem::api::host::WorkerStatus::Retrying => {
+                "retrying".into_js(ctx)
+            }
+            crate::bindings::golem::api::host::WorkerStatus::Failed => {
+                "failed".into_js(ctx)
+            }
+            crate::bindings::golem::api::host::WorkerStatus::Exited => {
+                "exited".into_js(ctx)
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::api::host::WorkerStatus {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let value = crate::wrappers::enum_case(
+            value,
+            "JS worker-status",
+            "WIT worker-status",
+        )?;
+        match value.as_str() {
+            "running" => Ok(crate::bindings::golem::api::host::WorkerStatus::Running),
+            "idle" => Ok(crate::bindings::golem::api::host::WorkerStatus::Idle),
+            "suspended" => Ok(crate::bindings::golem::api::host::WorkerStatus::Suspended),
+            "interrupted" => {
+                Ok(crate::bindings::golem::api::host::WorkerStatus::Interrupted)
+            }
+            "retrying" => Ok(crate::bindings::golem::api::host::WorkerStatus::Retrying),
+            "failed" => Ok(crate::bindings::golem::api::host::WorkerStatus::Failed),
+            "exited" => Ok(crate::bindings::golem::api::host::WorkerStatus::Exited),
+            _ => {
+                Err(
+                    crate::wrappers::unknown_enum_case(
+                        "JS worker-status",
+                        "WIT worker-status",
+                        &value,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::api::host::WorkerNameFilter {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::golem::api::host::StringFilterComparator,
+            >("comparator", self.comparator)?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("value", crate::wrappers::JsString(self.value))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::api::host::WorkerNameFilter {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT worker-name-filter",
+            &["comparator", "value"],
+            &[("comparator", "string-filter-comparator"), ("value", "string")],
+            false,
+        )?;
+        let comparator: crate::bindings::golem::api::host::StringFilterComparator = crate::wrappers::get_field(
+            &obj,
+            "comparator",
+            "string-filter-comparator",
+        )?;
+        let comparator: crate::bindings::golem::api::host::StringFilterComparator = comparator;
+        let value: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "value",
+            "string",
+        )?;
+        let value: String = value.0;
+        Ok(Self { comparator, value })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::api::host::WorkerStatusFilter {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::golem::api::host::FilterComparator,
+            >("comparator", self.comparator)?;
+        obj.set::<
+                _,
+                crate::bindings::golem::api::host::WorkerStatus,
+            >("value", self.value)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::api::host::WorkerStatusFilter {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT worker-status-filter",
+            &["comparator", "value"],
+            &[("comparator", "filter-comparator"), ("value", "worker-status")],
+            false,
+        )?;
+        let comparator: crate::bindings::golem::api::host::FilterComparator = crate::wrappers::get_field(
+            &obj,
+            "comparator",
+            "filter-comparator",
+        )?;
+        let comparator: crate::bindings::golem::api::host::FilterComparator = comparator;
+        let value: crate::bindings::golem::api::host::WorkerStatus = crate::wrappers::get_field(
+            &obj,
+            "value",
+            "worker-status",
+        )?;
+        let value: crate::bindings::golem::api::host::WorkerStatus = value;
+        Ok(Self { comparator, value })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::api::host::WorkerVersionFilter {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::golem::api::host::FilterComparator,
+            >("comparator", self.comparator)?;
+        obj.set::<
+                _,
+                crate::wrappers::BigIntWrapper<u64>,
+            >("value", crate::wrappers::BigIntWrapper(self.value))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::api::host::WorkerVersionFilter {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT worker-version-filter",
+            &["comparator", "value"],
+            &[("comparator", "filter-comparator"), ("value", "u64")],
+            false,
+        )?;
+        let comparator: crate::bindings::golem::api::host::FilterComparator = crate::wrappers::get_field(
+            &obj,
+            "comparator",
+            "filter-comparator",
+        )?;
+        let comparator: crate::bindings::golem::api::host::FilterComparator = comparator;
+        let value: crate::wrappers::BigIntWrapper<u64> = crate::wrappers::get_field(
+            &obj,
+            "value",
+            "u64",
+        )?;
+        let value: u64 = value.0;
+        Ok(Self { comparator, value })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::api::host::WorkerCreatedAtFilter {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::golem::api::host::FilterComparator,
+            >("comparator", self.comparator)?;
+        obj.set::<
+                _,
+                crate::wrappers::BigIntWrapper<u64>,
+            >("value", crate::wrappers::BigIntWrapper(self.value))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::api::host::WorkerCreatedAtFilter {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT worker-created-at-filter",
+            &["comparator", "value"],
+            &[("comparator", "filter-comparator"), ("value", "u64")],
+            false,
+        )?;
+        let comparator: crate::bindings::golem::api::host::FilterComparator = crate::wrappers::get_field(
+            &obj,
+            "comparator",
+            "filter-comparator",
+        )?;
+        let comparator: crate::bindings::golem::api::host::FilterComparator = comparator;
+        let value: crate::wrappers::BigIntWrapper<u64> = crate::wrappers::get_field(
+            &obj,
+            "value",
+            "u64",
+        )?;
+        let value: u64 = value.0;
+        Ok(Self { comparator, value })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::api::host::WorkerEnvFilter {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("name", crate::wrappers::JsString(self.name))?;
+        obj.set::<
+                _,
+                crate::bindings::golem::api::host::StringFilterComparator,
+            >("comparator", self.comparator)?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("value", crate::wrappers::JsString(self.value))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::api::host::WorkerEnvFilter {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT worker-env-filter",
+            &["name", "comparator", "value"],
+            &[
+                ("name", "string"),
+                ("comparator", "string-filter-comparator"),
+                ("value", "string"),
+            ],
+            false,
+        )?;
+        let name: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "name",
+            "string",
+        )?;
+        let name: String = name.0;
+        let comparator: crate::bindings::golem::api::host::StringFilterComparator = crate::wrappers::get_field(
+            &obj,
+            "comparator",
+            "string-filter-comparator",
+        )?;
+        let comparator: crate::bindings::golem::api::host::StringFilterComparator = comparator;
+        let value: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "value",
+            "string",
+        )?;
+        let value: String = value.0;
+        Ok(Self { name, comparator, value })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::api::host::WorkerWasiConfigVarsFilter {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("name", crate::wrappers::JsString(self.name))?;
+        obj.set::<
+                _,
+                crate::bindings::golem::api::host::StringFilterComparator,
+            >("comparator", self.comparator)?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("value", crate::wrappers::JsString(self.value))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::api::host::WorkerWasiConfigVarsFilter {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT worker-wasi-config-vars-filter",
+            &["name", "comparator", "value"],
+            &[
+                ("name", "string"),
+                ("comparator", "string-filter-comparator"),
+                ("value", "string"),
+            ],
+            false,
+        )?;
+        let name: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "name",
+            "string",
+        )?;
+        let name: String = name.0;
+        let comparator: crate::bindings::golem::api::host::StringFilterComparator = crate::wrappers::get_field(
+            &obj,
+            "comparator",
+            "string-filter-comparator",
+        )?;
+        let comparator: crate::bindings::golem::api::host::StringFilterComparator = comparator;
+        let value: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "value",
+            "string",
+        )?;
+        let value: String = value.0;
+        Ok(Self { name, comparator, value })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::api::host::WorkerPropertyFilter {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::api::host::WorkerPropertyFilter::Name(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::api::host::WorkerNameFilter,
+                >(ctx, "name", inner)
+            }
+            crate::bindings::golem::api::host::WorkerPropertyFilter::Status(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::api::host::WorkerStatusFilter,
+                >(ctx, "status", inner)
+            }
+            crate::bindings::golem::api::host::WorkerPropertyFilter::Version(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::api::host::WorkerVersionFilter,
+                >(ctx, "version", inner)
+            }
+            crate::bindings::golem::api::host::WorkerPropertyFilter::CreatedAt(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::api::host::WorkerCreatedAtFilter,
+                >(ctx, "created-at", inner)
+            }
+            crate::bindings::golem::api::host::WorkerPropertyFilter::Env(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::api::host::WorkerEnvFilter,
+                >(ctx, "env", inner)
+            }
+            crate::bindings::golem::api::host::WorkerPropertyFilter::WasiConfigVars(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::api::host::WorkerWasiConfigVarsFilter,
+                >(ctx, "wasi-config-vars", inner)
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::api::host::WorkerPropertyFilter {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "name" => {
+                let inner: crate::bindings::golem::api::host::WorkerNameFilter = crate::wrappers::variant_value(
+                    &obj,
+                    "worker-name-filter",
+                )?;
+                Ok(crate::bindings::golem::api::host::WorkerPropertyFilter::Name(inner))
+            }
+            "status" => {
+                let inner: crate::bindings::golem::api::host::WorkerStatusFilter = crate::wrappers::variant_value(
+                    &obj,
+                    "worker-status-filter",
+                )?;
+                Ok(
+                    crate::bindings::golem::api::host::WorkerPropertyFilter::Status(
+                        inner,
+                    ),
+                )
+            }
+            "version" => {
+                let inner: crate::bindings::golem::api::host::WorkerVersionFilter = crate::wrappers::variant_value(
+                    &obj,
+                    "worker-version-filter",
+                )?;
+                Ok(
+                    crate::bindings::golem::api::host::WorkerPropertyFilter::Version(
+                        inner,
+                    ),
+                )
+            }
+            "created-at" => {
+                let inner: crate::bindings::golem::api::host::WorkerCreatedAtFilter = crate::wrappers::variant_value(
+                    &obj,
+                    "worker-created-at-filter",
+                )?;
+                Ok(
+                    crate::bindings::golem::api::host::WorkerPropertyFilter::CreatedAt(
+                        inner,
+                    ),
+                )
+            }
+            "env" => {
+                let inner: crate::bindings::golem::api::host::WorkerEnvFilter = crate::wrappers::variant_value(
+                    &obj,
+                    "worker-env-filter",
+                )?;
+                Ok(crate::bindings::golem::api::host::WorkerPropertyFilter::Env(inner))
+            }
+            "wasi-config-vars" => {
+                let inner: crate::bindings::golem::api::host::WorkerWasiConfigVarsFilter = crate::wrappers::variant_value(
+                    &obj,
+                    "worker-wasi-config-vars-filter",
+                )?;
+                Ok(
+                    crate::bindings::golem::api::host::WorkerPropertyFilter::WasiConfigVars(
+                        inner,
+                    ),
+                )
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS worker-property-filter",
+                        "WIT worker-property-filter",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::api::host::WorkerAllFilter {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<
+                    crate::bindings::golem::api::host::WorkerPropertyFilter,
+                >,
+            >(
+            "filters",
+            crate::wrappers::JsList(
+                self.filters.into_iter().map(|v| v).collect::<Vec<_>>(),
+            ),
+        )?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::api::host::WorkerAllFilter {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT worker-all-filter",
+            &["filters"],
+            &[("filters", "list<worker-property-filter>")],
+            false,
+        )?;
+        let filters: crate::wrappers::JsList<
+            crate::bindings::golem::api::host::WorkerPropertyFilter,
+        > = crate::wrappers::get_field(&obj, "filters", "list<worker-property-filter>")?;
+        let filters: Vec<crate::bindings::golem::api::host::WorkerPropertyFilter> = filters
+            .0
+            .into_iter()
+            .map(|v| v)
+            .collect::<Vec<_>>();
+        Ok(Self { filters })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::api::host::WorkerAnyFilter {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<
+                    crate::bindings::golem::api::host::WorkerAllFilter,
+                >,
+            >(
+            "filters",
+            crate::wrappers::JsList(
+                self.filters.into_iter().map(|v| v).collect::<Vec<_>>(),
+            ),
+        )?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::api::host::WorkerAnyFilter {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT worker-any-filter",
+            &["filters"],
+            &[("filters", "list<worker-all-filter>")],
+            false,
+        )?;
+        let filters: crate::wrappers::JsList<
+            crate::bindings::golem::api::host::WorkerAllFilter,
+        > = crate::wrappers::get_field(&obj, "filters", "list<worker-all-filter>")?;
+        let filters: Vec<crate::bindings::golem::api::host::WorkerAllFilter> = filters
+            .0
+            .into_iter()
+            .map(|v| v)
+            .collect::<Vec<_>>();
+        Ok(Self { filters })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::api::host::WorkerMetadata {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::golem::rpc::types::WorkerId,
+            >("workerId", self.worker_id)?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<crate::wrappers::JsString>,
+            >(
+            "args",
+            crate::wrappers::JsList(
+                self
+                    .args
+                    .into_iter()
+                    .map(|v| crate::wrappers::JsString(v))
+                    .collect::<Vec<_>>(),
+            ),
+        )?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<
+                    rquickjs::convert::List<
+                        (crate::wrappers::JsString, crate::wrappers::JsString),
+                    >,
+                >,
+            >(
+            "env",
+            crate::wrappers::JsList(
+                self
+                    .env
+                    .into_iter()
+                    .map(|v| rquickjs::convert::List((
+                        crate::wrappers::JsString(v.0),
+                        crate::wrappers::JsString(v.1),
+                    )))
+                    .collect::<Vec<_>>(),
+            ),
+        )?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<
+                    rquickjs::convert::List<
+                        (crate::wrappers::JsString, crate::wrappers::JsString),
+                    >,
+                >,
+            >(
+            "wasiConfigVars",
+            crate::wrappers::JsList(
+                self
+                    .wasi_config_vars
+                    .into_iter()
+                    .map(|v| rquickjs::convert::List((
+                        crate::wrappers::JsString(v.0),
+                        crate::wrappers::JsString(v.1),
+                    )))
+                    .collect::<Vec<_>>(),
+            ),
+        )?;
+        obj.set::<
+                _,
+                crate::bindings::golem::api::host::WorkerStatus,
+            >("status", self.status)?;
+        obj.set::<
+                _,
+                crate::wrappers::BigIntWrapper<u64>,
+            >(
+            "componentVersion",
+            crate::wrappers::BigIntWrapper(self.component_version),
+        )?;
+        obj.set::<
+                _,
+                crate::wrappers::BigIntWrapper<u64>,
+            >("retryCount", crate::wrappers::BigIntWrapper(self.retry_count))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::api::host::WorkerMetadata {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT worker-metadata",
+            &[
+                "workerId",
+                "args",
+                "env",
+                "wasiConfigVars",
+                "status",
+                "componentVersion",
+                "retryCount",
+            ],
+            &[
+                ("workerId", "worker-id"),
+                ("args", "list<string>"),
+                ("env", "list<tuple<string, string>>"),
+                ("wasiConfigVars", "list<tuple<string, string>>"),
+                ("status", "worker-status"),
+                ("componentVersion", "u64"),
+                ("retryCount", "u64"),
+            ],
+            false,
+        )?;
+        let worker_id: crate::bindings::golem::rpc::types::WorkerId = crate::wrappers::get_field(
+            &obj,
+            "workerId",
+            "worker-id",
+        )?;
+        let worker_id: crate::bindings::golem::api::host::WorkerId = worker_id;
+        let args: crate::wrappers::JsList<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "args",
+            "list<string>",
+        )?;
+        let args: Vec<String> = args.0.into_iter().map(|v| v.0).collect::<Vec<_>>();
+        let env: crate::wrappers::JsList<
+            rquickjs::convert::List<
+                (crate::wrappers::JsString, crate::wrappers::JsString),
+            >,
+        > = crate::wrappers::get_field(&obj, "env", "list<tuple<string, string>>")?;
+        let env: Vec<(String, String)> = env
+            .0
+            .into_iter()
+            .map(|v| (v.0.0.0.clone(), v.0.1.0.clone()))
+            .collect::<Vec<_>>();
+        let wasi_config_vars: crate::wrappers::JsList<
+            rquickjs::convert::List<
+                (crate::wrappers::JsString, crate::wrappers::JsString),
+            >,
+        > = crate::wrappers::get_field(
+            &obj,
+            "wasiConfigVars",
+            "list<tuple<string, string>>",
+        )?;
+        let wasi_config_vars: Vec<(String, String)> = wasi_config_vars
+            .0
+            .into_iter()
+            .map(|v| (v.0.0.0.clone(), v.0.1.0.clone()))
+            .collect::<Vec<_>>();
+        let status: crate::bindings::golem::api::host::WorkerStatus = crate::wrappers::get_field(
+            &obj,
+            "status",
+            "worker-status",
+        )?;
+        let status: crate::bindings::golem::api::host::WorkerStatus = status;
+        let component_version: crate::wrappers::BigIntWrapper<u64> = crate::wrappers::get_field(
+            &obj,
+            "componentVersion",
+            "u64",
+        )?;
+        let component_version: u64 = component_version.0;
+        let retry_count: crate::wrappers::BigIntWrapper<u64> = crate::wrappers::get_field(
+            &obj,
+            "retryCount",
+            "u64",
+        )?;
+        let retry_count: u64 = retry_count.0;
+        Ok(Self {
+            worker_id,
+            args,
+            env,
+            wasi_config_vars,
+            status,
+            component_version,
+            retry_count,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::api::host::RevertWorkerTarget {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::api::host::RevertWorkerTarget::RevertToOplogIndex(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::BigIntWrapper<u64>,
+                >(ctx, "revert-to-oplog-index", crate::wrappers::BigIntWrapper(inner))
+            }
+            crate::bindings::golem::api::host::RevertWorkerTarget::RevertLastInvocations(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::BigIntWrapper<u64>,
+                >(ctx, "revert-last-invocations", crate::wrappers::BigIntWrapper(inner))
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::api::host::RevertWorkerTarget {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "revert-to-oplog-index" => {
+                let inner: crate::wrappers::BigIntWrapper<u64> = crate::wrappers::variant_value(
+                    &obj,
+                    "oplog-index",
+                )?;
+                Ok(
+                    crate::bindings::golem::api::host::RevertWorkerTarget::RevertToOplogIndex(
+                        inner.0,
+                    ),
+                )
+            }
+            "revert-last-invocations" => {
+                let inner: crate::wrappers::BigIntWrapper<u64> = crate::wrappers::variant_value(
+                    &obj,
+                    "u64",
+                )?;
+                Ok(
+                    crate::bindings::golem::api::host::RevertWorkerTarget::RevertLastInvocations(
+                        inner.0,
+                    ),
+                )
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS revert-worker-target",
+                        "WIT revert-worker-target",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::api::host::ForkResult {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::api::host::ForkResult::Original => {
+                "original".into_js(ctx)
+            }
+            crate::bindings::golem::api::host::ForkResult::Forked => {
+                "forked".into_js(ctx)
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::api::host::ForkResult {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let value = crate::wrappers::enum_case(
+            value,
+            "JS fork-result",
+            "WIT fork-result",
+        )?;
+        match value.as_str() {
+            "original" => Ok(crate::bindings::golem::api::host::ForkResult::Original),
+            "forked" => Ok(crate::bindings::golem::api::host::ForkResult::Forked),
+            _ => {
+                Err(
+                    crate::wrappers::unknown_enum_case(
+                        "JS fork-result",
+                        "WIT fork-result",
+                        &value,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::llm::llm::Role {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::llm::llm::Role::User => "user".into_js(ctx),
+            crate::bindings::golem::llm::llm::Role::Assistant => "assistant".into_js(ctx),
+            crate::bindings::golem::llm::llm::Role::System => "system".into_js(ctx),
+            crate::bindings::golem::llm::llm::Role::Tool => "tool".into_js(ctx),
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::llm::llm::Role {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let value = crate::wrappers::enum_case(value, "JS role", "WIT role")?;
+        match value.as_str() {
+            "user" => Ok(crate::bindings::golem::llm::llm::Role::User),
+            "assistant" => Ok(crate::bindings::golem::llm::llm::Role::Assistant),
+            "system" => Ok(crate::bindings::golem::llm::llm::Role::System),
+            "tool" => Ok(crate::bindings::golem::llm::llm::Role::Tool),
+            _ => Err(crate::wrappers::unknown_enum_case("JS role", "WIT role", &value)),
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::llm::llm::ErrorCode {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::llm::llm::ErrorCode::InvalidRequest => {
+                "invalid-request".into_js(ctx)
+            }
+            crate::bindings::golem::llm::llm::ErrorCode::AuthenticationFailed => {
+                "authentication-failed".into_js(ctx)
+            }
+            crate::bindings::golem::llm::llm::ErrorCode::RateLimitExceeded => {
+                "rate-limit-exceeded".into_js(ctx)
+            }
+            crate::bindings::golem::llm::llm::ErrorCode::InternalError => {
+                "internal-error".into_js(ctx)
+            }
+            crate::bindings::golem::llm::llm::ErrorCode::Unsupported => {
+                "unsupported".into_js(ctx)
+            }
+            crate::bindings::golem::llm::llm::ErrorCode::Unknown => {
+                "unknown".into_js(ctx)
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::llm::llm::ErrorCode {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let value = crate::wrappers::enum_case(
+            value,
+            "JS error-code",
+            "WIT error-code",
+        )?;
+        match value.as_str() {
+            "invalid-request" => {
+                Ok(crate::bindings::golem::llm::llm::ErrorCode::InvalidRequest)
+            }
+            "authentication-failed" => {
+                Ok(crate::bindings::golem::llm::llm::ErrorCode::AuthenticationFailed)
+            }
+            "rate-limit-exceeded" => {
+                Ok(crate::bindings::golem::llm::llm::ErrorCode::RateLimitExceeded)
+            }
+            "internal-error" => {
+                Ok(crate::bindings::golem::llm::llm::ErrorCode::InternalError)
+            }
+            "unsupported" => Ok(crate::bindings::golem::llm::llm::ErrorCode::Unsupported),
+            "unknown" => Ok(crate::bindings::golem::llm::llm::ErrorCode::Unknown),
+            _ => {
+                Err(
+                    crate::wrappers::unknown_enum_case(
+                        "JS error-code",
+                        "WIT error-code",
+                        &value,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::llm::llm::FinishReason {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::llm::llm::FinishReason::Stop => "stop".into_js(ctx),
+            crate::bindings::golem::llm::llm::FinishReason::Length => {
+                "length".into_js(ctx)
+            }
+            crate::bindings::golem::llm::llm::FinishReason::ToolCalls => {
+                "tool-calls".into_js(ctx)
+            }
+            crate::bindings::golem::llm::llm::FinishReason::ContentFilter => {
+                "content-filter".into_js(ctx)
+            }
+            crate::bindings::golem::llm::llm::FinishReason::Error => "error".into_js(ctx),
+            crate::bindings::golem::llm::llm::FinishReason::Other => "other".into_js(ctx),
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::llm::llm::FinishReason {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let value = crate::wrappers::enum_case(
+            value,
+            "JS finish-reason",
+            "WIT finish-reason",
+        )?;
+        match value.as_str() {
+            "stop" => Ok(crate::bindings::golem::llm::llm::FinishReason::Stop),
+            "length" => Ok(crate::bindings::golem::llm::llm::FinishReason::Length),
+            "tool-calls" => Ok(crate::bindings::golem::llm::llm::FinishReason::ToolCalls),
+            "content-filter" => {
+                Ok(crate::bindings::golem::llm::llm::FinishReason::ContentFilter)
+            }
+            "error" => Ok(crate::bindings::golem::llm::llm::FinishReason::Error),
+            "other" => Ok(crate::bindings::golem::llm::llm::FinishReason::Other),
+            _ => {
+                Err(
+                    crate::wrappers::unknown_enum_case(
+                        "JS finish-reason",
+                        "WIT finish-reason",
+                        &value,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::llm::llm::ImageDetail {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::llm::llm::ImageDetail::Low => "low".into_js(ctx),
+            crate::bindings::golem::llm::llm::ImageDetail::High => "high".into_js(ctx),
+            crate::bindings::golem::llm::llm::ImageDetail::Auto => "auto".into_js(ctx),
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::llm::llm::ImageDetail {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let value = crate::wrappers::enum_case(
+            value,
+            "JS image-detail",
+            "WIT image-detail",
+        )?;
+        match value.as_str() {
+            "low" => Ok(crate::bindings::golem::llm::llm::ImageDetail::Low),
+            "high" => Ok(crate::bindings::golem::llm::llm::ImageDetail::High),
+            "auto" => Ok(crate::bindings::golem::llm::llm::ImageDetail::Auto),
+            _ => {
+                Err(
+                    crate::wrappers::unknown_enum_case(
+                        "JS image-detail",
+                        "WIT image-detail",
+                        &value,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::llm::llm::ImageUrl {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("url", crate::wrappers::JsString(self.url))?;
+        obj.set::<
+                _,
+                Option<crate::bindings::golem::llm::llm::ImageDetail>,
+            >("detail", self.detail.map(|v| v))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::llm::llm::ImageUrl {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT image-url",
+            &["url", "detail"],
+            &[("url", "string")],
+            false,
+        )?;
+        let url: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "url",
+            "string",
+        )?;
+        let url: String = url.0;
+        let detail: Option<crate::bindings::golem::llm::llm::ImageDetail> = crate::wrappers::get_field(
+            &obj,
+            "detail",
+            "option<image-detail>",
+        )?;
+        let detail: Option<crate::bindings::golem::llm::llm::ImageDetail> = detail
+            .map(|v| v);
+        Ok(Self { url, detail })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::llm::llm::ImageSource {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::UInt8Array,
+            >("data", crate::wrappers::UInt8Array(self.data))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("mimeType", crate::wrappers::JsString(self.mime_type))?;
+        obj.set::<
+                _,
+                Option<crate::bindings::golem::llm::llm::ImageDetail>,
+            >("detail", self.detail.map(|v| v))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::llm::llm::ImageSource {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT image-source",
+            &["data", "mimeType", "detail"],
+            &[("data", "list<u8>"), ("mimeType", "string")],
+            false,
+        )?;
+        let data: crate::wrappers::UInt8Array = crate::wrappers::get_field(
+            &obj,
+            "data",
+            "list<u8>",
+        )?;
+        let data: Vec<u8> = data.0;
+        let mime_type: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "mimeType",
+            "string",
+        )?;
+        let mime_type: String = mime_type.0;
+        let detail: Option<crate::bindings::golem::llm::llm::ImageDetail> = crate::wrappers::get_field(
+            &obj,
+            "detail",
+            "option<image-detail>",
+        )?;
+        let detail: Option<crate::bindings::golem::llm::llm::ImageDetail> = detail
+            .map(|v| v);
+        Ok(Self { data, mime_type, detail })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::llm::llm::ImageReference {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::llm::llm::ImageReference::Url(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::llm::llm::ImageUrl,
+                >(ctx, "url", inner)
+            }
+            crate::bindings::golem::llm::llm::ImageReference::Inline(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::llm::llm::ImageSource,
+                >(ctx, "inline", inner)
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::llm::llm::ImageReference {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "url" => {
+                let inner: crate::bindings::golem::llm::llm::ImageUrl = crate::wrappers::variant_value(
+                    &obj,
+                    "image-url",
+                )?;
+                Ok(crate::bindings::golem::llm::llm::ImageReference::Url(inner))
+            }
+            "inline" => {
+                let inner: crate::bindings::golem::llm::llm::ImageSource = crate::wrappers::variant_value(
+                    &obj,
+                    "image-source",
+                )?;
+                Ok(crate::bindings::golem::llm::llm::ImageReference::Inline(inner))
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS image-reference",
+                        "WIT image-reference",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::llm::llm::ContentPart {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::llm::llm::ContentPart::Text(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "text", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::llm::llm::ContentPart::Image(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::llm::llm::ImageReference,
+                >(ctx, "image", inner)
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::llm::llm::ContentPart {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "text" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(crate::bindings::golem::llm::llm::ContentPart::Text(inner.0))
+            }
+            "image" => {
+                let inner: crate::bindings::golem::llm::llm::ImageReference = crate::wrappers::variant_value(
+                    &obj,
+                    "image-reference",
+                )?;
+                Ok(crate::bindings::golem::llm::llm::ContentPart::Image(inner))
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS content-part",
+                        "WIT content-part",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::llm::llm::Message {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<_, crate::bindings::golem::llm::llm::Role>("role", self.role)?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >("name", self.name.map(|v| crate::wrappers::JsString(v)))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<crate::bindings::golem::llm::llm::ContentPart>,
+            >(
+            "content",
+            crate::wrappers::JsList(
+                self.content.into_iter().map(|v| v).collect::<Vec<_>>(),
+            ),
+        )?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::llm::llm::Message {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT message",
+            &["role", "name", "content"],
+            &[("role", "role"), ("content", "list<content-part>")],
+            false,
+        )?;
+        let role: crate::bindings::golem::llm::llm::Role = crate::wrappers::get_field(
+            &obj,
+            "role",
+            "role",
+        )?;
+        let role: crate::bindings::golem::llm::llm::Role = role;
+        let name: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "name",
+            "option<string>",
+        )?;
+        let name: Option<String> = name.map(|v| v.0);
+        let content: crate::wrappers::JsList<
+            crate::bindings::golem::llm::llm::ContentPart,
+        > = crate::wrappers::get_field(&obj, "content", "list<content-part>")?;
+        let content: Vec<crate::bindings::golem::llm::llm::ContentPart> = content
+            .0
+            .into_iter()
+            .map(|v| v)
+            .collect::<Vec<_>>();
+        Ok(Self { role, name, content })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::llm::llm::ToolDefinition {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("name", crate::wrappers::JsString(self.name))?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >("description", self.description.map(|v| crate::wrappers::JsString(v)))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("parametersSchema", crate::wrappers::JsString(self.parameters_schema))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::llm::llm::ToolDefinition {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT tool-definition",
+            &["name", "description", "parametersSchema"],
+            &[("name", "string"), ("parametersSchema", "string")],
+            false,
+        )?;
+        let name: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "name",
+            "string",
+        )?;
+        let name: String = name.0;
+        let description: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "description",
+            "option<string>",
+        )?;
+        let description: Option<String> = description.map(|v| v.0);
+        let parameters_schema: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "parametersSchema",
+            "string",
+        )?;
+        let parameters_schema: String = parameters_schema.0;
+        Ok(Self {
+            name,
+            description,
+            parameters_schema,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::llm::llm::ToolCall {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("id", crate::wrappers::JsString(self.id))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("name", crate::wrappers::JsString(self.name))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("argumentsJson", crate::wrappers::JsString(self.arguments_json))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::llm::llm::ToolCall {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT tool-call",
+            &["id", "name", "argumentsJson"],
+            &[("id", "string"), ("name", "string"), ("argumentsJson", "string")],
+            false,
+        )?;
+        let id: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "id",
+            "string",
+        )?;
+        let id: String = id.0;
+        let name: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "name",
+            "string",
+        )?;
+        let name: String = name.0;
+        let arguments_json: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "argumentsJson",
+            "string",
+        )?;
+        let arguments_json: String = arguments_json.0;
+        Ok(Self { id, name, arguments_json })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::llm::llm::ToolSuccess {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("id", crate::wrappers::JsString(self.id))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("name", crate::wrappers::JsString(self.name))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("resultJson", crate::wrappers::JsString(self.result_json))?;
+        obj.set::<_, Option<u32>>("executionTimeMs", self.execution_time_ms.map(|v| v))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::llm::llm::ToolSuccess {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT tool-success",
+            &["id", "name", "resultJson", "executionTimeMs"],
+            &[("id", "string"), ("name", "string"), ("resultJson", "string")],
+            false,
+        )?;
+        let id: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "id",
+            "string",
+        )?;
+        let id: String = id.0;
+        let name: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "name",
+            "string",
+        )?;
+        let name: String = name.0;
+        let result_json: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "resultJson",
+            "string",
+        )?;
+        let result_json: String = result_json.0;
+        let execution_time_ms: Option<u32> = crate::wrappers::get_field(
+            &obj,
+            "executionTimeMs",
+            "option<u32>",
+        )?;
+        let execution_time_ms: Option<u32> = execution_time_ms.map(|v| v);
+        Ok(Self {
+            id,
+            name,
+            result_json,
+            execution_time_ms,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::llm::llm::ToolFailure {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("id", crate::wrappers::JsString(self.id))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("name", crate::wrappers::JsString(self.name))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("errorMessage", crate::wrappers::JsString(self.error_message))?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >("errorCode", self.error_code.map(|v| crate::wrappers::JsString(v)))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::llm::llm::ToolFailure {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT tool-failure",
+            &["id", "name", "errorMessage", "errorCode"],
+            &[("id", "string"), ("name", "string"), ("errorMessage", "string")],
+            false,
+        )?;
+        let id: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "id",
+            "string",
+        )?;
+        let id: String = id.0;
+        let name: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "name",
+            "string",
+        )?;
+        let name: String = name.0;
+        let error_message: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "errorMessage",
+            "string",
+        )?;
+        let error_message: String = error_message.0;
+        let error_code: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "errorCode",
+            "option<string>",
+        )?;
+        let error_code: Option<String> = error_code.map(|v| v.0);
+        Ok(Self {
+            id,
+            name,
+            error_message,
+            error_code,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::llm::llm::ToolResult {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::llm::llm::ToolResult::Success(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::llm::llm::ToolSuccess,
+                >(ctx, "success", inner)
+            }
+            crate::bindings::golem::llm::llm::ToolResult::Error(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::llm::llm::ToolFailure,
+                >(ctx, "error", inner)
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::llm::llm::ToolResult {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "success" => {
+                let inner: crate::bindings::golem::llm::llm::ToolSuccess = crate::wrappers::variant_value(
+                    &obj,
+                    "tool-success",
+                )?;
+                Ok(crate::bindings::golem::llm::llm::ToolResult::Success(inner))
+            }
+            "error" => {
+                let inner: crate::bindings::golem::llm::llm::ToolFailure = crate::wrappers::variant_value(
+                    &obj,
+                    "tool-failure",
+                )?;
+                Ok(crate::bindings::golem::llm::llm::ToolResult::Error(inner))
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS tool-result",
+                        "WIT tool-result",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::llm::llm::Kv {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("key", crate::wrappers::JsString(self.key))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("value", crate::wrappers::JsString(self.value))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::llm::llm::Kv {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT kv",
+            &["key", "value"],
+            &[("key", "string"), ("value", "string")],
+            false,
+        )?;
+        let key: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "key",
+            "string",
+        )?;
+        let key: String = key.0;
+        let value: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "value",
+            "string",
+        )?;
+        let value: String = value.0;
+        Ok(Self { key, value })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::llm::llm::Config {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("model", crate::wrappers::JsString(self.model))?;
+        obj.set::<_, Option<f32>>("temperature", self.temperature.map(|v| v))?;
+        obj.set::<_, Option<u32>>("maxTokens", self.max_tokens.map(|v| v))?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsList<crate::wrappers::JsString>>,
+            >(
+            "stopSequences",
+            self
+                .stop_sequences
+                .map(|v| crate::wrappers::JsList(
+                    v
+                        .into_iter()
+                        .map(|v| crate::wrappers::JsString(v))
+                        .collect::<Vec<_>>(),
+                )),
+        )?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<crate::bindings::golem::llm::llm::ToolDefinition>,
+            >(
+            "tools",
+            crate::wrappers::JsList(
+                self.tools.into_iter().map(|v| v).collect::<Vec<_>>(),
+            ),
+        )?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >("toolChoice", self.tool_choice.map(|v| crate::wrappers::JsString(v)))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<crate::bindings::golem::llm::llm::Kv>,
+            >(
+            "providerOptions",
+            crate::wrappers::JsList(
+                self.provider_options.into_iter().map(|v| v).collect::<Vec<_>>(),
+            ),
+        )?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::llm::llm::Config {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT config",
+            &[
+                "model",
+                "temperature",
+                "maxTokens",
+                "stopSequences",
+                "tools",
+                "toolChoice",
+                "providerOptions",
+            ],
+            &[
+                ("model", "string"),
+                ("tools", "list<tool-definition>"),
+                ("providerOptions", "list<kv>"),
+            ],
+            false,
+        )?;
+        let model: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "model",
+            "string",
+        )?;
+        let model: String = model.0;
+        let temperature: Option<f32> = crate::wrappers::get_field(
+            &obj,
+            "temperature",
+            "option<f32>",
+        )?;
+        let temperature: Option<f32> = temperature.map(|v| v);
+        let max_tokens: Option<u32> = crate::wrappers::get_field(
+            &obj,
+            "maxTokens",
+            "option<u32>",
+        )?;
+        let max_tokens: Option<u32> = max_tokens.map(|v| v);
+        let stop_sequences: Option<crate::wrappers::JsList<crate::wrappers::JsString>> = crate::wrappers::get_field(
+            &obj,
+            "stopSequences",
+            "option<list<string>>",
+        )?;
+        let stop_sequences: Option<Vec<String>> = stop_sequences
+            .map(|v| v.0.into_iter().map(|v| v.0).collect::<Vec<_>>());
+        let tools: crate::wrappers::JsList<
+            crate::bindings::golem::llm::llm::ToolDefinition,
+        > = crate::wrappers::get_field(&obj, "tools", "list<tool-definition>")?;
+        let tools: Vec<crate::bindings::golem::llm::llm::ToolDefinition> = tools
+            .0
+            .into_iter()
+            .map(|v| v)
+            .collect::<Vec<_>>();
+        let tool_choice: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "toolChoice",
+            "option<string>",
+        )?;
+        let tool_choice: Option<String> = tool_choice.map(|v| v.0);
+        let provider_options: crate::wrappers::JsList<
+            crate::bindings::golem::llm::llm::Kv,
+        > = crate::wrappers::get_field(&obj, "providerOptions", "list<kv>")?;
+        let provider_options: Vec<crate::bindings::golem::llm::llm::Kv> = provider_options
+            .0
+            .into_iter()
+            .map(|v| v)
+            .collect::<Vec<_>>();
+        Ok(Self {
+            model,
+            temperature,
+            max_tokens,
+            stop_sequences,
+            tools,
+            tool_choice,
+            provider_options,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::llm::llm::Usage {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<_, Option<u32>>("inputTokens", self.input_tokens.map(|v| v))?;
+        obj.set::<_, Option<u32>>("outputTokens", self.output_tokens.map(|v| v))?;
+        obj.set::<_, Option<u32>>("totalTokens", self.total_tokens.map(|v| v))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::llm::llm::Usage {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT usage",
+            &["inputTokens", "outputTokens", "totalTokens"],
+            &[],
+            false,
+        )?;
+        let input_tokens: Option<u32> = crate::wrappers::get_field(
+            &obj,
+            "inputTokens",
+            "option<u32>",
+        )?;
+        let input_tokens: Option<u32> = input_tokens.map(|v| v);
+        let output_tokens: Option<u32> = crate::wrappers::get_field(
+            &obj,
+            "outputTokens",
+            "option<u32>",
+        )?;
+        let output_tokens: Option<u32> = output_tokens.map(|v| v);
+        let total_tokens: Option<u32> = crate::wrappers::get_field(
+            &obj,
+            "totalTokens",
+            "option<u32>",
+        )?;
+        let total_tokens: Option<u32> = total_tokens.map(|v| v);
+        Ok(Self {
+            input_tokens,
+            output_tokens,
+            total_tokens,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::llm::llm::ResponseMetadata {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                Option<crate::bindings::golem::llm::llm::FinishReason>,
+            >("finishReason", self.finish_reason.map(|v| v))?;
+        obj.set::<
+                _,
+                Option<crate::bindings::golem::llm::llm::Usage>,
+            >("usage", self.usage.map(|v| v))?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >("providerId", self.provider_id.map(|v| crate::wrappers::JsString(v)))?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >("timestamp", self.timestamp.map(|v| crate::wrappers::JsString(v)))?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >(
+            "providerMetadataJson",
+            self.provider_metadata_json.map(|v| crate::wrappers::JsString(v)),
+        )?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::llm::llm::ResponseMetadata {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT response-metadata",
+            &[
+                "finishReason",
+                "usage",
+                "providerId",
+                "timestamp",
+                "providerMetadataJson",
+            ],
+            &[],
+            false,
+        )?;
+        let finish_reason: Option<crate::bindings::golem::llm::llm::FinishReason> = crate::wrappers::get_field(
+            &obj,
+            "finishReason",
+            "option<finish-reason>",
+        )?;
+        let finish_reason: Option<crate::bindings::golem::llm::llm::FinishReason> = finish_reason
+            .map(|v| v);
+        let usage: Option<crate::bindings::golem::llm::llm::Usage> = crate::wrappers::get_field(
+            &obj,
+            "usage",
+            "option<usage>",
+        )?;
+        let usage: Option<crate::bindings::golem::llm::llm::Usage> = usage.map(|v| v);
+        let provider_id: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "providerId",
+            "option<string>",
+        )?;
+        let provider_id: Option<String> = provider_id.map(|v| v.0);
+        let timestamp: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "timestamp",
+            "option<string>",
+        )?;
+        let timestamp: Option<String> = timestamp.map(|v| v.0);
+        let provider_metadata_json: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "providerMetadataJson",
+            "option<string>",
+        )?;
+        let provider_metadata_json: Option<String> = provider_metadata_json.map(|v| v.0);
+        Ok(Self {
+            finish_reason,
+            usage,
+            provider_id,
+            timestamp,
+            provider_metadata_json,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::llm::llm::CompleteResponse {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("id", crate::wrappers::JsString(self.id))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<crate::bindings::golem::llm::llm::ContentPart>,
+            >(
+            "content",
+            crate::wrappers::JsList(
+                self.content.into_iter().map(|v| v).collect::<Vec<_>>(),
+            ),
+        )?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<crate::bindings::golem::llm::llm::ToolCall>,
+            >(
+            "toolCalls",
+            crate::wrappers::JsList(
+                self.tool_calls.into_iter().map(|v| v).collect::<Vec<_>>(),
+            ),
+        )?;
+        obj.set::<
+                _,
+                crate::bindings::golem::llm::llm::ResponseMetadata,
+            >("metadata", self.metadata)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::llm::llm::CompleteResponse {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT complete-response",
+            &["id", "content", "toolCalls", "metadata"],
+            &[
+                ("id", "string"),
+                ("content", "list<content-part>"),
+                ("toolCalls", "list<tool-call>"),
+                ("metadata", "response-metadata"),
+            ],
+            false,
+        )?;
+        let id: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "id",
+            "string",
+        )?;
+        let id: String = id.0;
+        let content: crate::wrappers::JsList<
+            crate::bindings::golem::llm::llm::ContentPart,
+        > = crate::wrappers::get_field(&obj, "content", "list<content-part>")?;
+        let content: Vec<crate::bindings::golem::llm::llm::ContentPart> = content
+            .0
+            .into_iter()
+            .map(|v| v)
+            .collect::<Vec<_>>();
+        let tool_calls: crate::wrappers::JsList<
+            crate::bindings::golem::llm::llm::ToolCall,
+        > = crate::wrappers::get_field(&obj, "toolCalls", "list<tool-call>")?;
+        let tool_calls: Vec<crate::bindings::golem::llm::llm::ToolCall> = tool_calls
+            .0
+            .into_iter()
+            .map(|v| v)
+            .collect::<Vec<_>>();
+        let metadata: crate::bindings::golem::llm::llm::ResponseMetadata = crate::wrappers::get_field(
+            &obj,
+            "metadata",
+            "response-metadata",
+        )?;
+        let metadata: crate::bindings::golem::llm::llm::ResponseMetadata = metadata;
+        Ok(Self {
+            id,
+            content,
+            tool_calls,
+            metadata,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::llm::llm::Error {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<_, crate::bindings::golem::llm::llm::ErrorCode>("code", self.code)?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("message", crate::wrappers::JsString(self.message))?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >(
+            "providerErrorJson",
+            self.provider_error_json.map(|v| crate::wrappers::JsString(v)),
+        )?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::llm::llm::Error {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT error",
+            &["code", "message", "providerErrorJson"],
+            &[("code", "error-code"), ("message", "string")],
+            false,
+        )?;
+        let code: crate::bindings::golem::llm::llm::ErrorCode = crate::wrappers::get_field(
+            &obj,
+            "code",
+            "error-code",
+        )?;
+        let code: crate::bindings::golem::llm::llm::ErrorCode = code;
+        let message: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "message",
+            "string",
+        )?;
+        let message: String = message.0;
+        let provider_error_json: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "providerErrorJson",
+            "option<string>",
+        )?;
+        let provider_error_json: Option<String> = provider_error_json.map(|v| v.0);
+        Ok(Self {
+            code,
+            message,
+            provider_error_json,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::llm::llm::ChatEvent {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::llm::llm::ChatEvent::Message(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::llm::llm::CompleteResponse,
+                >(ctx, "message", inner)
+            }
+            crate::bindings::golem::llm::llm::ChatEvent::ToolRequest(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsList<crate::bindings::golem::llm::llm::ToolCall>,
+                >(
+                    ctx,
+                    "tool-request",
+                    crate::wrappers::JsList(
+                        inner.into_iter().map(|v| v).collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            crate::bindings::golem::llm::llm::ChatEvent::Error(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::llm::llm::Error,
+                >(ctx, "error", inner)
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::llm::llm::ChatEvent {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "message" => {
+                let inner: crate::bindings::golem::llm::llm::CompleteResponse = crate::wrappers::variant_value(
+                    &obj,
+                    "complete-response",
+                )?;
+                Ok(crate::bindings::golem::llm::llm::ChatEvent::Message(inner))
+            }
+            "tool-request" => {
+                let inner: crate::wrappers::JsList<
+                    crate::bindings::golem::llm::llm::ToolCall,
+                > = crate::wrappers::variant_value(&obj, "list<tool-call>")?;
+                Ok(
+                    crate::bindings::golem::llm::llm::ChatEvent::ToolRequest(
+                        inner.0.into_iter().map(|v| v).collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            "error" => {
+                let inner: crate::bindings::golem::llm::llm::Error = crate::wrappers::variant_value(
+                    &obj,
+                    "error",
+                )?;
+                Ok(crate::bindings::golem::llm::llm::ChatEvent::Error(inner))
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS chat-event",
+                        "WIT chat-event",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::llm::llm::StreamDelta {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                Option<
+                    crate::wrappers::JsList<
+                        crate::bindings::golem::llm::llm::ContentPart,
+                    >,
+                >,
+            >(
+            "content",
+            self
+                .content
+                .map(|v| crate::wrappers::JsList(
+                    v.into_iter().map(|v| v).collect::<Vec<_>>(),
+                )),
+        )?;
+        obj.set::<
+                _,
+                Option<
+                    crate::wrappers::JsList<crate::bindings::golem::llm::llm::ToolCall>,
+                >,
+            >(
+            "toolCalls",
+            self
+                .tool_calls
+                .map(|v| crate::wrappers::JsList(
+                    v.into_iter().map(|v| v).collect::<Vec<_>>(),
+                )),
+        )?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::llm::llm::StreamDelta {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT stream-delta",
+            &["content", "toolCalls"],
+            &[],
+            false,
+        )?;
+        let content: Option<
+            crate::wrappers::JsList<crate::bindings::golem::llm::llm::ContentPart>,
+        > = crate::wrappers::get_field(&obj, "content", "option<list<content-part>>")?;
+        let content: Option<Vec<crate::bindings::golem::llm::llm::ContentPart>> = content
+            .map(|v| v.0.into_iter().map(|v| v).collect::<Vec<_>>());
+        let tool_calls: Option<
+            crate::wrappers::JsList<crate::bindings::golem::llm::llm::ToolCall>,
+        > = crate::wrappers::get_field(&obj, "toolCalls", "option<list<tool-call>>")?;
+        let tool_calls: Option<Vec<crate::bindings::golem::llm::llm::ToolCall>> = tool_calls
+            .map(|v| v.0.into_iter().map(|v| v).collect::<Vec<_>>());
+        Ok(Self { content, tool_calls })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::llm::llm::StreamEvent {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::llm::llm::StreamEvent::Delta(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::llm::llm::StreamDelta,
+                >(ctx, "delta", inner)
+            }
+            crate::bindings::golem::llm::llm::StreamEvent::Finish(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::llm::llm::ResponseMetadata,
+                >(ctx, "finish", inner)
+            }
+            crate::bindings::golem::llm::llm::StreamEvent::Error(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::llm::llm::Error,
+                >(ctx, "error", inner)
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::llm::llm::StreamEvent {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "delta" => {
+                let inner: crate::bindings::golem::llm::llm::StreamDelta = crate::wrappers::variant_value(
+                    &obj,
+                    "stream-delta",
+                )?;
+                Ok(crate::bindings::golem::llm::llm::StreamEvent::Delta(inner))
+            }
+            "finish" => {
+                let inner: crate::bindings::golem::llm::llm::ResponseMetadata = crate::wrappers::variant_value(
+                    &obj,
+                    "response-metadata",
+                )?;
+                Ok(crate::bindings::golem::llm::llm::StreamEvent::Finish(inner))
+            }
+            "error" => {
+                let inner: crate::bindings::golem::llm::llm::Error = crate::wrappers::variant_value(
+                    &obj,
+                    "error",
+                )?;
+                Ok(crate::bindings::golem::llm::llm::StreamEvent::Error(inner))
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS stream-event",
+                        "WIT stream-event",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::types::Date {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<_, i32>("year", self.year)?;
+        obj.set::<_, u8>("month", self.month)?;
+        obj.set::<_, u8>("day", self.day)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::types::Date {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT date",
+            &["year", "month", "day"],
+            &[("year", "s32"), ("month", "u8"), ("day", "u8")],
+            false,
+        )?;
+        let year: i32 = crate::wrappers::get_field(&obj, "year", "s32")?;
+        let year: i32 = year;
+        let month: u8 = crate::wrappers::get_field(&obj, "month", "u8")?;
+        let month: u8 = month;
+        let day: u8 = crate::wrappers::get_field(&obj, "day", "u8")?;
+        let day: u8 = day;
+        Ok(Self { year, month, day })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::types::Time {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<_, u8>("hour", self.hour)?;
+        obj.set::<_, u8>("minute", self.minute)?;
+        obj.set::<_, u8>("second", self.second)?;
+        obj.set::<_, u32>("nanosecond", self.nanosecond)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::types::Time {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT time",
+            &["hour", "minute", "second", "nanosecond"],
+            &[("hour", "u8"), ("minute", "u8"), ("second", "u8"), ("nanosecond", "u32")],
+            false,
+        )?;
+        let hour: u8 = crate::wrappers::get_field(&obj, "hour", "u8")?;
+        let hour: u8 = hour;
+        let minute: u8 = crate::wrappers::get_field(&obj, "minute", "u8")?;
+        let minute: u8 = minute;
+        let second: u8 = crate::wrappers::get_field(&obj, "second", "u8")?;
+        let second: u8 = second;
+        let nanosecond: u32 = crate::wrappers::get_field(&obj, "nanosecond", "u32")?;
+        let nanosecond: u32 = nanosecond;
+        Ok(Self {
+            hour,
+            minute,
+            second,
+            nanosecond,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::types::Timestamp {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<_, crate::bindings::golem::rdbms::types::Date>("date", self.date)?;
+        obj.set::<_, crate::bindings::golem::rdbms::types::Time>("time", self.time)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::types::Timestamp {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT timestamp",
+            &["date", "time"],
+            &[("date", "date"), ("time", "time")],
+            false,
+        )?;
+        let date: crate::bindings::golem::rdbms::types::Date = crate::wrappers::get_field(
+            &obj,
+            "date",
+            "date",
+        )?;
+        let date: crate::bindings::golem::rdbms::types::Date = date;
+        let time: crate::bindings::golem::rdbms::types::Time = crate::wrappers::get_field(
+            &obj,
+            "time",
+            "time",
+        )?;
+        let time: crate::bindings::golem::rdbms::types::Time = time;
+        Ok(Self { date, time })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::types::Timetz {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<_, crate::bindings::golem::rdbms::types::Time>("time", self.time)?;
+        obj.set::<_, i32>("offset", self.offset)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::types::Timetz {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT timetz",
+            &["time", "offset"],
+            &[("time", "time"), ("offset", "s32")],
+            false,
+        )?;
+        let time: crate::bindings::golem::rdbms::types::Time = crate::wrappers::get_field(
+            &obj,
+            "time",
+            "time",
+        )?;
+        let time: crate::bindings::golem::rdbms::types::Time = time;
+        let offset: i32 = crate::wrappers::get_field(&obj, "offset", "s32")?;
+        let offset: i32 = offset;
+        Ok(Self { time, offset })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::types::Timestamptz {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::golem::rdbms::types::Timestamp,
+            >("timestamp", self.timestamp)?;
+        obj.set::<_, i32>("offset", self.offset)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::types::Timestamptz {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT timestamptz",
+            &["timestamp", "offset"],
+            &[("timestamp", "timestamp"), ("offset", "s32")],
+            false,
+        )?;
+        let timestamp: crate::bindings::golem::rdbms::types::Timestamp = crate::wrappers::get_field(
+            &obj,
+            "timestamp",
+            "timestamp",
+        )?;
+        let timestamp: crate::bindings::golem::rdbms::types::Timestamp = timestamp;
+        let offset: i32 = crate::wrappers::get_field(&obj, "offset", "s32")?;
+        let offset: i32 = offset;
+        Ok(Self { timestamp, offset })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::types::Uuid {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::BigIntWrapper<u64>,
+            >("highBits", crate::wrappers::BigIntWrapper(self.high_bits))?;
+        obj.set::<
+                _,
+                crate::wrappers::BigIntWrapper<u64>,
+            >("lowBits", crate::wrappers::BigIntWrapper(self.low_bits))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::types::Uuid {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT uuid",
+            &["highBits", "lowBits"],
+            &[("highBits", "u64"), ("lowBits", "u64")],
+            false,
+        )?;
+        let high_bits: crate::wrappers::BigIntWrapper<u64> = crate::wrappers::get_field(
+            &obj,
+            "highBits",
+            "u64",
+        )?;
+        let high_bits: u64 = high_bits.0;
+        let low_bits: crate::wrappers::BigIntWrapper<u64> = crate::wrappers::get_field(
+            &obj,
+            "lowBits",
+            "u64",
+        )?;
+        let low_bits: u64 = low_bits.0;
+        Ok(Self { high_bits, low_bits })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::types::IpAddress {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::rdbms::types::IpAddress::Ipv4(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    rquickjs::convert::List<(u8, u8, u8, u8)>,
+                >(
+                    ctx,
+                    "ipv4",
+                    rquickjs::convert::List((inner.0, inner.1, inner.2, inner.3)),
+                )
+            }
+            crate::bindings::golem::rdbms::types::IpAddress::Ipv6(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    rquickjs::convert::List<(u16, u16, u16, u16, u16, u16, u16, u16)>,
+                >(
+                    ctx,
+                    "ipv6",
+                    rquickjs::convert::List((
+                        inner.0,
+                        inner.1,
+                        inner.2,
+                        inner.3,
+                        inner.4,
+                        inner.5,
+                        inner.6,
+                        inner.7,
+                    )),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::types::IpAddress {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "ipv4" => {
+                let inner: rquickjs::convert::List<(u8, u8, u8, u8)> = crate::wrappers::variant_value(
+                    &obj,
+                    "tuple<u8, u8, u8, u8>",
+                )?;
+                Ok(
+                    crate::bindings::golem::rdbms::types::IpAddress::Ipv4((
+                        inner.0.0,
+                        inner.0.1,
+                        inner.0.2,
+                        inner.0.3,
+                    )),
+                )
+            }
+            "ipv6" => {
+                let inner: rquickjs::convert::List<
+                    (u16, u16, u16, u16, u16, u16, u16, u16),
+                > = crate::wrappers::variant_value(
+                    &obj,
+                    "tuple<u16, u16, u16, u16, u16, u16, u16, u16>",
+                )?;
+                Ok(
+                    crate::bindings::golem::rdbms::types::IpAddress::Ipv6((
+                        inner.0.0,
+                        inner.0.1,
+                        inner.0.2,
+                        inner.0.3,
+                        inner.0.4,
+                        inner.0.5,
+                        inner.0.6,
+                        inner.0.7,
+                    )),
+                )
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS ip-address",
+                        "WIT ip-address",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::types::MacAddress {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                rquickjs::convert::List<(u8, u8, u8, u8, u8, u8)>,
+            >(
+            "octets",
+            rquickjs::convert::List((
+                self.octets.0,
+                self.octets.1,
+                self.octets.2,
+                self.octets.3,
+                self.octets.4,
+                self.octets.5,
+            )),
+        )?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::types::MacAddress {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT mac-address",
+            &["octets"],
+            &[("octets", "tuple<u8, u8, u8, u8, u8, u8>")],
+            false,
+        )?;
+        let octets: rquickjs::convert::List<(u8, u8, u8, u8, u8, u8)> = crate::wrappers::get_field(
+            &obj,
+            "octets",
+            "tuple<u8, u8, u8, u8, u8, u8>",
+        )?;
+        let octets: (u8, u8, u8, u8, u8, u8) = (
+            octets.0.0,
+            octets.0.1,
+            octets.0.2,
+            octets.0.3,
+            octets.0.4,
+            octets.0.5,
+        );
+        Ok(Self { octets })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::postgres::Error {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::rdbms::postgres::Error::ConnectionFailure(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "connection-failure", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::rdbms::postgres::Error::QueryParameterFailure(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "query-parameter-failure", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::rdbms::postgres::Error::QueryExecutionFailure(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "query-execution-failure", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::rdbms::postgres::Error::QueryResponseFailure(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "query-response-failure", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::rdbms::postgres::Error::Other(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "other", crate::wrappers::JsString(inner))
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::postgres::Error {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "connection-failure" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(
+                    crate::bindings::golem::rdbms::postgres::Error::ConnectionFailure(
+                        inner.0,
+                    ),
+                )
+            }
+            "query-parameter-failure" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(
+                    crate::bindings::golem::rdbms::postgres::Error::QueryParameterFailure(
+                        inner.0,
+                    ),
+                )
+            }
+            "query-execution-failure" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(
+                    crate::bindings::golem::rdbms::postgres::Error::QueryExecutionFailure(
+                        inner.0,
+                    ),
+                )
+            }
+            "query-response-failure" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(
+                    crate::bindings::golem::rdbms::postgres::Error::QueryResponseFailure(
+                        inner.0,
+                    ),
+                )
+            }
+            "other" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::Error::Other(inner.0))
+            }
+            _ => {
+                Err(crate::wrappers::unknown_variant_case("JS error", "WIT error", &tag))
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::postgres::Interval {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<_, i32>("months", self.months)?;
+        obj.set::<_, i32>("days", self.days)?;
+        obj.set::<
+                _,
+                crate::wrappers::BigIntWrapper<i64>,
+            >("microseconds", crate::wrappers::BigIntWrapper(self.microseconds))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::postgres::Interval {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT interval",
+            &["months", "days", "microseconds"],
+            &[("months", "s32"), ("days", "s32"), ("microseconds", "s64")],
+            false,
+        )?;
+        let months: i32 = crate::wrappers::get_field(&obj, "months", "s32")?;
+        let months: i32 = months;
+        let days: i32 = crate::wrappers::get_field(&obj, "days", "s32")?;
+        let days: i32 = days;
+        let microseconds: crate::wrappers::BigIntWrapper<i64> = crate::wrappers::get_field(
+            &obj,
+            "microseconds",
+            "s64",
+        )?;
+        let microseconds: i64 = microseconds.0;
+        Ok(Self { months, days, microseconds })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::postgres::Int4bound {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::rdbms::postgres::Int4bound::Included(inner) => {
+                crate::wrappers::variant_case_with_value::<i32>(ctx, "included", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::Int4bound::Excluded(inner) => {
+                crate::wrappers::variant_case_with_value::<i32>(ctx, "excluded", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::Int4bound::Unbounded => {
+                crate::wrappers::variant_case(ctx, "unbounded")
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::postgres::Int4bound {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "included" => {
+                let inner: i32 = crate::wrappers::variant_value(&obj, "s32")?;
+                Ok(crate::bindings::golem::rdbms::postgres::Int4bound::Included(inner))
+            }
+            "excluded" => {
+                let inner: i32 = crate::wrappers::variant_value(&obj, "s32")?;
+                Ok(crate::bindings::golem::rdbms::postgres::Int4bound::Excluded(inner))
+            }
+            "unbounded" => {
+                Ok(crate::bindings::golem::rdbms::postgres::Int4bound::Unbounded)
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS int4bound",
+                        "WIT int4bound",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::postgres::Int8bound {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::rdbms::postgres::Int8bound::Included(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::BigIntWrapper<i64>,
+                >(ctx, "included", crate::wrappers::BigIntWrapper(inner))
+            }
+            crate::bindings::golem::rdbms::postgres::Int8bound::Excluded(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::BigIntWrapper<i64>,
+                >(ctx, "excluded", crate::wrappers::BigIntWrapper(inner))
+            }
+            crate::bindings::golem::rdbms::postgres::Int8bound::Unbounded => {
+                crate::wrappers::variant_case(ctx, "unbounded")
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::postgres::Int8bound {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "included" => {
+                let inner: crate::wrappers::BigIntWrapper<i64> = crate::wrappers::variant_value(
+                    &obj,
+                    "s64",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::Int8bound::Included(inner.0))
+            }
+            "excluded" => {
+                let inner: crate::wrappers::BigIntWrapper<i64> = crate::wrappers::variant_value(
+                    &obj,
+                    "s64",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::Int8bound::Excluded(inner.0))
+            }
+            "unbounded" => {
+                Ok(crate::bindings::golem::rdbms::postgres::Int8bound::Unbounded)
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS int8bound",
+                        "WIT int8bound",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::postgres::Numbound {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::rdbms::postgres::Numbound::Included(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "included", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::rdbms::postgres::Numbound::Excluded(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "excluded", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::rdbms::postgres::Numbound::Unbounded => {
+                crate::wrappers::variant_case(ctx, "unbounded")
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::postgres::Numbound {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "included" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::Numbound::Included(inner.0))
+            }
+            "excluded" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::Numbound::Excluded(inner.0))
+            }
+            "unbounded" => {
+                Ok(crate::bindings::golem::rdbms::postgres::Numbound::Unbounded)
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS numbound",
+                        "WIT numbound",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::postgres::Tsbound {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::rdbms::postgres::Tsbound::Included(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::types::Timestamp,
+                >(ctx, "included", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::Tsbound::Excluded(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::types::Timestamp,
+                >(ctx, "excluded", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::Tsbound::Unbounded => {
+                crate::wrappers::variant_case(ctx, "unbounded")
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::postgres::Tsbound {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "included" => {
+                let inner: crate::bindings::golem::rdbms::types::Timestamp = crate::wrappers::variant_value(
+                    &obj,
+                    "timestamp",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::Tsbound::Included(inner))
+            }
+            "excluded" => {
+                let inner: crate::bindings::golem::rdbms::types::Timestamp = crate::wrappers::variant_value(
+                    &obj,
+                    "timestamp",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::Tsbound::Excluded(inner))
+            }
+            "unbounded" => {
+                Ok(crate::bindings::golem::rdbms::postgres::Tsbound::Unbounded)
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS tsbound",
+                        "WIT tsbound",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::postgres::Tstzbound {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::rdbms::postgres::Tstzbound::Included(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::types::Timestamptz,
+                >(ctx, "included", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::Tstzbound::Excluded(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::types::Timestamptz,
+                >(ctx, "excluded", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::Tstzbound::Unbounded => {
+                crate::wrappers::variant_case(ctx, "unbounded")
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::postgres::Tstzbound {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "included" => {
+                let inner: crate::bindings::golem::rdbms::types::Timestamptz = crate::wrappers::variant_value(
+                    &obj,
+                    "timestamptz",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::Tstzbound::Included(inner))
+            }
+            "excluded" => {
+                let inner: crate::bindings::golem::rdbms::types::Timestamptz = crate::wrappers::variant_value(
+                    &obj,
+                    "timestamptz",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::Tstzbound::Excluded(inner))
+            }
+            "unbounded" => {
+                Ok(crate::bindings::golem::rdbms::postgres::Tstzbound::Unbounded)
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS tstzbound",
+                        "WIT tstzbound",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::postgres::Datebound {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::rdbms::postgres::Datebound::Included(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::types::Date,
+                >(ctx, "included", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::Datebound::Excluded(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::types::Date,
+                >(ctx, "excluded", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::Datebound::Unbounded => {
+                crate::wrappers::variant_case(ctx, "unbounded")
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::postgres::Datebound {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "included" => {
+                let inner: crate::bindings::golem::rdbms::types::Date = crate::wrappers::variant_value(
+                    &obj,
+                    "date",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::Datebound::Included(inner))
+            }
+            "excluded" => {
+                let inner: crate::bindings::golem::rdbms::types::Date = crate::wrappers::variant_value(
+                    &obj,
+                    "date",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::Datebound::Excluded(inner))
+            }
+            "unbounded" => {
+                Ok(crate::bindings::golem::rdbms::postgres::Datebound::Unbounded)
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS datebound",
+                        "WIT datebound",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::postgres::Int4range {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::golem::rdbms::postgres::Int4bound,
+            >("start", self.start)?;
+        obj.set::<
+                _,
+                crate::bindings::golem::rdbms::postgres::Int4bound,
+            >("end", self.end)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::postgres::Int4range {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT int4range",
+            &["start", "end"],
+            &[("start", "int4bound"), ("end", "int4bound")],
+            false,
+        )?;
+        let start: crate::bindings::golem::rdbms::postgres::Int4bound = crate::wrappers::get_field(
+            &obj,
+            "start",
+            "int4bound",
+        )?;
+        let start: crate::bindings::golem::rdbms::postgres::Int4bound = start;
+        let end: crate::bindings::golem::rdbms::postgres::Int4bound = crate::wrappers::get_field(
+            &obj,
+            "end",
+            "int4bound",
+        )?;
+        let end: crate::bindings::golem::rdbms::postgres::Int4bound = end;
+        Ok(Self { start, end })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::postgres::Int8range {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::golem::rdbms::postgres::Int8bound,
+            >("start", self.start)?;
+        obj.set::<
+                _,
+                crate::bindings::golem::rdbms::postgres::Int8bound,
+            >("end", self.end)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::postgres::Int8range {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT int8range",
+            &["start", "end"],
+            &[("start", "int8bound"), ("end", "int8bound")],
+            false,
+        )?;
+        let start: crate::bindings::golem::rdbms::postgres::Int8bound = crate::wrappers::get_field(
+            &obj,
+            "start",
+            "int8bound",
+        )?;
+        let start: crate::bindings::golem::rdbms::postgres::Int8bound = start;
+        let end: crate::bindings::golem::rdbms::postgres::Int8bound = crate::wrappers::get_field(
+            &obj,
+            "end",
+            "int8bound",
+        )?;
+        let end: crate::bindings::golem::rdbms::postgres::Int8bound = end;
+        Ok(Self { start, end })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::postgres::Numrange {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::golem::rdbms::postgres::Numbound,
+            >("start", self.start)?;
+        obj.set::<
+                _,
+                crate::bindings::golem::rdbms::postgres::Numbound,
+            >("end", self.end)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::postgres::Numrange {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT numrange",
+            &["start", "end"],
+            &[("start", "numbound"), ("end", "numbound")],
+            false,
+        )?;
+        let start: crate::bindings::golem::rdbms::postgres::Numbound = crate::wrappers::get_field(
+            &obj,
+            "start",
+            "numbound",
+        )?;
+        let start: crate::bindings::golem::rdbms::postgres::Numbound = start;
+        let end: crate::bindings::golem::rdbms::postgres::Numbound = crate::wrappers::get_field(
+            &obj,
+            "end",
+            "numbound",
+        )?;
+        let end: crate::bindings::golem::rdbms::postgres::Numbound = end;
+        Ok(Self { start, end })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::postgres::Tsrange {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::golem::rdbms::postgres::Tsbound,
+            >("start", self.start)?;
+        obj.set::<_, crate::bindings::golem::rdbms::postgres::Tsbound>("end", self.end)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::postgres::Tsrange {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT tsrange",
+            &["start", "end"],
+            &[("start", "tsbound"), ("end", "tsbound")],
+            false,
+        )?;
+        let start: crate::bindings::golem::rdbms::postgres::Tsbound = crate::wrappers::get_field(
+            &obj,
+            "start",
+            "tsbound",
+        )?;
+        let start: crate::bindings::golem::rdbms::postgres::Tsbound = start;
+        let end: crate::bindings::golem::rdbms::postgres::Tsbound = crate::wrappers::get_field(
+            &obj,
+            "end",
+            "tsbound",
+        )?;
+        let end: crate::bindings::golem::rdbms::postgres::Tsbound = end;
+        Ok(Self { start, end })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::postgres::Tstzrange {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::golem::rdbms::postgres::Tstzbound,
+            >("start", self.start)?;
+        obj.set::<
+                _,
+                crate::bindings::golem::rdbms::postgres::Tstzbound,
+            >("end", self.end)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::postgres::Tstzrange {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT tstzrange",
+            &["start", "end"],
+            &[("start", "tstzbound"), ("end", "tstzbound")],
+            false,
+        )?;
+        let start: crate::bindings::golem::rdbms::postgres::Tstzbound = crate::wrappers::get_field(
+            &obj,
+            "start",
+            "tstzbound",
+        )?;
+        let start: crate::bindings::golem::rdbms::postgres::Tstzbound = start;
+        let end: crate::bindings::golem::rdbms::postgres::Tstzbound = crate::wrappers::get_field(
+            &obj,
+            "end",
+            "tstzbound",
+        )?;
+        let end: crate::bindings::golem::rdbms::postgres::Tstzbound = end;
+        Ok(Self { start, end })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::postgres::Daterange {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::golem::rdbms::postgres::Datebound,
+            >("start", self.start)?;
+        obj.set::<
+                _,
+                crate::bindings::golem::rdbms::postgres::Datebound,
+            >("end", self.end)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::postgres::Daterange {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT daterange",
+            &["start", "end"],
+            &[("start", "datebound"), ("end", "datebound")],
+            false,
+        )?;
+        let start: crate::bindings::golem::rdbms::postgres::Datebound = crate::wrappers::get_field(
+            &obj,
+            "start",
+            "datebound",
+        )?;
+        let start: crate::bindings::golem::rdbms::postgres::Datebound = start;
+        let end: crate::bindings::golem::rdbms::postgres::Datebound = crate::wrappers::get_field(
+            &obj,
+            "end",
+            "datebound",
+        )?;
+        let end: crate::bindings::golem::rdbms::postgres::Datebound = end;
+        Ok(Self { start, end })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::rdbms::postgres::EnumerationType {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("name", crate::wrappers::JsString(self.name))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::rdbms::postgres::EnumerationType {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT enumeration-type",
+            &["name"],
+            &[("name", "string")],
+            false,
+        )?;
+        let name: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "name",
+            "string",
+        )?;
+        let name: String = name.0;
+        Ok(Self { name })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::rdbms::postgres::Enumeration {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("name", crate::wrappers::JsString(self.name))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("value", crate::wrappers::JsString(self.value))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::rdbms::postgres::Enumeration {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT enumeration",
+            &["name", "value"],
+            &[("name", "string"), ("value", "string")],
+            false,
+        )?;
+        let name: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "name",
+            "string",
+        )?;
+        let name: String = name.0;
+        let value: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "value",
+            "string",
+        )?;
+        let value: String = value.0;
+        Ok(Self { name, value })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::postgres::Composite {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("name", crate::wrappers::JsString(self.name))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<
+                    crate::bindings::golem::rdbms::postgres::LazyDbValue,
+                >,
+            >(
+            "values",
+            crate::wrappers::JsList(
+                self.values.into_iter().map(|v| v).collect::<Vec<_>>(),
+            ),
+        )?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::postgres::Composite {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT composite",
+            &["name", "values"],
+            &[("name", "string"), ("values", "list<lazy-db-value>")],
+            false,
+        )?;
+        let name: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "name",
+            "string",
+        )?;
+        let name: String = name.0;
+        let values: crate::wrappers::JsList<
+            crate::bindings::golem::rdbms::postgres::LazyDbValue,
+        > = crate::wrappers::get_field(&obj, "values", "list<lazy-db-value>")?;
+        let values: Vec<crate::bindings::golem::rdbms::postgres::LazyDbValue> = values
+            .0
+            .into_iter()
+            .map(|v| v)
+            .collect::<Vec<_>>();
+        Ok(Self { name, values })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::postgres::Domain {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("name", crate::wrappers::JsString(self.name))?;
+        obj.set::<
+                _,
+                crate::bindings::golem::rdbms::postgres::LazyDbValue,
+            >("value", self.value)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::postgres::Domain {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT domain",
+            &["name", "value"],
+            &[("name", "string"), ("value", "lazy-db-value")],
+            false,
+        )?;
+        let name: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "name",
+            "string",
+        )?;
+        let name: String = name.0;
+        let value: crate::bindings::golem::rdbms::postgres::LazyDbValue = crate::wrappers::get_field(
+            &obj,
+            "value",
+            "lazy-db-value",
+        )?;
+        let value: crate::bindings::golem::rdbms::postgres::LazyDbValue = value;
+        Ok(Self { name, value })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::postgres::ValueBound {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::rdbms::postgres::ValueBound::Included(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::postgres::LazyDbValue,
+                >(ctx, "included", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::ValueBound::Excluded(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::postgres::LazyDbValue,
+                >(ctx, "excluded", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::ValueBound::Unbounded => {
+                crate::wrappers::variant_case(ctx, "unbounded")
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::postgres::ValueBound {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "included" => {
+                let inner: crate::bindings::golem::rdbms::postgres::LazyDbValue = crate::wrappers::variant_value(
+                    &obj,
+                    "lazy-db-value",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::ValueBound::Included(inner))
+            }
+            "excluded" => {
+                let inner: crate::bindings::golem::rdbms::postgres::LazyDbValue = crate::wrappers::variant_value(
+                    &obj,
+                    "lazy-db-value",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::ValueBound::Excluded(inner))
+            }
+            "unbounded" => {
+                Ok(crate::bindings::golem::rdbms::postgres::ValueBound::Unbounded)
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS value-bound",
+                        "WIT value-bound",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::rdbms::postgres::ValuesRange {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::bindings::golem::rdbms::postgres::ValueBound,
+            >("start", self.start)?;
+        obj.set::<
+                _,
+                crate::bindings::golem::rdbms::postgres::ValueBound,
+            >("end", self.end)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::rdbms::postgres::ValuesRange {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT values-range",
+            &["start", "end"],
+            &[("start", "value-bound"), ("end", "value-bound")],
+            false,
+        )?;
+        let start: crate::bindings::golem::rdbms::postgres::ValueBound = crate::wrappers::get_field(
+            &obj,
+            "start",
+            "value-bound",
+        )?;
+        let start: crate::bindings::golem::rdbms::postgres::ValueBound = start;
+        let end: crate::bindings::golem::rdbms::postgres::ValueBound = crate::wrappers::get_field(
+            &obj,
+            "end",
+            "value-bound",
+        )?;
+        let end: crate::bindings::golem::rdbms::postgres::ValueBound = end;
+        Ok(Self { start, end })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::postgres::Range {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("name", crate::wrappers::JsString(self.name))?;
+        obj.set::<
+                _,
+                crate::bindings::golem::rdbms::postgres::ValuesRange,
+            >("value", self.value)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::postgres::Range {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT range",
+            &["name", "value"],
+            &[("name", "string"), ("value", "values-range")],
+            false,
+        )?;
+        let name: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "name",
+            "string",
+        )?;
+        let name: String = name.0;
+        let value: crate::bindings::golem::rdbms::postgres::ValuesRange = crate::wrappers::get_field(
+            &obj,
+            "value",
+            "values-range",
+        )?;
+        let value: crate::bindings::golem::rdbms::postgres::ValuesRange = value;
+        Ok(Self { name, value })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::postgres::DbValue {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::rdbms::postgres::DbValue::Character(inner) => {
+                crate::wrappers::variant_case_with_value::<i8>(ctx, "character", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Int2(inner) => {
+                crate::wrappers::variant_case_with_value::<i16>(ctx, "int2", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Int4(inner) => {
+                crate::wrappers::variant_case_with_value::<i32>(ctx, "int4", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Int8(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::BigIntWrapper<i64>,
+                >(ctx, "int8", crate::wrappers::BigIntWrapper(inner))
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Float4(inner) => {
+                crate::wrappers::variant_case_with_value::<f32>(ctx, "float4", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Float8(inner) => {
+                crate::wrappers::variant_case_with_value::<f64>(ctx, "float8", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Numeric(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "numeric", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Boolean(inner) => {
+                crate::wrappers::variant_case_with_value::<bool>(ctx, "boolean", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Text(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "text", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Varchar(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "varchar", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Bpchar(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "bpchar", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Timestamp(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::types::Timestamp,
+                >(ctx, "timestamp", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Timestamptz(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::types::Timestamptz,
+                >(ctx, "timestamptz", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Date(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::types::Date,
+                >(ctx, "date", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Time(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::types::Time,
+                >(ctx, "time", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Timetz(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::types::Timetz,
+                >(ctx, "timetz", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Interval(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::postgres::Interval,
+                >(ctx, "interval", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Bytea(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::UInt8Array,
+                >(ctx, "bytea", crate::wrappers::UInt8Array(inner))
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Json(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "json", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Jsonb(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "jsonb", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Jsonpath(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "jsonpath", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Xml(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "xml", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Uuid(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::types::Uuid,
+                >(ctx, "uuid", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Inet(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::types::IpAddress,
+                >(ctx, "inet", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Cidr(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::types::IpAddress,
+                >(ctx, "cidr", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Macaddr(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::types::MacAddress,
+                >(ctx, "macaddr", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Bit(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsList<bool>,
+                >(
+                    ctx,
+                    "bit",
+                    crate::wrappers::JsList(
+                        inner.into_iter().map(|v| v).collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Varbit(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsList<bool>,
+                >(
+                    ctx,
+                    "varbit",
+                    crate::wrappers::JsList(
+                        inner.into_iter().map(|v| v).collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Int4range(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::postgres::Int4range,
+                >(ctx, "int4range", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Int8range(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::postgres::Int8range,
+                >(ctx, "int8range", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Numrange(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::postgres::Numrange,
+                >(ctx, "numrange", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Tsrange(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::postgres::Tsrange,
+                >(ctx, "tsrange", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Tstzrange(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::postgres::Tstzrange,
+                >(ctx, "tstzrange", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Daterange(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::postgres::Daterange,
+                >(ctx, "daterange", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Money(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::BigIntWrapper<i64>,
+                >(ctx, "money", crate::wrappers::BigIntWrapper(inner))
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Oid(inner) => {
+                crate::wrappers::variant_case_with_value::<u32>(ctx, "oid", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Enumeration(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::postgres::Enumeration,
+                >(ctx, "enumeration", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Composite(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::postgres::Composite,
+                >(ctx, "composite", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Domain(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::postgres::Domain,
+                >(ctx, "domain", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Array(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsList<
+                        crate::bindings::golem::rdbms::postgres::LazyDbValue,
+                    >,
+                >(
+                    ctx,
+                    "array",
+                    crate::wrappers::JsList(
+                        inner.into_iter().map(|v| v).collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Range(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::postgres::Range,
+                >(ctx, "range", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::DbValue::Null => {
+                crate::wrappers::variant_case(ctx, "null")
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::postgres::DbValue {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "character" => {
+                let inner: i8 = crate::wrappers::variant_value(&obj, "s8")?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Character(inner))
+            }
+            "int2" => {
+                let inner: i16 = crate::wrappers::variant_value(&obj, "s16")?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Int2(inner))
+            }
+            "int4" => {
+                let inner: i32 = crate::wrappers::variant_value(&obj, "s32")?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Int4(inner))
+            }
+            "int8" => {
+                let inner: crate::wrappers::BigIntWrapper<i64> = crate::wrappers::variant_value(
+                    &obj,
+                    "s64",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Int8(inner.0))
+            }
+            "float4" => {
+                let inner: f32 = crate::wrappers::variant_value(&obj, "f32")?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Float4(inner))
+            }
+            "float8" => {
+                let inner: f64 = crate::wrappers::variant_value(&obj, "f64")?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Float8(inner))
+            }
+            "numeric" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Numeric(inner.0))
+            }
+            "boolean" => {
+                let inner: bool = crate::wrappers::variant_value(&obj, "bool")?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Boolean(inner))
+            }
+            "text" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Text(inner.0))
+            }
+            "varchar" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Varchar(inner.0))
+            }
+            "bpchar" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Bpchar(inner.0))
+            }
+            "timestamp" => {
+                let inner: crate::bindings::golem::rdbms::types::Timestamp = crate::wrappers::variant_value(
+                    &obj,
+                    "timestamp",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Timestamp(inner))
+            }
+            "timestamptz" => {
+                let inner: crate::bindings::golem::rdbms::types::Timestamptz = crate::wrappers::variant_value(
+                    &obj,
+                    "timestamptz",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Timestamptz(inner))
+            }
+            "date" => {
+                let inner: crate::bindings::golem::rdbms::types::Date = crate::wrappers::variant_value(
+                    &obj,
+                    "date",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Date(inner))
+            }
+            "time" => {
+                let inner: crate::bindings::golem::rdbms::types::Time = crate::wrappers::variant_value(
+                    &obj,
+                    "time",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Time(inner))
+            }
+            "timetz" => {
+                let inner: crate::bindings::golem::rdbms::types::Timetz = crate::wrappers::variant_value(
+                    &obj,
+                    "timetz",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Timetz(inner))
+            }
+            "interval" => {
+                let inner: crate::bindings::golem::rdbms::postgres::Interval = crate::wrappers::variant_value(
+                    &obj,
+                    "interval",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Interval(inner))
+            }
+            "bytea" => {
+                let inner: crate::wrappers::UInt8Array = crate::wrappers::variant_value(
+                    &obj,
+                    "list<u8>",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Bytea(inner.0))
+            }
+            "json" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Json(inner.0))
+            }
+            "jsonb" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Jsonb(inner.0))
+            }
+            "jsonpath" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Jsonpath(inner.0))
+            }
+            "xml" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Xml(inner.0))
+            }
+            "uuid" => {
+                let inner: crate::bindings::golem::rdbms::types::Uuid = crate::wrappers::variant_value(
+                    &obj,
+                    "uuid",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Uuid(inner))
+            }
+            "inet" => {
+                let inner: crate::bindings::golem::rdbms::types::IpAddress = crate::wrappers::variant_value(
+                    &obj,
+                    "ip-address",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Inet(inner))
+            }
+            "cidr" => {
+                let inner: crate::bindings::golem::rdbms::types::IpAddress = crate::wrappers::variant_value(
+                    &obj,
+                    "ip-address",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Cidr(inner))
+            }
+            "macaddr" => {
+                let inner: crate::bindings::golem::rdbms::types::MacAddress = crate::wrappers::variant_value(
+                    &obj,
+                    "mac-address",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Macaddr(inner))
+            }
+            "bit" => {
+                let inner: crate::wrappers::JsList<bool> = crate::wrappers::variant_value(
+                    &obj,
+                    "list<bool>",
+                )?;
+                Ok(
+                    crate::bindings::golem::rdbms::postgres::DbValue::Bit(
+                        inner.0.into_iter().map(|v| v).collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            "varbit" => {
+                let inner: crate::wrappers::JsList<bool> = crate::wrappers::variant_value(
+                    &obj,
+                    "list<bool>",
+                )?;
+                Ok(
+                    crate::bindings::golem::rdbms::postgres::DbValue::Varbit(
+                        inner.0.into_iter().map(|v| v).collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            "int4range" => {
+                let inner: crate::bindings::golem::rdbms::postgres::Int4range = crate::wrappers::variant_value(
+                    &obj,
+                    "int4range",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Int4range(inner))
+            }
+            "int8range" => {
+                let inner: crate::bindings::golem::rdbms::postgres::Int8range = crate::wrappers::variant_value(
+                    &obj,
+                    "int8range",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Int8range(inner))
+            }
+            "numrange" => {
+                let inner: crate::bindings::golem::rdbms::postgres::Numrange = crate::wrappers::variant_value(
+                    &obj,
+                    "numrange",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Numrange(inner))
+            }
+            "tsrange" => {
+                let inner: crate::bindings::golem::rdbms::postgres::Tsrange = crate::wrappers::variant_value(
+                    &obj,
+                    "tsrange",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Tsrange(inner))
+            }
+            "tstzrange" => {
+                let inner: crate::bindings::golem::rdbms::postgres::Tstzrange = crate::wrappers::variant_value(
+                    &obj,
+                    "tstzrange",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Tstzrange(inner))
+            }
+            "daterange" => {
+                let inner: crate::bindings::golem::rdbms::postgres::Daterange = crate::wrappers::variant_value(
+                    &obj,
+                    "daterange",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Daterange(inner))
+            }
+            "money" => {
+                let inner: crate::wrappers::BigIntWrapper<i64> = crate::wrappers::variant_value(
+                    &obj,
+                    "s64",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Money(inner.0))
+            }
+            "oid" => {
+                let inner: u32 = crate::wrappers::variant_value(&obj, "u32")?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Oid(inner))
+            }
+            "enumeration" => {
+                let inner: crate::bindings::golem::rdbms::postgres::Enumeration = crate::wrappers::variant_value(
+                    &obj,
+                    "enumeration",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Enumeration(inner))
+            }
+            "composite" => {
+                let inner: crate::bindings::golem::rdbms::postgres::Composite = crate::wrappers::variant_value(
+                    &obj,
+                    "composite",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Composite(inner))
+            }
+            "domain" => {
+                let inner: crate::bindings::golem::rdbms::postgres::Domain = crate::wrappers::variant_value(
+                    &obj,
+                    "domain",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Domain(inner))
+            }
+            "array" => {
+                let inner: crate::wrappers::JsList<
+                    crate::bindings::golem::rdbms::postgres::LazyDbValue,
+                > = crate::wrappers::variant_value(&obj, "list<lazy-db-value>")?;
+                Ok(
+                    crate::bindings::golem::rdbms::postgres::DbValue::Array(
+                        inner.0.into_iter().map(|v| v).collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            "range" => {
+                let inner: crate::bindings::golem::rdbms::postgres::Range = crate::wrappers::variant_value(
+                    &obj,
+                    "range",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbValue::Range(inner))
+            }
+            "null" => Ok(crate::bindings::golem::rdbms::postgres::DbValue::Null),
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS db-value",
+                        "WIT db-value",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::rdbms::postgres::CompositeType {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("name", crate::wrappers::JsString(self.name))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<
+                    rquickjs::convert::List<
+                        (
+                            crate::wrappers::JsString,
+                            crate::bindings::golem::rdbms::postgres::LazyDbColumnType,
+                        ),
+                    >,
+                >,
+            >(
+            "attributes",
+            crate::wrappers::JsList(
+                self
+                    .attributes
+                    .into_iter()
+                    .map(|v| rquickjs::convert::List((
+                        crate::wrappers::JsString(v.0),
+                        v.1,
+                    )))
+                    .collect::<Vec<_>>(),
+            ),
+        )?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::rdbms::postgres::CompositeType {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT composite-type",
+            &["name", "attributes"],
+            &[
+                ("name", "string"),
+                ("attributes", "list<tuple<string, lazy-db-column-type>>"),
+            ],
+            false,
+        )?;
+        let name: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "name",
+            "string",
+        )?;
+        let name: String = name.0;
+        let attributes: crate::wrappers::JsList<
+            rquickjs::convert::List<
+                (
+                    crate::wrappers::JsString,
+                    crate::bindings::golem::rdbms::postgres::LazyDbColumnType,
+                ),
+            >,
+        > = crate::wrappers::get_field(
+            &obj,
+            "attributes",
+            "list<tuple<string, lazy-db-column-type>>",
+        )?;
+        let attributes: Vec<
+            (String, crate::bindings::golem::rdbms::postgres::LazyDbColumnType),
+        > = attributes
+            .0
+            .into_iter()
+            .map(|v| (v.0.0.0.clone(), v.0.1))
+            .collect::<Vec<_>>();
+        Ok(Self { name, attributes })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::postgres::DomainType {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("name", crate::wrappers::JsString(self.name))?;
+        obj.set::<
+                _,
+                crate::bindings::golem::rdbms::postgres::LazyDbColumnType,
+            >("baseType", self.base_type)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::postgres::DomainType {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT domain-type",
+            &["name", "baseType"],
+            &[("name", "string"), ("baseType", "lazy-db-column-type")],
+            false,
+        )?;
+        let name: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "name",
+            "string",
+        )?;
+        let name: String = name.0;
+        let base_type: crate::bindings::golem::rdbms::postgres::LazyDbColumnType = crate::wrappers::get_field(
+            &obj,
+            "baseType",
+            "lazy-db-column-type",
+        )?;
+        let base_type: crate::bindings::golem::rdbms::postgres::LazyDbColumnType = base_type;
+        Ok(Self { name, base_type })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::postgres::RangeType {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("name", crate::wrappers::JsString(self.name))?;
+        obj.set::<
+                _,
+                crate::bindings::golem::rdbms::postgres::LazyDbColumnType,
+            >("baseType", self.base_type)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::postgres::RangeType {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT range-type",
+            &["name", "baseType"],
+            &[("name", "string"), ("baseType", "lazy-db-column-type")],
+            false,
+        )?;
+        let name: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "name",
+            "string",
+        )?;
+        let name: String = name.0;
+        let base_type: crate::bindings::golem::rdbms::postgres::LazyDbColumnType = crate::wrappers::get_field(
+            &obj,
+            "baseType",
+            "lazy-db-column-type",
+        )?;
+        let base_type: crate::bindings::golem::rdbms::postgres::LazyDbColumnType = base_type;
+        Ok(Self { name, base_type })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::golem::rdbms::postgres::DbColumnType {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Character => {
+                crate::wrappers::variant_case(ctx, "character")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Int2 => {
+                crate::wrappers::variant_case(ctx, "int2")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Int4 => {
+                crate::wrappers::variant_case(ctx, "int4")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Int8 => {
+                crate::wrappers::variant_case(ctx, "int8")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Float4 => {
+                crate::wrappers::variant_case(ctx, "float4")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Float8 => {
+                crate::wrappers::variant_case(ctx, "float8")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Numeric => {
+                crate::wrappers::variant_case(ctx, "numeric")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Boolean => {
+                crate::wrappers::variant_case(ctx, "boolean")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Text => {
+                crate::wrappers::variant_case(ctx, "text")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Varchar => {
+                crate::wrappers::variant_case(ctx, "varchar")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Bpchar => {
+                crate::wrappers::variant_case(ctx, "bpchar")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Timestamp => {
+                crate::wrappers::variant_case(ctx, "timestamp")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Timestamptz => {
+                crate::wrappers::variant_case(ctx, "timestamptz")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Date => {
+                crate::wrappers::variant_case(ctx, "date")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Time => {
+                crate::wrappers::variant_case(ctx, "time")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Timetz => {
+                crate::wrappers::variant_case(ctx, "timetz")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Interval => {
+                crate::wrappers::variant_case(ctx, "interval")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Bytea => {
+                crate::wrappers::variant_case(ctx, "bytea")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Uuid => {
+                crate::wrappers::variant_case(ctx, "uuid")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Xml => {
+                crate::wrappers::variant_case(ctx, "xml")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Json => {
+                crate::wrappers::variant_case(ctx, "json")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Jsonb => {
+                crate::wrappers::variant_case(ctx, "jsonb")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Jsonpath => {
+                crate::wrappers::variant_case(ctx, "jsonpath")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Inet => {
+                crate::wrappers::variant_case(ctx, "inet")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Cidr => {
+                crate::wrappers::variant_case(ctx, "cidr")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Macaddr => {
+                crate::wrappers::variant_case(ctx, "macaddr")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Bit => {
+                crate::wrappers::variant_case(ctx, "bit")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Varbit => {
+                crate::wrappers::variant_case(ctx, "varbit")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Int4range => {
+                crate::wrappers::variant_case(ctx, "int4range")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Int8range => {
+                crate::wrappers::variant_case(ctx, "int8range")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Numrange => {
+                crate::wrappers::variant_case(ctx, "numrange")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Tsrange => {
+                crate::wrappers::variant_case(ctx, "tsrange")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Tstzrange => {
+                crate::wrappers::variant_case(ctx, "tstzrange")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Daterange => {
+                crate::wrappers::variant_case(ctx, "daterange")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Money => {
+                crate::wrappers::variant_case(ctx, "money")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Oid => {
+                crate::wrappers::variant_case(ctx, "oid")
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Enumeration(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::postgres::EnumerationType,
+                >(ctx, "enumeration", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Composite(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::postgres::CompositeType,
+                >(ctx, "composite", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Domain(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::postgres::DomainType,
+                >(ctx, "domain", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Array(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::postgres::LazyDbColumnType,
+                >(ctx, "array", inner)
+            }
+            crate::bindings::golem::rdbms::postgres::DbColumnType::Range(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::bindings::golem::rdbms::postgres::RangeType,
+                >(ctx, "range", inner)
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::golem::rdbms::postgres::DbColumnType {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "character" => {
+                Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Character)
+            }
+            "int2" => Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Int2),
+            "int4" => Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Int4),
+            "int8" => Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Int8),
+            "float4" => Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Float4),
+            "float8" => Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Float8),
+            "numeric" => {
+                Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Numeric)
+            }
+            "boolean" => {
+                Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Boolean)
+            }
+            "text" => Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Text),
+            "varchar" => {
+                Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Varchar)
+            }
+            "bpchar" => Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Bpchar),
+            "timestamp" => {
+                Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Timestamp)
+            }
+            "timestamptz" => {
+                Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Timestamptz)
+            }
+            "date" => Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Date),
+            "time" => Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Time),
+            "timetz" => Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Timetz),
+            "interval" => {
+                Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Interval)
+            }
+            "bytea" => Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Bytea),
+            "uuid" => Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Uuid),
+            "xml" => Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Xml),
+            "json" => Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Json),
+            "jsonb" => Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Jsonb),
+            "jsonpath" => {
+                Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Jsonpath)
+            }
+            "inet" => Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Inet),
+            "cidr" => Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Cidr),
+            "macaddr" => {
+                Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Macaddr)
+            }
+            "bit" => Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Bit),
+            "varbit" => Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Varbit),
+            "int4range" => {
+                Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Int4range)
+            }
+            "int8range" => {
+                Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Int8range)
+            }
+            "numrange" => {
+                Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Numrange)
+            }
+            "tsrange" => {
+                Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Tsrange)
+            }
+            "tstzrange" => {
+                Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Tstzrange)
+            }
+            "daterange" => {
+                Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Daterange)
+            }
+            "money" => Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Money),
+            "oid" => Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Oid),
+            "enumeration" => {
+                let inner: crate::bindings::golem::rdbms::postgres::EnumerationType = crate::wrappers::variant_value(
+                    &obj,
+                    "enumeration-type",
+                )?;
+                Ok(
+                    crate::bindings::golem::rdbms::postgres::DbColumnType::Enumeration(
+                        inner,
+                    ),
+                )
+            }
+            "composite" => {
+                let inner: crate::bindings::golem::rdbms::postgres::CompositeType = crate::wrappers::variant_value(
+                    &obj,
+                    "composite-type",
+                )?;
+                Ok(
+                    crate::bindings::golem::rdbms::postgres::DbColumnType::Composite(
+                        inner,
+                    ),
+                )
+            }
+            "domain" => {
+                let inner: crate::bindings::golem::rdbms::postgres::DomainType = crate::wrappers::variant_value(
+                    &obj,
+                    "domain-type",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Domain(inner))
+            }
+            "array" => {
+                let inner: crate::bindings::golem::rdbms::postgres::LazyDbColumnType = crate::wrappers::variant_value(
+                    &obj,
+                    "lazy-db-column-type",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Array(inner))
+            }
+            "range" => {
+                let inner: crate::bindings::golem::rdbms::postgres::RangeType = crate::wrappers::variant_value(
+                    &obj,
+                    "range-type",
+                )?;
+                Ok(crate::bindings::golem::rdbms::postgres::DbColumnType::Range(inner))
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS db-column-type",
+                        "WIT db-column-type",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::postgres::DbColumn {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::BigIntWrapper<u64>,
+            >("ordinal", crate::wrappers::BigIntWrapper(self.ordinal))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("name", crate::wrappers::JsString(self.name))?;
+        obj.set::<
+                _,
+                crate::bindings::golem::rdbms::postgres::DbColumnType,
+            >("dbType", self.db_type)?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("dbTypeName", crate::wrappers::JsString(self.db_type_name))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::postgres::DbColumn {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT db-column",
+            &["ordinal", "name", "dbType", "dbTypeName"],
+            &[
+                ("ordinal", "u64"),
+                ("name", "string"),
+                ("dbType", "db-column-type"),
+                ("dbTypeName", "string"),
+            ],
+            false,
+        )?;
+        let ordinal: crate::wrappers::BigIntWrapper<u64> = crate::wrappers::get_field(
+            &obj,
+            "ordinal",
+            "u64",
+        )?;
+        let ordinal: u64 = ordinal.0;
+        let name: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "name",
+            "string",
+        )?;
+        let name: String = name.0;
+        let db_type: crate::bindings::golem::rdbms::postgres::DbColumnType = crate::wrappers::get_field(
+            &obj,
+            "dbType",
+            "db-column-type",
+        )?;
+        let db_type: crate::bindings::golem::rdbms::postgres::DbColumnType = db_type;
+        let db_type_name: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "dbTypeName",
+            "string",
+        )?;
+        let db_type_name: String = db_type_name.0;
+        Ok(Self {
+            ordinal,
+            name,
+            db_type,
+            db_type_name,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::postgres::DbRow {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<crate::bindings::golem::rdbms::postgres::DbValue>,
+            >(
+            "values",
+            crate::wrappers::JsList(
+                self.values.into_iter().map(|v| v).collect::<Vec<_>>(),
+            ),
+        )?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::postgres::DbRow {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT db-row",
+            &["values"],
+            &[("values", "list<db-value>")],
+            false,
+        )?;
+        let values: crate::wrappers::JsList<
+            crate::bindings::golem::rdbms::postgres::DbValue,
+        > = crate::wrappers::get_field(&obj, "values", "list<db-value>")?;
+        let values: Vec<crate::bindings::golem::rdbms::postgres::DbValue> = values
+            .0
+            .into_iter()
+            .map(|v| v)
+            .collect::<Vec<_>>();
+        Ok(Self { values })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::rdbms::postgres::DbResult {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<
+                    crate::bindings::golem::rdbms::postgres::DbColumn,
+                >,
+            >(
+            "columns",
+            crate::wrappers::JsList(
+                self.columns.into_iter().map(|v| v).collect::<Vec<_>>(),
+            ),
+        )?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<crate::bindings::golem::rdbms::postgres::DbRow>,
+            >(
+            "rows",
+            crate::wrappers::JsList(self.rows.into_iter().map(|v| v).collect::<Vec<_>>()),
+        )?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::rdbms::postgres::DbResult {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT db-result",
+            &["columns", "rows"],
+            &[("columns", "list<db-column>"), ("rows", "list<db-row>")],
+            false,
+        )?;
+        let columns: crate::wrappers::JsList<
+            crate::bindings::golem::rdbms::postgres::DbColumn,
+        > = crate::wrappers::get_field(&obj, "columns", "list<db-column>")?;
+        let columns: Vec<crate::bindings::golem::rdbms::postgres::DbColumn> = columns
+            .0
+            .into_iter()
+            .map(|v| v)
+            .collect::<Vec<_>>();
+        let rows: crate::wrappers::JsList<
+            crate::bindings::golem::rdbms::postgres::DbRow,
+        > = crate::wrappers::get_field(&obj, "rows", "list<db-row>")?;
+        let rows: Vec<crate::bindings::golem::rdbms::postgres::DbRow> = rows
+            .0
+            .into_iter()
+            .map(|v| v)
+            .collect::<Vec<_>>();
+        Ok(Self { columns, rows })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::wasi::blobstore::types::ContainerMetadata {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("name", crate::wrappers::JsString(self.name))?;
+        obj.set::<
+                _,
+                crate::wrappers::BigIntWrapper<u64>,
+            >("createdAt", crate::wrappers::BigIntWrapper(self.created_at))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::wasi::blobstore::types::ContainerMetadata {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT container-metadata",
+            &["name", "createdAt"],
+            &[("name", "container-name"), ("createdAt", "timestamp")],
+            false,
+        )?;
+        let name: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "name",
+            "container-name",
+        )?;
+        let name: crate::bindings::wasi::blobstore::types::ContainerName = name.0;
+        let created_at: crate::wrappers::BigIntWrapper<u64> = crate::wrappers::get_field(
+            &obj,
+            "createdAt",
+            "timestamp",
+        )?;
+        let created_at: crate::bindings::wasi::blobstore::types::Timestamp = created_at
+            .0;
+        Ok(Self { name, created_at })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::wasi::blobstore::types::ObjectMetadata {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("name", crate::wrappers::JsString(self.name))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("container", crate::wrappers::JsString(self.container))?;
+        obj.set::<
+                _,
+                crate::wrappers::BigIntWrapper<u64>,
+            >("createdAt", crate::wrappers::BigIntWrapper(self.created_at))?;
+        obj.set::<
+                _,
+                crate::wrappers::BigIntWrapper<u64>,
+            >("size", crate::wrappers::BigIntWrapper(self.size))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::wasi::blobstore::types::ObjectMetadata {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT object-metadata",
+            &["name", "container", "createdAt", "size"],
+            &[
+                ("name", "object-name"),
+                ("container", "container-name"),
+                ("createdAt", "timestamp"),
+                ("size", "object-size"),
+            ],
+            false,
+        )?;
+        let name: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "name",
+            "object-name",
+        )?;
+        let name: crate::bindings::wasi::blobstore::types::ObjectName = name.0;
+        let container: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "container",
+            "container-name",
+        )?;
+        let container: crate::bindings::wasi::blobstore::types::ContainerName = container
+            .0;
+        let created_at: crate::wrappers::BigIntWrapper<u64> = crate::wrappers::get_field(
+            &obj,
+            "createdAt",
+            "timestamp",
+        )?;
+        let created_at: crate::bindings::wasi::blobstore::types::Timestamp = created_at
+            .0;
+        let size: crate::wrappers::BigIntWrapper<u64> = crate::wrappers::get_field(
+            &obj,
+            "size",
+            "object-size",
+        )?;
+        let size: crate::bindings::wasi::blobstore::types::ObjectSize = size.0;
+        Ok(Self {
+            name,
+            container,
+            created_at,
+            size,
+        })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::wasi::blobstore::types::ObjectId {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("container", crate::wrappers::JsString(self.container))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("object", crate::wrappers::JsString(self.object))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::wasi::blobstore::types::ObjectId {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT object-id",
+            &["container", "object"],
+            &[("container", "container-name"), ("object", "object-name")],
+            false,
+        )?;
+        let container: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "container",
+            "container-name",
+        )?;
+        let container: crate::bindings::wasi::blobstore::types::ContainerName = container
+            .0;
+        let object: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "object",
+            "object-name",
+        )?;
+        let object: crate::bindings::wasi::blobstore::types::ObjectName = object.0;
+        Ok(Self { container, object })
+    }
+}
@@ -0,0 +1,326 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:mocked-golem-imports/mocked-golem-imports
+// wit: sha256:e2a5c934071279725dae2fd75e09aa4ebe6992aaedf7cac0976199427144664e
+// js mocked-golem-imports: sha256:1505fb2c5e8bcc2584584e3f366a93497be44e32b3a6e7d2f5bd49771d3ff4b2
+
+// Empty if the world does not import the interface of the database
+const POSTGRES_MODULE = 'golem:rdbms/postgres@0.0.1';
+const MYSQL_MODULE = '';
+
+const INT4_MIN = -2147483648;
+const INT4_MAX = 2147483647;
+
+export class DatabaseError extends Error {
+    constructor(message, options) {
+        super(message, options);
+        this.name = 'DatabaseError';
+    }
+}
+
+// Runs a call of the host interface, converting the thrown `error` variants to `DatabaseError`s
+function call(operation, f) {
+    try {
+        return f();
+    } catch (error) {
+        if (error instanceof Error) {
+            throw error;
+        }
+        const reason = error?.tag === undefined ? String(error) : `${error.tag}: ${error.val}`;
+        throw new DatabaseError(`Failed to ${operation}: ${reason}`, { cause: error });
+    }
+}
+
+// A parameter with an explicit database type, bypassing the conversion of JS values
+class TypedValue {
+    constructor(tag, val) {
+        this.tag = tag;
+        this.val = val;
+    }
+}
+
+export function typed(tag, val) {
+    return new TypedValue(tag, val);
+}
+
+function toDateParts(date) {
+    return {
+        date: { year: date.getUTCFullYear(), month: date.getUTCMonth() + 1, day: date.getUTCDate() },
+        time: {
+            hour: date.getUTCHours(),
+            minute: date.getUTCMinutes(),
+            second: date.getUTCSeconds(),
+            nanosecond: date.getUTCMilliseconds() * 1000000,
+        },
+    };
+}
+
+function fromDateParts({ date, time }, offsetSeconds = 0) {
+    const millis = Date.UTC(date.year, date.month - 1, date.day, time.hour, time.minute, time.second,
+        Math.floor(time.nanosecond / 1000000));
+    return new Date(millis - offsetSeconds * 1000);
+}
+
+function toBytes(value) {
+    if (value instanceof Uint8Array) {
+        return value;
+    }
+    if (ArrayBuffer.isView(value)) {
+        return new Uint8Array(value.buffer, value.byteOffset, value.byteLength);
+    }
+    return new Uint8Array(value);
+}
+
+function isBytes(value) {
+    return value instanceof ArrayBuffer || ArrayBuffer.isView(value);
+}
+
+function formatUuid({ highBits, lowBits }) {
+    const hex = ((highBits << 64n) | lowBits).toString(16).padStart(32, '0');
+    return `${hex.slice(0, 8)}-${hex.slice(8, 12)}-${hex.slice(12, 16)}-${hex.slice(16, 20)}-${hex.slice(20)}`;
+}
+
+// The conversions between JS values and the `db-value` variants of each database
+const DIALECTS = {
+    postgres: {
+        placeholder: (index) => `$${index + 1}`,
+        toDbValue(value) {
+            if (value instanceof TypedValue) {
+                return { tag: value.tag, val: value.val };
+            }
+            if (value === null || value === undefined) {
+                return { tag: 'null' };
+            }
+            switch (typeof value) {
+                case 'boolean':
+                    return { tag: 'boolean', val: value };
+                case 'number':
+                    if (Number.isInteger(value)) {
+                        return value >= INT4_MIN && value <= INT4_MAX
+                            ? { tag: 'int4', val: value }
+                            : { tag: 'int8', val: BigInt(value) };
+                    }
+                    return { tag: 'float8', val: value };
+                case 'bigint':
+                    return { tag: 'int8', val: value };
+                case 'string':
+                    return { tag: 'text', val: value };
+            }
+            if (value instanceof Date) {
+                return { tag: 'timestamptz', val: { timestamp: toDateParts(value), offset: 0 } };
+            }
+            if (isBytes(value)) {
+                return { tag: 'bytea', val: toBytes(value) };
+            }
+            return { tag: 'jsonb', val: JSON.stringify(value) };
+        },
+        fromDbValue(value) {
+            switch (value.tag) {
+                case 'null':
+                    return null;
+                case 'json':
+                case 'jsonb':
+                    return JSON.parse(value.val);
+                case 'timestamp':
+                    return fromDateParts(value.val);
+                case 'timestamptz':
+                    return fromDateParts(value.val.timestamp, value.val.offset);
+                case 'uuid':
+                    return formatUuid(value.val);
+                case 'enumeration':
+                    return value.val.value;
+                case 'array':
+                    return value.val.map((item) => DIALECTS.postgres.fromDbValue(item.get()));
+                case 'composite':
+                    return value.val.values.map((item) => DIALECTS.postgres.fromDbValue(item.get()));
+                case 'domain':
+                    return DIALECTS.postgres.fromDbValue(value.val.value.get());
+                default:
+                    return value.val;
+            }
+        },
+    },
+    mysql: {
+        placeholder: () => '?',
+        toDbValue(value) {
+            if (value instanceof TypedValue) {
+                return { tag: value.tag, val: value.val };
+            }
+            if (value === null || value === undefined) {
+                return { tag: 'null' };
+            }
+            switch (typeof value) {
+                case 'boolean':
+                    return { tag: 'boolean', val: value };
+                case 'number':
+                    if (Number.isInteger(value)) {
+                        return value >= INT4_MIN && value <= INT4_MAX
+                            ? { tag: 'int', val: value }
+                            : { tag: 'bigint', val: BigInt(value) };
+                    }
+                    return { tag: 'double', val: value };
+                case 'bigint':
+                    return { tag: 'bigint', val: value };
+                case 'string':
+                    return { tag: 'varchar', val: value };
+            }
+            if (value instanceof Date) {
+                return { tag: 'timestamp', val: toDateParts(value) };
+            }
+            if (isBytes(value)) {
+                return { tag: 'varbinary', val: toBytes(value) };
+            }
+            return { tag: 'json', val: JSON.stringify(value) };
+        },
+        fromDbValue(value) {
+            switch (value.tag) {
+                case 'null':
+                    return null;
+                case 'json':
+                    return JSON.parse(value.val);
+                case 'datetime':
+                case 'timestamp':
+                    return fromDateParts(value.val);
+                default:
+                    return value.val;
+            }
+        },
+    },
+};
+
+// Builds the statement and the parameters of a query given as a tagged template, or as a
+// statement string and an array of parameters
+function statement(dialect, strings, values) {
+    if (typeof strings === 'string') {
+        return [strings, (values[0] ?? []).map((value) => dialect.toDbValue(value))];
+    }
+    let text = strings[0];
+    for (let index = 0; index < values.length; index++) {
+        text += dialect.placeholder(index) + strings[index + 1];
+    }
+    return [text, values.map((value) => dialect.toDbValue(value))];
+}
+
+function toRows(dialect, columns, rows) {
+    return rows.map((row) => {
+        const result = {};
+        columns.forEach((column, index) => {
+            result[column.name] = dialect.fromDbValue(row.values[index]);
+        });
+        return result;
+    });
+}
+
+// The queries shared by connections and transactions, running on the `target` host resource
+class Queryable {
+    #dialect;
+    #target;
+
+    constructor(dialect, target) {
+        this.#dialect = dialect;
+        this.#target = target;
+    }
+
+    async query(strings, ...values) {
+        const [text, params] = statement(this.#dialect, strings, values);
+        const result = call('run the query', () => this.#target.query(text, params));
+        return toRows(this.#dialect, result.columns, result.rows);
+    }
+
+    async execute(strings, ...values) {
+        const [text, params] = statement(this.#dialect, strings, values);
+        return Number(call('execute the statement', () => this.#target.execute(text, params)));
+    }
+
+    async* stream(strings, ...values) {
+        const [text, params] = statement(this.#dialect, strings, values);
+        const stream = call('run the query', () => this.#target.queryStream(text, params));
+        try {
+            const columns = call('read the columns', () => stream.getColumns());
+            for (;;) {
+                const rows = call('read the rows', () => stream.getNext());
+                if (rows === undefined) {
+                    break;
+                }
+                yield* toRows(this.#dialect, columns, rows);
+            }
+        } finally {
+            stream[Symbol.dispose]();
+        }
+    }
+}
+
+export class Transaction extends Queryable {
+    #transaction;
+
+    constructor(dialect, transaction) {
+        super(dialect, transaction);
+        this.#transaction = transaction;
+    }
+
+    async commit() {
+        call('commit the transaction', () => this.#transaction.commit());
+    }
+
+    async rollback() {
+        call('roll back the transaction', () => this.#transaction.rollback());
+    }
+}
+
+export class Database extends Queryable {
+    #dialect;
+    #connection;
+
+    constructor(dialect, connection) {
+        super(dialect, connection);
+        this.#dialect = dialect;
+        this.#connection = connection;
+    }
+
+    // Runs `f` in a transaction, committing it if `f` succeeds and rolling it back if it throws
+    async transaction(f) {
+        const transaction = new Transaction(
+            this.#dialect,
+            call('begin the transaction', () => this.#connection.beginTransaction()),
+        );
+        let result;
+        try {
+            result = await f(transaction);
+        } catch (error) {
+            await transaction.rollback();
+            throw error;
+        }
+        await transaction.commit();
+        return result;
+    }
+
+    close() {
+        this.#connection[Symbol.dispose]();
+    }
+}
+
+function dialectOf(address) {
+    const scheme = address.slice(0, address.indexOf(':')).toLowerCase();
+    switch (scheme) {
+        case 'postgres':
+        case 'postgresql':
+            return 'postgres';
+        case 'mysql':
+            return 'mysql';
+        default:
+            throw new DatabaseError(`Unsupported database address scheme: ${scheme}`);
+    }
+}
+
+export async function connect(address, options) {
+    const name = options?.dialect ?? dialectOf(address);
+    const moduleName = { postgres: POSTGRES_MODULE, mysql: MYSQL_MODULE }[name];
+    if (moduleName === undefined) {
+        throw new DatabaseError(`Unsupported database: ${name}`);
+    }
+    if (moduleName === '') {
+        throw new DatabaseError(`Connecting to ${name} requires the world to import golem:rdbms/${name}`);
+    }
+    const module = await import(moduleName);
+    const connection = call(`connect to ${name}`, () => module.DbConnection.open(address));
+    return new Database(DIALECTS[name], connection);
+}
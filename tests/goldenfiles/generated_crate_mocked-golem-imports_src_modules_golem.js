@@ -0,0 +1,53 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:mocked-golem-imports/mocked-golem-imports
+// wit: sha256:e2a5c934071279725dae2fd75e09aa4ebe6992aaedf7cac0976199427144664e
+// js mocked-golem-imports: sha256:1505fb2c5e8bcc2584584e3f366a93497be44e32b3a6e7d2f5bd49771d3ff4b2
+
+import * as host from 'golem:api/host@1.1.7';
+
+// Runs `f`, and runs `cleanup` when it finished - if `f` returns a promise, after it settled
+function withCleanup(f, cleanup) {
+    let result;
+    try {
+        result = f();
+    } catch (error) {
+        cleanup();
+        throw error;
+    }
+    if (result instanceof Promise) {
+        return result.finally(cleanup);
+    }
+    cleanup();
+    return result;
+}
+
+export function atomically(f) {
+    const begin = host.markBeginOperation();
+    return withCleanup(f, () => host.markEndOperation(begin));
+}
+
+export function withIdempotenceMode(idempotent, f) {
+    const original = host.getIdempotenceMode();
+    host.setIdempotenceMode(idempotent);
+    return withCleanup(f, () => host.setIdempotenceMode(original));
+}
+
+export function withRetryPolicy(policy, f) {
+    const original = host.getRetryPolicy();
+    host.setRetryPolicy(policy);
+    return withCleanup(f, () => host.setRetryPolicy(original));
+}
+
+export function withPersistenceLevel(level, f) {
+    const original = host.getOplogPersistenceLevel();
+    host.setOplogPersistenceLevel(typeof level === 'string' ? { tag: level } : level);
+    return withCleanup(f, () => host.setOplogPersistenceLevel(original));
+}
+
+export function generateIdempotencyKey() {
+    return host.generateIdempotencyKey();
+}
+
+export function oplogCommit(replicas) {
+    host.oplogCommit(replicas);
+}
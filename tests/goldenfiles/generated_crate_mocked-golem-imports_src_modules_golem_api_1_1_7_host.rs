@@ -0,0 +1,992 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:mocked-golem-imports/mocked-golem-imports
+// wit: sha256:e2a5c934071279725dae2fd75e09aa4ebe6992aaedf7cac0976199427144664e
+// js mocked-golem-imports: sha256:1505fb2c5e8bcc2584584e3f366a93497be44e32b3a6e7d2f5bd49771d3ff4b2
+
+use rquickjs::JsLifetime;
+use rquickjs::class::{JsClass, Trace};
+use std::ops::Deref;
+#[rquickjs::function]
+fn create_promise() -> crate::bindings::golem::api::host::PromiseId {
+    let result: crate::bindings::golem::api::host::PromiseId = crate::bindings::golem::api::host::create_promise();
+    result
+}
+#[rquickjs::function]
+fn get_promise(
+    promise_id: crate::bindings::golem::api::host::PromiseId,
+) -> crate::bindings::golem::api::host::GetPromiseResult {
+    let result: crate::bindings::golem::api::host::GetPromiseResult = crate::bindings::golem::api::host::get_promise(
+        &promise_id,
+    );
+    result
+}
+#[rquickjs::function]
+fn complete_promise(
+    promise_id: crate::bindings::golem::api::host::PromiseId,
+    data: crate::wrappers::UInt8Array,
+) -> bool {
+    let result: bool = crate::bindings::golem::api::host::complete_promise(
+        &promise_id,
+        data.0.as_slice(),
+    );
+    result
+}
+#[rquickjs::function]
+fn get_oplog_index() -> crate::wrappers::BigIntWrapper<u64> {
+    let result: crate::bindings::golem::api::host::OplogIndex = crate::bindings::golem::api::host::get_oplog_index();
+    crate::wrappers::BigIntWrapper(result)
+}
+#[rquickjs::function]
+fn set_oplog_index(oplog_idx: crate::wrappers::BigIntWrapper<u64>) -> () {
+    let result: () = crate::bindings::golem::api::host::set_oplog_index(oplog_idx.0);
+    result
+}
+#[rquickjs::function]
+fn oplog_commit(replicas: u8) -> () {
+    let result: () = crate::bindings::golem::api::host::oplog_commit(replicas);
+    result
+}
+#[rquickjs::function]
+fn mark_begin_operation() -> crate::wrappers::BigIntWrapper<u64> {
+    let result: crate::bindings::golem::api::host::OplogIndex = crate::bindings::golem::api::host::mark_begin_operation();
+    crate::wrappers::BigIntWrapper(result)
+}
+#[rquickjs::function]
+fn mark_end_operation(begin: crate::wrappers::BigIntWrapper<u64>) -> () {
+    let result: () = crate::bindings::golem::api::host::mark_end_operation(begin.0);
+    result
+}
+#[rquickjs::function]
+fn get_retry_policy() -> crate::bindings::golem::api::host::RetryPolicy {
+    let result: crate::bindings::golem::api::host::RetryPolicy = crate::bindings::golem::api::host::get_retry_policy();
+    result
+}
+#[rquickjs::function]
+fn set_retry_policy(
+    new_retry_policy: crate::bindings::golem::api::host::RetryPolicy,
+) -> () {
+    let result: () = crate::bindings::golem::api::host::set_retry_policy(
+        new_retry_policy,
+    );
+    result
+}
+#[rquickjs::function]
+fn get_oplog_persistence_level() -> crate::bindings::golem::api::host::PersistenceLevel {
+    let result: crate::bindings::golem::api::host::PersistenceLevel = crate::bindings::golem::api::host::get_oplog_persistence_level();
+    result
+}
+#[rquickjs::function]
+fn set_oplog_persistence_level(
+    new_persistence_level: crate::bindings::golem::api::host::PersistenceLevel,
+) -> () {
+    let result: () = crate::bindings::golem::api::host::set_oplog_persistence_level(
+        new_persistence_level,
+    );
+    result
+}
+#[rquickjs::function]
+fn get_idempotence_mode() -> bool {
+    let result: bool = crate::bindings::golem::api::host::get_idempotence_mode();
+    result
+}
+#[rquickjs::function]
+fn set_idempotence_mode(idempotent: bool) -> () {
+    let result: () = crate::bindings::golem::api::host::set_idempotence_mode(idempotent);
+    result
+}
+#[rquickjs::function]
+fn generate_idempotency_key() -> crate::bindings::golem::rpc::types::Uuid {
+    let result: crate::bindings::golem::api::host::Uuid = crate::bindings::golem::api::host::generate_idempotency_key();
+    result
+}
+#[rquickjs::function]
+fn update_worker(
+    worker_id: crate::bindings::golem::rpc::types::WorkerId,
+    target_version: crate::wrappers::BigIntWrapper<u64>,
+    mode: crate::bindings::golem::api::host::UpdateMode,
+) -> () {
+    let result: () = crate::bindings::golem::api::host::update_worker(
+        &worker_id,
+        target_version.0,
+        mode,
+    );
+    result
+}
+#[rquickjs::function]
+fn get_self_metadata() -> crate::bindings::golem::api::host::WorkerMetadata {
+    let result: crate::bindings::golem::api::host::WorkerMetadata = crate::bindings::golem::api::host::get_self_metadata();
+    result
+}
+#[rquickjs::function]
+fn get_worker_metadata(
+    worker_id: crate::bindings::golem::rpc::types::WorkerId,
+) -> Option<crate::bindings::golem::api::host::WorkerMetadata> {
+    let result: Option<crate::bindings::golem::api::host::WorkerMetadata> = crate::bindings::golem::api::host::get_worker_metadata(
+        &worker_id,
+    );
+    result.map(|v| v)
+}
+#[rquickjs::function]
+fn fork_worker(
+    source_worker_id: crate::bindings::golem::rpc::types::WorkerId,
+    target_worker_id: crate::bindings::golem::rpc::types::WorkerId,
+    oplog_idx_cut_off: crate::wrappers::BigIntWrapper<u64>,
+) -> () {
+    let result: () = crate::bindings::golem::api::host::fork_worker(
+        &source_worker_id,
+        &target_worker_id,
+        oplog_idx_cut_off.0,
+    );
+    result
+}
+#[rquickjs::function]
+fn revert_worker(
+    worker_id: crate::bindings::golem::rpc::types::WorkerId,
+    revert_target: crate::bindings::golem::api::host::RevertWorkerTarget,
+) -> () {
+    let result: () = crate::bindings::golem::api::host::revert_worker(
+        &worker_id,
+        revert_target,
+    );
+    result
+}
+#[rquickjs::function]
+fn resolve_component_id(
+    component_reference: crate::wrappers::JsString,
+) -> Option<crate::bindings::golem::rpc::types::ComponentId> {
+    let result: Option<crate::bindings::golem::api::host::ComponentId> = crate::bindings::golem::api::host::resolve_component_id(
+        component_reference.0.as_str(),
+    );
+    result.map(|v| v)
+}
+#[rquickjs::function]
+fn resolve_worker_id(
+    component_reference: crate::wrappers::JsString,
+    worker_name: crate::wrappers::JsString,
+) -> Option<crate::bindings::golem::rpc::types::WorkerId> {
+    let result: Option<crate::bindings::golem::api::host::WorkerId> = crate::bindings::golem::api::host::resolve_worker_id(
+        component_reference.0.as_str(),
+        worker_name.0.as_str(),
+    );
+    result.map(|v| v)
+}
+#[rquickjs::function]
+fn resolve_worker_id_strict(
+    component_reference: crate::wrappers::JsString,
+    worker_name: crate::wrappers::JsString,
+) -> Option<crate::bindings::golem::rpc::types::WorkerId> {
+    let result: Option<crate::bindings::golem::api::host::WorkerId> = crate::bindings::golem::api::host::resolve_worker_id_strict(
+        component_reference.0.as_str(),
+        worker_name.0.as_str(),
+    );
+    result.map(|v| v)
+}
+#[rquickjs::function]
+fn fork(
+    new_name: crate::wrappers::JsString,
+) -> crate::bindings::golem::api::host::ForkResult {
+    let result: crate::bindings::golem::api::host::ForkResult = crate::bindings::golem::api::host::fork(
+        new_name.0.as_str(),
+    );
+    result
+}
+#[derive(Clone, JsLifetime, Trace)]
+pub struct GetWorkers {
+    #[qjs(skip_trace = true)]
+    inner: Option<std::rc::Rc<crate::bindings::golem::api::host::GetWorkers>>,
+    /// The export call epoch if the handle is only borrowed from the host
+    #[qjs(skip_trace = true)]
+    borrow_epoch: Option<usize>,
+}
+mod __impl_class_get_workers_ {
+    pub use super::*;
+    use rquickjs::{Atom, Symbol, Value};
+    impl<'js> rquickjs::class::JsClass<'js> for GetWorkers {
+        const NAME: &'static str = "GetWorkers";
+        type Mutable = rquickjs::class::Writable;
+        fn prototype(
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<Option<rquickjs::Object<'js>>> {
+            use rquickjs::class::impl_::MethodImplementor;
+            let proto = rquickjs::Object::new(ctx.clone())?;
+            let implementor = rquickjs::class::impl_::MethodImpl::<Self>::new();
+            (&implementor).implement(&proto)?;
+            let dispose_symbol: Symbol = ctx
+                .globals()
+                .get(crate::internal::DISPOSE_SYMBOL)?;
+            let dispose_fn: Value = proto.get("__dispose")?;
+            proto.set(dispose_symbol, dispose_fn)?;
+            Ok(Some(proto))
+        }
+        fn constructor(
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<Option<rquickjs::function::Constructor<'js>>> {
+            use rquickjs::class::impl_::ConstructorCreator;
+            let implementor = rquickjs::class::impl_::ConstructorCreate::<Self>::new();
+            (&implementor).create_constructor(ctx)
+        }
+    }
+    impl<'js> rquickjs::IntoJs<'js> for GetWorkers {
+        fn into_js(
+            self,
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<rquickjs::Value<'js>> {
+            let cls = rquickjs::class::Class::<Self>::instance(ctx.clone(), self)?;
+            rquickjs::IntoJs::into_js(cls, ctx)
+        }
+    }
+    impl<'js> rquickjs::FromJs<'js> for GetWorkers
+    where
+        for<'a> rquickjs::class::impl_::CloneWrapper<
+            'a,
+            Self,
+        >: rquickjs::class::impl_::CloneTrait<Self>,
+    {
+        fn from_js(
+            ctx: &rquickjs::Ctx<'js>,
+            value: rquickjs::Value<'js>,
+        ) -> rquickjs::Result<Self> {
+            use rquickjs::class::impl_::CloneTrait;
+            let value = rquickjs::class::Class::<Self>::from_js(ctx, value)?;
+            let borrow = value.try_borrow()?;
+            Ok(rquickjs::class::impl_::CloneWrapper(&*borrow).wrap_clone())
+        }
+    }
+}
+impl GetWorkers {
+    /// Gets the wrapped resource, failing if it has been disposed or if it is a
+    /// borrowed handle and the call it was passed to has already returned
+    fn checked_inner(
+        &self,
+    ) -> Result<
+        &std::rc::Rc<crate::bindings::golem::api::host::GetWorkers>,
+        &'static str,
+    > {
+        if self
+            .borrow_epoch
+            .is_some_and(|epoch| !crate::internal::is_borrow_active(epoch))
+        {
+            return Err(
+                "Borrowed resource handle cannot be used after the call it was passed to has returned",
+            );
+        }
+        self.inner.as_ref().ok_or("Resource has already been disposed")
+    }
+    /// Gets the wrapped resource for calling one of its methods, throwing a
+    /// `ReferenceError` if it cannot be used
+    fn resource(
+        &self,
+        ctx: &rquickjs::Ctx<'_>,
+    ) -> rquickjs::Result<&crate::bindings::golem::api::host::GetWorkers> {
+        self.checked_inner()
+            .map(|inner| inner.deref())
+            .map_err(|message| rquickjs::Exception::throw_reference(ctx, message))
+    }
+    /// Releases the wrapped resource. Borrowed handles belong to the host, so they
+    /// are never dropped, only forgotten.
+    fn release(&mut self) {
+        if let Some(inner) = self.inner.take() {
+            if self.borrow_epoch.is_some() {
+                if let Ok(inner) = std::rc::Rc::try_unwrap(inner) {
+                    let _ = inner.take_handle();
+                }
+            }
+        }
+    }
+}
+impl Drop for GetWorkers {
+    fn drop(&mut self) {
+        self.release();
+    }
+}
+#[rquickjs::methods(rename_all = "camelCase")]
+impl GetWorkers {
+    #[qjs(constructor)]
+    pub fn new(
+        component_id: crate::bindings::golem::rpc::types::ComponentId,
+        filter: Option<crate::bindings::golem::api::host::WorkerAnyFilter>,
+        precise: bool,
+    ) -> Self {
+        Self {
+            inner: Some(
+                std::rc::Rc::new(
+                    crate::bindings::golem::api::host::GetWorkers::new(
+                        component_id,
+                        filter.map(|v| v).as_ref(),
+                        precise,
+                    ),
+                ),
+            ),
+            borrow_epoch: None,
+        }
+    }
+    pub fn get_next(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+    ) -> rquickjs::Result<
+        Option<
+            crate::wrappers::JsList<crate::bindings::golem::api::host::WorkerMetadata>,
+        >,
+    > {
+        let result: Option<Vec<crate::bindings::golem::api::host::WorkerMetadata>> = self
+            .resource(&ctx)?
+            .get_next();
+        Ok(
+            result
+                .map(|v| crate::wrappers::JsList(
+                    v.into_iter().map(|v| v).collect::<Vec<_>>(),
+                )),
+        )
+    }
+    #[qjs(rename = "__dispose")]
+    pub fn __dispose(&mut self) {
+        self.release();
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::api::host::GetWorkers {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        GetWorkers {
+            inner: Some(std::rc::Rc::new(self)),
+            borrow_epoch: None,
+        }
+            .into_js(ctx)
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::api::host::GetWorkers {
+    fn from_js(
+        ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let wrapper = GetWorkers::from_js(ctx, value)?;
+        if wrapper.borrow_epoch.is_some() {
+            return Err(rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "GetWorkers",
+                message: Some(
+                    "The ownership of a borrowed resource handle cannot be transferred"
+                        .to_string(),
+                ),
+            });
+        }
+        let inner = wrapper
+            .checked_inner()
+            .map_err(|message| rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "GetWorkers",
+                message: Some(message.to_string()),
+            })?;
+        unsafe {
+            Ok(
+                crate::bindings::golem::api::host::GetWorkers::from_handle(
+                    inner.take_handle(),
+                ),
+            )
+        }
+    }
+}
+pub struct BorrowGetWorkersWrapper(pub crate::bindings::golem::api::host::GetWorkers);
+impl<'js> rquickjs::FromJs<'js> for BorrowGetWorkersWrapper {
+    fn from_js(
+        ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let wrapper = GetWorkers::from_js(ctx, value)?;
+        let inner = wrapper
+            .checked_inner()
+            .map_err(|message| rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "GetWorkers",
+                message: Some(message.to_string()),
+            })?;
+        unsafe {
+            Ok(
+                BorrowGetWorkersWrapper(
+                    crate::bindings::golem::api::host::GetWorkers::from_handle(
+                        inner.handle(),
+                    ),
+                ),
+            )
+        }
+    }
+}
+impl BorrowGetWorkersWrapper {
+    /// Borrows a handle passed to an exported function, without taking its ownership
+    pub fn from_ref(resource: &crate::bindings::golem::api::host::GetWorkers) -> Self {
+        unsafe {
+            BorrowGetWorkersWrapper(
+                crate::bindings::golem::api::host::GetWorkers::from_handle(
+                    resource.handle(),
+                ),
+            )
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for BorrowGetWorkersWrapper {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let resource = unsafe {
+            crate::bindings::golem::api::host::GetWorkers::from_handle(
+                self.0.take_handle(),
+            )
+        };
+        GetWorkers {
+            inner: Some(std::rc::Rc::new(resource)),
+            borrow_epoch: Some(crate::internal::current_borrow_epoch()),
+        }
+            .into_js(ctx)
+    }
+}
+impl Drop for BorrowGetWorkersWrapper {
+    fn drop(&mut self) {
+        let _ = self.0.take_handle();
+    }
+}
+#[derive(Clone, JsLifetime, Trace)]
+pub struct GetPromiseResult {
+    #[qjs(skip_trace = true)]
+    inner: Option<std::rc::Rc<crate::bindings::golem::api::host::GetPromiseResult>>,
+    /// The export call epoch if the handle is only borrowed from the host
+    #[qjs(skip_trace = true)]
+    borrow_epoch: Option<usize>,
+}
+mod __impl_class_get_promise_result_ {
+    pub use super::*;
+    use rquickjs::{Atom, Symbol, Value};
+    impl<'js> rquickjs::class::JsClass<'js> for GetPromiseResult {
+        const NAME: &'static str = "GetPromiseResult";
+        type Mutable = rquickjs::class::Writable;
+        fn prototype(
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<Option<rquickjs::Object<'js>>> {
+            use rquickjs::class::impl_::MethodImplementor;
+            let proto = rquickjs::Object::new(ctx.clone())?;
+            let implementor = rquickjs::class::impl_::MethodImpl::<Self>::new();
+            (&implementor).implement(&proto)?;
+            let dispose_symbol: Symbol = ctx
+                .globals()
+                .get(crate::internal::DISPOSE_SYMBOL)?;
+            let dispose_fn: Value = proto.get("__dispose")?;
+            proto.set(dispose_symbol, dispose_fn)?;
+            Ok(Some(proto))
+        }
+        fn constructor(
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<Option<rquickjs::function::Constructor<'js>>> {
+            use rquickjs::class::impl_::ConstructorCreator;
+            let implementor = rquickjs::class::impl_::ConstructorCreate::<Self>::new();
+            (&implementor).create_constructor(ctx)
+        }
+    }
+    impl<'js> rquickjs::IntoJs<'js> for GetPromiseResult {
+        fn into_js(
+            self,
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<rquickjs::Value<'js>> {
+            let cls = rquickjs::class::Class::<Self>::instance(ctx.clone(), self)?;
+            rquickjs::IntoJs::into_js(cls, ctx)
+        }
+    }
+    impl<'js> rquickjs::FromJs<'js> for GetPromiseResult
+    where
+        for<'a> rquickjs::class::impl_::CloneWrapper<
+            'a,
+            Self,
+        >: rquickjs::class::impl_::CloneTrait<Self>,
+    {
+        fn from_js(
+            ctx: &rquickjs::Ctx<'js>,
+            value: rquickjs::Value<'js>,
+        ) -> rquickjs::Result<Self> {
+            use rquickjs::class::impl_::CloneTrait;
+            let value = rquickjs::class::Class::<Self>::from_js(ctx, value)?;
+            let borrow = value.try_borrow()?;
+            Ok(rquickjs::class::impl_::CloneWrapper(&*borrow).wrap_clone())
+        }
+    }
+}
+impl GetPromiseResult {
+    /// Gets the wrapped resource, failing if it has been disposed or if it is a
+    /// borrowed handle and the call it was passed to has already returned
+    fn checked_inner(
+        &self,
+    ) -> Result<
+        &std::rc::Rc<crate::bindings::golem::api::host::GetPromiseResult>,
+        &'static str,
+    > {
+        if self
+            .borrow_epoch
+            .is_some_and(|epoch| !crate::internal::is_borrow_active(epoch))
+        {
+            return Err(
+                "Borrowed resource handle cannot be used after the call it was passed to has returned",
+            );
+        }
+        self.inner.as_ref().ok_or("Resource has already been disposed")
+    }
+    /// Gets the wrapped resource for calling one of its methods, throwing a
+    /// `ReferenceError` if it cannot be used
+    fn resource(
+        &self,
+        ctx: &rquickjs::Ctx<'_>,
+    ) -> rquickjs::Result<&crate::bindings::golem::api::host::GetPromiseResult> {
+        self.checked_inner()
+            .map(|inner| inner.deref())
+            .map_err(|message| rquickjs::Exception::throw_reference(ctx, message))
+    }
+    /// Releases the wrapped resource. Borrowed handles belong to the host, so they
+    /// are never dropped, only forgotten.
+    fn release(&mut self) {
+        if let Some(inner) = self.inner.take() {
+            if self.borrow_epoch.is_some() {
+                if let Ok(inner) = std::rc::Rc::try_unwrap(inner) {
+                    let _ = inner.take_handle();
+                }
+            }
+        }
+    }
+}
+impl Drop for GetPromiseResult {
+    fn drop(&mut self) {
+        self.release();
+    }
+}
+#[rquickjs::methods(rename_all = "camelCase")]
+impl GetPromiseResult {
+    #[qjs(constructor)]
+    pub fn new() -> Self {
+        Self {
+            inner: None,
+            borrow_epoch: None,
+        }
+    }
+    pub fn subscribe(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+    ) -> rquickjs::Result<crate::bindings::golem::api::host::Pollable> {
+        let result: crate::bindings::golem::api::host::Pollable = self
+            .resource(&ctx)?
+            .subscribe();
+        Ok(result)
+    }
+    pub fn get(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+    ) -> rquickjs::Result<Option<crate::wrappers::UInt8Array>> {
+        let result: Option<Vec<u8>> = self.resource(&ctx)?.get();
+        Ok(result.map(|v| crate::wrappers::UInt8Array(v)))
+    }
+    #[qjs(rename = "__dispose")]
+    pub fn __dispose(&mut self) {
+        self.release();
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::api::host::GetPromiseResult {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        GetPromiseResult {
+            inner: Some(std::rc::Rc::new(self)),
+            borrow_epoch: None,
+        }
+            .into_js(ctx)
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::api::host::GetPromiseResult {
+    fn from_js(
+        ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let wrapper = GetPromiseResult::from_js(ctx, value)?;
+        if wrapper.borrow_epoch.is_some() {
+            return Err(rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "GetPromiseResult",
+                message: Some(
+                    "The ownership of a borrowed resource handle cannot be transferred"
+                        .to_string(),
+                ),
+            });
+        }
+        let inner = wrapper
+            .checked_inner()
+            .map_err(|message| rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "GetPromiseResult",
+                message: Some(message.to_string()),
+            })?;
+        unsafe {
+            Ok(
+                crate::bindings::golem::api::host::GetPromiseResult::from_handle(
+                    inner.take_handle(),
+                ),
+            )
+        }
+    }
+}
+pub struct BorrowGetPromiseResultWrapper(
+    pub crate::bindings::golem::api::host::GetPromiseResult,
+);
+impl<'js> rquickjs::FromJs<'js> for BorrowGetPromiseResultWrapper {
+    fn from_js(
+        ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let wrapper = GetPromiseResult::from_js(ctx, value)?;
+        let inner = wrapper
+            .checked_inner()
+            .map_err(|message| rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "GetPromiseResult",
+                message: Some(message.to_string()),
+            })?;
+        unsafe {
+            Ok(
+                BorrowGetPromiseResultWrapper(
+                    crate::bindings::golem::api::host::GetPromiseResult::from_handle(
+                        inner.handle(),
+                    ),
+                ),
+            )
+        }
+    }
+}
+impl BorrowGetPromiseResultWrapper {
+    /// Borrows a handle passed to an exported function, without taking its ownership
+    pub fn from_ref(
+        resource: &crate::bindings::golem::api::host::GetPromiseResult,
+    ) -> Self {
+        unsafe {
+            BorrowGetPromiseResultWrapper(
+                crate::bindings::golem::api::host::GetPromiseResult::from_handle(
+                    resource.handle(),
+                ),
+            )
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for BorrowGetPromiseResultWrapper {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let resource = unsafe {
+            crate::bindings::golem::api::host::GetPromiseResult::from_handle(
+                self.0.take_handle(),
+            )
+        };
+        GetPromiseResult {
+            inner: Some(std::rc::Rc::new(resource)),
+            borrow_epoch: Some(crate::internal::current_borrow_epoch()),
+        }
+            .into_js(ctx)
+    }
+}
+impl Drop for BorrowGetPromiseResultWrapper {
+    fn drop(&mut self) {
+        let _ = self.0.take_handle();
+    }
+}
+pub struct JsHostModule;
+impl rquickjs::module::ModuleDef for JsHostModule {
+    fn declare(decl: &rquickjs::module::Declarations) -> rquickjs::Result<()> {
+        decl.declare("createPromise")?;
+        decl.declare("getPromise")?;
+        decl.declare("completePromise")?;
+        decl.declare("getOplogIndex")?;
+        decl.declare("setOplogIndex")?;
+        decl.declare("oplogCommit")?;
+        decl.declare("markBeginOperation")?;
+        decl.declare("markEndOperation")?;
+        decl.declare("getRetryPolicy")?;
+        decl.declare("setRetryPolicy")?;
+        decl.declare("getOplogPersistenceLevel")?;
+        decl.declare("setOplogPersistenceLevel")?;
+        decl.declare("getIdempotenceMode")?;
+        decl.declare("setIdempotenceMode")?;
+        decl.declare("generateIdempotencyKey")?;
+        decl.declare("updateWorker")?;
+        decl.declare("getSelfMetadata")?;
+        decl.declare("getWorkerMetadata")?;
+        decl.declare("forkWorker")?;
+        decl.declare("revertWorker")?;
+        decl.declare("resolveComponentId")?;
+        decl.declare("resolveWorkerId")?;
+        decl.declare("resolveWorkerIdStrict")?;
+        decl.declare("fork")?;
+        decl.declare("GetWorkers")?;
+        decl.declare("GetPromiseResult")?;
+        Ok(())
+    }
+    fn evaluate<'js>(
+        ctx: &rquickjs::Ctx<'js>,
+        exports: &rquickjs::module::Exports<'js>,
+    ) -> rquickjs::Result<()> {
+        exports
+            .export(
+                "createPromise",
+                crate::builtin::mock_import_function(
+                    ctx,
+                    "golem:api/host@1.1.7",
+                    "createPromise",
+                    js_create_promise,
+                )?,
+            )?;
+        exports
+            .export(
+                "getPromise",
+                crate::builtin::mock_import_function(
+                    ctx,
+                    "golem:api/host@1.1.7",
+                    "getPromise",
+                    js_get_promise,
+                )?,
+            )?;
+        exports
+            .export(
+                "completePromise",
+                crate::builtin::mock_import_function(
+                    ctx,
+                    "golem:api/host@1.1.7",
+                    "completePromise",
+                    js_complete_promise,
+                )?,
+            )?;
+        exports
+            .export(
+                "getOplogIndex",
+                crate::builtin::mock_import_function(
+                    ctx,
+                    "golem:api/host@1.1.7",
+                    "getOplogIndex",
+                    js_get_oplog_index,
+                )?,
+            )?;
+        exports
+            .export(
+                "setOplogIndex",
+                crate::builtin::mock_import_function(
+                    ctx,
+                    "golem:api/host@1.1.7",
+                    "setOplogIndex",
+                    js_set_oplog_index,
+                )?,
+            )?;
+        exports
+            .export(
+                "oplogCommit",
+                crate::builtin::mock_import_function(
+                    ctx,
+                    "golem:api/host@1.1.7",
+                    "oplogCommit",
+                    js_oplog_commit,
+                )?,
+            )?;
+        exports
+            .export(
+                "markBeginOperation",
+                crate::builtin::mock_import_function(
+                    ctx,
+                    "golem:api/host@1.1.7",
+                    "markBeginOperation",
+                    js_mark_begin_operation,
+                )?,
+            )?;
+        exports
+            .export(
+                "markEndOperation",
+                crate::builtin::mock_import_function(
+                    ctx,
+                    "golem:api/host@1.1.7",
+                    "markEndOperation",
+                    js_mark_end_operation,
+                )?,
+            )?;
+        exports
+            .export(
+                "getRetryPolicy",
+                crate::builtin::mock_import_function(
+                    ctx,
+                    "golem:api/host@1.1.7",
+                    "getRetryPolicy",
+                    js_get_retry_policy,
+                )?,
+            )?;
+        exports
+            .export(
+                "setRetryPolicy",
+                crate::builtin::mock_import_function(
+                    ctx,
+                    "golem:api/host@1.1.7",
+                    "setRetryPolicy",
+                    js_set_retry_policy,
+                )?,
+            )?;
+        exports
+            .export(
+                "getOplogPersistenceLevel",
+                crate::builtin::mock_import_function(
+                    ctx,
+                    "golem:api/host@1.1.7",
+                    "getOplogPersistenceLevel",
+                    js_get_oplog_persistence_level,
+                )?,
+            )?;
+        exports
+            .export(
+                "setOplogPersistenceLevel",
+                crate::builtin::mock_import_function(
+                    ctx,
+                    "golem:api/host@1.1.7",
+                    "setOplogPersistenceLevel",
+                    js_set_oplog_persistence_level,
+                )?,
+            )?;
+        exports
+            .export(
+                "getIdempotenceMode",
+                crate::builtin::mock_import_function(
+                    ctx,
+                    "golem:api/host@1.1.7",
+                    "getIdempotenceMode",
+                    js_get_idempotence_mode,
+                )?,
+            )?;
+        exports
+            .export(
+                "setIdempotenceMode",
+                crate::builtin::mock_import_function(
+                    ctx,
+                    "golem:api/host@1.1.7",
+                    "setIdempotenceMode",
+                    js_set_idempotence_mode,
+                )?,
+            )?;
+        exports
+            .export(
+                "generateIdempotencyKey",
+                crate::builtin::mock_import_function(
+                    ctx,
+                    "golem:api/host@1.1.7",
+                    "generateIdempotencyKey",
+                    js_generate_idempotency_key,
+                )?,
+            )?;
+        exports
+            .export(
+                "updateWorker",
+                crate::builtin::mock_import_function(
+                    ctx,
+                    "golem:api/host@1.1.7",
+                    "updateWorker",
+                    js_update_worker,
+                )?,
+            )?;
+        exports
+            .export(
+                "getSelfMetadata",
+                crate::builtin::mock_import_function(
+                    ctx,
+                    "golem:api/host@1.1.7",
+                    "getSelfMetadata",
+                    js_get_self_metadata,
+                )?,
+            )?;
+        exports
+            .export(
+                "getWorkerMetadata",
+                crate::builtin::mock_import_function(
+                    ctx,
+                    "golem:api/host@1.1.7",
+                    "getWorkerMetadata",
+                    js_get_worker_metadata,
+                )?,
+            )?;
+        exports
+            .export(
+                "forkWorker",
+                crate::builtin::mock_import_function(
+                    ctx,
+                    "golem:api/host@1.1.7",
+                    "forkWorker",
+                    js_fork_worker,
+                )?,
+            )?;
+        exports
+            .export(
+                "revertWorker",
+                crate::builtin::mock_import_function(
+                    ctx,
+                    "golem:api/host@1.1.7",
+                    "revertWorker",
+                    js_revert_worker,
+                )?,
+            )?;
+        exports
+            .export(
+                "resolveComponentId",
+                crate::builtin::mock_import_function(
+                    ctx,
+                    "golem:api/host@1.1.7",
+                    "resolveComponentId",
+                    js_resolve_component_id,
+                )?,
+            )?;
+        exports
+            .export(
+                "resolveWorkerId",
+                crate::builtin::mock_import_function(
+                    ctx,
+                    "golem:api/host@1.1.7",
+                    "resolveWorkerId",
+                    js_resolve_worker_id,
+                )?,
+            )?;
+        exports
+            .export(
+                "resolveWorkerIdStrict",
+                crate::builtin::mock_import_function(
+                    ctx,
+                    "golem:api/host@1.1.7",
+                    "resolveWorkerIdStrict",
+                    js_resolve_worker_id_strict,
+                )?,
+            )?;
+        exports
+            .export(
+                "fork",
+                crate::builtin::mock_import_function(
+                    ctx,
+                    "golem:api/host@1.1.7",
+                    "fork",
+                    js_fork,
+                )?,
+            )?;
+        exports
+            .export(
+                "GetWorkers",
+                crate::builtin::mock_import_class(
+                    ctx,
+                    "golem:api/host@1.1.7",
+                    "GetWorkers",
+                    GetWorkers::constructor(ctx)?,
+                )?,
+            )?;
+        exports
+            .export(
+                "GetPromiseResult",
+                crate::builtin::mock_import_class(
+                    ctx,
+                    "golem:api/host@1.1.7",
+                    "GetPromiseResult",
+                    GetPromiseResult::constructor(ctx)?,
+                )?,
+            )?;
+        Ok(())
+    }
+}
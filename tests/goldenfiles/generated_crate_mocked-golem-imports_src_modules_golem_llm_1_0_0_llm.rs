@@ -0,0 +1,364 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:mocked-golem-imports/mocked-golem-imports
+// wit: sha256:e2a5c934071279725dae2fd75e09aa4ebe6992aaedf7cac0976199427144664e
+// js mocked-golem-imports: sha256:1505fb2c5e8bcc2584584e3f366a93497be44e32b3a6e7d2f5bd49771d3ff4b2
+
+use rquickjs::JsLifetime;
+use rquickjs::class::{JsClass, Trace};
+use std::ops::Deref;
+#[rquickjs::function]
+fn send(
+    messages: crate::wrappers::JsList<crate::bindings::golem::llm::llm::Message>,
+    config: crate::bindings::golem::llm::llm::Config,
+) -> crate::bindings::golem::llm::llm::ChatEvent {
+    let result: crate::bindings::golem::llm::llm::ChatEvent = crate::bindings::golem::llm::llm::send(
+        messages.0.into_iter().map(|v| v).collect::<Vec<_>>().as_slice(),
+        &config,
+    );
+    result
+}
+#[rquickjs::function]
+fn continue_(
+    messages: crate::wrappers::JsList<crate::bindings::golem::llm::llm::Message>,
+    tool_results: crate::wrappers::JsList<
+        rquickjs::convert::List<
+            (
+                crate::bindings::golem::llm::llm::ToolCall,
+                crate::bindings::golem::llm::llm::ToolResult,
+            ),
+        >,
+    >,
+    config: crate::bindings::golem::llm::llm::Config,
+) -> crate::bindings::golem::llm::llm::ChatEvent {
+    let result: crate::bindings::golem::llm::llm::ChatEvent = crate::bindings::golem::llm::llm::continue_(
+        messages.0.into_iter().map(|v| v).collect::<Vec<_>>().as_slice(),
+        tool_results
+            .0
+            .into_iter()
+            .map(|v| (v.0.0, v.0.1))
+            .collect::<Vec<_>>()
+            .as_slice(),
+        &config,
+    );
+    result
+}
+#[rquickjs::function]
+fn stream(
+    messages: crate::wrappers::JsList<crate::bindings::golem::llm::llm::Message>,
+    config: crate::bindings::golem::llm::llm::Config,
+) -> crate::bindings::golem::llm::llm::ChatStream {
+    let result: crate::bindings::golem::llm::llm::ChatStream = crate::bindings::golem::llm::llm::stream(
+        messages.0.into_iter().map(|v| v).collect::<Vec<_>>().as_slice(),
+        &config,
+    );
+    result
+}
+#[derive(Clone, JsLifetime, Trace)]
+pub struct ChatStream {
+    #[qjs(skip_trace = true)]
+    inner: Option<std::rc::Rc<crate::bindings::golem::llm::llm::ChatStream>>,
+    /// The export call epoch if the handle is only borrowed from the host
+    #[qjs(skip_trace = true)]
+    borrow_epoch: Option<usize>,
+}
+mod __impl_class_chat_stream_ {
+    pub use super::*;
+    use rquickjs::{Atom, Symbol, Value};
+    impl<'js> rquickjs::class::JsClass<'js> for ChatStream {
+        const NAME: &'static str = "ChatStream";
+        type Mutable = rquickjs::class::Writable;
+        fn prototype(
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<Option<rquickjs::Object<'js>>> {
+            use rquickjs::class::impl_::MethodImplementor;
+            let proto = rquickjs::Object::new(ctx.clone())?;
+            let implementor = rquickjs::class::impl_::MethodImpl::<Self>::new();
+            (&implementor).implement(&proto)?;
+            let dispose_symbol: Symbol = ctx
+                .globals()
+                .get(crate::internal::DISPOSE_SYMBOL)?;
+            let dispose_fn: Value = proto.get("__dispose")?;
+            proto.set(dispose_symbol, dispose_fn)?;
+            Ok(Some(proto))
+        }
+        fn constructor(
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<Option<rquickjs::function::Constructor<'js>>> {
+            use rquickjs::class::impl_::ConstructorCreator;
+            let implementor = rquickjs::class::impl_::ConstructorCreate::<Self>::new();
+            (&implementor).create_constructor(ctx)
+        }
+    }
+    impl<'js> rquickjs::IntoJs<'js> for ChatStream {
+        fn into_js(
+            self,
+            ctx: &rquickjs::Ctx<'js>,
+        ) -> rquickjs::Result<rquickjs::Value<'js>> {
+            let cls = rquickjs::class::Class::<Self>::instance(ctx.clone(), self)?;
+            rquickjs::IntoJs::into_js(cls, ctx)
+        }
+    }
+    impl<'js> rquickjs::FromJs<'js> for ChatStream
+    where
+        for<'a> rquickjs::class::impl_::CloneWrapper<
+            'a,
+            Self,
+        >: rquickjs::class::impl_::CloneTrait<Self>,
+    {
+        fn from_js(
+            ctx: &rquickjs::Ctx<'js>,
+            value: rquickjs::Value<'js>,
+        ) -> rquickjs::Result<Self> {
+            use rquickjs::class::impl_::CloneTrait;
+            let value = rquickjs::class::Class::<Self>::from_js(ctx, value)?;
+            let borrow = value.try_borrow()?;
+            Ok(rquickjs::class::impl_::CloneWrapper(&*borrow).wrap_clone())
+        }
+    }
+}
+impl ChatStream {
+    /// Gets the wrapped resource, failing if it has been disposed or if it is a
+    /// borrowed handle and the call it was passed to has already returned
+    fn checked_inner(
+        &self,
+    ) -> Result<
+        &std::rc::Rc<crate::bindings::golem::llm::llm::ChatStream>,
+        &'static str,
+    > {
+        if self
+            .borrow_epoch
+            .is_some_and(|epoch| !crate::internal::is_borrow_active(epoch))
+        {
+            return Err(
+                "Borrowed resource handle cannot be used after the call it was passed to has returned",
+            );
+        }
+        self.inner.as_ref().ok_or("Resource has already been disposed")
+    }
+    /// Gets the wrapped resource for calling one of its methods, throwing a
+    /// `ReferenceError` if it cannot be used
+    fn resource(
+        &self,
+        ctx: &rquickjs::Ctx<'_>,
+    ) -> rquickjs::Result<&crate::bindings::golem::llm::llm::ChatStream> {
+        self.checked_inner()
+            .map(|inner| inner.deref())
+            .map_err(|message| rquickjs::Exception::throw_reference(ctx, message))
+    }
+    /// Releases the wrapped resource. Borrowed handles belong to the host, so they
+    /// are never dropped, only forgotten.
+    fn release(&mut self) {
+        if let Some(inner) = self.inner.take() {
+            if self.borrow_epoch.is_some() {
+                if let Ok(inner) = std::rc::Rc::try_unwrap(inner) {
+                    let _ = inner.take_handle();
+                }
+            }
+        }
+    }
+}
+impl Drop for ChatStream {
+    fn drop(&mut self) {
+        self.release();
+    }
+}
+#[rquickjs::methods(rename_all = "camelCase")]
+impl ChatStream {
+    #[qjs(constructor)]
+    pub fn new() -> Self {
+        Self {
+            inner: None,
+            borrow_epoch: None,
+        }
+    }
+    pub fn get_next(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+    ) -> rquickjs::Result<
+        Option<crate::wrappers::JsList<crate::bindings::golem::llm::llm::StreamEvent>>,
+    > {
+        let result: Option<Vec<crate::bindings::golem::llm::llm::StreamEvent>> = self
+            .resource(&ctx)?
+            .get_next();
+        Ok(
+            result
+                .map(|v| crate::wrappers::JsList(
+                    v.into_iter().map(|v| v).collect::<Vec<_>>(),
+                )),
+        )
+    }
+    pub fn blocking_get_next(
+        &self,
+        ctx: rquickjs::Ctx<'_>,
+    ) -> rquickjs::Result<
+        crate::wrappers::JsList<crate::bindings::golem::llm::llm::StreamEvent>,
+    > {
+        let result: Vec<crate::bindings::golem::llm::llm::StreamEvent> = self
+            .resource(&ctx)?
+            .blocking_get_next();
+        Ok(crate::wrappers::JsList(result.into_iter().map(|v| v).collect::<Vec<_>>()))
+    }
+    #[qjs(rename = "__dispose")]
+    pub fn __dispose(&mut self) {
+        self.release();
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::golem::llm::llm::ChatStream {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        ChatStream {
+            inner: Some(std::rc::Rc::new(self)),
+            borrow_epoch: None,
+        }
+            .into_js(ctx)
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::golem::llm::llm::ChatStream {
+    fn from_js(
+        ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let wrapper = ChatStream::from_js(ctx, value)?;
+        if wrapper.borrow_epoch.is_some() {
+            return Err(rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "ChatStream",
+                message: Some(
+                    "The ownership of a borrowed resource handle cannot be transferred"
+                        .to_string(),
+                ),
+            });
+        }
+        let inner = wrapper
+            .checked_inner()
+            .map_err(|message| rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "ChatStream",
+                message: Some(message.to_string()),
+            })?;
+        unsafe {
+            Ok(
+                crate::bindings::golem::llm::llm::ChatStream::from_handle(
+                    inner.take_handle(),
+                ),
+            )
+        }
+    }
+}
+pub struct BorrowChatStreamWrapper(pub crate::bindings::golem::llm::llm::ChatStream);
+impl<'js> rquickjs::FromJs<'js> for BorrowChatStreamWrapper {
+    fn from_js(
+        ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let wrapper = ChatStream::from_js(ctx, value)?;
+        let inner = wrapper
+            .checked_inner()
+            .map_err(|message| rquickjs::Error::FromJs {
+                from: "JavaScript object",
+                to: "ChatStream",
+                message: Some(message.to_string()),
+            })?;
+        unsafe {
+            Ok(
+                BorrowChatStreamWrapper(
+                    crate::bindings::golem::llm::llm::ChatStream::from_handle(
+                        inner.handle(),
+                    ),
+                ),
+            )
+        }
+    }
+}
+impl BorrowChatStreamWrapper {
+    /// Borrows a handle passed to an exported function, without taking its ownership
+    pub fn from_ref(resource: &crate::bindings::golem::llm::llm::ChatStream) -> Self {
+        unsafe {
+            BorrowChatStreamWrapper(
+                crate::bindings::golem::llm::llm::ChatStream::from_handle(
+                    resource.handle(),
+                ),
+            )
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for BorrowChatStreamWrapper {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let resource = unsafe {
+            crate::bindings::golem::llm::llm::ChatStream::from_handle(
+                self.0.take_handle(),
+            )
+        };
+        ChatStream {
+            inner: Some(std::rc::Rc::new(resource)),
+            borrow_epoch: Some(crate::internal::current_borrow_epoch()),
+        }
+            .into_js(ctx)
+    }
+}
+impl Drop for BorrowChatStreamWrapper {
+    fn drop(&mut self) {
+        let _ = self.0.take_handle();
+    }
+}
+pub struct JsLlmModule;
+impl rquickjs::module::ModuleDef for JsLlmModule {
+    fn declare(decl: &rquickjs::module::Declarations) -> rquickjs::Result<()> {
+        decl.declare("send")?;
+        decl.declare("continue_")?;
+        decl.declare("stream")?;
+        decl.declare("ChatStream")?;
+        Ok(())
+    }
+    fn evaluate<'js>(
+        ctx: &rquickjs::Ctx<'js>,
+        exports: &rquickjs::module::Exports<'js>,
+    ) -> rquickjs::Result<()> {
+        exports
+            .export(
+                "send",
+                crate::builtin::mock_import_function(
+                    ctx,
+                    "golem:llm/llm@1.0.0",
+                    "send",
+                    js_send,
+                )?,
+            )?;
+        exports
+            .export(
+                "continue_",
+                crate::builtin::mock_import_function(
+                    ctx,
+                    "golem:llm/llm@1.0.0",
+                    "continue_",
+                    js_continue_,
+                )?,
+            )?;
+        exports
+            .export(
+                "stream",
+                crate::builtin::mock_import_function(
+                    ctx,
+                    "golem:llm/llm@1.0.0",
+                    "stream",
+                    js_stream,
+                )?,
+            )?;
+        exports
+            .export(
+                "ChatStream",
+                crate::builtin::mock_import_class(
+                    ctx,
+                    "golem:llm/llm@1.0.0",
+                    "ChatStream",
+                    ChatStream::constructor(ctx)?,
+                )?,
+            )?;
+        Ok(())
+    }
+}
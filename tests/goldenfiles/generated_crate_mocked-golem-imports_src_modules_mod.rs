@@ -0,0 +1,169 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:mocked-golem-imports/mocked-golem-imports
+// wit: sha256:e2a5c934071279725dae2fd75e09aa4ebe6992aaedf7cac0976199427144664e
+// js mocked-golem-imports: sha256:1505fb2c5e8bcc2584584e3f366a93497be44e32b3a6e7d2f5bd49771d3ff4b2
+
+use rquickjs::JsLifetime;
+use rquickjs::class::{JsClass, Trace};
+use std::ops::Deref;
+pub mod golem_rdbms_0_0_1_types;
+pub mod golem_rdbms_0_0_1_postgres;
+pub mod wasi_io_0_2_3_error;
+pub mod wasi_io_0_2_3_poll;
+pub mod wasi_io_0_2_3_streams;
+pub mod wasi_blobstore_types;
+pub mod wasi_blobstore_container;
+pub mod wasi_blobstore_blobstore;
+pub mod golem_llm_1_0_0_llm;
+pub mod wasi_clocks_0_2_3_monotonic_clock;
+pub mod wasi_clocks_0_2_3_wall_clock;
+pub mod golem_rpc_0_2_2_types;
+pub mod golem_api_1_1_7_host;
+const NATIVE_MODULE_NAMES: &[&str] = &[
+    "golem:rdbms/types@0.0.1",
+    "golem:rdbms/postgres@0.0.1",
+    "wasi:io/error@0.2.3",
+    "wasi:io/poll@0.2.3",
+    "wasi:io/streams@0.2.3",
+    "wasi:blobstore/types",
+    "wasi:blobstore/container",
+    "wasi:blobstore/blobstore",
+    "golem:llm/llm@1.0.0",
+    "wasi:clocks/monotonic-clock@0.2.3",
+    "wasi:clocks/wall-clock@0.2.3",
+    "golem:rpc/types@0.2.2",
+    "golem:api/host@1.1.7",
+    "golem",
+    "blobstore",
+    "db",
+    "llm",
+];
+pub struct NativeModuleResolver;
+impl rquickjs::loader::Resolver for NativeModuleResolver {
+    fn resolve<'js>(
+        &mut self,
+        _ctx: &rquickjs::Ctx<'js>,
+        base: &str,
+        name: &str,
+    ) -> rquickjs::Result<String> {
+        if NATIVE_MODULE_NAMES.contains(&name) {
+            Ok(name.to_string())
+        } else {
+            Err(rquickjs::Error::new_resolving(base, name))
+        }
+    }
+}
+pub struct NativeModuleLoader;
+impl rquickjs::loader::Loader for NativeModuleLoader {
+    fn load<'js>(
+        &mut self,
+        ctx: &rquickjs::Ctx<'js>,
+        name: &str,
+    ) -> rquickjs::Result<rquickjs::Module<'js, rquickjs::module::Declared>> {
+        match name {
+            "golem:rdbms/types@0.0.1" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::golem_rdbms_0_0_1_types::JsTypesModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "golem:rdbms/postgres@0.0.1" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::golem_rdbms_0_0_1_postgres::JsPostgresModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "wasi:io/error@0.2.3" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::wasi_io_0_2_3_error::JsErrorModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "wasi:io/poll@0.2.3" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::wasi_io_0_2_3_poll::JsPollModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "wasi:io/streams@0.2.3" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::wasi_io_0_2_3_streams::JsStreamsModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "wasi:blobstore/types" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::wasi_blobstore_types::JsTypesModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "wasi:blobstore/container" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::wasi_blobstore_container::JsContainerModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "wasi:blobstore/blobstore" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::wasi_blobstore_blobstore::JsBlobstoreModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "golem:llm/llm@1.0.0" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::golem_llm_1_0_0_llm::JsLlmModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "wasi:clocks/monotonic-clock@0.2.3" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::wasi_clocks_0_2_3_monotonic_clock::JsMonotonicClockModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "wasi:clocks/wall-clock@0.2.3" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::wasi_clocks_0_2_3_wall_clock::JsWallClockModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "golem:rpc/types@0.2.2" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::golem_rpc_0_2_2_types::JsTypesModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "golem:api/host@1.1.7" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::golem_api_1_1_7_host::JsHostModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "golem" => {
+                rquickjs::Module::declare(ctx.clone(), name, include_str!("golem.js"))
+            }
+            "blobstore" => {
+                rquickjs::Module::declare(
+                    ctx.clone(),
+                    name,
+                    include_str!("blobstore.js"),
+                )
+            }
+            "db" => rquickjs::Module::declare(ctx.clone(), name, include_str!("db.js")),
+            "llm" => rquickjs::Module::declare(ctx.clone(), name, include_str!("llm.js")),
+            _ => Err(rquickjs::Error::new_loading(name)),
+        }
+    }
+}
+pub struct JsMockedGolemImportsModule;
+impl rquickjs::module::ModuleDef for JsMockedGolemImportsModule {
+    fn declare(decl: &rquickjs::module::Declarations) -> rquickjs::Result<()> {
+        Ok(())
+    }
+    fn evaluate<'js>(
+        ctx: &rquickjs::Ctx<'js>,
+        exports: &rquickjs::module::Exports<'js>,
+    ) -> rquickjs::Result<()> {
+        Ok(())
+    }
+}
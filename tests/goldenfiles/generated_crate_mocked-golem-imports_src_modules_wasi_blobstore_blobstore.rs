@@ -0,0 +1,188 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:mocked-golem-imports/mocked-golem-imports
+// wit: sha256:e2a5c934071279725dae2fd75e09aa4ebe6992aaedf7cac0976199427144664e
+// js mocked-golem-imports: sha256:1505fb2c5e8bcc2584584e3f366a93497be44e32b3a6e7d2f5bd49771d3ff4b2
+
+use rquickjs::JsLifetime;
+use rquickjs::class::{JsClass, Trace};
+use std::ops::Deref;
+#[rquickjs::function]
+fn create_container(
+    ctx: rquickjs::Ctx<'_>,
+    name: crate::wrappers::JsString,
+) -> rquickjs::Result<crate::bindings::wasi::blobstore::blobstore::Container> {
+    let result: Result<
+        crate::bindings::wasi::blobstore::blobstore::Container,
+        crate::bindings::wasi::blobstore::blobstore::Error,
+    > = crate::bindings::wasi::blobstore::blobstore::create_container(name.0.as_str());
+    match result {
+        Ok(result) => Ok(result),
+        Err(error) => {
+            let error: crate::wrappers::JsString = crate::wrappers::JsString(error);
+            Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+        }
+    }
+}
+#[rquickjs::function]
+fn get_container(
+    ctx: rquickjs::Ctx<'_>,
+    name: crate::wrappers::JsString,
+) -> rquickjs::Result<crate::bindings::wasi::blobstore::blobstore::Container> {
+    let result: Result<
+        crate::bindings::wasi::blobstore::blobstore::Container,
+        crate::bindings::wasi::blobstore::blobstore::Error,
+    > = crate::bindings::wasi::blobstore::blobstore::get_container(name.0.as_str());
+    match result {
+        Ok(result) => Ok(result),
+        Err(error) => {
+            let error: crate::wrappers::JsString = crate::wrappers::JsString(error);
+            Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+        }
+    }
+}
+#[rquickjs::function]
+fn delete_container(
+    ctx: rquickjs::Ctx<'_>,
+    name: crate::wrappers::JsString,
+) -> rquickjs::Result<()> {
+    let result: Result<(), crate::bindings::wasi::blobstore::blobstore::Error> = crate::bindings::wasi::blobstore::blobstore::delete_container(
+        name.0.as_str(),
+    );
+    match result {
+        Ok(result) => Ok(result),
+        Err(error) => {
+            let error: crate::wrappers::JsString = crate::wrappers::JsString(error);
+            Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+        }
+    }
+}
+#[rquickjs::function]
+fn container_exists(
+    ctx: rquickjs::Ctx<'_>,
+    name: crate::wrappers::JsString,
+) -> rquickjs::Result<bool> {
+    let result: Result<bool, crate::bindings::wasi::blobstore::blobstore::Error> = crate::bindings::wasi::blobstore::blobstore::container_exists(
+        name.0.as_str(),
+    );
+    match result {
+        Ok(result) => Ok(result),
+        Err(error) => {
+            let error: crate::wrappers::JsString = crate::wrappers::JsString(error);
+            Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+        }
+    }
+}
+#[rquickjs::function]
+fn copy_object(
+    ctx: rquickjs::Ctx<'_>,
+    src: crate::bindings::wasi::blobstore::types::ObjectId,
+    dest: crate::bindings::wasi::blobstore::types::ObjectId,
+) -> rquickjs::Result<()> {
+    let result: Result<(), crate::bindings::wasi::blobstore::blobstore::Error> = crate::bindings::wasi::blobstore::blobstore::copy_object(
+        &src,
+        &dest,
+    );
+    match result {
+        Ok(result) => Ok(result),
+        Err(error) => {
+            let error: crate::wrappers::JsString = crate::wrappers::JsString(error);
+            Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+        }
+    }
+}
+#[rquickjs::function]
+fn move_object(
+    ctx: rquickjs::Ctx<'_>,
+    src: crate::bindings::wasi::blobstore::types::ObjectId,
+    dest: crate::bindings::wasi::blobstore::types::ObjectId,
+) -> rquickjs::Result<()> {
+    let result: Result<(), crate::bindings::wasi::blobstore::blobstore::Error> = crate::bindings::wasi::blobstore::blobstore::move_object(
+        &src,
+        &dest,
+    );
+    match result {
+        Ok(result) => Ok(result),
+        Err(error) => {
+            let error: crate::wrappers::JsString = crate::wrappers::JsString(error);
+            Err(ctx.throw(rquickjs::IntoJs::into_js(error, &ctx)?))
+        }
+    }
+}
+pub struct JsBlobstoreModule;
+impl rquickjs::module::ModuleDef for JsBlobstoreModule {
+    fn declare(decl: &rquickjs::module::Declarations) -> rquickjs::Result<()> {
+        decl.declare("createContainer")?;
+        decl.declare("getContainer")?;
+        decl.declare("deleteContainer")?;
+        decl.declare("containerExists")?;
+        decl.declare("copyObject")?;
+        decl.declare("moveObject")?;
+        Ok(())
+    }
+    fn evaluate<'js>(
+        ctx: &rquickjs::Ctx<'js>,
+        exports: &rquickjs::module::Exports<'js>,
+    ) -> rquickjs::Result<()> {
+        exports
+            .export(
+                "createContainer",
+                crate::builtin::mock_import_function(
+                    ctx,
+                    "wasi:blobstore/blobstore",
+                    "createContainer",
+                    js_create_container,
+                )?,
+            )?;
+        exports
+            .export(
+                "getContainer",
+                crate::builtin::mock_import_function(
+                    ctx,
+                    "wasi:blobstore/blobstore",
+                    "getContainer",
+                    js_get_container,
+                )?,
+            )?;
+        exports
+            .export(
+                "deleteContainer",
+                crate::builtin::mock_import_function(
+                    ctx,
+                    "wasi:blobstore/blobstore",
+                    "deleteContainer",
+                    js_delete_container,
+                )?,
+            )?;
+        exports
+            .export(
+                "containerExists",
+                crate::builtin::mock_import_function(
+                    ctx,
+                    "wasi:blobstore/blobstore",
+                    "containerExists",
+                    js_container_exists,
+                )?,
+            )?;
+        exports
+            .export(
+                "copyObject",
+                crate::builtin::mock_import_function(
+                    ctx,
+                    "wasi:blobstore/blobstore",
+                    "copyObject",
+                    js_copy_object,
+                )?,
+            )?;
+        exports
+            .export(
+                "moveObject",
+                crate::builtin::mock_import_function(
+                    ctx,
+                    "wasi:blobstore/blobstore",
+                    "moveObject",
+                    js_move_object,
+                )?,
+            )?;
+        Ok(())
+    }
+}
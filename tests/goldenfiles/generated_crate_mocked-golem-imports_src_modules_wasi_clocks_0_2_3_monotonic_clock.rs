@@ -0,0 +1,92 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:mocked-golem-imports/mocked-golem-imports
+// wit: sha256:e2a5c934071279725dae2fd75e09aa4ebe6992aaedf7cac0976199427144664e
+// js mocked-golem-imports: sha256:1505fb2c5e8bcc2584584e3f366a93497be44e32b3a6e7d2f5bd49771d3ff4b2
+
+use rquickjs::JsLifetime;
+use rquickjs::class::{JsClass, Trace};
+use std::ops::Deref;
+#[rquickjs::function]
+fn now() -> crate::wrappers::BigIntWrapper<u64> {
+    let result: crate::bindings::wasi::clocks::monotonic_clock::Instant = crate::bindings::wasi::clocks::monotonic_clock::now();
+    crate::wrappers::BigIntWrapper(result)
+}
+#[rquickjs::function]
+fn resolution() -> crate::wrappers::BigIntWrapper<u64> {
+    let result: crate::bindings::wasi::clocks::monotonic_clock::Duration = crate::bindings::wasi::clocks::monotonic_clock::resolution();
+    crate::wrappers::BigIntWrapper(result)
+}
+#[rquickjs::function]
+fn subscribe_instant(
+    when: crate::wrappers::BigIntWrapper<u64>,
+) -> crate::bindings::wasi::clocks::monotonic_clock::Pollable {
+    let result: crate::bindings::wasi::clocks::monotonic_clock::Pollable = crate::bindings::wasi::clocks::monotonic_clock::subscribe_instant(
+        when.0,
+    );
+    result
+}
+#[rquickjs::function]
+fn subscribe_duration(
+    when: crate::wrappers::BigIntWrapper<u64>,
+) -> crate::bindings::wasi::clocks::monotonic_clock::Pollable {
+    let result: crate::bindings::wasi::clocks::monotonic_clock::Pollable = crate::bindings::wasi::clocks::monotonic_clock::subscribe_duration(
+        when.0,
+    );
+    result
+}
+pub struct JsMonotonicClockModule;
+impl rquickjs::module::ModuleDef for JsMonotonicClockModule {
+    fn declare(decl: &rquickjs::module::Declarations) -> rquickjs::Result<()> {
+        decl.declare("now")?;
+        decl.declare("resolution")?;
+        decl.declare("subscribeInstant")?;
+        decl.declare("subscribeDuration")?;
+        Ok(())
+    }
+    fn evaluate<'js>(
+        ctx: &rquickjs::Ctx<'js>,
+        exports: &rquickjs::module::Exports<'js>,
+    ) -> rquickjs::Result<()> {
+        exports
+            .export(
+                "now",
+                crate::builtin::mock_import_function(
+                    ctx,
+                    "wasi:clocks/monotonic-clock@0.2.3",
+                    "now",
+                    js_now,
+                )?,
+            )?;
+        exports
+            .export(
+                "resolution",
+                crate::builtin::mock_import_function(
+                    ctx,
+                    "wasi:clocks/monotonic-clock@0.2.3",
+                    "resolution",
+                    js_resolution,
+                )?,
+            )?;
+        exports
+            .export(
+                "subscribeInstant",
+                crate::builtin::mock_import_function(
+                    ctx,
+                    "wasi:clocks/monotonic-clock@0.2.3",
+                    "subscribeInstant",
+                    js_subscribe_instant,
+                )?,
+            )?;
+        exports
+            .export(
+                "subscribeDuration",
+                crate::builtin::mock_import_function(
+                    ctx,
+                    "wasi:clocks/monotonic-clock@0.2.3",
+                    "subscribeDuration",
+                    js_subscribe_duration,
+                )?,
+            )?;
+        Ok(())
+    }
+}
@@ -0,0 +1,52 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:mocked-golem-imports/mocked-golem-imports
+// wit: sha256:e2a5c934071279725dae2fd75e09aa4ebe6992aaedf7cac0976199427144664e
+// js mocked-golem-imports: sha256:1505fb2c5e8bcc2584584e3f366a93497be44e32b3a6e7d2f5bd49771d3ff4b2
+
+use rquickjs::JsLifetime;
+use rquickjs::class::{JsClass, Trace};
+use std::ops::Deref;
+#[rquickjs::function]
+fn now() -> crate::bindings::wasi::clocks::wall_clock::Datetime {
+    let result: crate::bindings::wasi::clocks::wall_clock::Datetime = crate::bindings::wasi::clocks::wall_clock::now();
+    result
+}
+#[rquickjs::function]
+fn resolution() -> crate::bindings::wasi::clocks::wall_clock::Datetime {
+    let result: crate::bindings::wasi::clocks::wall_clock::Datetime = crate::bindings::wasi::clocks::wall_clock::resolution();
+    result
+}
+pub struct JsWallClockModule;
+impl rquickjs::module::ModuleDef for JsWallClockModule {
+    fn declare(decl: &rquickjs::module::Declarations) -> rquickjs::Result<()> {
+        decl.declare("now")?;
+        decl.declare("resolution")?;
+        Ok(())
+    }
+    fn evaluate<'js>(
+        ctx: &rquickjs::Ctx<'js>,
+        exports: &rquickjs::module::Exports<'js>,
+    ) -> rquickjs::Result<()> {
+        exports
+            .export(
+                "now",
+                crate::builtin::mock_import_function(
+                    ctx,
+                    "wasi:clocks/wall-clock@0.2.3",
+                    "now",
+                    js_now,
+                )?,
+            )?;
+        exports
+            .export(
+                "resolution",
+                crate::builtin::mock_import_function(
+                    ctx,
+                    "wasi:clocks/wall-clock@0.2.3",
+                    "resolution",
+                    js_resolution,
+                )?,
+            )?;
+        Ok(())
+    }
+}
@@ -0,0 +1,5 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:mocked-imports/mocked-imports
+// wit: sha256:354e129e1104f0ffb11dee061b8439069cbca221ddb2c4be9d89a6cda724656f
+// js mocked-imports: sha256:e51b5032a23f488f23afe3ad9f983a358523f003edbef58c5a552b13de6d960d
+
@@ -0,0 +1,54 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:mocked-imports/mocked-imports
+// wit: sha256:354e129e1104f0ffb11dee061b8439069cbca221ddb2c4be9d89a6cda724656f
+// js mocked-imports: sha256:e51b5032a23f488f23afe3ad9f983a358523f003edbef58c5a552b13de6d960d
+
+use rquickjs::JsLifetime;
+use rquickjs::class::{JsClass, Trace};
+use std::ops::Deref;
+#[rquickjs::function]
+fn get_random_bytes(
+    len: crate::wrappers::BigIntWrapper<u64>,
+) -> crate::wrappers::UInt8Array {
+    let result: Vec<u8> = crate::bindings::wasi::random::random::get_random_bytes(len.0);
+    crate::wrappers::UInt8Array(result)
+}
+#[rquickjs::function]
+fn get_random_u64() -> crate::wrappers::BigIntWrapper<u64> {
+    let result: u64 = crate::bindings::wasi::random::random::get_random_u64();
+    crate::wrappers::BigIntWrapper(result)
+}
+pub struct JsRandomModule;
+impl rquickjs::module::ModuleDef for JsRandomModule {
+    fn declare(decl: &rquickjs::module::Declarations) -> rquickjs::Result<()> {
+        decl.declare("getRandomBytes")?;
+        decl.declare("getRandomU64")?;
+        Ok(())
+    }
+    fn evaluate<'js>(
+        ctx: &rquickjs::Ctx<'js>,
+        exports: &rquickjs::module::Exports<'js>,
+    ) -> rquickjs::Result<()> {
+        exports
+            .export(
+                "getRandomBytes",
+                crate::builtin::mock_import_function(
+                    ctx,
+                    "wasi:random/random@0.2.3",
+                    "getRandomBytes",
+                    js_get_random_bytes,
+                )?,
+            )?;
+        exports
+            .export(
+                "getRandomU64",
+                crate::builtin::mock_import_function(
+                    ctx,
+                    "wasi:random/random@0.2.3",
+                    "getRandomU64",
+                    js_get_random_u64,
+                )?,
+            )?;
+        Ok(())
+    }
+}
@@ -0,0 +1,8 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:module-paths/module-paths
+// wit: sha256:795d0edfde5da205ce1d5c0af07505a42843fcb549e93b8db5dbc0401cdec982
+// js lib/format: sha256:e99bb34dd19cb9f885a9cf6b7c1ba1c8a50f05d052cca8be6f72d3d5e2f4bf6a
+// js lib/utils: sha256:7724c6337c64b252bdc167cda9c6eaab35ebba24cb413db0bfd5c3328d9cdad0
+// js shared/constants: sha256:1c363db9d4585cc408421df9b2654f6a8af784eb490e3e335c487135abbed167
+// js module-paths: sha256:1bfe32ab8fbfc8f624de27df53682ea367acc16a250e1d7ed80ec5d5b080221f
+
@@ -0,0 +1,91 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:module-paths/module-paths
+// wit: sha256:795d0edfde5da205ce1d5c0af07505a42843fcb549e93b8db5dbc0401cdec982
+// js lib/format: sha256:e99bb34dd19cb9f885a9cf6b7c1ba1c8a50f05d052cca8be6f72d3d5e2f4bf6a
+// js lib/utils: sha256:7724c6337c64b252bdc167cda9c6eaab35ebba24cb413db0bfd5c3328d9cdad0
+// js shared/constants: sha256:1c363db9d4585cc408421df9b2654f6a8af784eb490e3e335c487135abbed167
+// js module-paths: sha256:1bfe32ab8fbfc8f624de27df53682ea367acc16a250e1d7ed80ec5d5b080221f
+
+#[allow(static_mut_refs)]
+#[allow(unsafe_op_in_unsafe_fn)]
+mod bindings;
+mod assets;
+mod builtin;
+mod conversions;
+#[allow(unused)]
+mod internal;
+#[allow(unused)]
+mod modules;
+mod wrappers;
+static JS_EXPORT_MODULE_NAME: &str = "module-paths";
+static JS_EXPORT_NAMES_VERBATIM: bool = false;
+static INTERN_FIELD_NAMES: bool = false;
+#[allow(dead_code)]
+static REPLACE_LONE_SURROGATES: bool = false;
+static MATH_RANDOM_SEED: crate::builtin::MathRandomSeed = crate::builtin::MathRandomSeed::Default;
+static DATE_CLOCK: Option<fn() -> f64> = None;
+static IMPORT_HOOKS: bool = false;
+static MOCK_IMPORTS: bool = false;
+static IMPORT_RECORDING: crate::builtin::ImportRecording = crate::builtin::ImportRecording::None;
+static CANCEL_BACKGROUND_WORK: bool = false;
+static HTTP_LIMITS: crate::builtin::HttpLimits = crate::builtin::HttpLimits::UNLIMITED;
+#[allow(dead_code)]
+static COOKIE_JAR: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE_LIMITS: crate::builtin::HttpCacheLimits = crate::builtin::HttpCacheLimits {
+    max_entries: Some(256usize),
+    max_size: Some(16777216usize),
+    max_body_size: Some(1048576usize),
+};
+#[allow(dead_code)]
+static FETCH_BASE_URL: Option<&str> = None;
+#[allow(dead_code)]
+static FETCH_GATEWAY: Option<&str> = None;
+static JS_EXPORT_MODULE: &str = include_str!("module-paths.js");
+static JS_ADDITIONAL_MODULES: std::sync::LazyLock<
+    Vec<(&str, Box<dyn (Fn() -> String) + Send + Sync>)>,
+> = std::sync::LazyLock::new(|| {
+    vec![
+        ("lib/format", Box::new(|| { include_str!("lib/format.js") .to_string() })),
+        ("lib/utils", Box::new(|| { include_str!("lib/utils.js") .to_string() })),
+        ("shared/constants", Box::new(|| { include_str!("shared/constants.js")
+        .to_string() }))
+    ]
+});
+static JS_MODULE_ORDER: &[&str] = &[
+    "lib/format",
+    "lib/utils",
+    "shared/constants",
+    "module-paths",
+];
+static JS_BUILTIN_PERMISSIONS: &[(&str, &[&str])] = &[];
+struct Component;
+impl crate::bindings::Guest for Component {
+    fn hello(name: String) -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:module-paths",
+                    0usize,
+                    &["hello"],
+                    (crate::wrappers::JsString(name),),
+                )
+                .await;
+            result.0
+        })
+    }
+    fn banner(text: String) -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:module-paths",
+                    1usize,
+                    &["banner"],
+                    (crate::wrappers::JsString(text),),
+                )
+                .await;
+            result.0
+        })
+    }
+}
+bindings::export!(Component with_types_in bindings);
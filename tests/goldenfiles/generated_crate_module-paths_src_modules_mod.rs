@@ -0,0 +1,51 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:module-paths/module-paths
+// wit: sha256:795d0edfde5da205ce1d5c0af07505a42843fcb549e93b8db5dbc0401cdec982
+// js lib/format: sha256:e99bb34dd19cb9f885a9cf6b7c1ba1c8a50f05d052cca8be6f72d3d5e2f4bf6a
+// js lib/utils: sha256:7724c6337c64b252bdc167cda9c6eaab35ebba24cb413db0bfd5c3328d9cdad0
+// js shared/constants: sha256:1c363db9d4585cc408421df9b2654f6a8af784eb490e3e335c487135abbed167
+// js module-paths: sha256:1bfe32ab8fbfc8f624de27df53682ea367acc16a250e1d7ed80ec5d5b080221f
+
+use rquickjs::JsLifetime;
+use rquickjs::class::{JsClass, Trace};
+use std::ops::Deref;
+const NATIVE_MODULE_NAMES: &[&str] = &[];
+pub struct NativeModuleResolver;
+impl rquickjs::loader::Resolver for NativeModuleResolver {
+    fn resolve<'js>(
+        &mut self,
+        _ctx: &rquickjs::Ctx<'js>,
+        base: &str,
+        name: &str,
+    ) -> rquickjs::Result<String> {
+        if NATIVE_MODULE_NAMES.contains(&name) {
+            Ok(name.to_string())
+        } else {
+            Err(rquickjs::Error::new_resolving(base, name))
+        }
+    }
+}
+pub struct NativeModuleLoader;
+impl rquickjs::loader::Loader for NativeModuleLoader {
+    fn load<'js>(
+        &mut self,
+        ctx: &rquickjs::Ctx<'js>,
+        name: &str,
+    ) -> rquickjs::Result<rquickjs::Module<'js, rquickjs::module::Declared>> {
+        match name {
+            _ => Err(rquickjs::Error::new_loading(name)),
+        }
+    }
+}
+pub struct JsModulePathsModule;
+impl rquickjs::module::ModuleDef for JsModulePathsModule {
+    fn declare(decl: &rquickjs::module::Declarations) -> rquickjs::Result<()> {
+        Ok(())
+    }
+    fn evaluate<'js>(
+        ctx: &rquickjs::Ctx<'js>,
+        exports: &rquickjs::module::Exports<'js>,
+    ) -> rquickjs::Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,6 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:partial-records/partial-records
+// wit: sha256:57a968ccc636cf583cf4c7a671b19deec4dcc1b7bfb5e6849a288bdfff5c75ae
+// js partial-records: sha256:234c13b1bafd18e8f408fbdc39acbaa638dab976004ec3bc70e2a74c7c557bde
+
+pub static ASSETS: &[(&str, &[u8])] = &[];
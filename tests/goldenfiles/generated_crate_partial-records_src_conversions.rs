@@ -0,0 +1,189 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:partial-records/partial-records
+// wit: sha256:57a968ccc636cf583cf4c7a671b19deec4dcc1b7bfb5e6849a288bdfff5c75ae
+// js partial-records: sha256:234c13b1bafd18e8f408fbdc39acbaa638dab976004ec3bc70e2a74c7c557bde
+
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::exports::quickjs::partial_records::api::Person {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("name", crate::wrappers::JsString(self.name))?;
+        obj.set::<_, u32>("age", self.age)?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >("nickname", self.nickname.map(|v| crate::wrappers::JsString(v)))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::exports::quickjs::partial_records::api::Person {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT person",
+            &["name", "age", "nickname"],
+            &[("name", "string"), ("age", "u32")],
+            false,
+        )?;
+        let name: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "name",
+            "string",
+        )?;
+        let name: String = name.0;
+        let age: u32 = crate::wrappers::get_field(&obj, "age", "u32")?;
+        let age: u32 = age;
+        let nickname: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "nickname",
+            "option<string>",
+        )?;
+        let nickname: Option<String> = nickname.map(|v| v.0);
+        Ok(Self { name, age, nickname })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::exports::quickjs::partial_records::api::Address {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("street", crate::wrappers::JsString(self.street))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("zip", crate::wrappers::JsString(self.zip))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::exports::quickjs::partial_records::api::Address {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT address",
+            &["street", "zip"],
+            &[("street", "string"), ("zip", "string")],
+            false,
+        )?;
+        let street: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "street",
+            "string",
+        )?;
+        let street: String = street.0;
+        let zip: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "zip",
+            "string",
+        )?;
+        let zip: String = zip.0;
+        Ok(Self { street, zip })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::exports::quickjs::partial_records::api::Customer {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("name", crate::wrappers::JsString(self.name))?;
+        obj.set::<
+                _,
+                crate::bindings::exports::quickjs::partial_records::api::Address,
+            >("address", self.address)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::exports::quickjs::partial_records::api::Customer {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT customer",
+            &["name", "address"],
+            &[("name", "string"), ("address", "address")],
+            false,
+        )?;
+        let name: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "name",
+            "string",
+        )?;
+        let name: String = name.0;
+        let address: crate::bindings::exports::quickjs::partial_records::api::Address = crate::wrappers::get_field(
+            &obj,
+            "address",
+            "address",
+        )?;
+        let address: crate::bindings::exports::quickjs::partial_records::api::Address = address;
+        Ok(Self { name, address })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::exports::quickjs::partial_records::api::Order {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<_, u32>("id", self.id)?;
+        obj.set::<
+                _,
+                crate::bindings::exports::quickjs::partial_records::api::Customer,
+            >("customer", self.customer)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::exports::quickjs::partial_records::api::Order {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT order",
+            &["id", "customer"],
+            &[("id", "u32"), ("customer", "customer")],
+            false,
+        )?;
+        let id: u32 = crate::wrappers::get_field(&obj, "id", "u32")?;
+        let id: u32 = id;
+        let customer: crate::bindings::exports::quickjs::partial_records::api::Customer = crate::wrappers::get_field(
+            &obj,
+            "customer",
+            "customer",
+        )?;
+        let customer: crate::bindings::exports::quickjs::partial_records::api::Customer = customer;
+        Ok(Self { id, customer })
+    }
+}
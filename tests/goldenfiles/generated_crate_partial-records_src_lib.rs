@@ -0,0 +1,104 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:partial-records/partial-records
+// wit: sha256:57a968ccc636cf583cf4c7a671b19deec4dcc1b7bfb5e6849a288bdfff5c75ae
+// js partial-records: sha256:234c13b1bafd18e8f408fbdc39acbaa638dab976004ec3bc70e2a74c7c557bde
+
+#[allow(static_mut_refs)]
+#[allow(unsafe_op_in_unsafe_fn)]
+mod bindings;
+mod assets;
+mod builtin;
+mod conversions;
+#[allow(unused)]
+mod internal;
+#[allow(unused)]
+mod modules;
+mod wrappers;
+static JS_EXPORT_MODULE_NAME: &str = "partial-records";
+static JS_EXPORT_NAMES_VERBATIM: bool = false;
+static INTERN_FIELD_NAMES: bool = false;
+#[allow(dead_code)]
+static REPLACE_LONE_SURROGATES: bool = false;
+static MATH_RANDOM_SEED: crate::builtin::MathRandomSeed = crate::builtin::MathRandomSeed::Default;
+static DATE_CLOCK: Option<fn() -> f64> = None;
+static IMPORT_HOOKS: bool = false;
+static MOCK_IMPORTS: bool = false;
+static IMPORT_RECORDING: crate::builtin::ImportRecording = crate::builtin::ImportRecording::None;
+static CANCEL_BACKGROUND_WORK: bool = false;
+static HTTP_LIMITS: crate::builtin::HttpLimits = crate::builtin::HttpLimits::UNLIMITED;
+#[allow(dead_code)]
+static COOKIE_JAR: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE_LIMITS: crate::builtin::HttpCacheLimits = crate::builtin::HttpCacheLimits {
+    max_entries: Some(256usize),
+    max_size: Some(16777216usize),
+    max_body_size: Some(1048576usize),
+};
+#[allow(dead_code)]
+static FETCH_BASE_URL: Option<&str> = None;
+#[allow(dead_code)]
+static FETCH_GATEWAY: Option<&str> = None;
+static JS_EXPORT_MODULE: &str = include_str!("partial-records.js");
+static JS_ADDITIONAL_MODULES: std::sync::LazyLock<
+    Vec<(&str, Box<dyn (Fn() -> String) + Send + Sync>)>,
+> = std::sync::LazyLock::new(|| { vec![] });
+static JS_MODULE_ORDER: &[&str] = &["partial-records"];
+static JS_BUILTIN_PERMISSIONS: &[(&str, &[&str])] = &[];
+struct Component;
+impl crate::bindings::exports::quickjs::partial_records::api::Guest for Component {
+    fn person_without_nickname() -> crate::bindings::exports::quickjs::partial_records::api::Person {
+        crate::internal::async_exported_function(async move {
+            let result: crate::bindings::exports::quickjs::partial_records::api::Person = crate::internal::call_js_export(
+                    "quickjs:partial-records",
+                    0usize,
+                    &["api", "personWithoutNickname"],
+                    crate::wrappers::JsArgs(()),
+                )
+                .await;
+            result
+        })
+    }
+    fn person_with_extra_properties() -> crate::bindings::exports::quickjs::partial_records::api::Person {
+        crate::internal::async_exported_function(async move {
+            let result: crate::bindings::exports::quickjs::partial_records::api::Person = crate::internal::call_js_export(
+                    "quickjs:partial-records",
+                    1usize,
+                    &["api", "personWithExtraProperties"],
+                    crate::wrappers::JsArgs(()),
+                )
+                .await;
+            result
+        })
+    }
+    fn person_without_name_and_age() -> crate::bindings::exports::quickjs::partial_records::api::Person {
+        crate::internal::async_exported_function(async move {
+            let result: crate::bindings::exports::quickjs::partial_records::api::Person = crate::internal::call_js_export(
+                    "quickjs:partial-records",
+                    2usize,
+                    &["api", "personWithoutNameAndAge"],
+                    crate::wrappers::JsArgs(()),
+                )
+                .await;
+            result
+        })
+    }
+    fn orders_with_invalid_zip() -> Vec<
+        crate::bindings::exports::quickjs::partial_records::api::Order,
+    > {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsList<
+                crate::bindings::exports::quickjs::partial_records::api::Order,
+            > = crate::internal::call_js_export(
+                    "quickjs:partial-records",
+                    3usize,
+                    &["api", "ordersWithInvalidZip"],
+                    crate::wrappers::JsArgs(()),
+                )
+                .await;
+            result.0.into_iter().map(|v| v).collect::<Vec<_>>()
+        })
+    }
+}
+bindings::export!(Component with_types_in bindings);
@@ -0,0 +1,6 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:passwords/passwords
+// wit: sha256:b1cdcd38342e041fe8a5ad01da97988dd0922211b7aba5b41b2a294a4f6faf25
+// js passwords: sha256:5b13a34ec9f837858915bcb866d2cd62be13c13dbaf2a0073d9dcf831cc28445
+
+pub static ASSETS: &[(&str, &[u8])] = &[];
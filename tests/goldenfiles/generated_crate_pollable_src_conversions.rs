@@ -0,0 +1,5 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:pollable/pollable
+// wit: sha256:80abef02665ee533e57adf37dcd9ad0aeb17c53f9e211ed9face87872268f2eb
+// js pollable: sha256:0e36abdef582be7f502294d62200f0ce7d84d6ca013ada69b1a5b3d3ae7d4012
+
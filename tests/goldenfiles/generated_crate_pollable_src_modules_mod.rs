@@ -0,0 +1,65 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:pollable/pollable
+// wit: sha256:80abef02665ee533e57adf37dcd9ad0aeb17c53f9e211ed9face87872268f2eb
+// js pollable: sha256:0e36abdef582be7f502294d62200f0ce7d84d6ca013ada69b1a5b3d3ae7d4012
+
+use rquickjs::JsLifetime;
+use rquickjs::class::{JsClass, Trace};
+use std::ops::Deref;
+pub mod wasi_io_0_2_3_poll;
+pub mod wasi_clocks_0_2_3_monotonic_clock;
+const NATIVE_MODULE_NAMES: &[&str] = &[
+    "wasi:io/poll@0.2.3",
+    "wasi:clocks/monotonic-clock@0.2.3",
+];
+pub struct NativeModuleResolver;
+impl rquickjs::loader::Resolver for NativeModuleResolver {
+    fn resolve<'js>(
+        &mut self,
+        _ctx: &rquickjs::Ctx<'js>,
+        base: &str,
+        name: &str,
+    ) -> rquickjs::Result<String> {
+        if NATIVE_MODULE_NAMES.contains(&name) {
+            Ok(name.to_string())
+        } else {
+            Err(rquickjs::Error::new_resolving(base, name))
+        }
+    }
+}
+pub struct NativeModuleLoader;
+impl rquickjs::loader::Loader for NativeModuleLoader {
+    fn load<'js>(
+        &mut self,
+        ctx: &rquickjs::Ctx<'js>,
+        name: &str,
+    ) -> rquickjs::Result<rquickjs::Module<'js, rquickjs::module::Declared>> {
+        match name {
+            "wasi:io/poll@0.2.3" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::wasi_io_0_2_3_poll::JsPollModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "wasi:clocks/monotonic-clock@0.2.3" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::wasi_clocks_0_2_3_monotonic_clock::JsMonotonicClockModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            _ => Err(rquickjs::Error::new_loading(name)),
+        }
+    }
+}
+pub struct JsPollableModule;
+impl rquickjs::module::ModuleDef for JsPollableModule {
+    fn declare(decl: &rquickjs::module::Declarations) -> rquickjs::Result<()> {
+        Ok(())
+    }
+    fn evaluate<'js>(
+        ctx: &rquickjs::Ctx<'js>,
+        exports: &rquickjs::module::Exports<'js>,
+    ) -> rquickjs::Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,6 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:protobuf/protobuf
+// wit: sha256:64a6185b9b33240897a0051ee9845bc1c0b5e43e1be293f8b9ae7782549cab2f
+// js protobuf: sha256:98d8b15bd1ff3434d9f6dc6ff1f5f0c822e60022f76d12930d8376171ec22c86
+
+pub static ASSETS: &[(&str, &[u8])] = &[];
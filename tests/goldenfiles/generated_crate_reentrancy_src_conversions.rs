@@ -0,0 +1,5 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:reentrancy/reentrancy
+// wit: sha256:a99f31a2e30ae24e45da017eaec14c8b0c4357232933a15584d8acee3a9fa3d1
+// js reentrancy: sha256:25d6021273ba3e9f6876d1486c7904fe8f98cbd4981e362a553371b1484a3ebb
+
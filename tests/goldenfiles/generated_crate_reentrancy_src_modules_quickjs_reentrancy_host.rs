@@ -0,0 +1,27 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:reentrancy/reentrancy
+// wit: sha256:a99f31a2e30ae24e45da017eaec14c8b0c4357232933a15584d8acee3a9fa3d1
+// js reentrancy: sha256:25d6021273ba3e9f6876d1486c7904fe8f98cbd4981e362a553371b1484a3ebb
+
+use rquickjs::JsLifetime;
+use rquickjs::class::{JsClass, Trace};
+use std::ops::Deref;
+#[rquickjs::function]
+fn reenter() -> () {
+    let result: () = crate::bindings::quickjs::reentrancy::host::reenter();
+    result
+}
+pub struct JsHostModule;
+impl rquickjs::module::ModuleDef for JsHostModule {
+    fn declare(decl: &rquickjs::module::Declarations) -> rquickjs::Result<()> {
+        decl.declare("reenter")?;
+        Ok(())
+    }
+    fn evaluate<'js>(
+        ctx: &rquickjs::Ctx<'js>,
+        exports: &rquickjs::module::Exports<'js>,
+    ) -> rquickjs::Result<()> {
+        exports.export("reenter", js_reenter)?;
+        Ok(())
+    }
+}
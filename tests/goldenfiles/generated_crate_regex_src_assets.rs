@@ -0,0 +1,6 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:regex/regex
+// wit: sha256:0ae8bf43df98af4f9839de04678b1299eccfbf7eb0028b75d4a6b0499c642a28
+// js regex: sha256:b1531112b959bdaae28dd45b18ed196113023f023d021331d2814d3f754bd344
+
+pub static ASSETS: &[(&str, &[u8])] = &[];
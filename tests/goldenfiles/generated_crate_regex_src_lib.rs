@@ -0,0 +1,158 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:regex/regex
+// wit: sha256:0ae8bf43df98af4f9839de04678b1299eccfbf7eb0028b75d4a6b0499c642a28
+// js regex: sha256:b1531112b959bdaae28dd45b18ed196113023f023d021331d2814d3f754bd344
+
+#[allow(static_mut_refs)]
+#[allow(unsafe_op_in_unsafe_fn)]
+mod bindings;
+mod assets;
+mod builtin;
+mod conversions;
+#[allow(unused)]
+mod internal;
+#[allow(unused)]
+mod modules;
+mod wrappers;
+static JS_EXPORT_MODULE_NAME: &str = "regex";
+static JS_EXPORT_NAMES_VERBATIM: bool = false;
+static INTERN_FIELD_NAMES: bool = false;
+#[allow(dead_code)]
+static REPLACE_LONE_SURROGATES: bool = false;
+static MATH_RANDOM_SEED: crate::builtin::MathRandomSeed = crate::builtin::MathRandomSeed::Default;
+static DATE_CLOCK: Option<fn() -> f64> = None;
+static IMPORT_HOOKS: bool = false;
+static MOCK_IMPORTS: bool = false;
+static IMPORT_RECORDING: crate::builtin::ImportRecording = crate::builtin::ImportRecording::None;
+static CANCEL_BACKGROUND_WORK: bool = false;
+static HTTP_LIMITS: crate::builtin::HttpLimits = crate::builtin::HttpLimits::UNLIMITED;
+#[allow(dead_code)]
+static COOKIE_JAR: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE_LIMITS: crate::builtin::HttpCacheLimits = crate::builtin::HttpCacheLimits {
+    max_entries: Some(256usize),
+    max_size: Some(16777216usize),
+    max_body_size: Some(1048576usize),
+};
+#[allow(dead_code)]
+static FETCH_BASE_URL: Option<&str> = None;
+#[allow(dead_code)]
+static FETCH_GATEWAY: Option<&str> = None;
+static JS_EXPORT_MODULE: &str = include_str!("regex.js");
+static JS_ADDITIONAL_MODULES: std::sync::LazyLock<
+    Vec<(&str, Box<dyn (Fn() -> String) + Send + Sync>)>,
+> = std::sync::LazyLock::new(|| { vec![] });
+static JS_MODULE_ORDER: &[&str] = &["regex"];
+static JS_BUILTIN_PERMISSIONS: &[(&str, &[&str])] = &[];
+struct Component;
+impl crate::bindings::Guest for Component {
+    fn exec(pattern: String, regex_flags: String, text: String, start: u32) -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:regex",
+                    0usize,
+                    &["exec"],
+                    crate::wrappers::JsArgs((
+                        crate::wrappers::JsString(pattern),
+                        crate::wrappers::JsString(regex_flags),
+                        crate::wrappers::JsString(text),
+                        start,
+                    )),
+                )
+                .await;
+            result.0
+        })
+    }
+    fn match_all(pattern: String, regex_flags: String, text: String) -> Vec<String> {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsList<crate::wrappers::JsString> = crate::internal::call_js_export(
+                    "quickjs:regex",
+                    1usize,
+                    &["matchAll"],
+                    crate::wrappers::JsArgs((
+                        crate::wrappers::JsString(pattern),
+                        crate::wrappers::JsString(regex_flags),
+                        crate::wrappers::JsString(text),
+                    )),
+                )
+                .await;
+            result.0.into_iter().map(|v| v.0).collect::<Vec<_>>()
+        })
+    }
+    fn replace_all(pattern: String, text: String, replacement: String) -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:regex",
+                    2usize,
+                    &["replaceAll"],
+                    crate::wrappers::JsArgs((
+                        crate::wrappers::JsString(pattern),
+                        crate::wrappers::JsString(text),
+                        crate::wrappers::JsString(replacement),
+                    )),
+                )
+                .await;
+            result.0
+        })
+    }
+    fn replace_with_function(pattern: String, text: String) -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:regex",
+                    3usize,
+                    &["replaceWithFunction"],
+                    crate::wrappers::JsArgs((
+                        crate::wrappers::JsString(pattern),
+                        crate::wrappers::JsString(text),
+                    )),
+                )
+                .await;
+            result.0
+        })
+    }
+    fn split(pattern: String, text: String) -> Vec<String> {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsList<crate::wrappers::JsString> = crate::internal::call_js_export(
+                    "quickjs:regex",
+                    4usize,
+                    &["split"],
+                    crate::wrappers::JsArgs((
+                        crate::wrappers::JsString(pattern),
+                        crate::wrappers::JsString(text),
+                    )),
+                )
+                .await;
+            result.0.into_iter().map(|v| v.0).collect::<Vec<_>>()
+        })
+    }
+    fn literal(text: String, haystack: String) -> bool {
+        crate::internal::async_exported_function(async move {
+            let result: bool = crate::internal::call_js_export(
+                    "quickjs:regex",
+                    5usize,
+                    &["literal"],
+                    crate::wrappers::JsArgs((
+                        crate::wrappers::JsString(text),
+                        crate::wrappers::JsString(haystack),
+                    )),
+                )
+                .await;
+            result
+        })
+    }
+    fn errors() -> Vec<String> {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsList<crate::wrappers::JsString> = crate::internal::call_js_export(
+                    "quickjs:regex",
+                    6usize,
+                    &["errors"],
+                    crate::wrappers::JsArgs(()),
+                )
+                .await;
+            result.0.into_iter().map(|v| v.0).collect::<Vec<_>>()
+        })
+    }
+}
+bindings::export!(Component with_types_in bindings);
@@ -0,0 +1,6 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:replayed-imports/replayed-imports
+// wit: sha256:b706b2f816488e20c2dbed9ffb5fb07ff5fd3386eb39c9b585fbc1a0f3bc5b06
+// js replayed-imports: sha256:0fe34d85a0c36e3c3a6dd44cccad1511d64f32c7e8d65ac9e2ce5c1df64d7fb6
+
+pub static ASSETS: &[(&str, &[u8])] = &[];
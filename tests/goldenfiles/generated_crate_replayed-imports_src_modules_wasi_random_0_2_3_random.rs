@@ -0,0 +1,54 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:replayed-imports/replayed-imports
+// wit: sha256:b706b2f816488e20c2dbed9ffb5fb07ff5fd3386eb39c9b585fbc1a0f3bc5b06
+// js replayed-imports: sha256:0fe34d85a0c36e3c3a6dd44cccad1511d64f32c7e8d65ac9e2ce5c1df64d7fb6
+
+use rquickjs::JsLifetime;
+use rquickjs::class::{JsClass, Trace};
+use std::ops::Deref;
+#[rquickjs::function]
+fn get_random_bytes(
+    len: crate::wrappers::BigIntWrapper<u64>,
+) -> crate::wrappers::UInt8Array {
+    let result: Vec<u8> = crate::bindings::wasi::random::random::get_random_bytes(len.0);
+    crate::wrappers::UInt8Array(result)
+}
+#[rquickjs::function]
+fn get_random_u64() -> crate::wrappers::BigIntWrapper<u64> {
+    let result: u64 = crate::bindings::wasi::random::random::get_random_u64();
+    crate::wrappers::BigIntWrapper(result)
+}
+pub struct JsRandomModule;
+impl rquickjs::module::ModuleDef for JsRandomModule {
+    fn declare(decl: &rquickjs::module::Declarations) -> rquickjs::Result<()> {
+        decl.declare("getRandomBytes")?;
+        decl.declare("getRandomU64")?;
+        Ok(())
+    }
+    fn evaluate<'js>(
+        ctx: &rquickjs::Ctx<'js>,
+        exports: &rquickjs::module::Exports<'js>,
+    ) -> rquickjs::Result<()> {
+        exports
+            .export(
+                "getRandomBytes",
+                crate::builtin::hook_import_function(
+                    ctx,
+                    "wasi:random/random@0.2.3",
+                    "getRandomBytes",
+                    js_get_random_bytes,
+                )?,
+            )?;
+        exports
+            .export(
+                "getRandomU64",
+                crate::builtin::hook_import_function(
+                    ctx,
+                    "wasi:random/random@0.2.3",
+                    "getRandomU64",
+                    js_get_random_u64,
+                )?,
+            )?;
+        Ok(())
+    }
+}
@@ -0,0 +1,5 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:seeded-random/seeded-random
+// wit: sha256:9393de598598d3f1809a90e96bb6b160fbe30993a3300b67d57c188d8e94f87a
+// js seeded-random: sha256:afc5b6c3f39c52b02786f02034ad868dfd984111c7e6be81d33c32c77e9f66cc
+
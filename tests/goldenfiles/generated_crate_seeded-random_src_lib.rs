@@ -0,0 +1,66 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:seeded-random/seeded-random
+// wit: sha256:9393de598598d3f1809a90e96bb6b160fbe30993a3300b67d57c188d8e94f87a
+// js seeded-random: sha256:afc5b6c3f39c52b02786f02034ad868dfd984111c7e6be81d33c32c77e9f66cc
+
+#[allow(static_mut_refs)]
+#[allow(unsafe_op_in_unsafe_fn)]
+mod bindings;
+mod assets;
+mod builtin;
+mod conversions;
+#[allow(unused)]
+mod internal;
+#[allow(unused)]
+mod modules;
+mod wrappers;
+static JS_EXPORT_MODULE_NAME: &str = "seeded-random";
+static JS_EXPORT_NAMES_VERBATIM: bool = false;
+static INTERN_FIELD_NAMES: bool = false;
+#[allow(dead_code)]
+static REPLACE_LONE_SURROGATES: bool = false;
+static MATH_RANDOM_SEED: crate::builtin::MathRandomSeed = crate::builtin::MathRandomSeed::Fixed(
+    42u64,
+);
+static DATE_CLOCK: Option<fn() -> f64> = None;
+static IMPORT_HOOKS: bool = false;
+static MOCK_IMPORTS: bool = false;
+static IMPORT_RECORDING: crate::builtin::ImportRecording = crate::builtin::ImportRecording::None;
+static CANCEL_BACKGROUND_WORK: bool = false;
+static HTTP_LIMITS: crate::builtin::HttpLimits = crate::builtin::HttpLimits::UNLIMITED;
+#[allow(dead_code)]
+static COOKIE_JAR: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE_LIMITS: crate::builtin::HttpCacheLimits = crate::builtin::HttpCacheLimits {
+    max_entries: Some(256usize),
+    max_size: Some(16777216usize),
+    max_body_size: Some(1048576usize),
+};
+#[allow(dead_code)]
+static FETCH_BASE_URL: Option<&str> = None;
+#[allow(dead_code)]
+static FETCH_GATEWAY: Option<&str> = None;
+static JS_EXPORT_MODULE: &str = include_str!("seeded-random.js");
+static JS_ADDITIONAL_MODULES: std::sync::LazyLock<
+    Vec<(&str, Box<dyn (Fn() -> String) + Send + Sync>)>,
+> = std::sync::LazyLock::new(|| { vec![] });
+static JS_MODULE_ORDER: &[&str] = &["seeded-random"];
+static JS_BUILTIN_PERMISSIONS: &[(&str, &[&str])] = &[];
+struct Component;
+impl crate::bindings::exports::quickjs::seeded_random::api::Guest for Component {
+    fn numbers(count: u32) -> Vec<f64> {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsList<f64> = crate::internal::call_js_export(
+                    "quickjs:seeded-random",
+                    0usize,
+                    &["api", "numbers"],
+                    (count,),
+                )
+                .await;
+            result.0.into_iter().map(|v| v).collect::<Vec<_>>()
+        })
+    }
+}
+bindings::export!(Component with_types_in bindings);
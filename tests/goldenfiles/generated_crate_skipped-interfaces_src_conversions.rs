@@ -0,0 +1,5 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:skipped-interfaces/skipped-interfaces
+// wit: sha256:474b3148d6a222f91be345689b4ecff98ff3b1a3ef6a4559b4e917fb594c52ee
+// js skipped-interfaces: sha256:3ce7b757bb954c9a987ed042b46d59efb6ec8e78b1d1d17c070e08e85d407dd5
+
@@ -0,0 +1,168 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:skipped-interfaces/skipped-interfaces
+// wit: sha256:474b3148d6a222f91be345689b4ecff98ff3b1a3ef6a4559b4e917fb594c52ee
+// js skipped-interfaces: sha256:3ce7b757bb954c9a987ed042b46d59efb6ec8e78b1d1d17c070e08e85d407dd5
+
+#[allow(static_mut_refs)]
+#[allow(unsafe_op_in_unsafe_fn)]
+mod bindings;
+mod assets;
+mod builtin;
+mod conversions;
+#[allow(unused)]
+mod internal;
+#[allow(unused)]
+mod modules;
+mod wrappers;
+static JS_EXPORT_MODULE_NAME: &str = "skipped-interfaces";
+static JS_EXPORT_NAMES_VERBATIM: bool = false;
+static INTERN_FIELD_NAMES: bool = false;
+#[allow(dead_code)]
+static REPLACE_LONE_SURROGATES: bool = false;
+static MATH_RANDOM_SEED: crate::builtin::MathRandomSeed = crate::builtin::MathRandomSeed::Default;
+static DATE_CLOCK: Option<fn() -> f64> = None;
+static IMPORT_HOOKS: bool = false;
+static MOCK_IMPORTS: bool = false;
+static IMPORT_RECORDING: crate::builtin::ImportRecording = crate::builtin::ImportRecording::None;
+static CANCEL_BACKGROUND_WORK: bool = false;
+static HTTP_LIMITS: crate::builtin::HttpLimits = crate::builtin::HttpLimits::UNLIMITED;
+#[allow(dead_code)]
+static COOKIE_JAR: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE_LIMITS: crate::builtin::HttpCacheLimits = crate::builtin::HttpCacheLimits {
+    max_entries: Some(256usize),
+    max_size: Some(16777216usize),
+    max_body_size: Some(1048576usize),
+};
+#[allow(dead_code)]
+static FETCH_BASE_URL: Option<&str> = None;
+#[allow(dead_code)]
+static FETCH_GATEWAY: Option<&str> = None;
+static JS_EXPORT_MODULE: &str = include_str!("skipped-interfaces.js");
+static JS_ADDITIONAL_MODULES: std::sync::LazyLock<
+    Vec<(&str, Box<dyn (Fn() -> String) + Send + Sync>)>,
+> = std::sync::LazyLock::new(|| { vec![] });
+static JS_MODULE_ORDER: &[&str] = &["skipped-interfaces"];
+static JS_BUILTIN_PERMISSIONS: &[(&str, &[&str])] = &[];
+struct Component;
+impl crate::bindings::exports::quickjs::skipped_interfaces::api::Guest for Component {
+    fn greet(name: String) -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:skipped-interfaces",
+                    0usize,
+                    &["api", "greet"],
+                    (crate::wrappers::JsString(name),),
+                )
+                .await;
+            result.0
+        })
+    }
+}
+struct Session {
+    resource_id: usize,
+}
+impl crate::bindings::exports::quickjs::skipped_interfaces::admin::GuestSession
+for Session {
+    #[allow(unused_variables)]
+    fn new(user: String) -> Self {
+        panic!(
+            "Exported function [constructor]session of interface admin is not implemented by this component"
+        )
+    }
+    #[allow(unused_variables)]
+    fn user(&self) -> String {
+        panic!(
+            "Exported function [method]session.user of interface admin is not implemented by this component"
+        )
+    }
+}
+impl Drop for Session {
+    fn drop(&mut self) {
+        crate::internal::enqueue_drop_js_resource(self.resource_id);
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::exports::quickjs::skipped_interfaces::admin::SessionBorrow<'_> {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let inner: &Session = self.get();
+        let resource_table: rquickjs::Object = ctx
+            .globals()
+            .get(crate::internal::RESOURCE_TABLE_NAME)
+            .expect("Failed to get the resource table");
+        let resource_instance: rquickjs::Object = resource_table
+            .get(inner.resource_id.to_string())
+            .expect(
+                &format!("Failed to get resource instance with id {}", inner.resource_id),
+            );
+        Ok(resource_instance.into_value())
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::exports::quickjs::skipped_interfaces::admin::Session {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let inner: &Session = self.get();
+        let resource_table: rquickjs::Object = ctx
+            .globals()
+            .get(crate::internal::RESOURCE_TABLE_NAME)
+            .expect("Failed to get the resource table");
+        let resource_instance: rquickjs::Object = resource_table
+            .get(inner.resource_id.to_string())
+            .expect(
+                &format!("Failed to get resource instance with id {}", inner.resource_id),
+            );
+        Ok(resource_instance.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::exports::quickjs::skipped_interfaces::admin::Session {
+    fn from_js(
+        ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let resource = value
+            .into_object()
+            .ok_or_else(|| {
+                rquickjs::Error::new_from_js_message(
+                    "JS Resource instance",
+                    "WASM resource instance",
+                    "The value is not an object",
+                )
+            })?;
+        let already_registered = resource
+            .contains_key(crate::internal::RESOURCE_ID_KEY)?;
+        let resource_id: usize = if already_registered {
+            resource.get(crate::internal::RESOURCE_ID_KEY)?
+        } else {
+            let resource_table: rquickjs::Object = ctx
+                .globals()
+                .get(crate::internal::RESOURCE_TABLE_NAME)?;
+            let resource_id = crate::internal::get_free_resource_id();
+            resource_table.set(resource_id.to_string(), resource)?;
+            resource_id
+        };
+        Ok(
+            crate::bindings::exports::quickjs::skipped_interfaces::admin::Session::new(Session {
+                resource_id,
+            }),
+        )
+    }
+}
+impl crate::bindings::exports::quickjs::skipped_interfaces::admin::Guest for Component {
+    type Session = Session;
+    #[allow(unused_variables)]
+    fn shutdown(code: u32) -> Result<(), String> {
+        panic!(
+            "Exported function shutdown of interface admin is not implemented by this component"
+        )
+    }
+}
+bindings::export!(Component with_types_in bindings);
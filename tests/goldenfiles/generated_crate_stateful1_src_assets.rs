@@ -0,0 +1,6 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:stateful1/stateful1
+// wit: sha256:4bb6f4ed95a47c490ee617ce022ac66e3c08cf8a0aac6b551a91eb60f51b673d
+// js stateful1: sha256:6ee83e8e2566e9039efa5b61657a2055d71ab37b27052ccce534023a65d46b61
+
+pub static ASSETS: &[(&str, &[u8])] = &[];
@@ -0,0 +1,9 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:static-assets/static-assets
+// wit: sha256:1d42618a7260379bb53ef20825a2e1590dc113c4cd401a8bccdaf120df41b7ea
+// js static-assets: sha256:5e2b55cbf2f8f0df9d304085113d40858345a00d49316590ae01f87bb447785c
+
+pub static ASSETS: &[(&str, &[u8])] = &[
+    ("logo.png", include_bytes!("../assets/logo.png")),
+    ("templates/index.html", include_bytes!("../assets/templates/index.html")),
+];
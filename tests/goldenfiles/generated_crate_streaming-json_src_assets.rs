@@ -0,0 +1,6 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:streaming-json/streaming-json
+// wit: sha256:c1a64da792a3a4a9eb621563a83cfa92fc31171862bba53867151cc6b6cc5e4a
+// js streaming-json: sha256:054593a7ebd398501daf040dc04dd4d776f4da75fa1c028d6218657b9f67eb3f
+
+pub static ASSETS: &[(&str, &[u8])] = &[];
@@ -0,0 +1,138 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:streaming-json/streaming-json
+// wit: sha256:c1a64da792a3a4a9eb621563a83cfa92fc31171862bba53867151cc6b6cc5e4a
+// js streaming-json: sha256:054593a7ebd398501daf040dc04dd4d776f4da75fa1c028d6218657b9f67eb3f
+
+#[allow(static_mut_refs)]
+#[allow(unsafe_op_in_unsafe_fn)]
+mod bindings;
+mod assets;
+mod builtin;
+mod conversions;
+#[allow(unused)]
+mod internal;
+#[allow(unused)]
+mod modules;
+mod wrappers;
+static JS_EXPORT_MODULE_NAME: &str = "streaming-json";
+static JS_EXPORT_NAMES_VERBATIM: bool = false;
+static INTERN_FIELD_NAMES: bool = false;
+#[allow(dead_code)]
+static REPLACE_LONE_SURROGATES: bool = false;
+static MATH_RANDOM_SEED: crate::builtin::MathRandomSeed = crate::builtin::MathRandomSeed::Default;
+static DATE_CLOCK: Option<fn() -> f64> = None;
+static IMPORT_HOOKS: bool = false;
+static MOCK_IMPORTS: bool = false;
+static IMPORT_RECORDING: crate::builtin::ImportRecording = crate::builtin::ImportRecording::None;
+static CANCEL_BACKGROUND_WORK: bool = false;
+static HTTP_LIMITS: crate::builtin::HttpLimits = crate::builtin::HttpLimits::UNLIMITED;
+#[allow(dead_code)]
+static COOKIE_JAR: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE_LIMITS: crate::builtin::HttpCacheLimits = crate::builtin::HttpCacheLimits {
+    max_entries: Some(256usize),
+    max_size: Some(16777216usize),
+    max_body_size: Some(1048576usize),
+};
+#[allow(dead_code)]
+static FETCH_BASE_URL: Option<&str> = None;
+#[allow(dead_code)]
+static FETCH_GATEWAY: Option<&str> = None;
+static JS_EXPORT_MODULE: &str = include_str!("streaming-json.js");
+static JS_ADDITIONAL_MODULES: std::sync::LazyLock<
+    Vec<(&str, Box<dyn (Fn() -> String) + Send + Sync>)>,
+> = std::sync::LazyLock::new(|| { vec![] });
+static JS_MODULE_ORDER: &[&str] = &["streaming-json"];
+static JS_BUILTIN_PERMISSIONS: &[(&str, &[&str])] = &[];
+struct Component;
+impl crate::bindings::Guest for Component {
+    fn trace_events(chunks: Vec<Vec<u8>>) -> Vec<String> {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsList<crate::wrappers::JsString> = crate::internal::call_js_export(
+                    "quickjs:streaming-json",
+                    0usize,
+                    &["traceEvents"],
+                    (
+                        crate::wrappers::JsList(
+                            chunks
+                                .into_iter()
+                                .map(|v| crate::wrappers::UInt8Array(v))
+                                .collect::<Vec<_>>(),
+                        ),
+                    ),
+                )
+                .await;
+            result.0.into_iter().map(|v| v.0).collect::<Vec<_>>()
+        })
+    }
+    fn sum_prices(chunks: Vec<String>) -> f64 {
+        crate::internal::async_exported_function(async move {
+            let result: f64 = crate::internal::call_js_export(
+                    "quickjs:streaming-json",
+                    1usize,
+                    &["sumPrices"],
+                    (
+                        crate::wrappers::JsList(
+                            chunks
+                                .into_iter()
+                                .map(|v| crate::wrappers::JsString(v))
+                                .collect::<Vec<_>>(),
+                        ),
+                    ),
+                )
+                .await;
+            result
+        })
+    }
+    fn ids(ndjson: String) -> Vec<u32> {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsList<u32> = crate::internal::call_js_export(
+                    "quickjs:streaming-json",
+                    2usize,
+                    &["ids"],
+                    (crate::wrappers::JsString(ndjson),),
+                )
+                .await;
+            result.0.into_iter().map(|v| v).collect::<Vec<_>>()
+        })
+    }
+    fn stringify_chunks(items: u32, chunk_size: u32) -> (u32, bool) {
+        crate::internal::async_exported_function(async move {
+            let result: rquickjs::convert::List<(u32, bool)> = crate::internal::call_js_export(
+                    "quickjs:streaming-json",
+                    3usize,
+                    &["stringifyChunks"],
+                    crate::wrappers::JsArgs((items, chunk_size)),
+                )
+                .await;
+            (result.0.0, result.0.1)
+        })
+    }
+    fn round_trip(json: String) -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:streaming-json",
+                    4usize,
+                    &["roundTrip"],
+                    (crate::wrappers::JsString(json),),
+                )
+                .await;
+            result.0
+        })
+    }
+    fn parse_error(json: String) -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:streaming-json",
+                    5usize,
+                    &["parseError"],
+                    (crate::wrappers::JsString(json),),
+                )
+                .await;
+            result.0
+        })
+    }
+}
+bindings::export!(Component with_types_in bindings);
@@ -0,0 +1,5 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:streams/streams
+// wit: sha256:66350824cb586f62c293e2511adee9cf85bc5bc41d610a0d579706d8a441fab5
+// js streams: sha256:6a7526787f138041424eb5f873b7934363e236e0544777db32222664a004fbe6
+
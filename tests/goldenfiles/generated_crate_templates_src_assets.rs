@@ -0,0 +1,12 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:templates/templates
+// wit: sha256:3610a967960a27f9a5c8891244c48f4e53984910e07b00a478bc4d8bc1c98321
+// js templates: sha256:e00603e6526861014bddfe9bae6335acfd06f77a257d2344605b49be01a599e1
+
+pub static ASSETS: &[(&str, &[u8])] = &[
+    ("emails/base.html", include_bytes!("../assets/emails/base.html")),
+    ("emails/macros.html", include_bytes!("../assets/emails/macros.html")),
+    ("emails/signature.txt", include_bytes!("../assets/emails/signature.txt")),
+    ("emails/welcome.html", include_bytes!("../assets/emails/welcome.html")),
+    ("emails/welcome.txt", include_bytes!("../assets/emails/welcome.txt")),
+];
@@ -0,0 +1,50 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:templates/templates
+// wit: sha256:3610a967960a27f9a5c8891244c48f4e53984910e07b00a478bc4d8bc1c98321
+// js templates: sha256:e00603e6526861014bddfe9bae6335acfd06f77a257d2344605b49be01a599e1
+
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::Item {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("description", crate::wrappers::JsString(self.description))?;
+        obj.set::<_, u32>("quantity", self.quantity)?;
+        obj.set::<_, f64>("price", self.price)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::Item {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT item",
+            &["description", "quantity", "price"],
+            &[("description", "string"), ("quantity", "u32"), ("price", "f64")],
+            false,
+        )?;
+        let description: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "description",
+            "string",
+        )?;
+        let description: String = description.0;
+        let quantity: u32 = crate::wrappers::get_field(&obj, "quantity", "u32")?;
+        let quantity: u32 = quantity;
+        let price: f64 = crate::wrappers::get_field(&obj, "price", "f64")?;
+        let price: f64 = price;
+        Ok(Self {
+            description,
+            quantity,
+            price,
+        })
+    }
+}
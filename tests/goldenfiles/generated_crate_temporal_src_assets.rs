@@ -0,0 +1,6 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:temporal/temporal
+// wit: sha256:fc8b7f9544089cf3ab487ae29ff5a07be05e36fd9d405d7a44cddbdda281950c
+// js temporal: sha256:e9aa82ff00d6bef05a9c7ff094e04d7ee179befd96cacf9ca17f23a7715afb65
+
+pub static ASSETS: &[(&str, &[u8])] = &[];
@@ -0,0 +1,151 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:temporal/temporal
+// wit: sha256:fc8b7f9544089cf3ab487ae29ff5a07be05e36fd9d405d7a44cddbdda281950c
+// js temporal: sha256:e9aa82ff00d6bef05a9c7ff094e04d7ee179befd96cacf9ca17f23a7715afb65
+
+#[allow(static_mut_refs)]
+#[allow(unsafe_op_in_unsafe_fn)]
+mod bindings;
+mod assets;
+mod builtin;
+mod conversions;
+#[allow(unused)]
+mod internal;
+#[allow(unused)]
+mod modules;
+mod wrappers;
+static JS_EXPORT_MODULE_NAME: &str = "temporal";
+static JS_EXPORT_NAMES_VERBATIM: bool = false;
+static INTERN_FIELD_NAMES: bool = false;
+#[allow(dead_code)]
+static REPLACE_LONE_SURROGATES: bool = false;
+static MATH_RANDOM_SEED: crate::builtin::MathRandomSeed = crate::builtin::MathRandomSeed::Default;
+static DATE_CLOCK: Option<fn() -> f64> = None;
+static IMPORT_HOOKS: bool = false;
+static MOCK_IMPORTS: bool = false;
+static IMPORT_RECORDING: crate::builtin::ImportRecording = crate::builtin::ImportRecording::None;
+static CANCEL_BACKGROUND_WORK: bool = false;
+static HTTP_LIMITS: crate::builtin::HttpLimits = crate::builtin::HttpLimits::UNLIMITED;
+#[allow(dead_code)]
+static COOKIE_JAR: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE_LIMITS: crate::builtin::HttpCacheLimits = crate::builtin::HttpCacheLimits {
+    max_entries: Some(256usize),
+    max_size: Some(16777216usize),
+    max_body_size: Some(1048576usize),
+};
+#[allow(dead_code)]
+static FETCH_BASE_URL: Option<&str> = None;
+#[allow(dead_code)]
+static FETCH_GATEWAY: Option<&str> = None;
+static JS_EXPORT_MODULE: &str = include_str!("temporal.js");
+static JS_ADDITIONAL_MODULES: std::sync::LazyLock<
+    Vec<(&str, Box<dyn (Fn() -> String) + Send + Sync>)>,
+> = std::sync::LazyLock::new(|| { vec![] });
+static JS_MODULE_ORDER: &[&str] = &["temporal"];
+static JS_BUILTIN_PERMISSIONS: &[(&str, &[&str])] = &[];
+struct Component;
+impl crate::bindings::Guest for Component {
+    fn add_to_zoned(start: String, duration: String) -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:temporal",
+                    0usize,
+                    &["addToZoned"],
+                    crate::wrappers::JsArgs((
+                        crate::wrappers::JsString(start),
+                        crate::wrappers::JsString(duration),
+                    )),
+                )
+                .await;
+            result.0
+        })
+    }
+    fn difference(start: String, end: String, largest_unit: String) -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:temporal",
+                    1usize,
+                    &["difference"],
+                    crate::wrappers::JsArgs((
+                        crate::wrappers::JsString(start),
+                        crate::wrappers::JsString(end),
+                        crate::wrappers::JsString(largest_unit),
+                    )),
+                )
+                .await;
+            result.0
+        })
+    }
+    fn daily(first_day: String, hour: u8, time_zone: String, days: u32) -> Vec<String> {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsList<crate::wrappers::JsString> = crate::internal::call_js_export(
+                    "quickjs:temporal",
+                    2usize,
+                    &["daily"],
+                    crate::wrappers::JsArgs((
+                        crate::wrappers::JsString(first_day),
+                        hour,
+                        crate::wrappers::JsString(time_zone),
+                        days,
+                    )),
+                )
+                .await;
+            result.0.into_iter().map(|v| v.0).collect::<Vec<_>>()
+        })
+    }
+    fn describe_date(date: String) -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:temporal",
+                    3usize,
+                    &["describeDate"],
+                    (crate::wrappers::JsString(date),),
+                )
+                .await;
+            result.0
+        })
+    }
+    fn total_hours(duration: String, relative_to: Option<String>) -> f64 {
+        crate::internal::async_exported_function(async move {
+            let result: f64 = crate::internal::call_js_export(
+                    "quickjs:temporal",
+                    4usize,
+                    &["totalHours"],
+                    crate::wrappers::JsArgs((
+                        crate::wrappers::JsString(duration),
+                        relative_to.map(|v| crate::wrappers::JsString(v)),
+                    )),
+                )
+                .await;
+            result
+        })
+    }
+    fn now_matches_date() -> bool {
+        crate::internal::async_exported_function(async move {
+            let result: bool = crate::internal::call_js_export(
+                    "quickjs:temporal",
+                    5usize,
+                    &["nowMatchesDate"],
+                    crate::wrappers::JsArgs(()),
+                )
+                .await;
+            result
+        })
+    }
+    fn errors() -> Vec<String> {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsList<crate::wrappers::JsString> = crate::internal::call_js_export(
+                    "quickjs:temporal",
+                    6usize,
+                    &["errors"],
+                    crate::wrappers::JsArgs(()),
+                )
+                .await;
+            result.0.into_iter().map(|v| v.0).collect::<Vec<_>>()
+        })
+    }
+}
+bindings::export!(Component with_types_in bindings);
@@ -0,0 +1,6 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:timeout/timeout
+// wit: sha256:dbc005a0f94e94208cd7b708fb11ad4d5f6cd2e709d4626345841c22a6c06d06
+// js timeout: sha256:a527de8548ac072c3243348bfb157bc790e74401702bfe374e38af729ca57400
+
+pub static ASSETS: &[(&str, &[u8])] = &[];
@@ -0,0 +1,408 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:types-in-exports/types-in-exports
+// wit: sha256:c65d4cf2d90de3c5e1dacdb67056d769093806c9fe281e02f73ba6efc98aa513
+// js types-in-exports: sha256:2de3ddcb352d312f438847eb6dd96113998e51ddffcee7e39d905d443f84e89f
+
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::quickjs::types_in_exports::other_types::Rec2 {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<_, i8>("x", self.x)?;
+        obj.set::<_, i8>("y", self.y)?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::quickjs::types_in_exports::other_types::Rec2 {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT rec2",
+            &["x", "y"],
+            &[("x", "s8"), ("y", "s8")],
+            false,
+        )?;
+        let x: i8 = crate::wrappers::get_field(&obj, "x", "s8")?;
+        let x: i8 = x;
+        let y: i8 = crate::wrappers::get_field(&obj, "y", "s8")?;
+        let y: i8 = y;
+        Ok(Self { x, y })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::quickjs::types_in_exports::other_types::Color {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::quickjs::types_in_exports::other_types::Color::Red => {
+                "red".into_js(ctx)
+            }
+            crate::bindings::quickjs::types_in_exports::other_types::Color::Green => {
+                "green".into_js(ctx)
+            }
+            crate::bindings::quickjs::types_in_exports::other_types::Color::Blue => {
+                "blue".into_js(ctx)
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::quickjs::types_in_exports::other_types::Color {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let value = crate::wrappers::enum_case(value, "JS color", "WIT color")?;
+        match value.as_str() {
+            "red" => {
+                Ok(crate::bindings::quickjs::types_in_exports::other_types::Color::Red)
+            }
+            "green" => {
+                Ok(crate::bindings::quickjs::types_in_exports::other_types::Color::Green)
+            }
+            "blue" => {
+                Ok(crate::bindings::quickjs::types_in_exports::other_types::Color::Blue)
+            }
+            _ => Err(crate::wrappers::unknown_enum_case("JS color", "WIT color", &value)),
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::exports::quickjs::types_in_exports::types::Rec1 {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<_, crate::wrappers::JsString>("a", crate::wrappers::JsString(self.a))?;
+        obj.set::<_, u32>("b", self.b)?;
+        obj.set::<_, f32>("c", self.c)?;
+        obj.set::<
+                _,
+                crate::bindings::quickjs::types_in_exports::other_types::Rec2,
+            >("d", self.d)?;
+        obj.set::<
+                _,
+                Option<crate::bindings::quickjs::types_in_exports::other_types::Rec2>,
+            >("e", self.e.map(|v| v))?;
+        obj.set::<
+                _,
+                crate::wrappers::JsResult<u32, crate::wrappers::JsString>,
+            >(
+            "f",
+            crate::wrappers::JsResult(
+                match self.f {
+                    Ok(v) => Ok(v),
+                    Err(v) => Err(crate::wrappers::JsString(v)),
+                },
+            ),
+        )?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<
+                    crate::bindings::quickjs::types_in_exports::other_types::Rec2,
+                >,
+            >(
+            "g",
+            crate::wrappers::JsList(self.g.into_iter().map(|v| v).collect::<Vec<_>>()),
+        )?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<crate::wrappers::JsString>,
+            >(
+            "h",
+            crate::wrappers::JsList(
+                self
+                    .h
+                    .into_iter()
+                    .map(|v| crate::wrappers::JsString(v))
+                    .collect::<Vec<_>>(),
+            ),
+        )?;
+        obj.set::<
+                _,
+                rquickjs::convert::List<(crate::wrappers::JsString, u32, f32)>,
+            >(
+            "i",
+            rquickjs::convert::List((
+                crate::wrappers::JsString(self.i.0),
+                self.i.1,
+                self.i.2,
+            )),
+        )?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::exports::quickjs::types_in_exports::types::Rec1 {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT rec1",
+            &["a", "b", "c", "d", "e", "f", "g", "h", "i"],
+            &[
+                ("a", "string"),
+                ("b", "u32"),
+                ("c", "f32"),
+                ("d", "rec2"),
+                ("f", "result<u32, string>"),
+                ("g", "list<rec2>"),
+                ("h", "list-of-strings"),
+                ("i", "tuple<string, u32, f32>"),
+            ],
+            false,
+        )?;
+        let a: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "a",
+            "string",
+        )?;
+        let a: String = a.0;
+        let b: u32 = crate::wrappers::get_field(&obj, "b", "u32")?;
+        let b: u32 = b;
+        let c: f32 = crate::wrappers::get_field(&obj, "c", "f32")?;
+        let c: f32 = c;
+        let d: crate::bindings::quickjs::types_in_exports::other_types::Rec2 = crate::wrappers::get_field(
+            &obj,
+            "d",
+            "rec2",
+        )?;
+        let d: crate::bindings::exports::quickjs::types_in_exports::types::Rec2 = d;
+        let e: Option<crate::bindings::quickjs::types_in_exports::other_types::Rec2> = crate::wrappers::get_field(
+            &obj,
+            "e",
+            "option<rec2>",
+        )?;
+        let e: Option<
+            crate::bindings::exports::quickjs::types_in_exports::types::Rec2,
+        > = e.map(|v| v);
+        let f: crate::wrappers::JsResult<u32, crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "f",
+            "result<u32, string>",
+        )?;
+        let f: Result<u32, String> = f.0.map_err(|v| v.0);
+        let g: crate::wrappers::JsList<
+            crate::bindings::quickjs::types_in_exports::other_types::Rec2,
+        > = crate::wrappers::get_field(&obj, "g", "list<rec2>")?;
+        let g: Vec<crate::bindings::exports::quickjs::types_in_exports::types::Rec2> = g
+            .0
+            .into_iter()
+            .map(|v| v)
+            .collect::<Vec<_>>();
+        let h: crate::wrappers::JsList<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "h",
+            "list-of-strings",
+        )?;
+        let h: crate::bindings::exports::quickjs::types_in_exports::types::ListOfStrings = h
+            .0
+            .into_iter()
+            .map(|v| v.0)
+            .collect::<Vec<_>>();
+        let i: rquickjs::convert::List<(crate::wrappers::JsString, u32, f32)> = crate::wrappers::get_field(
+            &obj,
+            "i",
+            "tuple<string, u32, f32>",
+        )?;
+        let i: (String, u32, f32) = (i.0.0.0.clone(), i.0.1, i.0.2);
+        Ok(Self { a, b, c, d, e, f, g, h, i })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::exports::quickjs::types_in_exports::types::Var1 {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::exports::quickjs::types_in_exports::types::Var1::None => {
+                crate::wrappers::variant_case(ctx, "none")
+            }
+            crate::bindings::exports::quickjs::types_in_exports::types::Var1::Any => {
+                crate::wrappers::variant_case(ctx, "any")
+            }
+            crate::bindings::exports::quickjs::types_in_exports::types::Var1::Specific(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "specific", crate::wrappers::JsString(inner))
+            }
+            crate::bindings::exports::quickjs::types_in_exports::types::Var1::Many(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsList<
+                        crate::bindings::exports::quickjs::types_in_exports::types::Rec1,
+                    >,
+                >(
+                    ctx,
+                    "many",
+                    crate::wrappers::JsList(
+                        inner.into_iter().map(|v| v).collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            crate::bindings::exports::quickjs::types_in_exports::types::Var1::WrappedTuple(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    rquickjs::convert::List<(crate::wrappers::JsString, u32, f32)>,
+                >(
+                    ctx,
+                    "wrapped-tuple",
+                    rquickjs::convert::List((
+                        crate::wrappers::JsString(inner.0),
+                        inner.1,
+                        inner.2,
+                    )),
+                )
+            }
+            crate::bindings::exports::quickjs::types_in_exports::types::Var1::WrappedResult(
+                inner,
+            ) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsResult<u32, crate::wrappers::JsString>,
+                >(
+                    ctx,
+                    "wrapped-result",
+                    crate::wrappers::JsResult(
+                        match inner {
+                            Ok(v) => Ok(v),
+                            Err(v) => Err(crate::wrappers::JsString(v)),
+                        },
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::exports::quickjs::types_in_exports::types::Var1 {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "none" => {
+                Ok(
+                    crate::bindings::exports::quickjs::types_in_exports::types::Var1::None,
+                )
+            }
+            "any" => {
+                Ok(crate::bindings::exports::quickjs::types_in_exports::types::Var1::Any)
+            }
+            "specific" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(
+                    crate::bindings::exports::quickjs::types_in_exports::types::Var1::Specific(
+                        inner.0,
+                    ),
+                )
+            }
+            "many" => {
+                let inner: crate::wrappers::JsList<
+                    crate::bindings::exports::quickjs::types_in_exports::types::Rec1,
+                > = crate::wrappers::variant_value(&obj, "list<rec1>")?;
+                Ok(
+                    crate::bindings::exports::quickjs::types_in_exports::types::Var1::Many(
+                        inner.0.into_iter().map(|v| v).collect::<Vec<_>>(),
+                    ),
+                )
+            }
+            "wrapped-tuple" => {
+                let inner: rquickjs::convert::List<
+                    (crate::wrappers::JsString, u32, f32),
+                > = crate::wrappers::variant_value(&obj, "tuple<string, u32, f32>")?;
+                Ok(
+                    crate::bindings::exports::quickjs::types_in_exports::types::Var1::WrappedTuple((
+                        inner.0.0.0.clone(),
+                        inner.0.1,
+                        inner.0.2,
+                    )),
+                )
+            }
+            "wrapped-result" => {
+                let inner: crate::wrappers::JsResult<u32, crate::wrappers::JsString> = crate::wrappers::variant_value(
+                    &obj,
+                    "result<u32, string>",
+                )?;
+                Ok(
+                    crate::bindings::exports::quickjs::types_in_exports::types::Var1::WrappedResult(
+                        inner.0.map_err(|v| v.0),
+                    ),
+                )
+            }
+            _ => Err(crate::wrappers::unknown_variant_case("JS var1", "WIT var1", &tag)),
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::exports::quickjs::types_in_exports::types::Permissions {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set(
+            "read",
+            self
+                & crate::bindings::exports::quickjs::types_in_exports::types::Permissions::READ
+                == crate::bindings::exports::quickjs::types_in_exports::types::Permissions::READ,
+        )?;
+        obj.set(
+            "write",
+            self
+                & crate::bindings::exports::quickjs::types_in_exports::types::Permissions::WRITE
+                == crate::bindings::exports::quickjs::types_in_exports::types::Permissions::WRITE,
+        )?;
+        obj.set(
+            "execute",
+            self
+                & crate::bindings::exports::quickjs::types_in_exports::types::Permissions::EXECUTE
+                == crate::bindings::exports::quickjs::types_in_exports::types::Permissions::EXECUTE,
+        )?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::exports::quickjs::types_in_exports::types::Permissions {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        let mut result = crate::bindings::exports::quickjs::types_in_exports::types::Permissions::empty();
+        if obj.get("read")? {
+            result
+                |= crate::bindings::exports::quickjs::types_in_exports::types::Permissions::READ;
+        }
+        if obj.get("write")? {
+            result
+                |= crate::bindings::exports::quickjs::types_in_exports::types::Permissions::WRITE;
+        }
+        if obj.get("execute")? {
+            result
+                |= crate::bindings::exports::quickjs::types_in_exports::types::Permissions::EXECUTE;
+        }
+        Ok(result)
+    }
+}
@@ -0,0 +1,405 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:types-in-exports/types-in-exports
+// wit: sha256:c65d4cf2d90de3c5e1dacdb67056d769093806c9fe281e02f73ba6efc98aa513
+// js types-in-exports: sha256:2de3ddcb352d312f438847eb6dd96113998e51ddffcee7e39d905d443f84e89f
+
+#[allow(static_mut_refs)]
+#[allow(unsafe_op_in_unsafe_fn)]
+mod bindings;
+mod assets;
+mod builtin;
+mod conversions;
+#[allow(unused)]
+mod internal;
+#[allow(unused)]
+mod modules;
+mod wrappers;
+static JS_EXPORT_MODULE_NAME: &str = "types-in-exports";
+static JS_EXPORT_NAMES_VERBATIM: bool = false;
+static INTERN_FIELD_NAMES: bool = false;
+#[allow(dead_code)]
+static REPLACE_LONE_SURROGATES: bool = false;
+static MATH_RANDOM_SEED: crate::builtin::MathRandomSeed = crate::builtin::MathRandomSeed::Default;
+static DATE_CLOCK: Option<fn() -> f64> = None;
+static IMPORT_HOOKS: bool = false;
+static MOCK_IMPORTS: bool = false;
+static IMPORT_RECORDING: crate::builtin::ImportRecording = crate::builtin::ImportRecording::None;
+static CANCEL_BACKGROUND_WORK: bool = false;
+static HTTP_LIMITS: crate::builtin::HttpLimits = crate::builtin::HttpLimits::UNLIMITED;
+#[allow(dead_code)]
+static COOKIE_JAR: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE_LIMITS: crate::builtin::HttpCacheLimits = crate::builtin::HttpCacheLimits {
+    max_entries: Some(256usize),
+    max_size: Some(16777216usize),
+    max_body_size: Some(1048576usize),
+};
+#[allow(dead_code)]
+static FETCH_BASE_URL: Option<&str> = None;
+#[allow(dead_code)]
+static FETCH_GATEWAY: Option<&str> = None;
+static JS_EXPORT_MODULE: &str = include_str!("types-in-exports.js");
+static JS_ADDITIONAL_MODULES: std::sync::LazyLock<
+    Vec<(&str, Box<dyn (Fn() -> String) + Send + Sync>)>,
+> = std::sync::LazyLock::new(|| { vec![] });
+static JS_MODULE_ORDER: &[&str] = &["types-in-exports"];
+static JS_BUILTIN_PERMISSIONS: &[(&str, &[&str])] = &[];
+struct Component;
+impl crate::bindings::exports::quickjs::types_in_exports::types::Guest for Component {
+    fn f1(
+        a: Vec<f32>,
+        b: crate::bindings::exports::quickjs::types_in_exports::types::ListOfStrings,
+        c: Vec<String>,
+    ) -> Vec<String> {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsList<crate::wrappers::JsString> = crate::internal::call_js_export(
+                    "quickjs:types-in-exports",
+                    0usize,
+                    &["types", "f1"],
+                    crate::wrappers::JsArgs((
+                        crate::wrappers::JsList(
+                            a.into_iter().map(|v| v).collect::<Vec<_>>(),
+                        ),
+                        crate::wrappers::JsList(
+                            b
+                                .into_iter()
+                                .map(|v| crate::wrappers::JsString(v))
+                                .collect::<Vec<_>>(),
+                        ),
+                        crate::wrappers::JsList(
+                            c
+                                .into_iter()
+                                .map(|v| crate::wrappers::JsString(v))
+                                .collect::<Vec<_>>(),
+                        ),
+                    )),
+                )
+                .await;
+            result.0.into_iter().map(|v| v.0).collect::<Vec<_>>()
+        })
+    }
+    fn f2(a: Option<String>) -> Option<u32> {
+        crate::internal::async_exported_function(async move {
+            let result: Option<u32> = crate::internal::call_js_export(
+                    "quickjs:types-in-exports",
+                    1usize,
+                    &["types", "f2"],
+                    (a.map(|v| crate::wrappers::JsString(v)),),
+                )
+                .await;
+            result.map(|v| v)
+        })
+    }
+    fn f3(
+        a: bool,
+        b: i8,
+        c: i16,
+        d: i32,
+        e: i64,
+        f: u8,
+        g: u16,
+        h: u32,
+        i: u64,
+        j: f32,
+        k: f64,
+        l: char,
+        m: String,
+    ) -> (bool, i8, i16, i32, i64, u8, u16, u32, u64, f32, f64, char, String) {
+        crate::internal::async_exported_function(async move {
+            let result: rquickjs::convert::List<
+                (
+                    bool,
+                    i8,
+                    i16,
+                    i32,
+                    crate::wrappers::BigIntWrapper<i64>,
+                    u8,
+                    u16,
+                    u32,
+                    crate::wrappers::BigIntWrapper<u64>,
+                    f32,
+                    f64,
+                    char,
+                    crate::wrappers::JsString,
+                ),
+            > = crate::internal::call_js_export(
+                    "quickjs:types-in-exports",
+                    2usize,
+                    &["types", "f3"],
+                    crate::wrappers::JsArgs((
+                        a,
+                        b,
+                        c,
+                        d,
+                        crate::wrappers::BigIntWrapper(e),
+                        f,
+                        g,
+                        h,
+                        crate::wrappers::BigIntWrapper(i),
+                        j,
+                        k,
+                        l,
+                        crate::wrappers::JsString(m),
+                    )),
+                )
+                .await;
+            (
+                result.0.0,
+                result.0.1,
+                result.0.2,
+                result.0.3,
+                result.0.4.0,
+                result.0.5,
+                result.0.6,
+                result.0.7,
+                result.0.8.0,
+                result.0.9,
+                result.0.10,
+                result.0.11,
+                result.0.12.0.clone(),
+            )
+        })
+    }
+    fn f4(a: Result<i32, String>) -> Result<i32, String> {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsResult<i32, crate::wrappers::JsString> = crate::internal::call_js_export_returning_result(
+                    "quickjs:types-in-exports",
+                    3usize,
+                    &["types", "f4"],
+                    (
+                        crate::wrappers::JsResult(
+                            match a {
+                                Ok(v) => Ok(v),
+                                Err(v) => Err(crate::wrappers::JsString(v)),
+                            },
+                        ),
+                    ),
+                )
+                .await;
+            result.0.map_err(|v| v.0)
+        })
+    }
+    fn f5(a: Result<(), String>) -> Result<(), String> {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsResult<(), crate::wrappers::JsString> = crate::internal::call_js_export_returning_result(
+                    "quickjs:types-in-exports",
+                    4usize,
+                    &["types", "f5"],
+                    (
+                        crate::wrappers::JsResult(
+                            match a {
+                                Ok(v) => Ok(v),
+                                Err(v) => Err(crate::wrappers::JsString(v)),
+                            },
+                        ),
+                    ),
+                )
+                .await;
+            result.0.map_err(|v| v.0)
+        })
+    }
+    fn f6(a: Result<String, ()>) -> Result<String, ()> {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsResult<crate::wrappers::JsString, ()> = crate::internal::call_js_export_returning_result(
+                    "quickjs:types-in-exports",
+                    5usize,
+                    &["types", "f6"],
+                    (
+                        crate::wrappers::JsResult(
+                            match a {
+                                Ok(v) => Ok(crate::wrappers::JsString(v)),
+                                Err(v) => Err(v),
+                            },
+                        ),
+                    ),
+                )
+                .await;
+            result.0.map(|v| v.0)
+        })
+    }
+    fn f7(a: Result<(), ()>) -> Result<(), ()> {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsResult<(), ()> = crate::internal::call_js_export_returning_result(
+                    "quickjs:types-in-exports",
+                    6usize,
+                    &["types", "f7"],
+                    (
+                        crate::wrappers::JsResult(
+                            match a {
+                                Ok(v) => Ok(v),
+                                Err(v) => Err(v),
+                            },
+                        ),
+                    ),
+                )
+                .await;
+            result.0
+        })
+    }
+    fn f8(a: (String, u32, f32)) -> () {
+        crate::internal::async_exported_function(async move {
+            let result: () = crate::internal::call_js_export(
+                    "quickjs:types-in-exports",
+                    7usize,
+                    &["types", "f8"],
+                    (
+                        rquickjs::convert::List((
+                            crate::wrappers::JsString(a.0),
+                            a.1,
+                            a.2,
+                        )),
+                    ),
+                )
+                .await;
+            result
+        })
+    }
+    fn f9(
+        a: crate::bindings::exports::quickjs::types_in_exports::types::Rec1,
+    ) -> Option<crate::bindings::exports::quickjs::types_in_exports::types::Rec1> {
+        crate::internal::async_exported_function(async move {
+            let result: Option<
+                crate::bindings::exports::quickjs::types_in_exports::types::Rec1,
+            > = crate::internal::call_js_export(
+                    "quickjs:types-in-exports",
+                    8usize,
+                    &["types", "f9"],
+                    (a,),
+                )
+                .await;
+            result.map(|v| v)
+        })
+    }
+    fn f10(
+        a: crate::bindings::exports::quickjs::types_in_exports::types::Var1,
+    ) -> crate::bindings::exports::quickjs::types_in_exports::types::Var1 {
+        crate::internal::async_exported_function(async move {
+            let result: crate::bindings::exports::quickjs::types_in_exports::types::Var1 = crate::internal::call_js_export(
+                    "quickjs:types-in-exports",
+                    9usize,
+                    &["types", "f10"],
+                    (a,),
+                )
+                .await;
+            result
+        })
+    }
+    fn f11(
+        a: crate::bindings::exports::quickjs::types_in_exports::types::Color,
+    ) -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:types-in-exports",
+                    10usize,
+                    &["types", "f11"],
+                    (a,),
+                )
+                .await;
+            result.0
+        })
+    }
+    fn f12(
+        a: String,
+    ) -> Option<crate::bindings::exports::quickjs::types_in_exports::types::Color> {
+        crate::internal::async_exported_function(async move {
+            let result: Option<
+                crate::bindings::quickjs::types_in_exports::other_types::Color,
+            > = crate::internal::call_js_export(
+                    "quickjs:types-in-exports",
+                    11usize,
+                    &["types", "f12"],
+                    (crate::wrappers::JsString(a),),
+                )
+                .await;
+            result.map(|v| v)
+        })
+    }
+    fn f13(
+        a: crate::bindings::exports::quickjs::types_in_exports::types::Permissions,
+    ) -> crate::bindings::exports::quickjs::types_in_exports::types::Permissions {
+        crate::internal::async_exported_function(async move {
+            let result: crate::bindings::exports::quickjs::types_in_exports::types::Permissions = crate::internal::call_js_export(
+                    "quickjs:types-in-exports",
+                    12usize,
+                    &["types", "f13"],
+                    (a,),
+                )
+                .await;
+            result
+        })
+    }
+    fn f14(a: u64) -> u64 {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::BigIntWrapper<u64> = crate::internal::call_js_export(
+                    "quickjs:types-in-exports",
+                    13usize,
+                    &["types", "f14"],
+                    (crate::wrappers::BigIntWrapper(a),),
+                )
+                .await;
+            result.0
+        })
+    }
+    fn f15(bytes: Vec<u8>) -> Vec<u8> {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::UInt8Array = crate::internal::call_js_export(
+                    "quickjs:types-in-exports",
+                    14usize,
+                    &["types", "f15"],
+                    (crate::wrappers::UInt8Array(bytes),),
+                )
+                .await;
+            result.0
+        })
+    }
+    fn f16(
+        in_: Option<
+            Vec<crate::bindings::exports::quickjs::types_in_exports::types::Rec2>,
+        >,
+    ) -> Option<Vec<String>> {
+        crate::internal::async_exported_function(async move {
+            let result: Option<crate::wrappers::JsList<crate::wrappers::JsString>> = crate::internal::call_js_export(
+                    "quickjs:types-in-exports",
+                    15usize,
+                    &["types", "f16"],
+                    (
+                        in_
+                            .map(|v| crate::wrappers::JsList(
+                                v.into_iter().map(|v| v).collect::<Vec<_>>(),
+                            )),
+                    ),
+                )
+                .await;
+            result.map(|v| v.0.into_iter().map(|v| v.0).collect::<Vec<_>>())
+        })
+    }
+    fn f17(
+        in_: Option<Vec<String>>,
+    ) -> Option<Vec<crate::bindings::exports::quickjs::types_in_exports::types::Rec2>> {
+        crate::internal::async_exported_function(async move {
+            let result: Option<
+                crate::wrappers::JsList<
+                    crate::bindings::quickjs::types_in_exports::other_types::Rec2,
+                >,
+            > = crate::internal::call_js_export(
+                    "quickjs:types-in-exports",
+                    16usize,
+                    &["types", "f17"],
+                    (
+                        in_
+                            .map(|v| crate::wrappers::JsList(
+                                v
+                                    .into_iter()
+                                    .map(|v| crate::wrappers::JsString(v))
+                                    .collect::<Vec<_>>(),
+                            )),
+                    ),
+                )
+                .await;
+            result.map(|v| v.0.into_iter().map(|v| v).collect::<Vec<_>>())
+        })
+    }
+}
+bindings::export!(Component with_types_in bindings);
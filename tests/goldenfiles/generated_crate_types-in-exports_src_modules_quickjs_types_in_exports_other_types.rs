@@ -0,0 +1,20 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:types-in-exports/types-in-exports
+// wit: sha256:c65d4cf2d90de3c5e1dacdb67056d769093806c9fe281e02f73ba6efc98aa513
+// js types-in-exports: sha256:2de3ddcb352d312f438847eb6dd96113998e51ddffcee7e39d905d443f84e89f
+
+use rquickjs::JsLifetime;
+use rquickjs::class::{JsClass, Trace};
+use std::ops::Deref;
+pub struct JsOtherTypesModule;
+impl rquickjs::module::ModuleDef for JsOtherTypesModule {
+    fn declare(decl: &rquickjs::module::Declarations) -> rquickjs::Result<()> {
+        Ok(())
+    }
+    fn evaluate<'js>(
+        ctx: &rquickjs::Ctx<'js>,
+        exports: &rquickjs::module::Exports<'js>,
+    ) -> rquickjs::Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,5 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:unversioned-imports/unversioned-imports
+// wit: sha256:611a265b5291bcc14051fbdf615eb90091d6f78bb28c9ceb800ecf2d11d4fd8d
+// js unversioned-imports: sha256:94b6d6bcc3cfc6f3415bc9edc562dead309cb947da9cd5c86a5b4315aa85050e
+
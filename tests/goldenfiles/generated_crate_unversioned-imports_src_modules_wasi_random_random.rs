@@ -0,0 +1,36 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:unversioned-imports/unversioned-imports
+// wit: sha256:611a265b5291bcc14051fbdf615eb90091d6f78bb28c9ceb800ecf2d11d4fd8d
+// js unversioned-imports: sha256:94b6d6bcc3cfc6f3415bc9edc562dead309cb947da9cd5c86a5b4315aa85050e
+
+use rquickjs::JsLifetime;
+use rquickjs::class::{JsClass, Trace};
+use std::ops::Deref;
+#[rquickjs::function]
+fn get_random_bytes(
+    len: crate::wrappers::BigIntWrapper<u64>,
+) -> crate::wrappers::UInt8Array {
+    let result: Vec<u8> = crate::bindings::wasi::random::random::get_random_bytes(len.0);
+    crate::wrappers::UInt8Array(result)
+}
+#[rquickjs::function]
+fn get_random_u64() -> crate::wrappers::BigIntWrapper<u64> {
+    let result: u64 = crate::bindings::wasi::random::random::get_random_u64();
+    crate::wrappers::BigIntWrapper(result)
+}
+pub struct JsRandomModule;
+impl rquickjs::module::ModuleDef for JsRandomModule {
+    fn declare(decl: &rquickjs::module::Declarations) -> rquickjs::Result<()> {
+        decl.declare("getRandomBytes")?;
+        decl.declare("getRandomU64")?;
+        Ok(())
+    }
+    fn evaluate<'js>(
+        ctx: &rquickjs::Ctx<'js>,
+        exports: &rquickjs::module::Exports<'js>,
+    ) -> rquickjs::Result<()> {
+        exports.export("getRandomBytes", js_get_random_bytes)?;
+        exports.export("getRandomU64", js_get_random_u64)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,5 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:url/url
+// wit: sha256:3f3c6b4f8ab6d442d89ddb2aa4c136e437d3b64460888c4480a6fea3a02ec4bc
+// js url: sha256:84b23d52fc2b73fdd8c9f86908869188fdcfb89c7575b0bbafab55fe753b66fd
+
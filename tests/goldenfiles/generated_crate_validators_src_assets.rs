@@ -0,0 +1,6 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:validators/validators
+// wit: sha256:348734c1f30f5c037026f4c496a32c55a1edc029707cf05266d927dd63b16ee0
+// js validators: sha256:5aa452b370d1be4470ee04d9f7bfe3943f3d37d95d4faf41cc0d928d324e8725
+
+pub static ASSETS: &[(&str, &[u8])] = &[];
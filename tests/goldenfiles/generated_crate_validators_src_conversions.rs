@@ -0,0 +1,291 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:validators/validators
+// wit: sha256:348734c1f30f5c037026f4c496a32c55a1edc029707cf05266d927dd63b16ee0
+// js validators: sha256:5aa452b370d1be4470ee04d9f7bfe3943f3d37d95d4faf41cc0d928d324e8725
+
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::quickjs::validators::types::Customer {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::JsString,
+            >("name", crate::wrappers::JsString(self.name))?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::JsString>,
+            >("email", self.email.map(|v| crate::wrappers::JsString(v)))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::quickjs::validators::types::Customer {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT customer",
+            &["name", "email"],
+            &[("name", "string")],
+            false,
+        )?;
+        let name: crate::wrappers::JsString = crate::wrappers::get_field(
+            &obj,
+            "name",
+            "string",
+        )?;
+        let name: String = name.0;
+        let email: Option<crate::wrappers::JsString> = crate::wrappers::get_field(
+            &obj,
+            "email",
+            "option<string>",
+        )?;
+        let email: Option<String> = email.map(|v| v.0);
+        Ok(Self { name, email })
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::quickjs::validators::types::Status {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::quickjs::validators::types::Status::Pending => {
+                "pending".into_js(ctx)
+            }
+            crate::bindings::quickjs::validators::types::Status::Shipped => {
+                "shipped".into_js(ctx)
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::quickjs::validators::types::Status {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let value = crate::wrappers::enum_case(value, "JS status", "WIT status")?;
+        match value.as_str() {
+            "pending" => Ok(crate::bindings::quickjs::validators::types::Status::Pending),
+            "shipped" => Ok(crate::bindings::quickjs::validators::types::Status::Shipped),
+            _ => {
+                Err(
+                    crate::wrappers::unknown_enum_case("JS status", "WIT status", &value),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::quickjs::validators::types::Labels {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set(
+            "urgent",
+            self & crate::bindings::quickjs::validators::types::Labels::URGENT
+                == crate::bindings::quickjs::validators::types::Labels::URGENT,
+        )?;
+        obj.set(
+            "gift",
+            self & crate::bindings::quickjs::validators::types::Labels::GIFT
+                == crate::bindings::quickjs::validators::types::Labels::GIFT,
+        )?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::quickjs::validators::types::Labels {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        let mut result = crate::bindings::quickjs::validators::types::Labels::empty();
+        if obj.get("urgent")? {
+            result |= crate::bindings::quickjs::validators::types::Labels::URGENT;
+        }
+        if obj.get("gift")? {
+            result |= crate::bindings::quickjs::validators::types::Labels::GIFT;
+        }
+        Ok(result)
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::quickjs::validators::types::Payment {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        match self {
+            crate::bindings::quickjs::validators::types::Payment::Cash => {
+                crate::wrappers::variant_case(ctx, "cash")
+            }
+            crate::bindings::quickjs::validators::types::Payment::Card(inner) => {
+                crate::wrappers::variant_case_with_value::<
+                    crate::wrappers::JsString,
+                >(ctx, "card", crate::wrappers::JsString(inner))
+            }
+        }
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::quickjs::validators::types::Payment {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let (obj, tag) = crate::wrappers::variant_tag(value)?;
+        match tag.as_str() {
+            "cash" => Ok(crate::bindings::quickjs::validators::types::Payment::Cash),
+            "card" => {
+                let inner: crate::wrappers::JsString = crate::wrappers::variant_value(
+                    &obj,
+                    "string",
+                )?;
+                Ok(crate::bindings::quickjs::validators::types::Payment::Card(inner.0))
+            }
+            _ => {
+                Err(
+                    crate::wrappers::unknown_variant_case(
+                        "JS payment",
+                        "WIT payment",
+                        &tag,
+                    ),
+                )
+            }
+        }
+    }
+}
+impl<'js> rquickjs::IntoJs<'js> for crate::bindings::quickjs::validators::types::Order {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let obj = rquickjs::Object::new(ctx.clone())?;
+        obj.set::<
+                _,
+                crate::wrappers::BigIntWrapper<u64>,
+            >("id", crate::wrappers::BigIntWrapper(self.id))?;
+        obj.set::<
+                _,
+                crate::bindings::quickjs::validators::types::Customer,
+            >("customer", self.customer)?;
+        obj.set::<
+                _,
+                crate::wrappers::JsList<
+                    rquickjs::convert::List<(crate::wrappers::JsString, u32)>,
+                >,
+            >(
+            "items",
+            crate::wrappers::JsList(
+                self
+                    .items
+                    .into_iter()
+                    .map(|v| rquickjs::convert::List((
+                        crate::wrappers::JsString(v.0),
+                        v.1,
+                    )))
+                    .collect::<Vec<_>>(),
+            ),
+        )?;
+        obj.set::<
+                _,
+                crate::bindings::quickjs::validators::types::Status,
+            >("status", self.status)?;
+        obj.set::<
+                _,
+                crate::bindings::quickjs::validators::types::Labels,
+            >("labels", self.labels)?;
+        obj.set::<
+                _,
+                crate::bindings::quickjs::validators::types::Payment,
+            >("payment", self.payment)?;
+        obj.set::<
+                _,
+                Option<crate::wrappers::UInt8Array>,
+            >("attachment", self.attachment.map(|v| crate::wrappers::UInt8Array(v)))?;
+        Ok(obj.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js> for crate::bindings::quickjs::validators::types::Order {
+    fn from_js(
+        _ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let obj = rquickjs::Object::from_value(value)?;
+        crate::wrappers::check_record_fields(
+            &obj,
+            "WIT order",
+            &["id", "customer", "items", "status", "labels", "payment", "attachment"],
+            &[
+                ("id", "u64"),
+                ("customer", "customer"),
+                ("items", "list<tuple<string, u32>>"),
+                ("status", "status"),
+                ("labels", "labels"),
+                ("payment", "payment"),
+            ],
+            false,
+        )?;
+        let id: crate::wrappers::BigIntWrapper<u64> = crate::wrappers::get_field(
+            &obj,
+            "id",
+            "u64",
+        )?;
+        let id: u64 = id.0;
+        let customer: crate::bindings::quickjs::validators::types::Customer = crate::wrappers::get_field(
+            &obj,
+            "customer",
+            "customer",
+        )?;
+        let customer: crate::bindings::quickjs::validators::types::Customer = customer;
+        let items: crate::wrappers::JsList<
+            rquickjs::convert::List<(crate::wrappers::JsString, u32)>,
+        > = crate::wrappers::get_field(&obj, "items", "list<tuple<string, u32>>")?;
+        let items: Vec<(String, u32)> = items
+            .0
+            .into_iter()
+            .map(|v| (v.0.0.0.clone(), v.0.1))
+            .collect::<Vec<_>>();
+        let status: crate::bindings::quickjs::validators::types::Status = crate::wrappers::get_field(
+            &obj,
+            "status",
+            "status",
+        )?;
+        let status: crate::bindings::quickjs::validators::types::Status = status;
+        let labels: crate::bindings::quickjs::validators::types::Labels = crate::wrappers::get_field(
+            &obj,
+            "labels",
+            "labels",
+        )?;
+        let labels: crate::bindings::quickjs::validators::types::Labels = labels;
+        let payment: crate::bindings::quickjs::validators::types::Payment = crate::wrappers::get_field(
+            &obj,
+            "payment",
+            "payment",
+        )?;
+        let payment: crate::bindings::quickjs::validators::types::Payment = payment;
+        let attachment: Option<crate::wrappers::UInt8Array> = crate::wrappers::get_field(
+            &obj,
+            "attachment",
+            "option<list<u8>>",
+        )?;
+        let attachment: Option<Vec<u8>> = attachment.map(|v| v.0);
+        Ok(Self {
+            id,
+            customer,
+            items,
+            status,
+            labels,
+            payment,
+            attachment,
+        })
+    }
+}
@@ -0,0 +1,62 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:validators/validators
+// wit: sha256:348734c1f30f5c037026f4c496a32c55a1edc029707cf05266d927dd63b16ee0
+// js validators: sha256:5aa452b370d1be4470ee04d9f7bfe3943f3d37d95d4faf41cc0d928d324e8725
+
+use rquickjs::JsLifetime;
+use rquickjs::class::{JsClass, Trace};
+use std::ops::Deref;
+pub mod quickjs_validators_types;
+const NATIVE_MODULE_NAMES: &[&str] = &["quickjs:validators/types", "validators"];
+pub struct NativeModuleResolver;
+impl rquickjs::loader::Resolver for NativeModuleResolver {
+    fn resolve<'js>(
+        &mut self,
+        _ctx: &rquickjs::Ctx<'js>,
+        base: &str,
+        name: &str,
+    ) -> rquickjs::Result<String> {
+        if NATIVE_MODULE_NAMES.contains(&name) {
+            Ok(name.to_string())
+        } else {
+            Err(rquickjs::Error::new_resolving(base, name))
+        }
+    }
+}
+pub struct NativeModuleLoader;
+impl rquickjs::loader::Loader for NativeModuleLoader {
+    fn load<'js>(
+        &mut self,
+        ctx: &rquickjs::Ctx<'js>,
+        name: &str,
+    ) -> rquickjs::Result<rquickjs::Module<'js, rquickjs::module::Declared>> {
+        match name {
+            "quickjs:validators/types" => {
+                rquickjs::Module::declare_def::<
+                    crate::modules::quickjs_validators_types::JsTypesModule,
+                    _,
+                >(ctx.clone(), name)
+            }
+            "validators" => {
+                rquickjs::Module::declare(
+                    ctx.clone(),
+                    name,
+                    include_str!("validators.js"),
+                )
+            }
+            _ => Err(rquickjs::Error::new_loading(name)),
+        }
+    }
+}
+pub struct JsValidatorsModule;
+impl rquickjs::module::ModuleDef for JsValidatorsModule {
+    fn declare(decl: &rquickjs::module::Declarations) -> rquickjs::Result<()> {
+        Ok(())
+    }
+    fn evaluate<'js>(
+        ctx: &rquickjs::Ctx<'js>,
+        exports: &rquickjs::module::Exports<'js>,
+    ) -> rquickjs::Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,5 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:verbatim-names/verbatim-names
+// wit: sha256:ba32c0e6b43734727241d133e942309ae2c49a9225e20f44c8c1fb0a4150ec12
+// js verbatim-names: sha256:192a3462d3a0df378a9bc0cd02d98315b19c2437fca84880d001b309617610f0
+
@@ -0,0 +1,188 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:verbatim-names/verbatim-names
+// wit: sha256:ba32c0e6b43734727241d133e942309ae2c49a9225e20f44c8c1fb0a4150ec12
+// js verbatim-names: sha256:192a3462d3a0df378a9bc0cd02d98315b19c2437fca84880d001b309617610f0
+
+#[allow(static_mut_refs)]
+#[allow(unsafe_op_in_unsafe_fn)]
+mod bindings;
+mod assets;
+mod builtin;
+mod conversions;
+#[allow(unused)]
+mod internal;
+#[allow(unused)]
+mod modules;
+mod wrappers;
+static JS_EXPORT_MODULE_NAME: &str = "verbatim-names";
+static JS_EXPORT_NAMES_VERBATIM: bool = true;
+static INTERN_FIELD_NAMES: bool = false;
+#[allow(dead_code)]
+static REPLACE_LONE_SURROGATES: bool = false;
+static MATH_RANDOM_SEED: crate::builtin::MathRandomSeed = crate::builtin::MathRandomSeed::Default;
+static DATE_CLOCK: Option<fn() -> f64> = None;
+static IMPORT_HOOKS: bool = false;
+static MOCK_IMPORTS: bool = false;
+static IMPORT_RECORDING: crate::builtin::ImportRecording = crate::builtin::ImportRecording::None;
+static CANCEL_BACKGROUND_WORK: bool = false;
+static HTTP_LIMITS: crate::builtin::HttpLimits = crate::builtin::HttpLimits::UNLIMITED;
+#[allow(dead_code)]
+static COOKIE_JAR: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE: bool = false;
+#[allow(dead_code)]
+static HTTP_CACHE_LIMITS: crate::builtin::HttpCacheLimits = crate::builtin::HttpCacheLimits {
+    max_entries: Some(256usize),
+    max_size: Some(16777216usize),
+    max_body_size: Some(1048576usize),
+};
+#[allow(dead_code)]
+static FETCH_BASE_URL: Option<&str> = None;
+#[allow(dead_code)]
+static FETCH_GATEWAY: Option<&str> = None;
+static JS_EXPORT_MODULE: &str = include_str!("verbatim-names.js");
+static JS_ADDITIONAL_MODULES: std::sync::LazyLock<
+    Vec<(&str, Box<dyn (Fn() -> String) + Send + Sync>)>,
+> = std::sync::LazyLock::new(|| { vec![] });
+static JS_MODULE_ORDER: &[&str] = &["verbatim-names"];
+static JS_BUILTIN_PERMISSIONS: &[(&str, &[&str])] = &[];
+struct Component;
+impl crate::bindings::Guest for Component {
+    fn say_hello(name: String) -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:verbatim-names",
+                    0usize,
+                    &["say-hello"],
+                    (crate::wrappers::JsString(name),),
+                )
+                .await;
+            result.0
+        })
+    }
+}
+struct WordCounter {
+    resource_id: usize,
+}
+impl crate::bindings::exports::quickjs::verbatim_names::text_tools::GuestWordCounter
+for WordCounter {
+    fn new(text: String) -> Self {
+        crate::internal::async_exported_function(async move {
+            let resource_id = crate::internal::call_js_resource_constructor(
+                    "quickjs:verbatim-names",
+                    &["text-tools", "word-counter"],
+                    (crate::wrappers::JsString(text),),
+                )
+                .await;
+            Self { resource_id }
+        })
+    }
+    fn count_words(&self) -> u32 {
+        crate::internal::async_exported_function(async move {
+            let result: u32 = crate::internal::call_js_resource_method(
+                    "quickjs:verbatim-names",
+                    &["text-tools", "word-counter"],
+                    self.resource_id,
+                    "count-words",
+                    crate::wrappers::JsArgs(()),
+                )
+                .await;
+            result
+        })
+    }
+}
+impl Drop for WordCounter {
+    fn drop(&mut self) {
+        crate::internal::enqueue_drop_js_resource(self.resource_id);
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::exports::quickjs::verbatim_names::text_tools::WordCounterBorrow<
+    '_,
+> {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let inner: &WordCounter = self.get();
+        let resource_table: rquickjs::Object = ctx
+            .globals()
+            .get(crate::internal::RESOURCE_TABLE_NAME)
+            .expect("Failed to get the resource table");
+        let resource_instance: rquickjs::Object = resource_table
+            .get(inner.resource_id.to_string())
+            .expect(
+                &format!("Failed to get resource instance with id {}", inner.resource_id),
+            );
+        Ok(resource_instance.into_value())
+    }
+}
+impl<'js> rquickjs::IntoJs<'js>
+for crate::bindings::exports::quickjs::verbatim_names::text_tools::WordCounter {
+    fn into_js(
+        self,
+        ctx: &rquickjs::Ctx<'js>,
+    ) -> rquickjs::Result<rquickjs::Value<'js>> {
+        let inner: &WordCounter = self.get();
+        let resource_table: rquickjs::Object = ctx
+            .globals()
+            .get(crate::internal::RESOURCE_TABLE_NAME)
+            .expect("Failed to get the resource table");
+        let resource_instance: rquickjs::Object = resource_table
+            .get(inner.resource_id.to_string())
+            .expect(
+                &format!("Failed to get resource instance with id {}", inner.resource_id),
+            );
+        Ok(resource_instance.into_value())
+    }
+}
+impl<'js> rquickjs::FromJs<'js>
+for crate::bindings::exports::quickjs::verbatim_names::text_tools::WordCounter {
+    fn from_js(
+        ctx: &rquickjs::Ctx<'js>,
+        value: rquickjs::Value<'js>,
+    ) -> rquickjs::Result<Self> {
+        let resource = value
+            .into_object()
+            .ok_or_else(|| {
+                rquickjs::Error::new_from_js_message(
+                    "JS Resource instance",
+                    "WASM resource instance",
+                    "The value is not an object",
+                )
+            })?;
+        let already_registered = resource
+            .contains_key(crate::internal::RESOURCE_ID_KEY)?;
+        let resource_id: usize = if already_registered {
+            resource.get(crate::internal::RESOURCE_ID_KEY)?
+        } else {
+            let resource_table: rquickjs::Object = ctx
+                .globals()
+                .get(crate::internal::RESOURCE_TABLE_NAME)?;
+            let resource_id = crate::internal::get_free_resource_id();
+            resource_table.set(resource_id.to_string(), resource)?;
+            resource_id
+        };
+        Ok(
+            crate::bindings::exports::quickjs::verbatim_names::text_tools::WordCounter::new(WordCounter {
+                resource_id,
+            }),
+        )
+    }
+}
+impl crate::bindings::exports::quickjs::verbatim_names::text_tools::Guest for Component {
+    type WordCounter = WordCounter;
+    fn to_upper_case(value: String) -> String {
+        crate::internal::async_exported_function(async move {
+            let result: crate::wrappers::JsString = crate::internal::call_js_export(
+                    "quickjs:verbatim-names",
+                    1usize,
+                    &["text-tools", "to-upper-case"],
+                    (crate::wrappers::JsString(value),),
+                )
+                .await;
+            result.0
+        })
+    }
+}
+bindings::export!(Component with_types_in bindings);
@@ -1,3 +1,7 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:all-golem-imports/all-golem-imports
+// wit: sha256:3ec76077ee8750a4a3d97cf8f89273803d43fba31aa02651185015b5648e2fc7
+
 declare module 'golem:durability/durability@1.2.1' {
   import * as golemApi117Host from 'golem:api/host@1.1.7';
   import * as golemApi117Oplog from 'golem:api/oplog@1.1.7';
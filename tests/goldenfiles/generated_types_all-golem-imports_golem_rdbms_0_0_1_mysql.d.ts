@@ -1,3 +1,7 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:all-golem-imports/all-golem-imports
+// wit: sha256:3ec76077ee8750a4a3d97cf8f89273803d43fba31aa02651185015b5648e2fc7
+
 declare module 'golem:rdbms/mysql@0.0.1' {
   import * as golemRdbms001Types from 'golem:rdbms/types@0.0.1';
   export class DbResultStream {
@@ -0,0 +1,37 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:all-golem-imports/all-golem-imports
+// wit: sha256:3ec76077ee8750a4a3d97cf8f89273803d43fba31aa02651185015b5648e2fc7
+
+/**
+ * Reading the requests received by an exported `wasi:http/incoming-handler`, built on top of
+ * `wasi:http/types`.
+ */
+declare module 'incoming-http' {
+  import type { IncomingRequest } from 'wasi:http/types@0.2.3';
+  /**
+   * A received request, as returned by `readRequest`.
+   */
+  export interface ReceivedRequest {
+    method: string;
+    pathWithQuery: string | undefined;
+    /**
+     * The headers by their names, with the values of repeated headers joined by `, `.
+     */
+    headers: Record<string, string>;
+    body: Uint8Array;
+    /**
+     * The body decoded as UTF-8.
+     */
+    text(): string;
+    /**
+     * The body parsed as JSON.
+     */
+    json<T = unknown>(): T;
+  }
+  /**
+   * Reads the method, headers and body of a request received by the `handle` function of
+   * `wasi:http/incoming-handler`, consuming its body. Throws a `TypeError` if the request exceeds
+   * the header count or request body size limits of the `http` feature.
+   */
+  export function readRequest(request: IncomingRequest): ReceivedRequest;
+}
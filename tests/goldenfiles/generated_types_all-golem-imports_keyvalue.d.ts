@@ -0,0 +1,66 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:all-golem-imports/all-golem-imports
+// wit: sha256:3ec76077ee8750a4a3d97cf8f89273803d43fba31aa02651185015b5648e2fc7
+
+/**
+ * An async, `Map`-like API for key-value stores, built on top of `wasi:keyvalue`.
+ */
+declare module 'keyvalue' {
+  /**
+   * Thrown when the key-value store fails, with the trace of the store's error in the message.
+   */
+  export class KeyValueError extends Error {
+  }
+  /**
+   * The values accepted by `Bucket.set`. Strings are stored UTF-8 encoded.
+   */
+  export type Value = string | Uint8Array | ArrayBuffer | ArrayBufferView;
+  /**
+   * A bucket of the key-value store, opened by `openBucket`.
+   */
+  export class Bucket implements AsyncIterable<[string, Uint8Array]> {
+    readonly name: string;
+    /**
+     * Gets the value of a key, or `undefined` if the key does not exist.
+     */
+    get(key: string): Promise<Uint8Array | undefined>;
+    /**
+     * Gets the value of a key decoded as UTF-8.
+     */
+    getText(key: string): Promise<string | undefined>;
+    /**
+     * Gets the value of a key parsed as JSON.
+     */
+    getJson<T = unknown>(key: string): Promise<T | undefined>;
+    /**
+     * Sets the value of a key, replacing the existing value.
+     */
+    set(key: string, value: Value): Promise<void>;
+    /**
+     * Sets the value of a key to the JSON serialization of `value`.
+     */
+    setJson(key: string, value: unknown): Promise<void>;
+    /**
+     * Deletes a key. Deleting a key that does not exist is not an error.
+     */
+    delete(key: string): Promise<void>;
+    /**
+     * Checks whether a key exists.
+     */
+    has(key: string): Promise<boolean>;
+    /**
+     * Lists the keys of the bucket. Requires the world to import `wasi:keyvalue/eventual-batch`.
+     */
+    keys(): Promise<string[]>;
+    /**
+     * Iterates over the keys and values of the bucket. Requires the world to import
+     * `wasi:keyvalue/eventual-batch`.
+     */
+    entries(): AsyncIterableIterator<[string, Uint8Array]>;
+    [Symbol.asyncIterator](): AsyncIterableIterator<[string, Uint8Array]>;
+  }
+  /**
+   * Opens a bucket of the key-value store by its name.
+   */
+  export function openBucket(name: string): Promise<Bucket>;
+}
@@ -1,3 +1,7 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:all-golem-imports/all-golem-imports
+// wit: sha256:3ec76077ee8750a4a3d97cf8f89273803d43fba31aa02651185015b5648e2fc7
+
 declare module 'wasi:sockets/ip-name-lookup@0.2.3' {
   import * as wasiIo023Poll from 'wasi:io/poll@0.2.3';
   import * as wasiSockets023Network from 'wasi:sockets/network@0.2.3';
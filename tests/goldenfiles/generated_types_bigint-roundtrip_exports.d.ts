@@ -1,3 +1,7 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:bigint-roundtrip/bigint-roundtrip
+// wit: sha256:c4100f6fe2994b9b3d4b908577d5287c1d52bb412e618731e7761a4285305511
+
 declare module 'bigint-roundtrip' {
   export function roundtripU64(v: bigint): Promise<bigint>;
   export function roundtripS64(v: bigint): Promise<bigint>;
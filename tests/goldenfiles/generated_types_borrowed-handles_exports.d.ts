@@ -0,0 +1,22 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:borrowed-handles/borrowed-handles
+// wit: sha256:44131dc2e3018218c9da8dd7da6eb227e15d9aec9a7422e89f82bb50a4cbecb5
+
+declare module 'borrowed-handles' {
+  import * as quickjsBorrowedHandlesCounters from 'quickjs:borrowed-handles/counters';
+  export namespace api {
+    /**
+     * Gets the value of a counter owned by the caller
+     */
+    export function peek(c: Counter): Promise<number>;
+    /**
+     * Uses the counter passed to the last `peek` call, after it has returned
+     */
+    export function peekAgain(): Promise<string>;
+    /**
+     * Takes the ownership of a counter
+     */
+    export function consume(c: Counter): Promise<number>;
+    export type Counter = quickjsBorrowedHandlesCounters.Counter;
+  }
+}
@@ -0,0 +1,14 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:borrowed-handles/borrowed-handles
+// wit: sha256:44131dc2e3018218c9da8dd7da6eb227e15d9aec9a7422e89f82bb50a4cbecb5
+
+/**
+ * Resource implemented by the host
+ */
+declare module 'quickjs:borrowed-handles/counters' {
+  export class Counter {
+    constructor();
+    increment(): void;
+    get(): number;
+  }
+}
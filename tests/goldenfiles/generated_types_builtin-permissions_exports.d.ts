@@ -0,0 +1,10 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:builtin-permissions/builtin-permissions
+// wit: sha256:4485e35180497639ddae8cfe68b6d701f58d865522be090e1182374ceaeee681
+
+declare module 'builtin-permissions' {
+  export namespace api {
+    export function entryImport(name: string): Promise<string>;
+    export function pluginImport(name: string): Promise<string>;
+  }
+}
@@ -0,0 +1,24 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:byte-buffers/byte-buffers
+// wit: sha256:b0744d7b93ab700d61a0cd92c6aec8133655ca920488b1eb84da85c65e528188
+
+declare module 'byte-buffers' {
+  export namespace api {
+    /**
+     * Returns the received array
+     */
+    export function echo(bytes: Uint8Array): Promise<Uint8Array>;
+    /**
+     * Returns a view of the received array without its first byte
+     */
+    export function echoTail(bytes: Uint8Array): Promise<Uint8Array>;
+    /**
+     * Returns a copy of the received array allocated by JS
+     */
+    export function copy(bytes: Uint8Array): Promise<Uint8Array>;
+    /**
+     * Byte length of the array received by the last call
+     */
+    export function lastByteLength(): Promise<number>;
+  }
+}
@@ -0,0 +1,12 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:call-cancellation/call-cancellation
+// wit: sha256:2c3b11fa6f26c198e13d36cebb40d72335bc364fe7b734ed960c686f4eaf16dd
+
+declare module 'call-cancellation' {
+  export namespace api {
+    export function leaveTimer(): Promise<string>;
+    export function cancelItself(): Promise<string>;
+    export function signalPerCall(): Promise<boolean>;
+    export function cancelFetch(url: string): Promise<string>;
+  }
+}
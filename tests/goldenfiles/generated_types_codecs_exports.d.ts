@@ -0,0 +1,9 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:codecs/codecs
+// wit: sha256:f307449766ddeb49fd6f3ceb53e53fb58a878ed53a61eb85fe0cb34dcbfd9cc8
+
+declare module 'codecs' {
+  export function encodeHex(format: string, json: string): Promise<string>;
+  export function decodeHex(format: string, hex: string): Promise<string>;
+  export function roundTrip(format: string): Promise<string[]>;
+}
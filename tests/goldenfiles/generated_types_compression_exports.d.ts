@@ -0,0 +1,9 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:compression/compression
+// wit: sha256:6c59e8bbac2274db62808ac40562fb159b6b92a09a36b42a27b0f2b22910709d
+
+declare module 'compression' {
+  export function compressHex(format: string, text: string, level: number | undefined): Promise<string>;
+  export function decompressHex(format: string, hex: string, maxLength: number | undefined): Promise<string>;
+  export function roundTrip(format: string, size: number): Promise<string>;
+}
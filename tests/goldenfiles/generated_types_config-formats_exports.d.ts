@@ -0,0 +1,13 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:config-formats/config-formats
+// wit: sha256:14359d0ab36111fcab5b2adec25804cc06da87f00150d87440a4de350eb99f20
+
+declare module 'config-formats' {
+  export function tomlToJson(text: string): Promise<string>;
+  export function jsonToToml(json: string): Promise<string>;
+  export function yamlToJson(text: string): Promise<string>;
+  export function yamlDocuments(text: string): Promise<string[]>;
+  export function jsonToYaml(json: string): Promise<string>;
+  export function roundTrip(): Promise<string[]>;
+  export function errors(): Promise<string[]>;
+}
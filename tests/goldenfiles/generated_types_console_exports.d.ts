@@ -1,3 +1,7 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:console/console
+// wit: sha256:f151c94359b908b01a7e2a9851002559516ce82bf9d03329c4f62b3a22919f1f
+
 declare module 'console' {
   export function run(): Promise<void>;
 }
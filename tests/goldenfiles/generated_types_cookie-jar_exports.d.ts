@@ -0,0 +1,8 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:cookie-jar/cookie-jar
+// wit: sha256:bfd54239329fd2ba781065e0a34a657c57d75f387b13965a60c64ab3ed3528c9
+
+declare module 'cookie-jar' {
+  export function visit(url: string, credentials: string): Promise<string>;
+  export function visitWithCookie(url: string, cookie: string): Promise<string>;
+}
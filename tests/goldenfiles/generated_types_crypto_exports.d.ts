@@ -1,3 +1,7 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:crypto/crypto
+// wit: sha256:ec77b117e15693650db63c56521441c57e396c69db4aeec2508f3f476c6fe992
+
 declare module 'crypto' {
   export function newUuids(): Promise<[string, string]>;
   export function randomS8(count: number): Promise<number[]>;
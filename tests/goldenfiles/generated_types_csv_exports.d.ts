@@ -0,0 +1,27 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:csv/csv
+// wit: sha256:2d3e97f0573d80a338b6bf82a3ed57b6c909c9cbf630fe099a26cdc706c542ba
+
+declare module 'csv' {
+  /**
+   * Parses a CSV text split into the given chunks, returning the number of sales and the sum of
+   * their `amount` column
+   */
+  export function totalSales(chunks: Uint8Array[]): Promise<[number, number]>;
+  /**
+   * Parses a whole CSV text with the given delimiter
+   */
+  export function parseTable(text: string, delimiter: string): Promise<string[][]>;
+  /**
+   * Serializes the given products as a CSV text with a header row
+   */
+  export function report(products: [string, number][]): Promise<string>;
+  /**
+   * Streams a CSV text through the parser and the serializer, with all the fields quoted
+   */
+  export function roundTrip(text: string): Promise<string>;
+  /**
+   * Returns the errors of invalid inputs and options
+   */
+  export function errors(): Promise<string[]>;
+}
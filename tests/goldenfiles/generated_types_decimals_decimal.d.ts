@@ -0,0 +1,38 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:decimals/decimals
+// wit: sha256:29dd8e1abe0b85347997eda142ce6c7376ea40fb53cf6a82d3b248c0e746ad8d
+
+/**
+ * A decimal number with up to 28 significant digits, represented exactly. WIT strings configured as
+ * decimal types are passed to JavaScript as instances of this class, and can be returned as
+ * instances of it, strings or numbers.
+ */
+declare class Decimal {
+  constructor(value: Decimal | string | number | bigint);
+  /** The number of digits after the decimal point */
+  readonly scale: number;
+  add(other: Decimal | string | number | bigint): Decimal;
+  sub(other: Decimal | string | number | bigint): Decimal;
+  mul(other: Decimal | string | number | bigint): Decimal;
+  /** @throws RangeError when dividing by zero */
+  div(other: Decimal | string | number | bigint): Decimal;
+  /** @throws RangeError when dividing by zero */
+  rem(other: Decimal | string | number | bigint): Decimal;
+  neg(): Decimal;
+  abs(): Decimal;
+  /** Rounds to the given number of digits after the decimal point, rounding half away from zero */
+  round(digits?: number): Decimal;
+  /** Returns -1, 0 or 1 */
+  cmp(other: Decimal | string | number | bigint): number;
+  eq(other: Decimal | string | number | bigint): boolean;
+  lt(other: Decimal | string | number | bigint): boolean;
+  lte(other: Decimal | string | number | bigint): boolean;
+  gt(other: Decimal | string | number | bigint): boolean;
+  gte(other: Decimal | string | number | bigint): boolean;
+  isZero(): boolean;
+  isNegative(): boolean;
+  toFixed(digits?: number): string;
+  toNumber(): number;
+  toString(): string;
+  toJSON(): string;
+}
@@ -0,0 +1,29 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:decimals/decimals
+// wit: sha256:29dd8e1abe0b85347997eda142ce6c7376ea40fb53cf6a82d3b248c0e746ad8d
+
+declare module 'decimals' {
+  import * as quickjsDecimalsTypes from 'quickjs:decimals/types';
+  /**
+   * Sums the prices of the items multiplied by their quantities
+   */
+  export function total(items: LineItem[]): Promise<Money>;
+  /**
+   * Applies a tax rate given in percents, rounding to cents
+   */
+  export function withTax(amount: Money, rate: Money): Promise<Money>;
+  /**
+   * Splits an amount into equal shares, the last share getting the remainder
+   */
+  export function split(amount: Money, shares: number): Promise<Money[]>;
+  /**
+   * Returns the largest of the amounts, if any
+   */
+  export function largest(amounts: Money[]): Promise<Money | undefined>;
+  /**
+   * Tries to divide by zero, returning the error
+   */
+  export function divideByZero(amount: Money): Promise<string>;
+  export type Money = quickjsDecimalsTypes.Money;
+  export type LineItem = quickjsDecimalsTypes.LineItem;
+}
@@ -0,0 +1,15 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:decimals/decimals
+// wit: sha256:29dd8e1abe0b85347997eda142ce6c7376ea40fb53cf6a82d3b248c0e746ad8d
+
+declare module 'quickjs:decimals/types' {
+  /**
+   * An amount of money, represented by the builtin `Decimal` class in JS
+   */
+  export type Money = Decimal;
+  export type LineItem = {
+    name: string;
+    price: Money;
+    quantity: number;
+  };
+}
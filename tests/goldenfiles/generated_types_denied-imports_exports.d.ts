@@ -0,0 +1,9 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:denied-imports/denied-imports
+// wit: sha256:8064a397f806e1fb91160569cf35339ea24128cef90b8f324214ecbc03b4a583
+
+declare module 'denied-imports' {
+  export namespace api {
+    export function tryRandom(): Promise<string>;
+  }
+}
@@ -1,3 +1,7 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:encoding/encoding
+// wit: sha256:81acc88a00756cd4a7fcb55d4f0071927926a09e90af362cc28eec033c1c0ea9
+
 declare module 'encoding' {
   export function test1(): Promise<void>;
 }
@@ -0,0 +1,7 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:enum-constants/enum-constants
+// wit: sha256:0832cec2e3fa69df3985a31a1798ba9d4a363df8664e63f5488b3d07bd2ead6a
+
+declare module 'enums' {
+  export const LogLevel: { readonly Debug: 'debug'; readonly Info: 'info'; readonly Warning: 'warning'; readonly OutOfRange: 'out-of-range' };
+}
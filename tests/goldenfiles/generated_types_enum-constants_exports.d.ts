@@ -0,0 +1,22 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:enum-constants/enum-constants
+// wit: sha256:0832cec2e3fa69df3985a31a1798ba9d4a363df8664e63f5488b3d07bd2ead6a
+
+declare module 'enum-constants' {
+  import * as quickjsEnumConstantsTypes from 'quickjs:enum-constants/types';
+  export namespace api {
+    /**
+     * Gets the log level of a verbosity, using the constants of the `enums` module
+     */
+    export function levelFor(verbosity: number): Promise<LogLevel>;
+    /**
+     * Checks whether a log level is a warning by comparing it to a constant
+     */
+    export function isWarning(level: LogLevel): Promise<boolean>;
+    /**
+     * Lists the properties of the `LogLevel` constant object and whether it is frozen
+     */
+    export function describeConstants(): Promise<string>;
+    export type LogLevel = quickjsEnumConstantsTypes.LogLevel;
+  }
+}
@@ -1,9 +1,15 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:errors/errors
+// wit: sha256:e4ff3efde332e0690c88f95f019091d8f02d906d4b1234aefcfbf0aab7b0ef56
+
 declare module 'errors' {
   export function fun1(name: string): Promise<string>;
   export function fun3(): Promise<string>;
   export function fun4(a: number, b: number): Promise<string>;
   export function fun5(a: number, b: string): Promise<string>;
   export function fun6(): Promise<number>;
+  export function loneSurrogate(): Promise<string>;
+  export function fail(): Promise<string>;
   export namespace api {
     export function fun2(name: string): Promise<string>;
   }
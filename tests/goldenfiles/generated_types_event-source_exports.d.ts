@@ -0,0 +1,7 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:event-source/event-source
+// wit: sha256:01ab3c824b47d3902e36d53f239b9e6ce7b79c56cb94f59868de8f98c5e32505
+
+declare module 'event-source' {
+  export function collect(url: string, count: number): Promise<string[]>;
+}
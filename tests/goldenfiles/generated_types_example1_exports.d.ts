@@ -1,3 +1,7 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:example1/example1
+// wit: sha256:96fc5a64a24d27e91e54808e45f1f1700c743d020d3596e6b286637e51eac4e2
+
 declare module 'example1' {
   export function hello(name: string): Promise<string>;
   export function asyncHello(name: string): Promise<string>;
@@ -1,3 +1,7 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:example2/example2
+// wit: sha256:59ec9a4d559e00f0833eb3f79ec92f404ca4554c1a5fbbf8930db050836d24cb
+
 declare module 'example2' {
   export function version(): Promise<string>;
   export namespace exp1 {
@@ -1,3 +1,7 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:example3/example3
+// wit: sha256:c5b869b96ba916e25c7d6b3e6d9b60ad23271e17fc766dd386f9229e1c1e6827
+
 declare module 'example3' {
   /**
    * The exported interface
@@ -0,0 +1,13 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:example4/example4
+// wit: sha256:0b8fd1289107302bf93945e9633742a7a31e303bc2a469003bf1243c215255a1
+
+declare module 'example4' {
+  /**
+   * Anonymous interface export, imported by the imports4 example
+   */
+  export namespace greeter {
+    export function greet(name: string): Promise<string>;
+    export function greetAll(names: string[]): Promise<string[]>;
+  }
+}
@@ -1,3 +1,7 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:export-from-inner-package/export-from-inner-package
+// wit: sha256:c0baee3fee9ee6ff541710364bfabd25ff445057e8cf3b2af9497824dcfd305e
+
 declare module 'export-from-inner-package' {
   export namespace exp1 {
     export function hello(name: string): Promise<string>;
@@ -0,0 +1,14 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:feature-pragmas/feature-pragmas
+// wit: sha256:458887e9f528536c1ff2fe2fca732b9b5f6bee748dae40f9fa18a22448509a3d
+
+declare module 'feature-pragmas' {
+  /**
+   * The regions of the JS module kept for the enabled cargo features
+   */
+  export function keptRegions(): Promise<string[]>;
+  /**
+   * Describes how the given message would be reported
+   */
+  export function report(message: string): Promise<string>;
+}
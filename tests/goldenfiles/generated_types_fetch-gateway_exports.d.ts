@@ -0,0 +1,8 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:fetch-gateway/fetch-gateway
+// wit: sha256:bda89df07bf8b272800c86e93a4b4d55be44a0fa588caa63460998fbbdce3d60
+
+declare module 'fetch-gateway' {
+  export function fetchText(url: string): Promise<string>;
+  export function fetchError(url: string): Promise<string>;
+}
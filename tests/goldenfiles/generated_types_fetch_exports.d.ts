@@ -1,3 +1,7 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:fetch/fetch
+// wit: sha256:7acd73112f80f7af6788a708677e18b167b512561e9c9ce53ee3d36f0159e30e
+
 declare module 'fetch' {
   export function test1(port: number): Promise<void>;
   export function test2(port: number): Promise<void>;
@@ -10,4 +14,5 @@ declare module 'fetch' {
   export function test8(port: number): Promise<void>;
   export function test9(port: number): Promise<void>;
   export function test10(port: number): Promise<void>;
+  export function test11(port: number): Promise<void>;
 }
@@ -0,0 +1,12 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:fixed-clock/fixed-clock
+// wit: sha256:94e6822168aac66dbcbc6234723f25e4c5b4ff93d4663491486c614d7e3c0496
+
+declare module 'fixed-clock' {
+  export namespace api {
+    export function now(): Promise<number>;
+    export function nowIso(): Promise<string>;
+    export function epochIso(): Promise<string>;
+    export function isDate(): Promise<boolean>;
+  }
+}
@@ -0,0 +1,10 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:form-data/form-data
+// wit: sha256:99861966886a1a111a14c49fcc7b3dd1131cfaf804749b883be6db072fbaf258
+
+declare module 'form-data' {
+  export function roundTrip(): Promise<string>;
+  export function urlEncoded(): Promise<string>;
+  export function postUrlEncoded(port: number): Promise<string>;
+  export function parseResponse(port: number): Promise<string>;
+}
@@ -1,3 +1,7 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:fs/fs
+// wit: sha256:9edc15a24f4e17a7aa02d28ec7a8fcd333e189899cf01a6a6fbcfa2490e3c7f5
+
 declare module 'fs' {
   export function runAsync(): Promise<void>;
   export function run(): Promise<void>;
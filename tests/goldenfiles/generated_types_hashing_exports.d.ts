@@ -0,0 +1,11 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:hashing/hashing
+// wit: sha256:90415c6ba73da793277116b376f0a670a98be7a523181b76def118e05caf9c31
+
+declare module 'hashing' {
+  export function hashText(algorithm: string, text: string, encoding: string): Promise<string>;
+  export function hmacText(algorithm: string, key: string, text: string): Promise<string>;
+  export function checksum(chunks: string[]): Promise<number>;
+  export function signingKey(secret: string, date: string, region: string, service: string): Promise<string>;
+  export function errors(): Promise<string[]>;
+}
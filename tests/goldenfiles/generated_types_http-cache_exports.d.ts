@@ -0,0 +1,8 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:http-cache/http-cache
+// wit: sha256:8d56384f2409063e66a24fb92e5cbacf1e85974df2e6e6c7a9400f576b9be6cb
+
+declare module 'http-cache' {
+  export function fetchWithCache(url: string, cache: string): Promise<string>;
+  export function fetchRequestWithCookie(url: string, requestCookie: string, cookie: string): Promise<string>;
+}
@@ -0,0 +1,27 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:http-handler/http-handler
+// wit: sha256:50e4a1bebab498e752ae328835ec3c85cfd815c061dfa531fe9a22921a588468
+
+declare module 'http-handler' {
+  import * as wasiHttp023Types from 'wasi:http/types@0.2.3';
+  /**
+   * This interface defines a handler of incoming HTTP Requests. It should
+   * be exported by components which can respond to HTTP Requests.
+   */
+  export namespace incomingHandler {
+    /**
+     * This function is invoked with an incoming HTTP Request, and a resource
+     * `response-outparam` which provides the capability to reply with an HTTP
+     * Response. The response is sent by calling the `response-outparam.set`
+     * method, which allows execution to continue after the response has been
+     * sent. This enables both streaming to the response body, and performing other
+     * work.
+     * The implementor of this function must write a response to the
+     * `response-outparam` before returning, or else the caller will respond
+     * with an error on its behalf.
+     */
+    export function handle(request: IncomingRequest, responseOut: ResponseOutparam): Promise<void>;
+    export type IncomingRequest = wasiHttp023Types.IncomingRequest;
+    export type ResponseOutparam = wasiHttp023Types.ResponseOutparam;
+  }
+}
@@ -0,0 +1,17 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:http-handler/http-handler
+// wit: sha256:50e4a1bebab498e752ae328835ec3c85cfd815c061dfa531fe9a22921a588468
+
+declare module 'wasi:io/error@0.2.3' {
+  export class Error {
+    /**
+     * Returns a string that is suitable to assist humans in debugging
+     * this error.
+     * WARNING: The returned string should not be consumed mechanically!
+     * It may change across platforms, hosts, or other implementation
+     * details. Parsing this string is a major platform-compatibility
+     * hazard.
+     */
+    toDebugString(): string;
+  }
+}
@@ -0,0 +1,9 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:http-limits/http-limits
+// wit: sha256:c3bbe48ee3128d9072ebddc87d61f44df250dc4168acec302de144c09c900b94
+
+declare module 'http-limits' {
+  export function fetchText(url: string): Promise<string>;
+  export function readStream(url: string): Promise<string>;
+  export function postText(url: string, body: string): Promise<string>;
+}
@@ -0,0 +1,12 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:ids/ids
+// wit: sha256:5cf723036b16e4c612e8f00bde9f13ee92036edcbe849d35aa1f9a258a0680d1
+
+declare module 'ids' {
+  export function uuidV4(): Promise<string>;
+  export function uuidV7(): Promise<string>;
+  export function ulid(): Promise<string>;
+  export function uuidVersion(id: string): Promise<number | undefined>;
+  export function ulidTime(id: string): Promise<bigint | undefined>;
+  export function inOrder(count: number): Promise<boolean>;
+}
@@ -0,0 +1,11 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:import-hooks/import-hooks
+// wit: sha256:5630e7138f5d717cdfd1ad0a41669e0cca54dba0349fd0d45efa784d6c34ab77
+
+declare module 'import-hooks' {
+  export namespace api {
+    export function mockedNumber(): Promise<bigint>;
+    export function byteCount(len: number): Promise<number>;
+    export function calls(): Promise<string[]>;
+  }
+}
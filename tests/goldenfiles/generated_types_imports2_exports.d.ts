@@ -1,3 +1,7 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:imports2/imports2
+// wit: sha256:4578efe1209456a7fa4b644612afec7f426af4990809f0b0a9f91a5a0d51dce7
+
 declare module 'imports2' {
   export function test(name: string): Promise<string>;
   export function testStaticCreate(name: string): Promise<string>;
@@ -1,3 +1,7 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:imports2/imports2
+// wit: sha256:4578efe1209456a7fa4b644612afec7f426af4990809f0b0a9f91a5a0d51dce7
+
 declare module 'quickjs:example3/iface' {
   export function dump(h: Hello | undefined): string;
   export function dumpAll(hs: Hello[]): string;
@@ -1,3 +1,7 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:imports3/imports3
+// wit: sha256:167b2b1d4f217517a49747ec35541ed70720785a02845803b2062c9e2f32b52b
+
 declare module 'quickjs:types-in-exports/other-types' {
   export type ListOfStrings = string[];
   export type Rec2 = {
@@ -0,0 +1,11 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:imports4/imports4
+// wit: sha256:7e1432460751d1890c6ee3207247e424befb4a1e864b6bf7c1d76dbf23d21eba
+
+/**
+ * Anonymous interface import, implemented by the example4 example
+ */
+declare module 'quickjs:imports4/imports4/greeter' {
+  export function greet(name: string): string;
+  export function greetAll(names: string[]): string[];
+}
@@ -0,0 +1,10 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:imports5/imports5
+// wit: sha256:0fff48cb2b529f47fd07ca2a7b171e0bc1a79090d40eb427eb7e46713d6bb129
+
+declare module 'world-imports' {
+  /**
+   * Function imported directly into the world, implemented by the example1 example
+   */
+  export function hello(name: string): string;
+}
@@ -0,0 +1,19 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:include-worlds/include-worlds
+// wit: sha256:8e584a8d5f2bf90843c1c48df96bb4063c97beafe940d1982e857642444ecbc1
+
+declare module 'include-worlds' {
+  export function version(): Promise<string>;
+  export namespace counter {
+    export function increment(): Promise<Snapshot>;
+    export type Snapshot = {
+      value: bigint;
+    };
+  }
+  export namespace greeter {
+    export function greet(name: string): Promise<string>;
+  }
+  export namespace stats {
+    export function total(): Promise<bigint>;
+  }
+}
@@ -0,0 +1,18 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:init-hooks/init-hooks
+// wit: sha256:90497acf63cc2069edd2650516cd4d54373563591b925544df8f89047e80af83
+
+declare module 'init-hooks' {
+  /**
+   * Shouts the text using a string method added by the polyfills module
+   */
+  export function shout(text: string): Promise<string>;
+  /**
+   * Looks up a value the cache module loaded in its init hook
+   */
+  export function cached(key: string): Promise<number | undefined>;
+  /**
+   * The names of the modules in the order their init hooks were called
+   */
+  export function initOrder(): Promise<string[]>;
+}
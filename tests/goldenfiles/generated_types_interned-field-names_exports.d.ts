@@ -0,0 +1,27 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:interned-field-names/interned-field-names
+// wit: sha256:f0607874fbba2a73f2f5912de72982100c4b7cea838fdd887aa4b55f106f6d39
+
+declare module 'interned-field-names' {
+  export namespace api {
+    export function movePoint(p: Point, dx: number, dy: number): Promise<Point>;
+    export function moveShape(s: Shape, dx: number, dy: number): Promise<Shape>;
+    export type Point = {
+      x: number;
+      y: number;
+      label?: string;
+    };
+    export type Shape = 
+    {
+      tag: 'dot'
+      val: Point
+    } |
+    {
+      tag: 'line'
+      val: [Point, Point]
+    } |
+    {
+      tag: 'empty'
+    };
+  }
+}
@@ -0,0 +1,12 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:intl/intl
+// wit: sha256:272eb82085c30a5021309da827fdffb2272e081314f0aff14f326390febd229b
+
+declare module 'intl' {
+  export function formatNumber(locale: string, value: number, fractionDigits: number | undefined): Promise<string>;
+  export function formatPercent(value: number): Promise<string>;
+  export function formatDate(locale: string, epochMillis: number, dateStyle: string, timeStyle: string | undefined): Promise<string>;
+  export function sortWords(locale: string, words: string[]): Promise<string[]>;
+  export function toLocaleStrings(): Promise<string[]>;
+  export function invalidLocale(): Promise<string>;
+}
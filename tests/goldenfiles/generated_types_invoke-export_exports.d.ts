@@ -0,0 +1,17 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:invoke-export/invoke-export
+// wit: sha256:e5bf6400e40a07945adc81c344f28e12277ef2dd8eda6415e0a50f5fb8945c2f
+
+declare module 'invoke-export' {
+  export function greet(name: string): Promise<string>;
+  export namespace api {
+    export function add(a: number, b: number): Promise<number>;
+    export function translate(p: Point, dx: number): Promise<Point>;
+    export function big(): Promise<bigint>;
+    export function fail(): Promise<string>;
+    export type Point = {
+      x: number;
+      y: number;
+    };
+  }
+}
@@ -0,0 +1,10 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:jwt/jwt
+// wit: sha256:67b48c472da33d4ef750c4ef254e97868f7f8de96994119882bbd0d900c7af89
+
+declare module 'jwt' {
+  export function signToken(payload: string, keyName: string, options: string): Promise<string>;
+  export function verifyToken(token: string, keyName: string, options: string): Promise<string>;
+  export function roundTrip(algorithm: string, privateKey: string, publicKey: string): Promise<string[]>;
+  export function errors(): Promise<string[]>;
+}
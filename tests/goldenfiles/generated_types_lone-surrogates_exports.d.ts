@@ -0,0 +1,15 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:lone-surrogates/lone-surrogates
+// wit: sha256:9be526801f4d02b25db35ffe89ab1cfe24ca663351567fd25c022fb10c75255b
+
+declare module 'lone-surrogates' {
+  export namespace api {
+    export function truncate(s: string, len: number): Promise<string>;
+    export function makeLabelled(label: string): Promise<Labelled>;
+    export function wellFormed(s: string): Promise<string>;
+    export type Labelled = {
+      label: string;
+      tags: string[];
+    };
+  }
+}
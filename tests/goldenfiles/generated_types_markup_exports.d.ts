@@ -0,0 +1,11 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:markup/markup
+// wit: sha256:59e525a38727bcd494faad4f4c84fb64376a93e63d8519ad8a85ebce4c403c6b
+
+declare module 'markup' {
+  export function feedTitles(feed: string): Promise<string[]>;
+  export function links(page: string): Promise<string[]>;
+  export function rewrite(page: string): Promise<string>;
+  export function buildFeed(titles: string[]): Promise<string>;
+  export function errors(): Promise<string[]>;
+}
@@ -0,0 +1,16 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:message-echo/message-echo
+// wit: sha256:d132c4ae6ebd79459a3d0ee38bef99ec9a4ebe33e67d5da352ea0295a8398103
+
+declare module 'message-echo' {
+  import * as wasiMessaging020DraftTypes from 'wasi:messaging/types@0.2.0-draft';
+  /**
+   * Whenever this guest receives a message in one of the subscribed topics, the message is
+   * sent to this handler.
+   * @throws Error
+   */
+  export function onMessage(message: Message): Promise<void>;
+  export type Message = wasiMessaging020DraftTypes.Message;
+  export type Error = wasiMessaging020DraftTypes.Error;
+  export type Result<T, E> = { tag: 'ok', val: T } | { tag: 'err', val: E };
+}
@@ -0,0 +1,57 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:message-echo/message-echo
+// wit: sha256:d132c4ae6ebd79459a3d0ee38bef99ec9a4ebe33e67d5da352ea0295a8398103
+
+/**
+ * Publishing and reading messages, built on top of `wasi:messaging`.
+ */
+declare module 'messaging' {
+  import type { Message } from 'wasi:messaging/types@0.2.0-draft';
+  /**
+   * Thrown when the messaging service fails, with the service's error in the message.
+   */
+  export class MessagingError extends Error {
+  }
+  /**
+   * The data accepted by `publish`. Strings are sent UTF-8 encoded.
+   */
+  export type Data = string | Uint8Array | ArrayBuffer | ArrayBufferView;
+  export interface PublishOptions {
+    /**
+     * Name of the client connection to publish with, `default` by default. Connections are
+     * opened on their first use and reused afterwards.
+     */
+    connection?: string;
+    contentType?: string;
+    metadata?: Record<string, string>;
+  }
+  /**
+   * A received message, as returned by `readMessage`.
+   */
+  export interface ReceivedMessage {
+    topic: string | undefined;
+    contentType: string | undefined;
+    data: Uint8Array;
+    metadata: Record<string, string>;
+    /**
+     * The data decoded as UTF-8.
+     */
+    text(): string;
+    /**
+     * The data parsed as JSON.
+     */
+    json<T = unknown>(): T;
+  }
+  /**
+   * Publishes a message to a topic.
+   */
+  export function publish(topic: string, data: Data, options?: PublishOptions): Promise<void>;
+  /**
+   * Disconnects a client connection opened by `publish`.
+   */
+  export function disconnect(connection?: string): Promise<void>;
+  /**
+   * Reads the contents of a message received by the `onMessage` handler.
+   */
+  export function readMessage(message: Message): ReceivedMessage;
+}
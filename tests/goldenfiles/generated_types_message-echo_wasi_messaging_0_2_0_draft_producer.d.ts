@@ -0,0 +1,20 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:message-echo/message-echo
+// wit: sha256:d132c4ae6ebd79459a3d0ee38bef99ec9a4ebe33e67d5da352ea0295a8398103
+
+/**
+ * The producer interface is used to send messages to a channel/topic.
+ */
+declare module 'wasi:messaging/producer@0.2.0-draft' {
+  import * as wasiMessaging020DraftTypes from 'wasi:messaging/types@0.2.0-draft';
+  /**
+   * Sends the message using the given client.
+   * @throws Error
+   */
+  export function send(c: Client, topic: Topic, message: Message): void;
+  export type Client = wasiMessaging020DraftTypes.Client;
+  export type Message = wasiMessaging020DraftTypes.Message;
+  export type Error = wasiMessaging020DraftTypes.Error;
+  export type Topic = wasiMessaging020DraftTypes.Topic;
+  export type Result<T, E> = { tag: 'ok', val: T } | { tag: 'err', val: E };
+}
@@ -0,0 +1,84 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:message-echo/message-echo
+// wit: sha256:d132c4ae6ebd79459a3d0ee38bef99ec9a4ebe33e67d5da352ea0295a8398103
+
+declare module 'wasi:messaging/types@0.2.0-draft' {
+  export class Client {
+    /**
+     * @throws Error
+     */
+    static connect(name: string): Client;
+    disconnect(): void;
+  }
+  export class Message {
+    constructor(data: Uint8Array);
+    /**
+     * The topic/subject/channel this message was received on, if any
+     */
+    topic(): Topic | undefined;
+    /**
+     * An optional content-type describing the format of the data in the message
+     */
+    contentType(): string | undefined;
+    /**
+     * Set the content-type describing the format of the data in the message
+     */
+    setContentType(contentType: string): void;
+    /**
+     * An opaque blob of data
+     */
+    data(): Uint8Array;
+    /**
+     * Set the opaque blob of data for this message, discarding the old value
+     */
+    setData(buf: Uint8Array): void;
+    /**
+     * Optional metadata (also called headers or attributes in some systems) attached to the message
+     */
+    metadata(): Metadata | undefined;
+    /**
+     * Add a new key-value pair to the metadata, overwriting any existing value for the same key
+     */
+    addMetadata(key: string, value: string): void;
+    /**
+     * Set the metadata
+     */
+    setMetadata(meta: Metadata): void;
+    /**
+     * Remove a key-value pair from the metadata
+     */
+    removeMetadata(key: string): void;
+  }
+  /**
+   * Errors that can occur when using the messaging interface.
+   */
+  export type Error = 
+  /** The request or operation timed out. */
+  {
+    tag: 'timeout'
+  } |
+  /** An error occurred with the connection. Includes a message for additional context */
+  {
+    tag: 'connection'
+    val: string
+  } |
+  /** A permission error where the operation is forbidden. Includes a message for additional context */
+  {
+    tag: 'permission-denied'
+    val: string
+  } |
+  /** A catch all for other types of errors */
+  {
+    tag: 'other'
+    val: string
+  };
+  /**
+   * A key-value pair, used for metadata
+   */
+  export type Metadata = [string, string][];
+  /**
+   * The topic a message is sent to
+   */
+  export type Topic = string;
+  export type Result<T, E> = { tag: 'ok', val: T } | { tag: 'err', val: E };
+}
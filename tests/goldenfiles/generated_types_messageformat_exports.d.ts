@@ -0,0 +1,11 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:messageformat/messageformat
+// wit: sha256:72c61b1c24da26ef232e94167bea17b8c2a8f08d64ab9395c4dd48585a4abc68
+
+declare module 'messageformat' {
+  export function cart(locale: string, name: string, count: number): Promise<string>;
+  export function ranking(place: number): Promise<string>;
+  export function invitation(host: string, guests: string[]): Promise<string>;
+  export function receipt(locale: string, total: number, date: number): Promise<string>;
+  export function errors(): Promise<string[]>;
+}
@@ -0,0 +1,29 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:mocked-golem-imports/mocked-golem-imports
+// wit: sha256:e2a5c934071279725dae2fd75e09aa4ebe6992aaedf7cac0976199427144664e
+
+/**
+ * Mocks of the imported interfaces, used instead of the host when the wrapper crate is built with
+ * the `mock-imports` feature. Interfaces are given by their module names, and functions by their
+ * JS names: `Class.method` for the static functions of resources, and `new Class` for their
+ * constructors.
+ */
+declare module '__mocks' {
+  /**
+   * Sets the implementation of an imported function. Calling a function without a mock throws an
+   * error.
+   */
+  export function mock(iface: string, fn: string, implementation: (...args: any[]) => any): void;
+  /**
+   * Sets the implementations of several functions of an imported interface, by their names.
+   */
+  export function mockModule(iface: string, implementations: Record<string, (...args: any[]) => any>): void;
+  /**
+   * Gets the arguments of the calls of a mocked function, in order.
+   */
+  export function calls(iface: string, fn: string): unknown[][];
+  /**
+   * Removes the mocks and the recorded calls of an interface, or of all interfaces.
+   */
+  export function resetMocks(iface?: string): void;
+}
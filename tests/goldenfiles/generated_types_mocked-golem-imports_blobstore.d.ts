@@ -0,0 +1,93 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:mocked-golem-imports/mocked-golem-imports
+// wit: sha256:e2a5c934071279725dae2fd75e09aa4ebe6992aaedf7cac0976199427144664e
+
+/**
+ * An async API for blob stores with web stream support, built on top of `wasi:blobstore`.
+ */
+declare module 'blobstore' {
+  /**
+   * Thrown when the blob store fails, with the store's error in the message.
+   */
+  export class BlobstoreError extends Error {
+  }
+  /**
+   * The data accepted by `Container.put`. Strings are stored UTF-8 encoded.
+   */
+  export type Data = string | Uint8Array | ArrayBuffer | ArrayBufferView;
+  /**
+   * A byte range of an object: `start` is inclusive, `end` is exclusive and defaults to the size
+   * of the object.
+   */
+  export interface Range {
+    start?: number | bigint;
+    end?: number | bigint;
+  }
+  /**
+   * Identifies an object of a container.
+   */
+  export interface ObjectId {
+    container: string;
+    object: string;
+  }
+  export interface ContainerInfo {
+    name: string;
+    createdAt: Date;
+  }
+  export interface ObjectInfo {
+    name: string;
+    container: string;
+    createdAt: Date;
+    size: number;
+  }
+  /**
+   * A container of objects, iterable with `for await` over the names of its objects.
+   */
+  export class Container implements AsyncIterable<string> {
+    readonly name: string;
+    info(): Promise<ContainerInfo>;
+    /**
+     * Reads an object, or a range of it, into memory.
+     */
+    get(name: string, range?: Range): Promise<Uint8Array>;
+    /**
+     * Reads an object, or a range of it, decoded as UTF-8.
+     */
+    getText(name: string, range?: Range): Promise<string>;
+    /**
+     * Streams an object, or a range of it.
+     */
+    readable(name: string, range?: Range): ReadableStream<Uint8Array>;
+    /**
+     * Writes an object, replacing the existing object.
+     */
+    put(name: string, data: Data | ReadableStream<Data>): Promise<void>;
+    /**
+     * A stream writing an object, which is stored when the stream is closed.
+     */
+    writable(name: string): WritableStream<Data>;
+    delete(name: string): Promise<void>;
+    deleteMany(names: string[]): Promise<void>;
+    has(name: string): Promise<boolean>;
+    objectInfo(name: string): Promise<ObjectInfo>;
+    /**
+     * Iterates over the names of the objects, reading them from the store in batches.
+     */
+    objects(): AsyncIterableIterator<string>;
+    /**
+     * Lists the names of all objects.
+     */
+    list(): Promise<string[]>;
+    /**
+     * Deletes all objects.
+     */
+    clear(): Promise<void>;
+    [Symbol.asyncIterator](): AsyncIterableIterator<string>;
+  }
+  export function createContainer(name: string): Promise<Container>;
+  export function getContainer(name: string): Promise<Container>;
+  export function deleteContainer(name: string): Promise<void>;
+  export function containerExists(name: string): Promise<boolean>;
+  export function copyObject(src: ObjectId, dest: ObjectId): Promise<void>;
+  export function moveObject(src: ObjectId, dest: ObjectId): Promise<void>;
+}
@@ -0,0 +1,12 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:mocked-golem-imports/mocked-golem-imports
+// wit: sha256:e2a5c934071279725dae2fd75e09aa4ebe6992aaedf7cac0976199427144664e
+
+declare module 'mocked-golem-imports' {
+  export namespace api {
+    export function queryUsers(): Promise<string>;
+    export function storeBlob(): Promise<string>;
+    export function chat(): Promise<string>;
+    export function runAtomically(): Promise<string>;
+  }
+}
@@ -0,0 +1,45 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:mocked-golem-imports/mocked-golem-imports
+// wit: sha256:e2a5c934071279725dae2fd75e09aa4ebe6992aaedf7cac0976199427144664e
+
+declare module 'golem:rdbms/types@0.0.1' {
+  export type Uuid = {
+    highBits: bigint;
+    lowBits: bigint;
+  };
+  export type IpAddress = 
+  {
+    tag: 'ipv4'
+    val: [number, number, number, number]
+  } |
+  {
+    tag: 'ipv6'
+    val: [number, number, number, number, number, number, number, number]
+  };
+  export type MacAddress = {
+    octets: [number, number, number, number, number, number];
+  };
+  export type Date = {
+    year: number;
+    month: number;
+    day: number;
+  };
+  export type Time = {
+    hour: number;
+    minute: number;
+    second: number;
+    nanosecond: number;
+  };
+  export type Timestamp = {
+    date: Date;
+    time: Time;
+  };
+  export type Timestamptz = {
+    timestamp: Timestamp;
+    offset: number;
+  };
+  export type Timetz = {
+    time: Time;
+    offset: number;
+  };
+}
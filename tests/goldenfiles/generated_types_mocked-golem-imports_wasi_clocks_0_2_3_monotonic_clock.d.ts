@@ -0,0 +1,47 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:mocked-golem-imports/mocked-golem-imports
+// wit: sha256:e2a5c934071279725dae2fd75e09aa4ebe6992aaedf7cac0976199427144664e
+
+/**
+ * WASI Monotonic Clock is a clock API intended to let users measure elapsed
+ * time.
+ * It is intended to be portable at least between Unix-family platforms and
+ * Windows.
+ * A monotonic clock is a clock which has an unspecified initial value, and
+ * successive reads of the clock will produce non-decreasing values.
+ */
+declare module 'wasi:clocks/monotonic-clock@0.2.3' {
+  import * as wasiIo023Poll from 'wasi:io/poll@0.2.3';
+  /**
+   * Read the current value of the clock.
+   * The clock is monotonic, therefore calling this function repeatedly will
+   * produce a sequence of non-decreasing values.
+   */
+  export function now(): Instant;
+  /**
+   * Query the resolution of the clock. Returns the duration of time
+   * corresponding to a clock tick.
+   */
+  export function resolution(): Duration;
+  /**
+   * Create a `pollable` which will resolve once the specified instant
+   * has occurred.
+   */
+  export function subscribeInstant(when: Instant): Pollable;
+  /**
+   * Create a `pollable` that will resolve after the specified duration has
+   * elapsed from the time this function is invoked.
+   */
+  export function subscribeDuration(when: Duration): Pollable;
+  export type Pollable = wasiIo023Poll.Pollable;
+  /**
+   * An instant in time, in nanoseconds. An instant is relative to an
+   * unspecified initial value, and can only be compared to instances from
+   * the same monotonic-clock.
+   */
+  export type Instant = bigint;
+  /**
+   * A duration of time, in nanoseconds.
+   */
+  export type Duration = bigint;
+}
@@ -0,0 +1,17 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:mocked-golem-imports/mocked-golem-imports
+// wit: sha256:e2a5c934071279725dae2fd75e09aa4ebe6992aaedf7cac0976199427144664e
+
+declare module 'wasi:io/error@0.2.3' {
+  export class Error {
+    /**
+     * Returns a string that is suitable to assist humans in debugging
+     * this error.
+     * WARNING: The returned string should not be consumed mechanically!
+     * It may change across platforms, hosts, or other implementation
+     * details. Parsing this string is a major platform-compatibility
+     * hazard.
+     */
+    toDebugString(): string;
+  }
+}
@@ -0,0 +1,10 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:mocked-imports/mocked-imports
+// wit: sha256:354e129e1104f0ffb11dee061b8439069cbca221ddb2c4be9d89a6cda724656f
+
+declare module 'mocked-imports' {
+  export namespace api {
+    export function rollWithMock(value: bigint): Promise<[number, number]>;
+    export function rollWithoutMock(): Promise<string>;
+  }
+}
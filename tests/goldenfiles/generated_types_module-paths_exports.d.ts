@@ -0,0 +1,14 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:module-paths/module-paths
+// wit: sha256:795d0edfde5da205ce1d5c0af07505a42843fcb549e93b8db5dbc0401cdec982
+
+declare module 'module-paths' {
+  /**
+   * Greets the given name, using functions of the other embedded modules
+   */
+  export function hello(name: string): Promise<string>;
+  /**
+   * Frames the text between two separator lines
+   */
+  export function banner(text: string): Promise<string>;
+}
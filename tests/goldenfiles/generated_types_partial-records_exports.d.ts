@@ -0,0 +1,29 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:partial-records/partial-records
+// wit: sha256:57a968ccc636cf583cf4c7a671b19deec4dcc1b7bfb5e6849a288bdfff5c75ae
+
+declare module 'partial-records' {
+  export namespace api {
+    export function personWithoutNickname(): Promise<Person>;
+    export function personWithExtraProperties(): Promise<Person>;
+    export function personWithoutNameAndAge(): Promise<Person>;
+    export function ordersWithInvalidZip(): Promise<Order[]>;
+    export type Person = {
+      name: string;
+      age: number;
+      nickname?: string;
+    };
+    export type Address = {
+      street: string;
+      zip: string;
+    };
+    export type Customer = {
+      name: string;
+      address: Address;
+    };
+    export type Order = {
+      id: number;
+      customer: Customer;
+    };
+  }
+}
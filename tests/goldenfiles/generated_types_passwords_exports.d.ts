@@ -0,0 +1,9 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:passwords/passwords
+// wit: sha256:b1cdcd38342e041fe8a5ad01da97988dd0922211b7aba5b41b2a294a4f6faf25
+
+declare module 'passwords' {
+  export function hashAndVerify(options: string, password: string, attempt: string): Promise<string[]>;
+  export function deriveKey(password: string, salt: string, hash: string, iterations: number, length: number): Promise<string>;
+  export function errors(): Promise<string[]>;
+}
@@ -0,0 +1,10 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:protobuf/protobuf
+// wit: sha256:64a6185b9b33240897a0051ee9845bc1c0b5e43e1be293f8b9ae7782549cab2f
+
+declare module 'protobuf' {
+  export function roundTrip(): Promise<string>;
+  export function grpcUnary(port: number): Promise<string>;
+  export function grpcStreaming(port: number): Promise<string[]>;
+  export function grpcError(port: number): Promise<string>;
+}
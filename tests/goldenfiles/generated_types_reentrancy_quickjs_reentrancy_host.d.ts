@@ -0,0 +1,10 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:reentrancy/reentrancy
+// wit: sha256:a99f31a2e30ae24e45da017eaec14c8b0c4357232933a15584d8acee3a9fa3d1
+
+/**
+ * Implemented by the host calling back into the component's exports
+ */
+declare module 'quickjs:reentrancy/host' {
+  export function reenter(): void;
+}
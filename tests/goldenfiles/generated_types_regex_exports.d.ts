@@ -0,0 +1,13 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:regex/regex
+// wit: sha256:0ae8bf43df98af4f9839de04678b1299eccfbf7eb0028b75d4a6b0499c642a28
+
+declare module 'regex' {
+  export function exec(pattern: string, regexFlags: string, text: string, start: number): Promise<string>;
+  export function matchAll(pattern: string, regexFlags: string, text: string): Promise<string[]>;
+  export function replaceAll(pattern: string, text: string, replacement: string): Promise<string>;
+  export function replaceWithFunction(pattern: string, text: string): Promise<string>;
+  export function split(pattern: string, text: string): Promise<string[]>;
+  export function literal(text: string, haystack: string): Promise<boolean>;
+  export function errors(): Promise<string[]>;
+}
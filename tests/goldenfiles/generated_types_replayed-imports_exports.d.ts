@@ -0,0 +1,10 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:replayed-imports/replayed-imports
+// wit: sha256:b706b2f816488e20c2dbed9ffb5fb07ff5fd3386eb39c9b585fbc1a0f3bc5b06
+
+declare module 'replayed-imports' {
+  export namespace api {
+    export function randomNumbers(): Promise<bigint[]>;
+    export function randomBytes(): Promise<Uint8Array>;
+  }
+}
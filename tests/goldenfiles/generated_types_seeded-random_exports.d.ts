@@ -0,0 +1,9 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:seeded-random/seeded-random
+// wit: sha256:9393de598598d3f1809a90e96bb6b160fbe30993a3300b67d57c188d8e94f87a
+
+declare module 'seeded-random' {
+  export namespace api {
+    export function numbers(count: number): Promise<number[]>;
+  }
+}
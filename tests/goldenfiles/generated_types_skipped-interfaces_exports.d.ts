@@ -0,0 +1,9 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:skipped-interfaces/skipped-interfaces
+// wit: sha256:474b3148d6a222f91be345689b4ecff98ff3b1a3ef6a4559b4e917fb594c52ee
+
+declare module 'skipped-interfaces' {
+  export namespace api {
+    export function greet(name: string): Promise<string>;
+  }
+}
@@ -1,3 +1,7 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:stateful1/stateful1
+// wit: sha256:4bb6f4ed95a47c490ee617ce022ac66e3c08cf8a0aac6b551a91eb60f51b673d
+
 declare module 'stateful1' {
   export function inc(delta: number): Promise<void>;
   export function get(): Promise<number>;
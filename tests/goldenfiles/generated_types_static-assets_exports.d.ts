@@ -0,0 +1,10 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:static-assets/static-assets
+// wit: sha256:1d42618a7260379bb53ef20825a2e1590dc113c4cd401a8bccdaf120df41b7ea
+
+declare module 'static-assets' {
+  export function renderPage(title: string): Promise<string>;
+  export function logoSize(): Promise<number>;
+  export function listAssets(): Promise<string[]>;
+  export function readMissing(): Promise<string>;
+}
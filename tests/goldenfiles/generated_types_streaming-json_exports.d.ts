@@ -0,0 +1,32 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:streaming-json/streaming-json
+// wit: sha256:c1a64da792a3a4a9eb621563a83cfa92fc31171862bba53867151cc6b6cc5e4a
+
+declare module 'streaming-json' {
+  /**
+   * Parses a JSON document split into the given chunks, returning the parser events
+   */
+  export function traceEvents(chunks: Uint8Array[]): Promise<string[]>;
+  /**
+   * Sums the `price` fields of the objects in a JSON array, parsing it item by item
+   */
+  export function sumPrices(chunks: string[]): Promise<number>;
+  /**
+   * Parses newline-delimited JSON objects, returning their `id` fields
+   */
+  export function ids(ndjson: string): Promise<number[]>;
+  /**
+   * Serializes a generated array in chunks, returning the number of chunks and whether they add
+   * up to the result of `JSON.stringify`
+   */
+  export function stringifyChunks(items: number, chunkSize: number): Promise<[number, boolean]>;
+  /**
+   * Streams a document through the serializer and the parser, returning the parsed value
+   * serialized with `JSON.stringify`
+   */
+  export function roundTrip(json: string): Promise<string>;
+  /**
+   * Returns the error of parsing an invalid document
+   */
+  export function parseError(json: string): Promise<string>;
+}
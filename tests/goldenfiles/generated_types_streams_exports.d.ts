@@ -1,3 +1,7 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:streams/streams
+// wit: sha256:66350824cb586f62c293e2511adee9cf85bc5bc41d610a0d579706d8a441fab5
+
 declare module 'streams' {
   export function test1(): Promise<void>;
 }
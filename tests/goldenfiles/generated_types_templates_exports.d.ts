@@ -0,0 +1,16 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:templates/templates
+// wit: sha256:3610a967960a27f9a5c8891244c48f4e53984910e07b00a478bc4d8bc1c98321
+
+declare module 'templates' {
+  export function greeting(name: string): Promise<string>;
+  export function invoice(customer: string, items: Item[]): Promise<string>;
+  export function welcomeHtml(name: string, url: string): Promise<string>;
+  export function welcomeText(name: string): Promise<string>;
+  export function errors(): Promise<string[]>;
+  export type Item = {
+    description: string;
+    quantity: number;
+    price: number;
+  };
+}
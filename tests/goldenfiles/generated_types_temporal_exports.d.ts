@@ -0,0 +1,13 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:temporal/temporal
+// wit: sha256:fc8b7f9544089cf3ab487ae29ff5a07be05e36fd9d405d7a44cddbdda281950c
+
+declare module 'temporal' {
+  export function addToZoned(start: string, duration: string): Promise<string>;
+  export function difference(start: string, end: string, largestUnit: string): Promise<string>;
+  export function daily(firstDay: string, hour: number, timeZone: string, days: number): Promise<string[]>;
+  export function describeDate(date: string): Promise<string>;
+  export function totalHours(duration: string, relativeTo: string | undefined): Promise<number>;
+  export function nowMatchesDate(): Promise<boolean>;
+  export function errors(): Promise<string[]>;
+}
@@ -1,3 +1,7 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:timeout/timeout
+// wit: sha256:dbc005a0f94e94208cd7b708fb11ad4d5f6cd2e709d4626345841c22a6c06d06
+
 declare module 'timeout' {
   export function run(): Promise<void>;
   export function parallel(): Promise<void>;
@@ -1,3 +1,7 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:types-in-exports/types-in-exports
+// wit: sha256:c65d4cf2d90de3c5e1dacdb67056d769093806c9fe281e02f73ba6efc98aa513
+
 declare module 'types-in-exports' {
   import * as quickjsTypesInExportsOtherTypes from 'quickjs:types-in-exports/other-types';
   export namespace types {
@@ -1,3 +1,7 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:url/url
+// wit: sha256:3f3c6b4f8ab6d442d89ddb2aa4c136e437d3b64460888c4480a6fea3a02ec4bc
+
 declare module 'url' {
   export function test1(): Promise<boolean>;
   export function test2(): Promise<boolean>;
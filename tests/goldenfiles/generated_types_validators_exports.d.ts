@@ -0,0 +1,18 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:validators/validators
+// wit: sha256:348734c1f30f5c037026f4c496a32c55a1edc029707cf05266d927dd63b16ee0
+
+declare module 'validators' {
+  import * as quickjsValidatorsTypes from 'quickjs:validators/types';
+  export namespace api {
+    /**
+     * Creates an order, validating it before returning it
+     */
+    export function createOrder(id: bigint, customer: string): Promise<Order>;
+    /**
+     * Validates one of the predefined broken orders, returning the validation error
+     */
+    export function checkBrokenOrder(name: string): Promise<string>;
+    export type Order = quickjsValidatorsTypes.Order;
+  }
+}
@@ -0,0 +1,32 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:validators/validators
+// wit: sha256:348734c1f30f5c037026f4c496a32c55a1edc029707cf05266d927dd63b16ee0
+
+declare module 'quickjs:validators/types' {
+  export type Customer = {
+    name: string;
+    email?: string;
+  };
+  export type Status = "pending" | "shipped";
+  export type Labels = {
+    urgent: boolean;
+    gift: boolean;
+  };
+  export type Payment = 
+  {
+    tag: 'cash'
+  } |
+  {
+    tag: 'card'
+    val: string
+  };
+  export type Order = {
+    id: bigint;
+    customer: Customer;
+    items: [string, number][];
+    status: Status;
+    labels: Labels;
+    payment: Payment;
+    attachment?: Uint8Array;
+  };
+}
@@ -0,0 +1,11 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:validators/validators
+// wit: sha256:348734c1f30f5c037026f4c496a32c55a1edc029707cf05266d927dd63b16ee0
+
+declare module 'validators' {
+  export function validateCustomer(value: unknown): void;
+  export function validateStatus(value: unknown): void;
+  export function validateLabels(value: unknown): void;
+  export function validatePayment(value: unknown): void;
+  export function validateOrder(value: unknown): void;
+}
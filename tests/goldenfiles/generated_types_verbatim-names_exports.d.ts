@@ -0,0 +1,20 @@
+// @generated by wasm-rquickjs 0.0.0, do not edit
+// world: quickjs:verbatim-names/verbatim-names
+// wit: sha256:ba32c0e6b43734727241d133e942309ae2c49a9225e20f44c8c1fb0a4150ec12
+
+declare module 'verbatim-names' {
+  export namespace textTools {
+    export class WordCounter {
+      constructor(text: string);
+      "count-words"(): Promise<number>;
+    }
+  }
+  const _default: {
+    "say-hello"(name: string): Promise<string>;
+    "text-tools": {
+      "to-upper-case"(value: string): Promise<string>;
+      "word-counter": typeof textTools.WordCounter;
+    };
+  };
+  export default _default;
+}
@@ -0,0 +1,154 @@
+test_r::enable!();
+
+use crate::common::example_js_modules;
+use camino::Utf8Path;
+use camino_tempfile::Utf8TempDir;
+use test_r::test;
+use wasm_rquickjs::paths::{relative_slash_path, simplified, to_slash};
+use wasm_rquickjs::{GeneratorOptions, generate_inline_module, generate_wrapper_crate};
+
+#[allow(dead_code)]
+mod common;
+
+#[test]
+fn to_slash_accepts_both_separators() {
+    assert_eq!(
+        to_slash(Utf8Path::new(r"deps\random\random.wit")),
+        "deps/random/random.wit"
+    );
+    assert_eq!(to_slash(Utf8Path::new(r".\deps/random\")), "deps/random");
+    assert_eq!(to_slash(Utf8Path::new("deps/random")), "deps/random");
+}
+
+#[test]
+fn relative_slash_path_of_backslash_separated_paths() {
+    assert_eq!(
+        relative_slash_path(
+            Utf8Path::new(r"examples\imports1\wit\deps\random\random.wit"),
+            Utf8Path::new(r"examples\imports1\wit")
+        ),
+        Some("deps/random/random.wit".to_string())
+    );
+    assert_eq!(
+        relative_slash_path(
+            Utf8Path::new(r"examples\imports1\wit\deps\random\random.wit"),
+            Utf8Path::new("examples/imports1/wit/")
+        ),
+        Some("deps/random/random.wit".to_string())
+    );
+}
+
+#[test]
+fn relative_slash_path_of_drive_letter_roots() {
+    assert_eq!(
+        relative_slash_path(
+            Utf8Path::new(r"C:\work\component\wit\deps\io\streams.wit"),
+            Utf8Path::new(r"c:\work\component\wit")
+        ),
+        Some("deps/io/streams.wit".to_string())
+    );
+    assert_eq!(
+        relative_slash_path(
+            Utf8Path::new(r"\\?\C:\work\component\wit\world.wit"),
+            Utf8Path::new("C:/work/component/wit")
+        ),
+        Some("world.wit".to_string())
+    );
+    assert_eq!(
+        relative_slash_path(
+            Utf8Path::new(r"D:\work\component\wit\world.wit"),
+            Utf8Path::new(r"C:\work\component\wit")
+        ),
+        None
+    );
+}
+
+#[test]
+fn relative_slash_path_requires_whole_segments() {
+    assert_eq!(
+        relative_slash_path(
+            Utf8Path::new("/work/wit-deps/a.wit"),
+            Utf8Path::new("/work/wit")
+        ),
+        None
+    );
+    assert_eq!(
+        relative_slash_path(Utf8Path::new("work/wit/a.wit"), Utf8Path::new("/work/wit")),
+        None
+    );
+}
+
+#[test]
+fn simplified_removes_verbatim_prefix_of_drive_paths() {
+    assert_eq!(
+        simplified(Utf8Path::new(r"\\?\C:\work\component")),
+        Utf8Path::new(r"C:\work\component")
+    );
+    assert_eq!(
+        simplified(Utf8Path::new(r"\\?\UNC\server\share")),
+        Utf8Path::new(r"\\?\UNC\server\share")
+    );
+    assert_eq!(
+        simplified(Utf8Path::new("/work/component")),
+        Utf8Path::new("/work/component")
+    );
+}
+
+#[test]
+fn wit_dependencies_use_forward_slashes() -> anyhow::Result<()> {
+    let path = Utf8Path::new("examples/imports1");
+    let output = Utf8TempDir::new()?;
+    // A non-normalized WIT path, whose textual prefix differs from the paths of its files
+    generate_wrapper_crate(
+        Utf8Path::new("examples/./imports1/wit/"),
+        &example_js_modules(path),
+        output.path(),
+        None,
+        &GeneratorOptions::default(),
+    )?;
+
+    let cargo_toml = std::fs::read_to_string(output.path().join("Cargo.toml"))?;
+    assert!(
+        cargo_toml.contains(r#"path = "wit/deps/random""#),
+        "unexpected dependencies in Cargo.toml:\n{cargo_toml}"
+    );
+    Ok(())
+}
+
+#[cfg(unix)]
+#[test]
+fn wrapper_crates_can_be_generated_into_non_utf8_directories() -> anyhow::Result<()> {
+    use std::os::unix::ffi::OsStrExt;
+
+    let path = Utf8Path::new("examples/imports1");
+    let dir = Utf8TempDir::new()?;
+    let output = dir
+        .path()
+        .as_std_path()
+        .join(std::ffi::OsStr::from_bytes(b"out-\xff"));
+
+    generate_wrapper_crate(
+        path.join("wit"),
+        &example_js_modules(path),
+        &output,
+        None,
+        &GeneratorOptions::default(),
+    )?;
+    assert!(output.join("Cargo.toml").is_file());
+    assert!(output.join("wit").join("deps").join("random").is_dir());
+
+    // The inline module embeds the absolute paths into the generated code
+    let error = generate_inline_module(
+        path.join("wit"),
+        &example_js_modules(path),
+        &output,
+        None,
+        &GeneratorOptions::default(),
+    )
+    .unwrap_err();
+    assert!(
+        format!("{error:#}").contains("is not valid UTF-8"),
+        "unexpected error: {error:#}"
+    );
+    Ok(())
+}